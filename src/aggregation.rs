@@ -0,0 +1,29 @@
+//! Per-indicator aggregation: how a day with several observations becomes
+//! one value. Minutes exercised add up, the last weigh-in stands, ratings
+//! average. The rule lives on the indicator; `IndicatorAggregation::daily`
+//! applies it.
+
+use crate::models::{IndicatorAggregation, IndicatorsData};
+use anyhow::{bail, Result};
+
+/// Combine the days of the indicator found by id or name with
+/// `aggregation`; returns what happened
+pub fn set_aggregation(
+    indicators: &mut IndicatorsData,
+    indicator: &str,
+    aggregation: IndicatorAggregation,
+) -> Result<String> {
+    let Some(id) = indicators.find(indicator).map(|def| def.id.clone()) else {
+        bail!("No indicator named '{}'", indicator);
+    };
+    let Some(def) = indicators.indicators.iter_mut().find(|def| def.id == id) else {
+        bail!("No indicator named '{}'", indicator);
+    };
+    def.aggregation = aggregation;
+    def.modified = chrono::Utc::now();
+    Ok(match aggregation {
+        IndicatorAggregation::Last => format!("{} now keeps the last value of each day", def.name),
+        IndicatorAggregation::Sum => format!("{} now adds up each day's values", def.name),
+        IndicatorAggregation::Average => format!("{} now averages each day's values", def.name),
+    })
+}
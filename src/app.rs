@@ -2,6 +2,7 @@ use crate::data::{get_yesterday_goals, load_or_create_templates, save_objectives
 use crate::models::{
     ActionTemplates, Config, DailyGoals, FiveYearVision, OutcomeType, RitualPhase,
 };
+use crate::summary;
 use crate::ui_state;
 use anyhow::Result;
 use chrono::{Local, Timelike};
@@ -970,6 +971,12 @@ impl App {
                     priority: None,
                     tags: vec![],
                     objective_id: None,
+                    carried_from: None,
+                    snoozed_from: None,
+                    blocked_by: None,
+                    scheduled_at: None,
+                    weekly_focus: false,
+                    completed_at: None,
                 };
                 action_meta_list.push(meta);
             } else {
@@ -1481,7 +1488,11 @@ impl App {
                                 indicators[selection_index].name = buffer.clone();
                             }
                             IndicatorEditField::Target(ref buffer) => {
-                                indicators[selection_index].target = buffer.parse::<f64>().ok();
+                                let indicator = &mut indicators[selection_index];
+                                let parsed = crate::targets::parse_target(buffer, &indicator.unit);
+                                indicator.target = parsed.as_ref().ok().map(|(target, _)| *target);
+                                indicator.target_period =
+                                    parsed.map(|(_, period)| period).unwrap_or_default();
                             }
                             IndicatorEditField::Notes(ref buffer) => {
                                 indicators[selection_index].notes = if buffer.is_empty() {
@@ -1627,9 +1638,14 @@ impl App {
             }
             KeyCode::Char('t') if selection_index < indicators.len() => {
                 // Edit target
-                let current_target = indicators[selection_index]
+                // "20" or "20/week", as parse_target reads it back
+                let indicator = &indicators[selection_index];
+                let current_target = indicator
                     .target
-                    .map(|t| t.to_string())
+                    .map(|t| match indicator.target_period {
+                        crate::models::TargetPeriod::Day => t.to_string(),
+                        period => format!("{}/{}", t, period.as_str()),
+                    })
                     .unwrap_or_default();
                 self.input_mode = InputMode::IndicatorManagement {
                     objective_id,
@@ -1752,7 +1768,7 @@ impl App {
                 let current_value = self.get_latest_indicator_value(indicator_id).unwrap_or(0.0);
 
                 // Calculate progress as percentage of target
-                let progress = if let Some(target) = indicator.target {
+                let progress = if let Some(target) = indicator.target_on(self.goals.date) {
                     if target > 0.0 {
                         (current_value / target * 100.0).min(100.0)
                     } else {
@@ -1814,6 +1830,7 @@ impl App {
                     action_id: None,
                     note: None,
                     created: chrono::Utc::now(),
+                    at: None,
                 };
 
                 // Save observation to observations file
@@ -2015,7 +2032,12 @@ impl App {
                         unit.clone(),
                     );
                     indicator.objective_id = Some(objective_id.clone());
-                    indicator.target = target_buffer.parse::<f64>().ok();
+                    if let Ok((target, period)) =
+                        crate::targets::parse_target(&target_buffer, &indicator.unit)
+                    {
+                        indicator.target = Some(target);
+                        indicator.target_period = period;
+                    }
                     indicator.direction = direction;
                     indicator.notes = if notes_buffer.is_empty() {
                         None
@@ -2094,8 +2116,8 @@ impl App {
                         }
                     }
                     3 => {
-                        // Target (numeric only)
-                        if c.is_ascii_digit() || c == '.' {
+                        // Target, e.g. "8" or "20km/week"
+                        if c.is_ascii_alphanumeric() || c == '.' || c == '/' {
                             target_buffer.push(c);
                         }
                     }
@@ -2282,49 +2304,28 @@ impl App {
         self.toggle_action_by_global_index(index);
     }
 
-    /// Generate daily summary for evening review
+    /// Generate daily summary for evening review from the summary template
     pub fn generate_daily_summary(&mut self) {
-        let stats = self.goals.completion_stats();
-        let mut summary = String::new();
-
-        summary.push_str(&format!(
-            "📊 Day {} Summary\n",
-            self.goals.day_number.unwrap_or(0)
-        ));
-        summary.push_str(&format!(
-            "Completion: {}/{} ({}%)\n\n",
-            stats.completed, stats.total, stats.percentage
-        ));
-
-        // Per-outcome breakdown
-        for (name, done, total) in &stats.by_outcome {
-            let emoji = match *done {
-                3 => "✅",
-                2 => "🔶",
-                1 => "⚠️",
-                _ => "❌",
-            };
-            summary.push_str(&format!("{} {}: {}/{}\n", emoji, name, done, total));
-        }
-
-        // Add reflections if any
-        if self.goals.work.reflection.is_some()
-            || self.goals.health.reflection.is_some()
-            || self.goals.family.reflection.is_some()
-        {
-            summary.push_str("\n📝 Reflections:\n");
-            if let Some(ref r) = self.goals.work.reflection {
-                summary.push_str(&format!("Work: {}\n", r));
-            }
-            if let Some(ref r) = self.goals.health.reflection {
-                summary.push_str(&format!("Health: {}\n", r));
-            }
-            if let Some(ref r) = self.goals.family.reflection {
-                summary.push_str(&format!("Family: {}\n", r));
+        let settings = crate::data::load_settings(&self.config).unwrap_or_default();
+        match summary::summary_for_day(
+            &self.goals,
+            &self.indicators,
+            &settings.summary,
+            &self.config,
+        ) {
+            Ok(text) => {
+                self.daily_summary = text;
+                self.set_error("Daily summary generated! Press '?' to view".to_string());
+            }
+            Err(e) => {
+                // Fall back to the built-in template so the evening still has a summary
+                let input = summary::SummaryInput::gather(&self.goals, &self.indicators, &[]);
+                let values = summary::summary_values(&self.goals, &input, None);
+                self.daily_summary =
+                    summary::render_template(summary::DEFAULT_SUMMARY_TEMPLATE, &values)
+                        .unwrap_or_default();
+                self.set_error(format!("{:#}; using the built-in summary", e));
             }
         }
-
-        self.daily_summary = summary;
-        self.set_error("Daily summary generated! Press '?' to view".to_string());
     }
 }
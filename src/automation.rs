@@ -0,0 +1,98 @@
+//! Auto-observation rules: an indicator can carry rules like "when an
+//! action tagged #pushups completes, add 20", checked whenever an action is
+//! checked off. Tags are `#hashtags` in the action text or tags kept in the
+//! day's metadata, such as the project of an imported task.
+
+use crate::models::{Action, AutoRule, IndicatorsData, Observation, ObservationSource};
+use anyhow::{bail, Result};
+use chrono::NaiveDate;
+
+/// Lowercase tags of an action: hashtags in its text, then `extra` tags
+pub fn action_tags(text: &str, extra: &[String]) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    let hashtags = text
+        .split_whitespace()
+        .filter_map(|word| word.strip_prefix('#'))
+        .map(|tag| {
+            tag.trim_end_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        });
+    for tag in hashtags.chain(extra.iter().map(|tag| normalize_tag(tag))) {
+        if !tag.is_empty() && !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    tags
+}
+
+/// A tag as rules store it: lowercase, without the leading '#'
+pub fn normalize_tag(tag: &str) -> String {
+    tag.trim().trim_start_matches('#').to_lowercase()
+}
+
+/// Observations the rules of active indicators add for a completed action
+/// with `tags`, dated `date` and attributed to the action
+pub fn observations_for(
+    action: &Action,
+    tags: &[String],
+    indicators: &IndicatorsData,
+    date: NaiveDate,
+) -> Vec<Observation> {
+    let mut observations = Vec::new();
+    for def in indicators.indicators.iter().filter(|def| def.active) {
+        for rule in def
+            .auto_rules
+            .iter()
+            .filter(|rule| tags.contains(&rule.tag))
+        {
+            let mut observation =
+                Observation::new(def.id.clone(), date, rule.value, def.unit.clone());
+            observation.source = ObservationSource::Automated;
+            observation.action_id = Some(action.id.clone());
+            observation.note = Some(format!("#{}: {}", rule.tag, action.text));
+            observations.push(observation);
+        }
+    }
+    observations
+}
+
+/// Add, change or (with a `None` value) remove the rule for `tag` on the
+/// indicator found by id or name; returns what happened
+pub fn set_rule(
+    indicators: &mut IndicatorsData,
+    indicator: &str,
+    tag: &str,
+    value: Option<f64>,
+) -> Result<String> {
+    let tag = normalize_tag(tag);
+    if tag.is_empty() {
+        bail!("A rule needs a tag, e.g. #pushups");
+    }
+    let Some(id) = indicators.find(indicator).map(|def| def.id.clone()) else {
+        bail!("No indicator named '{}'", indicator);
+    };
+    let Some(def) = indicators.indicators.iter_mut().find(|def| def.id == id) else {
+        bail!("No indicator named '{}'", indicator);
+    };
+    let existing = def.auto_rules.iter().position(|rule| rule.tag == tag);
+    let message = match (value, existing) {
+        (Some(value), Some(index)) => {
+            def.auto_rules[index].value = value;
+            format!("{}: #{} now adds {}", def.name, tag, value)
+        }
+        (Some(value), None) => {
+            def.auto_rules.push(AutoRule {
+                tag: tag.clone(),
+                value,
+            });
+            format!("{}: #{} adds {}", def.name, tag, value)
+        }
+        (None, Some(index)) => {
+            def.auto_rules.remove(index);
+            format!("{}: removed the rule for #{}", def.name, tag)
+        }
+        (None, None) => bail!("{} has no rule for #{}", def.name, tag),
+    };
+    def.modified = chrono::Utc::now();
+    Ok(message)
+}
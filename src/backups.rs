@@ -0,0 +1,196 @@
+//! Timestamped backups: before a goals or data file is overwritten, its
+//! previous contents are copied to backups/<file>/<timestamp>, pruned by
+//! the retention in settings.json. Any copy can be restored, and restoring
+//! backs up the file it replaces first so it can be undone the same way.
+
+use crate::models::{BackupSettings, Config};
+use anyhow::{Context, Result};
+use chrono::{Local, NaiveDateTime};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Prefix of the backups of files under the goals directory
+const GOALS: &str = "goals";
+const STAMP: &str = "%Y%m%d-%H%M%S%.3f";
+/// Length of a formatted `STAMP`
+const STAMP_LEN: usize = 19;
+
+/// One saved copy of a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Backup {
+    /// The file it is a copy of, relative to the data directory, with `/`
+    /// separators; files of the goals directory start with "goals/"
+    pub file: String,
+    pub taken: NaiveDateTime,
+    pub path: PathBuf,
+}
+
+impl Backup {
+    pub fn size(&self) -> u64 {
+        fs::metadata(&self.path).map(|meta| meta.len()).unwrap_or(0)
+    }
+}
+
+fn backups_dir(config: &Config) -> PathBuf {
+    Path::new(&config.data_root).join("backups")
+}
+
+fn slash_path(relative: &Path) -> String {
+    relative
+        .components()
+        .map(|part| part.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// `path` as a `Backup::file`; None for files outside the data and goals
+/// directories, which are not backed up
+fn file_key(path: &Path, config: &Config) -> Option<String> {
+    if let Ok(relative) = path.strip_prefix(&config.goals_dir) {
+        return Some(format!("{}/{}", GOALS, slash_path(relative)));
+    }
+    path.strip_prefix(&config.data_root).ok().map(slash_path)
+}
+
+/// Where the file of a `Backup::file` lives
+pub fn original_path(file: &str, config: &Config) -> PathBuf {
+    match file.strip_prefix(&format!("{}/", GOALS)) {
+        Some(relative) => Path::new(&config.goals_dir).join(relative),
+        None => Path::new(&config.data_root).join(file),
+    }
+}
+
+/// Sort key of copies: copies taken in the same millisecond get a "-N"
+/// suffix, so longer names are later
+fn order(backup: &Backup) -> (NaiveDateTime, usize, PathBuf) {
+    let name = backup.path.file_name().unwrap_or_default();
+    (backup.taken, name.len(), backup.path.clone())
+}
+
+/// Backups of one file, newest first
+fn copies(dir: &Path, file: &str) -> Result<Vec<Backup>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut copies = Vec::new();
+    for entry in
+        fs::read_dir(dir).with_context(|| format!("Failed to read backups: {}", dir.display()))?
+    {
+        let path = entry?.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let taken = name
+            .get(..STAMP_LEN)
+            .and_then(|stamp| NaiveDateTime::parse_from_str(stamp, STAMP).ok());
+        if let Some(taken) = taken {
+            copies.push(Backup {
+                file: file.to_string(),
+                taken,
+                path,
+            });
+        }
+    }
+    copies.sort_by_key(|backup| std::cmp::Reverse(order(backup)));
+    Ok(copies)
+}
+
+/// Copy `path` into backups/ before it is overwritten. Nothing is copied
+/// when the file does not exist yet or matches its newest backup. Returns
+/// the new backup.
+pub fn backup_file(path: &Path, config: &Config) -> Result<Option<PathBuf>> {
+    let Some(file) = file_key(path, config) else {
+        return Ok(None);
+    };
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content =
+        fs::read(path).with_context(|| format!("Failed to read {} to back up", path.display()))?;
+    let dir = backups_dir(config).join(&file);
+    let existing = copies(&dir, &file)?;
+    if existing
+        .first()
+        .is_some_and(|newest| fs::read(&newest.path).ok().as_deref() == Some(&content[..]))
+    {
+        return Ok(None);
+    }
+
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create backups directory: {}", dir.display()))?;
+    let stamp = Local::now().naive_local().format(STAMP).to_string();
+    let ext = path
+        .extension()
+        .map(|ext| format!(".{}", ext.to_string_lossy()))
+        .unwrap_or_default();
+    let target = (0..)
+        .map(|n| match n {
+            0 => dir.join(format!("{}{}", stamp, ext)),
+            n => dir.join(format!("{}-{}{}", stamp, n, ext)),
+        })
+        .find(|candidate| !candidate.exists())
+        .expect("unbounded range");
+    fs::write(&target, &content)
+        .with_context(|| format!("Failed to write backup: {}", target.display()))?;
+
+    let settings = crate::data::load_settings(config)
+        .map(|settings| settings.backups)
+        .unwrap_or_default();
+    prune(&dir, &file, &settings)?;
+    Ok(Some(target))
+}
+
+/// Remove copies past the retention, always keeping the newest
+fn prune(dir: &Path, file: &str, settings: &BackupSettings) -> Result<()> {
+    let oldest = Local::now().naive_local() - chrono::Duration::days(settings.max_age_days.into());
+    for (i, backup) in copies(dir, file)?.iter().enumerate().skip(1) {
+        if i >= settings.keep.max(1) || backup.taken < oldest {
+            fs::remove_file(&backup.path).with_context(|| {
+                format!("Failed to remove old backup: {}", backup.path.display())
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Every backup, newest first
+pub fn list(config: &Config) -> Result<Vec<Backup>> {
+    let root = backups_dir(config);
+    let mut all = Vec::new();
+    let mut dirs = vec![root.clone()];
+    while let Some(dir) = dirs.pop() {
+        if !dir.exists() {
+            continue;
+        }
+        let mut has_files = false;
+        for entry in fs::read_dir(&dir)
+            .with_context(|| format!("Failed to read backups: {}", dir.display()))?
+        {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else {
+                has_files = true;
+            }
+        }
+        if has_files {
+            if let Ok(relative) = dir.strip_prefix(&root) {
+                all.extend(copies(&dir, &slash_path(relative))?);
+            }
+        }
+    }
+    all.sort_by_key(|backup| std::cmp::Reverse(order(backup)));
+    Ok(all)
+}
+
+/// Put `backup` back in place of its file, backing up the current file first
+pub fn restore(backup: &Backup, config: &Config) -> Result<PathBuf> {
+    let content = fs::read(&backup.path)
+        .with_context(|| format!("Failed to read backup: {}", backup.path.display()))?;
+    let path = original_path(&backup.file, config);
+    backup_file(&path, config)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+    }
+    crate::data::atomic_write(&path, &content)?;
+    Ok(path)
+}
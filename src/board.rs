@@ -0,0 +1,129 @@
+//! Kanban view of a day's actions by ActionStatus. The markdown only knows
+//! done or not done, so the finer status lives in the day's metadata.
+
+use crate::models::{ActionStatus, Config, DailyGoals, OutcomeType};
+use anyhow::{bail, Result};
+
+/// Board columns, left to right
+pub const COLUMNS: [ActionStatus; 5] = [
+    ActionStatus::Planned,
+    ActionStatus::InProgress,
+    ActionStatus::Blocked,
+    ActionStatus::Done,
+    ActionStatus::Skipped,
+];
+
+pub fn column_label(status: ActionStatus) -> &'static str {
+    match status {
+        ActionStatus::Planned => "Planned",
+        ActionStatus::InProgress => "In Progress",
+        ActionStatus::Blocked => "Blocked",
+        ActionStatus::Done => "Done",
+        ActionStatus::Skipped => "Skipped",
+    }
+}
+
+/// Column index of `status`
+pub fn column_of(status: ActionStatus) -> usize {
+    COLUMNS.iter().position(|s| *s == status).unwrap_or(0)
+}
+
+/// A written action on the board
+#[derive(Debug, Clone, PartialEq)]
+pub struct Card {
+    pub outcome_type: OutcomeType,
+    pub index: usize,
+    pub text: String,
+    pub status: ActionStatus,
+}
+
+/// The day's written actions with their status. The checkbox wins over a
+/// stale status: checked is Done, unchecked is never Done.
+pub fn cards(goals: &DailyGoals, config: &Config) -> Result<Vec<Card>> {
+    let meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+    let mut cards = Vec::new();
+    for outcome in goals.outcomes() {
+        let metas = meta.outcome(outcome.outcome_type);
+        for (index, action) in outcome.actions.iter().enumerate() {
+            if action.text.trim().is_empty() {
+                continue;
+            }
+            let status = match metas.get(index).map(|m| m.status) {
+                _ if action.completed => ActionStatus::Done,
+                Some(ActionStatus::Done) | None => ActionStatus::Planned,
+                Some(status) => status,
+            };
+            cards.push(Card {
+                outcome_type: outcome.outcome_type,
+                index,
+                text: action.text.clone(),
+                status,
+            });
+        }
+    }
+    Ok(cards)
+}
+
+/// Set an action's status, checking it off when Done and unchecking it
+/// otherwise; writes the goals file and the metadata. Leaving Blocked drops
+/// the blocker link.
+pub fn set_status(
+    goals: &mut DailyGoals,
+    outcome_type: OutcomeType,
+    index: usize,
+    status: ActionStatus,
+    config: &Config,
+) -> Result<()> {
+    let Some(action) = goals.outcome_mut(outcome_type).actions.get_mut(index) else {
+        bail!("Invalid action index: {}", index);
+    };
+    action.completed = status == ActionStatus::Done;
+    crate::data::write_goals_file(goals, config)?;
+
+    let mut meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+    if let Some(action_meta) = meta.outcome_mut(outcome_type).get_mut(index) {
+        action_meta.status = status;
+        if status != ActionStatus::Blocked {
+            action_meta.blocked_by = None;
+        }
+    }
+    meta.stamp_completions(goals, chrono::Utc::now());
+    crate::data::save_day_meta(goals.date, &meta, config)?;
+    Ok(())
+}
+
+/// Number of written actions in each status
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatusCounts {
+    pub planned: usize,
+    pub in_progress: usize,
+    pub blocked: usize,
+    pub done: usize,
+    pub skipped: usize,
+}
+
+impl StatusCounts {
+    pub fn from_cards(cards: &[Card]) -> Self {
+        let mut counts = Self::default();
+        for card in cards {
+            match card.status {
+                ActionStatus::Planned => counts.planned += 1,
+                ActionStatus::InProgress => counts.in_progress += 1,
+                ActionStatus::Blocked => counts.blocked += 1,
+                ActionStatus::Done => counts.done += 1,
+                ActionStatus::Skipped => counts.skipped += 1,
+            }
+        }
+        counts
+    }
+
+    /// Done as a share of the actions still meant to happen, leaving out
+    /// skipped ones; 0-100
+    pub fn completion(&self) -> f64 {
+        let open = self.planned + self.in_progress + self.blocked + self.done;
+        if open == 0 {
+            return 0.0;
+        }
+        self.done as f64 * 100.0 / open as f64
+    }
+}
@@ -0,0 +1,91 @@
+//! Planned load against the daily capacity: the day's action estimates
+//! summed and compared with the hours available, so an over-full plan is
+//! flagged while it is being made.
+
+use crate::models::{format_minutes, Config, DailyGoals, OutcomeType, Settings};
+use crate::tracking::OutcomeTime;
+use anyhow::{bail, Result};
+
+/// Share of the capacity past which the gauge turns to a warning
+pub const NEAR_CAPACITY: f64 = 0.85;
+
+/// Estimated minutes planned into a day and the minutes available
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DayCapacity {
+    pub planned: u32,
+    pub capacity: u32,
+}
+
+impl DayCapacity {
+    pub fn new(times: &[OutcomeTime], settings: &Settings) -> Self {
+        Self {
+            planned: times.iter().map(|time| time.estimated).sum(),
+            capacity: settings.daily_capacity(),
+        }
+    }
+
+    /// Sum of the estimates of the day's written actions
+    pub fn for_day(goals: &DailyGoals, settings: &Settings, config: &Config) -> Result<Self> {
+        let times = crate::tracking::outcome_times(goals, config)?;
+        Ok(Self::new(&times, settings))
+    }
+
+    /// Planned share of the capacity; above 1.0 when over
+    pub fn ratio(&self) -> f64 {
+        f64::from(self.planned) / f64::from(self.capacity.max(1))
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.planned > self.capacity
+    }
+
+    /// "11h / 8h"
+    pub fn label(&self) -> String {
+        format!(
+            "{} / {}",
+            format_minutes(self.planned),
+            format_minutes(self.capacity)
+        )
+    }
+
+    /// Warning text when the plan does not fit the day
+    pub fn warning(&self) -> Option<String> {
+        self.is_over().then(|| {
+            format!(
+                "Planned {} of work for a capacity of {} ({} over)",
+                format_minutes(self.planned),
+                format_minutes(self.capacity),
+                format_minutes(self.planned - self.capacity)
+            )
+        })
+    }
+}
+
+/// Set or clear the estimate of the action at `index`
+pub fn set_estimate(
+    goals: &DailyGoals,
+    outcome_type: OutcomeType,
+    index: usize,
+    minutes: Option<u32>,
+    config: &Config,
+) -> Result<()> {
+    match goals.outcome(outcome_type).actions.get(index) {
+        Some(action) if !action.text.trim().is_empty() => {}
+        Some(_) => bail!("Nothing to estimate in an empty action"),
+        None => bail!("Invalid action index: {}", index),
+    }
+    let mut meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+    if let Some(action_meta) = meta.outcome_mut(outcome_type).get_mut(index) {
+        action_meta.estimated_min = minutes;
+    }
+    crate::data::save_day_meta(goals.date, &meta, config)?;
+    Ok(())
+}
+
+/// Remember the daily capacity in settings.json
+pub fn save_capacity(minutes: u32, config: &Config) -> Result<()> {
+    let mut settings = crate::data::load_settings(config)?;
+    settings.daily_capacity_min = Some(minutes);
+    crate::data::save_settings(&settings, config)?;
+    Ok(())
+}
@@ -0,0 +1,260 @@
+//! Triage of yesterday's unfinished actions when a new day is opened: each
+//! is carried over, dropped or snoozed to a later date. Carried actions keep
+//! the day they were first planned in the day's metadata, so the lineage
+//! survives repeated carry-overs. Single actions can also be snoozed to a
+//! later day at any time.
+
+use crate::models::{
+    Action, ActionMeta, ActionOrigin, Config, DailyGoals, IndicatorLink, OutcomeType, SnoozedAction,
+};
+use anyhow::{bail, Context, Result};
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CarryChoice {
+    Carry,
+    Drop,
+    Snooze(NaiveDate),
+}
+
+/// An unfinished action from yesterday and what to do with it
+#[derive(Debug, Clone, PartialEq)]
+pub struct CarryItem {
+    pub outcome_type: OutcomeType,
+    pub text: String,
+    pub objective_ids: Vec<String>,
+    pub indicator_links: Vec<IndicatorLink>,
+    /// Day the action was first planned, before any carry-over
+    pub first_planned: NaiveDate,
+    pub choice: CarryChoice,
+}
+
+/// What `apply` did
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CarrySummary {
+    pub carried: usize,
+    pub dropped: usize,
+    pub snoozed: usize,
+    /// Actions left behind because their outcome already had 5 actions
+    pub no_room: usize,
+}
+
+/// Whether `today` is still untouched: no file yet, or only actions that
+/// were snoozed into it and no triage done
+pub fn is_new_day(today: NaiveDate, config: &Config) -> Result<bool> {
+    let path = crate::data::goals_file_path(today, config);
+    if !path.exists() {
+        return Ok(true);
+    }
+    let goals = crate::data::read_goals_for(today, config)?;
+    let meta = crate::data::load_or_create_day_meta(today, &goals, config)?;
+    if meta.triaged {
+        return Ok(false);
+    }
+    let mut arrived = 0;
+    for outcome in goals.outcomes() {
+        let metas = meta.outcome(outcome.outcome_type);
+        for (action, action_meta) in outcome.actions.iter().zip(metas) {
+            if action.text.trim().is_empty() {
+                continue;
+            }
+            if action_meta.snoozed_from.is_none() || action.completed {
+                return Ok(false);
+            }
+            arrived += 1;
+        }
+    }
+    Ok(arrived > 0)
+}
+
+/// Yesterday's unfinished actions, all proposed for carrying over
+pub fn pending_items(today: NaiveDate, config: &Config) -> Result<Vec<CarryItem>> {
+    let yesterday = today.pred_opt().context("Cannot get yesterday's date")?;
+    unfinished_items(yesterday, config)
+}
+
+/// Unfinished actions of `date`, all proposed for carrying over
+pub fn unfinished_items(date: NaiveDate, config: &Config) -> Result<Vec<CarryItem>> {
+    let path = crate::data::goals_file_path(date, config);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let goals = crate::data::read_goals_for(date, config)?;
+    let meta = crate::data::load_or_create_day_meta(date, &goals, config)?;
+
+    let mut items = Vec::new();
+    for outcome in goals.outcomes() {
+        let metas = meta.outcome(outcome.outcome_type);
+        for (index, action) in outcome.actions.iter().enumerate() {
+            if action.completed || action.text.trim().is_empty() {
+                continue;
+            }
+            items.push(CarryItem {
+                outcome_type: outcome.outcome_type,
+                text: action.text.clone(),
+                objective_ids: action.get_all_objective_ids(),
+                indicator_links: action.indicator_links.clone(),
+                first_planned: metas
+                    .get(index)
+                    .and_then(|m| m.carried_from)
+                    .unwrap_or(date),
+                choice: CarryChoice::Carry,
+            });
+        }
+    }
+    Ok(items)
+}
+
+/// Put `item` into `goals` as a carried-over action; returns its index
+pub fn place_carried(goals: &mut DailyGoals, item: &CarryItem) -> Result<usize> {
+    let outcome = goals.outcome_mut(item.outcome_type);
+    let index = outcome.place_action(item.text.clone())?;
+    let action = &mut outcome.actions[index];
+    action.origin = ActionOrigin::CarryOver;
+    for id in &item.objective_ids {
+        action.add_objective_id(id.clone());
+    }
+    action.indicator_links = item.indicator_links.clone();
+    Ok(index)
+}
+
+/// Place `items` into `goals`, write the file and record each action's
+/// lineage in the day's metadata, including the day it was snoozed on.
+/// Returns how many did not fit.
+fn place_all(
+    goals: &mut DailyGoals,
+    items: &[&CarryItem],
+    snoozed_from: Option<NaiveDate>,
+    config: &Config,
+) -> Result<usize> {
+    let mut placed = Vec::new();
+    let mut no_room = 0;
+    for item in items {
+        match place_carried(goals, item) {
+            Ok(index) => placed.push((item.outcome_type, index, item.first_planned)),
+            Err(_) => no_room += 1,
+        }
+    }
+    crate::data::write_goals_file(goals, config)?;
+
+    let mut meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+    for (outcome_type, index, first_planned) in placed {
+        if let Some(action_meta) = meta.outcome_mut(outcome_type).get_mut(index) {
+            action_meta.origin = ActionOrigin::CarryOver;
+            action_meta.carried_from = Some(first_planned);
+            action_meta.snoozed_from = snoozed_from;
+        }
+    }
+    crate::data::save_day_meta(goals.date, &meta, config)?;
+    Ok(no_room)
+}
+
+/// Carry items into `today` and snooze the rest into the files of their
+/// dates, creating them if needed. `today` is written even when nothing is
+/// carried, so the triage is not offered again.
+pub fn apply(items: &[CarryItem], today: &mut DailyGoals, config: &Config) -> Result<CarrySummary> {
+    let mut summary = CarrySummary::default();
+    let mut carried = Vec::new();
+    let mut snoozed: BTreeMap<NaiveDate, Vec<&CarryItem>> = BTreeMap::new();
+    for item in items {
+        match item.choice {
+            CarryChoice::Carry => carried.push(item),
+            CarryChoice::Snooze(date) if date <= today.date => carried.push(item),
+            CarryChoice::Snooze(date) => snoozed.entry(date).or_default().push(item),
+            CarryChoice::Drop => summary.dropped += 1,
+        }
+    }
+
+    let no_room = place_all(today, &carried, None, config)?;
+    let mut meta = crate::data::load_or_create_day_meta(today.date, today, config)?;
+    meta.triaged = true;
+    crate::data::save_day_meta(today.date, &meta, config)?;
+    summary.carried = carried.len() - no_room;
+    summary.no_room = no_room;
+    for (date, items) in snoozed {
+        let mut goals = crate::data::load_or_create_goals(date, config)?;
+        let no_room = place_all(&mut goals, &items, Some(today.date), config)?;
+        summary.snoozed += items.len() - no_room;
+        summary.no_room += no_room;
+    }
+    Ok(summary)
+}
+
+/// Move the action at `index` to the later day `until`, leaving an empty
+/// slot behind and a pointer in this day's metadata. Both files are written.
+pub fn snooze_action(
+    goals: &mut DailyGoals,
+    outcome_type: OutcomeType,
+    index: usize,
+    until: NaiveDate,
+    config: &Config,
+) -> Result<()> {
+    if until <= goals.date {
+        bail!("Snooze date must be after {}", goals.date);
+    }
+    let action = goals
+        .outcome_mut(outcome_type)
+        .actions
+        .get(index)
+        .cloned()
+        .with_context(|| format!("Invalid action index: {}", index))?;
+    if action.text.trim().is_empty() {
+        bail!("Nothing to snooze in an empty action");
+    }
+
+    let mut meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+    let item = CarryItem {
+        outcome_type,
+        text: action.text.clone(),
+        objective_ids: action.get_all_objective_ids(),
+        indicator_links: action.indicator_links.clone(),
+        first_planned: meta
+            .outcome(outcome_type)
+            .get(index)
+            .and_then(|m| m.carried_from)
+            .unwrap_or(goals.date),
+        choice: CarryChoice::Snooze(until),
+    };
+
+    let mut target = crate::data::load_or_create_goals(until, config)?;
+    if place_all(&mut target, &[&item], Some(goals.date), config)? > 0 {
+        bail!("{} already has 5 {} actions", until, outcome_type.as_str());
+    }
+
+    let empty = Action::new_empty();
+    if let Some(action_meta) = meta.outcome_mut(outcome_type).get_mut(index) {
+        *action_meta = ActionMeta {
+            id: empty.id.clone(),
+            ..ActionMeta::default()
+        };
+    }
+    goals.outcome_mut(outcome_type).actions[index] = empty;
+    crate::data::write_goals_file(goals, config)?;
+
+    meta.snoozed.push(SnoozedAction {
+        outcome_type,
+        text: item.text,
+        until,
+    });
+    crate::data::save_day_meta(goals.date, &meta, config)?;
+    Ok(())
+}
+
+/// Texts of the actions in `goals` that arrived by snoozing
+pub fn arrivals(goals: &DailyGoals, config: &Config) -> Result<Vec<String>> {
+    let meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+    let mut texts = Vec::new();
+    for outcome in goals.outcomes() {
+        for (action, action_meta) in outcome
+            .actions
+            .iter()
+            .zip(meta.outcome(outcome.outcome_type))
+        {
+            if action_meta.snoozed_from.is_some() && !action.text.trim().is_empty() {
+                texts.push(action.text.clone());
+            }
+        }
+    }
+    Ok(texts)
+}
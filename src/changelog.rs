@@ -0,0 +1,120 @@
+//! What's new: notes on each release, shown once after an upgrade and with
+//! `:whatsnew`. The last version seen is kept in settings.json; installs
+//! from before it was kept start counting from the version they run.
+//! Add a release here when bumping the version in Cargo.toml.
+
+use crate::models::Config;
+use anyhow::Result;
+
+/// Version of this build
+pub const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// A feature worth pointing out, with the keys that reach it if any
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Feature {
+    pub keys: &'static str,
+    pub text: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Release {
+    pub version: &'static str,
+    pub features: &'static [Feature],
+}
+
+/// Newest first
+pub const RELEASES: &[Release] = &[Release {
+    version: "0.1.0",
+    features: &[
+        Feature {
+            keys: "d",
+            text: "Dashboard of your indicators: trends, comparisons and signals",
+        },
+        Feature {
+            keys: "?",
+            text: "Every key, searchable; remap keys under \"keys\" in settings.json",
+        },
+        Feature {
+            keys: ":",
+            text: "Command line for :export, :theme, :compare, :sort and more",
+        },
+        Feature {
+            keys: "f",
+            text: "Focus mode: only the top action of each outcome",
+        },
+        Feature {
+            keys: "p/P",
+            text: "Pomodoro and stopwatch focus sessions",
+        },
+        Feature {
+            keys: "Y",
+            text: "Copy today's checklist to the clipboard for a standup",
+        },
+        Feature {
+            keys: "Ctrl+E",
+            text: "Edit visions and reflections in $EDITOR",
+        },
+        Feature {
+            keys: "F12",
+            text: "Save the screen as ANSI text and SVG under exports/",
+        },
+        Feature {
+            keys: "r/@",
+            text: "Record keys into a register and replay them, vim style",
+        },
+        Feature {
+            keys: "!",
+            text: "Every notification shown this session",
+        },
+        Feature {
+            keys: "",
+            text: "A setup wizard seeds outcomes, visions and a first objective on first run",
+        },
+    ],
+}];
+
+/// `1.10.2` as numbers, for comparing; unparsable parts count as 0
+fn parse_version(version: &str) -> Vec<u32> {
+    version
+        .trim()
+        .trim_start_matches('v')
+        .split('.')
+        .map(|part| part.parse().unwrap_or(0))
+        .collect()
+}
+
+/// Releases after `seen` up to this build, newest first
+pub fn unseen(seen: &str) -> Vec<&'static Release> {
+    let seen = parse_version(seen);
+    let current = parse_version(CURRENT_VERSION);
+    RELEASES
+        .iter()
+        .filter(|release| {
+            let version = parse_version(release.version);
+            version > seen && version <= current
+        })
+        .collect()
+}
+
+/// Record this build's version as seen
+pub fn mark_seen(config: &Config) -> Result<()> {
+    let mut settings = crate::data::load_settings(config)?;
+    settings.last_seen_version = Some(CURRENT_VERSION.to_string());
+    crate::data::save_settings(&settings, config)?;
+    Ok(())
+}
+
+/// Plain-text notes for `releases`, for `focusfive exec whatsnew`
+pub fn format_releases(releases: &[&Release]) -> String {
+    let mut out = String::new();
+    for release in releases {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        out.push_str(&format!("What's new in {}\n", release.version));
+        for feature in release.features {
+            out.push_str(&format!("  {:<8} {}\n", feature.keys, feature.text));
+        }
+    }
+    out
+}
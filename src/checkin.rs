@@ -0,0 +1,136 @@
+//! Morning and evening mood/energy check-ins and how they line up with
+//! daily completion. Check-ins are stored as observations of the built-in
+//! Mood and Energy indicators, so they chart like any other indicator.
+
+use crate::models::{Config, Observation, ENERGY_INDICATOR_ID, MOOD_INDICATOR_ID};
+use crate::ui::stats::pearson;
+use anyhow::Result;
+use chrono::{Duration, NaiveDate};
+use std::collections::BTreeMap;
+
+/// Lowest and highest check-in rating
+pub const CHECK_IN_MIN: u8 = 1;
+pub const CHECK_IN_MAX: u8 = 5;
+
+/// Which check-in of the day; mornings run until 3pm
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckInPeriod {
+    Morning,
+    Evening,
+}
+
+impl CheckInPeriod {
+    pub fn for_hour(hour: u32) -> Self {
+        if hour < 15 {
+            CheckInPeriod::Morning
+        } else {
+            CheckInPeriod::Evening
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            CheckInPeriod::Morning => "Morning check-in",
+            CheckInPeriod::Evening => "Evening check-in",
+        }
+    }
+}
+
+/// Average check-in ratings and completion for one day
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckInDay {
+    pub date: NaiveDate,
+    pub mood: Option<f64>,
+    pub energy: Option<f64>,
+    pub completion: f64,
+}
+
+/// Mood and energy against completion over a window of days
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CheckInAnalytics {
+    pub days: Vec<CheckInDay>,
+    pub mood_correlation: Option<f64>,
+    pub energy_correlation: Option<f64>,
+    /// Average completion on days with each rounded mood rating, 1 to 5
+    pub completion_by_mood: [Option<f64>; 5],
+}
+
+/// Mean of each day's values for one indicator
+fn daily_means(indicator_id: &str, observations: &[Observation]) -> BTreeMap<NaiveDate, f64> {
+    let mut sums: BTreeMap<NaiveDate, (f64, u32)> = BTreeMap::new();
+    for obs in observations
+        .iter()
+        .filter(|o| o.indicator_id == indicator_id)
+    {
+        let entry = sums.entry(obs.when).or_default();
+        entry.0 += obs.value;
+        entry.1 += 1;
+    }
+    sums.into_iter()
+        .map(|(date, (sum, count))| (date, sum / f64::from(count)))
+        .collect()
+}
+
+impl CheckInAnalytics {
+    /// Join check-ins with `completion` (date, percent); days without any
+    /// check-in are left out
+    pub fn from_data(observations: &[Observation], completion: &[(NaiveDate, f64)]) -> Self {
+        let mood = daily_means(MOOD_INDICATOR_ID, observations);
+        let energy = daily_means(ENERGY_INDICATOR_ID, observations);
+
+        let days: Vec<CheckInDay> = completion
+            .iter()
+            .map(|(date, completion)| CheckInDay {
+                date: *date,
+                mood: mood.get(date).copied(),
+                energy: energy.get(date).copied(),
+                completion: *completion,
+            })
+            .filter(|day| day.mood.is_some() || day.energy.is_some())
+            .collect();
+
+        let paired = |value: fn(&CheckInDay) -> Option<f64>| -> Vec<(f64, f64)> {
+            days.iter()
+                .filter_map(|day| value(day).map(|v| (v, day.completion)))
+                .collect()
+        };
+        let mood_pairs = paired(|day| day.mood);
+        let energy_pairs = paired(|day| day.energy);
+
+        let mut completion_by_mood = [None; 5];
+        for (rating, slot) in completion_by_mood.iter_mut().enumerate() {
+            let matching: Vec<f64> = mood_pairs
+                .iter()
+                .filter(|(mood, _)| mood.round() as usize == rating + 1)
+                .map(|(_, completion)| *completion)
+                .collect();
+            if !matching.is_empty() {
+                *slot = Some(matching.iter().sum::<f64>() / matching.len() as f64);
+            }
+        }
+
+        Self {
+            mood_correlation: pearson(&mood_pairs),
+            energy_correlation: pearson(&energy_pairs),
+            completion_by_mood,
+            days,
+        }
+    }
+
+    /// Analytics for the `days` days ending at `end`
+    pub fn calculate(end: NaiveDate, days: i64, config: &Config) -> Result<Self> {
+        let start = end - Duration::days(days.saturating_sub(1));
+        let observations = crate::data::read_observations_range(start, end, config)?;
+        let completion: Vec<(NaiveDate, f64)> = start
+            .iter_days()
+            .take_while(|date| *date <= end)
+            .map(|date| {
+                let percent = crate::data::load_or_create_goals(date, config)
+                    .map(|goals| f64::from(goals.completion_stats().percentage))
+                    .unwrap_or(0.0);
+                (date, percent)
+            })
+            .collect();
+        Ok(Self::from_data(&observations, &completion))
+    }
+}
@@ -0,0 +1,668 @@
+//! Non-interactive subcommands: `focusfive <command> [args]`.
+//! Running `focusfive` with no arguments starts the TUI instead.
+
+use crate::models::{Config, GoalsLayout, IndicatorsData};
+use anyhow::{bail, Context, Result};
+use std::path::PathBuf;
+
+const USAGE: &str = "\
+Usage: focusfive [command]
+
+Without a command the interactive TUI starts.
+
+Commands:
+  exec <command...>
+      Run a TUI ':' command without the TUI, e.g. exec goto 2024-03-10,
+      exec add work 'Ship v2', exec export week, exec theme colorblind,
+      exec capacity 7h30m, exec estimate work 2 45m,
+      exec daytemplate 'Monday planning' mon.
+  import-health <export.xml|daily.csv> [--steps IND] [--sleep IND] [--workout IND] [--dry-run]
+      Import Apple Health or Google Fit totals as observations. IND is an
+      indicator id or name.
+  import-journal <dir> [--map HEADING=work|health|family|skip]... [--dry-run]
+      Turn past daily notes (markdown checklists named with a YYYY-MM-DD
+      date) into goals files. Headings not given with --map are asked for
+      interactively. Days that already have goals are left alone.
+  import-tasks <export.csv>... [--project NAME] [--map PROJECT=work|health|family|skip]... [--dry-run]
+      Import a Todoist project export or a TickTick backup. Each project
+      (the file name for Todoist, unless --project is given) maps to an
+      outcome; projects not given with --map are asked for interactively.
+      Open tasks due by today become today's actions tagged with their
+      project; daily and weekly recurring tasks become weekday templates
+      named after it.
+  import-pack <pack.toml|pack.json|pack.yaml> [--on-conflict skip|replace|rename] [--dry-run]
+      Add the templates, objectives and indicators of a shared pack. Items
+      whose id or name is taken are skipped (default), replace the existing
+      definition, or are renamed and added beside it.
+  export-pack <pack.toml|pack.json|pack.yaml> [--name NAME]
+      Save the templates, active objectives and their indicators as a pack.
+  digest [YYYY-MM-DD] [--to NAME] [--print]
+      Send the weekly accountability digest for the week containing the
+      date (this week by default) to the recipients under digest in
+      settings.json, or only to NAME. Each gets completion per outcome, the
+      days with something done and the streak; action text only for the
+      outcomes in their share_actions. Digests are written to a recipient's
+      folder and emailed by piping them to digest.mail_command, e.g.
+      'sendmail -t'. --print shows the digest instead of sending it.
+  doctor [--fix]
+      Check the goals files against the parser and the data files for
+      missing objective or indicator references, duplicate ids and
+      observations out of date order. --fix repairs what it can; unreadable
+      observations are moved to observations.rejected.ndjson.
+  mcp
+      Run a Model Context Protocol server on stdin/stdout for local assistants.
+  obsidian [VAULT_FOLDER|on|off]
+      Write goals files as Obsidian daily notes: YAML frontmatter and
+      [[wiki-links]] to objectives, keeping the rest of each note. Given a
+      folder of a vault, its notes are used as the goals files; tasks there
+      tagged #work, #health or #family count as actions. Without an
+      argument, shows the current mode.
+  plan [YYYY-MM-DD]
+      Print a one-page plan of the day (today by default) for printing or
+      pasting into notes. exec export plan saves it under exports/ instead.
+  recompute-stats
+      Rebuild cached statistics and indexes (stats.json, sentiment.json,
+      observations.idx) from the goals, reflection and observation files,
+      e.g. after editing them by hand or importing history.
+  status [--short]
+      Print today's completed and planned actions per outcome and the
+      current streak. --short prints one line such as 'W 2/3 H 1/3 F 3/3
+      🔥12' for a tmux status bar or shell prompt. Figures come from the
+      stats.json cache, so only goals files that changed are read.
+  summary [YYYY-MM-DD] [--template FILE]
+      Print a day's summary (today by default) using the summary template:
+      FILE, the settings.json summary.template, summary_template.md in the
+      data directory, or the built-in one. Pipe it to mail or a chat bridge.
+  sync [FOLDER|URL]
+      Sync the data directory with FOLDER, e.g. a mounted share or an
+      rclone mount, or with a WebDAV folder at an http(s) URL, and remember
+      it for next time. WebDAV credentials are read from
+      FOCUSFIVE_WEBDAV_USER and FOCUSFIVE_WEBDAV_PASSWORD. Files changed on
+      both sides are merged where possible; conflicts are listed and can be
+      resolved with :sync in the TUI.
+  year-review [YYYY] [--export]
+      Print a year's highlights (this year by default): completion by
+      month, longest streak, objectives completed and indicator bests.
+      --export saves it under exports/ instead.
+  migrate-layout <pattern> [--dry-run]
+      Move goals files to a new path pattern under the goals directory and
+      remember it, e.g. '%Y/%m/%Y-%m-%d.md'. The default is '%Y-%m-%d.md'.
+  help
+      Show this message.";
+
+/// Dispatch a subcommand. `args` excludes the program name.
+pub fn run(args: &[String], config: &Config) -> Result<()> {
+    let Some((command, rest)) = args.split_first() else {
+        bail!("No command given\n\n{}", USAGE);
+    };
+
+    match command.as_str() {
+        "digest" => digest(rest, config),
+        "doctor" => doctor(rest, config),
+        "exec" => exec(rest, config),
+        "import-health" => import_health(rest, config),
+        "import-journal" => import_journal(rest, config),
+        "import-pack" => import_pack(rest, config),
+        "import-tasks" => import_tasks(rest, config),
+        "export-pack" => export_pack(rest, config),
+        "migrate-layout" => migrate_layout(rest, config),
+        "obsidian" => obsidian(rest, config),
+        "plan" => print_plan(rest, config),
+        "recompute-stats" => recompute_stats(rest, config),
+        "status" => status(rest, config),
+        "summary" => print_summary(rest, config),
+        "sync" => sync(rest, config),
+        "year-review" => year_review(rest, config),
+        "mcp" => {
+            let stdin = std::io::stdin();
+            crate::mcp::serve(stdin.lock(), std::io::stdout(), config)
+        }
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        other => bail!("Unknown command '{}'\n\n{}", other, USAGE),
+    }
+}
+
+/// Find an indicator by exact id or case-insensitive name
+fn resolve_indicator(indicators: &IndicatorsData, key: &str) -> Result<String> {
+    indicators
+        .find(key)
+        .map(|def| def.id.clone())
+        .with_context(|| format!("No indicator with id or name '{}'", key))
+}
+
+fn import_health(args: &[String], config: &Config) -> Result<()> {
+    use crate::health_import::{import_samples, parse_export_file, HealthMapping};
+
+    let indicators = crate::data::load_or_create_indicators(config)?;
+    let mut path: Option<PathBuf> = None;
+    let mut mapping = HealthMapping::default();
+    let mut dry_run = false;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = |flag: &str| {
+            iter.next()
+                .with_context(|| format!("{} requires an indicator", flag))
+        };
+        match arg.as_str() {
+            "--steps" => mapping.steps = Some(resolve_indicator(&indicators, value(arg)?)?),
+            "--sleep" => mapping.sleep = Some(resolve_indicator(&indicators, value(arg)?)?),
+            "--workout" => mapping.workout = Some(resolve_indicator(&indicators, value(arg)?)?),
+            "--dry-run" => dry_run = true,
+            flag if flag.starts_with("--") => bail!("Unknown option '{}'", flag),
+            file => path = Some(PathBuf::from(file)),
+        }
+    }
+
+    let path = path.context("import-health needs an export file")?;
+    if mapping.steps.is_none() && mapping.sleep.is_none() && mapping.workout.is_none() {
+        bail!("Map at least one metric with --steps, --sleep or --workout");
+    }
+
+    let samples = parse_export_file(&path)?;
+    let summary = import_samples(&samples, &mapping, &indicators, config, dry_run)?;
+
+    println!(
+        "{}{} imported, {} duplicates skipped, {} unmapped, {} incompatible units",
+        if dry_run { "[dry run] " } else { "" },
+        summary.imported,
+        summary.duplicates,
+        summary.unmapped,
+        summary.incompatible
+    );
+    Ok(())
+}
+
+fn import_journal(args: &[String], config: &Config) -> Result<()> {
+    use crate::journal_import::{
+        distinct_headings, heading_key, import_notes, parse_outcome_choice, prompt_mapping,
+        scan_journal_dir, HeadingMap,
+    };
+
+    let mut dir: Option<PathBuf> = None;
+    let mut mapping = HeadingMap::new();
+    let mut skipped = Vec::new();
+    let mut dry_run = false;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--map" => {
+                let value = iter.next().context("--map requires HEADING=OUTCOME")?;
+                let (heading, outcome) = value
+                    .rsplit_once('=')
+                    .with_context(|| format!("Expected HEADING=OUTCOME, got '{}'", value))?;
+                match parse_outcome_choice(outcome)? {
+                    Some(outcome) => {
+                        mapping.insert(heading_key(heading), outcome);
+                    }
+                    None => skipped.push(heading_key(heading)),
+                }
+            }
+            "--dry-run" => dry_run = true,
+            flag if flag.starts_with("--") => bail!("Unknown option '{}'", flag),
+            path => dir = Some(PathBuf::from(path)),
+        }
+    }
+
+    let dir = dir.context("import-journal needs a directory of notes")?;
+    let (notes, undated) = scan_journal_dir(&dir)?;
+    for path in &undated {
+        println!("skipped (no date in name): {}", path.display());
+    }
+
+    let stdin = std::io::stdin();
+    let mapping = prompt_mapping(
+        &distinct_headings(&notes),
+        mapping,
+        &skipped,
+        stdin.lock(),
+        std::io::stdout(),
+    )?;
+    let summary = import_notes(&notes, &mapping, config, dry_run)?;
+
+    println!(
+        "{}{} imported, {} already had goals, {} with nothing mapped, {} items over the 5-action limit",
+        if dry_run { "[dry run] " } else { "" },
+        summary.imported,
+        summary.existing,
+        summary.empty,
+        summary.dropped_items
+    );
+    Ok(())
+}
+
+fn import_tasks(args: &[String], config: &Config) -> Result<()> {
+    use crate::journal_import::{heading_key, parse_outcome_choice, prompt_mapping_of, HeadingMap};
+    use crate::task_import::{distinct_projects, import_tasks, read_export};
+
+    let mut files: Vec<PathBuf> = Vec::new();
+    let mut project: Option<String> = None;
+    let mut mapping = HeadingMap::new();
+    let mut skipped = Vec::new();
+    let mut dry_run = false;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--project" => {
+                project = Some(iter.next().context("--project requires a name")?.clone())
+            }
+            "--map" => {
+                let value = iter.next().context("--map requires PROJECT=OUTCOME")?;
+                let (name, outcome) = value
+                    .rsplit_once('=')
+                    .with_context(|| format!("Expected PROJECT=OUTCOME, got '{}'", value))?;
+                match parse_outcome_choice(outcome)? {
+                    Some(outcome) => {
+                        mapping.insert(heading_key(name), outcome);
+                    }
+                    None => skipped.push(heading_key(name)),
+                }
+            }
+            "--dry-run" => dry_run = true,
+            flag if flag.starts_with("--") => bail!("Unknown option '{}'", flag),
+            path => files.push(PathBuf::from(path)),
+        }
+    }
+    if files.is_empty() {
+        bail!("import-tasks needs a Todoist or TickTick CSV export");
+    }
+
+    let today = chrono::Local::now().date_naive();
+    let mut tasks = Vec::new();
+    for file in &files {
+        tasks.extend(read_export(file, project.as_deref(), today)?);
+    }
+    let stdin = std::io::stdin();
+    let mapping = prompt_mapping_of(
+        "tasks",
+        &distinct_projects(&tasks),
+        mapping,
+        &skipped,
+        stdin.lock(),
+        std::io::stdout(),
+    )?;
+    let summary = import_tasks(&tasks, &mapping, today, config, dry_run)?;
+
+    println!(
+        "{}{} actions added to today, {} templates ({}), {} due later, {} done, {} with other repeats, {} in skipped projects, {} over the 5-action limit",
+        if dry_run { "[dry run] " } else { "" },
+        summary.actions,
+        summary.templates.len(),
+        summary.templates.join(", "),
+        summary.later,
+        summary.done,
+        summary.unsupported_repeat,
+        summary.skipped,
+        summary.dropped
+    );
+    Ok(())
+}
+
+fn import_pack(args: &[String], config: &Config) -> Result<()> {
+    use crate::packs::{import, read_pack, OnConflict};
+
+    let mut path: Option<PathBuf> = None;
+    let mut on_conflict = OnConflict::default();
+    let mut dry_run = false;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--on-conflict" => {
+                let value = iter
+                    .next()
+                    .context("--on-conflict requires skip, replace or rename")?;
+                on_conflict = OnConflict::parse(value)?;
+            }
+            "--dry-run" => dry_run = true,
+            flag if flag.starts_with("--") => bail!("Unknown option '{}'", flag),
+            file => path = Some(PathBuf::from(file)),
+        }
+    }
+
+    let path = path.context("import-pack needs a pack file")?;
+    let pack = read_pack(&path)?;
+    let report = import(&pack, on_conflict, config, dry_run)?;
+    println!(
+        "{}Pack '{}': objectives {}; indicators {}; templates {}",
+        if dry_run { "[dry run] " } else { "" },
+        pack.name,
+        report.objectives.describe(),
+        report.indicators.describe(),
+        report.templates.describe()
+    );
+    Ok(())
+}
+
+fn export_pack(args: &[String], config: &Config) -> Result<()> {
+    let mut path: Option<PathBuf> = None;
+    let mut name: Option<String> = None;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--name" => name = Some(iter.next().context("--name requires a value")?.clone()),
+            flag if flag.starts_with("--") => bail!("Unknown option '{}'", flag),
+            file => path = Some(PathBuf::from(file)),
+        }
+    }
+
+    let path = path.context("export-pack needs a file to write")?;
+    let name = name.unwrap_or_else(|| {
+        path.file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    });
+    let pack = crate::packs::export(&name, config)?;
+    crate::packs::write_pack(&pack, &path)?;
+    println!(
+        "Saved {} objectives, {} indicators and {} templates to {}",
+        pack.objectives.len(),
+        pack.indicators.len(),
+        pack.templates.len() + pack.weekday_templates.len(),
+        path.display()
+    );
+    Ok(())
+}
+
+fn migrate_layout(args: &[String], config: &Config) -> Result<()> {
+    let mut pattern: Option<&String> = None;
+    let mut dry_run = false;
+    for arg in args {
+        match arg.as_str() {
+            "--dry-run" => dry_run = true,
+            flag if flag.starts_with("--") => bail!("Unknown option '{}'", flag),
+            _ => pattern = Some(arg),
+        }
+    }
+    let layout = GoalsLayout(
+        pattern
+            .context("migrate-layout needs a pattern such as '%Y/%m/%Y-%m-%d.md'")?
+            .clone(),
+    );
+
+    let report = crate::data::migrate_goals_layout(&layout, config, dry_run)?;
+    for (from, to) in &report.moved {
+        println!("{} -> {}", from.display(), to.display());
+    }
+    for path in &report.conflicts {
+        println!("skipped (destination exists): {}", path.display());
+    }
+    for path in &report.unrecognized {
+        println!("skipped (not a goals file): {}", path.display());
+    }
+    println!(
+        "{}{} moved, {} already in place, {} conflicts, {} unrecognized",
+        if dry_run { "[dry run] " } else { "" },
+        report.moved.len(),
+        report.unchanged,
+        report.conflicts.len(),
+        report.unrecognized.len()
+    );
+    Ok(())
+}
+
+/// Redraw a one-line progress bar on stderr; finishes the line when done
+fn draw_progress(label: &str, done: usize, total: usize) {
+    const WIDTH: usize = 30;
+    let filled = (done * WIDTH).checked_div(total).unwrap_or(WIDTH);
+    eprint!(
+        "\r{:<20} [{}{}] {}/{}",
+        label,
+        "#".repeat(filled),
+        ".".repeat(WIDTH - filled),
+        done,
+        total
+    );
+    if done >= total {
+        eprintln!();
+    }
+}
+
+fn recompute_stats(args: &[String], config: &Config) -> Result<()> {
+    if let Some(arg) = args.first() {
+        bail!("recompute-stats takes no arguments, got '{}'", arg);
+    }
+
+    draw_progress("Observation index", 0, 1);
+    let observations = crate::data::rebuild_observation_index(config)?;
+    draw_progress("Observation index", 1, 1);
+
+    let dates = crate::data::goals_file_dates(config)?;
+    if dates.is_empty() {
+        draw_progress("Daily statistics", 0, 0);
+    }
+    let days = crate::ui::stats::rebuild_stats_cache(&dates, config, |done, total| {
+        draw_progress("Daily statistics", done, total)
+    })?;
+
+    let mut scored_any = false;
+    let sentiment = crate::sentiment::rebuild_history(config, |done, total| {
+        scored_any = true;
+        draw_progress("Reflection sentiment", done, total)
+    })?;
+    if !scored_any {
+        draw_progress("Reflection sentiment", 0, 0);
+    }
+
+    println!(
+        "{} observations indexed, {} days of statistics, {} sentiment scores",
+        observations,
+        days,
+        sentiment.points.len()
+    );
+    Ok(())
+}
+
+fn status(args: &[String], config: &Config) -> Result<()> {
+    let short = match args {
+        [] => false,
+        [flag] if flag == "--short" => true,
+        _ => bail!("Usage: focusfive status [--short]"),
+    };
+    let status = crate::ui::stats::day_status(chrono::Local::now().date_naive(), config);
+    if short {
+        println!("{}", status.short());
+        return Ok(());
+    }
+    for (name, (done, planned)) in ["Work", "Health", "Family"].iter().zip(status.actions) {
+        println!("{:<7} {}/{}", name, done, planned);
+    }
+    println!("Streak  {} days", status.streak);
+    Ok(())
+}
+
+fn digest(args: &[String], config: &Config) -> Result<()> {
+    let mut date = chrono::Local::now().date_naive();
+    let mut to = None;
+    let mut print = false;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--to" => to = Some(iter.next().context("--to requires a recipient name")?),
+            "--print" => print = true,
+            flag if flag.starts_with("--") => bail!("Unknown option '{}'", flag),
+            day => {
+                date = chrono::NaiveDate::parse_from_str(day, "%Y-%m-%d")
+                    .with_context(|| format!("Expected a YYYY-MM-DD date, got '{}'", day))?;
+            }
+        }
+    }
+
+    let settings = crate::data::load_settings(config)?.digest;
+    let recipients: Vec<_> = settings
+        .recipients
+        .iter()
+        .filter(|r| to.is_none_or(|name| r.name.eq_ignore_ascii_case(name)))
+        .collect();
+    if let Some(name) = to.filter(|_| recipients.is_empty()) {
+        bail!("No digest recipient named '{}' in settings.json", name);
+    }
+
+    let digest = crate::digest::build(date, config)?;
+    if print {
+        match recipients.as_slice() {
+            [] => print!("{}", crate::digest::render(&digest, None)),
+            recipients => {
+                for recipient in recipients {
+                    print!("{}", crate::digest::render(&digest, Some(recipient)));
+                }
+            }
+        }
+        return Ok(());
+    }
+    if recipients.is_empty() {
+        bail!("No digest recipients; add them under digest.recipients in settings.json");
+    }
+    for recipient in recipients {
+        let delivered = crate::digest::send(&digest, recipient, &settings, config)?;
+        println!("Sent to {}: {}", recipient.name, delivered.join(", "));
+    }
+    Ok(())
+}
+
+fn doctor(args: &[String], config: &Config) -> Result<()> {
+    let fix = match args {
+        [] => false,
+        [flag] if flag == "--fix" => true,
+        _ => bail!("Usage: focusfive doctor [--fix]"),
+    };
+    let report = crate::doctor::run(config, fix)?;
+    println!("{}", crate::doctor::format_report(&report));
+    if report.has_unfixed_errors() {
+        bail!("The data has errors that need fixing by hand");
+    }
+    Ok(())
+}
+
+fn sync(args: &[String], config: &Config) -> Result<()> {
+    let target = match args {
+        [] => crate::sync::target(config)?,
+        [location] => {
+            crate::sync::set_target(location, config)?;
+            crate::sync::Target::parse(location)
+        }
+        _ => bail!("Usage: focusfive sync [FOLDER|URL]"),
+    };
+    let report = crate::sync::run(&target, config)?;
+    println!("{}", crate::sync::format_report(&report));
+    Ok(())
+}
+
+fn obsidian(args: &[String], config: &Config) -> Result<()> {
+    let mut settings = crate::data::load_settings(config)?;
+    let mode = &mut settings.obsidian;
+    match args {
+        [] => {}
+        [word] if word == "off" => mode.enabled = false,
+        [word] if word == "on" => {
+            mode.enabled = true;
+            mode.vault_dir = None;
+        }
+        [folder] => {
+            let folder = std::fs::canonicalize(folder)
+                .with_context(|| format!("Vault folder {} not found", folder))?;
+            if !folder.is_dir() {
+                bail!("{} is not a folder", folder.display());
+            }
+            mode.enabled = true;
+            mode.vault_dir = Some(folder.to_string_lossy().into_owned());
+        }
+        _ => bail!("Usage: focusfive obsidian [VAULT_FOLDER|on|off]"),
+    }
+    if !args.is_empty() {
+        crate::data::save_settings(&settings, config)?;
+    }
+    match (settings.obsidian.enabled, &settings.obsidian.vault_dir) {
+        (false, _) => println!("Obsidian mode is off"),
+        (true, None) => println!("Obsidian mode is on"),
+        (true, Some(dir)) => println!("Obsidian mode is on; notes are kept in {}", dir),
+    }
+    Ok(())
+}
+
+fn exec(args: &[String], config: &Config) -> Result<()> {
+    // The shell has already split and unquoted the words
+    let command = crate::command::parse_words(args)?;
+    let today = chrono::Local::now().date_naive();
+    println!(
+        "{}",
+        crate::command::run_headless(&command, today, config)?.trim_end()
+    );
+    Ok(())
+}
+
+fn print_plan(args: &[String], config: &Config) -> Result<()> {
+    let date = match args {
+        [] => chrono::Local::now().date_naive(),
+        [day] => chrono::NaiveDate::parse_from_str(day, "%Y-%m-%d")
+            .with_context(|| format!("Expected a YYYY-MM-DD date, got '{}'", day))?,
+        _ => bail!("Usage: focusfive plan [YYYY-MM-DD]"),
+    };
+    print!("{}", crate::export::render_plan(date, config)?);
+    Ok(())
+}
+
+fn year_review(args: &[String], config: &Config) -> Result<()> {
+    let today = chrono::Local::now().date_naive();
+    let mut year = chrono::Datelike::year(&today);
+    let mut export = false;
+    for arg in args {
+        match arg.as_str() {
+            "--export" => export = true,
+            other => {
+                year = other
+                    .parse()
+                    .with_context(|| format!("Expected a year like 2025, got '{}'", other))?
+            }
+        }
+    }
+
+    let review = crate::year_review::build(year, today, config)?;
+    if export {
+        let path = crate::year_review::write_export(&review, config)?;
+        println!("Exported the {} review to {}", year, path.display());
+    } else {
+        print!("{}", crate::year_review::to_markdown(&review));
+    }
+    Ok(())
+}
+
+fn print_summary(args: &[String], config: &Config) -> Result<()> {
+    let mut date = chrono::Local::now().date_naive();
+    let mut settings = crate::data::load_settings(config)?.summary;
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--template" => {
+                let file = iter.next().context("--template requires a file")?;
+                // Relative to where the command runs, not the data root
+                let path = std::env::current_dir()?.join(file);
+                settings.template = Some(path.to_string_lossy().into_owned());
+            }
+            flag if flag.starts_with("--") => bail!("Unknown option '{}'", flag),
+            day => {
+                date = chrono::NaiveDate::parse_from_str(day, "%Y-%m-%d")
+                    .with_context(|| format!("Expected a YYYY-MM-DD date, got '{}'", day))?;
+            }
+        }
+    }
+
+    let path = crate::data::goals_file_path(date, config);
+    if !path.exists() {
+        bail!("No goals for {}", date);
+    }
+    let goals = crate::data::read_goals_for(date, config)?;
+    let indicators = crate::data::load_or_create_indicators(config)?;
+    print!(
+        "{}",
+        crate::summary::summary_for_day(&goals, &indicators, &settings, config)?
+    );
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//! System clipboard access without a native dependency: the first of the
+//! platform's clipboard tools found on PATH is run (pbcopy/pbpaste on
+//! macOS, wl-copy/wl-paste under Wayland, then xclip or xsel under X11).
+
+use anyhow::{bail, Context, Result};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+const PASTE_TOOLS: &[(&str, &[&str])] = &[
+    ("pbpaste", &[]),
+    ("wl-paste", &["--no-newline"]),
+    ("xclip", &["-selection", "clipboard", "-o"]),
+    ("xsel", &["--clipboard", "--output"]),
+];
+
+/// Text currently on the system clipboard
+pub fn read() -> Result<String> {
+    for (tool, args) in PASTE_TOOLS {
+        let output = match Command::new(tool)
+            .args(*args)
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output()
+        {
+            Ok(output) => output,
+            // Not installed; try the next one
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e).with_context(|| format!("Failed to run {}", tool)),
+        };
+        if !output.status.success() {
+            bail!("{} could not read the clipboard ({})", tool, output.status);
+        }
+        return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+    }
+    bail!("No clipboard tool found (install wl-clipboard, xclip or xsel)")
+}
+
+const COPY_TOOLS: &[(&str, &[&str])] = &[
+    ("pbcopy", &[]),
+    ("wl-copy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+];
+
+/// Put `text` on the system clipboard, through `command` when one is
+/// configured (`clipboard_command` in settings.json)
+pub fn write(text: &str, command: Option<&str>) -> Result<()> {
+    if let Some(command) = command.map(str::trim).filter(|c| !c.is_empty()) {
+        return pipe(Command::new("sh").arg("-c").arg(command), command, text);
+    }
+    for (tool, args) in COPY_TOOLS {
+        let mut child = Command::new(tool);
+        child.args(*args);
+        match pipe(&mut child, tool, text) {
+            Err(e)
+                if e.downcast_ref::<std::io::Error>()
+                    .is_some_and(|e| e.kind() == std::io::ErrorKind::NotFound) =>
+            {
+                continue
+            }
+            result => return result,
+        }
+    }
+    bail!("No clipboard tool found (install wl-clipboard, xclip or xsel, or set clipboard_command)")
+}
+
+/// Run `command` with `text` on stdin
+fn pipe(command: &mut Command, name: &str, text: &str) -> Result<()> {
+    let mut child = command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(text.as_bytes())
+            .with_context(|| format!("Failed to send text to {}", name))?;
+    }
+    let status = child.wait()?;
+    if !status.success() {
+        bail!("{} could not copy to the clipboard ({})", name, status);
+    }
+    Ok(())
+}
@@ -0,0 +1,120 @@
+//! Closing a day: its final numbers and rendered summary are written to
+//! summaries/YYYY-MM-DD.md, unfinished actions roll into the next day as
+//! carry-overs, and the day's metadata records the close so the app keeps
+//! it read-only until it is explicitly reopened.
+
+use crate::carryover::{self, CarrySummary};
+use crate::models::{Config, DailyGoals, IndicatorsData, SummarySettings};
+use anyhow::{bail, Context, Result};
+use chrono::NaiveDate;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// What closing a day did
+#[derive(Debug, Clone, PartialEq)]
+pub struct CloseOut {
+    pub date: NaiveDate,
+    pub completed: usize,
+    pub planned: usize,
+    /// Unfinished actions moved into the next day
+    pub rolled: CarrySummary,
+    pub summary_path: PathBuf,
+}
+
+impl CloseOut {
+    pub fn percent(&self) -> u32 {
+        (self.completed * 100)
+            .checked_div(self.planned)
+            .unwrap_or(0) as u32
+    }
+}
+
+/// Whether `date` has been closed and not reopened since
+pub fn is_closed(date: NaiveDate, config: &Config) -> Result<bool> {
+    let path = crate::data::goals_file_path(date, config);
+    if !path.exists() {
+        return Ok(false);
+    }
+    let goals = crate::data::read_goals_for(date, config)?;
+    let meta = crate::data::load_or_create_day_meta(date, &goals, config)?;
+    Ok(meta.closed.is_some())
+}
+
+fn summary_path(date: NaiveDate, config: &Config) -> PathBuf {
+    Path::new(&config.data_root)
+        .join("summaries")
+        .join(format!("{}.md", date.format("%Y-%m-%d")))
+}
+
+/// Close the day of `goals`. Actions already waiting in the next day are
+/// not rolled again, so a reopened day can be closed a second time.
+pub fn close_day(
+    goals: &DailyGoals,
+    indicators: &IndicatorsData,
+    settings: &SummarySettings,
+    config: &Config,
+) -> Result<CloseOut> {
+    let mut meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+    if meta.closed.is_some() {
+        bail!("{} is already closed", goals.date);
+    }
+    crate::data::write_goals_file(goals, config)?;
+
+    let actions: Vec<_> = goals
+        .outcomes()
+        .into_iter()
+        .flat_map(|outcome| &outcome.actions)
+        .filter(|action| !action.text.trim().is_empty())
+        .collect();
+    let completed = actions.iter().filter(|action| action.completed).count();
+
+    let summary = crate::summary::summary_for_day(goals, indicators, settings, config)?;
+    let path = summary_path(goals.date, config);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create summaries directory: {}", dir.display()))?;
+    }
+    fs::write(&path, summary)
+        .with_context(|| format!("Failed to write summary: {}", path.display()))?;
+
+    let next_date = goals.date.succ_opt().context("Cannot get the next date")?;
+    let mut next = crate::data::load_or_create_goals(next_date, config)?;
+    let items: Vec<_> = carryover::unfinished_items(goals.date, config)?
+        .into_iter()
+        .filter(|item| {
+            !next
+                .outcome(item.outcome_type)
+                .actions
+                .iter()
+                .any(|action| action.text.trim() == item.text.trim())
+        })
+        .collect();
+    let rolled = if items.is_empty() {
+        CarrySummary::default()
+    } else {
+        carryover::apply(&items, &mut next, config)?
+    };
+
+    meta.closed = Some(chrono::Utc::now());
+    meta.modified = chrono::Utc::now();
+    crate::data::save_day_meta(goals.date, &meta, config)?;
+
+    Ok(CloseOut {
+        date: goals.date,
+        completed,
+        planned: actions.len(),
+        rolled,
+        summary_path: path,
+    })
+}
+
+/// Make a closed day editable again; returns false when it was not closed
+pub fn reopen_day(goals: &DailyGoals, config: &Config) -> Result<bool> {
+    let mut meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+    if meta.closed.take().is_none() {
+        return Ok(false);
+    }
+    meta.modified = chrono::Utc::now();
+    crate::data::save_day_meta(goals.date, &meta, config)?;
+    Ok(true)
+}
@@ -0,0 +1,567 @@
+//! Ex-style commands, typed after `:` in the TUI or passed to
+//! `focusfive exec`. Both front ends share the parser; the TUI applies
+//! commands to the day on screen, `run_headless` to the files directly.
+
+use crate::capacity::DayCapacity;
+use crate::export::ExportPeriod;
+use crate::models::{
+    format_minutes, parse_minutes, Config, DailyGoals, IndicatorAggregation, IndicatorPanel,
+    OutcomeType, ThemePreset, WeekdayTemplate,
+};
+use crate::ui::stats::{ComparePreset, CompareRanges, DateRange};
+use anyhow::{bail, Context, Result};
+use chrono::{Duration, Local, NaiveDate, Weekday};
+
+/// One-line summary of the commands, shown on errors
+pub const COMMAND_HELP: &str = "goto YYYY-MM-DD|today|yesterday, add work|health|family \"text\", \
+     export day|week|month|plan, theme dark|colorblind|tritan|monochrome, capacity 8h, \
+     estimate [work|health|family N] 45m|none, \
+     daytemplate \"name\" mon,fri|weekdays|weekend|daily|none, doctor [fix], sync, \
+     sort [work|health|family], compare [week|month|quarter|RANGE RANGE], \
+     rule \"indicator\" #tag +20|none, group \"indicator\" NAME|none, \
+     panel metrics|performance|signals GROUP,GROUP|all, aggregate \"indicator\" sum|last|average, \
+     units hours|minutes km|miles|defined compact|plain, \
+     target \"indicator\" 20km/week|150/month|8|none, whatsnew";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExCommand {
+    Goto(NaiveDate),
+    Add {
+        outcome: OutcomeType,
+        text: String,
+    },
+    Export(ExportPeriod),
+    Theme(ThemePreset),
+    Capacity(u32),
+    /// Estimate for an action, or the selected one in the TUI when no
+    /// outcome and 0-based index are given; `None` clears it
+    Estimate {
+        target: Option<(OutcomeType, usize)>,
+        minutes: Option<u32>,
+    },
+    /// Save the day's actions as a template for new days of `weekdays`;
+    /// no weekdays removes the template
+    DayTemplate {
+        name: String,
+        weekdays: Vec<Weekday>,
+    },
+    /// Check the data files, repairing what can be repaired with `fix`
+    Doctor {
+        fix: bool,
+    },
+    /// Sync the data directory with the folder set in settings.json
+    Sync,
+    /// Order an outcome's actions by priority; the selected outcome in the
+    /// TUI, or every outcome headless, when none is given
+    Sort(Option<OutcomeType>),
+    /// Side-by-side statistics of two ranges
+    Compare(CompareRanges),
+    /// Add `value` to an indicator whenever an action with `tag` is
+    /// completed; `None` removes the rule
+    Rule {
+        indicator: String,
+        tag: String,
+        value: Option<f64>,
+    },
+    /// Put an indicator in a group, or in none
+    Group {
+        indicator: String,
+        group: Option<String>,
+    },
+    /// Groups a dashboard panel shows; none shows every indicator
+    Panel {
+        panel: IndicatorPanel,
+        groups: Vec<String>,
+    },
+    /// How an indicator combines several observations on one day
+    Aggregate {
+        indicator: String,
+        aggregation: IndicatorAggregation,
+    },
+    /// Display preferences for indicator units, e.g. hours and miles
+    Units(Vec<String>),
+    /// An indicator's target, per day, week or month, or none
+    Target { indicator: String, target: String },
+    /// Notes on every release
+    WhatsNew,
+}
+
+/// Split a command line into words; double or single quotes group words
+/// and a backslash escapes the next character
+pub fn split_words(line: &str) -> Result<Vec<String>> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut quote: Option<char> = None;
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match (c, quote) {
+            ('\\', _) => {
+                word.push(chars.next().context("Trailing backslash in command")?);
+                in_word = true;
+            }
+            (c, Some(q)) if c == q => quote = None,
+            (_, Some(_)) => word.push(c),
+            ('"' | '\'', None) => {
+                quote = Some(c);
+                in_word = true;
+            }
+            (c, None) if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            (c, None) => {
+                word.push(c);
+                in_word = true;
+            }
+        }
+    }
+    if let Some(q) = quote {
+        bail!("Unclosed {} in command", q);
+    }
+    if in_word {
+        words.push(word);
+    }
+    Ok(words)
+}
+
+fn parse_outcome(word: &str) -> Result<OutcomeType> {
+    match word.to_lowercase().as_str() {
+        "w" | "work" => Ok(OutcomeType::Work),
+        "h" | "health" => Ok(OutcomeType::Health),
+        "f" | "family" => Ok(OutcomeType::Family),
+        other => bail!(
+            "Unknown outcome '{}' (expected work, health or family)",
+            other
+        ),
+    }
+}
+
+/// "mon,fri", "weekdays", "weekend", "daily" or "none"
+fn parse_weekdays(word: &str) -> Result<Vec<Weekday>> {
+    const WEEK: [Weekday; 7] = [
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ];
+    match word.to_lowercase().as_str() {
+        "none" => Ok(Vec::new()),
+        "weekdays" => Ok(WEEK[..5].to_vec()),
+        "weekend" => Ok(WEEK[5..].to_vec()),
+        "daily" => Ok(WEEK.to_vec()),
+        list => list
+            .split(',')
+            .map(|day| {
+                day.trim()
+                    .parse::<Weekday>()
+                    .map_err(|_| anyhow::anyhow!("Unknown weekday '{}'", day.trim()))
+            })
+            .collect(),
+    }
+}
+
+fn parse_date(word: &str) -> Result<NaiveDate> {
+    let today = Local::now().date_naive();
+    match word.to_lowercase().as_str() {
+        "today" => Ok(today),
+        "yesterday" => Ok(today - Duration::days(1)),
+        _ => NaiveDate::parse_from_str(word, "%Y-%m-%d")
+            .with_context(|| format!("Expected a YYYY-MM-DD date, got '{}'", word)),
+    }
+}
+
+/// Parse already split words; unquoted text after `add OUTCOME` is joined
+pub fn parse_words<S: AsRef<str>>(words: &[S]) -> Result<ExCommand> {
+    let Some((name, args)) = words.split_first() else {
+        bail!("Empty command. Commands: {}", COMMAND_HELP);
+    };
+    let args: Vec<&str> = args.iter().map(AsRef::as_ref).collect();
+    let one_arg = |what: &str| -> Result<&str> {
+        match args.as_slice() {
+            [arg] => Ok(arg),
+            [] => bail!("{} needs {}", name.as_ref(), what),
+            _ => bail!("{} takes only {}", name.as_ref(), what),
+        }
+    };
+
+    match name.as_ref().to_lowercase().as_str() {
+        "goto" | "go" | "g" => Ok(ExCommand::Goto(parse_date(one_arg("a date")?)?)),
+        "add" | "a" => {
+            let Some((outcome, text)) = args.split_first() else {
+                bail!("add needs an outcome and the action text");
+            };
+            let text = text.join(" ");
+            if text.trim().is_empty() {
+                bail!("add needs the action text");
+            }
+            Ok(ExCommand::Add {
+                outcome: parse_outcome(outcome)?,
+                text: text.trim().to_string(),
+            })
+        }
+        "export" => Ok(ExCommand::Export(
+            one_arg("day, week, month or plan")?.parse()?,
+        )),
+        "theme" => Ok(ExCommand::Theme(one_arg("a theme name")?.parse()?)),
+        "capacity" => {
+            let input = one_arg("a duration like 8h or 7h30m")?;
+            match parse_minutes(input) {
+                Some(minutes) if minutes > 0 => Ok(ExCommand::Capacity(minutes)),
+                _ => bail!("Invalid capacity '{}': use minutes or e.g. 7h30m", input),
+            }
+        }
+        "estimate" | "est" => {
+            let (target, input) = match args.as_slice() {
+                [input] => (None, *input),
+                [outcome, number, input] => {
+                    let index = number
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|n| n.checked_sub(1))
+                        .with_context(|| format!("Expected an action number, got '{}'", number))?;
+                    (Some((parse_outcome(outcome)?, index)), *input)
+                }
+                _ => bail!(
+                    "estimate needs a duration, optionally after an outcome and action number"
+                ),
+            };
+            let minutes = match input.to_lowercase().as_str() {
+                "none" | "-" => None,
+                _ => Some(parse_minutes(input).with_context(|| {
+                    format!("Invalid estimate '{}': use minutes or e.g. 1h30m", input)
+                })?),
+            };
+            Ok(ExCommand::Estimate { target, minutes })
+        }
+        "daytemplate" | "dt" => match args.as_slice() {
+            [name, days] if !name.trim().is_empty() => Ok(ExCommand::DayTemplate {
+                name: name.trim().to_string(),
+                weekdays: parse_weekdays(days)?,
+            }),
+            _ => bail!(
+                "daytemplate needs a name and weekdays, e.g. daytemplate \"Monday planning\" mon"
+            ),
+        },
+        "doctor" => match args.as_slice() {
+            [] => Ok(ExCommand::Doctor { fix: false }),
+            [fix] if fix.eq_ignore_ascii_case("fix") => Ok(ExCommand::Doctor { fix: true }),
+            _ => bail!("doctor takes no arguments, or fix"),
+        },
+        "sync" => match args.as_slice() {
+            [] => Ok(ExCommand::Sync),
+            _ => bail!("sync takes no arguments; set the folder with `focusfive sync <folder>`"),
+        },
+        "sort" => match args.as_slice() {
+            [] => Ok(ExCommand::Sort(None)),
+            [outcome] => Ok(ExCommand::Sort(Some(parse_outcome(outcome)?))),
+            _ => bail!("sort takes at most an outcome"),
+        },
+        "compare" => match args.as_slice() {
+            [] => Ok(ExCommand::Compare(CompareRanges::Preset(
+                ComparePreset::Week,
+            ))),
+            [preset] => Ok(ExCommand::Compare(CompareRanges::Preset(
+                match preset.to_lowercase().as_str() {
+                    "week" => ComparePreset::Week,
+                    "month" => ComparePreset::Month,
+                    "quarter" => ComparePreset::Quarter,
+                    other => bail!(
+                        "Unknown period '{}' (expected week, month or quarter, or two ranges)",
+                        other
+                    ),
+                },
+            ))),
+            [first, second] => Ok(ExCommand::Compare(CompareRanges::Ranges(
+                DateRange::parse(first)?,
+                DateRange::parse(second)?,
+            ))),
+            _ => bail!("compare takes a period or two ranges, e.g. compare 2025-03 2025-02"),
+        },
+        "rule" => match args.as_slice() {
+            [indicator, tag, input] if tag.starts_with('#') => {
+                let value = match input.to_lowercase().as_str() {
+                    "none" | "-" => None,
+                    _ => Some(
+                        input
+                            .trim_start_matches('+')
+                            .parse::<f64>()
+                            .ok()
+                            .filter(|value| value.is_finite())
+                            .with_context(|| format!("Invalid amount '{}'", input))?,
+                    ),
+                };
+                Ok(ExCommand::Rule {
+                    indicator: indicator.to_string(),
+                    tag: tag.to_string(),
+                    value,
+                })
+            }
+            _ => bail!(
+                "rule needs an indicator, a #tag and an amount, e.g. rule Pushups #pushups +20"
+            ),
+        },
+        "group" => match args.as_slice() {
+            [indicator, group] => Ok(ExCommand::Group {
+                indicator: indicator.to_string(),
+                group: match group.to_lowercase().as_str() {
+                    "none" | "-" => None,
+                    _ => Some(group.trim().to_string()),
+                },
+            }),
+            _ => bail!("group needs an indicator and a group name, e.g. group Pushups Fitness"),
+        },
+        "panel" => match args.as_slice() {
+            [panel, groups] => Ok(ExCommand::Panel {
+                panel: IndicatorPanel::parse(panel).with_context(|| {
+                    format!(
+                        "Unknown panel '{}' (expected metrics, performance or signals)",
+                        panel
+                    )
+                })?,
+                groups: match groups.to_lowercase().as_str() {
+                    "all" | "none" | "-" => Vec::new(),
+                    _ => groups
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|group| !group.is_empty())
+                        .map(String::from)
+                        .collect(),
+                },
+            }),
+            _ => bail!("panel needs a panel and its groups, e.g. panel performance Fitness,Output"),
+        },
+        "aggregate" => match args.as_slice() {
+            [indicator, aggregation] => Ok(ExCommand::Aggregate {
+                indicator: indicator.to_string(),
+                aggregation: IndicatorAggregation::parse(aggregation).with_context(|| {
+                    format!(
+                        "Unknown aggregation '{}' (expected sum, last or average)",
+                        aggregation
+                    )
+                })?,
+            }),
+            _ => bail!("aggregate needs an indicator and sum, last or average, e.g. aggregate Exercise sum"),
+        },
+        "target" => match args.as_slice() {
+            [indicator, target @ ..] if !target.is_empty() => Ok(ExCommand::Target {
+                indicator: indicator.to_string(),
+                target: target.join(" "),
+            }),
+            _ => bail!("target needs an indicator and a target, e.g. target Running 20km/week"),
+        },
+        "units" => {
+            if args.is_empty() {
+                bail!("units needs a preference, e.g. units hours miles compact");
+            }
+            let mut preferences = crate::models::UnitPreferences::default();
+            for word in &args {
+                crate::units::set_preference(&mut preferences, word)?;
+            }
+            Ok(ExCommand::Units(args.iter().map(|word| word.to_string()).collect()))
+        }
+        "whatsnew" | "news" => match args.as_slice() {
+            [] => Ok(ExCommand::WhatsNew),
+            _ => bail!("whatsnew takes no arguments"),
+        },
+        other => bail!("Unknown command '{}'. Commands: {}", other, COMMAND_HELP),
+    }
+}
+
+/// Parse a command line, with or without its leading `:`
+pub fn parse(line: &str) -> Result<ExCommand> {
+    let line = line.trim();
+    parse_words(&split_words(line.strip_prefix(':').unwrap_or(line))?)
+}
+
+/// Remember `preset` in settings.json
+pub fn save_theme(preset: ThemePreset, config: &Config) -> Result<()> {
+    let mut settings = crate::data::load_settings(config)?;
+    settings.theme = preset;
+    crate::data::save_settings(&settings, config)?;
+    Ok(())
+}
+
+/// Save the actions of `goals` as the weekday template `name`, or remove it
+/// when `weekdays` is empty; returns what happened
+pub fn save_day_template(
+    goals: &DailyGoals,
+    name: &str,
+    weekdays: &[Weekday],
+    config: &Config,
+) -> Result<String> {
+    let mut templates = crate::data::load_or_create_templates(config)?;
+    let message = if weekdays.is_empty() {
+        if !templates.remove_weekday_template(name) {
+            bail!("No weekday template named '{}'", name);
+        }
+        format!("Removed weekday template '{}'", name)
+    } else {
+        let template = WeekdayTemplate::from_goals(goals, weekdays.to_vec());
+        if template.action_count() == 0 {
+            bail!("{} has no actions to save as a template", goals.date);
+        }
+        let days: Vec<String> = weekdays.iter().map(|day| day.to_string()).collect();
+        let message = format!(
+            "Saved '{}' with {} actions for new {} days",
+            name,
+            template.action_count(),
+            days.join("/")
+        );
+        templates.set_weekday_template(name.to_string(), template);
+        message
+    };
+    crate::data::save_templates(&templates, config)?;
+    Ok(message)
+}
+
+/// Apply a command straight to the files, relative to `today`, and return
+/// what to print
+pub fn run_headless(command: &ExCommand, today: NaiveDate, config: &Config) -> Result<String> {
+    match command {
+        ExCommand::Goto(date) => {
+            let path = crate::data::goals_file_path(*date, config);
+            if !path.exists() {
+                bail!("No goals for {}", date);
+            }
+            let goals = crate::data::read_goals_for(*date, config)?;
+            crate::export::day_markdown(&goals, config)
+        }
+        ExCommand::Add { outcome, text } => {
+            let mut goals = crate::data::load_or_create_goals(today, config)?;
+            let index = goals.outcome_mut(*outcome).place_action(text.clone())?;
+            crate::data::write_goals_file(&goals, config)?;
+            Ok(format!(
+                "Added to {} for {} (action {}): {}",
+                outcome.as_str(),
+                today,
+                index + 1,
+                text
+            ))
+        }
+        ExCommand::Export(period) => {
+            let path = crate::export::write_export(*period, today, config)?;
+            Ok(format!(
+                "Exported {} to {}",
+                period.as_str(),
+                path.display()
+            ))
+        }
+        ExCommand::Theme(preset) => {
+            save_theme(*preset, config)?;
+            Ok(format!("Theme set to {}", preset.as_str()))
+        }
+        ExCommand::Capacity(minutes) => {
+            crate::capacity::save_capacity(*minutes, config)?;
+            Ok(format!(
+                "Daily capacity set to {}",
+                format_minutes(*minutes)
+            ))
+        }
+        ExCommand::Estimate { target, minutes } => {
+            let Some((outcome, index)) = *target else {
+                bail!("estimate needs an outcome and action number here, e.g. estimate work 2 45m");
+            };
+            let goals = crate::data::load_or_create_goals(today, config)?;
+            crate::capacity::set_estimate(&goals, outcome, index, *minutes, config)?;
+            let text = &goals.outcome(outcome).actions[index].text;
+            let mut message = match minutes {
+                Some(minutes) => format!("Estimated {} for: {}", format_minutes(*minutes), text),
+                None => format!("Cleared the estimate for: {}", text),
+            };
+            let settings = crate::data::load_settings(config)?;
+            if let Some(warning) = DayCapacity::for_day(&goals, &settings, config)?.warning() {
+                message.push_str(&format!("\nWarning: {}", warning));
+            }
+            Ok(message)
+        }
+        ExCommand::DayTemplate { name, weekdays } => {
+            let goals = crate::data::load_or_create_goals(today, config)?;
+            save_day_template(&goals, name, weekdays, config)
+        }
+        ExCommand::Doctor { fix } => {
+            let report = crate::doctor::run(config, *fix)?;
+            Ok(crate::doctor::format_report(&report))
+        }
+        ExCommand::Sync => {
+            let report = crate::sync::run(&crate::sync::target(config)?, config)?;
+            Ok(crate::sync::format_report(&report))
+        }
+        ExCommand::Sort(outcome) => {
+            let mut goals = crate::data::load_or_create_goals(today, config)?;
+            let outcomes = match outcome {
+                Some(outcome) => vec![*outcome],
+                None => vec![OutcomeType::Work, OutcomeType::Health, OutcomeType::Family],
+            };
+            for outcome in outcomes {
+                crate::priority::sort_by_priority(&mut goals, outcome, config)?;
+            }
+            Ok(format!("Sorted the actions of {} by priority", today))
+        }
+        ExCommand::Compare(ranges) => {
+            let (first, second) = ranges.resolve(today);
+            let comparison = crate::ui::stats::compare_ranges(&first, &second, today, config)?;
+            let indicators = crate::data::load_or_create_indicators(config)?;
+            Ok(crate::ui::stats::format_comparison(
+                &comparison,
+                &indicators,
+            ))
+        }
+        ExCommand::Rule {
+            indicator,
+            tag,
+            value,
+        } => {
+            let mut indicators = crate::data::load_or_create_indicators(config)?;
+            let message = crate::automation::set_rule(&mut indicators, indicator, tag, *value)?;
+            crate::data::save_indicators(&indicators, config)?;
+            Ok(message)
+        }
+        ExCommand::Group { indicator, group } => {
+            let mut indicators = crate::data::load_or_create_indicators(config)?;
+            let message =
+                crate::indicator_groups::set_group(&mut indicators, indicator, group.as_deref())?;
+            crate::data::save_indicators(&indicators, config)?;
+            Ok(message)
+        }
+        ExCommand::Panel { panel, groups } => {
+            let layout = crate::indicator_groups::save_panel_groups(*panel, groups, config)?;
+            let indicators = crate::data::load_or_create_indicators(config)?;
+            Ok(crate::indicator_groups::describe_panel(
+                *panel,
+                &layout,
+                &indicators,
+            ))
+        }
+        ExCommand::Aggregate {
+            indicator,
+            aggregation,
+        } => {
+            let mut indicators = crate::data::load_or_create_indicators(config)?;
+            let message =
+                crate::aggregation::set_aggregation(&mut indicators, indicator, *aggregation)?;
+            crate::data::save_indicators(&indicators, config)?;
+            Ok(message)
+        }
+        ExCommand::Target { indicator, target } => {
+            let mut indicators = crate::data::load_or_create_indicators(config)?;
+            let message = crate::targets::set_target(&mut indicators, indicator, target)?;
+            crate::data::save_indicators(&indicators, config)?;
+            Ok(message)
+        }
+        ExCommand::Units(words) => {
+            let preferences = crate::units::save_preferences(words, config)?;
+            Ok(format!("Units: {}", crate::units::describe(&preferences)))
+        }
+        ExCommand::WhatsNew => {
+            let releases: Vec<_> = crate::changelog::RELEASES.iter().collect();
+            Ok(crate::changelog::format_releases(&releases))
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! When in the day actions get checked off: completion times are stamped
+//! into the day's metadata as actions are completed, and summed up here by
+//! hour to show how the day's work is spread and which outcome tends to
+//! slip into the late evening.
+
+use crate::models::{Config, OutcomeType};
+use anyhow::Result;
+use chrono::{Duration, Local, NaiveDate, Timelike};
+use std::path::Path;
+
+/// Hour from which a completion counts as late evening
+pub const LATE_EVENING_HOUR: usize = 20;
+/// Fewest completions an outcome needs before it is said to slip
+const MIN_COMPLETIONS: u32 = 3;
+
+const OUTCOMES: [OutcomeType; 3] = [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family];
+
+/// Completions by local hour of the day over a window of days
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CompletionTimes {
+    /// Completions per hour, 0 to 23, for Work, Health and Family
+    pub by_hour: [[u32; 3]; 24],
+    /// Days in the window with at least one stamped completion
+    pub days: usize,
+}
+
+impl CompletionTimes {
+    /// Tally (outcome, hour) pairs
+    pub fn from_hours(hours: &[(OutcomeType, u32)]) -> Self {
+        let mut times = Self::default();
+        for (outcome_type, hour) in hours {
+            if let Some(row) = times.by_hour.get_mut(*hour as usize) {
+                row[outcome_index(*outcome_type)] += 1;
+            }
+        }
+        times
+    }
+
+    /// Completion times stamped in the metadata of the `days` days ending
+    /// on `end`
+    pub fn calculate(end: NaiveDate, days: i64, config: &Config) -> Result<Self> {
+        let mut hours = Vec::new();
+        let mut stamped_days = 0;
+        for day in (end - Duration::days(days - 1))
+            .iter_days()
+            .take(days as usize)
+        {
+            let goals_path = crate::data::goals_file_path(day, config);
+            let meta_path = Path::new(&config.data_root)
+                .join("meta")
+                .join(format!("{}.meta.json", day.format("%Y-%m-%d")));
+            if !goals_path.exists() || !meta_path.exists() {
+                continue;
+            }
+
+            let goals = crate::data::read_goals_for(day, config)?;
+            let meta = crate::data::load_or_create_day_meta(day, &goals, config)?;
+            let before = hours.len();
+            for outcome_type in OUTCOMES {
+                hours.extend(
+                    meta.outcome(outcome_type)
+                        .iter()
+                        .filter_map(|action_meta| action_meta.completed_at)
+                        .map(|at| (outcome_type, at.with_timezone(&Local).hour())),
+                );
+            }
+            stamped_days += usize::from(hours.len() > before);
+        }
+        let mut times = Self::from_hours(&hours);
+        times.days = stamped_days;
+        Ok(times)
+    }
+
+    pub fn total(&self, outcome_type: OutcomeType) -> u32 {
+        let index = outcome_index(outcome_type);
+        self.by_hour.iter().map(|row| row[index]).sum()
+    }
+
+    /// Completions of every outcome in `hour`
+    pub fn hour_total(&self, hour: usize) -> u32 {
+        self.by_hour[hour].iter().sum()
+    }
+
+    /// Share of an outcome's completions from [`LATE_EVENING_HOUR`] on
+    pub fn late_share(&self, outcome_type: OutcomeType) -> Option<f64> {
+        let total = self.total(outcome_type);
+        let index = outcome_index(outcome_type);
+        let late: u32 = self.by_hour[LATE_EVENING_HOUR..]
+            .iter()
+            .map(|row| row[index])
+            .sum();
+        (total > 0).then(|| f64::from(late) / f64::from(total))
+    }
+
+    /// Hour by which half of an outcome's completions are done
+    pub fn median_hour(&self, outcome_type: OutcomeType) -> Option<usize> {
+        let total = self.total(outcome_type);
+        let index = outcome_index(outcome_type);
+        let mut seen = 0;
+        self.by_hour.iter().position(|row| {
+            seen += row[index];
+            total > 0 && seen * 2 >= total
+        })
+    }
+
+    /// The outcome with the largest share of late-evening completions, if
+    /// any has some and enough completions to tell
+    pub fn slipping_outcome(&self) -> Option<(OutcomeType, f64)> {
+        OUTCOMES
+            .into_iter()
+            .filter(|outcome_type| self.total(*outcome_type) >= MIN_COMPLETIONS)
+            .filter_map(|outcome_type| Some((outcome_type, self.late_share(outcome_type)?)))
+            .filter(|(_, share)| *share > 0.0)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+    }
+}
+
+fn outcome_index(outcome_type: OutcomeType) -> usize {
+    OUTCOMES
+        .iter()
+        .position(|candidate| *candidate == outcome_type)
+        .unwrap_or(0)
+}
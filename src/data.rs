@@ -1,13 +1,18 @@
 use crate::models::{
-    Action, ActionTemplates, Config, DailyGoals, DayMeta, FiveYearVision, IndicatorsData,
-    ObjectivesData, Observation, Outcome, Review, ReviewData,
+    Action, ActionOrigin, ActionTemplates, ActivityEvent, Config, DailyGoals, DayMeta,
+    FiveYearVision, FocusSession, GoalsLayout, IndicatorsData, ObjectivesData, Observation,
+    ObservationSource, Outcome, OutcomeType, Profile, ProjectsData, Review, ReviewData,
+    SentimentHistory, Settings, StatsCache, TrashData, VisionVersion, WeeklyGoals,
+    MAX_ACTION_LENGTH,
 };
 use anyhow::{Context, Result};
-use chrono::{Local, NaiveDate};
+use chrono::{Datelike, Local, NaiveDate};
 use regex::Regex;
+use std::collections::HashMap;
 use std::fs;
 use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Safely get capture group as string
@@ -28,25 +33,46 @@ where
         .with_context(|| format!("Failed to parse capture group {} as expected type", index))
 }
 
+/// Width at which action text longer than MAX_ACTION_LENGTH is wrapped
+pub const MARKDOWN_WRAP_WIDTH: usize = 80;
+
 /// Parse a markdown file into DailyGoals
 pub fn parse_markdown(content: &str) -> Result<DailyGoals> {
-    let lines: Vec<&str> = content.lines().collect();
+    parse_daily_note(content, None)
+}
+
+/// Parse goals, dating notes that have neither a date header nor a
+/// frontmatter date with `fallback_date`
+fn parse_daily_note(content: &str, fallback_date: Option<NaiveDate>) -> Result<DailyGoals> {
+    // Obsidian daily notes may start with YAML frontmatter
+    let (frontmatter, body) = crate::obsidian::split_frontmatter(content);
+    let frontmatter = crate::obsidian::Frontmatter::parse(frontmatter);
+    let lines: Vec<&str> = body.lines().collect();
 
     // Find the date header in first 10 lines (not just line 0)
-    let (header_index, date) = find_date_header(&lines)?;
+    let (start, date) = match find_date_header(&lines) {
+        Ok((header_index, date)) => (header_index + 1, date),
+        Err(e) => match frontmatter.date.or(fallback_date) {
+            Some(date) => (0, date),
+            None => return Err(e),
+        },
+    };
 
     let mut goals = DailyGoals::new(date);
     let mut current_outcome: Option<&mut Outcome> = None;
     let mut action_index = 0;
+    // Tasks tagged with an outcome outside its section, added at the end
+    let mut tagged: Vec<(OutcomeType, Action)> = Vec::new();
 
     // Extract day number from the header line we found
-    if let Some(day_num) = extract_day_number(lines[header_index]) {
-        goals.day_number = Some(day_num);
-    }
+    goals.day_number = lines[..start]
+        .last()
+        .and_then(|header| extract_day_number(header))
+        .or(frontmatter.day);
 
     // Parse from header onwards, tracking line numbers for better errors
     let mut skip_next = false;
-    for (line_num, line) in lines.iter().enumerate().skip(header_index + 1) {
+    for (line_num, line) in lines.iter().enumerate().skip(start) {
         let line = line.trim();
 
         // Skip if this line was already processed as objective metadata
@@ -61,19 +87,20 @@ pub fn parse_markdown(content: &str) -> Result<DailyGoals> {
         }
 
         // Check for outcome headers (case-insensitive)
-        let line_lower = line.to_lowercase();
-        if line_lower.starts_with("## work") {
-            goals.work.goal = extract_goal_from_header(line);
-            current_outcome = Some(&mut goals.work);
-            action_index = 0;
-        } else if line_lower.starts_with("## health") {
-            goals.health.goal = extract_goal_from_header(line);
-            current_outcome = Some(&mut goals.health);
-            action_index = 0;
-        } else if line_lower.starts_with("## family") {
-            goals.family.goal = extract_goal_from_header(line);
-            current_outcome = Some(&mut goals.family);
+        if let Some(outcome_type) = outcome_header(line) {
+            let outcome = goals.outcome_mut(outcome_type);
+            outcome.goal = extract_goal_from_header(line);
+            current_outcome = Some(outcome);
             action_index = 0;
+        } else if is_heading(line) {
+            // Any other section of a note ends the outcome's
+            current_outcome = None;
+        } else if line.starts_with("- [") && current_outcome.is_none() {
+            if let Some((outcome_type, text)) = crate::obsidian::outcome_tag(line) {
+                let (completed, text) = parse_action_line(&text)
+                    .with_context(|| format!("Failed to parse action on line {}", line_num + 1))?;
+                tagged.push((outcome_type, Action::from_markdown(text, completed)));
+            }
         } else if line.starts_with("- [") {
             // Parse action
             if let Some(outcome) = current_outcome.as_mut() {
@@ -86,12 +113,19 @@ pub fn parse_markdown(content: &str) -> Result<DailyGoals> {
                     // Create the action
                     let mut action = Action::from_markdown(text, completed);
 
-                    // Check if next lines have objective metadata
+                    // Check if next lines continue the text or have objective metadata
                     let mut check_line = line_num + 1;
+                    let mut in_text = true;
                     while check_line < lines.len() {
-                        let next_line = lines[check_line].trim();
+                        let raw_line = lines[check_line];
+                        let next_line = raw_line.trim();
 
-                        if next_line.starts_with("objective:")
+                        if in_text && is_continuation_line(raw_line) {
+                            // Long text saved past the limit is wrapped
+                            action.text.push(' ');
+                            action.text.push_str(next_line);
+                            check_line += 1;
+                        } else if next_line.starts_with("objective:")
                             || next_line.starts_with("objectives:")
                         {
                             // Extract objective reference(s)
@@ -104,16 +138,29 @@ pub fn parse_markdown(content: &str) -> Result<DailyGoals> {
                             if let Some(obj_refs) = next_line.strip_prefix(obj_prefix) {
                                 // Split by comma for multiple objectives
                                 for obj_ref in obj_refs.split(',') {
-                                    let obj_id = obj_ref.trim().to_string();
+                                    let obj_id = frontmatter.objective_id(obj_ref.trim());
                                     if !obj_id.is_empty() {
                                         action.add_objective_id(obj_id);
                                     }
                                 }
                                 skip_next = true; // Skip the objective line in next iteration
                             }
+                            in_text = false;
+                            check_line += 1;
+                        } else if let Some(link) = next_line.strip_prefix("indicator:") {
+                            // "indicator: ID" asks for a value, "indicator: ID = 5" logs 5
+                            let (id, value) = match link.split_once('=') {
+                                Some((id, value)) => (id, value.trim().parse::<f64>().ok()),
+                                None => (link, None),
+                            };
+                            if !id.trim().is_empty() {
+                                action.link_indicator(id.trim(), value);
+                            }
+                            in_text = false;
                             check_line += 1;
                         } else if next_line.is_empty() {
                             // Keep checking if it's an empty line
+                            in_text = false;
                             check_line += 1;
                         } else {
                             // Stop if we hit a non-objective, non-empty line
@@ -142,9 +189,69 @@ pub fn parse_markdown(content: &str) -> Result<DailyGoals> {
         }
     }
 
+    for (outcome_type, action) in tagged {
+        let outcome = goals.outcome_mut(outcome_type);
+        // After the actions of the outcome's section, if it has one
+        let parsed = outcome.actions.iter().rposition(|a| !a.text.is_empty());
+        let index = parsed.map_or(0, |i| i + 1);
+        if index < outcome.actions.len() {
+            outcome.actions[index] = action;
+        } else if outcome.actions.len() < 5 {
+            outcome.actions.push(action);
+        }
+    }
+
     Ok(goals)
 }
 
+/// The outcome a `## Work`, `## Health` or `## Family` header starts
+pub(crate) fn outcome_header(line: &str) -> Option<OutcomeType> {
+    let line = line.trim().to_lowercase();
+    [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family]
+        .into_iter()
+        .find(|outcome| line.starts_with(&format!("## {}", outcome.as_str().to_lowercase())))
+}
+
+/// A markdown heading of any level; `#tag` lines are not headings
+pub(crate) fn is_heading(line: &str) -> bool {
+    let hashes = line.trim_start().trim_start_matches('#');
+    hashes.len() < line.trim_start().len() && (hashes.is_empty() || hashes.starts_with(' '))
+}
+
+/// An indented line under an action that carries on its text rather than
+/// starting a list item or objective metadata
+fn is_continuation_line(raw_line: &str) -> bool {
+    let line = raw_line.trim();
+    raw_line.starts_with([' ', '\t']) && !line.is_empty() && !starts_markup(line)
+}
+
+/// Text that would be read back as something other than action text when
+/// it begins a line
+fn starts_markup(text: &str) -> bool {
+    text.starts_with(['-', '#', '*'])
+        || text.starts_with("objective")
+        || text.starts_with("indicator:")
+}
+
+/// Split `text` into lines of at most `width` characters at spaces. A word
+/// that would begin a line as markup stays on the previous line.
+fn wrap_words(text: &str, width: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for word in text.split_whitespace() {
+        match lines.last_mut() {
+            Some(line)
+                if line.chars().count() + 1 + word.chars().count() <= width
+                    || starts_markup(word) =>
+            {
+                line.push(' ');
+                line.push_str(word);
+            }
+            _ => lines.push(word.to_string()),
+        }
+    }
+    lines
+}
+
 /// Find the date header in the first few lines of the file
 fn find_date_header(lines: &[&str]) -> Result<(usize, NaiveDate)> {
     // Search first 10 lines for a valid date header
@@ -158,7 +265,7 @@ fn find_date_header(lines: &[&str]) -> Result<(usize, NaiveDate)> {
 }
 
 /// Parse the date from the header line
-fn parse_date_header(header: &str) -> Result<NaiveDate> {
+pub(crate) fn parse_date_header(header: &str) -> Result<NaiveDate> {
     // Pattern: # Month DD, YYYY - Day N
     let re = Regex::new(r"#\s*(\w+)\s+(\d{1,2}),\s*(\d{4})")?;
 
@@ -228,6 +335,19 @@ pub fn read_goals_file(path: &Path) -> Result<DailyGoals> {
     parse_markdown(&content)
 }
 
+/// Read the goals file of `date`. In Obsidian mode a note with no date in
+/// it is that day's, and objectives linked by title are resolved.
+pub fn read_goals_for(date: NaiveDate, config: &Config) -> Result<DailyGoals> {
+    let path = goals_file_path(date, config);
+    if !crate::obsidian::enabled(config) {
+        return read_goals_file(&path);
+    }
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read file: {}", path.display()))?;
+    let goals = parse_daily_note(&content, Some(date))?;
+    Ok(crate::obsidian::resolve_links(goals, config))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -307,6 +427,15 @@ mod tests {
 
 /// Generate markdown content from DailyGoals
 pub fn generate_markdown(goals: &DailyGoals) -> String {
+    generate_markdown_with(goals, &|id| id.to_string())
+}
+
+/// Generate markdown, writing each objective reference as `objective_ref`
+/// gives it
+pub(crate) fn generate_markdown_with(
+    goals: &DailyGoals,
+    objective_ref: &dyn Fn(&str) -> String,
+) -> String {
     let mut content = String::new();
 
     // Header with date and optional day number
@@ -319,7 +448,7 @@ pub fn generate_markdown(goals: &DailyGoals) -> String {
 
     // Generate each outcome section
     for outcome in goals.outcomes() {
-        generate_outcome_section(&mut content, outcome);
+        generate_outcome_section(&mut content, outcome, objective_ref);
         content.push('\n');
     }
 
@@ -327,7 +456,11 @@ pub fn generate_markdown(goals: &DailyGoals) -> String {
 }
 
 /// Generate markdown for a single outcome section
-fn generate_outcome_section(content: &mut String, outcome: &Outcome) {
+fn generate_outcome_section(
+    content: &mut String,
+    outcome: &Outcome,
+    objective_ref: &dyn Fn(&str) -> String,
+) {
     // Header with optional goal
     let header = if let Some(goal) = &outcome.goal {
         format!("## {} (Goal: {})\n", outcome.outcome_type.as_str(), goal)
@@ -339,10 +472,24 @@ fn generate_outcome_section(content: &mut String, outcome: &Outcome) {
     // Actions with optional objective metadata
     for action in &outcome.actions {
         let checkbox = if action.completed { "[x]" } else { "[ ]" };
-        content.push_str(&format!("- {} {}\n", checkbox, action.text));
+        if action.text.chars().count() > MAX_ACTION_LENGTH {
+            // Saved past the soft limit: wrap so the file stays readable
+            let mut lines = wrap_words(&action.text, MARKDOWN_WRAP_WIDTH).into_iter();
+            let first = lines.next().unwrap_or_default();
+            content.push_str(&format!("- {} {}\n", checkbox, first));
+            for line in lines {
+                content.push_str(&format!("  {}\n", line));
+            }
+        } else {
+            content.push_str(&format!("- {} {}\n", checkbox, action.text));
+        }
 
         // Add objective metadata if present
-        let all_objectives = action.get_all_objective_ids();
+        let all_objectives: Vec<String> = action
+            .get_all_objective_ids()
+            .iter()
+            .map(|id| objective_ref(id))
+            .collect();
         if !all_objectives.is_empty() {
             if all_objectives.len() == 1 {
                 // Single objective - use "objective:" for backward compatibility
@@ -352,9 +499,192 @@ fn generate_outcome_section(content: &mut String, outcome: &Outcome) {
                 content.push_str(&format!("  objectives: {}\n", all_objectives.join(", ")));
             }
         }
+        for link in &action.indicator_links {
+            match link.value {
+                Some(value) => {
+                    content.push_str(&format!("  indicator: {} = {}\n", link.indicator_id, value))
+                }
+                None => content.push_str(&format!("  indicator: {}\n", link.indicator_id)),
+            }
+        }
+    }
+}
+
+fn goals_layout_cache() -> &'static Mutex<HashMap<PathBuf, GoalsLayout>> {
+    static CACHE: OnceLock<Mutex<HashMap<PathBuf, GoalsLayout>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// The goals file layout from settings.json; missing or invalid settings
+/// fall back to the flat `YYYY-MM-DD.md` layout. Cached per data root and
+/// refreshed whenever settings are saved.
+pub fn goals_layout(config: &Config) -> GoalsLayout {
+    let key = PathBuf::from(&config.data_root);
+    let mut cache = goals_layout_cache()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    cache
+        .entry(key)
+        .or_insert_with(|| {
+            load_settings(config)
+                .map(|settings| settings.goals_layout)
+                .ok()
+                .filter(|layout| layout.validate().is_ok())
+                .unwrap_or_default()
+        })
+        .clone()
+}
+
+/// Where the goals file for `date` lives under the configured layout
+pub fn goals_file_path(date: NaiveDate, config: &Config) -> PathBuf {
+    let relative = goals_layout(config).relative_path(date);
+    relative
+        .split('/')
+        .fold(PathBuf::from(&config.goals_dir), |path, part| {
+            path.join(part)
+        })
+}
+
+/// Outcome of moving goals files to a new layout
+#[derive(Debug, Default, PartialEq)]
+pub struct LayoutMigration {
+    /// (from, to) for every file moved, or to be moved on a dry run
+    pub moved: Vec<(PathBuf, PathBuf)>,
+    /// Files already where the new layout puts them
+    pub unchanged: usize,
+    /// Markdown files whose names don't match the old or new layout
+    pub unrecognized: Vec<PathBuf>,
+    /// Files left alone because their destination already exists
+    pub conflicts: Vec<PathBuf>,
+}
+
+fn markdown_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in
+        fs::read_dir(dir).with_context(|| format!("Failed to read directory: {}", dir.display()))?
+    {
+        let path = entry?.path();
+        if path.is_dir() {
+            markdown_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "md") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// `path` relative to the goals directory, with `/` separators
+fn relative_goals_path(path: &Path, goals_dir: &Path) -> String {
+    path.strip_prefix(goals_dir)
+        .unwrap_or(path)
+        .components()
+        .map(|part| part.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Dates that have a goals file under the configured layout, oldest first
+pub fn goals_file_dates(config: &Config) -> Result<Vec<NaiveDate>> {
+    let goals_dir = Path::new(&config.goals_dir);
+    let mut files = Vec::new();
+    if goals_dir.exists() {
+        markdown_files(goals_dir, &mut files)?;
+    }
+
+    let layout = goals_layout(config);
+    let mut dates: Vec<NaiveDate> = files
+        .iter()
+        .filter_map(|path| layout.parse_date(&relative_goals_path(path, goals_dir)))
+        .collect();
+    dates.sort();
+    dates.dedup();
+    Ok(dates)
+}
+
+/// Remove directories under (not including) `root` that are left empty
+fn prune_empty_dirs(dir: &Path, root: &Path) {
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                prune_empty_dirs(&path, root);
+            }
+        }
+    }
+    if dir != root {
+        // Fails harmlessly when the directory still has files
+        let _ = fs::remove_dir(dir);
     }
 }
 
+/// Move every goals file to `layout` and record it in settings.json.
+///
+/// Files are recognised by the current layout (or already by the new one);
+/// anything else is reported and left in place. With `dry_run` nothing is
+/// moved or saved.
+pub fn migrate_goals_layout(
+    layout: &GoalsLayout,
+    config: &Config,
+    dry_run: bool,
+) -> Result<LayoutMigration> {
+    layout.validate()?;
+    let current = goals_layout(config);
+    let goals_dir = Path::new(&config.goals_dir);
+    let mut report = LayoutMigration::default();
+
+    let mut files = Vec::new();
+    if goals_dir.exists() {
+        markdown_files(goals_dir, &mut files)?;
+    }
+    files.sort();
+
+    for path in files {
+        let relative = relative_goals_path(&path, goals_dir);
+        let Some(date) = current
+            .parse_date(&relative)
+            .or_else(|| layout.parse_date(&relative))
+        else {
+            report.unrecognized.push(path);
+            continue;
+        };
+
+        let target = layout
+            .relative_path(date)
+            .split('/')
+            .fold(goals_dir.to_path_buf(), |target, part| target.join(part));
+        if target == path {
+            report.unchanged += 1;
+            continue;
+        }
+        if target.exists() {
+            report.conflicts.push(path);
+            continue;
+        }
+
+        if !dry_run {
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent).with_context(|| {
+                    format!("Failed to create goals directory: {}", parent.display())
+                })?;
+            }
+            fs::rename(&path, &target).with_context(|| {
+                format!("Failed to move {} to {}", path.display(), target.display())
+            })?;
+        }
+        report.moved.push((path, target));
+    }
+
+    if !dry_run {
+        let mut settings = load_settings(config)?;
+        settings.goals_layout = layout.clone();
+        save_settings(&settings, config)?;
+        if goals_dir.exists() {
+            prune_empty_dirs(goals_dir, goals_dir);
+        }
+    }
+
+    Ok(report)
+}
+
 /// Calculate the current streak of consecutive days with at least one completed task
 pub fn calculate_streak(config: &Config) -> Result<u32> {
     let goals_dir = Path::new(&config.goals_dir);
@@ -366,11 +696,11 @@ pub fn calculate_streak(config: &Config) -> Result<u32> {
     let mut current_date = Local::now().date_naive();
 
     loop {
-        let file_path = goals_dir.join(format!("{}.md", current_date.format("%Y-%m-%d")));
+        let file_path = goals_file_path(current_date, config);
 
         if file_path.exists() {
             // Try to read and parse the file
-            match read_goals_file(&file_path) {
+            match read_goals_for(current_date, config) {
                 Ok(goals) => {
                     // Check if at least one action is completed
                     let has_completion = goals
@@ -403,26 +733,37 @@ pub fn calculate_streak(config: &Config) -> Result<u32> {
 
 /// Write goals to a file atomically
 pub fn write_goals_file(goals: &DailyGoals, config: &Config) -> Result<PathBuf> {
-    // Ensure goals directory exists
-    let goals_dir = Path::new(&config.goals_dir);
+    // Path from the configured layout, YYYY-MM-DD.md by default
+    let file_path = goals_file_path(goals.date, config);
+
+    // Ensure the goals directory (and any layout subfolders) exists
+    let goals_dir = file_path.parent().unwrap_or(Path::new(&config.goals_dir));
     fs::create_dir_all(goals_dir)
         .with_context(|| format!("Failed to create goals directory: {}", goals_dir.display()))?;
 
-    // Generate filename: YYYY-MM-DD.md
-    let filename = format!("{}.md", goals.date.format("%Y-%m-%d"));
-    let file_path = goals_dir.join(&filename);
-
     // Generate markdown content
-    let content = generate_markdown(goals);
+    let content = match crate::obsidian::enabled(config) {
+        true => crate::obsidian::render_note(goals, fs::read_to_string(&file_path).ok(), config),
+        false => generate_markdown(goals),
+    };
 
-    // Write atomically using temp file + rename
-    atomic_write(&file_path, content.as_bytes())?;
+    // Write atomically, keeping a backup of the version it replaces
+    write_with_backup(&file_path, content.as_bytes(), config)?;
 
     Ok(file_path)
 }
 
+/// Atomically replace `path` with `content`, first backing up the old
+/// contents when they differ
+fn write_with_backup(path: &Path, content: &[u8], config: &Config) -> Result<()> {
+    if fs::read(path).is_ok_and(|old| old != content) {
+        crate::backups::backup_file(path, config)?;
+    }
+    atomic_write(path, content)
+}
+
 /// Atomically write to a file by writing to temp file then renaming
-fn atomic_write(path: &Path, content: &[u8]) -> Result<()> {
+pub(crate) fn atomic_write(path: &Path, content: &[u8]) -> Result<()> {
     // Create unique temp filename with timestamp and process ID
     let timestamp = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -461,16 +802,27 @@ fn atomic_write(path: &Path, content: &[u8]) -> Result<()> {
     Ok(())
 }
 
-/// Load existing goals for a date, or create new ones
+/// Load existing goals for a date, or create new ones. A new day from today
+/// on starts with the weekday templates bound to its weekday.
 pub fn load_or_create_goals(date: NaiveDate, config: &Config) -> Result<DailyGoals> {
-    let goals_dir = Path::new(&config.goals_dir);
-    let filename = format!("{}.md", date.format("%Y-%m-%d"));
-    let file_path = goals_dir.join(filename);
+    let file_path = goals_file_path(date, config);
 
     if file_path.exists() {
-        read_goals_file(&file_path)
+        let mut goals = read_goals_for(date, config)?;
+        // The goals file has no ids; the day's metadata keeps them
+        if let Some(meta) = read_day_meta(date, config) {
+            meta.apply_ids(&mut goals);
+        }
+        Ok(goals)
     } else {
-        Ok(DailyGoals::new(date))
+        let mut goals = DailyGoals::new(date);
+        if date >= chrono::Local::now().date_naive() {
+            // A broken templates file must not keep the day from opening
+            if let Ok(templates) = load_or_create_templates(config) {
+                templates.apply_weekday_templates(&mut goals);
+            }
+        }
+        Ok(goals)
     }
 }
 
@@ -478,17 +830,179 @@ pub fn load_or_create_goals(date: NaiveDate, config: &Config) -> Result<DailyGoa
 pub fn get_yesterday_goals(today: NaiveDate, config: &Config) -> Result<Option<DailyGoals>> {
     let yesterday = today.pred_opt().context("Cannot get yesterday's date")?;
 
-    let goals_dir = Path::new(&config.goals_dir);
-    let filename = format!("{}.md", yesterday.format("%Y-%m-%d"));
-    let file_path = goals_dir.join(filename);
+    let file_path = goals_file_path(yesterday, config);
 
     if file_path.exists() {
-        Ok(Some(read_goals_file(&file_path)?))
+        Ok(Some(read_goals_for(yesterday, config)?))
     } else {
         Ok(None)
     }
 }
 
+/// Load settings.json from the data root; a missing file means defaults
+pub fn load_settings(config: &Config) -> Result<Settings> {
+    let settings_path = Path::new(&config.data_root).join("settings.json");
+    if !settings_path.exists() {
+        return Ok(Settings::default());
+    }
+
+    let content = fs::read_to_string(&settings_path)
+        .with_context(|| format!("Failed to read settings: {}", settings_path.display()))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse settings: {}", settings_path.display()))
+}
+
+/// Save settings.json atomically
+pub fn save_settings(settings: &Settings, config: &Config) -> Result<PathBuf> {
+    fs::create_dir_all(&config.data_root)
+        .with_context(|| format!("Failed to create data root directory: {}", config.data_root))?;
+
+    let settings_path = Path::new(&config.data_root).join("settings.json");
+    let json_content =
+        serde_json::to_string_pretty(settings).context("Failed to serialize settings")?;
+    atomic_write(&settings_path, json_content.as_bytes())?;
+
+    goals_layout_cache()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .remove(Path::new(&config.data_root));
+
+    Ok(settings_path)
+}
+
+/// Load sentiment.json, or an empty history if none has been scored yet
+pub fn load_sentiment_history(config: &Config) -> Result<SentimentHistory> {
+    let path = Path::new(&config.data_root).join("sentiment.json");
+    if !path.exists() {
+        return Ok(SentimentHistory::default());
+    }
+
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read sentiment history: {}", path.display()))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse sentiment history: {}", path.display()))
+}
+
+/// Save sentiment.json atomically
+pub fn save_sentiment_history(history: &SentimentHistory, config: &Config) -> Result<PathBuf> {
+    fs::create_dir_all(&config.data_root)
+        .with_context(|| format!("Failed to create data root directory: {}", config.data_root))?;
+
+    let path = Path::new(&config.data_root).join("sentiment.json");
+    let json_content =
+        serde_json::to_string_pretty(history).context("Failed to serialize sentiment history")?;
+    atomic_write(&path, json_content.as_bytes())?;
+
+    Ok(path)
+}
+
+/// Load stats.json, or an empty cache if it doesn't exist yet
+pub fn load_stats_cache(config: &Config) -> Result<StatsCache> {
+    let path = Path::new(&config.data_root).join("stats.json");
+    if !path.exists() {
+        return Ok(StatsCache::default());
+    }
+
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read stats cache: {}", path.display()))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse stats cache: {}", path.display()))
+}
+
+/// Save stats.json atomically
+pub fn save_stats_cache(cache: &StatsCache, config: &Config) -> Result<PathBuf> {
+    fs::create_dir_all(&config.data_root)
+        .with_context(|| format!("Failed to create data root directory: {}", config.data_root))?;
+
+    let path = Path::new(&config.data_root).join("stats.json");
+    let json_content =
+        serde_json::to_string_pretty(cache).context("Failed to serialize stats cache")?;
+    atomic_write(&path, json_content.as_bytes())?;
+
+    Ok(path)
+}
+
+/// Load profile.json, or an empty profile if nothing has been scored yet
+pub fn load_profile(config: &Config) -> Result<Profile> {
+    let path = Path::new(&config.data_root).join("profile.json");
+    if !path.exists() {
+        return Ok(Profile::default());
+    }
+
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read profile: {}", path.display()))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse profile: {}", path.display()))
+}
+
+/// Save profile.json atomically
+pub fn save_profile(profile: &Profile, config: &Config) -> Result<PathBuf> {
+    fs::create_dir_all(&config.data_root)
+        .with_context(|| format!("Failed to create data root directory: {}", config.data_root))?;
+
+    let path = Path::new(&config.data_root).join("profile.json");
+    let json_content =
+        serde_json::to_string_pretty(profile).context("Failed to serialize profile")?;
+    atomic_write(&path, json_content.as_bytes())?;
+
+    Ok(path)
+}
+
+/// Path of the evening reflection for `date`: data_root/reflections/YYYY-MM-DD.md
+pub fn reflection_path(date: NaiveDate, config: &Config) -> PathBuf {
+    Path::new(&config.data_root)
+        .join("reflections")
+        .join(format!("{}.md", date.format("%Y-%m-%d")))
+}
+
+/// Read the evening reflection for `date`, if one has been saved
+pub fn load_reflection(date: NaiveDate, config: &Config) -> Result<Option<String>> {
+    let path = reflection_path(date, config);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read reflection: {}", path.display()))?;
+    Ok(Some(content.trim_end().to_string()))
+}
+
+/// Dates with a saved evening reflection, oldest first
+pub fn reflection_dates(config: &Config) -> Result<Vec<NaiveDate>> {
+    let dir = Path::new(&config.data_root).join("reflections");
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut dates = Vec::new();
+    for entry in fs::read_dir(&dir)
+        .with_context(|| format!("Failed to read directory: {}", dir.display()))?
+    {
+        let path = entry?.path();
+        let date = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_suffix(".md"))
+            .and_then(|stem| NaiveDate::parse_from_str(stem, "%Y-%m-%d").ok());
+        dates.extend(date);
+    }
+    dates.sort();
+    Ok(dates)
+}
+
+/// Save the evening reflection for `date` atomically
+pub fn save_reflection(date: NaiveDate, text: &str, config: &Config) -> Result<PathBuf> {
+    let path = reflection_path(date, config);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| {
+            format!("Failed to create reflections directory: {}", dir.display())
+        })?;
+    }
+
+    atomic_write(&path, format!("{}\n", text.trim_end()).as_bytes())?;
+    Ok(path)
+}
+
 /// Load or create the 5-year vision file
 pub fn load_or_create_vision(config: &Config) -> Result<FiveYearVision> {
     let vision_path = Path::new(&config.goals_dir)
@@ -505,8 +1019,11 @@ pub fn load_or_create_vision(config: &Config) -> Result<FiveYearVision> {
     }
 }
 
-/// Save the 5-year vision to file
+/// Save the 5-year vision to file, keeping a dated version when the text
+/// changed
 pub fn save_vision(vision: &FiveYearVision, config: &Config) -> Result<()> {
+    record_vision_version(vision, config)?;
+
     let vision_dir = Path::new(&config.goals_dir)
         .parent()
         .unwrap_or(Path::new(&config.goals_dir));
@@ -529,6 +1046,96 @@ pub fn save_vision(vision: &FiveYearVision, config: &Config) -> Result<()> {
     Ok(())
 }
 
+fn vision_history_dir(config: &Config) -> PathBuf {
+    Path::new(&config.goals_dir)
+        .parent()
+        .unwrap_or(Path::new(&config.goals_dir))
+        .join("vision_history")
+}
+
+const VISION_VERSION_FORMAT: &str = "%Y-%m-%dT%H%M%S%.3f";
+
+/// Saved versions of the vision, oldest first
+pub fn load_vision_history(config: &Config) -> Result<Vec<VisionVersion>> {
+    let dir = vision_history_dir(config);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut versions = Vec::new();
+    for entry in fs::read_dir(&dir)
+        .with_context(|| format!("Failed to read vision history: {}", dir.display()))?
+    {
+        let path = entry?.path();
+        let Some(saved) = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(|stem| {
+                chrono::NaiveDateTime::parse_from_str(stem, VISION_VERSION_FORMAT).ok()
+            })
+        else {
+            continue;
+        };
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read vision version: {}", path.display()))?;
+        let vision = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse vision version: {}", path.display()))?;
+        versions.push(VisionVersion { saved, vision });
+    }
+    versions.sort_by_key(|version| version.saved);
+    Ok(versions)
+}
+
+fn write_vision_version(version: &VisionVersion, config: &Config) -> Result<()> {
+    let dir = vision_history_dir(config);
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create vision history: {}", dir.display()))?;
+    let path = dir.join(format!(
+        "{}.json",
+        version.saved.format(VISION_VERSION_FORMAT)
+    ));
+    let json =
+        serde_json::to_string_pretty(&version.vision).context("Failed to serialize vision")?;
+    atomic_write(&path, json.as_bytes())
+}
+
+/// Keep `vision` as a new version unless its text matches the latest one.
+/// The first time, the vision already on disk is kept too, dated by its
+/// last change.
+fn record_vision_version(vision: &FiveYearVision, config: &Config) -> Result<()> {
+    let history = load_vision_history(config)?;
+    match history.last() {
+        Some(latest) if latest.vision.same_text(vision) => return Ok(()),
+        Some(_) => {}
+        None => {
+            let existing = load_or_create_vision(config)?;
+            let has_text = [&existing.work, &existing.health, &existing.family]
+                .iter()
+                .any(|text| !text.trim().is_empty());
+            if has_text && !existing.same_text(vision) {
+                write_vision_version(
+                    &VisionVersion {
+                        saved: existing.modified.and_time(chrono::NaiveTime::MIN),
+                        vision: existing,
+                    },
+                    config,
+                )?;
+            }
+        }
+    }
+    // Versions stay in save order even when the clock is coarse
+    let mut saved = Local::now().naive_local();
+    if let Some(latest) = history.last() {
+        saved = saved.max(latest.saved + chrono::Duration::milliseconds(1));
+    }
+    write_vision_version(
+        &VisionVersion {
+            saved,
+            vision: vision.clone(),
+        },
+        config,
+    )
+}
+
 /// Load or create the action templates file
 pub fn load_or_create_templates(config: &Config) -> Result<ActionTemplates> {
     let templates_path = Path::new(&config.goals_dir)
@@ -568,7 +1175,7 @@ pub fn save_templates(templates: &ActionTemplates, config: &Config) -> Result<()
         serde_json::to_string_pretty(templates).with_context(|| "Failed to serialize templates")?;
 
     // Use atomic write for safety
-    atomic_write(&templates_path, json_content.as_bytes())?;
+    write_with_backup(&templates_path, json_content.as_bytes(), config)?;
 
     Ok(())
 }
@@ -613,6 +1220,101 @@ pub fn load_or_create_day_meta(
     }
 }
 
+/// The metadata of `date` if it has been saved and can be read
+pub(crate) fn read_day_meta(date: NaiveDate, config: &Config) -> Option<DayMeta> {
+    let meta_path = Path::new(&config.data_root)
+        .join("meta")
+        .join(format!("{}.meta.json", date.format("%Y-%m-%d")));
+    let content = fs::read_to_string(meta_path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// The note attached to `date`, if its metadata has one
+pub fn load_day_note(date: NaiveDate, config: &Config) -> Result<Option<String>> {
+    let meta_path = Path::new(&config.data_root)
+        .join("meta")
+        .join(format!("{}.meta.json", date.format("%Y-%m-%d")));
+    if !meta_path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&meta_path)
+        .with_context(|| format!("Failed to read meta file: {}", meta_path.display()))?;
+    let meta: DayMeta = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse meta file: {}", meta_path.display()))?;
+    Ok(meta.note)
+}
+
+/// Attach a note to the day of `goals`; a blank note removes it
+pub fn save_day_note(goals: &DailyGoals, note: &str, config: &Config) -> Result<Option<String>> {
+    let mut meta = load_or_create_day_meta(goals.date, goals, config)?;
+    let note = note.trim();
+    meta.note = (!note.is_empty()).then(|| note.to_string());
+    meta.modified = chrono::Utc::now();
+    save_day_meta(goals.date, &meta, config)?;
+    Ok(meta.note)
+}
+
+/// Minutes spent on each objective during the Monday-to-Sunday week that
+/// contains `date`, summed from `actual_min` of the actions linked to it
+pub fn objective_minutes_for_week(
+    date: NaiveDate,
+    config: &Config,
+) -> Result<HashMap<String, u32>> {
+    let week_start = date - chrono::Duration::days(date.weekday().num_days_from_monday() as i64);
+    let mut totals: HashMap<String, u32> = HashMap::new();
+
+    for day in week_start.iter_days().take(7) {
+        let goals_path = goals_file_path(day, config);
+        let meta_path = Path::new(&config.data_root)
+            .join("meta")
+            .join(format!("{}.meta.json", day.format("%Y-%m-%d")));
+        if !goals_path.exists() || !meta_path.exists() {
+            continue;
+        }
+
+        let goals = read_goals_for(day, config)?;
+        let meta = load_or_create_day_meta(day, &goals, config)?;
+        let outcomes = [
+            (&goals.work, &meta.work),
+            (&goals.health, &meta.health),
+            (&goals.family, &meta.family),
+        ];
+        for (outcome, metas) in outcomes {
+            for (action, action_meta) in outcome.actions.iter().zip(metas) {
+                let Some(minutes) = action_meta.actual_min else {
+                    continue;
+                };
+                for objective_id in action.get_all_objective_ids() {
+                    *totals.entry(objective_id).or_default() += minutes;
+                }
+            }
+        }
+    }
+
+    Ok(totals)
+}
+
+/// Record in the day's metadata when its actions were checked off. Days
+/// with nothing done and no metadata yet are left without it.
+pub fn record_completion_times(goals: &DailyGoals, config: &Config) -> Result<()> {
+    let meta_path = Path::new(&config.data_root)
+        .join("meta")
+        .join(format!("{}.meta.json", goals.date.format("%Y-%m-%d")));
+    let any_done = goals
+        .outcomes()
+        .iter()
+        .any(|outcome| outcome.actions.iter().any(|a| a.completed));
+    if !any_done && !meta_path.exists() {
+        return Ok(());
+    }
+    let mut meta = load_or_create_day_meta(goals.date, goals, config)?;
+    if meta.stamp_completions(goals, chrono::Utc::now()) {
+        meta.modified = chrono::Utc::now();
+        save_day_meta(goals.date, &meta, config)?;
+    }
+    Ok(())
+}
+
 /// Save day metadata atomically
 pub fn save_day_meta(date: NaiveDate, meta: &DayMeta, config: &Config) -> Result<PathBuf> {
     let meta_dir = ensure_meta_dir(config)?;
@@ -629,6 +1331,97 @@ pub fn save_day_meta(date: NaiveDate, meta: &DayMeta, config: &Config) -> Result
     Ok(meta_path)
 }
 
+/// Follow an action moved from `from` to `to` in the day's metadata, which
+/// is matched to actions by position. Days without metadata are left alone.
+pub fn move_day_meta_action(
+    date: NaiveDate,
+    outcome_type: OutcomeType,
+    from: usize,
+    to: usize,
+    config: &Config,
+) -> Result<()> {
+    let meta_path = Path::new(&config.data_root)
+        .join("meta")
+        .join(format!("{}.meta.json", date.format("%Y-%m-%d")));
+    if !meta_path.exists() {
+        return Ok(());
+    }
+
+    let content = fs::read_to_string(&meta_path)
+        .with_context(|| format!("Failed to read meta file: {}", meta_path.display()))?;
+    let mut meta: DayMeta = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse meta file: {}", meta_path.display()))?;
+    let actions = meta.outcome_mut(outcome_type);
+    if from < actions.len() && to < actions.len() {
+        let moved = actions.remove(from);
+        actions.insert(to, moved);
+        meta.modified = chrono::Utc::now();
+        save_day_meta(date, &meta, config)?;
+    }
+    Ok(())
+}
+
+/// Copy a day's plan to another date. Action text, goals, objective links,
+/// tags and estimates carry over; completion and reflections do not.
+/// Refuses to overwrite a target day that already has actions written.
+pub fn clone_day_plan(
+    source: &DailyGoals,
+    target: NaiveDate,
+    config: &Config,
+) -> Result<DailyGoals> {
+    let existing = load_or_create_goals(target, config)?;
+    let has_plan = existing
+        .outcomes()
+        .iter()
+        .any(|outcome| outcome.actions.iter().any(|a| !a.text.trim().is_empty()));
+    if has_plan {
+        anyhow::bail!("{} already has a plan", target.format("%Y-%m-%d"));
+    }
+
+    let source_meta = load_or_create_day_meta(source.date, source, config)?;
+
+    let mut cloned = DailyGoals::new(target);
+    let offset = (target - source.date).num_days();
+    cloned.day_number = source
+        .day_number
+        .and_then(|day| u32::try_from(i64::from(day) + offset).ok());
+
+    for (from, to) in source.outcomes().into_iter().zip(cloned.outcomes_mut()) {
+        to.goal = from.goal.clone();
+        to.actions = from
+            .actions
+            .iter()
+            .map(|action| {
+                let mut copy = Action::new(action.text.clone());
+                copy.origin = ActionOrigin::Template;
+                copy.objective_id = action.objective_id.clone();
+                copy.objective_ids = action.objective_ids.clone();
+                copy.indicator_links = action.indicator_links.clone();
+                copy
+            })
+            .collect();
+    }
+
+    let mut meta = DayMeta::from_goals(&cloned);
+    for (from, to) in [
+        (&source_meta.work, &mut meta.work),
+        (&source_meta.health, &mut meta.health),
+        (&source_meta.family, &mut meta.family),
+    ] {
+        for (source_action, action) in from.iter().zip(to.iter_mut()) {
+            action.origin = ActionOrigin::Template;
+            action.estimated_min = source_action.estimated_min;
+            action.priority = source_action.priority;
+            action.tags = source_action.tags.clone();
+            action.objective_id = source_action.objective_id.clone();
+        }
+    }
+
+    write_goals_file(&cloned, config)?;
+    save_day_meta(target, &meta, config)?;
+    Ok(cloned)
+}
+
 /// Load or create objectives from JSON file
 pub fn load_or_create_objectives(config: &Config) -> Result<ObjectivesData> {
     let objectives_path = Path::new(&config.data_root).join("objectives.json");
@@ -669,11 +1462,55 @@ pub fn save_objectives(objectives: &ObjectivesData, config: &Config) -> Result<P
         serde_json::to_string_pretty(objectives).context("Failed to serialize objectives")?;
 
     // Write atomically
-    atomic_write(&objectives_path, json_content.as_bytes())?;
+    write_with_backup(&objectives_path, json_content.as_bytes(), config)?;
 
     Ok(objectives_path)
 }
 
+/// Load projects.json; empty when no project was created yet
+pub fn load_or_create_projects(config: &Config) -> Result<ProjectsData> {
+    let path = Path::new(&config.data_root).join("projects.json");
+    if !path.exists() {
+        return Ok(ProjectsData::default());
+    }
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read projects file: {}", path.display()))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse projects file: {}", path.display()))
+}
+
+/// Save projects to JSON file atomically
+pub fn save_projects(projects: &ProjectsData, config: &Config) -> Result<PathBuf> {
+    fs::create_dir_all(&config.data_root)
+        .with_context(|| format!("Failed to create data root directory: {}", config.data_root))?;
+    let path = Path::new(&config.data_root).join("projects.json");
+    let json = serde_json::to_string_pretty(projects).context("Failed to serialize projects")?;
+    write_with_backup(&path, json.as_bytes(), config)?;
+    Ok(path)
+}
+
+/// Load trash.json; empty when nothing was deleted yet
+pub fn load_or_create_trash(config: &Config) -> Result<TrashData> {
+    let path = Path::new(&config.data_root).join("trash.json");
+    if !path.exists() {
+        return Ok(TrashData::default());
+    }
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read trash file: {}", path.display()))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse trash file: {}", path.display()))
+}
+
+/// Save trash.json atomically
+pub fn save_trash(trash: &TrashData, config: &Config) -> Result<PathBuf> {
+    fs::create_dir_all(&config.data_root)
+        .with_context(|| format!("Failed to create data root directory: {}", config.data_root))?;
+    let path = Path::new(&config.data_root).join("trash.json");
+    let json = serde_json::to_string_pretty(trash).context("Failed to serialize trash")?;
+    write_with_backup(&path, json.as_bytes(), config)?;
+    Ok(path)
+}
+
 /// Load or create indicators from JSON file
 pub fn load_or_create_indicators(config: &Config) -> Result<IndicatorsData> {
     let indicators_path = Path::new(&config.data_root).join("indicators.json");
@@ -714,7 +1551,7 @@ pub fn save_indicators(indicators: &IndicatorsData, config: &Config) -> Result<P
         serde_json::to_string_pretty(indicators).context("Failed to serialize indicators")?;
 
     // Write atomically
-    atomic_write(&indicators_path, json_content.as_bytes())?;
+    write_with_backup(&indicators_path, json_content.as_bytes(), config)?;
 
     Ok(indicators_path)
 }
@@ -728,7 +1565,7 @@ pub fn append_observation(obs: &Observation, config: &Config) -> Result<()> {
     fs::create_dir_all(&config.data_root)
         .with_context(|| format!("Failed to create data root directory: {}", config.data_root))?;
 
-    let observations_path = Path::new(&config.data_root).join("observations.ndjson");
+    let observations_path = observations_path(config);
 
     // Serialize observation to JSON (single line)
     let json_line = serde_json::to_string(obs).context("Failed to serialize observation")?;
@@ -754,19 +1591,266 @@ pub fn append_observation(obs: &Observation, config: &Config) -> Result<()> {
     Ok(())
 }
 
-/// Read observations within a date range (streaming, not loading entire file)
-pub fn read_observations_range(
-    start: NaiveDate,
-    end: NaiveDate,
+/// Bring Percent indicators derived from two counters up to date after
+/// `changed` was appended: each dependent gets a fresh observation for that
+/// day. Returns the observations written.
+pub fn sync_ratio_indicators(
+    changed: &Observation,
+    indicators: &IndicatorsData,
     config: &Config,
 ) -> Result<Vec<Observation>> {
-    use std::io::{BufRead, BufReader};
+    let dependents: Vec<_> = indicators
+        .indicators
+        .iter()
+        .filter(|def| {
+            def.ratio_of
+                .as_ref()
+                .is_some_and(|source| source.involves(&changed.indicator_id))
+        })
+        .collect();
+    if dependents.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let history = read_observations_range(NaiveDate::MIN, changed.when, config)?;
+    let mut derived = Vec::new();
+    for def in dependents {
+        let Some(percent) = def.ratio_percent(&history, changed.when) else {
+            continue;
+        };
+        let mut obs = Observation::new(def.id.clone(), changed.when, percent, def.unit.clone());
+        obs.source = ObservationSource::Automated;
+        append_observation(&obs, config)
+            .with_context(|| format!("Failed to record ratio indicator {}", def.name))?;
+        derived.push(obs);
+    }
+    Ok(derived)
+}
+
+/// Version of the observations index sidecar format
+const OBSERVATION_INDEX_VERSION: u32 = 1;
+
+/// Date-sorted index of line offsets into the observations log.
+///
+/// The log is append-only, so the index is extended incrementally from
+/// `indexed_len` instead of being rebuilt on every read. It is also kept in
+/// a sidecar file (`observations.idx`) so a new process can seek straight to
+/// a date range without scanning the whole log first.
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize)]
+struct ObservationIndex {
+    version: u32,
+    indexed_len: usize,
+    /// (date, byte offset, byte length), sorted by date then offset
+    entries: Vec<(NaiveDate, usize, usize)>,
+}
+
+fn observation_index_cache() -> &'static Mutex<HashMap<PathBuf, ObservationIndex>> {
+    static CACHE: OnceLock<Mutex<HashMap<PathBuf, ObservationIndex>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// The observations log, one JSON record per line
+pub fn observations_path(config: &Config) -> PathBuf {
+    Path::new(&config.data_root).join("observations.ndjson")
+}
+
+/// Replace the observations log with `lines`, one JSON record each, and
+/// rebuild its date index
+pub fn replace_observations(lines: &[String], config: &Config) -> Result<()> {
+    let mut content = lines.join("\n");
+    if !content.is_empty() {
+        content.push('\n');
+    }
+    atomic_write(&observations_path(config), content.as_bytes())?;
+    rebuild_observation_index(config)?;
+    Ok(())
+}
+
+/// Sidecar holding the date index of the observations log
+pub fn observation_index_path(config: &Config) -> PathBuf {
+    Path::new(&config.data_root).join("observations.idx")
+}
+
+/// Pull the `when` date out of an observation line without a full parse.
+fn observation_line_date(line: &str) -> Option<NaiveDate> {
+    const KEY: &str = "\"when\":\"";
+    let fast = line.find(KEY).and_then(|pos| {
+        let start = pos + KEY.len();
+        let date = line.get(start..start + 10)?;
+        NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+    });
+
+    // Hand-edited lines may use different spacing; fall back to serde
+    fast.or_else(|| {
+        #[derive(serde::Deserialize)]
+        struct WhenOnly {
+            when: NaiveDate,
+        }
+        serde_json::from_str::<WhenOnly>(line).ok().map(|w| w.when)
+    })
+}
 
-    let observations_path = Path::new(&config.data_root).join("observations.ndjson");
+impl ObservationIndex {
+    /// Index entries for complete lines past `indexed_len`; returns whether
+    /// any were added
+    fn extend(&mut self, bytes: &[u8]) -> Result<bool> {
+        let mut offset = self.indexed_len;
+        let first_new = self.entries.len();
+
+        // Only index complete lines; a partially written tail is picked up next time
+        while let Some(newline) = bytes[offset..].iter().position(|&b| b == b'\n') {
+            let line_bytes = &bytes[offset..offset + newline];
+            let line = std::str::from_utf8(line_bytes)
+                .context("Observations file contains invalid UTF-8")?;
+
+            if !line.trim().is_empty() {
+                let date = observation_line_date(line)
+                    .with_context(|| format!("Failed to parse observation: {}", line))?;
+                self.entries.push((date, offset, line_bytes.len()));
+            }
 
-    // Return empty vec if file doesn't exist
+            offset += newline + 1;
+        }
+
+        self.indexed_len = offset;
+        let appended = self.entries.len() > first_new;
+        // Observations are usually logged in date order, so a re-sort is rare
+        if appended
+            && !self.entries[first_new.saturating_sub(1)..]
+                .is_sorted_by_key(|&(date, offset, _)| (date, offset))
+        {
+            self.entries
+                .sort_by_key(|&(date, offset, _)| (date, offset));
+        }
+        Ok(appended)
+    }
+
+    /// Load the sidecar index, if it still matches the log in `bytes`
+    fn load_sidecar(path: &Path, bytes: &[u8]) -> Option<Self> {
+        let content = fs::read(path).ok()?;
+        let index: ObservationIndex = serde_json::from_slice(&content).ok()?;
+        index.matches(bytes).then_some(index)
+    }
+
+    /// Whether the log still looks like the one this index was built from:
+    /// it ends a line where indexing stopped, and the last indexed line still
+    /// carries its recorded date
+    fn matches(&self, bytes: &[u8]) -> bool {
+        if self.version != OBSERVATION_INDEX_VERSION || self.indexed_len > bytes.len() {
+            return false;
+        }
+        if self.indexed_len > 0 && bytes[self.indexed_len - 1] != b'\n' {
+            return false;
+        }
+        let Some(&(date, offset, len)) = self.entries.iter().max_by_key(|entry| entry.1) else {
+            return true;
+        };
+        bytes
+            .get(offset..offset + len)
+            .and_then(|line| std::str::from_utf8(line).ok())
+            .and_then(observation_line_date)
+            == Some(date)
+    }
+
+    fn save_sidecar(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_vec(self).context("Failed to serialize observations index")?;
+        atomic_write(path, &json)
+    }
+
+    /// Byte ranges of lines dated within `start..=end`, in date order
+    fn range(&self, start: NaiveDate, end: NaiveDate) -> Vec<(usize, usize)> {
+        let lo = self.entries.partition_point(|&(date, _, _)| date < start);
+        let hi = self.entries.partition_point(|&(date, _, _)| date <= end);
+        if lo >= hi {
+            return Vec::new();
+        }
+
+        self.entries[lo..hi]
+            .iter()
+            .map(|&(_, offset, len)| (offset, len))
+            .collect()
+    }
+}
+
+fn parse_observation_line(bytes: &[u8]) -> Result<Observation> {
+    let line = std::str::from_utf8(bytes).context("Observations file contains invalid UTF-8")?;
+    serde_json::from_str(line).with_context(|| format!("Failed to parse observation: {}", line))
+}
+
+/// Streams observations for a date range out of the memory-mapped log,
+/// parsing each record only when it is reached. Skipping ahead with `nth`
+/// (or `skip`) doesn't parse the skipped records, so pages are cheap.
+pub struct ObservationIter {
+    mmap: Option<memmap2::Mmap>,
+    spans: std::vec::IntoIter<(usize, usize)>,
+    /// A final line without a trailing newline, which isn't indexed yet
+    tail: Option<Observation>,
+}
+
+impl ObservationIter {
+    fn empty() -> Self {
+        ObservationIter {
+            mmap: None,
+            spans: Vec::new().into_iter(),
+            tail: None,
+        }
+    }
+
+    fn parse(&self, (offset, len): (usize, usize)) -> Result<Observation> {
+        let bytes = self.mmap.as_deref().unwrap_or_default();
+        let line = bytes
+            .get(offset..offset + len)
+            .context("Observations index points past the end of the log")?;
+        parse_observation_line(line)
+    }
+}
+
+impl Iterator for ObservationIter {
+    type Item = Result<Observation>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.spans.next() {
+            Some(span) => Some(self.parse(span)),
+            None => self.tail.take().map(Ok),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let indexed = self.spans.len();
+        if n < indexed {
+            let span = self.spans.nth(n)?;
+            return Some(self.parse(span));
+        }
+        self.spans = Vec::new().into_iter();
+        let tail = self.tail.take();
+        if n == indexed {
+            tail.map(Ok)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.spans.len() + usize::from(self.tail.is_some());
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for ObservationIter {}
+
+/// Map the observations log and look up the lines dated within
+/// `start..=end`, in date order (or file order with `file_order`).
+fn open_observations(
+    start: NaiveDate,
+    end: NaiveDate,
+    config: &Config,
+    file_order: bool,
+) -> Result<ObservationIter> {
+    let observations_path = observations_path(config);
+
+    // No log yet means no observations
     if !observations_path.exists() {
-        return Ok(Vec::new());
+        return Ok(ObservationIter::empty());
     }
 
     let file = fs::File::open(&observations_path).with_context(|| {
@@ -776,29 +1860,221 @@ pub fn read_observations_range(
         )
     })?;
 
-    let reader = BufReader::new(file);
-    let mut observations = Vec::new();
-
-    // Stream line by line
-    for line in reader.lines() {
-        let line = line.context("Failed to read line from observations file")?;
+    let file_len = file
+        .metadata()
+        .context("Failed to read observations file metadata")?
+        .len() as usize;
+    if file_len == 0 || start > end {
+        return Ok(ObservationIter::empty());
+    }
 
-        // Skip empty lines
-        if line.trim().is_empty() {
-            continue;
+    // SAFETY: the observations log is only ever appended to, so mapped bytes
+    // are never truncated underneath us while the map is alive.
+    let mmap = unsafe { memmap2::Mmap::map(&file) }.with_context(|| {
+        format!(
+            "Failed to map observations file: {}",
+            observations_path.display()
+        )
+    })?;
+    let bytes = &mmap[..];
+
+    let mut cache = observation_index_cache()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let index_path = observation_index_path(config);
+    let index = cache
+        .entry(observations_path.clone())
+        .or_insert_with(|| ObservationIndex::load_sidecar(&index_path, bytes).unwrap_or_default());
+
+    // File was rewritten or truncated externally; start over
+    if index.indexed_len > bytes.len() {
+        *index = ObservationIndex::default();
+    }
+    index.version = OBSERVATION_INDEX_VERSION;
+    match index.extend(bytes) {
+        // The sidecar only speeds up the next start; failing to write it is harmless
+        Ok(true) => {
+            let _ = index.save_sidecar(&index_path);
         }
+        Ok(false) => {}
+        Err(e) => {
+            // Don't keep a half-built index around for the next call
+            *index = ObservationIndex::default();
+            return Err(e);
+        }
+    }
+
+    let mut spans = index.range(start, end);
+    if file_order {
+        spans.sort_unstable();
+    }
 
-        // Parse JSON
-        let obs: Observation = serde_json::from_str(&line)
-            .with_context(|| format!("Failed to parse observation: {}", line))?;
+    // A final line without a trailing newline isn't indexed yet; include it if complete
+    let tail = std::str::from_utf8(&bytes[index.indexed_len..])
+        .ok()
+        .filter(|tail| !tail.trim().is_empty())
+        .and_then(|tail| serde_json::from_str::<Observation>(tail).ok())
+        .filter(|obs| obs.when >= start && obs.when <= end);
+    drop(cache);
+
+    Ok(ObservationIter {
+        mmap: Some(mmap),
+        spans: spans.into_iter(),
+        tail,
+    })
+}
 
-        // Check if within date range
-        if obs.when >= start && obs.when <= end {
-            observations.push(obs);
-        }
+/// Throw away the observations date index, in memory and on disk, and
+/// rebuild it from the log. Returns how many observations were indexed.
+pub fn rebuild_observation_index(config: &Config) -> Result<usize> {
+    let observations_path = observations_path(config);
+    let index_path = observation_index_path(config);
+    observation_index_cache()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .remove(&observations_path);
+    if index_path.exists() {
+        fs::remove_file(&index_path).with_context(|| {
+            format!(
+                "Failed to remove observations index: {}",
+                index_path.display()
+            )
+        })?;
     }
 
-    Ok(observations)
+    // Opening any range indexes the whole log and writes the sidecar
+    open_observations(NaiveDate::MIN, NaiveDate::MIN, config, false)?;
+    let cache = observation_index_cache()
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    Ok(cache
+        .get(&observations_path)
+        .map_or(0, |index| index.entries.len()))
+}
+
+/// Stream observations dated within `start..=end` in date order (ties in
+/// the order they were logged) without loading them all into memory.
+///
+/// The log is memory-mapped and a cached date index is binary-searched, so
+/// only the records actually consumed are parsed.
+pub fn iter_observations_range(
+    start: NaiveDate,
+    end: NaiveDate,
+    config: &Config,
+) -> Result<ObservationIter> {
+    open_observations(start, end, config, false)
+}
+
+/// Read observations within a date range, in the order they were logged.
+pub fn read_observations_range(
+    start: NaiveDate,
+    end: NaiveDate,
+    config: &Config,
+) -> Result<Vec<Observation>> {
+    open_observations(start, end, config, true)?.collect()
+}
+
+/// Append an event to the activity log (activity.ndjson)
+pub fn append_activity(event: &ActivityEvent, config: &Config) -> Result<()> {
+    use std::fs::OpenOptions;
+
+    fs::create_dir_all(&config.data_root)
+        .with_context(|| format!("Failed to create data root directory: {}", config.data_root))?;
+
+    let activity_path = Path::new(&config.data_root).join("activity.ndjson");
+    let json_line = serde_json::to_string(event).context("Failed to serialize activity")?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&activity_path)
+        .with_context(|| format!("Failed to open activity log: {}", activity_path.display()))?;
+    writeln!(file, "{}", json_line).context("Failed to write activity")?;
+
+    Ok(())
+}
+
+/// Read the most recent `limit` activity events, newest first.
+/// Unparseable lines are skipped so a torn write never hides the whole feed.
+pub fn read_recent_activity(limit: usize, config: &Config) -> Result<Vec<ActivityEvent>> {
+    let activity_path = Path::new(&config.data_root).join("activity.ndjson");
+    if !activity_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(&activity_path)
+        .with_context(|| format!("Failed to read activity log: {}", activity_path.display()))?;
+
+    Ok(content
+        .lines()
+        .rev()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .take(limit)
+        .collect())
+}
+
+/// Append a finished session to the focus log (focus_sessions.ndjson)
+pub fn append_focus_session(session: &FocusSession, config: &Config) -> Result<()> {
+    use std::fs::OpenOptions;
+
+    fs::create_dir_all(&config.data_root)
+        .with_context(|| format!("Failed to create data root directory: {}", config.data_root))?;
+
+    let sessions_path = Path::new(&config.data_root).join("focus_sessions.ndjson");
+    let json_line = serde_json::to_string(session).context("Failed to serialize focus session")?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&sessions_path)
+        .with_context(|| format!("Failed to open focus log: {}", sessions_path.display()))?;
+    writeln!(file, "{}", json_line).context("Failed to write focus session")?;
+
+    Ok(())
+}
+
+/// Read focus sessions credited to days in [start, end]
+pub fn read_focus_sessions(
+    start: NaiveDate,
+    end: NaiveDate,
+    config: &Config,
+) -> Result<Vec<FocusSession>> {
+    let sessions_path = Path::new(&config.data_root).join("focus_sessions.ndjson");
+    if !sessions_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(&sessions_path)
+        .with_context(|| format!("Failed to read focus log: {}", sessions_path.display()))?;
+
+    Ok(content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str::<FocusSession>(line).ok())
+        .filter(|session| session.day() >= start && session.day() <= end)
+        .collect())
+}
+
+/// Total focused minutes per day in [start, end]; days without sessions are 0
+pub fn focus_minutes_by_day(
+    start: NaiveDate,
+    end: NaiveDate,
+    config: &Config,
+) -> Result<Vec<(NaiveDate, f64)>> {
+    let sessions = read_focus_sessions(start, end, config)?;
+    Ok(start
+        .iter_days()
+        .take_while(|day| *day <= end)
+        .map(|day| {
+            let minutes = sessions
+                .iter()
+                .filter(|session| session.day() == day)
+                .map(FocusSession::minutes)
+                .sum();
+            (day, minutes)
+        })
+        .collect())
 }
 
 /// Save a review for a specific ISO week
@@ -832,6 +2108,54 @@ pub fn save_review(week_iso: (i32, u32), review: &Review, config: &Config) -> Re
     Ok(review_path)
 }
 
+fn weekly_goals_path(week_iso: (i32, u32), config: &Config) -> PathBuf {
+    Path::new(&config.data_root)
+        .join("weekly")
+        .join(format!("{}-W{:02}.json", week_iso.0, week_iso.1))
+}
+
+/// Load the weekly focus for an ISO week; empty when none was planned
+pub fn load_weekly_goals(week_iso: (i32, u32), config: &Config) -> Result<WeeklyGoals> {
+    let path = weekly_goals_path(week_iso, config);
+    if !path.exists() {
+        return Ok(WeeklyGoals::new(week_iso));
+    }
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read weekly goals: {}", path.display()))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse weekly goals: {}", path.display()))
+}
+
+/// Save the weekly focus under weekly/YYYY-Www.json
+pub fn save_weekly_goals(weekly: &WeeklyGoals, config: &Config) -> Result<PathBuf> {
+    let path = weekly_goals_path(weekly.week_iso(), config);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create weekly directory: {}", dir.display()))?;
+    }
+    let json = serde_json::to_string_pretty(weekly).context("Failed to serialize weekly goals")?;
+    atomic_write(&path, json.as_bytes())?;
+    Ok(path)
+}
+
+/// Link the action at `index` to its outcome's weekly focus, or unlink it
+pub fn set_weekly_focus_link(
+    goals: &DailyGoals,
+    outcome_type: OutcomeType,
+    index: usize,
+    linked: bool,
+    config: &Config,
+) -> Result<()> {
+    let mut meta = load_or_create_day_meta(goals.date, goals, config)?;
+    let action_meta = meta
+        .outcome_mut(outcome_type)
+        .get_mut(index)
+        .with_context(|| format!("Invalid action index: {}", index))?;
+    action_meta.weekly_focus = linked;
+    save_day_meta(goals.date, &meta, config)?;
+    Ok(())
+}
+
 /// Load a review for a specific ISO week
 pub fn load_review(week_iso: (i32, u32), config: &Config) -> Result<Option<Review>> {
     let reviews_dir = Path::new(&config.data_root).join("reviews");
@@ -0,0 +1,180 @@
+//! "Blocked by" links between actions. A blocked action is marked Blocked in
+//! the day's metadata and flips back to Planned once its blocker is done.
+
+use crate::models::{ActionStatus, BlockerRef, Config, DailyGoals, OutcomeType};
+use anyhow::{bail, Result};
+use std::collections::HashMap;
+
+/// How many earlier days to offer blockers from
+pub const BLOCKER_LOOKBACK_DAYS: i64 = 7;
+
+/// Unfinished actions that the action at `index` could wait on: the rest of
+/// the day, then earlier days, most recent first. Actions of earlier days
+/// without metadata have no id yet; [`set_blocker`] gives them one.
+pub fn candidates(
+    goals: &DailyGoals,
+    outcome_type: OutcomeType,
+    index: usize,
+    config: &Config,
+) -> Result<Vec<BlockerRef>> {
+    let mut refs = Vec::new();
+    let mut push_open = |day: &DailyGoals, skip: Option<(OutcomeType, usize)>, ids: bool| {
+        for outcome in day.outcomes() {
+            for (i, action) in outcome.actions.iter().enumerate() {
+                if action.completed
+                    || action.text.trim().is_empty()
+                    || skip == Some((outcome.outcome_type, i))
+                {
+                    continue;
+                }
+                refs.push(BlockerRef {
+                    date: day.date,
+                    outcome_type: outcome.outcome_type,
+                    text: action.text.clone(),
+                    action_id: ids.then(|| action.id.clone()),
+                });
+            }
+        }
+    };
+
+    push_open(goals, Some((outcome_type, index)), true);
+    for days_back in 1..=BLOCKER_LOOKBACK_DAYS {
+        let date = goals.date - chrono::Duration::days(days_back);
+        let path = crate::data::goals_file_path(date, config);
+        if path.exists() {
+            let mut day = crate::data::read_goals_for(date, config)?;
+            let meta = crate::data::read_day_meta(date, config);
+            if let Some(meta) = &meta {
+                meta.apply_ids(&mut day);
+            }
+            push_open(&day, None, meta.is_some());
+        }
+    }
+    Ok(refs)
+}
+
+/// Whether the blocker is done; `None` when it can no longer be found.
+/// `today` is used as is for blockers on its own date.
+pub fn blocker_done(
+    blocker: &BlockerRef,
+    today: &DailyGoals,
+    config: &Config,
+) -> Result<Option<bool>> {
+    let loaded;
+    let goals = if blocker.date == today.date {
+        today
+    } else {
+        let path = crate::data::goals_file_path(blocker.date, config);
+        if !path.exists() {
+            return Ok(None);
+        }
+        loaded = crate::data::load_or_create_goals(blocker.date, config)?;
+        &loaded
+    };
+    let actions = &goals.outcome(blocker.outcome_type).actions;
+    let done = match &blocker.action_id {
+        Some(id) => actions.iter().find(|a| &a.id == id),
+        None => actions.iter().find(|a| a.text == blocker.text),
+    }
+    .map(|action| action.completed);
+    Ok(done)
+}
+
+/// Pin an earlier day's blocker to the id that day's metadata keeps for
+/// it, saving the metadata so the id lasts; the ids of a day without
+/// metadata change with every load
+fn keep_blocker_id(blocker: &mut BlockerRef, config: &Config) -> Result<()> {
+    if !crate::data::goals_file_path(blocker.date, config).exists() {
+        return Ok(());
+    }
+    let day = crate::data::load_or_create_goals(blocker.date, config)?;
+    let meta = crate::data::load_or_create_day_meta(blocker.date, &day, config)?;
+    let ids = meta.outcome(blocker.outcome_type);
+    if let Some(id) = &blocker.action_id {
+        if ids.iter().any(|m| &m.id == id) {
+            return Ok(());
+        }
+    }
+    let position = day
+        .outcome(blocker.outcome_type)
+        .actions
+        .iter()
+        .position(|a| a.text == blocker.text);
+    blocker.action_id = position.and_then(|i| ids.get(i)).map(|m| m.id.clone());
+    crate::data::save_day_meta(blocker.date, &meta, config)?;
+    Ok(())
+}
+
+/// Set or clear what the action at `index` waits on
+pub fn set_blocker(
+    goals: &DailyGoals,
+    outcome_type: OutcomeType,
+    index: usize,
+    mut blocker: Option<BlockerRef>,
+    config: &Config,
+) -> Result<()> {
+    if let Some(blocker) = &mut blocker {
+        if blocker.date > goals.date {
+            bail!("An action can only wait on the same day or an earlier one");
+        }
+        if blocker.date < goals.date {
+            keep_blocker_id(blocker, config)?;
+        }
+    }
+    let mut meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+    let Some(action_meta) = meta.outcome_mut(outcome_type).get_mut(index) else {
+        bail!("Invalid action index: {}", index);
+    };
+    action_meta.status = match blocker {
+        Some(_) => ActionStatus::Blocked,
+        None if action_meta.status == ActionStatus::Blocked => ActionStatus::Planned,
+        None => action_meta.status,
+    };
+    action_meta.blocked_by = blocker;
+    crate::data::save_day_meta(goals.date, &meta, config)?;
+    Ok(())
+}
+
+/// Blocked actions of the day and the text of what they wait on
+pub fn blocked_actions(
+    goals: &DailyGoals,
+    config: &Config,
+) -> Result<HashMap<(OutcomeType, usize), String>> {
+    let meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+    let mut blocked = HashMap::new();
+    for outcome in goals.outcomes() {
+        for (index, action_meta) in meta.outcome(outcome.outcome_type).iter().enumerate() {
+            if let Some(blocker) = &action_meta.blocked_by {
+                blocked.insert((outcome.outcome_type, index), blocker.text.clone());
+            }
+        }
+    }
+    Ok(blocked)
+}
+
+/// Flip the day's actions whose blocker is done back to Planned; returns
+/// their texts. Nothing is written when nothing changed.
+pub fn release_unblocked(goals: &DailyGoals, config: &Config) -> Result<Vec<String>> {
+    let date = goals.date;
+    let mut meta = crate::data::load_or_create_day_meta(date, goals, config)?;
+    let mut released = Vec::new();
+    for outcome in goals.outcomes() {
+        let metas = meta.outcome_mut(outcome.outcome_type);
+        for (action, action_meta) in outcome.actions.iter().zip(metas.iter_mut()) {
+            let Some(blocker) = &action_meta.blocked_by else {
+                continue;
+            };
+            if blocker_done(blocker, goals, config)? == Some(true) {
+                action_meta.blocked_by = None;
+                if action_meta.status == ActionStatus::Blocked {
+                    action_meta.status = ActionStatus::Planned;
+                }
+                released.push(action.text.clone());
+            }
+        }
+    }
+    if !released.is_empty() {
+        crate::data::save_day_meta(date, &meta, config)?;
+    }
+    Ok(released)
+}
@@ -0,0 +1,265 @@
+//! Weekly accountability digests for a partner: completion, per-outcome
+//! progress and the streak, without the text of any action unless the
+//! recipient is allowed to see that outcome's actions.
+//!
+//! Recipients are set under `digest` in settings.json. A digest is written
+//! to the recipient's share folder and/or piped, as a message with headers,
+//! to `digest.mail_command` (e.g. `sendmail -t`), so mail goes out through
+//! whatever the machine already uses instead of FocusFive itself.
+
+use crate::export::ExportPeriod;
+use crate::models::{Config, DigestRecipient, DigestSettings, OutcomeType};
+use anyhow::{bail, Context, Result};
+use chrono::{Datelike, NaiveDate};
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+const OUTCOMES: [OutcomeType; 3] = [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family];
+
+/// One week's figures, with every action so digests can share some of them
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeeklyDigest {
+    /// Monday of the week
+    pub start: NaiveDate,
+    /// Last day covered: Sunday, or the day the digest was made
+    pub end: NaiveDate,
+    /// Completed and planned actions per day with a goals file
+    pub days: Vec<(NaiveDate, u32, u32)>,
+    /// Completed and planned actions over the week: Work, Health, Family
+    pub outcomes: [(u32, u32); 3],
+    /// Consecutive days up to `end` with a completed action
+    pub streak: u32,
+    /// Day, outcome, text and whether it was done, for every non-empty action
+    pub actions: Vec<(NaiveDate, OutcomeType, String, bool)>,
+}
+
+impl WeeklyDigest {
+    /// Completed and planned actions over the week
+    pub fn totals(&self) -> (u32, u32) {
+        self.outcomes
+            .iter()
+            .fold((0, 0), |(done, planned), o| (done + o.0, planned + o.1))
+    }
+}
+
+/// The digest of the week containing `date`, up to `date`
+pub fn build(date: NaiveDate, config: &Config) -> Result<WeeklyDigest> {
+    let (start, sunday) = ExportPeriod::Week.bounds(date);
+    let end = sunday.min(date);
+    let cache = crate::ui::stats::load_day_cache(start, end, config);
+
+    let mut days = Vec::new();
+    let mut outcomes = [(0, 0); 3];
+    for (day, rollup) in cache.days.range(start..=end) {
+        let mut totals = (0, 0);
+        for (sum, counts) in outcomes.iter_mut().zip(rollup.actions) {
+            sum.0 += counts.0;
+            sum.1 += counts.1;
+            totals.0 += counts.0;
+            totals.1 += counts.1;
+        }
+        days.push((*day, totals.0, totals.1));
+    }
+
+    let mut actions = Vec::new();
+    for (day, _, _) in &days {
+        let goals = crate::data::read_goals_for(*day, config)?;
+        for outcome in goals.outcomes() {
+            for action in outcome.actions.iter().filter(|a| !a.text.trim().is_empty()) {
+                actions.push((
+                    *day,
+                    outcome.outcome_type,
+                    action.text.clone(),
+                    action.completed,
+                ));
+            }
+        }
+    }
+
+    Ok(WeeklyDigest {
+        start,
+        end,
+        days,
+        outcomes,
+        streak: crate::ui::stats::day_status(end, config).streak,
+        actions,
+    })
+}
+
+fn percent(done: u32, planned: u32) -> u32 {
+    if planned == 0 {
+        0
+    } else {
+        (f64::from(done) * 100.0 / f64::from(planned)).round() as u32
+    }
+}
+
+/// Subject line of the digest
+pub fn subject(digest: &WeeklyDigest) -> String {
+    let week = digest.start.iso_week();
+    format!(
+        "FocusFive weekly digest: {}-W{:02}",
+        week.year(),
+        week.week()
+    )
+}
+
+/// The digest as `recipient` gets it: action text only for the outcomes in
+/// their `share_actions`
+pub fn render(digest: &WeeklyDigest, recipient: Option<&DigestRecipient>) -> String {
+    let (done, planned) = digest.totals();
+    let mut text = format!("{}\n", subject(digest));
+    if let Some(recipient) = recipient {
+        text.push_str(&format!("For {}\n", recipient.name));
+    }
+    text.push_str(&format!(
+        "{} to {}\n\n",
+        digest.start.format("%a %b %d"),
+        digest.end.format("%a %b %d, %Y")
+    ));
+    text.push_str(&format!(
+        "Completion: {}% ({}/{} actions)\n",
+        percent(done, planned),
+        done,
+        planned
+    ));
+    for (outcome_type, (done, planned)) in OUTCOMES.iter().zip(digest.outcomes) {
+        text.push_str(&format!(
+            "  {:<7} {:>3}% ({}/{})\n",
+            outcome_type.as_str(),
+            percent(done, planned),
+            done,
+            planned
+        ));
+    }
+    let active = digest.days.iter().filter(|(_, done, _)| *done > 0).count();
+    text.push_str(&format!(
+        "Days with something done: {}/{}\n",
+        active,
+        (digest.end - digest.start).num_days() + 1
+    ));
+    text.push_str(&format!("Current streak: {} days\n", digest.streak));
+
+    if !digest.days.is_empty() {
+        text.push('\n');
+        for (day, done, planned) in &digest.days {
+            let pct = percent(*done, *planned);
+            let filled = (pct / 10) as usize;
+            text.push_str(&format!(
+                "{}  {}{} {:>3}%\n",
+                day.format("%a %b %d"),
+                "█".repeat(filled),
+                "░".repeat(10 - filled),
+                pct
+            ));
+        }
+    }
+
+    let shared = recipient.map_or(&[][..], |r| r.share_actions.as_slice());
+    for outcome_type in OUTCOMES.iter().filter(|o| shared.contains(o)) {
+        text.push_str(&format!("\n{} actions\n", outcome_type.as_str()));
+        let mut any = false;
+        for (day, _, action, completed) in digest
+            .actions
+            .iter()
+            .filter(|(_, outcome, _, _)| outcome == outcome_type)
+        {
+            any = true;
+            text.push_str(&format!(
+                "  {} {} ({})\n",
+                if *completed { "✓" } else { "○" },
+                action,
+                day.format("%a")
+            ));
+        }
+        if !any {
+            text.push_str("  Nothing planned\n");
+        }
+    }
+    text
+}
+
+/// File a digest is saved as in a share folder
+pub fn file_name(digest: &WeeklyDigest) -> String {
+    let week = digest.start.iso_week();
+    format!("focusfive-digest-{}-W{:02}.txt", week.year(), week.week())
+}
+
+/// Deliver the digest to `recipient` and describe where it went
+pub fn send(
+    digest: &WeeklyDigest,
+    recipient: &DigestRecipient,
+    settings: &DigestSettings,
+    config: &Config,
+) -> Result<Vec<String>> {
+    if recipient.email.is_none() && recipient.folder.is_none() {
+        bail!(
+            "Recipient {} has neither an email nor a folder",
+            recipient.name
+        );
+    }
+    let body = render(digest, Some(recipient));
+    let mut delivered = Vec::new();
+
+    if let Some(folder) = &recipient.folder {
+        let dir = Path::new(&config.data_root).join(folder);
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create share folder: {}", dir.display()))?;
+        let path = dir.join(file_name(digest));
+        crate::data::atomic_write(&path, body.as_bytes())?;
+        delivered.push(path.display().to_string());
+    }
+
+    if let Some(email) = &recipient.email {
+        let Some(command) = settings
+            .mail_command
+            .as_deref()
+            .map(str::trim)
+            .filter(|c| !c.is_empty())
+        else {
+            bail!(
+                "Set digest.mail_command in settings.json (e.g. \"sendmail -t\") to email {}",
+                email
+            );
+        };
+        let message = format!(
+            "To: {}\nSubject: {}\nContent-Type: text/plain; charset=utf-8\n\n{}",
+            email,
+            subject(digest),
+            body
+        );
+        pipe_to(command, &message)?;
+        delivered.push(email.clone());
+    }
+    Ok(delivered)
+}
+
+fn pipe_to(command: &str, message: &str) -> Result<()> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to start mail command: {}", command))?;
+
+    // A command may exit without reading stdin; report its status first
+    let sent = match child.stdin.take() {
+        Some(mut stdin) => stdin.write_all(message.as_bytes()),
+        None => Ok(()),
+    };
+
+    let output = child
+        .wait_with_output()
+        .context("Mail command did not finish")?;
+    if !output.status.success() {
+        bail!(
+            "Mail command failed ({}): {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    sent.context("Failed to send digest to mail command")
+}
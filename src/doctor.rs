@@ -0,0 +1,467 @@
+//! Data doctor: checks the data directory for goals files the parser
+//! rejects, references to objectives or indicators that no longer exist,
+//! duplicate ids and an observations log out of date order, and repairs
+//! what can be repaired without guessing.
+
+use crate::models::{Config, Observation};
+use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub severity: Severity,
+    /// File the issue is in, relative to the data directory
+    pub area: String,
+    pub message: String,
+    /// Whether a fixing run repairs it
+    pub fixable: bool,
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "{:<7} {}: {}", severity, self.area, self.message)?;
+        if self.fixable {
+            write!(f, " [fixable]")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    /// Goals files read
+    pub files: usize,
+    pub issues: Vec<Issue>,
+    /// Issues repaired, on a fixing run
+    pub fixed: usize,
+}
+
+impl Report {
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Errors still in the data after this run
+    pub fn has_unfixed_errors(&self) -> bool {
+        self.issues
+            .iter()
+            .any(|issue| issue.severity == Severity::Error && !(issue.fixable && self.fixed > 0))
+    }
+
+    /// One line, e.g. "3 problems in 42 goals files and the data files, 2 fixable"
+    pub fn summary(&self) -> String {
+        if self.is_clean() {
+            return format!(
+                "No problems in {} goals files and the data files",
+                self.files
+            );
+        }
+        let fixable = self.issues.iter().filter(|issue| issue.fixable).count();
+        let mut summary = format!(
+            "{} problem{} in {} goals files and the data files",
+            self.issues.len(),
+            if self.issues.len() == 1 { "" } else { "s" },
+            self.files
+        );
+        if self.fixed > 0 {
+            summary.push_str(&format!(", {} fixed", self.fixed));
+        } else if fixable > 0 {
+            summary.push_str(&format!(", {} fixable", fixable));
+        }
+        summary
+    }
+}
+
+/// The report as printed by `focusfive doctor`
+pub fn format_report(report: &Report) -> String {
+    let mut lines: Vec<String> = report
+        .issues
+        .iter()
+        .map(|issue| issue.to_string())
+        .collect();
+    lines.push(report.summary());
+    let fixable = report.issues.iter().filter(|issue| issue.fixable).count();
+    if report.fixed == 0 && fixable > 0 {
+        lines.push("Run `focusfive doctor --fix` to repair the fixable ones".to_string());
+    }
+    lines.join("\n")
+}
+
+struct Checker {
+    report: Report,
+    fix: bool,
+}
+
+impl Checker {
+    fn issue(&mut self, severity: Severity, area: &str, message: String, fixable: bool) {
+        if self.fix && fixable {
+            self.report.fixed += 1;
+        }
+        self.report.issues.push(Issue {
+            severity,
+            area: area.to_string(),
+            message,
+            fixable,
+        });
+    }
+}
+
+/// Check everything; with `fix` the fixable issues are repaired as well
+pub fn run(config: &Config, fix: bool) -> Result<Report> {
+    let mut checker = Checker {
+        report: Report::default(),
+        fix,
+    };
+    check_goals(&mut checker, config)?;
+    check_references(&mut checker, config)?;
+    check_observations(&mut checker, config)?;
+    Ok(checker.report)
+}
+
+fn check_goals(checker: &mut Checker, config: &Config) -> Result<()> {
+    let goals_dir = Path::new(&config.goals_dir);
+    for date in crate::data::goals_file_dates(config)? {
+        let path = crate::data::goals_file_path(date, config);
+        let area = format!(
+            "goals/{}",
+            path.strip_prefix(goals_dir).unwrap_or(&path).display()
+        );
+        checker.report.files += 1;
+        match crate::data::read_goals_for(date, config) {
+            Err(e) => checker.issue(Severity::Error, &area, format!("{:#}", e), false),
+            Ok(mut goals) if goals.date != date => {
+                checker.issue(
+                    Severity::Warning,
+                    &area,
+                    format!(
+                        "header is dated {} but the file is for {}",
+                        goals.date, date
+                    ),
+                    true,
+                );
+                if checker.fix {
+                    goals.date = date;
+                    crate::data::write_goals_file(&goals, config)?;
+                }
+            }
+            Ok(_) => {}
+        }
+    }
+    Ok(())
+}
+
+/// Ids after the first use of an id get a fresh one when fixing
+fn duplicate_ids<'a>(
+    checker: &mut Checker,
+    area: &str,
+    kind: &str,
+    ids: impl Iterator<Item = &'a mut String>,
+) -> bool {
+    let mut seen = HashSet::new();
+    let mut changed = false;
+    for id in ids {
+        if seen.insert(id.clone()) {
+            continue;
+        }
+        checker.issue(
+            Severity::Error,
+            area,
+            format!("{} id {} is used more than once", kind, id),
+            true,
+        );
+        if checker.fix {
+            *id = uuid::Uuid::new_v4().to_string();
+            changed = true;
+        }
+    }
+    changed
+}
+
+fn check_references(checker: &mut Checker, config: &Config) -> Result<()> {
+    let mut objectives = crate::data::load_or_create_objectives(config)?;
+    let mut indicators = crate::data::load_or_create_indicators(config)?;
+    let mut projects = crate::data::load_or_create_projects(config)?;
+
+    let mut objectives_changed = duplicate_ids(
+        checker,
+        "objectives.json",
+        "Objective",
+        objectives.objectives.iter_mut().map(|o| &mut o.id),
+    );
+    let mut indicators_changed = duplicate_ids(
+        checker,
+        "indicators.json",
+        "Indicator",
+        indicators.indicators.iter_mut().map(|def| &mut def.id),
+    );
+    // Links to trashed items are kept so they can be restored
+    let trash = crate::data::load_or_create_trash(config)?;
+    let objective_ids: HashSet<String> = objectives
+        .objectives
+        .iter()
+        .chain(trash.objectives.iter().map(|t| &t.item))
+        .map(|o| o.id.clone())
+        .collect();
+    let indicator_ids: HashSet<String> = indicators
+        .indicators
+        .iter()
+        .chain(trash.indicators.iter().map(|t| &t.item))
+        .map(|def| def.id.clone())
+        .collect();
+
+    for objective in &mut objectives.objectives {
+        if let Some(parent_id) = objective.parent_id.clone() {
+            if parent_id == objective.id || !objective_ids.contains(&parent_id) {
+                checker.issue(
+                    Severity::Warning,
+                    "objectives.json",
+                    format!("'{}' has a missing parent {}", objective.title, parent_id),
+                    true,
+                );
+                if checker.fix {
+                    objective.parent_id = None;
+                    objectives_changed = true;
+                }
+            }
+        }
+        let missing: Vec<String> = objective
+            .indicators
+            .iter()
+            .filter(|id| !indicator_ids.contains(*id))
+            .cloned()
+            .collect();
+        for id in &missing {
+            checker.issue(
+                Severity::Warning,
+                "objectives.json",
+                format!("'{}' links missing indicator {}", objective.title, id),
+                true,
+            );
+        }
+        if checker.fix && !missing.is_empty() {
+            objective.indicators.retain(|id| indicator_ids.contains(id));
+            objectives_changed = true;
+        }
+    }
+
+    for def in &mut indicators.indicators {
+        if let Some(objective_id) = def.objective_id.clone() {
+            if !objective_ids.contains(&objective_id) {
+                checker.issue(
+                    Severity::Warning,
+                    "indicators.json",
+                    format!(
+                        "'{}' belongs to missing objective {}",
+                        def.name, objective_id
+                    ),
+                    true,
+                );
+                if checker.fix {
+                    def.objective_id = None;
+                    indicators_changed = true;
+                }
+            }
+        }
+        let broken_ratio = def.ratio_of.as_ref().is_some_and(|ratio| {
+            !indicator_ids.contains(&ratio.numerator_id)
+                || !indicator_ids.contains(&ratio.denominator_id)
+        });
+        if broken_ratio {
+            checker.issue(
+                Severity::Warning,
+                "indicators.json",
+                format!("'{}' is a ratio of a missing counter", def.name),
+                true,
+            );
+            if checker.fix {
+                def.ratio_of = None;
+                indicators_changed = true;
+            }
+        }
+    }
+
+    let mut projects_changed = false;
+    for project in &mut projects.projects {
+        let missing = project
+            .objective_ids
+            .iter()
+            .filter(|id| !objective_ids.contains(*id))
+            .count();
+        if missing > 0 {
+            checker.issue(
+                Severity::Warning,
+                "projects.json",
+                format!(
+                    "'{}' lists {} missing objective{}",
+                    project.title,
+                    missing,
+                    if missing == 1 { "" } else { "s" }
+                ),
+                true,
+            );
+            if checker.fix {
+                project
+                    .objective_ids
+                    .retain(|id| objective_ids.contains(id));
+                projects_changed = true;
+            }
+        }
+    }
+
+    if objectives_changed {
+        crate::data::save_objectives(&objectives, config)?;
+    }
+    if indicators_changed {
+        crate::data::save_indicators(&indicators, config)?;
+    }
+    if projects_changed {
+        crate::data::save_projects(&projects, config)?;
+    }
+    Ok(())
+}
+
+fn check_observations(checker: &mut Checker, config: &Config) -> Result<()> {
+    const AREA: &str = "observations.ndjson";
+    let path = crate::data::observations_path(config);
+    if !path.exists() {
+        return Ok(());
+    }
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read observations file: {}", path.display()))?;
+    let indicator_ids: HashSet<String> = crate::data::load_or_create_indicators(config)?
+        .indicators
+        .into_iter()
+        .map(|def| def.id)
+        .chain(
+            crate::data::load_or_create_trash(config)?
+                .indicators
+                .into_iter()
+                .map(|t| t.item.id),
+        )
+        .collect();
+
+    let mut changed = false;
+    let mut rejected = Vec::new();
+    // Kept records with their line, re-serialized only when changed
+    let mut kept: Vec<(Observation, String)> = Vec::new();
+    let mut seen: HashMap<String, String> = HashMap::new();
+    let mut unknown: HashMap<String, usize> = HashMap::new();
+    for (number, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut obs: Observation = match serde_json::from_str(line) {
+            Ok(obs) => obs,
+            Err(e) => {
+                checker.issue(
+                    Severity::Error,
+                    AREA,
+                    format!("line {} cannot be read: {}", number + 1, e),
+                    true,
+                );
+                rejected.push(line.to_string());
+                changed = true;
+                continue;
+            }
+        };
+        if !indicator_ids.contains(&obs.indicator_id) {
+            *unknown.entry(obs.indicator_id.clone()).or_default() += 1;
+        }
+        match seen.get(&obs.id) {
+            Some(first) if first == line => {
+                checker.issue(
+                    Severity::Warning,
+                    AREA,
+                    format!("line {} repeats observation {}", number + 1, obs.id),
+                    true,
+                );
+                changed = true;
+                continue;
+            }
+            Some(_) => {
+                checker.issue(
+                    Severity::Error,
+                    AREA,
+                    format!("observation id {} is used more than once", obs.id),
+                    true,
+                );
+                obs.id = uuid::Uuid::new_v4().to_string();
+                let line =
+                    serde_json::to_string(&obs).context("Failed to serialize observation")?;
+                kept.push((obs, line));
+                changed = true;
+                continue;
+            }
+            None => {
+                seen.insert(obs.id.clone(), line.to_string());
+            }
+        }
+        kept.push((obs, line.to_string()));
+    }
+
+    let mut unknown: Vec<_> = unknown.into_iter().collect();
+    unknown.sort();
+    for (indicator_id, count) in unknown {
+        checker.issue(
+            Severity::Warning,
+            AREA,
+            format!(
+                "{} observation{} for missing indicator {}",
+                count,
+                if count == 1 { "" } else { "s" },
+                indicator_id
+            ),
+            false,
+        );
+    }
+
+    let out_of_order = kept
+        .windows(2)
+        .filter(|pair| pair[1].0.when < pair[0].0.when)
+        .count();
+    if out_of_order > 0 {
+        checker.issue(
+            Severity::Warning,
+            AREA,
+            format!(
+                "{} observation{} logged out of date order",
+                out_of_order,
+                if out_of_order == 1 { "" } else { "s" }
+            ),
+            true,
+        );
+        kept.sort_by_key(|(obs, _)| obs.when);
+        changed = true;
+    }
+
+    if !checker.fix || !changed {
+        return Ok(());
+    }
+    if !rejected.is_empty() {
+        let rejected_path = path.with_file_name("observations.rejected.ndjson");
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&rejected_path)
+            .with_context(|| format!("Failed to open {}", rejected_path.display()))?;
+        for line in &rejected {
+            writeln!(file, "{}", line).context("Failed to set aside an unreadable observation")?;
+        }
+    }
+    let lines: Vec<String> = kept.into_iter().map(|(_, line)| line).collect();
+    crate::data::replace_observations(&lines, config)
+}
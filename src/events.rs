@@ -0,0 +1,140 @@
+//! Structured state-change events for external automations (Home Assistant,
+//! n8n, shell scripts).
+//!
+//! Each event is one JSON line, written to a unix socket, appended to an
+//! NDJSON file, or both, as configured under `events` in settings.json.
+//! Anything that reads a socket or tails a file can consume them, with a
+//! small bridge where a webhook is wanted. The `EventBus` hands events to a
+//! worker thread, so a slow or missing listener never stalls the UI.
+
+use crate::models::EventSettings;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::sync::mpsc::{self, Sender};
+use std::thread::JoinHandle;
+
+/// What changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKind {
+    ActionCompleted,
+    ObservationAdded,
+    DayClosed,
+    StreakAtRisk,
+    ObjectiveCompleted,
+}
+
+/// One event as delivered to sinks: `{"type": ..., "at": ..., "data": {...}}`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Event {
+    #[serde(rename = "type")]
+    pub kind: EventKind,
+    pub at: chrono::DateTime<chrono::Utc>,
+    pub data: serde_json::Value,
+}
+
+impl Event {
+    pub fn new(kind: EventKind, data: serde_json::Value) -> Self {
+        Event {
+            kind,
+            at: chrono::Utc::now(),
+            data,
+        }
+    }
+}
+
+/// Hands events to a background delivery thread. Dropping the bus delivers
+/// anything still queued before returning.
+pub struct EventBus {
+    sender: Option<Sender<Event>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl EventBus {
+    /// A bus that drops every event
+    pub fn disabled() -> Self {
+        EventBus {
+            sender: None,
+            worker: None,
+        }
+    }
+
+    /// Start delivering to the configured sinks; disabled when none are set
+    pub fn start(settings: &EventSettings) -> Self {
+        if settings.socket.is_none() && settings.file.is_none() {
+            return Self::disabled();
+        }
+
+        let settings = settings.clone();
+        let (sender, receiver) = mpsc::channel::<Event>();
+        let worker = std::thread::spawn(move || {
+            for event in receiver {
+                // Listeners come and go; a failed delivery must not stop the bus
+                let _ = deliver(&event, &settings);
+            }
+        });
+
+        EventBus {
+            sender: Some(sender),
+            worker: Some(worker),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.sender.is_some()
+    }
+
+    /// Queue an event without blocking
+    pub fn emit(&self, event: Event) {
+        if let Some(sender) = &self.sender {
+            let _ = sender.send(event);
+        }
+    }
+}
+
+impl Drop for EventBus {
+    fn drop(&mut self) {
+        // Closing the channel ends the worker loop once the queue drains
+        self.sender.take();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+fn deliver(event: &Event, settings: &EventSettings) -> Result<()> {
+    let mut line = serde_json::to_string(event).context("Failed to serialize event")?;
+    line.push('\n');
+
+    if let Some(path) = &settings.file {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Failed to open event file: {}", path))?;
+        file.write_all(line.as_bytes())
+            .context("Failed to write event")?;
+    }
+
+    if let Some(path) = &settings.socket {
+        send_to_socket(path, &line)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(unix)]
+fn send_to_socket(path: &str, line: &str) -> Result<()> {
+    let mut stream = std::os::unix::net::UnixStream::connect(path)
+        .with_context(|| format!("Failed to connect to event socket: {}", path))?;
+    stream
+        .write_all(line.as_bytes())
+        .context("Failed to write event to socket")
+}
+
+#[cfg(not(unix))]
+fn send_to_socket(_path: &str, _line: &str) -> Result<()> {
+    anyhow::bail!("Event sockets are only supported on unix")
+}
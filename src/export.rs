@@ -0,0 +1,238 @@
+//! Markdown exports of the goals recorded over a day, week or month, and a
+//! printable one-page plan for a single day, written under `exports/` in the
+//! data root.
+
+use crate::models::{format_minutes, Config, DailyGoals};
+use anyhow::{bail, Context, Result};
+use chrono::{Datelike, Duration, NaiveDate};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Span of days an export covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportPeriod {
+    Day,
+    Week,
+    Month,
+    /// One-page plain-text plan for the day, for printing or pasting
+    Plan,
+}
+
+/// Width of the printable plan page
+pub const PLAN_WIDTH: usize = 72;
+
+impl ExportPeriod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExportPeriod::Day => "day",
+            ExportPeriod::Week => "week",
+            ExportPeriod::Month => "month",
+            ExportPeriod::Plan => "plan",
+        }
+    }
+
+    /// First and last day of the period containing `date`; weeks start on Monday
+    pub fn bounds(&self, date: NaiveDate) -> (NaiveDate, NaiveDate) {
+        match self {
+            ExportPeriod::Day | ExportPeriod::Plan => (date, date),
+            ExportPeriod::Week => {
+                let start = date - Duration::days(date.weekday().num_days_from_monday() as i64);
+                (start, start + Duration::days(6))
+            }
+            ExportPeriod::Month => {
+                let start = date.with_day(1).unwrap_or(date);
+                let next = start
+                    .checked_add_months(chrono::Months::new(1))
+                    .unwrap_or(start);
+                (start, next - Duration::days(1))
+            }
+        }
+    }
+
+    fn file_name(&self, date: NaiveDate) -> String {
+        match self {
+            ExportPeriod::Day => format!("day-{}.md", date.format("%Y-%m-%d")),
+            ExportPeriod::Week => {
+                let week = date.iso_week();
+                format!("week-{}-W{:02}.md", week.year(), week.week())
+            }
+            ExportPeriod::Month => format!("month-{}.md", date.format("%Y-%m")),
+            ExportPeriod::Plan => format!("plan-{}.txt", date.format("%Y-%m-%d")),
+        }
+    }
+}
+
+impl std::str::FromStr for ExportPeriod {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "day" | "today" => Ok(ExportPeriod::Day),
+            "week" => Ok(ExportPeriod::Week),
+            "month" => Ok(ExportPeriod::Month),
+            "plan" | "print" => Ok(ExportPeriod::Plan),
+            other => bail!(
+                "Unknown export period '{}' (expected day, week, month or plan)",
+                other
+            ),
+        }
+    }
+}
+
+/// A day's goals as markdown, with its day note quoted under the heading
+pub fn day_markdown(goals: &DailyGoals, config: &Config) -> Result<String> {
+    let markdown = crate::data::generate_markdown(goals);
+    let Some(note) = crate::data::load_day_note(goals.date, config)? else {
+        return Ok(markdown);
+    };
+    let (heading, rest) = markdown.split_once("\n\n").unwrap_or((&markdown, ""));
+    let quoted: Vec<String> = note.lines().map(|line| format!("> {}", line)).collect();
+    Ok(format!("{}\n\n{}\n\n{}", heading, quoted.join("\n"), rest))
+}
+
+/// Markdown for every recorded day in the period containing `date`, each
+/// goals file nested one heading level down
+pub fn render_export(period: ExportPeriod, date: NaiveDate, config: &Config) -> Result<String> {
+    if period == ExportPeriod::Plan {
+        return render_plan(date, config);
+    }
+    let (start, end) = period.bounds(date);
+    let mut content = format!(
+        "# FocusFive {} export: {} to {}\n\n",
+        period.as_str(),
+        start.format("%Y-%m-%d"),
+        end.format("%Y-%m-%d")
+    );
+
+    let mut days = 0;
+    for day in start.iter_days().take_while(|day| *day <= end) {
+        let path = crate::data::goals_file_path(day, config);
+        if !path.exists() {
+            continue;
+        }
+        let goals = crate::data::read_goals_for(day, config)?;
+        for line in day_markdown(&goals, config)?.lines() {
+            if line.starts_with('#') {
+                content.push('#');
+            }
+            content.push_str(line);
+            content.push('\n');
+        }
+        days += 1;
+    }
+    if days == 0 {
+        content.push_str("_No goals recorded._\n");
+    }
+    Ok(content)
+}
+
+/// Split `text` into lines of at most `width` characters at spaces
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for word in text.split_whitespace() {
+        match lines.last_mut() {
+            Some(line) if line.chars().count() + 1 + word.chars().count() <= width => {
+                line.push(' ');
+                line.push_str(word);
+            }
+            _ => lines.push(word.to_string()),
+        }
+    }
+    lines
+}
+
+/// Append `text` wrapped to the page, the first line after `first` and the
+/// rest indented to match it
+fn push_wrapped(page: &mut String, first: &str, text: &str) {
+    let indent = " ".repeat(first.chars().count());
+    let width = PLAN_WIDTH.saturating_sub(indent.len()).max(20);
+    for (i, line) in wrap(text, width).iter().enumerate() {
+        page.push_str(if i == 0 { first } else { &indent });
+        page.push_str(line);
+        page.push('\n');
+    }
+}
+
+/// Plain-text page with the day's note, each outcome's goal and actions as
+/// checkboxes with their estimates, linked objectives and blockers, and room
+/// for notes. Days without a file print an empty plan.
+pub fn render_plan(date: NaiveDate, config: &Config) -> Result<String> {
+    let goals = crate::data::load_or_create_goals(date, config)?;
+    let meta = crate::data::load_or_create_day_meta(date, &goals, config)?;
+    let objectives = crate::data::load_or_create_objectives(config)?;
+
+    let mut title = format!("FOCUSFIVE - {}", date.format("%A, %B %d, %Y"));
+    if let Some(day) = goals.day_number {
+        title.push_str(&format!(" - Day {}", day));
+    }
+    let mut page = format!("{}\n{}\n\n", title, "=".repeat(title.chars().count()));
+    if let Some(note) = &meta.note {
+        push_wrapped(&mut page, "Today: ", note);
+        page.push('\n');
+    }
+
+    let mut planned = 0;
+    for outcome in goals.outcomes() {
+        let heading = outcome.outcome_type.as_str().to_uppercase();
+        match &outcome.goal {
+            Some(goal) => push_wrapped(&mut page, &format!("{} - ", heading), goal),
+            None => page.push_str(&format!("{}\n", heading)),
+        }
+        page.push_str(&format!("{}\n", "-".repeat(PLAN_WIDTH)));
+
+        let metas = meta.outcome(outcome.outcome_type);
+        let mut written = 0;
+        for (index, action) in outcome.actions.iter().enumerate() {
+            if action.text.trim().is_empty() {
+                continue;
+            }
+            written += 1;
+            let action_meta = metas.get(index);
+            let mut text = action.text.clone();
+            if let Some(start) = action_meta.and_then(|m| m.scheduled_at) {
+                text = format!("{} {}", start.format("%H:%M"), text);
+            }
+            if let Some(minutes) = action_meta.and_then(|m| m.estimated_min) {
+                planned += minutes;
+                text.push_str(&format!(" ({})", format_minutes(minutes)));
+            }
+            let checkbox = if action.completed { "[x] " } else { "[ ] " };
+            push_wrapped(&mut page, checkbox, &text);
+            for id in action.get_all_objective_ids() {
+                if let Some(objective) = objectives.objectives.iter().find(|o| o.id == id) {
+                    push_wrapped(&mut page, "    > ", &objective.title);
+                }
+            }
+            if let Some(blocker) = action_meta.and_then(|m| m.blocked_by.as_ref()) {
+                push_wrapped(&mut page, "    waits on: ", &blocker.text);
+            }
+        }
+        // Blank lines to write on when nothing is planned yet
+        for _ in written..3 {
+            page.push_str("[ ] ____________________________________________\n");
+        }
+        page.push('\n');
+    }
+
+    if planned > 0 {
+        page.push_str(&format!("Planned time: {}\n\n", format_minutes(planned)));
+    }
+    page.push_str("NOTES\n");
+    page.push_str(&format!("{}\n", "-".repeat(PLAN_WIDTH)));
+    for _ in 0..4 {
+        page.push_str(&format!("{}\n\n", "_".repeat(PLAN_WIDTH)));
+    }
+    Ok(page)
+}
+
+/// Write the export for the period containing `date` and return its path
+pub fn write_export(period: ExportPeriod, date: NaiveDate, config: &Config) -> Result<PathBuf> {
+    let content = render_export(period, date, config)?;
+    let dir = Path::new(&config.data_root).join("exports");
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create exports directory: {}", dir.display()))?;
+    let path = dir.join(period.file_name(date));
+    fs::write(&path, content)
+        .with_context(|| format!("Failed to write export: {}", path.display()))?;
+    Ok(path)
+}
@@ -0,0 +1,47 @@
+//! Long-form text (visions, reflections) in the user's own editor. The text
+//! goes through a temporary markdown file; the TUI suspends itself around
+//! the call (see `ui::terminal::run_app`).
+
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::process::Command;
+
+/// `$VISUAL`, then `$EDITOR`, then vi
+pub fn editor_command() -> String {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .map(|value| value.trim().to_string())
+        .find(|value| !value.is_empty())
+        .unwrap_or_else(|| "vi".to_string())
+}
+
+/// Open `text` in the configured editor and return what was saved
+pub fn edit(text: &str) -> Result<String> {
+    edit_with(&editor_command(), text)
+}
+
+/// Open `text` with `command`, which may carry arguments ("code --wait");
+/// the file path is appended. A trailing newline added by the editor is
+/// dropped.
+pub fn edit_with(command: &str, text: &str) -> Result<String> {
+    let path = std::env::temp_dir().join(format!("focusfive-{}.md", uuid::Uuid::new_v4()));
+    fs::write(&path, text).with_context(|| format!("Failed to write {}", path.display()))?;
+
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(format!("{} \"$1\"", command))
+        .arg("focusfive")
+        .arg(&path)
+        .status()
+        .with_context(|| format!("Failed to start editor: {}", command));
+    let edited = fs::read_to_string(&path);
+    let _ = fs::remove_file(&path);
+
+    let status = status?;
+    if !status.success() {
+        bail!("{} exited with {}; nothing changed", command, status);
+    }
+    let edited = edited.with_context(|| format!("Failed to read back {}", path.display()))?;
+    Ok(edited.trim_end_matches(['\r', '\n']).to_string())
+}
@@ -0,0 +1,233 @@
+//! Trend projection for indicator trajectories.
+//!
+//! A linear and, when every value is positive, an exponential model are fitted
+//! by least squares over days since the first observation; whichever leaves
+//! the smaller squared error is kept. The fit gives the day the indicator is
+//! expected to reach its target, compared with the objective's due date.
+
+use crate::models::{IndicatorDef, IndicatorDirection, Objective, ObjectiveStatus, Observation};
+use chrono::{Duration, NaiveDate};
+
+/// Furthest ahead an ETA is projected; beyond this there is no useful ETA
+const MAX_ETA_DAYS: f64 = 3650.0;
+
+/// Shape of the fitted trend
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrendModel {
+    Linear,
+    Exponential,
+}
+
+/// Fitted trend; `t` is days since `origin`.
+/// Linear: `intercept + slope * t`. Exponential: `intercept * e^(slope * t)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrendFit {
+    pub model: TrendModel,
+    pub origin: NaiveDate,
+    pub intercept: f64,
+    pub slope: f64,
+}
+
+/// Least-squares line through `points`, or `None` when x never varies
+fn least_squares(points: &[(f64, f64)]) -> Option<(f64, f64)> {
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let sxx: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    if sxx <= f64::EPSILON {
+        return None;
+    }
+    let sxy: f64 = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    let slope = sxy / sxx;
+    Some((mean_y - slope * mean_x, slope))
+}
+
+impl TrendFit {
+    /// Fit `points` (date, value); needs at least two distinct dates
+    pub fn fit(points: &[(NaiveDate, f64)]) -> Option<Self> {
+        let origin = points.iter().map(|(date, _)| *date).min()?;
+        let xy: Vec<(f64, f64)> = points
+            .iter()
+            .map(|(date, value)| ((*date - origin).num_days() as f64, *value))
+            .collect();
+
+        let (intercept, slope) = least_squares(&xy)?;
+        let linear = TrendFit {
+            model: TrendModel::Linear,
+            origin,
+            intercept,
+            slope,
+        };
+
+        let exponential = if xy.iter().all(|(_, y)| *y > 0.0) {
+            let logs: Vec<(f64, f64)> = xy.iter().map(|(x, y)| (*x, y.ln())).collect();
+            least_squares(&logs).map(|(intercept, slope)| TrendFit {
+                model: TrendModel::Exponential,
+                origin,
+                intercept: intercept.exp(),
+                slope,
+            })
+        } else {
+            None
+        };
+
+        let error = |fit: &TrendFit| -> f64 {
+            xy.iter()
+                .map(|(x, y)| (fit.value_at_day(*x) - y).powi(2))
+                .sum()
+        };
+        match exponential {
+            // Exponential only wins when it fits clearly better
+            Some(exp) if error(&exp) < error(&linear) * 0.9 => Some(exp),
+            _ => Some(linear),
+        }
+    }
+
+    fn value_at_day(&self, t: f64) -> f64 {
+        match self.model {
+            TrendModel::Linear => self.intercept + self.slope * t,
+            TrendModel::Exponential => self.intercept * (self.slope * t).exp(),
+        }
+    }
+
+    /// Projected value on `date`
+    pub fn value_at(&self, date: NaiveDate) -> f64 {
+        self.value_at_day((date - self.origin).num_days() as f64)
+    }
+
+    /// Day the trend reaches `target`, or `None` when it is flat, heading
+    /// the wrong way, or too far off
+    pub fn crossing(&self, target: f64, direction: IndicatorDirection) -> Option<NaiveDate> {
+        let rising = match direction {
+            IndicatorDirection::HigherIsBetter => true,
+            IndicatorDirection::LowerIsBetter => false,
+            IndicatorDirection::WithinRange => return None,
+        };
+        if self.slope.abs() <= f64::EPSILON || (self.slope > 0.0) != rising {
+            return None;
+        }
+        let t = match self.model {
+            TrendModel::Linear => (target - self.intercept) / self.slope,
+            TrendModel::Exponential if target > 0.0 => (target / self.intercept).ln() / self.slope,
+            TrendModel::Exponential => return None,
+        };
+        if !t.is_finite() || t > MAX_ETA_DAYS {
+            return None;
+        }
+        // Shave float noise so an exact hit doesn't round up a day
+        let days = (t - 1e-9).max(0.0).ceil() as i64;
+        Some(self.origin + Duration::days(days))
+    }
+}
+
+/// Whether an indicator is on course to hit its target by a due date
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Forecast {
+    pub fit: TrendFit,
+    pub target: f64,
+    pub last_date: NaiveDate,
+    /// Already at or past the target on the latest observation
+    pub reached: bool,
+    pub eta: Option<NaiveDate>,
+    pub due: Option<NaiveDate>,
+}
+
+impl Forecast {
+    /// Forecast from `points` (date, value). `None` for WithinRange
+    /// indicators or fewer than two distinct dates.
+    pub fn new(
+        points: &[(NaiveDate, f64)],
+        target: f64,
+        direction: IndicatorDirection,
+        due: Option<NaiveDate>,
+    ) -> Option<Self> {
+        if direction == IndicatorDirection::WithinRange {
+            return None;
+        }
+        let fit = TrendFit::fit(points)?;
+        let (last_date, last_value) = points.iter().copied().max_by_key(|(date, _)| *date)?;
+        let reached = match direction {
+            IndicatorDirection::LowerIsBetter => last_value <= target,
+            _ => last_value >= target,
+        };
+        let eta = if reached {
+            Some(last_date)
+        } else {
+            // Never earlier than the day after the latest value
+            fit.crossing(target, direction)
+                .map(|date| date.max(last_date + Duration::days(1)))
+        };
+        Some(Self {
+            fit,
+            target,
+            last_date,
+            reached,
+            eta,
+            due,
+        })
+    }
+
+    /// On track when the ETA lands by the due date (or there is no due date)
+    pub fn on_track(&self) -> bool {
+        match (self.eta, self.due) {
+            (Some(eta), Some(due)) => eta <= due,
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+
+    /// Short ETA summary for the chart border
+    pub fn label(&self) -> String {
+        if self.reached {
+            return "Target hit".to_string();
+        }
+        match (self.eta, self.due) {
+            (Some(eta), Some(due)) if eta <= due => {
+                format!("ETA {} · due {}", eta.format("%b %d"), due.format("%b %d"))
+            }
+            (Some(eta), Some(due)) => format!(
+                "ETA {} · {}d late",
+                eta.format("%b %d"),
+                (eta - due).num_days()
+            ),
+            (Some(eta), None) => format!("ETA {}", eta.format("%b %d")),
+            (None, _) => "No ETA at current trend".to_string(),
+        }
+    }
+}
+
+/// Indicators of an active objective with a target date whose trend won't
+/// reach their target by then. Indicators without a target or enough
+/// observations to fit a trend are left out.
+pub fn behind_pace<'a>(
+    objective: &Objective,
+    indicators: &'a [IndicatorDef],
+    observations: &[Observation],
+) -> Vec<&'a IndicatorDef> {
+    if objective.status != ObjectiveStatus::Active || objective.end.is_none() {
+        return Vec::new();
+    }
+    indicators
+        .iter()
+        .filter(|def| {
+            def.active
+                && (objective.indicators.contains(&def.id)
+                    || def.objective_id.as_deref() == Some(objective.id.as_str()))
+        })
+        .filter(|def| {
+            let Some(target) = def.current_target() else {
+                return false;
+            };
+            let points: Vec<(NaiveDate, f64)> = observations
+                .iter()
+                .filter(|obs| obs.indicator_id == def.id)
+                .map(|obs| (obs.when, obs.value))
+                .collect();
+            Forecast::new(&points, target, def.direction.clone(), objective.end)
+                .is_some_and(|forecast| !forecast.reached && !forecast.on_track())
+        })
+        .collect()
+}
@@ -0,0 +1,125 @@
+//! Gratitude log: up to three things captured in the evening, kept as a
+//! "Grateful for" list at the end of the day's reflection file.
+
+use crate::models::Config;
+use anyhow::Result;
+use chrono::{Datelike, NaiveDate};
+
+/// Items asked for each evening
+pub const GRATITUDE_ITEMS: usize = 3;
+
+const HEADING: &str = "## Grateful for";
+
+/// Split a reflection into its text and its gratitude items
+pub fn split(reflection: &str) -> (String, Vec<String>) {
+    let mut lines = reflection.lines();
+    let body: Vec<&str> = lines
+        .by_ref()
+        .take_while(|line| line.trim() != HEADING)
+        .collect();
+    let items = lines
+        .filter_map(|line| line.trim().strip_prefix("- "))
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect();
+    (body.join("\n").trim_end().to_string(), items)
+}
+
+/// The reflection text followed by the gratitude list, if any
+pub fn join(body: &str, items: &[String]) -> String {
+    let items: Vec<&str> = items
+        .iter()
+        .map(|item| item.trim())
+        .filter(|item| !item.is_empty())
+        .collect();
+    if items.is_empty() {
+        return body.trim_end().to_string();
+    }
+    let mut text = body.trim_end().to_string();
+    if !text.is_empty() {
+        text.push_str("\n\n");
+    }
+    text.push_str(HEADING);
+    for item in items {
+        text.push_str("\n- ");
+        text.push_str(item);
+    }
+    text
+}
+
+/// Gratitude items saved for `date`
+pub fn load(date: NaiveDate, config: &Config) -> Result<Vec<String>> {
+    let reflection = crate::data::load_reflection(date, config)?.unwrap_or_default();
+    Ok(split(&reflection).1)
+}
+
+/// Replace the gratitude items of `date`, keeping its reflection text
+pub fn save(date: NaiveDate, items: &[String], config: &Config) -> Result<()> {
+    let reflection = crate::data::load_reflection(date, config)?.unwrap_or_default();
+    let (body, _) = split(&reflection);
+    crate::data::save_reflection(date, &join(&body, items), config)?;
+    Ok(())
+}
+
+/// Consecutive days with gratitude up to `today`; a day not yet logged
+/// today does not break the streak
+pub fn streak(today: NaiveDate, config: &Config) -> Result<u32> {
+    let mut date = today;
+    if load(date, config)?.is_empty() {
+        date = match date.pred_opt() {
+            Some(yesterday) => yesterday,
+            None => return Ok(0),
+        };
+    }
+    let mut days = 0;
+    while !load(date, config)?.is_empty() {
+        days += 1;
+        match date.pred_opt() {
+            Some(previous) => date = previous,
+            None => break,
+        }
+    }
+    Ok(days)
+}
+
+/// A past gratitude item to resurface
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recall {
+    pub date: NaiveDate,
+    pub item: String,
+    /// Same calendar day in an earlier year
+    pub on_this_day: bool,
+}
+
+/// One item from before `today`: from this calendar day in an earlier year
+/// when there is one, else any past day. The pick changes daily.
+pub fn recall(today: NaiveDate, config: &Config) -> Result<Option<Recall>> {
+    let mut past = Vec::new();
+    for date in crate::data::reflection_dates(config)? {
+        if date >= today {
+            continue;
+        }
+        let items = load(date, config)?;
+        if !items.is_empty() {
+            past.push((date, items));
+        }
+    }
+    let same_day: Vec<_> = past
+        .iter()
+        .filter(|(date, _)| date.month() == today.month() && date.day() == today.day())
+        .cloned()
+        .collect();
+    let on_this_day = !same_day.is_empty();
+    let pool = if on_this_day { same_day } else { past };
+    if pool.is_empty() {
+        return Ok(None);
+    }
+
+    let seed = today.num_days_from_ce().unsigned_abs() as usize;
+    let (date, items) = &pool[seed % pool.len()];
+    Ok(Some(Recall {
+        date: *date,
+        item: items[seed / pool.len() % items.len()].clone(),
+        on_this_day,
+    }))
+}
@@ -0,0 +1,395 @@
+//! Import daily fitness totals from Apple Health (`export.xml`) and Google Fit
+//! (Takeout daily metrics CSV) exports and record them as indicator observations.
+
+use crate::models::{Config, IndicatorUnit, IndicatorsData, Observation, ObservationSource};
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, FixedOffset, NaiveDate};
+use regex::Regex;
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+/// Metrics the importer understands. Values are kept in canonical units:
+/// steps as a count, sleep and workouts in minutes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HealthMetric {
+    Steps,
+    SleepMinutes,
+    WorkoutMinutes,
+}
+
+impl HealthMetric {
+    pub fn label(&self) -> &'static str {
+        match self {
+            HealthMetric::Steps => "steps",
+            HealthMetric::SleepMinutes => "sleep",
+            HealthMetric::WorkoutMinutes => "workout minutes",
+        }
+    }
+}
+
+/// One day's total for one metric
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthSample {
+    pub date: NaiveDate,
+    pub metric: HealthMetric,
+    pub value: f64,
+}
+
+/// Which indicator (by id) each metric should be recorded against
+#[derive(Debug, Clone, Default)]
+pub struct HealthMapping {
+    pub steps: Option<String>,
+    pub sleep: Option<String>,
+    pub workout: Option<String>,
+}
+
+impl HealthMapping {
+    fn indicator_for(&self, metric: HealthMetric) -> Option<&str> {
+        match metric {
+            HealthMetric::Steps => self.steps.as_deref(),
+            HealthMetric::SleepMinutes => self.sleep.as_deref(),
+            HealthMetric::WorkoutMinutes => self.workout.as_deref(),
+        }
+    }
+}
+
+/// Outcome of an import run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub imported: usize,
+    pub duplicates: usize,
+    pub unmapped: usize,
+    pub incompatible: usize,
+}
+
+/// Parse an export file, picking the format from its extension
+pub fn parse_export_file(path: &Path) -> Result<Vec<HealthSample>> {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase());
+
+    match extension.as_deref() {
+        Some("xml") => {
+            let file = fs::File::open(path)
+                .with_context(|| format!("Failed to open export: {}", path.display()))?;
+            parse_apple_health_xml(BufReader::new(file))
+        }
+        Some("csv") => {
+            let content = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read export: {}", path.display()))?;
+            parse_google_fit_csv(&content)
+        }
+        _ => bail!(
+            "Unsupported export format: {} (expected .xml or .csv)",
+            path.display()
+        ),
+    }
+}
+
+fn parse_health_timestamp(value: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S %z").ok()
+}
+
+/// Parse Apple Health's `export.xml`, summing records into daily totals.
+///
+/// The export puts one element per line, so it is streamed rather than loaded
+/// whole. Sleep counts only "asleep" stages and is credited to the wake-up day.
+pub fn parse_apple_health_xml<R: BufRead>(reader: R) -> Result<Vec<HealthSample>> {
+    let attr_re = Regex::new(r#"(\w+)="([^"]*)""#).context("Failed to build attribute regex")?;
+    let mut totals: BTreeMap<(NaiveDate, HealthMetric), f64> = BTreeMap::new();
+
+    for line in reader.lines() {
+        let line = line.context("Failed to read Apple Health export")?;
+        let trimmed = line.trim_start();
+        let is_record = trimmed.starts_with("<Record ");
+        let is_workout = trimmed.starts_with("<Workout ");
+        if !is_record && !is_workout {
+            continue;
+        }
+
+        let attrs: BTreeMap<&str, &str> = attr_re
+            .captures_iter(trimmed)
+            .filter_map(|caps| Some((caps.get(1)?.as_str(), caps.get(2)?.as_str())))
+            .collect();
+        let start = attrs
+            .get("startDate")
+            .and_then(|v| parse_health_timestamp(v));
+        let end = attrs.get("endDate").and_then(|v| parse_health_timestamp(v));
+
+        let sample = if is_workout {
+            let minutes = match (attrs.get("duration"), attrs.get("durationUnit")) {
+                (Some(duration), unit) => duration.parse::<f64>().ok().map(|d| match unit {
+                    Some(&"hr") | Some(&"h") => d * 60.0,
+                    Some(&"s") | Some(&"sec") => d / 60.0,
+                    _ => d,
+                }),
+                (None, _) => match (start, end) {
+                    (Some(start), Some(end)) => Some((end - start).num_seconds() as f64 / 60.0),
+                    _ => None,
+                },
+            };
+            start
+                .zip(minutes)
+                .map(|(start, minutes)| (start.date_naive(), HealthMetric::WorkoutMinutes, minutes))
+        } else {
+            match attrs.get("type").copied() {
+                Some("HKQuantityTypeIdentifierStepCount") => {
+                    let steps = attrs.get("value").and_then(|v| v.parse::<f64>().ok());
+                    start
+                        .zip(steps)
+                        .map(|(start, steps)| (start.date_naive(), HealthMetric::Steps, steps))
+                }
+                Some("HKCategoryTypeIdentifierSleepAnalysis") => {
+                    let asleep = attrs.get("value").is_some_and(|v| v.contains("Asleep"));
+                    match (asleep, start, end) {
+                        (true, Some(start), Some(end)) => Some((
+                            end.date_naive(),
+                            HealthMetric::SleepMinutes,
+                            (end - start).num_seconds() as f64 / 60.0,
+                        )),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        };
+
+        if let Some((date, metric, value)) = sample {
+            if value.is_finite() && value > 0.0 {
+                *totals.entry((date, metric)).or_insert(0.0) += value;
+            }
+        }
+    }
+
+    Ok(totals
+        .into_iter()
+        .map(|((date, metric), value)| HealthSample {
+            date,
+            metric,
+            value,
+        })
+        .collect())
+}
+
+/// Parse a Google Fit "Daily activity metrics" CSV.
+///
+/// Columns are matched by header name: `Date`, `Step count`,
+/// `Move Minutes count`, and any sleep column in milliseconds or minutes.
+pub fn parse_google_fit_csv(content: &str) -> Result<Vec<HealthSample>> {
+    let mut lines = content.lines().filter(|line| !line.trim().is_empty());
+    let header = lines.next().context("Google Fit CSV is empty")?;
+    let columns: Vec<String> = header
+        .split(',')
+        .map(|c| c.trim().trim_matches('"').to_ascii_lowercase())
+        .collect();
+
+    let find = |pred: &dyn Fn(&str) -> bool| columns.iter().position(|c| pred(c));
+    let date_col = find(&|c| c == "date").context("Google Fit CSV has no Date column")?;
+    let steps_col = find(&|c| c == "step count");
+    let move_col = find(&|c| c.starts_with("move minutes"));
+    let sleep_col = find(&|c| c.contains("sleep"));
+    let sleep_in_ms = sleep_col.is_some_and(|i| columns[i].contains("(ms)"));
+
+    let mut samples = Vec::new();
+    for (line_no, line) in lines.enumerate() {
+        let cells: Vec<&str> = line
+            .split(',')
+            .map(|c| c.trim().trim_matches('"'))
+            .collect();
+        let date_text = cells.get(date_col).copied().unwrap_or_default();
+        let date = NaiveDate::parse_from_str(date_text, "%Y-%m-%d")
+            .with_context(|| format!("Invalid date '{}' on CSV row {}", date_text, line_no + 2))?;
+
+        let number = |col: Option<usize>| {
+            col.and_then(|i| cells.get(i))
+                .and_then(|cell| cell.parse::<f64>().ok())
+                .filter(|value| value.is_finite() && *value > 0.0)
+        };
+
+        if let Some(steps) = number(steps_col) {
+            samples.push(HealthSample {
+                date,
+                metric: HealthMetric::Steps,
+                value: steps,
+            });
+        }
+        if let Some(minutes) = number(move_col) {
+            samples.push(HealthSample {
+                date,
+                metric: HealthMetric::WorkoutMinutes,
+                value: minutes,
+            });
+        }
+        if let Some(sleep) = number(sleep_col) {
+            samples.push(HealthSample {
+                date,
+                metric: HealthMetric::SleepMinutes,
+                value: if sleep_in_ms { sleep / 60_000.0 } else { sleep },
+            });
+        }
+    }
+
+    Ok(samples)
+}
+
+/// Convert a canonical sample value into the indicator's unit, or `None` when
+/// the units can't be reconciled (e.g. steps into a dollars indicator).
+pub fn convert_value(value: f64, metric: HealthMetric, unit: &IndicatorUnit) -> Option<f64> {
+    match (metric, unit) {
+        (HealthMetric::Steps, IndicatorUnit::Count) => Some(value.round()),
+        (HealthMetric::Steps, IndicatorUnit::Custom(label))
+            if label.eq_ignore_ascii_case("steps") =>
+        {
+            Some(value.round())
+        }
+        (HealthMetric::Steps, _) => None,
+        (_, IndicatorUnit::Minutes) => Some(value.round()),
+        (_, IndicatorUnit::Custom(label))
+            if ["hours", "hour", "hrs", "h"]
+                .iter()
+                .any(|h| label.eq_ignore_ascii_case(h)) =>
+        {
+            Some((value / 60.0 * 100.0).round() / 100.0)
+        }
+        _ => None,
+    }
+}
+
+/// Record samples as `ObservationSource::Import` observations.
+///
+/// A day that already has an imported observation for the target indicator is
+/// treated as a duplicate and skipped, so re-running an import is harmless.
+pub fn import_samples(
+    samples: &[HealthSample],
+    mapping: &HealthMapping,
+    indicators: &IndicatorsData,
+    config: &Config,
+    dry_run: bool,
+) -> Result<ImportSummary> {
+    let mut summary = ImportSummary::default();
+    if samples.is_empty() {
+        return Ok(summary);
+    }
+
+    let first = samples.iter().map(|s| s.date).min().unwrap_or_default();
+    let last = samples.iter().map(|s| s.date).max().unwrap_or_default();
+    let mut seen: HashSet<(String, NaiveDate)> =
+        crate::data::read_observations_range(first, last, config)?
+            .into_iter()
+            .filter(|obs| obs.source == ObservationSource::Import)
+            .map(|obs| (obs.indicator_id, obs.when))
+            .collect();
+
+    for sample in samples {
+        let Some(indicator_id) = mapping.indicator_for(sample.metric) else {
+            summary.unmapped += 1;
+            continue;
+        };
+        let indicator = indicators
+            .indicators
+            .iter()
+            .find(|def| def.id == indicator_id)
+            .with_context(|| format!("Indicator not found: {}", indicator_id))?;
+
+        let Some(value) = convert_value(sample.value, sample.metric, &indicator.unit) else {
+            summary.incompatible += 1;
+            continue;
+        };
+
+        if !seen.insert((indicator.id.clone(), sample.date)) {
+            summary.duplicates += 1;
+            continue;
+        }
+
+        if !dry_run {
+            let mut obs = Observation::new(
+                indicator.id.clone(),
+                sample.date,
+                value,
+                indicator.unit.clone(),
+            );
+            obs.source = ObservationSource::Import;
+            obs.note = Some(format!("Imported {}", sample.metric.label()));
+            crate::data::append_observation(&obs, config)?;
+            crate::data::sync_ratio_indicators(&obs, indicators, config)?;
+        }
+        summary.imported += 1;
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apple_health_records_are_summed_per_day() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<HealthData locale="en_US">
+ <Record type="HKQuantityTypeIdentifierStepCount" unit="count" startDate="2025-03-01 08:00:00 -0800" endDate="2025-03-01 08:10:00 -0800" value="1200"/>
+ <Record type="HKQuantityTypeIdentifierStepCount" unit="count" startDate="2025-03-01 18:00:00 -0800" endDate="2025-03-01 18:30:00 -0800" value="3300"/>
+ <Record type="HKCategoryTypeIdentifierSleepAnalysis" startDate="2025-03-01 23:00:00 -0800" endDate="2025-03-02 03:00:00 -0800" value="HKCategoryValueSleepAnalysisAsleepCore"/>
+ <Record type="HKCategoryTypeIdentifierSleepAnalysis" startDate="2025-03-02 03:00:00 -0800" endDate="2025-03-02 03:30:00 -0800" value="HKCategoryValueSleepAnalysisAwake"/>
+ <Workout workoutActivityType="HKWorkoutActivityTypeRunning" duration="0.5" durationUnit="hr" startDate="2025-03-02 07:00:00 -0800" endDate="2025-03-02 07:30:00 -0800"/>
+</HealthData>
+"#;
+        let samples = parse_apple_health_xml(xml.as_bytes()).unwrap();
+        let day = |d| NaiveDate::from_ymd_opt(2025, 3, d).unwrap();
+
+        assert_eq!(
+            samples,
+            vec![
+                HealthSample {
+                    date: day(1),
+                    metric: HealthMetric::Steps,
+                    value: 4500.0
+                },
+                HealthSample {
+                    date: day(2),
+                    metric: HealthMetric::SleepMinutes,
+                    value: 240.0
+                },
+                HealthSample {
+                    date: day(2),
+                    metric: HealthMetric::WorkoutMinutes,
+                    value: 30.0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn google_fit_csv_maps_known_columns() {
+        let csv = "Date,Move Minutes count,Calories (kcal),Step count,Sleep duration (ms)\n\
+                   2025-03-01,45,2100.5,8123,27000000\n\
+                   2025-03-02,,1900,,\n";
+        let samples = parse_google_fit_csv(csv).unwrap();
+
+        assert_eq!(samples.len(), 3);
+        assert_eq!(samples[0].metric, HealthMetric::Steps);
+        assert_eq!(samples[0].value, 8123.0);
+        assert_eq!(samples[1].metric, HealthMetric::WorkoutMinutes);
+        assert_eq!(samples[2].value, 450.0);
+    }
+
+    #[test]
+    fn values_convert_into_indicator_units() {
+        let hours = IndicatorUnit::Custom("hours".to_string());
+        assert_eq!(
+            convert_value(450.0, HealthMetric::SleepMinutes, &hours),
+            Some(7.5)
+        );
+        assert_eq!(
+            convert_value(45.4, HealthMetric::WorkoutMinutes, &IndicatorUnit::Minutes),
+            Some(45.0)
+        );
+        assert_eq!(
+            convert_value(100.0, HealthMetric::Steps, &IndicatorUnit::Dollars),
+            None
+        );
+    }
+}
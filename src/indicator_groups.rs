@@ -0,0 +1,89 @@
+//! Indicator groups such as Fitness, Finances or Output, and which groups
+//! each dashboard panel shows. Groups live on the indicators; the panel
+//! layout is kept in settings.json.
+
+use crate::models::{Config, DashboardGroups, IndicatorPanel, IndicatorsData};
+use anyhow::{bail, Result};
+
+/// Put the indicator found by id or name in `group`, or in no group with
+/// `None`; returns what happened
+pub fn set_group(
+    indicators: &mut IndicatorsData,
+    indicator: &str,
+    group: Option<&str>,
+) -> Result<String> {
+    let group = group.map(str::trim).filter(|group| !group.is_empty());
+    let Some(id) = indicators.find(indicator).map(|def| def.id.clone()) else {
+        bail!("No indicator named '{}'", indicator);
+    };
+    // Reuse the spelling of an existing group so "fitness" joins "Fitness"
+    let group = group.map(|group| {
+        indicators
+            .indicators
+            .iter()
+            .filter_map(|def| def.group.as_deref())
+            .find(|existing| existing.eq_ignore_ascii_case(group))
+            .unwrap_or(group)
+            .to_string()
+    });
+    let Some(def) = indicators.indicators.iter_mut().find(|def| def.id == id) else {
+        bail!("No indicator named '{}'", indicator);
+    };
+    def.group = group;
+    def.modified = chrono::Utc::now();
+    Ok(match &def.group {
+        Some(group) => format!("{} is now in {}", def.name, group),
+        None => format!("{} is no longer in a group", def.name),
+    })
+}
+
+/// Names of the groups in use, in order of first appearance
+pub fn group_names(indicators: &IndicatorsData) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for group in indicators
+        .indicators
+        .iter()
+        .filter_map(|def| def.group.as_ref())
+    {
+        if !names.iter().any(|name| name.eq_ignore_ascii_case(group)) {
+            names.push(group.clone());
+        }
+    }
+    names
+}
+
+/// Show only `groups` in `panel`, or everything when empty, and remember it
+/// in settings.json; returns the new layout
+pub fn save_panel_groups(
+    panel: IndicatorPanel,
+    groups: &[String],
+    config: &Config,
+) -> Result<DashboardGroups> {
+    let mut settings = crate::data::load_settings(config)?;
+    *settings.dashboard.groups_mut(panel) = groups.to_vec();
+    crate::data::save_settings(&settings, config)?;
+    Ok(settings.dashboard)
+}
+
+/// "performance shows Fitness, Output", noting groups no indicator is in
+pub fn describe_panel(
+    panel: IndicatorPanel,
+    layout: &DashboardGroups,
+    indicators: &IndicatorsData,
+) -> String {
+    let groups = layout.groups(panel);
+    if groups.is_empty() {
+        return format!("{} shows all indicators", panel.as_str());
+    }
+    let known = group_names(indicators);
+    let unknown: Vec<&str> = groups
+        .iter()
+        .filter(|group| !known.iter().any(|name| name.eq_ignore_ascii_case(group)))
+        .map(String::as_str)
+        .collect();
+    let mut message = format!("{} shows {}", panel.as_str(), groups.join(", "));
+    if !unknown.is_empty() {
+        message.push_str(&format!(" (no indicators in {} yet)", unknown.join(", ")));
+    }
+    message
+}
@@ -0,0 +1,84 @@
+//! Reflections as a journal: every saved evening reflection with its word
+//! count, newest first, and a case-insensitive search over them.
+
+use crate::models::Config;
+use anyhow::Result;
+use chrono::NaiveDate;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct JournalEntry {
+    pub date: NaiveDate,
+    pub text: String,
+    pub words: usize,
+}
+
+impl JournalEntry {
+    pub fn new(date: NaiveDate, text: String) -> Self {
+        Self {
+            date,
+            words: word_count(&text),
+            text,
+        }
+    }
+
+    /// Whether every word of `query` appears in the text or the date
+    pub fn matches(&self, query: &str) -> bool {
+        let text = self.text.to_lowercase();
+        let date = self
+            .date
+            .format("%Y-%m-%d %A %B")
+            .to_string()
+            .to_lowercase();
+        query
+            .to_lowercase()
+            .split_whitespace()
+            .all(|word| text.contains(word) || date.contains(word))
+    }
+
+    /// First line mentioning the query, or the first non-empty line
+    pub fn snippet(&self, query: &str) -> &str {
+        let words: Vec<String> = query
+            .to_lowercase()
+            .split_whitespace()
+            .map(str::to_string)
+            .collect();
+        let mut lines = self
+            .text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty());
+        lines
+            .clone()
+            .find(|line| {
+                let line = line.to_lowercase();
+                !words.is_empty() && words.iter().any(|word| line.contains(word))
+            })
+            .or_else(|| lines.next())
+            .unwrap_or("")
+    }
+}
+
+pub fn word_count(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+/// All saved reflections, newest first
+pub fn load_entries(config: &Config) -> Result<Vec<JournalEntry>> {
+    let mut entries = Vec::new();
+    for date in crate::data::reflection_dates(config)?.into_iter().rev() {
+        if let Some(text) = crate::data::load_reflection(date, config)? {
+            entries.push(JournalEntry::new(date, text));
+        }
+    }
+    Ok(entries)
+}
+
+/// Indices of the entries matching `query`; all of them when it is blank
+pub fn search(entries: &[JournalEntry], query: &str) -> Vec<usize> {
+    entries
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| entry.matches(query))
+        .map(|(i, _)| i)
+        .collect()
+}
@@ -0,0 +1,341 @@
+//! Import a directory of existing daily notes (markdown checklists and bullet
+//! lists) as FocusFive goals files, so new users keep their history.
+//!
+//! Each note's date comes from a `YYYY-MM-DD` in its file name. Items are
+//! grouped under the nearest heading above them, and each distinct heading is
+//! mapped to an outcome (or skipped) before anything is written. Days that
+//! already have a goals file are left alone.
+
+use crate::models::{Action, Config, DailyGoals, Outcome, OutcomeType};
+use anyhow::{bail, Context, Result};
+use chrono::NaiveDate;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+
+/// Most actions an outcome can hold; later items are dropped
+const MAX_IMPORTED_ACTIONS: usize = 5;
+/// Outcomes are padded to the usual three action slots
+const MIN_ACTIONS: usize = 3;
+
+/// A checklist or bullet item
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalItem {
+    pub text: String,
+    pub done: bool,
+}
+
+/// Items under one heading; items above the first heading have an empty one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalSection {
+    pub heading: String,
+    pub items: Vec<JournalItem>,
+}
+
+/// One day's note
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournalNote {
+    pub date: NaiveDate,
+    pub path: PathBuf,
+    pub sections: Vec<JournalSection>,
+}
+
+/// Heading (lowercased, trimmed) to the outcome its items go under;
+/// headings missing from the map are skipped
+pub type HeadingMap = HashMap<String, OutcomeType>;
+
+/// Key a heading is looked up by in a `HeadingMap`
+pub fn heading_key(heading: &str) -> String {
+    heading.trim().to_lowercase()
+}
+
+/// Split a note into headed sections of checklist/bullet items
+pub fn parse_note(content: &str) -> Vec<JournalSection> {
+    let item = Regex::new(r"^\s*[-*+]\s+(?:\[([ xX])\]\s*)?(.+?)\s*$").unwrap();
+    let mut sections = vec![JournalSection {
+        heading: String::new(),
+        items: Vec::new(),
+    }];
+
+    for line in content.lines() {
+        if let Some(heading) = line.trim_start().strip_prefix('#') {
+            sections.push(JournalSection {
+                heading: heading.trim_start_matches('#').trim().to_string(),
+                items: Vec::new(),
+            });
+        } else if let Some(caps) = item.captures(line) {
+            let done = caps.get(1).is_some_and(|mark| mark.as_str() != " ");
+            if let Some(section) = sections.last_mut() {
+                section.items.push(JournalItem {
+                    text: caps[2].to_string(),
+                    done,
+                });
+            }
+        }
+    }
+
+    sections.retain(|section| !section.items.is_empty());
+    sections
+}
+
+/// Date from a `YYYY-MM-DD` anywhere in the file name
+pub fn note_date(path: &Path) -> Option<NaiveDate> {
+    let pattern = Regex::new(r"(\d{4})-(\d{2})-(\d{2})").unwrap();
+    let name = path.file_stem()?.to_str()?;
+    let caps = pattern.captures(name)?;
+    NaiveDate::from_ymd_opt(
+        caps[1].parse().ok()?,
+        caps[2].parse().ok()?,
+        caps[3].parse().ok()?,
+    )
+}
+
+fn collect_markdown(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    let entries = fs::read_dir(dir)
+        .with_context(|| format!("Failed to read directory: {}", dir.display()))?;
+    for entry in entries {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_markdown(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "md") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Notes found under `dir`, oldest first, plus markdown files without a date
+/// in their name
+pub fn scan_journal_dir(dir: &Path) -> Result<(Vec<JournalNote>, Vec<PathBuf>)> {
+    let mut files = Vec::new();
+    collect_markdown(dir, &mut files)?;
+    files.sort();
+
+    let mut notes = Vec::new();
+    let mut undated = Vec::new();
+    for path in files {
+        let Some(date) = note_date(&path) else {
+            undated.push(path);
+            continue;
+        };
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read note: {}", path.display()))?;
+        notes.push(JournalNote {
+            date,
+            path,
+            sections: parse_note(&content),
+        });
+    }
+    notes.sort_by_key(|note| note.date);
+    Ok((notes, undated))
+}
+
+/// Distinct headings across `notes` with how many notes use each, in the
+/// order they first appear
+pub fn distinct_headings(notes: &[JournalNote]) -> Vec<(String, usize)> {
+    let mut headings: Vec<(String, usize)> = Vec::new();
+    for note in notes {
+        let mut seen = Vec::new();
+        for section in &note.sections {
+            let key = heading_key(&section.heading);
+            if seen.contains(&key) {
+                continue;
+            }
+            match headings
+                .iter_mut()
+                .find(|(heading, _)| heading_key(heading) == key)
+            {
+                Some((_, count)) => *count += 1,
+                None => headings.push((section.heading.clone(), 1)),
+            }
+            seen.push(key);
+        }
+    }
+    headings
+}
+
+const WORK_WORDS: &[&str] = &["work", "job", "office", "career", "project", "business"];
+const HEALTH_WORDS: &[&str] = &["health", "fitness", "exercise", "workout", "gym", "sleep"];
+const FAMILY_WORDS: &[&str] = &["family", "home", "kids", "friends", "personal", "household"];
+
+/// Outcome a heading most likely belongs to, from common words
+pub fn suggest_outcome(heading: &str) -> Option<OutcomeType> {
+    let heading = heading_key(heading);
+    [
+        (OutcomeType::Work, WORK_WORDS),
+        (OutcomeType::Health, HEALTH_WORDS),
+        (OutcomeType::Family, FAMILY_WORDS),
+    ]
+    .into_iter()
+    .find(|(_, words)| words.iter().any(|word| heading.contains(word)))
+    .map(|(outcome, _)| outcome)
+}
+
+/// Parse a `--map` value or a prompt answer: work/health/family or skip
+/// (first letters are enough). `Ok(None)` means skip.
+pub fn parse_outcome_choice(choice: &str) -> Result<Option<OutcomeType>> {
+    match choice.trim().to_lowercase().as_str() {
+        "w" | "work" => Ok(Some(OutcomeType::Work)),
+        "h" | "health" => Ok(Some(OutcomeType::Health)),
+        "f" | "family" => Ok(Some(OutcomeType::Family)),
+        "s" | "skip" => Ok(None),
+        other => bail!("Expected work, health, family or skip, got '{}'", other),
+    }
+}
+
+/// Ask for an outcome for each heading not already in `mapping` or
+/// `skipped`. An empty answer takes the suggested outcome (or skips when
+/// there is none).
+pub fn prompt_mapping<R: BufRead, W: Write>(
+    headings: &[(String, usize)],
+    mapping: HeadingMap,
+    skipped: &[String],
+    input: R,
+    output: W,
+) -> Result<HeadingMap> {
+    prompt_mapping_of("notes", headings, mapping, skipped, input, output)
+}
+
+/// `prompt_mapping` over groups counted in `noun`, e.g. the projects of a
+/// task export counted in tasks
+pub fn prompt_mapping_of<R: BufRead, W: Write>(
+    noun: &str,
+    headings: &[(String, usize)],
+    mut mapping: HeadingMap,
+    skipped: &[String],
+    mut input: R,
+    mut output: W,
+) -> Result<HeadingMap> {
+    for (heading, count) in headings {
+        let key = heading_key(heading);
+        if mapping.contains_key(&key) || skipped.contains(&key) {
+            continue;
+        }
+        let suggestion = suggest_outcome(heading);
+        let default = suggestion
+            .as_ref()
+            .map_or("skip", |outcome| outcome.as_str());
+        let label = if heading.is_empty() {
+            "(no heading)"
+        } else {
+            heading
+        };
+
+        loop {
+            write!(
+                output,
+                "{} ({} {}) -> [w]ork, [h]ealth, [f]amily, [s]kip [{}]: ",
+                label, count, noun, default
+            )?;
+            output.flush()?;
+
+            let mut answer = String::new();
+            if input.read_line(&mut answer)? == 0 {
+                bail!("Input ended before every heading was mapped");
+            }
+            let choice = if answer.trim().is_empty() {
+                Ok(suggestion)
+            } else {
+                parse_outcome_choice(&answer)
+            };
+            match choice {
+                Ok(Some(outcome)) => {
+                    mapping.insert(key, outcome);
+                    break;
+                }
+                Ok(None) => break,
+                Err(e) => writeln!(output, "{}", e)?,
+            }
+        }
+    }
+    Ok(mapping)
+}
+
+fn outcome_mut(goals: &mut DailyGoals, outcome_type: OutcomeType) -> &mut Outcome {
+    match outcome_type {
+        OutcomeType::Work => &mut goals.work,
+        OutcomeType::Health => &mut goals.health,
+        OutcomeType::Family => &mut goals.family,
+    }
+}
+
+/// Goals for a note under `mapping`, and how many items didn't fit.
+/// `None` when no item maps to an outcome.
+pub fn note_to_goals(note: &JournalNote, mapping: &HeadingMap) -> Option<(DailyGoals, usize)> {
+    let mut goals = DailyGoals::new(note.date);
+    for outcome in goals.outcomes_mut() {
+        outcome.actions.clear();
+    }
+
+    let mut dropped = 0;
+    for section in &note.sections {
+        let Some(outcome_type) = mapping.get(&heading_key(&section.heading)) else {
+            continue;
+        };
+        let outcome = outcome_mut(&mut goals, *outcome_type);
+        for item in &section.items {
+            if outcome.actions.len() < MAX_IMPORTED_ACTIONS {
+                outcome
+                    .actions
+                    .push(Action::from_markdown(item.text.clone(), item.done));
+            } else {
+                dropped += 1;
+            }
+        }
+    }
+
+    if goals
+        .outcomes()
+        .iter()
+        .all(|outcome| outcome.actions.is_empty())
+    {
+        return None;
+    }
+    for outcome in goals.outcomes_mut() {
+        while outcome.actions.len() < MIN_ACTIONS {
+            outcome.actions.push(Action::new(String::new()));
+        }
+    }
+    Some((goals, dropped))
+}
+
+/// Outcome of an import run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JournalImportSummary {
+    pub imported: usize,
+    /// Days that already had a goals file
+    pub existing: usize,
+    /// Notes with nothing under a mapped heading
+    pub empty: usize,
+    /// Items beyond five per outcome that were left out
+    pub dropped_items: usize,
+}
+
+/// Write goals files for `notes`; with `dry_run` only count what would happen
+pub fn import_notes(
+    notes: &[JournalNote],
+    mapping: &HeadingMap,
+    config: &Config,
+    dry_run: bool,
+) -> Result<JournalImportSummary> {
+    let mut summary = JournalImportSummary::default();
+    for note in notes {
+        if crate::data::goals_file_path(note.date, config).exists() {
+            summary.existing += 1;
+            continue;
+        }
+        let Some((goals, dropped)) = note_to_goals(note, mapping) else {
+            summary.empty += 1;
+            continue;
+        };
+        if !dry_run {
+            crate::data::write_goals_file(&goals, config)
+                .with_context(|| format!("Failed to import note: {}", note.path.display()))?;
+        }
+        summary.imported += 1;
+        summary.dropped_items += dropped;
+    }
+    Ok(summary)
+}
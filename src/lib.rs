@@ -1,7 +1,58 @@
+pub mod aggregation;
 pub mod app;
+pub mod automation;
+pub mod backups;
+pub mod board;
+pub mod capacity;
+pub mod carryover;
+pub mod changelog;
+pub mod checkin;
+pub mod clipboard;
+pub mod closeout;
+pub mod command;
+pub mod completion_times;
 pub mod data;
 pub mod data_capture;
+pub mod dependencies;
+pub mod digest;
+pub mod doctor;
+pub mod events;
+pub mod export;
+pub mod external_editor;
+pub mod forecast;
+pub mod gratitude;
+pub mod health_import;
+pub mod indicator_groups;
+pub mod journal;
+pub mod journal_import;
+pub mod loader;
+pub mod mcp;
 pub mod models;
+pub mod morning;
+pub mod obsidian;
+pub mod onboarding;
+pub mod packs;
+pub mod portfolio;
+pub mod priority;
+pub mod projects;
+pub mod quarterly;
+pub mod recovery;
+pub mod retarget;
+pub mod scoreboard;
+pub mod sentiment;
+pub mod summary;
+pub mod sync;
+pub mod targets;
+pub mod task_import;
+pub mod templates;
+pub mod test_support;
+pub mod timeline;
+pub mod tracking;
+pub mod trash;
 pub mod ui;
 pub mod ui_state;
+pub mod units;
+pub mod vision_history;
+pub mod webdav;
 pub mod widgets;
+pub mod year_review;
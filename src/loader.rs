@@ -0,0 +1,233 @@
+//! Background loading for the dashboard and the stats panel history.
+//!
+//! Reading weeks of observations and re-scanning past goals files gets slow
+//! with years of data, so it happens on a worker thread instead of during
+//! rendering. The app queues requests and picks up results from `tick`;
+//! panels show a spinner until their first result arrives.
+
+use crate::models::{Config, IndicatorUnit, Observation, ObservationSource, FOCUS_INDICATOR_ID};
+use crate::ui::stats::StatsHistory;
+use chrono::{Datelike, Duration, NaiveDate};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::JoinHandle;
+use std::time::Instant;
+
+/// Days of observations the Signals panel compares
+pub const SIGNAL_DAYS: i64 = 30;
+/// Days shown by the Live Metrics and Performance panels
+pub const PERFORMANCE_DAYS: i64 = 7;
+
+const SPINNER_FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+/// Spinner glyph for the current moment; advances every 100ms
+pub fn spinner_frame() -> char {
+    let millis = chrono::Local::now().timestamp_subsec_millis() as usize;
+    SPINNER_FRAMES[millis / 100 % SPINNER_FRAMES.len()]
+}
+
+/// Everything the dashboard panels chart, read in one pass
+#[derive(Debug, Clone, Default)]
+pub struct DashboardData {
+    pub date: NaiveDate,
+    /// Observations from the start of the month (or `SIGNAL_DAYS` back,
+    /// whichever is earlier) through `date`
+    pub observations: Vec<Observation>,
+    /// Focus minutes as one observation per day for the last `PERFORMANCE_DAYS`
+    pub focus_daily: Vec<Observation>,
+}
+
+impl DashboardData {
+    pub fn load(date: NaiveDate, config: &Config) -> Self {
+        let month_start = date.with_day(1).unwrap_or(date);
+        let start = month_start.min(date - Duration::days(SIGNAL_DAYS));
+        Self {
+            date,
+            observations: crate::data::read_observations_range(start, date, config)
+                .unwrap_or_default(),
+            focus_daily: focus_daily_observations(date, PERFORMANCE_DAYS, config),
+        }
+    }
+
+    /// Observations dated `start` or later
+    pub fn since(&self, start: NaiveDate) -> Vec<Observation> {
+        self.observations
+            .iter()
+            .filter(|obs| obs.when >= start)
+            .cloned()
+            .collect()
+    }
+}
+
+/// One synthetic observation per day holding that day's focused minutes,
+/// for the `days` days ending on `end`
+pub fn focus_daily_observations(end: NaiveDate, days: i64, config: &Config) -> Vec<Observation> {
+    let start = end - Duration::days(days - 1);
+    crate::data::focus_minutes_by_day(start, end, config)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(day, minutes)| {
+            let mut obs = Observation::new(
+                FOCUS_INDICATOR_ID.to_string(),
+                day,
+                minutes,
+                IndicatorUnit::Minutes,
+            );
+            obs.source = ObservationSource::Automated;
+            obs
+        })
+        .collect()
+}
+
+/// A finished load
+#[derive(Debug, Clone)]
+pub enum Loaded {
+    Dashboard(DashboardData),
+    History(Box<StatsHistory>),
+}
+
+enum Request {
+    Dashboard(u64, NaiveDate),
+    History(u64, NaiveDate),
+}
+
+/// Runs loads on a worker thread. Only the newest request of each kind
+/// matters: queued duplicates are skipped and superseded results dropped.
+pub struct BackgroundLoader {
+    requests: Option<Sender<Request>>,
+    results: Receiver<(u64, Loaded)>,
+    worker: Option<JoinHandle<()>>,
+    next_generation: u64,
+    dashboard_pending: Option<u64>,
+    history_pending: Option<u64>,
+}
+
+impl BackgroundLoader {
+    pub fn start(config: Config) -> Self {
+        let (request_tx, request_rx) = mpsc::channel::<Request>();
+        let (result_tx, result_rx) = mpsc::channel();
+        let worker = std::thread::spawn(move || {
+            while let Ok(first) = request_rx.recv() {
+                let mut dashboard = None;
+                let mut history = None;
+                for request in std::iter::once(first).chain(request_rx.try_iter()) {
+                    match request {
+                        Request::Dashboard(generation, date) => {
+                            dashboard = Some((generation, date))
+                        }
+                        Request::History(generation, date) => history = Some((generation, date)),
+                    }
+                }
+
+                let mut results = Vec::new();
+                if let Some((generation, date)) = dashboard {
+                    results.push((
+                        generation,
+                        Loaded::Dashboard(DashboardData::load(date, &config)),
+                    ));
+                }
+                if let Some((generation, date)) = history {
+                    results.push((
+                        generation,
+                        Loaded::History(Box::new(StatsHistory::load(date, &config))),
+                    ));
+                }
+                // The app has gone away; nothing left to load for
+                if results
+                    .into_iter()
+                    .any(|result| result_tx.send(result).is_err())
+                {
+                    break;
+                }
+            }
+        });
+
+        BackgroundLoader {
+            requests: Some(request_tx),
+            results: result_rx,
+            worker: Some(worker),
+            next_generation: 0,
+            dashboard_pending: None,
+            history_pending: None,
+        }
+    }
+
+    fn next_generation(&mut self) -> u64 {
+        self.next_generation += 1;
+        self.next_generation
+    }
+
+    fn send(&self, request: Request) {
+        if let Some(requests) = &self.requests {
+            let _ = requests.send(request);
+        }
+    }
+
+    /// Queue a dashboard load for the days ending on `date`
+    pub fn request_dashboard(&mut self, date: NaiveDate) {
+        let generation = self.next_generation();
+        self.dashboard_pending = Some(generation);
+        self.send(Request::Dashboard(generation, date));
+    }
+
+    /// Queue a statistics history scan ending on `date`
+    pub fn request_history(&mut self, date: NaiveDate) {
+        let generation = self.next_generation();
+        self.history_pending = Some(generation);
+        self.send(Request::History(generation, date));
+    }
+
+    pub fn dashboard_loading(&self) -> bool {
+        self.dashboard_pending.is_some()
+    }
+
+    pub fn history_loading(&self) -> bool {
+        self.history_pending.is_some()
+    }
+
+    /// Keep `result` only if it answers the newest request of its kind
+    fn accept(&mut self, generation: u64, result: Loaded) -> Option<Loaded> {
+        let pending = match result {
+            Loaded::Dashboard(_) => &mut self.dashboard_pending,
+            Loaded::History(_) => &mut self.history_pending,
+        };
+        if *pending != Some(generation) {
+            return None;
+        }
+        *pending = None;
+        Some(result)
+    }
+
+    /// Next finished load, without blocking
+    pub fn try_next(&mut self) -> Option<Loaded> {
+        while let Ok((generation, result)) = self.results.try_recv() {
+            if let Some(result) = self.accept(generation, result) {
+                return Some(result);
+            }
+        }
+        None
+    }
+
+    /// Block until every pending load has finished or `timeout` passes
+    pub fn wait(&mut self, timeout: std::time::Duration) -> Vec<Loaded> {
+        let deadline = Instant::now() + timeout;
+        let mut loaded = Vec::new();
+        while self.dashboard_loading() || self.history_loading() {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match self.results.recv_timeout(remaining) {
+                Ok((generation, result)) => loaded.extend(self.accept(generation, result)),
+                Err(_) => break,
+            }
+        }
+        loaded
+    }
+}
+
+impl Drop for BackgroundLoader {
+    fn drop(&mut self) {
+        // Closing the request channel ends the worker after its current load
+        self.requests.take();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
@@ -1,8 +1,59 @@
+mod aggregation;
+mod automation;
+mod backups;
+mod board;
+mod capacity;
+mod carryover;
+mod changelog;
+mod checkin;
+mod cli;
+mod clipboard;
+mod closeout;
+mod command;
+mod completion_times;
 mod data;
+mod dependencies;
+mod digest;
+mod doctor;
+mod events;
+mod export;
+mod external_editor;
+mod forecast;
+mod gratitude;
+mod health_import;
+mod indicator_groups;
+mod journal;
+mod journal_import;
+mod loader;
+mod mcp;
 mod models;
+mod morning;
+mod obsidian;
+mod onboarding;
+mod packs;
+mod portfolio;
+mod priority;
+mod projects;
+mod quarterly;
+mod recovery;
+mod retarget;
+mod scoreboard;
+mod sentiment;
+mod summary;
+mod sync;
+mod targets;
+mod task_import;
+mod templates;
+mod timeline;
+mod tracking;
+mod trash;
 mod ui;
 mod ui_state;
+mod units;
+mod vision_history;
+mod webdav;
 mod widgets;
+mod year_review;
 
 use ui::{init_terminal, restore_terminal, run_app, App};
 
@@ -14,8 +65,32 @@ fn main() -> anyhow::Result<()> {
             data_root: "./FocusFive".to_string(),
         }
     });
+    // A data directory picked during setup wins over the default
+    let config = onboarding::location_file()
+        .and_then(|file| onboarding::saved_location(&file))
+        .map(|root| models::Config::at(&root))
+        .unwrap_or(config);
+    // An Obsidian vault can stand in for the goals directory
+    let config = obsidian::with_vault(config);
 
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        return cli::run(&args, &config);
+    }
+
+    let first_run = onboarding::is_first_run(&config);
     let mut terminal = init_terminal()?;
+    let config = if first_run {
+        match ui::first_run::run(&mut terminal, config.clone()) {
+            Ok(config) => config,
+            Err(e) => {
+                restore_terminal(&mut terminal)?;
+                return Err(e);
+            }
+        }
+    } else {
+        config
+    };
     let app = App::new(config)?;
 
     let result = run_app(&mut terminal, app);
@@ -0,0 +1,315 @@
+//! Model Context Protocol server (`focusfive mcp`).
+//!
+//! Speaks JSON-RPC 2.0 over stdin/stdout, one message per line, so a locally
+//! launched assistant can read the day's plan, append actions and log
+//! observations. Every tool goes through `crate::data`, so files stay in the
+//! exact format the TUI reads and writes. Nothing listens on the network.
+
+use crate::data;
+use crate::models::{Config, Observation, ObservationSource, OutcomeType};
+use anyhow::{bail, Context, Result};
+use chrono::NaiveDate;
+use serde_json::{json, Value};
+use std::io::{BufRead, Write};
+
+pub const PROTOCOL_VERSION: &str = "2024-11-05";
+
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+
+/// Serve requests from `input` until it closes
+pub fn serve<R: BufRead, W: Write>(input: R, mut output: W, config: &Config) -> Result<()> {
+    for line in input.lines() {
+        let line = line.context("Failed to read MCP request")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match serde_json::from_str::<Value>(&line) {
+            Ok(message) => handle_message(&message, config),
+            Err(e) => Some(error_response(
+                Value::Null,
+                PARSE_ERROR,
+                &format!("Parse error: {}", e),
+            )),
+        };
+
+        if let Some(response) = response {
+            writeln!(output, "{}", response).context("Failed to write MCP response")?;
+            output.flush().context("Failed to flush MCP response")?;
+        }
+    }
+    Ok(())
+}
+
+/// Handle one JSON-RPC message. Notifications (no `id`) get no response.
+pub fn handle_message(message: &Value, config: &Config) -> Option<Value> {
+    let id = message.get("id")?.clone();
+    let method = message.get("method").and_then(Value::as_str).unwrap_or("");
+
+    let result = match method {
+        "initialize" => json!({
+            "protocolVersion": PROTOCOL_VERSION,
+            "capabilities": { "tools": {} },
+            "serverInfo": { "name": "focusfive", "version": env!("CARGO_PKG_VERSION") },
+        }),
+        "ping" => json!({}),
+        "tools/list" => json!({ "tools": tool_definitions() }),
+        "tools/call" => call_tool(message.get("params").unwrap_or(&Value::Null), config),
+        other => {
+            return Some(error_response(
+                id,
+                METHOD_NOT_FOUND,
+                &format!("Unknown method: {}", other),
+            ))
+        }
+    };
+
+    Some(json!({ "jsonrpc": "2.0", "id": id, "result": result }))
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message },
+    })
+}
+
+fn tool_definitions() -> Value {
+    let date = json!({ "type": "string", "description": "YYYY-MM-DD, defaults to today" });
+    json!([
+        {
+            "name": "get_plan",
+            "description": "Read the outcomes, goals and actions planned for a day",
+            "inputSchema": {
+                "type": "object",
+                "properties": { "date": date },
+            },
+        },
+        {
+            "name": "append_action",
+            "description": "Add an action to one outcome (at most 5 per outcome)",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "outcome": { "type": "string", "enum": ["work", "health", "family"] },
+                    "text": { "type": "string" },
+                    "date": date,
+                },
+                "required": ["outcome", "text"],
+            },
+        },
+        {
+            "name": "list_objectives",
+            "description": "List objectives, optionally for one outcome",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "outcome": { "type": "string", "enum": ["work", "health", "family"] },
+                },
+            },
+        },
+        {
+            "name": "list_indicators",
+            "description": "List indicators with their units and targets",
+            "inputSchema": { "type": "object", "properties": {} },
+        },
+        {
+            "name": "log_observation",
+            "description": "Record a value for an indicator (by id or name)",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "indicator": { "type": "string" },
+                    "value": { "type": "number" },
+                    "date": date,
+                    "note": { "type": "string" },
+                },
+                "required": ["indicator", "value"],
+            },
+        },
+    ])
+}
+
+/// Run a tool; failures are reported in-band with `isError` as MCP expects
+fn call_tool(params: &Value, config: &Config) -> Value {
+    let name = params.get("name").and_then(Value::as_str).unwrap_or("");
+    let args = params.get("arguments").cloned().unwrap_or(json!({}));
+
+    let outcome = match name {
+        "get_plan" => get_plan(&args, config),
+        "append_action" => append_action(&args, config),
+        "list_objectives" => list_objectives(&args, config),
+        "list_indicators" => list_indicators(config),
+        "log_observation" => log_observation(&args, config),
+        other => Err(anyhow::anyhow!("Unknown tool: {}", other)),
+    };
+
+    match outcome {
+        Ok(text) => json!({ "content": [{ "type": "text", "text": text }], "isError": false }),
+        Err(e) => json!({
+            "content": [{ "type": "text", "text": format!("{:#}", e) }],
+            "isError": true,
+        }),
+    }
+}
+
+fn str_arg<'a>(args: &'a Value, key: &str) -> Result<&'a str> {
+    args.get(key)
+        .and_then(Value::as_str)
+        .with_context(|| format!("Missing string argument '{}'", key))
+}
+
+fn date_arg(args: &Value) -> Result<NaiveDate> {
+    match args.get("date").and_then(Value::as_str) {
+        Some(date) => NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .with_context(|| format!("Invalid date '{}', expected YYYY-MM-DD", date)),
+        None => Ok(chrono::Local::now().date_naive()),
+    }
+}
+
+fn outcome_arg(value: &str) -> Result<OutcomeType> {
+    match value.to_ascii_lowercase().as_str() {
+        "work" => Ok(OutcomeType::Work),
+        "health" => Ok(OutcomeType::Health),
+        "family" => Ok(OutcomeType::Family),
+        other => bail!(
+            "Unknown outcome '{}', expected work, health or family",
+            other
+        ),
+    }
+}
+
+fn pretty(value: Value) -> Result<String> {
+    serde_json::to_string_pretty(&value).context("Failed to serialize tool result")
+}
+
+fn get_plan(args: &Value, config: &Config) -> Result<String> {
+    let goals = data::load_or_create_goals(date_arg(args)?, config)?;
+    let outcomes: Vec<Value> = goals
+        .outcomes()
+        .iter()
+        .map(|outcome| {
+            json!({
+                "outcome": outcome.outcome_type.as_str(),
+                "goal": outcome.goal,
+                "actions": outcome.actions.iter().map(|action| json!({
+                    "id": action.id,
+                    "text": action.text,
+                    "completed": action.completed,
+                    "objectives": action.get_all_objective_ids(),
+                })).collect::<Vec<_>>(),
+            })
+        })
+        .collect();
+
+    pretty(json!({
+        "date": goals.date,
+        "day_number": goals.day_number,
+        "outcomes": outcomes,
+    }))
+}
+
+fn append_action(args: &Value, config: &Config) -> Result<String> {
+    let outcome_type = outcome_arg(str_arg(args, "outcome")?)?;
+    let text = str_arg(args, "text")?.trim();
+    if text.is_empty() {
+        bail!("Action text cannot be empty");
+    }
+
+    let mut goals = data::load_or_create_goals(date_arg(args)?, config)?;
+    let outcome = match outcome_type {
+        OutcomeType::Work => &mut goals.work,
+        OutcomeType::Health => &mut goals.health,
+        OutcomeType::Family => &mut goals.family,
+    };
+
+    // Fill a blank slot before growing the outcome
+    let index = match outcome
+        .actions
+        .iter()
+        .position(|a| a.text.trim().is_empty())
+    {
+        Some(index) => index,
+        None => {
+            outcome.add_action()?;
+            outcome.actions.len() - 1
+        }
+    };
+    outcome.actions[index].text = text.to_string();
+    outcome.actions[index].modified = chrono::Utc::now();
+
+    data::write_goals_file(&goals, config)?;
+    Ok(format!(
+        "Added to {} on {}: {}",
+        outcome_type.as_str(),
+        goals.date,
+        text
+    ))
+}
+
+fn list_objectives(args: &Value, config: &Config) -> Result<String> {
+    let filter = match args.get("outcome").and_then(Value::as_str) {
+        Some(outcome) => Some(outcome_arg(outcome)?),
+        None => None,
+    };
+
+    let objectives = data::load_or_create_objectives(config)?;
+    let listed: Vec<Value> = objectives
+        .objectives
+        .iter()
+        .filter(|obj| filter.is_none_or(|domain| obj.domain == domain))
+        .map(|obj| {
+            json!({
+                "id": obj.id,
+                "title": obj.title,
+                "outcome": obj.domain.as_str(),
+                "status": obj.status,
+                "indicators": obj.indicators,
+            })
+        })
+        .collect();
+    pretty(json!(listed))
+}
+
+fn list_indicators(config: &Config) -> Result<String> {
+    let indicators = data::load_or_create_indicators(config)?;
+    let listed: Vec<Value> = indicators
+        .indicators
+        .iter()
+        .map(|def| {
+            json!({
+                "id": def.id,
+                "name": def.name,
+                "unit": def.unit,
+                "target": def.current_target(),
+                "direction": def.direction,
+                "active": def.active,
+            })
+        })
+        .collect();
+    pretty(json!(listed))
+}
+
+fn log_observation(args: &Value, config: &Config) -> Result<String> {
+    let key = str_arg(args, "indicator")?;
+    let value = args
+        .get("value")
+        .and_then(Value::as_f64)
+        .context("Missing numeric argument 'value'")?;
+    let when = date_arg(args)?;
+
+    let indicators = data::load_or_create_indicators(config)?;
+    let def = indicators
+        .find(key)
+        .with_context(|| format!("No indicator with id or name '{}'", key))?;
+
+    let mut observation = Observation::new(def.id.clone(), when, value, def.unit.clone());
+    observation.source = ObservationSource::Automated;
+    observation.note = args.get("note").and_then(Value::as_str).map(str::to_string);
+    data::append_observation(&observation, config)?;
+    data::sync_ratio_indicators(&observation, &indicators, config)?;
+
+    Ok(format!("Logged {} = {} on {}", def.name, value, when))
+}
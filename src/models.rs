@@ -1,11 +1,18 @@
-use chrono::{Local, NaiveDate};
+use chrono::{Datelike, Local, NaiveDate};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 // Input validation constants
 pub const MAX_ACTION_LENGTH: usize = 500;
 pub const MAX_GOAL_LENGTH: usize = 100;
 pub const MAX_VISION_LENGTH: usize = 1000;
+pub const MAX_REFLECTION_LENGTH: usize = 2000;
+pub const MAX_DAY_NOTE_LENGTH: usize = 300;
+pub const MAX_WEEKLY_FOCUS_LENGTH: usize = 200;
+/// Minutes of work a day holds when no capacity is set
+pub const DEFAULT_DAILY_CAPACITY_MIN: u32 = 8 * 60;
+/// Days a deleted objective or indicator stays in the trash
+pub const DEFAULT_TRASH_RETENTION_DAYS: u32 = 30;
 
 /// A single action item with completion status
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -28,6 +35,17 @@ pub struct Action {
     pub modified: chrono::DateTime<chrono::Utc>,
     #[serde(default)]
     pub completed_at: Option<chrono::DateTime<chrono::Utc>>, // When completed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub indicator_links: Vec<IndicatorLink>, // Indicators fed directly on completion
+}
+
+/// An indicator an action feeds without going through an objective
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IndicatorLink {
+    pub indicator_id: String,
+    /// Logged automatically on completion; `None` asks for the value
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
 }
 
 impl Action {
@@ -60,14 +78,18 @@ impl Action {
             created: now,
             modified: now,
             completed_at: None,
+            indicator_links: Vec::new(),
         };
         action.sync_completed_from_status();
         action
     }
 
-    /// Create an action from markdown parsing (preserves completion status)
+    /// Create an action from markdown parsing (preserves completion status).
+    /// Text past MAX_ACTION_LENGTH is kept: the editor only saves it that
+    /// way after the user confirms.
     pub fn from_markdown(text: String, completed: bool) -> Self {
-        let mut action = Self::new(text);
+        let mut action = Self::new_empty();
+        action.text = text;
         action.completed = completed;
         // Set status based on completed field for backward compatibility
         action.status = if completed {
@@ -103,6 +125,7 @@ impl Action {
             created: now,
             modified: now,
             completed_at: None,
+            indicator_links: Vec::new(),
         };
         action.sync_completed_from_status();
         action
@@ -194,6 +217,32 @@ impl Action {
             }
         }
     }
+
+    /// Link an indicator, or change the value logged for an existing link
+    pub fn link_indicator(&mut self, indicator_id: &str, value: Option<f64>) {
+        match self
+            .indicator_links
+            .iter_mut()
+            .find(|link| link.indicator_id == indicator_id)
+        {
+            Some(link) => link.value = value,
+            None => self.indicator_links.push(IndicatorLink {
+                indicator_id: indicator_id.to_string(),
+                value,
+            }),
+        }
+    }
+
+    pub fn unlink_indicator(&mut self, indicator_id: &str) {
+        self.indicator_links
+            .retain(|link| link.indicator_id != indicator_id);
+    }
+
+    pub fn indicator_link(&self, indicator_id: &str) -> Option<&IndicatorLink> {
+        self.indicator_links
+            .iter()
+            .find(|link| link.indicator_id == indicator_id)
+    }
 }
 
 /// The three life outcome areas - fixed enum to enforce exactly 3
@@ -274,6 +323,30 @@ impl Outcome {
         Ok(())
     }
 
+    /// Put `text` in the first empty action slot, adding a slot when all are
+    /// used; returns the action's index
+    pub fn place_action(&mut self, text: String) -> anyhow::Result<usize> {
+        let index = match self.actions.iter().position(|a| a.text.trim().is_empty()) {
+            Some(index) => index,
+            None => {
+                self.add_action()?;
+                self.actions.len() - 1
+            }
+        };
+        self.actions[index].text = text;
+        Ok(index)
+    }
+
+    /// Move the action at `from` to position `to`, shifting those between
+    pub fn move_action(&mut self, from: usize, to: usize) -> anyhow::Result<()> {
+        if from >= self.actions.len() || to >= self.actions.len() {
+            anyhow::bail!("Invalid action index: {}", from.max(to));
+        }
+        let action = self.actions.remove(from);
+        self.actions.insert(to, action);
+        Ok(())
+    }
+
     /// Remove an action (min 1 must remain)
     pub fn remove_action(&mut self, index: usize) -> anyhow::Result<()> {
         if self.actions.len() <= 1 {
@@ -343,6 +416,22 @@ impl DailyGoals {
         [&mut self.work, &mut self.health, &mut self.family]
     }
 
+    pub fn outcome(&self, outcome_type: OutcomeType) -> &Outcome {
+        match outcome_type {
+            OutcomeType::Work => &self.work,
+            OutcomeType::Health => &self.health,
+            OutcomeType::Family => &self.family,
+        }
+    }
+
+    pub fn outcome_mut(&mut self, outcome_type: OutcomeType) -> &mut Outcome {
+        match outcome_type {
+            OutcomeType::Work => &mut self.work,
+            OutcomeType::Health => &mut self.health,
+            OutcomeType::Family => &mut self.family,
+        }
+    }
+
     /// Calculate completion statistics for the day
     pub fn completion_stats(&self) -> CompletionStats {
         let work_done = self.work.count_completed();
@@ -398,6 +487,62 @@ impl DailyGoals {
     }
 }
 
+/// Focus for each outcome over one ISO week, the layer between the vision
+/// and the day's actions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WeeklyGoals {
+    pub year: i32,
+    pub week: u32,
+    #[serde(default)]
+    pub work: String,
+    #[serde(default)]
+    pub health: String,
+    #[serde(default)]
+    pub family: String,
+}
+
+impl WeeklyGoals {
+    pub fn new(week_iso: (i32, u32)) -> Self {
+        Self {
+            year: week_iso.0,
+            week: week_iso.1,
+            ..Self::default()
+        }
+    }
+
+    pub fn week_iso(&self) -> (i32, u32) {
+        (self.year, self.week)
+    }
+
+    /// Monday of the week
+    pub fn start(&self) -> Option<NaiveDate> {
+        NaiveDate::from_isoywd_opt(self.year, self.week, chrono::Weekday::Mon)
+    }
+
+    pub fn get_focus(&self, outcome_type: OutcomeType) -> &str {
+        match outcome_type {
+            OutcomeType::Work => &self.work,
+            OutcomeType::Health => &self.health,
+            OutcomeType::Family => &self.family,
+        }
+    }
+
+    pub fn set_focus(&mut self, outcome_type: OutcomeType, focus: &str) {
+        let focus = focus.trim().chars().take(MAX_WEEKLY_FOCUS_LENGTH).collect();
+        match outcome_type {
+            OutcomeType::Work => self.work = focus,
+            OutcomeType::Health => self.health = focus,
+            OutcomeType::Family => self.family = focus,
+        }
+    }
+}
+
+/// ISO year and week number of `date`, as used in weekly file names
+pub fn iso_week_of(date: NaiveDate) -> (i32, u32) {
+    let week = date.iso_week();
+    (week.year(), week.week())
+}
+
 /// Five-year vision for each life outcome area
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FiveYearVision {
@@ -408,6 +553,13 @@ pub struct FiveYearVision {
     pub modified: NaiveDate,
 }
 
+/// The vision as it was saved at one moment
+#[derive(Debug, Clone)]
+pub struct VisionVersion {
+    pub saved: chrono::NaiveDateTime,
+    pub vision: FiveYearVision,
+}
+
 impl Default for FiveYearVision {
     fn default() -> Self {
         Self::new()
@@ -426,6 +578,11 @@ impl FiveYearVision {
         }
     }
 
+    /// Whether the three texts match, ignoring the dates
+    pub fn same_text(&self, other: &FiveYearVision) -> bool {
+        self.work == other.work && self.health == other.health && self.family == other.family
+    }
+
     pub fn get_vision(&self, outcome_type: &OutcomeType) -> &str {
         match outcome_type {
             OutcomeType::Work => &self.work,
@@ -440,7 +597,12 @@ impl FiveYearVision {
         } else {
             vision
         };
+        self.set_vision_past_limit(outcome_type, vision);
+    }
 
+    /// Store `vision` even past MAX_VISION_LENGTH; the editor only allows
+    /// that after the user confirms the override
+    pub fn set_vision_past_limit(&mut self, outcome_type: &OutcomeType, vision: String) {
         match outcome_type {
             OutcomeType::Work => self.work = vision,
             OutcomeType::Health => self.health = vision,
@@ -455,6 +617,9 @@ impl FiveYearVision {
 pub struct ActionTemplates {
     /// Map of template name to list of action texts (up to 5 per template)
     pub templates: HashMap<String, Vec<String>>,
+    /// Templates placed into each new day file of their weekdays, by name
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub weekday_templates: HashMap<String, WeekdayTemplate>,
     pub created: NaiveDate,
     pub modified: NaiveDate,
 }
@@ -470,6 +635,7 @@ impl ActionTemplates {
         let today = chrono::Local::now().date_naive();
         Self {
             templates: HashMap::new(),
+            weekday_templates: HashMap::new(),
             created: today,
             modified: today,
         }
@@ -514,6 +680,120 @@ impl ActionTemplates {
         names.sort();
         names
     }
+
+    /// Add or update a weekday template, with the same limits as `add_template`
+    pub fn set_weekday_template(&mut self, name: String, mut template: WeekdayTemplate) {
+        for outcome_type in [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family] {
+            let actions = template.actions_mut(outcome_type);
+            actions.retain(|text| !text.trim().is_empty());
+            actions.truncate(5);
+            for text in actions.iter_mut() {
+                if text.len() > MAX_ACTION_LENGTH {
+                    *text = text.chars().take(MAX_ACTION_LENGTH).collect();
+                }
+            }
+        }
+        template
+            .weekdays
+            .sort_by_key(|day| day.num_days_from_monday());
+        template.weekdays.dedup();
+        self.weekday_templates.insert(name, template);
+        self.modified = chrono::Local::now().date_naive();
+    }
+
+    pub fn remove_weekday_template(&mut self, name: &str) -> bool {
+        let removed = self.weekday_templates.remove(name).is_some();
+        if removed {
+            self.modified = chrono::Local::now().date_naive();
+        }
+        removed
+    }
+
+    /// Weekday templates bound to the weekday of `date`, by name
+    pub fn weekday_templates_for(&self, date: NaiveDate) -> Vec<(&String, &WeekdayTemplate)> {
+        let mut bound: Vec<_> = self
+            .weekday_templates
+            .iter()
+            .filter(|(_, template)| template.weekdays.contains(&date.weekday()))
+            .collect();
+        bound.sort_by(|a, b| a.0.cmp(b.0));
+        bound
+    }
+
+    /// Place the actions of the weekday templates for the day of `goals`
+    /// into it, skipping texts already there and outcomes that are full.
+    /// Returns how many actions were added.
+    pub fn apply_weekday_templates(&self, goals: &mut DailyGoals) -> usize {
+        let mut added = 0;
+        for (_, template) in self.weekday_templates_for(goals.date) {
+            for outcome_type in [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family] {
+                let outcome = goals.outcome_mut(outcome_type);
+                for text in template.actions(outcome_type) {
+                    if outcome.actions.iter().any(|a| a.text.trim() == text.trim()) {
+                        continue;
+                    }
+                    if let Ok(index) = outcome.place_action(text.clone()) {
+                        outcome.actions[index].origin = ActionOrigin::Template;
+                        added += 1;
+                    }
+                }
+            }
+        }
+        added
+    }
+}
+
+/// A template bound to weekdays, e.g. "Monday planning", with actions for
+/// each outcome
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct WeekdayTemplate {
+    pub weekdays: Vec<chrono::Weekday>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub work: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub health: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub family: Vec<String>,
+}
+
+impl WeekdayTemplate {
+    /// A template holding the actions of `goals` that have text
+    pub fn from_goals(goals: &DailyGoals, weekdays: Vec<chrono::Weekday>) -> Self {
+        let texts = |outcome: &Outcome| {
+            outcome
+                .actions
+                .iter()
+                .filter(|action| !action.text.trim().is_empty())
+                .map(|action| action.text.clone())
+                .collect()
+        };
+        Self {
+            weekdays,
+            work: texts(&goals.work),
+            health: texts(&goals.health),
+            family: texts(&goals.family),
+        }
+    }
+
+    pub fn actions(&self, outcome_type: OutcomeType) -> &[String] {
+        match outcome_type {
+            OutcomeType::Work => &self.work,
+            OutcomeType::Health => &self.health,
+            OutcomeType::Family => &self.family,
+        }
+    }
+
+    pub fn actions_mut(&mut self, outcome_type: OutcomeType) -> &mut Vec<String> {
+        match outcome_type {
+            OutcomeType::Work => &mut self.work,
+            OutcomeType::Health => &mut self.health,
+            OutcomeType::Family => &mut self.family,
+        }
+    }
+
+    pub fn action_count(&self) -> usize {
+        self.work.len() + self.health.len() + self.family.len()
+    }
 }
 
 /// Application configuration
@@ -534,6 +814,36 @@ pub struct ActionMeta {
     pub priority: Option<u32>,
     pub tags: Vec<String>,
     pub objective_id: Option<String>, // Link to objective UUID
+    /// Day the action was first planned, kept across carry-overs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub carried_from: Option<NaiveDate>,
+    /// Day the action was snoozed on, when it arrived by snoozing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snoozed_from: Option<NaiveDate>,
+    /// Action that has to be done before this one can start
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocked_by: Option<BlockerRef>,
+    /// Start of the action's time block; it lasts `estimated_min`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scheduled_at: Option<chrono::NaiveTime>,
+    /// Whether the action serves its outcome's focus for the week
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub weekly_focus: bool,
+    /// When the action was checked off; cleared when it is unchecked
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Reference to an action on the same day or an earlier one, found again by
+/// the id its day's metadata keeps, or by its text for references saved
+/// before ids were kept
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BlockerRef {
+    pub date: NaiveDate,
+    pub outcome_type: OutcomeType,
+    pub text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action_id: Option<String>,
 }
 
 impl Default for ActionMeta {
@@ -547,6 +857,12 @@ impl Default for ActionMeta {
             priority: None,
             tags: Vec::new(),
             objective_id: None,
+            carried_from: None,
+            snoozed_from: None,
+            blocked_by: None,
+            scheduled_at: None,
+            weekly_focus: false,
+            completed_at: None,
         }
     }
 }
@@ -569,6 +885,14 @@ pub enum ActionOrigin {
     CarryOver,
 }
 
+/// Pointer to an action snoozed away from a day
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SnoozedAction {
+    pub outcome_type: OutcomeType,
+    pub text: String,
+    pub until: NaiveDate,
+}
+
 /// Day metadata stored as sidecar to markdown files
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DayMeta {
@@ -576,6 +900,18 @@ pub struct DayMeta {
     pub work: Vec<ActionMeta>,
     pub health: Vec<ActionMeta>,
     pub family: Vec<ActionMeta>,
+    /// Free-form context for the whole day (meetings, travel, ...)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Actions moved from this day to a later one
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub snoozed: Vec<SnoozedAction>,
+    /// Set once yesterday's unfinished actions were triaged into this day
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub triaged: bool,
+    /// When the day was closed; the app keeps a closed day read-only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub closed: Option<chrono::DateTime<chrono::Utc>>,
     pub created: chrono::DateTime<chrono::Utc>,
     pub modified: chrono::DateTime<chrono::Utc>,
 }
@@ -628,11 +964,67 @@ impl DayMeta {
                     ..ActionMeta::default()
                 })
                 .collect(),
+            note: None,
+            snoozed: Vec::new(),
+            triaged: false,
+            closed: None,
             created: now,
             modified: now,
         }
     }
 
+    pub fn outcome(&self, outcome_type: OutcomeType) -> &[ActionMeta] {
+        match outcome_type {
+            OutcomeType::Work => &self.work,
+            OutcomeType::Health => &self.health,
+            OutcomeType::Family => &self.family,
+        }
+    }
+
+    pub fn outcome_mut(&mut self, outcome_type: OutcomeType) -> &mut Vec<ActionMeta> {
+        match outcome_type {
+            OutcomeType::Work => &mut self.work,
+            OutcomeType::Health => &mut self.health,
+            OutcomeType::Family => &mut self.family,
+        }
+    }
+
+    /// Stamp actions checked off since the last call with `now` and clear
+    /// the stamps of unchecked ones; returns whether anything changed
+    pub fn stamp_completions(
+        &mut self,
+        goals: &DailyGoals,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> bool {
+        let mut changed = false;
+        for outcome in goals.outcomes() {
+            let metas = self.outcome_mut(outcome.outcome_type);
+            for (action, action_meta) in outcome.actions.iter().zip(metas.iter_mut()) {
+                let done = action.completed && !action.text.trim().is_empty();
+                match (done, action_meta.completed_at) {
+                    (true, None) => action_meta.completed_at = Some(now),
+                    (false, Some(_)) => action_meta.completed_at = None,
+                    _ => continue,
+                }
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Give the actions of `goals` the ids kept here, position by position,
+    /// so an action keeps its id from one load of the goals file to the next
+    pub fn apply_ids(&self, goals: &mut DailyGoals) {
+        for outcome in goals.outcomes_mut() {
+            let metas = self.outcome(outcome.outcome_type);
+            for (action, action_meta) in outcome.actions.iter_mut().zip(metas) {
+                if !action_meta.id.is_empty() {
+                    action.id = action_meta.id.clone();
+                }
+            }
+        }
+    }
+
     /// Reconcile metadata with current action counts
     pub fn reconcile_with_goals(&mut self, goals: &DailyGoals) {
         Self::reconcile_outcome_meta(&mut self.work, &goals.work);
@@ -698,6 +1090,49 @@ pub enum ObjectiveStatus {
     Dropped,
 }
 
+impl ObjectiveStatus {
+    /// Next status when cycling in the picker: Active, Paused, Dropped and
+    /// back; a completed objective is reopened as Active
+    pub fn cycle(&self) -> Self {
+        match self {
+            ObjectiveStatus::Active => ObjectiveStatus::Paused,
+            ObjectiveStatus::Paused => ObjectiveStatus::Dropped,
+            ObjectiveStatus::Dropped | ObjectiveStatus::Completed => ObjectiveStatus::Active,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ObjectiveStatus::Active => "active",
+            ObjectiveStatus::Paused => "paused",
+            ObjectiveStatus::Completed => "completed",
+            ObjectiveStatus::Dropped => "dropped",
+        }
+    }
+}
+
+/// What progress on an objective is measured by
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ObjectiveKind {
+    #[default]
+    Standard,
+    Queue, // Ordered reading/learning queue; linked completions advance it
+}
+
+impl ObjectiveKind {
+    pub fn is_standard(&self) -> bool {
+        *self == ObjectiveKind::Standard
+    }
+}
+
+/// One entry in a queue objective, e.g. a book or course
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueueItem {
+    pub title: String,
+    #[serde(default)]
+    pub completed_on: Option<NaiveDate>,
+}
+
 /// Long-term objective that can be linked to daily actions
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Objective {
@@ -713,6 +1148,18 @@ pub struct Objective {
     pub created: chrono::DateTime<chrono::Utc>, // Creation timestamp
     pub modified: chrono::DateTime<chrono::Utc>, // Last modification timestamp
     pub parent_id: Option<String>,   // For hierarchical objectives
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>, // Accent color: name or #rrggbb
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weekly_budget_min: Option<u32>, // Planned effort per week, in minutes
+    #[serde(default, skip_serializing_if = "ObjectiveKind::is_standard")]
+    pub kind: ObjectiveKind,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub queue: Vec<QueueItem>, // Items in reading order (Queue objectives)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_on: Option<NaiveDate>, // Day it was marked complete
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub archived: bool, // Hidden from the picker once complete
 }
 
 impl Objective {
@@ -731,7 +1178,129 @@ impl Objective {
             created: now,
             modified: now,
             parent_id: None,
+            color: None,
+            weekly_budget_min: None,
+            kind: ObjectiveKind::Standard,
+            queue: Vec::new(),
+            completed_on: None,
+            archived: false,
+        }
+    }
+
+    /// Mark complete on `date` and archive it
+    pub fn complete(&mut self, date: NaiveDate) {
+        self.status = ObjectiveStatus::Completed;
+        self.completed_on = Some(date);
+        self.archived = true;
+    }
+
+    /// Move to the next status in the cycle; reopening a completed
+    /// objective brings it back out of the archive
+    pub fn cycle_status(&mut self) {
+        self.status = self.status.cycle();
+        if self.status == ObjectiveStatus::Active {
+            self.completed_on = None;
+            self.archived = false;
+        }
+    }
+
+    /// Append an item to the queue, turning this into a queue objective
+    pub fn push_queue_item(&mut self, title: String) {
+        self.kind = ObjectiveKind::Queue;
+        self.queue.push(QueueItem {
+            title,
+            completed_on: None,
+        });
+    }
+
+    /// (completed, total) items for queue objectives
+    pub fn queue_progress(&self) -> Option<(usize, usize)> {
+        (self.kind == ObjectiveKind::Queue).then(|| {
+            let done = self
+                .queue
+                .iter()
+                .filter(|item| item.completed_on.is_some())
+                .count();
+            (done, self.queue.len())
+        })
+    }
+
+    /// First item not yet completed
+    pub fn next_queue_item(&self) -> Option<&QueueItem> {
+        self.queue.iter().find(|item| item.completed_on.is_none())
+    }
+
+    /// Complete the next item on `date`; returns its title
+    pub fn advance_queue(&mut self, date: NaiveDate) -> Option<String> {
+        if self.kind != ObjectiveKind::Queue {
+            return None;
+        }
+        let item = self
+            .queue
+            .iter_mut()
+            .find(|item| item.completed_on.is_none())?;
+        item.completed_on = Some(date);
+        Some(item.title.clone())
+    }
+
+    /// Undo the last item completed on `date`; returns its title
+    pub fn rewind_queue(&mut self, date: NaiveDate) -> Option<String> {
+        if self.kind != ObjectiveKind::Queue {
+            return None;
+        }
+        let item = self
+            .queue
+            .iter_mut()
+            .rev()
+            .find(|item| item.completed_on == Some(date))?;
+        item.completed_on = None;
+        Some(item.title.clone())
+    }
+
+    /// Days from `today` to the target date; negative once it has passed
+    pub fn days_left(&self, today: NaiveDate) -> Option<i64> {
+        self.end.map(|end| (end - today).num_days())
+    }
+
+    /// "34 days left", "due today" or "3 days overdue"; `None` without a
+    /// target date or once the objective is no longer active
+    pub fn countdown(&self, today: NaiveDate) -> Option<String> {
+        if self.status != ObjectiveStatus::Active {
+            return None;
         }
+        let plural = |n: i64| if n == 1 { "" } else { "s" };
+        Some(match self.days_left(today)? {
+            0 => "due today".to_string(),
+            days if days > 0 => format!("{} day{} left", days, plural(days)),
+            days => format!("{} day{} overdue", -days, plural(-days)),
+        })
+    }
+}
+
+/// Parse an effort like "90", "45m", "2h" or "1h30m" into minutes
+pub fn parse_minutes(input: &str) -> Option<u32> {
+    let input = input.trim().to_lowercase();
+    if let Ok(minutes) = input.parse::<u32>() {
+        return Some(minutes);
+    }
+    let (hours, rest) = match input.split_once('h') {
+        Some((hours, rest)) => (hours.trim().parse::<f64>().ok()?, rest.trim()),
+        None => (0.0, input.as_str()),
+    };
+    let minutes = match rest.strip_suffix('m').unwrap_or(rest).trim() {
+        "" => 0,
+        value => value.parse::<u32>().ok()?,
+    };
+    let total = (hours * 60.0).round() + f64::from(minutes);
+    (hours >= 0.0 && total <= f64::from(u32::MAX)).then_some(total as u32)
+}
+
+/// Format minutes compactly, e.g. "45m", "2h", "1h30m"
+pub fn format_minutes(minutes: u32) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{}m", m),
+        (h, 0) => format!("{}h", h),
+        (h, m) => format!("{}h{:02}m", h, m),
     }
 }
 
@@ -751,6 +1320,74 @@ impl Default for ObjectivesData {
     }
 }
 
+/// Initiative grouping objectives that may span several domains
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Project {
+    pub id: String, // UUID as string
+    pub title: String,
+    #[serde(default)]
+    pub objective_ids: Vec<String>, // Member objectives, in any domain
+    pub created: chrono::DateTime<chrono::Utc>,
+    pub modified: chrono::DateTime<chrono::Utc>,
+}
+
+impl Project {
+    pub fn new(title: String) -> Self {
+        let now = chrono::Utc::now();
+        Project {
+            id: uuid::Uuid::new_v4().to_string(),
+            title,
+            objective_ids: Vec::new(),
+            created: now,
+            modified: now,
+        }
+    }
+}
+
+/// Root structure for trash.json: deleted objectives and indicators, kept
+/// until they are restored or purged (see `crate::trash`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashData {
+    pub version: u32,
+    #[serde(default)]
+    pub objectives: Vec<Trashed<Objective>>,
+    #[serde(default)]
+    pub indicators: Vec<Trashed<IndicatorDef>>,
+}
+
+impl Default for TrashData {
+    fn default() -> Self {
+        TrashData {
+            version: 1,
+            objectives: Vec::new(),
+            indicators: Vec::new(),
+        }
+    }
+}
+
+/// An item in the trash and when it was deleted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Trashed<T> {
+    pub item: T,
+    pub trashed: chrono::DateTime<chrono::Utc>,
+}
+
+/// Root structure for projects.json
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectsData {
+    pub version: u32,
+    pub projects: Vec<Project>,
+}
+
+impl Default for ProjectsData {
+    fn default() -> Self {
+        ProjectsData {
+            version: 1,
+            projects: Vec::new(),
+        }
+    }
+}
+
 /// Kind of indicator (leading or lagging)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum IndicatorKind {
@@ -792,27 +1429,459 @@ pub struct IndicatorDef {
     pub modified: chrono::DateTime<chrono::Utc>, // Last modification
     pub lineage_of: Option<String>,              // Previous version ID
     pub notes: Option<String>,                   // Additional notes
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub target_schedule: Vec<TargetStep>, // Dated targets, ramped between points
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range_min: Option<f64>, // Lower bound for WithinRange indicators
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range_max: Option<f64>, // Upper bound for WithinRange indicators
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monthly_target: Option<f64>, // Month total to reach, for Dollars indicators
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ratio_of: Option<RatioSource>, // Percent computed from two counters
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub exclude_from_signals: bool, // Too volatile for Alternative Signals
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub auto_rules: Vec<AutoRule>, // Observations added when tagged actions complete
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>, // e.g. "Fitness", for dashboard panel layouts
+    #[serde(default, skip_serializing_if = "IndicatorAggregation::is_default")]
+    pub aggregation: IndicatorAggregation, // How a day's observations combine
+    #[serde(default, skip_serializing_if = "TargetPeriod::is_default")]
+    pub target_period: TargetPeriod, // Whether the target is per day, week or month
 }
 
-impl IndicatorDef {
-    /// Create a new indicator with generated UUID
-    pub fn new(name: String, kind: IndicatorKind, unit: IndicatorUnit) -> Self {
-        let now = chrono::Utc::now();
-        IndicatorDef {
-            id: uuid::Uuid::new_v4().to_string(),
-            name,
-            kind,
-            unit,
-            objective_id: None,
-            target: None,
-            direction: IndicatorDirection::HigherIsBetter,
-            active: true,
-            created: now,
-            modified: now,
-            lineage_of: None,
-            notes: None,
-        }
+/// The span a target covers. Weekly and monthly targets are totals of the
+/// period's daily values, e.g. "run 20 km a week", and pace against them is
+/// pro-rated by how much of the period has passed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TargetPeriod {
+    #[default]
+    Day,
+    Week,
+    Month,
+}
+
+impl TargetPeriod {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TargetPeriod::Day => "day",
+            TargetPeriod::Week => "week",
+            TargetPeriod::Month => "month",
+        }
+    }
+
+    pub fn parse(word: &str) -> Option<Self> {
+        match word.trim().to_lowercase().as_str() {
+            "d" | "day" | "daily" => Some(TargetPeriod::Day),
+            "w" | "wk" | "week" | "weekly" => Some(TargetPeriod::Week),
+            "mo" | "month" | "monthly" => Some(TargetPeriod::Month),
+            _ => None,
+        }
+    }
+
+    /// First and last day of the period containing `date`; weeks start on
+    /// Monday
+    pub fn bounds(&self, date: NaiveDate) -> (NaiveDate, NaiveDate) {
+        match self {
+            TargetPeriod::Day => (date, date),
+            TargetPeriod::Week => {
+                let start =
+                    date - chrono::Duration::days(i64::from(date.weekday().num_days_from_monday()));
+                (start, start + chrono::Duration::days(6))
+            }
+            TargetPeriod::Month => {
+                let start = date.with_day(1).unwrap_or(date);
+                let next = start
+                    .checked_add_months(chrono::Months::new(1))
+                    .unwrap_or(start);
+                (start, next.pred_opt().unwrap_or(start))
+            }
+        }
+    }
+}
+
+/// Progress through a weekly or monthly target
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PeriodProgress {
+    pub period: TargetPeriod,
+    pub total: f64,     // Sum of daily values so far this period
+    pub target: f64,    // The whole period's target
+    pub pro_rated: f64, // Share of the target due by today
+    pub days_elapsed: u32,
+    pub days_in_period: u32,
+}
+
+impl PeriodProgress {
+    /// Total as a percentage of what is due by today
+    pub fn pace_percent(&self) -> Option<f64> {
+        (self.pro_rated > 0.0).then(|| self.total / self.pro_rated * 100.0)
+    }
+
+    /// How far the total is ahead of (positive) or behind what is due
+    pub fn pace_gap(&self) -> f64 {
+        self.total - self.pro_rated
+    }
+}
+
+/// How several observations on one day become that day's value: minutes
+/// exercised add up, a weight reading replaces the last one, ratings average
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IndicatorAggregation {
+    #[default]
+    Last,
+    Sum,
+    Average,
+}
+
+impl IndicatorAggregation {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn parse(word: &str) -> Option<Self> {
+        match word.to_lowercase().as_str() {
+            "last" | "latest" => Some(IndicatorAggregation::Last),
+            "sum" | "total" => Some(IndicatorAggregation::Sum),
+            "average" | "avg" | "mean" => Some(IndicatorAggregation::Average),
+            _ => None,
+        }
+    }
+
+    /// One value per day for `indicator_id`, oldest first. Last picks the
+    /// latest sample of the day by time of day, then by when it was recorded.
+    pub fn daily(&self, indicator_id: &str, observations: &[Observation]) -> Vec<(NaiveDate, f64)> {
+        let mut days: BTreeMap<NaiveDate, Vec<&Observation>> = BTreeMap::new();
+        for obs in observations
+            .iter()
+            .filter(|obs| obs.indicator_id == indicator_id)
+        {
+            days.entry(obs.when).or_default().push(obs);
+        }
+        days.into_iter()
+            .map(|(date, mut day)| {
+                let value = match self {
+                    IndicatorAggregation::Last => {
+                        day.sort_by_key(|obs| (obs.at, obs.created));
+                        day.last().map(|obs| obs.value).unwrap_or_default()
+                    }
+                    IndicatorAggregation::Sum => day.iter().map(|obs| obs.value).sum(),
+                    IndicatorAggregation::Average => {
+                        day.iter().map(|obs| obs.value).sum::<f64>() / day.len() as f64
+                    }
+                };
+                (date, value)
+            })
+            .collect()
+    }
+}
+
+/// "When an action tagged #pushups completes, add 20": an observation of
+/// `value` is logged for each completed action carrying `tag`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AutoRule {
+    pub tag: String, // Without the leading '#', lowercase
+    pub value: f64,
+}
+
+/// The two counter indicators a Percent indicator is derived from, e.g.
+/// "applications responded" over "applications sent"
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RatioSource {
+    pub numerator_id: String,
+    pub denominator_id: String,
+}
+
+impl RatioSource {
+    /// Whether `indicator_id` is one of the two counters
+    pub fn involves(&self, indicator_id: &str) -> bool {
+        self.numerator_id == indicator_id || self.denominator_id == indicator_id
+    }
+}
+
+/// Running month-to-date figures for a Dollars indicator
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonthToDate {
+    pub total: f64,
+    pub projected: f64, // Month-end total at the pace so far
+    pub target: Option<f64>,
+    pub days_elapsed: u32,
+    pub days_in_month: u32,
+}
+
+/// A dated point on an indicator's target schedule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TargetStep {
+    pub date: NaiveDate,
+    pub target: f64,
+}
+
+impl IndicatorDef {
+    /// Create a new indicator with generated UUID
+    pub fn new(name: String, kind: IndicatorKind, unit: IndicatorUnit) -> Self {
+        let now = chrono::Utc::now();
+        IndicatorDef {
+            id: uuid::Uuid::new_v4().to_string(),
+            name,
+            kind,
+            unit,
+            objective_id: None,
+            target: None,
+            direction: IndicatorDirection::HigherIsBetter,
+            active: true,
+            created: now,
+            modified: now,
+            lineage_of: None,
+            notes: None,
+            target_schedule: Vec::new(),
+            range_min: None,
+            range_max: None,
+            monthly_target: None,
+            ratio_of: None,
+            exclude_from_signals: false,
+            auto_rules: Vec::new(),
+            group: None,
+            aggregation: IndicatorAggregation::default(),
+            target_period: TargetPeriod::default(),
+        }
+    }
+
+    /// This indicator's value for each day with observations, oldest first
+    pub fn daily_values(&self, observations: &[Observation]) -> Vec<(NaiveDate, f64)> {
+        self.aggregation.daily(&self.id, observations)
+    }
+
+    /// Percent from the counters' totals through `through`; each counter
+    /// observation adds to its total. `None` when this is not a ratio
+    /// indicator or nothing has been counted in the denominator yet.
+    pub fn ratio_percent(&self, observations: &[Observation], through: NaiveDate) -> Option<f64> {
+        let source = self.ratio_of.as_ref()?;
+        let total = |id: &str| -> f64 {
+            observations
+                .iter()
+                .filter(|obs| obs.indicator_id == id && obs.when <= through)
+                .map(|obs| obs.value)
+                .sum()
+        };
+        let denominator = total(&source.denominator_id);
+        if denominator <= 0.0 {
+            return None;
+        }
+        Some(total(&source.numerator_id) / denominator * 100.0)
+    }
+
+    /// Sum of this month's observations up to `today`, projected to month
+    /// end. Only Dollars indicators have running totals; single days of
+    /// savings or revenue say little on their own.
+    pub fn month_to_date(
+        &self,
+        observations: &[Observation],
+        today: NaiveDate,
+    ) -> Option<MonthToDate> {
+        if self.unit != IndicatorUnit::Dollars {
+            return None;
+        }
+        let month_start = today.with_day(1)?;
+        let next_month = if today.month() == 12 {
+            NaiveDate::from_ymd_opt(today.year() + 1, 1, 1)?
+        } else {
+            NaiveDate::from_ymd_opt(today.year(), today.month() + 1, 1)?
+        };
+        let days_in_month = (next_month - month_start).num_days() as u32;
+        let days_elapsed = today.day();
+
+        let total: f64 = observations
+            .iter()
+            .filter(|obs| obs.indicator_id == self.id)
+            .filter(|obs| obs.when >= month_start && obs.when <= today)
+            .map(|obs| obs.value)
+            .sum();
+
+        Some(MonthToDate {
+            total,
+            projected: total * f64::from(days_in_month) / f64::from(days_elapsed),
+            target: self.monthly_target,
+            days_elapsed,
+            days_in_month,
+        })
+    }
+
+    /// This week's or month's total against its target, pro-rated to
+    /// `today`. `None` for daily targets or when no target is set.
+    pub fn period_to_date(
+        &self,
+        observations: &[Observation],
+        today: NaiveDate,
+    ) -> Option<PeriodProgress> {
+        if self.target_period == TargetPeriod::Day {
+            return None;
+        }
+        let target = self.target_on(today)?;
+        let (start, end) = self.target_period.bounds(today);
+        let days_in_period = (end - start).num_days() as u32 + 1;
+        let days_elapsed = (today - start).num_days() as u32 + 1;
+        let total = self
+            .daily_values(observations)
+            .into_iter()
+            .filter(|(date, _)| *date >= start && *date <= today)
+            .map(|(_, value)| value)
+            .sum();
+
+        Some(PeriodProgress {
+            period: self.target_period,
+            total,
+            target,
+            pro_rated: target * f64::from(days_elapsed) / f64::from(days_in_period),
+            days_elapsed,
+            days_in_period,
+        })
+    }
+
+    /// Target in effect on `date`.
+    ///
+    /// With a schedule, the target ramps linearly between consecutive points and
+    /// holds the last point afterwards. Before the first point the plain `target`
+    /// applies (or the first point if no plain target is set).
+    pub fn target_on(&self, date: NaiveDate) -> Option<f64> {
+        let mut steps: Vec<&TargetStep> = self.target_schedule.iter().collect();
+        if steps.is_empty() {
+            return self.target;
+        }
+        steps.sort_by_key(|step| step.date);
+
+        let first = steps[0];
+        if date < first.date {
+            return self.target.or(Some(first.target));
+        }
+
+        for pair in steps.windows(2) {
+            let (from, to) = (pair[0], pair[1]);
+            if date >= from.date && date < to.date {
+                let span = (to.date - from.date).num_days() as f64;
+                let elapsed = (date - from.date).num_days() as f64;
+                return Some(from.target + (to.target - from.target) * elapsed / span);
+            }
+        }
+
+        steps.last().map(|step| step.target)
+    }
+
+    /// The built-in "Focused minutes" indicator, fed by the focus timer
+    pub fn focus_minutes() -> Self {
+        let mut def = Self::new(
+            "Focused minutes".to_string(),
+            IndicatorKind::Leading,
+            IndicatorUnit::Minutes,
+        );
+        def.id = FOCUS_INDICATOR_ID.to_string();
+        def.target = Some(120.0);
+        def.notes = Some("Recorded automatically from focus sessions".to_string());
+        def
+    }
+
+    /// The built-in 1-5 "Mood" indicator, fed by check-ins
+    pub fn mood() -> Self {
+        Self::check_in(MOOD_INDICATOR_ID, "Mood")
+    }
+
+    /// The built-in 1-5 "Energy" indicator, fed by check-ins
+    pub fn energy() -> Self {
+        Self::check_in(ENERGY_INDICATOR_ID, "Energy")
+    }
+
+    fn check_in(id: &str, name: &str) -> Self {
+        let mut def = Self::new(
+            name.to_string(),
+            IndicatorKind::Leading,
+            IndicatorUnit::Custom("/5".to_string()),
+        );
+        def.id = id.to_string();
+        def.target = Some(4.0);
+        def.aggregation = IndicatorAggregation::Average;
+        def.notes = Some("Recorded from morning and evening check-ins".to_string());
+        def
+    }
+
+    /// Target in effect today
+    pub fn current_target(&self) -> Option<f64> {
+        self.target_on(Local::now().date_naive())
+    }
+
+    /// Acceptable band for a WithinRange indicator on `date`.
+    ///
+    /// Explicit `range_min`/`range_max` win (a missing side is unbounded);
+    /// otherwise the band is the target ±20% (at least ±1). Returns `None` for
+    /// other directions or when there is nothing to derive a band from.
+    pub fn range_on(&self, date: NaiveDate) -> Option<(f64, f64)> {
+        if self.direction != IndicatorDirection::WithinRange {
+            return None;
+        }
+        if self.range_min.is_some() || self.range_max.is_some() {
+            return Some((
+                self.range_min.unwrap_or(f64::NEG_INFINITY),
+                self.range_max.unwrap_or(f64::INFINITY),
+            ));
+        }
+        self.target_on(date).map(|target| {
+            let tolerance = (target.abs() * 0.2).max(1.0);
+            (target - tolerance, target + tolerance)
+        })
     }
+
+    /// Range in effect today
+    pub fn current_range(&self) -> Option<(f64, f64)> {
+        self.range_on(Local::now().date_naive())
+    }
+
+    /// Signed distance from `value` to the band: 0 inside, negative below,
+    /// positive above.
+    pub fn range_deviation(value: f64, (min, max): (f64, f64)) -> f64 {
+        if value < min {
+            value - min
+        } else if value > max {
+            value - max
+        } else {
+            0.0
+        }
+    }
+
+    /// Where `value` sits against the daily cap of a LowerIsBetter indicator
+    /// (its target on `date`). `None` for other directions or without a target.
+    pub fn cap_status_on(&self, value: f64, date: NaiveDate) -> Option<CapStatus> {
+        if self.direction != IndicatorDirection::LowerIsBetter {
+            return None;
+        }
+        let cap = self.target_on(date)?;
+        Some(if value > cap {
+            CapStatus::Exceeded
+        } else if value >= cap * CAP_WARNING_RATIO && value > 0.0 {
+            CapStatus::Approaching
+        } else {
+            CapStatus::Under
+        })
+    }
+
+    /// Cap status against today's target
+    pub fn cap_status(&self, value: f64) -> Option<CapStatus> {
+        self.cap_status_on(value, Local::now().date_naive())
+    }
+}
+
+/// Share of a LowerIsBetter cap at which a value counts as approaching it
+pub const CAP_WARNING_RATIO: f64 = 0.8;
+
+/// Position of a value relative to a LowerIsBetter indicator's daily cap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapStatus {
+    Under,
+    Approaching,
+    Exceeded,
 }
 
 /// Root structure for indicators.json
@@ -831,6 +1900,20 @@ impl Default for IndicatorsData {
     }
 }
 
+impl IndicatorsData {
+    /// Find an indicator by exact id, falling back to a case-insensitive name
+    pub fn find(&self, key: &str) -> Option<&IndicatorDef> {
+        self.indicators
+            .iter()
+            .find(|def| def.id == key)
+            .or_else(|| {
+                self.indicators
+                    .iter()
+                    .find(|def| def.name.eq_ignore_ascii_case(key))
+            })
+    }
+}
+
 /// New Indicator type for UI enhancement (as per plan)
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum IndicatorType {
@@ -904,6 +1987,8 @@ pub struct Observation {
     pub action_id: Option<String>,              // Link to action that produced it
     pub note: Option<String>,                   // Optional note
     pub created: chrono::DateTime<chrono::Utc>, // When recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub at: Option<chrono::NaiveTime>, // Local time of day of the sample, for intra-day logs
 }
 
 impl Observation {
@@ -919,8 +2004,86 @@ impl Observation {
             action_id: None,
             note: None,
             created: chrono::Utc::now(),
+            at: None,
         }
     }
+
+    /// A sample taken now: dated today and stamped with the local time
+    pub fn now(indicator_id: String, value: f64, unit: IndicatorUnit) -> Self {
+        let mut obs = Self::new(indicator_id, Local::now().date_naive(), value, unit);
+        obs.stamp_time();
+        obs
+    }
+
+    /// Record the current time of day on an untimed sample dated today;
+    /// backfilled days stay untimed
+    pub fn stamp_time(&mut self) {
+        let now = Local::now();
+        if self.at.is_none() && self.when == now.date_naive() {
+            self.at = Some(now.time());
+        }
+    }
+}
+
+/// Category of an entry in the activity log
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActivityKind {
+    Completion,
+    Observation,
+    Objective,
+}
+
+/// A timestamped event shown in the recent activity feed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityEvent {
+    pub at: chrono::DateTime<chrono::Utc>, // When it happened
+    pub kind: ActivityKind,                // What sort of event
+    pub summary: String,                   // One-line description
+}
+
+impl ActivityEvent {
+    /// Create an event stamped with the current time
+    pub fn new(kind: ActivityKind, summary: impl Into<String>) -> Self {
+        ActivityEvent {
+            at: chrono::Utc::now(),
+            kind,
+            summary: summary.into(),
+        }
+    }
+}
+
+/// Id of the built-in indicator that focus sessions feed
+pub const FOCUS_INDICATOR_ID: &str = "focus-minutes";
+
+/// Ids of the built-in indicators that check-ins feed
+pub const MOOD_INDICATOR_ID: &str = "mood";
+pub const ENERGY_INDICATOR_ID: &str = "energy";
+
+/// How a focus session was timed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FocusSessionKind {
+    Pomodoro,
+    Stopwatch,
+}
+
+/// A finished focus timer run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FocusSession {
+    pub kind: FocusSessionKind,
+    pub started: chrono::DateTime<chrono::Utc>,
+    pub ended: chrono::DateTime<chrono::Utc>,
+}
+
+impl FocusSession {
+    /// Whole minutes spent focused
+    pub fn minutes(&self) -> f64 {
+        (self.ended - self.started).num_minutes().max(0) as f64
+    }
+
+    /// Local day the session is credited to (the day it started)
+    pub fn day(&self) -> NaiveDate {
+        self.started.with_timezone(&Local).date_naive()
+    }
 }
 
 /// Period type for reviews
@@ -965,6 +2128,538 @@ impl Review {
     }
 }
 
+/// Sentiment of one outcome's reflection text on one day (see `crate::sentiment`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SentimentPoint {
+    pub date: NaiveDate,
+    pub outcome: OutcomeType,
+    pub score: f64, // -1.0 (negative) to 1.0 (positive)
+    pub hits: u32,  // Lexicon words the score is based on
+}
+
+/// Root structure for sentiment.json
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SentimentHistory {
+    pub version: u32,
+    pub points: Vec<SentimentPoint>,
+}
+
+impl Default for SentimentHistory {
+    fn default() -> Self {
+        Self {
+            version: 1,
+            points: Vec::new(),
+        }
+    }
+}
+
+impl SentimentHistory {
+    /// Scores for `outcome` in date order
+    pub fn series(&self, outcome: OutcomeType) -> Vec<(NaiveDate, f64)> {
+        let mut series: Vec<(NaiveDate, f64)> = self
+            .points
+            .iter()
+            .filter(|point| point.outcome == outcome)
+            .map(|point| (point.date, point.score))
+            .collect();
+        series.sort_by_key(|(date, _)| *date);
+        series
+    }
+}
+
+/// Completion figures for one day's goals file, cached in stats.json so
+/// rollups only re-parse files whose size or mtime changed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayRollup {
+    pub modified: u64, // File mtime, seconds since the epoch
+    pub len: u64,
+    pub completion: f64,
+    pub outcomes: [f64; 3], // Work, Health, Family
+    /// Completed and planned (non-empty) actions per outcome
+    #[serde(default)]
+    pub actions: [(u32, u32); 3],
+}
+
+/// Root structure for stats.json
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatsCache {
+    pub version: u32,
+    #[serde(default)]
+    pub days: BTreeMap<NaiveDate, DayRollup>,
+}
+
+impl Default for StatsCache {
+    fn default() -> Self {
+        Self {
+            version: 2,
+            days: BTreeMap::new(),
+        }
+    }
+}
+
+/// What one day earned on the scoreboard (see `crate::scoreboard`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayScore {
+    pub completions: u32,
+    /// Non-empty actions planned
+    pub planned: u32,
+    /// Consecutive days up to this one with a completed action
+    pub streak: u32,
+    /// Indicators whose last reading of the day met its target
+    pub target_hits: u32,
+}
+
+/// Root structure for profile.json
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    pub version: u32,
+    #[serde(default)]
+    pub days: BTreeMap<NaiveDate, DayScore>,
+    /// Achievement ids and the day each was earned
+    #[serde(default)]
+    pub achievements: BTreeMap<String, NaiveDate>,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self {
+            version: 1,
+            days: BTreeMap::new(),
+            achievements: BTreeMap::new(),
+        }
+    }
+}
+
+/// Local sinks for state-change events (see `crate::events`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EventSettings {
+    #[serde(default)]
+    pub socket: Option<String>, // Unix socket an automation listens on
+    #[serde(default)]
+    pub file: Option<String>, // NDJSON file each event is appended to
+}
+
+/// How evening summaries are drafted (see `crate::summary`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SummarySettings {
+    #[serde(default)]
+    pub endpoint: Option<String>, // OpenAI-compatible chat completions URL, e.g. "http://localhost:11434/v1/chat/completions"
+    #[serde(default)]
+    pub model: Option<String>, // Model name sent with each request; "llama3" when unset
+    #[serde(default)]
+    pub api_key_env: Option<String>, // Environment variable holding the API key, e.g. "OPENAI_API_KEY"
+    #[serde(default)]
+    pub timeout_secs: Option<u64>, // Seconds before a draft request gives up; 30 when unset
+    #[serde(default)]
+    pub template: Option<String>, // Summary template file; relative paths are under the data root
+}
+
+/// User preferences stored in settings.json under the data root
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Settings {
+    #[serde(default)]
+    pub events: EventSettings,
+    #[serde(default)]
+    pub summary: SummarySettings,
+    #[serde(default, skip_serializing_if = "GoalsLayout::is_default")]
+    pub goals_layout: GoalsLayout,
+    #[serde(default, skip_serializing_if = "ThemePreset::is_default")]
+    pub theme: ThemePreset,
+    #[serde(default, skip_serializing_if = "WorkingHours::is_default")]
+    pub working_hours: WorkingHours,
+    /// Minutes of estimated work that fit in a day
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_capacity_min: Option<u32>,
+    #[serde(default, skip_serializing_if = "BackupSettings::is_default")]
+    pub backups: BackupSettings,
+    /// Days before trashed objectives and indicators are purged
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trash_retention_days: Option<u32>,
+    /// Folder the data directory is synced with (see `crate::sync`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sync_dir: Option<String>,
+    #[serde(default, skip_serializing_if = "ObsidianSettings::is_default")]
+    pub obsidian: ObsidianSettings,
+    #[serde(default, skip_serializing_if = "NudgeSettings::is_default")]
+    pub nudge: NudgeSettings,
+    #[serde(default, skip_serializing_if = "DigestSettings::is_default")]
+    pub digest: DigestSettings,
+    #[serde(default, skip_serializing_if = "DashboardGroups::is_default")]
+    pub dashboard: DashboardGroups,
+    #[serde(default, skip_serializing_if = "UnitPreferences::is_default")]
+    pub units: UnitPreferences,
+    /// Command that takes text to copy on stdin, e.g. "clip.exe" under WSL;
+    /// unset, the platform's clipboard tool is found on PATH
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clipboard_command: Option<String>,
+    /// Key remaps by binding name, e.g. "goals.snooze": "s"; several keys
+    /// are separated by spaces (see `ui::keymap`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub keys: BTreeMap<String, String>,
+    /// Version whose what's-new notes were last shown (see `crate::changelog`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_seen_version: Option<String>,
+}
+
+impl Settings {
+    pub fn daily_capacity(&self) -> u32 {
+        self.daily_capacity_min
+            .unwrap_or(DEFAULT_DAILY_CAPACITY_MIN)
+    }
+
+    pub fn trash_retention(&self) -> u32 {
+        self.trash_retention_days
+            .unwrap_or(DEFAULT_TRASH_RETENTION_DAYS)
+    }
+}
+
+/// Retention of the copies kept under backups/ (see `crate::backups`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupSettings {
+    /// Copies kept per file
+    pub keep: usize,
+    /// Copies older than this are removed, except the newest of each file
+    pub max_age_days: u32,
+}
+
+impl Default for BackupSettings {
+    fn default() -> Self {
+        Self {
+            keep: 20,
+            max_age_days: 30,
+        }
+    }
+}
+
+impl BackupSettings {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Obsidian interop (see `crate::obsidian`)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObsidianSettings {
+    /// Write goals files as Obsidian daily notes
+    #[serde(default)]
+    pub enabled: bool,
+    /// Vault folder of the daily notes, used as the goals directory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vault_dir: Option<String>,
+}
+
+impl ObsidianSettings {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Weekly accountability digests (see `crate::digest`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DigestSettings {
+    #[serde(default)]
+    pub mail_command: Option<String>, // Local command fed the message on stdin, e.g. "sendmail -t"
+    #[serde(default)]
+    pub recipients: Vec<DigestRecipient>,
+}
+
+impl DigestSettings {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// How indicator values are shown (see `crate::units`). Values are always
+/// stored in the indicator's own unit; these only change the display.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnitPreferences {
+    #[serde(default)]
+    pub duration: DurationDisplay,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distance: Option<DistanceUnit>, // None shows each indicator's own unit
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub compact_money: bool, // "$1.2k" rather than "1200 dollars"
+}
+
+impl UnitPreferences {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Minutes indicators read as "90 minutes" or as "1h30m"
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DurationDisplay {
+    #[default]
+    Minutes,
+    Hours,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DistanceUnit {
+    Km,
+    Miles,
+}
+
+impl DistanceUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DistanceUnit::Km => "km",
+            DistanceUnit::Miles => "mi",
+        }
+    }
+
+    /// Kilometres in one of this unit
+    pub fn km(&self) -> f64 {
+        match self {
+            DistanceUnit::Km => 1.0,
+            DistanceUnit::Miles => 1.609344,
+        }
+    }
+}
+
+/// Dashboard panels that list indicators
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IndicatorPanel {
+    LiveMetrics,
+    Performance,
+    Signals,
+}
+
+impl IndicatorPanel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IndicatorPanel::LiveMetrics => "metrics",
+            IndicatorPanel::Performance => "performance",
+            IndicatorPanel::Signals => "signals",
+        }
+    }
+
+    pub fn parse(word: &str) -> Option<Self> {
+        match word.to_lowercase().as_str() {
+            "metrics" | "live" | "live_metrics" => Some(IndicatorPanel::LiveMetrics),
+            "performance" | "perf" => Some(IndicatorPanel::Performance),
+            "signals" => Some(IndicatorPanel::Signals),
+            _ => None,
+        }
+    }
+}
+
+/// Indicator groups each dashboard panel shows; a panel without groups
+/// shows every active indicator
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DashboardGroups {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub live_metrics: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub performance: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub signals: Vec<String>,
+}
+
+impl DashboardGroups {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn groups(&self, panel: IndicatorPanel) -> &[String] {
+        match panel {
+            IndicatorPanel::LiveMetrics => &self.live_metrics,
+            IndicatorPanel::Performance => &self.performance,
+            IndicatorPanel::Signals => &self.signals,
+        }
+    }
+
+    pub fn groups_mut(&mut self, panel: IndicatorPanel) -> &mut Vec<String> {
+        match panel {
+            IndicatorPanel::LiveMetrics => &mut self.live_metrics,
+            IndicatorPanel::Performance => &mut self.performance,
+            IndicatorPanel::Signals => &mut self.signals,
+        }
+    }
+
+    /// Whether `indicator` belongs in `panel`; group names match without case
+    pub fn shows(&self, panel: IndicatorPanel, indicator: &IndicatorDef) -> bool {
+        let groups = self.groups(panel);
+        groups.is_empty()
+            || indicator
+                .group
+                .as_deref()
+                .is_some_and(|group| groups.iter().any(|name| name.eq_ignore_ascii_case(group)))
+    }
+}
+
+/// Someone a weekly digest goes to, by email and/or a shared folder
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DigestRecipient {
+    pub name: String,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub folder: Option<String>, // Relative paths are under the data root
+    /// Outcomes whose action text the recipient may see; others are counts only
+    #[serde(default)]
+    pub share_actions: Vec<OutcomeType>,
+}
+
+/// Evening nudge when an outcome has nothing done yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NudgeSettings {
+    /// Local hour from which the streak-at-risk banner is shown
+    pub hour: u32,
+    /// Also emit a `streak_at_risk` event to the configured event sinks
+    #[serde(default)]
+    pub notify: bool,
+}
+
+impl Default for NudgeSettings {
+    fn default() -> Self {
+        Self {
+            hour: 19,
+            notify: false,
+        }
+    }
+}
+
+impl NudgeSettings {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Hours shown on the day timeline, as whole hours of the day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkingHours {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl Default for WorkingHours {
+    fn default() -> Self {
+        Self { start: 9, end: 18 }
+    }
+}
+
+impl WorkingHours {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Color preset for the TUI. Status is also shown with symbols, so every
+/// preset stays readable without telling hues apart.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ThemePreset {
+    #[default]
+    Dark,
+    /// Blue/orange/vermillion, distinguishable with red-green color blindness
+    Colorblind,
+    /// Cyan/pink/red, distinguishable with blue-yellow color blindness
+    Tritan,
+    /// Shades of grey only
+    Monochrome,
+}
+
+impl ThemePreset {
+    pub const ALL: [ThemePreset; 4] = [
+        ThemePreset::Dark,
+        ThemePreset::Colorblind,
+        ThemePreset::Tritan,
+        ThemePreset::Monochrome,
+    ];
+
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThemePreset::Dark => "dark",
+            ThemePreset::Colorblind => "colorblind",
+            ThemePreset::Tritan => "tritan",
+            ThemePreset::Monochrome => "monochrome",
+        }
+    }
+}
+
+impl std::str::FromStr for ThemePreset {
+    type Err = anyhow::Error;
+
+    fn from_str(name: &str) -> anyhow::Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.as_str().eq_ignore_ascii_case(name.trim()))
+            .ok_or_else(|| {
+                let names: Vec<&str> = Self::ALL.iter().map(|p| p.as_str()).collect();
+                anyhow::anyhow!("Unknown theme '{}' (expected {})", name, names.join(", "))
+            })
+    }
+}
+
+/// strftime-style pattern for goals file paths under the goals directory,
+/// e.g. `%Y/%m/%Y-%m-%d.md` for year and month folders
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GoalsLayout(pub String);
+
+impl Default for GoalsLayout {
+    fn default() -> Self {
+        Self("%Y-%m-%d.md".to_string())
+    }
+}
+
+impl GoalsLayout {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Check the pattern names a `.md` file inside the goals directory and
+    /// maps every date to its own path and back
+    pub fn validate(&self) -> anyhow::Result<()> {
+        use chrono::format::{Item, StrftimeItems};
+
+        let pattern = self.0.as_str();
+        if StrftimeItems::new(pattern).any(|item| matches!(item, Item::Error)) {
+            anyhow::bail!("Invalid date specifier in layout '{}'", pattern);
+        }
+        if !pattern.ends_with(".md") {
+            anyhow::bail!("Layout '{}' must end in .md", pattern);
+        }
+        if pattern.starts_with('/')
+            || pattern.contains('\\')
+            || pattern
+                .split('/')
+                .any(|part| part.is_empty() || part == "..")
+        {
+            anyhow::bail!(
+                "Layout '{}' must be a relative path inside the goals directory",
+                pattern
+            );
+        }
+        for (y, m, d) in [(2024, 2, 29), (2025, 1, 1), (2025, 12, 31)] {
+            let date = NaiveDate::from_ymd_opt(y, m, d).expect("valid sample date");
+            if self.parse_date(&self.relative_path(date)) != Some(date) {
+                anyhow::bail!("Layout '{}' needs the year, month and day", pattern);
+            }
+        }
+        Ok(())
+    }
+
+    /// Path of `date`'s goals file relative to the goals directory, using `/`
+    pub fn relative_path(&self, date: NaiveDate) -> String {
+        date.format(&self.0).to_string()
+    }
+
+    /// Date of a goals file from its `/`-separated relative path
+    pub fn parse_date(&self, relative: &str) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(relative, &self.0).ok()
+    }
+}
+
 /// Root structure for review JSON files
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReviewData {
@@ -1018,6 +2713,14 @@ impl Config {
         })
     }
 
+    /// Config for a data directory chosen by the user, goals under goals/
+    pub fn at(data_root: &std::path::Path) -> Self {
+        Self {
+            goals_dir: data_root.join("goals").to_string_lossy().to_string(),
+            data_root: data_root.to_string_lossy().to_string(),
+        }
+    }
+
     /// Safe default that won't panic
     pub fn default_safe() -> Self {
         Self::new().unwrap_or_else(|_| Self {
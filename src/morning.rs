@@ -0,0 +1,202 @@
+//! Morning intentions: a guided pass that looks back at yesterday, picks one
+//! priority action per outcome with a time estimate, and writes every choice
+//! in a single save at the end.
+
+use crate::models::{Config, DailyGoals, OutcomeType};
+use anyhow::Result;
+
+/// Outcomes in the order the wizard walks through them
+pub const OUTCOMES: [OutcomeType; 3] =
+    [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family];
+
+/// Priority tier given to the action chosen for an outcome
+pub const TOP_PRIORITY: u32 = 1;
+
+/// How one outcome went yesterday
+#[derive(Debug, Clone, PartialEq)]
+pub struct YesterdayOutcome {
+    pub outcome_type: OutcomeType,
+    pub completed: usize,
+    pub planned: usize,
+    pub unfinished: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Choice {
+    /// Index of one of today's actions
+    Existing(usize),
+    /// A new action, placed in the first free slot on save
+    New(String),
+}
+
+/// Today's priority for one outcome
+#[derive(Debug, Clone, PartialEq)]
+pub struct Intention {
+    pub outcome_type: OutcomeType,
+    /// Today's actions with text, as (index, text)
+    pub actions: Vec<(usize, String)>,
+    pub choice: Option<Choice>,
+    pub estimate_min: Option<u32>,
+}
+
+impl Intention {
+    pub fn chosen_text(&self) -> Option<&str> {
+        match self.choice.as_ref()? {
+            Choice::Existing(index) => self
+                .actions
+                .iter()
+                .find(|(i, _)| i == index)
+                .map(|(_, text)| text.as_str()),
+            Choice::New(text) => Some(text),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MorningPlan {
+    /// None when there is no file for yesterday
+    pub yesterday: Option<Vec<YesterdayOutcome>>,
+    /// In `OUTCOMES` order
+    pub intentions: Vec<Intention>,
+}
+
+impl MorningPlan {
+    /// A plan for the day of `goals`, starting from the priorities and
+    /// estimates already saved, else the first unfinished action
+    pub fn start(goals: &DailyGoals, config: &Config) -> Result<Self> {
+        let yesterday = crate::data::get_yesterday_goals(goals.date, config)?.map(|yesterday| {
+            OUTCOMES
+                .iter()
+                .map(|&outcome_type| {
+                    let actions: Vec<_> = yesterday
+                        .outcome(outcome_type)
+                        .actions
+                        .iter()
+                        .filter(|action| !action.text.trim().is_empty())
+                        .collect();
+                    YesterdayOutcome {
+                        outcome_type,
+                        completed: actions.iter().filter(|action| action.completed).count(),
+                        planned: actions.len(),
+                        unfinished: actions
+                            .iter()
+                            .filter(|action| !action.completed)
+                            .map(|action| action.text.clone())
+                            .collect(),
+                    }
+                })
+                .collect()
+        });
+
+        let meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+        let intentions = OUTCOMES
+            .iter()
+            .map(|&outcome_type| {
+                let outcome = goals.outcome(outcome_type);
+                let metas = meta.outcome(outcome_type);
+                let top = metas
+                    .iter()
+                    .position(|action_meta| action_meta.priority == Some(TOP_PRIORITY))
+                    .filter(|&i| {
+                        outcome
+                            .actions
+                            .get(i)
+                            .is_some_and(|a| !a.text.trim().is_empty())
+                    });
+                let choice = top.or_else(|| {
+                    outcome
+                        .actions
+                        .iter()
+                        .position(|a| !a.completed && !a.text.trim().is_empty())
+                });
+                Intention {
+                    outcome_type,
+                    actions: outcome
+                        .actions
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, action)| !action.text.trim().is_empty())
+                        .map(|(i, action)| (i, action.text.clone()))
+                        .collect(),
+                    choice: choice.map(Choice::Existing),
+                    estimate_min: choice
+                        .and_then(|i| metas.get(i))
+                        .and_then(|action_meta| action_meta.estimated_min),
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            yesterday,
+            intentions,
+        })
+    }
+
+    /// Estimated minutes of the chosen priorities
+    pub fn total_estimate(&self) -> u32 {
+        self.intentions
+            .iter()
+            .filter(|intention| intention.choice.is_some())
+            .filter_map(|intention| intention.estimate_min)
+            .sum()
+    }
+}
+
+/// Whether any action of the day already has the top priority
+pub fn is_planned(goals: &DailyGoals, config: &Config) -> Result<bool> {
+    let meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+    Ok(OUTCOMES.iter().any(|&outcome_type| {
+        meta.outcome(outcome_type)
+            .iter()
+            .any(|action_meta| action_meta.priority == Some(TOP_PRIORITY))
+    }))
+}
+
+/// Write the plan: new actions go into `goals`, and the chosen ones get the
+/// top priority and their estimates, taking it from any other action of the
+/// outcome. Returns how many priorities were set.
+pub fn apply(plan: &MorningPlan, goals: &mut DailyGoals, config: &Config) -> Result<usize> {
+    let mut chosen = Vec::new();
+    let mut added = false;
+    for intention in &plan.intentions {
+        let index = match &intention.choice {
+            Some(Choice::Existing(index)) => Some(*index),
+            Some(Choice::New(text)) => {
+                added = true;
+                Some(
+                    goals
+                        .outcome_mut(intention.outcome_type)
+                        .place_action(text.trim().to_string())?,
+                )
+            }
+            None => None,
+        };
+        chosen.push((intention, index));
+    }
+    if added {
+        crate::data::write_goals_file(goals, config)?;
+    }
+
+    let mut meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+    let mut set = 0;
+    for (intention, index) in chosen {
+        for (i, action_meta) in meta
+            .outcome_mut(intention.outcome_type)
+            .iter_mut()
+            .enumerate()
+        {
+            if Some(i) == index {
+                action_meta.priority = Some(TOP_PRIORITY);
+                if intention.estimate_min.is_some() {
+                    action_meta.estimated_min = intention.estimate_min;
+                }
+                set += 1;
+            } else if action_meta.priority == Some(TOP_PRIORITY) {
+                action_meta.priority = None;
+            }
+        }
+    }
+    meta.modified = chrono::Utc::now();
+    crate::data::save_day_meta(goals.date, &meta, config)?;
+    Ok(set)
+}
@@ -0,0 +1,277 @@
+//! Obsidian interop: with the mode on, goals files are written as daily
+//! notes with YAML frontmatter and `[[wiki-links]]` to objectives, and the
+//! goals directory can be a folder of an Obsidian vault. Rewriting a note
+//! keeps whatever else it holds; tasks tagged #work, #health or #family
+//! outside the outcome sections are read as actions of that outcome.
+
+use crate::models::{Config, DailyGoals, OutcomeType};
+use chrono::NaiveDate;
+use regex::Regex;
+use serde_yaml::{Mapping, Value};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Tag added to the notes FocusFive writes
+const TAG: &str = "focusfive";
+
+/// What FocusFive reads from a note's frontmatter
+#[derive(Debug, Default)]
+pub struct Frontmatter {
+    pub date: Option<NaiveDate>,
+    pub day: Option<u32>,
+    /// Objective ids by the titles the note links to
+    pub objectives: HashMap<String, String>,
+}
+
+impl Frontmatter {
+    /// Read the YAML of `split_frontmatter`; anything unreadable is ignored
+    pub fn parse(yaml: Option<&str>) -> Self {
+        let Some(Ok(Value::Mapping(map))) = yaml.map(serde_yaml::from_str::<Value>) else {
+            return Self::default();
+        };
+        let get = |key: &str| map.get(Value::from(key));
+        let date = get("date")
+            .and_then(Value::as_str)
+            .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok());
+        let day = get("day")
+            .and_then(Value::as_u64)
+            .and_then(|day| u32::try_from(day).ok());
+        let objectives = match get("objectives") {
+            Some(Value::Mapping(links)) => links
+                .iter()
+                .filter_map(|(title, id)| Some((title.as_str()?.into(), id.as_str()?.into())))
+                .collect(),
+            _ => HashMap::new(),
+        };
+        Self {
+            date,
+            day,
+            objectives,
+        }
+    }
+
+    /// The objective id of an `objective:` reference, which is either an
+    /// id or a wiki-link; links to titles the note does not map stay titles
+    pub fn objective_id(&self, reference: &str) -> String {
+        match link_target(reference) {
+            Some(title) => self
+                .objectives
+                .get(title)
+                .cloned()
+                .unwrap_or_else(|| title.to_string()),
+            None => reference.to_string(),
+        }
+    }
+}
+
+/// Split a note into the YAML between its leading `---` lines and the rest
+pub fn split_frontmatter(content: &str) -> (Option<&str>, &str) {
+    let Some(rest) = content
+        .strip_prefix("---\n")
+        .or_else(|| content.strip_prefix("---\r\n"))
+    else {
+        return (None, content);
+    };
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            return (Some(&rest[..offset]), &rest[offset + line.len()..]);
+        }
+        offset += line.len();
+    }
+    (None, content)
+}
+
+/// "Title" of `[[Title]]` or `[[Title|shown text]]`
+fn link_target(reference: &str) -> Option<&str> {
+    let inner = reference.strip_prefix("[[")?.strip_suffix("]]")?;
+    Some(inner.split('|').next().unwrap_or(inner).trim())
+}
+
+fn tag_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"(?i)(^|\s)#(work|health|family)\b").expect("valid outcome tag pattern")
+    })
+}
+
+/// The outcome a task line is tagged with, and the line without the tag
+pub fn outcome_tag(line: &str) -> Option<(OutcomeType, String)> {
+    let caps = tag_pattern().captures(line)?;
+    let outcome = match caps[2].to_lowercase().as_str() {
+        "work" => OutcomeType::Work,
+        "health" => OutcomeType::Health,
+        _ => OutcomeType::Family,
+    };
+    let whole = caps.get(0)?;
+    let untagged = format!("{}{}", &line[..whole.start()], &line[whole.end()..]);
+    Some((outcome, untagged.trim_end().to_string()))
+}
+
+/// Whether goals files are written as Obsidian notes
+pub fn enabled(config: &Config) -> bool {
+    crate::data::load_settings(config).is_ok_and(|settings| settings.obsidian.enabled)
+}
+
+/// `config` with the vault folder from settings.json as its goals directory
+pub fn with_vault(mut config: Config) -> Config {
+    if let Ok(settings) = crate::data::load_settings(&config) {
+        if let (true, Some(dir)) = (settings.obsidian.enabled, settings.obsidian.vault_dir) {
+            config.goals_dir = dir;
+        }
+    }
+    config
+}
+
+/// Titles that can be written inside a wiki-link
+fn linkable(title: &str) -> bool {
+    !title.is_empty() && !title.contains(['[', ']', '|', ',', '\n'])
+}
+
+/// `goals` as an Obsidian note, keeping the frontmatter keys and sections
+/// of `existing` that FocusFive does not write
+pub fn render_note(goals: &DailyGoals, existing: Option<String>, config: &Config) -> String {
+    let titles: HashMap<String, String> = crate::data::load_or_create_objectives(config)
+        .map(|data| {
+            data.objectives
+                .into_iter()
+                .filter(|objective| linkable(&objective.title))
+                .map(|objective| (objective.id, objective.title))
+                .collect()
+        })
+        .unwrap_or_default();
+    let mut links = Mapping::new();
+    for outcome in goals.outcomes() {
+        for id in outcome
+            .actions
+            .iter()
+            .flat_map(|a| a.get_all_objective_ids())
+        {
+            if let Some(title) = titles.get(&id) {
+                links.insert(title.as_str().into(), id.into());
+            }
+        }
+    }
+    let body = crate::data::generate_markdown_with(goals, &|id| match titles.get(id) {
+        Some(title) => format!("[[{}]]", title),
+        None => id.to_string(),
+    });
+
+    let existing = existing.unwrap_or_default();
+    let (yaml, rest) = split_frontmatter(&existing);
+    let frontmatter = match yaml.map(serde_yaml::from_str::<Value>) {
+        None | Some(Ok(Value::Null)) => Some(Mapping::new()),
+        Some(Ok(Value::Mapping(map))) => Some(map),
+        // Frontmatter FocusFive cannot read is left as it is
+        Some(_) => None,
+    };
+    let frontmatter = match frontmatter {
+        Some(mut map) => {
+            map.insert(
+                "date".into(),
+                goals.date.format("%Y-%m-%d").to_string().into(),
+            );
+            match goals.day_number {
+                Some(day) => map.insert("day".into(), day.into()),
+                None => map.remove("day"),
+            };
+            let tags = map
+                .entry("tags".into())
+                .or_insert_with(|| Value::Sequence(Vec::new()));
+            if let Value::Sequence(tags) = tags {
+                if !tags.iter().any(|tag| tag.as_str() == Some(TAG)) {
+                    tags.push(TAG.into());
+                }
+            }
+            match links.is_empty() {
+                true => map.remove("objectives"),
+                false => map.insert("objectives".into(), Value::Mapping(links)),
+            };
+            serde_yaml::to_string(&map).unwrap_or_default()
+        }
+        None => yaml.unwrap_or_default().to_string(),
+    };
+
+    let mut note = format!("---\n{}", frontmatter);
+    if !note.ends_with('\n') {
+        note.push('\n');
+    }
+    note.push_str("---\n\n");
+    note.push_str(&body);
+    let kept = other_content(rest);
+    if !kept.is_empty() {
+        note.push_str(&kept);
+        note.push('\n');
+    }
+    note
+}
+
+/// The lines of a note body that FocusFive does not write: all but the
+/// date header, the outcome sections and the tagged tasks, which the
+/// outcome sections take in
+fn other_content(body: &str) -> String {
+    let mut kept: Vec<&str> = Vec::new();
+    let mut in_outcome = false;
+    let mut header_seen = false;
+    for (index, line) in body.lines().enumerate() {
+        if crate::data::outcome_header(line).is_some() {
+            in_outcome = true;
+            continue;
+        }
+        if crate::data::is_heading(line) {
+            in_outcome = false;
+        }
+        let trimmed = line.trim();
+        let date_header = !header_seen
+            && index < 10
+            && trimmed.starts_with('#')
+            && crate::data::parse_date_header(trimmed).is_ok();
+        header_seen |= date_header;
+        let tagged_task = trimmed.starts_with("- [") && outcome_tag(trimmed).is_some();
+        if !(in_outcome || date_header || tagged_task) {
+            kept.push(line);
+        }
+    }
+    while kept.first().is_some_and(|line| line.trim().is_empty()) {
+        kept.remove(0);
+    }
+    while kept.last().is_some_and(|line| line.trim().is_empty()) {
+        kept.pop();
+    }
+    // Text after the last outcome section would otherwise be read as part of it
+    if kept
+        .first()
+        .is_some_and(|line| !crate::data::is_heading(line))
+    {
+        kept.insert(0, "## Notes");
+    }
+    kept.join("\n")
+}
+
+/// Link objectives typed by title in Obsidian (`[[Title]]` with no id in
+/// the frontmatter) to the objective of that title
+pub fn resolve_links(mut goals: DailyGoals, config: &Config) -> DailyGoals {
+    let Ok(objectives) = crate::data::load_or_create_objectives(config) else {
+        return goals;
+    };
+    let by_title: HashMap<String, String> = objectives
+        .objectives
+        .iter()
+        .map(|objective| (objective.title.to_lowercase(), objective.id.clone()))
+        .collect();
+    let is_id = |id: &str| objectives.objectives.iter().any(|o| o.id == id);
+    for outcome in goals.outcomes_mut() {
+        for action in &mut outcome.actions {
+            for linked in action.get_all_objective_ids() {
+                if is_id(&linked) {
+                    continue;
+                }
+                if let Some(id) = by_title.get(&linked.to_lowercase()) {
+                    action.remove_objective_id(&linked);
+                    action.add_objective_id(id.clone());
+                }
+            }
+        }
+    }
+    goals
+}
@@ -0,0 +1,125 @@
+//! First run: with nothing in the data root yet, a setup wizard
+//! (`ui::first_run`) asks where the data should live, what each outcome is
+//! about and a one-line vision for it, and for a starter objective and
+//! indicator; `apply` writes them so the app opens on that data. A data
+//! directory other than the default is remembered in the user's config
+//! directory (see `location_file`).
+
+use crate::models::{Config, IndicatorDef, IndicatorKind, IndicatorUnit, Objective, OutcomeType};
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Whether `dir` holds anything
+fn has_content(dir: &str) -> bool {
+    fs::read_dir(dir).is_ok_and(|mut entries| entries.next().is_some())
+}
+
+/// Nothing has been written yet, so the setup wizard should run
+pub fn is_first_run(config: &Config) -> bool {
+    !has_content(&config.data_root) && !has_content(&config.goals_dir)
+}
+
+/// File remembering a data directory chosen during setup
+pub fn location_file() -> Option<PathBuf> {
+    directories::ProjectDirs::from("com", "Correia", "FocusFive")
+        .map(|dirs| dirs.config_dir().join("data_root"))
+}
+
+/// Data directory remembered in `file`, if any
+pub fn saved_location(file: &Path) -> Option<PathBuf> {
+    let saved = fs::read_to_string(file).ok()?;
+    let saved = saved.trim();
+    (!saved.is_empty()).then(|| PathBuf::from(saved))
+}
+
+/// What the setup wizard collected; empty text is skipped
+#[derive(Debug, Clone, PartialEq)]
+pub struct Setup {
+    pub data_root: PathBuf,
+    /// Goal of each outcome (Work, Health, Family), shown in its heading
+    pub goals: [String; 3],
+    pub visions: [String; 3],
+    pub objective: String,
+    pub objective_domain: OutcomeType,
+    pub indicator: String,
+    pub indicator_unit: IndicatorUnit,
+}
+
+impl Setup {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            data_root: PathBuf::from(&config.data_root),
+            goals: Default::default(),
+            visions: Default::default(),
+            objective: String::new(),
+            objective_domain: OutcomeType::Work,
+            indicator: String::new(),
+            indicator_unit: IndicatorUnit::Count,
+        }
+    }
+}
+
+/// Write `setup` and return the config to open the app with. A data root
+/// other than `config`'s is saved to `location` so later runs find it.
+pub fn apply(setup: &Setup, config: &Config, location: Option<&Path>) -> Result<Config> {
+    let config = if setup.data_root == Path::new(&config.data_root) {
+        config.clone()
+    } else {
+        let moved = Config::at(&setup.data_root);
+        if let Some(location) = location {
+            if let Some(parent) = location.parent() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create {}", parent.display()))?;
+            }
+            fs::write(location, setup.data_root.to_string_lossy().as_bytes())
+                .with_context(|| format!("Failed to write {}", location.display()))?;
+        }
+        moved
+    };
+    fs::create_dir_all(&config.goals_dir)
+        .with_context(|| format!("Failed to create {}", config.goals_dir))?;
+
+    let outcomes = [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family];
+    let mut vision = crate::data::load_or_create_vision(&config)?;
+    if setup.visions.iter().any(|line| !line.trim().is_empty()) {
+        for (outcome_type, line) in outcomes.iter().zip(&setup.visions) {
+            vision.set_vision(outcome_type, line.trim().to_string());
+        }
+        crate::data::save_vision(&vision, &config)?;
+    }
+
+    let today = chrono::Local::now().date_naive();
+    let mut goals = crate::data::load_or_create_goals(today, &config)?;
+    for (outcome_type, goal) in outcomes.iter().zip(&setup.goals) {
+        let goal = goal.trim();
+        if !goal.is_empty() {
+            goals.outcome_mut(*outcome_type).goal = Some(goal.to_string());
+        }
+    }
+    crate::data::write_goals_file(&goals, &config)?;
+
+    let mut objectives = crate::data::load_or_create_objectives(&config)?;
+    let mut indicators = crate::data::load_or_create_indicators(&config)?;
+    let mut objective = (!setup.objective.trim().is_empty())
+        .then(|| Objective::new(setup.objective_domain, setup.objective.trim().to_string()));
+    if !setup.indicator.trim().is_empty() {
+        let mut indicator = IndicatorDef::new(
+            setup.indicator.trim().to_string(),
+            IndicatorKind::Leading,
+            setup.indicator_unit.clone(),
+        );
+        if let Some(objective) = objective.as_mut() {
+            indicator.objective_id = Some(objective.id.clone());
+            objective.indicators.push(indicator.id.clone());
+        }
+        indicators.indicators.push(indicator);
+        crate::data::save_indicators(&indicators, &config)?;
+    }
+    if let Some(objective) = objective {
+        objectives.objectives.push(objective);
+        crate::data::save_objectives(&objectives, &config)?;
+    }
+
+    Ok(config)
+}
@@ -0,0 +1,421 @@
+//! Packs: a shareable goal structure (action templates, weekday templates,
+//! objectives and indicator definitions) in one TOML, JSON or YAML file, so a
+//! coach or teammate can hand over a ready-made setup.
+//!
+//! Ids in a pack only need to be unique within the pack. On import an id or
+//! template name already in use is a conflict, resolved by `OnConflict`;
+//! references between the pack's objectives and indicators follow any ids
+//! that had to change.
+
+use crate::models::{
+    Config, IndicatorDef, IndicatorDirection, IndicatorKind, IndicatorUnit, Objective,
+    ObjectiveStatus, OutcomeType, WeekdayTemplate,
+};
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Pack {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub templates: BTreeMap<String, Vec<String>>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub weekday_templates: BTreeMap<String, WeekdayTemplate>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub objectives: Vec<PackObjective>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub indicators: Vec<PackIndicator>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PackObjective {
+    pub id: String,
+    pub domain: OutcomeType,
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Id of another objective in the pack or already in the data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weekly_budget_min: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PackIndicator {
+    pub id: String,
+    pub name: String,
+    pub kind: IndicatorKind,
+    pub unit: IndicatorUnit,
+    /// Id of an objective in the pack or already in the data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub objective_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<f64>,
+    pub direction: IndicatorDirection,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+/// What to do with a pack item whose id or name is already taken
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OnConflict {
+    /// Keep what is there and leave the pack's item out
+    #[default]
+    Skip,
+    /// Overwrite the definition, keeping history such as creation time,
+    /// status and linked indicators
+    Replace,
+    /// Import the pack's item beside the existing one under a new id or name
+    Rename,
+}
+
+impl OnConflict {
+    pub fn parse(text: &str) -> Result<Self> {
+        match text.trim().to_lowercase().as_str() {
+            "skip" => Ok(OnConflict::Skip),
+            "replace" => Ok(OnConflict::Replace),
+            "rename" => Ok(OnConflict::Rename),
+            other => bail!(
+                "Unknown conflict policy '{}': use skip, replace or rename",
+                other
+            ),
+        }
+    }
+}
+
+/// Items of one kind handled by an import
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImportCounts {
+    pub added: usize,
+    pub replaced: usize,
+    pub renamed: usize,
+    pub skipped: usize,
+}
+
+impl ImportCounts {
+    fn record(&mut self, conflict: bool, on_conflict: OnConflict) {
+        match (conflict, on_conflict) {
+            (false, _) => self.added += 1,
+            (true, OnConflict::Skip) => self.skipped += 1,
+            (true, OnConflict::Replace) => self.replaced += 1,
+            (true, OnConflict::Rename) => self.renamed += 1,
+        }
+    }
+
+    /// e.g. "2 added, 1 skipped"
+    pub fn describe(&self) -> String {
+        let parts: Vec<String> = [
+            (self.added, "added"),
+            (self.replaced, "replaced"),
+            (self.renamed, "renamed"),
+            (self.skipped, "skipped"),
+        ]
+        .iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, label)| format!("{} {}", count, label))
+        .collect();
+        if parts.is_empty() {
+            "none".to_string()
+        } else {
+            parts.join(", ")
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImportReport {
+    pub objectives: ImportCounts,
+    pub indicators: ImportCounts,
+    pub templates: ImportCounts,
+}
+
+enum Format {
+    Toml,
+    Json,
+    Yaml,
+}
+
+fn format_of(path: &Path) -> Result<Format> {
+    match path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+        .as_deref()
+    {
+        Some("toml") => Ok(Format::Toml),
+        Some("json") => Ok(Format::Json),
+        Some("yaml") | Some("yml") => Ok(Format::Yaml),
+        _ => bail!(
+            "Unsupported pack file {}: use .toml, .json, .yaml or .yml",
+            path.display()
+        ),
+    }
+}
+
+pub fn read_pack(path: &Path) -> Result<Pack> {
+    let format = format_of(path)?;
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read pack: {}", path.display()))?;
+    let pack: Pack = match format {
+        Format::Toml => toml::from_str(&content).map_err(anyhow::Error::from),
+        Format::Json => serde_json::from_str(&content).map_err(anyhow::Error::from),
+        Format::Yaml => serde_yaml::from_str(&content).map_err(anyhow::Error::from),
+    }
+    .with_context(|| format!("Failed to parse pack: {}", path.display()))?;
+    validate(&pack)?;
+    Ok(pack)
+}
+
+pub fn write_pack(pack: &Pack, path: &Path) -> Result<()> {
+    let content = match format_of(path)? {
+        Format::Toml => toml::to_string_pretty(pack)?,
+        Format::Json => serde_json::to_string_pretty(pack)?,
+        Format::Yaml => serde_yaml::to_string(pack)?,
+    };
+    fs::write(path, content).with_context(|| format!("Failed to write pack: {}", path.display()))
+}
+
+/// Ids must be unique within the pack
+fn validate(pack: &Pack) -> Result<()> {
+    let mut seen = HashSet::new();
+    for objective in &pack.objectives {
+        if !seen.insert(&objective.id) {
+            bail!("Pack lists objective '{}' twice", objective.id);
+        }
+    }
+    let mut seen = HashSet::new();
+    for indicator in &pack.indicators {
+        if !seen.insert(&indicator.id) {
+            bail!("Pack lists indicator '{}' twice", indicator.id);
+        }
+    }
+    Ok(())
+}
+
+/// A pack of the current templates, and the active and paused objectives
+/// with their active indicators
+pub fn export(name: &str, config: &Config) -> Result<Pack> {
+    let objectives = crate::data::load_or_create_objectives(config)?;
+    let indicators = crate::data::load_or_create_indicators(config)?;
+    let templates = crate::data::load_or_create_templates(config)?;
+
+    let objectives: Vec<PackObjective> = objectives
+        .objectives
+        .iter()
+        .filter(|o| matches!(o.status, ObjectiveStatus::Active | ObjectiveStatus::Paused))
+        .map(|o| PackObjective {
+            id: o.id.clone(),
+            domain: o.domain,
+            title: o.title.clone(),
+            description: o.description.clone(),
+            parent_id: o.parent_id.clone(),
+            color: o.color.clone(),
+            weekly_budget_min: o.weekly_budget_min,
+        })
+        .collect();
+    let exported: HashSet<&str> = objectives.iter().map(|o| o.id.as_str()).collect();
+    let indicators = indicators
+        .indicators
+        .iter()
+        .filter(|def| def.active)
+        .map(|def| PackIndicator {
+            id: def.id.clone(),
+            name: def.name.clone(),
+            kind: def.kind.clone(),
+            unit: def.unit.clone(),
+            objective_id: def
+                .objective_id
+                .clone()
+                .filter(|id| exported.contains(id.as_str())),
+            target: def.target,
+            direction: def.direction.clone(),
+            notes: def.notes.clone(),
+        })
+        .collect();
+    let objectives = objectives
+        .iter()
+        .cloned()
+        .map(|mut o| {
+            o.parent_id = o.parent_id.filter(|id| exported.contains(id.as_str()));
+            o
+        })
+        .collect();
+
+    Ok(Pack {
+        name: name.to_string(),
+        description: None,
+        templates: templates.templates.into_iter().collect(),
+        weekday_templates: templates.weekday_templates.into_iter().collect(),
+        objectives,
+        indicators,
+    })
+}
+
+/// First of "name (2)", "name (3)", ... not in `taken`
+fn free_name(name: &str, taken: impl Fn(&str) -> bool) -> String {
+    (2..)
+        .map(|n| format!("{} ({})", name, n))
+        .find(|candidate| !taken(candidate))
+        .unwrap_or_else(|| name.to_string())
+}
+
+/// Merge `pack` into the data. With `dry_run` nothing is written.
+pub fn import(
+    pack: &Pack,
+    on_conflict: OnConflict,
+    config: &Config,
+    dry_run: bool,
+) -> Result<ImportReport> {
+    validate(pack)?;
+    let mut objectives = crate::data::load_or_create_objectives(config)?;
+    let mut indicators = crate::data::load_or_create_indicators(config)?;
+    let mut templates = crate::data::load_or_create_templates(config)?;
+    let mut report = ImportReport::default();
+    let now = chrono::Utc::now();
+
+    // The id each pack objective has in the data; a skipped one keeps
+    // naming the existing objective
+    let mut objective_ids: HashMap<&str, String> = HashMap::new();
+    let mut write_objectives = Vec::new();
+    for item in &pack.objectives {
+        let conflict = objectives.objectives.iter().any(|o| o.id == item.id);
+        report.objectives.record(conflict, on_conflict);
+        let id = match (conflict, on_conflict) {
+            (true, OnConflict::Rename) => uuid::Uuid::new_v4().to_string(),
+            _ => item.id.clone(),
+        };
+        objective_ids.insert(&item.id, id.clone());
+        if !(conflict && on_conflict == OnConflict::Skip) {
+            write_objectives.push((item, id));
+        }
+    }
+    let resolve_objective = |id: &str, known: &[Objective]| -> Result<String> {
+        match objective_ids.get(id) {
+            Some(stored) => Ok(stored.clone()),
+            None if known.iter().any(|o| o.id == id) => Ok(id.to_string()),
+            None => bail!("Pack refers to unknown objective '{}'", id),
+        }
+    };
+
+    let mut write_indicators = Vec::new();
+    for item in &pack.indicators {
+        let conflict = indicators.indicators.iter().any(|def| def.id == item.id);
+        report.indicators.record(conflict, on_conflict);
+        let objective_id = item
+            .objective_id
+            .as_deref()
+            .map(|id| resolve_objective(id, &objectives.objectives))
+            .transpose()?;
+        if conflict && on_conflict == OnConflict::Skip {
+            continue;
+        }
+        let id = match (conflict, on_conflict) {
+            (true, OnConflict::Rename) => uuid::Uuid::new_v4().to_string(),
+            _ => item.id.clone(),
+        };
+        write_indicators.push((item, id, objective_id));
+    }
+    let parents: Vec<Option<String>> = write_objectives
+        .iter()
+        .map(|(item, _)| {
+            item.parent_id
+                .as_deref()
+                .map(|id| resolve_objective(id, &objectives.objectives))
+                .transpose()
+        })
+        .collect::<Result<_>>()?;
+
+    for ((item, id), parent_id) in write_objectives.into_iter().zip(parents) {
+        let objective = match objectives.objectives.iter_mut().find(|o| o.id == id) {
+            Some(existing) => existing,
+            None => {
+                let mut objective = Objective::new(item.domain, item.title.clone());
+                objective.id = id;
+                objectives.objectives.push(objective);
+                objectives.objectives.last_mut().expect("just pushed")
+            }
+        };
+        objective.domain = item.domain;
+        objective.title = item.title.clone();
+        objective.description = item.description.clone();
+        objective.parent_id = parent_id;
+        objective.color = item.color.clone();
+        objective.weekly_budget_min = item.weekly_budget_min;
+        objective.modified = now;
+    }
+
+    for (item, id, objective_id) in write_indicators {
+        let def = match indicators.indicators.iter_mut().find(|def| def.id == id) {
+            Some(existing) => existing,
+            None => {
+                let mut def =
+                    IndicatorDef::new(item.name.clone(), item.kind.clone(), item.unit.clone());
+                def.id = id.clone();
+                indicators.indicators.push(def);
+                indicators.indicators.last_mut().expect("just pushed")
+            }
+        };
+        def.name = item.name.clone();
+        def.kind = item.kind.clone();
+        def.unit = item.unit.clone();
+        def.objective_id = objective_id.clone();
+        def.target = item.target;
+        def.direction = item.direction.clone();
+        def.notes = item.notes.clone();
+        def.modified = now;
+        if let Some(objective) =
+            objective_id.and_then(|oid| objectives.objectives.iter_mut().find(|o| o.id == oid))
+        {
+            if !objective.indicators.contains(&id) {
+                objective.indicators.push(id);
+            }
+        }
+    }
+
+    for (name, actions) in &pack.templates {
+        let conflict = templates.templates.contains_key(name);
+        report.templates.record(conflict, on_conflict);
+        match (conflict, on_conflict) {
+            (true, OnConflict::Skip) => {}
+            (true, OnConflict::Rename) => {
+                let name = free_name(name, |n| templates.templates.contains_key(n));
+                templates.add_template(name, actions.clone());
+            }
+            _ => templates.add_template(name.clone(), actions.clone()),
+        }
+    }
+    for (name, weekday) in &pack.weekday_templates {
+        let conflict = templates.weekday_templates.contains_key(name);
+        report.templates.record(conflict, on_conflict);
+        match (conflict, on_conflict) {
+            (true, OnConflict::Skip) => {}
+            (true, OnConflict::Rename) => {
+                let name = free_name(name, |n| templates.weekday_templates.contains_key(n));
+                templates.set_weekday_template(name, weekday.clone());
+            }
+            _ => templates.set_weekday_template(name.clone(), weekday.clone()),
+        }
+    }
+
+    if !dry_run {
+        let changed = |counts: &ImportCounts| counts.added + counts.replaced + counts.renamed > 0;
+        if changed(&report.objectives) || changed(&report.indicators) {
+            crate::data::save_objectives(&objectives, config)?;
+        }
+        if changed(&report.indicators) {
+            crate::data::save_indicators(&indicators, config)?;
+        }
+        if changed(&report.templates) {
+            crate::data::save_templates(&templates, config)?;
+        }
+    }
+    Ok(report)
+}
@@ -0,0 +1,120 @@
+//! Active objectives summarized as a portfolio for the dashboard: each one
+//! is on track, at risk or behind, judged from its target date and the
+//! trends of the indicators linked to it.
+
+use crate::forecast;
+use crate::models::{IndicatorDef, Objective, ObjectiveStatus, Observation};
+use chrono::{Duration, NaiveDate};
+
+/// Days without an observation of any linked indicator before an objective
+/// counts as stalled
+pub const STALE_DAYS: i64 = 14;
+
+/// Ordered worst first, so sorting puts what needs attention on top
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Health {
+    Behind,
+    AtRisk,
+    OnTrack,
+}
+
+impl Health {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Health::Behind => "behind",
+            Health::AtRisk => "at risk",
+            Health::OnTrack => "on track",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PortfolioEntry<'a> {
+    pub objective: &'a Objective,
+    pub health: Health,
+    /// Why it isn't on track, or its countdown when it is
+    pub detail: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Portfolio<'a> {
+    pub entries: Vec<PortfolioEntry<'a>>,
+}
+
+impl<'a> Portfolio<'a> {
+    /// Active, unarchived objectives, worst first and then by target date
+    pub fn build(
+        objectives: &'a [Objective],
+        indicators: &[IndicatorDef],
+        observations: &[Observation],
+        today: NaiveDate,
+    ) -> Self {
+        let mut entries: Vec<PortfolioEntry<'a>> = objectives
+            .iter()
+            .filter(|objective| objective.status == ObjectiveStatus::Active && !objective.archived)
+            .map(|objective| {
+                let (health, detail) = assess(objective, indicators, observations, today);
+                PortfolioEntry {
+                    objective,
+                    health,
+                    detail,
+                }
+            })
+            .collect();
+        entries.sort_by_key(|entry| (entry.health, entry.objective.end.unwrap_or(NaiveDate::MAX)));
+        Self { entries }
+    }
+
+    pub fn count(&self, health: Health) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.health == health)
+            .count()
+    }
+}
+
+/// Behind when overdue or at least half its linked indicators are behind
+/// pace; at risk when any is, or none has been updated in `STALE_DAYS`
+fn assess(
+    objective: &Objective,
+    indicators: &[IndicatorDef],
+    observations: &[Observation],
+    today: NaiveDate,
+) -> (Health, Option<String>) {
+    if objective.days_left(today).is_some_and(|days| days < 0) {
+        return (Health::Behind, objective.countdown(today));
+    }
+
+    let linked: Vec<&IndicatorDef> = indicators
+        .iter()
+        .filter(|def| {
+            def.active
+                && (objective.indicators.contains(&def.id)
+                    || def.objective_id.as_deref() == Some(objective.id.as_str()))
+        })
+        .collect();
+    let behind = forecast::behind_pace(objective, indicators, observations);
+    if !behind.is_empty() {
+        let names: Vec<&str> = behind.iter().map(|def| def.name.as_str()).collect();
+        let detail = format!("{} behind pace", names.join(", "));
+        let health = if behind.len() * 2 >= linked.len() {
+            Health::Behind
+        } else {
+            Health::AtRisk
+        };
+        return (health, Some(detail));
+    }
+
+    let since = today - Duration::days(STALE_DAYS);
+    let updated = observations
+        .iter()
+        .any(|obs| obs.when > since && linked.iter().any(|def| def.id == obs.indicator_id));
+    if !linked.is_empty() && !updated {
+        return (
+            Health::AtRisk,
+            Some(format!("no updates in {} days", STALE_DAYS)),
+        );
+    }
+
+    (Health::OnTrack, objective.countdown(today))
+}
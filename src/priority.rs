@@ -0,0 +1,150 @@
+//! Action priorities: levels 1 (highest) to 3 kept in the day's metadata,
+//! shown as badges next to the actions, used to order an outcome's actions
+//! and tracked for how often each level gets done.
+
+use crate::models::{Config, DailyGoals, OutcomeType};
+use anyhow::{bail, Result};
+use chrono::{Duration, NaiveDate};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Priority levels, highest first
+pub const LEVELS: [u32; 3] = [1, 2, 3];
+
+/// Set or clear the priority of the action at `index`
+pub fn set_priority(
+    goals: &DailyGoals,
+    outcome_type: OutcomeType,
+    index: usize,
+    priority: Option<u32>,
+    config: &Config,
+) -> Result<()> {
+    match goals.outcome(outcome_type).actions.get(index) {
+        Some(action) if !action.text.trim().is_empty() => {}
+        Some(_) => bail!("Nothing to prioritize in an empty action"),
+        None => bail!("Invalid action index: {}", index),
+    }
+    if let Some(level) = priority.filter(|level| !LEVELS.contains(level)) {
+        bail!("Priority must be 1, 2 or 3, got {}", level);
+    }
+    let mut meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+    if let Some(action_meta) = meta.outcome_mut(outcome_type).get_mut(index) {
+        action_meta.priority = priority;
+    }
+    crate::data::save_day_meta(goals.date, &meta, config)?;
+    Ok(())
+}
+
+/// Priorities of the day's actions by outcome and position
+pub fn priorities(
+    goals: &DailyGoals,
+    config: &Config,
+) -> Result<HashMap<(OutcomeType, usize), u32>> {
+    let meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+    let mut priorities = HashMap::new();
+    for outcome_type in [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family] {
+        for (index, action_meta) in meta.outcome(outcome_type).iter().enumerate() {
+            if let Some(priority) = action_meta.priority {
+                priorities.insert((outcome_type, index), priority);
+            }
+        }
+    }
+    Ok(priorities)
+}
+
+/// The unfinished action of `outcome_type` to work on next: the one with
+/// the highest priority, or the first when none has one
+pub fn top_action(
+    goals: &DailyGoals,
+    outcome_type: OutcomeType,
+    priorities: &HashMap<(OutcomeType, usize), u32>,
+) -> Option<usize> {
+    goals
+        .outcome(outcome_type)
+        .actions
+        .iter()
+        .enumerate()
+        .filter(|(_, action)| !action.completed && !action.text.trim().is_empty())
+        .min_by_key(|(index, _)| {
+            let priority = priorities.get(&(outcome_type, *index));
+            (priority.copied().unwrap_or(u32::MAX), *index)
+        })
+        .map(|(index, _)| index)
+}
+
+/// Order the actions of `outcome_type` by priority, highest first. Actions
+/// without one keep their order after those with one, and empty actions go
+/// last. Writes the goals file and its metadata when anything moved and
+/// returns the old position of each action in its new place.
+pub fn sort_by_priority(
+    goals: &mut DailyGoals,
+    outcome_type: OutcomeType,
+    config: &Config,
+) -> Result<Vec<usize>> {
+    let mut meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+    let actions = &goals.outcome(outcome_type).actions;
+    let metas = meta.outcome(outcome_type);
+    let mut order: Vec<usize> = (0..actions.len()).collect();
+    order.sort_by_key(|&index| {
+        let empty = actions[index].text.trim().is_empty();
+        let priority = metas.get(index).and_then(|m| m.priority);
+        (empty, priority.unwrap_or(u32::MAX))
+    });
+    if order.iter().enumerate().all(|(new, old)| new == *old) {
+        return Ok(order);
+    }
+
+    let outcome = goals.outcome_mut(outcome_type);
+    outcome.actions = order
+        .iter()
+        .map(|&old| outcome.actions[old].clone())
+        .collect();
+    let metas = meta.outcome_mut(outcome_type);
+    if metas.len() == order.len() {
+        *metas = order.iter().map(|&old| metas[old].clone()).collect();
+    }
+    meta.modified = chrono::Utc::now();
+    crate::data::write_goals_file(goals, config)?;
+    crate::data::save_day_meta(goals.date, &meta, config)?;
+    Ok(order)
+}
+
+/// Completed and planned actions at each priority level over the `days`
+/// days ending on `end`; days without metadata have no priorities
+pub fn completion_by_level(end: NaiveDate, days: i64, config: &Config) -> Result<[(u32, u32); 3]> {
+    let mut counts = [(0, 0); 3];
+    for day in (end - Duration::days(days - 1))
+        .iter_days()
+        .take(days as usize)
+    {
+        let goals_path = crate::data::goals_file_path(day, config);
+        let meta_path = Path::new(&config.data_root)
+            .join("meta")
+            .join(format!("{}.meta.json", day.format("%Y-%m-%d")));
+        if !goals_path.exists() || !meta_path.exists() {
+            continue;
+        }
+
+        let goals = crate::data::read_goals_for(day, config)?;
+        let meta = crate::data::load_or_create_day_meta(day, &goals, config)?;
+        for outcome_type in [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family] {
+            let actions = &goals.outcome(outcome_type).actions;
+            for (action, action_meta) in actions.iter().zip(meta.outcome(outcome_type)) {
+                let Some(level) = action_meta
+                    .priority
+                    .and_then(|p| LEVELS.iter().position(|l| *l == p))
+                else {
+                    continue;
+                };
+                if action.text.trim().is_empty() {
+                    continue;
+                }
+                counts[level].1 += 1;
+                if action.completed {
+                    counts[level].0 += 1;
+                }
+            }
+        }
+    }
+    Ok(counts)
+}
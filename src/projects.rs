@@ -0,0 +1,162 @@
+//! Projects: initiatives that group objectives across Work, Health and
+//! Family, with a progress rollup over their member objectives.
+
+use crate::models::{Config, Objective, ObjectiveStatus, OutcomeType, Project, ProjectsData};
+use anyhow::{bail, Context, Result};
+use std::collections::HashMap;
+
+/// Progress of a project's member objectives
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProjectRollup {
+    pub objectives: usize,
+    pub completed: usize,
+    /// Domains covered, in Work, Health, Family order
+    pub domains: Vec<OutcomeType>,
+    /// Mean progress of the members, 0-100
+    pub progress: f64,
+}
+
+impl ProjectRollup {
+    /// Roll up the members found in `objectives`; ids of deleted objectives
+    /// are skipped. `indicator_progress` maps indicator ids to 0.0-1.0.
+    pub fn new(
+        project: &Project,
+        objectives: &[Objective],
+        indicator_progress: &HashMap<String, f64>,
+    ) -> Self {
+        let members: Vec<&Objective> = project
+            .objective_ids
+            .iter()
+            .filter_map(|id| objectives.iter().find(|objective| &objective.id == id))
+            .collect();
+        if members.is_empty() {
+            return Self::default();
+        }
+        let domains = [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family]
+            .into_iter()
+            .filter(|domain| members.iter().any(|objective| objective.domain == *domain))
+            .collect();
+        let total: f64 = members
+            .iter()
+            .map(|objective| objective_progress(objective, indicator_progress))
+            .sum();
+        Self {
+            objectives: members.len(),
+            completed: members
+                .iter()
+                .filter(|objective| objective.status == ObjectiveStatus::Completed)
+                .count(),
+            domains,
+            progress: total / members.len() as f64 * 100.0,
+        }
+    }
+}
+
+/// Progress of one objective, 0.0-1.0: complete when its status says so,
+/// the share of items done for queues, else the mean of its indicators
+pub fn objective_progress(objective: &Objective, indicator_progress: &HashMap<String, f64>) -> f64 {
+    if objective.status == ObjectiveStatus::Completed {
+        return 1.0;
+    }
+    if let Some((done, total)) = objective.queue_progress() {
+        return done as f64 / total.max(1) as f64;
+    }
+    let values: Vec<f64> = objective
+        .indicators
+        .iter()
+        .filter_map(|id| indicator_progress.get(id))
+        .map(|value| value.clamp(0.0, 1.0))
+        .collect();
+    if values.is_empty() {
+        0.0
+    } else {
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+}
+
+/// Create a project and save it; returns its id
+pub fn create_project(title: &str, config: &Config) -> Result<String> {
+    let title = title.trim();
+    if title.is_empty() {
+        bail!("Project title cannot be empty");
+    }
+    let mut data = crate::data::load_or_create_projects(config)?;
+    let project = Project::new(title.to_string());
+    let id = project.id.clone();
+    data.projects.push(project);
+    crate::data::save_projects(&data, config)?;
+    Ok(id)
+}
+
+fn update_project(
+    project_id: &str,
+    config: &Config,
+    change: impl FnOnce(&mut Project),
+) -> Result<ProjectsData> {
+    let mut data = crate::data::load_or_create_projects(config)?;
+    let project = data
+        .projects
+        .iter_mut()
+        .find(|project| project.id == project_id)
+        .context("Project not found")?;
+    change(project);
+    project.modified = chrono::Utc::now();
+    crate::data::save_projects(&data, config)?;
+    Ok(data)
+}
+
+pub fn rename_project(project_id: &str, title: &str, config: &Config) -> Result<ProjectsData> {
+    let title = title.trim();
+    if title.is_empty() {
+        bail!("Project title cannot be empty");
+    }
+    update_project(project_id, config, |project| {
+        project.title = title.to_string()
+    })
+}
+
+/// Add the objective to the project, or take it out if already a member
+pub fn toggle_objective(
+    project_id: &str,
+    objective_id: &str,
+    config: &Config,
+) -> Result<ProjectsData> {
+    update_project(project_id, config, |project| {
+        if let Some(position) = project
+            .objective_ids
+            .iter()
+            .position(|id| id == objective_id)
+        {
+            project.objective_ids.remove(position);
+        } else {
+            project.objective_ids.push(objective_id.to_string());
+        }
+    })
+}
+
+/// Delete the project; its objectives are kept
+pub fn delete_project(project_id: &str, config: &Config) -> Result<ProjectsData> {
+    let mut data = crate::data::load_or_create_projects(config)?;
+    let before = data.projects.len();
+    data.projects.retain(|project| project.id != project_id);
+    if data.projects.len() == before {
+        bail!("Project not found");
+    }
+    crate::data::save_projects(&data, config)?;
+    Ok(data)
+}
+
+/// Drop a deleted objective from every project
+pub fn forget_objective(objective_id: &str, config: &Config) -> Result<()> {
+    let mut data = crate::data::load_or_create_projects(config)?;
+    let mut changed = false;
+    for project in &mut data.projects {
+        let before = project.objective_ids.len();
+        project.objective_ids.retain(|id| id != objective_id);
+        changed |= project.objective_ids.len() != before;
+    }
+    if changed {
+        crate::data::save_projects(&data, config)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,165 @@
+//! Quarterly vision check-in: a guided pass over each domain's vision that
+//! asks whether its active objectives still ladder up to it and collects
+//! alignment notes, saved as a markdown document per quarter.
+
+use crate::models::{Config, FiveYearVision, ObjectiveStatus, ObjectivesData, OutcomeType};
+use anyhow::{Context, Result};
+use chrono::{Datelike, NaiveDate};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Domains in the order the check-in walks through them
+pub const DOMAINS: [OutcomeType; 3] = [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alignment {
+    Unanswered,
+    Aligned,
+    Drifting,
+}
+
+impl Alignment {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Alignment::Unanswered => "not reviewed",
+            Alignment::Aligned => "aligned",
+            Alignment::Drifting => "drifting",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectiveAlignment {
+    pub objective_id: String,
+    pub title: String,
+    pub alignment: Alignment,
+}
+
+/// One domain's page of the check-in
+#[derive(Debug, Clone, PartialEq)]
+pub struct DomainCheckIn {
+    pub outcome_type: OutcomeType,
+    pub vision: String,
+    pub objectives: Vec<ObjectiveAlignment>,
+    pub note: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuarterlyCheckIn {
+    pub year: i32,
+    pub quarter: u32,
+    pub date: NaiveDate,
+    /// In `DOMAINS` order
+    pub domains: Vec<DomainCheckIn>,
+}
+
+/// Year and quarter (1-4) of `date`
+pub fn quarter_of(date: NaiveDate) -> (i32, u32) {
+    (date.year(), (date.month() - 1) / 3 + 1)
+}
+
+impl QuarterlyCheckIn {
+    /// A check-in dated `date` over the vision and the active objectives
+    pub fn start(date: NaiveDate, vision: &FiveYearVision, objectives: &ObjectivesData) -> Self {
+        let (year, quarter) = quarter_of(date);
+        let domains = DOMAINS
+            .into_iter()
+            .map(|outcome_type| DomainCheckIn {
+                outcome_type,
+                vision: vision.get_vision(&outcome_type).to_string(),
+                objectives: objectives
+                    .objectives
+                    .iter()
+                    .filter(|objective| {
+                        objective.domain == outcome_type
+                            && objective.status == ObjectiveStatus::Active
+                    })
+                    .map(|objective| ObjectiveAlignment {
+                        objective_id: objective.id.clone(),
+                        title: objective.title.clone(),
+                        alignment: Alignment::Unanswered,
+                    })
+                    .collect(),
+                note: String::new(),
+            })
+            .collect();
+        Self {
+            year,
+            quarter,
+            date,
+            domains,
+        }
+    }
+
+    /// "2025-Q3"
+    pub fn label(&self) -> String {
+        format!("{}-Q{}", self.year, self.quarter)
+    }
+
+    /// Objectives answered as drifting, across domains
+    pub fn drifting(&self) -> usize {
+        self.domains
+            .iter()
+            .flat_map(|domain| &domain.objectives)
+            .filter(|objective| objective.alignment == Alignment::Drifting)
+            .count()
+    }
+}
+
+/// The check-in as a markdown document
+pub fn to_markdown(checkin: &QuarterlyCheckIn) -> String {
+    let mut doc = format!(
+        "# Quarterly check-in: {}\n\n_{}_\n",
+        checkin.label(),
+        checkin.date.format("%B %d, %Y")
+    );
+    for domain in &checkin.domains {
+        doc.push_str(&format!("\n## {}\n\n", domain.outcome_type.as_str()));
+        if domain.vision.trim().is_empty() {
+            doc.push_str("> _No vision written yet._\n\n");
+        } else {
+            for line in domain.vision.lines() {
+                doc.push_str(&format!("> {}\n", line));
+            }
+            doc.push('\n');
+        }
+        if domain.objectives.is_empty() {
+            doc.push_str("No active objectives.\n");
+        }
+        for objective in &domain.objectives {
+            doc.push_str(&format!(
+                "- [{}] {}\n",
+                objective.alignment.label(),
+                objective.title
+            ));
+        }
+        if !domain.note.trim().is_empty() {
+            doc.push_str(&format!("\nNotes: {}\n", domain.note.trim()));
+        }
+    }
+    doc
+}
+
+fn checkin_path(year: i32, quarter: u32, config: &Config) -> PathBuf {
+    Path::new(&config.data_root)
+        .join("quarterly")
+        .join(format!("{}-Q{}.md", year, quarter))
+}
+
+/// Whether the quarter of `date` already has a check-in document
+pub fn has_checkin(date: NaiveDate, config: &Config) -> bool {
+    let (year, quarter) = quarter_of(date);
+    checkin_path(year, quarter, config).exists()
+}
+
+/// Write the check-in to quarterly/YYYY-Qn.md, replacing an earlier one
+pub fn save(checkin: &QuarterlyCheckIn, config: &Config) -> Result<PathBuf> {
+    let path = checkin_path(checkin.year, checkin.quarter, config);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create quarterly directory: {}", dir.display()))?;
+    }
+    fs::write(&path, to_markdown(checkin))
+        .with_context(|| format!("Failed to write check-in: {}", path.display()))?;
+    Ok(path)
+}
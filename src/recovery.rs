@@ -0,0 +1,98 @@
+//! Crash recovery journal: while an action is being edited or an
+//! observation typed, the unsaved input is written to recovery.json on
+//! every change and removed once it is saved or dropped. A journal still
+//! there on launch means the last session ended mid-edit, and the app
+//! offers the input back.
+
+use crate::models::{Config, OutcomeType};
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Input that has not been saved yet
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PendingEdit {
+    /// Text typed into the editor of an action
+    Action {
+        date: NaiveDate,
+        outcome: OutcomeType,
+        index: usize,
+        text: String,
+    },
+    /// Value typed for an indicator
+    Observation {
+        indicator_id: String,
+        name: String,
+        value: String,
+    },
+}
+
+impl PendingEdit {
+    pub fn describe(&self) -> String {
+        match self {
+            PendingEdit::Action {
+                date,
+                outcome,
+                index,
+                text,
+            } => format!(
+                "{} action {} on {}: \"{}\"",
+                outcome.as_str(),
+                index + 1,
+                date,
+                text
+            ),
+            PendingEdit::Observation { name, value, .. } => {
+                format!("{} for {}", value, name)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Journal {
+    pub written: DateTime<Utc>,
+    pub edit: PendingEdit,
+}
+
+pub fn journal_path(config: &Config) -> PathBuf {
+    Path::new(&config.data_root).join("recovery.json")
+}
+
+/// Journal `edit`, replacing whatever was journaled before
+pub fn record(edit: &PendingEdit, config: &Config) -> Result<()> {
+    fs::create_dir_all(&config.data_root)
+        .with_context(|| format!("Failed to create data root directory: {}", config.data_root))?;
+    let journal = Journal {
+        written: Utc::now(),
+        edit: edit.clone(),
+    };
+    let json = serde_json::to_string(&journal).context("Failed to serialize recovery journal")?;
+    crate::data::atomic_write(&journal_path(config), json.as_bytes())
+}
+
+/// Drop the journal once its edit is saved or abandoned
+pub fn clear(config: &Config) -> Result<()> {
+    let path = journal_path(config);
+    if path.exists() {
+        fs::remove_file(&path)
+            .with_context(|| format!("Failed to remove recovery journal: {}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// The edit left behind by the last session, if it ended mid-edit
+pub fn load(config: &Config) -> Result<Option<Journal>> {
+    let path = journal_path(config);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read recovery journal: {}", path.display()))?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .with_context(|| format!("Failed to parse recovery journal: {}", path.display()))
+}
@@ -0,0 +1,182 @@
+//! New-year re-targeting: proposes next year's indicator targets from last
+//! year's actuals and carries active objectives into the new year. Nothing is
+//! written until the whole batch is applied.
+
+use crate::models::{
+    IndicatorDef, IndicatorDirection, IndicatorsData, ObjectiveStatus, ObjectivesData, Observation,
+    TargetStep,
+};
+use chrono::{Datelike, NaiveDate};
+use std::collections::BTreeMap;
+
+/// Stretch applied to last year's average when proposing a target
+pub const RETARGET_STRETCH: f64 = 0.10;
+
+/// What a wizard step changes
+#[derive(Debug, Clone, PartialEq)]
+pub enum RetargetSubject {
+    Indicator {
+        id: String,
+        current: Option<f64>,
+        /// Average of last year's daily values and how many days had data
+        actual: Option<(f64, usize)>,
+    },
+    Objective {
+        id: String,
+        current_end: Option<NaiveDate>,
+    },
+}
+
+/// One proposal in the wizard
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetargetItem {
+    pub name: String,
+    pub subject: RetargetSubject,
+    /// Proposed target; unused for objectives, which move to year end
+    pub proposed: Option<f64>,
+    pub accepted: bool,
+}
+
+/// The year being planned: next year once December starts, otherwise this one
+pub fn plan_year(today: NaiveDate) -> i32 {
+    if today.month() == 12 {
+        today.year() + 1
+    } else {
+        today.year()
+    }
+}
+
+/// Average of the last value logged each day
+fn daily_average(indicator_id: &str, observations: &[Observation]) -> Option<(f64, usize)> {
+    let mut by_day: BTreeMap<NaiveDate, &Observation> = BTreeMap::new();
+    for obs in observations
+        .iter()
+        .filter(|o| o.indicator_id == indicator_id)
+    {
+        let latest = by_day.entry(obs.when).or_insert(obs);
+        if obs.created >= latest.created {
+            *latest = obs;
+        }
+    }
+    if by_day.is_empty() {
+        return None;
+    }
+    let total: f64 = by_day.values().map(|obs| obs.value).sum();
+    Some((total / by_day.len() as f64, by_day.len()))
+}
+
+/// Round to a whole number for larger values, one decimal otherwise
+fn round_target(value: f64) -> f64 {
+    if value.abs() >= 10.0 {
+        value.round()
+    } else {
+        (value * 10.0).round() / 10.0
+    }
+}
+
+/// Target proposed for `def` given last year's daily average
+pub fn propose_target(def: &IndicatorDef, average: f64) -> f64 {
+    let proposed = match def.direction {
+        IndicatorDirection::HigherIsBetter => average * (1.0 + RETARGET_STRETCH),
+        IndicatorDirection::LowerIsBetter => average * (1.0 - RETARGET_STRETCH),
+        IndicatorDirection::WithinRange => average,
+    };
+    round_target(proposed)
+}
+
+/// Build wizard steps for every active indicator and objective.
+/// `observations` should cover the year before `year`.
+pub fn build_items(
+    indicators: &IndicatorsData,
+    objectives: &ObjectivesData,
+    observations: &[Observation],
+    year: i32,
+) -> Vec<RetargetItem> {
+    let year_start = NaiveDate::from_ymd_opt(year, 1, 1).unwrap_or_default();
+    let mut items: Vec<RetargetItem> = indicators
+        .indicators
+        .iter()
+        .filter(|def| def.active)
+        .map(|def| {
+            let current = def.target_on(year_start);
+            let actual = daily_average(&def.id, observations);
+            let proposed = actual.map(|(avg, _)| propose_target(def, avg)).or(current);
+            RetargetItem {
+                name: def.name.clone(),
+                subject: RetargetSubject::Indicator {
+                    id: def.id.clone(),
+                    current,
+                    actual,
+                },
+                accepted: actual.is_some() && proposed != current,
+                proposed,
+            }
+        })
+        .collect();
+
+    items.extend(
+        objectives
+            .objectives
+            .iter()
+            .filter(|obj| obj.status == ObjectiveStatus::Active)
+            .map(|obj| RetargetItem {
+                name: obj.title.clone(),
+                subject: RetargetSubject::Objective {
+                    id: obj.id.clone(),
+                    current_end: obj.end,
+                },
+                proposed: None,
+                accepted: obj.end.is_some_and(|end| end.year() < year),
+            }),
+    );
+
+    items
+}
+
+/// Apply accepted items; returns how many changes were made. Indicators with
+/// a target schedule get a new step on January 1 instead of a flat target.
+pub fn apply(
+    items: &[RetargetItem],
+    indicators: &mut IndicatorsData,
+    objectives: &mut ObjectivesData,
+    year: i32,
+) -> usize {
+    let year_start = NaiveDate::from_ymd_opt(year, 1, 1).unwrap_or_default();
+    let year_end = NaiveDate::from_ymd_opt(year, 12, 31).unwrap_or_default();
+    let now = chrono::Utc::now();
+    let mut changed = 0;
+
+    for item in items.iter().filter(|item| item.accepted) {
+        match &item.subject {
+            RetargetSubject::Indicator { id, .. } => {
+                let (Some(target), Some(def)) = (
+                    item.proposed,
+                    indicators.indicators.iter_mut().find(|d| &d.id == id),
+                ) else {
+                    continue;
+                };
+                if def.target_schedule.is_empty() {
+                    def.target = Some(target);
+                } else {
+                    def.target_schedule.retain(|step| step.date != year_start);
+                    def.target_schedule.push(TargetStep {
+                        date: year_start,
+                        target,
+                    });
+                    def.target_schedule.sort_by_key(|step| step.date);
+                }
+                def.modified = now;
+                changed += 1;
+            }
+            RetargetSubject::Objective { id, .. } => {
+                if let Some(obj) = objectives.objectives.iter_mut().find(|o| &o.id == id) {
+                    obj.end = Some(year_end);
+                    obj.modified = now;
+                    changed += 1;
+                }
+            }
+        }
+    }
+
+    changed
+}
@@ -0,0 +1,230 @@
+//! Scoreboard: XP for completed actions, streak bonuses and indicator
+//! targets hit, levels earned from XP, and achievements. Scores per day and
+//! earned achievements are kept in profile.json so history is only scored
+//! once.
+
+use crate::models::{Config, DayScore, IndicatorDef, IndicatorDirection, Profile};
+use anyhow::Result;
+use chrono::{Datelike, Duration, NaiveDate};
+use std::collections::HashMap;
+
+/// XP for each completed action
+pub const XP_PER_COMPLETION: u32 = 10;
+/// XP for each indicator whose day ended on target
+pub const XP_PER_TARGET_HIT: u32 = 15;
+/// Bonus XP per day of the streak a day extends
+pub const XP_PER_STREAK_DAY: u32 = 2;
+/// Streak length after which the bonus stops growing
+const STREAK_BONUS_CAP: u32 = 30;
+/// XP from one level to the next grows by this much per level
+const LEVEL_STEP: u32 = 100;
+/// Days of history scored when there is no profile yet
+const BACKFILL_DAYS: i64 = 365;
+/// Days before the last scored one that are scored again, to pick up edits
+const RESCORE_DAYS: i64 = 7;
+
+/// Something to earn, shown on the trophies screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Achievement {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+pub const ACHIEVEMENTS: [Achievement; 8] = [
+    Achievement {
+        id: "first_step",
+        name: "First Step",
+        description: "Complete an action",
+    },
+    Achievement {
+        id: "perfect_day",
+        name: "Perfect Day",
+        description: "Complete every planned action of a day",
+    },
+    Achievement {
+        id: "week_streak",
+        name: "Week Streak",
+        description: "Complete something 7 days in a row",
+    },
+    Achievement {
+        id: "month_streak",
+        name: "Month Streak",
+        description: "Complete something 30 days in a row",
+    },
+    Achievement {
+        id: "century",
+        name: "Century",
+        description: "Complete 100 actions",
+    },
+    Achievement {
+        id: "on_target",
+        name: "On Target",
+        description: "End a day with an indicator on target",
+    },
+    Achievement {
+        id: "sharpshooter",
+        name: "Sharpshooter",
+        description: "Hit indicator targets 25 times",
+    },
+    Achievement {
+        id: "level_5",
+        name: "Level 5",
+        description: "Reach level 5",
+    },
+];
+
+/// Level reached and progress towards the next one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Level {
+    pub level: u32,
+    /// XP earned since reaching `level`
+    pub progress: u32,
+    /// XP from `level` to the next
+    pub needed: u32,
+}
+
+/// XP a day earned
+pub fn day_xp(score: &DayScore) -> u32 {
+    score.completions * XP_PER_COMPLETION
+        + score.target_hits * XP_PER_TARGET_HIT
+        + score.streak.min(STREAK_BONUS_CAP) * XP_PER_STREAK_DAY
+}
+
+pub fn total_xp(profile: &Profile) -> u32 {
+    profile.days.values().map(day_xp).sum()
+}
+
+/// XP earned in the ISO week of `date`, up to `date`
+pub fn week_xp(profile: &Profile, date: NaiveDate) -> u32 {
+    let monday = date - Duration::days(i64::from(date.weekday().num_days_from_monday()));
+    profile
+        .days
+        .range(monday..=date)
+        .map(|(_, score)| day_xp(score))
+        .sum()
+}
+
+/// Level 1 starts at 0 XP; reaching level n + 1 takes n × 100 XP more
+pub fn level_for(xp: u32) -> Level {
+    let mut level = 1;
+    let mut progress = xp;
+    while progress >= level * LEVEL_STEP {
+        progress -= level * LEVEL_STEP;
+        level += 1;
+    }
+    Level {
+        level,
+        progress,
+        needed: level * LEVEL_STEP,
+    }
+}
+
+/// Whether `profile` has what `achievement` asks for
+pub fn is_earned(achievement: &Achievement, profile: &Profile) -> bool {
+    let total = |field: fn(&DayScore) -> u32| profile.days.values().map(field).sum::<u32>();
+    let longest_streak = profile.days.values().map(|d| d.streak).max().unwrap_or(0);
+    match achievement.id {
+        "first_step" => total(|d| d.completions) >= 1,
+        "perfect_day" => profile
+            .days
+            .values()
+            .any(|d| d.planned > 0 && d.completions == d.planned),
+        "week_streak" => longest_streak >= 7,
+        "month_streak" => longest_streak >= 30,
+        "century" => total(|d| d.completions) >= 100,
+        "on_target" => total(|d| d.target_hits) >= 1,
+        "sharpshooter" => total(|d| d.target_hits) >= 25,
+        "level_5" => level_for(total_xp(profile)).level >= 5,
+        _ => false,
+    }
+}
+
+/// Whether `value` meets the indicator's target on `date`
+pub fn meets_target(def: &IndicatorDef, value: f64, date: NaiveDate) -> bool {
+    match def.direction {
+        IndicatorDirection::HigherIsBetter => def.target_on(date).is_some_and(|t| value >= t),
+        IndicatorDirection::LowerIsBetter => def.target_on(date).is_some_and(|t| value <= t),
+        IndicatorDirection::WithinRange => def
+            .range_on(date)
+            .is_some_and(|range| IndicatorDef::range_deviation(value, range) == 0.0),
+    }
+}
+
+/// Score the days since the profile was last updated, up to `today`, and
+/// award achievements newly earned. Returns the profile and those
+/// achievements; profile.json is saved when anything changed.
+pub fn refresh_profile(
+    today: NaiveDate,
+    config: &Config,
+) -> Result<(Profile, Vec<&'static Achievement>)> {
+    let mut profile = crate::data::load_profile(config)?;
+    let before = profile.clone();
+    let start = match profile.days.keys().next_back() {
+        Some(last) => (*last).min(today) - Duration::days(RESCORE_DAYS),
+        None => today - Duration::days(BACKFILL_DAYS),
+    };
+
+    let cache = crate::ui::stats::load_day_cache(start, today, config);
+    let indicators = crate::data::load_or_create_indicators(config)?;
+    // The last reading of each indicator on each day is the one that counts
+    let mut readings: HashMap<(NaiveDate, String), f64> = HashMap::new();
+    for observation in crate::data::iter_observations_range(start, today, config)? {
+        let observation = observation?;
+        readings.insert(
+            (observation.when, observation.indicator_id),
+            observation.value,
+        );
+    }
+
+    let mut streak = start
+        .pred_opt()
+        .and_then(|day| profile.days.get(&day))
+        .map_or(0, |score| score.streak);
+    for day in start.iter_days().take_while(|day| *day <= today) {
+        let target_hits = indicators
+            .indicators
+            .iter()
+            .filter(|def| {
+                readings
+                    .get(&(day, def.id.clone()))
+                    .is_some_and(|value| meets_target(def, *value, day))
+            })
+            .count() as u32;
+        let (completions, planned) = cache.days.get(&day).map_or((0, 0), |rollup| {
+            rollup
+                .actions
+                .iter()
+                .fold((0, 0), |(done, planned), a| (done + a.0, planned + a.1))
+        });
+        streak = if completions > 0 { streak + 1 } else { 0 };
+        if completions == 0 && planned == 0 && target_hits == 0 {
+            profile.days.remove(&day);
+            continue;
+        }
+        profile.days.insert(
+            day,
+            DayScore {
+                completions,
+                planned,
+                streak,
+                target_hits,
+            },
+        );
+    }
+
+    let earned: Vec<&'static Achievement> = ACHIEVEMENTS
+        .iter()
+        .filter(|a| !profile.achievements.contains_key(a.id) && is_earned(a, &profile))
+        .collect();
+    for achievement in &earned {
+        profile
+            .achievements
+            .insert(achievement.id.to_string(), today);
+    }
+
+    if profile != before {
+        crate::data::save_profile(&profile, config)?;
+    }
+    Ok((profile, earned))
+}
@@ -0,0 +1,249 @@
+//! Lexicon-based sentiment scoring of reflections.
+//!
+//! Scoring runs entirely offline: each word found in a small positive or
+//! negative lexicon counts one point, and a negator ("not", "didn't", ...)
+//! within the three preceding words flips it.
+//!
+//! A day's score for an outcome covers that outcome's own reflection and the
+//! sentences of the evening reflection that name the outcome or one of its
+//! actions; sentences naming no outcome count toward all three. Scores are
+//! kept in sentiment.json so the dashboard can chart them.
+
+use crate::models::{Config, DailyGoals, Outcome, SentimentHistory, SentimentPoint};
+use anyhow::Result;
+use chrono::{Duration, NaiveDate};
+
+const POSITIVE: &[&str] = &[
+    "accomplished",
+    "awesome",
+    "better",
+    "best",
+    "calm",
+    "clear",
+    "confident",
+    "connected",
+    "enjoyed",
+    "energized",
+    "energetic",
+    "excited",
+    "fantastic",
+    "focused",
+    "fun",
+    "glad",
+    "good",
+    "grateful",
+    "great",
+    "happy",
+    "helpful",
+    "improved",
+    "joy",
+    "laughed",
+    "love",
+    "loved",
+    "motivated",
+    "nice",
+    "peaceful",
+    "productive",
+    "progress",
+    "proud",
+    "relaxed",
+    "rested",
+    "satisfied",
+    "smooth",
+    "solid",
+    "strong",
+    "success",
+    "successful",
+    "thankful",
+    "win",
+    "won",
+];
+
+const NEGATIVE: &[&str] = &[
+    "angry",
+    "annoyed",
+    "anxious",
+    "bad",
+    "behind",
+    "blocked",
+    "bored",
+    "difficult",
+    "distracted",
+    "drained",
+    "exhausted",
+    "fail",
+    "failed",
+    "frustrated",
+    "frustrating",
+    "guilty",
+    "hard",
+    "hurt",
+    "lazy",
+    "late",
+    "lonely",
+    "missed",
+    "overwhelmed",
+    "pain",
+    "procrastinated",
+    "rough",
+    "sad",
+    "sick",
+    "skipped",
+    "slipped",
+    "sore",
+    "stressed",
+    "stress",
+    "struggle",
+    "struggled",
+    "stuck",
+    "tense",
+    "tired",
+    "upset",
+    "worried",
+    "worse",
+    "worst",
+];
+
+const NEGATORS: &[&str] = &["not", "no", "never", "hardly", "without"];
+
+/// How many preceding words a negator reaches
+const NEGATION_WINDOW: usize = 3;
+
+/// Sum of word polarities and how many lexicon words were found
+fn tally(text: &str) -> (i32, u32) {
+    let mut sum = 0;
+    let mut hits = 0;
+    let mut since_negator = usize::MAX;
+
+    for raw in text.split_whitespace() {
+        let word: String = raw
+            .replace('\u{2019}', "'")
+            .chars()
+            .filter(|c| c.is_alphanumeric() || *c == '\'')
+            .collect::<String>()
+            .to_lowercase();
+        if word.is_empty() {
+            continue;
+        }
+
+        let polarity = if POSITIVE.contains(&word.as_str()) {
+            1
+        } else if NEGATIVE.contains(&word.as_str()) {
+            -1
+        } else {
+            0
+        };
+
+        if polarity != 0 {
+            let negated = since_negator < NEGATION_WINDOW;
+            sum += if negated { -polarity } else { polarity };
+            hits += 1;
+        }
+
+        if NEGATORS.contains(&word.as_str()) || word.ends_with("n't") {
+            since_negator = 0;
+        } else {
+            since_negator = since_negator.saturating_add(1);
+        }
+    }
+
+    (sum, hits)
+}
+
+/// Whether a lowercased sentence names the outcome or one of its actions
+fn mentions(sentence: &str, outcome: &Outcome) -> bool {
+    sentence.contains(&outcome.outcome_type.as_str().to_lowercase())
+        || outcome.actions.iter().any(|action| {
+            let text = action.text.trim().to_lowercase();
+            !text.is_empty() && sentence.contains(&text)
+        })
+}
+
+/// Score each outcome for one day; outcomes without any sentiment words are
+/// left out
+pub fn score_day(goals: &DailyGoals, evening: Option<&str>) -> Vec<SentimentPoint> {
+    let outcomes = goals.outcomes();
+    let sentences: Vec<(String, Vec<bool>)> = evening
+        .unwrap_or_default()
+        .split(['.', '!', '?', '\n'])
+        .map(|sentence| {
+            let lower = sentence.to_lowercase();
+            let named = outcomes.iter().map(|o| mentions(&lower, o)).collect();
+            (lower, named)
+        })
+        .collect();
+
+    outcomes
+        .iter()
+        .enumerate()
+        .filter_map(|(index, outcome)| {
+            let (mut sum, mut hits) = outcome.reflection.as_deref().map(tally).unwrap_or((0, 0));
+            for (sentence, named) in &sentences {
+                if named[index] || !named.contains(&true) {
+                    let (sentence_sum, sentence_hits) = tally(sentence);
+                    sum += sentence_sum;
+                    hits += sentence_hits;
+                }
+            }
+            (hits > 0).then(|| SentimentPoint {
+                date: goals.date,
+                outcome: outcome.outcome_type,
+                score: f64::from(sum) / f64::from(hits),
+                hits,
+            })
+        })
+        .collect()
+}
+
+/// Scores for one day's goals file and evening reflection, if either exists
+fn score_date(date: NaiveDate, config: &Config) -> Result<Vec<SentimentPoint>> {
+    let goals_path = crate::data::goals_file_path(date, config);
+    let evening = crate::data::load_reflection(date, config)?;
+    if !goals_path.exists() && evening.is_none() {
+        return Ok(Vec::new());
+    }
+    // A day that no longer parses keeps no score rather than failing the rest
+    let Ok(goals) = crate::data::load_or_create_goals(date, config) else {
+        return Ok(Vec::new());
+    };
+    Ok(score_day(&goals, evening.as_deref()))
+}
+
+/// Re-score the `days` days ending at `end` and persist the result.
+/// Points outside that window are kept as they were.
+pub fn refresh_history(end: NaiveDate, days: i64, config: &Config) -> Result<SentimentHistory> {
+    let start = end - Duration::days(days.saturating_sub(1));
+    let mut history = crate::data::load_sentiment_history(config)?;
+    history
+        .points
+        .retain(|point| point.date < start || point.date > end);
+
+    for date in start.iter_days().take_while(|date| *date <= end) {
+        history.points.extend(score_date(date, config)?);
+    }
+
+    // Stable, so each day keeps its Work/Health/Family order
+    history.points.sort_by_key(|point| point.date);
+    crate::data::save_sentiment_history(&history, config)?;
+    Ok(history)
+}
+
+/// Score every day with a goals file or reflection from scratch, replacing
+/// sentiment.json. `progress` gets (done, total) after each day.
+pub fn rebuild_history(
+    config: &Config,
+    mut progress: impl FnMut(usize, usize),
+) -> Result<SentimentHistory> {
+    let mut dates = crate::data::goals_file_dates(config)?;
+    dates.extend(crate::data::reflection_dates(config)?);
+    dates.sort();
+    dates.dedup();
+
+    let mut history = SentimentHistory::default();
+    for (done, date) in dates.iter().enumerate() {
+        history.points.extend(score_date(*date, config)?);
+        progress(done + 1, dates.len());
+    }
+    crate::data::save_sentiment_history(&history, config)?;
+    Ok(history)
+}
@@ -0,0 +1,754 @@
+//! Evening summary drafts.
+//!
+//! With `summary.endpoint` set in settings.json, the day's facts go as a
+//! prompt to that OpenAI-compatible chat completions endpoint (a hosted
+//! provider, or a local server such as Ollama) and its reply becomes the
+//! draft. The API key is read from the environment variable named by
+//! `summary.api_key_env`, so it never lands in the data directory. Requests
+//! run on a worker thread and give up after `summary.timeout_secs`; without
+//! an endpoint, or when the request fails, a plain template draft is built
+//! from the same facts. Either way the user edits the draft before it is
+//! saved as the day's reflection.
+//!
+//! The daily summary shown in the evening phase, printed by `focusfive
+//! summary` and attached to `day_closed` events comes from a template with
+//! handlebars-style placeholders (`{{completed}}`, `{{#each wins}}`,
+//! `{{#if notes}}`). Users can replace the built-in one with their own file;
+//! see [`load_template`].
+
+use crate::models::{
+    Config, DailyGoals, IndicatorUnit, IndicatorsData, Observation, SummarySettings,
+    MAX_REFLECTION_LENGTH,
+};
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::time::{Duration, Instant};
+
+/// Model asked for when `summary.model` is unset
+pub const DEFAULT_MODEL: &str = "llama3";
+/// Seconds to wait for the provider when `summary.timeout_secs` is unset
+pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// Change in one indicator on the summarized day
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndicatorDelta {
+    pub name: String,
+    pub unit: IndicatorUnit,
+    pub previous: Option<f64>,
+    pub current: f64,
+}
+
+/// Facts an evening summary is drafted from
+#[derive(Debug, Clone, PartialEq)]
+pub struct SummaryInput {
+    pub date: NaiveDate,
+    pub completed: Vec<(String, String)>, // (outcome, action text)
+    pub open: usize,
+    pub deltas: Vec<IndicatorDelta>,
+    pub notes: Vec<String>,
+}
+
+impl SummaryInput {
+    /// Collect completed actions, indicator movements and outcome notes for
+    /// `goals.date`. `observations` should reach back before the day so the
+    /// previous value of each indicator is known.
+    pub fn gather(
+        goals: &DailyGoals,
+        indicators: &IndicatorsData,
+        observations: &[Observation],
+    ) -> Self {
+        let mut completed = Vec::new();
+        let mut open = 0;
+        for outcome in goals.outcomes() {
+            for action in outcome.actions.iter().filter(|a| !a.text.trim().is_empty()) {
+                if action.completed {
+                    completed.push((
+                        outcome.outcome_type.as_str().to_string(),
+                        action.text.trim().to_string(),
+                    ));
+                } else {
+                    open += 1;
+                }
+            }
+        }
+
+        let deltas = indicators
+            .indicators
+            .iter()
+            .filter(|def| def.active)
+            .filter_map(|def| {
+                let mut history: Vec<&Observation> = observations
+                    .iter()
+                    .filter(|obs| obs.indicator_id == def.id && obs.when <= goals.date)
+                    .collect();
+                history.sort_by_key(|obs| (obs.when, obs.created));
+                let current = history.iter().rev().find(|obs| obs.when == goals.date)?;
+                let previous = history
+                    .iter()
+                    .rev()
+                    .find(|obs| obs.when < goals.date)
+                    .map(|obs| obs.value);
+                Some(IndicatorDelta {
+                    name: def.name.clone(),
+                    unit: def.unit.clone(),
+                    previous,
+                    current: current.value,
+                })
+            })
+            .collect();
+
+        let notes = goals
+            .outcomes()
+            .iter()
+            .filter_map(|outcome| {
+                let note = outcome.reflection.as_deref()?.trim();
+                (!note.is_empty()).then(|| format!("{}: {}", outcome.outcome_type.as_str(), note))
+            })
+            .collect();
+
+        SummaryInput {
+            date: goals.date,
+            completed,
+            open,
+            deltas,
+            notes,
+        }
+    }
+}
+
+/// A value with its unit, e.g. "45 min" or "$12.5"
+pub fn format_value(value: f64, unit: &IndicatorUnit) -> String {
+    let number = if value.fract().abs() < f64::EPSILON {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.1}", value)
+    };
+    match unit {
+        IndicatorUnit::Count => number,
+        IndicatorUnit::Minutes => format!("{} min", number),
+        IndicatorUnit::Dollars => format!("${}", number),
+        IndicatorUnit::Percent => format!("{}%", number),
+        IndicatorUnit::Custom(label) => format!("{} {}", number, label),
+    }
+}
+
+fn describe_delta(delta: &IndicatorDelta) -> String {
+    let current = format_value(delta.current, &delta.unit);
+    match delta.previous {
+        Some(previous) => format!(
+            "{} {} (from {})",
+            delta.name,
+            current,
+            format_value(previous, &delta.unit)
+        ),
+        None => format!("{} {}", delta.name, current),
+    }
+}
+
+/// Prompt sent to the configured summary provider
+pub fn build_prompt(input: &SummaryInput) -> String {
+    let mut prompt = format!(
+        "Write a short first-person evening reflection (3-5 sentences, one paragraph) \
+         for {}. Mention what went well, what slipped and one intention for tomorrow. \
+         Use only these facts:\n",
+        input.date.format("%A, %B %-d")
+    );
+
+    prompt.push_str("\nCompleted actions:\n");
+    if input.completed.is_empty() {
+        prompt.push_str("- none\n");
+    }
+    for (outcome, text) in &input.completed {
+        prompt.push_str(&format!("- [{}] {}\n", outcome, text));
+    }
+    prompt.push_str(&format!("\nActions left open: {}\n", input.open));
+
+    if !input.deltas.is_empty() {
+        prompt.push_str("\nIndicators logged today:\n");
+        for delta in &input.deltas {
+            prompt.push_str(&format!("- {}\n", describe_delta(delta)));
+        }
+    }
+
+    if !input.notes.is_empty() {
+        prompt.push_str("\nMy notes:\n");
+        for note in &input.notes {
+            prompt.push_str(&format!("- {}\n", note));
+        }
+    }
+
+    prompt
+}
+
+/// Draft built without a provider, from the same facts as the prompt
+pub fn template_draft(input: &SummaryInput) -> String {
+    let mut sentences = Vec::new();
+
+    if input.completed.is_empty() {
+        sentences.push("I didn't complete any planned actions today.".to_string());
+    } else {
+        let done: Vec<&str> = input
+            .completed
+            .iter()
+            .map(|(_, text)| text.as_str())
+            .collect();
+        sentences.push(format!(
+            "I completed {} action{}: {}.",
+            done.len(),
+            if done.len() == 1 { "" } else { "s" },
+            done.join("; ")
+        ));
+    }
+
+    if input.open > 0 {
+        sentences.push(format!(
+            "{} action{} stayed open.",
+            input.open,
+            if input.open == 1 { "" } else { "s" }
+        ));
+    }
+
+    if !input.deltas.is_empty() {
+        let logged: Vec<String> = input.deltas.iter().map(describe_delta).collect();
+        sentences.push(format!("Logged {}.", logged.join(", ")));
+    }
+
+    sentences.extend(
+        input
+            .notes
+            .iter()
+            .map(|note| format!("{}.", note.trim_end_matches('.'))),
+    );
+    sentences.push("Tomorrow I want to ".to_string());
+
+    sentences.join(" ")
+}
+
+/// The key for the provider, from the environment variable named by
+/// `summary.api_key_env`; `Ok(None)` when no variable is configured
+fn api_key(settings: &SummarySettings) -> Result<Option<String>> {
+    let Some(name) = settings
+        .api_key_env
+        .as_deref()
+        .map(str::trim)
+        .filter(|n| !n.is_empty())
+    else {
+        return Ok(None);
+    };
+    match std::env::var(name) {
+        Ok(key) if !key.trim().is_empty() => Ok(Some(key.trim().to_string())),
+        _ => bail!("Summary API key variable {} is not set", name),
+    }
+}
+
+fn timeout(settings: &SummarySettings) -> Duration {
+    Duration::from_secs(settings.timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS).max(1))
+}
+
+fn endpoint(settings: &SummarySettings) -> Option<&str> {
+    settings
+        .endpoint
+        .as_deref()
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+}
+
+/// Send the prompt to the configured endpoint and return the reply as a
+/// single paragraph. Returns `Ok(None)` when no endpoint is configured.
+/// Blocks for up to `summary.timeout_secs`; the app goes through
+/// [`DraftRequest`] instead.
+pub fn request_draft(input: &SummaryInput, settings: &SummarySettings) -> Result<Option<String>> {
+    let Some(endpoint) = endpoint(settings) else {
+        return Ok(None);
+    };
+
+    let agent = ureq::AgentBuilder::new().timeout(timeout(settings)).build();
+    let mut request = agent.post(endpoint);
+    if let Some(key) = api_key(settings)? {
+        request = request.set("Authorization", &format!("Bearer {}", key));
+    }
+    let body = serde_json::json!({
+        "model": settings.model.as_deref().unwrap_or(DEFAULT_MODEL),
+        "messages": [{ "role": "user", "content": build_prompt(input) }],
+        "stream": false,
+    });
+    let response = match request.send_json(body) {
+        Ok(response) => response,
+        Err(ureq::Error::Status(status, response)) => bail!(
+            "Summary provider failed ({}): {}",
+            status,
+            response.into_string().unwrap_or_default().trim()
+        ),
+        Err(e) => return Err(anyhow!(e)).context("Summary provider unreachable"),
+    };
+    let reply: serde_json::Value = response
+        .into_json()
+        .context("Summary provider sent an unreadable reply")?;
+
+    // The reflection editor is single-line, so collapse the reply
+    let draft = reply["choices"][0]["message"]["content"]
+        .as_str()
+        .unwrap_or_default()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if draft.is_empty() {
+        bail!("Summary provider returned no text");
+    }
+    Ok(Some(draft.chars().take(MAX_REFLECTION_LENGTH).collect()))
+}
+
+/// A draft being requested on a worker thread, so a slow provider doesn't
+/// hold up the interface. The app polls it from `tick`.
+pub struct DraftRequest {
+    /// Day the reflection is for
+    pub date: NaiveDate,
+    /// Template draft, used when the request fails
+    pub fallback: String,
+    pub(crate) result: Receiver<Result<String>>,
+    timeout: Duration,
+    pub(crate) deadline: Instant,
+}
+
+impl DraftRequest {
+    /// Start requesting a draft for `input`; `None` when no endpoint is
+    /// configured
+    pub fn start(input: &SummaryInput, settings: &SummarySettings) -> Option<Self> {
+        endpoint(settings)?;
+        let (result_tx, result_rx) = mpsc::channel();
+        let worker_input = input.clone();
+        let worker_settings = settings.clone();
+        // Not joined: the agent's timeout ends the thread, and a request
+        // still running at exit is abandoned
+        std::thread::spawn(move || {
+            let draft = request_draft(&worker_input, &worker_settings);
+            let _ = result_tx.send(draft.map(Option::unwrap_or_default));
+        });
+
+        let timeout = timeout(settings);
+        Some(DraftRequest {
+            date: input.date,
+            fallback: template_draft(input),
+            result: result_rx,
+            timeout,
+            deadline: Instant::now() + timeout,
+        })
+    }
+
+    pub(crate) fn timed_out(&self) -> anyhow::Error {
+        anyhow!(
+            "Summary provider did not answer within {}s",
+            self.timeout.as_secs()
+        )
+    }
+
+    /// The draft once the provider has answered or the timeout has passed,
+    /// without blocking
+    pub fn try_finish(&self) -> Option<Result<String>> {
+        match self.result.try_recv() {
+            Ok(draft) => Some(draft),
+            Err(TryRecvError::Empty) if Instant::now() < self.deadline => None,
+            Err(TryRecvError::Empty) => Some(Err(self.timed_out())),
+            Err(TryRecvError::Disconnected) => Some(Err(anyhow!("Summary request stopped"))),
+        }
+    }
+}
+
+/// The day's goals and completion as a plain-text checklist for pasting
+/// into a standup or chat, copied with `Y`
+pub fn standup_text(goals: &DailyGoals) -> String {
+    let planned: Vec<_> = goals
+        .outcomes()
+        .into_iter()
+        .flat_map(|outcome| outcome.actions.iter())
+        .filter(|action| !action.text.trim().is_empty())
+        .collect();
+    let done = planned.iter().filter(|action| action.completed).count();
+    let mut text = format!(
+        "FocusFive · {} · {}/{} done\n",
+        goals.date.format("%a, %b %-d"),
+        done,
+        planned.len()
+    );
+
+    for outcome in goals.outcomes() {
+        let actions: Vec<_> = outcome
+            .actions
+            .iter()
+            .filter(|action| !action.text.trim().is_empty())
+            .collect();
+        let done = actions.iter().filter(|action| action.completed).count();
+        text.push('\n');
+        text.push_str(outcome.outcome_type.as_str());
+        if let Some(goal) = outcome.goal.as_deref().filter(|goal| !goal.trim().is_empty()) {
+            text.push_str(&format!(" - {}", goal.trim()));
+        }
+        text.push_str(&format!(" ({}/{})\n", done, actions.len()));
+        if actions.is_empty() {
+            text.push_str("- nothing planned\n");
+        }
+        for action in actions {
+            let checkbox = if action.completed { "[x]" } else { "[ ]" };
+            text.push_str(&format!("- {} {}\n", checkbox, action.text.trim()));
+        }
+    }
+    text
+}
+
+/// Template file used from the data root when `summary.template` is unset
+pub const SUMMARY_TEMPLATE_FILE: &str = "summary_template.md";
+
+/// Built-in daily summary template
+pub const DEFAULT_SUMMARY_TEMPLATE: &str = "\
+📊 Day {{day_number}} Summary
+Completion: {{completed}}/{{total}} ({{percentage}}%)
+
+{{#each outcomes}}
+{{this}}
+{{/each}}
+{{#if notes}}
+
+📝 Reflections:
+{{#each notes}}
+{{this}}
+{{/each}}
+{{/if}}
+";
+
+/// Value of a template placeholder
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplateValue {
+    Text(String),
+    List(Vec<String>),
+}
+
+impl TemplateValue {
+    /// Whether `{{#if}}` takes its first branch: non-empty text other than
+    /// "0", or a non-empty list
+    fn is_truthy(&self) -> bool {
+        match self {
+            TemplateValue::Text(text) => !text.is_empty() && text != "0",
+            TemplateValue::List(items) => !items.is_empty(),
+        }
+    }
+}
+
+/// Placeholder names and values a summary template is rendered with
+pub type SummaryValues = BTreeMap<&'static str, TemplateValue>;
+
+fn outcome_icon(done: usize) -> &'static str {
+    match done {
+        3 => "✅",
+        2 => "🔶",
+        1 => "⚠️",
+        _ => "❌",
+    }
+}
+
+/// Values for the day's summary. `reflection` is the saved evening
+/// reflection, if any.
+pub fn summary_values(
+    goals: &DailyGoals,
+    input: &SummaryInput,
+    reflection: Option<&str>,
+) -> SummaryValues {
+    let stats = goals.completion_stats();
+    let text = |value: String| TemplateValue::Text(value);
+    let mut values = SummaryValues::new();
+
+    values.insert("date", text(goals.date.format("%Y-%m-%d").to_string()));
+    values.insert("weekday", text(goals.date.format("%A").to_string()));
+    values.insert(
+        "day_number",
+        text(goals.day_number.unwrap_or(0).to_string()),
+    );
+    values.insert("completed", text(stats.completed.to_string()));
+    values.insert("total", text(stats.total.to_string()));
+    values.insert("percentage", text(stats.percentage.to_string()));
+    values.insert("open", text(input.open.to_string()));
+    for (key, (_, done, total)) in ["work", "health", "family"]
+        .into_iter()
+        .zip(&stats.by_outcome)
+    {
+        values.insert(key, text(format!("{}/{}", done, total)));
+    }
+    values.insert(
+        "outcomes",
+        TemplateValue::List(
+            stats
+                .by_outcome
+                .iter()
+                .map(|(name, done, total)| {
+                    format!("{} {}: {}/{}", outcome_icon(*done), name, done, total)
+                })
+                .collect(),
+        ),
+    );
+    values.insert(
+        "wins",
+        TemplateValue::List(input.completed.iter().map(|(_, t)| t.clone()).collect()),
+    );
+    values.insert(
+        "open_actions",
+        TemplateValue::List(
+            goals
+                .outcomes()
+                .iter()
+                .flat_map(|outcome| outcome.actions.iter())
+                .filter(|action| !action.completed && !action.text.trim().is_empty())
+                .map(|action| action.text.trim().to_string())
+                .collect(),
+        ),
+    );
+    values.insert("notes", TemplateValue::List(input.notes.clone()));
+    values.insert(
+        "indicators",
+        TemplateValue::List(input.deltas.iter().map(describe_delta).collect()),
+    );
+    values.insert(
+        "reflection",
+        text(reflection.map(str::trim).unwrap_or_default().to_string()),
+    );
+    values
+}
+
+#[derive(Debug)]
+enum Node {
+    Text(String),
+    Value(String),
+    Each(String, Vec<Node>),
+    If(String, Vec<Node>, Vec<Node>),
+}
+
+/// An open `{{#each}}`/`{{#if}}` block while parsing
+struct Block {
+    kind: &'static str,
+    name: String,
+    nodes: Vec<Node>,
+    else_nodes: Option<Vec<Node>>,
+}
+
+impl Block {
+    fn nodes_mut(&mut self) -> &mut Vec<Node> {
+        self.else_nodes.as_mut().unwrap_or(&mut self.nodes)
+    }
+}
+
+fn parse_template(template: &str) -> Result<Vec<Node>> {
+    let mut stack = vec![Block {
+        kind: "",
+        name: String::new(),
+        nodes: Vec::new(),
+        else_nodes: None,
+    }];
+    let mut pos = 0;
+
+    while let Some(offset) = template[pos..].find("{{") {
+        let start = pos + offset;
+        let Some(close) = template[start..].find("}}") else {
+            bail!("Unclosed '{{{{' in summary template");
+        };
+        let end = start + close + 2;
+        let tag = template[start + 2..end - 2].trim();
+        let is_block = tag.starts_with('#') || tag.starts_with('/') || tag == "else";
+
+        // A block tag alone on its line takes the whole line with it
+        let line_start = template[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = template[end..]
+            .find('\n')
+            .map_or(template.len(), |i| end + i);
+        let standalone = is_block
+            && template[line_start..start].trim().is_empty()
+            && template[end..line_end].trim().is_empty();
+        let (text_end, next) = if standalone {
+            (line_start.max(pos), (line_end + 1).min(template.len()))
+        } else {
+            (start, end)
+        };
+        if text_end > pos {
+            let text = template[pos..text_end].to_string();
+            stack.last_mut().unwrap().nodes_mut().push(Node::Text(text));
+        }
+        pos = next;
+
+        if let Some(open) = tag.strip_prefix('#') {
+            let (kind, name) = open.split_once(char::is_whitespace).unwrap_or((open, ""));
+            let kind = match kind {
+                "each" => "each",
+                "if" => "if",
+                other => bail!("Unknown block '{{{{#{}}}}}' in summary template", other),
+            };
+            if name.trim().is_empty() {
+                bail!("'{{{{#{}}}}}' needs a placeholder name", kind);
+            }
+            stack.push(Block {
+                kind,
+                name: name.trim().to_string(),
+                nodes: Vec::new(),
+                else_nodes: None,
+            });
+        } else if let Some(kind) = tag.strip_prefix('/') {
+            let kind = kind.trim();
+            if stack.len() == 1 || stack.last().unwrap().kind != kind {
+                bail!("Unexpected '{{{{/{}}}}}' in summary template", kind);
+            }
+            let block = stack.pop().unwrap();
+            let node = if kind == "each" {
+                Node::Each(block.name, block.nodes)
+            } else {
+                Node::If(
+                    block.name,
+                    block.nodes,
+                    block.else_nodes.unwrap_or_default(),
+                )
+            };
+            stack.last_mut().unwrap().nodes_mut().push(node);
+        } else if tag == "else" {
+            match stack.last_mut() {
+                Some(block) if block.kind == "if" && block.else_nodes.is_none() => {
+                    block.else_nodes = Some(Vec::new());
+                }
+                _ => bail!("'{{{{else}}}}' outside '{{{{#if}}}}' in summary template"),
+            }
+        } else {
+            let node = Node::Value(tag.to_string());
+            stack.last_mut().unwrap().nodes_mut().push(node);
+        }
+    }
+    if pos < template.len() {
+        let text = template[pos..].to_string();
+        stack.last_mut().unwrap().nodes_mut().push(Node::Text(text));
+    }
+
+    if stack.len() > 1 {
+        let block = stack.pop().unwrap();
+        bail!(
+            "Unclosed '{{{{#{} {}}}}}' in summary template",
+            block.kind,
+            block.name
+        );
+    }
+    Ok(stack.pop().unwrap().nodes)
+}
+
+fn lookup<'a>(values: &'a SummaryValues, name: &str) -> Result<&'a TemplateValue> {
+    values.get(name).with_context(|| {
+        let known: Vec<&str> = values.keys().copied().collect();
+        format!(
+            "Unknown placeholder '{}' in summary template (available: {})",
+            name,
+            known.join(", ")
+        )
+    })
+}
+
+fn render_nodes(
+    nodes: &[Node],
+    values: &SummaryValues,
+    item: Option<&str>,
+    out: &mut String,
+) -> Result<()> {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Value(name) if name == "this" => match item {
+                Some(item) => out.push_str(item),
+                None => bail!("'{{{{this}}}}' used outside '{{{{#each}}}}' in summary template"),
+            },
+            Node::Value(name) => match lookup(values, name)? {
+                TemplateValue::Text(text) => out.push_str(text),
+                TemplateValue::List(items) => out.push_str(&items.join(", ")),
+            },
+            Node::Each(name, body) => match lookup(values, name)? {
+                TemplateValue::List(items) => {
+                    for entry in items {
+                        render_nodes(body, values, Some(entry), out)?;
+                    }
+                }
+                TemplateValue::Text(_) => {
+                    bail!(
+                        "'{}' is not a list and can't be used with '{{{{#each}}}}'",
+                        name
+                    )
+                }
+            },
+            Node::If(name, then, otherwise) => {
+                let branch = if lookup(values, name)?.is_truthy() {
+                    then
+                } else {
+                    otherwise
+                };
+                render_nodes(branch, values, item, out)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Fill in a handlebars-style template. Unknown placeholders and unbalanced
+/// blocks are errors so typos show up instead of vanishing.
+pub fn render_template(template: &str, values: &SummaryValues) -> Result<String> {
+    let nodes = parse_template(template)?;
+    let mut out = String::new();
+    render_nodes(&nodes, values, None, &mut out)?;
+    Ok(out)
+}
+
+/// The user's summary template: the file named by `summary.template`
+/// (relative to the data root) or `summary_template.md` in the data root.
+/// `Ok(None)` means the built-in template applies.
+pub fn load_template(settings: &SummarySettings, config: &Config) -> Result<Option<String>> {
+    let root = Path::new(&config.data_root);
+    let path = match settings
+        .template
+        .as_deref()
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+    {
+        Some(configured) => root.join(configured),
+        None => {
+            let path = root.join(SUMMARY_TEMPLATE_FILE);
+            if !path.exists() {
+                return Ok(None);
+            }
+            path
+        }
+    };
+    fs::read_to_string(&path)
+        .map(Some)
+        .with_context(|| format!("Failed to read summary template: {}", path.display()))
+}
+
+/// The day's summary from the user's template, or the built-in one
+pub fn render_summary(
+    goals: &DailyGoals,
+    input: &SummaryInput,
+    reflection: Option<&str>,
+    settings: &SummarySettings,
+    config: &Config,
+) -> Result<String> {
+    let template = load_template(settings, config)?;
+    let template = template.as_deref().unwrap_or(DEFAULT_SUMMARY_TEMPLATE);
+    render_template(template, &summary_values(goals, input, reflection))
+}
+
+/// Gather the day's facts from disk and render its summary
+pub fn summary_for_day(
+    goals: &DailyGoals,
+    indicators: &IndicatorsData,
+    settings: &SummarySettings,
+    config: &Config,
+) -> Result<String> {
+    let observations = crate::data::read_observations_range(
+        goals.date - chrono::Duration::days(30),
+        goals.date,
+        config,
+    )?;
+    let input = SummaryInput::gather(goals, indicators, &observations);
+    let reflection = crate::data::load_reflection(goals.date, config)?;
+    render_summary(goals, &input, reflection.as_deref(), settings, config)
+}
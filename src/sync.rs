@@ -0,0 +1,881 @@
+//! Sync: the data directory is mirrored with a target, either a folder (a
+//! mounted share, an rclone mount, a Syncthing folder) or a folder on a
+//! WebDAV server, reached through [`crate::webdav`]. The content hash of
+//! each file at the last sync tells which side changed since; on WebDAV,
+//! an ETag unchanged since the last sync saves downloading the file. Files changed on both
+//! sides are merged where that is safe (goals files part by part, logs by
+//! line); the rest are conflicts, resolved by picking a side for each part.
+//! The actions of a goals file are matched across versions by the ids in
+//! the day's metadata, which follows the merged file.
+
+use crate::models::{Action, ActionMeta, Config, DailyGoals, DayMeta, Outcome, OutcomeType};
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Prefix of the files of the goals directory, as in `crate::backups`
+const GOALS: &str = "goals";
+/// Per-machine state and caches rebuilt from the other files
+const LOCAL_ONLY: [&str; 7] = [
+    "backups",
+    "sync",
+    "settings.json",
+    "recovery.json",
+    "stats.json",
+    "sentiment.json",
+    "observations.idx",
+];
+const OUTCOMES: [OutcomeType; 3] = [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Local,
+    Remote,
+}
+
+/// A part of a goals file that both sides changed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Spot {
+    Goal(OutcomeType),
+    Action(OutcomeType, usize),
+    Reflection(OutcomeType),
+}
+
+impl Spot {
+    pub fn label(&self) -> String {
+        match self {
+            Spot::Goal(outcome) => format!("{} goal", outcome.as_str()),
+            Spot::Action(outcome, index) => format!("{} action {}", outcome.as_str(), index + 1),
+            Spot::Reflection(outcome) => format!("{} reflection", outcome.as_str()),
+        }
+    }
+}
+
+/// The versions of one conflicting part; None where it is absent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hunk {
+    pub spot: Spot,
+    pub base: Option<String>,
+    pub local: Option<String>,
+    pub remote: Option<String>,
+}
+
+/// A file changed on both sides that could not be merged. Goals files list
+/// the parts in conflict; other files are taken whole from one side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict {
+    pub file: String,
+    pub hunks: Vec<Hunk>,
+}
+
+impl Conflict {
+    /// Sides to choose: one per hunk, or one for the whole file
+    pub fn choices_needed(&self) -> usize {
+        self.hunks.len().max(1)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SyncReport {
+    pub pushed: Vec<String>,
+    pub pulled: Vec<String>,
+    pub merged: Vec<String>,
+    pub conflicts: Vec<Conflict>,
+}
+
+impl SyncReport {
+    pub fn summary(&self) -> String {
+        let mut summary = format!(
+            "Synced: {} pushed, {} pulled, {} merged",
+            self.pushed.len(),
+            self.pulled.len(),
+            self.merged.len()
+        );
+        if !self.conflicts.is_empty() {
+            summary.push_str(&format!(", {} in conflict", self.conflicts.len()));
+        }
+        summary
+    }
+}
+
+/// The summary followed by the conflicts and their parts
+pub fn format_report(report: &SyncReport) -> String {
+    let mut lines = vec![report.summary()];
+    for conflict in &report.conflicts {
+        lines.push(format!("  conflict: {}", conflict.file));
+        lines.extend(
+            conflict
+                .hunks
+                .iter()
+                .map(|hunk| format!("    {}", hunk.spot.label())),
+        );
+    }
+    if !report.conflicts.is_empty() {
+        lines.push("Resolve them with :sync in the app.".to_string());
+    }
+    lines.join("\n")
+}
+
+/// Hashes of the files as of their last sync, and the WebDAV ETags of
+/// those versions
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SyncState {
+    #[serde(default)]
+    files: BTreeMap<String, String>,
+    #[serde(default)]
+    etags: BTreeMap<String, String>,
+}
+
+/// FNV-1a; stable across builds, which the saved hashes rely on
+fn content_hash(content: &[u8]) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in content {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    format!("{:016x}", hash)
+}
+
+fn state_dir(config: &Config) -> PathBuf {
+    Path::new(&config.data_root).join("sync")
+}
+
+fn load_state(config: &Config) -> Result<SyncState> {
+    let path = state_dir(config).join("state.json");
+    if !path.exists() {
+        return Ok(SyncState::default());
+    }
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read sync state: {}", path.display()))?;
+    serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse sync state: {}", path.display()))
+}
+
+fn save_state(state: &SyncState, config: &Config) -> Result<()> {
+    let dir = state_dir(config);
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create sync directory: {}", dir.display()))?;
+    let json = serde_json::to_string_pretty(state).context("Failed to serialize sync state")?;
+    crate::data::atomic_write(&dir.join("state.json"), json.as_bytes())
+}
+
+/// The copy of a file as of its last sync, the base of three-way merges
+fn base_path(file: &str, config: &Config) -> PathBuf {
+    state_dir(config).join("base").join(file)
+}
+
+/// Where the data directory is synced to
+#[derive(Debug, Clone, PartialEq)]
+pub enum Target {
+    Folder(PathBuf),
+    WebDav(crate::webdav::Client),
+}
+
+impl Target {
+    /// A WebDAV folder for http(s) URLs, a local folder otherwise
+    pub fn parse(location: &str) -> Self {
+        if crate::webdav::is_url(location) {
+            Target::WebDav(crate::webdav::Client::new(location))
+        } else {
+            Target::Folder(PathBuf::from(location))
+        }
+    }
+
+    /// The files there with their ETags, where the target has them
+    fn files(&self) -> Result<BTreeMap<String, Option<String>>> {
+        match self {
+            Target::Folder(dir) => {
+                if !dir.is_dir() {
+                    bail!(
+                        "Sync folder {} not found; mount or create it first",
+                        dir.display()
+                    );
+                }
+                let mut files = BTreeSet::new();
+                collect(dir, dir, None, &mut files)?;
+                Ok(files.into_iter().map(|file| (file, None)).collect())
+            }
+            Target::WebDav(client) => Ok(client
+                .list()?
+                .into_iter()
+                .filter(|(file, _)| is_synced(file))
+                .collect()),
+        }
+    }
+
+    fn read(&self, file: &str) -> Result<Option<Vec<u8>>> {
+        match self {
+            Target::Folder(dir) => Ok(read(&dir.join(file))),
+            Target::WebDav(client) => client.get(file),
+        }
+    }
+
+    /// Write `content` to `file`, or delete it for None; returns the new
+    /// ETag when the target gives one
+    fn put(&self, file: &str, content: Option<&[u8]>) -> Result<Option<String>> {
+        match (self, content) {
+            (Target::Folder(dir), content) => put(&dir.join(file), content).map(|_| None),
+            (Target::WebDav(client), Some(content)) => client.put(file, content),
+            (Target::WebDav(client), None) => client.delete(file).map(|_| None),
+        }
+    }
+}
+
+impl std::fmt::Display for Target {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Target::Folder(dir) => write!(f, "{}", dir.display()),
+            Target::WebDav(client) => write!(f, "{}", client),
+        }
+    }
+}
+
+/// The sync target remembered in settings.json
+pub fn target(config: &Config) -> Result<Target> {
+    match crate::data::load_settings(config)?.sync_dir {
+        Some(location) => Ok(Target::parse(&location)),
+        None => bail!("No sync folder set; run `focusfive sync <folder|url>` once"),
+    }
+}
+
+/// Remember `location`, a folder or a WebDAV URL, as the sync target
+pub fn set_target(location: &str, config: &Config) -> Result<()> {
+    let mut settings = crate::data::load_settings(config)?;
+    settings.sync_dir = Some(location.to_string());
+    crate::data::save_settings(&settings, config)?;
+    Ok(())
+}
+
+fn slash_path(relative: &Path) -> String {
+    relative
+        .components()
+        .map(|part| part.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Files under `dir` by their path relative to `root`, skipping `skip`,
+/// the local-only files at the top and temporary dotfiles
+fn collect(
+    dir: &Path,
+    root: &Path,
+    skip: Option<&Path>,
+    files: &mut BTreeSet<String>,
+) -> Result<()> {
+    if !dir.exists() {
+        return Ok(());
+    }
+    for entry in fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir.display()))? {
+        let path = entry?.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if name.starts_with('.') || skip == Some(path.as_path()) {
+            continue;
+        }
+        if dir == root && LOCAL_ONLY.contains(&name.as_ref()) {
+            continue;
+        }
+        if path.is_dir() {
+            collect(&path, root, skip, files)?;
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            files.insert(slash_path(relative));
+        }
+    }
+    Ok(())
+}
+
+/// Whether a file listed on a server is one `collect` would have kept
+fn is_synced(file: &str) -> bool {
+    let top = file.split('/').next().unwrap_or_default();
+    !LOCAL_ONLY.contains(&top) && !file.split('/').any(|part| part.starts_with('.'))
+}
+
+fn local_files(config: &Config) -> Result<BTreeSet<String>> {
+    let goals_dir = Path::new(&config.goals_dir);
+    let mut files = BTreeSet::new();
+    collect(
+        Path::new(&config.data_root),
+        Path::new(&config.data_root),
+        Some(goals_dir),
+        &mut files,
+    )?;
+    let mut goals = BTreeSet::new();
+    collect(goals_dir, goals_dir, None, &mut goals)?;
+    files.extend(goals.into_iter().map(|file| format!("{}/{}", GOALS, file)));
+    Ok(files)
+}
+
+fn local_path(file: &str, config: &Config) -> PathBuf {
+    crate::backups::original_path(file, config)
+}
+
+fn read(path: &Path) -> Option<Vec<u8>> {
+    fs::read(path).ok()
+}
+
+/// Write `content` to `path`, or remove it for None, creating directories
+fn put(path: &Path, content: Option<&[u8]>) -> Result<()> {
+    match content {
+        Some(content) => {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)
+                    .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+            }
+            crate::data::atomic_write(path, content)
+        }
+        None if path.exists() => {
+            fs::remove_file(path).with_context(|| format!("Failed to remove {}", path.display()))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Replace a local file, backing up what it replaces
+fn put_local(file: &str, content: Option<&[u8]>, config: &Config) -> Result<()> {
+    let path = local_path(file, config);
+    crate::backups::backup_file(&path, config)?;
+    put(&path, content)
+}
+
+/// Record `content` as the synced version of `file`, with the ETag the
+/// target has for it
+fn settle(
+    file: &str,
+    content: Option<&[u8]>,
+    etag: Option<String>,
+    state: &mut SyncState,
+    config: &Config,
+) -> Result<()> {
+    put(&base_path(file, config), content)?;
+    match content {
+        Some(content) => state.files.insert(file.to_string(), content_hash(content)),
+        None => state.files.remove(file),
+    };
+    match etag {
+        Some(etag) => state.etags.insert(file.to_string(), etag),
+        None => state.etags.remove(file),
+    };
+    Ok(())
+}
+
+fn is_goals_file(file: &str) -> bool {
+    file.starts_with(&format!("{}/", GOALS)) && file.ends_with(".md")
+}
+
+/// The metadata file of the day of goals file `file`
+fn meta_file(file: &str) -> Option<String> {
+    let date = file
+        .strip_prefix(&format!("{}/", GOALS))?
+        .strip_suffix(".md")?;
+    chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?;
+    Some(format!("meta/{}.meta.json", date))
+}
+
+/// The day metadata of goals file `file` as last synced, here and in
+/// `target`; all None for other files
+fn day_metas(file: &str, target: &Target, config: &Config) -> Result<[Option<DayMeta>; 3]> {
+    let Some(meta) = meta_file(file) else {
+        return Ok([None, None, None]);
+    };
+    let parse = |content: Option<Vec<u8>>| content.and_then(|c| serde_json::from_slice(&c).ok());
+    Ok([
+        parse(read(&base_path(&meta, config))),
+        parse(read(&local_path(&meta, config))),
+        parse(target.read(&meta)?),
+    ])
+}
+
+/// Write the metadata of a merged goals file to both sides
+fn put_day_meta(
+    file: &str,
+    meta: &DayMeta,
+    target: &Target,
+    state: &mut SyncState,
+    config: &Config,
+) -> Result<()> {
+    let Some(meta_file) = meta_file(file) else {
+        return Ok(());
+    };
+    let content = serde_json::to_vec_pretty(meta).context("Failed to serialize day metadata")?;
+    put_local(&meta_file, Some(&content), config)?;
+    let etag = target.put(&meta_file, Some(&content))?;
+    settle(&meta_file, Some(&content), etag, state, config)
+}
+
+/// Mirror the data directory with `target`
+pub fn run(target: &Target, config: &Config) -> Result<SyncReport> {
+    let remote_files = target.files()?;
+    let mut state = load_state(config)?;
+    let mut files = local_files(config)?;
+    files.extend(remote_files.keys().cloned());
+    files.extend(state.files.keys().cloned());
+
+    let mut report = SyncReport::default();
+    // Metadata of goals files in conflict, settled when they are resolved
+    let mut following = HashSet::new();
+    for file in files {
+        if following.contains(&file) {
+            continue;
+        }
+        let local = read(&local_path(&file, config));
+        let etag = remote_files.get(&file).cloned().flatten();
+        // An ETag unchanged since the last sync means the base copy is
+        // what the server has
+        let unchanged = etag.is_some() && state.etags.get(&file) == etag.as_ref();
+        let remote = match unchanged.then(|| read(&base_path(&file, config))).flatten() {
+            Some(content) => Some(content),
+            None if remote_files.contains_key(&file) => target.read(&file)?,
+            None => None,
+        };
+        let hash = |content: &Option<Vec<u8>>| content.as_deref().map(content_hash);
+        let base = state.files.get(&file).cloned();
+        let (local_hash, remote_hash) = (hash(&local), hash(&remote));
+
+        if local_hash == remote_hash {
+            if local_hash != base || state.etags.get(&file) != etag.as_ref() {
+                settle(&file, local.as_deref(), etag, &mut state, config)?;
+            }
+        } else if local_hash == base {
+            put_local(&file, remote.as_deref(), config)?;
+            settle(&file, remote.as_deref(), etag, &mut state, config)?;
+            report.pulled.push(file);
+        } else if remote_hash == base {
+            let etag = target.put(&file, local.as_deref())?;
+            settle(&file, local.as_deref(), etag, &mut state, config)?;
+            report.pushed.push(file);
+        } else {
+            let base_content = base.and_then(|_| read(&base_path(&file, config)));
+            match merge(
+                &file,
+                base_content.as_deref(),
+                local.as_deref(),
+                remote.as_deref(),
+                &day_metas(&file, target, config)?,
+                &[],
+            ) {
+                Ok((merged, meta)) => {
+                    put_local(&file, merged.as_deref(), config)?;
+                    let etag = target.put(&file, merged.as_deref())?;
+                    settle(&file, merged.as_deref(), etag, &mut state, config)?;
+                    if let Some(meta) = meta {
+                        put_day_meta(&file, &meta, target, &mut state, config)?;
+                    }
+                    report.merged.push(file);
+                }
+                Err(hunks) => {
+                    following.extend(meta_file(&file));
+                    report.conflicts.push(Conflict { file, hunks });
+                }
+            }
+        }
+    }
+    save_state(&state, config)?;
+
+    let changed = report.pulled.iter().chain(&report.merged);
+    if changed
+        .into_iter()
+        .any(|file| file == "observations.ndjson")
+    {
+        crate::data::rebuild_observation_index(config)?;
+    }
+    Ok(report)
+}
+
+/// A merged file, None when deleted, and the day metadata to go with it
+type Merged = (Option<Vec<u8>>, Option<DayMeta>);
+
+/// Merge two versions of `file` changed since `base`, taking `choices` for
+/// the conflicting parts in order; None when the file is to be deleted.
+/// Goals files also give the day metadata to go with the merge, when
+/// `metas` has any. Returns the parts still in conflict when there are not
+/// enough choices.
+fn merge(
+    file: &str,
+    base: Option<&[u8]>,
+    local: Option<&[u8]>,
+    remote: Option<&[u8]>,
+    metas: &[Option<DayMeta>; 3],
+    choices: &[Side],
+) -> std::result::Result<Merged, Vec<Hunk>> {
+    let text = |content: Option<&[u8]>| content.map(|c| String::from_utf8_lossy(c).into_owned());
+    let whole = |choice: Option<&Side>| match choice {
+        Some(Side::Local) => Ok((local.map(<[u8]>::to_vec), None)),
+        Some(Side::Remote) => Ok((remote.map(<[u8]>::to_vec), None)),
+        None => Err(Vec::new()),
+    };
+    let (Some(local_text), Some(remote_text)) = (text(local), text(remote)) else {
+        // Changed on one side, deleted on the other
+        return whole(choices.first());
+    };
+
+    if file.ends_with(".ndjson") {
+        let merged = merge_lines(text(base).as_deref(), &local_text, &remote_text);
+        return Ok((Some(merged.into_bytes()), None));
+    }
+    if is_goals_file(file) {
+        // Parsed actions get fresh ids; the day's metadata has the kept ones
+        let parse = |text: &str, meta: &Option<DayMeta>| {
+            crate::data::parse_markdown(text).map(|mut goals| {
+                if let Some(meta) = meta {
+                    meta.apply_ids(&mut goals);
+                }
+                goals
+            })
+        };
+        let [base_meta, local_meta, remote_meta] = metas;
+        let parsed = (
+            parse(&local_text, local_meta),
+            parse(&remote_text, remote_meta),
+        );
+        if let (Ok(local_goals), Ok(remote_goals)) = parsed {
+            let base_goals = text(base).and_then(|b| parse(&b, base_meta).ok());
+            let (merged, hunks) =
+                merge_goals(base_goals.as_ref(), &local_goals, &remote_goals, choices);
+            if !hunks.is_empty() {
+                return Err(hunks);
+            }
+            let meta = merged_meta(&merged, local_meta.as_ref(), remote_meta.as_ref());
+            return Ok((
+                Some(crate::data::generate_markdown(&merged).into_bytes()),
+                meta,
+            ));
+        }
+    }
+    whole(choices.first())
+}
+
+/// Union of appended lines: lines either side added are kept, lines either
+/// side removed are dropped
+fn merge_lines(base: Option<&str>, local: &str, remote: &str) -> String {
+    let base: HashSet<&str> = base.map(|b| b.lines().collect()).unwrap_or_default();
+    let local_lines: HashSet<&str> = local.lines().collect();
+    let remote_lines: HashSet<&str> = remote.lines().collect();
+    let mut merged: Vec<&str> = local
+        .lines()
+        .filter(|line| remote_lines.contains(line) || !base.contains(line))
+        .collect();
+    merged.extend(
+        remote
+            .lines()
+            .filter(|line| !local_lines.contains(line) && !base.contains(line)),
+    );
+    let mut merged = merged.join("\n");
+    if !merged.is_empty() {
+        merged.push('\n');
+    }
+    merged
+}
+
+/// Which side a three-way merge takes; None when both changed differently
+fn pick<T: PartialEq>(base: Option<T>, local: T, remote: T) -> Option<Side> {
+    if local == remote || base.as_ref() == Some(&remote) {
+        Some(Side::Local)
+    } else if base.as_ref() == Some(&local) {
+        Some(Side::Remote)
+    } else {
+        None
+    }
+}
+
+fn action_text(action: &Action) -> String {
+    format!(
+        "[{}] {}",
+        if action.completed { "x" } else { " " },
+        action.text
+    )
+}
+
+/// What a merge compares of an action
+fn action_key(action: &Action) -> (String, bool, Vec<String>) {
+    (
+        action.text.clone(),
+        action.completed,
+        action.get_all_objective_ids(),
+    )
+}
+
+/// Day metadata for `merged`: the local one with its actions' entries
+/// lined up with the merged actions by id, taken from either side
+fn merged_meta(
+    merged: &DailyGoals,
+    local: Option<&DayMeta>,
+    remote: Option<&DayMeta>,
+) -> Option<DayMeta> {
+    let mut meta = local.or(remote)?.clone();
+    for outcome in merged.outcomes() {
+        let kept = |id: &str| {
+            [local, remote]
+                .into_iter()
+                .flatten()
+                .find_map(|m| m.outcome(outcome.outcome_type).iter().find(|a| a.id == id))
+                .cloned()
+        };
+        *meta.outcome_mut(outcome.outcome_type) = outcome
+            .actions
+            .iter()
+            .map(|action| {
+                kept(&action.id).unwrap_or_else(|| ActionMeta {
+                    id: action.id.clone(),
+                    ..ActionMeta::default()
+                })
+            })
+            .collect();
+    }
+    Some(meta)
+}
+
+/// The actions already in a row of `align` and the row's position
+type Slot<'a> = (Option<usize>, Vec<&'a Action>);
+/// Whether the action at an index belongs to a slot
+type Matches = fn(usize, &Action, &Slot) -> bool;
+
+/// Pair each of `actions` with at most one of `slots`: by id, then by
+/// text, then by position for actions edited without a kept id
+fn pair(actions: &[Action], slots: &[Slot]) -> Vec<Option<usize>> {
+    let passes: [Matches; 3] = [
+        |_, action, slot| {
+            let id = &action.id;
+            slot.1.iter().any(|a| !id.is_empty() && &a.id == id)
+        },
+        |_, action, slot| slot.1.iter().any(|a| a.text == action.text),
+        |index, _, slot| slot.0 == Some(index),
+    ];
+
+    let mut paired = vec![None; actions.len()];
+    let mut taken = vec![false; slots.len()];
+    for matches in passes {
+        for (index, action) in actions.iter().enumerate() {
+            if paired[index].is_some() {
+                continue;
+            }
+            let found = (0..slots.len()).find(|&s| !taken[s] && matches(index, action, &slots[s]));
+            if let Some(s) = found {
+                paired[index] = Some(s);
+                taken[s] = true;
+            }
+        }
+    }
+    paired
+}
+
+/// Line up the actions of an outcome across the versions: rows of indices
+/// into base, local and remote, in local order with actions only the
+/// others have placed after the action before them there
+fn align(base: &[Action], local: &[Action], remote: &[Action]) -> Vec<[Option<usize>; 3]> {
+    let after = |rows: &[[Option<usize>; 3]], side: usize, index: usize| {
+        let previous = index.checked_sub(1);
+        rows.iter()
+            .position(|row| previous.is_some() && row[side] == previous)
+            .map_or(0, |p| p + 1)
+    };
+
+    let slots: Vec<Slot> = base
+        .iter()
+        .enumerate()
+        .map(|(i, action)| (Some(i), vec![action]))
+        .collect();
+    let mut rows: Vec<[Option<usize>; 3]> = pair(local, &slots)
+        .into_iter()
+        .enumerate()
+        .map(|(i, b)| [b, Some(i), None])
+        .collect();
+    for b in 0..base.len() {
+        if !rows.iter().any(|row| row[0] == Some(b)) {
+            let at = after(&rows, 0, b);
+            rows.insert(at, [Some(b), None, None]);
+        }
+    }
+
+    let slots: Vec<Slot> = rows
+        .iter()
+        .map(|row| {
+            let actions = [row[0].map(|i| &base[i]), row[1].map(|i| &local[i])];
+            (row[1], actions.into_iter().flatten().collect())
+        })
+        .collect();
+    let paired = pair(remote, &slots);
+    for (r, row) in paired.iter().enumerate() {
+        if let Some(row) = row {
+            rows[*row][2] = Some(r);
+        }
+    }
+    for (r, row) in paired.iter().enumerate() {
+        if row.is_none() {
+            let at = after(&rows, 2, r);
+            rows.insert(at, [None, None, Some(r)]);
+        }
+    }
+    rows
+}
+
+/// Merge goals part by part: each outcome's goal and reflection and each
+/// action, lined up by `align`. Conflicting parts take the next of
+/// `choices`, or the local version and a hunk when none is left.
+fn merge_goals(
+    base: Option<&DailyGoals>,
+    local: &DailyGoals,
+    remote: &DailyGoals,
+    choices: &[Side],
+) -> (DailyGoals, Vec<Hunk>) {
+    let mut merged = local.clone();
+    let mut hunks = Vec::new();
+    let mut next_choice = choices.iter();
+    let mut resolve = |spot: Spot,
+                       picked: Option<Side>,
+                       versions: [Option<String>; 3],
+                       hunks: &mut Vec<Hunk>|
+     -> Side {
+        if let Some(side) = picked {
+            return side;
+        }
+        if let Some(side) = next_choice.next() {
+            return *side;
+        }
+        let [base, local, remote] = versions;
+        hunks.push(Hunk {
+            spot,
+            base,
+            local,
+            remote,
+        });
+        Side::Local
+    };
+
+    for outcome_type in OUTCOMES {
+        let base_outcome = base.map(|b| b.outcome(outcome_type));
+        let (local_outcome, remote_outcome) =
+            (local.outcome(outcome_type), remote.outcome(outcome_type));
+        let merged_outcome = merged.outcome_mut(outcome_type);
+
+        let text_field = |outcome: &Outcome, reflection: bool| match reflection {
+            false => outcome.goal.clone(),
+            true => outcome.reflection.clone(),
+        };
+        for (reflection, spot) in [
+            (false, Spot::Goal(outcome_type)),
+            (true, Spot::Reflection(outcome_type)),
+        ] {
+            let versions = [
+                base_outcome.and_then(|o| text_field(o, reflection)),
+                text_field(local_outcome, reflection),
+                text_field(remote_outcome, reflection),
+            ];
+            let picked = pick(
+                base_outcome.map(|o| text_field(o, reflection)),
+                versions[1].clone(),
+                versions[2].clone(),
+            );
+            let value = match resolve(spot, picked, versions, &mut hunks) {
+                Side::Local => text_field(local_outcome, reflection),
+                Side::Remote => text_field(remote_outcome, reflection),
+            };
+            match reflection {
+                false => merged_outcome.goal = value,
+                true => merged_outcome.reflection = value,
+            }
+        }
+
+        let base_actions = base_outcome.map_or(&[][..], |o| &o.actions);
+        let rows = align(
+            base_actions,
+            &local_outcome.actions,
+            &remote_outcome.actions,
+        );
+        let mut actions: Vec<Action> = Vec::new();
+        for [b, l, r] in rows {
+            let local_action = l.map(|i| &local_outcome.actions[i]);
+            let remote_action = r.map(|i| &remote_outcome.actions[i]);
+            let base_action = base_outcome.map(|_| b.map(|i| &base_actions[i]));
+            let picked = pick(
+                base_action.map(|a| a.map(action_key)),
+                local_action.map(action_key),
+                remote_action.map(action_key),
+            );
+            let versions = [
+                base_action.flatten().map(action_text),
+                local_action.map(action_text),
+                remote_action.map(action_text),
+            ];
+            let chosen = match resolve(
+                Spot::Action(outcome_type, actions.len()),
+                picked,
+                versions,
+                &mut hunks,
+            ) {
+                Side::Local => local_action,
+                Side::Remote => remote_action,
+            };
+            actions.extend(chosen.cloned());
+        }
+        merged_outcome.actions = actions;
+    }
+    (merged, hunks)
+}
+
+/// Settle `conflict` with a side per hunk, or one side for a whole file,
+/// writing the result to both sides
+pub fn resolve(
+    conflict: &Conflict,
+    choices: &[Side],
+    target: &Target,
+    config: &Config,
+) -> Result<()> {
+    if choices.len() != conflict.choices_needed() {
+        bail!(
+            "{} needs {} choices, got {}",
+            conflict.file,
+            conflict.choices_needed(),
+            choices.len()
+        );
+    }
+    let file = &conflict.file;
+    let mut state = load_state(config)?;
+    let base = state
+        .files
+        .contains_key(file)
+        .then(|| read(&base_path(file, config)))
+        .flatten();
+    let local = read(&local_path(file, config));
+    let remote = target.read(file)?;
+
+    let metas = day_metas(file, target, config)?;
+
+    // The conflict must still be the one the choices were made for
+    let current = match merge(
+        file,
+        base.as_deref(),
+        local.as_deref(),
+        remote.as_deref(),
+        &metas,
+        &[],
+    ) {
+        Err(hunks) => hunks,
+        Ok(_) => Vec::new(),
+    };
+    if current != conflict.hunks {
+        bail!("{} changed since the sync; sync again", file);
+    }
+
+    let Ok((resolved, meta)) = merge(
+        file,
+        base.as_deref(),
+        local.as_deref(),
+        remote.as_deref(),
+        &metas,
+        choices,
+    ) else {
+        bail!("{} is still in conflict", file);
+    };
+    put_local(file, resolved.as_deref(), config)?;
+    let etag = target.put(file, resolved.as_deref())?;
+    settle(file, resolved.as_deref(), etag, &mut state, config)?;
+    if let Some(meta) = meta {
+        put_day_meta(file, &meta, target, &mut state, config)?;
+    }
+    save_state(&state, config)?;
+    if file == "observations.ndjson" {
+        crate::data::rebuild_observation_index(config)?;
+    }
+    Ok(())
+}
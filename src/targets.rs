@@ -0,0 +1,64 @@
+//! Indicator targets per day, week or month. "20km/week" sets a weekly
+//! total of 20 on a km indicator; the value part goes through
+//! `crate::units`, so "10h/month" works for a minutes indicator too.
+
+use crate::models::{IndicatorUnit, IndicatorsData, TargetPeriod};
+use anyhow::{bail, Context, Result};
+
+/// Split "20km/week", "20 km per week" or a plain "8" into a value in the
+/// indicator's unit and the period it covers
+pub fn parse_target(text: &str, unit: &IndicatorUnit) -> Result<(f64, TargetPeriod)> {
+    let text = text.trim();
+    let lower = text.to_lowercase();
+    let (value, period) = match lower.split_once('/').or_else(|| lower.split_once(" per ")) {
+        Some((value, period)) => (
+            value,
+            TargetPeriod::parse(period).with_context(|| {
+                format!(
+                    "Unknown period '{}' (expected day, week or month)",
+                    period.trim()
+                )
+            })?,
+        ),
+        None => (lower.as_str(), TargetPeriod::Day),
+    };
+    let value = crate::units::parse_value(value, unit)?;
+    if !value.is_finite() || value < 0.0 {
+        bail!("Target must be a positive number, got '{}'", text);
+    }
+    Ok((value, period))
+}
+
+/// Set the target of the indicator found by id or name from `text`, or
+/// clear it with "none"; returns what happened
+pub fn set_target(indicators: &mut IndicatorsData, indicator: &str, text: &str) -> Result<String> {
+    let Some(id) = indicators.find(indicator).map(|def| def.id.clone()) else {
+        bail!("No indicator named '{}'", indicator);
+    };
+    let Some(def) = indicators.indicators.iter_mut().find(|def| def.id == id) else {
+        bail!("No indicator named '{}'", indicator);
+    };
+    def.modified = chrono::Utc::now();
+    if matches!(text.trim().to_lowercase().as_str(), "none" | "-") {
+        def.target = None;
+        def.target_period = TargetPeriod::Day;
+        return Ok(format!("{} has no target", def.name));
+    }
+
+    let (target, period) = parse_target(text, &def.unit)?;
+    def.target = Some(target);
+    def.target_period = period;
+    let amount = match &def.unit {
+        IndicatorUnit::Count => target.to_string(),
+        IndicatorUnit::Minutes => format!("{} minutes", target),
+        IndicatorUnit::Dollars => format!("${}", target),
+        IndicatorUnit::Percent => format!("{}%", target),
+        IndicatorUnit::Custom(label) => format!("{} {}", target, label),
+    };
+    Ok(format!(
+        "{} now targets {} a {}",
+        def.name,
+        amount,
+        period.as_str()
+    ))
+}
@@ -0,0 +1,416 @@
+//! Import tasks from Todoist and TickTick CSV exports, so moving over does
+//! not mean retyping them. Each project (a Todoist export file or a
+//! TickTick list) is mapped to an outcome. Open tasks due by today become
+//! today's actions tagged with their project; daily and weekly recurring
+//! tasks become weekday templates named after it. Only export files are
+//! read: FocusFive does not call the services' APIs.
+
+use crate::journal_import::{heading_key, HeadingMap};
+use crate::models::{Config, OutcomeType, WeekdayTemplate};
+use anyhow::{bail, Context, Result};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+const WEEK: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+/// Most actions a template holds per outcome, as in `ActionTemplates`
+const MAX_TEMPLATE_ACTIONS: usize = 5;
+
+/// How a task repeats
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Repeat {
+    /// On these weekdays
+    Weekly(Vec<Weekday>),
+    /// Some other way, e.g. monthly, which templates cannot hold
+    Other(String),
+}
+
+/// A task read from an export
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedTask {
+    pub project: String,
+    pub text: String,
+    pub due: Option<NaiveDate>,
+    pub repeat: Option<Repeat>,
+    pub done: bool,
+}
+
+/// Split CSV into records of fields; quoted fields may hold commas,
+/// doubled quotes and line breaks
+pub fn csv_records(content: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = content.trim_start_matches('\u{feff}').chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', _) => quoted = !quoted,
+            (',', false) => record.push(std::mem::take(&mut field)),
+            ('\r', false) => {}
+            ('\n', false) => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            (c, _) => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    records.retain(|record| record.iter().any(|field| !field.trim().is_empty()));
+    records
+}
+
+fn weekday(word: &str) -> Option<Weekday> {
+    let word = word.trim_matches(|c: char| !c.is_alphabetic());
+    if word.len() < 2 {
+        return None;
+    }
+    WEEK.into_iter().find(|day| {
+        let name = match day {
+            Weekday::Mon => "monday",
+            Weekday::Tue => "tuesday",
+            Weekday::Wed => "wednesday",
+            Weekday::Thu => "thursday",
+            Weekday::Fri => "friday",
+            Weekday::Sat => "saturday",
+            Weekday::Sun => "sunday",
+        };
+        name.starts_with(word)
+    })
+}
+
+/// A Todoist due string such as "every day", "every weekday" or
+/// "every mon, thu"; None when it does not repeat
+pub fn todoist_repeat(due: &str) -> Option<Repeat> {
+    let due = due.trim().to_lowercase();
+    let rule = due
+        .strip_prefix("every ")
+        .or_else(|| due.strip_prefix("ev "))?
+        .trim();
+    let weekdays = match rule {
+        "day" | "daily" => WEEK.to_vec(),
+        "weekday" | "workday" => WEEK[..5].to_vec(),
+        "weekend" => WEEK[5..].to_vec(),
+        _ => rule
+            .split([',', ' '])
+            .filter(|word| !word.is_empty() && *word != "and")
+            .map(weekday)
+            .collect::<Option<Vec<_>>>()
+            .unwrap_or_default(),
+    };
+    Some(match weekdays.is_empty() {
+        true => Repeat::Other(due),
+        false => Repeat::Weekly(weekdays),
+    })
+}
+
+/// A TickTick (iCalendar) rule such as "FREQ=WEEKLY;BYDAY=MO,TH". Weekly
+/// rules without days repeat on the weekday of `due`.
+pub fn ticktick_repeat(rule: &str, due: Option<NaiveDate>) -> Option<Repeat> {
+    let rule = rule.trim().trim_start_matches("RRULE:");
+    if rule.is_empty() {
+        return None;
+    }
+    let part = |key: &str| {
+        rule.split(';')
+            .find_map(|part| part.strip_prefix(&format!("{}=", key)))
+    };
+    let every_period = part("INTERVAL").is_none_or(|interval| interval == "1");
+    let weekdays = match (part("FREQ"), part("BYDAY")) {
+        (Some("DAILY"), _) if every_period => WEEK.to_vec(),
+        (Some("WEEKLY"), Some(days)) if every_period => {
+            days.split(',').filter_map(weekday).collect()
+        }
+        (Some("WEEKLY"), None) if every_period => {
+            due.map(|d| vec![d.weekday()]).unwrap_or_default()
+        }
+        _ => Vec::new(),
+    };
+    Some(match weekdays.is_empty() {
+        true => Repeat::Other(rule.to_string()),
+        false => Repeat::Weekly(weekdays),
+    })
+}
+
+/// A Todoist due date: an ISO date, "Mar 20" or "20 Mar" this year, today,
+/// tomorrow or yesterday
+fn todoist_due(due: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let due = due.trim().to_lowercase();
+    match due.as_str() {
+        "" => None,
+        "today" | "tod" => Some(today),
+        "tomorrow" | "tom" => Some(today + Duration::days(1)),
+        "yesterday" => Some(today - Duration::days(1)),
+        other => NaiveDate::parse_from_str(other.get(..10).unwrap_or(other), "%Y-%m-%d")
+            .ok()
+            .or_else(|| {
+                let dated = format!("{} {}", other, today.year());
+                ["%b %d %Y", "%d %b %Y"]
+                    .iter()
+                    .find_map(|format| NaiveDate::parse_from_str(&dated, format).ok())
+            }),
+    }
+}
+
+/// Column positions by lowercased header name
+fn columns(header: &[String]) -> impl Fn(&str) -> Option<usize> + '_ {
+    move |name: &str| {
+        header
+            .iter()
+            .position(|column| column.trim().eq_ignore_ascii_case(name))
+    }
+}
+
+/// Tasks of a Todoist project export, whose rows are tasks, sections and
+/// notes. Due strings that are not dates leave the task undated.
+pub fn parse_todoist_csv(
+    content: &str,
+    project: &str,
+    today: NaiveDate,
+) -> Result<Vec<ImportedTask>> {
+    let records = csv_records(content);
+    let Some((header, rows)) = records.split_first() else {
+        bail!("The Todoist export is empty");
+    };
+    let column = columns(header);
+    let (Some(kind), Some(text)) = (column("TYPE"), column("CONTENT")) else {
+        bail!("Not a Todoist export: no TYPE and CONTENT columns");
+    };
+    let date = column("DATE");
+    let field = |row: &Vec<String>, index: Option<usize>| {
+        index
+            .and_then(|i| row.get(i))
+            .map(|field| field.trim().to_string())
+            .unwrap_or_default()
+    };
+
+    let mut tasks = Vec::new();
+    for row in rows {
+        if field(row, Some(kind)) != "task" || field(row, Some(text)).is_empty() {
+            continue;
+        }
+        let due_text = field(row, date);
+        tasks.push(ImportedTask {
+            project: project.to_string(),
+            text: field(row, Some(text)),
+            due: todoist_due(&due_text, today),
+            repeat: todoist_repeat(&due_text),
+            done: false,
+        });
+    }
+    Ok(tasks)
+}
+
+/// Tasks of a TickTick backup, which starts with a few lines about the
+/// export before the header
+pub fn parse_ticktick_csv(content: &str) -> Result<Vec<ImportedTask>> {
+    let records = csv_records(content);
+    let start = records
+        .iter()
+        .position(|record| {
+            let column = columns(record);
+            column("Title").is_some() && column("List Name").is_some()
+        })
+        .context("Not a TickTick backup: no Title and List Name columns")?;
+    let header = &records[start];
+    let column = columns(header);
+    let field = |row: &Vec<String>, name: &str| {
+        column(name)
+            .and_then(|i| row.get(i))
+            .map(|field| field.trim().to_string())
+            .unwrap_or_default()
+    };
+
+    let mut tasks = Vec::new();
+    for row in &records[start + 1..] {
+        let text = field(row, "Title");
+        if text.is_empty() {
+            continue;
+        }
+        let due_text = field(row, "Due Date");
+        let due =
+            NaiveDate::parse_from_str(due_text.get(..10).unwrap_or(&due_text), "%Y-%m-%d").ok();
+        tasks.push(ImportedTask {
+            project: field(row, "List Name"),
+            text,
+            due,
+            repeat: ticktick_repeat(&field(row, "Repeat"), due),
+            // 0 is open; completed and abandoned tasks have other codes
+            done: !matches!(field(row, "Status").as_str(), "" | "0"),
+        });
+    }
+    Ok(tasks)
+}
+
+/// Tasks of a Todoist or TickTick CSV export; Todoist tasks take the
+/// project named by `project`, or else by the file name
+pub fn read_export(
+    path: &Path,
+    project: Option<&str>,
+    today: NaiveDate,
+) -> Result<Vec<ImportedTask>> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read export: {}", path.display()))?;
+    let is_todoist = csv_records(&content).first().is_some_and(|header| {
+        let column = columns(header);
+        column("TYPE").is_some() && column("CONTENT").is_some()
+    });
+    if !is_todoist {
+        return parse_ticktick_csv(&content);
+    }
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    parse_todoist_csv(&content, project.unwrap_or(&stem), today)
+}
+
+/// Projects with how many tasks each has, in order of first appearance
+pub fn distinct_projects(tasks: &[ImportedTask]) -> Vec<(String, usize)> {
+    let mut projects: Vec<(String, usize)> = Vec::new();
+    for task in tasks {
+        match projects.iter_mut().find(|(name, _)| *name == task.project) {
+            Some((_, count)) => *count += 1,
+            None => projects.push((task.project.clone(), 1)),
+        }
+    }
+    projects
+}
+
+/// Outcome of an import run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskImportSummary {
+    /// Actions added to today
+    pub actions: usize,
+    /// Weekday templates written, by name
+    pub templates: Vec<String>,
+    /// Tasks due after today, left for later
+    pub later: usize,
+    pub done: usize,
+    /// Recurring tasks that repeat other than daily or weekly
+    pub unsupported_repeat: usize,
+    /// Tasks of projects mapped to no outcome
+    pub skipped: usize,
+    /// Tasks that did not fit in today's or a template's five actions
+    pub dropped: usize,
+}
+
+fn day_names(weekdays: &[Weekday]) -> String {
+    weekdays
+        .iter()
+        .map(|day| day.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Add `tasks` to the day `today` and to weekday templates, under the
+/// outcomes `mapping` gives their projects. Texts already on the day are
+/// not added twice; templates replace earlier ones of the same name.
+pub fn import_tasks(
+    tasks: &[ImportedTask],
+    mapping: &HeadingMap,
+    today: NaiveDate,
+    config: &Config,
+    dry_run: bool,
+) -> Result<TaskImportSummary> {
+    let mut summary = TaskImportSummary::default();
+    let mut goals = crate::data::load_or_create_goals(today, config)?;
+    let mut placed: Vec<(OutcomeType, usize, String)> = Vec::new();
+    // Recurring tasks by project, then weekdays
+    let mut recurring: BTreeMap<String, BTreeMap<Vec<u32>, WeekdayTemplate>> = BTreeMap::new();
+
+    for task in tasks {
+        let Some(&outcome_type) = mapping.get(&heading_key(&task.project)) else {
+            summary.skipped += 1;
+            continue;
+        };
+        match &task.repeat {
+            _ if task.done => summary.done += 1,
+            Some(Repeat::Other(_)) => summary.unsupported_repeat += 1,
+            Some(Repeat::Weekly(weekdays)) => {
+                let mut weekdays = weekdays.clone();
+                weekdays.sort_by_key(|day| day.num_days_from_monday());
+                weekdays.dedup();
+                let key = weekdays
+                    .iter()
+                    .map(|day| day.num_days_from_monday())
+                    .collect();
+                let template = recurring
+                    .entry(task.project.clone())
+                    .or_default()
+                    .entry(key)
+                    .or_insert_with(|| WeekdayTemplate {
+                        weekdays,
+                        work: Vec::new(),
+                        health: Vec::new(),
+                        family: Vec::new(),
+                    });
+                let actions = template.actions_mut(outcome_type);
+                if actions.len() >= MAX_TEMPLATE_ACTIONS {
+                    summary.dropped += 1;
+                } else if !actions.contains(&task.text) {
+                    actions.push(task.text.clone());
+                }
+            }
+            None if task.due.is_some_and(|due| due > today) => summary.later += 1,
+            None => {
+                let outcome = goals.outcome_mut(outcome_type);
+                if outcome.actions.iter().any(|a| a.text.trim() == task.text) {
+                    continue;
+                }
+                match outcome.place_action(task.text.clone()) {
+                    Ok(index) => {
+                        placed.push((outcome_type, index, task.project.clone()));
+                        summary.actions += 1;
+                    }
+                    Err(_) => summary.dropped += 1,
+                }
+            }
+        }
+    }
+
+    let mut templates = crate::data::load_or_create_templates(config)?;
+    for (project, by_days) in recurring {
+        let named_by_days = by_days.len() > 1;
+        for template in by_days.into_values() {
+            let name = match named_by_days {
+                true => format!("{} ({})", project, day_names(&template.weekdays)),
+                false => project.clone(),
+            };
+            templates.set_weekday_template(name.clone(), template);
+            summary.templates.push(name);
+        }
+    }
+
+    if !dry_run {
+        if !placed.is_empty() {
+            crate::data::write_goals_file(&goals, config)?;
+            let mut meta = crate::data::load_or_create_day_meta(today, &goals, config)?;
+            for (outcome_type, index, project) in placed {
+                if let Some(action) = meta.outcome_mut(outcome_type).get_mut(index) {
+                    if !action.tags.contains(&project) {
+                        action.tags.push(project);
+                    }
+                }
+            }
+            crate::data::save_day_meta(today, &meta, config)?;
+        }
+        if !summary.templates.is_empty() {
+            crate::data::save_templates(&templates, config)?;
+        }
+    }
+    Ok(summary)
+}
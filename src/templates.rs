@@ -0,0 +1,208 @@
+//! Template housekeeping behind the template manager: listing, renaming,
+//! deleting and editing the actions of saved templates, each change written
+//! straight to templates.json, and applying a template to a day.
+
+use crate::models::{ActionOrigin, ActionTemplates, Config, DailyGoals, OutcomeType};
+use anyhow::{bail, Result};
+
+/// Outcomes of a weekday template, in display order
+const OUTCOMES: [OutcomeType; 3] = [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family];
+
+/// A saved template: a plain action list, or a weekday template with a list
+/// per outcome
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateRef {
+    Actions(String),
+    Weekday(String),
+}
+
+impl TemplateRef {
+    pub fn name(&self) -> &str {
+        match self {
+            TemplateRef::Actions(name) | TemplateRef::Weekday(name) => name,
+        }
+    }
+}
+
+/// One action of a template; `outcome` is set for weekday templates
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateAction {
+    pub outcome: Option<OutcomeType>,
+    pub index: usize,
+    pub text: String,
+}
+
+/// Action templates by name, then weekday templates by name
+pub fn list(templates: &ActionTemplates) -> Vec<TemplateRef> {
+    let mut weekday: Vec<_> = templates.weekday_templates.keys().cloned().collect();
+    weekday.sort();
+    templates
+        .get_template_names()
+        .into_iter()
+        .map(TemplateRef::Actions)
+        .chain(weekday.into_iter().map(TemplateRef::Weekday))
+        .collect()
+}
+
+/// The actions of `template` in display order; empty when it is gone
+pub fn actions(templates: &ActionTemplates, template: &TemplateRef) -> Vec<TemplateAction> {
+    match template {
+        TemplateRef::Actions(name) => templates
+            .get_template(name)
+            .into_iter()
+            .flatten()
+            .enumerate()
+            .map(|(index, text)| TemplateAction {
+                outcome: None,
+                index,
+                text: text.clone(),
+            })
+            .collect(),
+        TemplateRef::Weekday(name) => templates
+            .weekday_templates
+            .get(name)
+            .map(|weekday| {
+                OUTCOMES
+                    .iter()
+                    .flat_map(|&outcome_type| {
+                        weekday.actions(outcome_type).iter().enumerate().map(
+                            move |(index, text)| TemplateAction {
+                                outcome: Some(outcome_type),
+                                index,
+                                text: text.clone(),
+                            },
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default(),
+    }
+}
+
+fn exists(templates: &ActionTemplates, template: &TemplateRef) -> bool {
+    match template {
+        TemplateRef::Actions(name) => templates.templates.contains_key(name),
+        TemplateRef::Weekday(name) => templates.weekday_templates.contains_key(name),
+    }
+}
+
+/// Load the templates, change `template` with `change` and save them
+fn update(
+    template: &TemplateRef,
+    config: &Config,
+    change: impl FnOnce(&mut ActionTemplates) -> Result<()>,
+) -> Result<ActionTemplates> {
+    let mut templates = crate::data::load_or_create_templates(config)?;
+    if !exists(&templates, template) {
+        bail!("Template '{}' not found", template.name());
+    }
+    change(&mut templates)?;
+    templates.modified = chrono::Local::now().date_naive();
+    crate::data::save_templates(&templates, config)?;
+    Ok(templates)
+}
+
+pub fn rename(template: &TemplateRef, name: &str, config: &Config) -> Result<ActionTemplates> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        bail!("Template name cannot be empty");
+    }
+    update(template, config, |templates| {
+        let renamed = match template {
+            TemplateRef::Actions(_) => TemplateRef::Actions(name.clone()),
+            TemplateRef::Weekday(_) => TemplateRef::Weekday(name.clone()),
+        };
+        if renamed != *template && exists(templates, &renamed) {
+            bail!("A template named '{}' already exists", name);
+        }
+        match template {
+            TemplateRef::Actions(old) => {
+                if let Some(actions) = templates.templates.remove(old) {
+                    templates.templates.insert(name, actions);
+                }
+            }
+            TemplateRef::Weekday(old) => {
+                if let Some(weekday) = templates.weekday_templates.remove(old) {
+                    templates.weekday_templates.insert(name, weekday);
+                }
+            }
+        }
+        Ok(())
+    })
+}
+
+pub fn delete(template: &TemplateRef, config: &Config) -> Result<ActionTemplates> {
+    update(template, config, |templates| {
+        match template {
+            TemplateRef::Actions(name) => templates.remove_template(name),
+            TemplateRef::Weekday(name) => templates.remove_weekday_template(name),
+        };
+        Ok(())
+    })
+}
+
+/// Replace the action at `index` of `template`, or append one when `index`
+/// is past the end; blank text removes the action. Weekday templates need
+/// the `outcome` whose list is edited.
+pub fn set_action(
+    template: &TemplateRef,
+    outcome: Option<OutcomeType>,
+    index: usize,
+    text: &str,
+    config: &Config,
+) -> Result<ActionTemplates> {
+    let text = text.trim().to_string();
+    update(template, config, |templates| {
+        let list = match template {
+            TemplateRef::Actions(name) => templates.templates.get_mut(name),
+            TemplateRef::Weekday(name) => match outcome {
+                Some(outcome_type) => templates
+                    .weekday_templates
+                    .get_mut(name)
+                    .map(|weekday| weekday.actions_mut(outcome_type)),
+                None => bail!("Choose the outcome of the action"),
+            },
+        };
+        let Some(list) = list else {
+            return Ok(());
+        };
+        match (index < list.len(), text.is_empty()) {
+            (true, true) => {
+                list.remove(index);
+            }
+            (true, false) => list[index] = text,
+            (false, true) => {}
+            (false, false) if list.len() >= 5 => bail!("A template holds at most 5 actions"),
+            (false, false) => list.push(text),
+        }
+        Ok(())
+    })
+}
+
+/// Place the actions of `template` into `goals`: an action list goes into
+/// `outcome_type`, a weekday template into each of its outcomes. Texts
+/// already there, and those that find their outcome full, are skipped.
+/// Returns how many actions were added.
+pub fn apply(
+    templates: &ActionTemplates,
+    template: &TemplateRef,
+    goals: &mut DailyGoals,
+    outcome_type: OutcomeType,
+) -> usize {
+    let mut added = 0;
+    for action in actions(templates, template) {
+        let outcome = goals.outcome_mut(action.outcome.unwrap_or(outcome_type));
+        if outcome
+            .actions
+            .iter()
+            .any(|a| a.text.trim() == action.text.trim())
+        {
+            continue;
+        }
+        if let Ok(index) = outcome.place_action(action.text) {
+            outcome.actions[index].origin = ActionOrigin::Template;
+            added += 1;
+        }
+    }
+    added
+}
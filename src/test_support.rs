@@ -0,0 +1,31 @@
+//! Blocking waits for tests and scripts that drive the app without a
+//! terminal. Only the library has them; the binary polls from `tick`.
+
+use crate::summary::DraftRequest;
+use crate::ui::App;
+use anyhow::{anyhow, Result};
+use std::sync::mpsc::RecvTimeoutError;
+use std::time::{Duration, Instant};
+
+impl App {
+    /// Block until background loads and any reflection draft have landed
+    pub fn wait_for_background(&mut self) {
+        self.wait_for_loads(Duration::from_secs(10));
+        if let Some(request) = self.summary_draft.take() {
+            let result = request.finish();
+            self.finish_summary_draft(request, result);
+        }
+    }
+}
+
+impl DraftRequest {
+    /// Block until the provider answers or the timeout passes
+    pub fn finish(&self) -> Result<String> {
+        let remaining = self.deadline.saturating_duration_since(Instant::now());
+        match self.result.recv_timeout(remaining) {
+            Ok(draft) => draft,
+            Err(RecvTimeoutError::Timeout) => Err(self.timed_out()),
+            Err(RecvTimeoutError::Disconnected) => Err(anyhow!("Summary request stopped")),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+//! Time blocks for a day's actions. A block starts at the action's
+//! `scheduled_at` and lasts its estimate, stored in the day's metadata.
+
+use crate::models::{Config, DailyGoals, OutcomeType, WorkingHours};
+use anyhow::{bail, Result};
+use chrono::{Duration, NaiveTime, Timelike};
+use std::collections::HashSet;
+
+/// Length of a block for an action without an estimate
+pub const DEFAULT_BLOCK_MIN: u32 = 30;
+/// Step used when placing, shifting and resizing blocks
+pub const SLOT_MIN: u32 = 15;
+
+/// A written action of the day, scheduled or not
+#[derive(Debug, Clone, PartialEq)]
+pub struct Block {
+    pub outcome_type: OutcomeType,
+    pub index: usize,
+    pub text: String,
+    pub start: Option<NaiveTime>,
+    pub minutes: u32,
+}
+
+impl Block {
+    pub fn end(&self) -> Option<NaiveTime> {
+        self.start
+            .map(|start| start + Duration::minutes(i64::from(self.minutes)))
+    }
+
+    /// "09:00-10:30", or an empty string when unscheduled
+    pub fn span_label(&self) -> String {
+        match (self.start, self.end()) {
+            (Some(start), Some(end)) => {
+                format!("{}-{}", start.format("%H:%M"), end.format("%H:%M"))
+            }
+            _ => String::new(),
+        }
+    }
+
+    fn overlaps(&self, other: &Block) -> bool {
+        match (self.start, self.end(), other.start, other.end()) {
+            (Some(a), Some(a_end), Some(b), Some(b_end)) => a < b_end && b < a_end,
+            _ => false,
+        }
+    }
+}
+
+/// The day's written actions as blocks: scheduled ones by start time, then
+/// the unscheduled ones in outcome order
+pub fn blocks(goals: &DailyGoals, config: &Config) -> Result<Vec<Block>> {
+    let meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+    let mut blocks = Vec::new();
+    for outcome in goals.outcomes() {
+        let metas = meta.outcome(outcome.outcome_type);
+        for (index, action) in outcome.actions.iter().enumerate() {
+            if action.text.trim().is_empty() {
+                continue;
+            }
+            let action_meta = metas.get(index);
+            blocks.push(Block {
+                outcome_type: outcome.outcome_type,
+                index,
+                text: action.text.clone(),
+                start: action_meta.and_then(|m| m.scheduled_at),
+                minutes: action_meta
+                    .and_then(|m| m.estimated_min)
+                    .unwrap_or(DEFAULT_BLOCK_MIN),
+            });
+        }
+    }
+    // Scheduled blocks first, by start time
+    blocks.sort_by_key(|block| (block.start.is_none(), block.start));
+    Ok(blocks)
+}
+
+/// Blocks that overlap another block
+pub fn conflicts(blocks: &[Block]) -> HashSet<(OutcomeType, usize)> {
+    let mut found = HashSet::new();
+    for (i, a) in blocks.iter().enumerate() {
+        for b in &blocks[i + 1..] {
+            if a.overlaps(b) {
+                found.insert((a.outcome_type, a.index));
+                found.insert((b.outcome_type, b.index));
+            }
+        }
+    }
+    found
+}
+
+/// Earliest slot within working hours where a block of `minutes` fits
+/// without overlapping the scheduled ones; the start of the day if none does
+pub fn first_free_slot(blocks: &[Block], minutes: u32, hours: WorkingHours) -> NaiveTime {
+    let day_start = NaiveTime::from_hms_opt(hours.start.min(23), 0, 0).unwrap_or_default();
+    let day_end_min = hours.end.min(24) * 60;
+    let mut start = day_start;
+    while start.hour() * 60 + start.minute() + minutes <= day_end_min {
+        let candidate = Block {
+            outcome_type: OutcomeType::Work,
+            index: usize::MAX,
+            text: String::new(),
+            start: Some(start),
+            minutes,
+        };
+        if !blocks.iter().any(|block| block.overlaps(&candidate)) {
+            return start;
+        }
+        let next = start + Duration::minutes(i64::from(SLOT_MIN));
+        if next < start {
+            break;
+        }
+        start = next;
+    }
+    day_start
+}
+
+/// Set or clear the start of an action's block and, when given, its length
+pub fn schedule(
+    goals: &DailyGoals,
+    outcome_type: OutcomeType,
+    index: usize,
+    start: Option<NaiveTime>,
+    minutes: Option<u32>,
+    config: &Config,
+) -> Result<()> {
+    let mut meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+    let Some(action_meta) = meta.outcome_mut(outcome_type).get_mut(index) else {
+        bail!("Invalid action index: {}", index);
+    };
+    action_meta.scheduled_at = start;
+    if let Some(minutes) = minutes {
+        action_meta.estimated_min = Some(minutes.max(SLOT_MIN));
+    }
+    crate::data::save_day_meta(goals.date, &meta, config)?;
+    Ok(())
+}
+
+/// `time` moved by `minutes`, kept within the same day
+pub fn shift_time(time: NaiveTime, minutes: i64) -> NaiveTime {
+    let total = i64::from(time.hour() * 60 + time.minute()) + minutes;
+    let clamped = total.clamp(0, 24 * 60 - i64::from(SLOT_MIN)) as u32;
+    NaiveTime::from_hms_opt(clamped / 60, clamped % 60, 0).unwrap_or(time)
+}
@@ -0,0 +1,104 @@
+//! Time actually spent on actions. A timer runs on one action at a time and
+//! its minutes are added to the action's `actual_min` in the day's metadata.
+
+use crate::models::{Config, DailyGoals, OutcomeType};
+use anyhow::{bail, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+
+/// An action whose timer is running
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActionTimer {
+    pub date: NaiveDate,
+    pub outcome_type: OutcomeType,
+    pub index: usize,
+    pub text: String,
+    pub started: DateTime<Utc>,
+}
+
+impl ActionTimer {
+    pub fn start(goals: &DailyGoals, outcome_type: OutcomeType, index: usize) -> Result<Self> {
+        let Some(action) = goals.outcome(outcome_type).actions.get(index) else {
+            bail!("Invalid action index: {}", index);
+        };
+        if action.text.trim().is_empty() {
+            bail!("Nothing to track in an empty action");
+        }
+        Ok(Self {
+            date: goals.date,
+            outcome_type,
+            index,
+            text: action.text.clone(),
+            started: Utc::now(),
+        })
+    }
+
+    pub fn elapsed(&self) -> chrono::Duration {
+        Utc::now() - self.started
+    }
+
+    /// Whole minutes to record, rounded to the nearest minute
+    pub fn minutes(&self) -> u32 {
+        ((self.elapsed().num_seconds().max(0) + 30) / 60) as u32
+    }
+
+    /// "12:34", or "1:02:03" past an hour
+    pub fn display(&self) -> String {
+        let seconds = self.elapsed().num_seconds().max(0);
+        if seconds >= 3600 {
+            format!(
+                "{}:{:02}:{:02}",
+                seconds / 3600,
+                seconds / 60 % 60,
+                seconds % 60
+            )
+        } else {
+            format!("{:02}:{:02}", seconds / 60, seconds % 60)
+        }
+    }
+}
+
+/// Add `minutes` to the tracked time of the action at `index`; returns the
+/// action's new total
+pub fn add_actual_minutes(
+    goals: &DailyGoals,
+    outcome_type: OutcomeType,
+    index: usize,
+    minutes: u32,
+    config: &Config,
+) -> Result<u32> {
+    let mut meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+    let Some(action_meta) = meta.outcome_mut(outcome_type).get_mut(index) else {
+        bail!("Invalid action index: {}", index);
+    };
+    let total = action_meta.actual_min.unwrap_or(0) + minutes;
+    action_meta.actual_min = Some(total);
+    crate::data::save_day_meta(goals.date, &meta, config)?;
+    Ok(total)
+}
+
+/// Estimated and tracked minutes of an outcome's actions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OutcomeTime {
+    pub estimated: u32,
+    pub actual: u32,
+}
+
+/// Estimated and tracked minutes per outcome, in Work, Health, Family order
+pub fn outcome_times(goals: &DailyGoals, config: &Config) -> Result<[OutcomeTime; 3]> {
+    let meta = crate::data::load_or_create_day_meta(goals.date, goals, config)?;
+    let mut times = [OutcomeTime::default(); 3];
+    for (time, outcome) in times.iter_mut().zip(goals.outcomes()) {
+        for (action, action_meta) in outcome
+            .actions
+            .iter()
+            .zip(meta.outcome(outcome.outcome_type))
+        {
+            if action.text.trim().is_empty() {
+                continue;
+            }
+            time.estimated += action_meta.estimated_min.unwrap_or(0);
+            time.actual += action_meta.actual_min.unwrap_or(0);
+        }
+    }
+    Ok(times)
+}
@@ -0,0 +1,299 @@
+//! Soft delete: deleted objectives and indicators move to trash.json with
+//! their links left in place, so restoring one brings it back whole. Items
+//! older than the retention in settings.json are purged for good, which is
+//! when the links pointing at them are removed.
+
+use crate::models::{
+    Config, IndicatorDef, IndicatorsData, Objective, ObjectivesData, Observation, TrashData,
+    Trashed,
+};
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Duration, Utc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrashKind {
+    Objective,
+    Indicator,
+}
+
+impl TrashKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            TrashKind::Objective => "objective",
+            TrashKind::Indicator => "indicator",
+        }
+    }
+}
+
+/// One item of the trash, as listed by the trash browser
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashEntry {
+    pub kind: TrashKind,
+    pub id: String,
+    pub name: String,
+    pub trashed: DateTime<Utc>,
+}
+
+impl TrashEntry {
+    /// When the item is purged under a retention of `days`
+    pub fn expires(&self, days: u32) -> DateTime<Utc> {
+        self.trashed + Duration::days(days.into())
+    }
+}
+
+/// Everything in the trash, most recently deleted first
+pub fn entries(trash: &TrashData) -> Vec<TrashEntry> {
+    let objectives = trash.objectives.iter().map(|t| TrashEntry {
+        kind: TrashKind::Objective,
+        id: t.item.id.clone(),
+        name: t.item.title.clone(),
+        trashed: t.trashed,
+    });
+    let indicators = trash.indicators.iter().map(|t| TrashEntry {
+        kind: TrashKind::Indicator,
+        id: t.item.id.clone(),
+        name: t.item.name.clone(),
+        trashed: t.trashed,
+    });
+    let mut all: Vec<_> = objectives.chain(indicators).collect();
+    all.sort_by_key(|entry| std::cmp::Reverse(entry.trashed));
+    all
+}
+
+/// Move the objective `id` out of `objectives` into the trash. Actions,
+/// indicators and projects keep pointing at it.
+pub fn trash_objective(
+    objectives: &mut ObjectivesData,
+    id: &str,
+    config: &Config,
+) -> Result<Objective> {
+    let Some(index) = objectives.objectives.iter().position(|o| o.id == id) else {
+        bail!("Objective {} not found", id);
+    };
+    let mut trash = crate::data::load_or_create_trash(config)?;
+    let objective = objectives.objectives.remove(index);
+    trash.objectives.push(Trashed {
+        item: objective.clone(),
+        trashed: Utc::now(),
+    });
+    // The trash is written first so a failed save cannot lose the objective
+    crate::data::save_trash(&trash, config)?;
+    if let Err(e) = crate::data::save_objectives(objectives, config) {
+        objectives.objectives.insert(index, objective);
+        return Err(e);
+    }
+    Ok(objective)
+}
+
+/// Move the indicator `id` out of `indicators` into the trash; its
+/// observations are kept
+pub fn trash_indicator(
+    indicators: &mut IndicatorsData,
+    id: &str,
+    config: &Config,
+) -> Result<IndicatorDef> {
+    let Some(index) = indicators.indicators.iter().position(|def| def.id == id) else {
+        bail!("Indicator {} not found", id);
+    };
+    let mut trash = crate::data::load_or_create_trash(config)?;
+    let indicator = indicators.indicators.remove(index);
+    trash.indicators.push(Trashed {
+        item: indicator.clone(),
+        trashed: Utc::now(),
+    });
+    crate::data::save_trash(&trash, config)?;
+    if let Err(e) = crate::data::save_indicators(indicators, config) {
+        indicators.indicators.insert(index, indicator);
+        return Err(e);
+    }
+    Ok(indicator)
+}
+
+enum TrashItem {
+    Objective(Objective),
+    Indicator(IndicatorDef),
+}
+
+/// Take the item out of the trash; None when it is not there
+fn take(trash: &mut TrashData, kind: TrashKind, id: &str) -> Option<TrashItem> {
+    match kind {
+        TrashKind::Objective => {
+            let index = trash.objectives.iter().position(|t| t.item.id == id)?;
+            Some(TrashItem::Objective(trash.objectives.remove(index).item))
+        }
+        TrashKind::Indicator => {
+            let index = trash.indicators.iter().position(|t| t.item.id == id)?;
+            Some(TrashItem::Indicator(trash.indicators.remove(index).item))
+        }
+    }
+}
+
+/// Put a trashed item back where it came from. Returns its name.
+pub fn restore(kind: TrashKind, id: &str, config: &Config) -> Result<String> {
+    let mut trash = crate::data::load_or_create_trash(config)?;
+    let name = match take(&mut trash, kind, id) {
+        None => bail!("The {} is no longer in the trash", kind.label()),
+        Some(TrashItem::Objective(objective)) => {
+            let mut objectives = crate::data::load_or_create_objectives(config)?;
+            if objectives.objectives.iter().any(|o| o.id == objective.id) {
+                bail!("Objective '{}' already exists", objective.title);
+            }
+            let name = objective.title.clone();
+            objectives.objectives.push(objective);
+            crate::data::save_objectives(&objectives, config)?;
+            name
+        }
+        Some(TrashItem::Indicator(indicator)) => {
+            let mut indicators = crate::data::load_or_create_indicators(config)?;
+            if indicators
+                .indicators
+                .iter()
+                .any(|def| def.id == indicator.id)
+            {
+                bail!("Indicator '{}' already exists", indicator.name);
+            }
+            let name = indicator.name.clone();
+            indicators.indicators.push(indicator);
+            crate::data::save_indicators(&indicators, config)?;
+            name
+        }
+    };
+    crate::data::save_trash(&trash, config)?;
+    Ok(name)
+}
+
+/// Delete a trashed item for good, removing every link to it: objectives
+/// are unlinked from the actions of all days, projects, child objectives
+/// and indicators; indicators lose their observations and the objectives
+/// and ratios that name them
+pub fn purge(kind: TrashKind, id: &str, config: &Config) -> Result<()> {
+    let mut trash = crate::data::load_or_create_trash(config)?;
+    match take(&mut trash, kind, id) {
+        None => bail!("The {} is no longer in the trash", kind.label()),
+        Some(TrashItem::Objective(_)) => forget_objective(id, config)?,
+        Some(TrashItem::Indicator(_)) => forget_indicator(id, config)?,
+    }
+    crate::data::save_trash(&trash, config)?;
+    Ok(())
+}
+
+fn forget_objective(id: &str, config: &Config) -> Result<()> {
+    for date in crate::data::goals_file_dates(config)? {
+        // Files that do not parse are left for the doctor
+        let Ok(mut goals) = crate::data::read_goals_for(date, config) else {
+            continue;
+        };
+        let mut changed = false;
+        for outcome in goals.outcomes_mut() {
+            for action in &mut outcome.actions {
+                if action
+                    .get_all_objective_ids()
+                    .iter()
+                    .any(|linked| linked == id)
+                {
+                    action.remove_objective_id(id);
+                    changed = true;
+                }
+            }
+        }
+        if changed {
+            crate::data::write_goals_file(&goals, config)?;
+        }
+    }
+
+    let mut objectives = crate::data::load_or_create_objectives(config)?;
+    let mut children = false;
+    for objective in &mut objectives.objectives {
+        if objective.parent_id.as_deref() == Some(id) {
+            objective.parent_id = None;
+            children = true;
+        }
+    }
+    if children {
+        crate::data::save_objectives(&objectives, config)?;
+    }
+
+    let mut indicators = crate::data::load_or_create_indicators(config)?;
+    let mut owned = false;
+    for def in &mut indicators.indicators {
+        if def.objective_id.as_deref() == Some(id) {
+            def.objective_id = None;
+            owned = true;
+        }
+    }
+    if owned {
+        crate::data::save_indicators(&indicators, config)?;
+    }
+
+    crate::projects::forget_objective(id, config)
+}
+
+fn forget_indicator(id: &str, config: &Config) -> Result<()> {
+    let mut objectives = crate::data::load_or_create_objectives(config)?;
+    let mut linked = false;
+    for objective in &mut objectives.objectives {
+        let before = objective.indicators.len();
+        objective
+            .indicators
+            .retain(|indicator_id| indicator_id != id);
+        linked |= objective.indicators.len() != before;
+    }
+    if linked {
+        crate::data::save_objectives(&objectives, config)?;
+    }
+
+    let mut indicators = crate::data::load_or_create_indicators(config)?;
+    let mut ratios = false;
+    for def in &mut indicators.indicators {
+        let uses = def
+            .ratio_of
+            .as_ref()
+            .is_some_and(|ratio| ratio.numerator_id == id || ratio.denominator_id == id);
+        if uses {
+            def.ratio_of = None;
+            ratios = true;
+        }
+    }
+    if ratios {
+        crate::data::save_indicators(&indicators, config)?;
+    }
+
+    let path = crate::data::observations_path(config);
+    if path.exists() {
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read observations file: {}", path.display()))?;
+        let lines: Vec<String> = content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(str::to_string)
+            .collect();
+        // Lines that do not parse are left for the doctor
+        let kept: Vec<String> = lines
+            .iter()
+            .filter(|line| {
+                serde_json::from_str::<Observation>(line).map_or(true, |obs| obs.indicator_id != id)
+            })
+            .cloned()
+            .collect();
+        if kept.len() != lines.len() {
+            crate::data::replace_observations(&kept, config)?;
+        }
+    }
+    Ok(())
+}
+
+/// Purge what has been in the trash longer than the retention in
+/// settings.json. Returns how many items were purged.
+pub fn purge_expired(config: &Config) -> Result<usize> {
+    let trash = crate::data::load_or_create_trash(config)?;
+    let days = crate::data::load_settings(config)?.trash_retention();
+    let now = Utc::now();
+    let expired: Vec<_> = entries(&trash)
+        .into_iter()
+        .filter(|entry| entry.expires(days) <= now)
+        .collect();
+    for entry in &expired {
+        purge(entry.kind, &entry.id, config)?;
+    }
+    Ok(expired.len())
+}
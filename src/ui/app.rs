@@ -1,35 +1,128 @@
+use crate::backups::{self, Backup};
+use crate::board::{self, Card, StatusCounts};
+use crate::capacity::{self, DayCapacity};
+use crate::carryover::{self, CarryChoice, CarryItem};
+use crate::changelog::{self, Release};
+use crate::checkin::{CheckInAnalytics, CheckInPeriod, CHECK_IN_MAX, CHECK_IN_MIN};
+use crate::closeout;
+use crate::command::{self, ExCommand};
+use crate::completion_times::{CompletionTimes, LATE_EVENING_HOUR};
+use crate::dependencies;
+use crate::events::{Event, EventBus, EventKind};
+use crate::forecast;
+use crate::gratitude;
+use crate::journal::{self, JournalEntry};
+use crate::loader::{self, BackgroundLoader, DashboardData, Loaded};
 use crate::models::{
-    Config, DailyGoals, FiveYearVision, Indicator, IndicatorDirection, IndicatorKind,
-    IndicatorType, IndicatorUnit, IndicatorsData, Objective, ObjectiveStatus, ObjectivesData,
-    Observation, ObservationSource, OutcomeType,
+    iso_week_of, ActionStatus, ActionTemplates, ActivityEvent, ActivityKind, BlockerRef, CapStatus,
+    Config, DailyGoals, FiveYearVision, FocusSession, FocusSessionKind, Indicator,
+    IndicatorAggregation, IndicatorDef, IndicatorDirection, IndicatorKind, IndicatorType,
+    IndicatorUnit, IndicatorsData, Objective, ObjectiveStatus, ObjectivesData, Observation,
+    ObservationSource, OutcomeType, PeriodProgress, Profile, ProjectsData, RitualPhase,
+    SentimentHistory, Settings, TargetPeriod, VisionVersion, WeeklyGoals, FOCUS_INDICATOR_ID,
 };
+use crate::morning::{self, MorningPlan};
+use crate::portfolio::{Health, Portfolio};
+use crate::priority;
+use crate::projects::{self, ProjectRollup};
+use crate::quarterly::{self, QuarterlyCheckIn};
+use crate::recovery::{self, Journal, PendingEdit};
+use crate::retarget::{self, RetargetItem, RetargetSubject};
+use crate::scoreboard::{self, ACHIEVEMENTS};
+use crate::summary::{self, DraftRequest, SummaryInput};
+use crate::sync::{self, Conflict, Side};
+use crate::templates::{self, TemplateRef};
+use crate::timeline;
+use crate::tracking::{self, ActionTimer};
+use crate::trash::{self, TrashEntry};
+
 use crate::ui::{
     dashboard_layout::DashboardLayout,
-    error::ErrorDisplay,
+    fuzzy::{fuzzy_filter, keyword_score},
     help,
+    keymap::{KeyAction, Keymap, Mode as KeyMode, Scope},
     layout::create_layout,
+    macros::{self, Macros, Pending},
     popup::{centered_rect, EditorResult, TextEditor},
-    stats::Statistics,
-    theme::{FinancialTheme, FocusFiveTheme},
+    snapshot,
+    stats::{self, ComparePreset, CompareRanges, PeriodComparison, Statistics},
+    theme::{self, FinancialTheme, FocusFiveTheme, Severity},
+    toast::Toasts,
+    wrap,
 };
 use crate::ui_state::ExpandableActionState;
+use crate::vision_history::{self, DiffPart, VisionChange};
 use crate::widgets::{
     alternative_signals::{AlternativeSignal, AlternativeSignalsWidget},
-    LiveMetricsWidget, PerformanceChart, SentimentWidget,
+    performance_chart::CHART_WINDOWS,
+    CompareChart, CompareScale, CompareSeries, GratitudeWidget, LiveMetricsWidget,
+    PerformanceChart, SentimentWidget, StatusLineWidget, VirtualList, VirtualListState,
 };
-use crossterm::event::KeyCode;
+use crate::year_review::{self, YearReview};
+use chrono::{Datelike, Timelike};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
+    backend::TestBackend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     symbols::border,
     text::{Line, Span},
     widgets::{
-        Block, BorderType, Borders, Clear, Gauge, List, ListItem, ListState, Paragraph, Sparkline,
+        Block, BorderType, Borders, Clear, Gauge, List, ListItem, ListState, Paragraph, Scrollbar,
+        ScrollbarOrientation, ScrollbarState, Sparkline,
     },
-    Frame,
+    Frame, Terminal,
 };
-use std::collections::HashMap;
-use uuid::Uuid;
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+/// How long to wait after the last checkbox toggle before writing the goals file.
+pub const SAVE_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Number of events shown in the stats panel's recent activity list
+pub const RECENT_ACTIVITY_LIMIT: usize = 10;
+/// Days of reflections re-scored for the Sentiment panel at startup
+pub const SENTIMENT_DAYS: i64 = 30;
+
+/// Days covered by the `M` mood/energy analytics view
+pub const CHECK_IN_ANALYTICS_DAYS: i64 = 30;
+/// Days of completion times shown by hour
+pub const COMPLETION_TIMES_DAYS: i64 = 30;
+
+/// Length of a Pomodoro focus block
+pub const POMODORO_MINUTES: i64 = 25;
+
+/// Outcomes in the order the weekly plan lists them
+const WEEKLY_OUTCOMES: [OutcomeType; 3] =
+    [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family];
+
+/// Local hour after which the end-of-day cap callout is shown
+pub const END_OF_DAY_HOUR: u32 = 20;
+
+/// A focus timer that is currently running
+#[derive(Debug, Clone, PartialEq)]
+pub struct FocusTimer {
+    pub kind: FocusSessionKind,
+    pub started: chrono::DateTime<chrono::Utc>,
+}
+
+impl FocusTimer {
+    pub fn elapsed(&self) -> chrono::Duration {
+        chrono::Utc::now() - self.started
+    }
+
+    /// Text shown while running: countdown for Pomodoro, count-up for stopwatch
+    pub fn display(&self) -> String {
+        let seconds = match self.kind {
+            FocusSessionKind::Pomodoro => {
+                (chrono::Duration::minutes(POMODORO_MINUTES) - self.elapsed()).num_seconds()
+            }
+            FocusSessionKind::Stopwatch => self.elapsed().num_seconds(),
+        }
+        .max(0);
+        format!("{:02}:{:02}", seconds / 60, seconds % 60)
+    }
+}
 
 #[derive(PartialEq)]
 pub enum FocusPanel {
@@ -43,6 +136,7 @@ pub enum DashboardPanel {
     Performance,
     Sentiment,
     Signals,
+    Portfolio,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -59,6 +153,58 @@ pub enum EditorContext {
         objective_id: Option<String>,
         link_action: Option<usize>,
     },
+    Reflection {
+        date: chrono::NaiveDate,
+    },
+    ObjectiveBudget {
+        objective_id: String,
+    },
+    ObjectiveDates {
+        objective_id: String,
+    },
+    IndicatorLinkValue {
+        outcome_type: OutcomeType,
+        action_index: usize,
+        indicator_id: String,
+    },
+    QueueItem {
+        objective_id: String,
+    },
+    DayNote,
+    WeeklyFocus {
+        week_iso: (i32, u32),
+        outcome_type: OutcomeType,
+    },
+    ProjectTitle {
+        project_id: Option<String>,
+    },
+    AlignmentNote {
+        outcome_type: OutcomeType,
+    },
+    /// New priority action typed in the morning wizard
+    MorningAction {
+        outcome_type: OutcomeType,
+    },
+    /// Estimate of an outcome's priority in the morning wizard
+    MorningEstimate {
+        outcome_type: OutcomeType,
+    },
+    /// Next gratitude item for `date`, after those already given
+    Gratitude {
+        date: chrono::NaiveDate,
+        items: Vec<String>,
+    },
+    TemplateName {
+        template: TemplateRef,
+    },
+    /// Action of a template in the template manager; an `index` past the
+    /// end adds one
+    TemplateAction {
+        template: TemplateRef,
+        outcome: Option<OutcomeType>,
+        index: usize,
+    },
+    Command,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -66,6 +212,16 @@ pub struct ObjectiveModalState {
     pub outcome_type: OutcomeType,
     pub action_index: usize,
     pub selection: usize,
+    /// List completed objectives moved to the archive too
+    pub show_archived: bool,
+}
+
+/// Celebration shown before an objective is marked complete; Esc returns
+/// to the picker it was opened from
+#[derive(Debug, Clone)]
+pub struct ObjectiveCompleteState {
+    pub picker: ObjectiveModalState,
+    pub objective_id: String,
 }
 
 #[derive(Debug, Clone)]
@@ -74,12 +230,426 @@ pub struct ObjectiveChoice {
     pub id: String,
     pub title: String,
     pub status: ObjectiveStatus,
+    pub color: Option<String>,
+    pub weekly_budget_min: Option<u32>,
+    pub queue_progress: Option<(usize, usize)>,
+    pub archived: bool,
 }
 
 #[derive(Debug, Clone)]
 pub enum ModalState {
     ObjectivePicker(ObjectiveModalState),
+    ObjectiveComplete(ObjectiveCompleteState),
     IndicatorUpdate(IndicatorUpdateState),
+    IndicatorLinks(IndicatorLinkState),
+    QuickObservation(QuickObservationState),
+    Retarget(RetargetState),
+    CheckIn(CheckInState),
+    CheckInAnalytics(CheckInAnalytics),
+    CompletionTimes(CompletionTimes),
+    Trophies,
+    Compare(PeriodComparison),
+    LinkSuggestion(LinkSuggestion),
+    CarryOver(CarryOverState),
+    Snooze(SnoozeState),
+    BlockerPicker(BlockerPickerState),
+    Board(BoardState),
+    Timeline(TimelineState),
+    WeeklyPlan(WeeklyPlanState),
+    YearReview(YearReviewState),
+    Projects(ProjectsState),
+    VisionHistory(VisionHistoryState),
+    QuarterlyCheckIn(QuarterlyState),
+    Journal(JournalState),
+    Morning(MorningState),
+    CloseDay(CloseDayState),
+    Templates(TemplatesState),
+    Backups(BackupsState),
+    Trash(TrashState),
+    Recovery(RecoveryState),
+    SyncMerge(SyncMergeState),
+    /// Toast history, newest first, scrolled by the offset
+    Messages(usize),
+    Help(HelpState),
+    WhatsNew(WhatsNewState),
+}
+
+/// `?`: the bindings of the screen it was opened from, filtered as you type
+#[derive(Debug, Clone, PartialEq)]
+pub struct HelpState {
+    pub mode: KeyMode,
+    pub query: String,
+    pub scroll: usize,
+}
+
+/// Release notes after an upgrade, or every release with `:whatsnew`
+#[derive(Debug, Clone, PartialEq)]
+pub struct WhatsNewState {
+    pub releases: Vec<&'static Release>,
+    pub scroll: usize,
+}
+
+/// Merge dialog over the conflicts a sync left, one file at a time: a side
+/// is chosen for each conflicting part, or for the whole file
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyncMergeState {
+    pub target: sync::Target,
+    pub conflicts: Vec<Conflict>,
+    pub hunk: usize,
+    pub choices: Vec<Option<Side>>,
+}
+
+impl SyncMergeState {
+    fn start(target: sync::Target, conflicts: Vec<Conflict>) -> Self {
+        let choices = vec![None; conflicts.first().map_or(0, Conflict::choices_needed)];
+        Self {
+            target,
+            conflicts,
+            hunk: 0,
+            choices,
+        }
+    }
+
+    /// Move on to the next file; false when none is left
+    fn next_file(&mut self) -> bool {
+        self.conflicts.remove(0);
+        let Some(next) = self.conflicts.first() else {
+            return false;
+        };
+        self.hunk = 0;
+        self.choices = vec![None; next.choices_needed()];
+        true
+    }
+}
+
+/// Offer of the input a crashed session left unsaved
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecoveryState {
+    pub journal: Journal,
+    /// Open the carry-over wizard once the offer is answered
+    pub carry_over: bool,
+}
+
+/// Trash browser over deleted objectives and indicators, newest first
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrashState {
+    pub entries: Vec<TrashEntry>,
+    pub selection: usize,
+    pub confirm_purge: bool,
+}
+
+/// Restore picker over the saved backups, newest first
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackupsState {
+    pub backups: Vec<Backup>,
+    pub selection: usize,
+    pub confirm_restore: bool,
+}
+
+/// Template manager; `action` is the selected action of the previewed
+/// template while editing its actions
+#[derive(Debug, Clone, PartialEq)]
+pub struct TemplatesState {
+    pub templates: ActionTemplates,
+    pub selection: usize,
+    pub action: Option<usize>,
+    pub confirm_delete: bool,
+}
+
+/// Confirmation before closing the day on screen
+#[derive(Debug, Clone, PartialEq)]
+pub struct CloseDayState {
+    pub completed: usize,
+    pub planned: usize,
+    /// Unfinished actions that will roll into the next day
+    pub unfinished: Vec<CarryItem>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MorningStep {
+    Review,
+    /// Index into `morning::OUTCOMES`
+    Priority(usize),
+    Estimates,
+    Confirm,
+}
+
+impl MorningStep {
+    fn next(self) -> Self {
+        match self {
+            MorningStep::Review => MorningStep::Priority(0),
+            MorningStep::Priority(i) if i + 1 < morning::OUTCOMES.len() => {
+                MorningStep::Priority(i + 1)
+            }
+            MorningStep::Priority(_) => MorningStep::Estimates,
+            MorningStep::Estimates | MorningStep::Confirm => MorningStep::Confirm,
+        }
+    }
+
+    fn previous(self) -> Self {
+        match self {
+            MorningStep::Review | MorningStep::Priority(0) => MorningStep::Review,
+            MorningStep::Priority(i) => MorningStep::Priority(i - 1),
+            MorningStep::Estimates => MorningStep::Priority(morning::OUTCOMES.len() - 1),
+            MorningStep::Confirm => MorningStep::Estimates,
+        }
+    }
+}
+
+/// Morning intentions wizard; `selection` is the action on a priority page
+/// and the outcome on the estimates page
+#[derive(Debug, Clone, PartialEq)]
+pub struct MorningState {
+    pub plan: MorningPlan,
+    pub step: MorningStep,
+    pub selection: usize,
+}
+
+/// Reflection browser; `results` indexes `entries` matching `query` and
+/// `list` selects among `results`
+#[derive(Debug, Clone, PartialEq)]
+pub struct JournalState {
+    pub entries: Vec<JournalEntry>,
+    pub query: String,
+    pub searching: bool,
+    pub results: Vec<usize>,
+    pub list: VirtualListState,
+    pub scroll: u16,
+}
+
+impl JournalState {
+    pub fn new(entries: Vec<JournalEntry>) -> Self {
+        let mut state = Self {
+            entries,
+            query: String::new(),
+            searching: false,
+            results: Vec::new(),
+            list: VirtualListState::default(),
+            scroll: 0,
+        };
+        state.refilter();
+        state
+    }
+
+    fn refilter(&mut self) {
+        self.results = journal::search(&self.entries, &self.query);
+        let last = self.results.len().checked_sub(1);
+        self.list
+            .select(last.map(|last| self.list.selected().unwrap_or(0).min(last)));
+        self.scroll = 0;
+    }
+
+    pub fn selected(&self) -> Option<&JournalEntry> {
+        self.results
+            .get(self.list.selected()?)
+            .and_then(|&i| self.entries.get(i))
+    }
+}
+
+/// Quarterly check-in in progress; `step` is the domain page and
+/// `selection` the objective on it
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuarterlyState {
+    pub checkin: QuarterlyCheckIn,
+    pub step: usize,
+    pub selection: usize,
+}
+
+/// Vision history viewer; `selection` counts from the newest change
+#[derive(Debug, Clone)]
+pub struct VisionHistoryState {
+    pub history: Vec<VisionVersion>,
+    pub outcome_type: OutcomeType,
+    pub changes: Vec<VisionChange>,
+    pub selection: usize,
+}
+
+impl VisionHistoryState {
+    fn show_outcome(&mut self, outcome_type: OutcomeType) {
+        self.outcome_type = outcome_type;
+        self.changes = vision_history::changes(&self.history, outcome_type);
+        self.selection = 0;
+    }
+}
+
+/// Projects browser; `assigning` is the selected objective while choosing
+/// the members of the selected project
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectsState {
+    pub projects: ProjectsData,
+    pub selection: usize,
+    pub assigning: Option<usize>,
+    pub confirm_delete: bool,
+}
+
+/// Year review screen; `scroll` is the first line shown
+#[derive(Debug, Clone, PartialEq)]
+pub struct YearReviewState {
+    pub review: YearReview,
+    pub scroll: u16,
+}
+
+/// Weekly planning screen for `weekly`'s week; `selection` indexes outcomes
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeeklyPlanState {
+    pub weekly: WeeklyGoals,
+    pub selection: usize,
+}
+
+/// Day timeline; `selection` indexes `blocks`
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimelineState {
+    pub blocks: Vec<timeline::Block>,
+    pub selection: usize,
+}
+
+/// Kanban board of the day's actions; `column` indexes `board::COLUMNS`
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoardState {
+    pub cards: Vec<Card>,
+    pub column: usize,
+    pub row: usize,
+}
+
+impl BoardState {
+    pub fn column_cards(&self, column: usize) -> Vec<&Card> {
+        self.cards
+            .iter()
+            .filter(|card| board::column_of(card.status) == column)
+            .collect()
+    }
+
+    pub fn selected(&self) -> Option<&Card> {
+        self.column_cards(self.column).get(self.row).copied()
+    }
+
+    fn clamp_row(&mut self) {
+        let len = self.column_cards(self.column).len();
+        self.row = self.row.min(len.saturating_sub(1));
+    }
+}
+
+/// Choosing the action that the selected one waits on
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockerPickerState {
+    pub outcome_type: OutcomeType,
+    pub index: usize,
+    pub candidates: Vec<BlockerRef>,
+    pub selection: usize,
+}
+
+/// Action being snoozed and the day it will move to
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnoozeState {
+    pub outcome_type: OutcomeType,
+    pub index: usize,
+    pub text: String,
+    pub until: chrono::NaiveDate,
+}
+
+/// State for the triage of yesterday's unfinished actions on a new day
+#[derive(Debug, Clone)]
+pub struct CarryOverState {
+    pub items: Vec<CarryItem>,
+    pub index: usize,
+}
+
+impl CarryOverState {
+    /// Snooze the selected item `days` further out, starting from tomorrow
+    fn shift_snooze(&mut self, today: chrono::NaiveDate, days: i64) {
+        let tomorrow = today + chrono::Duration::days(1);
+        if let Some(item) = self.items.get_mut(self.index) {
+            let date = match item.choice {
+                CarryChoice::Snooze(date) => (date + chrono::Duration::days(days)).max(tomorrow),
+                _ => tomorrow,
+            };
+            item.choice = CarryChoice::Snooze(date);
+        }
+    }
+}
+
+/// One-key offer to link a newly written action to a matching objective
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkSuggestion {
+    pub outcome_type: OutcomeType,
+    pub action_index: usize,
+    pub objective_id: String,
+    pub title: String,
+}
+
+/// State for the `m` mood/energy check-in
+#[derive(Debug, Clone)]
+pub struct CheckInState {
+    pub period: CheckInPeriod,
+    pub mood: Option<u8>,
+    pub energy: Option<u8>,
+    pub editing_energy: bool,
+}
+
+impl CheckInState {
+    fn current(&mut self) -> &mut Option<u8> {
+        if self.editing_energy {
+            &mut self.energy
+        } else {
+            &mut self.mood
+        }
+    }
+}
+
+/// State for the `R` new-year re-target wizard
+#[derive(Debug, Clone)]
+pub struct RetargetState {
+    pub year: i32,
+    pub items: Vec<RetargetItem>,
+    pub index: usize,
+    /// Editable proposed target for the current indicator step
+    pub buffer: String,
+    /// Showing the final "apply N changes" confirmation
+    pub confirming: bool,
+}
+
+impl RetargetState {
+    /// Move to `index` (clamped) and load its proposal into the edit buffer
+    fn select(&mut self, index: usize) {
+        self.index = index.min(self.items.len().saturating_sub(1));
+        self.buffer = self
+            .items
+            .get(self.index)
+            .and_then(|item| item.proposed)
+            .map(|value| format!("{}", value))
+            .unwrap_or_default();
+    }
+
+    /// Store an edited target back on the current item
+    fn commit_buffer(&mut self) {
+        let Some(item) = self.items.get_mut(self.index) else {
+            return;
+        };
+        if !matches!(item.subject, RetargetSubject::Indicator { .. }) {
+            return;
+        }
+        if let Ok(value) = self.buffer.trim().parse::<f64>() {
+            if item.proposed != Some(value) {
+                item.proposed = Some(value);
+                item.accepted = true;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuickObservationField {
+    Indicator,
+    Value,
+}
+
+/// State for the `+` quick-add modal: fuzzy indicator picker plus a value field
+#[derive(Debug, Clone)]
+pub struct QuickObservationState {
+    pub query: String,
+    pub selection: usize,
+    pub value: String,
+    pub field: QuickObservationField,
 }
 
 #[derive(Debug, Clone)]
@@ -90,11 +660,38 @@ pub struct IndicatorUpdateState {
     pub indicator_type: IndicatorType,
     pub direction: IndicatorDirection,
     pub target: Option<f64>,
+    pub range: Option<(f64, f64)>,
     pub previous_value: Option<f64>,
     pub latest_value: Option<f64>,
     pub history: Vec<f64>,
     pub last_updated: Option<chrono::NaiveDate>,
     pub buffer: String,
+    /// (1-based position, total) while stepping through a bulk review
+    pub review_position: Option<(usize, usize)>,
+    /// Action whose completion asked for this value
+    pub action_id: Option<String>,
+    pub aggregation: IndicatorAggregation,
+    /// Samples logged today as (time of day, value), earliest first
+    pub today_samples: Vec<(Option<chrono::NaiveTime>, f64)>,
+    /// This week's or month's total, for weekly and monthly targets
+    pub period_progress: Option<PeriodProgress>,
+}
+
+/// Observations read for a Performance chart window, kept until the
+/// window moves or a new observation is logged
+struct PerformanceHistory {
+    start: chrono::NaiveDate,
+    end: chrono::NaiveDate,
+    observations: Vec<Observation>,
+    focus_daily: Vec<Observation>,
+}
+
+/// Indicators an action feeds directly, picked from all active ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndicatorLinkState {
+    pub outcome_type: OutcomeType,
+    pub action_index: usize,
+    pub selection: usize,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -111,132 +708,302 @@ pub struct App {
     pub financial_theme: FinancialTheme,
     pub selected_outcome: OutcomeType,
     pub selected_action: usize,
+    /// First visible line of the actions panel, kept so the selected
+    /// action and its expanded lines stay in view
+    pub actions_scroll: usize,
     pub focus_panel: FocusPanel,
     pub show_dashboard: bool,
+    /// Distraction-free view of each outcome's top unfinished action
+    pub focus_mode: bool,
     pub dashboard_focus: DashboardPanel,
     pub text_editor: TextEditor,
     pub editor_context: Option<EditorContext>,
     pub modal: Option<ModalState>,
     pub statistics: Statistics,
-    pub error_display: ErrorDisplay,
+    pub toasts: Toasts,
+    pub keymap: Keymap,
+    /// Size of the last frame drawn; screenshots are rendered at it
+    pub frame_size: Rect,
+    pub macros: Macros,
     pub ui_state: ExpandableActionState,
     pub objectives: ObjectivesData,
     pub indicators: IndicatorsData,
     pub indicators_map: HashMap<String, Indicator>,
     pub vision: FiveYearVision,
     pub vision_needs_save: bool,
+    /// Time of the last unsaved change; the goals file is written once this is
+    /// older than [`SAVE_DEBOUNCE`] or on quit/day navigation.
+    pub pending_save: Option<Instant>,
+    /// Whether the last write of the goals file failed
+    pub save_failed: bool,
+    /// Indicators still to visit in the current bulk review
+    pub bulk_review_queue: Vec<String>,
+    pub bulk_review_total: usize,
+    /// Newest-first feed shown in the stats panel
+    pub recent_activity: Vec<ActivityEvent>,
+    pub focus_timer: Option<FocusTimer>,
+    pub weekly_goals: WeeklyGoals,
+    /// Actions of the day on screen linked to their weekly focus
+    pub weekly_linked: HashSet<(OutcomeType, usize)>,
+    /// Priorities of the actions of the day on screen
+    pub action_priorities: HashMap<(OutcomeType, usize), u32>,
+    pub action_timer: Option<ActionTimer>,
+    /// Day the end-of-day cap callout was last shown for
+    pub cap_callout_date: Option<chrono::NaiveDate>,
+    /// Day the streak-at-risk event was last emitted for
+    pub nudge_date: Option<chrono::NaiveDate>,
+    pub settings: Settings,
+    /// Evening reflection draft being requested from the summary provider
+    pub summary_draft: Option<DraftRequest>,
+    /// Reflection sentiment scores charted in the Sentiment panel
+    pub sentiment: SentimentHistory,
+    pub gratitude_streak: u32,
+    pub gratitude_recall: Option<gratitude::Recall>,
+    /// XP scored per day and achievements earned
+    pub profile: Profile,
+    /// Names of the indicators behind pace, by objective id
+    pub objective_pace: HashMap<String, Vec<String>>,
+    /// Outbound state-change events for local automations
+    pub events: EventBus,
     pub dashboard_signal_index: usize,
     pub dashboard_signal_ids: Vec<String>,
+    /// The bottom-right quadrant shows Alternative Signals instead of the
+    /// objective portfolio
+    pub dashboard_show_signals: bool,
+    pub dashboard_portfolio_index: usize,
+    pub dashboard_portfolio_ids: Vec<String>,
     pub dashboard_performance_index: usize,
     pub dashboard_performance_ids: Vec<String>,
+    /// Performance charts plot each sample at its time of day
+    pub dashboard_intraday: bool,
+    /// Index into `CHART_WINDOWS` for the Performance panel's span
+    pub dashboard_chart_window: usize,
+    /// Days the Performance panel's window is panned back from today
+    pub dashboard_chart_pan: i64,
+    /// Observations read for a chart window wider than the dashboard load
+    performance_history: Option<PerformanceHistory>,
+    /// First indicator picked with `c` while choosing a pair to compare
+    pub compare_anchor: Option<String>,
+    /// Indicators overlaid in the Performance panel instead of separate charts
+    pub compare_pair: Option<(String, String)>,
+    pub compare_scale: CompareScale,
     pub dashboard_market_index: usize,
     pub dashboard_market_ids: Vec<String>,
+    /// Reads dashboard observations and statistics history off the render path
+    pub loader: BackgroundLoader,
+    /// Latest dashboard load; `None` until the first one arrives
+    pub dashboard_data: Option<DashboardData>,
     // NEW: Day navigation support
     pub current_date: chrono::NaiveDate,
     pub max_date: chrono::NaiveDate,
+    /// Note attached to the displayed day
+    pub day_note: Option<String>,
+    /// Actions snoozed into the displayed day
+    pub snoozed_arrivals: Vec<String>,
+    /// Blocked actions of the displayed day and what they wait on
+    pub blocked: HashMap<(OutcomeType, usize), String>,
+    /// Whether the displayed day is closed and so read-only
+    pub day_closed: bool,
+    /// The edit last written to the recovery journal
+    pub recovery_written: Option<PendingEdit>,
+    /// Ctrl+E was pressed in the editor; the run loop suspends the TUI
+    external_edit_requested: bool,
 }
 
 impl App {
     pub fn new(config: Config) -> anyhow::Result<Self> {
         let today = chrono::Local::now().date_naive();
+        // Expired trash goes before anything is loaded that could link to it
+        let _ = trash::purge_expired(&config);
+        let is_new_day = carryover::is_new_day(today, &config).unwrap_or(false);
         let goals = crate::data::load_or_create_goals(today, &config)?;
-        let theme = FocusFiveTheme::default();
-        let statistics = Statistics::from_current_goals(&goals, &config);
+        let statistics = Statistics::for_today(&goals);
+        let mut loader = BackgroundLoader::start(config.clone());
+        loader.request_history(today);
         let objectives = crate::data::load_or_create_objectives(&config)?;
         let indicators = crate::data::load_or_create_indicators(&config)?;
         let vision = crate::data::load_or_create_vision(&config)?;
+        let recent_activity =
+            crate::data::read_recent_activity(RECENT_ACTIVITY_LIMIT, &config).unwrap_or_default();
+        let settings = crate::data::load_settings(&config)?;
+        let (keymap, keymap_warnings) = Keymap::with_overrides(&settings.keys);
+        let theme = FocusFiveTheme::preset(settings.theme);
+        let events = EventBus::start(&settings.events);
+        let sentiment =
+            crate::sentiment::refresh_history(today, SENTIMENT_DAYS, &config).unwrap_or_default();
+        let gratitude_streak = gratitude::streak(today, &config).unwrap_or(0);
+        let gratitude_recall = gratitude::recall(today, &config).unwrap_or_default();
 
         // Create indicators map for quick lookup
-        let mut indicators_map = HashMap::new();
-        for ind_def in &indicators.indicators {
-            // Determine indicator type based on unit
-            let indicator_type = match &ind_def.unit {
-                crate::models::IndicatorUnit::Minutes => IndicatorType::Duration,
-                crate::models::IndicatorUnit::Percent => IndicatorType::Percentage,
-                crate::models::IndicatorUnit::Count => IndicatorType::Counter,
-                crate::models::IndicatorUnit::Dollars => IndicatorType::Counter,
-                crate::models::IndicatorUnit::Custom(s) if s == "boolean" => IndicatorType::Boolean,
-                crate::models::IndicatorUnit::Custom(s) if s == "hours" => IndicatorType::Duration,
-                crate::models::IndicatorUnit::Custom(s) if s == "percentage" => {
-                    IndicatorType::Percentage
-                }
-                _ => IndicatorType::Counter,
-            };
-
-            // Convert unit to string representation
-            let unit_str = match &ind_def.unit {
-                crate::models::IndicatorUnit::Count => "count",
-                crate::models::IndicatorUnit::Minutes => "minutes",
-                crate::models::IndicatorUnit::Dollars => "dollars",
-                crate::models::IndicatorUnit::Percent => "percentage",
-                crate::models::IndicatorUnit::Custom(s) => s.as_str(),
-            }
-            .to_string();
-
-            let indicator = Indicator {
-                id: ind_def.id.clone(),
-                name: ind_def.name.clone(),
-                indicator_type,
-                current_value: 0.0, // Start at 0, will be updated from observations
-                target_value: ind_def.target.unwrap_or(100.0),
-                unit: unit_str,
-                history: vec![],
-            };
-            indicators_map.insert(ind_def.id.clone(), indicator);
-        }
+        let indicators_map: HashMap<String, Indicator> = indicators
+            .indicators
+            .iter()
+            .map(|def| (def.id.clone(), Self::indicator_from_def(def)))
+            .collect();
 
-        Ok(Self {
+        let mut app = Self {
             goals,
             config: config.clone(),
             statistics,
             theme,
-            financial_theme: FinancialTheme::default(),
+            financial_theme: FinancialTheme::preset(settings.theme),
             selected_outcome: OutcomeType::Work,
             selected_action: 0,
+            actions_scroll: 0,
             focus_panel: FocusPanel::Outcomes,
             show_dashboard: false,
+            focus_mode: false,
             dashboard_focus: DashboardPanel::Market,
             text_editor: TextEditor::new("Edit Action"),
             editor_context: None,
             modal: None,
-            error_display: ErrorDisplay::new(),
+            toasts: Toasts::new(),
+            keymap,
+            frame_size: Rect::new(0, 0, 120, 40),
+            macros: Macros::default(),
             ui_state: ExpandableActionState::new(),
             objectives,
             indicators,
             indicators_map,
             vision,
             vision_needs_save: false,
+            pending_save: None,
+            save_failed: false,
+            bulk_review_queue: Vec::new(),
+            bulk_review_total: 0,
+            recent_activity,
+            focus_timer: None,
+            weekly_goals: crate::data::load_weekly_goals(iso_week_of(today), &config)
+                .unwrap_or_else(|_| WeeklyGoals::new(iso_week_of(today))),
+            weekly_linked: HashSet::new(),
+            action_priorities: HashMap::new(),
+            action_timer: None,
+            cap_callout_date: None,
+            nudge_date: None,
+            settings,
+            summary_draft: None,
+            sentiment,
+            gratitude_streak,
+            gratitude_recall,
+            profile: Profile::default(),
+            objective_pace: HashMap::new(),
+            events,
             dashboard_signal_index: 0,
             dashboard_signal_ids: Vec::new(),
+            dashboard_show_signals: false,
+            dashboard_portfolio_index: 0,
+            dashboard_portfolio_ids: Vec::new(),
             dashboard_performance_index: 0,
             dashboard_performance_ids: Vec::new(),
+            dashboard_intraday: false,
+            dashboard_chart_window: 0,
+            dashboard_chart_pan: 0,
+            performance_history: None,
+            compare_anchor: None,
+            compare_pair: None,
+            compare_scale: CompareScale::default(),
             dashboard_market_index: 0,
             dashboard_market_ids: Vec::new(),
+            loader,
+            dashboard_data: None,
             // NEW: Initialize day navigation fields
             current_date: today,
             max_date: today,
-        })
+            day_note: crate::data::load_day_note(today, &config).unwrap_or_default(),
+            snoozed_arrivals: Vec::new(),
+            blocked: HashMap::new(),
+            day_closed: closeout::is_closed(today, &config).unwrap_or(false),
+            recovery_written: None,
+            external_edit_requested: false,
+        };
+        app.refresh_blocked();
+        app.refresh_meta_stats();
+        // Trophies earned before this run are on the trophies screen, not announced
+        app.profile = scoreboard::refresh_profile(today, &config)
+            .map(|(profile, _)| profile)
+            .unwrap_or_default();
+        app.refresh_objective_pace();
+        app.warn_if_over_capacity();
+        app.snoozed_arrivals = carryover::arrivals(&app.goals, &config).unwrap_or_default();
+        if !app.snoozed_arrivals.is_empty() {
+            app.toasts.show_info(format!(
+                "Snoozed items arriving today: {}",
+                app.snoozed_arrivals.join(", ")
+            ));
+        }
+        for warning in keymap_warnings {
+            app.toasts.show_warning(warning);
+        }
+        if let Ok(Some(journal)) = recovery::load(&config) {
+            app.recovery_written = Some(journal.edit.clone());
+            app.modal = Some(ModalState::Recovery(RecoveryState {
+                journal,
+                carry_over: is_new_day,
+            }));
+        } else if is_new_day {
+            app.open_carry_over_wizard();
+        } else if RitualPhase::from_hour(chrono::Local::now().hour()) == RitualPhase::Morning
+            && !morning::is_planned(&app.goals, &config).unwrap_or(true)
+        {
+            app.toasts
+                .show_info("Good morning! Press I to set today's intentions".to_string());
+        }
+        match app.settings.last_seen_version.clone() {
+            // Installs from before the version was recorded start from this one
+            None => app.mark_changelog_seen(),
+            Some(seen) => {
+                let releases = changelog::unseen(&seen);
+                if !releases.is_empty() && app.modal.is_none() {
+                    app.modal = Some(ModalState::WhatsNew(WhatsNewState {
+                        releases,
+                        scroll: 0,
+                    }));
+                }
+            }
+        }
+        Ok(app)
+    }
+
+    /// Runtime view of an indicator definition used by the widgets
+    fn indicator_from_def(def: &IndicatorDef) -> Indicator {
+        // Determine indicator type based on unit
+        let indicator_type = match &def.unit {
+            crate::models::IndicatorUnit::Minutes => IndicatorType::Duration,
+            crate::models::IndicatorUnit::Percent => IndicatorType::Percentage,
+            crate::models::IndicatorUnit::Count => IndicatorType::Counter,
+            crate::models::IndicatorUnit::Dollars => IndicatorType::Counter,
+            crate::models::IndicatorUnit::Custom(s) if s == "boolean" => IndicatorType::Boolean,
+            crate::models::IndicatorUnit::Custom(s) if s == "hours" => IndicatorType::Duration,
+            crate::models::IndicatorUnit::Custom(s) if s == "percentage" => {
+                IndicatorType::Percentage
+            }
+            _ => IndicatorType::Counter,
+        };
+
+        // Convert unit to string representation
+        let unit_str = match &def.unit {
+            crate::models::IndicatorUnit::Count => "count",
+            crate::models::IndicatorUnit::Minutes => "minutes",
+            crate::models::IndicatorUnit::Dollars => "dollars",
+            crate::models::IndicatorUnit::Percent => "percentage",
+            crate::models::IndicatorUnit::Custom(s) => s.as_str(),
+        }
+        .to_string();
+
+        Indicator {
+            id: def.id.clone(),
+            name: def.name.clone(),
+            indicator_type,
+            current_value: 0.0, // Start at 0, will be updated from observations
+            target_value: def.current_target().unwrap_or(100.0),
+            unit: unit_str,
+            history: vec![],
+        }
     }
 
     // NEW: Day navigation methods
     pub fn navigate_to_previous_day(&mut self) -> anyhow::Result<()> {
-        let previous_date = self.current_date - chrono::Duration::days(1);
-        
-        // Save current changes before navigating
-        self.save_current_goals()?;
-        
-        // Load goals for previous day
-        self.goals = crate::data::load_or_create_goals(previous_date, &self.config)?;
-        self.current_date = previous_date;
-        
-        // Reset selection to avoid out-of-bounds
-        self.selected_outcome = OutcomeType::Work;
-        self.selected_action = 0;
-        
-        // Update statistics for new date
-        self.statistics = Statistics::from_current_goals(&self.goals, &self.config);
-        
-        Ok(())
+        self.navigate_to_date(self.current_date - chrono::Duration::days(1))
     }
 
     pub fn navigate_to_next_day(&mut self) -> anyhow::Result<()> {
@@ -247,516 +1014,1439 @@ impl App {
             return Ok(()); // Silently ignore future navigation attempts
         }
         
+        self.navigate_to_date(next_date)
+    }
+
+    /// Save the current day and show `date`, which may not be in the future
+    pub fn navigate_to_date(&mut self, date: chrono::NaiveDate) -> anyhow::Result<()> {
+        if date > self.max_date {
+            anyhow::bail!("{} is in the future", date);
+        }
+
         // Save current changes before navigating
         self.save_current_goals()?;
         
-        // Load goals for next day
-        self.goals = crate::data::load_or_create_goals(next_date, &self.config)?;
-        self.current_date = next_date;
-        
+        self.goals = crate::data::load_or_create_goals(date, &self.config)?;
+        self.current_date = date;
+        self.day_note = crate::data::load_day_note(date, &self.config).unwrap_or_default();
+        if self.weekly_goals.week_iso() != iso_week_of(date) {
+            self.weekly_goals = crate::data::load_weekly_goals(iso_week_of(date), &self.config)
+                .unwrap_or_else(|_| WeeklyGoals::new(iso_week_of(date)));
+        }
+        self.snoozed_arrivals = carryover::arrivals(&self.goals, &self.config).unwrap_or_default();
+        self.day_closed = closeout::is_closed(date, &self.config).unwrap_or(false);
+        self.refresh_blocked();
+
         // Reset selection to avoid out-of-bounds
         self.selected_outcome = OutcomeType::Work;
         self.selected_action = 0;
         
         // Update statistics for new date
-        self.statistics = Statistics::from_current_goals(&self.goals, &self.config);
+        self.statistics = Statistics::for_today(&self.goals);
+        self.refresh_meta_stats();
+        self.loader.request_history(self.goals.date);
         
         Ok(())
     }
 
-    fn save_current_goals(&self) -> anyhow::Result<()> {
-        crate::data::write_goals_file(&self.goals, &self.config)?;
+    /// Copy the current day's plan to the following day, leaving completion behind
+    pub fn clone_day_to_tomorrow(&mut self) -> anyhow::Result<()> {
+        self.save_current_goals()?;
+        let tomorrow = self.goals.date + chrono::Duration::days(1);
+        match crate::data::clone_day_plan(&self.goals, tomorrow, &self.config) {
+            Ok(_) => self
+                .toasts
+                .show_success(format!("Copied plan to {}", tomorrow.format("%b %d"))),
+            Err(e) => self
+                .toasts
+                .show_warning(format!("Could not copy plan: {}", e)),
+        }
         Ok(())
     }
 
-    pub fn handle_key(&mut self, key: KeyCode) -> anyhow::Result<bool> {
-        // If editor is active, route input to it
-        if self.text_editor.is_active {
-            match self.text_editor.handle_input(key) {
-                EditorResult::Save => {
-                    let new_text = self.text_editor.text.clone();
-                    self.text_editor.deactivate();
-
-                    if let Some(context) = self.editor_context.take() {
-                        match context {
-                            EditorContext::Action {
-                                outcome_type,
-                                index,
-                            } => {
-                                let outcome_snapshot = self.get_outcome_by_type(outcome_type);
-                                if index >= outcome_snapshot.actions.len() {
-                                    self.error_display.show_error(
-                                        "Action index out of range when saving".to_string(),
-                                    );
-                                    return Ok(false);
-                                }
-
-                                let previous_text = outcome_snapshot.actions[index].text.clone();
+    /// Tell automations the day on screen was closed out
+    fn emit_day_closed(&self) {
+        let total: usize = self.goals.outcomes().iter().map(|o| o.actions.len()).sum();
+        let completed: usize = self
+            .goals
+            .outcomes()
+            .iter()
+            .map(|o| o.actions.iter().filter(|a| a.completed).count())
+            .sum();
+        let mut data = serde_json::json!({
+            "date": self.goals.date,
+            "completed": completed,
+            "total": total,
+        });
+        // The rendered summary lets a local bridge forward it to chat or email
+        if self.events.is_enabled() {
+            if let Ok(text) = summary::summary_for_day(
+                &self.goals,
+                &self.indicators,
+                &self.settings.summary,
+                &self.config,
+            ) {
+                data["summary"] = serde_json::Value::String(text);
+            }
+        }
+        self.events.emit(Event::new(EventKind::DayClosed, data));
+    }
 
-                                {
-                                    let outcome = self.get_outcome_by_type_mut(outcome_type);
-                                    outcome.actions[index].text = new_text.clone();
-                                }
+    fn save_current_goals(&mut self) -> anyhow::Result<()> {
+        let written = crate::data::write_goals_file(&self.goals, &self.config);
+        self.save_failed = written.is_err();
+        written?;
+        self.pending_save = None;
+        self.record_completion_times();
+        self.refresh_scoreboard();
+        Ok(())
+    }
 
-                                if let Err(e) =
-                                    crate::data::write_goals_file(&self.goals, &self.config)
-                                {
-                                    self.error_display
-                                        .show_error(format!("Failed to save: {}", e));
-                                    let outcome = self.get_outcome_by_type_mut(outcome_type);
-                                    outcome.actions[index].text = previous_text;
-                                    return Err(e);
-                                }
+    /// Write the goals file ahead of a change to the day's metadata, which
+    /// is aligned with the saved file action by action. A pending save stays
+    /// queued, so completion times and the scoreboard catch up on the
+    /// debounced write as usual.
+    fn save_goals_for_meta(&mut self) -> anyhow::Result<()> {
+        let written = crate::data::write_goals_file(&self.goals, &self.config);
+        self.save_failed = written.is_err();
+        written?;
+        Ok(())
+    }
 
-                                // Refresh statistics when actions change
-                                self.statistics =
-                                    Statistics::from_current_goals(&self.goals, &self.config);
-                            }
-                            EditorContext::Vision { outcome_type } => {
-                                let backup = self.vision.clone();
-                                self.vision.set_vision(&outcome_type, new_text.clone());
+    /// Stamp actions checked off since the last save in the day's metadata
+    fn record_completion_times(&mut self) {
+        if let Err(e) = crate::data::record_completion_times(&self.goals, &self.config) {
+            self.toasts
+                .show_warning(format!("Could not record completion times: {}", e));
+        }
+    }
 
-                                if let Err(e) = crate::data::save_vision(&self.vision, &self.config)
-                                {
-                                    self.error_display
-                                        .show_error(format!("Failed to save vision: {}", e));
-                                    self.vision = backup;
-                                    self.vision_needs_save = true;
-                                    return Err(e);
-                                }
+    /// Score what changed since the last refresh and announce new trophies
+    fn refresh_scoreboard(&mut self) {
+        let today = chrono::Local::now().date_naive();
+        match scoreboard::refresh_profile(today, &self.config) {
+            Ok((profile, earned)) => {
+                self.profile = profile;
+                if !earned.is_empty() {
+                    let names: Vec<&str> = earned.iter().map(|a| a.name).collect();
+                    self.toasts
+                        .show_info(format!("🏆 Trophy earned: {}", names.join(", ")));
+                }
+            }
+            Err(e) => self
+                .toasts
+                .show_warning(format!("Could not update the scoreboard: {}", e)),
+        }
+    }
 
-                                self.vision_needs_save = false;
-                            }
-                            EditorContext::ObjectiveTitle {
-                                outcome_type,
-                                objective_id,
-                                link_action,
-                            } => {
-                                let title = new_text.trim();
-                                if title.is_empty() {
-                                    self.error_display
-                                        .show_error("Objective title cannot be empty".to_string());
-                                    return Ok(false);
-                                }
+    /// Re-check which dated objectives have indicators trending past their
+    /// target date
+    fn refresh_objective_pace(&mut self) {
+        let today = chrono::Local::now().date_naive();
+        let start = today - chrono::Duration::days(loader::PERFORMANCE_DAYS);
+        let observations =
+            crate::data::read_observations_range(start, today, &self.config).unwrap_or_default();
+        self.objective_pace = self
+            .objectives
+            .objectives
+            .iter()
+            .filter_map(|objective| {
+                let behind =
+                    forecast::behind_pace(objective, &self.indicators.indicators, &observations);
+                (!behind.is_empty()).then(|| {
+                    let names = behind.iter().map(|def| def.name.clone()).collect();
+                    (objective.id.clone(), names)
+                })
+            })
+            .collect();
+    }
 
-                                let backup = self.objectives.clone();
-                                let created_id = match objective_id {
-                                    Some(ref existing_id) => {
-                                        if let Some(objective) = self
-                                            .objectives
-                                            .objectives
-                                            .iter_mut()
-                                            .find(|o| &o.id == existing_id)
-                                        {
-                                            objective.title = title.to_string();
-                                            objective.modified = chrono::Utc::now();
-                                            None
-                                        } else {
-                                            self.error_display.show_error(
-                                                "Objective not found for update".to_string(),
-                                            );
-                                            return Ok(false);
-                                        }
-                                    }
-                                    None => {
-                                        let objective =
-                                            Objective::new(outcome_type, title.to_string());
-                                        let new_id = objective.id.clone();
-                                        self.objectives.objectives.push(objective);
-                                        Some(new_id)
-                                    }
-                                };
+    /// Queue a write of the goals file; changes less than [`SAVE_DEBOUNCE`]
+    /// apart coalesce into one write
+    fn mark_dirty(&mut self) {
+        self.pending_save = Some(Instant::now());
+    }
 
-                                if let Err(e) =
-                                    crate::data::save_objectives(&self.objectives, &self.config)
-                                {
-                                    self.error_display
-                                        .show_error(format!("Failed to save objectives: {}", e));
-                                    self.objectives = backup;
-                                    return Err(e);
-                                }
+    /// Update today's statistics now and rescan their history in the background
+    fn refresh_statistics(&mut self) {
+        self.statistics.refresh_today(&self.goals);
+        self.refresh_meta_stats();
+        // A debounced save rescans once today's file is on disk
+        if self.pending_save.is_none() {
+            self.loader.request_history(self.goals.date);
+        }
+    }
 
-                                if let Some(obj_id) = created_id.clone() {
-                                    if let Some(action_idx) = link_action {
-                                        if let Err(e) = self.link_action_to_objective(
-                                            outcome_type,
-                                            action_idx,
-                                            &obj_id,
-                                        ) {
-                                            self.error_display.show_error(format!(
-                                                "Failed to link objective: {}",
-                                                e
-                                            ));
-                                            self.objectives = backup;
-                                            let _ = crate::data::save_objectives(
-                                                &self.objectives,
-                                                &self.config,
-                                            );
-                                            return Err(e);
-                                        }
+    fn apply_loaded(&mut self, loaded: Loaded) {
+        match loaded {
+            Loaded::Dashboard(data) => self.dashboard_data = Some(data),
+            // A scan for a day we've since navigated away from is dropped
+            Loaded::History(history) if history.date == Some(self.goals.date) => {
+                self.statistics.apply_history(*history)
+            }
+            Loaded::History(_) => {}
+        }
+    }
 
-                                        if let Some(index) =
-                                            self.objective_index_in_domain(outcome_type, &obj_id)
-                                        {
-                                            if let Some(ModalState::ObjectivePicker(
-                                                ref mut modal,
-                                            )) = self.modal
-                                            {
-                                                modal.selection = index;
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
+    /// Apply the background loads that finish within `timeout`
+    pub fn wait_for_loads(&mut self, timeout: Duration) {
+        for loaded in self.loader.wait(timeout) {
+            self.apply_loaded(loaded);
+        }
+    }
+
+    /// Reload the dashboard's observations if it is open, keeping the
+    /// current ones on screen until the new ones arrive
+    fn refresh_dashboard_data(&mut self) {
+        if self.show_dashboard {
+            self.loader
+                .request_dashboard(chrono::Local::now().date_naive());
+        }
+    }
+
+    /// Observations for the dashboard from `start` on, or `None` while the
+    /// first load is still running
+    fn dashboard_observations(&mut self, start: chrono::NaiveDate) -> Option<Vec<Observation>> {
+        let today = chrono::Local::now().date_naive();
+        let stale = self
+            .dashboard_data
+            .as_ref()
+            .is_none_or(|data| data.date != today);
+        if stale && !self.loader.dashboard_loading() {
+            self.loader.request_dashboard(today);
+        }
+        self.dashboard_data.as_ref().map(|data| data.since(start))
+    }
+
+    /// Panel body shown until the dashboard's first load arrives
+    fn render_loading_panel(&self, f: &mut Frame, area: Rect, title: &str, focused: bool) {
+        let title_color = if focused {
+            self.financial_theme.accent_yellow
+        } else {
+            self.financial_theme.text_dim
+        };
+        let placeholder = Paragraph::new(format!("{} Loading...", loader::spinner_frame()))
+            .style(
+                Style::default()
+                    .fg(self.financial_theme.text_dim)
+                    .bg(self.financial_theme.bg_panel),
+            )
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .title(title.to_string())
+                    .title_style(
+                        Style::default()
+                            .fg(title_color)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(self.financial_theme.text_dim))
+                    .style(Style::default().bg(self.financial_theme.bg_panel)),
+            );
+        f.render_widget(placeholder, area);
+    }
+
+    /// Write debounced changes once the quiet period has elapsed.
+    /// Called from the event loop on every iteration.
+    pub fn tick(&mut self) {
+        while let Some(loaded) = self.loader.try_next() {
+            self.apply_loaded(loaded);
+        }
+
+        // Hold a finished draft until the editor is free for it
+        if !self.text_editor.is_active {
+            if let Some(result) = self
+                .summary_draft
+                .as_ref()
+                .and_then(DraftRequest::try_finish)
+            {
+                if let Some(request) = self.summary_draft.take() {
+                    self.finish_summary_draft(request, result);
                 }
-                EditorResult::Cancel => {
-                    self.text_editor.deactivate();
-                    self.editor_context = None;
+            }
+        }
+
+        if let Some(changed_at) = self.pending_save {
+            if changed_at.elapsed() >= SAVE_DEBOUNCE {
+                match self.flush_pending_save() {
+                    Ok(()) => self.loader.request_history(self.goals.date),
+                    Err(e) => self.toasts.show_error(format!("Failed to save: {}", e)),
                 }
-                EditorResult::Continue => {}
             }
-            return Ok(false);
         }
 
-        if self.modal.is_some() {
-            if self.handle_modal_key(key)? {
-                return Ok(false);
+        let pomodoro_done = self.focus_timer.as_ref().is_some_and(|timer| {
+            timer.kind == FocusSessionKind::Pomodoro
+                && timer.elapsed() >= chrono::Duration::minutes(POMODORO_MINUTES)
+        });
+        if pomodoro_done {
+            if let Err(e) = self.finish_focus_session() {
+                self.toasts
+                    .show_error(format!("Failed to record focus session: {}", e));
             }
         }
 
-        // Dashboard toggle is available globally when editor is not active
-        if key == KeyCode::Char('d') {
-            self.toggle_dashboard_view();
-            return Ok(false);
+        let now = chrono::Local::now();
+        let today = now.date_naive();
+        if now.hour() >= END_OF_DAY_HOUR && self.cap_callout_date != Some(today) {
+            self.cap_callout_date = Some(today);
+            if let Some(message) = self.end_of_day_cap_callout(today) {
+                self.toasts.show_warning(message);
+            }
         }
 
-        // When dashboard is visible, delegate key handling to dashboard controls
-        if self.show_dashboard {
-            return self.handle_dashboard_key(key);
+        if self.settings.nudge.notify && self.nudge_date != Some(today) {
+            let at_risk = self.outcomes_at_risk(now.naive_local());
+            if !at_risk.is_empty() {
+                self.nudge_date = Some(today);
+                let outcomes: Vec<&str> = at_risk.iter().map(|o| o.as_str()).collect();
+                self.events.emit(Event::new(
+                    EventKind::StreakAtRisk,
+                    serde_json::json!({ "date": today, "outcomes": outcomes }),
+                ));
+            }
         }
+    }
 
-        // Normal key handling when editor is not active
-        match key {
-            KeyCode::Char('q') => return Ok(true), // Exit
-            KeyCode::Tab => self.switch_panel(),
-            KeyCode::Up | KeyCode::Char('k') => self.move_up(),
-            KeyCode::Down | KeyCode::Char('j') => self.move_down(),
-            KeyCode::Char(' ') => self.toggle_current()?,
-            KeyCode::Enter | KeyCode::Char('e') => self.toggle_expansion(),
-            KeyCode::Char('E') => self.open_editor(),
-            KeyCode::Char('v') => self.open_vision_editor(),
-            KeyCode::Char('o') => self.open_objective_picker(),
-            KeyCode::Char('i') => self.open_selected_indicator_update()?,
-            // NEW: Day navigation using Page Up/Down keys
-            KeyCode::PageUp => {
-                if let Err(e) = self.navigate_to_previous_day() {
-                    self.error_display.show_error(format!("Navigation failed: {}", e));
-                }
+    /// Outcomes of today with actions planned but none done once it is past
+    /// the nudge hour
+    pub fn outcomes_at_risk(&self, now: chrono::NaiveDateTime) -> Vec<OutcomeType> {
+        if self.goals.date != now.date() || now.hour() < self.settings.nudge.hour {
+            return Vec::new();
+        }
+        let (work, health, family) = self.statistics.outcome_percentages;
+        [
+            (OutcomeType::Work, work),
+            (OutcomeType::Health, health),
+            (OutcomeType::Family, family),
+        ]
+        .into_iter()
+        .filter(|(outcome_type, percentage)| {
+            *percentage == 0.0
+                && self
+                    .goals
+                    .outcome(*outcome_type)
+                    .actions
+                    .iter()
+                    .any(|action| !action.text.trim().is_empty())
+        })
+        .map(|(outcome_type, _)| outcome_type)
+        .collect()
+    }
+
+    /// Warning text when `value` is at or near a LowerIsBetter indicator's cap
+    fn cap_warning(&self, indicator_id: &str, value: f64) -> Option<String> {
+        let def = self
+            .indicators
+            .indicators
+            .iter()
+            .find(|def| def.id == indicator_id)?;
+        let cap = def.current_target()?;
+        let reading = format!(
+            "{} / {}",
+            self.format_value_with_unit(value, &def.unit),
+            self.format_value_with_unit(cap, &def.unit)
+        );
+        match def.cap_status(value)? {
+            CapStatus::Exceeded => {
+                Some(format!("{} is over its daily cap ({})", def.name, reading))
             }
-            KeyCode::PageDown => {
-                if let Err(e) = self.navigate_to_next_day() {
-                    self.error_display.show_error(format!("Navigation failed: {}", e));
-                }
+            CapStatus::Approaching => Some(format!(
+                "{} is nearing its daily cap ({})",
+                def.name, reading
+            )),
+            CapStatus::Under => None,
+        }
+    }
+
+    /// Summary of capped indicators whose latest value on `date` is at or over the cap
+    pub fn end_of_day_cap_callout(&self, date: chrono::NaiveDate) -> Option<String> {
+        let observations = crate::data::read_observations_range(date, date, &self.config).ok()?;
+
+        let mut over = Vec::new();
+        let mut near = Vec::new();
+        for def in self.indicators.indicators.iter().filter(|def| def.active) {
+            let Some(latest) = observations
+                .iter()
+                .filter(|obs| obs.indicator_id == def.id)
+                .max_by_key(|obs| obs.created)
+            else {
+                continue;
+            };
+            let Some(cap) = def.target_on(date) else {
+                continue;
+            };
+            let entry = format!(
+                "{} {}/{}",
+                def.name,
+                Self::format_value_for_unit(latest.value, &def.unit),
+                self.format_value_with_unit(cap, &def.unit)
+            );
+            match def.cap_status_on(latest.value, date) {
+                Some(CapStatus::Exceeded) => over.push(entry),
+                Some(CapStatus::Approaching) => near.push(entry),
+                _ => {}
             }
-            _ => {}
         }
-        Ok(false)
+
+        let mut parts = Vec::new();
+        if !over.is_empty() {
+            parts.push(format!("Over cap today: {}", over.join(", ")));
+        }
+        if !near.is_empty() {
+            parts.push(format!("Near cap: {}", near.join(", ")));
+        }
+        (!parts.is_empty()).then(|| parts.join(" · "))
     }
 
-    fn toggle_dashboard_view(&mut self) {
-        self.show_dashboard = !self.show_dashboard;
+    /// Start a focus timer of `kind`, or stop and record whichever one is running
+    pub fn toggle_focus_timer(&mut self, kind: FocusSessionKind) -> anyhow::Result<()> {
+        if self.focus_timer.is_some() {
+            return self.finish_focus_session();
+        }
+        self.focus_timer = Some(FocusTimer {
+            kind,
+            started: chrono::Utc::now(),
+        });
+        let label = match kind {
+            FocusSessionKind::Pomodoro => format!("{}-minute Pomodoro", POMODORO_MINUTES),
+            FocusSessionKind::Stopwatch => "focus stopwatch".to_string(),
+        };
+        self.toasts.show_info(format!("Started {}", label));
+        Ok(())
+    }
 
-        if self.show_dashboard {
-            self.dashboard_focus = DashboardPanel::Market;
-            self.dashboard_signal_index = 0;
-            self.dashboard_performance_index = 0;
-            self.dashboard_market_index = 0;
+    /// Start tracking time on the selected action, or stop the running
+    /// tracker; pressing `t` on another action switches to it
+    pub fn toggle_action_timer(&mut self) -> anyhow::Result<()> {
+        let selected = (self.goals.date, self.selected_outcome, self.selected_action);
+        if let Some(timer) = &self.action_timer {
+            let same = (timer.date, timer.outcome_type, timer.index) == selected;
+            self.stop_action_timer()?;
+            if same {
+                return Ok(());
+            }
+        }
+        match ActionTimer::start(&self.goals, self.selected_outcome, self.selected_action) {
+            Ok(timer) => {
+                self.toasts
+                    .show_info(format!("Tracking time on: {}", timer.text));
+                self.action_timer = Some(timer);
+            }
+            Err(e) => self.toasts.show_warning(e.to_string()),
+        }
+        Ok(())
+    }
+
+    /// Stop the action timer and add its minutes to the action's tracked time
+    pub fn stop_action_timer(&mut self) -> anyhow::Result<()> {
+        let Some(timer) = self.action_timer.take() else {
+            return Ok(());
+        };
+        let minutes = timer.minutes();
+        if minutes == 0 {
+            self.toasts
+                .show_info("Tracked under a minute, not recorded".to_string());
+            return Ok(());
+        }
+
+        let goals = if timer.date == self.goals.date {
+            self.save_goals_for_meta()?;
+            self.goals.clone()
         } else {
-            self.focus_panel = FocusPanel::Outcomes;
-            self.dashboard_signal_ids.clear();
-            self.dashboard_performance_ids.clear();
-            self.dashboard_market_ids.clear();
+            crate::data::load_or_create_goals(timer.date, &self.config)?
+        };
+        // The action may have been reordered while the timer ran
+        let index = goals
+            .outcome(timer.outcome_type)
+            .actions
+            .iter()
+            .position(|action| action.text == timer.text)
+            .unwrap_or(timer.index);
+        let total =
+            tracking::add_actual_minutes(&goals, timer.outcome_type, index, minutes, &self.config)?;
+        self.refresh_meta_stats();
+        self.toasts.show_info(format!(
+            "Tracked {} on {} ({} total)",
+            crate::models::format_minutes(minutes),
+            timer.text,
+            crate::models::format_minutes(total)
+        ));
+        Ok(())
+    }
+
+    /// Stop the running timer, log the session and update the focus indicator
+    /// with the day's total. Pomodoros are capped at their planned length.
+    pub fn finish_focus_session(&mut self) -> anyhow::Result<()> {
+        let Some(timer) = self.focus_timer.take() else {
+            return Ok(());
+        };
+
+        let mut ended = chrono::Utc::now();
+        if timer.kind == FocusSessionKind::Pomodoro {
+            ended = ended.min(timer.started + chrono::Duration::minutes(POMODORO_MINUTES));
+        }
+        let session = FocusSession {
+            kind: timer.kind,
+            started: timer.started,
+            ended,
+        };
+        if session.minutes() < 1.0 {
+            self.toasts
+                .show_info("Focus session under a minute, not recorded".to_string());
+            return Ok(());
+        }
+
+        crate::data::append_focus_session(&session, &self.config)?;
+        self.ensure_builtin_indicator(IndicatorDef::focus_minutes());
+
+        let day = session.day();
+        let total: f64 = crate::data::focus_minutes_by_day(day, day, &self.config)?
+            .iter()
+            .map(|(_, minutes)| minutes)
+            .sum();
+
+        let mut observation = Observation::new(
+            FOCUS_INDICATOR_ID.to_string(),
+            day,
+            total,
+            IndicatorUnit::Minutes,
+        );
+        observation.source = ObservationSource::Automated;
+        observation.note = Some(format!("Focus session: {} min", session.minutes()));
+        self.record_observation(observation)?;
+
+        self.refresh_statistics();
+        self.toasts.show_info(format!(
+            "Focused {} min ({} min today)",
+            session.minutes(),
+            total
+        ));
+        Ok(())
+    }
+
+    /// Add a built-in indicator the first time something records to it
+    fn ensure_builtin_indicator(&mut self, def: IndicatorDef) {
+        if !self.indicators.indicators.iter().any(|d| d.id == def.id) {
+            self.indicators_map
+                .insert(def.id.clone(), Self::indicator_from_def(&def));
+            self.indicators.indicators.push(def);
         }
     }
 
-    fn handle_dashboard_key(&mut self, key: KeyCode) -> anyhow::Result<bool> {
-        match key {
-            KeyCode::Char('q') => return Ok(true),
-            KeyCode::Esc => {
-                self.toggle_dashboard_view();
+    /// Re-read the day on screen, the objectives and the indicators after
+    /// their files changed underneath the app
+    fn reload_data_files(&mut self) -> anyhow::Result<()> {
+        self.goals = crate::data::load_or_create_goals(self.goals.date, &self.config)?;
+        self.objectives = crate::data::load_or_create_objectives(&self.config)?;
+        self.indicators = crate::data::load_or_create_indicators(&self.config)?;
+        self.indicators_map = self
+            .indicators
+            .indicators
+            .iter()
+            .map(|def| (def.id.clone(), Self::indicator_from_def(def)))
+            .collect();
+        self.day_closed = closeout::is_closed(self.goals.date, &self.config).unwrap_or(false);
+        self.refresh_blocked();
+        let count = self.goals.outcome(self.selected_outcome).actions.len();
+        self.selected_action = self.selected_action.min(count.saturating_sub(1));
+        self.refresh_statistics();
+        Ok(())
+    }
+
+    /// Check the data files and show the outcome in the message bar; a
+    /// fixing run reloads the data it may have changed
+    fn run_doctor(&mut self, fix: bool) -> anyhow::Result<()> {
+        self.flush_pending_save()?;
+        let report = match crate::doctor::run(&self.config, fix) {
+            Ok(report) => report,
+            Err(e) => {
+                self.toasts.show_error(format!("Data check failed: {}", e));
+                return Ok(());
             }
-            KeyCode::Left | KeyCode::Char('h') => {
-                self.dashboard_focus = match self.dashboard_focus {
-                    DashboardPanel::Market => DashboardPanel::Signals,
-                    DashboardPanel::Performance => DashboardPanel::Market,
-                    DashboardPanel::Sentiment => DashboardPanel::Performance,
-                    DashboardPanel::Signals => DashboardPanel::Sentiment,
-                };
+        };
+        if report.fixed > 0 {
+            self.reload_data_files()?;
+        }
+        // Issues a fixing run repaired are not worth showing
+        let open = report
+            .issues
+            .iter()
+            .find(|issue| !(issue.fixable && report.fixed > 0));
+        match open {
+            None => self.toasts.show_info(report.summary()),
+            Some(issue) => self.toasts.show_warning(format!(
+                "{}. {}; run focusfive doctor for the full list",
+                report.summary(),
+                issue
+            )),
+        }
+        Ok(())
+    }
+
+    /// Sync with the folder in settings.json, then reload what it changed
+    /// and open the merge dialog on any conflicts
+    fn run_sync(&mut self) -> anyhow::Result<()> {
+        self.flush_pending_save()?;
+        let result = sync::target(&self.config)
+            .and_then(|target| Ok((sync::run(&target, &self.config)?, target)));
+        let (report, target) = match result {
+            Ok(done) => done,
+            Err(e) => {
+                self.toasts.show_error(format!("Sync failed: {}", e));
+                return Ok(());
             }
-            KeyCode::Right | KeyCode::Char('l') => {
-                self.dashboard_focus = match self.dashboard_focus {
-                    DashboardPanel::Market => DashboardPanel::Performance,
-                    DashboardPanel::Performance => DashboardPanel::Sentiment,
-                    DashboardPanel::Sentiment => DashboardPanel::Signals,
-                    DashboardPanel::Signals => DashboardPanel::Market,
-                };
+        };
+        self.reload_data_files()?;
+        if report.conflicts.is_empty() {
+            self.toasts.show_info(report.summary());
+        } else {
+            self.toasts.show_warning(report.summary());
+            self.modal = Some(ModalState::SyncMerge(SyncMergeState::start(
+                target,
+                report.conflicts,
+            )));
+        }
+        Ok(())
+    }
+
+    fn open_command_line(&mut self) {
+        self.text_editor.activate_with("Command (:)", "", 200);
+        self.editor_context = Some(EditorContext::Command);
+    }
+
+    /// Run a line typed after `:` against the day on screen
+    pub fn run_ex_command(&mut self, line: &str) -> anyhow::Result<()> {
+        let command = match command::parse(line) {
+            Ok(command) => command,
+            Err(e) => {
+                self.toasts.show_error(e.to_string());
+                return Ok(());
             }
-            KeyCode::Up | KeyCode::Char('k') => {
-                if self.dashboard_focus == DashboardPanel::Market
-                    && !self.dashboard_market_ids.is_empty()
-                {
-                    if self.dashboard_market_index == 0 {
-                        self.dashboard_market_index = self.dashboard_market_ids.len() - 1;
-                    } else {
-                        self.dashboard_market_index -= 1;
+        };
+
+        match command {
+            ExCommand::Goto(date) => {
+                if let Err(e) = self.navigate_to_date(date) {
+                    self.toasts.show_error(format!("Navigation failed: {}", e));
+                }
+            }
+            ExCommand::Add { .. } | ExCommand::Estimate { .. } if self.day_is_locked() => {}
+            ExCommand::Add { outcome, text } => {
+                let backup = self.goals.clone();
+                let index = match self.get_outcome_by_type_mut(outcome).place_action(text) {
+                    Ok(index) => index,
+                    Err(e) => {
+                        self.toasts.show_error(e.to_string());
+                        return Ok(());
                     }
-                } else if self.dashboard_focus == DashboardPanel::Signals
-                    && !self.dashboard_signal_ids.is_empty()
-                {
-                    if self.dashboard_signal_index == 0 {
-                        self.dashboard_signal_index = self.dashboard_signal_ids.len() - 1;
-                    } else {
-                        self.dashboard_signal_index -= 1;
+                };
+                if let Err(e) = self.save_current_goals() {
+                    self.toasts.show_error(format!("Failed to save: {}", e));
+                    self.goals = backup;
+                    return Err(e);
+                }
+                self.selected_outcome = outcome;
+                self.selected_action = index;
+                self.refresh_statistics();
+                self.toasts.show_success(format!(
+                    "Added to {} (action {})",
+                    outcome.as_str(),
+                    index + 1
+                ));
+                self.offer_link_suggestion(outcome, index);
+            }
+            ExCommand::Export(period) => {
+                self.save_current_goals()?;
+                match crate::export::write_export(period, self.goals.date, &self.config) {
+                    Ok(path) => self
+                        .toasts
+                        .show_success(format!("Exported to {}", path.display())),
+                    Err(e) => self.toasts.show_error(format!("Export failed: {}", e)),
+                }
+            }
+            ExCommand::DayTemplate { name, weekdays } => {
+                match command::save_day_template(&self.goals, &name, &weekdays, &self.config) {
+                    Ok(message) => self.toasts.show_info(message),
+                    Err(e) => self.toasts.show_error(e.to_string()),
+                }
+            }
+            ExCommand::Doctor { fix } => self.run_doctor(fix)?,
+            ExCommand::Sync => self.run_sync()?,
+            ExCommand::Sort(outcome) => {
+                self.sort_by_priority(outcome.unwrap_or(self.selected_outcome))?
+            }
+            ExCommand::Compare(ranges) => self.open_compare(&ranges),
+            ExCommand::Rule {
+                indicator,
+                tag,
+                value,
+            } => match crate::automation::set_rule(&mut self.indicators, &indicator, &tag, value) {
+                Ok(message) => {
+                    crate::data::save_indicators(&self.indicators, &self.config)?;
+                    self.toasts.show_info(message);
+                }
+                Err(e) => self.toasts.show_error(e.to_string()),
+            },
+            ExCommand::Group { indicator, group } => {
+                match crate::indicator_groups::set_group(
+                    &mut self.indicators,
+                    &indicator,
+                    group.as_deref(),
+                ) {
+                    Ok(message) => {
+                        crate::data::save_indicators(&self.indicators, &self.config)?;
+                        self.toasts.show_info(message);
                     }
-                } else if self.dashboard_focus == DashboardPanel::Performance
-                    && !self.dashboard_performance_ids.is_empty()
-                {
-                    if self.dashboard_performance_index == 0 {
-                        self.dashboard_performance_index = self.dashboard_performance_ids.len() - 1;
-                    } else {
-                        self.dashboard_performance_index -= 1;
+                    Err(e) => self.toasts.show_error(e.to_string()),
+                }
+            }
+            ExCommand::Aggregate {
+                indicator,
+                aggregation,
+            } => match crate::aggregation::set_aggregation(
+                &mut self.indicators,
+                &indicator,
+                aggregation,
+            ) {
+                Ok(message) => {
+                    crate::data::save_indicators(&self.indicators, &self.config)?;
+                    self.toasts.show_info(message);
+                }
+                Err(e) => self.toasts.show_error(e.to_string()),
+            },
+            ExCommand::Target { indicator, target } => {
+                match crate::targets::set_target(&mut self.indicators, &indicator, &target) {
+                    Ok(message) => {
+                        crate::data::save_indicators(&self.indicators, &self.config)?;
+                        self.toasts.show_info(message);
                     }
-                } else {
-                    self.dashboard_focus = match self.dashboard_focus {
-                        DashboardPanel::Market => DashboardPanel::Market,
-                        DashboardPanel::Performance => DashboardPanel::Performance,
-                        DashboardPanel::Sentiment => DashboardPanel::Market,
-                        DashboardPanel::Signals => DashboardPanel::Performance,
-                    };
+                    Err(e) => self.toasts.show_error(e.to_string()),
                 }
             }
-            KeyCode::Down | KeyCode::Char('j') => {
-                if self.dashboard_focus == DashboardPanel::Market
-                    && !self.dashboard_market_ids.is_empty()
-                {
-                    self.dashboard_market_index =
-                        (self.dashboard_market_index + 1) % self.dashboard_market_ids.len();
-                } else if self.dashboard_focus == DashboardPanel::Signals
-                    && !self.dashboard_signal_ids.is_empty()
-                {
-                    self.dashboard_signal_index =
-                        (self.dashboard_signal_index + 1) % self.dashboard_signal_ids.len();
-                } else if self.dashboard_focus == DashboardPanel::Performance
-                    && !self.dashboard_performance_ids.is_empty()
+            ExCommand::WhatsNew => {
+                self.modal = Some(ModalState::WhatsNew(WhatsNewState {
+                    releases: changelog::RELEASES.iter().collect(),
+                    scroll: 0,
+                }));
+            }
+            ExCommand::Units(words) => match crate::units::save_preferences(&words, &self.config) {
+                Ok(preferences) => {
+                    self.settings.units = preferences;
+                    self.toasts
+                        .show_info(format!("Units: {}", crate::units::describe(&preferences)));
+                }
+                Err(e) => self.toasts.show_error(e.to_string()),
+            },
+            ExCommand::Panel { panel, groups } => {
+                match crate::indicator_groups::save_panel_groups(panel, &groups, &self.config) {
+                    Ok(layout) => {
+                        self.settings.dashboard = layout;
+                        self.toasts
+                            .show_info(crate::indicator_groups::describe_panel(
+                                panel,
+                                &self.settings.dashboard,
+                                &self.indicators,
+                            ));
+                    }
+                    Err(e) => self.toasts.show_error(e.to_string()),
+                }
+            }
+            ExCommand::Capacity(minutes) => {
+                if let Err(e) = capacity::save_capacity(minutes, &self.config) {
+                    self.toasts
+                        .show_error(format!("Failed to save capacity: {}", e));
+                    return Ok(());
+                }
+                self.settings.daily_capacity_min = Some(minutes);
+                self.toasts.show_info(format!(
+                    "Daily capacity set to {}",
+                    crate::models::format_minutes(minutes)
+                ));
+                self.warn_if_over_capacity();
+            }
+            ExCommand::Estimate { target, minutes } => {
+                let (outcome_type, index) =
+                    target.unwrap_or((self.selected_outcome, self.selected_action));
+                self.save_goals_for_meta()?;
+                if let Err(e) =
+                    capacity::set_estimate(&self.goals, outcome_type, index, minutes, &self.config)
                 {
-                    self.dashboard_performance_index =
-                        (self.dashboard_performance_index + 1) % self.dashboard_performance_ids.len();
-                } else {
-                    self.dashboard_focus = match self.dashboard_focus {
-                        DashboardPanel::Market => DashboardPanel::Sentiment,
-                        DashboardPanel::Performance => DashboardPanel::Signals,
-                        DashboardPanel::Sentiment => DashboardPanel::Sentiment,
-                        DashboardPanel::Signals => DashboardPanel::Signals,
-                    };
+                    self.toasts.show_error(e.to_string());
+                    return Ok(());
                 }
+                self.refresh_meta_stats();
+                let text = &self.goals.outcome(outcome_type).actions[index].text;
+                let message = match minutes {
+                    Some(minutes) => format!(
+                        "Estimated {} for: {}",
+                        crate::models::format_minutes(minutes),
+                        text
+                    ),
+                    None => format!("Cleared the estimate for: {}", text),
+                };
+                self.toasts.show_info(message);
+                self.warn_if_over_capacity();
             }
-            KeyCode::Enter | KeyCode::Char('i') => {
-                if self.dashboard_focus == DashboardPanel::Signals {
-                    if let Some(indicator_id) = self
-                        .dashboard_signal_ids
-                        .get(self.dashboard_signal_index)
-                        .cloned()
-                    {
-                        self.open_indicator_update_modal(&indicator_id)?;
-                    }
+            ExCommand::Theme(preset) => {
+                if let Err(e) = command::save_theme(preset, &self.config) {
+                    self.toasts
+                        .show_error(format!("Failed to save theme: {}", e));
+                    return Ok(());
                 }
+                self.settings.theme = preset;
+                self.theme = FocusFiveTheme::preset(preset);
+                self.financial_theme = FinancialTheme::preset(preset);
+                self.toasts
+                    .show_info(format!("Theme set to {}", preset.as_str()));
             }
-            _ => {}
         }
-        Ok(false)
+        Ok(())
     }
 
-    fn handle_modal_key(&mut self, key: KeyCode) -> anyhow::Result<bool> {
-        if let Some(ModalState::ObjectivePicker(mut state)) = self.modal.clone() {
-            let choices = self.objective_choices(state.outcome_type);
-            let total_items = choices.len() + 1; // +1 for "Create New"
+    /// Remember that this version's notes have been shown
+    fn mark_changelog_seen(&mut self) {
+        match changelog::mark_seen(&self.config) {
+            Ok(()) => {
+                self.settings.last_seen_version = Some(changelog::CURRENT_VERSION.to_string())
+            }
+            Err(e) => self
+                .toasts
+                .show_error(format!("Failed to save settings: {}", e)),
+        }
+    }
 
-            match key {
-                KeyCode::Esc => {
-                    self.modal = None;
-                    return Ok(true);
-                }
-                KeyCode::Up | KeyCode::Char('k') => {
-                    if total_items == 0 {
-                        state.selection = 0;
-                    } else if state.selection == 0 {
-                        state.selection = total_items.saturating_sub(1);
-                    } else {
-                        state.selection -= 1;
-                    }
-                }
-                KeyCode::Down | KeyCode::Char('j') => {
-                    if total_items == 0 {
-                        state.selection = 0;
-                    } else {
-                        state.selection = (state.selection + 1) % total_items.max(1);
-                    }
-                }
-                KeyCode::Enter => {
-                    if state.selection == choices.len() {
-                        self.start_objective_creation(state.outcome_type, Some(state.action_index));
-                    } else if let Some(choice) = choices.get(state.selection) {
-                        let objective_id = choice.id.clone();
-                        self.toggle_action_objective(
-                            state.outcome_type,
-                            state.action_index,
-                            &objective_id,
-                        )?;
+    fn open_check_in(&mut self) {
+        let period = CheckInPeriod::for_hour(chrono::Local::now().hour());
+        self.modal = Some(ModalState::CheckIn(CheckInState {
+            period,
+            mood: None,
+            energy: None,
+            editing_energy: false,
+        }));
+    }
 
-                        let updated_len = self.objective_choices(state.outcome_type).len();
-                        if state.selection >= updated_len {
-                            state.selection = updated_len;
-                        }
-                    }
-                }
-                KeyCode::Char('n') => {
-                    self.start_objective_creation(state.outcome_type, Some(state.action_index));
+    /// Record the check-in ratings as Mood and Energy observations for today
+    fn save_check_in(&mut self, state: &CheckInState) -> anyhow::Result<()> {
+        let today = chrono::Local::now().date_naive();
+        let ratings = [
+            (IndicatorDef::mood(), state.mood),
+            (IndicatorDef::energy(), state.energy),
+        ];
+        let mut logged = Vec::new();
+        for (def, rating) in ratings {
+            let Some(rating) = rating else {
+                continue;
+            };
+            let (id, name, unit) = (def.id.clone(), def.name.clone(), def.unit.clone());
+            self.ensure_builtin_indicator(def);
+            let mut observation = Observation::new(id, today, f64::from(rating), unit);
+            observation.note = Some(state.period.label().to_string());
+            self.record_observation(observation)?;
+            logged.push(format!("{} {}/{}", name, rating, CHECK_IN_MAX));
+        }
+        self.toasts
+            .show_info(format!("{}: {}", state.period.label(), logged.join(", ")));
+        Ok(())
+    }
+
+    fn open_check_in_analytics(&mut self) {
+        let today = chrono::Local::now().date_naive();
+        match CheckInAnalytics::calculate(today, CHECK_IN_ANALYTICS_DAYS, &self.config) {
+            Ok(analytics) => self.modal = Some(ModalState::CheckInAnalytics(analytics)),
+            Err(e) => self
+                .toasts
+                .show_error(format!("Failed to load check-ins: {}", e)),
+        }
+    }
+
+    /// Side-by-side statistics of two ranges, presets taken from the day on screen
+    fn open_compare(&mut self, ranges: &CompareRanges) {
+        if let Err(e) = self.flush_pending_save() {
+            self.toasts.show_error(format!("Failed to save: {}", e));
+            return;
+        }
+        let (first, second) = ranges.resolve(self.current_date);
+        let today = chrono::Local::now().date_naive();
+        match stats::compare_ranges(&first, &second, today, &self.config) {
+            Ok(comparison) => self.modal = Some(ModalState::Compare(comparison)),
+            Err(e) => self.toasts.show_error(format!("Failed to compare: {}", e)),
+        }
+    }
+
+    fn open_completion_times(&mut self) {
+        let today = chrono::Local::now().date_naive();
+        // Checked-off actions still waiting for the debounced save count too
+        if let Err(e) = self.flush_pending_save() {
+            self.toasts.show_error(format!("Failed to save: {}", e));
+            return;
+        }
+        match CompletionTimes::calculate(today, COMPLETION_TIMES_DAYS, &self.config) {
+            Ok(times) => self.modal = Some(ModalState::CompletionTimes(times)),
+            Err(e) => self
+                .toasts
+                .show_error(format!("Failed to load completion times: {}", e)),
+        }
+    }
+
+    /// Immediately write any pending goal changes to disk.
+    pub fn flush_pending_save(&mut self) -> anyhow::Result<()> {
+        if self.pending_save.is_some() {
+            // Clear first so a failing disk doesn't trigger a retry every tick
+            self.pending_save = None;
+            self.save_current_goals()?;
+        }
+        Ok(())
+    }
+
+    /// Entry point for terminal key events: handles modifier chords, then
+    /// passes everything else to `handle_key`
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> anyhow::Result<bool> {
+        self.macros.record(key);
+        if let Some(pending) = self.macros.pending.take() {
+            return self.finish_macro_key(pending, key.code);
+        }
+        if self.text_editor.is_active {
+            let result = if key.code == KeyCode::Char('v')
+                && key.modifiers.contains(KeyModifiers::CONTROL)
+            {
+                match crate::clipboard::read() {
+                    Ok(text) => self.text_editor.paste(&text),
+                    Err(e) => self.toasts.show_warning(format!("Nothing pasted: {}", e)),
                 }
-                KeyCode::Char('r') => {
-                    if let Some(choice) = choices.get(state.selection) {
-                        self.start_objective_rename(state.outcome_type, choice.id.clone());
-                    }
+                EditorResult::Continue
+            } else {
+                self.text_editor.handle_key_event(key)
+            };
+            let result = self.apply_editor_result(result);
+            self.sync_recovery_journal();
+            return result;
+        }
+        let plain_view =
+            !self.text_editor.is_active && self.modal.is_none() && !self.show_dashboard;
+        if plain_view && key.modifiers.contains(KeyModifiers::ALT) {
+            match key.code {
+                KeyCode::Up | KeyCode::Char('k') if !self.day_is_locked() => {
+                    return self.move_selected_action(-1).map(|_| false)
                 }
-                KeyCode::Char('d') => {
-                    if let Some(choice) = choices.get(state.selection) {
-                        self.delete_objective(choice.storage_index, &choice.id)?;
-                        let updated_len = self.objective_choices(state.outcome_type).len();
-                        if state.selection >= updated_len {
-                            state.selection = updated_len;
-                        }
-                    }
+                KeyCode::Down | KeyCode::Char('j') if !self.day_is_locked() => {
+                    return self.move_selected_action(1).map(|_| false)
                 }
                 _ => {}
             }
+        }
+        let result = self.handle_key(key.code);
+        self.sync_recovery_journal();
+        result
+    }
 
-            if self.modal.is_some() {
-                self.modal = Some(ModalState::ObjectivePicker(state));
+    /// The editor text to open in $EDITOR, once, after Ctrl+E
+    pub fn take_external_edit(&mut self) -> Option<String> {
+        std::mem::take(&mut self.external_edit_requested)
+            .then(|| self.text_editor.text.clone())
+    }
+
+    /// Save the text that came back from $EDITOR as if Enter had been
+    /// pressed; on failure the in-app editor stays open as it was
+    pub fn finish_external_edit(&mut self, edited: anyhow::Result<String>) -> anyhow::Result<()> {
+        match edited {
+            Ok(text) => {
+                self.text_editor.text = text;
+                self.text_editor.cursor_position = self.text_editor.text.len();
+                self.text_editor.selection_anchor = None;
+                self.apply_editor_result(EditorResult::Save)?;
             }
+            Err(e) => self.toasts.show_error(format!("{:#}", e)),
+        }
+        self.sync_recovery_journal();
+        Ok(())
+    }
 
-            return Ok(true);
+    /// Text pasted into the terminal; only the editor takes it
+    pub fn handle_paste(&mut self, text: &str) {
+        if self.text_editor.is_active {
+            self.text_editor.paste(text);
+            self.sync_recovery_journal();
         }
+    }
 
-        if let Some(ModalState::IndicatorUpdate(mut state)) = self.modal.clone() {
-            match key {
-                KeyCode::Esc => {
-                    self.modal = None;
-                    return Ok(true);
+    /// The unsaved input of an action edit or an observation, if any
+    fn pending_edit(&self) -> Option<PendingEdit> {
+        if self.text_editor.is_active {
+            let Some(EditorContext::Action {
+                outcome_type,
+                index,
+            }) = self.editor_context
+            else {
+                return None;
+            };
+            let saved = self.goals.outcome(outcome_type).actions.get(index);
+            if saved.is_some_and(|action| action.text == self.text_editor.text) {
+                return None;
+            }
+            return Some(PendingEdit::Action {
+                date: self.goals.date,
+                outcome: outcome_type,
+                index,
+                text: self.text_editor.text.clone(),
+            });
+        }
+        match &self.modal {
+            // Kept until the offer is answered
+            Some(ModalState::Recovery(state)) => Some(state.journal.edit.clone()),
+            Some(ModalState::IndicatorUpdate(state)) => {
+                let prefill = state.latest_value.or(state.previous_value).unwrap_or(0.0);
+                let typed = !state.buffer.trim().is_empty()
+                    && state.buffer != Self::format_value_for_unit(prefill, &state.unit);
+                typed.then(|| PendingEdit::Observation {
+                    indicator_id: state.indicator_id.clone(),
+                    name: state.name.clone(),
+                    value: state.buffer.clone(),
+                })
+            }
+            Some(ModalState::QuickObservation(state)) if !state.value.is_empty() => {
+                let index = *self
+                    .quick_observation_matches(&state.query)
+                    .get(state.selection)?;
+                let def = &self.indicators.indicators[index];
+                Some(PendingEdit::Observation {
+                    indicator_id: def.id.clone(),
+                    name: def.name.clone(),
+                    value: state.value.clone(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Bring recovery.json in line with the unsaved input after a key
+    fn sync_recovery_journal(&mut self) {
+        let pending = self.pending_edit();
+        if pending == self.recovery_written {
+            return;
+        }
+        let written = match &pending {
+            Some(edit) => recovery::record(edit, &self.config),
+            None => recovery::clear(&self.config),
+        };
+        match written {
+            Ok(()) => self.recovery_written = pending,
+            Err(e) => self
+                .toasts
+                .show_warning(format!("Recovery journal not updated: {}", e)),
+        }
+    }
+
+    /// Take back the input of a crashed session: an action edit reopens in
+    /// the editor, an observation is recorded
+    fn recover_edit(&mut self, edit: PendingEdit) -> anyhow::Result<()> {
+        match edit {
+            PendingEdit::Action {
+                date,
+                outcome,
+                index,
+                text,
+            } => {
+                if date != self.goals.date {
+                    self.navigate_to_date(date)?;
                 }
-                KeyCode::Enter => {
-                    self.apply_indicator_update(&state)?;
-                    self.modal = None;
-                    return Ok(true);
+                if index >= self.goals.outcome(outcome).actions.len() || self.day_is_locked() {
+                    self.toasts
+                        .show_error(format!("Cannot reopen the edit: \"{}\"", text));
+                    return Ok(());
                 }
-                KeyCode::Backspace => {
-                    state.buffer.pop();
+                self.selected_outcome = outcome;
+                self.selected_action = index;
+                self.focus_panel = FocusPanel::Actions;
+                self.text_editor.activate_with(
+                    "Edit Action",
+                    &text,
+                    crate::models::MAX_ACTION_LENGTH,
+                );
+                self.editor_context = Some(EditorContext::Action {
+                    outcome_type: outcome,
+                    index,
+                });
+            }
+            PendingEdit::Observation {
+                indicator_id,
+                name,
+                value,
+            } => {
+                let Some(unit) = self
+                    .indicators
+                    .indicators
+                    .iter()
+                    .find(|def| def.id == indicator_id)
+                    .map(|def| def.unit.clone())
+                else {
+                    self.toasts
+                        .show_error(format!("Indicator '{}' no longer exists", name));
+                    return Ok(());
+                };
+                match Self::parse_value_from_buffer(&value, &unit) {
+                    Ok(parsed) => {
+                        self.record_indicator_observation(&indicator_id, &unit, parsed, None)?;
+                        self.toasts
+                            .show_info(format!("Recovered {} for {}", value, name));
+                    }
+                    Err(e) => self
+                        .toasts
+                        .show_error(format!("Invalid recovered value: {}", e)),
                 }
-                KeyCode::Char(ch) => {
-                    match ch {
-                        '+' | '=' => {
-                            let delta = Self::indicator_fine_delta(&state);
-                            if delta > 0.0 {
-                                state.buffer =
-                                    Self::adjust_buffer_value(&state.buffer, &state.unit, delta);
+            }
+        }
+        Ok(())
+    }
+
+    /// Save or discard the editor's text once it reports Enter or Esc
+    fn apply_editor_result(&mut self, result: EditorResult) -> anyhow::Result<bool> {
+        match result {
+            EditorResult::Save => {
+                let new_text = self.text_editor.text.clone();
+                self.text_editor.deactivate();
+
+                if let Some(context) = self.editor_context.take() {
+                    match context {
+                        EditorContext::Action {
+                            outcome_type,
+                            index,
+                        } => {
+                            let outcome_snapshot = self.get_outcome_by_type(outcome_type);
+                            if index >= outcome_snapshot.actions.len() {
+                                self.toasts.show_error(
+                                    "Action index out of range when saving".to_string(),
+                                );
+                                return Ok(false);
+                            }
+
+                            let previous_text = outcome_snapshot.actions[index].text.clone();
+
+                            {
+                                let outcome = self.get_outcome_by_type_mut(outcome_type);
+                                outcome.actions[index].text = new_text.clone();
+                            }
+
+                            if let Err(e) = self.save_current_goals() {
+                                self.toasts.show_error(format!("Failed to save: {}", e));
+                                let outcome = self.get_outcome_by_type_mut(outcome_type);
+                                outcome.actions[index].text = previous_text;
+                                return Err(e);
+                            }
+
+                            // Refresh statistics when actions change
+                            self.refresh_statistics();
+
+                            if previous_text.trim().is_empty() {
+                                self.offer_link_suggestion(outcome_type, index);
                             }
                         }
-                        '-' | '_' => {
-                            let delta = Self::indicator_fine_delta(&state);
-                            if delta > 0.0 {
-                                state.buffer =
-                                    Self::adjust_buffer_value(&state.buffer, &state.unit, -delta);
+                        EditorContext::Vision { outcome_type } => {
+                            let backup = self.vision.clone();
+                            self.vision
+                                .set_vision_past_limit(&outcome_type, new_text.clone());
+
+                            if let Err(e) = crate::data::save_vision(&self.vision, &self.config) {
+                                self.toasts
+                                    .show_error(format!("Failed to save vision: {}", e));
+                                self.vision = backup;
+                                self.vision_needs_save = true;
+                                return Err(e);
                             }
+
+                            self.vision_needs_save = false;
                         }
-                        _ => {
-                            let lower = ch.to_ascii_lowercase();
-                            match lower {
-                                'c' => state.buffer.clear(),
-                                'a' => match state.indicator_type {
-                                    IndicatorType::Percentage => {
-                                        state.buffer =
-                                            Self::format_value_for_unit(25.0, &state.unit);
-                                    }
-                                    IndicatorType::Boolean => {}
-                                    _ => {
-                                        let delta = Self::indicator_small_delta(&state);
-                                        if delta > 0.0 {
-                                            state.buffer = Self::adjust_buffer_value(
-                                                &state.buffer,
-                                                &state.unit,
-                                                delta,
-                                            );
-                                        }
-                                    }
-                                },
-                                's' => match state.indicator_type {
-                                    IndicatorType::Percentage => {
-                                        state.buffer =
-                                            Self::format_value_for_unit(50.0, &state.unit);
-                                    }
-                                    IndicatorType::Boolean => {}
-                                    _ => {
-                                        let delta = Self::indicator_medium_delta(&state);
-                                        if delta > 0.0 {
-                                            state.buffer = Self::adjust_buffer_value(
-                                                &state.buffer,
-                                                &state.unit,
-                                                delta,
-                                            );
-                                        }
-                                    }
-                                },
-                                'd' => match state.indicator_type {
-                                    IndicatorType::Percentage => {
-                                        state.buffer =
-                                            Self::format_value_for_unit(75.0, &state.unit);
-                                    }
-                                    IndicatorType::Boolean => {}
-                                    _ => {
-                                        let delta = Self::indicator_large_delta(&state);
-                                        if delta > 0.0 {
-                                            state.buffer = Self::adjust_buffer_value(
-                                                &state.buffer,
-                                                &state.unit,
-                                                delta,
-                                            );
-                                        }
-                                    }
-                                },
-                                'f' => {
-                                    if state.indicator_type == IndicatorType::Percentage {
-                                        state.buffer =
-                                            Self::format_value_for_unit(100.0, &state.unit);
+                        EditorContext::Reflection { date } => {
+                            // The gratitude list is kept unless one was typed in
+                            let (body, mut items) = gratitude::split(&new_text);
+                            if items.is_empty() {
+                                items = gratitude::load(date, &self.config).unwrap_or_default();
+                            }
+                            if let Err(e) = crate::data::save_reflection(
+                                date,
+                                &gratitude::join(&body, &items),
+                                &self.config,
+                            ) {
+                                self.toasts
+                                    .show_error(format!("Failed to save reflection: {}", e));
+                                return Err(e);
+                            }
+                            if let Some(ModalState::Journal(ref mut state)) = self.modal {
+                                if let Ok(entries) = journal::load_entries(&self.config) {
+                                    state.entries = entries;
+                                    state.refilter();
+                                }
+                            }
+                            match crate::sentiment::refresh_history(date, 1, &self.config) {
+                                Ok(history) => {
+                                    self.sentiment = history;
+                                    self.toasts
+                                        .show_success(format!("Reflection saved for {}", date));
+                                }
+                                Err(e) => self.toasts.show_warning(format!(
+                                    "Reflection saved for {}, but scoring failed: {}",
+                                    date, e
+                                )),
+                            }
+                            // The evening flow goes on to gratitude; the journal does not
+                            if self.modal.is_none() {
+                                self.prompt_gratitude(date, Vec::new());
+                            }
+                        }
+                        EditorContext::Gratitude { date, mut items } => {
+                            let item = new_text.trim();
+                            if !item.is_empty() {
+                                items.push(item.to_string());
+                                if let Err(e) = gratitude::save(date, &items, &self.config) {
+                                    self.toasts
+                                        .show_error(format!("Failed to save gratitude: {}", e));
+                                    return Err(e);
+                                }
+                                self.refresh_gratitude();
+                            }
+                            if item.is_empty() || items.len() >= gratitude::GRATITUDE_ITEMS {
+                                if !items.is_empty() {
+                                    self.toasts.show_success(format!(
+                                        "Gratitude saved · {} day streak",
+                                        self.gratitude_streak
+                                    ));
+                                }
+                            } else {
+                                self.prompt_gratitude(date, items);
+                            }
+                        }
+                        EditorContext::ObjectiveBudget { objective_id } => {
+                            let input = new_text.trim();
+                            let budget = if input.is_empty() {
+                                None
+                            } else if let Some(minutes) = crate::models::parse_minutes(input) {
+                                Some(minutes)
+                            } else {
+                                self.toasts.show_error(format!(
+                                    "Invalid weekly budget '{}': use minutes or e.g. 4h30m",
+                                    input
+                                ));
+                                return Ok(false);
+                            };
+                            self.set_objective_budget(&objective_id, budget)?;
+                        }
+                        EditorContext::IndicatorLinkValue {
+                            outcome_type,
+                            action_index,
+                            indicator_id,
+                        } => {
+                            let input = new_text.trim();
+                            let value = if input.is_empty() {
+                                None
+                            } else if let Ok(value) = input.parse::<f64>() {
+                                Some(value)
+                            } else {
+                                self.toasts.show_error(format!(
+                                    "Invalid value '{}': enter a number, or nothing to be asked",
+                                    input
+                                ));
+                                return Ok(false);
+                            };
+                            self.set_indicator_link(
+                                outcome_type,
+                                action_index,
+                                &indicator_id,
+                                Some(value),
+                            );
+                        }
+                        EditorContext::ObjectiveDates { objective_id } => {
+                            let input = new_text.trim();
+                            let parse = |s: &str| {
+                                chrono::NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d").ok()
+                            };
+                            let dates = match input.split_once("..") {
+                                _ if input.is_empty() => Some((None, None)),
+                                Some((start, end)) => parse(start)
+                                    .zip(parse(end))
+                                    .map(|(start, end)| (Some(start), Some(end))),
+                                None => parse(input).map(|end| (None, Some(end))),
+                            };
+                            let Some((start, end)) = dates else {
+                                self.toasts.show_error(format!(
+                                    "Invalid dates '{}': use YYYY-MM-DD or START..TARGET",
+                                    input
+                                ));
+                                return Ok(false);
+                            };
+                            self.set_objective_dates(&objective_id, start, end)?;
+                        }
+                        EditorContext::QueueItem { objective_id } => {
+                            let title = new_text.trim();
+                            if !title.is_empty() {
+                                self.add_queue_item(&objective_id, title.to_string())?;
+                            }
+                        }
+                        EditorContext::DayNote => {
+                            match crate::data::save_day_note(&self.goals, &new_text, &self.config) {
+                                Ok(note) => self.day_note = note,
+                                Err(e) => {
+                                    self.toasts
+                                        .show_error(format!("Failed to save day note: {}", e));
+                                    return Err(e);
+                                }
+                            }
+                        }
+                        EditorContext::WeeklyFocus {
+                            week_iso,
+                            outcome_type,
+                        } => self.save_weekly_focus(week_iso, outcome_type, &new_text)?,
+                        EditorContext::AlignmentNote { outcome_type } => {
+                            if let Some(ModalState::QuarterlyCheckIn(ref mut state)) =
+                                self.modal
+                            {
+                                if let Some(domain) = state
+                                    .checkin
+                                    .domains
+                                    .iter_mut()
+                                    .find(|domain| domain.outcome_type == outcome_type)
+                                {
+                                    domain.note = new_text.trim().to_string();
+                                }
+                            }
+                        }
+                        EditorContext::MorningAction { outcome_type } => {
+                            if let Some(ModalState::Morning(ref mut state)) = self.modal {
+                                let text = new_text.trim();
+                                if let Some(intention) = state
+                                    .plan
+                                    .intentions
+                                    .iter_mut()
+                                    .find(|i| i.outcome_type == outcome_type)
+                                {
+                                    if !text.is_empty() {
+                                        intention.choice =
+                                            Some(morning::Choice::New(text.to_string()));
                                     }
                                 }
-                                'y' => {
-                                    if state.indicator_type == IndicatorType::Boolean {
-                                        state.buffer =
-                                            Self::format_value_for_unit(1.0, &state.unit);
-                                    }
+                            }
+                        }
+                        EditorContext::MorningEstimate { outcome_type } => {
+                            let input = new_text.trim();
+                            let minutes = crate::models::parse_minutes(input);
+                            if minutes.is_none() && !input.is_empty() {
+                                self.toasts.show_error(format!(
+                                    "Not a duration: {} (try 45m or 1h30m)",
+                                    input
+                                ));
+                            } else if let Some(ModalState::Morning(ref mut state)) = self.modal
+                            {
+                                if let Some(intention) = state
+                                    .plan
+                                    .intentions
+                                    .iter_mut()
+                                    .find(|i| i.outcome_type == outcome_type)
+                                {
+                                    intention.estimate_min = minutes;
                                 }
-                                'n' => {
-                                    if state.indicator_type == IndicatorType::Boolean {
-                                        state.buffer =
-                                            Self::format_value_for_unit(0.0, &state.unit);
+                            }
+                        }
+                        EditorContext::ProjectTitle { project_id } => {
+                            self.save_project_title(project_id, &new_text)?
+                        }
+                        EditorContext::TemplateName { template } => {
+                            self.save_template_name(&template, &new_text)
+                        }
+                        EditorContext::TemplateAction {
+                            template,
+                            outcome,
+                            index,
+                        } => self.save_template_action(&template, outcome, index, &new_text),
+                        EditorContext::Command => self.run_ex_command(&new_text)?,
+                        EditorContext::ObjectiveTitle {
+                            outcome_type,
+                            objective_id,
+                            link_action,
+                        } => {
+                            let title = new_text.trim();
+                            if title.is_empty() {
+                                self.toasts
+                                    .show_error("Objective title cannot be empty".to_string());
+                                return Ok(false);
+                            }
+
+                            let backup = self.objectives.clone();
+                            let created_id = match objective_id {
+                                Some(ref existing_id) => {
+                                    if let Some(objective) = self
+                                        .objectives
+                                        .objectives
+                                        .iter_mut()
+                                        .find(|o| &o.id == existing_id)
+                                    {
+                                        objective.title = title.to_string();
+                                        objective.modified = chrono::Utc::now();
+                                        None
+                                    } else {
+                                        self.toasts.show_error(
+                                            "Objective not found for update".to_string(),
+                                        );
+                                        return Ok(false);
                                     }
                                 }
-                                _ => {
-                                    if lower.is_ascii_digit() || lower == '.' {
-                                        if lower == '.' && state.buffer.contains('.') {
-                                            // ignore duplicate decimal
-                                        } else {
-                                            state.buffer.push(ch);
+                                None => {
+                                    let mut objective =
+                                        Objective::new(outcome_type, title.to_string());
+                                    let palette_index = self.objectives.objectives.len()
+                                        % theme::OBJECTIVE_PALETTE.len();
+                                    objective.color = Some(
+                                        theme::OBJECTIVE_PALETTE[palette_index].to_string(),
+                                    );
+                                    let new_id = objective.id.clone();
+                                    self.objectives.objectives.push(objective);
+                                    Some(new_id)
+                                }
+                            };
+
+                            if let Err(e) =
+                                crate::data::save_objectives(&self.objectives, &self.config)
+                            {
+                                self.toasts
+                                    .show_error(format!("Failed to save objectives: {}", e));
+                                self.objectives = backup;
+                                return Err(e);
+                            }
+
+                            let verb = if created_id.is_some() {
+                                "Created"
+                            } else {
+                                "Renamed"
+                            };
+                            self.log_activity(
+                                ActivityKind::Objective,
+                                format!("{} objective: {}", verb, title),
+                            );
+
+                            if let Some(obj_id) = created_id.clone() {
+                                if let Some(action_idx) = link_action {
+                                    if let Err(e) = self.link_action_to_objective(
+                                        outcome_type,
+                                        action_idx,
+                                        &obj_id,
+                                    ) {
+                                        self.toasts
+                                            .show_error(format!("Failed to link objective: {}", e));
+                                        self.objectives = backup;
+                                        let _ = crate::data::save_objectives(
+                                            &self.objectives,
+                                            &self.config,
+                                        );
+                                        return Err(e);
+                                    }
+
+                                    if let Some(index) =
+                                        self.objective_index_in_domain(outcome_type, &obj_id)
+                                    {
+                                        if let Some(ModalState::ObjectivePicker(
+                                            ref mut modal,
+                                        )) = self.modal
+                                        {
+                                            modal.selection = index;
                                         }
                                     }
                                 }
@@ -764,349 +2454,3672 @@ impl App {
                         }
                     }
                 }
-                _ => {}
             }
-
-            if self.modal.is_some() {
-                self.modal = Some(ModalState::IndicatorUpdate(state));
+            EditorResult::Cancel => {
+                self.text_editor.deactivate();
+                self.editor_context = None;
             }
-
-            return Ok(true);
+            EditorResult::External => self.external_edit_requested = true,
+            EditorResult::Continue => {}
         }
-
         Ok(false)
     }
 
-    fn switch_panel(&mut self) {
-        self.focus_panel = match self.focus_panel {
-            FocusPanel::Outcomes => FocusPanel::Actions,
-            FocusPanel::Actions => FocusPanel::Outcomes,
-        };
+    pub fn handle_key(&mut self, key: KeyCode) -> anyhow::Result<bool> {
+        // If editor is active, route input to it
+        if self.text_editor.is_active {
+            let result = self.text_editor.handle_input(key);
+            return self.apply_editor_result(result);
+        }
 
-        // When switching to actions, reset selected_action to be within range for selected outcome
-        if self.focus_panel == FocusPanel::Actions {
-            self.selected_action = 0;
+        if self.modal.is_some() {
+            if self.handle_modal_key(key)? {
+                return Ok(false);
+            }
         }
-    }
 
-    fn move_up(&mut self) {
-        match self.focus_panel {
-            FocusPanel::Outcomes => {
-                self.selected_outcome = match self.selected_outcome {
-                    OutcomeType::Work => OutcomeType::Family,
-                    OutcomeType::Health => OutcomeType::Work,
-                    OutcomeType::Family => OutcomeType::Health,
-                };
+        let mode = self.key_mode();
+        let Some(action) = self.keymap.action(mode, key) else {
+            return Ok(false);
+        };
+        match action {
+            KeyAction::Help => {
+                self.modal = Some(ModalState::Help(HelpState {
+                    mode,
+                    query: String::new(),
+                    scroll: 0,
+                }));
+                return Ok(false);
             }
-            FocusPanel::Actions => {
-                let total = self.get_selected_outcome().actions.len();
-                if total == 0 {
-                    self.selected_action = 0;
-                } else if self.selected_action == 0 {
-                    self.selected_action = total - 1;
-                } else {
-                    self.selected_action -= 1;
-                }
+            KeyAction::Screenshot => {
+                self.save_screenshot();
+                return Ok(false);
+            }
+            KeyAction::RecordMacro => {
+                self.toggle_macro_recording();
+                return Ok(false);
+            }
+            KeyAction::ReplayMacro => {
+                self.macros.pending = Some(Pending::Replay);
+                return Ok(false);
             }
+            _ => {}
+        }
+        match mode {
+            KeyMode::Focus => return self.handle_focus_mode_key(action),
+            KeyMode::Dashboard => return self.handle_dashboard_key(action),
+            KeyMode::Goals => {}
         }
-    }
 
-    fn move_down(&mut self) {
-        match self.focus_panel {
-            FocusPanel::Outcomes => {
-                self.selected_outcome = match self.selected_outcome {
-                    OutcomeType::Work => OutcomeType::Health,
-                    OutcomeType::Health => OutcomeType::Family,
-                    OutcomeType::Family => OutcomeType::Work,
-                };
+        // Normal key handling when editor is not active
+        match action {
+            KeyAction::Quit => return Ok(true), // Exit
+            KeyAction::SwitchPanel => self.switch_panel(),
+            KeyAction::MoveUp => self.move_up(),
+            KeyAction::MoveDown => self.move_down(),
+            KeyAction::ToggleDone => self.toggle_current()?,
+            KeyAction::Expand => self.toggle_expansion(),
+            KeyAction::EditAction => self.open_editor(),
+            KeyAction::EditVision => self.open_vision_editor(),
+            KeyAction::Objectives => self.open_objective_picker(),
+            KeyAction::IndicatorLinks => self.open_indicator_links(),
+            KeyAction::UpdateIndicator => self.open_selected_indicator_update()?,
+            KeyAction::LogMetric => self.open_quick_observation(),
+            KeyAction::LogAll => self.start_bulk_indicator_review()?,
+            KeyAction::RepeatTomorrow => self.clone_day_to_tomorrow()?,
+            KeyAction::CopySummary => self.copy_day_summary(),
+            KeyAction::Pomodoro => self.toggle_focus_timer(FocusSessionKind::Pomodoro)?,
+            KeyAction::Stopwatch => self.toggle_focus_timer(FocusSessionKind::Stopwatch)?,
+            KeyAction::ActionTimer => self.toggle_action_timer()?,
+            KeyAction::Reflect => self.open_evening_summary()?,
+            KeyAction::DayNote => self.open_day_note_editor(),
+            KeyAction::Snooze => self.open_snooze(),
+            KeyAction::Blocker => self.open_blocker_picker()?,
+            KeyAction::Board => self.open_board(),
+            KeyAction::Timeline => self.open_timeline(),
+            KeyAction::WeeklyPlan => self.open_weekly_plan(),
+            KeyAction::YearReview => self.open_year_review(self.goals.date.year()),
+            KeyAction::Projects => self.open_projects(),
+            KeyAction::VisionHistory => self.open_vision_history(),
+            KeyAction::QuarterlyCheckIn => self.start_quarterly_checkin(),
+            KeyAction::Journal => self.open_journal(),
+            KeyAction::MorningPlan => self.open_morning_wizard(),
+            KeyAction::CloseDay => self.close_or_reopen_day()?,
+            KeyAction::Templates => self.open_templates(),
+            KeyAction::Backups => self.open_backups()?,
+            KeyAction::Trash => self.open_trash()?,
+            KeyAction::WeeklyFocusLink => self.toggle_weekly_focus_link()?,
+            KeyAction::FocusMode => self.enter_focus_mode(),
+            KeyAction::Priority(level) => self.set_selected_priority(level)?,
+            KeyAction::Retarget => self.open_retarget_wizard(),
+            KeyAction::CheckIn => self.open_check_in(),
+            KeyAction::CheckInAnalytics => self.open_check_in_analytics(),
+            KeyAction::CompletionTimes => self.open_completion_times(),
+            KeyAction::Trophies => self.modal = Some(ModalState::Trophies),
+            KeyAction::Messages => {
+                self.toasts.clear();
+                self.modal = Some(ModalState::Messages(0));
             }
-            FocusPanel::Actions => {
-                let total = self.get_selected_outcome().actions.len();
-                if total == 0 {
-                    self.selected_action = 0;
-                } else {
-                    self.selected_action = (self.selected_action + 1) % total;
+            // NEW: Day navigation using Page Up/Down keys
+            KeyAction::PreviousDay => {
+                if let Err(e) = self.navigate_to_previous_day() {
+                    self.toasts.show_error(format!("Navigation failed: {}", e));
+                }
+            }
+            KeyAction::NextDay => {
+                if let Err(e) = self.navigate_to_next_day() {
+                    self.toasts.show_error(format!("Navigation failed: {}", e));
                 }
             }
+            KeyAction::CommandLine => self.open_command_line(),
+            KeyAction::ToggleDashboard => self.toggle_dashboard_view(),
+            _ => {}
         }
+        Ok(false)
     }
 
-    fn toggle_current(&mut self) -> anyhow::Result<()> {
-        if self.focus_panel == FocusPanel::Actions {
-            let action_index = self.selected_action;
-
-            // Get the current completion status
-            let was_completed = {
-                let outcome = self.get_selected_outcome();
-                outcome.actions[action_index].completed
-            };
+    /// `r`: stop recording, or wait for the register to record into
+    fn toggle_macro_recording(&mut self) {
+        match self.macros.stop() {
+            Some((register, count)) => self
+                .toasts
+                .show_info(format!("Recorded {} keys into @{}", count, register)),
+            None => self.macros.pending = Some(Pending::Record),
+        }
+    }
 
-            // Toggle the completion status
-            {
-                let outcome = self.get_selected_outcome_mut();
-                outcome.actions[action_index].completed = !was_completed;
+    /// The register key after `r` or `@`
+    fn finish_macro_key(&mut self, pending: Pending, key: KeyCode) -> anyhow::Result<bool> {
+        let Some(register) = macros::register_for(key, pending) else {
+            if key != KeyCode::Esc {
+                self.toasts
+                    .show_warning("Macro registers are the letters a-z".to_string());
+            }
+            return Ok(false);
+        };
+        match pending {
+            Pending::Record => {
+                self.macros.start(register);
+                self.toasts
+                    .show_info(format!("Recording into @{}", register));
+                Ok(false)
             }
+            Pending::Replay => self.replay_macro(register),
+        }
+    }
 
-            // Auto-save
-            match crate::data::write_goals_file(&self.goals, &self.config) {
-                Ok(_) => {
-                    // Silent save - no popup notification
-                }
-                Err(e) => {
-                    self.error_display
-                        .show_error(format!("Failed to save: {}", e));
-                    // Revert the change
-                    let outcome = self.get_selected_outcome_mut();
-                    outcome.actions[action_index].completed = was_completed;
-                    return Err(e);
-                }
+    /// Send a register's keys through `handle_key_event` as if typed
+    fn replay_macro(&mut self, register: char) -> anyhow::Result<bool> {
+        if self.macros.depth >= macros::MAX_DEPTH {
+            self.macros.overflowed = true;
+            self.toasts
+                .show_warning("Macro stopped: it keeps replaying itself".to_string());
+            return Ok(false);
+        }
+        let Some((_, keys)) = self.macros.keys(register) else {
+            self.toasts.show_warning(match register {
+                '@' => "No macro replayed yet".to_string(),
+                _ => format!("Nothing recorded in @{}", register),
+            });
+            return Ok(false);
+        };
+        self.macros.depth += 1;
+        let mut result = Ok(false);
+        for key in keys {
+            if self.macros.overflowed {
+                break;
             }
+            result = self.handle_key_event(key);
+            if !matches!(result, Ok(false)) {
+                break;
+            }
+        }
+        self.macros.depth -= 1;
+        if self.macros.depth == 0 {
+            self.macros.overflowed = false;
+        }
+        result
+    }
 
-            // Update statistics after toggling
-            self.statistics = Statistics::from_current_goals(&self.goals, &self.config);
+    /// Which keymap table keys are looked up in
+    fn key_mode(&self) -> KeyMode {
+        if self.focus_mode {
+            KeyMode::Focus
+        } else if self.show_dashboard {
+            KeyMode::Dashboard
+        } else {
+            KeyMode::Goals
         }
-        Ok(())
     }
 
-    fn get_selected_outcome_mut(&mut self) -> &mut crate::models::Outcome {
-        self.get_outcome_by_type_mut(self.selected_outcome)
+    /// Whether a binding's hint belongs in the status bar right now
+    fn hint_applies(&self, scope: Scope) -> bool {
+        match scope {
+            Scope::All => true,
+            Scope::Outcomes => self.focus_panel == FocusPanel::Outcomes,
+            Scope::Actions => self.focus_panel == FocusPanel::Actions,
+            Scope::Performance => self.dashboard_focus == DashboardPanel::Performance,
+            Scope::Signals => self.dashboard_focus == DashboardPanel::Signals,
+            Scope::Corner => matches!(
+                self.dashboard_focus,
+                DashboardPanel::Signals | DashboardPanel::Portfolio
+            ),
+            Scope::Indicators => matches!(
+                self.dashboard_focus,
+                DashboardPanel::Signals | DashboardPanel::Performance
+            ),
+            Scope::Comparing => self.compare_pair.is_some(),
+        }
     }
 
-    fn get_selected_outcome(&self) -> &crate::models::Outcome {
-        self.get_outcome_by_type(self.selected_outcome)
+    /// Status bar for the current screen and panel, from the keymap
+    fn status_bar(&self, width: u16, badge: Style, key: Style) -> Vec<Line<'static>> {
+        let mode = self.key_mode();
+        let label = match (mode, self.dashboard_focus, &self.focus_panel) {
+            (KeyMode::Focus, _, _) => "FOCUS",
+            (KeyMode::Dashboard, DashboardPanel::Market, _) => "LIVE METRICS",
+            (KeyMode::Dashboard, DashboardPanel::Performance, _) => "PERFORMANCE",
+            (KeyMode::Dashboard, DashboardPanel::Sentiment, _) => "SENTIMENT",
+            (KeyMode::Dashboard, DashboardPanel::Signals, _) => "SIGNALS",
+            (KeyMode::Dashboard, DashboardPanel::Portfolio, _) => "PORTFOLIO",
+            (KeyMode::Goals, _, FocusPanel::Outcomes) => "OUTCOMES",
+            (KeyMode::Goals, _, FocusPanel::Actions) => "ACTIONS",
+        };
+        let label = match self.macros.recording() {
+            Some(register) => format!("{} · REC @{}", label, register),
+            None => label.to_string(),
+        };
+        let hints = self.keymap.hints(mode, |scope| self.hint_applies(scope));
+        help::status_bar_lines(&label, &hints, width, badge, key)
     }
 
-    fn current_action_indicator_ids(&self) -> Vec<String> {
-        let outcome = self.get_selected_outcome();
-        if self.selected_action >= outcome.actions.len() {
-            return Vec::new();
-        }
+    /// `f`: show only the top unfinished action of each outcome
+    fn enter_focus_mode(&mut self) {
+        let outcomes = [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family];
+        let Some(outcome_type) = std::iter::once(self.selected_outcome)
+            .chain(outcomes)
+            .find(|outcome_type| self.top_action(*outcome_type).is_some())
+        else {
+            self.toasts
+                .show_info("Nothing left to do today".to_string());
+            return;
+        };
+        self.focus_mode = true;
+        self.focus_panel = FocusPanel::Actions;
+        self.focus_on(outcome_type);
+    }
 
-        let action = &outcome.actions[self.selected_action];
-        let mut ids = Vec::new();
+    fn top_action(&self, outcome_type: OutcomeType) -> Option<usize> {
+        priority::top_action(&self.goals, outcome_type, &self.action_priorities)
+    }
 
-        for objective_id in action.get_all_objective_ids() {
-            if let Some(objective) = self
-                .objectives
-                .objectives
-                .iter()
-                .find(|obj| obj.id == objective_id)
-            {
-                for indicator_id in &objective.indicators {
-                    if self
-                        .indicators
-                        .indicators
-                        .iter()
-                        .any(|indicator| indicator.id == *indicator_id)
-                    {
-                        ids.push(indicator_id.clone());
-                    }
+    /// Select the top action of `outcome_type`
+    fn focus_on(&mut self, outcome_type: OutcomeType) {
+        self.selected_outcome = outcome_type;
+        if let Some(index) = self.top_action(outcome_type) {
+            self.selected_action = index;
+        }
+    }
+
+    fn handle_focus_mode_key(&mut self, action: KeyAction) -> anyhow::Result<bool> {
+        let outcomes = [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family];
+        let current = outcomes
+            .iter()
+            .position(|outcome_type| *outcome_type == self.selected_outcome)
+            .unwrap_or(0);
+        match action {
+            KeyAction::Quit => return Ok(true),
+            KeyAction::LeaveFocus => self.focus_mode = false,
+            KeyAction::MoveDown => self.focus_on(outcomes[(current + 1) % outcomes.len()]),
+            KeyAction::MoveUp => {
+                self.focus_on(outcomes[(current + outcomes.len() - 1) % outcomes.len()])
+            }
+            KeyAction::ToggleDone => {
+                if let Some(index) = self.top_action(self.selected_outcome) {
+                    self.selected_action = index;
+                    self.toggle_current()?;
+                    self.focus_on(self.selected_outcome);
                 }
             }
+            KeyAction::ActionTimer => {
+                self.focus_on(self.selected_outcome);
+                self.toggle_action_timer()?;
+            }
+            KeyAction::Pomodoro => self.toggle_focus_timer(FocusSessionKind::Pomodoro)?,
+            KeyAction::Stopwatch => self.toggle_focus_timer(FocusSessionKind::Stopwatch)?,
+            KeyAction::CommandLine => self.open_command_line(),
+            _ => {}
         }
-
-        ids
+        Ok(false)
     }
 
-    fn open_selected_indicator_update(&mut self) -> anyhow::Result<()> {
-        if self.focus_panel != FocusPanel::Actions {
-            return Ok(());
-        }
+    fn toggle_dashboard_view(&mut self) {
+        self.show_dashboard = !self.show_dashboard;
 
-        // Open indicator update for the first indicator linked to the current action
-        let indicator_ids = self.current_action_indicator_ids();
-        if let Some(indicator_id) = indicator_ids.first() {
-            self.open_indicator_update_modal(indicator_id)?;
+        if self.show_dashboard {
+            self.refresh_dashboard_data();
+            self.dashboard_focus = DashboardPanel::Market;
+            self.dashboard_signal_index = 0;
+            self.dashboard_performance_index = 0;
+            self.dashboard_market_index = 0;
         } else {
-            self.error_display
-                .show_info("No indicators linked to this action".to_string());
+            self.focus_panel = FocusPanel::Outcomes;
+            self.dashboard_signal_ids.clear();
+            self.dashboard_performance_ids.clear();
+            self.dashboard_market_ids.clear();
+            self.compare_anchor = None;
+            self.compare_pair = None;
         }
-
-        Ok(())
     }
 
-    fn open_indicator_update_modal(&mut self, indicator_id: &str) -> anyhow::Result<()> {
-        let indicator_def = if let Some(def) = self
+    /// `c` in the Performance panel: pick the first indicator, then the
+    /// second to overlay them; pressing it again leaves compare mode.
+    /// Flip whether an indicator may appear in Alternative Signals. Excluded
+    /// indicators still chart under Performance, where `x` brings them back.
+    pub fn toggle_signal_exclusion(&mut self, indicator_id: &str) -> anyhow::Result<()> {
+        let Some(indicator) = self
             .indicators
             .indicators
-            .iter()
-            .find(|indicator| indicator.id == indicator_id)
-        {
-            def.clone()
-        } else {
-            self.error_display
-                .show_error("Indicator definition not found".to_string());
+            .iter_mut()
+            .find(|def| def.id == indicator_id)
+        else {
             return Ok(());
         };
+        indicator.exclude_from_signals = !indicator.exclude_from_signals;
+        indicator.modified = chrono::Utc::now();
+        let message = if indicator.exclude_from_signals {
+            format!("{} hidden from signals", indicator.name)
+        } else {
+            format!("{} back in signals", indicator.name)
+        };
+        crate::data::save_indicators(&self.indicators, &self.config)?;
 
-        let indicator_type = self
-            .indicators_map
-            .get(indicator_id)
-            .map(|indicator| indicator.indicator_type)
-            .unwrap_or_else(|| Self::infer_indicator_type(&indicator_def.unit));
-
-        let (history, latest, previous, last_updated) =
-            self.collect_indicator_history(indicator_id)?;
-
-        let buffer_source = latest.or(previous).unwrap_or(0.0);
-        let buffer = Self::format_value_for_unit(buffer_source, &indicator_def.unit);
-
-        let state = IndicatorUpdateState {
-            indicator_id: indicator_def.id.clone(),
-            name: indicator_def.name.clone(),
-            unit: indicator_def.unit.clone(),
-            indicator_type,
-            direction: indicator_def.direction.clone(),
-            target: indicator_def.target,
-            previous_value: previous,
-            latest_value: latest,
-            history,
-            last_updated,
-            buffer,
-        };
-
-        self.modal = Some(ModalState::IndicatorUpdate(state));
+        self.dashboard_signal_ids.retain(|id| {
+            self.indicators
+                .indicators
+                .iter()
+                .any(|def| &def.id == id && !def.exclude_from_signals)
+        });
+        self.dashboard_signal_index = self
+            .dashboard_signal_index
+            .min(self.dashboard_signal_ids.len().saturating_sub(1));
+        self.toasts.show_info(message);
         Ok(())
     }
 
-    fn collect_indicator_history(
-        &self,
-        indicator_id: &str,
-    ) -> anyhow::Result<(
-        Vec<f64>,
-        Option<f64>,
-        Option<f64>,
-        Option<chrono::NaiveDate>,
-    )> {
-        let today = chrono::Local::now().date_naive();
-        let mut observations = crate::data::read_observations_range(
-            today - chrono::Duration::days(60),
-            today,
-            &self.config,
-        )?;
-
-        observations.sort_by(|a, b| a.when.cmp(&b.when).then(a.created.cmp(&b.created)));
-
-        let mut history_values = Vec::new();
-        let mut history_dates = Vec::new();
-        for obs in observations
-            .into_iter()
-            .filter(|obs| obs.indicator_id == indicator_id)
-        {
-            history_values.push(obs.value);
-            history_dates.push(obs.when);
+    fn toggle_compare_selection(&mut self) {
+        if self.compare_pair.take().is_some() {
+            return;
         }
+        let Some(selected) = self
+            .dashboard_performance_ids
+            .get(self.dashboard_performance_index)
+            .cloned()
+        else {
+            return;
+        };
 
-        let fallback_current = self
-            .indicators_map
-            .get(indicator_id)
-            .map(|indicator| indicator.current_value);
-
-        let mut last_updated = history_dates.last().copied();
-        if history_values.is_empty() {
-            if let Some(current) = fallback_current {
-                history_values.push(current);
-                last_updated = Some(today);
+        match self.compare_anchor.take() {
+            Some(anchor) if anchor != selected => {
+                self.compare_pair = Some((anchor, selected));
+            }
+            Some(_) => {}
+            None => {
+                let name = self
+                    .indicators
+                    .indicators
+                    .iter()
+                    .find(|def| def.id == selected)
+                    .map(|def| def.name.clone())
+                    .unwrap_or_default();
+                self.toasts.show_info(format!(
+                    "Comparing {} - select another indicator and press c",
+                    name
+                ));
+                self.compare_anchor = Some(selected);
             }
         }
+    }
 
-        let latest = history_values.last().copied().or(fallback_current);
-        let previous = if history_values.len() >= 2 {
-            history_values.get(history_values.len() - 2).copied()
+    /// Panel in the bottom-right quadrant, which `a` switches
+    fn corner_panel(&self) -> DashboardPanel {
+        if self.dashboard_show_signals {
+            DashboardPanel::Signals
         } else {
-            None
-        };
-
-        let start = history_values.len().saturating_sub(7);
-        let history_window = history_values[start..].to_vec();
-
-        Ok((history_window, latest, previous, last_updated))
+            DashboardPanel::Portfolio
+        }
     }
 
-    fn infer_indicator_type(unit: &IndicatorUnit) -> IndicatorType {
-        match unit {
-            IndicatorUnit::Minutes => IndicatorType::Duration,
-            IndicatorUnit::Percent => IndicatorType::Percentage,
-            IndicatorUnit::Custom(label) if label.eq_ignore_ascii_case("boolean") => {
-                IndicatorType::Boolean
+    fn handle_dashboard_key(&mut self, action: KeyAction) -> anyhow::Result<bool> {
+        match action {
+            KeyAction::Quit => return Ok(true),
+            KeyAction::Close if self.compare_pair.is_some() || self.compare_anchor.is_some() => {
+                self.compare_pair = None;
+                self.compare_anchor = None;
             }
-            IndicatorUnit::Custom(label) if label.eq_ignore_ascii_case("hours") => {
-                IndicatorType::Duration
+            KeyAction::Close | KeyAction::ToggleDashboard => {
+                self.toggle_dashboard_view();
             }
-            IndicatorUnit::Custom(label) if label.eq_ignore_ascii_case("percentage") => {
-                IndicatorType::Percentage
+            KeyAction::Compare if self.dashboard_focus == DashboardPanel::Performance => {
+                self.toggle_compare_selection();
             }
-            _ => IndicatorType::Counter,
-        }
-    }
-
-    fn format_value_with_unit(value: f64, unit: &IndicatorUnit) -> String {
-        let base = Self::format_value_for_unit(value, unit);
-        match unit {
-            IndicatorUnit::Percent => format!("{}%", base),
-            _ => {
-                let label = Self::unit_label(unit);
-                if label.is_empty() {
-                    base
+            KeyAction::Intraday if self.dashboard_focus == DashboardPanel::Performance => {
+                self.dashboard_intraday = !self.dashboard_intraday;
+            }
+            KeyAction::ZoomIn if self.dashboard_focus == DashboardPanel::Performance => {
+                self.dashboard_chart_window =
+                    (self.dashboard_chart_window + 1) % CHART_WINDOWS.len();
+            }
+            KeyAction::ZoomOut if self.dashboard_focus == DashboardPanel::Performance => {
+                self.dashboard_chart_window =
+                    (self.dashboard_chart_window + CHART_WINDOWS.len() - 1) % CHART_WINDOWS.len();
+            }
+            KeyAction::PanBack if self.dashboard_focus == DashboardPanel::Performance => {
+                let step = (CHART_WINDOWS[self.dashboard_chart_window] / 2).max(1);
+                self.dashboard_chart_pan += step;
+            }
+            KeyAction::PanForward if self.dashboard_focus == DashboardPanel::Performance => {
+                let step = (CHART_WINDOWS[self.dashboard_chart_window] / 2).max(1);
+                self.dashboard_chart_pan = (self.dashboard_chart_pan - step).max(0);
+            }
+            KeyAction::Normalize if self.compare_pair.is_some() => {
+                self.compare_scale = self.compare_scale.toggle();
+            }
+            KeyAction::HideFromSignals => {
+                let selected = match self.dashboard_focus {
+                    DashboardPanel::Signals => {
+                        self.dashboard_signal_ids.get(self.dashboard_signal_index)
+                    }
+                    DashboardPanel::Performance => self
+                        .dashboard_performance_ids
+                        .get(self.dashboard_performance_index),
+                    _ => None,
+                };
+                if let Some(indicator_id) = selected.cloned() {
+                    self.toggle_signal_exclusion(&indicator_id)?;
+                }
+            }
+            KeyAction::DeleteIndicator => {
+                let selected = match self.dashboard_focus {
+                    DashboardPanel::Signals => {
+                        self.dashboard_signal_ids.get(self.dashboard_signal_index)
+                    }
+                    DashboardPanel::Performance => self
+                        .dashboard_performance_ids
+                        .get(self.dashboard_performance_index),
+                    _ => None,
+                };
+                if let Some(indicator_id) = selected.cloned() {
+                    self.delete_indicator(&indicator_id)?;
+                }
+            }
+            KeyAction::ToggleCorner
+                if matches!(
+                    self.dashboard_focus,
+                    DashboardPanel::Signals | DashboardPanel::Portfolio
+                ) =>
+            {
+                self.dashboard_show_signals = !self.dashboard_show_signals;
+                self.dashboard_focus = self.corner_panel();
+            }
+            KeyAction::PanelLeft => {
+                self.dashboard_focus = match self.dashboard_focus {
+                    DashboardPanel::Market => self.corner_panel(),
+                    DashboardPanel::Performance => DashboardPanel::Market,
+                    DashboardPanel::Sentiment => DashboardPanel::Performance,
+                    DashboardPanel::Signals | DashboardPanel::Portfolio => {
+                        DashboardPanel::Sentiment
+                    }
+                };
+            }
+            KeyAction::PanelRight => {
+                self.dashboard_focus = match self.dashboard_focus {
+                    DashboardPanel::Market => DashboardPanel::Performance,
+                    DashboardPanel::Performance => DashboardPanel::Sentiment,
+                    DashboardPanel::Sentiment => self.corner_panel(),
+                    DashboardPanel::Signals | DashboardPanel::Portfolio => DashboardPanel::Market,
+                };
+            }
+            KeyAction::MoveUp => {
+                if self.dashboard_focus == DashboardPanel::Market
+                    && !self.dashboard_market_ids.is_empty()
+                {
+                    if self.dashboard_market_index == 0 {
+                        self.dashboard_market_index = self.dashboard_market_ids.len() - 1;
+                    } else {
+                        self.dashboard_market_index -= 1;
+                    }
+                } else if self.dashboard_focus == DashboardPanel::Signals
+                    && !self.dashboard_signal_ids.is_empty()
+                {
+                    if self.dashboard_signal_index == 0 {
+                        self.dashboard_signal_index = self.dashboard_signal_ids.len() - 1;
+                    } else {
+                        self.dashboard_signal_index -= 1;
+                    }
+                } else if self.dashboard_focus == DashboardPanel::Portfolio
+                    && !self.dashboard_portfolio_ids.is_empty()
+                {
+                    if self.dashboard_portfolio_index == 0 {
+                        self.dashboard_portfolio_index = self.dashboard_portfolio_ids.len() - 1;
+                    } else {
+                        self.dashboard_portfolio_index -= 1;
+                    }
+                } else if self.dashboard_focus == DashboardPanel::Performance
+                    && !self.dashboard_performance_ids.is_empty()
+                {
+                    if self.dashboard_performance_index == 0 {
+                        self.dashboard_performance_index = self.dashboard_performance_ids.len() - 1;
+                    } else {
+                        self.dashboard_performance_index -= 1;
+                    }
                 } else {
-                    format!("{} {}", base, label)
+                    self.dashboard_focus = match self.dashboard_focus {
+                        DashboardPanel::Market => DashboardPanel::Market,
+                        DashboardPanel::Performance => DashboardPanel::Performance,
+                        DashboardPanel::Sentiment => DashboardPanel::Market,
+                        DashboardPanel::Signals | DashboardPanel::Portfolio => {
+                            DashboardPanel::Performance
+                        }
+                    };
                 }
             }
+            KeyAction::MoveDown => {
+                if self.dashboard_focus == DashboardPanel::Market
+                    && !self.dashboard_market_ids.is_empty()
+                {
+                    self.dashboard_market_index =
+                        (self.dashboard_market_index + 1) % self.dashboard_market_ids.len();
+                } else if self.dashboard_focus == DashboardPanel::Signals
+                    && !self.dashboard_signal_ids.is_empty()
+                {
+                    self.dashboard_signal_index =
+                        (self.dashboard_signal_index + 1) % self.dashboard_signal_ids.len();
+                } else if self.dashboard_focus == DashboardPanel::Portfolio
+                    && !self.dashboard_portfolio_ids.is_empty()
+                {
+                    self.dashboard_portfolio_index =
+                        (self.dashboard_portfolio_index + 1) % self.dashboard_portfolio_ids.len();
+                } else if self.dashboard_focus == DashboardPanel::Performance
+                    && !self.dashboard_performance_ids.is_empty()
+                {
+                    self.dashboard_performance_index =
+                        (self.dashboard_performance_index + 1) % self.dashboard_performance_ids.len();
+                } else {
+                    self.dashboard_focus = match self.dashboard_focus {
+                        DashboardPanel::Market => DashboardPanel::Sentiment,
+                        DashboardPanel::Performance => self.corner_panel(),
+                        DashboardPanel::Sentiment => DashboardPanel::Sentiment,
+                        DashboardPanel::Signals => DashboardPanel::Signals,
+                        DashboardPanel::Portfolio => DashboardPanel::Portfolio,
+                    };
+                }
+            }
+            KeyAction::Inspect => {
+                if self.dashboard_focus == DashboardPanel::Signals {
+                    if let Some(indicator_id) = self
+                        .dashboard_signal_ids
+                        .get(self.dashboard_signal_index)
+                        .cloned()
+                    {
+                        self.open_indicator_update_modal(&indicator_id)?;
+                    }
+                }
+            }
+            KeyAction::CommandLine => self.open_command_line(),
+            _ => {}
         }
+        Ok(false)
     }
 
-    fn indicator_delta_threshold(indicator_type: IndicatorType) -> f64 {
-        match indicator_type {
-            IndicatorType::Counter => 0.5,
-            IndicatorType::Duration => 0.1,
-            IndicatorType::Percentage => 0.5,
-            IndicatorType::Boolean => 0.5,
-        }
-    }
-
-    fn indicator_delta_label(state: &IndicatorUpdateState) -> Option<String> {
-        let target = state.target?;
-        let latest = state.latest_value?;
-        let threshold = Self::indicator_delta_threshold(state.indicator_type);
-        let diff = latest - target;
-        if diff.abs() < threshold {
-            return Some("on target".to_string());
-        }
-
-        let magnitude = Self::format_value_with_unit(diff.abs(), &state.unit);
-        if diff > 0.0 {
-            Some(format!("(+{} ahead)", magnitude))
-        } else {
-            Some(format!("(-{} behind)", magnitude))
+    fn handle_modal_key(&mut self, key: KeyCode) -> anyhow::Result<bool> {
+        if let Some(ModalState::ObjectiveComplete(state)) = self.modal.clone() {
+            match key {
+                KeyCode::Enter => {
+                    self.complete_objective(&state.objective_id)?;
+                    let mut picker = state.picker;
+                    let remaining = self
+                        .objective_choices(picker.outcome_type, picker.show_archived)
+                        .len();
+                    picker.selection = picker.selection.min(remaining);
+                    self.modal = Some(ModalState::ObjectivePicker(picker));
+                }
+                KeyCode::Esc => {
+                    self.modal = Some(ModalState::ObjectivePicker(state.picker));
+                }
+                _ => {}
+            }
+            return Ok(true);
         }
-    }
 
-    fn indicator_trend_status(state: &IndicatorUpdateState) -> TrendStatus {
-        match (state.latest_value, state.previous_value) {
-            (Some(latest), Some(previous)) => {
-                let diff = latest - previous;
-                let threshold = Self::indicator_delta_threshold(state.indicator_type);
-                if diff > threshold {
-                    TrendStatus::Improving
-                } else if diff < -threshold {
-                    TrendStatus::Declining
-                } else {
-                    TrendStatus::Stable
+        if let Some(ModalState::IndicatorLinks(mut state)) = self.modal {
+            let choices: Vec<IndicatorDef> =
+                self.indicator_link_choices().into_iter().cloned().collect();
+            match key {
+                KeyCode::Esc | KeyCode::Char('q') => {
+                    self.modal = None;
+                    return Ok(true);
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    state.selection = state.selection.saturating_sub(1);
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    state.selection = (state.selection + 1).min(choices.len().saturating_sub(1));
+                }
+                KeyCode::Enter | KeyCode::Char(' ') => {
+                    if let Some(def) = choices.get(state.selection) {
+                        let linked = self
+                            .get_outcome_by_type(state.outcome_type)
+                            .actions
+                            .get(state.action_index)
+                            .and_then(|action| action.indicator_link(&def.id))
+                            .is_some();
+                        let link = if linked { None } else { Some(None) };
+                        let id = def.id.clone();
+                        self.set_indicator_link(state.outcome_type, state.action_index, &id, link);
+                    }
+                }
+                KeyCode::Char('a') => {
+                    if let Some(def) = choices.get(state.selection) {
+                        let current = self
+                            .get_outcome_by_type(state.outcome_type)
+                            .actions
+                            .get(state.action_index)
+                            .and_then(|action| action.indicator_link(&def.id))
+                            .and_then(|link| link.value)
+                            .map(|value| value.to_string())
+                            .unwrap_or_default();
+                        self.text_editor.activate_with(
+                            &format!("Log to {} on completion (empty to be asked)", def.name),
+                            &current,
+                            16,
+                        );
+                        self.editor_context = Some(EditorContext::IndicatorLinkValue {
+                            outcome_type: state.outcome_type,
+                            action_index: state.action_index,
+                            indicator_id: def.id.clone(),
+                        });
+                    }
                 }
+                _ => {}
             }
-            _ => TrendStatus::Stable,
+            if self.modal.is_some() {
+                self.modal = Some(ModalState::IndicatorLinks(state));
+            }
+            return Ok(true);
         }
-    }
 
-    fn indicator_trend_display(status: TrendStatus) -> (&'static str, &'static str) {
-        match status {
-            TrendStatus::Improving => ("▲", "Improving"),
-            TrendStatus::Declining => ("▼", "Declining"),
-            TrendStatus::Stable => ("■", "Stable"),
-        }
-    }
+        if let Some(ModalState::ObjectivePicker(mut state)) = self.modal.clone() {
+            let choices = self.objective_choices(state.outcome_type, state.show_archived);
+            let total_items = choices.len() + 1; // +1 for "Create New"
 
-    fn trend_color(&self, status: TrendStatus) -> Color {
+            match key {
+                KeyCode::Esc => {
+                    self.modal = None;
+                    return Ok(true);
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    if total_items == 0 {
+                        state.selection = 0;
+                    } else if state.selection == 0 {
+                        state.selection = total_items.saturating_sub(1);
+                    } else {
+                        state.selection -= 1;
+                    }
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    if total_items == 0 {
+                        state.selection = 0;
+                    } else {
+                        state.selection = (state.selection + 1) % total_items.max(1);
+                    }
+                }
+                KeyCode::Enter => {
+                    if state.selection == choices.len() {
+                        self.start_objective_creation(state.outcome_type, Some(state.action_index));
+                    } else if let Some(choice) = choices.get(state.selection) {
+                        let objective_id = choice.id.clone();
+                        self.toggle_action_objective(
+                            state.outcome_type,
+                            state.action_index,
+                            &objective_id,
+                        )?;
+
+                        let updated_len = self
+                            .objective_choices(state.outcome_type, state.show_archived)
+                            .len();
+                        if state.selection >= updated_len {
+                            state.selection = updated_len;
+                        }
+                    }
+                }
+                KeyCode::Char('n') => {
+                    self.start_objective_creation(state.outcome_type, Some(state.action_index));
+                }
+                KeyCode::Char('r') => {
+                    if let Some(choice) = choices.get(state.selection) {
+                        self.start_objective_rename(state.outcome_type, choice.id.clone());
+                    }
+                }
+                KeyCode::Char('c') => {
+                    if let Some(choice) = choices.get(state.selection) {
+                        self.cycle_objective_color(choice.storage_index)?;
+                    }
+                }
+                KeyCode::Char('b') => {
+                    if let Some(choice) = choices.get(state.selection) {
+                        self.start_objective_budget_edit(choice.id.clone());
+                    }
+                }
+                KeyCode::Char('t') => {
+                    if let Some(choice) = choices.get(state.selection) {
+                        self.start_objective_dates_edit(choice.id.clone());
+                    }
+                }
+                KeyCode::Char('s') => {
+                    if let Some(choice) = choices.get(state.selection) {
+                        self.cycle_objective_status(choice.storage_index)?;
+                        let updated_len = self
+                            .objective_choices(state.outcome_type, state.show_archived)
+                            .len();
+                        state.selection = state.selection.min(updated_len);
+                    }
+                }
+                KeyCode::Char('x') => {
+                    if let Some(choice) = choices.get(state.selection) {
+                        if choice.status == ObjectiveStatus::Completed {
+                            self.toasts
+                                .show_info(format!("{} is already complete", choice.title));
+                        } else {
+                            self.modal =
+                                Some(ModalState::ObjectiveComplete(ObjectiveCompleteState {
+                                    picker: state,
+                                    objective_id: choice.id.clone(),
+                                }));
+                            return Ok(true);
+                        }
+                    }
+                }
+                KeyCode::Char('v') => {
+                    state.show_archived = !state.show_archived;
+                    state.selection = 0;
+                }
+                KeyCode::Char('a') => {
+                    if let Some(choice) = choices.get(state.selection) {
+                        self.text_editor.activate_with(
+                            "Add Queue Item (book, course, ...)",
+                            "",
+                            crate::models::MAX_GOAL_LENGTH,
+                        );
+                        self.editor_context = Some(EditorContext::QueueItem {
+                            objective_id: choice.id.clone(),
+                        });
+                    }
+                }
+                KeyCode::Char('d') => {
+                    if let Some(choice) = choices.get(state.selection) {
+                        self.delete_objective(&choice.id)?;
+                        let updated_len = self
+                            .objective_choices(state.outcome_type, state.show_archived)
+                            .len();
+                        if state.selection >= updated_len {
+                            state.selection = updated_len;
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            if self.modal.is_some() {
+                self.modal = Some(ModalState::ObjectivePicker(state));
+            }
+
+            return Ok(true);
+        }
+
+        if let Some(ModalState::IndicatorUpdate(mut state)) = self.modal.clone() {
+            match key {
+                KeyCode::Esc => {
+                    self.modal = None;
+                    if state.review_position.is_some() {
+                        self.bulk_review_queue.clear();
+                        self.bulk_review_total = 0;
+                    }
+                    return Ok(true);
+                }
+                KeyCode::Enter => {
+                    self.apply_indicator_update(&state)?;
+                    self.modal = None;
+                    if state.review_position.is_some() {
+                        self.advance_bulk_review()?;
+                    }
+                    return Ok(true);
+                }
+                KeyCode::Tab if state.review_position.is_some() => {
+                    // Skip this indicator without recording anything
+                    self.modal = None;
+                    self.advance_bulk_review()?;
+                    return Ok(true);
+                }
+                KeyCode::Backspace => {
+                    state.buffer.pop();
+                }
+                KeyCode::Char(ch) => {
+                    match ch {
+                        '+' | '=' => {
+                            let delta = Self::indicator_fine_delta(&state);
+                            if delta > 0.0 {
+                                state.buffer =
+                                    Self::adjust_buffer_value(&state.buffer, &state.unit, delta);
+                            }
+                        }
+                        '-' | '_' => {
+                            let delta = Self::indicator_fine_delta(&state);
+                            if delta > 0.0 {
+                                state.buffer =
+                                    Self::adjust_buffer_value(&state.buffer, &state.unit, -delta);
+                            }
+                        }
+                        _ => {
+                            let lower = ch.to_ascii_lowercase();
+                            match lower {
+                                'c' => state.buffer.clear(),
+                                'a' => match state.indicator_type {
+                                    IndicatorType::Percentage => {
+                                        state.buffer =
+                                            Self::format_value_for_unit(25.0, &state.unit);
+                                    }
+                                    IndicatorType::Boolean => {}
+                                    _ => {
+                                        let delta = Self::indicator_small_delta(&state);
+                                        if delta > 0.0 {
+                                            state.buffer = Self::adjust_buffer_value(
+                                                &state.buffer,
+                                                &state.unit,
+                                                delta,
+                                            );
+                                        }
+                                    }
+                                },
+                                's' => match state.indicator_type {
+                                    IndicatorType::Percentage => {
+                                        state.buffer =
+                                            Self::format_value_for_unit(50.0, &state.unit);
+                                    }
+                                    IndicatorType::Boolean => {}
+                                    _ => {
+                                        let delta = Self::indicator_medium_delta(&state);
+                                        if delta > 0.0 {
+                                            state.buffer = Self::adjust_buffer_value(
+                                                &state.buffer,
+                                                &state.unit,
+                                                delta,
+                                            );
+                                        }
+                                    }
+                                },
+                                'd' => match state.indicator_type {
+                                    IndicatorType::Percentage => {
+                                        state.buffer =
+                                            Self::format_value_for_unit(75.0, &state.unit);
+                                    }
+                                    IndicatorType::Boolean => {}
+                                    _ => {
+                                        let delta = Self::indicator_large_delta(&state);
+                                        if delta > 0.0 {
+                                            state.buffer = Self::adjust_buffer_value(
+                                                &state.buffer,
+                                                &state.unit,
+                                                delta,
+                                            );
+                                        }
+                                    }
+                                },
+                                'f' => {
+                                    if state.indicator_type == IndicatorType::Percentage {
+                                        state.buffer =
+                                            Self::format_value_for_unit(100.0, &state.unit);
+                                    }
+                                }
+                                'y' => {
+                                    if state.indicator_type == IndicatorType::Boolean {
+                                        state.buffer =
+                                            Self::format_value_for_unit(1.0, &state.unit);
+                                    }
+                                }
+                                'n' => {
+                                    if state.indicator_type == IndicatorType::Boolean {
+                                        state.buffer =
+                                            Self::format_value_for_unit(0.0, &state.unit);
+                                    }
+                                }
+                                _ => {
+                                    // Digits plus unit suffixes like "1.5h" or "1.2k"
+                                    if crate::units::accepts(&state.buffer, ch) {
+                                        state.buffer.push(ch);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            if self.modal.is_some() {
+                self.modal = Some(ModalState::IndicatorUpdate(state));
+            }
+
+            return Ok(true);
+        }
+
+        if let Some(ModalState::QuickObservation(mut state)) = self.modal.clone() {
+            let matches = self.quick_observation_matches(&state.query);
+
+            match key {
+                KeyCode::Esc => {
+                    self.modal = None;
+                    return Ok(true);
+                }
+                KeyCode::Tab | KeyCode::BackTab => {
+                    state.field = match state.field {
+                        QuickObservationField::Indicator if !matches.is_empty() => {
+                            QuickObservationField::Value
+                        }
+                        _ => QuickObservationField::Indicator,
+                    };
+                }
+                KeyCode::Up if state.field == QuickObservationField::Indicator => {
+                    state.selection = state.selection.saturating_sub(1);
+                }
+                KeyCode::Down
+                    if state.field == QuickObservationField::Indicator
+                        && state.selection + 1 < matches.len() =>
+                {
+                    state.selection += 1;
+                }
+                KeyCode::Enter => match state.field {
+                    QuickObservationField::Indicator => {
+                        if !matches.is_empty() {
+                            state.field = QuickObservationField::Value;
+                        }
+                    }
+                    QuickObservationField::Value => {
+                        if let Some(&index) = matches.get(state.selection) {
+                            let indicator_id = self.indicators.indicators[index].id.clone();
+                            let unit = self.indicators.indicators[index].unit.clone();
+                            if state.value.trim().is_empty() {
+                                self.toasts
+                                    .show_error("Enter a value before saving".to_string());
+                            } else {
+                                match Self::parse_value_from_buffer(&state.value, &unit) {
+                                    Ok(value) => {
+                                        self.record_indicator_observation(
+                                            &indicator_id,
+                                            &unit,
+                                            value,
+                                            None,
+                                        )?;
+                                        self.modal = None;
+                                        return Ok(true);
+                                    }
+                                    Err(err) => self
+                                        .toasts
+                                        .show_error(format!("Invalid indicator value: {}", err)),
+                                }
+                            }
+                        }
+                    }
+                },
+                KeyCode::Backspace => match state.field {
+                    QuickObservationField::Indicator => {
+                        state.query.pop();
+                        state.selection = 0;
+                    }
+                    QuickObservationField::Value => {
+                        state.value.pop();
+                    }
+                },
+                KeyCode::Char(ch) => match state.field {
+                    QuickObservationField::Indicator => {
+                        state.query.push(ch);
+                        state.selection = 0;
+                    }
+                    QuickObservationField::Value => {
+                        if crate::units::accepts(&state.value, ch) {
+                            state.value.push(ch);
+                        }
+                    }
+                },
+                _ => {}
+            }
+
+            if self.modal.is_some() {
+                self.modal = Some(ModalState::QuickObservation(state));
+            }
+
+            return Ok(true);
+        }
+
+        if let Some(ModalState::CheckIn(mut state)) = self.modal.clone() {
+            match key {
+                KeyCode::Esc => {
+                    self.modal = None;
+                    return Ok(true);
+                }
+                KeyCode::Enter => {
+                    if state.mood.is_none() && state.energy.is_none() {
+                        self.toasts
+                            .show_warning("Rate mood or energy from 1 to 5 first".to_string());
+                    } else {
+                        self.modal = None;
+                        self.save_check_in(&state)?;
+                        return Ok(true);
+                    }
+                }
+                KeyCode::Char(ch @ '1'..='5') => {
+                    *state.current() = ch.to_digit(10).map(|d| d as u8);
+                    state.editing_energy = true;
+                }
+                KeyCode::Left | KeyCode::Char('h') => {
+                    let rating = state.current();
+                    *rating = Some(
+                        rating
+                            .map_or(CHECK_IN_MIN, |r| r.saturating_sub(1))
+                            .max(CHECK_IN_MIN),
+                    );
+                }
+                KeyCode::Right | KeyCode::Char('l') => {
+                    let rating = state.current();
+                    *rating = Some(rating.map_or(CHECK_IN_MAX, |r| r + 1).min(CHECK_IN_MAX));
+                }
+                KeyCode::Up
+                | KeyCode::Down
+                | KeyCode::Tab
+                | KeyCode::Char('j')
+                | KeyCode::Char('k') => {
+                    state.editing_energy = !state.editing_energy;
+                }
+                _ => {}
+            }
+            self.modal = Some(ModalState::CheckIn(state));
+            return Ok(true);
+        }
+
+        if let Some(ModalState::LinkSuggestion(suggestion)) = self.modal.clone() {
+            match key {
+                KeyCode::Char('y') | KeyCode::Enter => {
+                    self.modal = None;
+                    if !self.link_action_to_objective(
+                        suggestion.outcome_type,
+                        suggestion.action_index,
+                        &suggestion.objective_id,
+                    )? {
+                        return Ok(true);
+                    }
+                    self.log_activity(
+                        ActivityKind::Objective,
+                        format!("Linked objective: {}", suggestion.title),
+                    );
+                    self.toasts
+                        .show_success(format!("Linked to {}", suggestion.title));
+                }
+                KeyCode::Char('n') | KeyCode::Esc => self.modal = None,
+                _ => {}
+            }
+            return Ok(true);
+        }
+
+        if let Some(ModalState::Journal(mut state)) = self.modal.clone() {
+            if state.searching {
+                match key {
+                    KeyCode::Enter => state.searching = false,
+                    KeyCode::Esc => {
+                        state.searching = false;
+                        state.query.clear();
+                        state.refilter();
+                    }
+                    KeyCode::Backspace => {
+                        state.query.pop();
+                        state.refilter();
+                    }
+                    KeyCode::Char(c) => {
+                        state.query.push(c);
+                        state.refilter();
+                    }
+                    _ => {}
+                }
+                self.modal = Some(ModalState::Journal(state));
+                return Ok(true);
+            }
+            match key {
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('J') => {
+                    self.modal = None;
+                    return Ok(true);
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    state.list.previous(state.results.len());
+                    state.scroll = 0;
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    state.list.next(state.results.len());
+                    state.scroll = 0;
+                }
+                KeyCode::PageUp => state.scroll = state.scroll.saturating_sub(5),
+                KeyCode::PageDown => state.scroll = state.scroll.saturating_add(5),
+                KeyCode::Char('/') => state.searching = true,
+                KeyCode::Enter | KeyCode::Char('e') => {
+                    if let Some(entry) = state.selected() {
+                        self.text_editor.activate_with(
+                            &format!("Reflection for {}", entry.date.format("%a %b %d, %Y")),
+                            &gratitude::split(&entry.text).0,
+                            crate::models::MAX_REFLECTION_LENGTH,
+                        );
+                        self.text_editor.allow_external = true;
+                        self.editor_context = Some(EditorContext::Reflection { date: entry.date });
+                    }
+                }
+                _ => {}
+            }
+            self.modal = Some(ModalState::Journal(state));
+            return Ok(true);
+        }
+
+        if let Some(ModalState::Templates(mut state)) = self.modal.clone() {
+            let confirm_delete = std::mem::take(&mut state.confirm_delete);
+            let selected = templates::list(&state.templates)
+                .get(state.selection)
+                .cloned();
+            if let (Some(template), Some(cursor)) = (selected.clone(), state.action) {
+                let actions = templates::actions(&state.templates, &template);
+                let current = actions.get(cursor);
+                match key {
+                    KeyCode::Esc | KeyCode::Left | KeyCode::Char('h') => state.action = None,
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        state.action = Some(cursor.saturating_sub(1))
+                    }
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        state.action = Some((cursor + 1).min(actions.len().saturating_sub(1)))
+                    }
+                    KeyCode::Enter | KeyCode::Char('e') => {
+                        if let Some(action) = current {
+                            self.text_editor.activate_with(
+                                "Edit template action",
+                                &action.text,
+                                crate::models::MAX_ACTION_LENGTH,
+                            );
+                            self.editor_context = Some(EditorContext::TemplateAction {
+                                template,
+                                outcome: action.outcome,
+                                index: action.index,
+                            });
+                        }
+                    }
+                    KeyCode::Char('n') => {
+                        let outcome = match template {
+                            TemplateRef::Actions(_) => None,
+                            TemplateRef::Weekday(_) => Some(
+                                current
+                                    .and_then(|action| action.outcome)
+                                    .unwrap_or(OutcomeType::Work),
+                            ),
+                        };
+                        let index = actions
+                            .iter()
+                            .filter(|action| action.outcome == outcome)
+                            .count();
+                        self.text_editor.activate_with(
+                            "New template action",
+                            "",
+                            crate::models::MAX_ACTION_LENGTH,
+                        );
+                        self.editor_context = Some(EditorContext::TemplateAction {
+                            template,
+                            outcome,
+                            index,
+                        });
+                    }
+                    KeyCode::Char('x') => {
+                        if let Some(action) = current {
+                            match templates::set_action(
+                                &template,
+                                action.outcome,
+                                action.index,
+                                "",
+                                &self.config,
+                            ) {
+                                Ok(data) => {
+                                    state.templates = data;
+                                    state.action =
+                                        Some(cursor.min(actions.len().saturating_sub(2)));
+                                }
+                                Err(e) => self
+                                    .toasts
+                                    .show_error(format!("Failed to update template: {}", e)),
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+                self.modal = Some(ModalState::Templates(state));
+                return Ok(true);
+            }
+
+            match key {
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('D') => {
+                    self.modal = None;
+                    return Ok(true);
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    state.selection = state.selection.saturating_sub(1)
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    state.selection = (state.selection + 1)
+                        .min(templates::list(&state.templates).len().saturating_sub(1))
+                }
+                KeyCode::Enter | KeyCode::Right | KeyCode::Char('l') if selected.is_some() => {
+                    state.action = Some(0)
+                }
+                KeyCode::Char('r') => {
+                    if let Some(template) = selected {
+                        self.text_editor.activate_with(
+                            "Rename template",
+                            template.name(),
+                            crate::models::MAX_GOAL_LENGTH,
+                        );
+                        self.editor_context = Some(EditorContext::TemplateName { template });
+                    }
+                }
+                KeyCode::Char('a') => {
+                    if let Some(template) = selected {
+                        self.apply_template(&state.templates, &template)?;
+                    }
+                }
+                KeyCode::Char('d') => {
+                    if let Some(template) = selected {
+                        if confirm_delete {
+                            match templates::delete(&template, &self.config) {
+                                Ok(data) => {
+                                    state.selection = state
+                                        .selection
+                                        .min(templates::list(&data).len().saturating_sub(1));
+                                    state.templates = data;
+                                    self.toasts.show_info(format!(
+                                        "Deleted template '{}'",
+                                        template.name()
+                                    ));
+                                }
+                                Err(e) => self
+                                    .toasts
+                                    .show_error(format!("Failed to delete template: {}", e)),
+                            }
+                        } else {
+                            state.confirm_delete = true;
+                            self.toasts.show_warning(format!(
+                                "Press d again to delete '{}'",
+                                template.name()
+                            ));
+                        }
+                    }
+                }
+                _ => {}
+            }
+            self.modal = Some(ModalState::Templates(state));
+            return Ok(true);
+        }
+
+        if let Some(ModalState::Recovery(state)) = self.modal.clone() {
+            let recover = match key {
+                KeyCode::Enter | KeyCode::Char('y') => true,
+                KeyCode::Esc | KeyCode::Char('n') => false,
+                _ => return Ok(true),
+            };
+            self.modal = None;
+            if state.carry_over {
+                // The editor of a recovered action opens on top of it
+                self.open_carry_over_wizard();
+            }
+            if recover {
+                self.recover_edit(state.journal.edit)?;
+            }
+            return Ok(true);
+        }
+
+        if let Some(ModalState::SyncMerge(mut state)) = self.modal.clone() {
+            let last = state.choices.len().saturating_sub(1);
+            let choose = |state: &mut SyncMergeState, side: Side| {
+                state.choices[state.hunk] = Some(side);
+                state.hunk = (state.hunk + 1).min(last);
+            };
+            match key {
+                KeyCode::Esc | KeyCode::Char('q') => {
+                    self.toasts.show_warning(format!(
+                        "{} file(s) left in conflict; run :sync again to resolve them",
+                        state.conflicts.len()
+                    ));
+                    self.modal = None;
+                    return Ok(true);
+                }
+                KeyCode::Up | KeyCode::Char('k') => state.hunk = state.hunk.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => state.hunk = (state.hunk + 1).min(last),
+                KeyCode::Char('m') => choose(&mut state, Side::Local),
+                KeyCode::Char('t') => choose(&mut state, Side::Remote),
+                KeyCode::Char('s') if !state.next_file() => {
+                    self.modal = None;
+                    return Ok(true);
+                }
+                KeyCode::Enter => {
+                    let choices: Option<Vec<Side>> = state.choices.iter().copied().collect();
+                    let Some(choices) = choices else {
+                        self.toasts
+                            .show_warning("Choose mine or theirs for every part first".to_string());
+                        self.modal = Some(ModalState::SyncMerge(state));
+                        return Ok(true);
+                    };
+                    let conflict = state.conflicts[0].clone();
+                    match sync::resolve(&conflict, &choices, &state.target, &self.config) {
+                        Ok(()) => {
+                            self.reload_data_files()?;
+                            self.toasts
+                                .show_success(format!("Resolved {}", conflict.file));
+                        }
+                        Err(e) => self
+                            .toasts
+                            .show_error(format!("Failed to resolve {}: {}", conflict.file, e)),
+                    }
+                    if !state.next_file() {
+                        self.modal = None;
+                        return Ok(true);
+                    }
+                }
+                _ => {}
+            }
+            self.modal = Some(ModalState::SyncMerge(state));
+            return Ok(true);
+        }
+
+        if let Some(ModalState::Trash(mut state)) = self.modal.clone() {
+            let confirm_purge = std::mem::take(&mut state.confirm_purge);
+            match key {
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('Z') => {
+                    self.modal = None;
+                    return Ok(true);
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    state.selection = state.selection.saturating_sub(1)
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    state.selection =
+                        (state.selection + 1).min(state.entries.len().saturating_sub(1))
+                }
+                KeyCode::Enter | KeyCode::Char('r') => {
+                    if let Some(entry) = state.entries.get(state.selection).cloned() {
+                        self.restore_trashed(&entry)?;
+                        state.entries.retain(|e| *e != entry);
+                    }
+                }
+                KeyCode::Char('d') => {
+                    if let Some(entry) = state.entries.get(state.selection).cloned() {
+                        if confirm_purge {
+                            self.purge_trashed(&entry)?;
+                            state.entries.retain(|e| *e != entry);
+                        } else {
+                            state.confirm_purge = true;
+                            self.toasts.show_warning(format!(
+                                "Press d again to delete '{}' for good",
+                                entry.name
+                            ));
+                        }
+                    }
+                }
+                _ => {}
+            }
+            if state.entries.is_empty() {
+                self.modal = None;
+                return Ok(true);
+            }
+            state.selection = state.selection.min(state.entries.len() - 1);
+            self.modal = Some(ModalState::Trash(state));
+            return Ok(true);
+        }
+
+        if let Some(ModalState::Backups(mut state)) = self.modal.clone() {
+            let confirm_restore = std::mem::take(&mut state.confirm_restore);
+            match key {
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('U') => {
+                    self.modal = None;
+                    return Ok(true);
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    state.selection = state.selection.saturating_sub(1)
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    state.selection =
+                        (state.selection + 1).min(state.backups.len().saturating_sub(1))
+                }
+                KeyCode::Enter => {
+                    if let Some(backup) = state.backups.get(state.selection).cloned() {
+                        if confirm_restore {
+                            self.modal = None;
+                            self.restore_backup(&backup)?;
+                            return Ok(true);
+                        }
+                        state.confirm_restore = true;
+                        self.toasts.show_warning(format!(
+                            "Press Enter again to replace {} with the copy from {}",
+                            backup.file,
+                            backup.taken.format("%b %d %H:%M:%S")
+                        ));
+                    }
+                }
+                _ => {}
+            }
+            self.modal = Some(ModalState::Backups(state));
+            return Ok(true);
+        }
+
+        if let Some(ModalState::CloseDay(_)) = self.modal {
+            match key {
+                KeyCode::Enter | KeyCode::Char('y') => {
+                    self.modal = None;
+                    self.close_day()?;
+                }
+                KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('q') => self.modal = None,
+                _ => {}
+            }
+            return Ok(true);
+        }
+
+        if let Some(ModalState::Morning(mut state)) = self.modal.clone() {
+            match key {
+                KeyCode::Esc | KeyCode::Char('q') => {
+                    self.modal = None;
+                    self.toasts
+                        .show_info("Morning intentions discarded".to_string());
+                    return Ok(true);
+                }
+                KeyCode::Left | KeyCode::Char('h') => {
+                    state.step = state.step.previous();
+                    state.selection = 0;
+                }
+                KeyCode::Enter | KeyCode::Char('s') if state.step == MorningStep::Confirm => {
+                    self.modal = None;
+                    if self.save_morning_plan(&state.plan).is_err() {
+                        self.modal = Some(ModalState::Morning(state));
+                    }
+                    return Ok(true);
+                }
+                _ => match state.step {
+                    MorningStep::Priority(page) => {
+                        let intention = &mut state.plan.intentions[page];
+                        let outcome_type = intention.outcome_type;
+                        match key {
+                            KeyCode::Up | KeyCode::Char('k') => {
+                                state.selection = state.selection.saturating_sub(1)
+                            }
+                            KeyCode::Down | KeyCode::Char('j') => {
+                                state.selection = (state.selection + 1)
+                                    .min(intention.actions.len().saturating_sub(1))
+                            }
+                            KeyCode::Char(' ') | KeyCode::Enter => {
+                                if let Some(&(index, _)) = intention.actions.get(state.selection) {
+                                    intention.choice = Some(morning::Choice::Existing(index));
+                                }
+                                if key == KeyCode::Enter {
+                                    state.step = state.step.next();
+                                    state.selection = 0;
+                                }
+                            }
+                            KeyCode::Char('x') => intention.choice = None,
+                            KeyCode::Char('n') => {
+                                let text = match &intention.choice {
+                                    Some(morning::Choice::New(text)) => text.clone(),
+                                    _ => String::new(),
+                                };
+                                self.text_editor.activate_with(
+                                    &format!("New {} priority", outcome_type.as_str()),
+                                    &text,
+                                    crate::models::MAX_GOAL_LENGTH,
+                                );
+                                self.editor_context =
+                                    Some(EditorContext::MorningAction { outcome_type });
+                            }
+                            KeyCode::Right | KeyCode::Char('l') => {
+                                state.step = state.step.next();
+                                state.selection = 0;
+                            }
+                            _ => {}
+                        }
+                    }
+                    MorningStep::Estimates => {
+                        let intention = &mut state.plan.intentions[state.selection];
+                        let outcome_type = intention.outcome_type;
+                        match key {
+                            KeyCode::Up | KeyCode::Char('k') => {
+                                state.selection = state.selection.saturating_sub(1)
+                            }
+                            KeyCode::Down | KeyCode::Char('j') => {
+                                state.selection =
+                                    (state.selection + 1).min(morning::OUTCOMES.len() - 1)
+                            }
+                            KeyCode::Char('+') | KeyCode::Char('=')
+                                if intention.choice.is_some() =>
+                            {
+                                intention.estimate_min =
+                                    Some(intention.estimate_min.unwrap_or(0) + 15)
+                            }
+                            KeyCode::Char('-') => {
+                                intention.estimate_min = intention
+                                    .estimate_min
+                                    .map(|minutes| minutes.saturating_sub(15))
+                                    .filter(|minutes| *minutes > 0)
+                            }
+                            KeyCode::Char('e') if intention.choice.is_some() => {
+                                self.text_editor.activate_with(
+                                    &format!(
+                                        "{} estimate (e.g. 45m, 1h30m)",
+                                        outcome_type.as_str()
+                                    ),
+                                    &intention
+                                        .estimate_min
+                                        .map(crate::models::format_minutes)
+                                        .unwrap_or_default(),
+                                    10,
+                                );
+                                self.editor_context =
+                                    Some(EditorContext::MorningEstimate { outcome_type });
+                            }
+                            KeyCode::Right | KeyCode::Char('l') | KeyCode::Enter => {
+                                state.step = state.step.next();
+                                state.selection = 0;
+                            }
+                            _ => {}
+                        }
+                    }
+                    MorningStep::Review | MorningStep::Confirm => {
+                        if matches!(key, KeyCode::Right | KeyCode::Char('l') | KeyCode::Enter) {
+                            state.step = state.step.next();
+                            state.selection = 0;
+                        }
+                    }
+                },
+            }
+            self.modal = Some(ModalState::Morning(state));
+            return Ok(true);
+        }
+
+        if let Some(ModalState::QuarterlyCheckIn(mut state)) = self.modal.clone() {
+            let domain = &mut state.checkin.domains[state.step];
+            let outcome_type = domain.outcome_type;
+            match key {
+                KeyCode::Esc | KeyCode::Char('q') => {
+                    self.modal = None;
+                    self.toasts
+                        .show_info("Quarterly check-in discarded".to_string());
+                    return Ok(true);
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    state.selection = state.selection.saturating_sub(1)
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    state.selection =
+                        (state.selection + 1).min(domain.objectives.len().saturating_sub(1))
+                }
+                KeyCode::Char('y') | KeyCode::Char('n') => {
+                    let alignment = if key == KeyCode::Char('y') {
+                        quarterly::Alignment::Aligned
+                    } else {
+                        quarterly::Alignment::Drifting
+                    };
+                    if let Some(objective) = domain.objectives.get_mut(state.selection) {
+                        objective.alignment = alignment;
+                        state.selection =
+                            (state.selection + 1).min(domain.objectives.len().saturating_sub(1));
+                    }
+                }
+                KeyCode::Char('e') => {
+                    self.text_editor.activate_with(
+                        &format!("{} alignment notes", outcome_type.as_str()),
+                        &domain.note,
+                        crate::models::MAX_VISION_LENGTH,
+                    );
+                    self.editor_context = Some(EditorContext::AlignmentNote { outcome_type });
+                }
+                KeyCode::Left | KeyCode::Char('h') if state.step > 0 => {
+                    state.step -= 1;
+                    state.selection = 0;
+                }
+                KeyCode::Right | KeyCode::Char('l') | KeyCode::Enter
+                    if state.step + 1 < quarterly::DOMAINS.len() =>
+                {
+                    state.step += 1;
+                    state.selection = 0;
+                }
+                KeyCode::Char('s') | KeyCode::Enter => {
+                    match quarterly::save(&state.checkin, &self.config) {
+                        Ok(path) => {
+                            let drifting = state.checkin.drifting();
+                            self.toasts.show_info(format!(
+                                "Check-in saved to {}{}",
+                                path.display(),
+                                if drifting > 0 {
+                                    format!(" ({} objectives drifting)", drifting)
+                                } else {
+                                    String::new()
+                                }
+                            ));
+                            self.modal = None;
+                            return Ok(true);
+                        }
+                        Err(e) => self
+                            .toasts
+                            .show_error(format!("Failed to save check-in: {}", e)),
+                    }
+                }
+                _ => {}
+            }
+            self.modal = Some(ModalState::QuarterlyCheckIn(state));
+            return Ok(true);
+        }
+
+        if let Some(ModalState::VisionHistory(mut state)) = self.modal.clone() {
+            let position = WEEKLY_OUTCOMES
+                .iter()
+                .position(|outcome_type| *outcome_type == state.outcome_type)
+                .unwrap_or(0);
+            match key {
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('V') => {
+                    self.modal = None;
+                    return Ok(true);
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    state.selection = state.selection.saturating_sub(1)
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    state.selection =
+                        (state.selection + 1).min(state.changes.len().saturating_sub(1))
+                }
+                KeyCode::Tab | KeyCode::Right | KeyCode::Char('l') => {
+                    state.show_outcome(WEEKLY_OUTCOMES[(position + 1) % 3])
+                }
+                KeyCode::BackTab | KeyCode::Left | KeyCode::Char('h') => {
+                    state.show_outcome(WEEKLY_OUTCOMES[(position + 2) % 3])
+                }
+                _ => {}
+            }
+            self.modal = Some(ModalState::VisionHistory(state));
+            return Ok(true);
+        }
+
+        if let Some(ModalState::Projects(mut state)) = self.modal.clone() {
+            let confirm_delete = std::mem::take(&mut state.confirm_delete);
+            let project_id = state
+                .projects
+                .projects
+                .get(state.selection)
+                .map(|project| project.id.clone());
+            if let Some(selected) = state.assigning {
+                let count = self.objectives.objectives.len();
+                match key {
+                    KeyCode::Esc | KeyCode::Char('q') => state.assigning = None,
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        state.assigning = Some(selected.saturating_sub(1))
+                    }
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        state.assigning = Some((selected + 1).min(count.saturating_sub(1)))
+                    }
+                    KeyCode::Char(' ') | KeyCode::Enter => {
+                        let objective_id = self
+                            .objectives
+                            .objectives
+                            .get(selected)
+                            .map(|objective| objective.id.clone());
+                        if let (Some(project_id), Some(objective_id)) = (project_id, objective_id) {
+                            match projects::toggle_objective(
+                                &project_id,
+                                &objective_id,
+                                &self.config,
+                            ) {
+                                Ok(data) => state.projects = data,
+                                Err(e) => self
+                                    .toasts
+                                    .show_error(format!("Failed to update project: {}", e)),
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+                self.modal = Some(ModalState::Projects(state));
+                return Ok(true);
+            }
+
+            match key {
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('G') => {
+                    self.modal = None;
+                    return Ok(true);
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    state.selection = state.selection.saturating_sub(1)
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    state.selection =
+                        (state.selection + 1).min(state.projects.projects.len().saturating_sub(1))
+                }
+                KeyCode::Char('n') => {
+                    self.text_editor.activate_with(
+                        "New project",
+                        "",
+                        crate::models::MAX_GOAL_LENGTH,
+                    );
+                    self.editor_context = Some(EditorContext::ProjectTitle { project_id: None });
+                }
+                KeyCode::Char('r') => {
+                    if let Some(project) = state.projects.projects.get(state.selection) {
+                        self.text_editor.activate_with(
+                            "Rename project",
+                            &project.title,
+                            crate::models::MAX_GOAL_LENGTH,
+                        );
+                        self.editor_context = Some(EditorContext::ProjectTitle {
+                            project_id: project_id.clone(),
+                        });
+                    }
+                }
+                KeyCode::Enter | KeyCode::Char('a') if project_id.is_some() => {
+                    if self.objectives.objectives.is_empty() {
+                        self.toasts
+                            .show_warning("Create objectives first with o".to_string());
+                    } else {
+                        state.assigning = Some(0);
+                    }
+                }
+                KeyCode::Char('d') => {
+                    if let Some(project_id) = project_id {
+                        if confirm_delete {
+                            match projects::delete_project(&project_id, &self.config) {
+                                Ok(data) => {
+                                    state.projects = data;
+                                    state.selection = state
+                                        .selection
+                                        .min(state.projects.projects.len().saturating_sub(1));
+                                }
+                                Err(e) => self
+                                    .toasts
+                                    .show_error(format!("Failed to delete project: {}", e)),
+                            }
+                        } else {
+                            state.confirm_delete = true;
+                            self.toasts.show_warning(
+                                "Press d again to delete the project; its objectives are kept"
+                                    .to_string(),
+                            );
+                        }
+                    }
+                }
+                _ => {}
+            }
+            self.modal = Some(ModalState::Projects(state));
+            return Ok(true);
+        }
+
+        if let Some(ModalState::YearReview(mut state)) = self.modal.clone() {
+            match key {
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('A') => {
+                    self.modal = None;
+                    return Ok(true);
+                }
+                KeyCode::Up | KeyCode::Char('k') => state.scroll = state.scroll.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => state.scroll = state.scroll.saturating_add(1),
+                KeyCode::Left | KeyCode::Char('h') => {
+                    self.open_year_review(state.review.year - 1);
+                    return Ok(true);
+                }
+                KeyCode::Right | KeyCode::Char('l') => {
+                    self.open_year_review(state.review.year + 1);
+                    return Ok(true);
+                }
+                KeyCode::Char('x') => {
+                    match year_review::write_export(&state.review, &self.config) {
+                        Ok(path) => self
+                            .toasts
+                            .show_success(format!("Exported to {}", path.display())),
+                        Err(e) => self.toasts.show_error(format!("Export failed: {}", e)),
+                    }
+                }
+                _ => {}
+            }
+            self.modal = Some(ModalState::YearReview(state));
+            return Ok(true);
+        }
+
+        if let Some(ModalState::WeeklyPlan(mut state)) = self.modal.clone() {
+            match key {
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('W') => {
+                    self.modal = None;
+                    return Ok(true);
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    state.selection = state.selection.saturating_sub(1);
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    state.selection = (state.selection + 1).min(2);
+                }
+                KeyCode::Left | KeyCode::Char('h') | KeyCode::Right | KeyCode::Char('l') => {
+                    let days = if matches!(key, KeyCode::Left | KeyCode::Char('h')) {
+                        -7
+                    } else {
+                        7
+                    };
+                    if let Some(start) = state.weekly.start() {
+                        let week_iso = iso_week_of(start + chrono::Duration::days(days));
+                        match crate::data::load_weekly_goals(week_iso, &self.config) {
+                            Ok(weekly) => state.weekly = weekly,
+                            Err(e) => self
+                                .toasts
+                                .show_error(format!("Could not load that week: {}", e)),
+                        }
+                    }
+                }
+                KeyCode::Enter | KeyCode::Char('e') => {
+                    let outcome_type = WEEKLY_OUTCOMES[state.selection];
+                    self.text_editor.activate_with(
+                        &format!(
+                            "{} focus for {}-W{:02}",
+                            outcome_type.as_str(),
+                            state.weekly.year,
+                            state.weekly.week
+                        ),
+                        state.weekly.get_focus(outcome_type),
+                        crate::models::MAX_WEEKLY_FOCUS_LENGTH,
+                    );
+                    self.editor_context = Some(EditorContext::WeeklyFocus {
+                        week_iso: state.weekly.week_iso(),
+                        outcome_type,
+                    });
+                }
+                _ => {}
+            }
+            self.modal = Some(ModalState::WeeklyPlan(state));
+            return Ok(true);
+        }
+
+        if let Some(ModalState::Timeline(mut state)) = self.modal.clone() {
+            let step = i64::from(timeline::SLOT_MIN);
+            let selected = state.blocks.get(state.selection).cloned();
+            // New start and length for the selected block
+            let change = match (key, &selected) {
+                (KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('T'), _) => {
+                    self.modal = None;
+                    return Ok(true);
+                }
+                (KeyCode::Up | KeyCode::Char('k'), _) => {
+                    state.selection = state.selection.saturating_sub(1);
+                    None
+                }
+                (KeyCode::Down | KeyCode::Char('j'), _) => {
+                    state.selection =
+                        (state.selection + 1).min(state.blocks.len().saturating_sub(1));
+                    None
+                }
+                (KeyCode::Enter | KeyCode::Char('s'), Some(block)) if block.start.is_none() => {
+                    let hours = self.settings.working_hours;
+                    let start = timeline::first_free_slot(&state.blocks, block.minutes, hours);
+                    Some((Some(start), None))
+                }
+                (KeyCode::Char('x') | KeyCode::Backspace | KeyCode::Delete, Some(_)) => {
+                    Some((None, None))
+                }
+                (KeyCode::Char(c @ ('h' | 'l' | 'H' | 'L')), Some(block)) => {
+                    let minutes = match c {
+                        'h' => -step,
+                        'l' => step,
+                        'H' => -60,
+                        _ => 60,
+                    };
+                    block
+                        .start
+                        .map(|start| (Some(timeline::shift_time(start, minutes)), None))
+                }
+                (KeyCode::Char('[') | KeyCode::Char(']'), Some(block)) => {
+                    let minutes = if key == KeyCode::Char('[') {
+                        block.minutes.saturating_sub(timeline::SLOT_MIN)
+                    } else {
+                        block.minutes + timeline::SLOT_MIN
+                    };
+                    Some((block.start, Some(minutes)))
+                }
+                _ => None,
+            };
+
+            if let (Some((start, minutes)), Some(block)) = (change, selected) {
+                self.reschedule(&block, start, minutes)?;
+                state.blocks = timeline::blocks(&self.goals, &self.config)?;
+                state.selection = state
+                    .blocks
+                    .iter()
+                    .position(|b| b.outcome_type == block.outcome_type && b.index == block.index)
+                    .unwrap_or(0);
+            }
+            self.modal = Some(ModalState::Timeline(state));
+            return Ok(true);
+        }
+
+        if let Some(ModalState::Board(mut state)) = self.modal.clone() {
+            let last_column = board::COLUMNS.len() - 1;
+            match key {
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('K') => {
+                    self.modal = None;
+                    return Ok(true);
+                }
+                KeyCode::Left => state.column = state.column.saturating_sub(1),
+                KeyCode::Right => state.column = (state.column + 1).min(last_column),
+                KeyCode::Up | KeyCode::Char('k') => state.row = state.row.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => state.row += 1,
+                KeyCode::Char('h') | KeyCode::Char('l') => {
+                    let target = match key {
+                        KeyCode::Char('h') => state.column.checked_sub(1),
+                        _ => Some(state.column + 1).filter(|c| *c <= last_column),
+                    };
+                    if let (Some(card), Some(target)) = (state.selected().cloned(), target) {
+                        self.move_card(&card, board::COLUMNS[target])?;
+                        state.cards = board::cards(&self.goals, &self.config)?;
+                        state.column = target;
+                        state.row = state
+                            .column_cards(target)
+                            .iter()
+                            .position(|c| {
+                                c.outcome_type == card.outcome_type && c.index == card.index
+                            })
+                            .unwrap_or(0);
+                    }
+                }
+                _ => {}
+            }
+            state.clamp_row();
+            self.modal = Some(ModalState::Board(state));
+            return Ok(true);
+        }
+
+        if let Some(ModalState::BlockerPicker(mut state)) = self.modal.clone() {
+            match key {
+                KeyCode::Esc => {
+                    self.modal = None;
+                    return Ok(true);
+                }
+                KeyCode::Enter => {
+                    self.modal = None;
+                    let blocker = state.candidates.get(state.selection).cloned();
+                    self.set_blocker(state.outcome_type, state.index, blocker)?;
+                    return Ok(true);
+                }
+                KeyCode::Char('x') | KeyCode::Backspace | KeyCode::Delete => {
+                    self.modal = None;
+                    self.set_blocker(state.outcome_type, state.index, None)?;
+                    return Ok(true);
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    state.selection = state.selection.saturating_sub(1)
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    state.selection =
+                        (state.selection + 1).min(state.candidates.len().saturating_sub(1))
+                }
+                _ => {}
+            }
+            self.modal = Some(ModalState::BlockerPicker(state));
+            return Ok(true);
+        }
+
+        if let Some(ModalState::Snooze(mut state)) = self.modal.clone() {
+            let tomorrow = self.goals.date + chrono::Duration::days(1);
+            match key {
+                KeyCode::Esc => {
+                    self.modal = None;
+                    return Ok(true);
+                }
+                KeyCode::Enter => {
+                    self.modal = None;
+                    self.snooze_selected(&state)?;
+                    return Ok(true);
+                }
+                KeyCode::Right | KeyCode::Char('l') | KeyCode::Char('+') | KeyCode::Char('z') => {
+                    state.until += chrono::Duration::days(1)
+                }
+                KeyCode::Left | KeyCode::Char('h') | KeyCode::Char('-') => {
+                    state.until = (state.until - chrono::Duration::days(1)).max(tomorrow)
+                }
+                KeyCode::Char('w') => state.until += chrono::Duration::days(7),
+                _ => {}
+            }
+            self.modal = Some(ModalState::Snooze(state));
+            return Ok(true);
+        }
+
+        if let Some(ModalState::CarryOver(mut state)) = self.modal.clone() {
+            let today = self.goals.date;
+            let choice = |state: &mut CarryOverState, choice: CarryChoice| {
+                if let Some(item) = state.items.get_mut(state.index) {
+                    item.choice = choice;
+                }
+            };
+            match key {
+                KeyCode::Esc => {
+                    self.modal = None;
+                    self.toasts
+                        .show_info("Yesterday's actions left as they were".to_string());
+                    return Ok(true);
+                }
+                KeyCode::Enter => {
+                    self.modal = None;
+                    self.apply_carry_over(&state.items)?;
+                    return Ok(true);
+                }
+                KeyCode::Up | KeyCode::Char('k') => state.index = state.index.saturating_sub(1),
+                KeyCode::Down | KeyCode::Char('j') => {
+                    state.index = (state.index + 1).min(state.items.len().saturating_sub(1))
+                }
+                KeyCode::Char('c') => choice(&mut state, CarryChoice::Carry),
+                KeyCode::Char('x') => choice(&mut state, CarryChoice::Drop),
+                KeyCode::Char('z') | KeyCode::Right | KeyCode::Char('+') => {
+                    state.shift_snooze(today, 1)
+                }
+                KeyCode::Left | KeyCode::Char('-') => state.shift_snooze(today, -1),
+                _ => {}
+            }
+            self.modal = Some(ModalState::CarryOver(state));
+            return Ok(true);
+        }
+
+        if matches!(
+            self.modal,
+            Some(
+                ModalState::CheckInAnalytics(_)
+                    | ModalState::CompletionTimes(_)
+                    | ModalState::Trophies
+            )
+        ) {
+            if matches!(key, KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q')) {
+                self.modal = None;
+            }
+            return Ok(true);
+        }
+
+        if let Some(ModalState::Help(state)) = &mut self.modal {
+            let last = self
+                .keymap
+                .search(state.mode, &state.query)
+                .len()
+                .saturating_sub(1);
+            let mut close = false;
+            match key {
+                KeyCode::Esc if !state.query.is_empty() => state.query.clear(),
+                KeyCode::Esc | KeyCode::Enter => close = true,
+                KeyCode::Char('?') if state.query.is_empty() => close = true,
+                KeyCode::Down => state.scroll = (state.scroll + 1).min(last),
+                KeyCode::Up => state.scroll = state.scroll.saturating_sub(1),
+                KeyCode::PageDown => state.scroll = (state.scroll + 10).min(last),
+                KeyCode::PageUp => state.scroll = state.scroll.saturating_sub(10),
+                KeyCode::Backspace => {
+                    state.query.pop();
+                    state.scroll = 0;
+                }
+                KeyCode::Char(c) => {
+                    state.query.push(c);
+                    state.scroll = 0;
+                }
+                _ => {}
+            }
+            if close {
+                self.modal = None;
+            }
+            return Ok(true);
+        }
+
+        if let Some(ModalState::WhatsNew(state)) = &mut self.modal {
+            let last = state
+                .releases
+                .iter()
+                .map(|release| release.features.len() + 2)
+                .sum::<usize>()
+                .saturating_sub(1);
+            match key {
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                    self.modal = None;
+                    self.mark_changelog_seen();
+                }
+                KeyCode::Down | KeyCode::Char('j') => state.scroll = (state.scroll + 1).min(last),
+                KeyCode::Up | KeyCode::Char('k') => state.scroll = state.scroll.saturating_sub(1),
+                _ => {}
+            }
+            return Ok(true);
+        }
+
+        if let Some(ModalState::Messages(scroll)) = self.modal {
+            let last = self.toasts.history().len().saturating_sub(1);
+            self.modal = match key {
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') | KeyCode::Char('!') => None,
+                KeyCode::Down | KeyCode::Char('j') => {
+                    Some(ModalState::Messages((scroll + 1).min(last)))
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    Some(ModalState::Messages(scroll.saturating_sub(1)))
+                }
+                _ => Some(ModalState::Messages(scroll)),
+            };
+            return Ok(true);
+        }
+
+        if matches!(self.modal, Some(ModalState::Compare(_))) {
+            let preset = match key {
+                KeyCode::Esc | KeyCode::Enter => {
+                    self.modal = None;
+                    None
+                }
+                KeyCode::Char('w') => Some(ComparePreset::Week),
+                KeyCode::Char('m') => Some(ComparePreset::Month),
+                KeyCode::Char('q') => Some(ComparePreset::Quarter),
+                _ => None,
+            };
+            if let Some(preset) = preset {
+                self.open_compare(&CompareRanges::Preset(preset));
+            }
+            return Ok(true);
+        }
+
+        if let Some(ModalState::Retarget(mut state)) = self.modal.clone() {
+            if state.confirming {
+                match key {
+                    KeyCode::Enter | KeyCode::Char('y') => {
+                        self.modal = None;
+                        self.apply_retarget(&state)?;
+                        return Ok(true);
+                    }
+                    KeyCode::Esc | KeyCode::Char('n') => state.confirming = false,
+                    _ => {}
+                }
+                self.modal = Some(ModalState::Retarget(state));
+                return Ok(true);
+            }
+
+            match key {
+                KeyCode::Esc => {
+                    self.modal = None;
+                    self.toasts
+                        .show_info("Re-target cancelled; nothing changed".to_string());
+                    return Ok(true);
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    state.commit_buffer();
+                    state.select(state.index.saturating_sub(1));
+                }
+                KeyCode::Down | KeyCode::Char('j') => {
+                    state.commit_buffer();
+                    state.select(state.index + 1);
+                }
+                KeyCode::Char(' ') => {
+                    if let Some(item) = state.items.get_mut(state.index) {
+                        item.accepted = !item.accepted;
+                    }
+                }
+                KeyCode::Enter | KeyCode::Char('n') => {
+                    let accept = key == KeyCode::Enter;
+                    state.commit_buffer();
+                    if let Some(item) = state.items.get_mut(state.index) {
+                        item.accepted = accept
+                            && (item.proposed.is_some()
+                                || matches!(item.subject, RetargetSubject::Objective { .. }));
+                    }
+                    if state.index + 1 >= state.items.len() {
+                        state.confirming = true;
+                    } else {
+                        state.select(state.index + 1);
+                    }
+                }
+                KeyCode::Backspace => {
+                    state.buffer.pop();
+                }
+                KeyCode::Char(ch) if ch.is_ascii_digit() || ch == '.' => {
+                    let is_indicator = matches!(
+                        state.items.get(state.index).map(|item| &item.subject),
+                        Some(RetargetSubject::Indicator { .. })
+                    );
+                    if is_indicator && (ch != '.' || !state.buffer.contains('.')) {
+                        state.buffer.push(ch);
+                    }
+                }
+                _ => {}
+            }
+
+            self.modal = Some(ModalState::Retarget(state));
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+
+    /// Warn when the day on screen is closed; edits to it wait for a reopen
+    fn day_is_locked(&mut self) -> bool {
+        if self.day_closed {
+            self.toasts.show_warning(format!(
+                "{} is closed; press X to reopen it",
+                self.goals.date.format("%b %d")
+            ));
+        }
+        self.day_closed
+    }
+
+    /// X: ask before closing the day on screen, or reopen a closed one
+    fn close_or_reopen_day(&mut self) -> anyhow::Result<()> {
+        self.flush_pending_save()?;
+        if self.day_closed {
+            closeout::reopen_day(&self.goals, &self.config)?;
+            self.day_closed = false;
+            self.toasts.show_info(format!(
+                "{} reopened for editing",
+                self.goals.date.format("%b %d")
+            ));
+            return Ok(());
+        }
+        let actions: Vec<_> = self
+            .goals
+            .outcomes()
+            .into_iter()
+            .flat_map(|outcome| &outcome.actions)
+            .filter(|action| !action.text.trim().is_empty())
+            .collect();
+        match carryover::unfinished_items(self.goals.date, &self.config) {
+            Ok(unfinished) => {
+                self.modal = Some(ModalState::CloseDay(CloseDayState {
+                    completed: actions.iter().filter(|action| action.completed).count(),
+                    planned: actions.len(),
+                    unfinished,
+                }))
+            }
+            Err(e) => self
+                .toasts
+                .show_error(format!("Could not read the day's actions: {}", e)),
+        }
+        Ok(())
+    }
+
+    fn close_day(&mut self) -> anyhow::Result<()> {
+        let closed = match closeout::close_day(
+            &self.goals,
+            &self.indicators,
+            &self.settings.summary,
+            &self.config,
+        ) {
+            Ok(closed) => closed,
+            Err(e) => {
+                self.toasts
+                    .show_error(format!("Failed to close the day: {}", e));
+                return Err(e);
+            }
+        };
+        self.day_closed = true;
+        self.emit_day_closed();
+        self.refresh_statistics();
+        let mut message = format!(
+            "Closed {}: {}/{} done ({}%)",
+            closed.date.format("%b %d"),
+            closed.completed,
+            closed.planned,
+            closed.percent()
+        );
+        if closed.rolled.carried > 0 {
+            message.push_str(&format!(", {} rolled to tomorrow", closed.rolled.carried));
+        }
+        if closed.rolled.no_room > 0 {
+            message.push_str(&format!(", {} did not fit", closed.rolled.no_room));
+        }
+        self.toasts.show_info(message);
+        Ok(())
+    }
+
+    /// Offer yesterday's unfinished actions for triage
+    fn open_carry_over_wizard(&mut self) {
+        match carryover::pending_items(self.goals.date, &self.config) {
+            Ok(items) if !items.is_empty() => {
+                self.modal = Some(ModalState::CarryOver(CarryOverState { items, index: 0 }));
+            }
+            Ok(_) => {}
+            Err(e) => self
+                .toasts
+                .show_warning(format!("Could not read yesterday's actions: {}", e)),
+        }
+    }
+
+    fn open_journal(&mut self) {
+        match journal::load_entries(&self.config) {
+            Ok(entries) => self.modal = Some(ModalState::Journal(JournalState::new(entries))),
+            Err(e) => self
+                .toasts
+                .show_error(format!("Failed to load reflections: {}", e)),
+        }
+    }
+
+    fn open_morning_wizard(&mut self) {
+        if self.day_is_locked() {
+            return;
+        }
+        if let Err(e) = self.flush_pending_save() {
+            self.toasts.show_error(format!("Failed to save: {}", e));
+            return;
+        }
+        match MorningPlan::start(&self.goals, &self.config) {
+            Ok(plan) => {
+                self.modal = Some(ModalState::Morning(MorningState {
+                    plan,
+                    step: MorningStep::Review,
+                    selection: 0,
+                }))
+            }
+            Err(e) => self
+                .toasts
+                .show_error(format!("Failed to start morning intentions: {}", e)),
+        }
+    }
+
+    /// Write the wizard's choices in one go
+    fn save_morning_plan(&mut self, plan: &MorningPlan) -> anyhow::Result<()> {
+        let backup = self.goals.clone();
+        match morning::apply(plan, &mut self.goals, &self.config) {
+            Ok(set) => {
+                let total = plan.total_estimate();
+                self.toasts.show_info(format!(
+                    "Set {} priorit{} for today{}",
+                    set,
+                    if set == 1 { "y" } else { "ies" },
+                    if total > 0 {
+                        format!(" · {} estimated", crate::models::format_minutes(total))
+                    } else {
+                        String::new()
+                    }
+                ));
+                self.refresh_statistics();
+                self.warn_if_over_capacity();
+                Ok(())
+            }
+            Err(e) => {
+                self.goals = backup;
+                self.toasts
+                    .show_error(format!("Failed to save intentions: {}", e));
+                Err(e)
+            }
+        }
+    }
+
+    fn start_quarterly_checkin(&mut self) {
+        let checkin = QuarterlyCheckIn::start(self.max_date, &self.vision, &self.objectives);
+        if quarterly::has_checkin(self.max_date, &self.config) {
+            self.toasts.show_warning(format!(
+                "{} already has a check-in; saving replaces it",
+                checkin.label()
+            ));
+        }
+        self.modal = Some(ModalState::QuarterlyCheckIn(QuarterlyState {
+            checkin,
+            step: 0,
+            selection: 0,
+        }));
+    }
+
+    fn open_vision_history(&mut self) {
+        match crate::data::load_vision_history(&self.config) {
+            Ok(history) => {
+                let mut state = VisionHistoryState {
+                    history,
+                    outcome_type: self.selected_outcome,
+                    changes: Vec::new(),
+                    selection: 0,
+                };
+                state.show_outcome(self.selected_outcome);
+                self.modal = Some(ModalState::VisionHistory(state));
+            }
+            Err(e) => self
+                .toasts
+                .show_error(format!("Failed to load vision history: {}", e)),
+        }
+    }
+
+    fn open_projects(&mut self) {
+        match crate::data::load_or_create_projects(&self.config) {
+            Ok(projects) => {
+                self.modal = Some(ModalState::Projects(ProjectsState {
+                    projects,
+                    selection: 0,
+                    assigning: None,
+                    confirm_delete: false,
+                }))
+            }
+            Err(e) => self
+                .toasts
+                .show_error(format!("Failed to load projects: {}", e)),
+        }
+    }
+
+    fn open_backups(&mut self) -> anyhow::Result<()> {
+        self.flush_pending_save()?;
+        match backups::list(&self.config) {
+            Ok(backups) if backups.is_empty() => self.toasts.show_info(
+                "No backups yet; they are taken before files are overwritten".to_string(),
+            ),
+            Ok(backups) => {
+                self.modal = Some(ModalState::Backups(BackupsState {
+                    backups,
+                    selection: 0,
+                    confirm_restore: false,
+                }))
+            }
+            Err(e) => self
+                .toasts
+                .show_error(format!("Failed to list backups: {}", e)),
+        }
+        Ok(())
+    }
+
+    fn open_trash(&mut self) -> anyhow::Result<()> {
+        match crate::data::load_or_create_trash(&self.config) {
+            Ok(data) if data.objectives.is_empty() && data.indicators.is_empty() => {
+                self.toasts.show_info("The trash is empty".to_string())
+            }
+            Ok(data) => {
+                self.modal = Some(ModalState::Trash(TrashState {
+                    entries: trash::entries(&data),
+                    selection: 0,
+                    confirm_purge: false,
+                }))
+            }
+            Err(e) => self
+                .toasts
+                .show_error(format!("Failed to read the trash: {}", e)),
+        }
+        Ok(())
+    }
+
+    fn restore_trashed(&mut self, entry: &TrashEntry) -> anyhow::Result<()> {
+        self.flush_pending_save()?;
+        if let Err(e) = trash::restore(entry.kind, &entry.id, &self.config) {
+            self.toasts
+                .show_error(format!("Failed to restore '{}': {}", entry.name, e));
+            return Ok(());
+        }
+        self.reload_data_files()?;
+        self.toasts
+            .show_success(format!("Restored {} '{}'", entry.kind.label(), entry.name));
+        Ok(())
+    }
+
+    /// Delete a trashed item for good; links to it in other days' files go too
+    fn purge_trashed(&mut self, entry: &TrashEntry) -> anyhow::Result<()> {
+        self.flush_pending_save()?;
+        if let Err(e) = trash::purge(entry.kind, &entry.id, &self.config) {
+            self.toasts
+                .show_error(format!("Failed to delete '{}': {}", entry.name, e));
+            return Ok(());
+        }
+        self.reload_data_files()?;
+        self.toasts.show_info(format!(
+            "Deleted {} '{}' for good",
+            entry.kind.label(),
+            entry.name
+        ));
+        Ok(())
+    }
+
+    fn restore_backup(&mut self, backup: &Backup) -> anyhow::Result<()> {
+        if let Err(e) = backups::restore(backup, &self.config) {
+            self.toasts
+                .show_error(format!("Failed to restore {}: {}", backup.file, e));
+            return Ok(());
+        }
+        self.reload_data_files()?;
+        self.toasts.show_success(format!(
+            "Restored {} from {}; the replaced version was backed up",
+            backup.file,
+            backup.taken.format("%b %d %H:%M:%S")
+        ));
+        Ok(())
+    }
+
+    fn open_templates(&mut self) {
+        match crate::data::load_or_create_templates(&self.config) {
+            Ok(templates) => {
+                self.modal = Some(ModalState::Templates(TemplatesState {
+                    templates,
+                    selection: 0,
+                    action: None,
+                    confirm_delete: false,
+                }))
+            }
+            Err(e) => self
+                .toasts
+                .show_error(format!("Failed to load templates: {}", e)),
+        }
+    }
+
+    fn save_template_name(&mut self, template: &TemplateRef, name: &str) {
+        let data = match templates::rename(template, name, &self.config) {
+            Ok(data) => data,
+            Err(e) => {
+                self.toasts
+                    .show_error(format!("Failed to rename template: {}", e));
+                return;
+            }
+        };
+        if let Some(ModalState::Templates(ref mut state)) = self.modal {
+            let renamed = match template {
+                TemplateRef::Actions(_) => TemplateRef::Actions(name.trim().to_string()),
+                TemplateRef::Weekday(_) => TemplateRef::Weekday(name.trim().to_string()),
+            };
+            state.selection = templates::list(&data)
+                .iter()
+                .position(|t| *t == renamed)
+                .unwrap_or(0);
+            state.templates = data;
+        }
+    }
+
+    /// Save an action edited in the template manager and select it
+    fn save_template_action(
+        &mut self,
+        template: &TemplateRef,
+        outcome: Option<OutcomeType>,
+        index: usize,
+        text: &str,
+    ) {
+        let data = match templates::set_action(template, outcome, index, text, &self.config) {
+            Ok(data) => data,
+            Err(e) => {
+                self.toasts
+                    .show_error(format!("Failed to update template: {}", e));
+                return;
+            }
+        };
+        if let Some(ModalState::Templates(ref mut state)) = self.modal {
+            let actions = templates::actions(&data, template);
+            state.action = Some(
+                actions
+                    .iter()
+                    .position(|action| action.outcome == outcome && action.index == index)
+                    .unwrap_or(actions.len().saturating_sub(1)),
+            );
+            state.templates = data;
+        }
+    }
+
+    /// Add the actions of `template` to the day on screen; an action list
+    /// goes into the selected outcome
+    fn apply_template(
+        &mut self,
+        data: &ActionTemplates,
+        template: &TemplateRef,
+    ) -> anyhow::Result<()> {
+        if self.day_is_locked() {
+            return Ok(());
+        }
+        let backup = self.goals.clone();
+        let added = templates::apply(data, template, &mut self.goals, self.selected_outcome);
+        if added == 0 {
+            self.toasts.show_warning(format!(
+                "Nothing added from '{}': its actions are already there or the outcome is full",
+                template.name()
+            ));
+            return Ok(());
+        }
+        if let Err(e) = self.save_current_goals() {
+            self.goals = backup;
+            return Err(e);
+        }
+        self.refresh_statistics();
+        self.warn_if_over_capacity();
+        self.toasts.show_success(format!(
+            "Added {} action{} from '{}'",
+            added,
+            if added == 1 { "" } else { "s" },
+            template.name()
+        ));
+        Ok(())
+    }
+
+    /// Create a project, or rename `project_id`, and select it in the browser
+    fn save_project_title(
+        &mut self,
+        project_id: Option<String>,
+        title: &str,
+    ) -> anyhow::Result<()> {
+        let saved = match project_id {
+            Some(id) => projects::rename_project(&id, title, &self.config).map(|data| (id, data)),
+            None => projects::create_project(title, &self.config).and_then(|id| {
+                crate::data::load_or_create_projects(&self.config).map(|data| (id, data))
+            }),
+        };
+        let (id, data) = match saved {
+            Ok(saved) => saved,
+            Err(e) => {
+                self.toasts
+                    .show_error(format!("Failed to save project: {}", e));
+                return Ok(());
+            }
+        };
+        if let Some(ModalState::Projects(ref mut state)) = self.modal {
+            state.selection = data
+                .projects
+                .iter()
+                .position(|project| project.id == id)
+                .unwrap_or(0);
+            state.projects = data;
+        }
+        Ok(())
+    }
+
+    /// Indicator progress toward target, 0.0-1.0, by indicator id
+    fn indicator_progress(&self) -> HashMap<String, f64> {
+        self.indicators_map
+            .iter()
+            .map(|(id, indicator)| {
+                let progress = if indicator.target_value > 0.0 {
+                    (indicator.current_value / indicator.target_value).min(1.0)
+                } else {
+                    0.0
+                };
+                (id.clone(), progress)
+            })
+            .collect()
+    }
+
+    /// Open the review of `year`; a year that has not started keeps the
+    /// current screen
+    fn open_year_review(&mut self, year: i32) {
+        if let Err(e) = self.flush_pending_save() {
+            self.toasts.show_error(format!("Failed to save: {}", e));
+        }
+        match year_review::build(year, self.max_date, &self.config) {
+            Ok(review) => {
+                self.modal = Some(ModalState::YearReview(YearReviewState {
+                    review,
+                    scroll: 0,
+                }))
+            }
+            Err(e) => self
+                .toasts
+                .show_warning(format!("No review for {}: {}", year, e)),
+        }
+    }
+
+    fn open_weekly_plan(&mut self) {
+        let selection = WEEKLY_OUTCOMES
+            .iter()
+            .position(|outcome_type| *outcome_type == self.selected_outcome)
+            .unwrap_or(0);
+        self.modal = Some(ModalState::WeeklyPlan(WeeklyPlanState {
+            weekly: self.weekly_goals.clone(),
+            selection,
+        }));
+    }
+
+    fn save_weekly_focus(
+        &mut self,
+        week_iso: (i32, u32),
+        outcome_type: OutcomeType,
+        focus: &str,
+    ) -> anyhow::Result<()> {
+        let mut weekly = crate::data::load_weekly_goals(week_iso, &self.config)?;
+        weekly.set_focus(outcome_type, focus);
+        if let Err(e) = crate::data::save_weekly_goals(&weekly, &self.config) {
+            self.toasts
+                .show_error(format!("Failed to save weekly focus: {}", e));
+            return Err(e);
+        }
+        if week_iso == self.weekly_goals.week_iso() {
+            self.weekly_goals = weekly.clone();
+        }
+        if let Some(ModalState::WeeklyPlan(ref mut state)) = self.modal {
+            state.weekly = weekly;
+        }
+        Ok(())
+    }
+
+    /// Link the selected action to its outcome's focus for the week, or unlink it
+    fn toggle_weekly_focus_link(&mut self) -> anyhow::Result<()> {
+        if self.day_is_locked() {
+            return Ok(());
+        }
+        let outcome_type = self.selected_outcome;
+        let index = self.selected_action;
+        let Some(action) = self.get_selected_outcome().actions.get(index) else {
+            return Ok(());
+        };
+        if action.text.trim().is_empty() {
+            self.toasts
+                .show_warning("Nothing to link in an empty action".to_string());
+            return Ok(());
+        }
+        let linked = !self.weekly_linked.contains(&(outcome_type, index));
+        self.save_goals_for_meta()?;
+        if let Err(e) = crate::data::set_weekly_focus_link(
+            &self.goals,
+            outcome_type,
+            index,
+            linked,
+            &self.config,
+        ) {
+            self.toasts
+                .show_error(format!("Failed to link weekly focus: {}", e));
+            return Err(e);
+        }
+        self.refresh_meta_stats();
+
+        let focus = self.weekly_goals.get_focus(outcome_type);
+        let message = match (linked, focus.is_empty()) {
+            (false, _) => "Unlinked from the weekly focus".to_string(),
+            (true, true) => format!(
+                "Linked, but no {} focus is set this week (W to plan it)",
+                outcome_type.as_str()
+            ),
+            (true, false) => format!("Linked to this week's focus: {}", focus),
+        };
+        self.toasts.show_info(message);
+        Ok(())
+    }
+
+    /// Give the selected action priority `level`, or clear it when it
+    /// already has that level
+    fn set_selected_priority(&mut self, level: u32) -> anyhow::Result<()> {
+        if self.day_is_locked() {
+            return Ok(());
+        }
+        let key = (self.selected_outcome, self.selected_action);
+        let priority = (self.action_priorities.get(&key) != Some(&level)).then_some(level);
+        self.save_goals_for_meta()?;
+        if let Err(e) = priority::set_priority(&self.goals, key.0, key.1, priority, &self.config) {
+            self.toasts.show_warning(e.to_string());
+            return Ok(());
+        }
+        self.refresh_meta_stats();
+        self.toasts.show_info(match priority {
+            Some(level) => format!("Priority set to P{}", level),
+            None => "Priority cleared".to_string(),
+        });
+        Ok(())
+    }
+
+    /// Order the actions of `outcome_type` by priority, keeping the
+    /// selected one selected
+    fn sort_by_priority(&mut self, outcome_type: OutcomeType) -> anyhow::Result<()> {
+        if self.day_is_locked() {
+            return Ok(());
+        }
+        self.save_current_goals()?;
+        let backup = self.goals.clone();
+        let order = match priority::sort_by_priority(&mut self.goals, outcome_type, &self.config) {
+            Ok(order) => order,
+            Err(e) => {
+                self.goals = backup;
+                self.toasts.show_error(format!("Failed to sort: {}", e));
+                return Err(e);
+            }
+        };
+        if outcome_type == self.selected_outcome {
+            if let Some(index) = order.iter().position(|old| *old == self.selected_action) {
+                self.selected_action = index;
+            }
+        }
+        self.refresh_meta_stats();
+        self.refresh_blocked();
+        self.toasts.show_info(format!(
+            "Sorted {} actions by priority",
+            outcome_type.as_str()
+        ));
+        Ok(())
+    }
+
+    fn open_timeline(&mut self) {
+        match timeline::blocks(&self.goals, &self.config) {
+            Ok(blocks) => {
+                self.modal = Some(ModalState::Timeline(TimelineState {
+                    blocks,
+                    selection: 0,
+                }))
+            }
+            Err(e) => self
+                .toasts
+                .show_error(format!("Could not load the timeline: {}", e)),
+        }
+    }
+
+    fn reschedule(
+        &mut self,
+        block: &timeline::Block,
+        start: Option<chrono::NaiveTime>,
+        minutes: Option<u32>,
+    ) -> anyhow::Result<()> {
+        self.save_goals_for_meta()?;
+        if let Err(e) = timeline::schedule(
+            &self.goals,
+            block.outcome_type,
+            block.index,
+            start,
+            minutes,
+            &self.config,
+        ) {
+            self.toasts
+                .show_error(format!("Failed to save time block: {}", e));
+            return Err(e);
+        }
+        if minutes.is_some() {
+            self.refresh_meta_stats();
+            self.warn_if_over_capacity();
+        }
+        Ok(())
+    }
+
+    fn open_board(&mut self) {
+        match board::cards(&self.goals, &self.config) {
+            Ok(cards) => {
+                self.modal = Some(ModalState::Board(BoardState {
+                    cards,
+                    column: 0,
+                    row: 0,
+                }))
+            }
+            Err(e) => self
+                .toasts
+                .show_error(format!("Could not load the board: {}", e)),
+        }
+    }
+
+    /// Move a board card to another status column and save both files
+    fn move_card(&mut self, card: &Card, status: ActionStatus) -> anyhow::Result<()> {
+        if self.day_is_locked() {
+            return Ok(());
+        }
+        self.save_current_goals()?;
+        let backup = self.goals.clone();
+        if let Err(e) = board::set_status(
+            &mut self.goals,
+            card.outcome_type,
+            card.index,
+            status,
+            &self.config,
+        ) {
+            self.goals = backup;
+            self.toasts
+                .show_error(format!("Failed to move action: {}", e));
+            return Err(e);
+        }
+        if status == ActionStatus::Done && card.status != ActionStatus::Done {
+            self.log_activity(
+                ActivityKind::Completion,
+                format!("Completed: {}", card.text),
+            );
+        }
+        self.refresh_statistics();
+        self.refresh_blocked();
+        Ok(())
+    }
+
+    /// Recount today's actions by board status
+    /// Board status counts, tracked time and weekly focus links, all kept in
+    /// the day's metadata
+    fn refresh_meta_stats(&mut self) {
+        self.weekly_linked =
+            crate::data::load_or_create_day_meta(self.goals.date, &self.goals, &self.config)
+                .map(|meta| {
+                    let mut linked = HashSet::new();
+                    for outcome_type in
+                        [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family]
+                    {
+                        for (index, action_meta) in meta.outcome(outcome_type).iter().enumerate() {
+                            if action_meta.weekly_focus {
+                                linked.insert((outcome_type, index));
+                            }
+                        }
+                    }
+                    linked
+                })
+                .unwrap_or_default();
+        self.action_priorities =
+            priority::priorities(&self.goals, &self.config).unwrap_or_default();
+        self.statistics.status_counts = board::cards(&self.goals, &self.config)
+            .map(|cards| StatusCounts::from_cards(&cards))
+            .unwrap_or_default();
+        self.statistics.outcome_times =
+            tracking::outcome_times(&self.goals, &self.config).unwrap_or_default();
+    }
+
+    /// Estimated work planned into the day on screen against the capacity
+    pub fn day_capacity(&self) -> DayCapacity {
+        DayCapacity::new(&self.statistics.outcome_times, &self.settings)
+    }
+
+    fn warn_if_over_capacity(&mut self) {
+        if let Some(warning) = self.day_capacity().warning() {
+            self.toasts.show_warning(warning);
+        }
+    }
+
+    /// Reload which actions are blocked, releasing those whose blocker is done
+    fn refresh_blocked(&mut self) {
+        match dependencies::release_unblocked(&self.goals, &self.config) {
+            Ok(released) if !released.is_empty() => self
+                .toasts
+                .show_info(format!("Unblocked: {}", released.join(", "))),
+            Ok(_) => {}
+            Err(e) => self
+                .toasts
+                .show_warning(format!("Could not check blocked actions: {}", e)),
+        }
+        self.blocked = dependencies::blocked_actions(&self.goals, &self.config).unwrap_or_default();
+    }
+
+    fn open_blocker_picker(&mut self) -> anyhow::Result<()> {
+        if self.day_is_locked() {
+            return Ok(());
+        }
+        let outcome_type = self.selected_outcome;
+        let index = self.selected_action;
+        let has_text = self
+            .get_selected_outcome()
+            .actions
+            .get(index)
+            .is_some_and(|a| !a.text.trim().is_empty());
+        if !has_text {
+            self.toasts
+                .show_warning("Write the action before linking what blocks it".to_string());
+            return Ok(());
+        }
+        let candidates = dependencies::candidates(&self.goals, outcome_type, index, &self.config)?;
+        if candidates.is_empty() && !self.blocked.contains_key(&(outcome_type, index)) {
+            self.toasts
+                .show_info("No unfinished actions to wait on".to_string());
+            return Ok(());
+        }
+        self.modal = Some(ModalState::BlockerPicker(BlockerPickerState {
+            outcome_type,
+            index,
+            candidates,
+            selection: 0,
+        }));
+        Ok(())
+    }
+
+    fn set_blocker(
+        &mut self,
+        outcome_type: OutcomeType,
+        index: usize,
+        blocker: Option<BlockerRef>,
+    ) -> anyhow::Result<()> {
+        self.save_goals_for_meta()?;
+        let message = match &blocker {
+            Some(blocker) => format!("Blocked by: {}", blocker.text),
+            None => "No longer blocked".to_string(),
+        };
+        if let Err(e) =
+            dependencies::set_blocker(&self.goals, outcome_type, index, blocker, &self.config)
+        {
+            self.toasts
+                .show_error(format!("Failed to save blocker: {}", e));
+            return Err(e);
+        }
+        self.blocked = dependencies::blocked_actions(&self.goals, &self.config).unwrap_or_default();
+        self.toasts.show_info(message);
+        Ok(())
+    }
+
+    /// Ask for the day to snooze the selected action to, tomorrow first
+    fn open_snooze(&mut self) {
+        if self.day_is_locked() {
+            return;
+        }
+        let outcome_type = self.selected_outcome;
+        let index = self.selected_action;
+        let Some(action) = self.get_selected_outcome().actions.get(index) else {
+            return;
+        };
+        if action.text.trim().is_empty() {
+            self.toasts
+                .show_warning("Nothing to snooze in an empty action".to_string());
+            return;
+        }
+        self.modal = Some(ModalState::Snooze(SnoozeState {
+            outcome_type,
+            index,
+            text: action.text.clone(),
+            until: self.goals.date + chrono::Duration::days(1),
+        }));
+    }
+
+    fn snooze_selected(&mut self, state: &SnoozeState) -> anyhow::Result<()> {
+        self.save_current_goals()?;
+        let backup = self.goals.clone();
+        if let Err(e) = carryover::snooze_action(
+            &mut self.goals,
+            state.outcome_type,
+            state.index,
+            state.until,
+            &self.config,
+        ) {
+            self.goals = backup;
+            self.toasts.show_error(format!("Failed to snooze: {}", e));
+            return Err(e);
+        }
+        self.pending_save = None;
+        self.refresh_statistics();
+        self.refresh_blocked();
+        self.toasts.show_info(format!(
+            "Snoozed to {}: {}",
+            state.until.format("%a %b %d"),
+            state.text
+        ));
+        Ok(())
+    }
+
+    fn apply_carry_over(&mut self, items: &[CarryItem]) -> anyhow::Result<()> {
+        let backup = self.goals.clone();
+        let summary = match carryover::apply(items, &mut self.goals, &self.config) {
+            Ok(summary) => summary,
+            Err(e) => {
+                self.goals = backup;
+                self.toasts
+                    .show_error(format!("Failed to carry over actions: {}", e));
+                return Err(e);
+            }
+        };
+        self.pending_save = None;
+        self.refresh_statistics();
+
+        let mut message = format!(
+            "Carried {}, snoozed {}, dropped {}",
+            summary.carried, summary.snoozed, summary.dropped
+        );
+        if summary.no_room > 0 {
+            message.push_str(&format!(
+                " ({} did not fit: 5 actions max)",
+                summary.no_room
+            ));
+            self.toasts.show_warning(message);
+        } else {
+            self.toasts.show_info(message);
+        }
+        Ok(())
+    }
+
+    fn open_retarget_wizard(&mut self) {
+        let year = retarget::plan_year(chrono::Local::now().date_naive());
+        let observations = match (
+            chrono::NaiveDate::from_ymd_opt(year - 1, 1, 1),
+            chrono::NaiveDate::from_ymd_opt(year - 1, 12, 31),
+        ) {
+            (Some(start), Some(end)) => {
+                crate::data::read_observations_range(start, end, &self.config).unwrap_or_default()
+            }
+            _ => Vec::new(),
+        };
+
+        let items = retarget::build_items(&self.indicators, &self.objectives, &observations, year);
+        if items.is_empty() {
+            self.toasts
+                .show_warning("No active indicators or objectives to re-target".to_string());
+            return;
+        }
+
+        let mut state = RetargetState {
+            year,
+            items,
+            index: 0,
+            buffer: String::new(),
+            confirming: false,
+        };
+        state.select(0);
+        self.modal = Some(ModalState::Retarget(state));
+    }
+
+    /// Write every accepted change in one batch, restoring on failure
+    fn apply_retarget(&mut self, state: &RetargetState) -> anyhow::Result<()> {
+        let indicators_backup = self.indicators.clone();
+        let objectives_backup = self.objectives.clone();
+        let changed = retarget::apply(
+            &state.items,
+            &mut self.indicators,
+            &mut self.objectives,
+            state.year,
+        );
+        if changed == 0 {
+            self.toasts
+                .show_info("No changes selected; nothing re-targeted".to_string());
+            return Ok(());
+        }
+
+        let saved = crate::data::save_indicators(&self.indicators, &self.config)
+            .and_then(|_| crate::data::save_objectives(&self.objectives, &self.config));
+        if let Err(e) = saved {
+            self.indicators = indicators_backup;
+            self.objectives = objectives_backup;
+            let _ = crate::data::save_indicators(&self.indicators, &self.config);
+            self.toasts
+                .show_error(format!("Failed to apply new targets: {}", e));
+            return Err(e);
+        }
+
+        for def in &self.indicators.indicators {
+            if let Some(target) = def.current_target() {
+                if let Some(indicator) = self.indicators_map.get_mut(&def.id) {
+                    indicator.target_value = target;
+                }
+            }
+        }
+        self.log_activity(
+            ActivityKind::Objective,
+            format!("Re-targeted {} items for {}", changed, state.year),
+        );
+        self.toasts
+            .show_info(format!("Applied {} changes for {}", changed, state.year));
+        Ok(())
+    }
+
+    fn open_quick_observation(&mut self) {
+        if !self.indicators.indicators.iter().any(|def| def.active) {
+            self.toasts
+                .show_warning("No active indicators to log".to_string());
+            return;
+        }
+
+        self.modal = Some(ModalState::QuickObservation(QuickObservationState {
+            query: String::new(),
+            selection: 0,
+            value: String::new(),
+            field: QuickObservationField::Indicator,
+        }));
+    }
+
+    /// Active indicators matching the quick-add query, best match first.
+    /// Returned values index into `self.indicators.indicators`.
+    fn quick_observation_matches(&self, query: &str) -> Vec<usize> {
+        let active: Vec<usize> = self
+            .indicators
+            .indicators
+            .iter()
+            .enumerate()
+            .filter(|(_, def)| def.active)
+            .map(|(i, _)| i)
+            .collect();
+
+        fuzzy_filter(
+            query,
+            active
+                .iter()
+                .map(|&i| self.indicators.indicators[i].name.as_str()),
+        )
+        .into_iter()
+        .map(|i| active[i])
+        .collect()
+    }
+
+    fn switch_panel(&mut self) {
+        self.focus_panel = match self.focus_panel {
+            FocusPanel::Outcomes => FocusPanel::Actions,
+            FocusPanel::Actions => FocusPanel::Outcomes,
+        };
+
+        // When switching to actions, reset selected_action to be within range for selected outcome
+        if self.focus_panel == FocusPanel::Actions {
+            self.selected_action = 0;
+        }
+    }
+
+    fn move_up(&mut self) {
+        match self.focus_panel {
+            FocusPanel::Outcomes => {
+                self.selected_outcome = match self.selected_outcome {
+                    OutcomeType::Work => OutcomeType::Family,
+                    OutcomeType::Health => OutcomeType::Work,
+                    OutcomeType::Family => OutcomeType::Health,
+                };
+            }
+            FocusPanel::Actions => {
+                let total = self.get_selected_outcome().actions.len();
+                if total == 0 {
+                    self.selected_action = 0;
+                } else if self.selected_action == 0 {
+                    self.selected_action = total - 1;
+                } else {
+                    self.selected_action -= 1;
+                }
+            }
+        }
+    }
+
+    fn move_down(&mut self) {
+        match self.focus_panel {
+            FocusPanel::Outcomes => {
+                self.selected_outcome = match self.selected_outcome {
+                    OutcomeType::Work => OutcomeType::Health,
+                    OutcomeType::Health => OutcomeType::Family,
+                    OutcomeType::Family => OutcomeType::Work,
+                };
+            }
+            FocusPanel::Actions => {
+                let total = self.get_selected_outcome().actions.len();
+                if total == 0 {
+                    self.selected_action = 0;
+                } else {
+                    self.selected_action = (self.selected_action + 1) % total;
+                }
+            }
+        }
+    }
+
+    /// Alt+j/k: move the selected action down or up within its outcome so
+    /// the markdown keeps the user's priority order
+    pub fn move_selected_action(&mut self, offset: isize) -> anyhow::Result<()> {
+        if self.focus_panel != FocusPanel::Actions {
+            return Ok(());
+        }
+        let outcome_type = self.selected_outcome;
+        let from = self.selected_action;
+        let total = self.get_selected_outcome().actions.len();
+        let Some(to) = from.checked_add_signed(offset).filter(|to| *to < total) else {
+            return Ok(());
+        };
+
+        let backup = self.goals.clone();
+        self.get_outcome_by_type_mut(outcome_type)
+            .move_action(from, to)?;
+        if let Err(e) = self.save_current_goals() {
+            self.toasts.show_error(format!("Failed to save: {}", e));
+            self.goals = backup;
+            return Err(e);
+        }
+        self.selected_action = to;
+
+        if let Err(e) =
+            crate::data::move_day_meta_action(self.goals.date, outcome_type, from, to, &self.config)
+        {
+            self.toasts.show_warning(format!(
+                "Moved action, but its details did not follow: {}",
+                e
+            ));
+        }
+        self.refresh_blocked();
+        Ok(())
+    }
+
+    /// Record an event in the activity log and the in-memory feed
+    fn log_activity(&mut self, kind: ActivityKind, summary: String) {
+        let event = ActivityEvent::new(kind, summary);
+        if let Err(e) = crate::data::append_activity(&event, &self.config) {
+            self.toasts
+                .show_warning(format!("Failed to record activity: {}", e));
+        }
+        self.recent_activity.insert(0, event);
+        self.recent_activity.truncate(RECENT_ACTIVITY_LIMIT);
+    }
+
+    fn toggle_current(&mut self) -> anyhow::Result<()> {
+        if self.focus_panel == FocusPanel::Actions && !self.day_is_locked() {
+            let action_index = self.selected_action;
+
+            // Get the current completion status
+            let was_completed = {
+                let outcome = self.get_selected_outcome();
+                outcome.actions[action_index].completed
+            };
+
+            // Toggle the completion status
+            {
+                let outcome = self.get_selected_outcome_mut();
+                outcome.actions[action_index].completed = !was_completed;
+            }
+
+            self.mark_dirty();
+
+            let objective_ids =
+                self.get_selected_outcome().actions[action_index].get_all_objective_ids();
+            self.step_linked_queues(&objective_ids, !was_completed);
+
+            if !was_completed {
+                let text = self.get_selected_outcome().actions[action_index]
+                    .text
+                    .clone();
+                self.log_activity(ActivityKind::Completion, format!("Completed: {}", text));
+
+                let action = &self.get_selected_outcome().actions[action_index];
+                self.events.emit(Event::new(
+                    EventKind::ActionCompleted,
+                    serde_json::json!({
+                        "date": self.goals.date,
+                        "outcome": self.selected_outcome.as_str(),
+                        "action_id": action.id,
+                        "text": action.text,
+                    }),
+                ));
+                self.feed_linked_indicators(self.selected_outcome, action_index)?;
+            }
+
+            // Update statistics after toggling
+            self.refresh_statistics();
+            self.refresh_blocked();
+        }
+        Ok(())
+    }
+
+    fn get_selected_outcome_mut(&mut self) -> &mut crate::models::Outcome {
+        self.get_outcome_by_type_mut(self.selected_outcome)
+    }
+
+    fn get_selected_outcome(&self) -> &crate::models::Outcome {
+        self.get_outcome_by_type(self.selected_outcome)
+    }
+
+    fn current_action_indicator_ids(&self) -> Vec<String> {
+        let outcome = self.get_selected_outcome();
+        if self.selected_action >= outcome.actions.len() {
+            return Vec::new();
+        }
+
+        let action = &outcome.actions[self.selected_action];
+        let mut ids = Vec::new();
+
+        for objective_id in action.get_all_objective_ids() {
+            if let Some(objective) = self
+                .objectives
+                .objectives
+                .iter()
+                .find(|obj| obj.id == objective_id)
+            {
+                for indicator_id in &objective.indicators {
+                    if self
+                        .indicators
+                        .indicators
+                        .iter()
+                        .any(|indicator| indicator.id == *indicator_id)
+                    {
+                        ids.push(indicator_id.clone());
+                    }
+                }
+            }
+        }
+
+        ids
+    }
+
+    fn open_selected_indicator_update(&mut self) -> anyhow::Result<()> {
+        if self.focus_panel != FocusPanel::Actions {
+            return Ok(());
+        }
+
+        // Open indicator update for the first indicator linked to the current action
+        let indicator_ids = self.current_action_indicator_ids();
+        if let Some(indicator_id) = indicator_ids.first() {
+            self.open_indicator_update_modal(indicator_id)?;
+        } else {
+            self.toasts
+                .show_info("No indicators linked to this action".to_string());
+        }
+
+        Ok(())
+    }
+
+    fn open_indicator_update_modal(&mut self, indicator_id: &str) -> anyhow::Result<()> {
+        let indicator_def = if let Some(def) = self
+            .indicators
+            .indicators
+            .iter()
+            .find(|indicator| indicator.id == indicator_id)
+        {
+            def.clone()
+        } else {
+            self.toasts
+                .show_error("Indicator definition not found".to_string());
+            return Ok(());
+        };
+
+        let indicator_type = self
+            .indicators_map
+            .get(indicator_id)
+            .map(|indicator| indicator.indicator_type)
+            .unwrap_or_else(|| Self::infer_indicator_type(&indicator_def.unit));
+
+        let (history, latest, previous, last_updated) =
+            self.collect_indicator_history(indicator_id)?;
+
+        let today = chrono::Local::now().date_naive();
+        let mut samples: Vec<Observation> =
+            crate::data::read_observations_range(today, today, &self.config)?
+                .into_iter()
+                .filter(|obs| obs.indicator_id == indicator_id)
+                .collect();
+        samples.sort_by_key(|obs| (obs.at, obs.created));
+        let today_samples = samples.iter().map(|obs| (obs.at, obs.value)).collect();
+
+        // Summed indicators are logged in increments, so start from zero
+        let buffer_source = if indicator_def.aggregation == IndicatorAggregation::Sum {
+            0.0
+        } else {
+            latest.or(previous).unwrap_or(0.0)
+        };
+        let buffer = Self::format_value_for_unit(buffer_source, &indicator_def.unit);
+
+        let (period_start, _) = indicator_def.target_period.bounds(today);
+        let period_progress = indicator_def.period_to_date(
+            &crate::data::read_observations_range(period_start, today, &self.config)?,
+            today,
+        );
+
+        let state = IndicatorUpdateState {
+            indicator_id: indicator_def.id.clone(),
+            name: indicator_def.name.clone(),
+            unit: indicator_def.unit.clone(),
+            indicator_type,
+            direction: indicator_def.direction.clone(),
+            target: indicator_def.target_on(self.current_date),
+            range: indicator_def.range_on(self.current_date),
+            previous_value: previous,
+            latest_value: latest,
+            history,
+            last_updated,
+            buffer,
+            review_position: None,
+            action_id: None,
+            aggregation: indicator_def.aggregation,
+            today_samples,
+            period_progress,
+        };
+
+        self.modal = Some(ModalState::IndicatorUpdate(state));
+        Ok(())
+    }
+
+    /// Start the end-of-day flow that walks every active indicator in turn
+    fn start_bulk_indicator_review(&mut self) -> anyhow::Result<()> {
+        self.bulk_review_queue = self
+            .indicators
+            .indicators
+            .iter()
+            .filter(|def| def.active)
+            .map(|def| def.id.clone())
+            .collect();
+        self.bulk_review_total = self.bulk_review_queue.len();
+
+        if self.bulk_review_total == 0 {
+            self.toasts
+                .show_warning("No active indicators to review".to_string());
+            return Ok(());
+        }
+
+        self.advance_bulk_review()
+    }
+
+    /// Open the update modal for the next queued indicator, prefilled with
+    /// yesterday's value, or finish the review when the queue is empty.
+    fn advance_bulk_review(&mut self) -> anyhow::Result<()> {
+        if self.bulk_review_queue.is_empty() {
+            if self.bulk_review_total > 0 {
+                self.toasts
+                    .show_info(format!("Reviewed {} indicators", self.bulk_review_total));
+            }
+            self.bulk_review_total = 0;
+            return Ok(());
+        }
+
+        let indicator_id = self.bulk_review_queue.remove(0);
+        let position = self.bulk_review_total - self.bulk_review_queue.len();
+        self.open_indicator_update_modal(&indicator_id)?;
+
+        let yesterday = chrono::Local::now().date_naive() - chrono::Duration::days(1);
+        let yesterday_value =
+            crate::data::read_observations_range(yesterday, yesterday, &self.config)?
+                .into_iter()
+                .filter(|obs| obs.indicator_id == indicator_id)
+                .max_by_key(|obs| obs.created)
+                .map(|obs| obs.value);
+
+        match self.modal {
+            Some(ModalState::IndicatorUpdate(ref mut state)) => {
+                state.review_position = Some((position, self.bulk_review_total));
+                if let Some(value) = yesterday_value {
+                    state.buffer = Self::format_value_for_unit(value, &state.unit);
+                }
+            }
+            // Definition vanished; keep going rather than stalling the review
+            _ => return self.advance_bulk_review(),
+        }
+        Ok(())
+    }
+
+    fn collect_indicator_history(
+        &self,
+        indicator_id: &str,
+    ) -> anyhow::Result<(
+        Vec<f64>,
+        Option<f64>,
+        Option<f64>,
+        Option<chrono::NaiveDate>,
+    )> {
+        let today = chrono::Local::now().date_naive();
+        let mut observations = if indicator_id == FOCUS_INDICATOR_ID {
+            loader::focus_daily_observations(today, 7, &self.config)
+        } else {
+            crate::data::read_observations_range(
+                today - chrono::Duration::days(60),
+                today,
+                &self.config,
+            )?
+        };
+
+        observations.sort_by(|a, b| a.when.cmp(&b.when).then(a.created.cmp(&b.created)));
+
+        // Each day's observations combine by the indicator's aggregation
+        let aggregation = self
+            .indicators
+            .indicators
+            .iter()
+            .find(|def| def.id == indicator_id)
+            .map(|def| def.aggregation)
+            .unwrap_or_default();
+        let (history_dates, mut history_values): (Vec<_>, Vec<_>) = aggregation
+            .daily(indicator_id, &observations)
+            .into_iter()
+            .unzip();
+
+        let fallback_current = self
+            .indicators_map
+            .get(indicator_id)
+            .map(|indicator| indicator.current_value);
+
+        let mut last_updated = history_dates.last().copied();
+        if history_values.is_empty() {
+            if let Some(current) = fallback_current {
+                history_values.push(current);
+                last_updated = Some(today);
+            }
+        }
+
+        let latest = history_values.last().copied().or(fallback_current);
+        let previous = if history_values.len() >= 2 {
+            history_values.get(history_values.len() - 2).copied()
+        } else {
+            None
+        };
+
+        let start = history_values.len().saturating_sub(7);
+        let history_window = history_values[start..].to_vec();
+
+        Ok((history_window, latest, previous, last_updated))
+    }
+
+    fn infer_indicator_type(unit: &IndicatorUnit) -> IndicatorType {
+        match unit {
+            IndicatorUnit::Minutes => IndicatorType::Duration,
+            IndicatorUnit::Percent => IndicatorType::Percentage,
+            IndicatorUnit::Custom(label) if label.eq_ignore_ascii_case("boolean") => {
+                IndicatorType::Boolean
+            }
+            IndicatorUnit::Custom(label) if label.eq_ignore_ascii_case("hours") => {
+                IndicatorType::Duration
+            }
+            IndicatorUnit::Custom(label) if label.eq_ignore_ascii_case("percentage") => {
+                IndicatorType::Percentage
+            }
+            _ => IndicatorType::Counter,
+        }
+    }
+
+    fn format_value_with_unit(&self, value: f64, unit: &IndicatorUnit) -> String {
+        if let Some(shown) = crate::units::display(value, unit, &self.settings.units) {
+            return shown;
+        }
+        let base = Self::format_value_for_unit(value, unit);
+        match unit {
+            IndicatorUnit::Percent => format!("{}%", base),
+            _ => {
+                let label = Self::unit_label(unit);
+                if label.is_empty() {
+                    base
+                } else {
+                    format!("{} {}", base, label)
+                }
+            }
+        }
+    }
+
+    fn indicator_delta_threshold(indicator_type: IndicatorType) -> f64 {
+        match indicator_type {
+            IndicatorType::Counter => 0.5,
+            IndicatorType::Duration => 0.1,
+            IndicatorType::Percentage => 0.5,
+            IndicatorType::Boolean => 0.5,
+        }
+    }
+
+    fn indicator_delta_label(&self, state: &IndicatorUpdateState) -> Option<String> {
+        let latest = state.latest_value?;
+        if let Some(range) = state.range {
+            let deviation = IndicatorDef::range_deviation(latest, range);
+            let magnitude = self.format_value_with_unit(deviation.abs(), &state.unit);
+            return Some(if deviation > 0.0 {
+                format!("(+{} above range)", magnitude)
+            } else if deviation < 0.0 {
+                format!("(-{} below range)", magnitude)
+            } else {
+                "in range".to_string()
+            });
+        }
+
+        if let Some(progress) = state.period_progress {
+            return Some(self.period_pace_label(progress, state));
+        }
+
+        let target = state.target?;
+        let threshold = Self::indicator_delta_threshold(state.indicator_type);
+        let diff = latest - target;
+        if diff.abs() < threshold {
+            return Some("on target".to_string());
+        }
+
+        let magnitude = self.format_value_with_unit(diff.abs(), &state.unit);
+        if diff > 0.0 {
+            Some(format!("(+{} ahead)", magnitude))
+        } else {
+            Some(format!("(-{} behind)", magnitude))
+        }
+    }
+
+    fn indicator_trend_status(state: &IndicatorUpdateState) -> TrendStatus {
+        match (state.latest_value, state.previous_value) {
+            (Some(latest), Some(previous)) => {
+                // Positive diff always means "moving the right way"
+                let diff = match (&state.direction, state.range) {
+                    (IndicatorDirection::WithinRange, Some(range)) => {
+                        IndicatorDef::range_deviation(previous, range).abs()
+                            - IndicatorDef::range_deviation(latest, range).abs()
+                    }
+                    (IndicatorDirection::LowerIsBetter, _) => previous - latest,
+                    _ => latest - previous,
+                };
+                let threshold = Self::indicator_delta_threshold(state.indicator_type);
+                if diff > threshold {
+                    TrendStatus::Improving
+                } else if diff < -threshold {
+                    TrendStatus::Declining
+                } else {
+                    TrendStatus::Stable
+                }
+            }
+            _ => TrendStatus::Stable,
+        }
+    }
+
+    fn indicator_trend_display(status: TrendStatus) -> (&'static str, &'static str) {
+        match status {
+            TrendStatus::Improving => ("▲", "Improving"),
+            TrendStatus::Declining => ("▼", "Declining"),
+            TrendStatus::Stable => ("■", "Stable"),
+        }
+    }
+
+    fn trend_color(&self, status: TrendStatus) -> Color {
         match status {
             TrendStatus::Improving => self.theme.completed,
             TrendStatus::Declining => self.theme.pending,
@@ -1114,977 +6127,4906 @@ impl App {
         }
     }
 
-    fn indicator_quick_actions_text(state: &IndicatorUpdateState) -> String {
-        match state.indicator_type {
-            IndicatorType::Counter => "+/- fine   a +1   s +3   d +5   c clear".to_string(),
-            IndicatorType::Duration => {
-                if Self::is_hours_unit(&state.unit) {
-                    "+/- fine   a +0.5h   s +1h   d +2h   c reset".to_string()
+    fn indicator_quick_actions_text(state: &IndicatorUpdateState) -> String {
+        match state.indicator_type {
+            IndicatorType::Counter => "+/- fine   a +1   s +3   d +5   c clear".to_string(),
+            IndicatorType::Duration => {
+                if Self::is_hours_unit(&state.unit) {
+                    "+/- fine   a +0.5h   s +1h   d +2h   c reset".to_string()
+                } else {
+                    "+/- fine   a +30m   s +60m   d +120m   c reset".to_string()
+                }
+            }
+            IndicatorType::Percentage => {
+                "+/- fine   a 25%   s 50%   d 75%   f 100%   c clear".to_string()
+            }
+            IndicatorType::Boolean => "y complete   n incomplete".to_string(),
+        }
+    }
+
+    /// "Add to Today · 09:10 20 · 12:30 15 = 35" once samples were logged
+    /// today; summed indicators always add to the day
+    /// "(+3 km ahead of pace)": the period total against the share of the
+    /// weekly or monthly target due by today
+    fn period_pace_label(&self, progress: PeriodProgress, state: &IndicatorUpdateState) -> String {
+        let gap = progress.pace_gap();
+        if gap.abs() <= progress.target * 0.01 {
+            return "on pace".to_string();
+        }
+        let magnitude = self.format_value_with_unit(gap.abs(), &state.unit);
+        let (above, below) = match state.direction {
+            IndicatorDirection::LowerIsBetter => ("over", "under"),
+            _ => ("ahead of", "behind"),
+        };
+        if gap > 0.0 {
+            format!("(+{} {} pace)", magnitude, above)
+        } else {
+            format!("(-{} {} pace)", magnitude, below)
+        }
+    }
+
+    /// " Goal Pace ", or " Week 3/7 Pace " for a weekly target
+    fn goal_pace_title(progress: Option<PeriodProgress>) -> String {
+        match progress {
+            Some(progress) => format!(
+                " {} {}/{} Pace ",
+                match progress.period {
+                    TargetPeriod::Week => "Week",
+                    TargetPeriod::Month => "Month",
+                    TargetPeriod::Day => "Day",
+                },
+                progress.days_elapsed,
+                progress.days_in_period
+            ),
+            None => " Goal Pace ".to_string(),
+        }
+    }
+
+    fn indicator_input_title(&self, state: &IndicatorUpdateState) -> String {
+        let adds = state.aggregation == IndicatorAggregation::Sum;
+        let mut title = if adds { " Add to Today" } else { " Input Value" }.to_string();
+        if state.today_samples.is_empty() {
+            title.push(' ');
+            return title;
+        }
+        let skipped = state.today_samples.len().saturating_sub(3);
+        if skipped > 0 {
+            title.push_str(&format!(" · +{} earlier", skipped));
+        }
+        for (at, value) in &state.today_samples[skipped..] {
+            let value = Self::format_value_for_unit(*value, &state.unit);
+            match at {
+                Some(at) => title.push_str(&format!(" · {} {}", at.format("%H:%M"), value)),
+                None => title.push_str(&format!(" · {}", value)),
+            }
+        }
+        if adds {
+            let total: f64 = state.today_samples.iter().map(|(_, value)| value).sum();
+            title.push_str(&format!(" = {}", self.format_value_with_unit(total, &state.unit)));
+        }
+        title.push(' ');
+        title
+    }
+
+    fn is_hours_unit(unit: &IndicatorUnit) -> bool {
+        match unit {
+            IndicatorUnit::Custom(label) => label.to_lowercase().contains("hour"),
+            _ => false,
+        }
+    }
+
+    fn indicator_fine_delta(state: &IndicatorUpdateState) -> f64 {
+        match state.indicator_type {
+            IndicatorType::Counter => 1.0,
+            IndicatorType::Duration => {
+                if Self::is_hours_unit(&state.unit) {
+                    0.25
+                } else {
+                    15.0
+                }
+            }
+            IndicatorType::Percentage => 5.0,
+            IndicatorType::Boolean => 0.0,
+        }
+    }
+
+    fn indicator_small_delta(state: &IndicatorUpdateState) -> f64 {
+        match state.indicator_type {
+            IndicatorType::Counter => 1.0,
+            IndicatorType::Duration => {
+                if Self::is_hours_unit(&state.unit) {
+                    0.5
+                } else {
+                    30.0
+                }
+            }
+            IndicatorType::Percentage => 0.0,
+            IndicatorType::Boolean => 0.0,
+        }
+    }
+
+    fn indicator_medium_delta(state: &IndicatorUpdateState) -> f64 {
+        match state.indicator_type {
+            IndicatorType::Counter => 3.0,
+            IndicatorType::Duration => {
+                if Self::is_hours_unit(&state.unit) {
+                    1.0
+                } else {
+                    60.0
+                }
+            }
+            IndicatorType::Percentage => 0.0,
+            IndicatorType::Boolean => 0.0,
+        }
+    }
+
+    fn indicator_large_delta(state: &IndicatorUpdateState) -> f64 {
+        match state.indicator_type {
+            IndicatorType::Counter => 5.0,
+            IndicatorType::Duration => {
+                if Self::is_hours_unit(&state.unit) {
+                    2.0
+                } else {
+                    120.0
+                }
+            }
+            IndicatorType::Percentage => 0.0,
+            IndicatorType::Boolean => 0.0,
+        }
+    }
+
+    fn clamp_text(text: &str, width: usize) -> String {
+        if width == 0 {
+            return String::new();
+        }
+        let count = text.chars().count();
+        if count <= width {
+            return text.to_string();
+        }
+        if width == 1 {
+            return "…".to_string();
+        }
+        let truncated: String = text.chars().take(width - 1).collect();
+        format!("{}…", truncated)
+    }
+
+    fn render_indicator_update_modal(&self, f: &mut Frame, state: &IndicatorUpdateState) {
+        let area = centered_rect(70, 65, f.area());
+        f.render_widget(Clear, area);
+
+        let border_set = border::Set {
+            top_left: "/",
+            top_right: "\\",
+            bottom_left: "\\",
+            bottom_right: "/",
+            vertical_left: "|",
+            vertical_right: "|",
+            horizontal_top: "-",
+            horizontal_bottom: "-",
+        };
+
+        let shell = Block::default()
+            .borders(Borders::ALL)
+            .border_set(border_set)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        f.render_widget(shell, area);
+
+        if area.width < 6 || area.height < 10 {
+            return;
+        }
+
+        let inner = Rect {
+            x: area.x + 1,
+            y: area.y + 1,
+            width: area.width.saturating_sub(2),
+            height: area.height.saturating_sub(2),
+        };
+
+        let header_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Min(6),
+            ])
+            .split(inner);
+
+        let title_text = match state.review_position {
+            Some((position, total)) => format!(
+                " FocusFive · Log All Metrics ({}/{}) · Tab Skip ",
+                position, total
+            ),
+            None => " FocusFive · Update Indicator ".to_string(),
+        };
+        let title_line = Line::from(vec![Span::styled(
+            title_text,
+            Style::default().fg(self.theme.header),
+        )]);
+        let title = Paragraph::new(title_line)
+            .alignment(Alignment::Left)
+            .style(Style::default().bg(self.theme.panel_bg));
+        f.render_widget(title, header_layout[0]);
+
+        let target_text = match state.range {
+            Some(range) => format!("Range {}", self.format_range(range, &state.unit)),
+            None => match state.period_progress {
+                Some(progress) => format!(
+                    "Target {}/{} · {} so far",
+                    self.format_value_with_unit(progress.target, &state.unit),
+                    progress.period.as_str(),
+                    self.format_value_with_unit(progress.total, &state.unit)
+                ),
+                None => format!(
+                    "Target {}",
+                    state
+                        .target
+                        .map(|value| self.format_value_with_unit(value, &state.unit))
+                        .unwrap_or_else(|| "—".to_string())
+                ),
+            },
+        };
+        let metrics_text = format!(
+            " {} | Latest {} | Previous {} ",
+            target_text,
+            state
+                .latest_value
+                .map(|value| self.format_value_with_unit(value, &state.unit))
+                .unwrap_or_else(|| "—".to_string()),
+            state
+                .previous_value
+                .map(|value| self.format_value_with_unit(value, &state.unit))
+                .unwrap_or_else(|| "—".to_string()),
+        );
+
+        let inner_width = header_layout[1].width.saturating_sub(2) as usize;
+        let clamped = Self::clamp_text(&metrics_text, inner_width);
+        let padding = inner_width.saturating_sub(clamped.chars().count());
+        let left_pad = padding / 2;
+        let right_pad = padding.saturating_sub(left_pad);
+
+        let mut spans = Vec::new();
+        spans.push(Span::styled("\\", Style::default().fg(self.theme.header)));
+        if left_pad > 0 {
+            spans.push(Span::styled(
+                "-".repeat(left_pad),
+                Style::default().fg(self.theme.border),
+            ));
+        }
+        spans.push(Span::styled(
+            clamped,
+            Style::default().fg(self.theme.text_secondary),
+        ));
+        if right_pad > 0 {
+            spans.push(Span::styled(
+                "-".repeat(right_pad),
+                Style::default().fg(self.theme.border),
+            ));
+        }
+        spans.push(Span::styled("/", Style::default().fg(self.theme.header)));
+        let metrics =
+            Paragraph::new(Line::from(spans)).style(Style::default().bg(self.theme.panel_bg));
+        f.render_widget(metrics, header_layout[1]);
+
+        let body_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(6),
+                Constraint::Length(2),
+                Constraint::Length(3),
+                Constraint::Length(1),
+            ])
+            .split(header_layout[2]);
+
+        if body_layout.len() < 6 {
+            return;
+        }
+
+        let summary_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(body_layout[0]);
+
+        let current_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.border))
+            .style(Style::default().bg(self.theme.background));
+        f.render_widget(current_block, summary_chunks[0]);
+
+        let current_inner = Rect {
+            x: summary_chunks[0].x + 1,
+            y: summary_chunks[0].y + 1,
+            width: summary_chunks[0].width.saturating_sub(2),
+            height: summary_chunks[0].height.saturating_sub(2),
+        };
+
+        let current_value = state
+            .latest_value
+            .map(|value| self.format_value_with_unit(value, &state.unit))
+            .unwrap_or_else(|| "—".to_string());
+
+        let mut current_line = vec![
+            Span::styled("Current ", Style::default().fg(self.theme.text_secondary)),
+            Span::styled(
+                current_value,
+                Style::default()
+                    .fg(self.theme.text_primary)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ];
+
+        if let Some(delta) = self.indicator_delta_label(state) {
+            current_line.push(Span::raw(" "));
+            current_line.push(Span::styled(delta, Style::default().fg(self.theme.partial)));
+        }
+
+        let current_paragraph = Paragraph::new(Line::from(current_line))
+            .alignment(Alignment::Center)
+            .style(Style::default().bg(self.theme.background));
+        f.render_widget(current_paragraph, current_inner);
+
+        let direction_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.border))
+            .style(Style::default().bg(self.theme.background));
+        f.render_widget(direction_block, summary_chunks[1]);
+
+        let direction_inner = Rect {
+            x: summary_chunks[1].x + 1,
+            y: summary_chunks[1].y + 1,
+            width: summary_chunks[1].width.saturating_sub(2),
+            height: summary_chunks[1].height.saturating_sub(2),
+        };
+
+        let direction_text = match state.direction {
+            IndicatorDirection::HigherIsBetter => "Higher is better",
+            IndicatorDirection::LowerIsBetter => "Lower is better",
+            IndicatorDirection::WithinRange => "Target range",
+        };
+
+        let trend_status = Self::indicator_trend_status(state);
+        let (trend_icon, trend_label) = Self::indicator_trend_display(trend_status);
+        let trend_color = self.trend_color(trend_status);
+
+        let direction_paragraph = Paragraph::new(vec![
+            Line::from(vec![
+                Span::styled("Direction ", Style::default().fg(self.theme.text_secondary)),
+                Span::styled(direction_text, Style::default().fg(self.theme.text_primary)),
+            ]),
+            Line::from(vec![
+                Span::styled(trend_icon, Style::default().fg(trend_color)),
+                Span::raw(" "),
+                Span::styled(trend_label, Style::default().fg(self.theme.text_secondary)),
+            ]),
+        ])
+        .alignment(Alignment::Center)
+        .style(Style::default().bg(self.theme.background));
+        f.render_widget(direction_paragraph, direction_inner);
+
+        let progress_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(72), Constraint::Percentage(28)])
+            .split(body_layout[1]);
+
+        let percent_value = match (state.range, state.period_progress) {
+            (Some(range), _) => state
+                .latest_value
+                .map(|current| Self::range_score(current, range)),
+            (None, Some(progress)) => progress.pace_percent(),
+            (None, None) => state
+                .target
+                .filter(|target| *target > 0.0)
+                .and_then(|target| state.latest_value.map(|current| (current / target) * 100.0)),
+        };
+        let gauge_percent = percent_value.unwrap_or(0.0).clamp(0.0, 100.0) as u16;
+        let severity = Severity::from_percentage(f64::from(gauge_percent), 100.0, 70.0);
+        let gauge_label = percent_value
+            .map(|value| format!("{} {:.0}%", severity.symbol(), value.clamp(0.0, 999.0)))
+            .unwrap_or_else(|| "—".to_string());
+
+        let gauge = Gauge::default()
+            .percent(gauge_percent)
+            .label(gauge_label)
+            .gauge_style(
+                Style::default()
+                    .fg(self.theme.severity_color(severity))
+                    .bg(self.theme.background),
+            )
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(Self::goal_pace_title(state.period_progress))
+                    .border_style(Style::default().fg(self.theme.border))
+                    .style(Style::default().bg(self.theme.background)),
+            );
+        f.render_widget(gauge, progress_chunks[0]);
+
+        let trend_line = Paragraph::new(Line::from(vec![
+            Span::styled(trend_icon, Style::default().fg(trend_color)),
+            Span::raw(" "),
+            Span::styled(trend_label, Style::default().fg(self.theme.text_secondary)),
+        ]))
+        .alignment(Alignment::Center)
+        .style(Style::default().bg(self.theme.panel_bg));
+        f.render_widget(trend_line, progress_chunks[1]);
+
+        let history_block = Block::default()
+            .borders(Borders::ALL)
+            .title(" 7-Day History ")
+            .border_style(Style::default().fg(self.theme.border))
+            .style(Style::default().bg(self.theme.background));
+        f.render_widget(history_block, body_layout[2]);
+
+        let history_inner = Rect {
+            x: body_layout[2].x + 1,
+            y: body_layout[2].y + 1,
+            width: body_layout[2].width.saturating_sub(2),
+            height: body_layout[2].height.saturating_sub(2),
+        };
+
+        if history_inner.height == 0 {
+            return;
+        }
+
+        let history_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(1)])
+            .split(history_inner);
+
+        if history_layout.len() < 2 {
+            return;
+        }
+
+        if !state.history.is_empty() {
+            let data: Vec<u64> = state
+                .history
+                .iter()
+                .map(|value| (value.max(0.0) * 100.0) as u64)
+                .collect();
+            let sparkline = Sparkline::default()
+                .data(&data)
+                .style(Style::default().fg(self.theme.partial));
+            f.render_widget(sparkline, history_layout[0]);
+        } else {
+            let placeholder = Paragraph::new("No history yet")
+                .alignment(Alignment::Center)
+                .style(
+                    Style::default()
+                        .fg(self.theme.text_secondary)
+                        .bg(self.theme.background),
+                );
+            f.render_widget(placeholder, history_layout[0]);
+        }
+
+        let start_value = state
+            .history
+            .first()
+            .copied()
+            .map(|value| self.format_value_with_unit(value, &state.unit))
+            .unwrap_or_else(|| "—".to_string());
+        let end_value = state
+            .history
+            .last()
+            .copied()
+            .map(|value| self.format_value_with_unit(value, &state.unit))
+            .unwrap_or_else(|| "—".to_string());
+        let last_update = state
+            .last_updated
+            .map(|date| date.to_string())
+            .unwrap_or_else(|| "—".to_string());
+
+        let footer = format!(
+            "Start {}   End {}   Last update {}",
+            start_value, end_value, last_update
+        );
+        let footer_paragraph = Paragraph::new(footer).alignment(Alignment::Center).style(
+            Style::default()
+                .fg(self.theme.text_secondary)
+                .bg(self.theme.background),
+        );
+        f.render_widget(footer_paragraph, history_layout[1]);
+
+        let quick_actions = Paragraph::new(Self::indicator_quick_actions_text(state))
+            .alignment(Alignment::Center)
+            .style(
+                Style::default()
+                    .fg(self.theme.text_secondary)
+                    .bg(self.theme.panel_bg),
+            );
+        f.render_widget(quick_actions, body_layout[3]);
+
+        let unit_label = Self::unit_label(&state.unit);
+        let input_display = format!("[ {} ] {}", state.buffer, unit_label);
+        let input_title = Self::clamp_text(
+            &self.indicator_input_title(state),
+            body_layout[4].width.saturating_sub(2) as usize,
+        );
+        let input_paragraph = Paragraph::new(input_display)
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(self.theme.text_primary))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(input_title)
+                    .border_style(Style::default().fg(self.theme.border))
+                    .style(Style::default().bg(self.theme.background)),
+            );
+        f.render_widget(input_paragraph, body_layout[4]);
+
+        let helper_footer = Paragraph::new(Line::from(vec![
+            Span::styled("Enter", Style::default().fg(self.theme.header)),
+            Span::raw(" Save  "),
+            Span::styled("Backspace", Style::default().fg(self.theme.header)),
+            Span::raw(" Delete  "),
+            Span::styled("Esc", Style::default().fg(self.theme.header)),
+            Span::raw(" Cancel"),
+        ]))
+        .alignment(Alignment::Center)
+        .style(
+            Style::default()
+                .fg(self.theme.text_secondary)
+                .bg(self.theme.panel_bg),
+        );
+        f.render_widget(helper_footer, body_layout[5]);
+    }
+
+    /// 100 inside the band, falling off linearly with distance measured in band widths
+    fn range_score(value: f64, range: (f64, f64)) -> f64 {
+        let deviation = IndicatorDef::range_deviation(value, range).abs();
+        let width = if (range.1 - range.0).is_finite() {
+            (range.1 - range.0).max(1.0)
+        } else {
+            value.abs().max(1.0)
+        };
+        (100.0 - deviation / width * 100.0).clamp(0.0, 100.0)
+    }
+
+    fn format_range(&self, (min, max): (f64, f64), unit: &IndicatorUnit) -> String {
+        match (min.is_finite(), max.is_finite()) {
+            (true, true) => format!(
+                "{}–{}",
+                Self::format_value_for_unit(min, unit),
+                self.format_value_with_unit(max, unit)
+            ),
+            (true, false) => format!("≥ {}", self.format_value_with_unit(min, unit)),
+            (false, true) => format!("≤ {}", self.format_value_with_unit(max, unit)),
+            (false, false) => "any".to_string(),
+        }
+    }
+
+    fn parse_value_from_buffer(buffer: &str, unit: &IndicatorUnit) -> anyhow::Result<f64> {
+        let value = crate::units::parse_value(buffer, unit)?;
+        Ok(Self::clamp_value_for_unit(value, unit))
+    }
+
+    fn clamp_value_for_unit(value: f64, unit: &IndicatorUnit) -> f64 {
+        match unit {
+            IndicatorUnit::Percent => value.clamp(0.0, 100.0),
+            _ => value.max(0.0),
+        }
+    }
+
+    fn format_value_for_unit(value: f64, unit: &IndicatorUnit) -> String {
+        match unit {
+            IndicatorUnit::Percent => format!("{:.0}", value),
+            IndicatorUnit::Count => format!("{:.0}", value),
+            IndicatorUnit::Minutes | IndicatorUnit::Dollars | IndicatorUnit::Custom(_) => {
+                if value.fract().abs() < f64::EPSILON {
+                    format!("{:.0}", value)
+                } else {
+                    format!("{:.2}", value)
+                }
+            }
+        }
+    }
+
+    fn unit_label(unit: &IndicatorUnit) -> String {
+        match unit {
+            IndicatorUnit::Count => "count".to_string(),
+            IndicatorUnit::Minutes => "minutes".to_string(),
+            IndicatorUnit::Dollars => "dollars".to_string(),
+            IndicatorUnit::Percent => "%".to_string(),
+            IndicatorUnit::Custom(label) => label.clone(),
+        }
+    }
+
+    fn adjust_buffer_value(buffer: &str, unit: &IndicatorUnit, delta: f64) -> String {
+        let current = crate::units::parse_value(buffer, unit).unwrap_or(0.0);
+        let adjusted = Self::clamp_value_for_unit(current + delta, unit);
+        Self::format_value_for_unit(adjusted, unit)
+    }
+
+    fn apply_indicator_update(&mut self, state: &IndicatorUpdateState) -> anyhow::Result<()> {
+        if state.buffer.trim().is_empty() {
+            self.toasts
+                .show_error("Enter a value before saving".to_string());
+            return Ok(());
+        }
+
+        let value = match Self::parse_value_from_buffer(&state.buffer, &state.unit) {
+            Ok(value) => value,
+            Err(err) => {
+                self.toasts
+                    .show_error(format!("Invalid indicator value: {}", err));
+                return Ok(());
+            }
+        };
+
+        self.record_indicator_observation(
+            &state.indicator_id,
+            &state.unit,
+            value,
+            state.action_id.clone(),
+        )
+    }
+
+    /// Append an observation for now and refresh the in-memory indicator caches
+    fn record_indicator_observation(
+        &mut self,
+        indicator_id: &str,
+        unit: &IndicatorUnit,
+        value: f64,
+        action_id: Option<String>,
+    ) -> anyhow::Result<()> {
+        let mut observation = Observation::now(indicator_id.to_string(), value, unit.clone());
+        observation.action_id = action_id;
+
+        self.record_observation(observation)
+    }
+
+    /// Persist an observation and refresh the in-memory indicator state
+    fn record_observation(&mut self, mut observation: Observation) -> anyhow::Result<()> {
+        observation.stamp_time();
+        self.performance_history = None;
+        crate::data::append_observation(&observation, &self.config)?;
+        self.events.emit(Event::new(
+            EventKind::ObservationAdded,
+            serde_json::json!({
+                "indicator_id": observation.indicator_id,
+                "when": observation.when,
+                "value": observation.value,
+                "unit": observation.unit,
+                "source": observation.source,
+            }),
+        ));
+        self.refresh_scoreboard();
+        self.refresh_objective_pace();
+        let indicator_id = observation.indicator_id.as_str();
+        let unit = &observation.unit;
+        let value = observation.value;
+
+        if let Some(indicator) = self
+            .indicators
+            .indicators
+            .iter_mut()
+            .find(|def| def.id == indicator_id)
+        {
+            indicator.modified = chrono::Utc::now();
+        }
+
+        if let Err(err) = crate::data::save_indicators(&self.indicators, &self.config) {
+            self.toasts
+                .show_error(format!("Failed to update indicators: {}", err));
+        }
+
+        if let Some(indicator) = self.indicators_map.get_mut(indicator_id) {
+            indicator.current_value = value;
+            indicator.history.push(crate::models::IndicatorEntry {
+                timestamp: chrono::Utc::now(),
+                value,
+                note: None,
+            });
+        }
+
+        let name = self
+            .indicators
+            .indicators
+            .iter()
+            .find(|def| def.id == indicator_id)
+            .map(|def| def.name.clone())
+            .unwrap_or_else(|| indicator_id.to_string());
+        self.log_activity(
+            ActivityKind::Observation,
+            format!(
+                "Logged {}: {}",
+                name,
+                self.format_value_with_unit(value, unit)
+            ),
+        );
+
+        self.toasts
+            .show_success("Indicator value recorded".to_string());
+        if let Some(message) = self.cap_warning(indicator_id, value) {
+            self.toasts.show_warning(message);
+        }
+
+        // Percent indicators computed from this counter follow along
+        match crate::data::sync_ratio_indicators(&observation, &self.indicators, &self.config) {
+            Ok(derived) => {
+                for obs in derived {
+                    if let Some(indicator) = self.indicators_map.get_mut(&obs.indicator_id) {
+                        indicator.current_value = obs.value;
+                        indicator.history.push(crate::models::IndicatorEntry {
+                            timestamp: chrono::Utc::now(),
+                            value: obs.value,
+                            note: None,
+                        });
+                    }
+                }
+            }
+            Err(err) => self
+                .toasts
+                .show_error(format!("Failed to update ratio indicators: {}", err)),
+        }
+        self.refresh_dashboard_data();
+
+        // Refresh dashboard cursor bounds
+        if !self.dashboard_signal_ids.is_empty() {
+            self.dashboard_signal_index = self
+                .dashboard_signal_index
+                .min(self.dashboard_signal_ids.len().saturating_sub(1));
+        }
+
+        Ok(())
+    }
+
+    fn get_outcome_by_type(&self, outcome_type: OutcomeType) -> &crate::models::Outcome {
+        match outcome_type {
+            OutcomeType::Work => &self.goals.work,
+            OutcomeType::Health => &self.goals.health,
+            OutcomeType::Family => &self.goals.family,
+        }
+    }
+
+    fn get_outcome_by_type_mut(
+        &mut self,
+        outcome_type: OutcomeType,
+    ) -> &mut crate::models::Outcome {
+        match outcome_type {
+            OutcomeType::Work => &mut self.goals.work,
+            OutcomeType::Health => &mut self.goals.health,
+            OutcomeType::Family => &mut self.goals.family,
+        }
+    }
+
+    fn open_editor(&mut self) {
+        // Only allow editing when focused on Actions panel
+        if self.focus_panel == FocusPanel::Actions && !self.day_is_locked() {
+            let action_text = {
+                let outcome = self.get_selected_outcome();
+                outcome.actions[self.selected_action].text.clone()
+            };
+            self.text_editor.activate_with(
+                "Edit Action",
+                &action_text,
+                crate::models::MAX_ACTION_LENGTH,
+            );
+            self.editor_context = Some(EditorContext::Action {
+                outcome_type: self.selected_outcome,
+                index: self.selected_action,
+            });
+        }
+    }
+
+    /// Put the displayed day's checklist on the system clipboard
+    fn copy_day_summary(&mut self) {
+        let text = summary::standup_text(&self.goals);
+        match crate::clipboard::write(&text, self.settings.clipboard_command.as_deref()) {
+            Ok(()) => self.toasts.show_success(format!(
+                "Copied the summary of {} to the clipboard",
+                self.goals.date.format("%b %-d")
+            )),
+            Err(e) => self.toasts.show_error(format!("Copy failed: {:#}", e)),
+        }
+    }
+
+    /// Draw the screen off-terminal at its current size and save it as
+    /// ANSI text and SVG for sharing
+    fn save_screenshot(&mut self) {
+        let area = self.frame_size;
+        let buffer =
+            Terminal::new(TestBackend::new(area.width, area.height)).and_then(|mut terminal| {
+                terminal.draw(|f| self.render(f))?;
+                Ok(terminal.backend().buffer().clone())
+            });
+        let saved = buffer.map_err(anyhow::Error::from).and_then(|buffer| {
+            snapshot::write(
+                &buffer,
+                self.theme.text_primary,
+                self.theme.background,
+                chrono::Local::now().naive_local(),
+                &self.config,
+            )
+        });
+        match saved {
+            Ok((text, image)) => self.toasts.show_success(format!(
+                "Saved the screen to {} and {}",
+                text.display(),
+                image.display()
+            )),
+            Err(e) => self
+                .toasts
+                .show_error(format!("Screenshot failed: {:#}", e)),
+        }
+    }
+
+    fn open_vision_editor(&mut self) {
+        if self.focus_panel != FocusPanel::Outcomes {
+            self.focus_panel = FocusPanel::Outcomes;
+        }
+        let outcome_type = self.selected_outcome;
+        let vision_text = self.vision.get_vision(&outcome_type).to_string();
+        self.text_editor.activate_with(
+            "Edit 5-Year Vision",
+            &vision_text,
+            crate::models::MAX_VISION_LENGTH,
+        );
+        self.text_editor.allow_external = true;
+        self.editor_context = Some(EditorContext::Vision { outcome_type });
+    }
+
+    /// Open the day's reflection for editing, drafting one first if none has
+    /// been saved. With a summary provider configured the draft is requested
+    /// in the background and the editor opens when it arrives.
+    fn open_evening_summary(&mut self) -> anyhow::Result<()> {
+        let date = self.goals.date;
+        if let Some(saved) = crate::data::load_reflection(date, &self.config)? {
+            self.open_reflection_editor(date, &gratitude::split(&saved).0);
+            return Ok(());
+        }
+        if self.summary_draft.is_some() {
+            self.toasts
+                .show_info("Still drafting your reflection…".to_string());
+            return Ok(());
+        }
+
+        let observations = crate::data::read_observations_range(
+            date - chrono::Duration::days(30),
+            date,
+            &self.config,
+        )
+        .unwrap_or_default();
+        let input = SummaryInput::gather(&self.goals, &self.indicators, &observations);
+        match DraftRequest::start(&input, &self.settings.summary) {
+            Some(request) => {
+                self.summary_draft = Some(request);
+                self.toasts
+                    .show_info("Drafting your reflection…".to_string());
+            }
+            None => self.open_reflection_editor(date, &summary::template_draft(&input)),
+        }
+        Ok(())
+    }
+
+    /// Open the reflection editor on a finished draft; a failed request
+    /// falls back to the template draft
+    pub(crate) fn finish_summary_draft(&mut self, request: DraftRequest, result: anyhow::Result<String>) {
+        let text = match result {
+            Ok(draft) => draft,
+            Err(e) => {
+                self.toasts
+                    .show_warning(format!("{:#}; using template draft", e));
+                request.fallback
+            }
+        };
+        self.open_reflection_editor(request.date, &text);
+    }
+
+    fn open_reflection_editor(&mut self, date: chrono::NaiveDate, text: &str) {
+        self.text_editor.activate_with(
+            "Evening Reflection",
+            text,
+            crate::models::MAX_REFLECTION_LENGTH,
+        );
+        self.text_editor.allow_external = true;
+        self.editor_context = Some(EditorContext::Reflection { date });
+    }
+
+    /// Ask for the next gratitude item of `date`, showing the one saved in
+    /// its place; an empty answer ends the list
+    fn prompt_gratitude(&mut self, date: chrono::NaiveDate, items: Vec<String>) {
+        let saved = gratitude::load(date, &self.config).unwrap_or_default();
+        self.text_editor.activate_with(
+            &format!(
+                "Grateful for ({}/{}) · empty to finish",
+                items.len() + 1,
+                gratitude::GRATITUDE_ITEMS
+            ),
+            saved.get(items.len()).map(String::as_str).unwrap_or(""),
+            crate::models::MAX_GOAL_LENGTH,
+        );
+        self.editor_context = Some(EditorContext::Gratitude { date, items });
+    }
+
+    fn refresh_gratitude(&mut self) {
+        let today = self.max_date;
+        self.gratitude_streak = gratitude::streak(today, &self.config).unwrap_or(0);
+        self.gratitude_recall = gratitude::recall(today, &self.config).unwrap_or_default();
+    }
+
+    fn open_day_note_editor(&mut self) {
+        let note = self.day_note.clone().unwrap_or_default();
+        self.text_editor.activate_with(
+            &format!("Note for {}", self.goals.date.format("%b %d")),
+            &note,
+            crate::models::MAX_DAY_NOTE_LENGTH,
+        );
+        self.editor_context = Some(EditorContext::DayNote);
+    }
+
+    fn open_indicator_links(&mut self) {
+        if self.day_is_locked() {
+            return;
+        }
+        if self.focus_panel != FocusPanel::Actions {
+            self.focus_panel = FocusPanel::Actions;
+        }
+        let action = self
+            .get_selected_outcome()
+            .actions
+            .get(self.selected_action);
+        if action.is_none_or(|action| action.text.trim().is_empty()) {
+            self.toasts
+                .show_info("Write the action before linking indicators".to_string());
+            return;
+        }
+        if self.indicator_link_choices().is_empty() {
+            self.toasts
+                .show_info("No active indicators to link".to_string());
+            return;
+        }
+        self.modal = Some(ModalState::IndicatorLinks(IndicatorLinkState {
+            outcome_type: self.selected_outcome,
+            action_index: self.selected_action,
+            selection: 0,
+        }));
+    }
+
+    fn indicator_link_choices(&self) -> Vec<&IndicatorDef> {
+        self.indicators
+            .indicators
+            .iter()
+            .filter(|def| def.active)
+            .collect()
+    }
+
+    /// Link an indicator to an action, logging `value` on completion or
+    /// asking when it is `None`; `link` of `None` removes the link
+    fn set_indicator_link(
+        &mut self,
+        outcome_type: OutcomeType,
+        action_index: usize,
+        indicator_id: &str,
+        link: Option<Option<f64>>,
+    ) {
+        if self.day_is_locked() {
+            return;
+        }
+        let Some(action) = self
+            .get_outcome_by_type_mut(outcome_type)
+            .actions
+            .get_mut(action_index)
+        else {
+            return;
+        };
+        match link {
+            Some(value) => action.link_indicator(indicator_id, value),
+            None => action.unlink_indicator(indicator_id),
+        }
+        self.mark_dirty();
+
+        let name = self
+            .indicators
+            .indicators
+            .iter()
+            .find(|def| def.id == indicator_id)
+            .map(|def| def.name.clone())
+            .unwrap_or_else(|| indicator_id.to_string());
+        let verb = if link.is_some() { "Linked" } else { "Unlinked" };
+        self.log_activity(
+            ActivityKind::Observation,
+            format!("{} indicator: {}", verb, name),
+        );
+    }
+
+    /// Log the values of an action's directly linked indicators and of the
+    /// auto rules its tags match after it is completed, then ask for the
+    /// first linked indicator without a set value
+    fn feed_linked_indicators(
+        &mut self,
+        outcome_type: OutcomeType,
+        action_index: usize,
+    ) -> anyhow::Result<()> {
+        let Some(action) = self
+            .get_outcome_by_type(outcome_type)
+            .actions
+            .get(action_index)
+            .cloned()
+        else {
+            return Ok(());
+        };
+        let mut ask = None;
+        for link in &action.indicator_links {
+            let Some(def) = self
+                .indicators
+                .indicators
+                .iter()
+                .find(|def| def.id == link.indicator_id && def.active)
+            else {
+                continue;
+            };
+            match link.value {
+                Some(value) => {
+                    let mut observation =
+                        Observation::new(def.id.clone(), self.goals.date, value, def.unit.clone());
+                    observation.source = ObservationSource::Automated;
+                    observation.action_id = Some(action.id.clone());
+                    observation.note = Some(format!("Completed: {}", action.text));
+                    self.record_observation(observation)?;
+                }
+                None if ask.is_none() => ask = Some(def.id.clone()),
+                None => {}
+            }
+        }
+        let meta_tags =
+            crate::data::load_or_create_day_meta(self.goals.date, &self.goals, &self.config)
+                .ok()
+                .and_then(|meta| {
+                    meta.outcome(outcome_type)
+                        .get(action_index)
+                        .map(|m| m.tags.clone())
+                })
+                .unwrap_or_default();
+        let tags = crate::automation::action_tags(&action.text, &meta_tags);
+        for observation in
+            crate::automation::observations_for(&action, &tags, &self.indicators, self.goals.date)
+        {
+            self.record_observation(observation)?;
+        }
+        if let Some(indicator_id) = ask {
+            self.open_indicator_update_modal(&indicator_id)?;
+            if let Some(ModalState::IndicatorUpdate(ref mut state)) = self.modal {
+                state.action_id = Some(action.id.clone());
+            }
+        }
+        Ok(())
+    }
+
+    fn open_objective_picker(&mut self) {
+        if self.day_is_locked() {
+            return;
+        }
+        if self.focus_panel != FocusPanel::Actions {
+            self.focus_panel = FocusPanel::Actions;
+        }
+
+        let outcome_type = self.selected_outcome;
+        let action_index = self.selected_action;
+
+        self.modal = Some(ModalState::ObjectivePicker(ObjectiveModalState {
+            outcome_type,
+            action_index,
+            selection: 0,
+            show_archived: false,
+        }));
+    }
+
+    fn objective_choices(
+        &self,
+        outcome_type: OutcomeType,
+        show_archived: bool,
+    ) -> Vec<ObjectiveChoice> {
+        self.objectives
+            .objectives
+            .iter()
+            .enumerate()
+            .filter(|(_, obj)| obj.domain == outcome_type && (show_archived || !obj.archived))
+            .map(|(index, obj)| ObjectiveChoice {
+                storage_index: index,
+                id: obj.id.clone(),
+                title: obj.title.clone(),
+                status: obj.status.clone(),
+                color: obj.color.clone(),
+                weekly_budget_min: obj.weekly_budget_min,
+                queue_progress: obj.queue_progress(),
+                archived: obj.archived,
+            })
+            .collect()
+    }
+
+    /// Move an objective to its next status and persist it
+    pub fn cycle_objective_status(&mut self, storage_index: usize) -> anyhow::Result<()> {
+        let Some(objective) = self.objectives.objectives.get_mut(storage_index) else {
+            return Ok(());
+        };
+        let previous = objective.clone();
+        objective.cycle_status();
+        objective.modified = chrono::Utc::now();
+
+        if let Err(e) = crate::data::save_objectives(&self.objectives, &self.config) {
+            self.objectives.objectives[storage_index] = previous;
+            self.toasts
+                .show_error(format!("Failed to save objectives: {}", e));
+            return Err(e);
+        }
+
+        let objective = &self.objectives.objectives[storage_index];
+        let message = format!("{} is now {}", objective.title, objective.status.label());
+        self.log_activity(ActivityKind::Objective, message);
+        self.refresh_objective_pace();
+        Ok(())
+    }
+
+    /// Mark an objective complete today, archive it, and record the
+    /// completion in the activity log and event stream
+    pub fn complete_objective(&mut self, objective_id: &str) -> anyhow::Result<()> {
+        let Some(index) = self
+            .objectives
+            .objectives
+            .iter()
+            .position(|o| o.id == objective_id)
+        else {
+            return Ok(());
+        };
+        let today = chrono::Local::now().date_naive();
+        let previous = self.objectives.objectives[index].clone();
+        let objective = &mut self.objectives.objectives[index];
+        objective.complete(today);
+        objective.modified = chrono::Utc::now();
+
+        if let Err(e) = crate::data::save_objectives(&self.objectives, &self.config) {
+            self.objectives.objectives[index] = previous;
+            self.toasts
+                .show_error(format!("Failed to save objectives: {}", e));
+            return Err(e);
+        }
+
+        let objective = &self.objectives.objectives[index];
+        let title = objective.title.clone();
+        self.events.emit(Event::new(
+            EventKind::ObjectiveCompleted,
+            serde_json::json!({
+                "objective_id": objective.id,
+                "title": objective.title,
+                "domain": objective.domain,
+                "start": objective.start,
+                "target": objective.end,
+                "completed_on": today,
+            }),
+        ));
+        self.log_activity(
+            ActivityKind::Objective,
+            format!("Completed objective: {}", title),
+        );
+        self.refresh_objective_pace();
+        self.toasts
+            .show_info(format!("🎉 {} complete and archived", title));
+        Ok(())
+    }
+
+    /// Move an objective to the next palette color and persist it
+    pub fn cycle_objective_color(&mut self, storage_index: usize) -> anyhow::Result<()> {
+        let Some(objective) = self.objectives.objectives.get_mut(storage_index) else {
+            return Ok(());
+        };
+        let previous = objective.color.clone();
+        objective.color = Some(theme::next_objective_color(previous.as_deref()).to_string());
+        objective.modified = chrono::Utc::now();
+
+        if let Err(e) = crate::data::save_objectives(&self.objectives, &self.config) {
+            self.objectives.objectives[storage_index].color = previous;
+            self.toasts
+                .show_error(format!("Failed to save objectives: {}", e));
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Accent for an action: its primary objective's color, if it has one
+    fn action_accent(&self, action: &crate::models::Action) -> Option<Color> {
+        let primary = action.get_all_objective_ids().into_iter().next()?;
+        let objective = self
+            .objectives
+            .objectives
+            .iter()
+            .find(|o| o.id == primary)?;
+        theme::objective_accent(objective.color.as_deref())
+    }
+
+    fn objective_index_in_domain(
+        &self,
+        outcome_type: OutcomeType,
+        objective_id: &str,
+    ) -> Option<usize> {
+        let mut index = 0;
+        for obj in self
+            .objectives
+            .objectives
+            .iter()
+            .filter(|o| o.domain == outcome_type)
+        {
+            if obj.id == objective_id {
+                return Some(index);
+            }
+            index += 1;
+        }
+        None
+    }
+
+    fn start_objective_creation(&mut self, outcome_type: OutcomeType, link_action: Option<usize>) {
+        self.text_editor
+            .activate_with("Create Objective", "", crate::models::MAX_GOAL_LENGTH);
+        self.editor_context = Some(EditorContext::ObjectiveTitle {
+            outcome_type,
+            objective_id: None,
+            link_action,
+        });
+    }
+
+    fn start_objective_rename(&mut self, outcome_type: OutcomeType, objective_id: String) {
+        if let Some(objective) = self
+            .objectives
+            .objectives
+            .iter()
+            .find(|o| o.id == objective_id)
+        {
+            self.text_editor.activate_with(
+                "Rename Objective",
+                &objective.title,
+                crate::models::MAX_GOAL_LENGTH,
+            );
+            self.editor_context = Some(EditorContext::ObjectiveTitle {
+                outcome_type,
+                objective_id: Some(objective.id.clone()),
+                link_action: None,
+            });
+        }
+    }
+
+    fn start_objective_budget_edit(&mut self, objective_id: String) {
+        if let Some(objective) = self
+            .objectives
+            .objectives
+            .iter()
+            .find(|o| o.id == objective_id)
+        {
+            let current = objective
+                .weekly_budget_min
+                .map(crate::models::format_minutes)
+                .unwrap_or_default();
+            self.text_editor.activate_with(
+                "Weekly Budget (e.g. 4h30m, empty to clear)",
+                &current,
+                16,
+            );
+            self.editor_context = Some(EditorContext::ObjectiveBudget { objective_id });
+        }
+    }
+
+    fn start_objective_dates_edit(&mut self, objective_id: String) {
+        if let Some(objective) = self
+            .objectives
+            .objectives
+            .iter()
+            .find(|o| o.id == objective_id)
+        {
+            let current = match objective.end {
+                Some(end) => format!("{}..{}", objective.start, end),
+                None => String::new(),
+            };
+            self.text_editor.activate_with(
+                "Target Date (YYYY-MM-DD or START..TARGET, empty to clear)",
+                &current,
+                24,
+            );
+            self.editor_context = Some(EditorContext::ObjectiveDates { objective_id });
+        }
+    }
+
+    /// Set an objective's start and target dates and persist them. A
+    /// `None` start keeps the current one; a `None` target makes the
+    /// objective open-ended.
+    pub fn set_objective_dates(
+        &mut self,
+        objective_id: &str,
+        start: Option<chrono::NaiveDate>,
+        end: Option<chrono::NaiveDate>,
+    ) -> anyhow::Result<()> {
+        let Some(index) = self
+            .objectives
+            .objectives
+            .iter()
+            .position(|o| o.id == objective_id)
+        else {
+            return Ok(());
+        };
+        let objective = &mut self.objectives.objectives[index];
+        let start = start.unwrap_or(objective.start);
+        if let Some(end) = end.filter(|end| *end < start) {
+            self.toasts.show_error(format!(
+                "Target date {} is before the start date {}",
+                end, start
+            ));
+            return Ok(());
+        }
+        let previous = (objective.start, objective.end);
+        objective.start = start;
+        objective.end = end;
+        objective.modified = chrono::Utc::now();
+
+        if let Err(e) = crate::data::save_objectives(&self.objectives, &self.config) {
+            let objective = &mut self.objectives.objectives[index];
+            (objective.start, objective.end) = previous;
+            self.toasts
+                .show_error(format!("Failed to save objectives: {}", e));
+            return Err(e);
+        }
+
+        let title = self.objectives.objectives[index].title.clone();
+        let message = match end {
+            Some(end) => format!("Target date for {}: {}", title, end),
+            None => format!("Cleared target date for {}", title),
+        };
+        self.log_activity(ActivityKind::Objective, message);
+        self.refresh_objective_pace();
+        Ok(())
+    }
+
+    /// Set or clear an objective's weekly effort budget and persist it
+    pub fn set_objective_budget(
+        &mut self,
+        objective_id: &str,
+        budget: Option<u32>,
+    ) -> anyhow::Result<()> {
+        let Some(index) = self
+            .objectives
+            .objectives
+            .iter()
+            .position(|o| o.id == objective_id)
+        else {
+            return Ok(());
+        };
+        let objective = &mut self.objectives.objectives[index];
+        let previous = objective.weekly_budget_min;
+        objective.weekly_budget_min = budget;
+        objective.modified = chrono::Utc::now();
+
+        if let Err(e) = crate::data::save_objectives(&self.objectives, &self.config) {
+            self.objectives.objectives[index].weekly_budget_min = previous;
+            self.toasts
+                .show_error(format!("Failed to save objectives: {}", e));
+            return Err(e);
+        }
+
+        let title = self.objectives.objectives[index].title.clone();
+        let message = match budget {
+            Some(minutes) => format!(
+                "Weekly budget for {}: {}",
+                title,
+                crate::models::format_minutes(minutes)
+            ),
+            None => format!("Cleared weekly budget for {}", title),
+        };
+        self.log_activity(ActivityKind::Objective, message);
+        Ok(())
+    }
+
+    /// Append an item to an objective's queue and persist it
+    pub fn add_queue_item(&mut self, objective_id: &str, title: String) -> anyhow::Result<()> {
+        let backup = self.objectives.clone();
+        let Some(objective) = self
+            .objectives
+            .objectives
+            .iter_mut()
+            .find(|o| o.id == objective_id)
+        else {
+            return Ok(());
+        };
+        objective.push_queue_item(title.clone());
+        objective.modified = chrono::Utc::now();
+        let (done, total) = objective.queue_progress().unwrap_or_default();
+
+        if let Err(e) = crate::data::save_objectives(&self.objectives, &self.config) {
+            self.objectives = backup;
+            self.toasts
+                .show_error(format!("Failed to save objectives: {}", e));
+            return Err(e);
+        }
+        self.toasts
+            .show_info(format!("Queued {} ({}/{} items)", title, done, total));
+        Ok(())
+    }
+
+    /// Move the queues of objectives linked to an action forward when it is
+    /// completed, or back when it is unchecked the same day
+    fn step_linked_queues(&mut self, objective_ids: &[String], completed: bool) {
+        let date = self.goals.date;
+        let mut moved = Vec::new();
+        for objective in self
+            .objectives
+            .objectives
+            .iter_mut()
+            .filter(|o| objective_ids.contains(&o.id))
+        {
+            let stepped = if completed {
+                objective.advance_queue(date)
+            } else {
+                objective.rewind_queue(date)
+            };
+            if let Some(item) = stepped {
+                objective.modified = chrono::Utc::now();
+                let (done, total) = objective.queue_progress().unwrap_or_default();
+                moved.push(format!("{} {}/{} items", item, done, total));
+            }
+        }
+        if moved.is_empty() {
+            return;
+        }
+
+        if let Err(e) = crate::data::save_objectives(&self.objectives, &self.config) {
+            self.toasts
+                .show_error(format!("Failed to save queue progress: {}", e));
+            return;
+        }
+        if completed {
+            self.log_activity(
+                ActivityKind::Objective,
+                format!("Finished {}", moved.join(", ")),
+            );
+        }
+    }
+
+    /// Move an objective to the trash; its action links stay until it is
+    /// purged, so restoring it from the trash brings them back
+    fn delete_objective(&mut self, objective_id: &str) -> anyhow::Result<()> {
+        match trash::trash_objective(&mut self.objectives, objective_id, &self.config) {
+            Ok(removed) => {
+                self.log_activity(
+                    ActivityKind::Objective,
+                    format!("Trashed objective: {}", removed.title),
+                );
+                self.toasts.show_info(format!(
+                    "Moved '{}' to the trash (Z to restore)",
+                    removed.title
+                ));
+            }
+            Err(e) => self
+                .toasts
+                .show_error(format!("Failed to trash objective: {}", e)),
+        }
+        Ok(())
+    }
+
+    /// Move an indicator to the trash, keeping its observations
+    fn delete_indicator(&mut self, indicator_id: &str) -> anyhow::Result<()> {
+        match trash::trash_indicator(&mut self.indicators, indicator_id, &self.config) {
+            Ok(removed) => {
+                self.indicators_map.remove(indicator_id);
+                self.dashboard_signal_ids.retain(|id| id != indicator_id);
+                self.dashboard_performance_ids
+                    .retain(|id| id != indicator_id);
+                self.dashboard_signal_index = self
+                    .dashboard_signal_index
+                    .min(self.dashboard_signal_ids.len().saturating_sub(1));
+                self.dashboard_performance_index = self
+                    .dashboard_performance_index
+                    .min(self.dashboard_performance_ids.len().saturating_sub(1));
+                self.toasts.show_info(format!(
+                    "Moved '{}' to the trash (Z to restore)",
+                    removed.name
+                ));
+            }
+            Err(e) => self
+                .toasts
+                .show_error(format!("Failed to trash indicator: {}", e)),
+        }
+        Ok(())
+    }
+
+    fn toggle_action_objective(
+        &mut self,
+        outcome_type: OutcomeType,
+        action_index: usize,
+        objective_id: &str,
+    ) -> anyhow::Result<()> {
+        let outcome = self.get_outcome_by_type_mut(outcome_type);
+        if action_index >= outcome.actions.len() {
+            self.toasts
+                .show_error("Invalid action selection".to_string());
+            return Ok(());
+        }
+
+        let action = &mut outcome.actions[action_index];
+        let already_linked = action
+            .get_all_objective_ids()
+            .iter()
+            .any(|id| id == objective_id);
+
+        if already_linked {
+            action.remove_objective_id(objective_id);
+        } else {
+            action.add_objective_id(objective_id.to_string());
+        }
+        self.mark_dirty();
+
+        let title = self
+            .objectives
+            .objectives
+            .iter()
+            .find(|o| o.id == objective_id)
+            .map(|o| o.title.clone())
+            .unwrap_or_else(|| objective_id.to_string());
+        let verb = if already_linked { "Unlinked" } else { "Linked" };
+        self.log_activity(
+            ActivityKind::Objective,
+            format!("{} objective: {}", verb, title),
+        );
+        Ok(())
+    }
+
+    /// Link an action to an objective; false when the day is closed or the
+    /// action does not exist
+    fn link_action_to_objective(
+        &mut self,
+        outcome_type: OutcomeType,
+        action_index: usize,
+        objective_id: &str,
+    ) -> anyhow::Result<bool> {
+        if self.day_is_locked() {
+            return Ok(false);
+        }
+        let outcome = self.get_outcome_by_type_mut(outcome_type);
+        if action_index >= outcome.actions.len() {
+            self.toasts
+                .show_error("Invalid action selection".to_string());
+            return Ok(false);
+        }
+
+        let action = &mut outcome.actions[action_index];
+        if !action
+            .get_all_objective_ids()
+            .iter()
+            .any(|id| id == objective_id)
+        {
+            action.add_objective_id(objective_id.to_string());
+            self.save_current_goals()?;
+        }
+
+        Ok(true)
+    }
+
+    /// The active objective in the action's domain whose title best matches
+    /// its text, if the action is not linked to anything yet
+    pub fn suggest_objective(
+        &self,
+        outcome_type: OutcomeType,
+        action_index: usize,
+    ) -> Option<LinkSuggestion> {
+        let action = self
+            .get_outcome_by_type(outcome_type)
+            .actions
+            .get(action_index)?;
+        if !action.get_all_objective_ids().is_empty() {
+            return None;
+        }
+        self.objectives
+            .objectives
+            .iter()
+            .filter(|o| o.domain == outcome_type && o.status == ObjectiveStatus::Active)
+            .filter_map(|o| keyword_score(&action.text, &o.title).map(|score| (score, o)))
+            // Best score; the first objective wins ties
+            .min_by_key(|(score, _)| std::cmp::Reverse(*score))
+            .map(|(_, o)| LinkSuggestion {
+                outcome_type,
+                action_index,
+                objective_id: o.id.clone(),
+                title: o.title.clone(),
+            })
+    }
+
+    fn offer_link_suggestion(&mut self, outcome_type: OutcomeType, action_index: usize) {
+        if let Some(suggestion) = self.suggest_objective(outcome_type, action_index) {
+            self.modal = Some(ModalState::LinkSuggestion(suggestion));
+        }
+    }
+
+    fn toggle_expansion(&mut self) {
+        // Toggle expansion of current action when in Actions panel
+        if self.focus_panel == FocusPanel::Actions {
+            let action_id = {
+                let outcome = self.get_selected_outcome();
+                outcome.actions[self.selected_action].id.clone()
+            };
+            self.ui_state.toggle_expansion(action_id);
+        }
+    }
+
+    pub fn render(&mut self, f: &mut Frame) {
+        self.frame_size = f.area();
+        if self.show_dashboard {
+            self.render_dashboard(f);
+            return;
+        }
+        if self.focus_mode {
+            self.render_focus_mode(f);
+            return;
+        }
+
+        // Clear background
+        f.render_widget(
+            Block::default().style(Style::default().bg(self.theme.background)),
+            f.area(),
+        );
+
+        let at_risk = self.outcomes_at_risk(chrono::Local::now().naive_local());
+        let area = if at_risk.is_empty() {
+            f.area()
+        } else {
+            let rows = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(1), Constraint::Min(0)])
+                .split(f.area());
+            self.render_nudge_banner(f, rows[0], &at_risk);
+            rows[1]
+        };
+        let layout = create_layout(area);
+
+        self.render_header(f, layout.header, layout.compact_header);
+        self.render_outcomes(f, layout.outcomes);
+        if self.action_timer.is_some() {
+            let actions = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(5), Constraint::Length(2)])
+                .split(layout.actions);
+            self.render_actions(f, actions[0]);
+            if let Some(timer) = &self.action_timer {
+                f.render_widget(
+                    StatusLineWidget::new(&self.financial_theme)
+                        .timer(&timer.text, timer.display()),
+                    actions[1],
+                );
+            }
+        } else {
+            self.render_actions(f, layout.actions);
+        }
+        if let Some(stats) = layout.stats {
+            self.render_stats(f, stats);
+        }
+        self.render_footer(f, layout.footer);
+
+        // Render editor popup on top if active
+        if self.text_editor.is_active {
+            self.text_editor.render(f, &self.theme);
+        }
+
+        // Render error display on top if active
+        if self.toasts.is_active() {
+            self.toasts.render(f, f.area(), &self.theme);
+        }
+
+        self.render_modal(f);
+    }
+
+    fn render_nudge_banner(&self, f: &mut Frame, area: Rect, at_risk: &[OutcomeType]) {
+        let names: Vec<&str> = at_risk.iter().map(|o| o.as_str()).collect();
+        let verb = if names.len() == 1 { "has" } else { "have" };
+        let banner = Paragraph::new(format!(
+            "⚠ Streak at risk: {} {} nothing done yet today",
+            names.join(", "),
+            verb
+        ))
+        .alignment(Alignment::Center)
+        .style(
+            Style::default()
+                .fg(self.theme.background)
+                .bg(self.theme.severity_color(Severity::Warning))
+                .add_modifier(Modifier::BOLD),
+        );
+        f.render_widget(banner, area);
+    }
+
+    /// One card per outcome with its top unfinished action in bold, the
+    /// timer running on it, and nothing else
+    fn render_focus_mode(&self, f: &mut Frame) {
+        f.render_widget(
+            Block::default().style(Style::default().bg(self.theme.background)),
+            f.area(),
+        );
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Min(9),
+                Constraint::Length(1),
+            ])
+            .split(f.area().inner(ratatui::layout::Margin {
+                horizontal: 2,
+                vertical: 1,
+            }));
+
+        let mut title = vec![Span::styled(
+            format!("FOCUS · {}", self.goals.date.format("%A, %B %-d")),
+            Style::default()
+                .fg(self.theme.header)
+                .add_modifier(Modifier::BOLD),
+        )];
+        if let Some(timer) = &self.focus_timer {
+            title.push(Span::styled(format!("   ⏱ {}", timer.display()), muted));
+        }
+        f.render_widget(
+            Paragraph::new(Line::from(title)).alignment(Alignment::Center),
+            rows[0],
+        );
+
+        let outcomes = [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family];
+        let cards = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Ratio(1, 3); 3])
+            .split(rows[1]);
+        for (outcome_type, area) in outcomes.into_iter().zip(cards.iter()) {
+            let color = self.outcome_color(outcome_type);
+            let selected = outcome_type == self.selected_outcome;
+            let block = Block::default()
+                .title(format!(" {} ", outcome_type.as_str().to_uppercase()))
+                .title_style(Style::default().fg(color).add_modifier(Modifier::BOLD))
+                .borders(Borders::ALL)
+                .border_type(if selected {
+                    BorderType::Thick
                 } else {
-                    "+/- fine   a +30m   s +60m   d +120m   c reset".to_string()
+                    BorderType::Rounded
+                })
+                .border_style(Style::default().fg(if selected { color } else { self.theme.border }))
+                .style(Style::default().bg(self.theme.panel_bg));
+            let inner = block.inner(*area);
+            f.render_widget(block, *area);
+
+            let mut lines = Vec::new();
+            match self.top_action(outcome_type) {
+                Some(index) => {
+                    let action = &self.goals.outcome(outcome_type).actions[index];
+                    let mut spans = Vec::new();
+                    if let Some(level) = self.action_priorities.get(&(outcome_type, index)) {
+                        spans.push(self.priority_badge(*level));
+                    }
+                    spans.push(Span::styled(
+                        action.text.clone(),
+                        Style::default()
+                            .fg(self.theme.text_primary)
+                            .add_modifier(Modifier::BOLD),
+                    ));
+                    lines.push(Line::from(spans));
+                    let timed = self.action_timer.as_ref().filter(|timer| {
+                        timer.date == self.goals.date
+                            && timer.outcome_type == outcome_type
+                            && timer.text == action.text
+                    });
+                    if let Some(timer) = timed {
+                        lines.push(Line::raw(""));
+                        lines.push(Line::styled(
+                            format!("⏱ {}", timer.display()),
+                            Style::default().fg(color).add_modifier(Modifier::BOLD),
+                        ));
+                    }
                 }
+                None => lines.push(Line::styled("All done ✓", muted)),
             }
-            IndicatorType::Percentage => {
-                "+/- fine   a 25%   s 50%   d 75%   f 100%   c clear".to_string()
-            }
-            IndicatorType::Boolean => "y complete   n incomplete".to_string(),
+            // Vertically centered; long actions wrap onto more lines
+            let text_width = usize::from(inner.width.max(1));
+            let height: usize = lines
+                .iter()
+                .map(|line| line.width().max(1).div_ceil(text_width))
+                .sum();
+            let top = (usize::from(inner.height).saturating_sub(height) / 2) as u16;
+            let text_area = Rect {
+                y: inner.y + top,
+                height: inner.height - top,
+                ..inner
+            };
+            f.render_widget(
+                Paragraph::new(lines)
+                    .alignment(Alignment::Center)
+                    .wrap(ratatui::widgets::Wrap { trim: true }),
+                text_area,
+            );
+        }
+
+        f.render_widget(
+            Paragraph::new(
+                self.status_bar(
+                    rows[2].width,
+                    Style::default()
+                        .fg(self.theme.background)
+                        .bg(self.theme.header)
+                        .add_modifier(Modifier::BOLD),
+                    Style::default().fg(self.theme.header),
+                ),
+            )
+            .style(muted)
+            .alignment(Alignment::Center),
+            rows[2],
+        );
+
+        if self.toasts.is_active() {
+            self.toasts.render(f, f.area(), &self.theme);
         }
+        self.render_modal(f);
     }
 
-    fn is_hours_unit(unit: &IndicatorUnit) -> bool {
-        match unit {
-            IndicatorUnit::Custom(label) => label.to_lowercase().contains("hour"),
-            _ => false,
+    fn render_dashboard(&mut self, f: &mut Frame) {
+        f.render_widget(
+            Block::default().style(Style::default().bg(self.financial_theme.bg_primary)),
+            f.area(),
+        );
+
+        let layout = DashboardLayout::new(f.area());
+
+        self.render_dashboard_header(f, layout.header);
+        self.render_dashboard_live_metrics(f, layout.live_metrics);
+        self.render_dashboard_performance(f, layout.performance);
+        self.render_dashboard_sentiment(f, layout.sentiment);
+        if self.dashboard_show_signals {
+            self.render_dashboard_signals(f, layout.portfolio);
+        } else {
+            self.render_dashboard_portfolio(f, layout.portfolio);
+        }
+        self.render_dashboard_status_line(f, layout.status_line);
+        self.render_dashboard_footer(f, layout.footer);
+
+        if self.toasts.is_active() {
+            self.toasts.render(f, f.area(), &self.theme);
         }
+
+        self.render_modal(f);
     }
 
-    fn indicator_fine_delta(state: &IndicatorUpdateState) -> f64 {
-        match state.indicator_type {
-            IndicatorType::Counter => 1.0,
-            IndicatorType::Duration => {
-                if Self::is_hours_unit(&state.unit) {
-                    0.25
-                } else {
-                    15.0
-                }
+    fn render_modal(&self, f: &mut Frame) {
+        match self.modal {
+            Some(ModalState::ObjectivePicker(state)) => {
+                let area = centered_rect(60, 60, f.area());
+                f.render_widget(Clear, area);
+
+                let choices = self.objective_choices(state.outcome_type, state.show_archived);
+                let outcome = self.get_outcome_by_type(state.outcome_type);
+                let action_title = outcome
+                    .actions
+                    .get(state.action_index)
+                    .map(|a| a.text.clone())
+                    .unwrap_or_else(|| "(unknown action)".to_string());
+                let linked_ids = outcome
+                    .actions
+                    .get(state.action_index)
+                    .map(|a| a.get_all_objective_ids())
+                    .unwrap_or_default();
+
+                let mut items: Vec<ListItem> = choices
+                    .iter()
+                    .map(|choice| {
+                        let linked = linked_ids.iter().any(|id| id == &choice.id);
+                        let status_icon = match choice.status {
+                            ObjectiveStatus::Active => "●",
+                            ObjectiveStatus::Paused => "⏸",
+                            ObjectiveStatus::Completed => "✓",
+                            ObjectiveStatus::Dropped => "✗",
+                        };
+
+                        let swatch = theme::objective_accent(choice.color.as_deref())
+                            .unwrap_or(self.theme.text_secondary);
+
+                        let mut spans = vec![
+                            Span::styled(
+                                if linked { "[x] " } else { "[ ] " },
+                                Style::default().fg(self.theme.text_secondary),
+                            ),
+                            Span::styled(
+                                format!("{} ", status_icon),
+                                Style::default().fg(self.theme.header),
+                            ),
+                            Span::styled("■ ", Style::default().fg(swatch)),
+                            Span::styled(
+                                &choice.title,
+                                Style::default().fg(self.theme.text_primary),
+                            ),
+                            Span::raw("  "),
+                            Span::styled(
+                                &choice.id[..8.min(choice.id.len())],
+                                Style::default().fg(self.theme.text_secondary),
+                            ),
+                        ];
+                        if let Some((done, total)) = choice.queue_progress {
+                            spans.push(Span::styled(
+                                format!("  {}/{} items", done, total),
+                                Style::default().fg(self.theme.partial),
+                            ));
+                        }
+                        if let Some(budget) = choice.weekly_budget_min {
+                            spans.push(self.budget_span(&choice.id, budget));
+                        }
+                        if choice.archived {
+                            spans.push(Span::styled(
+                                "  archived",
+                                Style::default()
+                                    .fg(self.theme.text_secondary)
+                                    .add_modifier(Modifier::ITALIC),
+                            ));
+                        }
+                        if let Some(span) = self
+                            .objectives
+                            .objectives
+                            .get(choice.storage_index)
+                            .and_then(|objective| self.countdown_span(objective))
+                        {
+                            spans.push(span);
+                        }
+                        ListItem::new(Line::from(spans))
+                    })
+                    .collect();
+
+                items.push(ListItem::new(Line::from(vec![Span::styled(
+                    "➕ Create New Objective",
+                    Style::default()
+                        .fg(self.theme.header)
+                        .add_modifier(Modifier::BOLD),
+                )])));
+
+                let mut list_state = ListState::default();
+                list_state.select(Some(state.selection.min(items.len().saturating_sub(1))));
+
+                let block = Block::default()
+                    .title(format!(
+                        " Objectives for {:?} • Action: {} ",
+                        state.outcome_type, action_title
+                    ))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(self.theme.header))
+                    .style(Style::default().bg(self.theme.panel_bg));
+
+                let list = List::new(items)
+                    .block(block)
+                    .highlight_style(
+                        Style::default()
+                            .fg(self.theme.header)
+                            .bg(self.theme.border)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                    .highlight_symbol("➤ ");
+
+                let layout = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Min(4), Constraint::Length(2)])
+                    .split(area);
+
+                f.render_stateful_widget(list, layout[0], &mut list_state);
+
+                let help_text = Paragraph::new(Line::from(vec![
+                    Span::styled("↑/↓", Style::default().fg(self.theme.header)),
+                    Span::raw(" Navigate  "),
+                    Span::styled("Enter", Style::default().fg(self.theme.header)),
+                    Span::raw(" Link/Unlink  "),
+                    Span::styled("n", Style::default().fg(self.theme.header)),
+                    Span::raw(" New  "),
+                    Span::styled("r", Style::default().fg(self.theme.header)),
+                    Span::raw(" Rename  "),
+                    Span::styled("d", Style::default().fg(self.theme.header)),
+                    Span::raw(" Trash  "),
+                    Span::styled("c", Style::default().fg(self.theme.header)),
+                    Span::raw(" Color  "),
+                    Span::styled("b", Style::default().fg(self.theme.header)),
+                    Span::raw(" Budget  "),
+                    Span::styled("t", Style::default().fg(self.theme.header)),
+                    Span::raw(" Target date  "),
+                    Span::styled("s", Style::default().fg(self.theme.header)),
+                    Span::raw(" Status  "),
+                    Span::styled("x", Style::default().fg(self.theme.header)),
+                    Span::raw(" Complete  "),
+                    Span::styled("v", Style::default().fg(self.theme.header)),
+                    Span::raw(if state.show_archived {
+                        " Hide archived  "
+                    } else {
+                        " Show archived  "
+                    }),
+                    Span::styled("a", Style::default().fg(self.theme.header)),
+                    Span::raw(" Queue item  "),
+                    Span::styled("Esc", Style::default().fg(self.theme.header)),
+                    Span::raw(" Close"),
+                ]))
+                .style(Style::default().fg(self.theme.text_secondary))
+                .alignment(Alignment::Left);
+
+                f.render_widget(help_text, layout[1]);
             }
-            IndicatorType::Percentage => 5.0,
-            IndicatorType::Boolean => 0.0,
+            Some(ModalState::IndicatorUpdate(ref state)) => {
+                self.render_indicator_update_modal(f, state);
+            }
+            Some(ModalState::QuickObservation(ref state)) => {
+                self.render_quick_observation_modal(f, state);
+            }
+            Some(ModalState::Retarget(ref state)) => {
+                self.render_retarget_modal(f, state);
+            }
+            Some(ModalState::CheckIn(ref state)) => {
+                self.render_check_in_modal(f, state);
+            }
+            Some(ModalState::CheckInAnalytics(ref analytics)) => {
+                self.render_check_in_analytics(f, analytics);
+            }
+            Some(ModalState::CompletionTimes(ref times)) => {
+                self.render_completion_times(f, times);
+            }
+            Some(ModalState::Trophies) => self.render_trophies(f),
+            Some(ModalState::Messages(scroll)) => self.render_messages(f, scroll),
+            Some(ModalState::WhatsNew(ref state)) => self.render_whats_new(f, state),
+            Some(ModalState::Help(ref state)) => self.render_help(f, state),
+            Some(ModalState::IndicatorLinks(state)) => self.render_indicator_links(f, state),
+            Some(ModalState::ObjectiveComplete(ref state)) => {
+                self.render_objective_complete(f, state)
+            }
+            Some(ModalState::Compare(ref comparison)) => self.render_compare(f, comparison),
+            Some(ModalState::LinkSuggestion(ref suggestion)) => {
+                self.render_link_suggestion(f, suggestion);
+            }
+            Some(ModalState::CarryOver(ref state)) => {
+                self.render_carry_over_modal(f, state);
+            }
+            Some(ModalState::Snooze(ref state)) => {
+                self.render_snooze_modal(f, state);
+            }
+            Some(ModalState::BlockerPicker(ref state)) => {
+                self.render_blocker_picker(f, state);
+            }
+            Some(ModalState::Board(ref state)) => {
+                self.render_board(f, state);
+            }
+            Some(ModalState::Timeline(ref state)) => {
+                self.render_timeline(f, state);
+            }
+            Some(ModalState::WeeklyPlan(ref state)) => {
+                self.render_weekly_plan(f, state);
+            }
+            Some(ModalState::YearReview(ref state)) => {
+                self.render_year_review(f, state);
+            }
+            Some(ModalState::Projects(ref state)) => {
+                self.render_projects(f, state);
+            }
+            Some(ModalState::VisionHistory(ref state)) => {
+                self.render_vision_history(f, state);
+            }
+            Some(ModalState::QuarterlyCheckIn(ref state)) => {
+                self.render_quarterly_checkin(f, state);
+            }
+            Some(ModalState::Journal(ref state)) => {
+                self.render_journal(f, state);
+            }
+            Some(ModalState::Morning(ref state)) => {
+                self.render_morning(f, state);
+            }
+            Some(ModalState::CloseDay(ref state)) => {
+                self.render_close_day(f, state);
+            }
+            Some(ModalState::Templates(ref state)) => {
+                self.render_templates(f, state);
+            }
+            Some(ModalState::Backups(ref state)) => {
+                self.render_backups(f, state);
+            }
+            Some(ModalState::Trash(ref state)) => {
+                self.render_trash(f, state);
+            }
+            Some(ModalState::Recovery(ref state)) => {
+                self.render_recovery(f, state);
+            }
+            Some(ModalState::SyncMerge(ref state)) => {
+                self.render_sync_merge(f, state);
+            }
+            _ => {}
         }
     }
 
-    fn indicator_small_delta(state: &IndicatorUpdateState) -> f64 {
-        match state.indicator_type {
-            IndicatorType::Counter => 1.0,
-            IndicatorType::Duration => {
-                if Self::is_hours_unit(&state.unit) {
-                    0.5
-                } else {
-                    30.0
+    fn render_carry_over_modal(&self, f: &mut Frame, state: &CarryOverState) {
+        let area = centered_rect(70, 60, f.area());
+        f.render_widget(Clear, area);
+
+        let shell = Block::default()
+            .title(" Unfinished Yesterday ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = shell.inner(area);
+        f.render_widget(shell, area);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(1)])
+            .split(inner);
+
+        let yesterday = self.goals.date - chrono::Duration::days(1);
+        let items: Vec<ListItem> = state
+            .items
+            .iter()
+            .map(|item| {
+                let (label, color) = match item.choice {
+                    CarryChoice::Carry => ("carry".to_string(), self.theme.completed),
+                    CarryChoice::Drop => ("drop".to_string(), self.theme.text_secondary),
+                    CarryChoice::Snooze(date) => {
+                        (format!("→ {}", date.format("%b %d")), self.theme.partial)
+                    }
+                };
+                let mut spans = vec![
+                    Span::styled(format!("{:<9}", label), Style::default().fg(color)),
+                    Span::styled(
+                        format!("{:<7}", item.outcome_type.as_str()),
+                        Style::default().fg(self.theme.text_secondary),
+                    ),
+                    Span::styled(
+                        item.text.clone(),
+                        Style::default().fg(self.theme.text_primary),
+                    ),
+                ];
+                if item.first_planned < yesterday {
+                    spans.push(Span::styled(
+                        format!("  since {}", item.first_planned.format("%b %d")),
+                        Style::default().fg(self.theme.text_secondary),
+                    ));
                 }
-            }
-            IndicatorType::Percentage => 0.0,
-            IndicatorType::Boolean => 0.0,
-        }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let mut list_state = ListState::default();
+        list_state.select(Some(state.index));
+        let list = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .fg(self.theme.header)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("➤ ");
+        f.render_stateful_widget(list, layout[0], &mut list_state);
+
+        let accent = Style::default().fg(self.theme.header);
+        let help = vec![
+            Span::styled("c", accent),
+            Span::raw(" Carry  "),
+            Span::styled("x", accent),
+            Span::raw(" Drop  "),
+            Span::styled("z", accent),
+            Span::raw(" Snooze  "),
+            Span::styled("←/→", accent),
+            Span::raw(" Date  "),
+            Span::styled("Enter", accent),
+            Span::raw(" Apply  "),
+            Span::styled("Esc", accent),
+            Span::raw(" Later"),
+        ];
+        f.render_widget(
+            Paragraph::new(Line::from(help)).style(Style::default().fg(self.theme.text_secondary)),
+            layout[1],
+        );
     }
 
-    fn indicator_medium_delta(state: &IndicatorUpdateState) -> f64 {
-        match state.indicator_type {
-            IndicatorType::Counter => 3.0,
-            IndicatorType::Duration => {
-                if Self::is_hours_unit(&state.unit) {
-                    1.0
+    fn render_timeline(&self, f: &mut Frame, state: &TimelineState) {
+        let area = centered_rect(90, 85, f.area());
+        f.render_widget(Clear, area);
+
+        let shell = Block::default()
+            .title(format!(" Timeline - {} ", self.goals.date.format("%b %d")))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = shell.inner(area);
+        f.render_widget(shell, area);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(1)])
+            .split(inner);
+        let panes = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(layout[0]);
+
+        let conflicts = timeline::conflicts(&state.blocks);
+        let selected = state.blocks.get(state.selection);
+        let conflict_color = self.theme.severity_color(Severity::Bad);
+        let block_color = |block: &timeline::Block| {
+            if conflicts.contains(&(block.outcome_type, block.index)) {
+                conflict_color
+            } else {
+                match block.outcome_type {
+                    OutcomeType::Work => self.theme.work_color,
+                    OutcomeType::Health => self.theme.health_color,
+                    OutcomeType::Family => self.theme.family_color,
+                }
+            }
+        };
+
+        // Half-hour rows across working hours, plus any block outside them
+        let hours = self.settings.working_hours;
+        let mut first = hours.start * 60;
+        let mut last = hours.end.max(hours.start + 1) * 60;
+        for block in &state.blocks {
+            if let (Some(start), Some(end)) = (block.start, block.end()) {
+                first = first.min(start.hour() * 60 + start.minute() / 30 * 30);
+                let end_min = if end < start {
+                    24 * 60
                 } else {
-                    60.0
-                }
+                    end.hour() * 60 + end.minute()
+                };
+                last = last.max(end_min);
             }
-            IndicatorType::Percentage => 0.0,
-            IndicatorType::Boolean => 0.0,
         }
-    }
-
-    fn indicator_large_delta(state: &IndicatorUpdateState) -> f64 {
-        match state.indicator_type {
-            IndicatorType::Counter => 5.0,
-            IndicatorType::Duration => {
-                if Self::is_hours_unit(&state.unit) {
-                    2.0
+        let mut rows = Vec::new();
+        for row_start in (first..last.min(24 * 60)).step_by(30) {
+            let mut spans = vec![Span::styled(
+                format!("{:02}:{:02} │ ", row_start / 60, row_start % 60),
+                Style::default().fg(self.theme.text_secondary),
+            )];
+            for block in &state.blocks {
+                let (Some(start), Some(end)) = (block.start, block.end()) else {
+                    continue;
+                };
+                let start_min = start.hour() * 60 + start.minute();
+                let end_min = if end < start {
+                    24 * 60
                 } else {
-                    120.0
+                    end.hour() * 60 + end.minute()
+                };
+                if start_min < row_start + 30 && row_start < end_min {
+                    let mut style = Style::default().fg(block_color(block));
+                    if selected == Some(block) {
+                        style = style.add_modifier(Modifier::BOLD | Modifier::REVERSED);
+                    }
+                    let label = if start_min >= row_start {
+                        format!("█ {} ", block.text)
+                    } else {
+                        "█ ".to_string()
+                    };
+                    spans.push(Span::styled(label, style));
                 }
             }
-            IndicatorType::Percentage => 0.0,
-            IndicatorType::Boolean => 0.0,
+            rows.push(Line::from(spans));
         }
-    }
+        f.render_widget(
+            Paragraph::new(rows).block(
+                Block::default()
+                    .borders(Borders::RIGHT)
+                    .border_style(Style::default().fg(self.theme.border)),
+            ),
+            panes[0],
+        );
 
-    fn clamp_text(text: &str, width: usize) -> String {
-        if width == 0 {
-            return String::new();
-        }
-        let count = text.chars().count();
-        if count <= width {
-            return text.to_string();
-        }
-        if width == 1 {
-            return "…".to_string();
+        let items: Vec<ListItem> = state
+            .blocks
+            .iter()
+            .map(|block| {
+                let when = if block.start.is_some() {
+                    block.span_label()
+                } else {
+                    "unscheduled".to_string()
+                };
+                let mut spans = vec![
+                    Span::styled(
+                        format!("{:<12}", when),
+                        Style::default().fg(self.theme.text_secondary),
+                    ),
+                    Span::styled(block.text.clone(), Style::default().fg(block_color(block))),
+                ];
+                if conflicts.contains(&(block.outcome_type, block.index)) {
+                    spans.push(Span::styled(
+                        format!("  {} overlaps", Severity::Bad.symbol()),
+                        Style::default().fg(conflict_color),
+                    ));
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+        let mut list_state = ListState::default();
+        if !state.blocks.is_empty() {
+            list_state.select(Some(state.selection));
         }
-        let truncated: String = text.chars().take(width - 1).collect();
-        format!("{}…", truncated)
+        let list = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .bg(self.theme.border)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("➤ ");
+        f.render_stateful_widget(list, panes[1], &mut list_state);
+
+        let accent = Style::default().fg(self.theme.header);
+        let help = vec![
+            Span::styled("s", accent),
+            Span::raw(" Schedule  "),
+            Span::styled("h/l", accent),
+            Span::raw(" ±15m  "),
+            Span::styled("H/L", accent),
+            Span::raw(" ±1h  "),
+            Span::styled("[/]", accent),
+            Span::raw(" Length  "),
+            Span::styled("x", accent),
+            Span::raw(" Unschedule  "),
+            Span::styled("Esc", accent),
+            Span::raw(" Close"),
+        ];
+        f.render_widget(
+            Paragraph::new(Line::from(help)).style(Style::default().fg(self.theme.text_secondary)),
+            layout[1],
+        );
     }
 
-    fn render_indicator_update_modal(&self, f: &mut Frame, state: &IndicatorUpdateState) {
-        let area = centered_rect(70, 65, f.area());
+    fn render_board(&self, f: &mut Frame, state: &BoardState) {
+        let area = centered_rect(90, 80, f.area());
         f.render_widget(Clear, area);
 
-        let border_set = border::Set {
-            top_left: "/",
-            top_right: "\\",
-            bottom_left: "\\",
-            bottom_right: "/",
-            vertical_left: "|",
-            vertical_right: "|",
-            horizontal_top: "-",
-            horizontal_bottom: "-",
-        };
-
         let shell = Block::default()
+            .title(format!(" Board - {} ", self.goals.date.format("%b %d")))
             .borders(Borders::ALL)
-            .border_set(border_set)
             .border_style(Style::default().fg(self.theme.header))
             .style(Style::default().bg(self.theme.panel_bg));
+        let inner = shell.inner(area);
         f.render_widget(shell, area);
 
-        if area.width < 6 || area.height < 10 {
-            return;
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(1)])
+            .split(inner);
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Ratio(1, board::COLUMNS.len() as u32); board::COLUMNS.len()])
+            .split(layout[0]);
+
+        for (column, status) in board::COLUMNS.iter().enumerate() {
+            let cards = state.column_cards(column);
+            let focused = column == state.column;
+            let items: Vec<ListItem> = cards
+                .iter()
+                .map(|card| {
+                    let color = match card.outcome_type {
+                        OutcomeType::Work => self.theme.work_color,
+                        OutcomeType::Health => self.theme.health_color,
+                        OutcomeType::Family => self.theme.family_color,
+                    };
+                    ListItem::new(Line::from(vec![
+                        Span::styled("■ ", Style::default().fg(color)),
+                        Span::styled(
+                            card.text.clone(),
+                            Style::default().fg(self.theme.text_primary),
+                        ),
+                    ]))
+                })
+                .collect();
+
+            let mut list_state = ListState::default();
+            if focused && !cards.is_empty() {
+                list_state.select(Some(state.row));
+            }
+            let border = if focused {
+                self.theme.header
+            } else {
+                self.theme.border
+            };
+            let list = List::new(items)
+                .block(
+                    Block::default()
+                        .title(format!(
+                            " {} ({}) ",
+                            board::column_label(*status),
+                            cards.len()
+                        ))
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded)
+                        .border_style(Style::default().fg(border)),
+                )
+                .highlight_style(
+                    Style::default()
+                        .bg(self.theme.border)
+                        .add_modifier(Modifier::BOLD),
+                );
+            f.render_stateful_widget(list, columns[column], &mut list_state);
         }
 
-        let inner = Rect {
-            x: area.x + 1,
-            y: area.y + 1,
-            width: area.width.saturating_sub(2),
-            height: area.height.saturating_sub(2),
-        };
+        let accent = Style::default().fg(self.theme.header);
+        let help = vec![
+            Span::styled("←/→", accent),
+            Span::raw(" Column  "),
+            Span::styled("j/k", accent),
+            Span::raw(" Select  "),
+            Span::styled("h/l", accent),
+            Span::raw(" Move Action  "),
+            Span::styled("Esc", accent),
+            Span::raw(" Close"),
+        ];
+        f.render_widget(
+            Paragraph::new(Line::from(help)).style(Style::default().fg(self.theme.text_secondary)),
+            layout[1],
+        );
+    }
 
-        let header_layout = Layout::default()
+    fn render_blocker_picker(&self, f: &mut Frame, state: &BlockerPickerState) {
+        let area = centered_rect(60, 50, f.area());
+        f.render_widget(Clear, area);
+
+        let shell = Block::default()
+            .title(" Blocked By ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = shell.inner(area);
+        f.render_widget(shell, area);
+
+        let layout = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(1),
-                Constraint::Length(1),
-                Constraint::Min(6),
-            ])
+            .constraints([Constraint::Min(3), Constraint::Length(1)])
             .split(inner);
 
-        let title_line = Line::from(vec![Span::styled(
-            " FocusFive · Update Indicator ",
-            Style::default().fg(self.theme.header),
-        )]);
-        let title = Paragraph::new(title_line)
-            .alignment(Alignment::Left)
-            .style(Style::default().bg(self.theme.panel_bg));
-        f.render_widget(title, header_layout[0]);
+        let items: Vec<ListItem> = state
+            .candidates
+            .iter()
+            .map(|blocker| {
+                let day = if blocker.date == self.goals.date {
+                    "today ".to_string()
+                } else {
+                    blocker.date.format("%b %d").to_string()
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:<7}{:<7}", day, blocker.outcome_type.as_str()),
+                        Style::default().fg(self.theme.text_secondary),
+                    ),
+                    Span::styled(
+                        blocker.text.clone(),
+                        Style::default().fg(self.theme.text_primary),
+                    ),
+                ]))
+            })
+            .collect();
 
-        let metrics_text = format!(
-            " Target {} | Latest {} | Previous {} ",
-            state
-                .target
-                .map(|value| Self::format_value_with_unit(value, &state.unit))
-                .unwrap_or_else(|| "—".to_string()),
-            state
-                .latest_value
-                .map(|value| Self::format_value_with_unit(value, &state.unit))
-                .unwrap_or_else(|| "—".to_string()),
-            state
-                .previous_value
-                .map(|value| Self::format_value_with_unit(value, &state.unit))
-                .unwrap_or_else(|| "—".to_string()),
+        let mut list_state = ListState::default();
+        list_state.select(Some(state.selection));
+        let list = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .fg(self.theme.header)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("➤ ");
+        f.render_stateful_widget(list, layout[0], &mut list_state);
+
+        let accent = Style::default().fg(self.theme.header);
+        let help = vec![
+            Span::styled("Enter", accent),
+            Span::raw(" Wait on this  "),
+            Span::styled("x", accent),
+            Span::raw(" Clear  "),
+            Span::styled("Esc", accent),
+            Span::raw(" Cancel"),
+        ];
+        f.render_widget(
+            Paragraph::new(Line::from(help)).style(Style::default().fg(self.theme.text_secondary)),
+            layout[1],
         );
+    }
 
-        let inner_width = header_layout[1].width.saturating_sub(2) as usize;
-        let clamped = Self::clamp_text(&metrics_text, inner_width);
-        let padding = inner_width.saturating_sub(clamped.chars().count());
-        let left_pad = padding / 2;
-        let right_pad = padding.saturating_sub(left_pad);
-
-        let mut spans = Vec::new();
-        spans.push(Span::styled("\\", Style::default().fg(self.theme.header)));
-        if left_pad > 0 {
-            spans.push(Span::styled(
-                "-".repeat(left_pad),
-                Style::default().fg(self.theme.border),
-            ));
-        }
-        spans.push(Span::styled(
-            clamped,
-            Style::default().fg(self.theme.text_secondary),
-        ));
-        if right_pad > 0 {
-            spans.push(Span::styled(
-                "-".repeat(right_pad),
-                Style::default().fg(self.theme.border),
-            ));
+    fn outcome_color(&self, outcome_type: OutcomeType) -> Color {
+        match outcome_type {
+            OutcomeType::Work => self.theme.work_color,
+            OutcomeType::Health => self.theme.health_color,
+            OutcomeType::Family => self.theme.family_color,
         }
-        spans.push(Span::styled("/", Style::default().fg(self.theme.header)));
-        let metrics =
-            Paragraph::new(Line::from(spans)).style(Style::default().bg(self.theme.panel_bg));
-        f.render_widget(metrics, header_layout[1]);
+    }
 
-        let body_layout = Layout::default()
+    fn render_journal(&self, f: &mut Frame, state: &JournalState) {
+        let area = centered_rect(85, 85, f.area());
+        f.render_widget(Clear, area);
+
+        let total_words: usize = state.entries.iter().map(|entry| entry.words).sum();
+        let block = Block::default()
+            .title(format!(
+                " Journal · {} reflections · {} words ",
+                state.entries.len(),
+                total_words
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let rows = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(3),
-                Constraint::Length(3),
-                Constraint::Min(6),
-                Constraint::Length(2),
-                Constraint::Length(3),
+                Constraint::Length(1),
+                Constraint::Min(3),
                 Constraint::Length(1),
             ])
-            .split(header_layout[2]);
-
-        if body_layout.len() < 6 {
-            return;
-        }
-
-        let summary_chunks = Layout::default()
+            .split(inner);
+        let columns = Layout::default()
             .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
-            .split(body_layout[0]);
+            .constraints([Constraint::Length(36), Constraint::Min(20)])
+            .split(rows[1]);
 
-        let current_block = Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(self.theme.border))
-            .style(Style::default().bg(self.theme.background));
-        f.render_widget(current_block, summary_chunks[0]);
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let primary = Style::default().fg(self.theme.text_primary);
+        let accent = Style::default().fg(self.theme.header);
 
-        let current_inner = Rect {
-            x: summary_chunks[0].x + 1,
-            y: summary_chunks[0].y + 1,
-            width: summary_chunks[0].width.saturating_sub(2),
-            height: summary_chunks[0].height.saturating_sub(2),
+        let search = if state.searching || !state.query.is_empty() {
+            Line::from(vec![
+                Span::styled("/", accent),
+                Span::styled(state.query.clone(), primary),
+                Span::styled(if state.searching { "▌" } else { "" }, accent),
+                Span::styled(format!("  {} matching", state.results.len()), muted),
+            ])
+        } else {
+            Line::styled("Press / to search", muted)
         };
+        f.render_widget(Paragraph::new(search), rows[0]);
 
-        let current_value = state
-            .latest_value
-            .map(|value| Self::format_value_with_unit(value, &state.unit))
-            .unwrap_or_else(|| "—".to_string());
-
-        let mut current_line = vec![
-            Span::styled("Current ", Style::default().fg(self.theme.text_secondary)),
-            Span::styled(
-                current_value,
-                Style::default()
-                    .fg(self.theme.text_primary)
-                    .add_modifier(Modifier::BOLD),
-            ),
-        ];
-
-        if let Some(delta) = Self::indicator_delta_label(state) {
-            current_line.push(Span::raw(" "));
-            current_line.push(Span::styled(delta, Style::default().fg(self.theme.partial)));
+        if state.entries.is_empty() {
+            f.render_widget(
+                Paragraph::new("No reflections yet. Write one in the evening with S.").style(muted),
+                rows[1],
+            );
+        } else {
+            let list = VirtualList::new(state.results.len(), |i| {
+                let entry = &state.entries[state.results[i]];
+                ListItem::new(Line::from(vec![
+                    Span::raw(entry.date.format("%Y-%m-%d %a").to_string()),
+                    Span::styled(format!(" {:>4}w ", entry.words), muted),
+                    Span::styled(
+                        entry
+                            .snippet(&state.query)
+                            .chars()
+                            .take(14)
+                            .collect::<String>(),
+                        muted,
+                    ),
+                ]))
+            })
+            .style(primary)
+            .highlight_style(accent.add_modifier(Modifier::BOLD))
+            .highlight_symbol("➤ ");
+            // Only the rows on screen are built, in a window worked out from
+            // the selection each frame
+            let mut list_state = state.list;
+            f.render_stateful_widget(list, columns[0], &mut list_state);
+
+            if let Some(entry) = state.selected() {
+                f.render_widget(
+                    Paragraph::new(entry.text.clone())
+                        .style(primary)
+                        .wrap(ratatui::widgets::Wrap { trim: false })
+                        .scroll((state.scroll, 0))
+                        .block(
+                            Block::default()
+                                .title(format!(" {} ", entry.date.format("%A, %B %d, %Y")))
+                                .borders(Borders::LEFT)
+                                .border_style(Style::default().fg(self.theme.border)),
+                        ),
+                    columns[1],
+                );
+            }
         }
 
-        let current_paragraph = Paragraph::new(Line::from(current_line))
-            .alignment(Alignment::Center)
-            .style(Style::default().bg(self.theme.background));
-        f.render_widget(current_paragraph, current_inner);
+        let help = vec![
+            Span::styled("j/k", accent),
+            Span::raw(" Day  "),
+            Span::styled("/", accent),
+            Span::raw(" Search  "),
+            Span::styled("e", accent),
+            Span::raw(" Edit  "),
+            Span::styled("PgUp/PgDn", accent),
+            Span::raw(" Scroll  "),
+            Span::styled("Esc", accent),
+            Span::raw(" Close"),
+        ];
+        f.render_widget(Paragraph::new(Line::from(help)).style(muted), rows[2]);
+    }
 
-        let direction_block = Block::default()
+    fn render_quarterly_checkin(&self, f: &mut Frame, state: &QuarterlyState) {
+        let area = centered_rect(75, 80, f.area());
+        f.render_widget(Clear, area);
+
+        let domain = &state.checkin.domains[state.step];
+        let color = self.outcome_color(domain.outcome_type);
+        let block = Block::default()
+            .title(format!(
+                " Quarterly Check-in {} · {} ({}/{}) ",
+                state.checkin.label(),
+                domain.outcome_type.as_str(),
+                state.step + 1,
+                state.checkin.domains.len()
+            ))
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(self.theme.border))
-            .style(Style::default().bg(self.theme.background));
-        f.render_widget(direction_block, summary_chunks[1]);
+            .border_style(Style::default().fg(color))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(5),
+                Constraint::Min(3),
+                Constraint::Length(4),
+                Constraint::Length(1),
+            ])
+            .split(inner);
 
-        let direction_inner = Rect {
-            x: summary_chunks[1].x + 1,
-            y: summary_chunks[1].y + 1,
-            width: summary_chunks[1].width.saturating_sub(2),
-            height: summary_chunks[1].height.saturating_sub(2),
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let primary = Style::default().fg(self.theme.text_primary);
+        let accent = Style::default().fg(self.theme.header);
+        let vision = if domain.vision.trim().is_empty() {
+            Line::styled("No vision written yet; add one with v.", muted)
+        } else {
+            Line::styled(
+                domain.vision.clone(),
+                primary.add_modifier(Modifier::ITALIC),
+            )
         };
+        f.render_widget(
+            Paragraph::new(vision)
+                .wrap(ratatui::widgets::Wrap { trim: true })
+                .block(
+                    Block::default()
+                        .title(" VISION ")
+                        .borders(Borders::BOTTOM)
+                        .border_style(Style::default().fg(self.theme.border)),
+                ),
+            layout[0],
+        );
 
-        let direction_text = match state.direction {
-            IndicatorDirection::HigherIsBetter => "Higher is better",
-            IndicatorDirection::LowerIsBetter => "Lower is better",
-            IndicatorDirection::WithinRange => "Target range",
-        };
+        let mut lines = vec![Line::styled(
+            "Does each objective still ladder up to this vision?",
+            accent,
+        )];
+        if domain.objectives.is_empty() {
+            lines.push(Line::styled("No active objectives in this domain.", muted));
+        }
+        for (i, objective) in domain.objectives.iter().enumerate() {
+            let (symbol, style) = match objective.alignment {
+                quarterly::Alignment::Unanswered => ("?", muted),
+                quarterly::Alignment::Aligned => ("✓", Style::default().fg(self.theme.completed)),
+                quarterly::Alignment::Drifting => (
+                    "✗",
+                    Style::default().fg(self.theme.severity_color(Severity::Bad)),
+                ),
+            };
+            lines.push(Line::from(vec![
+                Span::styled(if i == state.selection { "➤ " } else { "  " }, accent),
+                Span::styled(format!("{} ", symbol), style),
+                Span::styled(objective.title.clone(), primary),
+            ]));
+        }
+        f.render_widget(Paragraph::new(lines), layout[1]);
 
-        let trend_status = Self::indicator_trend_status(state);
-        let (trend_icon, trend_label) = Self::indicator_trend_display(trend_status);
-        let trend_color = self.trend_color(trend_status);
+        let note = if domain.note.is_empty() {
+            Line::styled("No notes yet; press e to add some.", muted)
+        } else {
+            Line::styled(domain.note.clone(), primary)
+        };
+        f.render_widget(
+            Paragraph::new(note)
+                .wrap(ratatui::widgets::Wrap { trim: true })
+                .block(
+                    Block::default()
+                        .title(" NOTES ")
+                        .borders(Borders::TOP)
+                        .border_style(Style::default().fg(self.theme.border)),
+                ),
+            layout[2],
+        );
 
-        let direction_paragraph = Paragraph::new(vec![
-            Line::from(vec![
-                Span::styled("Direction ", Style::default().fg(self.theme.text_secondary)),
-                Span::styled(direction_text, Style::default().fg(self.theme.text_primary)),
-            ]),
-            Line::from(vec![
-                Span::styled(trend_icon, Style::default().fg(trend_color)),
-                Span::raw(" "),
-                Span::styled(trend_label, Style::default().fg(self.theme.text_secondary)),
-            ]),
-        ])
-        .alignment(Alignment::Center)
-        .style(Style::default().bg(self.theme.background));
-        f.render_widget(direction_paragraph, direction_inner);
+        let last = state.step + 1 == state.checkin.domains.len();
+        let help = vec![
+            Span::styled("y/n", accent),
+            Span::raw(" Aligned/drifting  "),
+            Span::styled("e", accent),
+            Span::raw(" Notes  "),
+            Span::styled("h/l", accent),
+            Span::raw(" Domain  "),
+            Span::styled(if last { "Enter/s" } else { "s" }, accent),
+            Span::raw(" Save  "),
+            Span::styled("Esc", accent),
+            Span::raw(" Discard"),
+        ];
+        f.render_widget(Paragraph::new(Line::from(help)).style(muted), layout[3]);
+    }
 
-        let progress_chunks = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([Constraint::Percentage(72), Constraint::Percentage(28)])
-            .split(body_layout[1]);
+    fn render_recovery(&self, f: &mut Frame, state: &RecoveryState) {
+        let area = centered_rect(60, 30, f.area());
+        f.render_widget(Clear, area);
 
-        let percent_value = state
-            .target
-            .filter(|target| *target > 0.0)
-            .and_then(|target| state.latest_value.map(|current| (current / target) * 100.0));
-        let gauge_percent = percent_value.unwrap_or(0.0).clamp(0.0, 100.0) as u16;
-        let gauge_color = match gauge_percent {
-            100.. => self.theme.completed,
-            70..=99 => self.theme.partial,
-            _ => self.theme.pending,
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let primary = Style::default().fg(self.theme.text_primary);
+        let what = match state.journal.edit {
+            PendingEdit::Action { .. } => "an action edit",
+            PendingEdit::Observation { .. } => "an indicator value",
         };
-        let gauge_label = percent_value
-            .map(|value| format!("{:.0}%", value.clamp(0.0, 999.0)))
-            .unwrap_or_else(|| "—".to_string());
-
-        let gauge = Gauge::default()
-            .percent(gauge_percent)
-            .label(gauge_label)
-            .gauge_style(Style::default().fg(gauge_color).bg(self.theme.background))
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(" Goal Pace ")
-                    .border_style(Style::default().fg(self.theme.border))
-                    .style(Style::default().bg(self.theme.background)),
-            );
-        f.render_widget(gauge, progress_chunks[0]);
+        let lines = vec![
+            Line::from(Span::styled(
+                format!(
+                    "The last session ended with {} unsaved ({}):",
+                    what,
+                    state
+                        .journal
+                        .written
+                        .with_timezone(&chrono::Local)
+                        .format("%b %d %H:%M")
+                ),
+                muted,
+            )),
+            Line::from(""),
+            Line::from(Span::styled(state.journal.edit.describe(), primary)),
+            Line::from(""),
+            Line::from(Span::styled("Enter recover · Esc discard", muted)),
+        ];
+        f.render_widget(
+            Paragraph::new(lines)
+                .wrap(ratatui::widgets::Wrap { trim: false })
+                .block(
+                    Block::default()
+                        .title(" Recover unsaved input ")
+                        .borders(Borders::ALL)
+                        .border_style(Style::default().fg(self.theme.header))
+                        .style(Style::default().bg(self.theme.panel_bg)),
+                ),
+            area,
+        );
+    }
 
-        let trend_line = Paragraph::new(Line::from(vec![
-            Span::styled(trend_icon, Style::default().fg(trend_color)),
-            Span::raw(" "),
-            Span::styled(trend_label, Style::default().fg(self.theme.text_secondary)),
-        ]))
-        .alignment(Alignment::Center)
-        .style(Style::default().bg(self.theme.panel_bg));
-        f.render_widget(trend_line, progress_chunks[1]);
+    fn render_sync_merge(&self, f: &mut Frame, state: &SyncMergeState) {
+        let area = centered_rect(80, 70, f.area());
+        f.render_widget(Clear, area);
+        let Some(conflict) = state.conflicts.first() else {
+            return;
+        };
 
-        let history_block = Block::default()
+        let block = Block::default()
+            .title(format!(
+                " Sync conflict: {} ({} left) ",
+                conflict.file,
+                state.conflicts.len()
+            ))
             .borders(Borders::ALL)
-            .title(" 7-Day History ")
-            .border_style(Style::default().fg(self.theme.border))
-            .style(Style::default().bg(self.theme.background));
-        f.render_widget(history_block, body_layout[2]);
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(3),
+                Constraint::Length(7),
+                Constraint::Length(1),
+            ])
+            .split(inner);
 
-        let history_inner = Rect {
-            x: body_layout[2].x + 1,
-            y: body_layout[2].y + 1,
-            width: body_layout[2].width.saturating_sub(2),
-            height: body_layout[2].height.saturating_sub(2),
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let primary = Style::default().fg(self.theme.text_primary);
+        let accent = Style::default().fg(self.theme.header);
+        let chosen = |i: usize| match state.choices.get(i).copied().flatten() {
+            Some(Side::Local) => "mine",
+            Some(Side::Remote) => "theirs",
+            None => "?",
         };
 
-        if history_inner.height == 0 {
-            return;
-        }
+        let parts: Vec<String> = match conflict.hunks.is_empty() {
+            true => vec!["Whole file changed on both sides".to_string()],
+            false => conflict.hunks.iter().map(|h| h.spot.label()).collect(),
+        };
+        let height = rows[0].height as usize;
+        let first = state.hunk.saturating_sub(height.saturating_sub(1));
+        let lines: Vec<Line> = parts
+            .into_iter()
+            .enumerate()
+            .skip(first)
+            .take(height)
+            .map(|(i, label)| {
+                let selected = i == state.hunk;
+                Line::from(vec![
+                    Span::styled(if selected { "➤ " } else { "  " }, accent),
+                    Span::styled(format!("{:<8}", chosen(i)), muted),
+                    Span::styled(
+                        label,
+                        if selected {
+                            primary.add_modifier(Modifier::BOLD)
+                        } else {
+                            primary
+                        },
+                    ),
+                ])
+            })
+            .collect();
+        f.render_widget(Paragraph::new(lines), rows[0]);
+
+        let version = |text: &Option<String>| text.clone().unwrap_or_else(|| "(none)".to_string());
+        let detail = match conflict.hunks.get(state.hunk) {
+            Some(hunk) => vec![
+                Line::from(vec![
+                    Span::styled("base    ", muted),
+                    Span::styled(version(&hunk.base), muted),
+                ]),
+                Line::from(vec![
+                    Span::styled("mine    ", accent),
+                    Span::styled(version(&hunk.local), primary),
+                ]),
+                Line::from(vec![
+                    Span::styled("theirs  ", accent),
+                    Span::styled(version(&hunk.remote), primary),
+                ]),
+            ],
+            None => vec![Line::from(Span::styled(
+                "Mine keeps this device's copy, theirs the sync folder's",
+                muted,
+            ))],
+        };
+        f.render_widget(
+            Paragraph::new(detail)
+                .wrap(ratatui::widgets::Wrap { trim: false })
+                .block(Block::default().borders(Borders::TOP).border_style(muted)),
+            rows[1],
+        );
 
-        let history_layout = Layout::default()
+        f.render_widget(
+            Paragraph::new("j/k part · m mine · t theirs · Enter apply · s skip file · Esc later")
+                .style(muted),
+            rows[2],
+        );
+    }
+
+    fn render_trash(&self, f: &mut Frame, state: &TrashState) {
+        let area = centered_rect(70, 60, f.area());
+        f.render_widget(Clear, area);
+
+        let block = Block::default()
+            .title(" Trash ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let rows = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([Constraint::Min(1), Constraint::Length(1)])
-            .split(history_inner);
+            .constraints([Constraint::Min(3), Constraint::Length(1)])
+            .split(inner);
 
-        if history_layout.len() < 2 {
-            return;
-        }
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let primary = Style::default().fg(self.theme.text_primary);
+        let accent = Style::default().fg(self.theme.header);
+        let retention = self.settings.trash_retention();
+        let now = chrono::Utc::now();
+
+        let list = VirtualList::new(state.entries.len(), |i| {
+            let entry = &state.entries[i];
+            let days_left = (entry.expires(retention) - now).num_days().max(0);
+            ListItem::new(Line::from(vec![
+                Span::styled(format!("{:<10}", entry.kind.label()), muted),
+                Span::raw(entry.name.clone()),
+                Span::styled(
+                    format!(
+                        "  deleted {} · purged in {}d",
+                        entry.trashed.with_timezone(&chrono::Local).format("%b %d"),
+                        days_left
+                    ),
+                    muted,
+                ),
+            ]))
+        })
+        .style(primary)
+        .highlight_style(accent.add_modifier(Modifier::BOLD))
+        .highlight_symbol("➤ ");
+        f.render_stateful_widget(
+            list,
+            rows[0],
+            &mut VirtualListState::default().with_selected(Some(state.selection)),
+        );
 
-        if !state.history.is_empty() {
-            let data: Vec<u64> = state
-                .history
-                .iter()
-                .map(|value| (value.max(0.0) * 100.0) as u64)
-                .collect();
-            let sparkline = Sparkline::default()
-                .data(&data)
-                .style(Style::default().fg(self.theme.partial));
-            f.render_widget(sparkline, history_layout[0]);
-        } else {
-            let placeholder = Paragraph::new("No history yet")
-                .alignment(Alignment::Center)
-                .style(
-                    Style::default()
-                        .fg(self.theme.text_secondary)
-                        .bg(self.theme.background),
-                );
-            f.render_widget(placeholder, history_layout[0]);
-        }
+        f.render_widget(
+            Paragraph::new("j/k select · Enter restore · d d delete for good · Esc close")
+                .style(muted),
+            rows[1],
+        );
+    }
 
-        let start_value = state
-            .history
-            .first()
-            .copied()
-            .map(|value| Self::format_value_with_unit(value, &state.unit))
-            .unwrap_or_else(|| "—".to_string());
-        let end_value = state
-            .history
-            .last()
-            .copied()
-            .map(|value| Self::format_value_with_unit(value, &state.unit))
-            .unwrap_or_else(|| "—".to_string());
-        let last_update = state
-            .last_updated
-            .map(|date| date.to_string())
-            .unwrap_or_else(|| "—".to_string());
+    fn render_backups(&self, f: &mut Frame, state: &BackupsState) {
+        let area = centered_rect(80, 75, f.area());
+        f.render_widget(Clear, area);
 
-        let footer = format!(
-            "Start {}   End {}   Last update {}",
-            start_value, end_value, last_update
+        let block = Block::default()
+            .title(" Restore a backup ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(1)])
+            .split(inner);
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(rows[0]);
+
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let primary = Style::default().fg(self.theme.text_primary);
+        let accent = Style::default().fg(self.theme.header);
+
+        let list = VirtualList::new(state.backups.len(), |i| {
+            let backup = &state.backups[i];
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    backup.taken.format("%Y-%m-%d %H:%M:%S  ").to_string(),
+                    muted,
+                ),
+                Span::raw(backup.file.clone()),
+                Span::styled(format!("  {} B", backup.size()), muted),
+            ]))
+        })
+        .style(primary)
+        .highlight_style(accent.add_modifier(Modifier::BOLD))
+        .highlight_symbol("➤ ");
+        f.render_stateful_widget(
+            list,
+            columns[0],
+            &mut VirtualListState::default().with_selected(Some(state.selection)),
         );
-        let footer_paragraph = Paragraph::new(footer).alignment(Alignment::Center).style(
-            Style::default()
-                .fg(self.theme.text_secondary)
-                .bg(self.theme.background),
+
+        let preview = state
+            .backups
+            .get(state.selection)
+            .and_then(|backup| std::fs::read_to_string(&backup.path).ok())
+            .unwrap_or_default();
+        f.render_widget(
+            Paragraph::new(preview)
+                .style(primary)
+                .wrap(ratatui::widgets::Wrap { trim: false })
+                .block(Block::default().borders(Borders::LEFT).border_style(muted)),
+            columns[1],
         );
-        f.render_widget(footer_paragraph, history_layout[1]);
 
-        let quick_actions = Paragraph::new(Self::indicator_quick_actions_text(state))
-            .alignment(Alignment::Center)
-            .style(
-                Style::default()
-                    .fg(self.theme.text_secondary)
-                    .bg(self.theme.panel_bg),
-            );
-        f.render_widget(quick_actions, body_layout[3]);
+        f.render_widget(
+            Paragraph::new("j/k select · Enter Enter restore · Esc close").style(muted),
+            rows[1],
+        );
+    }
 
-        let unit_label = Self::unit_label(&state.unit);
-        let input_display = format!("[ {} ] {}", state.buffer, unit_label);
-        let input_paragraph = Paragraph::new(input_display)
-            .alignment(Alignment::Center)
-            .style(Style::default().fg(self.theme.text_primary))
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(" Input Value ")
-                    .border_style(Style::default().fg(self.theme.border))
-                    .style(Style::default().bg(self.theme.background)),
-            );
-        f.render_widget(input_paragraph, body_layout[4]);
+    fn render_templates(&self, f: &mut Frame, state: &TemplatesState) {
+        let area = centered_rect(75, 70, f.area());
+        f.render_widget(Clear, area);
 
-        let helper_footer = Paragraph::new(Line::from(vec![
-            Span::styled("Enter", Style::default().fg(self.theme.header)),
-            Span::raw(" Save  "),
-            Span::styled("Backspace", Style::default().fg(self.theme.header)),
-            Span::raw(" Delete  "),
-            Span::styled("Esc", Style::default().fg(self.theme.header)),
-            Span::raw(" Cancel"),
-        ]))
-        .alignment(Alignment::Center)
-        .style(
-            Style::default()
-                .fg(self.theme.text_secondary)
-                .bg(self.theme.panel_bg),
+        let block = Block::default()
+            .title(" Templates ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(1)])
+            .split(inner);
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+            .split(rows[0]);
+
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let primary = Style::default().fg(self.theme.text_primary);
+        let accent = Style::default().fg(self.theme.header);
+        let list = templates::list(&state.templates);
+
+        let mut lines = Vec::new();
+        if list.is_empty() {
+            lines.push(Line::styled(
+                "No templates yet. Save one with :daytemplate.",
+                muted,
+            ));
+        }
+        for (i, template) in list.iter().enumerate() {
+            let kind = match template {
+                TemplateRef::Actions(_) => "actions".to_string(),
+                TemplateRef::Weekday(name) => state
+                    .templates
+                    .weekday_templates
+                    .get(name)
+                    .map(|weekday| {
+                        let days: Vec<_> = weekday.weekdays.iter().map(|d| d.to_string()).collect();
+                        days.join(" ")
+                    })
+                    .unwrap_or_default(),
+            };
+            let count = templates::actions(&state.templates, template).len();
+            let selected = i == state.selection;
+            lines.push(Line::from(vec![
+                Span::styled(if selected { "➤ " } else { "  " }, accent),
+                Span::styled(
+                    format!("{:<20}", template.name()),
+                    if selected && state.action.is_none() {
+                        primary.add_modifier(Modifier::BOLD)
+                    } else {
+                        primary
+                    },
+                ),
+                Span::styled(format!(" {} · {}", kind, count), muted),
+            ]));
+        }
+        f.render_widget(Paragraph::new(lines), columns[0]);
+
+        let mut preview = Vec::new();
+        if let Some(template) = list.get(state.selection) {
+            preview.push(Line::styled(
+                template.name().to_string(),
+                accent.add_modifier(Modifier::BOLD),
+            ));
+            let actions = templates::actions(&state.templates, template);
+            if actions.is_empty() {
+                preview.push(Line::styled("No actions. Press Enter, then n.", muted));
+            }
+            let mut heading = None;
+            for (i, action) in actions.iter().enumerate() {
+                if let Some(outcome_type) = action.outcome {
+                    if heading != Some(outcome_type) {
+                        heading = Some(outcome_type);
+                        preview.push(Line::styled(
+                            outcome_type.as_str().to_string(),
+                            Style::default()
+                                .fg(self.outcome_color(outcome_type))
+                                .add_modifier(Modifier::BOLD),
+                        ));
+                    }
+                }
+                let selected = state.action == Some(i);
+                preview.push(Line::from(vec![
+                    Span::styled(if selected { "➤ " } else { "  " }, accent),
+                    Span::styled(
+                        format!("• {}", action.text),
+                        if selected {
+                            primary.add_modifier(Modifier::BOLD)
+                        } else {
+                            primary
+                        },
+                    ),
+                ]));
+            }
+        }
+        f.render_widget(
+            Paragraph::new(preview).wrap(ratatui::widgets::Wrap { trim: true }),
+            columns[1],
         );
-        f.render_widget(helper_footer, body_layout[5]);
-    }
 
-    fn parse_value_from_buffer(buffer: &str, unit: &IndicatorUnit) -> anyhow::Result<f64> {
-        let value = buffer.trim().parse::<f64>()?;
-        Ok(Self::clamp_value_for_unit(value, unit))
+        let help = if state.action.is_some() {
+            "j/k select · e edit · n new · x remove · h back".to_string()
+        } else {
+            format!(
+                "j/k select · Enter actions · r rename · a apply to {} · d d delete · Esc close",
+                self.selected_outcome.as_str()
+            )
+        };
+        f.render_widget(Paragraph::new(help).style(muted), rows[1]);
     }
 
-    fn clamp_value_for_unit(value: f64, unit: &IndicatorUnit) -> f64 {
-        match unit {
-            IndicatorUnit::Percent => value.clamp(0.0, 100.0),
-            _ => value.max(0.0),
+    fn render_close_day(&self, f: &mut Frame, state: &CloseDayState) {
+        let height = (state.unfinished.len() as u16 + 9).min(f.area().height);
+        let area = centered_rect(60, 100, f.area());
+        let area = Rect {
+            y: area.y + area.height.saturating_sub(height) / 2,
+            height,
+            ..area
+        };
+        f.render_widget(Clear, area);
+
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let primary = Style::default().fg(self.theme.text_primary);
+        let accent = Style::default().fg(self.theme.header);
+        let percent = (state.completed * 100)
+            .checked_div(state.planned)
+            .unwrap_or(0);
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled(
+                    format!("{}/{} actions done ", state.completed, state.planned),
+                    primary.add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(format!("({}%)", percent), muted),
+            ]),
+            Line::raw(""),
+        ];
+        if state.unfinished.is_empty() {
+            lines.push(Line::styled("Nothing left to roll over.", muted));
+        } else {
+            lines.push(Line::styled("Rolling into tomorrow:", accent));
+            for item in &state.unfinished {
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("  {:<8}", item.outcome_type.as_str()),
+                        Style::default().fg(self.outcome_color(item.outcome_type)),
+                    ),
+                    Span::styled(item.text.clone(), primary),
+                ]));
+            }
         }
+        lines.push(Line::raw(""));
+        lines.push(Line::styled(
+            "The summary is saved and the day becomes read-only.",
+            muted,
+        ));
+        lines.push(Line::from(vec![
+            Span::styled("Enter/y", accent),
+            Span::raw(" Close the day  "),
+            Span::styled("Esc", accent),
+            Span::raw(" Cancel"),
+        ]));
+
+        let block = Block::default()
+            .title(format!(" Close {} ", self.goals.date.format("%A, %B %d")))
+            .borders(Borders::ALL)
+            .border_style(accent)
+            .style(Style::default().bg(self.theme.panel_bg));
+        f.render_widget(Paragraph::new(lines).block(block), area);
     }
 
-    fn format_value_for_unit(value: f64, unit: &IndicatorUnit) -> String {
-        match unit {
-            IndicatorUnit::Percent => format!("{:.0}", value),
-            IndicatorUnit::Count => format!("{:.0}", value),
-            IndicatorUnit::Minutes | IndicatorUnit::Dollars | IndicatorUnit::Custom(_) => {
-                if value.fract().abs() < f64::EPSILON {
-                    format!("{:.0}", value)
-                } else {
-                    format!("{:.2}", value)
+    fn render_morning(&self, f: &mut Frame, state: &MorningState) {
+        let area = centered_rect(70, 75, f.area());
+        f.render_widget(Clear, area);
+
+        let (title, color) = match state.step {
+            MorningStep::Review => ("Yesterday".to_string(), self.theme.header),
+            MorningStep::Priority(page) => {
+                let outcome_type = state.plan.intentions[page].outcome_type;
+                (
+                    format!("{} priority", outcome_type.as_str()),
+                    self.outcome_color(outcome_type),
+                )
+            }
+            MorningStep::Estimates => ("Time estimates".to_string(), self.theme.header),
+            MorningStep::Confirm => ("Confirm".to_string(), self.theme.header),
+        };
+        let block = Block::default()
+            .title(format!(" Morning Intentions · {} ", title))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(color))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(1)])
+            .split(inner);
+
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let primary = Style::default().fg(self.theme.text_primary);
+        let accent = Style::default().fg(self.theme.header);
+        let mut lines = Vec::new();
+        let mut help = Vec::new();
+        match state.step {
+            MorningStep::Review => {
+                match &state.plan.yesterday {
+                    None => lines.push(Line::styled("Nothing was planned yesterday.", muted)),
+                    Some(outcomes) => {
+                        for outcome in outcomes {
+                            lines.push(Line::from(vec![
+                                Span::styled(
+                                    format!("{:<8}", outcome.outcome_type.as_str()),
+                                    Style::default()
+                                        .fg(self.outcome_color(outcome.outcome_type))
+                                        .add_modifier(Modifier::BOLD),
+                                ),
+                                Span::styled(
+                                    format!("{}/{} done", outcome.completed, outcome.planned),
+                                    primary,
+                                ),
+                            ]));
+                            for text in &outcome.unfinished {
+                                lines.push(Line::styled(format!("  ○ {}", text), muted));
+                            }
+                        }
+                    }
+                }
+                help.extend([
+                    Span::styled("Enter", accent),
+                    Span::raw(" Pick priorities  "),
+                ]);
+            }
+            MorningStep::Priority(page) => {
+                let intention = &state.plan.intentions[page];
+                lines.push(Line::styled("What matters most here today?", accent));
+                if intention.actions.is_empty() {
+                    lines.push(Line::styled("No actions yet; press n to add one.", muted));
+                }
+                for (i, (index, text)) in intention.actions.iter().enumerate() {
+                    let chosen = intention.choice == Some(morning::Choice::Existing(*index));
+                    lines.push(Line::from(vec![
+                        Span::styled(if i == state.selection { "➤ " } else { "  " }, accent),
+                        Span::styled(
+                            if chosen { "★ " } else { "  " },
+                            Style::default().fg(self.theme.completed),
+                        ),
+                        Span::styled(text.clone(), primary),
+                    ]));
+                }
+                if let Some(morning::Choice::New(text)) = &intention.choice {
+                    lines.push(Line::from(vec![
+                        Span::raw("  "),
+                        Span::styled("★ ", Style::default().fg(self.theme.completed)),
+                        Span::styled(format!("{} (new)", text), primary),
+                    ]));
+                }
+                help.extend([
+                    Span::styled("Space", accent),
+                    Span::raw(" Choose  "),
+                    Span::styled("Enter", accent),
+                    Span::raw(" Choose & next  "),
+                    Span::styled("n", accent),
+                    Span::raw(" New  "),
+                    Span::styled("x", accent),
+                    Span::raw(" None  "),
+                ]);
+            }
+            MorningStep::Estimates => {
+                lines.push(Line::styled("How long will each take?", accent));
+                for (i, intention) in state.plan.intentions.iter().enumerate() {
+                    let estimate = match (intention.chosen_text(), intention.estimate_min) {
+                        (None, _) => "—".to_string(),
+                        (Some(_), None) => "?".to_string(),
+                        (Some(_), Some(minutes)) => crate::models::format_minutes(minutes),
+                    };
+                    lines.push(Line::from(vec![
+                        Span::styled(if i == state.selection { "➤ " } else { "  " }, accent),
+                        Span::styled(
+                            format!("{:>6}  ", estimate),
+                            Style::default().fg(self.theme.header),
+                        ),
+                        Span::styled(
+                            intention.chosen_text().unwrap_or("no priority").to_string(),
+                            if intention.choice.is_some() { primary } else { muted },
+                        ),
+                    ]));
+                }
+                help.extend([
+                    Span::styled("+/-", accent),
+                    Span::raw(" 15m  "),
+                    Span::styled("e", accent),
+                    Span::raw(" Type  "),
+                    Span::styled("Enter", accent),
+                    Span::raw(" Next  "),
+                ]);
+            }
+            MorningStep::Confirm => {
+                lines.push(Line::styled("Today's intentions:", accent));
+                for intention in &state.plan.intentions {
+                    lines.push(Line::from(vec![
+                        Span::styled(
+                            format!("{:<8}", intention.outcome_type.as_str()),
+                            Style::default().fg(self.outcome_color(intention.outcome_type)),
+                        ),
+                        Span::styled(
+                            intention.chosen_text().unwrap_or("no priority").to_string(),
+                            if intention.choice.is_some() { primary } else { muted },
+                        ),
+                        Span::styled(
+                            intention
+                                .estimate_min
+                                .filter(|_| intention.choice.is_some())
+                                .map(|minutes| {
+                                    format!(" ({})", crate::models::format_minutes(minutes))
+                                })
+                                .unwrap_or_default(),
+                            muted,
+                        ),
+                    ]));
                 }
+                let total = state.plan.total_estimate();
+                if total > 0 {
+                    lines.push(Line::raw(""));
+                    lines.push(Line::styled(
+                        format!("{} planned in total", crate::models::format_minutes(total)),
+                        muted,
+                    ));
+                }
+                help.extend([Span::styled("Enter/s", accent), Span::raw(" Save  ")]);
             }
         }
+        help.extend([
+            Span::styled("h", accent),
+            Span::raw(" Back  "),
+            Span::styled("Esc", accent),
+            Span::raw(" Discard"),
+        ]);
+        f.render_widget(
+            Paragraph::new(lines).wrap(ratatui::widgets::Wrap { trim: false }),
+            layout[0],
+        );
+        f.render_widget(Paragraph::new(Line::from(help)).style(muted), layout[1]);
     }
 
-    fn unit_label(unit: &IndicatorUnit) -> String {
-        match unit {
-            IndicatorUnit::Count => "count".to_string(),
-            IndicatorUnit::Minutes => "minutes".to_string(),
-            IndicatorUnit::Dollars => "dollars".to_string(),
-            IndicatorUnit::Percent => "%".to_string(),
-            IndicatorUnit::Custom(label) => label.clone(),
+    fn render_vision_history(&self, f: &mut Frame, state: &VisionHistoryState) {
+        let area = centered_rect(80, 75, f.area());
+        f.render_widget(Clear, area);
+
+        let block = Block::default()
+            .title(" Vision History ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Min(3),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(22), Constraint::Min(20)])
+            .split(rows[1]);
+
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let accent = Style::default().fg(self.theme.header);
+        let tabs: Vec<Span> = WEEKLY_OUTCOMES
+            .into_iter()
+            .map(|outcome_type| {
+                let style = if outcome_type == state.outcome_type {
+                    Style::default()
+                        .fg(self.outcome_color(outcome_type))
+                        .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+                } else {
+                    muted
+                };
+                Span::styled(format!(" {} ", outcome_type.as_str().to_uppercase()), style)
+            })
+            .collect();
+        f.render_widget(Paragraph::new(Line::from(tabs)), rows[0]);
+
+        if state.changes.is_empty() {
+            f.render_widget(
+                Paragraph::new("No saved versions yet. Versions are kept each time the vision is edited with v.")
+                    .style(muted)
+                    .wrap(ratatui::widgets::Wrap { trim: true }),
+                rows[1],
+            );
+        } else {
+            // Newest first
+            let dates = VirtualList::new(state.changes.len(), |i| {
+                let change = &state.changes[state.changes.len() - 1 - i];
+                ListItem::new(change.saved.format("%Y-%m-%d %H:%M").to_string())
+            })
+            .style(muted)
+            .highlight_style(accent.add_modifier(Modifier::BOLD))
+            .highlight_symbol("➤ ");
+            f.render_stateful_widget(
+                dates,
+                columns[0],
+                &mut VirtualListState::default().with_selected(Some(state.selection)),
+            );
+
+            let change = &state.changes[state.changes.len() - 1 - state.selection];
+            let removed = Style::default()
+                .fg(self.theme.severity_color(Severity::Bad))
+                .add_modifier(Modifier::CROSSED_OUT);
+            let added = Style::default()
+                .fg(self.theme.completed)
+                .add_modifier(Modifier::BOLD);
+            let mut spans = Vec::new();
+            for part in change.diff() {
+                let (text, style) = match part {
+                    DiffPart::Same(text) => (text, Style::default().fg(self.theme.text_primary)),
+                    DiffPart::Added(text) => (text, added),
+                    DiffPart::Removed(text) => (text, removed),
+                };
+                if !spans.is_empty() {
+                    spans.push(Span::raw(" "));
+                }
+                spans.push(Span::styled(text, style));
+            }
+            f.render_widget(
+                Paragraph::new(Line::from(spans))
+                    .wrap(ratatui::widgets::Wrap { trim: true })
+                    .block(
+                        Block::default()
+                            .borders(Borders::LEFT)
+                            .border_style(Style::default().fg(self.theme.border)),
+                    ),
+                columns[1],
+            );
         }
-    }
 
-    fn adjust_buffer_value(buffer: &str, unit: &IndicatorUnit, delta: f64) -> String {
-        let current = buffer.trim().parse::<f64>().unwrap_or(0.0);
-        let adjusted = Self::clamp_value_for_unit(current + delta, unit);
-        Self::format_value_for_unit(adjusted, unit)
+        let help = vec![
+            Span::styled("j/k", accent),
+            Span::raw(" Version  "),
+            Span::styled("Tab", accent),
+            Span::raw(" Outcome  "),
+            Span::styled("Esc", accent),
+            Span::raw(" Close"),
+        ];
+        f.render_widget(Paragraph::new(Line::from(help)).style(muted), rows[2]);
     }
 
-    fn apply_indicator_update(&mut self, state: &IndicatorUpdateState) -> anyhow::Result<()> {
-        if state.buffer.trim().is_empty() {
-            self.error_display
-                .show_error("Enter a value before saving".to_string());
-            return Ok(());
-        }
+    fn render_projects(&self, f: &mut Frame, state: &ProjectsState) {
+        let area = centered_rect(75, 80, f.area());
+        f.render_widget(Clear, area);
 
-        let value = match Self::parse_value_from_buffer(&state.buffer, &state.unit) {
-            Ok(value) => value,
-            Err(err) => {
-                self.error_display
-                    .show_error(format!("Invalid indicator value: {}", err));
-                return Ok(());
+        let block = Block::default()
+            .title(" Projects ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Percentage(45),
+                Constraint::Min(3),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let primary = Style::default().fg(self.theme.text_primary);
+        let accent = Style::default().fg(self.theme.header);
+        let objectives = &self.objectives.objectives;
+        let indicator_progress = self.indicator_progress();
+
+        let mut lines = Vec::new();
+        if state.projects.projects.is_empty() {
+            lines.push(Line::styled(
+                "No projects yet. Press n to group objectives from several domains.",
+                muted,
+            ));
+        }
+        for (i, project) in state.projects.projects.iter().enumerate() {
+            let rollup = ProjectRollup::new(project, objectives, &indicator_progress);
+            let marker = if i == state.selection { "➤ " } else { "  " };
+            let mut spans = vec![
+                Span::styled(marker, accent),
+                Span::styled(
+                    format!("{:<28}", project.title),
+                    primary.add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    year_review::bar(rollup.progress),
+                    Style::default().fg(self.theme.completed),
+                ),
+                Span::styled(
+                    format!(
+                        " {:>3.0}%  {}/{} done  ",
+                        rollup.progress, rollup.completed, rollup.objectives
+                    ),
+                    muted,
+                ),
+            ];
+            for domain in &rollup.domains {
+                spans.push(Span::styled(
+                    "● ",
+                    Style::default().fg(self.outcome_color(*domain)),
+                ));
             }
-        };
+            lines.push(Line::from(spans));
+        }
+        f.render_widget(Paragraph::new(lines), layout[0]);
+
+        let selected = state.projects.projects.get(state.selection);
+        let mut detail = Vec::new();
+        match (selected, state.assigning) {
+            (Some(project), Some(cursor)) => {
+                detail.push(Line::styled(
+                    format!("Objectives in {}", project.title),
+                    accent.add_modifier(Modifier::BOLD),
+                ));
+                for (i, objective) in objectives.iter().enumerate() {
+                    let member = project.objective_ids.contains(&objective.id);
+                    detail.push(Line::from(vec![
+                        Span::styled(if i == cursor { "➤ " } else { "  " }, accent),
+                        Span::styled(if member { "[x] " } else { "[ ] " }, primary),
+                        Span::styled(
+                            format!("{:<7}", objective.domain.as_str()),
+                            Style::default().fg(self.outcome_color(objective.domain)),
+                        ),
+                        Span::styled(objective.title.clone(), primary),
+                    ]));
+                }
+            }
+            (Some(project), None) => {
+                for objective in project
+                    .objective_ids
+                    .iter()
+                    .filter_map(|id| objectives.iter().find(|objective| &objective.id == id))
+                {
+                    let progress = projects::objective_progress(objective, &indicator_progress);
+                    detail.push(Line::from(vec![
+                        Span::styled(
+                            format!("{:<7}", objective.domain.as_str()),
+                            Style::default().fg(self.outcome_color(objective.domain)),
+                        ),
+                        Span::styled(format!("{:<32}", objective.title), primary),
+                        Span::styled(
+                            format!("{:>3.0}%  {:?}", progress * 100.0, objective.status),
+                            muted,
+                        ),
+                    ]));
+                }
+                if detail.is_empty() {
+                    detail.push(Line::styled(
+                        "No objectives yet. Press Enter to choose some.",
+                        muted,
+                    ));
+                }
+            }
+            (None, _) => {}
+        }
+        let scroll = state.assigning.map_or(0, |cursor| {
+            (cursor + 1).saturating_sub(layout[1].height.saturating_sub(1) as usize) as u16
+        });
+        f.render_widget(
+            Paragraph::new(detail)
+                .block(
+                    Block::default()
+                        .borders(Borders::TOP)
+                        .border_style(Style::default().fg(self.theme.border)),
+                )
+                .scroll((scroll, 0)),
+            layout[1],
+        );
 
-        let observation = Observation {
-            id: Uuid::new_v4().to_string(),
-            indicator_id: state.indicator_id.clone(),
-            when: chrono::Local::now().date_naive(),
-            value,
-            unit: state.unit.clone(),
-            source: ObservationSource::Manual,
-            action_id: None,
-            note: None,
-            created: chrono::Utc::now(),
+        let help = if state.assigning.is_some() {
+            vec![
+                Span::styled("j/k", accent),
+                Span::raw(" Move  "),
+                Span::styled("Space", accent),
+                Span::raw(" Add/remove  "),
+                Span::styled("Esc", accent),
+                Span::raw(" Done"),
+            ]
+        } else {
+            vec![
+                Span::styled("n", accent),
+                Span::raw(" New  "),
+                Span::styled("r", accent),
+                Span::raw(" Rename  "),
+                Span::styled("Enter", accent),
+                Span::raw(" Objectives  "),
+                Span::styled("d", accent),
+                Span::raw(" Delete  "),
+                Span::styled("Esc", accent),
+                Span::raw(" Close"),
+            ]
         };
+        f.render_widget(Paragraph::new(Line::from(help)).style(muted), layout[2]);
+    }
 
-        crate::data::append_observation(&observation, &self.config)?;
+    fn render_year_review(&self, f: &mut Frame, state: &YearReviewState) {
+        let area = centered_rect(80, 85, f.area());
+        f.render_widget(Clear, area);
 
-        if let Some(indicator) = self
-            .indicators
-            .indicators
-            .iter_mut()
-            .find(|def| def.id == state.indicator_id)
-        {
-            indicator.modified = chrono::Utc::now();
-        }
+        let review = &state.review;
+        let block = Block::default()
+            .title(format!(" {} in Review ", review.year))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(3), Constraint::Length(1)])
+            .split(inner);
 
-        if let Err(err) = crate::data::save_indicators(&self.indicators, &self.config) {
-            self.error_display
-                .show_error(format!("Failed to update indicators: {}", err));
+        let heading = Style::default()
+            .fg(self.theme.header)
+            .add_modifier(Modifier::BOLD);
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let primary = Style::default().fg(self.theme.text_primary);
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled(format!("{} days tracked", review.days_tracked), primary),
+                Span::styled(
+                    format!("  ·  {:.0}% average completion", review.completion),
+                    primary,
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled(
+                    format!("Work {:.0}%  ", review.outcome_completion[0]),
+                    Style::default().fg(self.theme.work_color),
+                ),
+                Span::styled(
+                    format!("Health {:.0}%  ", review.outcome_completion[1]),
+                    Style::default().fg(self.theme.health_color),
+                ),
+                Span::styled(
+                    format!("Family {:.0}%", review.outcome_completion[2]),
+                    Style::default().fg(self.theme.family_color),
+                ),
+            ]),
+            Line::styled(
+                match review.longest_streak {
+                    Some(streak) => format!(
+                        "Longest streak: {} days ({} - {})",
+                        streak.days,
+                        streak.start.format("%b %d"),
+                        streak.end.format("%b %d")
+                    ),
+                    None => "Longest streak: none yet".to_string(),
+                },
+                Style::default().fg(self.theme.partial),
+            ),
+            Line::raw(""),
+            Line::styled("COMPLETION BY MONTH", heading),
+        ];
+        for month in &review.months {
+            let color = if month.days_tracked == 0 {
+                self.theme.text_secondary
+            } else if month.completion >= 70.0 {
+                self.theme.completed
+            } else {
+                self.theme.partial
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("{:<10}", month.label), muted),
+                Span::styled(
+                    year_review::bar(month.completion),
+                    Style::default().fg(color),
+                ),
+                Span::styled(
+                    format!(" {:>3.0}%  {} days", month.completion, month.days_tracked),
+                    muted,
+                ),
+            ]));
         }
 
-        if let Some(indicator) = self.indicators_map.get_mut(&state.indicator_id) {
-            indicator.current_value = value;
-            indicator.history.push(crate::models::IndicatorEntry {
-                timestamp: chrono::Utc::now(),
-                value,
-                note: None,
-            });
+        lines.push(Line::raw(""));
+        lines.push(Line::styled("OBJECTIVES COMPLETED", heading));
+        if review.objectives.is_empty() {
+            lines.push(Line::styled("None this year", muted));
         }
-
-        self.error_display
-            .show_info("Indicator value recorded".to_string());
-
-        // Refresh dashboard cursor bounds
-        if !self.dashboard_signal_ids.is_empty() {
-            self.dashboard_signal_index = self
-                .dashboard_signal_index
-                .min(self.dashboard_signal_ids.len().saturating_sub(1));
+        for objective in review.objectives.iter().take(year_review::TOP_OBJECTIVES) {
+            lines.push(Line::from(vec![
+                Span::styled("✓ ", Style::default().fg(self.theme.completed)),
+                Span::styled(objective.title.clone(), primary),
+                Span::styled(
+                    format!(
+                        "  {} · {}",
+                        objective.domain.as_str(),
+                        objective.completed.format("%b %d")
+                    ),
+                    muted,
+                ),
+            ]));
         }
-
-        Ok(())
-    }
-
-    fn get_outcome_by_type(&self, outcome_type: OutcomeType) -> &crate::models::Outcome {
-        match outcome_type {
-            OutcomeType::Work => &self.goals.work,
-            OutcomeType::Health => &self.goals.health,
-            OutcomeType::Family => &self.goals.family,
+        if review.objectives.len() > year_review::TOP_OBJECTIVES {
+            lines.push(Line::styled(
+                format!(
+                    "…and {} more",
+                    review.objectives.len() - year_review::TOP_OBJECTIVES
+                ),
+                muted,
+            ));
         }
-    }
 
-    fn get_outcome_by_type_mut(
-        &mut self,
-        outcome_type: OutcomeType,
-    ) -> &mut crate::models::Outcome {
-        match outcome_type {
-            OutcomeType::Work => &mut self.goals.work,
-            OutcomeType::Health => &mut self.goals.health,
-            OutcomeType::Family => &mut self.goals.family,
+        lines.push(Line::raw(""));
+        lines.push(Line::styled("INDICATOR BESTS", heading));
+        if review.indicator_bests.is_empty() {
+            lines.push(Line::styled("No observations this year", muted));
         }
+        for best in &review.indicator_bests {
+            lines.push(Line::from(vec![
+                Span::styled(format!("{}: ", best.name), primary),
+                Span::styled(
+                    best.value.clone(),
+                    Style::default().fg(self.theme.completed),
+                ),
+                Span::styled(format!("  {}", best.date.format("%b %d")), muted),
+            ]));
+        }
+
+        f.render_widget(Paragraph::new(lines).scroll((state.scroll, 0)), layout[0]);
+
+        let accent = Style::default().fg(self.theme.header);
+        let help = vec![
+            Span::styled("j/k", accent),
+            Span::raw(" Scroll  "),
+            Span::styled("h/l", accent),
+            Span::raw(" Year  "),
+            Span::styled("x", accent),
+            Span::raw(" Export markdown  "),
+            Span::styled("Esc", accent),
+            Span::raw(" Close"),
+        ];
+        f.render_widget(Paragraph::new(Line::from(help)).style(muted), layout[1]);
     }
 
-    fn open_editor(&mut self) {
-        // Only allow editing when focused on Actions panel
-        if self.focus_panel == FocusPanel::Actions {
-            let action_text = {
-                let outcome = self.get_selected_outcome();
-                outcome.actions[self.selected_action].text.clone()
-            };
-            self.text_editor.activate_with(
-                "Edit Action",
-                &action_text,
-                crate::models::MAX_ACTION_LENGTH,
-            );
-            self.editor_context = Some(EditorContext::Action {
-                outcome_type: self.selected_outcome,
-                index: self.selected_action,
+    fn render_weekly_plan(&self, f: &mut Frame, state: &WeeklyPlanState) {
+        let area = centered_rect(70, 70, f.area());
+        f.render_widget(Clear, area);
+
+        let range = state
+            .weekly
+            .start()
+            .map(|monday| {
+                format!(
+                    " ({} - {})",
+                    monday.format("%b %d"),
+                    (monday + chrono::Duration::days(6)).format("%b %d")
+                )
+            })
+            .unwrap_or_default();
+        let block = Block::default()
+            .title(format!(
+                " Weekly Plan {}-W{:02}{} ",
+                state.weekly.year, state.weekly.week, range
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let on_screen = state.weekly.week_iso() == self.weekly_goals.week_iso();
+        let mut lines = Vec::new();
+        for (i, outcome_type) in WEEKLY_OUTCOMES.into_iter().enumerate() {
+            let color = match outcome_type {
+                OutcomeType::Work => self.theme.work_color,
+                OutcomeType::Health => self.theme.health_color,
+                OutcomeType::Family => self.theme.family_color,
+            };
+            let marker = if i == state.selection { "➤ " } else { "  " };
+            lines.push(Line::from(vec![
+                Span::styled(marker, Style::default().fg(self.theme.header)),
+                Span::styled(
+                    outcome_type.as_str().to_uppercase(),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+            ]));
+            let vision = self.vision.get_vision(&outcome_type);
+            if !vision.is_empty() {
+                lines.push(Line::styled(format!("    Vision: {}", vision), muted));
+            }
+            let focus = state.weekly.get_focus(outcome_type);
+            lines.push(if focus.is_empty() {
+                Line::styled("    Focus: (not set)", muted.add_modifier(Modifier::ITALIC))
+            } else {
+                Line::from(vec![
+                    Span::styled("    Focus: ", muted),
+                    Span::styled(
+                        focus.to_string(),
+                        Style::default().fg(self.theme.text_primary),
+                    ),
+                ])
             });
+            if on_screen {
+                let linked = self
+                    .weekly_linked
+                    .iter()
+                    .filter(|(linked_type, _)| *linked_type == outcome_type)
+                    .count();
+                lines.push(Line::styled(
+                    format!(
+                        "    {} action{} on {} linked",
+                        linked,
+                        if linked == 1 { "" } else { "s" },
+                        self.goals.date.format("%b %d")
+                    ),
+                    muted,
+                ));
+            }
+            lines.push(Line::raw(""));
         }
-    }
 
-    fn open_vision_editor(&mut self) {
-        if self.focus_panel != FocusPanel::Outcomes {
-            self.focus_panel = FocusPanel::Outcomes;
-        }
-        let outcome_type = self.selected_outcome;
-        let vision_text = self.vision.get_vision(&outcome_type).to_string();
-        self.text_editor.activate_with(
-            "Edit 5-Year Vision",
-            &vision_text,
-            crate::models::MAX_VISION_LENGTH,
+        let accent = Style::default().fg(self.theme.header);
+        lines.push(Line::from(vec![
+            Span::styled("j/k", accent),
+            Span::raw(" Outcome  "),
+            Span::styled("Enter", accent),
+            Span::raw(" Edit focus  "),
+            Span::styled("h/l", accent),
+            Span::raw(" Week  "),
+            Span::styled("Esc", accent),
+            Span::raw(" Close"),
+        ]));
+        f.render_widget(
+            Paragraph::new(lines)
+                .style(muted)
+                .wrap(ratatui::widgets::Wrap { trim: false }),
+            inner,
         );
-        self.editor_context = Some(EditorContext::Vision { outcome_type });
     }
 
-    fn open_objective_picker(&mut self) {
-        if self.focus_panel != FocusPanel::Actions {
-            self.focus_panel = FocusPanel::Actions;
-        }
+    fn render_snooze_modal(&self, f: &mut Frame, state: &SnoozeState) {
+        let area = centered_rect(50, 20, f.area());
+        f.render_widget(Clear, area);
 
-        let outcome_type = self.selected_outcome;
-        let action_index = self.selected_action;
+        let block = Block::default()
+            .title(" Snooze Action ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
 
-        self.modal = Some(ModalState::ObjectivePicker(ObjectiveModalState {
-            outcome_type,
-            action_index,
-            selection: 0,
-        }));
+        let days = (state.until - self.goals.date).num_days();
+        let accent = Style::default().fg(self.theme.header);
+        let lines = vec![
+            Line::styled(
+                state.text.clone(),
+                Style::default().fg(self.theme.text_primary),
+            ),
+            Line::raw(""),
+            Line::from(vec![
+                Span::raw("Move to "),
+                Span::styled(
+                    state.until.format("%A, %B %d").to_string(),
+                    Style::default()
+                        .fg(self.theme.text_primary)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(format!(
+                    " (in {} day{})",
+                    days,
+                    if days == 1 { "" } else { "s" }
+                )),
+            ]),
+            Line::raw(""),
+            Line::from(vec![
+                Span::styled("←/→", accent),
+                Span::raw(" Day  "),
+                Span::styled("w", accent),
+                Span::raw(" +Week  "),
+                Span::styled("Enter", accent),
+                Span::raw(" Snooze  "),
+                Span::styled("Esc", accent),
+                Span::raw(" Cancel"),
+            ]),
+        ];
+        f.render_widget(
+            Paragraph::new(lines)
+                .style(Style::default().fg(self.theme.text_secondary))
+                .wrap(ratatui::widgets::Wrap { trim: true }),
+            inner,
+        );
     }
 
-    fn objective_choices(&self, outcome_type: OutcomeType) -> Vec<ObjectiveChoice> {
-        self.objectives
-            .objectives
-            .iter()
-            .enumerate()
-            .filter(|(_, obj)| obj.domain == outcome_type)
-            .map(|(index, obj)| ObjectiveChoice {
-                storage_index: index,
-                id: obj.id.clone(),
-                title: obj.title.clone(),
-                status: obj.status.clone(),
-            })
-            .collect()
-    }
+    fn render_link_suggestion(&self, f: &mut Frame, suggestion: &LinkSuggestion) {
+        let area = centered_rect(50, 20, f.area());
+        f.render_widget(Clear, area);
 
-    fn objective_index_in_domain(
-        &self,
-        outcome_type: OutcomeType,
-        objective_id: &str,
-    ) -> Option<usize> {
-        let mut index = 0;
-        for obj in self
-            .objectives
-            .objectives
-            .iter()
-            .filter(|o| o.domain == outcome_type)
-        {
-            if obj.id == objective_id {
-                return Some(index);
-            }
-            index += 1;
-        }
-        None
-    }
+        let block = Block::default()
+            .title(" Link Objective? ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
 
-    fn start_objective_creation(&mut self, outcome_type: OutcomeType, link_action: Option<usize>) {
-        self.text_editor
-            .activate_with("Create Objective", "", crate::models::MAX_GOAL_LENGTH);
-        self.editor_context = Some(EditorContext::ObjectiveTitle {
-            outcome_type,
-            objective_id: None,
-            link_action,
-        });
+        let lines = vec![
+            Line::raw(""),
+            Line::from(vec![
+                Span::raw("This action looks like part of "),
+                Span::styled(
+                    suggestion.title.clone(),
+                    Style::default()
+                        .fg(self.theme.text_primary)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::raw(""),
+            Line::from(vec![
+                Span::styled("y", Style::default().fg(self.theme.header)),
+                Span::raw(" Link  "),
+                Span::styled("n", Style::default().fg(self.theme.header)),
+                Span::raw(" Not now"),
+            ]),
+        ];
+        f.render_widget(
+            Paragraph::new(lines)
+                .style(Style::default().fg(self.theme.text_secondary))
+                .wrap(ratatui::widgets::Wrap { trim: true }),
+            inner,
+        );
     }
 
-    fn start_objective_rename(&mut self, outcome_type: OutcomeType, objective_id: String) {
-        if let Some(objective) = self
-            .objectives
-            .objectives
-            .iter()
-            .find(|o| o.id == objective_id)
-        {
-            self.text_editor.activate_with(
-                "Rename Objective",
-                &objective.title,
-                crate::models::MAX_GOAL_LENGTH,
-            );
-            self.editor_context = Some(EditorContext::ObjectiveTitle {
-                outcome_type,
-                objective_id: Some(objective.id.clone()),
-                link_action: None,
-            });
-        }
+    fn render_check_in_modal(&self, f: &mut Frame, state: &CheckInState) {
+        let area = centered_rect(50, 30, f.area());
+        f.render_widget(Clear, area);
+
+        let block = Block::default()
+            .title(format!(" {} ", state.period.label()))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let row = |label: &str, rating: Option<u8>, selected: bool| {
+            let filled = usize::from(rating.unwrap_or(0));
+            let dots = (1..=usize::from(CHECK_IN_MAX))
+                .map(|i| if i <= filled { "●" } else { "○" })
+                .collect::<Vec<_>>()
+                .join(" ");
+            let label_style = if selected {
+                Style::default()
+                    .fg(self.theme.header)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(self.theme.text_secondary)
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!("{} {:<8}", if selected { "➤" } else { " " }, label),
+                    label_style,
+                ),
+                Span::styled(dots, Style::default().fg(self.theme.partial)),
+                Span::styled(
+                    rating
+                        .map(|r| format!("  {}/{}", r, CHECK_IN_MAX))
+                        .unwrap_or_else(|| "  -".to_string()),
+                    Style::default().fg(self.theme.text_primary),
+                ),
+            ])
+        };
+
+        let lines = vec![
+            Line::raw(""),
+            row("Mood", state.mood, !state.editing_energy),
+            Line::raw(""),
+            row("Energy", state.energy, state.editing_energy),
+            Line::raw(""),
+            Line::from(vec![
+                Span::styled("1-5", Style::default().fg(self.theme.header)),
+                Span::raw(" Rate  "),
+                Span::styled("←/→", Style::default().fg(self.theme.header)),
+                Span::raw(" Adjust  "),
+                Span::styled("↑/↓", Style::default().fg(self.theme.header)),
+                Span::raw(" Field  "),
+                Span::styled("Enter", Style::default().fg(self.theme.header)),
+                Span::raw(" Save  "),
+                Span::styled("Esc", Style::default().fg(self.theme.header)),
+                Span::raw(" Cancel"),
+            ]),
+        ];
+        f.render_widget(
+            Paragraph::new(lines).style(Style::default().fg(self.theme.text_secondary)),
+            inner,
+        );
     }
 
-    fn delete_objective(&mut self, storage_index: usize, objective_id: &str) -> anyhow::Result<()> {
-        if storage_index >= self.objectives.objectives.len() {
-            return Ok(());
-        }
+    fn render_check_in_analytics(&self, f: &mut Frame, analytics: &CheckInAnalytics) {
+        let area = centered_rect(70, 70, f.area());
+        f.render_widget(Clear, area);
 
-        let backup_objectives = self.objectives.clone();
-        let backup_goals = self.goals.clone();
+        let block = Block::default()
+            .title(format!(
+                " Mood & Energy vs Completion ({} days) ",
+                CHECK_IN_ANALYTICS_DAYS
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
 
-        self.objectives.objectives.remove(storage_index);
+        let heading = |text: &str| {
+            Line::from(Span::styled(
+                text.to_string(),
+                Style::default()
+                    .fg(self.theme.header)
+                    .add_modifier(Modifier::BOLD),
+            ))
+        };
+        let correlation = |label: &str, r: Option<f64>| {
+            let detail = match r {
+                Some(r) => {
+                    let strength = match r.abs() {
+                        a if a >= 0.5 => "strong",
+                        a if a >= 0.3 => "moderate",
+                        _ => "weak",
+                    };
+                    let direction = if r >= 0.0 { "positive" } else { "negative" };
+                    format!("r={:+.2} ({} {})", r, strength, direction)
+                }
+                None => "not enough data (3+ days needed)".to_string(),
+            };
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<22}", label),
+                    Style::default().fg(self.theme.text_secondary),
+                ),
+                Span::styled(detail, Style::default().fg(self.theme.text_primary)),
+            ])
+        };
 
-        // Remove objective references from all actions
-        for outcome in [
-            &mut self.goals.work,
-            &mut self.goals.health,
-            &mut self.goals.family,
-        ] {
-            for action in &mut outcome.actions {
-                action.remove_objective_id(objective_id);
-            }
+        let mut lines = vec![
+            Line::from(Span::styled(
+                format!("{} days with check-ins", analytics.days.len()),
+                Style::default().fg(self.theme.text_secondary),
+            )),
+            Line::raw(""),
+            correlation("Mood ↔ completion", analytics.mood_correlation),
+            correlation("Energy ↔ completion", analytics.energy_correlation),
+            Line::raw(""),
+            heading("Completion by mood"),
+        ];
+        for (index, average) in analytics.completion_by_mood.iter().enumerate() {
+            let detail = match average {
+                Some(pct) => {
+                    let filled = (pct / 10.0).round().clamp(0.0, 10.0) as usize;
+                    Span::styled(
+                        format!(
+                            "{}{} {:>3.0}%",
+                            "█".repeat(filled),
+                            "░".repeat(10 - filled),
+                            pct
+                        ),
+                        Style::default().fg(self.theme.completed),
+                    )
+                }
+                None => Span::styled("no data", Style::default().fg(self.theme.text_secondary)),
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {}  ", index + 1),
+                    Style::default().fg(self.theme.text_primary),
+                ),
+                detail,
+            ]));
         }
 
-        if let Err(e) = crate::data::save_objectives(&self.objectives, &self.config) {
-            self.error_display
-                .show_error(format!("Failed to save objectives: {}", e));
-            self.objectives = backup_objectives;
-            self.goals = backup_goals;
-            return Err(e);
+        lines.push(Line::raw(""));
+        lines.push(heading("Recent check-ins"));
+        let fmt = |value: Option<f64>| {
+            value
+                .map(|v| format!("{:.1}", v))
+                .unwrap_or_else(|| "-".to_string())
+        };
+        for day in analytics.days.iter().rev().take(7) {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  {}  mood {:>3}  energy {:>3}  done {:>3.0}%",
+                    day.date.format("%a %b %d"),
+                    fmt(day.mood),
+                    fmt(day.energy),
+                    day.completion
+                ),
+                Style::default().fg(self.theme.text_primary),
+            )));
         }
-
-        if let Err(e) = crate::data::write_goals_file(&self.goals, &self.config) {
-            self.error_display
-                .show_error(format!("Failed to update goals: {}", e));
-            self.objectives = backup_objectives;
-            self.goals = backup_goals;
-            return Err(e);
+        if analytics.days.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  No check-ins yet - press m to log mood and energy",
+                Style::default().fg(self.theme.text_secondary),
+            )));
         }
 
-        Ok(())
+        f.render_widget(Paragraph::new(lines), inner);
     }
 
-    fn toggle_action_objective(
-        &mut self,
-        outcome_type: OutcomeType,
-        action_index: usize,
-        objective_id: &str,
-    ) -> anyhow::Result<()> {
-        let outcome = self.get_outcome_by_type_mut(outcome_type);
-        if action_index >= outcome.actions.len() {
-            self.error_display
-                .show_error("Invalid action selection".to_string());
-            return Ok(());
-        }
+    /// Two ranges side by side: completion gauges, daily trends, then every
+    /// figure with its change marked up or down
+    fn render_compare(&self, f: &mut Frame, comparison: &PeriodComparison) {
+        use crate::ui::charts::{create_daily_gauge, render_trend_sparkline};
 
-        let action = &mut outcome.actions[action_index];
-        let already_linked = action
-            .get_all_objective_ids()
-            .iter()
-            .any(|id| id == objective_id);
+        let area = centered_rect(85, 85, f.area());
+        f.render_widget(Clear, area);
+        let (current, previous) = (&comparison.current, &comparison.previous);
+        let block = Block::default()
+            .title(format!(
+                " Compare: {} vs {} ",
+                current.label, previous.label
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
 
-        if already_linked {
-            action.remove_objective_id(objective_id);
-        } else {
-            action.add_objective_id(objective_id.to_string());
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Length(5),
+                Constraint::Min(3),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+        let halves = |area: Rect| {
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(area)
+        };
+        let (gauges, trends) = (halves(rows[0]), halves(rows[1]));
+        for (index, rollup) in [current, previous].into_iter().enumerate() {
+            let title = format!(
+                "{} · {} to {}",
+                rollup.label,
+                rollup.start.format("%b %d"),
+                rollup.end.format("%b %d")
+            );
+            f.render_widget(
+                create_daily_gauge(rollup.completion, &title, &self.theme),
+                gauges[index],
+            );
+            render_trend_sparkline(
+                &rollup.daily_completion,
+                "DAILY",
+                &self.theme,
+                f,
+                trends[index],
+            );
         }
 
-        let backup_goals = self.goals.clone();
-        if let Err(e) = crate::data::write_goals_file(&self.goals, &self.config) {
-            self.goals = backup_goals;
-            return Err(e);
+        let table = stats::comparison_rows(comparison, &self.indicators);
+        let width = table
+            .iter()
+            .map(|row| row.label.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(12);
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let mut lines = vec![Line::styled(
+            format!(
+                "  {:<width$}  {:>10}  {:>10}  change",
+                "",
+                current.label,
+                previous.label,
+                width = width
+            ),
+            Style::default()
+                .fg(self.theme.header)
+                .add_modifier(Modifier::BOLD),
+        )];
+        for row in &table {
+            let color = match row.improved() {
+                Some(true) => self.theme.completed,
+                Some(false) => self.theme.pending,
+                None => self.theme.text_secondary,
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:<width$}  ", row.label, width = width),
+                    Style::default().fg(self.theme.text_primary),
+                ),
+                Span::styled(
+                    format!("{:>10}  ", row.format(row.current)),
+                    Style::default().fg(self.theme.text_primary),
+                ),
+                Span::styled(format!("{:>10}  ", row.format(row.previous)), muted),
+                Span::styled(row.marker(), Style::default().fg(color)),
+            ]));
         }
-        Ok(())
+        f.render_widget(Paragraph::new(lines), rows[2]);
+        f.render_widget(
+            Paragraph::new(Line::styled(
+                "  w week · m month · q quarter · :compare RANGE RANGE for others · Esc close",
+                muted,
+            )),
+            rows[3],
+        );
     }
 
-    fn link_action_to_objective(
-        &mut self,
-        outcome_type: OutcomeType,
-        action_index: usize,
-        objective_id: &str,
-    ) -> anyhow::Result<()> {
-        let outcome = self.get_outcome_by_type_mut(outcome_type);
-        if action_index >= outcome.actions.len() {
-            self.error_display
-                .show_error("Invalid action selection".to_string());
-            return Ok(());
+    /// Level progress and every achievement, earned or still to earn
+    /// Every toast of the session with its time, newest first
+    fn render_messages(&self, f: &mut Frame, scroll: usize) {
+        let area = centered_rect(70, 70, f.area());
+        f.render_widget(Clear, area);
+        let block = Block::default()
+            .title(format!(" Messages ({}) ", self.toasts.history().len()))
+            .title_bottom(Line::from(" j/k scroll · Esc close ").right_aligned())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let mut lines = Vec::new();
+        for toast in self.toasts.history().iter().rev().skip(scroll) {
+            let color = toast.level.color(&self.theme);
+            let spans = vec![
+                Span::styled(format!("{} ", toast.at.format("%H:%M:%S")), muted),
+                Span::styled(
+                    format!("{} ", toast.level.icon()),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    toast.message.clone(),
+                    Style::default().fg(self.theme.text_primary),
+                ),
+            ];
+            let indent = spans.iter().take(2).map(|span| span.width()).sum();
+            lines.extend(wrap::wrap_spans(
+                spans,
+                usize::from(inner.width.saturating_sub(2)),
+                indent,
+            ));
+            if lines.len() >= usize::from(inner.height) {
+                break;
+            }
         }
+        if lines.is_empty() {
+            lines.push(Line::styled("No messages yet", muted));
+        }
+        f.render_widget(
+            Paragraph::new(lines)
+                .block(Block::default().padding(ratatui::widgets::Padding::horizontal(1))),
+            inner,
+        );
+    }
 
-        let action = &mut outcome.actions[action_index];
-        if !action
-            .get_all_objective_ids()
-            .iter()
-            .any(|id| id == objective_id)
-        {
-            action.add_objective_id(objective_id.to_string());
-            let backup_goals = self.goals.clone();
-            if let Err(e) = crate::data::write_goals_file(&self.goals, &self.config) {
-                self.goals = backup_goals;
-                return Err(e);
+    fn render_whats_new(&self, f: &mut Frame, state: &WhatsNewState) {
+        let area = centered_rect(60, 70, f.area());
+        f.render_widget(Clear, area);
+        let block = Block::default()
+            .title(" What's new in FocusFive ")
+            .title_bottom(Line::from(" j/k scroll · Esc close ").right_aligned())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let key_style = Style::default()
+            .fg(self.theme.header)
+            .add_modifier(Modifier::BOLD);
+        let mut lines = Vec::new();
+        for release in &state.releases {
+            if !lines.is_empty() {
+                lines.push(Line::raw(""));
+            }
+            lines.push(Line::styled(
+                format!("Version {}", release.version),
+                Style::default()
+                    .fg(self.theme.text_primary)
+                    .add_modifier(Modifier::BOLD),
+            ));
+            for feature in release.features {
+                let spans = vec![
+                    Span::styled(format!("  {:<8} ", feature.keys), key_style),
+                    Span::styled(
+                        feature.text.to_string(),
+                        Style::default().fg(self.theme.text_primary),
+                    ),
+                ];
+                lines.extend(wrap::wrap_spans(
+                    spans,
+                    usize::from(inner.width.saturating_sub(2)),
+                    11,
+                ));
             }
         }
+        f.render_widget(
+            Paragraph::new(lines.into_iter().skip(state.scroll).collect::<Vec<_>>())
+                .block(Block::default().padding(ratatui::widgets::Padding::horizontal(1))),
+            inner,
+        );
+    }
 
-        Ok(())
+    fn render_help(&self, f: &mut Frame, state: &HelpState) {
+        let area = centered_rect(70, 80, f.area());
+        f.render_widget(Clear, area);
+        let title = match state.mode {
+            KeyMode::Goals => " Keys ",
+            KeyMode::Focus => " Keys · Focus mode ",
+            KeyMode::Dashboard => " Keys · Dashboard ",
+        };
+        let block = Block::default()
+            .title(title)
+            .title_bottom(
+                Line::from(" type to search · ↑/↓ scroll · Esc close ").right_aligned(),
+            )
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let bindings = self.keymap.search(state.mode, &state.query);
+        let lines = help::help_lines(
+            &state.query,
+            &bindings,
+            state.scroll,
+            inner.width,
+            &self.theme,
+        );
+        f.render_widget(
+            Paragraph::new(lines)
+                .block(Block::default().padding(ratatui::widgets::Padding::horizontal(1))),
+            inner,
+        );
     }
 
-    fn toggle_expansion(&mut self) {
-        // Toggle expansion of current action when in Actions panel
-        if self.focus_panel == FocusPanel::Actions {
-            let action_id = {
-                let outcome = self.get_selected_outcome();
-                outcome.actions[self.selected_action].id.clone()
+    fn render_trophies(&self, f: &mut Frame) {
+        let area = centered_rect(60, 70, f.area());
+        f.render_widget(Clear, area);
+
+        let block = Block::default()
+            .title(" Trophies ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let xp = scoreboard::total_xp(&self.profile);
+        let level = scoreboard::level_for(xp);
+        let today = chrono::Local::now().date_naive();
+        let filled = (level.progress * 20 / level.needed) as usize;
+        let mut lines = vec![
+            Line::from(vec![
+                Span::styled(
+                    format!("  Level {}  ", level.level),
+                    Style::default()
+                        .fg(self.theme.header)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    "█".repeat(filled),
+                    Style::default().fg(self.theme.completed),
+                ),
+                Span::styled("░".repeat(20 - filled), muted),
+                Span::styled(
+                    format!(
+                        " {}/{} XP to level {}",
+                        level.progress,
+                        level.needed,
+                        level.level + 1
+                    ),
+                    muted,
+                ),
+            ]),
+            Line::styled(
+                format!(
+                    "  {} XP in total, {} this week",
+                    xp,
+                    scoreboard::week_xp(&self.profile, today)
+                ),
+                muted,
+            ),
+            Line::raw(""),
+        ];
+        for achievement in &ACHIEVEMENTS {
+            let line = match self.profile.achievements.get(achievement.id) {
+                Some(earned) => Line::from(vec![
+                    Span::styled("  🏆 ", Style::default().fg(self.theme.completed)),
+                    Span::styled(
+                        format!("{:<14}", achievement.name),
+                        Style::default()
+                            .fg(self.theme.text_primary)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!(
+                            "{} · {}",
+                            achievement.description,
+                            earned.format("%b %d, %Y")
+                        ),
+                        muted,
+                    ),
+                ]),
+                None => Line::from(vec![
+                    Span::styled("  ○  ", muted),
+                    Span::styled(format!("{:<14}", achievement.name), muted),
+                    Span::styled(achievement.description, muted),
+                ]),
             };
-            self.ui_state.toggle_expansion(action_id);
+            lines.push(line);
         }
+        lines.push(Line::raw(""));
+        lines.push(Line::styled("  Esc to close", muted));
+
+        f.render_widget(Paragraph::new(lines), inner);
     }
 
-    pub fn render(&mut self, f: &mut Frame) {
-        if self.show_dashboard {
-            self.render_dashboard(f);
-            return;
-        }
+    fn render_indicator_links(&self, f: &mut Frame, state: IndicatorLinkState) {
+        let area = centered_rect(60, 60, f.area());
+        f.render_widget(Clear, area);
 
-        // Clear background
-        f.render_widget(
-            Block::default().style(Style::default().bg(self.theme.background)),
-            f.area(),
+        let action = self
+            .get_outcome_by_type(state.outcome_type)
+            .actions
+            .get(state.action_index);
+        let block = Block::default()
+            .title(format!(
+                " Indicators fed by: {} ",
+                action
+                    .map(|a| a.text.as_str())
+                    .unwrap_or("(unknown action)")
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let items: Vec<ListItem> = self
+            .indicator_link_choices()
+            .into_iter()
+            .map(|def| {
+                let link = action.and_then(|a| a.indicator_link(&def.id));
+                let mut spans = vec![
+                    Span::styled(if link.is_some() { "[x] " } else { "[ ] " }, muted),
+                    Span::styled(
+                        def.name.clone(),
+                        Style::default().fg(self.theme.text_primary),
+                    ),
+                ];
+                match link.map(|link| link.value) {
+                    Some(Some(value)) => spans.push(Span::styled(
+                        format!("  logs {}", self.format_value_with_unit(value, &def.unit)),
+                        Style::default().fg(self.theme.completed),
+                    )),
+                    Some(None) => spans.push(Span::styled("  asks on completion", muted)),
+                    None => {}
+                }
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(4), Constraint::Length(2)])
+            .split(area);
+
+        let mut list_state = ListState::default();
+        list_state.select(Some(state.selection));
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(
+                Style::default()
+                    .fg(self.theme.header)
+                    .bg(self.theme.border)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("➤ ");
+        f.render_stateful_widget(list, layout[0], &mut list_state);
+
+        let help_text = Paragraph::new(Line::from(vec![
+            Span::styled("Enter", Style::default().fg(self.theme.header)),
+            Span::raw(" Link/Unlink  "),
+            Span::styled("a", Style::default().fg(self.theme.header)),
+            Span::raw(" Value to log  "),
+            Span::styled("Esc", Style::default().fg(self.theme.header)),
+            Span::raw(" Close"),
+        ]))
+        .style(muted)
+        .block(
+            Block::default()
+                .borders(Borders::TOP)
+                .border_style(Style::default().fg(self.theme.border))
+                .style(Style::default().bg(self.theme.panel_bg)),
         );
+        f.render_widget(help_text, layout[1]);
+    }
 
-        let layout = create_layout(f.area());
+    /// Celebration for an objective about to be marked complete: how long
+    /// it ran and how it finished against its target date
+    fn render_objective_complete(&self, f: &mut Frame, state: &ObjectiveCompleteState) {
+        let Some(objective) = self
+            .objectives
+            .objectives
+            .iter()
+            .find(|o| o.id == state.objective_id)
+        else {
+            return;
+        };
+        let area = centered_rect(50, 40, f.area());
+        f.render_widget(Clear, area);
 
-        self.render_header(f, layout.header);
-        self.render_outcomes(f, layout.outcomes);
-        self.render_actions(f, layout.actions);
-        self.render_stats(f, layout.stats);
-        self.render_footer(f, layout.footer);
+        let block = Block::default()
+            .title(" Objective Complete ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.completed))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
 
-        // Render editor popup on top if active
-        if self.text_editor.is_active {
-            self.text_editor.render(f, &self.theme);
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let today = chrono::Local::now().date_naive();
+        let days = (today - objective.start).num_days().max(0) + 1;
+        let mut lines = vec![
+            Line::raw(""),
+            Line::styled(
+                format!("🎉 {}", objective.title),
+                Style::default()
+                    .fg(self.theme.completed)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .alignment(Alignment::Center),
+            Line::raw(""),
+            Line::styled(
+                format!(
+                    "  Started {} · {} day{}",
+                    objective.start.format("%b %d, %Y"),
+                    days,
+                    if days == 1 { "" } else { "s" }
+                ),
+                muted,
+            ),
+        ];
+        if let Some(days_left) = objective.days_left(today) {
+            let plural = |n: i64| if n == 1 { "" } else { "s" };
+            let finish = match days_left {
+                0 => "  Finished on the target date".to_string(),
+                days if days > 0 => {
+                    format!("  Finished {} day{} ahead of target", days, plural(days))
+                }
+                days => format!("  Finished {} day{} past target", -days, plural(-days)),
+            };
+            lines.push(Line::styled(finish, muted));
         }
-
-        // Render error display on top if active
-        if self.error_display.is_active() {
-            self.error_display.render(f, f.area(), &self.theme);
+        if let Some((done, total)) = objective.queue_progress() {
+            lines.push(Line::styled(
+                format!("  {}/{} items done", done, total),
+                muted,
+            ));
+        } else if !objective.indicators.is_empty() {
+            lines.push(Line::styled(
+                format!(
+                    "  Indicators at {:.0}% of target",
+                    self.calculate_objective_progress(objective)
+                ),
+                muted,
+            ));
         }
+        lines.push(Line::raw(""));
+        lines.push(Line::styled(
+            "  Enter mark complete and archive · Esc back",
+            muted,
+        ));
 
-        self.render_modal(f);
+        f.render_widget(Paragraph::new(lines), inner);
     }
 
-    fn render_dashboard(&mut self, f: &mut Frame) {
-        f.render_widget(
-            Block::default().style(Style::default().bg(self.financial_theme.bg_primary)),
-            f.area(),
-        );
+    /// Completions by hour as stacked bars, one color per outcome, with
+    /// each outcome's typical hour and late-evening share
+    fn render_completion_times(&self, f: &mut Frame, times: &CompletionTimes) {
+        let area = centered_rect(70, 80, f.area());
+        f.render_widget(Clear, area);
 
-        let layout = DashboardLayout::new(f.area());
+        let block = Block::default()
+            .title(format!(
+                " Completions by Hour ({} days) ",
+                COMPLETION_TIMES_DAYS
+            ))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let outcomes = [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family];
+        let busiest = (0..24)
+            .map(|hour| times.hour_total(hour))
+            .max()
+            .unwrap_or(0);
+        if busiest == 0 {
+            f.render_widget(
+                Paragraph::new(Line::styled(
+                    "No completion times yet - they are recorded as actions are checked off",
+                    muted,
+                )),
+                inner,
+            );
+            return;
+        }
 
-        self.render_dashboard_header(f, layout.header);
-        self.render_dashboard_live_metrics(f, layout.live_metrics);
-        self.render_dashboard_performance(f, layout.performance);
-        self.render_dashboard_sentiment(f, layout.sentiment);
-        self.render_dashboard_signals(f, layout.signals);
-        self.render_dashboard_status_line(f, layout.status_line);
-        self.render_dashboard_footer(f, layout.footer);
+        let mut lines = vec![Line::styled(
+            format!("{} days with completions", times.days),
+            muted,
+        )];
+        lines.push(Line::raw(""));
+        // Hours from the first to the last with a completion
+        let first = (0..24)
+            .find(|hour| times.hour_total(*hour) > 0)
+            .unwrap_or(0);
+        let last = (0..24)
+            .rev()
+            .find(|hour| times.hour_total(*hour) > 0)
+            .unwrap_or(23);
+        let bar_width = u32::from(inner.width.saturating_sub(12).max(4));
+        for hour in first..=last {
+            let label_style = if hour >= LATE_EVENING_HOUR {
+                Style::default().fg(self.theme.severity_color(Severity::Warning))
+            } else {
+                muted
+            };
+            let mut spans = vec![Span::styled(format!("  {:02}:00 ", hour), label_style)];
+            for (index, outcome_type) in outcomes.into_iter().enumerate() {
+                let width = times.by_hour[hour][index] * bar_width / busiest;
+                spans.push(Span::styled(
+                    "█".repeat(width as usize),
+                    Style::default().fg(self.outcome_color(outcome_type)),
+                ));
+            }
+            spans.push(Span::styled(format!(" {}", times.hour_total(hour)), muted));
+            lines.push(Line::from(spans));
+        }
 
-        if self.error_display.is_active() {
-            self.error_display.render(f, f.area(), &self.theme);
+        lines.push(Line::raw(""));
+        for outcome_type in outcomes {
+            let detail = match (
+                times.median_hour(outcome_type),
+                times.late_share(outcome_type),
+            ) {
+                (Some(median), Some(late)) => format!(
+                    "{} done, half by {:02}:00, {:.0}% after {:02}:00",
+                    times.total(outcome_type),
+                    median + 1,
+                    late * 100.0,
+                    LATE_EVENING_HOUR
+                ),
+                _ => "nothing done".to_string(),
+            };
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:<8}", outcome_type.as_str()),
+                    Style::default().fg(self.outcome_color(outcome_type)),
+                ),
+                Span::styled(detail, Style::default().fg(self.theme.text_primary)),
+            ]));
+        }
+        if let Some((outcome_type, share)) = times.slipping_outcome() {
+            lines.push(Line::raw(""));
+            lines.push(Line::styled(
+                format!(
+                    "  {} slips into the late evening most: {:.0}% of it is done after {:02}:00",
+                    outcome_type.as_str(),
+                    share * 100.0,
+                    LATE_EVENING_HOUR
+                ),
+                Style::default().fg(self.theme.severity_color(Severity::Warning)),
+            ));
         }
 
-        self.render_modal(f);
+        f.render_widget(Paragraph::new(lines), inner);
     }
 
-    fn render_modal(&self, f: &mut Frame) {
-        match self.modal {
-            Some(ModalState::ObjectivePicker(state)) => {
-                let area = centered_rect(60, 60, f.area());
-                f.render_widget(Clear, area);
-
-                let choices = self.objective_choices(state.outcome_type);
-                let outcome = self.get_outcome_by_type(state.outcome_type);
-                let action_title = outcome
-                    .actions
-                    .get(state.action_index)
-                    .map(|a| a.text.clone())
-                    .unwrap_or_else(|| "(unknown action)".to_string());
-                let linked_ids = outcome
-                    .actions
-                    .get(state.action_index)
-                    .map(|a| a.get_all_objective_ids())
-                    .unwrap_or_default();
-
-                let mut items: Vec<ListItem> = choices
-                    .iter()
-                    .map(|choice| {
-                        let linked = linked_ids.iter().any(|id| id == &choice.id);
-                        let status_icon = match choice.status {
-                            ObjectiveStatus::Active => "●",
-                            ObjectiveStatus::Paused => "⏸",
-                            ObjectiveStatus::Completed => "✓",
-                            ObjectiveStatus::Dropped => "✗",
-                        };
+    fn render_retarget_modal(&self, f: &mut Frame, state: &RetargetState) {
+        let area = centered_rect(70, 70, f.area());
+        f.render_widget(Clear, area);
 
-                        ListItem::new(Line::from(vec![
-                            Span::styled(
-                                if linked { "[x] " } else { "[ ] " },
-                                Style::default().fg(self.theme.text_secondary),
-                            ),
-                            Span::styled(
-                                format!("{} ", status_icon),
-                                Style::default().fg(self.theme.header),
-                            ),
-                            Span::styled(
-                                &choice.title,
-                                Style::default().fg(self.theme.text_primary),
-                            ),
-                            Span::raw("  "),
-                            Span::styled(
-                                &choice.id[..8.min(choice.id.len())],
-                                Style::default().fg(self.theme.text_secondary),
-                            ),
-                        ]))
-                    })
-                    .collect();
+        let shell = Block::default()
+            .title(format!(" New Year Re-target ({}) ", state.year))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = shell.inner(area);
+        f.render_widget(shell, area);
 
-                items.push(ListItem::new(Line::from(vec![Span::styled(
-                    "➕ Create New Objective",
-                    Style::default()
-                        .fg(self.theme.header)
-                        .add_modifier(Modifier::BOLD),
-                )])));
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(3),
+                Constraint::Length(3),
+                Constraint::Length(1),
+            ])
+            .split(inner);
 
-                let mut list_state = ListState::default();
-                list_state.select(Some(state.selection.min(items.len().saturating_sub(1))));
+        let fmt = |value: Option<f64>| {
+            value
+                .map(|v| format!("{}", v))
+                .unwrap_or_else(|| "-".to_string())
+        };
+        let items: Vec<ListItem> = state
+            .items
+            .iter()
+            .map(|item| {
+                let detail = match &item.subject {
+                    RetargetSubject::Indicator {
+                        current, actual, ..
+                    } => {
+                        let basis = match actual {
+                            Some((avg, days)) => {
+                                format!("avg {:.1} over {} days in {}", avg, days, state.year - 1)
+                            }
+                            None => format!("no data in {}", state.year - 1),
+                        };
+                        format!(
+                            "target {} → {}  ({})",
+                            fmt(*current),
+                            fmt(item.proposed),
+                            basis
+                        )
+                    }
+                    RetargetSubject::Objective { current_end, .. } => format!(
+                        "ends {} → {}-12-31",
+                        current_end
+                            .map(|d| d.to_string())
+                            .unwrap_or_else(|| "open".to_string()),
+                        state.year
+                    ),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        if item.accepted { "[x] " } else { "[ ] " },
+                        Style::default().fg(self.theme.text_secondary),
+                    ),
+                    Span::styled(&item.name, Style::default().fg(self.theme.text_primary)),
+                    Span::raw("  "),
+                    Span::styled(detail, Style::default().fg(self.theme.text_secondary)),
+                ]))
+            })
+            .collect();
 
-                let block = Block::default()
-                    .title(format!(
-                        " Objectives for {:?} • Action: {} ",
-                        state.outcome_type, action_title
-                    ))
+        let mut list_state = ListState::default();
+        list_state.select(Some(state.index));
+        let list = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .fg(self.theme.header)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("➤ ");
+        f.render_stateful_widget(list, layout[0], &mut list_state);
+
+        let accepted = state.items.iter().filter(|item| item.accepted).count();
+        let (field_title, field_text) = if state.confirming {
+            (
+                " Confirm ".to_string(),
+                format!(
+                    "Apply {} change{} for {}? Nothing is saved until you confirm.",
+                    accepted,
+                    if accepted == 1 { "" } else { "s" },
+                    state.year
+                ),
+            )
+        } else {
+            match state.items.get(state.index).map(|item| &item.subject) {
+                Some(RetargetSubject::Indicator { .. }) => {
+                    (" New target ".to_string(), format!("{}▏", state.buffer))
+                }
+                _ => (
+                    " Objective ".to_string(),
+                    format!("Carry into {} by ending it on Dec 31", state.year),
+                ),
+            }
+        };
+        let field = Paragraph::new(field_text)
+            .style(Style::default().fg(self.theme.text_primary))
+            .block(
+                Block::default()
+                    .title(field_title)
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(self.theme.header))
-                    .style(Style::default().bg(self.theme.panel_bg));
+                    .border_style(Style::default().fg(self.theme.header)),
+            );
+        f.render_widget(field, layout[1]);
+
+        let accent = Style::default().fg(self.theme.header);
+        let help = if state.confirming {
+            vec![
+                Span::styled("Enter/y", accent),
+                Span::raw(" Apply  "),
+                Span::styled("Esc/n", accent),
+                Span::raw(" Back"),
+            ]
+        } else {
+            vec![
+                Span::styled("Enter", accent),
+                Span::raw(" Accept  "),
+                Span::styled("n", accent),
+                Span::raw(" Skip  "),
+                Span::styled("Space", accent),
+                Span::raw(" Toggle  "),
+                Span::styled("0-9", accent),
+                Span::raw(" Edit Target  "),
+                Span::styled("Esc", accent),
+                Span::raw(" Cancel"),
+            ]
+        };
+        f.render_widget(
+            Paragraph::new(Line::from(help)).style(Style::default().fg(self.theme.text_secondary)),
+            layout[2],
+        );
+    }
+
+    fn render_quick_observation_modal(&self, f: &mut Frame, state: &QuickObservationState) {
+        let area = centered_rect(50, 50, f.area());
+        f.render_widget(Clear, area);
 
-                let list = List::new(items)
-                    .block(block)
-                    .highlight_style(
-                        Style::default()
-                            .fg(self.theme.header)
-                            .bg(self.theme.border)
-                            .add_modifier(Modifier::BOLD),
-                    )
-                    .highlight_symbol("➤ ");
+        let shell = Block::default()
+            .title(" Log Observation ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.header))
+            .style(Style::default().bg(self.theme.panel_bg));
+        let inner = shell.inner(area);
+        f.render_widget(shell, area);
 
-                let layout = Layout::default()
-                    .direction(Direction::Vertical)
-                    .constraints([Constraint::Min(4), Constraint::Length(2)])
-                    .split(area);
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(3),
+                Constraint::Length(3),
+                Constraint::Length(1),
+            ])
+            .split(inner);
 
-                f.render_stateful_widget(list, layout[0], &mut list_state);
+        let focused = Style::default().fg(self.theme.header);
+        let unfocused = Style::default().fg(self.theme.border);
+        let field_style = |field| {
+            if state.field == field {
+                focused
+            } else {
+                unfocused
+            }
+        };
 
-                let help_text = Paragraph::new(Line::from(vec![
-                    Span::styled("↑/↓", Style::default().fg(self.theme.header)),
-                    Span::raw(" Navigate  "),
-                    Span::styled("Enter", Style::default().fg(self.theme.header)),
-                    Span::raw(" Link/Unlink  "),
-                    Span::styled("n", Style::default().fg(self.theme.header)),
-                    Span::raw(" New  "),
-                    Span::styled("r", Style::default().fg(self.theme.header)),
-                    Span::raw(" Rename  "),
-                    Span::styled("d", Style::default().fg(self.theme.header)),
-                    Span::raw(" Delete  "),
-                    Span::styled("Esc", Style::default().fg(self.theme.header)),
-                    Span::raw(" Close"),
+        let query = Paragraph::new(format!("{}▏", state.query))
+            .style(Style::default().fg(self.theme.text_primary))
+            .block(
+                Block::default()
+                    .title(" Indicator ")
+                    .borders(Borders::ALL)
+                    .border_style(field_style(QuickObservationField::Indicator)),
+            );
+        f.render_widget(query, layout[0]);
+
+        let matches = self.quick_observation_matches(&state.query);
+        let items: Vec<ListItem> = matches
+            .iter()
+            .map(|&index| {
+                let def = &self.indicators.indicators[index];
+                ListItem::new(Line::from(vec![
+                    Span::styled(&def.name, Style::default().fg(self.theme.text_primary)),
+                    Span::styled(
+                        format!("  ({})", Self::unit_label(&def.unit)),
+                        Style::default().fg(self.theme.text_secondary),
+                    ),
                 ]))
-                .style(Style::default().fg(self.theme.text_secondary))
-                .alignment(Alignment::Left);
+            })
+            .collect();
 
-                f.render_widget(help_text, layout[1]);
-            }
-            Some(ModalState::IndicatorUpdate(ref state)) => {
-                self.render_indicator_update_modal(f, state);
-            }
-            _ => {}
+        let mut list_state = ListState::default();
+        if !items.is_empty() {
+            list_state.select(Some(state.selection.min(items.len() - 1)));
         }
+        let list = List::new(items)
+            .highlight_style(
+                Style::default()
+                    .fg(self.theme.header)
+                    .bg(self.theme.border)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("➤ ");
+        f.render_stateful_widget(list, layout[1], &mut list_state);
+
+        let unit_hint = matches
+            .get(state.selection)
+            .map(|&index| {
+                format!(
+                    " Value ({}) ",
+                    Self::unit_label(&self.indicators.indicators[index].unit)
+                )
+            })
+            .unwrap_or_else(|| " Value ".to_string());
+        let value = Paragraph::new(state.value.as_str())
+            .style(Style::default().fg(self.theme.text_primary))
+            .block(
+                Block::default()
+                    .title(unit_hint)
+                    .borders(Borders::ALL)
+                    .border_style(field_style(QuickObservationField::Value)),
+            );
+        f.render_widget(value, layout[2]);
+
+        let help_text = Paragraph::new(Line::from(vec![
+            Span::styled("↑/↓", focused),
+            Span::raw(" Pick  "),
+            Span::styled("Tab", focused),
+            Span::raw(" Switch Field  "),
+            Span::styled("Enter", focused),
+            Span::raw(" Next/Save  "),
+            Span::styled("Esc", focused),
+            Span::raw(" Cancel"),
+        ]))
+        .style(Style::default().fg(self.theme.text_secondary));
+        f.render_widget(help_text, layout[3]);
     }
 
     fn render_dashboard_header(&self, f: &mut Frame, area: Rect) {
@@ -2133,21 +11075,56 @@ impl App {
         f.render_widget(header, area);
     }
 
+    /// " LIVE METRICS · Fitness " when the panel is limited to some groups
+    fn dashboard_panel_title(&self, title: &str, panel: crate::models::IndicatorPanel) -> String {
+        let groups = self.settings.dashboard.groups(panel);
+        if groups.is_empty() {
+            format!(" {} ", title)
+        } else {
+            format!(" {} · {} ", title, groups.join(", "))
+        }
+    }
+
+    /// Placeholder for a panel with nothing to show, naming its groups
+    fn empty_panel_hint(&self, default: &str, panel: crate::models::IndicatorPanel) -> String {
+        let groups = self.settings.dashboard.groups(panel);
+        if groups.is_empty() {
+            default.to_string()
+        } else {
+            format!(
+                "No active indicators in {}\nChange the panel's groups with :panel",
+                groups.join(", ")
+            )
+        }
+    }
+
     fn render_dashboard_live_metrics(&mut self, f: &mut Frame, area: Rect) {
         let today = chrono::Local::now().naive_local().date();
-        let observations = crate::data::read_observations_range(
-            today - chrono::Duration::days(7),
-            today,
-            &self.config,
-        )
-        .unwrap_or_default();
+        // Reach back to the 1st so Dollars indicators get month-to-date totals
+        let month_start = today.with_day(1).unwrap_or(today);
+        let Some(observations) = self.dashboard_observations(
+            month_start.min(today - chrono::Duration::days(loader::PERFORMANCE_DAYS)),
+        ) else {
+            let focused = self.dashboard_focus == DashboardPanel::Market;
+            self.render_loading_panel(f, area, " LIVE METRICS ", focused);
+            return;
+        };
 
-        // Populate market IDs with active indicators
-        self.dashboard_market_ids = self.indicators.indicators
+        // Populate market IDs with the active indicators of the panel's groups
+        let shown: Vec<IndicatorDef> = self
+            .indicators
+            .indicators
             .iter()
-            .filter(|ind| ind.active)
-            .map(|ind| ind.id.clone())
+            .filter(|ind| {
+                ind.active
+                    && self
+                        .settings
+                        .dashboard
+                        .shows(crate::models::IndicatorPanel::LiveMetrics, ind)
+            })
+            .cloned()
             .collect();
+        self.dashboard_market_ids = shown.iter().map(|ind| ind.id.clone()).collect();
 
         // Ensure index is within bounds
         if self.dashboard_market_ids.is_empty() {
@@ -2163,14 +11140,12 @@ impl App {
             self.financial_theme.text_dim
         };
 
-        let widget = LiveMetricsWidget::new(
-            &self.indicators.indicators,
-            &observations,
-            &self.financial_theme,
-        )
-        .block(
+        let widget = LiveMetricsWidget::new(&shown, &observations, &self.financial_theme).block(
             Block::default()
-                .title(" LIVE METRICS ")
+                .title(self.dashboard_panel_title(
+                    "LIVE METRICS",
+                    crate::models::IndicatorPanel::LiveMetrics,
+                ))
                 .title_style(
                     Style::default()
                         .fg(title_color)
@@ -2184,20 +11159,74 @@ impl App {
         f.render_widget(widget, area);
     }
 
+    /// Observations and daily focus minutes for the chart window from
+    /// `start` through `end`, read once per window
+    fn performance_history(
+        &mut self,
+        start: chrono::NaiveDate,
+        end: chrono::NaiveDate,
+    ) -> (Vec<Observation>, Vec<Observation>) {
+        let cached = self
+            .performance_history
+            .as_ref()
+            .is_some_and(|history| history.start == start && history.end == end);
+        if !cached {
+            let observations = crate::data::read_observations_range(start, end, &self.config)
+                .unwrap_or_default();
+            let focus_daily = loader::focus_daily_observations(
+                end,
+                (end - start).num_days() + 1,
+                &self.config,
+            );
+            self.performance_history = Some(PerformanceHistory {
+                start,
+                end,
+                observations,
+                focus_daily,
+            });
+        }
+        self.performance_history
+            .as_ref()
+            .map(|history| (history.observations.clone(), history.focus_daily.clone()))
+            .unwrap_or_default()
+    }
+
     fn render_dashboard_performance(&mut self, f: &mut Frame, area: Rect) {
         let today = chrono::Local::now().naive_local().date();
-        let observations = crate::data::read_observations_range(
-            today - chrono::Duration::days(7),
-            today,
-            &self.config,
-        )
-        .unwrap_or_default();
+        let window = CHART_WINDOWS[self.dashboard_chart_window];
+        let window_end = today - chrono::Duration::days(self.dashboard_chart_pan);
+        let Some(recent) =
+            self.dashboard_observations(today - chrono::Duration::days(loader::PERFORMANCE_DAYS))
+        else {
+            let focused = self.dashboard_focus == DashboardPanel::Performance;
+            self.render_loading_panel(f, area, " PERFORMANCE ANALYTICS ", focused);
+            return;
+        };
+        // The dashboard load covers the default week; wider or panned
+        // windows read their own range
+        let (observations, focus_daily) =
+            if window <= loader::PERFORMANCE_DAYS && self.dashboard_chart_pan == 0 {
+                let focus_daily = self
+                    .dashboard_data
+                    .as_ref()
+                    .map(|data| data.focus_daily.clone())
+                    .unwrap_or_default();
+                (recent, focus_daily)
+            } else {
+                self.performance_history(window_end - chrono::Duration::days(window - 1), window_end)
+            };
 
         let active_indicators: Vec<_> = self
             .indicators
             .indicators
             .iter()
-            .filter(|indicator| indicator.active)
+            .filter(|indicator| {
+                indicator.active
+                    && self
+                        .settings
+                        .dashboard
+                        .shows(crate::models::IndicatorPanel::Performance, indicator)
+            })
             .collect();
 
         if active_indicators.is_empty() {
@@ -2211,26 +11240,28 @@ impl App {
                 self.financial_theme.text_dim
             };
 
-            let placeholder =
-                Paragraph::new("PERFORMANCE ANALYTICS\nActivate indicators to see trend charts")
-                    .style(
+            let placeholder = Paragraph::new(self.empty_panel_hint(
+                "PERFORMANCE ANALYTICS\nActivate indicators to see trend charts",
+                crate::models::IndicatorPanel::Performance,
+            ))
+            .style(
+                Style::default()
+                    .fg(self.financial_theme.text_primary)
+                    .bg(self.financial_theme.bg_panel),
+            )
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .title(" PERFORMANCE ANALYTICS ")
+                    .title_style(
                         Style::default()
-                            .fg(self.financial_theme.text_primary)
-                            .bg(self.financial_theme.bg_panel),
+                            .fg(title_color)
+                            .add_modifier(Modifier::BOLD),
                     )
-                    .alignment(Alignment::Center)
-                    .block(
-                        Block::default()
-                            .title(" PERFORMANCE ANALYTICS ")
-                            .title_style(
-                                Style::default()
-                                    .fg(title_color)
-                                    .add_modifier(Modifier::BOLD),
-                            )
-                            .borders(Borders::ALL)
-                            .border_style(Style::default().fg(self.financial_theme.text_dim))
-                            .style(Style::default().bg(self.financial_theme.bg_panel)),
-                    );
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(self.financial_theme.text_dim))
+                    .style(Style::default().bg(self.financial_theme.bg_panel)),
+            );
 
             f.render_widget(placeholder, area);
             return;
@@ -2256,6 +11287,37 @@ impl App {
             self.financial_theme.text_dim
         };
 
+        if let Some((first_id, second_id)) = &self.compare_pair {
+            let first = active_indicators.iter().find(|def| &def.id == first_id);
+            let second = active_indicators.iter().find(|def| &def.id == second_id);
+            if let (Some(first), Some(second)) = (first, second) {
+                let series_for = |def: &'_ IndicatorDef| {
+                    if def.id == FOCUS_INDICATOR_ID {
+                        &focus_daily
+                    } else {
+                        &observations
+                    }
+                };
+                let chart = CompareChart::new(
+                    CompareSeries {
+                        name: &first.name,
+                        indicator_id: &first.id,
+                        observations: series_for(first),
+                    },
+                    CompareSeries {
+                        name: &second.name,
+                        indicator_id: &second.id,
+                        observations: series_for(second),
+                    },
+                    &self.financial_theme,
+                )
+                .scale(self.compare_scale)
+                .title_color(title_color);
+                f.render_widget(chart, area);
+                return;
+            }
+        }
+
         // Define viewport: show 2 charts at a time for better visibility
         let charts_per_page = 2;
         let start_index = if self.dashboard_performance_index >= charts_per_page {
@@ -2292,19 +11354,50 @@ impl App {
                 title_color
             };
 
-            let chart = PerformanceChart::new(
-                &observations,
+            // Focus minutes are charted as daily totals rather than per-session points
+            let series = if indicator.id == FOCUS_INDICATOR_ID {
+                &focus_daily
+            } else {
+                &observations
+            };
+            let mut chart = PerformanceChart::new(
+                series,
                 &indicator.id,
                 &self.financial_theme,
                 &indicator.name,
             )
-            .title_color(chart_title_color);
+            .title_color(chart_title_color)
+            .aggregation(indicator.aggregation)
+            .intraday(self.dashboard_intraday)
+            .window(window, window_end);
+            if let Some(range) = indicator.current_range() {
+                chart = chart.range(range);
+            } else if let Some(target) = indicator.current_target() {
+                let due = indicator.objective_id.as_ref().and_then(|objective_id| {
+                    self.objectives
+                        .objectives
+                        .iter()
+                        .find(|objective| &objective.id == objective_id)
+                        .and_then(|objective| objective.end)
+                });
+                chart = chart.forecast(target, indicator.direction.clone(), due);
+            }
 
             f.render_widget(chart, *chart_area);
         }
     }
 
     fn render_dashboard_sentiment(&self, f: &mut Frame, area: Rect) {
+        // Gratitude gets a strip at the bottom when there is room for it
+        let (area, gratitude_area) = if area.height >= 16 {
+            let split = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(12), Constraint::Length(4)])
+                .split(area);
+            (split[0], Some(split[1]))
+        } else {
+            (area, None)
+        };
         let segments = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -2328,19 +11421,50 @@ impl App {
         };
 
         for ((outcome, actions), segment) in outcomes.into_iter().zip(segments.iter()) {
+            let mood = self.sentiment.series(outcome);
             let widget = SentimentWidget::new(outcome, actions, &self.financial_theme)
+                .mood(&mood)
                 .title_color(title_color);
             f.render_widget(widget, *segment);
         }
+
+        if let Some(gratitude_area) = gratitude_area {
+            let mut widget = GratitudeWidget::new(self.gratitude_streak, &self.financial_theme)
+                .title_color(title_color);
+            if let Some(recall) = &self.gratitude_recall {
+                let when = if recall.on_this_day {
+                    format!("On this day in {}", recall.date.year())
+                } else {
+                    format!("On {}", recall.date.format("%b %d, %Y"))
+                };
+                widget = widget.recall(when, &recall.item);
+            }
+            f.render_widget(widget, gratitude_area);
+        }
     }
 
     fn render_dashboard_signals(&mut self, f: &mut Frame, area: Rect) {
+        let today = chrono::Local::now().naive_local().date();
+        let Some(observations) =
+            self.dashboard_observations(today - chrono::Duration::days(loader::SIGNAL_DAYS))
+        else {
+            let focused = self.dashboard_focus == DashboardPanel::Signals;
+            self.render_loading_panel(f, area, " ALTERNATIVE DATA SIGNALS ", focused);
+            return;
+        };
+
         let mut candidate_indicators: Vec<_> = self
             .indicators
             .indicators
             .iter()
             .filter(|indicator| {
-                indicator.active && matches!(indicator.kind, IndicatorKind::Leading)
+                indicator.active
+                    && !indicator.exclude_from_signals
+                    && matches!(indicator.kind, IndicatorKind::Leading)
+                    && self
+                        .settings
+                        .dashboard
+                        .shows(crate::models::IndicatorPanel::Signals, indicator)
             })
             .collect();
 
@@ -2349,7 +11473,14 @@ impl App {
                 .indicators
                 .indicators
                 .iter()
-                .filter(|indicator| indicator.active)
+                .filter(|indicator| {
+                    indicator.active
+                        && !indicator.exclude_from_signals
+                        && self
+                            .settings
+                            .dashboard
+                            .shows(crate::models::IndicatorPanel::Signals, indicator)
+                })
                 .collect();
         }
 
@@ -2364,9 +11495,10 @@ impl App {
                 self.financial_theme.text_dim
             };
 
-            let placeholder = Paragraph::new(
+            let placeholder = Paragraph::new(self.empty_panel_hint(
                 "ALTERNATIVE SIGNALS\nActivate indicators to surface secondary metrics",
-            )
+                crate::models::IndicatorPanel::Signals,
+            ))
             .style(
                 Style::default()
                     .fg(self.financial_theme.text_primary)
@@ -2390,34 +11522,15 @@ impl App {
             return;
         }
 
-        let today = chrono::Local::now().naive_local().date();
-        let mut observations = crate::data::read_observations_range(
-            today - chrono::Duration::days(30),
-            today,
-            &self.config,
-        )
-        .unwrap_or_default();
-        observations.sort_by_key(|obs| obs.when);
-
-        let mut latest_map: HashMap<String, (Option<f64>, Option<f64>)> = HashMap::new();
-        for obs in &observations {
-            let entry = latest_map
-                .entry(obs.indicator_id.clone())
-                .or_insert((None, None));
-            entry.0 = entry.1;
-            entry.1 = Some(obs.value);
-        }
-
         let mut pending: Vec<(AlternativeSignal, f64)> = Vec::new();
         for indicator in candidate_indicators {
-            let (previous, latest) = latest_map
-                .get(&indicator.id)
-                .cloned()
-                .unwrap_or((None, None));
+            let days = indicator.daily_values(&observations);
+            let mut values = days.iter().rev().map(|(_, value)| *value);
+            let (latest, previous) = (values.next(), values.next());
 
             let latest_value = latest.unwrap_or(0.0);
             let deviation = indicator
-                .target
+                .current_target()
                 .map(|target| (latest_value - target).abs() / target.max(1.0))
                 .unwrap_or(0.0);
             let base_weight = if matches!(indicator.kind, IndicatorKind::Leading) {
@@ -2532,6 +11645,116 @@ impl App {
         f.render_widget(widget, area);
     }
 
+    fn render_dashboard_portfolio(&mut self, f: &mut Frame, area: Rect) {
+        let focused = self.dashboard_focus == DashboardPanel::Portfolio;
+        let today = chrono::Local::now().date_naive();
+        let Some(observations) =
+            self.dashboard_observations(today - chrono::Duration::days(loader::SIGNAL_DAYS))
+        else {
+            self.render_loading_panel(f, area, " OBJECTIVE PORTFOLIO ", focused);
+            return;
+        };
+        let portfolio = Portfolio::build(
+            &self.objectives.objectives,
+            &self.indicators.indicators,
+            &observations,
+            today,
+        );
+        self.dashboard_portfolio_ids = portfolio
+            .entries
+            .iter()
+            .map(|entry| entry.objective.id.clone())
+            .collect();
+        self.dashboard_portfolio_index = self
+            .dashboard_portfolio_index
+            .min(self.dashboard_portfolio_ids.len().saturating_sub(1));
+
+        let title_color = if focused {
+            self.financial_theme.accent_yellow
+        } else {
+            self.financial_theme.text_dim
+        };
+        let block = Block::default()
+            .title(" OBJECTIVE PORTFOLIO ")
+            .title_style(
+                Style::default()
+                    .fg(title_color)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.financial_theme.text_dim))
+            .style(Style::default().bg(self.financial_theme.bg_panel));
+
+        if portfolio.entries.is_empty() {
+            let placeholder =
+                Paragraph::new("OBJECTIVE PORTFOLIO\nNo active objectives - press o on an action")
+                    .style(Style::default().fg(self.financial_theme.text_primary))
+                    .alignment(Alignment::Center)
+                    .block(block);
+            f.render_widget(placeholder, area);
+            return;
+        }
+
+        let health_style = |health: Health| {
+            Style::default().fg(match health {
+                Health::OnTrack => self.financial_theme.positive,
+                Health::AtRisk => self.financial_theme.neutral,
+                Health::Behind => self.financial_theme.negative,
+            })
+        };
+        let health_icon = |health: Health| match health {
+            Health::OnTrack => "●",
+            Health::AtRisk => "▲",
+            Health::Behind => "▼",
+        };
+
+        let mut summary = Vec::new();
+        for health in [Health::OnTrack, Health::AtRisk, Health::Behind] {
+            summary.push(Span::styled(
+                format!(
+                    "{} {} {}",
+                    health_icon(health),
+                    portfolio.count(health),
+                    health.label()
+                ),
+                health_style(health).add_modifier(Modifier::BOLD),
+            ));
+            summary.push(Span::raw("   "));
+        }
+        let mut lines = vec![Line::from(summary), Line::from("")];
+
+        for (index, entry) in portfolio.entries.iter().enumerate() {
+            let selected = focused && index == self.dashboard_portfolio_index;
+            let mut title_style = Style::default().fg(self.financial_theme.text_primary);
+            if selected {
+                title_style = title_style.add_modifier(Modifier::REVERSED);
+            }
+            let mut spans = vec![
+                Span::styled(
+                    format!("{} ", health_icon(entry.health)),
+                    health_style(entry.health),
+                ),
+                Span::styled(entry.objective.title.clone(), title_style),
+                Span::styled(
+                    format!("  {}", entry.objective.domain.as_str()),
+                    Style::default().fg(self.financial_theme.text_secondary),
+                ),
+            ];
+            if let Some(detail) = &entry.detail {
+                spans.push(Span::styled(
+                    format!(" · {}", detail),
+                    health_style(entry.health),
+                ));
+            }
+            lines.push(Line::from(spans));
+        }
+
+        // Keep the selection in view on short panels
+        let visible = area.height.saturating_sub(4) as usize;
+        let scroll = (self.dashboard_portfolio_index + 1).saturating_sub(visible) as u16;
+        f.render_widget(Paragraph::new(lines).block(block).scroll((scroll, 0)), area);
+    }
+
     fn render_dashboard_status_line(&self, f: &mut Frame, area: Rect) {
         let status_content = if self.dashboard_focus == DashboardPanel::Market {
             // Get selected indicator name from Live Metrics
@@ -2540,10 +11763,10 @@ impl App {
             } else {
                 None
             } {
-                if let Some(indicator) = self.indicators.indicators
-                    .iter()
-                    .filter(|ind| ind.active)
-                    .nth(selected_idx)
+                if let Some(indicator) = self
+                    .dashboard_market_ids
+                    .get(selected_idx)
+                    .and_then(|id| self.indicators.indicators.iter().find(|ind| &ind.id == id))
                 {
                     Line::from(vec![
                         Span::styled("Selected: ", Style::default().fg(self.financial_theme.text_secondary)),
@@ -2585,63 +11808,126 @@ impl App {
     }
 
     fn render_dashboard_footer(&self, f: &mut Frame, area: Rect) {
-        let accent = Style::default().fg(self.financial_theme.accent_yellow);
-        let lines = vec![
-            Line::from(vec![
-                Span::styled("h / l", accent),
-                Span::raw(" Cycle Panels  "),
-                Span::styled("↑/↓ j/k", accent),
-                Span::raw(" Navigate Signals  "),
-                Span::styled("Enter", accent),
-                Span::raw(" Inspect Indicator"),
-            ]),
-            Line::from(vec![
-                Span::styled("d", accent),
-                Span::raw(" Back to FocusFive  "),
-                Span::styled("Esc", accent),
-                Span::raw(" Close Dashboard  "),
-                Span::styled("q", accent),
-                Span::raw(" Quit"),
-            ]),
-        ];
-
         let outer = Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(self.financial_theme.text_dim))
             .style(Style::default().bg(self.financial_theme.bg_secondary));
         f.render_widget(outer, area);
 
-        let footer = Paragraph::new(lines)
-            .alignment(Alignment::Center)
-            .style(Style::default().fg(self.financial_theme.text_secondary));
         let inner = area.inner(ratatui::layout::Margin {
             horizontal: 1,
             vertical: 0,
         });
+        let lines = self.status_bar(
+            inner.width,
+            Style::default()
+                .fg(self.financial_theme.bg_primary)
+                .bg(self.financial_theme.accent_yellow)
+                .add_modifier(Modifier::BOLD),
+            Style::default().fg(self.financial_theme.accent_yellow),
+        );
+        let footer = Paragraph::new(lines)
+            .style(Style::default().fg(self.financial_theme.text_secondary));
         f.render_widget(footer, inner);
     }
 
-    fn render_header(&self, f: &mut Frame, area: Rect) {
-        let header = Paragraph::new(Line::from(vec![
+    /// `compact` drops the border and shortens the date for short terminals
+    fn render_header(&self, f: &mut Frame, area: Rect, compact: bool) {
+        let date_format = if compact { "%a %b %-d" } else { "%B %d, %Y" };
+        let mut spans = vec![
             Span::styled(
                 "FOCUSFIVE",
                 Style::default()
-                    .fg(self.theme.header)
+                    .fg(self.theme.header)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" - "),
+            Span::styled(
+                self.goals.date.format(date_format).to_string(),
+                Style::default().fg(self.theme.text_primary),
+            ),
+        ];
+        spans.push(if self.pending_save.is_some() {
+            Span::styled("  ●", Style::default().fg(self.theme.partial))
+        } else if self.save_failed {
+            Span::styled(
+                "  ✗ not saved",
+                Style::default().fg(self.theme.severity_color(Severity::Bad)),
+            )
+        } else {
+            Span::styled("  saved", Style::default().fg(self.theme.text_secondary))
+        });
+        if !self.snoozed_arrivals.is_empty() && self.goals.date == self.max_date {
+            spans.push(Span::raw("  "));
+            spans.push(Span::styled(
+                format!("⏰ {} snoozed arriving today", self.snoozed_arrivals.len()),
+                Style::default().fg(self.theme.partial),
+            ));
+        }
+        let capacity = self.day_capacity();
+        if capacity.planned > 0 {
+            let color = if capacity.is_over() {
+                self.theme.severity_color(Severity::Bad)
+            } else if capacity.ratio() >= capacity::NEAR_CAPACITY {
+                self.theme.partial
+            } else {
+                self.theme.completed
+            };
+            let filled = ((capacity.ratio() * 8.0).round() as usize).min(8);
+            spans.push(Span::raw("  ⏳ "));
+            spans.push(Span::styled("█".repeat(filled), Style::default().fg(color)));
+            spans.push(Span::styled(
+                "░".repeat(8 - filled),
+                Style::default().fg(self.theme.text_secondary),
+            ));
+            spans.push(Span::styled(
+                format!(" {}", capacity.label()),
+                Style::default().fg(color),
+            ));
+        }
+        if self.day_closed {
+            spans.push(Span::raw("  "));
+            spans.push(Span::styled(
+                "🔒 Closed",
+                Style::default()
+                    .fg(self.theme.text_secondary)
                     .add_modifier(Modifier::BOLD),
-            ),
-            Span::raw(" - "),
-            Span::styled(
-                self.goals.date.format("%B %d, %Y").to_string(),
-                Style::default().fg(self.theme.text_primary),
-            ),
-        ]))
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(self.theme.border))
-                .style(Style::default().bg(self.theme.panel_bg)),
-        );
+            ));
+        }
+        let xp = scoreboard::total_xp(&self.profile);
+        if xp > 0 {
+            let today = chrono::Local::now().date_naive();
+            spans.push(Span::raw("  "));
+            spans.push(Span::styled(
+                format!(
+                    "★ Lv {} · {} XP this week",
+                    scoreboard::level_for(xp).level,
+                    scoreboard::week_xp(&self.profile, today)
+                ),
+                Style::default().fg(self.theme.header),
+            ));
+        }
+        if let Some(note) = &self.day_note {
+            spans.push(Span::raw("  "));
+            spans.push(Span::styled(
+                format!("✎ {}", note.replace('\n', " ")),
+                Style::default()
+                    .fg(self.theme.text_secondary)
+                    .add_modifier(Modifier::ITALIC),
+            ));
+        }
+        let header = Paragraph::new(Line::from(spans));
+        let header = if compact {
+            header.style(Style::default().bg(self.theme.panel_bg))
+        } else {
+            header.block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(self.theme.border))
+                    .style(Style::default().bg(self.theme.panel_bg)),
+            )
+        };
         f.render_widget(header, area);
     }
 
@@ -2663,6 +11949,7 @@ impl App {
             };
 
             let completed = outcome.actions.iter().filter(|a| a.completed).count();
+            let trend = self.statistics.outcome_trend(*outcome_type);
 
             let is_selected =
                 self.focus_panel == FocusPanel::Outcomes && self.selected_outcome == *outcome_type;
@@ -2675,18 +11962,27 @@ impl App {
                 Style::default()
             };
 
-            outcomes.push(
-                ListItem::new(Line::from(vec![
-                    Span::styled("■ ", Style::default().fg(*color)),
-                    Span::styled(*label, Style::default().fg(self.theme.text_primary)),
-                    Span::raw(" "),
-                    Span::styled(
-                        format!("[{}/3]", completed),
-                        Style::default().fg(self.theme.text_secondary),
-                    ),
-                ]))
-                .style(style),
-            );
+            let mut spans = vec![
+                Span::styled("■ ", Style::default().fg(*color)),
+                Span::styled(*label, Style::default().fg(self.theme.text_primary)),
+                Span::raw(" "),
+                Span::styled(
+                    format!("[{}/3]", completed),
+                    Style::default().fg(self.theme.text_secondary),
+                ),
+            ];
+            // 14-day momentum, right-aligned when the panel has room for it
+            let used: usize = spans.iter().map(|span| span.width()).sum();
+            let room = usize::from(area.width.saturating_sub(2)).saturating_sub(used + 1);
+            if !trend.is_empty() && room >= trend.len() {
+                spans.push(Span::raw(" ".repeat(room - trend.len() + 1)));
+                spans.push(Span::styled(
+                    crate::ui::charts::completion_sparkline(trend),
+                    Style::default().fg(*color),
+                ));
+            }
+
+            outcomes.push(ListItem::new(Line::from(spans)).style(style));
         }
 
         let border_color = if self.focus_panel == FocusPanel::Outcomes {
@@ -2711,7 +12007,8 @@ impl App {
         let selected_outcome = self.get_selected_outcome();
         let mut actions_list = Vec::new();
         let mut current_line = 0;
-        let mut _selected_display_line = None;
+        // Lines of the selected action, including its expanded details
+        let mut selected_lines = None;
         let mut selected_indicator_counter = 0usize;
 
         let outcome_color = match self.selected_outcome {
@@ -2726,7 +12023,22 @@ impl App {
             OutcomeType::Family => "F",
         };
 
+        let focus = self.weekly_goals.get_focus(self.selected_outcome);
+        if !focus.is_empty() {
+            actions_list.push(ListItem::new(Line::from(vec![
+                Span::styled("◆ Week: ", Style::default().fg(outcome_color)),
+                Span::styled(
+                    focus,
+                    Style::default()
+                        .fg(self.theme.text_secondary)
+                        .add_modifier(Modifier::ITALIC),
+                ),
+            ])));
+            current_line += 1;
+        }
+
         for (idx, action) in selected_outcome.actions.iter().enumerate() {
+            let first_line = current_line;
             let is_expanded = self.ui_state.is_expanded(&action.id);
             let expansion_symbol = if is_expanded { "▼ " } else { "▶ " };
             let checkbox = if action.completed { "[x]" } else { "[ ]" };
@@ -2739,9 +12051,6 @@ impl App {
             let is_selected =
                 self.focus_panel == FocusPanel::Actions && self.selected_action == idx;
 
-            if is_selected {
-                _selected_display_line = Some(current_line);
-            }
 
             let style = if is_selected {
                 Style::default()
@@ -2751,24 +12060,51 @@ impl App {
                 Style::default()
             };
 
+            let prefix_color = self.action_accent(action).unwrap_or(outcome_color);
+            let blocker = self.blocked.get(&(self.selected_outcome, idx));
+            let text_style = match blocker {
+                Some(_) => Style::default()
+                    .fg(self.theme.text_secondary)
+                    .add_modifier(Modifier::DIM),
+                None => Style::default().fg(self.theme.text_primary),
+            };
+
             // Main action line with expansion symbol
-            actions_list.push(
-                ListItem::new(Line::from(vec![
-                    Span::styled(
-                        format!("{} ", outcome_prefix),
-                        Style::default().fg(outcome_color),
-                    ),
-                    Span::styled(
-                        expansion_symbol,
-                        Style::default().fg(self.theme.text_secondary),
-                    ),
-                    Span::styled(checkbox, Style::default().fg(color)),
-                    Span::raw(" "),
-                    Span::styled(&action.text, Style::default().fg(self.theme.text_primary)),
-                ]))
-                .style(style),
-            );
-            current_line += 1;
+            let mut spans = vec![
+                Span::styled(
+                    format!("{} ", outcome_prefix),
+                    Style::default().fg(prefix_color),
+                ),
+                Span::styled(
+                    expansion_symbol,
+                    Style::default().fg(self.theme.text_secondary),
+                ),
+                Span::styled(checkbox, Style::default().fg(color)),
+                Span::raw(" "),
+            ];
+            if let Some(level) = self.action_priorities.get(&(self.selected_outcome, idx)) {
+                spans.push(self.priority_badge(*level));
+            }
+            spans.push(Span::styled(&action.text, text_style));
+            if self.weekly_linked.contains(&(self.selected_outcome, idx)) {
+                spans.push(Span::styled(" ◆", Style::default().fg(outcome_color)));
+            }
+            if let Some(blocker) = blocker {
+                spans.push(Span::styled(
+                    format!("  ⛓ blocked by: {}", blocker),
+                    Style::default()
+                        .fg(self.theme.text_secondary)
+                        .add_modifier(Modifier::ITALIC),
+                ));
+            }
+            // Long text wraps under the checkbox; every row carries the
+            // selection style so the highlight covers the whole action
+            let indent = spans.iter().take(4).map(|span| span.width()).sum();
+            let width = usize::from(area.width.saturating_sub(2));
+            for line in wrap::wrap_spans(spans, width, indent) {
+                actions_list.push(ListItem::new(line).style(style));
+                current_line += 1;
+            }
 
             // Add objective and indicators if expanded
             if is_expanded {
@@ -2778,13 +12114,26 @@ impl App {
                             self.objectives.objectives.iter().find(|o| o.id == *obj_id)
                         {
                             // Objective line with icon
-                            actions_list.push(ListItem::new(Line::from(vec![
+                            let title_color = theme::objective_accent(objective.color.as_deref())
+                                .unwrap_or(self.theme.text_primary);
+                            let mut spans = vec![
                                 Span::raw("  └─ 📎 Objective: "),
-                                Span::styled(
-                                    &objective.title,
-                                    Style::default().fg(self.theme.text_primary),
-                                ),
-                            ])));
+                                Span::styled(&objective.title, Style::default().fg(title_color)),
+                            ];
+                            if let Some((done, total)) = objective.queue_progress() {
+                                let next = objective
+                                    .next_queue_item()
+                                    .map(|item| format!(" · next: {}", item.title))
+                                    .unwrap_or_default();
+                                spans.push(Span::styled(
+                                    format!("  {}/{} items{}", done, total, next),
+                                    Style::default().fg(self.theme.text_secondary),
+                                ));
+                            }
+                            if objective.indicators.is_empty() {
+                                spans.extend(self.countdown_span(objective));
+                            }
+                            actions_list.push(ListItem::new(Line::from(spans)));
                             current_line += 1;
 
                             // Add indicators for this objective
@@ -2843,7 +12192,7 @@ impl App {
                             // Add overall progress
                             if !objective.indicators.is_empty() {
                                 let overall_progress = self.calculate_objective_progress(objective);
-                                actions_list.push(ListItem::new(Line::from(vec![
+                                let mut spans = vec![
                                     Span::raw("      "),
                                     Span::styled(
                                         format!("Overall Progress: {:.0}%", overall_progress),
@@ -2851,7 +12200,9 @@ impl App {
                                             .fg(self.theme.text_secondary)
                                             .add_modifier(Modifier::ITALIC),
                                     ),
-                                ])));
+                                ];
+                                spans.extend(self.countdown_span(objective));
+                                actions_list.push(ListItem::new(Line::from(spans)));
                                 current_line += 1;
                             }
                         }
@@ -2869,9 +12220,46 @@ impl App {
                     ])));
                     current_line += 1;
                 }
+
+                for link in &action.indicator_links {
+                    let Some(def) = self
+                        .indicators
+                        .indicators
+                        .iter()
+                        .find(|def| def.id == link.indicator_id)
+                    else {
+                        continue;
+                    };
+                    let feeds = match link.value {
+                        Some(value) => format!(
+                            " · logs {} when done",
+                            self.format_value_with_unit(value, &def.unit)
+                        ),
+                        None => " · asks when done".to_string(),
+                    };
+                    actions_list.push(ListItem::new(Line::from(vec![
+                        Span::raw("  └─ 📈 Indicator: "),
+                        Span::styled(
+                            def.name.clone(),
+                            Style::default().fg(self.theme.text_primary),
+                        ),
+                        Span::styled(feeds, Style::default().fg(self.theme.text_secondary)),
+                    ])));
+                    current_line += 1;
+                }
+            }
+
+            if self.selected_action == idx {
+                selected_lines = Some((first_line, current_line));
             }
         }
 
+        let visible = usize::from(area.height.saturating_sub(2));
+        let total_lines = actions_list.len();
+        let scroll =
+            Self::scroll_to_show(self.actions_scroll, selected_lines, total_lines, visible);
+        let actions_list: Vec<ListItem> = actions_list.into_iter().skip(scroll).collect();
+
         let border_color = if self.focus_panel == FocusPanel::Actions {
             self.theme.header
         } else {
@@ -2895,6 +12283,45 @@ impl App {
         );
 
         f.render_widget(actions, area);
+        self.actions_scroll = scroll;
+
+        if total_lines > visible {
+            let mut scrollbar_state = ScrollbarState::new(total_lines - visible)
+                .position(scroll)
+                .viewport_content_length(visible);
+            f.render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                    .begin_symbol(None)
+                    .end_symbol(None)
+                    .style(Style::default().fg(border_color)),
+                area.inner(ratatui::layout::Margin {
+                    horizontal: 0,
+                    vertical: 1,
+                }),
+                &mut scrollbar_state,
+            );
+        }
+    }
+
+    /// Scroll offset that keeps `selected` (first line, end line) in a
+    /// window of `visible` lines, moving as little as possible from `offset`.
+    /// When the selection is taller than the window its first line wins.
+    fn scroll_to_show(
+        offset: usize,
+        selected: Option<(usize, usize)>,
+        total: usize,
+        visible: usize,
+    ) -> usize {
+        let mut offset = offset.min(total.saturating_sub(visible));
+        if let Some((start, end)) = selected {
+            if end > offset + visible {
+                offset = end - visible;
+            }
+            if start < offset {
+                offset = start;
+            }
+        }
+        offset
     }
 
     fn render_mini_progress(&self, indicator: &Indicator) -> String {
@@ -2965,9 +12392,14 @@ impl App {
             ])
             .split(area);
 
-        // Render title block
+        // Render title block, with a spinner while the history is being scanned
+        let title = if self.loader.history_loading() {
+            format!(" STATISTICS {} ", loader::spinner_frame())
+        } else {
+            " STATISTICS ".to_string()
+        };
         let title_block = Block::default()
-            .title(" STATISTICS ")
+            .title(title)
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(self.theme.border))
@@ -2979,13 +12411,51 @@ impl App {
             horizontal: 1,
             vertical: 1,
         });
+        let activity_height = if self.recent_activity.is_empty() {
+            0
+        } else {
+            self.recent_activity.len() as u16 + 2
+        };
+        let status_height = u16::from(self.statistics.status_counts != StatusCounts::default());
+        let time_height = if self
+            .statistics
+            .outcome_times
+            .iter()
+            .any(|time| time.estimated > 0 || time.actual > 0)
+        {
+            4
+        } else {
+            0
+        };
+        let focus_height =
+            u16::from(self.statistics.weekly_focus_minutes > 0.0 || self.focus_timer.is_some());
+        let effort_height = u16::from(self.budgeted_objectives().next().is_some());
+        let rollup_height = u16::from(
+            self.statistics
+                .month_over_month
+                .as_ref()
+                .is_some_and(|months| months.current.days_tracked > 0),
+        );
+        let priority_height = u16::from(
+            self.statistics
+                .priority_completion
+                .iter()
+                .any(|(_, planned)| *planned > 0),
+        );
         let inner_layout = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(4), // Daily gauge
-                Constraint::Length(4), // Outcome gauges
-                Constraint::Min(8),    // Weekly chart
-                Constraint::Length(4), // Monthly sparkline
+                Constraint::Length(4),               // Daily gauge
+                Constraint::Length(4),               // Outcome gauges
+                Constraint::Min(8),                  // Weekly chart
+                Constraint::Length(status_height),   // Board status counts
+                Constraint::Length(time_height),     // Estimated vs tracked time
+                Constraint::Length(focus_height),    // Focus time
+                Constraint::Length(effort_height),   // Objective effort vs budget
+                Constraint::Length(rollup_height),   // Month/quarter rollups
+                Constraint::Length(priority_height), // Completion by priority
+                Constraint::Length(4),               // Monthly sparkline
+                Constraint::Length(activity_height), // Recent activity
             ])
             .split(inner);
 
@@ -3020,34 +12490,366 @@ impl App {
             weekly_chart.render(f, inner_layout[2]);
         }
 
+        if inner_layout[3].height > 0 {
+            self.render_status_summary(f, inner_layout[3]);
+        }
+
+        if inner_layout[4].height > 0 {
+            self.render_time_summary(f, inner_layout[4]);
+        }
+
+        if inner_layout[5].height > 0 {
+            self.render_focus_summary(f, inner_layout[5]);
+        }
+
+        if inner_layout[6].height > 0 {
+            self.render_effort_summary(f, inner_layout[6]);
+        }
+
+        if inner_layout[7].height > 0 {
+            self.render_rollup_summary(f, inner_layout[7]);
+        }
+
+        if inner_layout[8].height > 0 {
+            self.render_priority_summary(f, inner_layout[8]);
+        }
+
         // Monthly trend sparkline
-        if !self.statistics.monthly_trend.is_empty() && inner_layout[3].height > 2 {
+        if !self.statistics.monthly_trend.is_empty() && inner_layout[9].height > 2 {
             render_trend_sparkline(
                 &self.statistics.monthly_trend,
                 "30-DAY TREND",
                 &self.theme,
                 f,
-                inner_layout[3],
+                inner_layout[9],
             );
         }
+
+        if inner_layout[10].height > 2 {
+            self.render_recent_activity(f, inner_layout[10]);
+        }
     }
 
-    fn render_footer(&self, f: &mut Frame, area: Rect) {
-        // Add a bordered frame around the help text
-        let footer_block = Block::default()
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(self.theme.border))
-            .style(Style::default().bg(self.theme.panel_bg));
+    /// Active objectives that declare a weekly effort budget
+    fn budgeted_objectives(&self) -> impl Iterator<Item = (&Objective, u32)> + '_ {
+        self.objectives
+            .objectives
+            .iter()
+            .filter(|obj| obj.status == ObjectiveStatus::Active)
+            .filter_map(|obj| obj.weekly_budget_min.map(|budget| (obj, budget)))
+    }
+
+    /// "actual/budget" for this week, red once over budget
+    /// Days to an objective's target date, flagged when it is overdue or
+    /// its indicators are behind pace
+    fn countdown_span(&self, objective: &Objective) -> Option<Span<'static>> {
+        let today = chrono::Local::now().date_naive();
+        let countdown = objective.countdown(today)?;
+        let behind = self
+            .objective_pace
+            .get(&objective.id)
+            .filter(|names| !names.is_empty());
+        let (text, color) = match behind {
+            Some(names) => (
+                format!(
+                    "  {} {} · behind pace: {}",
+                    Severity::Warning.symbol(),
+                    countdown,
+                    names.join(", ")
+                ),
+                self.theme.severity_color(Severity::Warning),
+            ),
+            None if objective.days_left(today).is_some_and(|days| days < 0) => (
+                format!("  {} {}", Severity::Bad.symbol(), countdown),
+                self.theme.severity_color(Severity::Bad),
+            ),
+            None => (format!("  ⏳ {}", countdown), self.theme.text_secondary),
+        };
+        Some(Span::styled(text, Style::default().fg(color)))
+    }
 
-        f.render_widget(footer_block.clone(), area);
+    fn budget_span(&self, objective_id: &str, budget: u32) -> Span<'static> {
+        let actual = self
+            .statistics
+            .objective_week_minutes
+            .get(objective_id)
+            .copied()
+            .unwrap_or(0);
+        let severity = if actual > budget {
+            Severity::Bad
+        } else if actual == budget {
+            Severity::Good
+        } else {
+            Severity::Warning
+        };
+        Span::styled(
+            format!(
+                "  {} {}/{}",
+                severity.symbol(),
+                crate::models::format_minutes(actual),
+                crate::models::format_minutes(budget)
+            ),
+            Style::default().fg(self.theme.severity_color(severity)),
+        )
+    }
 
-        // Render help text inside the border
-        let inner_area = area.inner(ratatui::layout::Margin {
-            horizontal: 1,
-            vertical: 0,
-        });
-        help::render_help(f, inner_area, &self.theme);
+    fn render_effort_summary(&self, f: &mut Frame, area: Rect) {
+        let mut spans = vec![Span::styled(
+            "EFFORT WK",
+            Style::default().fg(self.theme.header),
+        )];
+        for (objective, budget) in self.budgeted_objectives() {
+            spans.push(Span::styled(
+                format!("  {}", objective.title),
+                Style::default().fg(self.theme.text_primary),
+            ));
+            spans.push(self.budget_span(&objective.id, budget));
+        }
+        f.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+
+    /// "MONTH 64% ▲8 · best Work   QTR 58% ▼3" against the previous period
+    /// "P1 " in red, "P2 " in amber, "P3 " in the header color
+    fn priority_badge(&self, level: u32) -> Span<'static> {
+        let color = match level {
+            1 => self.theme.severity_color(Severity::Bad),
+            2 => self.theme.severity_color(Severity::Warning),
+            _ => self.theme.header,
+        };
+        Span::styled(
+            format!("P{} ", level),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        )
+    }
+
+    /// Completion rate of each priority level over the last 30 days
+    fn render_priority_summary(&self, f: &mut Frame, area: Rect) {
+        let mut spans = vec![Span::styled(
+            "PRIORITY ",
+            Style::default().fg(self.theme.header),
+        )];
+        for (level, (done, planned)) in priority::LEVELS
+            .iter()
+            .zip(self.statistics.priority_completion)
+        {
+            spans.push(self.priority_badge(*level));
+            let rate = match planned {
+                0 => "—".to_string(),
+                _ => format!("{:.0}%", f64::from(done) * 100.0 / f64::from(planned)),
+            };
+            spans.push(Span::styled(
+                format!("{} ({}/{})  ", rate, done, planned),
+                Style::default().fg(self.theme.text_primary),
+            ));
+        }
+        f.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+
+    fn render_rollup_summary(&self, f: &mut Frame, area: Rect) {
+        let mut spans = Vec::new();
+        let periods = [
+            ("MONTH", &self.statistics.month_over_month),
+            ("QTR", &self.statistics.quarter_over_quarter),
+        ];
+        for (label, comparison) in periods {
+            let Some(comparison) = comparison else {
+                continue;
+            };
+            if !spans.is_empty() {
+                spans.push(Span::raw("   "));
+            }
+            spans.push(Span::styled(
+                format!("{} ", label),
+                Style::default().fg(self.theme.header),
+            ));
+            spans.push(Span::styled(
+                format!("{:.0}%", comparison.current.completion),
+                Style::default().fg(self.theme.text_primary),
+            ));
+            if let Some(change) = comparison.completion_change() {
+                let (arrow, color) = if change >= 0.5 {
+                    ("▲", self.theme.completed)
+                } else if change <= -0.5 {
+                    ("▼", self.theme.pending)
+                } else {
+                    ("=", self.theme.text_secondary)
+                };
+                spans.push(Span::styled(
+                    format!(" {}{:.0}", arrow, change.abs()),
+                    Style::default().fg(color),
+                ));
+            }
+            if label == "MONTH" {
+                if let Some(best) = comparison.current.best_outcome {
+                    spans.push(Span::styled(
+                        format!(" · best {}", best.as_str()),
+                        Style::default().fg(self.theme.text_secondary),
+                    ));
+                }
+            }
+        }
+        f.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+
+    fn render_status_summary(&self, f: &mut Frame, area: Rect) {
+        let counts = self.statistics.status_counts;
+        let muted = Style::default().fg(self.theme.text_secondary);
+        let spans = vec![
+            Span::styled("STATUS ", Style::default().fg(self.theme.header)),
+            Span::styled(format!("{} planned", counts.planned), muted),
+            Span::styled(
+                format!("  {} active", counts.in_progress),
+                Style::default().fg(self.theme.partial),
+            ),
+            Span::styled(format!("  {} blocked", counts.blocked), muted),
+            Span::styled(
+                format!("  {} done", counts.done),
+                Style::default().fg(self.theme.completed),
+            ),
+            Span::styled(format!("  {} skipped", counts.skipped), muted),
+            Span::styled(
+                format!("  {:.0}% of planned", counts.completion()),
+                Style::default().fg(self.theme.text_primary),
+            ),
+        ];
+        f.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+
+    /// Tracked time per outcome as a bar over its estimate, red past it
+    fn render_time_summary(&self, f: &mut Frame, area: Rect) {
+        let times = &self.statistics.outcome_times;
+        let scale = times
+            .iter()
+            .map(|time| time.estimated.max(time.actual))
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let bar_width = area.width.saturating_sub(24).max(4) as u32;
+        let over_color = self.theme.severity_color(Severity::Bad);
+
+        let mut lines = vec![Line::from(vec![
+            Span::styled("TIME ", Style::default().fg(self.theme.header)),
+            Span::styled(
+                "tracked / estimated",
+                Style::default().fg(self.theme.text_secondary),
+            ),
+        ])];
+        let outcomes = [
+            ("Work", self.theme.work_color),
+            ("Health", self.theme.health_color),
+            ("Family", self.theme.family_color),
+        ];
+        for ((label, color), time) in outcomes.into_iter().zip(times) {
+            let cells = |minutes: u32| (minutes * bar_width).div_ceil(scale);
+            let (actual, estimated) = (cells(time.actual), cells(time.estimated));
+            let mut spans = vec![Span::styled(
+                format!("{:<7}", label),
+                Style::default().fg(self.theme.text_primary),
+            )];
+            spans.push(Span::styled(
+                "█".repeat(actual.min(estimated) as usize),
+                Style::default().fg(color),
+            ));
+            if actual > estimated {
+                spans.push(Span::styled(
+                    "█".repeat((actual - estimated) as usize),
+                    Style::default().fg(over_color),
+                ));
+            } else {
+                spans.push(Span::styled(
+                    "░".repeat((estimated - actual) as usize),
+                    Style::default().fg(self.theme.text_secondary),
+                ));
+            }
+            spans.push(Span::raw(
+                " ".repeat((bar_width - actual.max(estimated)) as usize),
+            ));
+            spans.push(Span::styled(
+                format!(
+                    " {}/{}",
+                    crate::models::format_minutes(time.actual),
+                    crate::models::format_minutes(time.estimated)
+                ),
+                Style::default().fg(self.theme.text_secondary),
+            ));
+            lines.push(Line::from(spans));
+        }
+        f.render_widget(Paragraph::new(lines), area);
+    }
+
+    fn render_focus_summary(&self, f: &mut Frame, area: Rect) {
+        let total = self.statistics.weekly_focus_minutes.round() as i64;
+        let mut spans = vec![
+            Span::styled("FOCUS 7D ", Style::default().fg(self.theme.header)),
+            Span::styled(
+                format!("{}h {:02}m", total / 60, total % 60),
+                Style::default().fg(self.theme.text_primary),
+            ),
+        ];
+        if let Some(timer) = &self.focus_timer {
+            let label = match timer.kind {
+                FocusSessionKind::Pomodoro => "Pomodoro",
+                FocusSessionKind::Stopwatch => "Stopwatch",
+            };
+            spans.push(Span::styled(
+                format!("  ⏱ {} {}", label, timer.display()),
+                Style::default().fg(self.theme.partial),
+            ));
+        }
+        f.render_widget(Paragraph::new(Line::from(spans)), area);
+    }
+
+    fn render_recent_activity(&self, f: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self
+            .recent_activity
+            .iter()
+            .map(|event| {
+                let (icon, color) = match event.kind {
+                    ActivityKind::Completion => ("✓", self.theme.completed),
+                    ActivityKind::Observation => ("◆", self.theme.partial),
+                    ActivityKind::Objective => ("●", self.theme.header),
+                };
+                let time = event.at.with_timezone(&chrono::Local).format("%H:%M");
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{} ", time),
+                        Style::default().fg(self.theme.text_secondary),
+                    ),
+                    Span::styled(format!("{} ", icon), Style::default().fg(color)),
+                    Span::styled(
+                        event.summary.clone(),
+                        Style::default().fg(self.theme.text_primary),
+                    ),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items).block(
+            Block::default()
+                .title(" RECENT ACTIVITY ")
+                .borders(Borders::TOP)
+                .border_style(Style::default().fg(self.theme.border)),
+        );
+        f.render_widget(list, area);
+    }
+
+    fn render_footer(&self, f: &mut Frame, area: Rect) {
+        let lines = self.status_bar(
+            area.width,
+            Style::default()
+                .fg(self.theme.background)
+                .bg(self.theme.header)
+                .add_modifier(Modifier::BOLD),
+            Style::default().fg(self.theme.header),
+        );
+        f.render_widget(
+            Paragraph::new(lines).style(
+                Style::default()
+                    .fg(self.theme.text_secondary)
+                    .bg(self.theme.panel_bg),
+            ),
+            area,
+        );
     }
 
     pub fn render_live_metrics(&self, f: &mut Frame, area: Rect) {
@@ -1,4 +1,7 @@
-use crate::ui::{stats::Statistics, theme::FocusFiveTheme};
+use crate::ui::{
+    stats::Statistics,
+    theme::{FocusFiveTheme, Severity},
+};
 use chrono::{Datelike, Duration, NaiveDate};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -13,7 +16,7 @@ pub struct WeeklyLineChart<'a> {
     data: Vec<(f64, f64)>,
     current_date: NaiveDate,
     theme: &'a FocusFiveTheme,
-    line_color: Color,
+    severity: Severity,
 }
 
 impl<'a> WeeklyLineChart<'a> {
@@ -33,19 +36,11 @@ impl<'a> WeeklyLineChart<'a> {
             0.0
         };
 
-        let line_color = if avg_completion >= 80.0 {
-            theme.completed
-        } else if avg_completion >= 40.0 {
-            theme.partial
-        } else {
-            theme.pending
-        };
-
         Self {
             data,
             current_date,
             theme,
-            line_color,
+            severity: Severity::from_completion(avg_completion),
         }
     }
 
@@ -70,7 +65,7 @@ impl<'a> WeeklyLineChart<'a> {
         let dataset = Dataset::default()
             .marker(symbols::Marker::Braille)
             .graph_type(GraphType::Line)
-            .style(Style::default().fg(self.line_color))
+            .style(Style::default().fg(self.theme.severity_color(self.severity)))
             .data(&chart_data);
             
         // Create x-axis with day labels
@@ -99,7 +94,10 @@ impl<'a> WeeklyLineChart<'a> {
         let chart = Chart::new(vec![dataset])
             .block(
                 Block::default()
-                    .title(" WEEKLY PROGRESS (7-DAY) ")
+                    .title(format!(
+                        " WEEKLY PROGRESS (7-DAY) {} ",
+                        self.severity.symbol()
+                    ))
                     .borders(Borders::ALL)
                     .border_style(Style::default().fg(self.theme.border))
                     .style(Style::default().bg(self.theme.panel_bg)),
@@ -212,18 +210,12 @@ pub fn create_weekly_chart<'a>(stats: &Statistics, theme: &FocusFiveTheme) -> Ba
 
     // Determine bar color based on average completion
     let avg_completion = stats.weekly_trend.iter().sum::<f64>() / stats.weekly_trend.len() as f64;
-    let bar_color = if avg_completion >= 80.0 {
-        theme.completed
-    } else if avg_completion >= 40.0 {
-        theme.partial
-    } else {
-        theme.pending
-    };
+    let severity = Severity::from_completion(avg_completion);
 
     BarChart::default()
         .block(
             Block::default()
-                .title(" WEEKLY PROGRESS ")
+                .title(format!(" WEEKLY PROGRESS {} ", severity.symbol()))
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(theme.border))
                 .style(Style::default().bg(theme.panel_bg)),
@@ -233,7 +225,7 @@ pub fn create_weekly_chart<'a>(stats: &Statistics, theme: &FocusFiveTheme) -> Ba
         .bar_gap(1)
         .value_style(Style::default().fg(theme.text_secondary))
         .label_style(Style::default().fg(theme.text_secondary))
-        .style(Style::default().fg(bar_color))
+        .style(Style::default().fg(theme.severity_color(severity)))
 }
 
 pub fn render_trend_sparkline(
@@ -253,37 +245,34 @@ pub fn render_trend_sparkline(
         0.0
     };
 
-    let color = if avg >= 80.0 {
-        theme.completed
-    } else if avg >= 40.0 {
-        theme.partial
-    } else {
-        theme.pending
-    };
+    let severity = Severity::from_completion(avg);
 
     let sparkline = Sparkline::default()
         .block(
             Block::default()
-                .title(format!(" {} ", title))
+                .title(format!(" {} {} ", title, severity.symbol()))
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(theme.border))
                 .style(Style::default().bg(theme.panel_bg)),
         )
         .data(&values)
-        .style(Style::default().fg(color))
+        .style(Style::default().fg(theme.severity_color(severity)))
         .max(100); // Set max to 100 since we're dealing with percentages
 
     f.render_widget(sparkline, area);
 }
 
+/// One block character per completion percentage, for inline trends like
+/// "▁▃▅█"; an empty day shows the lowest block
+pub fn completion_sparkline(data: &[f64]) -> String {
+    const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    data.iter()
+        .map(|value| LEVELS[(value.clamp(0.0, 100.0) / 100.0 * 7.0).round() as usize])
+        .collect()
+}
+
 pub fn create_daily_gauge<'a>(percentage: f64, title: &str, theme: &FocusFiveTheme) -> Gauge<'a> {
-    let color = if percentage >= 80.0 {
-        theme.completed
-    } else if percentage >= 40.0 {
-        theme.partial
-    } else {
-        theme.pending
-    };
+    let severity = Severity::from_completion(percentage);
 
     Gauge::default()
         .block(
@@ -293,10 +282,10 @@ pub fn create_daily_gauge<'a>(percentage: f64, title: &str, theme: &FocusFiveThe
                 .border_style(Style::default().fg(theme.border))
                 .style(Style::default().bg(theme.panel_bg)),
         )
-        .gauge_style(Style::default().fg(color))
+        .gauge_style(Style::default().fg(theme.severity_color(severity)))
         .percent(percentage.round() as u16)
         .label(Span::styled(
-            format!("{:.0}%", percentage),
+            format!("{} {:.0}%", severity.symbol(), percentage),
             Style::default().fg(theme.text_primary),
         ))
 }
@@ -366,6 +355,14 @@ mod tests {
             weekly_trend: vec![10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0],
             monthly_trend: vec![50.0; 30],
             outcome_percentages: (33.3, 66.6, 100.0),
+            weekly_focus_minutes: 0.0,
+            objective_week_minutes: Default::default(),
+            month_over_month: None,
+            quarter_over_quarter: None,
+            status_counts: Default::default(),
+            outcome_times: Default::default(),
+            priority_completion: Default::default(),
+            outcome_trends: Default::default(),
         };
 
         let theme = FocusFiveTheme::default();
@@ -388,6 +385,12 @@ mod tests {
         assert_eq!(values.len(), 30);
     }
 
+    #[test]
+    fn completion_sparkline_scales_percentages() {
+        assert_eq!(completion_sparkline(&[0.0, 33.3, 66.7, 100.0, 150.0]), "▁▃▆██");
+        assert_eq!(completion_sparkline(&[]), "");
+    }
+
     #[test]
     fn test_empty_data_handling() {
         let stats = Statistics {
@@ -395,6 +398,14 @@ mod tests {
             weekly_trend: vec![],
             monthly_trend: vec![],
             outcome_percentages: (0.0, 0.0, 0.0),
+            weekly_focus_minutes: 0.0,
+            objective_week_minutes: Default::default(),
+            month_over_month: None,
+            quarter_over_quarter: None,
+            status_counts: Default::default(),
+            outcome_times: Default::default(),
+            priority_completion: Default::default(),
+            outcome_trends: Default::default(),
         };
 
         let theme = FocusFiveTheme::default();
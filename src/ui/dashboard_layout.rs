@@ -5,7 +5,7 @@ pub struct DashboardLayout {
     pub live_metrics: Rect,
     pub performance: Rect,
     pub sentiment: Rect,
-    pub signals: Rect,
+    pub portfolio: Rect,
     pub status_line: Rect,
     pub footer: Rect,
 }
@@ -41,12 +41,12 @@ impl DashboardLayout {
             ])
             .split(content_rows[0]);
 
-        // Bottom row: Sentiment and signals
+        // Bottom row: Sentiment and the objective portfolio
         let bottom_row = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
                 Constraint::Percentage(50), // Sentiment analysis - aligned with live metrics
-                Constraint::Percentage(50), // Alternative signals - aligned with performance
+                Constraint::Percentage(50), // Portfolio or signals - aligned with performance
             ])
             .split(content_rows[1]);
 
@@ -55,7 +55,7 @@ impl DashboardLayout {
             live_metrics: top_row[0],
             performance: top_row[1],
             sentiment: bottom_row[0],
-            signals: bottom_row[1],
+            portfolio: bottom_row[1],
             status_line: main_chunks[2],
             footer: main_chunks[3],
         }
@@ -0,0 +1,355 @@
+//! The first-run setup wizard, shown before the app when the data root is
+//! empty (see `crate::onboarding`)
+
+use crate::models::{Config, IndicatorUnit, OutcomeType};
+use crate::onboarding::{self, Setup};
+use crate::ui::popup::centered_rect;
+use crate::ui::theme::FocusFiveTheme;
+use crossterm::event::{self, Event, KeyCode, KeyModifiers};
+use ratatui::{
+    backend::Backend,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
+    Frame, Terminal,
+};
+use std::path::PathBuf;
+
+const OUTCOMES: [OutcomeType; 3] = [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family];
+const UNITS: [IndicatorUnit; 4] = [
+    IndicatorUnit::Count,
+    IndicatorUnit::Minutes,
+    IndicatorUnit::Dollars,
+    IndicatorUnit::Percent,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    DataDir,
+    Goal(usize),
+    Vision(usize),
+    Objective,
+    Indicator,
+    Review,
+}
+
+impl Step {
+    const ALL: [Step; 10] = [
+        Step::DataDir,
+        Step::Goal(0),
+        Step::Vision(0),
+        Step::Goal(1),
+        Step::Vision(1),
+        Step::Goal(2),
+        Step::Vision(2),
+        Step::Objective,
+        Step::Indicator,
+        Step::Review,
+    ];
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|step| step == self).unwrap_or(0)
+    }
+}
+
+pub enum Finish {
+    Setup(Box<Setup>),
+    /// Esc on the first step: start with the defaults and no seeded data
+    Skip,
+}
+
+pub struct FirstRunWizard {
+    pub setup: Setup,
+    pub step: Step,
+    /// Typed form of `setup.data_root`, which may start with ~/
+    pub data_root: String,
+    pub error: Option<String>,
+}
+
+impl FirstRunWizard {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            setup: Setup::new(config),
+            step: Step::DataDir,
+            data_root: config.data_root.clone(),
+            error: None,
+        }
+    }
+
+    fn field_mut(&mut self) -> Option<&mut String> {
+        match self.step {
+            Step::DataDir => Some(&mut self.data_root),
+            Step::Goal(i) => Some(&mut self.setup.goals[i]),
+            Step::Vision(i) => Some(&mut self.setup.visions[i]),
+            Step::Objective => Some(&mut self.setup.objective),
+            Step::Indicator => Some(&mut self.setup.indicator),
+            Step::Review => None,
+        }
+    }
+
+    fn field(&self) -> Option<&str> {
+        match self.step {
+            Step::DataDir => Some(&self.data_root),
+            Step::Goal(i) => Some(&self.setup.goals[i]),
+            Step::Vision(i) => Some(&self.setup.visions[i]),
+            Step::Objective => Some(&self.setup.objective),
+            Step::Indicator => Some(&self.setup.indicator),
+            Step::Review => None,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) -> Option<Finish> {
+        self.error = None;
+        match key {
+            KeyCode::Enter => return self.advance(),
+            KeyCode::Esc => {
+                if self.step == Step::DataDir {
+                    return Some(Finish::Skip);
+                }
+                self.step = Step::ALL[self.step.index() - 1];
+            }
+            KeyCode::Tab => match self.step {
+                Step::Objective => {
+                    let next = OUTCOMES
+                        .iter()
+                        .position(|outcome| *outcome == self.setup.objective_domain)
+                        .map_or(0, |i| (i + 1) % OUTCOMES.len());
+                    self.setup.objective_domain = OUTCOMES[next];
+                }
+                Step::Indicator => {
+                    let next = UNITS
+                        .iter()
+                        .position(|unit| *unit == self.setup.indicator_unit)
+                        .map_or(0, |i| (i + 1) % UNITS.len());
+                    self.setup.indicator_unit = UNITS[next].clone();
+                }
+                _ => {}
+            },
+            KeyCode::Backspace => {
+                if let Some(field) = self.field_mut() {
+                    field.pop();
+                }
+            }
+            KeyCode::Char(c) => {
+                if let Some(field) = self.field_mut() {
+                    field.push(c);
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn advance(&mut self) -> Option<Finish> {
+        match self.step {
+            Step::DataDir => {
+                let typed = self.data_root.trim();
+                if typed.is_empty() {
+                    self.error = Some("Choose a directory for your data".to_string());
+                    return None;
+                }
+                self.setup.data_root = expand_home(typed);
+            }
+            Step::Review => return Some(Finish::Setup(Box::new(self.setup.clone()))),
+            _ => {}
+        }
+        self.step = Step::ALL[self.step.index() + 1];
+        None
+    }
+
+    fn prompt(&self) -> (String, String) {
+        match self.step {
+            Step::DataDir => (
+                "Where should FocusFive keep your data?".to_string(),
+                "Plain markdown and JSON files; a synced folder works too".to_string(),
+            ),
+            Step::Goal(i) => (
+                format!("What is your {} outcome about?", OUTCOMES[i].as_str()),
+                "e.g. \"Ship the new website\", shown with the outcome".to_string(),
+            ),
+            Step::Vision(i) => (
+                format!("Your {} vision, in one line", OUTCOMES[i].as_str()),
+                "Where you want to be in five years".to_string(),
+            ),
+            Step::Objective => (
+                "A first objective to work toward".to_string(),
+                format!(
+                    "For {} (Tab to change), e.g. \"Run a half marathon\"",
+                    self.setup.objective_domain.as_str()
+                ),
+            ),
+            Step::Indicator => (
+                "Something to measure along the way".to_string(),
+                format!(
+                    "Counted in {} (Tab to change), e.g. \"Minutes running\"",
+                    unit_name(&self.setup.indicator_unit)
+                ),
+            ),
+            Step::Review => (
+                "All set".to_string(),
+                "Enter starts FocusFive with this; Esc goes back to change it".to_string(),
+            ),
+        }
+    }
+
+    fn review_lines(&self, theme: &FocusFiveTheme) -> Vec<Line<'static>> {
+        let muted = Style::default().fg(theme.text_secondary);
+        let value = Style::default().fg(theme.text_primary);
+        let row = |label: &str, text: String| {
+            let text = if text.trim().is_empty() {
+                "-".to_string()
+            } else {
+                text
+            };
+            Line::from(vec![
+                Span::styled(format!("{:<11}", label), muted),
+                Span::styled(text, value),
+            ])
+        };
+        let mut lines = vec![row(
+            "Data",
+            self.setup.data_root.to_string_lossy().to_string(),
+        )];
+        for (i, outcome) in OUTCOMES.iter().enumerate() {
+            lines.push(row(outcome.as_str(), self.setup.goals[i].clone()));
+            lines.push(row("  vision", self.setup.visions[i].clone()));
+        }
+        lines.push(row(
+            "Objective",
+            if self.setup.objective.trim().is_empty() {
+                String::new()
+            } else {
+                format!(
+                    "{} ({})",
+                    self.setup.objective,
+                    self.setup.objective_domain.as_str()
+                )
+            },
+        ));
+        lines.push(row(
+            "Indicator",
+            if self.setup.indicator.trim().is_empty() {
+                String::new()
+            } else {
+                format!(
+                    "{} ({})",
+                    self.setup.indicator,
+                    unit_name(&self.setup.indicator_unit)
+                )
+            },
+        ));
+        lines
+    }
+
+    pub fn render(&self, f: &mut Frame) {
+        let theme = FocusFiveTheme::default();
+        f.render_widget(
+            Block::default().style(Style::default().bg(theme.background)),
+            f.area(),
+        );
+        let area = centered_rect(70, 60, f.area());
+        let area = Rect {
+            height: area.height.max(16).min(f.area().height),
+            ..area
+        };
+        f.render_widget(Clear, area);
+
+        let footer = match self.step {
+            Step::DataDir => " Enter next · Esc skip setup ",
+            Step::Objective | Step::Indicator => " Enter next · Tab change · Esc back ",
+            Step::Review => " Enter start · Esc back ",
+            _ => " Enter next (empty to skip) · Esc back ",
+        };
+        let block = Block::default()
+            .title(" Welcome to FocusFive ")
+            .title_bottom(Line::from(footer).right_aligned())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.header))
+            .padding(ratatui::widgets::Padding::new(2, 2, 1, 1))
+            .style(Style::default().bg(theme.panel_bg));
+
+        let (question, hint) = self.prompt();
+        let mut lines = vec![
+            Line::styled(
+                format!("Step {} of {}", self.step.index() + 1, Step::ALL.len()),
+                Style::default().fg(theme.text_secondary),
+            ),
+            Line::from(""),
+            Line::styled(
+                question,
+                Style::default()
+                    .fg(theme.text_primary)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Line::styled(hint, Style::default().fg(theme.text_secondary)),
+            Line::from(""),
+        ];
+        match self.field() {
+            Some(text) => lines.push(Line::from(vec![
+                Span::styled("> ", Style::default().fg(theme.header)),
+                Span::styled(text.to_string(), Style::default().fg(theme.text_primary)),
+                Span::styled("│", Style::default().fg(theme.header)),
+            ])),
+            None => lines.extend(self.review_lines(&theme)),
+        }
+        if let Some(error) = &self.error {
+            lines.push(Line::from(""));
+            lines.push(Line::styled(
+                error.clone(),
+                Style::default().fg(theme.pending),
+            ));
+        }
+
+        f.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .wrap(Wrap { trim: false }),
+            area,
+        );
+    }
+}
+
+fn unit_name(unit: &IndicatorUnit) -> &'static str {
+    match unit {
+        IndicatorUnit::Count => "count",
+        IndicatorUnit::Minutes => "minutes",
+        IndicatorUnit::Dollars => "dollars",
+        IndicatorUnit::Percent => "percent",
+        IndicatorUnit::Custom(_) => "custom units",
+    }
+}
+
+/// `~/x` as a path under the home directory
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), directories::BaseDirs::new()) {
+        (Some(rest), Some(base)) => base.home_dir().join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Run the wizard until it is finished or skipped, and return the config
+/// to open the app with
+pub fn run<B: Backend>(terminal: &mut Terminal<B>, config: Config) -> anyhow::Result<Config> {
+    let mut wizard = FirstRunWizard::new(&config);
+    loop {
+        terminal.draw(|f| wizard.render(f))?;
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return Ok(config);
+        }
+        match wizard.handle_key(key.code) {
+            Some(Finish::Skip) => return Ok(config),
+            Some(Finish::Setup(setup)) => {
+                match onboarding::apply(&setup, &config, onboarding::location_file().as_deref()) {
+                    Ok(config) => return Ok(config),
+                    Err(e) => wizard.error = Some(format!("Setup failed: {:#}", e)),
+                }
+            }
+            None => {}
+        }
+    }
+}
@@ -0,0 +1,133 @@
+/// Score `candidate` against `query` as a case-insensitive subsequence match.
+///
+/// Returns `None` when the query characters don't all appear in order. Higher
+/// scores are better: consecutive runs and matches at word starts are rewarded,
+/// and shorter candidates win ties.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let chars: Vec<char> = candidate.chars().flat_map(char::to_lowercase).collect();
+    let mut score = 0i64;
+    let mut qi = 0;
+    let mut previous_match: Option<usize> = None;
+
+    for (ci, &c) in chars.iter().enumerate() {
+        if qi == query.len() {
+            break;
+        }
+        if c != query[qi] {
+            continue;
+        }
+
+        score += 1;
+        if previous_match.is_some_and(|p| p + 1 == ci) {
+            score += 5;
+        }
+        if ci == 0 || !chars[ci - 1].is_alphanumeric() {
+            score += 3;
+        }
+        previous_match = Some(ci);
+        qi += 1;
+    }
+
+    if qi < query.len() {
+        return None;
+    }
+    Some(score * 100 - chars.len() as i64)
+}
+
+/// Filter and rank `items` by fuzzy match against `query`, best first.
+/// Returns indices into `items` so callers keep their own data.
+pub fn fuzzy_filter<'a, I>(query: &str, items: I) -> Vec<usize>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut scored: Vec<(usize, i64)> = items
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, text)| fuzzy_score(query, text).map(|score| (i, score)))
+        .collect();
+    // Stable sort keeps the original order for equal scores
+    scored.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+    scored.into_iter().map(|(i, _)| i).collect()
+}
+
+/// Words too common to say anything about what an action is for
+const STOP_WORDS: &[&str] = &[
+    "and", "the", "for", "with", "from", "into", "about", "this", "that", "your", "our",
+];
+
+fn keywords(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|word| word.chars().count() >= 3 && !STOP_WORDS.contains(&word.as_str()))
+        .collect()
+}
+
+/// Same word, or one is a longer form of the other ("launch"/"launching")
+fn same_stem(a: &str, b: &str) -> bool {
+    let (short, long) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    short == long || (short.chars().count() >= 4 && long.starts_with(short))
+}
+
+/// Score free `text` (an action) against the keywords of `title` (an
+/// objective). Returns `None` unless at least half of the title's keywords
+/// appear in the text; more matched keywords score higher.
+pub fn keyword_score(text: &str, title: &str) -> Option<i64> {
+    let title_words = keywords(title);
+    if title_words.is_empty() {
+        return None;
+    }
+    let text_words = keywords(text);
+    let matched = title_words
+        .iter()
+        .filter(|title_word| text_words.iter().any(|word| same_stem(word, title_word)))
+        .count();
+    if matched == 0 || matched * 2 < title_words.len() {
+        return None;
+    }
+    Some(matched as i64 * 100 - title_words.len() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_subsequences_case_insensitively() {
+        assert!(fuzzy_score("pu", "Pushups").is_some());
+        assert!(fuzzy_score("dw", "Deep Work minutes").is_some());
+        assert!(fuzzy_score("xyz", "Pushups").is_none());
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn ranks_word_starts_and_runs_higher() {
+        let items = ["Water intake", "Weekly review", "Deep Work minutes"];
+        let ranked = fuzzy_filter("work", items.iter().copied());
+        assert_eq!(ranked[0], 2);
+        assert!(!ranked.contains(&0));
+
+        let ranked = fuzzy_filter("", items.iter().copied());
+        assert_eq!(ranked, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn keywords_match_action_text_to_titles() {
+        let launch = keyword_score("Draft launching notes for the product", "Product launch");
+        assert!(launch.is_some());
+        assert!(keyword_score("Call the product team", "Product launch").is_some());
+        assert!(keyword_score("Call mom", "Product launch").is_none());
+        // One keyword out of three is not enough
+        assert!(keyword_score("Read a novel", "Marathon training novel plan").is_none());
+        assert!(keyword_score("anything", "To do").is_none());
+        assert!(launch > keyword_score("Product review", "Product launch"));
+    }
+}
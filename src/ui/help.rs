@@ -1,121 +1,119 @@
+use crate::ui::keymap::Binding;
 use crate::ui::theme::FocusFiveTheme;
 use ratatui::{
-    layout::{Alignment, Rect},
-    style::Style,
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::Paragraph,
-    Frame,
 };
 
-pub fn render_help(f: &mut Frame, area: Rect, theme: &FocusFiveTheme) {
-    let accent = Style::default().fg(theme.header);
-    let lines = vec![
-        Line::from(vec![
-            Span::styled("j/k", accent),
-            Span::raw(" Navigate  "),
-            Span::styled("Space", accent),
-            Span::raw(" Toggle  "),
-            Span::styled("Enter", accent),
-            Span::raw(" View/Expand  "),
-            Span::styled("Esc", accent),
-            Span::raw(" Close Popups"),
-        ]),
-        Line::from(vec![
-            Span::styled("o", accent),
-            Span::raw(" Objectives  "),
-            Span::styled("i", accent),
-            Span::raw(" Update Indicator  "),
-            Span::styled("v", accent),
-            Span::raw(" Vision  "),
-            Span::styled("d", accent),
-            Span::raw(" Dashboard  "),
-            Span::styled("q", accent),
-            Span::raw(" Quit"),
-        ]),
-    ];
-
-    let help = Paragraph::new(lines)
-        .style(Style::default().fg(theme.text_secondary))
-        .alignment(Alignment::Center);
-
-    f.render_widget(help, area);
+/// The status bar: a badge naming the current panel or mode, then the key
+/// hints for it. Hints are kept whole, starting a new line when one would
+/// not fit in `width`.
+pub fn status_bar_lines(
+    label: &str,
+    hints: &[(String, &str)],
+    width: u16,
+    badge: Style,
+    key: Style,
+) -> Vec<Line<'static>> {
+    let width = usize::from(width);
+    let badge = Span::styled(format!(" {} ", label), badge);
+    let mut used = badge.width() + 1;
+    let mut lines = vec![vec![badge, Span::raw(" ")]];
+    for (keys, hint) in hints {
+        let hint = [
+            Span::styled(keys.clone(), key),
+            Span::raw(format!(" {}  ", hint)),
+        ];
+        let hint_width: usize = hint.iter().map(Span::width).sum();
+        if used + hint_width > width + 2 && used > 0 {
+            lines.push(Vec::new());
+            used = 0;
+        }
+        used += hint_width;
+        lines.last_mut().expect("always one line").extend(hint);
+    }
+    lines.into_iter().map(Line::from).collect()
 }
 
-pub fn render_detailed_help(f: &mut Frame, area: Rect, theme: &FocusFiveTheme) {
-    let help_lines = vec![
-        Line::from(vec![Span::styled(
-            "Keyboard Shortcuts",
-            Style::default().fg(theme.header),
-        )]),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Navigation:",
-            Style::default().fg(theme.text_primary),
-        )]),
-        Line::from(vec![
-            Span::styled("  j           ", Style::default().fg(theme.header)),
-            Span::styled("Move down", Style::default().fg(theme.text_secondary)),
-        ]),
-        Line::from(vec![
-            Span::styled("  k           ", Style::default().fg(theme.header)),
-            Span::styled("Move up", Style::default().fg(theme.text_secondary)),
-        ]),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Actions:",
-            Style::default().fg(theme.text_primary),
-        )]),
-        Line::from(vec![
-            Span::styled("  Space/Enter ", Style::default().fg(theme.header)),
-            Span::styled(
-                "Toggle action completion",
-                Style::default().fg(theme.text_secondary),
-            ),
-        ]),
-        Line::from(vec![
-            Span::styled("  e           ", Style::default().fg(theme.header)),
-            Span::styled(
-                "Edit action text",
-                Style::default().fg(theme.text_secondary),
-            ),
-        ]),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Application:",
-            Style::default().fg(theme.text_primary),
-        )]),
-        Line::from(vec![
-            Span::styled("  q           ", Style::default().fg(theme.header)),
-            Span::styled(
-                "Quit application",
-                Style::default().fg(theme.text_secondary),
-            ),
-        ]),
+/// Body of the `?` overlay: the search line, then `bindings` under their
+/// category headings, skipping the first `scroll` rows. Remapped keys are
+/// marked, and each row names the binding for remapping in settings.json.
+pub fn help_lines(
+    query: &str,
+    bindings: &[&Binding],
+    scroll: usize,
+    width: u16,
+    theme: &FocusFiveTheme,
+) -> Vec<Line<'static>> {
+    let accent = Style::default().fg(theme.header);
+    let muted = Style::default().fg(theme.text_secondary);
+    let mut lines = vec![
+        if query.is_empty() {
+            Line::styled("Type to search", muted)
+        } else {
+            Line::from(vec![
+                Span::styled("Search: ", muted),
+                Span::styled(query.to_string(), Style::default().fg(theme.text_primary)),
+                Span::styled("│", accent),
+            ])
+        },
         Line::from(""),
-        Line::from(vec![
-            Span::styled("Note: ", Style::default().fg(theme.partial)),
-            Span::styled(
-                "Changes are auto-saved",
-                Style::default().fg(theme.text_secondary),
-            ),
-        ]),
     ];
+    if bindings.is_empty() {
+        lines.push(Line::styled("No keys match", muted));
+        return lines;
+    }
 
-    let help = Paragraph::new(help_lines)
-        .style(Style::default())
-        .alignment(Alignment::Left);
-
-    f.render_widget(help, area);
-}
-
-pub fn get_context_help(focused_panel: &str) -> String {
-    match focused_panel {
-        "outcomes" => {
-            "j/k: Select outcome | Space: View details".to_string()
+    let key_width = bindings
+        .iter()
+        .map(|binding| Span::raw(binding.keys_label()).width())
+        .max()
+        .unwrap_or(0)
+        .max(5);
+    let mut body = Vec::new();
+    let mut category = None;
+    for binding in bindings {
+        if category != Some(binding.action.category()) {
+            category = Some(binding.action.category());
+            if !body.is_empty() {
+                body.push(Line::from(""));
+            }
+            body.push(Line::styled(
+                format!("{}:", binding.action.category().label()),
+                Style::default()
+                    .fg(theme.text_primary)
+                    .add_modifier(Modifier::BOLD),
+            ));
         }
-        "actions" => "j/k: Select action | Space: Toggle | e: Edit text"
-            .to_string(),
-        "editor" => "Type to edit | Enter: Save | Esc: Cancel".to_string(),
-        _ => "j/k: Select | Space: Action | q: Quit".to_string(),
+        let keys = if binding.keys.is_empty() {
+            "-".to_string()
+        } else {
+            binding.keys_label()
+        };
+        let description = binding.action.description();
+        let name = if binding.remapped {
+            format!("{} *", binding.setting_name())
+        } else {
+            binding.setting_name()
+        };
+        let used = 2 + key_width + 2 + Span::raw(description).width();
+        let gap = usize::from(width)
+            .saturating_sub(used + Span::raw(name.as_str()).width() + 2)
+            .max(2);
+        body.push(Line::from(vec![
+            Span::styled(format!("  {:<1$}  ", keys, key_width), accent),
+            Span::styled(description.to_string(), muted),
+            Span::raw(" ".repeat(gap)),
+            Span::styled(
+                name,
+                Style::default().fg(if binding.remapped {
+                    theme.partial
+                } else {
+                    theme.border
+                }),
+            ),
+        ]));
     }
+    lines.extend(body.into_iter().skip(scroll));
+    lines
 }
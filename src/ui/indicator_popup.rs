@@ -296,16 +296,16 @@ impl IndicatorDetailPopup {
             .split(area);
 
         let percentage = self.progress.get_percentage();
-        let gauge_color = match percentage {
-            100.. => theme.completed,
-            70..=99 => theme.partial,
-            _ => theme.pending,
-        };
+        let severity = self.progress.severity();
 
         let gauge = Gauge::default()
             .percent(percentage)
-            .label(format!("{}%", percentage))
-            .gauge_style(Style::default().fg(gauge_color).bg(theme.background))
+            .label(format!("{} {}%", severity.symbol(), percentage))
+            .gauge_style(
+                Style::default()
+                    .fg(theme.severity_color(severity))
+                    .bg(theme.background),
+            )
             .block(
                 Block::default()
                     .borders(Borders::ALL)
@@ -605,15 +605,15 @@ impl IndicatorDetailPopup {
         f.render_widget(values, body_chunks[0]);
 
         let gauge_percentage = self.progress.get_percentage();
-        let gauge_color = match gauge_percentage {
-            100.. => theme.completed,
-            70..=99 => theme.partial,
-            _ => theme.pending,
-        };
+        let severity = self.progress.severity();
         let gauge = Gauge::default()
             .percent(gauge_percentage)
-            .label(format!("{}%", gauge_percentage))
-            .gauge_style(Style::default().fg(gauge_color).bg(theme.background))
+            .label(format!("{} {}%", severity.symbol(), gauge_percentage))
+            .gauge_style(
+                Style::default()
+                    .fg(theme.severity_color(severity))
+                    .bg(theme.background),
+            )
             .block(
                 Block::default()
                     .borders(Borders::ALL)
@@ -0,0 +1,609 @@
+//! The one table of key bindings. Key handlers look keys up here and the
+//! status bar and help overlay are generated from it, so none of them can
+//! disagree. Bindings can be remapped in settings.json under `keys`, e.g.
+//! `"goals.snooze": "s"`.
+
+use crossterm::event::KeyCode;
+use std::collections::BTreeMap;
+
+/// Which screen a binding belongs to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    Goals,
+    Focus,
+    Dashboard,
+}
+
+impl Mode {
+    /// Prefix of the binding names used for remaps
+    pub fn name(&self) -> &'static str {
+        match self {
+            Mode::Goals => "goals",
+            Mode::Focus => "focus",
+            Mode::Dashboard => "dashboard",
+        }
+    }
+}
+
+/// Help overlay sections, in display order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Category {
+    Navigation,
+    Actions,
+    Timers,
+    Planning,
+    Review,
+    Indicators,
+    Data,
+    General,
+}
+
+impl Category {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Category::Navigation => "Navigation",
+            Category::Actions => "Actions",
+            Category::Timers => "Timers",
+            Category::Planning => "Planning",
+            Category::Review => "Review",
+            Category::Indicators => "Indicators",
+            Category::Data => "Data",
+            Category::General => "General",
+        }
+    }
+}
+
+/// Where a hint is worth showing; keys still work everywhere in their mode
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scope {
+    All,
+    Outcomes,
+    Actions,
+    /// Dashboard Performance panel
+    Performance,
+    /// Dashboard Alternative Signals panel
+    Signals,
+    /// Either panel of the bottom-right dashboard quadrant
+    Corner,
+    /// Panels that list indicators (Performance, Signals)
+    Indicators,
+    /// Two indicators overlaid in the Performance chart
+    Comparing,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyAction {
+    Quit,
+    CommandLine,
+    ToggleDashboard,
+    SwitchPanel,
+    MoveUp,
+    MoveDown,
+    ToggleDone,
+    Expand,
+    EditAction,
+    EditVision,
+    Objectives,
+    IndicatorLinks,
+    UpdateIndicator,
+    LogMetric,
+    LogAll,
+    RepeatTomorrow,
+    CopySummary,
+    Pomodoro,
+    Stopwatch,
+    ActionTimer,
+    Reflect,
+    DayNote,
+    Snooze,
+    Blocker,
+    Board,
+    Timeline,
+    WeeklyPlan,
+    YearReview,
+    Projects,
+    VisionHistory,
+    QuarterlyCheckIn,
+    Journal,
+    MorningPlan,
+    CloseDay,
+    Templates,
+    Backups,
+    Trash,
+    WeeklyFocusLink,
+    FocusMode,
+    Priority(u32),
+    Retarget,
+    CheckIn,
+    CheckInAnalytics,
+    CompletionTimes,
+    Trophies,
+    Messages,
+    PreviousDay,
+    NextDay,
+    LeaveFocus,
+    Close,
+    PanelLeft,
+    PanelRight,
+    Compare,
+    Intraday,
+    ZoomIn,
+    ZoomOut,
+    PanBack,
+    PanForward,
+    Normalize,
+    HideFromSignals,
+    DeleteIndicator,
+    ToggleCorner,
+    Inspect,
+    Screenshot,
+    RecordMacro,
+    ReplayMacro,
+    Help,
+}
+
+impl KeyAction {
+    /// snake_case name used in remaps: `Priority(2)` is `priority_2`
+    pub fn name(&self) -> String {
+        let mut name = String::new();
+        for c in format!("{:?}", self).chars() {
+            if c.is_ascii_uppercase() {
+                if !name.is_empty() {
+                    name.push('_');
+                }
+                name.push(c.to_ascii_lowercase());
+            } else if c == '(' {
+                name.push('_');
+            } else if c.is_ascii_alphanumeric() {
+                name.push(c);
+            }
+        }
+        name
+    }
+
+    pub fn category(&self) -> Category {
+        use KeyAction::*;
+        match self {
+            MoveUp | MoveDown | SwitchPanel | PreviousDay | NextDay | PanelLeft | PanelRight
+            | FocusMode | LeaveFocus | ToggleDashboard | ToggleCorner => Category::Navigation,
+            ToggleDone | Expand | EditAction | Objectives | IndicatorLinks | Snooze | Blocker
+            | WeeklyFocusLink | Priority(_) | RepeatTomorrow | CopySummary => Category::Actions,
+            Pomodoro | Stopwatch | ActionTimer => Category::Timers,
+            EditVision | MorningPlan | DayNote | WeeklyPlan | Board | Timeline | Projects
+            | Retarget | Templates => Category::Planning,
+            Reflect | CloseDay | YearReview | VisionHistory | QuarterlyCheckIn | Journal
+            | CheckIn | CheckInAnalytics | CompletionTimes | Trophies => Category::Review,
+            UpdateIndicator | LogMetric | LogAll | Compare | Intraday | ZoomIn | ZoomOut
+            | PanBack | PanForward | Normalize | HideFromSignals | DeleteIndicator | Inspect => {
+                Category::Indicators
+            }
+            Backups | Trash | Screenshot => Category::Data,
+            Quit | CommandLine | Messages | Close | Help | RecordMacro | ReplayMacro => {
+                Category::General
+            }
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        use KeyAction::*;
+        match self {
+            Quit => "Quit",
+            CommandLine => "Command line (:sort, :theme, :export ...)",
+            ToggleDashboard => "Switch between planning and the dashboard",
+            SwitchPanel => "Switch between outcomes and actions",
+            MoveUp => "Move up",
+            MoveDown => "Move down",
+            ToggleDone => "Mark the action done or not done",
+            Expand => "Show the action's details",
+            EditAction => "Edit the action's text",
+            EditVision => "Edit the outcome's vision",
+            Objectives => "Link the action to an objective",
+            IndicatorLinks => "Link the action to indicators",
+            UpdateIndicator => "Update the action's indicator",
+            LogMetric => "Log a value for any indicator",
+            LogAll => "Log every indicator in turn",
+            RepeatTomorrow => "Copy today's plan to tomorrow",
+            CopySummary => "Copy today's checklist to the clipboard for a standup",
+            Pomodoro => "Start/stop a Pomodoro focus session",
+            Stopwatch => "Start/stop a stopwatch focus session",
+            ActionTimer => "Track time on the action",
+            Reflect => "Evening reflection, then three things you are grateful for",
+            DayNote => "Note on the day",
+            Snooze => "Snooze the action to a later day",
+            Blocker => "Mark the action as blocked by another",
+            Board => "Kanban board of the week's actions",
+            Timeline => "Timeline of the day",
+            WeeklyPlan => "Plan the week",
+            YearReview => "Year in review",
+            Projects => "Projects and their progress",
+            VisionHistory => "How the visions changed over time",
+            QuarterlyCheckIn => "Quarterly check-in",
+            Journal => "Journal",
+            MorningPlan => "Morning planning wizard",
+            CloseDay => "Close the day, or reopen it",
+            Templates => "Day templates",
+            Backups => "Restore a backup",
+            Trash => "Restore or purge trashed objectives and indicators",
+            WeeklyFocusLink => "Link the action to the week's focus",
+            FocusMode => "Focus mode: only the top action of each outcome",
+            Priority(_) => "Set the action's priority (again to clear)",
+            Retarget => "Set next year's targets",
+            CheckIn => "Energy and mood check-in",
+            CheckInAnalytics => "Check-in trends",
+            CompletionTimes => "When actions get done",
+            Trophies => "Trophies and level",
+            Messages => "Every notification shown this session",
+            PreviousDay => "Previous day",
+            NextDay => "Next day",
+            LeaveFocus => "Back to planning",
+            Close => "Leave compare mode, or close the dashboard",
+            PanelLeft => "Previous panel",
+            PanelRight => "Next panel",
+            Compare => "Compare two indicators",
+            Intraday => "Intra-day chart",
+            ZoomIn => "Zoom the chart in",
+            ZoomOut => "Zoom the chart out",
+            PanBack => "Pan the chart back in time",
+            PanForward => "Pan the chart forward in time",
+            Normalize => "Normalized or dual-axis comparison",
+            HideFromSignals => "Hide or show the indicator in signals",
+            DeleteIndicator => "Move the indicator to the trash",
+            ToggleCorner => "Switch between portfolio and signals",
+            Inspect => "Update the selected indicator",
+            Screenshot => "Save the screen as ANSI text and SVG under exports/",
+            RecordMacro => "Record keys into a register (a-z), or stop recording",
+            ReplayMacro => "Replay a register's keys; @@ replays the last one",
+            Help => "This help",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Binding {
+    pub mode: Mode,
+    pub action: KeyAction,
+    /// The first key is the one shown in hints
+    pub keys: Vec<KeyCode>,
+    pub scope: Scope,
+    /// Status bar label; adjacent bindings with the same label share a hint
+    pub hint: Option<&'static str>,
+    /// Keys changed in settings.json
+    pub remapped: bool,
+}
+
+impl Binding {
+    /// Name to remap it by, e.g. `goals.snooze`
+    pub fn setting_name(&self) -> String {
+        format!("{}.{}", self.mode.name(), self.action.name())
+    }
+
+    /// All of its keys, e.g. `j/↓`
+    pub fn keys_label(&self) -> String {
+        let keys: Vec<String> = self.keys.iter().map(key_label).collect();
+        keys.join("/")
+    }
+
+    fn matches(&self, terms: &[String]) -> bool {
+        let haystack = format!(
+            "{} {} {} {}",
+            self.keys_label(),
+            self.action.description(),
+            self.action.category().label(),
+            self.setting_name()
+        )
+        .to_lowercase();
+        terms.iter().all(|term| haystack.contains(term.as_str()))
+    }
+}
+
+fn bind(
+    mode: Mode,
+    action: KeyAction,
+    keys: &[KeyCode],
+    scope: Scope,
+    hint: Option<&'static str>,
+) -> Binding {
+    Binding {
+        mode,
+        action,
+        keys: keys.to_vec(),
+        scope,
+        hint,
+        remapped: false,
+    }
+}
+
+fn goals_bindings() -> Vec<Binding> {
+    use KeyAction::*;
+    use KeyCode::*;
+    use Scope::*;
+    let goals =
+        |action, keys: &[KeyCode], scope, hint| bind(Mode::Goals, action, keys, scope, hint);
+    vec![
+        goals(MoveDown, &[Char('j'), Down], All, Some("Move")),
+        goals(MoveUp, &[Char('k'), Up], All, Some("Move")),
+        goals(SwitchPanel, &[Tab], All, Some("Switch panel")),
+        goals(ToggleDone, &[Char(' ')], Actions, Some("Done")),
+        goals(Expand, &[Enter, Char('e')], Actions, Some("Expand")),
+        goals(EditAction, &[Char('E')], Actions, Some("Edit")),
+        goals(EditVision, &[Char('v')], Outcomes, Some("Vision")),
+        goals(Objectives, &[Char('o')], Actions, Some("Objective")),
+        goals(IndicatorLinks, &[Char('g')], Actions, Some("Indicators")),
+        goals(UpdateIndicator, &[Char('i')], Actions, Some("Update")),
+        goals(LogMetric, &[Char('+')], All, Some("Log metric")),
+        goals(LogAll, &[Char('L')], Outcomes, Some("Log all")),
+        goals(ActionTimer, &[Char('t')], Actions, Some("Track time")),
+        goals(Pomodoro, &[Char('p')], All, Some("Focus timer")),
+        goals(Stopwatch, &[Char('P')], All, Some("Focus timer")),
+        goals(Priority(1), &[Char('1')], Actions, Some("Priority")),
+        goals(Priority(2), &[Char('2')], Actions, Some("Priority")),
+        goals(Priority(3), &[Char('3')], Actions, Some("Priority")),
+        goals(Snooze, &[Char('z')], Actions, Some("Snooze")),
+        goals(Blocker, &[Char('B')], Actions, Some("Blocked by")),
+        goals(WeeklyFocusLink, &[Char('w')], Actions, Some("Weekly focus")),
+        goals(FocusMode, &[Char('f')], All, Some("Focus mode")),
+        goals(MorningPlan, &[Char('I')], Outcomes, Some("Plan day")),
+        goals(Reflect, &[Char('S')], Outcomes, Some("Reflect")),
+        goals(
+            RepeatTomorrow,
+            &[Char('C')],
+            Outcomes,
+            Some("Repeat tomorrow"),
+        ),
+        goals(CopySummary, &[Char('Y')], Outcomes, Some("Copy")),
+        goals(DayNote, &[Char('N')], Outcomes, Some("Note")),
+        goals(CloseDay, &[Char('X')], Outcomes, Some("Close day")),
+        goals(PreviousDay, &[PageUp], Outcomes, Some("Day")),
+        goals(NextDay, &[PageDown], Outcomes, Some("Day")),
+        goals(Board, &[Char('K')], All, None),
+        goals(Timeline, &[Char('T')], All, None),
+        goals(WeeklyPlan, &[Char('W')], All, None),
+        goals(YearReview, &[Char('A')], All, None),
+        goals(Projects, &[Char('G')], All, None),
+        goals(VisionHistory, &[Char('V')], All, None),
+        goals(QuarterlyCheckIn, &[Char('Q')], All, None),
+        goals(Journal, &[Char('J')], All, None),
+        goals(Templates, &[Char('D')], All, None),
+        goals(Backups, &[Char('U')], All, None),
+        goals(Trash, &[Char('Z')], All, None),
+        goals(Retarget, &[Char('R')], All, None),
+        goals(CheckIn, &[Char('m')], All, None),
+        goals(CheckInAnalytics, &[Char('M')], All, None),
+        goals(CompletionTimes, &[Char('H')], All, None),
+        goals(Trophies, &[Char('O')], All, None),
+        goals(ToggleDashboard, &[Char('d')], All, Some("Dashboard")),
+        goals(Screenshot, &[F(12)], All, None),
+        goals(RecordMacro, &[Char('r')], All, None),
+        goals(ReplayMacro, &[Char('@')], All, None),
+        goals(Messages, &[Char('!')], All, Some("Messages")),
+        goals(CommandLine, &[Char(':')], All, Some("Command")),
+        goals(Help, &[Char('?')], All, Some("Help")),
+        goals(Quit, &[Char('q')], All, Some("Quit")),
+    ]
+}
+
+fn focus_bindings() -> Vec<Binding> {
+    use KeyAction::*;
+    use KeyCode::*;
+    use Scope::*;
+    let focus = |action, keys: &[KeyCode], hint| bind(Mode::Focus, action, keys, All, hint);
+    vec![
+        focus(MoveDown, &[Char('j'), Down, Tab], Some("Choose")),
+        focus(MoveUp, &[Char('k'), Up], Some("Choose")),
+        focus(ToggleDone, &[Char(' ')], Some("Done")),
+        focus(ActionTimer, &[Char('t')], Some("Track time")),
+        focus(Pomodoro, &[Char('p')], Some("Focus timer")),
+        focus(Stopwatch, &[Char('P')], Some("Focus timer")),
+        focus(LeaveFocus, &[Esc, Char('f')], Some("Back to planning")),
+        focus(Screenshot, &[F(12)], None),
+        focus(RecordMacro, &[Char('r')], None),
+        focus(ReplayMacro, &[Char('@')], None),
+        focus(CommandLine, &[Char(':')], Some("Command")),
+        focus(Help, &[Char('?')], Some("Help")),
+        focus(Quit, &[Char('q')], Some("Quit")),
+    ]
+}
+
+fn dashboard_bindings() -> Vec<Binding> {
+    use KeyAction::*;
+    use KeyCode::*;
+    use Scope::*;
+    let dashboard =
+        |action, keys: &[KeyCode], scope, hint| bind(Mode::Dashboard, action, keys, scope, hint);
+    vec![
+        dashboard(PanelLeft, &[Char('h'), Left], All, Some("Panels")),
+        dashboard(PanelRight, &[Char('l'), Right], All, Some("Panels")),
+        dashboard(MoveDown, &[Char('j'), Down], All, Some("Move")),
+        dashboard(MoveUp, &[Char('k'), Up], All, Some("Move")),
+        dashboard(Inspect, &[Enter, Char('i')], Signals, Some("Update")),
+        dashboard(Compare, &[Char('c')], Performance, Some("Compare two")),
+        dashboard(
+            Normalize,
+            &[Char('n')],
+            Comparing,
+            Some("Normalize/Dual axis"),
+        ),
+        dashboard(Intraday, &[Char('t')], Performance, Some("Intra-day")),
+        dashboard(ZoomIn, &[Char('z')], Performance, Some("Zoom")),
+        dashboard(ZoomOut, &[Char('Z')], Performance, Some("Zoom")),
+        dashboard(PanBack, &[Char('[')], Performance, Some("Pan")),
+        dashboard(PanForward, &[Char(']')], Performance, Some("Pan")),
+        dashboard(
+            HideFromSignals,
+            &[Char('x')],
+            Indicators,
+            Some("Hide/Show in signals"),
+        ),
+        dashboard(DeleteIndicator, &[Delete], Indicators, Some("Trash")),
+        dashboard(
+            ToggleCorner,
+            &[Char('a')],
+            Corner,
+            Some("Portfolio/Signals"),
+        ),
+        dashboard(
+            ToggleDashboard,
+            &[Char('d')],
+            All,
+            Some("Back to FocusFive"),
+        ),
+        dashboard(Close, &[Esc], All, Some("Close")),
+        dashboard(Screenshot, &[F(12)], All, None),
+        dashboard(RecordMacro, &[Char('r')], All, None),
+        dashboard(ReplayMacro, &[Char('@')], All, None),
+        dashboard(CommandLine, &[Char(':')], All, Some("Command")),
+        dashboard(Help, &[Char('?')], All, Some("Help")),
+        dashboard(Quit, &[Char('q')], All, Some("Quit")),
+    ]
+}
+
+/// How a key reads in hints
+pub fn key_label(key: &KeyCode) -> String {
+    match key {
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Right => "→".to_string(),
+        KeyCode::PageUp => "PgUp".to_string(),
+        KeyCode::PageDown => "PgDn".to_string(),
+        KeyCode::Delete => "Del".to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        other => format!("{:?}", other),
+    }
+}
+
+/// A key as written in settings.json: a single character, or a name such
+/// as `Space`, `Enter`, `Esc`, `Tab`, `Up`, `PgDn`, `Del` or `F2`
+pub fn parse_key(text: &str) -> Option<KeyCode> {
+    let mut chars = text.chars();
+    if let (Some(c), None) = (chars.next(), chars.clone().next()) {
+        return Some(KeyCode::Char(c));
+    }
+    let key = match text.to_ascii_lowercase().as_str() {
+        "space" => KeyCode::Char(' '),
+        "enter" | "return" => KeyCode::Enter,
+        "esc" | "escape" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backspace" => KeyCode::Backspace,
+        "del" | "delete" => KeyCode::Delete,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pgup" | "pageup" => KeyCode::PageUp,
+        "pgdn" | "pagedown" => KeyCode::PageDown,
+        lower => {
+            let n = lower.strip_prefix('f')?.parse().ok()?;
+            KeyCode::F(n)
+        }
+    };
+    Some(key)
+}
+
+pub struct Keymap {
+    bindings: Vec<Binding>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let mut bindings = goals_bindings();
+        bindings.extend(focus_bindings());
+        bindings.extend(dashboard_bindings());
+        Self { bindings }
+    }
+}
+
+impl Keymap {
+    /// The defaults with the `keys` remaps from settings.json applied, and
+    /// a warning for each remap that could not be
+    pub fn with_overrides(overrides: &BTreeMap<String, String>) -> (Self, Vec<String>) {
+        let mut keymap = Self::default();
+        let mut warnings = Vec::new();
+        for (name, keys) in overrides {
+            let Some(index) = keymap
+                .bindings
+                .iter()
+                .position(|binding| binding.setting_name() == *name)
+            else {
+                warnings.push(format!("Unknown key binding '{}' in settings", name));
+                continue;
+            };
+            let mut parsed = Vec::new();
+            for key in keys.split_whitespace() {
+                match parse_key(key) {
+                    Some(code) => parsed.push(code),
+                    None => warnings.push(format!("Unknown key '{}' for {}", key, name)),
+                }
+            }
+            if parsed.is_empty() {
+                continue;
+            }
+
+            // A key can only do one thing per mode
+            let mode = keymap.bindings[index].mode;
+            for binding in keymap.bindings.iter_mut() {
+                if binding.mode == mode {
+                    binding.keys.retain(|key| !parsed.contains(key));
+                }
+            }
+            let binding = &mut keymap.bindings[index];
+            binding.keys = parsed;
+            binding.remapped = true;
+        }
+        (keymap, warnings)
+    }
+
+    /// Bindings in `mode` matching every word of `query`, by category
+    pub fn search(&self, mode: Mode, query: &str) -> Vec<&Binding> {
+        let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+        let mut found: Vec<&Binding> = self
+            .bindings
+            .iter()
+            .filter(|binding| binding.mode == mode && binding.matches(&terms))
+            .collect();
+        // Stable, so table order is kept within a category
+        found.sort_by_key(|binding| binding.action.category());
+        found
+    }
+
+    /// What `key` does in `mode`
+    pub fn action(&self, mode: Mode, key: KeyCode) -> Option<KeyAction> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.mode == mode && binding.keys.contains(&key))
+            .map(|binding| binding.action)
+    }
+
+    /// Status bar hints for `mode` as (keys, label), in table order, keeping
+    /// those whose scope `applies`
+    pub fn hints(
+        &self,
+        mode: Mode,
+        applies: impl Fn(Scope) -> bool,
+    ) -> Vec<(String, &'static str)> {
+        let mut hints: Vec<(String, &'static str)> = Vec::new();
+        for binding in &self.bindings {
+            let Some(label) = binding.hint else {
+                continue;
+            };
+            if binding.mode != mode || !applies(binding.scope) {
+                continue;
+            }
+            let Some(key) = binding.keys.first().map(key_label) else {
+                continue;
+            };
+            match hints.last_mut() {
+                Some((keys, last)) if *last == label => {
+                    keys.push('/');
+                    keys.push_str(&key);
+                }
+                _ => hints.push((key, label)),
+            }
+        }
+        hints
+    }
+}
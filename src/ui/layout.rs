@@ -1,37 +1,71 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 
+/// Below this many columns the stats column is dropped and the outcomes
+/// stack above the actions
+pub const NARROW_WIDTH: u16 = 100;
+/// Below this many rows the header shrinks to a single unbordered line
+pub const SHORT_HEIGHT: u16 = 30;
+
 pub struct AppLayout {
     pub header: Rect,
     pub outcomes: Rect,
     pub actions: Rect,
-    pub stats: Rect,
+    /// `None` when the terminal is too narrow for a third column
+    pub stats: Option<Rect>,
     pub footer: Rect,
+    /// The header has one line and no border
+    pub compact_header: bool,
 }
 
 pub fn create_layout(area: Rect) -> AppLayout {
+    let narrow = area.width < NARROW_WIDTH;
+    let short = area.height < SHORT_HEIGHT;
+
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3), // Header
-            Constraint::Min(10),   // Content
-            Constraint::Length(2), // Footer
+            Constraint::Length(if short { 1 } else { 3 }), // Header
+            Constraint::Min(if short { 5 } else { 10 }),   // Content
+            Constraint::Length(2),                         // Footer
         ])
         .split(area);
 
-    let content_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage(25), // Outcomes
-            Constraint::Percentage(45), // Actions
-            Constraint::Percentage(30), // Stats
-        ])
-        .split(main_chunks[1]);
+    let (outcomes, actions, stats) = if !narrow {
+        let content_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(25), // Outcomes
+                Constraint::Percentage(45), // Actions
+                Constraint::Percentage(30), // Stats
+            ])
+            .split(main_chunks[1]);
+        (
+            content_chunks[0],
+            content_chunks[1],
+            Some(content_chunks[2]),
+        )
+    } else if !short {
+        // Three outcome rows plus borders, with the actions underneath
+        let content_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(5), Constraint::Min(5)])
+            .split(main_chunks[1]);
+        (content_chunks[0], content_chunks[1], None)
+    } else {
+        // Too short to stack as well, so the two columns share the width
+        let content_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+            .split(main_chunks[1]);
+        (content_chunks[0], content_chunks[1], None)
+    };
 
     AppLayout {
         header: main_chunks[0],
-        outcomes: content_chunks[0],
-        actions: content_chunks[1],
-        stats: content_chunks[2],
+        outcomes,
+        actions,
+        stats,
         footer: main_chunks[2],
+        compact_header: short,
     }
 }
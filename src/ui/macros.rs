@@ -0,0 +1,83 @@
+//! Keyboard macros, vim style: `r` and a register letter records the keys
+//! that follow until `r` again; `@` and the letter replays them, and `@@`
+//! the register replayed last. Keys are recorded as they reach
+//! `App::handle_key_event`, so text typed into editors and popups replays
+//! too. Registers last for the session.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use std::collections::HashMap;
+
+/// Replays started from within replays before giving up, so a macro that
+/// replays itself stops
+pub const MAX_DEPTH: usize = 10;
+
+/// Waiting for the register after `r` or `@`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pending {
+    Record,
+    Replay,
+}
+
+#[derive(Debug, Default)]
+pub struct Macros {
+    registers: HashMap<char, Vec<KeyEvent>>,
+    recording: Option<(char, Vec<KeyEvent>)>,
+    pub pending: Option<Pending>,
+    last_replayed: Option<char>,
+    /// Replays in progress; keys they send are not recorded again
+    pub depth: usize,
+    /// A replay went past `MAX_DEPTH`; every replay in progress stops
+    pub overflowed: bool,
+}
+
+impl Macros {
+    /// Register being recorded into
+    pub fn recording(&self) -> Option<char> {
+        self.recording.as_ref().map(|(register, _)| *register)
+    }
+
+    /// Add a key to the recording, unless it comes from a replay
+    pub fn record(&mut self, key: KeyEvent) {
+        if self.depth > 0 {
+            return;
+        }
+        if let Some((_, keys)) = &mut self.recording {
+            keys.push(key);
+        }
+    }
+
+    pub fn start(&mut self, register: char) {
+        self.recording = Some((register, Vec::new()));
+    }
+
+    /// Stop recording and store the keys, dropping the one that stopped it;
+    /// returns the register and how many keys it holds
+    pub fn stop(&mut self) -> Option<(char, usize)> {
+        let (register, mut keys) = self.recording.take()?;
+        keys.pop();
+        let count = keys.len();
+        self.registers.insert(register, keys);
+        Some((register, count))
+    }
+
+    /// Keys to replay for `register`, `@` meaning the last one replayed
+    pub fn keys(&mut self, register: char) -> Option<(char, Vec<KeyEvent>)> {
+        let register = if register == '@' {
+            self.last_replayed?
+        } else {
+            register
+        };
+        let keys = self.registers.get(&register)?.clone();
+        self.last_replayed = Some(register);
+        Some((register, keys))
+    }
+}
+
+/// Register named by the key after `r` or `@`: a letter, or `@` on replay
+pub fn register_for(key: KeyCode, pending: Pending) -> Option<char> {
+    match key {
+        KeyCode::Char(c) if c.is_ascii_lowercase() => Some(c),
+        KeyCode::Char('@') if pending == Pending::Replay => Some('@'),
+        _ => None,
+    }
+}
@@ -1,18 +1,23 @@
 pub mod app;
 pub mod charts;
 pub mod dashboard_layout;
-pub mod error;
+pub mod first_run;
+pub mod fuzzy;
 pub mod help;
 pub mod indicator_popup;
+pub mod keymap;
 pub mod layout;
+pub mod macros;
 pub mod popup;
+pub mod snapshot;
 pub mod stats;
 pub mod terminal;
 pub mod theme;
+pub mod toast;
+pub mod wrap;
 
 pub use app::App;
 pub use dashboard_layout::DashboardLayout;
-pub use error::{ErrorDisplay, ErrorLevel};
 pub use indicator_popup::IndicatorDetailPopup;
 pub use layout::{create_layout, AppLayout};
 pub use popup::{EditorResult, TextEditor};
@@ -1,8 +1,9 @@
 use crate::ui::theme::FocusFiveTheme;
-use crossterm::event::KeyCode;
+use crate::ui::wrap::wrap_spans;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::Style,
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
@@ -13,14 +14,24 @@ pub enum EditorResult {
     Continue,
     Save,
     Cancel,
+    /// Ctrl+E: continue in $EDITOR
+    External,
 }
 
 pub struct TextEditor {
     pub text: String,
     pub cursor_position: usize,
+    /// Soft limit: longer text is allowed once the user confirms
     pub max_length: usize,
     pub is_active: bool,
     pub title: String,
+    /// Byte offset where a Shift selection started; the selection runs
+    /// from here to `cursor_position`
+    pub selection_anchor: Option<usize>,
+    /// Enter was pressed while over the limit; the next Enter saves anyway
+    pub override_pending: bool,
+    /// The text can be handed to $EDITOR with Ctrl+E
+    pub allow_external: bool,
 }
 
 impl TextEditor {
@@ -31,21 +42,29 @@ impl TextEditor {
             max_length: 500,
             is_active: false,
             title: default_title.to_string(),
+            selection_anchor: None,
+            override_pending: false,
+            allow_external: false,
         }
     }
 
-    pub fn activate(&mut self, text: &str) {
-        let current_title = self.title.clone();
-        let current_max = self.max_length;
-        self.activate_with(&current_title, text, current_max);
-    }
-
     pub fn activate_with(&mut self, title: &str, text: &str, max_length: usize) {
         self.title = title.to_string();
         self.text = text.to_string();
         self.cursor_position = text.len();
+        self.selection_anchor = None;
         self.max_length = max_length;
         self.is_active = true;
+        self.override_pending = false;
+        self.allow_external = false;
+    }
+
+    pub fn char_count(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    pub fn is_over_limit(&self) -> bool {
+        self.char_count() > self.max_length
     }
 
     pub fn deactivate(&mut self) {
@@ -53,81 +72,250 @@ impl TextEditor {
     }
 
     pub fn handle_input(&mut self, key: KeyCode) -> EditorResult {
-        match key {
+        self.handle_key_event(KeyEvent::new(key, KeyModifiers::NONE))
+    }
+
+    /// Keys with modifiers: Shift extends the selection, Ctrl or Alt with
+    /// an arrow jumps a word, Ctrl+Backspace deletes a word
+    pub fn handle_key_event(&mut self, key: KeyEvent) -> EditorResult {
+        let select = key.modifiers.contains(KeyModifiers::SHIFT);
+        let word = key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+        match key.code {
             KeyCode::Esc => return EditorResult::Cancel,
-            KeyCode::Enter => return EditorResult::Save,
+            KeyCode::Enter => {
+                // Past the soft limit, saving takes a second Enter
+                if self.is_over_limit() && !self.override_pending {
+                    self.override_pending = true;
+                    return EditorResult::Continue;
+                }
+                return EditorResult::Save;
+            }
+            KeyCode::Backspace if word && self.selection().is_none() => {
+                self.selection_anchor = Some(self.word_start(self.cursor_position));
+                self.delete_selection();
+            }
             KeyCode::Backspace => self.delete_char(),
-            KeyCode::Left => self.move_cursor_left(),
-            KeyCode::Right => self.move_cursor_right(),
-            KeyCode::Char(c) => self.insert_char(c),
+            KeyCode::Delete => self.delete_char_forward(),
+            KeyCode::Left => {
+                let target = if word {
+                    self.word_start(self.cursor_position)
+                } else {
+                    match self.selection() {
+                        Some((start, _)) if !select => start,
+                        _ => self.prev_boundary(self.cursor_position),
+                    }
+                };
+                self.move_cursor(target, select);
+            }
+            KeyCode::Right => {
+                let target = if word {
+                    self.word_end(self.cursor_position)
+                } else {
+                    match self.selection() {
+                        Some((_, end)) if !select => end,
+                        _ => self.next_boundary(self.cursor_position),
+                    }
+                };
+                self.move_cursor(target, select);
+            }
+            KeyCode::Home => self.move_cursor(0, select),
+            KeyCode::End => self.move_cursor(self.text.len(), select),
+            KeyCode::Char('e')
+                if key.modifiers.contains(KeyModifiers::CONTROL) && self.allow_external =>
+            {
+                return EditorResult::External;
+            }
+            KeyCode::Char('a') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.selection_anchor = Some(0);
+                self.cursor_position = self.text.len();
+            }
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.insert_str(c.encode_utf8(&mut [0; 4]))
+            }
             _ => {}
         }
         EditorResult::Continue
     }
 
-    fn insert_char(&mut self, c: char) {
-        if self.text.len() < self.max_length {
-            self.text.insert(self.cursor_position, c);
-            self.cursor_position += 1;
+    /// Insert pasted text at the cursor, replacing any selection. The
+    /// editor is single-line, so line breaks become spaces.
+    pub fn paste(&mut self, text: &str) {
+        let text = text
+            .trim_end_matches(['\r', '\n'])
+            .replace("\r\n", " ")
+            .replace(['\r', '\n', '\t'], " ");
+        self.insert_str(&text);
+    }
+
+    /// The selected byte range, if anything is selected
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        let anchor = self.selection_anchor?;
+        let cursor = self.cursor_position;
+        (anchor != cursor).then(|| (anchor.min(cursor), anchor.max(cursor)))
+    }
+
+    fn move_cursor(&mut self, target: usize, select: bool) {
+        if select {
+            self.selection_anchor.get_or_insert(self.cursor_position);
+        } else {
+            self.selection_anchor = None;
         }
+        self.cursor_position = target;
+    }
+
+    fn insert_str(&mut self, text: &str) {
+        self.delete_selection();
+        self.text.insert_str(self.cursor_position, text);
+        self.cursor_position += text.len();
+        self.override_pending = false;
+    }
+
+    /// Remove the selected text; false when nothing was selected
+    fn delete_selection(&mut self) -> bool {
+        let selection = self.selection();
+        self.selection_anchor = None;
+        let Some((start, end)) = selection else {
+            return false;
+        };
+        self.text.replace_range(start..end, "");
+        self.cursor_position = start;
+        self.override_pending = false;
+        true
     }
 
     fn delete_char(&mut self) {
-        if self.cursor_position > 0 {
-            self.cursor_position -= 1;
+        if !self.delete_selection() && self.cursor_position > 0 {
+            self.override_pending = false;
+            self.cursor_position = self.prev_boundary(self.cursor_position);
             self.text.remove(self.cursor_position);
         }
     }
 
-    fn move_cursor_left(&mut self) {
-        if self.cursor_position > 0 {
-            self.cursor_position -= 1;
+    fn delete_char_forward(&mut self) {
+        if !self.delete_selection() && self.cursor_position < self.text.len() {
+            self.override_pending = false;
+            self.text.remove(self.cursor_position);
         }
     }
 
-    fn move_cursor_right(&mut self) {
-        if self.cursor_position < self.text.len() {
-            self.cursor_position += 1;
-        }
+    fn prev_boundary(&self, from: usize) -> usize {
+        self.text[..from]
+            .char_indices()
+            .next_back()
+            .map_or(0, |(i, _)| i)
+    }
+
+    fn next_boundary(&self, from: usize) -> usize {
+        self.text[from..]
+            .chars()
+            .next()
+            .map_or(from, |c| from + c.len_utf8())
+    }
+
+    /// Start of the word before `from`, skipping the spaces in between
+    fn word_start(&self, from: usize) -> usize {
+        let before = self.text[..from].trim_end();
+        before
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_whitespace())
+            .map_or(0, |(i, c)| i + c.len_utf8())
+    }
+
+    /// End of the word after `from`, skipping the spaces in between
+    fn word_end(&self, from: usize) -> usize {
+        let after = &self.text[from..];
+        let word = after.len() - after.trim_start().len();
+        after[word..]
+            .char_indices()
+            .find(|(_, c)| c.is_whitespace())
+            .map_or(self.text.len(), |(i, _)| from + word + i)
     }
 
     pub fn render(&self, f: &mut Frame, theme: &FocusFiveTheme) {
-        let area = centered_rect(60, 20, f.area());
+        let mut area = centered_rect(60, 20, f.area());
 
-        // Clear background
-        f.render_widget(Clear, area);
+        // Text with the cursor drawn as a bar and the selection reversed
+        let cursor = self.cursor_position.min(self.text.len());
+        let (start, end) = self.selection().unwrap_or((cursor, cursor));
+        let selected = Style::default().add_modifier(Modifier::REVERSED);
+        let bar = Span::styled("│", Style::default().fg(theme.header));
+        let mut spans = vec![Span::raw(&self.text[..start])];
+        if cursor == start {
+            spans.push(bar.clone());
+        }
+        spans.push(Span::styled(&self.text[start..end], selected));
+        if cursor != start {
+            spans.push(bar);
+        }
+        spans.push(Span::raw(&self.text[end..]));
 
-        // Create text with cursor indicator
-        let mut display_text = self.text.clone();
-        if self.cursor_position <= display_text.len() {
-            display_text.insert(self.cursor_position, '│');
+        // Grow the popup so long text wraps in full above the counter
+        let text_lines = wrap_spans(spans, usize::from(area.width.saturating_sub(2)), 0);
+        let needed = (text_lines.len() as u16 + 7).min(f.area().height);
+        if needed > area.height {
+            area.y = f.area().y + (f.area().height - needed) / 2;
+            area.height = needed;
         }
 
-        // Character count display
-        let char_count = format!("{}/{}", self.text.len(), self.max_length);
-        let char_color = if self.text.len() > self.max_length - 50 {
+        // Clear background
+        f.render_widget(Clear, area);
+
+        // Live character count; past the limit it turns into a warning
+        let count = self.char_count();
+        let char_count = format!("{}/{}", count, self.max_length);
+        let char_color = if self.is_over_limit() {
+            theme.pending
+        } else if count > self.max_length.saturating_sub(50) {
             theme.partial
         } else {
             theme.text_secondary
         };
+        let mut counter = vec![
+            Span::raw("Characters: "),
+            Span::styled(char_count, Style::default().fg(char_color)),
+        ];
+        if self.is_over_limit() {
+            let warning = if self.override_pending {
+                format!("  Press Enter again to save all {} characters", count)
+            } else {
+                format!(
+                    "  ▲ {} over the limit: shorten it or press Enter twice",
+                    count - self.max_length
+                )
+            };
+            counter.push(Span::styled(warning, Style::default().fg(theme.pending)));
+        }
 
         // Create the popup content
-        let content = vec![
-            Line::from(""),
-            Line::from(display_text),
+        let mut keys = vec![
+            Span::styled("[Enter]", Style::default().fg(theme.header)),
+            Span::raw(" Save  "),
+            Span::styled("[Esc]", Style::default().fg(theme.header)),
+            Span::raw(" Cancel  "),
+            Span::styled("[Shift+←/→]", Style::default().fg(theme.header)),
+            Span::raw(" Select  "),
+            Span::styled("[Ctrl+←/→]", Style::default().fg(theme.header)),
+            Span::raw(" Word"),
+        ];
+        if self.allow_external {
+            keys.push(Span::styled(
+                "  [Ctrl+E]",
+                Style::default().fg(theme.header),
+            ));
+            keys.push(Span::raw(" $EDITOR"));
+        }
+
+        let mut content = vec![Line::from("")];
+        content.extend(text_lines);
+        content.extend([
             Line::from(""),
-            Line::from(vec![
-                Span::raw("Characters: "),
-                Span::styled(char_count, Style::default().fg(char_color)),
-            ]),
+            Line::from(counter),
             Line::from(""),
-            Line::from(vec![
-                Span::styled("[Enter]", Style::default().fg(theme.header)),
-                Span::raw(" Save  "),
-                Span::styled("[Esc]", Style::default().fg(theme.header)),
-                Span::raw(" Cancel"),
-            ]),
-        ];
+            Line::from(keys),
+        ]);
 
         // Render popup
         let popup = Paragraph::new(content)
@@ -0,0 +1,275 @@
+//! Snapshots of the screen for sharing: a rendered frame saved as ANSI text
+//! (`cat` it in a terminal) and as SVG, under `exports/` in the data root
+
+use crate::models::Config;
+use anyhow::{Context, Result};
+use chrono::NaiveDateTime;
+use ratatui::{
+    buffer::{Buffer, Cell},
+    style::{Color, Modifier},
+    text::Span,
+};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// SVG cell size in pixels, for a 14px monospace font
+const CELL_WIDTH: f32 = 8.4;
+const CELL_HEIGHT: f32 = 18.0;
+const FONT_SIZE: u32 = 14;
+
+/// How many columns a cell's symbol covers; the cells it hides are skipped
+fn cell_width(cell: &Cell) -> usize {
+    Span::raw(cell.symbol()).width().max(1)
+}
+
+/// Each row as runs of cells that share a style, with the column each
+/// run starts at
+fn runs(buffer: &Buffer) -> Vec<Vec<(u16, Vec<&Cell>)>> {
+    let area = buffer.area;
+    let mut rows = Vec::new();
+    for y in area.top()..area.bottom() {
+        let mut row: Vec<(u16, Vec<&Cell>)> = Vec::new();
+        let mut x = area.left();
+        while x < area.right() {
+            let cell = &buffer[(x, y)];
+            match row.last_mut() {
+                Some((_, cells))
+                    if cells[0].fg == cell.fg
+                        && cells[0].bg == cell.bg
+                        && cells[0].modifier == cell.modifier =>
+                {
+                    cells.push(cell)
+                }
+                _ => row.push((x - area.left(), vec![cell])),
+            }
+            x += cell_width(cell) as u16;
+        }
+        rows.push(row);
+    }
+    rows
+}
+
+fn sgr_color(color: Color, background: bool) -> Option<String> {
+    let base = if background { 40 } else { 30 };
+    let code = match color {
+        Color::Reset => return None,
+        Color::Black => base,
+        Color::Red => base + 1,
+        Color::Green => base + 2,
+        Color::Yellow => base + 3,
+        Color::Blue => base + 4,
+        Color::Magenta => base + 5,
+        Color::Cyan => base + 6,
+        Color::Gray => base + 7,
+        Color::DarkGray => base + 60,
+        Color::LightRed => base + 61,
+        Color::LightGreen => base + 62,
+        Color::LightYellow => base + 63,
+        Color::LightBlue => base + 64,
+        Color::LightMagenta => base + 65,
+        Color::LightCyan => base + 66,
+        Color::White => base + 67,
+        Color::Indexed(i) => return Some(format!("{};5;{}", base + 8, i)),
+        Color::Rgb(r, g, b) => return Some(format!("{};2;{};{};{}", base + 8, r, g, b)),
+    };
+    Some(code.to_string())
+}
+
+const SGR_MODIFIERS: [(Modifier, u8); 6] = [
+    (Modifier::BOLD, 1),
+    (Modifier::DIM, 2),
+    (Modifier::ITALIC, 3),
+    (Modifier::UNDERLINED, 4),
+    (Modifier::REVERSED, 7),
+    (Modifier::CROSSED_OUT, 9),
+];
+
+/// The frame as text with ANSI colour escapes, one line per row
+pub fn to_ansi(buffer: &Buffer) -> String {
+    let mut out = String::new();
+    for row in runs(buffer) {
+        for (_, cells) in row {
+            let style = cells[0];
+            let mut codes = vec!["0".to_string()];
+            codes.extend(sgr_color(style.fg, false));
+            codes.extend(sgr_color(style.bg, true));
+            for (modifier, code) in SGR_MODIFIERS {
+                if style.modifier.contains(modifier) {
+                    codes.push(code.to_string());
+                }
+            }
+            let _ = write!(out, "\x1b[{}m", codes.join(";"));
+            for cell in cells {
+                out.push_str(cell.symbol());
+            }
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    out
+}
+
+/// `color` as `#rrggbb`, using the xterm palette for named and indexed
+/// colours; `Reset` is `default`
+fn hex(color: Color, default: Color) -> String {
+    const PALETTE: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (205, 0, 0),
+        (0, 205, 0),
+        (205, 205, 0),
+        (0, 0, 238),
+        (205, 0, 205),
+        (0, 205, 205),
+        (229, 229, 229),
+        (127, 127, 127),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (92, 92, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+    let (r, g, b) = match color {
+        Color::Reset => return hex(default, Color::White),
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(i) if i < 16 => PALETTE[i as usize],
+        Color::Indexed(i) if i < 232 => {
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            let i = i - 16;
+            (level(i / 36), level(i / 6 % 6), level(i % 6))
+        }
+        Color::Indexed(i) => {
+            let v = 8 + (i - 232) * 10;
+            (v, v, v)
+        }
+        named => {
+            let index = match named {
+                Color::Black => 0,
+                Color::Red => 1,
+                Color::Green => 2,
+                Color::Yellow => 3,
+                Color::Blue => 4,
+                Color::Magenta => 5,
+                Color::Cyan => 6,
+                Color::Gray => 7,
+                Color::DarkGray => 8,
+                Color::LightRed => 9,
+                Color::LightGreen => 10,
+                Color::LightYellow => 11,
+                Color::LightBlue => 12,
+                Color::LightMagenta => 13,
+                Color::LightCyan => 14,
+                _ => 15,
+            };
+            PALETTE[index]
+        }
+    };
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// The frame as an SVG image; cells without a colour of their own use
+/// `fg` and `bg`
+pub fn to_svg(buffer: &Buffer, fg: Color, bg: Color) -> String {
+    let width = buffer.area.width as f32 * CELL_WIDTH;
+    let height = buffer.area.height as f32 * CELL_HEIGHT;
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{:.0}\" height=\"{:.0}\" \
+         viewBox=\"0 0 {:.1} {:.1}\" font-family=\"Menlo, Consolas, 'DejaVu Sans Mono', monospace\" \
+         font-size=\"{}\" xml:space=\"preserve\">",
+        width.ceil(),
+        height.ceil(),
+        width,
+        height,
+        FONT_SIZE
+    );
+    let _ = writeln!(
+        svg,
+        "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>",
+        hex(bg, Color::Black)
+    );
+    for (y, row) in runs(buffer).iter().enumerate() {
+        let top = y as f32 * CELL_HEIGHT;
+        for (x, cells) in row {
+            let style = cells[0];
+            let (mut cell_fg, mut cell_bg) = (hex(style.fg, fg), hex(style.bg, bg));
+            if style.modifier.contains(Modifier::REVERSED) {
+                std::mem::swap(&mut cell_fg, &mut cell_bg);
+            }
+            let left = *x as f32 * CELL_WIDTH;
+            let columns: usize = cells.iter().map(|cell| cell_width(cell)).sum();
+            if cell_bg != hex(bg, Color::Black) {
+                let _ = writeln!(
+                    svg,
+                    "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"{}\"/>",
+                    left,
+                    top,
+                    columns as f32 * CELL_WIDTH,
+                    CELL_HEIGHT,
+                    cell_bg
+                );
+            }
+            let text: String = cells.iter().map(|cell| cell.symbol()).collect();
+            if text.trim().is_empty() {
+                continue;
+            }
+            let mut attributes = String::new();
+            if style.modifier.contains(Modifier::BOLD) {
+                attributes.push_str(" font-weight=\"bold\"");
+            }
+            if style.modifier.contains(Modifier::ITALIC) {
+                attributes.push_str(" font-style=\"italic\"");
+            }
+            if style.modifier.contains(Modifier::DIM) {
+                attributes.push_str(" opacity=\"0.6\"");
+            }
+            if style.modifier.contains(Modifier::UNDERLINED) {
+                attributes.push_str(" text-decoration=\"underline\"");
+            } else if style.modifier.contains(Modifier::CROSSED_OUT) {
+                attributes.push_str(" text-decoration=\"line-through\"");
+            }
+            let _ = writeln!(
+                svg,
+                "<text x=\"{:.1}\" y=\"{:.1}\" textLength=\"{:.1}\" fill=\"{}\"{}>{}</text>",
+                left,
+                top + CELL_HEIGHT * 0.75,
+                columns as f32 * CELL_WIDTH,
+                cell_fg,
+                attributes,
+                escape_xml(&text)
+            );
+        }
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Write the frame as `screen-<time>.ans` and `.svg` under `exports/` and
+/// return both paths
+pub fn write(
+    buffer: &Buffer,
+    fg: Color,
+    bg: Color,
+    taken: NaiveDateTime,
+    config: &Config,
+) -> Result<(PathBuf, PathBuf)> {
+    let dir = Path::new(&config.data_root).join("exports");
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create exports directory: {}", dir.display()))?;
+    let stem = format!("screen-{}", taken.format("%Y-%m-%d-%H%M%S"));
+    let text = dir.join(format!("{}.ans", stem));
+    fs::write(&text, to_ansi(buffer))
+        .with_context(|| format!("Failed to write snapshot: {}", text.display()))?;
+    let image = dir.join(format!("{}.svg", stem));
+    fs::write(&image, to_svg(buffer, fg, bg))
+        .with_context(|| format!("Failed to write snapshot: {}", image.display()))?;
+    Ok((text, image))
+}
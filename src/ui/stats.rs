@@ -1,13 +1,32 @@
-use crate::models::{Config, DailyGoals, OutcomeType};
-use chrono::{Duration, NaiveDate};
+use crate::board::StatusCounts;
+use crate::models::{
+    Config, DailyGoals, DayRollup, IndicatorDirection, IndicatorsData, OutcomeType, StatsCache,
+};
+use crate::tracking::OutcomeTime;
+use anyhow::{bail, Context};
+use chrono::{Datelike, Duration, Months, NaiveDate, Weekday};
+use std::collections::HashMap;
+use std::fs;
+use std::time::UNIX_EPOCH;
 
 pub struct Statistics {
     pub daily_completion: f64,
     pub weekly_trend: Vec<f64>,
     pub monthly_trend: Vec<f64>,
     pub outcome_percentages: (f64, f64, f64), // work, health, family
+    pub weekly_focus_minutes: f64,            // focus timer total, last 7 days
+    pub objective_week_minutes: HashMap<String, u32>, // actual effort per objective, this week
+    pub month_over_month: Option<PeriodComparison>,
+    pub quarter_over_quarter: Option<PeriodComparison>,
+    pub status_counts: StatusCounts, // today's actions by board status
+    pub outcome_times: [OutcomeTime; 3], // today's estimated vs tracked minutes
+    pub priority_completion: [(u32, u32); 3], // done/planned per priority level, last 30 days
+    pub outcome_trends: [Vec<f64>; 3], // work, health, family completion, last OUTCOME_TREND_DAYS
 }
 
+/// Days of per-outcome completion shown next to each outcome
+pub const OUTCOME_TREND_DAYS: i64 = 14;
+
 impl Statistics {
     pub fn calculate(config: &Config, current_date: NaiveDate) -> Self {
         let mut weekly_trend = Vec::new();
@@ -35,6 +54,10 @@ impl Statistics {
             }
         }
 
+        let (month_over_month, quarter_over_quarter) =
+            calculate_rollups(current_date, config).unzip();
+        let outcome_trends = outcome_trends(current_date, config);
+
         // Calculate daily completion for today
         let daily_completion =
             if let Ok(goals) = crate::data::load_or_create_goals(current_date, config) {
@@ -59,49 +82,774 @@ impl Statistics {
             weekly_trend,
             monthly_trend,
             outcome_percentages,
+            weekly_focus_minutes: weekly_focus_minutes(current_date, config),
+            objective_week_minutes: crate::data::objective_minutes_for_week(current_date, config)
+                .unwrap_or_default(),
+            month_over_month,
+            quarter_over_quarter,
+            status_counts: StatusCounts::default(),
+            outcome_times: Default::default(),
+            priority_completion: crate::priority::completion_by_level(current_date, 30, config)
+                .unwrap_or_default(),
+            outcome_trends,
         }
     }
 
     // Calculate statistics from already loaded goals (more efficient for live updates)
     pub fn from_current_goals(goals: &DailyGoals, config: &Config) -> Self {
-        let daily_completion = calculate_completion_percentage(goals);
-        let outcome_percentages = (
-            calculate_outcome_percentage(goals, OutcomeType::Work),
-            calculate_outcome_percentage(goals, OutcomeType::Health),
-            calculate_outcome_percentage(goals, OutcomeType::Family),
-        );
-
-        // Still need to load historical data for trends
-        let mut weekly_trend = Vec::new();
-        let mut monthly_trend = Vec::new();
+        let mut stats = Self::for_today(goals);
+        stats.apply_history(StatsHistory::load(goals.date, config));
+        stats
+    }
 
-        // Load last 7 days for weekly trend
-        for i in (0..7).rev() {
-            let date = goals.date - Duration::days(i);
-            if let Ok(historical_goals) = crate::data::load_or_create_goals(date, config) {
-                weekly_trend.push(calculate_completion_percentage(&historical_goals));
-            } else {
-                weekly_trend.push(0.0);
+    /// Today's figures only; the history fields stay empty until
+    /// `apply_history` fills them in
+    pub fn for_today(goals: &DailyGoals) -> Self {
+        Self {
+            daily_completion: calculate_completion_percentage(goals),
+            weekly_trend: Vec::new(),
+            monthly_trend: Vec::new(),
+            outcome_percentages: (
+                calculate_outcome_percentage(goals, OutcomeType::Work),
+                calculate_outcome_percentage(goals, OutcomeType::Health),
+                calculate_outcome_percentage(goals, OutcomeType::Family),
+            ),
+            weekly_focus_minutes: 0.0,
+            objective_week_minutes: HashMap::new(),
+            month_over_month: None,
+            quarter_over_quarter: None,
+            status_counts: StatusCounts::default(),
+            outcome_times: Default::default(),
+            priority_completion: Default::default(),
+            outcome_trends: Default::default(),
+        }
+    }
+
+    /// Completion per day for one outcome, oldest first and ending today
+    pub fn outcome_trend(&self, outcome: OutcomeType) -> &[f64] {
+        match outcome {
+            OutcomeType::Work => &self.outcome_trends[0],
+            OutcomeType::Health => &self.outcome_trends[1],
+            OutcomeType::Family => &self.outcome_trends[2],
+        }
+    }
+
+    /// Recompute today's figures from in-memory goals, keeping the history
+    pub fn refresh_today(&mut self, goals: &DailyGoals) {
+        let today = Self::for_today(goals);
+        self.daily_completion = today.daily_completion;
+        self.outcome_percentages = today.outcome_percentages;
+        self.sync_trend_tails();
+    }
+
+    /// Take over a history scanned from disk, possibly on another thread
+    pub fn apply_history(&mut self, history: StatsHistory) {
+        self.weekly_trend = history.weekly_trend;
+        self.monthly_trend = history.monthly_trend;
+        self.weekly_focus_minutes = history.weekly_focus_minutes;
+        self.objective_week_minutes = history.objective_week_minutes;
+        self.month_over_month = history.month_over_month;
+        self.quarter_over_quarter = history.quarter_over_quarter;
+        self.priority_completion = history.priority_completion;
+        self.outcome_trends = history.outcome_trends;
+        self.sync_trend_tails();
+    }
+
+    // The last trend point is today, which may have unsaved edits
+    fn sync_trend_tails(&mut self) {
+        for trend in [&mut self.weekly_trend, &mut self.monthly_trend] {
+            if let Some(last) = trend.last_mut() {
+                *last = self.daily_completion;
+            }
+        }
+        let (work, health, family) = self.outcome_percentages;
+        for (trend, today) in self.outcome_trends.iter_mut().zip([work, health, family]) {
+            if let Some(last) = trend.last_mut() {
+                *last = today;
             }
         }
+    }
+}
 
-        // Load last 30 days for monthly trend
-        for i in (0..30).rev() {
-            let date = goals.date - Duration::days(i);
-            if let Ok(historical_goals) = crate::data::load_or_create_goals(date, config) {
-                monthly_trend.push(calculate_completion_percentage(&historical_goals));
-            } else {
-                monthly_trend.push(0.0);
+/// Each outcome's completion for the `OUTCOME_TREND_DAYS` days ending on `date`
+fn outcome_trends(date: NaiveDate, config: &Config) -> [Vec<f64>; 3] {
+    let mut trends: [Vec<f64>; 3] = Default::default();
+    for i in (0..OUTCOME_TREND_DAYS).rev() {
+        let goals = crate::data::load_or_create_goals(date - Duration::days(i), config).ok();
+        for (trend, outcome) in trends
+            .iter_mut()
+            .zip([OutcomeType::Work, OutcomeType::Health, OutcomeType::Family])
+        {
+            trend.push(
+                goals
+                    .as_ref()
+                    .map(|goals| calculate_outcome_percentage(goals, outcome))
+                    .unwrap_or(0.0),
+            );
+        }
+    }
+    trends
+}
+
+/// The parts of `Statistics` that scan past days on disk
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StatsHistory {
+    pub date: Option<NaiveDate>,
+    pub weekly_trend: Vec<f64>,
+    pub monthly_trend: Vec<f64>,
+    pub weekly_focus_minutes: f64,
+    pub objective_week_minutes: HashMap<String, u32>,
+    pub month_over_month: Option<PeriodComparison>,
+    pub quarter_over_quarter: Option<PeriodComparison>,
+    pub priority_completion: [(u32, u32); 3],
+    pub outcome_trends: [Vec<f64>; 3],
+}
+
+impl StatsHistory {
+    /// Trends, focus time, objective effort and rollups ending on `date`
+    pub fn load(date: NaiveDate, config: &Config) -> Self {
+        let trend = |days: i64| -> Vec<f64> {
+            (0..days)
+                .rev()
+                .map(|i| {
+                    crate::data::load_or_create_goals(date - Duration::days(i), config)
+                        .map(|goals| calculate_completion_percentage(&goals))
+                        .unwrap_or(0.0)
+                })
+                .collect()
+        };
+        let (month_over_month, quarter_over_quarter) = calculate_rollups(date, config).unzip();
+
+        Self {
+            date: Some(date),
+            weekly_trend: trend(7),
+            monthly_trend: trend(30),
+            weekly_focus_minutes: weekly_focus_minutes(date, config),
+            objective_week_minutes: crate::data::objective_minutes_for_week(date, config)
+                .unwrap_or_default(),
+            month_over_month,
+            quarter_over_quarter,
+            priority_completion: crate::priority::completion_by_level(date, 30, config)
+                .unwrap_or_default(),
+            outcome_trends: outcome_trends(date, config),
+        }
+    }
+}
+
+/// Calendar period a rollup covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RollupPeriod {
+    Month,
+    Quarter,
+}
+
+impl RollupPeriod {
+    fn months(&self) -> u32 {
+        match self {
+            RollupPeriod::Month => 1,
+            RollupPeriod::Quarter => 3,
+        }
+    }
+
+    /// First and last day of the period containing `date`
+    pub fn bounds(&self, date: NaiveDate) -> (NaiveDate, NaiveDate) {
+        let months = self.months();
+        let first_month = date.month0() / months * months + 1;
+        let start = NaiveDate::from_ymd_opt(date.year(), first_month, 1).unwrap_or(date);
+        let end = start
+            .checked_add_months(Months::new(months))
+            .and_then(|next| next.pred_opt())
+            .unwrap_or(start);
+        (start, end)
+    }
+
+    /// "Sep 2025" or "Q3 2025"
+    pub fn label(&self, start: NaiveDate) -> String {
+        match self {
+            RollupPeriod::Month => start.format("%b %Y").to_string(),
+            RollupPeriod::Quarter => format!("Q{} {}", start.month0() / 3 + 1, start.year()),
+        }
+    }
+}
+
+/// Aggregates over one month or quarter, counting days up to the anchor date
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeriodRollup {
+    pub label: String,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub days_tracked: u32,          // Days with a goals file
+    pub completion: f64,            // Mean daily completion %
+    pub daily_completion: Vec<f64>, // Completion % of each tracked day
+    pub outcome_completion: [f64; 3],
+    pub best_outcome: Option<OutcomeType>,
+    pub worst_outcome: Option<OutcomeType>,
+    pub indicator_averages: HashMap<String, f64>,
+}
+
+/// A period alongside the one before it
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeriodComparison {
+    pub current: PeriodRollup,
+    pub previous: PeriodRollup,
+}
+
+impl PeriodComparison {
+    /// Completion points gained since the previous period, if both were tracked
+    pub fn completion_change(&self) -> Option<f64> {
+        (self.current.days_tracked > 0 && self.previous.days_tracked > 0)
+            .then_some(self.current.completion - self.previous.completion)
+    }
+}
+
+/// Any span of days to compare: a week, a month or two dates
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateRange {
+    pub label: String,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+impl DateRange {
+    /// "2025-03" (a month), "2025-W11" (an ISO week), "2025-03-10" (a day)
+    /// or "2025-03-01..2025-03-15"
+    pub fn parse(spec: &str) -> anyhow::Result<Self> {
+        let spec = spec.trim();
+        let day = |word: &str| {
+            NaiveDate::parse_from_str(word, "%Y-%m-%d")
+                .with_context(|| format!("Expected a YYYY-MM-DD date, got '{}'", word))
+        };
+        if let Some((from, to)) = spec.split_once("..") {
+            let (start, end) = (day(from)?, day(to)?);
+            if end < start {
+                bail!("The range {} ends before it starts", spec);
+            }
+            return Ok(Self {
+                label: format!("{} to {}", start.format("%b %d"), end.format("%b %d %Y")),
+                start,
+                end,
+            });
+        }
+        if let Some((year, week)) = spec.split_once("-W").or_else(|| spec.split_once("-w")) {
+            if let (Ok(year), Ok(week)) = (year.parse(), week.parse()) {
+                if let Some(start) = NaiveDate::from_isoywd_opt(year, week, Weekday::Mon) {
+                    return Ok(Self::week(start));
+                }
             }
         }
+        if let Ok(date) = NaiveDate::parse_from_str(spec, "%Y-%m-%d") {
+            return Ok(Self {
+                label: date.format("%a %b %d %Y").to_string(),
+                start: date,
+                end: date,
+            });
+        }
+        if let Ok(first) = NaiveDate::parse_from_str(&format!("{}-01", spec), "%Y-%m-%d") {
+            return Ok(Self::period(RollupPeriod::Month, first));
+        }
+        bail!(
+            "Expected YYYY-MM, YYYY-Www, YYYY-MM-DD or DATE..DATE, got '{}'",
+            spec
+        )
+    }
 
+    /// The ISO week containing `date`
+    pub fn week(date: NaiveDate) -> Self {
+        let start = date - Duration::days(i64::from(date.weekday().num_days_from_monday()));
+        let week = start.iso_week();
         Self {
-            daily_completion,
-            weekly_trend,
-            monthly_trend,
-            outcome_percentages,
+            label: format!("Week {} {}", week.week(), week.year()),
+            start,
+            end: start + Duration::days(6),
         }
     }
+
+    /// The month or quarter containing `date`
+    pub fn period(period: RollupPeriod, date: NaiveDate) -> Self {
+        let (start, end) = period.bounds(date);
+        Self {
+            label: period.label(start),
+            start,
+            end,
+        }
+    }
+}
+
+/// Week, month or quarter compared with the one before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparePreset {
+    Week,
+    Month,
+    Quarter,
+}
+
+/// The two ranges a comparison screen shows
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompareRanges {
+    /// Relative to the day on screen
+    Preset(ComparePreset),
+    Ranges(DateRange, DateRange),
+}
+
+impl CompareRanges {
+    /// The range shown first and the one it is compared against
+    pub fn resolve(&self, date: NaiveDate) -> (DateRange, DateRange) {
+        let previous = |range: &DateRange| range.start - Duration::days(1);
+        match self {
+            CompareRanges::Preset(ComparePreset::Week) => {
+                let current = DateRange::week(date);
+                let before = DateRange::week(previous(&current));
+                (current, before)
+            }
+            CompareRanges::Preset(preset) => {
+                let period = if *preset == ComparePreset::Month {
+                    RollupPeriod::Month
+                } else {
+                    RollupPeriod::Quarter
+                };
+                let current = DateRange::period(period, date);
+                let before = DateRange::period(period, previous(&current));
+                (current, before)
+            }
+            CompareRanges::Ranges(first, second) => (first.clone(), second.clone()),
+        }
+    }
+}
+
+/// Roll up `first` and `second`, counting days up to `through`
+pub fn compare_ranges(
+    first: &DateRange,
+    second: &DateRange,
+    through: NaiveDate,
+    config: &Config,
+) -> anyhow::Result<PeriodComparison> {
+    let start = first.start.min(second.start);
+    let end = first.end.max(second.end).min(through);
+    let cache = load_day_cache(start, end, config);
+    let observations = crate::data::read_observations_range(start, end, config)?;
+    Ok(PeriodComparison {
+        current: range_rollup(first, through, &cache, &observations),
+        previous: range_rollup(second, through, &cache, &observations),
+    })
+}
+
+/// One figure of a comparison in both ranges
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompareRow {
+    pub label: String,
+    pub current: Option<f64>,
+    pub previous: Option<f64>,
+    /// Shown as a percentage rather than a number
+    pub percent: bool,
+    /// Whether a rise is an improvement; `None` when neither way is
+    pub higher_is_better: Option<bool>,
+}
+
+impl CompareRow {
+    pub fn change(&self) -> Option<f64> {
+        Some(self.current? - self.previous?)
+    }
+
+    /// Whether the change is for the better; `None` when flat or unknown
+    pub fn improved(&self) -> Option<bool> {
+        let change = self.change()?;
+        if change.abs() < 0.05 {
+            return None;
+        }
+        self.higher_is_better.map(|higher| higher == (change > 0.0))
+    }
+
+    /// "62%", "8250.5" or "—"
+    pub fn format(&self, value: Option<f64>) -> String {
+        match value {
+            None => "—".to_string(),
+            Some(value) if self.percent => format!("{:.0}%", value),
+            Some(value) if value.fract() == 0.0 => format!("{:.0}", value),
+            Some(value) => format!("{:.1}", value),
+        }
+    }
+
+    /// "▲14", "▼2.5" or "="
+    pub fn marker(&self) -> String {
+        match self.change() {
+            None => String::new(),
+            Some(change) if change.abs() < 0.05 => "=".to_string(),
+            Some(change) => {
+                let arrow = if change > 0.0 { "▲" } else { "▼" };
+                let row = CompareRow {
+                    percent: false,
+                    ..self.clone()
+                };
+                format!("{}{}", arrow, row.format(Some(change.abs())))
+            }
+        }
+    }
+}
+
+/// Completion, per-outcome completion, days tracked and the average of
+/// every indicator observed in either range
+pub fn comparison_rows(
+    comparison: &PeriodComparison,
+    indicators: &IndicatorsData,
+) -> Vec<CompareRow> {
+    let (current, previous) = (&comparison.current, &comparison.previous);
+    let tracked = |rollup: &PeriodRollup, value: f64| (rollup.days_tracked > 0).then_some(value);
+    let mut rows = vec![CompareRow {
+        label: "Completion".to_string(),
+        current: tracked(current, current.completion),
+        previous: tracked(previous, previous.completion),
+        percent: true,
+        higher_is_better: Some(true),
+    }];
+    let outcomes = [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family];
+    for (index, outcome_type) in outcomes.iter().enumerate() {
+        rows.push(CompareRow {
+            label: outcome_type.as_str().to_string(),
+            current: tracked(current, current.outcome_completion[index]),
+            previous: tracked(previous, previous.outcome_completion[index]),
+            percent: true,
+            higher_is_better: Some(true),
+        });
+    }
+    rows.push(CompareRow {
+        label: "Days tracked".to_string(),
+        current: Some(f64::from(current.days_tracked)),
+        previous: Some(f64::from(previous.days_tracked)),
+        percent: false,
+        higher_is_better: Some(true),
+    });
+
+    let mut observed: Vec<_> = indicators
+        .indicators
+        .iter()
+        .filter(|def| {
+            current.indicator_averages.contains_key(&def.id)
+                || previous.indicator_averages.contains_key(&def.id)
+        })
+        .collect();
+    observed.sort_by(|a, b| a.name.cmp(&b.name));
+    for def in observed {
+        rows.push(CompareRow {
+            label: format!("{} (avg)", def.name),
+            current: current.indicator_averages.get(&def.id).copied(),
+            previous: previous.indicator_averages.get(&def.id).copied(),
+            percent: false,
+            higher_is_better: match def.direction {
+                IndicatorDirection::HigherIsBetter => Some(true),
+                IndicatorDirection::LowerIsBetter => Some(false),
+                IndicatorDirection::WithinRange => None,
+            },
+        });
+    }
+    rows
+}
+
+/// A comparison as a plain-text table
+pub fn format_comparison(comparison: &PeriodComparison, indicators: &IndicatorsData) -> String {
+    let (current, previous) = (&comparison.current, &comparison.previous);
+    let rows = comparison_rows(comparison, indicators);
+    let width = rows
+        .iter()
+        .map(|row| row.label.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut text = format!(
+        "{} ({} to {}) vs {} ({} to {})\n",
+        current.label,
+        current.start.format("%b %d"),
+        current.end.format("%b %d"),
+        previous.label,
+        previous.start.format("%b %d"),
+        previous.end.format("%b %d")
+    );
+    for row in &rows {
+        text.push_str(&format!(
+            "{:<width$}  {:>8}  {:>8}  {}\n",
+            row.label,
+            row.format(row.current),
+            row.format(row.previous),
+            row.marker(),
+            width = width
+        ));
+    }
+    text
+}
+
+/// Re-parse goals files in `start..=end` whose size or mtime no longer match
+/// the cache. Returns whether anything changed.
+fn refresh_day_cache(
+    cache: &mut StatsCache,
+    start: NaiveDate,
+    end: NaiveDate,
+    config: &Config,
+) -> bool {
+    let mut changed = false;
+    for date in start.iter_days().take_while(|date| *date <= end) {
+        let path = crate::data::goals_file_path(date, config);
+        let Ok(metadata) = fs::metadata(&path) else {
+            changed |= cache.days.remove(&date).is_some();
+            continue;
+        };
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |elapsed| elapsed.as_secs());
+        let len = metadata.len();
+        if cache
+            .days
+            .get(&date)
+            .is_some_and(|day| day.modified == modified && day.len == len)
+        {
+            continue;
+        }
+
+        changed = true;
+        match crate::data::read_goals_for(date, config) {
+            Ok(goals) => {
+                cache.days.insert(
+                    date,
+                    DayRollup {
+                        modified,
+                        len,
+                        completion: calculate_completion_percentage(&goals),
+                        outcomes: [
+                            calculate_outcome_percentage(&goals, OutcomeType::Work),
+                            calculate_outcome_percentage(&goals, OutcomeType::Health),
+                            calculate_outcome_percentage(&goals, OutcomeType::Family),
+                        ],
+                        actions: goals.outcomes().map(|outcome| {
+                            let planned = outcome.actions.iter().filter(|a| !a.text.is_empty());
+                            let done = planned.clone().filter(|a| a.completed).count();
+                            (done as u32, planned.count() as u32)
+                        }),
+                    },
+                );
+            }
+            Err(_) => {
+                cache.days.remove(&date);
+            }
+        }
+    }
+    changed
+}
+
+/// Rebuild stats.json from scratch for `dates`, calling `progress` with
+/// (done, total) after each day. Returns how many days were cached.
+pub fn rebuild_stats_cache(
+    dates: &[NaiveDate],
+    config: &Config,
+    mut progress: impl FnMut(usize, usize),
+) -> anyhow::Result<usize> {
+    let mut cache = StatsCache::default();
+    for (done, date) in dates.iter().enumerate() {
+        refresh_day_cache(&mut cache, *date, *date, config);
+        progress(done + 1, dates.len());
+    }
+    crate::data::save_stats_cache(&cache, config)?;
+    Ok(cache.days.len())
+}
+
+/// Roll up the period containing `date` from cached day figures and
+/// `observations`, ignoring anything after `through`
+pub fn period_rollup(
+    period: RollupPeriod,
+    date: NaiveDate,
+    through: NaiveDate,
+    cache: &StatsCache,
+    observations: &[crate::models::Observation],
+) -> PeriodRollup {
+    range_rollup(
+        &DateRange::period(period, date),
+        through,
+        cache,
+        observations,
+    )
+}
+
+/// Roll up any range from cached day figures and `observations`, ignoring
+/// anything after `through`
+pub fn range_rollup(
+    range: &DateRange,
+    through: NaiveDate,
+    cache: &StatsCache,
+    observations: &[crate::models::Observation],
+) -> PeriodRollup {
+    let (start, end) = (range.start, range.end);
+    let last = end.min(through);
+    let days: Vec<&DayRollup> = cache.days.range(start..=last).map(|(_, day)| day).collect();
+
+    let count = days.len() as f64;
+    let mean = |value: &dyn Fn(&DayRollup) -> f64| {
+        if days.is_empty() {
+            0.0
+        } else {
+            days.iter().map(|day| value(day)).sum::<f64>() / count
+        }
+    };
+    let completion = mean(&|day| day.completion);
+    let outcome_completion = [0, 1, 2].map(|index| mean(&|day: &DayRollup| day.outcomes[index]));
+
+    let outcomes = [OutcomeType::Work, OutcomeType::Health, OutcomeType::Family];
+    let ranked = |best: bool| {
+        if days.is_empty() {
+            return None;
+        }
+        let mut order: Vec<usize> = (0..3).collect();
+        // Stable, so ties go to Work, then Health
+        order.sort_by(|a, b| {
+            let ordering = outcome_completion[*a].total_cmp(&outcome_completion[*b]);
+            if best {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+        Some(outcomes[order[0]])
+    };
+
+    let mut sums: HashMap<String, (f64, u32)> = HashMap::new();
+    for obs in observations
+        .iter()
+        .filter(|obs| obs.when >= start && obs.when <= last)
+    {
+        let entry = sums.entry(obs.indicator_id.clone()).or_default();
+        entry.0 += obs.value;
+        entry.1 += 1;
+    }
+
+    PeriodRollup {
+        label: range.label.clone(),
+        start,
+        end,
+        days_tracked: days.len() as u32,
+        completion,
+        daily_completion: days.iter().map(|day| day.completion).collect(),
+        outcome_completion,
+        best_outcome: ranked(true),
+        worst_outcome: ranked(false),
+        indicator_averages: sums
+            .into_iter()
+            .map(|(id, (sum, count))| (id, sum / f64::from(count)))
+            .collect(),
+    }
+}
+
+/// Cached day figures with `start..=end` brought up to date; the cache file
+/// is saved again when anything changed
+pub fn load_day_cache(start: NaiveDate, end: NaiveDate, config: &Config) -> StatsCache {
+    // A corrupt cache is rebuilt rather than blocking statistics, as is one
+    // from before per-outcome action counts
+    let mut cache = crate::data::load_stats_cache(config)
+        .ok()
+        .filter(|cache| cache.version >= StatsCache::default().version)
+        .unwrap_or_default();
+    if refresh_day_cache(&mut cache, start, end, config) {
+        let _ = crate::data::save_stats_cache(&cache, config);
+    }
+    cache
+}
+
+/// Today's progress and streak for `status`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayStatus {
+    /// Completed and planned actions: Work, Health, Family
+    pub actions: [(u32, u32); 3],
+    /// Consecutive days up to `date` with a completed action
+    pub streak: u32,
+}
+
+impl DayStatus {
+    /// One line for a tmux status bar or shell prompt: "W 2/3 H 1/3 F 3/3 🔥12"
+    pub fn short(&self) -> String {
+        let [work, health, family] = self.actions;
+        format!(
+            "W {}/{} H {}/{} F {}/{} 🔥{}",
+            work.0, work.1, health.0, health.1, family.0, family.1, self.streak
+        )
+    }
+}
+
+/// Progress on `date` and the streak ending there, from stats.json; only
+/// goals files changed since the last run are parsed
+pub fn day_status(date: NaiveDate, config: &Config) -> DayStatus {
+    // Same limit as `calculate_streak`
+    let cache = load_day_cache(date - Duration::days(365), date, config);
+    let has_completion = |day: &NaiveDate| {
+        cache
+            .days
+            .get(day)
+            .is_some_and(|day| day.actions.iter().any(|a| a.0 > 0))
+    };
+    let streak = date
+        .iter_days()
+        .rev()
+        .take(366)
+        .take_while(has_completion)
+        .count();
+    DayStatus {
+        actions: cache
+            .days
+            .get(&date)
+            .map(|day| day.actions)
+            .unwrap_or_default(),
+        streak: streak as u32,
+    }
+}
+
+/// Month-over-month and quarter-over-quarter comparisons up to `date`.
+///
+/// Per-day figures are cached in stats.json, so only goals files that changed
+/// since the last run are parsed again.
+pub fn calculate_rollups(
+    date: NaiveDate,
+    config: &Config,
+) -> Option<(PeriodComparison, PeriodComparison)> {
+    let (quarter_start, _) = RollupPeriod::Quarter.bounds(date);
+    let first = quarter_start.checked_sub_months(Months::new(3))?;
+
+    let cache = load_day_cache(first, date, config);
+    let observations =
+        crate::data::read_observations_range(first, date, config).unwrap_or_default();
+
+    let compare = |period: RollupPeriod| {
+        let (start, _) = period.bounds(date);
+        let previous_start = start.checked_sub_months(Months::new(period.months()))?;
+        Some(PeriodComparison {
+            current: period_rollup(period, date, date, &cache, &observations),
+            previous: period_rollup(period, previous_start, date, &cache, &observations),
+        })
+    };
+    Some((
+        compare(RollupPeriod::Month)?,
+        compare(RollupPeriod::Quarter)?,
+    ))
+}
+
+fn weekly_focus_minutes(end: NaiveDate, config: &Config) -> f64 {
+    crate::data::focus_minutes_by_day(end - Duration::days(6), end, config)
+        .map(|days| days.iter().map(|(_, minutes)| minutes).sum())
+        .unwrap_or(0.0)
+}
+
+/// Pearson correlation of paired values; needs at least three pairs and
+/// some variation in both
+pub fn pearson(pairs: &[(f64, f64)]) -> Option<f64> {
+    if pairs.len() < 3 {
+        return None;
+    }
+
+    let n = pairs.len() as f64;
+    let mean_a = pairs.iter().map(|(a, _)| a).sum::<f64>() / n;
+    let mean_b = pairs.iter().map(|(_, b)| b).sum::<f64>() / n;
+    let (mut cov, mut var_a, mut var_b) = (0.0, 0.0, 0.0);
+    for (a, b) in pairs {
+        cov += (a - mean_a) * (b - mean_b);
+        var_a += (a - mean_a).powi(2);
+        var_b += (b - mean_b).powi(2);
+    }
+    let denom = (var_a * var_b).sqrt();
+    (denom > f64::EPSILON).then(|| cov / denom)
 }
 
 fn calculate_completion_percentage(goals: &DailyGoals) -> f64 {
@@ -144,6 +892,7 @@ mod tests {
             origin: crate::models::ActionOrigin::Manual,
             objective_id: None,
             objective_ids: vec![],
+            indicator_links: vec![],
             created: chrono::Utc::now(),
             modified: chrono::Utc::now(),
             completed_at: if completed {
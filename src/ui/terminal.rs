@@ -1,5 +1,8 @@
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event},
+    event::{
+        self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        Event,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -9,10 +12,18 @@ use ratatui::{
 };
 use std::io;
 
+/// How long the first frame waits on the startup loads
+const STARTUP_LOAD_WAIT: std::time::Duration = std::time::Duration::from_millis(200);
+
 pub fn init_terminal() -> anyhow::Result<Terminal<CrosstermBackend<io::Stdout>>> {
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
     let backend = CrosstermBackend::new(stdout);
     let terminal = Terminal::new(backend)?;
     Ok(terminal)
@@ -25,25 +36,70 @@ pub fn restore_terminal(
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste
     )?;
     terminal.show_cursor()?;
     Ok(())
 }
 
+/// Hand the terminal to a child process: leave the alternate screen and
+/// raw mode, run `f`, then take the terminal back and redraw from scratch
+fn suspended<B: Backend, T>(
+    terminal: &mut Terminal<B>,
+    f: impl FnOnce() -> T,
+) -> anyhow::Result<T> {
+    disable_raw_mode()?;
+    execute!(
+        io::stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableBracketedPaste
+    )?;
+    terminal.show_cursor()?;
+
+    let result = f();
+
+    enable_raw_mode()?;
+    execute!(
+        io::stdout(),
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
+    terminal.clear()?;
+    Ok(result)
+}
+
 pub fn run_app<B: Backend>(
     terminal: &mut Terminal<B>,
     mut app: crate::ui::App,
 ) -> anyhow::Result<()> {
+    // Small data directories finish loading before the first frame instead
+    // of flashing a spinner
+    app.wait_for_loads(STARTUP_LOAD_WAIT);
     loop {
         terminal.draw(|f| app.render(f))?;
 
         if event::poll(std::time::Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                if app.handle_key(key.code)? {
+            match event::read()? {
+                Event::Key(key) if app.handle_key_event(key)? => {
+                    app.flush_pending_save()?;
+                    // Don't lose timers that are still running
+                    app.finish_focus_session()?;
+                    app.stop_action_timer()?;
                     return Ok(());
                 }
+                Event::Paste(text) => app.handle_paste(&text),
+                _ => {}
             }
         }
+
+        if let Some(text) = app.take_external_edit() {
+            let edited = suspended(terminal, || crate::external_editor::edit(&text))?;
+            app.finish_external_edit(edited)?;
+        }
+
+        app.tick();
     }
 }
@@ -1,3 +1,4 @@
+use crate::models::ThemePreset;
 use ratatui::style::Color;
 
 pub struct FocusFiveTheme {
@@ -41,6 +42,127 @@ impl Default for FocusFiveTheme {
     }
 }
 
+impl FocusFiveTheme {
+    /// Colors for a preset; anything not status-related stays as in `Dark`
+    pub fn preset(preset: ThemePreset) -> Self {
+        let (completed, pending, partial) = match preset {
+            ThemePreset::Dark => return Self::default(),
+            ThemePreset::Colorblind => (
+                Color::Rgb(86, 180, 233),
+                Color::Rgb(213, 94, 0),
+                Color::Rgb(240, 228, 66),
+            ),
+            ThemePreset::Tritan => (
+                Color::Rgb(64, 200, 200),
+                Color::Rgb(220, 40, 40),
+                Color::Rgb(255, 140, 180),
+            ),
+            ThemePreset::Monochrome => (
+                Color::Rgb(240, 240, 240),
+                Color::Rgb(110, 110, 110),
+                Color::Rgb(175, 175, 175),
+            ),
+        };
+        let (work_color, health_color, family_color) = match preset {
+            ThemePreset::Colorblind => (
+                Color::Rgb(0, 114, 178),
+                Color::Rgb(0, 158, 115),
+                Color::Rgb(204, 121, 167),
+            ),
+            ThemePreset::Monochrome => (
+                Color::Rgb(220, 220, 220),
+                Color::Rgb(220, 220, 220),
+                Color::Rgb(220, 220, 220),
+            ),
+            _ => (
+                Color::Rgb(100, 181, 246),
+                Color::Rgb(129, 199, 132),
+                Color::Rgb(255, 183, 77),
+            ),
+        };
+        let header = match preset {
+            ThemePreset::Monochrome => Color::Rgb(255, 255, 255),
+            _ => Color::Rgb(255, 200, 0),
+        };
+        Self {
+            completed,
+            pending,
+            partial,
+            work_color,
+            health_color,
+            family_color,
+            header,
+            ..Self::default()
+        }
+    }
+
+    pub fn severity_color(&self, severity: Severity) -> Color {
+        match severity {
+            Severity::Good => self.completed,
+            Severity::Warning => self.partial,
+            Severity::Bad => self.pending,
+        }
+    }
+}
+
+/// How good a status is. Always paired with its symbol when shown so the
+/// meaning doesn't depend on telling colors apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Good,
+    Warning,
+    Bad,
+}
+
+impl Severity {
+    /// `Good` at or above `good`, `Warning` at or above `warning`
+    pub fn from_percentage(value: f64, good: f64, warning: f64) -> Self {
+        if value >= good {
+            Severity::Good
+        } else if value >= warning {
+            Severity::Warning
+        } else {
+            Severity::Bad
+        }
+    }
+
+    /// Daily completion as the charts grade it: 80% good, 40% partial
+    pub fn from_completion(percentage: f64) -> Self {
+        Self::from_percentage(percentage, 80.0, 40.0)
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Severity::Good => "✓",
+            Severity::Warning => "▲",
+            Severity::Bad => "■",
+        }
+    }
+}
+
+/// Accent colors handed out to objectives, cycled with `c` in the picker
+pub const OBJECTIVE_PALETTE: [&str; 8] = [
+    "#e57373", "#ba68c8", "#4fc3f7", "#4db6ac", "#aed581", "#ffd54f", "#ff8a65", "#90a4ae",
+];
+
+/// Parse an objective's stored accent (color name or #rrggbb)
+pub fn objective_accent(color: Option<&str>) -> Option<Color> {
+    color?.parse().ok()
+}
+
+/// The palette entry after `current`, wrapping around
+pub fn next_objective_color(current: Option<&str>) -> &'static str {
+    let position = current.and_then(|c| {
+        OBJECTIVE_PALETTE
+            .iter()
+            .position(|p| p.eq_ignore_ascii_case(c))
+    });
+    match position {
+        Some(i) => OBJECTIVE_PALETTE[(i + 1) % OBJECTIVE_PALETTE.len()],
+        None => OBJECTIVE_PALETTE[0],
+    }
+}
+
 pub struct FinancialTheme {
     // Dark backgrounds
     pub bg_primary: Color,   // #0A0A0A - Almost black
@@ -85,23 +207,48 @@ impl Default for FinancialTheme {
 }
 
 impl FinancialTheme {
-    pub fn get_trend_color(&self, value: f64, previous: f64) -> Color {
+    /// Status colors for a preset over the default dashboard palette
+    pub fn preset(preset: ThemePreset) -> Self {
+        let focus = FocusFiveTheme::preset(preset);
+        match preset {
+            ThemePreset::Dark => Self::default(),
+            _ => Self {
+                positive: focus.completed,
+                negative: focus.pending,
+                neutral: focus.partial,
+                ..Self::default()
+            },
+        }
+    }
+
+    pub fn severity_color(&self, severity: Severity) -> Color {
+        match severity {
+            Severity::Good => self.positive,
+            Severity::Warning => self.neutral,
+            Severity::Bad => self.negative,
+        }
+    }
+
+    /// Good when `value` rose above `previous`, bad when it fell
+    pub fn trend_severity(value: f64, previous: f64) -> Severity {
         if value > previous {
-            self.positive
+            Severity::Good
         } else if value < previous {
-            self.negative
+            Severity::Bad
         } else {
-            self.neutral
+            Severity::Warning
         }
     }
 
+    pub fn status_severity(percentage: f64) -> Severity {
+        Severity::from_percentage(percentage, 80.0, 50.0)
+    }
+
+    pub fn get_trend_color(&self, value: f64, previous: f64) -> Color {
+        self.severity_color(Self::trend_severity(value, previous))
+    }
+
     pub fn get_status_color(&self, percentage: f64) -> Color {
-        if percentage >= 80.0 {
-            self.positive
-        } else if percentage >= 50.0 {
-            self.neutral
-        } else {
-            self.negative
-        }
+        self.severity_color(Self::status_severity(percentage))
     }
 }
@@ -0,0 +1,250 @@
+use crate::ui::theme::FocusFiveTheme;
+use crate::ui::wrap::wrap_spans;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::Span,
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Toasts on screen at once; older ones are still in the history
+pub const MAX_VISIBLE: usize = 4;
+/// Messages kept for the history view
+pub const HISTORY_LIMIT: usize = 100;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToastLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastLevel {
+    /// How long a toast stays up; errors linger so they can be read
+    pub fn lifetime(&self) -> Duration {
+        match self {
+            ToastLevel::Info | ToastLevel::Success => Duration::from_secs(3),
+            ToastLevel::Warning => Duration::from_secs(5),
+            ToastLevel::Error => Duration::from_secs(8),
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            ToastLevel::Info => "ℹ",
+            ToastLevel::Success => "✓",
+            ToastLevel::Warning => "⚠",
+            ToastLevel::Error => "✗",
+        }
+    }
+
+    pub fn color(&self, theme: &FocusFiveTheme) -> Color {
+        match self {
+            ToastLevel::Info => theme.text_secondary,
+            ToastLevel::Success => theme.completed,
+            ToastLevel::Warning => theme.partial,
+            ToastLevel::Error => theme.pending,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Toast {
+    pub message: String,
+    pub level: ToastLevel,
+    /// Wall-clock time, for the history
+    pub at: chrono::NaiveTime,
+    /// The same message shown again while still up bumps this instead
+    pub repeats: usize,
+    shown_at: Instant,
+}
+
+impl Toast {
+    fn is_expired(&self, now: Instant) -> bool {
+        now.duration_since(self.shown_at) >= self.level.lifetime()
+    }
+}
+
+/// Stacked, self-dismissing notifications with a history of everything
+/// shown, so a quick "saved" does not hide the error that follows it
+#[derive(Default)]
+pub struct Toasts {
+    active: Vec<Toast>,
+    history: VecDeque<Toast>,
+}
+
+impl Toasts {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn show(&mut self, message: String, level: ToastLevel) {
+        let now = Instant::now();
+        self.active.retain(|toast| !toast.is_expired(now));
+        if let Some(last) = self
+            .active
+            .last_mut()
+            .filter(|last| last.message == message && last.level == level)
+        {
+            last.repeats += 1;
+            last.shown_at = now;
+            return;
+        }
+
+        let toast = Toast {
+            message,
+            level,
+            at: chrono::Local::now().time(),
+            repeats: 1,
+            shown_at: now,
+        };
+        self.history.push_back(toast.clone());
+        if self.history.len() > HISTORY_LIMIT {
+            self.history.pop_front();
+        }
+        self.active.push(toast);
+    }
+
+    pub fn show_info(&mut self, message: String) {
+        self.show(message, ToastLevel::Info);
+    }
+
+    pub fn show_success(&mut self, message: String) {
+        self.show(message, ToastLevel::Success);
+    }
+
+    pub fn show_warning(&mut self, message: String) {
+        self.show(message, ToastLevel::Warning);
+    }
+
+    pub fn show_error(&mut self, message: String) {
+        self.show(message, ToastLevel::Error);
+    }
+
+    /// Dismiss everything on screen; the history keeps them
+    pub fn clear(&mut self) {
+        self.active.clear();
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.visible().is_empty()
+    }
+
+    /// Toasts still up, newest first
+    pub fn visible(&self) -> Vec<&Toast> {
+        let now = Instant::now();
+        self.active
+            .iter()
+            .rev()
+            .filter(|toast| !toast.is_expired(now))
+            .take(MAX_VISIBLE)
+            .collect()
+    }
+
+    /// Everything shown this session, oldest first
+    pub fn history(&self) -> &VecDeque<Toast> {
+        &self.history
+    }
+
+    /// Stack the visible toasts in the top-right corner of `area`, newest
+    /// on top, as far down as they fit
+    pub fn render(&self, f: &mut Frame, area: Rect, theme: &FocusFiveTheme) {
+        let width = (area.width * 2 / 5).clamp(30, 64).min(area.width);
+        let x = area.right().saturating_sub(width + 1).max(area.x);
+        let mut y = area.y + 1;
+
+        for toast in self.visible() {
+            let color = toast.level.color(theme);
+            let mut spans = vec![
+                Span::styled(
+                    format!("{} ", toast.level.icon()),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    toast.message.clone(),
+                    Style::default().fg(theme.text_primary),
+                ),
+            ];
+            if toast.repeats > 1 {
+                spans.push(Span::styled(
+                    format!(" ×{}", toast.repeats),
+                    Style::default().fg(theme.text_secondary),
+                ));
+            }
+            let lines = wrap_spans(spans, usize::from(width.saturating_sub(4)), 2);
+            let height = lines.len() as u16 + 2;
+            if y + height > area.bottom() {
+                break;
+            }
+
+            let toast_area = Rect::new(x, y, width, height);
+            f.render_widget(Clear, toast_area);
+            f.render_widget(
+                Paragraph::new(lines).block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded)
+                        .border_style(Style::default().fg(color))
+                        .padding(ratatui::widgets::Padding::horizontal(1))
+                        .style(Style::default().bg(theme.panel_bg)),
+                ),
+                toast_area,
+            );
+            y += height;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toasts_stack_and_keep_a_history() {
+        let mut toasts = Toasts::new();
+        assert!(!toasts.is_active());
+
+        toasts.show_success("Saved".to_string());
+        toasts.show_error("Sync failed".to_string());
+        let visible: Vec<&str> = toasts
+            .visible()
+            .iter()
+            .map(|toast| toast.message.as_str())
+            .collect();
+        assert_eq!(visible, vec!["Sync failed", "Saved"]);
+
+        // A repeat bumps the toast on screen rather than stacking a copy
+        toasts.show_error("Sync failed".to_string());
+        assert_eq!(toasts.visible().len(), 2);
+        assert_eq!(toasts.visible()[0].repeats, 2);
+
+        toasts.clear();
+        assert!(!toasts.is_active());
+        assert_eq!(toasts.history().len(), 2);
+        assert_eq!(toasts.history()[0].level, ToastLevel::Success);
+    }
+
+    #[test]
+    fn toasts_expire_by_level() {
+        let mut toasts = Toasts::new();
+        toasts.show_info("Info".to_string());
+        toasts.show_error("Error".to_string());
+        let later = Instant::now() + Duration::from_secs(4);
+        let expired: Vec<bool> = toasts
+            .active
+            .iter()
+            .map(|toast| toast.is_expired(later))
+            .collect();
+        assert_eq!(expired, vec![true, false]);
+
+        for i in 0..HISTORY_LIMIT + 5 {
+            toasts.show_info(format!("Message {}", i));
+        }
+        assert_eq!(toasts.visible().len(), MAX_VISIBLE);
+        assert_eq!(toasts.history().len(), HISTORY_LIMIT);
+    }
+}
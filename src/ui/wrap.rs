@@ -0,0 +1,205 @@
+//! Word wrapping for styled spans, for rows that have to know how many
+//! lines they take (list items, popups sized to their text)
+
+use ratatui::{
+    style::Style,
+    text::{Line, Span},
+};
+
+fn text_width(text: &str) -> usize {
+    Span::raw(text).width()
+}
+
+/// Runs of spaces and runs of everything else, in order
+fn pieces(text: &str) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    let mut in_space = None;
+    for (i, c) in text.char_indices() {
+        let space = c == ' ';
+        if in_space.is_some_and(|was| was != space) {
+            pieces.push(&text[start..i]);
+            start = i;
+        }
+        in_space = Some(space);
+    }
+    if start < text.len() {
+        pieces.push(&text[start..]);
+    }
+    pieces
+}
+
+struct Wrapper {
+    width: usize,
+    indent: usize,
+    lines: Vec<Vec<(String, Style)>>,
+    used: usize,
+}
+
+impl Wrapper {
+    fn line_start(&self) -> usize {
+        if self.lines.len() == 1 {
+            0
+        } else {
+            self.indent
+        }
+    }
+
+    fn push(&mut self, text: &str, style: Style) {
+        self.used += text_width(text);
+        let line = self.lines.last_mut().expect("always one line");
+        match line.last_mut() {
+            Some((last, last_style)) if *last_style == style => last.push_str(text),
+            _ => line.push((text.to_string(), style)),
+        }
+    }
+
+    fn break_line(&mut self) {
+        // Spaces left at the end of the row would only pad the highlight
+        if let Some(line) = self.lines.last_mut() {
+            if let Some((last, _)) = line.last_mut() {
+                last.truncate(last.trim_end_matches(' ').len());
+            }
+            line.retain(|(text, _)| !text.is_empty());
+        }
+        self.lines
+            .push(vec![(" ".repeat(self.indent), Style::default())]);
+        self.used = self.indent;
+    }
+
+    /// Lay out text without line breaks, starting rows as it fills them
+    fn push_pieces(&mut self, text: &str, style: Style) {
+        for piece in pieces(text) {
+            let piece_width = text_width(piece);
+            if piece.starts_with(' ') {
+                if self.used + piece_width > self.width {
+                    self.break_line();
+                } else if self.lines.len() == 1 || self.used > self.indent {
+                    // Spaces are kept except at the start of a wrapped row
+                    self.push(piece, style);
+                }
+                continue;
+            }
+            if self.used + piece_width > self.width && self.used > self.line_start() {
+                self.break_line();
+            }
+            let mut rest = piece;
+            while self.used + text_width(rest) > self.width {
+                let room = self.width - self.used;
+                let mut split = 0;
+                let mut taken = 0;
+                for (i, c) in rest.char_indices() {
+                    let w = text_width(&rest[i..i + c.len_utf8()]);
+                    if taken + w > room {
+                        break;
+                    }
+                    taken += w;
+                    split = i + c.len_utf8();
+                }
+                if split == 0 {
+                    // Not even one character fits on a fresh line
+                    if self.used == self.line_start() {
+                        split = rest.chars().next().map_or(rest.len(), char::len_utf8);
+                    } else {
+                        self.break_line();
+                        continue;
+                    }
+                }
+                self.push(&rest[..split], style);
+                rest = &rest[split..];
+                if rest.is_empty() {
+                    break;
+                }
+                self.break_line();
+            }
+            if !rest.is_empty() {
+                self.push(rest, style);
+            }
+        }
+    }
+}
+
+/// Wrap `spans` at spaces to lines of at most `width` columns, keeping each
+/// span's style. Continuation lines start with `indent` spaces so they line
+/// up under the text rather than under a prefix; words wider than a line
+/// are split and line breaks in the text start a new row.
+pub fn wrap_spans(spans: Vec<Span<'_>>, width: usize, indent: usize) -> Vec<Line<'static>> {
+    let indent = indent.min(width.saturating_sub(1));
+    let mut wrapper = Wrapper {
+        width: width.max(1),
+        indent,
+        lines: vec![Vec::new()],
+        used: 0,
+    };
+
+    for span in &spans {
+        for (n, segment) in span.content.split('\n').enumerate() {
+            if n > 0 {
+                wrapper.break_line();
+            }
+            wrapper.push_pieces(segment, span.style);
+        }
+    }
+
+    wrapper
+        .lines
+        .into_iter()
+        .map(|line| {
+            Line::from(
+                line.into_iter()
+                    .map(|(text, style)| Span::styled(text, style))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Color;
+
+    fn plain(lines: &[Line]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn wraps_at_spaces_under_the_prefix() {
+        let spans = vec![
+            Span::raw("[ ] "),
+            Span::styled(
+                "call the bank about the mortgage",
+                Style::default().fg(Color::Red),
+            ),
+        ];
+        let lines = wrap_spans(spans, 16, 4);
+        assert_eq!(
+            plain(&lines),
+            vec!["[ ] call the", "    bank about", "    the mortgage"]
+        );
+        // Styles carry over to the wrapped rows
+        assert_eq!(lines[1].spans[1].style.fg, Some(Color::Red));
+        assert!(lines.iter().all(|line| line.width() <= 16));
+    }
+
+    #[test]
+    fn splits_words_wider_than_the_line() {
+        let lines = wrap_spans(
+            vec![Span::raw("see https://example.com/a/long/path")],
+            12,
+            0,
+        );
+        assert_eq!(
+            plain(&lines),
+            vec!["see", "https://exam", "ple.com/a/lo", "ng/path"]
+        );
+        assert_eq!(
+            plain(&wrap_spans(vec![Span::raw("short")], 20, 2)),
+            vec!["short"]
+        );
+        assert_eq!(
+            plain(&wrap_spans(vec![Span::raw("one\n\ntwo")], 20, 0)),
+            vec!["one", "", "two"]
+        );
+    }
+}
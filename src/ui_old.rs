@@ -8,15 +8,6 @@ use ratatui::{
     Frame,
 };
 
-/// Get the platform-appropriate modifier key name
-fn get_modifier_key() -> &'static str {
-    if cfg!(target_os = "macos") {
-        "Cmd"
-    } else {
-        "Ctrl"
-    }
-}
-
 pub fn render_app(f: &mut Frame, app: &App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
@@ -34,8 +25,6 @@ pub fn render_app(f: &mut Frame, app: &App) {
         render_error(f, chunks[1], error_msg);
     } else if let Some(ref info_msg) = app.info_message {
         render_info(f, chunks[1], info_msg);
-    } else if app.show_help {
-        render_help(f, chunks[1]);
     } else if matches!(app.input_mode, InputMode::GoalEditing { .. }) {
         render_goal_editor(f, chunks[1], app);
     } else if matches!(app.input_mode, InputMode::VisionEditing { .. }) {
@@ -501,138 +490,6 @@ fn render_actions_pane(f: &mut Frame, area: Rect, app: &App) {
     f.render_widget(actions_list, area);
 }
 
-fn render_help(f: &mut Frame, area: Rect) {
-    let help_text = vec![
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "FocusFive Keyboard Shortcuts",
-            Style::default().add_modifier(Modifier::BOLD),
-        )]),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Navigation:",
-            Style::default().add_modifier(Modifier::UNDERLINED),
-        )]),
-        Line::from("  Tab       - Switch between Outcomes and Actions panes"),
-        Line::from("  j/↓       - Move down in list"),
-        Line::from("  k/↑       - Move up in list"),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Actions:",
-            Style::default().add_modifier(Modifier::UNDERLINED),
-        )]),
-        Line::from("  Space     - Cycle status: Planned → InProgress → Done → Skipped → Blocked (in Actions pane)"),
-        Line::from("  e/Enter   - Edit action text (in Actions pane)"),
-        Line::from("  a         - Add new action (max 5 per outcome, in Actions pane)"),
-        Line::from("  d         - Delete action (double-press to confirm, min 1 per outcome)"),
-        Line::from("  v         - Edit 5-year vision (in Outcomes pane)"),
-        Line::from("  g         - Edit goal for outcome (in Outcomes pane)"),
-        Line::from("  y         - Copy actions from yesterday"),
-        Line::from("  t         - Apply action template (in Actions pane)"),
-        Line::from("  T         - Save current actions as template (in Actions pane)"),
-        Line::from("  m         - Switch to Morning ritual phase"),
-        Line::from("  n         - Switch to Evening (night) ritual phase"),
-        Line::from("  s         - Save changes"),
-        Line::from("  q         - Save and quit"),
-        Line::from("  ?         - Show/hide this help"),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Edit Mode:",
-            Style::default().add_modifier(Modifier::UNDERLINED),
-        )]),
-        Line::from("  Type      - Add text"),
-        Line::from("  Backspace - Delete character"),
-        Line::from("  Enter     - Save changes"),
-        Line::from("  Ctrl+S    - Save (alternative)"),
-        Line::from("  Ctrl+Enter/Cmd+Enter - Save (alternative)"),
-        Line::from("  Esc       - Cancel editing"),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Vision Edit Mode:",
-            Style::default().add_modifier(Modifier::UNDERLINED),
-        )]),
-        Line::from("  Type       - Add text"),
-        Line::from("  Enter      - New line"),
-        Line::from(format!("  {}+Enter - Save vision", get_modifier_key())),
-        Line::from("  Ctrl+S     - Save vision (alternative)"),
-        Line::from("  F2         - Save vision (universal)"),
-        Line::from("  Esc        - Cancel editing"),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Morning Phase (Intention Setting):",
-            Style::default().add_modifier(Modifier::UNDERLINED),
-        )]),
-        Line::from("  y          - Quick-fill incomplete actions from yesterday"),
-        Line::from("  1-9        - Apply templates (dynamic based on template count)"),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Evening Phase (Reflection & Review):",
-            Style::default().add_modifier(Modifier::UNDERLINED),
-        )]),
-        Line::from("  1-9, a-f   - Quick complete actions (dynamic based on total count)"),
-        Line::from("  r          - Add reflection for current outcome"),
-        Line::from("  d          - Generate daily summary"),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Indicators & Objectives:",
-            Style::default().add_modifier(Modifier::UNDERLINED),
-        )]),
-        Line::from("  o          - Link action to objective (in Actions pane)"),
-        Line::from("  Enter      - Expand/collapse action to show indicators"),
-        Line::from("  u/U        - Enter indicator mode (on action with indicators)"),
-        Line::from("  i          - Update selected indicator (when in indicator mode)"),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Indicator Update Mode:",
-            Style::default().add_modifier(Modifier::UNDERLINED),
-        )]),
-        Line::from("  0-9        - Type custom value directly"),
-        Line::from("  a          - Small increment (+1 count, +15 min, +10%)"),
-        Line::from("  s          - Medium increment (+5 count, +30 min, +25%)"),
-        Line::from("  d          - Large increment (+10 count, +60 min, +50%)"),
-        Line::from("  +/-        - Fine increment/decrement"),
-        Line::from("  c          - Clear value to start fresh"),
-        Line::from("  Enter      - Save update"),
-        Line::from("  Esc        - Cancel update"),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Variable Actions:",
-            Style::default().add_modifier(Modifier::UNDERLINED),
-        )]),
-        Line::from("  • Each outcome can have 1-5 actions (flexible from fixed 3)"),
-        Line::from("  • Use 'a' to add actions, 'd' to remove them"),
-        Line::from("  • Progress shows dynamic counts like [2/4] or [1/5]"),
-        Line::from("  • All features adapt to your action count automatically"),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Tips:",
-            Style::default().add_modifier(Modifier::UNDERLINED),
-        )]),
-        Line::from("  • Complete at least one task daily to maintain your streak"),
-        Line::from("  • Focus on consistency over perfection"),
-        Line::from("  • Review your goals weekly and adjust as needed"),
-        Line::from(""),
-        Line::from(vec![Span::styled(
-            "Press any key to close this help",
-            Style::default().fg(Color::DarkGray),
-        )]),
-    ];
-
-    let help = Paragraph::new(help_text)
-        .block(
-            Block::default()
-                .title(" Help ")
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Yellow)),
-        )
-        .alignment(Alignment::Left);
-
-    // Center the help box
-    let help_area = centered_rect(80, 80, area);
-    f.render_widget(Clear, help_area); // Clear the background
-    f.render_widget(help, help_area);
-}
-
 fn render_info(f: &mut Frame, area: Rect, info_msg: &str) {
     let info_text = vec![
         Line::from(""),
@@ -0,0 +1,250 @@
+//! Unit conversion for indicator values. Values are stored in each
+//! indicator's own unit: minutes, dollars, or a custom label such as "km"
+//! or "hours". Entry also accepts other spellings of the same quantity,
+//! like "1.5h" or "1h30m" for minutes, "1.2k" for dollars and "3mi" for a
+//! km indicator. The display preferences in settings.json decide how
+//! values read back.
+
+use crate::models::{
+    format_minutes, Config, DistanceUnit, DurationDisplay, IndicatorUnit, UnitPreferences,
+};
+use anyhow::{bail, Result};
+
+/// What an indicator measures, as far as conversion goes
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Quantity {
+    /// Time, with the indicator's unit worth this many minutes
+    Duration(f64),
+    Money,
+    Distance(DistanceUnit),
+    Other,
+}
+
+fn quantity(unit: &IndicatorUnit) -> Quantity {
+    match unit {
+        IndicatorUnit::Minutes => Quantity::Duration(1.0),
+        IndicatorUnit::Dollars => Quantity::Money,
+        IndicatorUnit::Custom(label) => match distance_unit(label) {
+            Some(distance) => Quantity::Distance(distance),
+            None if label.to_lowercase().contains("hour") => Quantity::Duration(60.0),
+            None => Quantity::Other,
+        },
+        IndicatorUnit::Count | IndicatorUnit::Percent => Quantity::Other,
+    }
+}
+
+/// The distance a custom unit label names, e.g. "km" or "miles"
+pub fn distance_unit(label: &str) -> Option<DistanceUnit> {
+    match label.trim().to_lowercase().as_str() {
+        "km" | "kms" | "kilometer" | "kilometers" | "kilometre" | "kilometres" => {
+            Some(DistanceUnit::Km)
+        }
+        "mi" | "mile" | "miles" => Some(DistanceUnit::Miles),
+        _ => None,
+    }
+}
+
+/// How many of the indicator's unit one `suffix` is worth
+fn suffix_factor(suffix: &str, unit: &IndicatorUnit) -> Option<f64> {
+    match quantity(unit) {
+        Quantity::Duration(minutes) => {
+            let in_minutes = match suffix {
+                "s" | "sec" | "secs" | "second" | "seconds" => 1.0 / 60.0,
+                "m" | "min" | "mins" | "minute" | "minutes" => 1.0,
+                "h" | "hr" | "hrs" | "hour" | "hours" => 60.0,
+                _ => return None,
+            };
+            Some(in_minutes / minutes)
+        }
+        Quantity::Money => match suffix {
+            "k" => Some(1_000.0),
+            "m" | "mm" => Some(1_000_000.0),
+            "usd" | "dollar" | "dollars" => Some(1.0),
+            _ => None,
+        },
+        Quantity::Distance(own) => {
+            let km = match suffix {
+                "m" => 0.001,
+                other => distance_unit(other)?.km(),
+            };
+            Some(km / own.km())
+        }
+        Quantity::Other => match unit {
+            IndicatorUnit::Custom(label) if label.eq_ignore_ascii_case(suffix) => Some(1.0),
+            IndicatorUnit::Percent if suffix == "%" => Some(1.0),
+            _ => None,
+        },
+    }
+}
+
+/// Parse what was typed for an indicator into its own unit: a plain
+/// number, or numbers with unit suffixes such as "1h30m", "$1,200",
+/// "1.2k" or "5mi". Several parts only add up for durations.
+pub fn parse_value(input: &str, unit: &IndicatorUnit) -> Result<f64> {
+    let cleaned: String = input
+        .trim()
+        .trim_start_matches('$')
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != ',' && *c != '_')
+        .collect::<String>()
+        .to_lowercase();
+    if cleaned.is_empty() {
+        bail!("Enter a value");
+    }
+    if let Ok(value) = cleaned.parse::<f64>() {
+        return Ok(value);
+    }
+
+    let mut parts: Vec<(f64, String)> = Vec::new();
+    let mut rest = cleaned.as_str();
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-'))
+            .unwrap_or(rest.len());
+        let (number, after) = rest.split_at(number_len);
+        let suffix_len = after
+            .find(|c: char| c.is_ascii_digit() || c == '.' || c == '-')
+            .unwrap_or(after.len());
+        let (suffix, after) = after.split_at(suffix_len);
+        let Ok(number) = number.parse::<f64>() else {
+            bail!("Invalid value '{}'", input.trim());
+        };
+        parts.push((number, suffix.to_string()));
+        rest = after;
+    }
+
+    let duration = matches!(quantity(unit), Quantity::Duration(_));
+    if parts.len() > 1 && !duration {
+        bail!("Invalid value '{}'", input.trim());
+    }
+    let mut total = 0.0;
+    let mut previous = None;
+    for (number, suffix) in &parts {
+        // "1h30" reads the bare trailing number as minutes
+        let suffix = match (suffix.as_str(), previous) {
+            ("", Some("h" | "hr" | "hrs" | "hour" | "hours")) => "m",
+            (suffix, _) => suffix,
+        };
+        let factor = if suffix.is_empty() {
+            1.0
+        } else {
+            match suffix_factor(suffix, unit) {
+                Some(factor) => factor,
+                None => bail!("'{}' doesn't convert to {}", suffix, unit_name(unit)),
+            }
+        };
+        total += number * factor;
+        previous = Some(suffix);
+    }
+    Ok(total)
+}
+
+fn unit_name(unit: &IndicatorUnit) -> String {
+    match unit {
+        IndicatorUnit::Count => "a count".to_string(),
+        IndicatorUnit::Minutes => "minutes".to_string(),
+        IndicatorUnit::Dollars => "dollars".to_string(),
+        IndicatorUnit::Percent => "a percentage".to_string(),
+        IndicatorUnit::Custom(label) => label.clone(),
+    }
+}
+
+/// Whether `ch` may be typed after `buffer` in a value field: digits, one
+/// decimal point per number, and unit letters
+pub fn accepts(buffer: &str, ch: char) -> bool {
+    match ch {
+        '.' => !buffer
+            .rsplit(|c: char| c.is_ascii_alphabetic())
+            .next()
+            .unwrap_or_default()
+            .contains('.'),
+        ch => ch.is_ascii_digit() || ch.is_ascii_alphabetic(),
+    }
+}
+
+/// `value` as the preferences would show it, with its unit, or `None`
+/// when they leave the indicator's own formatting alone
+pub fn display(value: f64, unit: &IndicatorUnit, preferences: &UnitPreferences) -> Option<String> {
+    match quantity(unit) {
+        Quantity::Duration(minutes) if preferences.duration == DurationDisplay::Hours => {
+            let total = (value * minutes).round();
+            (total >= 0.0 && total <= f64::from(u32::MAX)).then(|| format_minutes(total as u32))
+        }
+        Quantity::Money if preferences.compact_money => Some(compact_dollars(value)),
+        Quantity::Distance(own) => {
+            let shown = preferences.distance.filter(|preferred| *preferred != own)?;
+            Some(format!(
+                "{:.1} {}",
+                value * own.km() / shown.km(),
+                shown.as_str()
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// "$950", "$1.2k", "$3.4M"
+fn compact_dollars(value: f64) -> String {
+    let sign = if value < 0.0 { "-" } else { "" };
+    let amount = value.abs();
+    let (scaled, suffix) = if amount >= 1_000_000.0 {
+        (amount / 1_000_000.0, "M")
+    } else if amount >= 1_000.0 {
+        (amount / 1_000.0, "k")
+    } else {
+        (amount, "")
+    };
+    let number = format!("{:.1}", scaled);
+    let number = number.strip_suffix(".0").unwrap_or(&number);
+    format!("{}${}{}", sign, number, suffix)
+}
+
+/// Change one display preference named by `word`: hours or minutes, km or
+/// miles (or "defined" to leave distances alone), compact or plain dollars
+pub fn set_preference(preferences: &mut UnitPreferences, word: &str) -> Result<()> {
+    match word.trim().to_lowercase().as_str() {
+        "hours" | "h" => preferences.duration = DurationDisplay::Hours,
+        "minutes" | "min" => preferences.duration = DurationDisplay::Minutes,
+        "compact" => preferences.compact_money = true,
+        "plain" => preferences.compact_money = false,
+        "defined" | "as-defined" => preferences.distance = None,
+        other => match distance_unit(other) {
+            Some(distance) => preferences.distance = Some(distance),
+            None => bail!(
+                "Unknown unit preference '{}' (expected hours, minutes, km, miles, defined, compact or plain)",
+                word
+            ),
+        },
+    }
+    Ok(())
+}
+
+/// Apply `words` to the saved preferences and store them in settings.json
+pub fn save_preferences(words: &[String], config: &Config) -> Result<UnitPreferences> {
+    let mut settings = crate::data::load_settings(config)?;
+    for word in words {
+        set_preference(&mut settings.units, word)?;
+    }
+    crate::data::save_settings(&settings, config)?;
+    Ok(settings.units)
+}
+
+/// "durations in hours, distances in mi, compact dollars"
+pub fn describe(preferences: &UnitPreferences) -> String {
+    format!(
+        "durations in {}, distances {}, {} dollars",
+        match preferences.duration {
+            DurationDisplay::Minutes => "minutes",
+            DurationDisplay::Hours => "hours",
+        },
+        match preferences.distance {
+            Some(distance) => format!("in {}", distance.as_str()),
+            None => "as defined".to_string(),
+        },
+        if preferences.compact_money {
+            "compact"
+        } else {
+            "plain"
+        }
+    )
+}
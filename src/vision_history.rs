@@ -0,0 +1,96 @@
+//! How the five-year vision evolved: the saved versions in which a domain's
+//! text changed, and word-level diffs between consecutive texts.
+
+use crate::models::{OutcomeType, VisionVersion};
+use chrono::NaiveDateTime;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffPart {
+    Same(String),
+    Added(String),
+    Removed(String),
+}
+
+/// One saved change to a domain's vision
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VisionChange {
+    pub saved: NaiveDateTime,
+    /// Text before this change; empty for the first version
+    pub previous: String,
+    pub text: String,
+}
+
+impl VisionChange {
+    pub fn diff(&self) -> Vec<DiffPart> {
+        diff_words(&self.previous, &self.text)
+    }
+}
+
+/// Versions in which the text of `outcome_type` changed, oldest first
+pub fn changes(history: &[VisionVersion], outcome_type: OutcomeType) -> Vec<VisionChange> {
+    let mut changes = Vec::new();
+    let mut previous = String::new();
+    for version in history {
+        let text = version.vision.get_vision(&outcome_type);
+        if text.trim() != previous.trim() {
+            changes.push(VisionChange {
+                saved: version.saved,
+                previous: previous.clone(),
+                text: text.to_string(),
+            });
+            previous = text.to_string();
+        }
+    }
+    changes
+}
+
+/// Word-level diff from `old` to `new`; runs of the same kind are joined
+/// with single spaces
+pub fn diff_words(old: &str, new: &str) -> Vec<DiffPart> {
+    let old: Vec<&str> = old.split_whitespace().collect();
+    let new: Vec<&str> = new.split_whitespace().collect();
+
+    // Longest common subsequence lengths of the suffixes
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if old[i] == new[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut parts: Vec<DiffPart> = Vec::new();
+    let mut push = |part: DiffPart| match (parts.last_mut(), &part) {
+        (Some(DiffPart::Same(run)), DiffPart::Same(word))
+        | (Some(DiffPart::Added(run)), DiffPart::Added(word))
+        | (Some(DiffPart::Removed(run)), DiffPart::Removed(word)) => {
+            run.push(' ');
+            run.push_str(word);
+        }
+        _ => parts.push(part),
+    };
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            push(DiffPart::Same(old[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if lengths[i + 1][j] >= lengths[i][j + 1] {
+            push(DiffPart::Removed(old[i].to_string()));
+            i += 1;
+        } else {
+            push(DiffPart::Added(new[j].to_string()));
+            j += 1;
+        }
+    }
+    for word in &old[i..] {
+        push(DiffPart::Removed(word.to_string()));
+    }
+    for word in &new[j..] {
+        push(DiffPart::Added(word.to_string()));
+    }
+    parts
+}
@@ -0,0 +1,285 @@
+//! WebDAV client for sync.
+//!
+//! Just enough of the protocol to mirror files with a server such as
+//! Nextcloud, ownCloud, Apache mod_dav or `rclone serve webdav`: PROPFIND
+//! lists a folder one level at a time (servers often refuse
+//! `Depth: infinity`), GET, PUT and DELETE move files, and MKCOL creates
+//! the folders a PUT needs. Credentials come from the environment
+//! variables below, so they never land in settings.json.
+
+use anyhow::{anyhow, bail, Context, Result};
+use base64::Engine;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::Read;
+use std::time::Duration;
+
+/// User name for Basic authentication
+pub const USER_ENV: &str = "FOCUSFIVE_WEBDAV_USER";
+/// Password, or app token, for Basic authentication
+pub const PASSWORD_ENV: &str = "FOCUSFIVE_WEBDAV_PASSWORD";
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+const PROPFIND_BODY: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<d:propfind xmlns:d="DAV:"><d:prop><d:resourcetype/><d:getetag/></d:prop></d:propfind>"#;
+
+/// Whether `location` names a WebDAV folder rather than a local one
+pub fn is_url(location: &str) -> bool {
+    location.starts_with("http://") || location.starts_with("https://")
+}
+
+/// A folder on a WebDAV server; files are named by their path below it
+#[derive(Clone)]
+pub struct Client {
+    agent: ureq::Agent,
+    /// The folder URL, ending in a slash
+    base: String,
+    authorization: Option<String>,
+}
+
+impl fmt::Debug for Client {
+    // The authorization header stays out of debug output
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client").field("base", &self.base).finish()
+    }
+}
+
+impl PartialEq for Client {
+    fn eq(&self, other: &Self) -> bool {
+        self.base == other.base && self.authorization == other.authorization
+    }
+}
+
+impl fmt::Display for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.base)
+    }
+}
+
+impl Client {
+    /// A client for the folder at `url`, authenticating with the
+    /// credentials in the environment when there are any
+    pub fn new(url: &str) -> Self {
+        let authorization = std::env::var(USER_ENV).ok().map(|user| {
+            let password = std::env::var(PASSWORD_ENV).unwrap_or_default();
+            let token =
+                base64::engine::general_purpose::STANDARD.encode(format!("{}:{}", user, password));
+            format!("Basic {}", token)
+        });
+        Self {
+            agent: ureq::AgentBuilder::new().timeout(TIMEOUT).build(),
+            base: format!("{}/", url.trim_end_matches('/')),
+            authorization,
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base, encode(path))
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        let request = self.agent.request(method, url);
+        match &self.authorization {
+            Some(authorization) => request.set("Authorization", authorization),
+            None => request,
+        }
+    }
+
+    /// Every file below the folder with its ETag, where the server gives
+    /// one. Fails when the folder does not exist.
+    pub fn list(&self) -> Result<BTreeMap<String, Option<String>>> {
+        let mut files = BTreeMap::new();
+        let mut folders = vec![String::new()];
+        while let Some(folder) = folders.pop() {
+            let url = self.url(&folder);
+            let response = match self
+                .request("PROPFIND", &url)
+                .set("Depth", "1")
+                .set("Content-Type", "application/xml; charset=utf-8")
+                .send_string(PROPFIND_BODY)
+            {
+                Ok(response) => response,
+                Err(ureq::Error::Status(404, _)) if folder.is_empty() => {
+                    bail!("WebDAV folder {} not found; create it first", self.base)
+                }
+                Err(e) => return Err(failure("list", &url, e)),
+            };
+            let body = response
+                .into_string()
+                .with_context(|| format!("Failed to read the listing of {}", url))?;
+            for entry in parse_multistatus(&body)
+                .with_context(|| format!("Unreadable listing of {}", url))?
+            {
+                let Some(path) = self.relative(&entry.href) else {
+                    continue;
+                };
+                if path.trim_end_matches('/') == folder.trim_end_matches('/') {
+                    continue;
+                }
+                if entry.collection {
+                    folders.push(format!("{}/", path.trim_end_matches('/')));
+                } else {
+                    files.insert(path, entry.etag);
+                }
+            }
+        }
+        Ok(files)
+    }
+
+    /// The content of `path`; None when the server has no such file
+    pub fn get(&self, path: &str) -> Result<Option<Vec<u8>>> {
+        let url = self.url(path);
+        match self.request("GET", &url).call() {
+            Ok(response) => {
+                let mut content = Vec::new();
+                response
+                    .into_reader()
+                    .read_to_end(&mut content)
+                    .with_context(|| format!("Failed to download {}", url))?;
+                Ok(Some(content))
+            }
+            Err(ureq::Error::Status(404, _)) => Ok(None),
+            Err(e) => Err(failure("download", &url, e)),
+        }
+    }
+
+    /// Upload `content` to `path`, creating missing folders. Returns the
+    /// new ETag when the server sends one.
+    pub fn put(&self, path: &str, content: &[u8]) -> Result<Option<String>> {
+        let url = self.url(path);
+        let response = match self.request("PUT", &url).send_bytes(content) {
+            // 409 Conflict: a folder on the way is missing
+            Err(ureq::Error::Status(409, _)) => {
+                self.create_folders(path)?;
+                self.request("PUT", &url).send_bytes(content)
+            }
+            sent => sent,
+        }
+        .map_err(|e| failure("upload", &url, e))?;
+        Ok(response.header("ETag").map(str::to_string))
+    }
+
+    /// Delete `path`; a file already gone is not an error
+    pub fn delete(&self, path: &str) -> Result<()> {
+        let url = self.url(path);
+        match self.request("DELETE", &url).call() {
+            Ok(_) | Err(ureq::Error::Status(404, _)) => Ok(()),
+            Err(e) => Err(failure("delete", &url, e)),
+        }
+    }
+
+    /// MKCOL each folder above `path`, outermost first
+    fn create_folders(&self, path: &str) -> Result<()> {
+        let parts: Vec<&str> = path.split('/').collect();
+        for depth in 1..parts.len() {
+            let url = format!("{}/", self.url(&parts[..depth].join("/")));
+            match self.request("MKCOL", &url).call() {
+                // 405 Method Not Allowed: the folder already exists
+                Ok(_) | Err(ureq::Error::Status(405, _)) => {}
+                Err(e) => return Err(failure("create", &url, e)),
+            }
+        }
+        Ok(())
+    }
+
+    /// The path below the folder of a listed href, which servers send as
+    /// an absolute path or a full URL, percent-encoded
+    fn relative(&self, href: &str) -> Option<String> {
+        let href = decode(strip_origin(href));
+        let base = decode(strip_origin(&self.base));
+        href.strip_prefix(&base).map(str::to_string)
+    }
+}
+
+fn failure(action: &str, url: &str, error: ureq::Error) -> anyhow::Error {
+    match error {
+        ureq::Error::Status(status, response) => anyhow!(
+            "WebDAV server refused to {} {} ({} {})",
+            action,
+            url,
+            status,
+            response.status_text()
+        ),
+        e => anyhow!(e).context(format!("WebDAV server unreachable: {}", url)),
+    }
+}
+
+/// One `<response>` of a PROPFIND reply
+struct Entry {
+    href: String,
+    collection: bool,
+    etag: Option<String>,
+}
+
+fn parse_multistatus(body: &str) -> Result<Vec<Entry>> {
+    let doc = roxmltree::Document::parse(body)?;
+    let dav = |node: &roxmltree::Node, name: &str| {
+        node.is_element()
+            && node.tag_name().name() == name
+            && node.tag_name().namespace() == Some("DAV:")
+    };
+    Ok(doc
+        .descendants()
+        .filter(|node| dav(node, "response"))
+        .filter_map(|response| {
+            let text = |name: &str| {
+                response
+                    .descendants()
+                    .find(|node| dav(node, name))
+                    .and_then(|node| node.text())
+                    .map(|text| text.trim().to_string())
+                    .filter(|text| !text.is_empty())
+            };
+            Some(Entry {
+                href: text("href")?,
+                collection: response.descendants().any(|node| dav(&node, "collection")),
+                etag: text("getetag"),
+            })
+        })
+        .collect())
+}
+
+/// The path of a URL, or the href unchanged when it is a path already
+fn strip_origin(href: &str) -> &str {
+    match href.split_once("://") {
+        Some((_, rest)) => rest.find('/').map_or("/", |at| &rest[at..]),
+        None => href,
+    }
+}
+
+/// Percent-encode a path, keeping its slashes
+fn encode(path: &str) -> String {
+    let mut encoded = String::new();
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+fn decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = (bytes[i] == b'%')
+            .then(|| text.get(i + 1..i + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match hex {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
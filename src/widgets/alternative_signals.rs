@@ -46,11 +46,12 @@ impl<'a> AlternativeSignalsWidget<'a> {
     fn format_signal_line(theme: &FinancialTheme, signal: &AlternativeSignal<'a>) -> ListItem<'a> {
         let indicator = signal.indicator;
         let strength = Self::compute_signal_strength(indicator, signal.latest_value);
-        let color = Self::signal_color(theme, strength);
+        let status = FinancialTheme::status_severity(strength);
+        let color = theme.severity_color(status);
         let weight_text = format!("Wt {:>5.1}%", signal.weight.max(0.0));
         let value_text = Self::format_value(indicator, signal.latest_value);
         let target_text = indicator
-            .target
+            .current_target()
             .map(|target| Self::format_value(indicator, target))
             .unwrap_or_else(|| "--".to_string());
         let delta_text = Self::directional_delta(indicator, signal.latest_value)
@@ -119,7 +120,7 @@ impl<'a> AlternativeSignalsWidget<'a> {
         spans.push(Span::styled(bar, Style::default().fg(color)));
         spans.push(Span::raw(" "));
         spans.push(Span::styled(
-            format!("{:>5.1}%", strength),
+            format!("{:>5.1}% {}", strength, status.symbol()),
             Style::default().fg(color),
         ));
 
@@ -127,7 +128,7 @@ impl<'a> AlternativeSignalsWidget<'a> {
     }
 
     fn compute_signal_strength(indicator: &IndicatorDef, value: f64) -> f64 {
-        let target = indicator.target.unwrap_or(100.0);
+        let target = indicator.current_target().unwrap_or(100.0);
 
         match indicator.direction {
             IndicatorDirection::HigherIsBetter => {
@@ -153,8 +154,14 @@ impl<'a> AlternativeSignalsWidget<'a> {
                 }
             }
             IndicatorDirection::WithinRange => {
-                let tolerance = (target.abs() * 0.2).max(1.0);
-                let diff = (value - target).abs();
+                let range = indicator.current_range().unwrap_or((target, target));
+                let width = range.1 - range.0;
+                let tolerance = if width.is_finite() && width > 0.0 {
+                    width
+                } else {
+                    (target.abs() * 0.2).max(1.0)
+                };
+                let diff = IndicatorDef::range_deviation(value, range).abs();
                 let ratio = (diff / tolerance).min(1.0);
                 (100.0 - ratio * 100.0).clamp(0.0, 100.0)
             }
@@ -162,21 +169,17 @@ impl<'a> AlternativeSignalsWidget<'a> {
     }
 
     fn directional_delta(indicator: &IndicatorDef, value: f64) -> Option<f64> {
-        indicator.target.map(|target| match indicator.direction {
-            IndicatorDirection::HigherIsBetter => value - target,
-            IndicatorDirection::LowerIsBetter => target - value,
-            IndicatorDirection::WithinRange => target - value,
-        })
-    }
-
-    fn signal_color(theme: &FinancialTheme, strength: f64) -> ratatui::style::Color {
-        if strength >= 80.0 {
-            theme.positive
-        } else if strength >= 50.0 {
-            theme.neutral
-        } else {
-            theme.negative
-        }
+        indicator
+            .current_target()
+            .map(|target| match indicator.direction {
+                IndicatorDirection::HigherIsBetter => value - target,
+                IndicatorDirection::LowerIsBetter => target - value,
+                IndicatorDirection::WithinRange => match indicator.current_range() {
+                    // Distance back into the band; zero while inside it
+                    Some(range) => -IndicatorDef::range_deviation(value, range),
+                    None => target - value,
+                },
+            })
     }
 
     fn create_signal_bar(percentage: f64) -> String {
@@ -0,0 +1,341 @@
+use crate::models::Observation;
+use crate::ui::stats::pearson;
+use crate::ui::theme::FinancialTheme;
+use chrono::NaiveDate;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    symbols,
+    text::Span,
+    widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Widget},
+};
+
+type Points = Vec<(f64, f64)>;
+
+/// How two series with different units share one y axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompareScale {
+    /// Each series rescaled to 0-100% of its own min/max
+    #[default]
+    Normalized,
+    /// First series on its own scale, second mapped onto it; labels show both
+    DualAxis,
+}
+
+impl CompareScale {
+    pub fn toggle(self) -> Self {
+        match self {
+            CompareScale::Normalized => CompareScale::DualAxis,
+            CompareScale::DualAxis => CompareScale::Normalized,
+        }
+    }
+}
+
+/// One indicator's observations to overlay
+pub struct CompareSeries<'a> {
+    pub name: &'a str,
+    pub indicator_id: &'a str,
+    pub observations: &'a [Observation],
+}
+
+/// Overlays two indicator series on shared date axis
+pub struct CompareChart<'a> {
+    first: CompareSeries<'a>,
+    second: CompareSeries<'a>,
+    theme: &'a FinancialTheme,
+    scale: CompareScale,
+    title_color: Option<Color>,
+}
+
+impl<'a> CompareChart<'a> {
+    pub fn new(
+        first: CompareSeries<'a>,
+        second: CompareSeries<'a>,
+        theme: &'a FinancialTheme,
+    ) -> Self {
+        Self {
+            first,
+            second,
+            theme,
+            scale: CompareScale::default(),
+            title_color: None,
+        }
+    }
+
+    pub fn scale(mut self, scale: CompareScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn title_color(mut self, color: Color) -> Self {
+        self.title_color = Some(color);
+        self
+    }
+
+    fn points(series: &CompareSeries<'a>) -> Vec<(NaiveDate, f64)> {
+        let mut points: Vec<(NaiveDate, f64)> = series
+            .observations
+            .iter()
+            .filter(|obs| obs.indicator_id == series.indicator_id)
+            .map(|obs| (obs.when, obs.value))
+            .collect();
+        points.sort_by_key(|(when, _)| *when);
+        points
+    }
+
+    fn value_bounds(points: &[(NaiveDate, f64)]) -> Option<(f64, f64)> {
+        let min = points.iter().map(|(_, v)| *v).fold(f64::INFINITY, f64::min);
+        let max = points
+            .iter()
+            .map(|(_, v)| *v)
+            .fold(f64::NEG_INFINITY, f64::max);
+        (min.is_finite() && max.is_finite()).then_some((min, max))
+    }
+
+    /// Map `value` from `from` onto `to`; flat series land mid-range
+    fn rescale(value: f64, from: (f64, f64), to: (f64, f64)) -> f64 {
+        let span = from.1 - from.0;
+        if span.abs() < f64::EPSILON {
+            return (to.0 + to.1) / 2.0;
+        }
+        to.0 + (value - from.0) / span * (to.1 - to.0)
+    }
+
+    /// Chart data for both series with x as days since the earliest point,
+    /// plus the y bounds of the plotted values.
+    fn datasets(&self) -> (Points, Points, (f64, f64)) {
+        let first = Self::points(&self.first);
+        let second = Self::points(&self.second);
+        let Some(start) = first.iter().chain(second.iter()).map(|(d, _)| *d).min() else {
+            return (Vec::new(), Vec::new(), (0.0, 1.0));
+        };
+        let x = |when: NaiveDate| (when - start).num_days() as f64;
+
+        let first_bounds = Self::value_bounds(&first).unwrap_or((0.0, 1.0));
+        let second_bounds = Self::value_bounds(&second).unwrap_or((0.0, 1.0));
+        let target = match self.scale {
+            CompareScale::Normalized => (0.0, 100.0),
+            CompareScale::DualAxis => first_bounds,
+        };
+
+        // In dual-axis mode target == first_bounds, so the first series stays raw
+        let first_data = first
+            .iter()
+            .map(|(when, value)| (x(*when), Self::rescale(*value, first_bounds, target)))
+            .collect();
+        let second_data = second
+            .iter()
+            .map(|(when, value)| (x(*when), Self::rescale(*value, second_bounds, target)))
+            .collect();
+
+        (first_data, second_data, target)
+    }
+
+    /// Pearson correlation over days where both indicators have a value
+    pub fn correlation(&self) -> Option<f64> {
+        let second = Self::points(&self.second);
+        let pairs: Vec<(f64, f64)> = Self::points(&self.first)
+            .into_iter()
+            .filter_map(|(when, a)| {
+                second
+                    .iter()
+                    .rev()
+                    .find(|(other, _)| *other == when)
+                    .map(|(_, b)| (a, *b))
+            })
+            .collect();
+        pearson(&pairs)
+    }
+
+    fn y_axis_labels(&self, bounds: (f64, f64)) -> Vec<Span<'static>> {
+        let fractions = [0.0, 0.5, 1.0];
+        match self.scale {
+            CompareScale::Normalized => fractions
+                .iter()
+                .map(|f| Span::raw(format!("{:.0}%", f * 100.0)))
+                .collect(),
+            CompareScale::DualAxis => {
+                let second_bounds =
+                    Self::value_bounds(&Self::points(&self.second)).unwrap_or((0.0, 1.0));
+                fractions
+                    .iter()
+                    .map(|f| {
+                        let a = bounds.0 + (bounds.1 - bounds.0) * f;
+                        let b = second_bounds.0 + (second_bounds.1 - second_bounds.0) * f;
+                        Span::raw(format!("{a:.1}│{b:.1}"))
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+impl<'a> Widget for CompareChart<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let (first_data, second_data, bounds) = self.datasets();
+        let x_max = first_data
+            .iter()
+            .chain(second_data.iter())
+            .map(|(x, _)| *x)
+            .fold(1.0, f64::max);
+        let y_padding = ((bounds.1 - bounds.0) * 0.05).max(0.5);
+        let y_labels = self.y_axis_labels(bounds);
+
+        let datasets = vec![
+            Dataset::default()
+                .name(self.first.name)
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(self.theme.accent_yellow))
+                .data(&first_data),
+            Dataset::default()
+                .name(self.second.name)
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(self.theme.info))
+                .data(&second_data),
+        ];
+
+        let scale_label = match self.scale {
+            CompareScale::Normalized => "NORMALIZED",
+            CompareScale::DualAxis => "DUAL AXIS",
+        };
+        let correlation = self
+            .correlation()
+            .map(|r| format!(" r={r:+.2}"))
+            .unwrap_or_default();
+        let title = format!(
+            " {} vs {} ({}){} ",
+            self.first.name.to_uppercase(),
+            self.second.name.to_uppercase(),
+            scale_label,
+            correlation
+        );
+
+        let chart = Chart::new(datasets)
+            .block(
+                Block::default()
+                    .title(title)
+                    .title_style(
+                        Style::default()
+                            .fg(self.title_color.unwrap_or(self.theme.text_dim))
+                            .add_modifier(Modifier::BOLD),
+                    )
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(self.theme.text_dim))
+                    .style(Style::default().bg(self.theme.bg_panel)),
+            )
+            .x_axis(
+                Axis::default()
+                    .style(Style::default().fg(self.theme.text_dim))
+                    .bounds([0.0, x_max]),
+            )
+            .y_axis(
+                Axis::default()
+                    .style(Style::default().fg(self.theme.text_dim))
+                    .bounds([bounds.0 - y_padding, bounds.1 + y_padding])
+                    .labels(y_labels),
+            );
+
+        chart.render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{IndicatorUnit, ObservationSource};
+    use chrono::{Duration, Utc};
+
+    fn observation(id: &str, offset: i64, value: f64) -> Observation {
+        Observation {
+            id: format!("{id}-{offset}"),
+            indicator_id: id.to_string(),
+            when: NaiveDate::from_ymd_opt(2025, 9, 1).unwrap() + Duration::days(offset),
+            value,
+            unit: IndicatorUnit::Count,
+            source: ObservationSource::Manual,
+            action_id: None,
+            note: None,
+            created: Utc::now(),
+            at: None,
+        }
+    }
+
+    fn series<'a>(id: &'a str, observations: &'a [Observation]) -> CompareSeries<'a> {
+        CompareSeries {
+            name: id,
+            indicator_id: id,
+            observations,
+        }
+    }
+
+    #[test]
+    fn normalized_scale_maps_both_series_to_percent() {
+        let theme = FinancialTheme::default();
+        let observations = vec![
+            observation("sleep", 0, 6.0),
+            observation("sleep", 2, 8.0),
+            observation("steps", 1, 4000.0),
+            observation("steps", 2, 12000.0),
+        ];
+        let chart = CompareChart::new(
+            series("sleep", &observations),
+            series("steps", &observations),
+            &theme,
+        );
+
+        let (first, second, bounds) = chart.datasets();
+        assert_eq!(bounds, (0.0, 100.0));
+        assert_eq!(first, vec![(0.0, 0.0), (2.0, 100.0)]);
+        assert_eq!(second, vec![(1.0, 0.0), (2.0, 100.0)]);
+    }
+
+    #[test]
+    fn dual_axis_keeps_first_series_raw() {
+        let theme = FinancialTheme::default();
+        let observations = vec![
+            observation("sleep", 0, 6.0),
+            observation("sleep", 1, 8.0),
+            observation("steps", 0, 4000.0),
+            observation("steps", 1, 12000.0),
+        ];
+        let chart = CompareChart::new(
+            series("sleep", &observations),
+            series("steps", &observations),
+            &theme,
+        )
+        .scale(CompareScale::DualAxis);
+
+        let (first, second, bounds) = chart.datasets();
+        assert_eq!(bounds, (6.0, 8.0));
+        assert_eq!(first, vec![(0.0, 6.0), (1.0, 8.0)]);
+        assert_eq!(second, vec![(0.0, 6.0), (1.0, 8.0)]);
+        assert_eq!(chart.y_axis_labels(bounds)[2].content, "8.0│12000.0");
+    }
+
+    #[test]
+    fn correlation_uses_shared_days() {
+        let theme = FinancialTheme::default();
+        let observations: Vec<Observation> = (0..4)
+            .flat_map(|day| {
+                [
+                    observation("a", day, day as f64),
+                    observation("b", day, 10.0 - day as f64),
+                ]
+            })
+            .collect();
+        let chart = CompareChart::new(
+            series("a", &observations),
+            series("b", &observations),
+            &theme,
+        );
+        let r = chart.correlation().unwrap();
+        assert!((r + 1.0).abs() < 1e-9);
+
+        let sparse = vec![observation("a", 0, 1.0), observation("b", 0, 2.0)];
+        let chart = CompareChart::new(series("a", &sparse), series("b", &sparse), &theme);
+        assert_eq!(chart.correlation(), None);
+    }
+}
@@ -0,0 +1,107 @@
+use crate::ui::theme::FinancialTheme;
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Widget, Wrap},
+};
+
+/// Gratitude streak and a resurfaced past item
+pub struct GratitudeWidget<'a> {
+    streak: u32,
+    recall: Option<(String, &'a str)>,
+    title_color: Option<Color>,
+    theme: &'a FinancialTheme,
+}
+
+impl<'a> GratitudeWidget<'a> {
+    pub fn new(streak: u32, theme: &'a FinancialTheme) -> Self {
+        Self {
+            streak,
+            recall: None,
+            title_color: None,
+            theme,
+        }
+    }
+
+    /// Past item and when it was written, e.g. "On this day in 2024"
+    pub fn recall(mut self, when: String, item: &'a str) -> Self {
+        self.recall = Some((when, item));
+        self
+    }
+
+    pub fn title_color(mut self, color: Color) -> Self {
+        self.title_color = Some(color);
+        self
+    }
+}
+
+impl<'a> Widget for GratitudeWidget<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let streak = if self.streak == 0 {
+            Span::styled(
+                "No gratitude streak yet",
+                Style::default().fg(self.theme.text_secondary),
+            )
+        } else {
+            Span::styled(
+                format!(
+                    "🙏 {} day{} of gratitude",
+                    self.streak,
+                    if self.streak == 1 { "" } else { "s" }
+                ),
+                Style::default()
+                    .fg(self.theme.positive)
+                    .add_modifier(Modifier::BOLD),
+            )
+        };
+        let mut lines = vec![Line::from(streak)];
+        match self.recall {
+            Some((when, item)) => lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{} you were grateful for ", when),
+                    Style::default().fg(self.theme.text_secondary),
+                ),
+                Span::styled(item, Style::default().fg(self.theme.text_primary)),
+            ])),
+            None => lines.push(Line::styled(
+                "Add three things in the evening reflection (S)",
+                Style::default().fg(self.theme.text_dim),
+            )),
+        }
+
+        let block = Block::default()
+            .title(" GRATITUDE ")
+            .title_style(Style::default().fg(self.title_color.unwrap_or(self.theme.text_dim)))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.text_dim));
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: true })
+            .style(Style::default().bg(self.theme.bg_panel))
+            .render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn widget_renders_streak_and_recall() {
+        let theme = FinancialTheme::default();
+        let widget = GratitudeWidget::new(4, &theme)
+            .recall("On this day in 2024".to_string(), "a quiet morning");
+
+        let area = Rect::new(0, 0, 80, 4);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+
+        let rows: Vec<String> = (0..area.height)
+            .map(|y| (0..area.width).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect();
+        assert!(rows[1].contains("4 days of gratitude"));
+        assert!(rows[2].contains("you were grateful for a quiet morning"));
+    }
+}
@@ -1,5 +1,9 @@
-use crate::models::{IndicatorDef, IndicatorDirection, Observation};
-use crate::ui::theme::FinancialTheme;
+use crate::models::{
+    CapStatus, IndicatorDef, IndicatorDirection, MonthToDate, Observation, PeriodProgress,
+    TargetPeriod,
+};
+use crate::ui::theme::{FinancialTheme, Severity};
+use chrono::NaiveDate;
 use ratatui::{
     buffer::Buffer,
     layout::{Constraint, Rect},
@@ -15,8 +19,11 @@ struct MetricSnapshot {
     spread_pct: f64,
     trend_delta: f64,
     trend_arrow: Option<char>,
+    value_status: Severity,
+    spread_status: Severity,
     value_color: Color,
     spread_color: Color,
+    cap_status: Option<CapStatus>,
 }
 
 impl MetricSnapshot {
@@ -26,7 +33,7 @@ impl MetricSnapshot {
         previous: f64,
         theme: &FinancialTheme,
     ) -> Self {
-        let target = indicator.target.unwrap_or(100.0);
+        let target = indicator.current_target().unwrap_or(100.0);
         // Avoid divide-by-zero when the target is zero (common for cost/defect metrics)
         let denominator = if target.abs() < f64::EPSILON {
             1.0
@@ -34,7 +41,13 @@ impl MetricSnapshot {
             target.abs()
         };
 
-        let spread_pct = ((current - target).abs() * 100.0 / denominator).min(999.9);
+        // WithinRange indicators are measured against the band, not its midpoint
+        let range = indicator.current_range();
+        let gap = match range {
+            Some(range) => IndicatorDef::range_deviation(current, range).abs(),
+            None => (current - target).abs(),
+        };
+        let spread_pct = (gap * 100.0 / denominator).min(999.9);
         let trend_delta = current - previous;
         let trend_arrow = if trend_delta.abs() < f64::EPSILON {
             None
@@ -44,30 +57,46 @@ impl MetricSnapshot {
             Some('↓')
         };
 
-        let value_color = match indicator.direction {
-            IndicatorDirection::HigherIsBetter => theme.get_trend_color(current, previous),
-            IndicatorDirection::LowerIsBetter => theme.get_trend_color(previous, current),
+        let mut value_status = match indicator.direction {
+            IndicatorDirection::HigherIsBetter => FinancialTheme::trend_severity(current, previous),
+            IndicatorDirection::LowerIsBetter => FinancialTheme::trend_severity(previous, current),
             IndicatorDirection::WithinRange => {
-                let distance_current = (current - target).abs();
-                let distance_previous = (previous - target).abs();
-                if distance_current < distance_previous {
-                    theme.positive
+                let range = range.unwrap_or((target, target));
+                let distance_current = IndicatorDef::range_deviation(current, range).abs();
+                let distance_previous = IndicatorDef::range_deviation(previous, range).abs();
+                if distance_current <= f64::EPSILON || distance_current < distance_previous {
+                    Severity::Good
                 } else if distance_current > distance_previous {
-                    theme.negative
+                    Severity::Bad
                 } else {
-                    theme.neutral
+                    Severity::Warning
                 }
             }
         };
 
-        let spread_color = if spread_pct < 10.0 {
-            theme.positive
+        let mut spread_status = if spread_pct < 10.0 {
+            Severity::Good
         } else if spread_pct < 25.0 {
-            theme.neutral
+            Severity::Warning
         } else {
-            theme.negative
+            Severity::Bad
         };
 
+        // Daily caps override the trend: at or over the cap is a warning regardless of direction
+        let cap_status = indicator.cap_status(current);
+        match cap_status {
+            Some(CapStatus::Exceeded) => {
+                value_status = Severity::Bad;
+                spread_status = Severity::Bad;
+            }
+            Some(CapStatus::Approaching) => {
+                value_status = Severity::Warning;
+                spread_status = Severity::Warning;
+            }
+            Some(CapStatus::Under) => spread_status = Severity::Good,
+            None => {}
+        }
+
         Self {
             current,
             previous,
@@ -75,8 +104,11 @@ impl MetricSnapshot {
             spread_pct,
             trend_delta,
             trend_arrow,
-            value_color,
-            spread_color,
+            value_status,
+            spread_status,
+            value_color: theme.severity_color(value_status),
+            spread_color: theme.severity_color(spread_status),
+            cap_status,
         }
     }
 }
@@ -86,6 +118,7 @@ pub struct LiveMetricsWidget<'a> {
     observations: &'a [Observation],
     theme: &'a FinancialTheme,
     block: Option<Block<'a>>,
+    today: NaiveDate,
 }
 
 impl<'a> LiveMetricsWidget<'a> {
@@ -99,6 +132,7 @@ impl<'a> LiveMetricsWidget<'a> {
             observations,
             theme,
             block: None,
+            today: chrono::Local::now().date_naive(),
         }
     }
 
@@ -107,25 +141,117 @@ impl<'a> LiveMetricsWidget<'a> {
         self
     }
 
-    fn current_and_previous(&self, indicator_id: &str) -> (Option<f64>, Option<f64>) {
-        let mut previous = None;
-        let mut current = None;
+    /// Day month-to-date totals run up to; defaults to today
+    pub fn today(mut self, today: NaiveDate) -> Self {
+        self.today = today;
+        self
+    }
 
-        for value in self
-            .observations
-            .iter()
-            .filter(|obs| obs.indicator_id == indicator_id)
-            .map(|obs| obs.value)
-        {
-            previous = current;
-            current = Some(value);
-        }
+    /// Dollars rows: month-to-date total against the monthly target, with
+    /// the projected month-end total in the trend column
+    fn month_to_date_row(
+        &self,
+        indicator: &IndicatorDef,
+        mtd: MonthToDate,
+        indicator_width: usize,
+    ) -> Row<'a> {
+        let name = format!("{} MTD", indicator.name);
+        let (target_text, spread_text, color) = match mtd.target {
+            Some(target) => {
+                let denominator = target.abs().max(1.0);
+                let gap_pct = ((mtd.projected - target) * 100.0 / denominator).clamp(-999.9, 999.9);
+                let on_track = match indicator.direction {
+                    IndicatorDirection::LowerIsBetter => mtd.projected <= target,
+                    _ => mtd.projected >= target,
+                };
+                let status = if on_track {
+                    Severity::Good
+                } else if gap_pct.abs() < 10.0 {
+                    Severity::Warning
+                } else {
+                    Severity::Bad
+                };
+                (
+                    self.format_metric_value(target, 0),
+                    format!("{} {:+.1}%", status.symbol(), gap_pct),
+                    self.theme.severity_color(status),
+                )
+            }
+            None => ("-".to_string(), "-".to_string(), self.theme.text_secondary),
+        };
 
-        (current, previous)
+        Row::new(vec![
+            Cell::from(self.format_indicator_name(&name, indicator_width))
+                .style(Style::default().fg(self.theme.text_primary)),
+            Cell::from(self.format_metric_value(mtd.total, 0))
+                .style(Style::default().fg(color).add_modifier(Modifier::BOLD)),
+            Cell::from(target_text).style(Style::default().fg(self.theme.text_secondary)),
+            Cell::from(spread_text).style(Style::default().fg(color)),
+            Cell::from(format!("→ {}", self.format_metric_value(mtd.projected, 0)))
+                .style(Style::default().fg(color)),
+        ])
+    }
+
+    /// Weekly and monthly targets: the period's total so far against the
+    /// share of the target due by today, with the whole target in the
+    /// trend column
+    fn period_row(
+        &self,
+        indicator: &IndicatorDef,
+        progress: PeriodProgress,
+        indicator_width: usize,
+    ) -> Row<'a> {
+        let suffix = match progress.period {
+            TargetPeriod::Week => "WTD",
+            TargetPeriod::Month => "MTD",
+            TargetPeriod::Day => "",
+        };
+        let name = format!("{} {}", indicator.name, suffix);
+        let gap_pct = progress
+            .pace_percent()
+            .map(|percent| (percent - 100.0).clamp(-999.9, 999.9))
+            .unwrap_or(0.0);
+        let on_pace = match indicator.direction {
+            IndicatorDirection::LowerIsBetter => progress.total <= progress.pro_rated,
+            _ => progress.total >= progress.pro_rated,
+        };
+        let status = if on_pace {
+            Severity::Good
+        } else if gap_pct.abs() < 10.0 {
+            Severity::Warning
+        } else {
+            Severity::Bad
+        };
+        let color = self.theme.severity_color(status);
+
+        Row::new(vec![
+            Cell::from(self.format_indicator_name(&name, indicator_width))
+                .style(Style::default().fg(self.theme.text_primary)),
+            Cell::from(self.format_metric_value(progress.total, 1))
+                .style(Style::default().fg(color).add_modifier(Modifier::BOLD)),
+            Cell::from(self.format_metric_value(progress.pro_rated, 1))
+                .style(Style::default().fg(self.theme.text_secondary)),
+            Cell::from(format!("{} {:+.1}%", status.symbol(), gap_pct))
+                .style(Style::default().fg(color)),
+            Cell::from(format!(
+                "/ {}",
+                self.format_metric_value(progress.target, 0)
+            ))
+            .style(Style::default().fg(self.theme.text_secondary)),
+        ])
+    }
+
+    /// The latest day's value and the day before it, each combined by the
+    /// indicator's aggregation
+    fn current_and_previous(&self, indicator: &IndicatorDef) -> (Option<f64>, Option<f64>) {
+        let days = indicator.daily_values(self.observations);
+        let mut values = days.iter().rev().map(|(_, value)| *value);
+        let current = values.next();
+        (current, values.next())
     }
 
     fn build_snapshot(&self, indicator: &IndicatorDef) -> MetricSnapshot {
-        let (current, previous) = self.current_and_previous(&indicator.id);
+        let (current, previous) = self.current_and_previous(indicator);
         let current = current.unwrap_or(0.0);
         let previous = previous.unwrap_or(current);
 
@@ -165,31 +291,53 @@ impl<'a> LiveMetricsWidget<'a> {
     }
 
     fn format_metric_row(&self, indicator: &IndicatorDef, _row_index: usize, indicator_width: usize) -> Row<'a> {
+        if let Some(mtd) = indicator.month_to_date(self.observations, self.today) {
+            return self.month_to_date_row(indicator, mtd, indicator_width);
+        }
+        if let Some(progress) = indicator.period_to_date(self.observations, self.today) {
+            return self.period_row(indicator, progress, indicator_width);
+        }
+
         let snapshot = self.build_snapshot(indicator);
 
         // Format indicator name without arrow - no selection needed
         let indicator_name = self.format_indicator_name(&indicator.name, indicator_width);
+        let name_style = match snapshot.cap_status {
+            Some(CapStatus::Exceeded) => Style::default()
+                .fg(self.theme.negative)
+                .add_modifier(Modifier::BOLD),
+            _ => Style::default().fg(self.theme.text_primary),
+        };
 
         let mut cells = vec![
-            Cell::from(indicator_name)
-                .style(Style::default().fg(self.theme.text_primary)),
+            Cell::from(indicator_name).style(name_style),
             Cell::from(self.format_metric_value(snapshot.current, 1))
                 .style(Style::default()
                     .fg(snapshot.value_color)
                     .add_modifier(Modifier::BOLD)),
             Cell::from(self.format_metric_value(snapshot.target, 1))
                 .style(Style::default().fg(self.theme.text_secondary)),
-            Cell::from(format!("{}%", self.format_metric_value(snapshot.spread_pct, 1)))
+            Cell::from(format!(
+                "{} {}%",
+                snapshot.spread_status.symbol(),
+                self.format_metric_value(snapshot.spread_pct, 1)
+            ))
                 .style(Style::default().fg(snapshot.spread_color)),
         ];
 
         // Add trend column if trend data exists
         if let Some(arrow) = snapshot.trend_arrow {
-            cells.push(Cell::from(format!("{} {:+.1}", arrow, snapshot.trend_delta))
-                .style(Style::default().fg(snapshot.value_color)));
+            cells.push(
+                Cell::from(format!(
+                    "{} {} {:+.1}",
+                    snapshot.value_status.symbol(),
+                    arrow,
+                    snapshot.trend_delta
+                ))
+                .style(Style::default().fg(snapshot.value_color)),
+            );
         } else {
-            cells.push(Cell::from("-")
-                .style(Style::default().fg(self.theme.text_secondary)));
+            cells.push(Cell::from("-").style(Style::default().fg(self.theme.text_secondary)));
         }
 
         Row::new(cells)
@@ -275,7 +423,7 @@ impl<'a> Widget for LiveMetricsWidget<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{IndicatorKind, IndicatorUnit, ObservationSource};
+    use crate::models::{IndicatorAggregation, IndicatorKind, IndicatorUnit, ObservationSource};
     use chrono::{Duration, NaiveDate, Utc};
 
     fn indicator(
@@ -298,6 +446,16 @@ mod tests {
             modified: now,
             lineage_of: None,
             notes: None,
+            target_schedule: Vec::new(),
+            range_min: None,
+            range_max: None,
+            monthly_target: None,
+            ratio_of: None,
+            exclude_from_signals: false,
+            auto_rules: Vec::new(),
+            group: None,
+            aggregation: Default::default(),
+            target_period: Default::default(),
         }
     }
 
@@ -313,6 +471,7 @@ mod tests {
             action_id: None,
             note: None,
             created: Utc::now(),
+            at: None,
         }
     }
 
@@ -336,6 +495,29 @@ mod tests {
         assert_eq!(snapshot.trend_arrow, Some('↑'));
     }
 
+    #[test]
+    fn same_day_observations_follow_the_aggregation() {
+        let theme = FinancialTheme::default();
+        let mut minutes = indicator("ex", "Exercise", Some(60.0), IndicatorDirection::HigherIsBetter);
+        let observations = vec![
+            observation("ex", 25.0, 0),
+            observation("ex", 20.0, 1),
+            observation("ex", 30.0, 1),
+        ];
+
+        // Last keeps the later entry of the day, not the earlier one as previous
+        let indicators = vec![minutes.clone()];
+        let widget = LiveMetricsWidget::new(&indicators, &observations, &theme);
+        let snapshot = widget.build_snapshot(&indicators[0]);
+        assert_eq!((snapshot.current, snapshot.previous), (30.0, 25.0));
+
+        minutes.aggregation = IndicatorAggregation::Sum;
+        let indicators = vec![minutes];
+        let widget = LiveMetricsWidget::new(&indicators, &observations, &theme);
+        let snapshot = widget.build_snapshot(&indicators[0]);
+        assert_eq!((snapshot.current, snapshot.previous), (50.0, 25.0));
+    }
+
     #[test]
     fn lower_is_better_trend_flips_color() {
         let theme = FinancialTheme::default();
@@ -354,6 +536,36 @@ mod tests {
         assert!(snapshot.trend_delta < 0.0);
     }
 
+    #[test]
+    fn lower_is_better_cap_turns_red_when_exceeded() {
+        let theme = FinancialTheme::default();
+        let indicators = vec![indicator(
+            "screen",
+            "Screen time",
+            Some(120.0),
+            IndicatorDirection::LowerIsBetter,
+        )];
+
+        // Improving on yesterday but still over the cap
+        let observations = vec![
+            observation("screen", 180.0, 0),
+            observation("screen", 150.0, 1),
+        ];
+        let widget = LiveMetricsWidget::new(&indicators, &observations, &theme);
+        let snapshot = widget.build_snapshot(&indicators[0]);
+        assert_eq!(snapshot.cap_status, Some(CapStatus::Exceeded));
+        assert_eq!(snapshot.value_color, theme.negative);
+
+        let observations = vec![
+            observation("screen", 60.0, 0),
+            observation("screen", 100.0, 1),
+        ];
+        let widget = LiveMetricsWidget::new(&indicators, &observations, &theme);
+        let snapshot = widget.build_snapshot(&indicators[0]);
+        assert_eq!(snapshot.cap_status, Some(CapStatus::Approaching));
+        assert_eq!(snapshot.value_color, theme.neutral);
+    }
+
     #[test]
     fn within_range_uses_distance_to_target() {
         let theme = FinancialTheme::default();
@@ -372,6 +584,70 @@ mod tests {
         assert!(snapshot.spread_pct < 15.0);
     }
 
+    #[test]
+    fn within_range_uses_explicit_bounds() {
+        let theme = FinancialTheme::default();
+        let mut sleep = indicator("sleep", "Sleep", None, IndicatorDirection::WithinRange);
+        sleep.range_min = Some(7.0);
+        sleep.range_max = Some(9.0);
+        let indicators = vec![sleep];
+
+        // Moving from inside the band to just outside is a regression
+        let observations = vec![observation("sleep", 8.0, 0), observation("sleep", 9.5, 1)];
+        let widget = LiveMetricsWidget::new(&indicators, &observations, &theme);
+        let snapshot = widget.build_snapshot(&indicators[0]);
+        assert_eq!(snapshot.value_color, theme.negative);
+
+        // Staying inside the band reads as healthy with zero spread
+        let observations = vec![observation("sleep", 7.5, 0), observation("sleep", 8.5, 1)];
+        let widget = LiveMetricsWidget::new(&indicators, &observations, &theme);
+        let snapshot = widget.build_snapshot(&indicators[0]);
+        assert_eq!(snapshot.value_color, theme.positive);
+        assert_eq!(snapshot.spread_pct, 0.0);
+    }
+
+    #[test]
+    fn dollars_rows_show_month_to_date_and_projection() {
+        let theme = FinancialTheme::default();
+        let mut savings = indicator(
+            "save",
+            "Savings",
+            Some(50.0),
+            IndicatorDirection::HigherIsBetter,
+        );
+        savings.unit = IndicatorUnit::Dollars;
+        savings.monthly_target = Some(3000.0);
+        // Sep 10-12, plus one August entry that must not count
+        let observations = vec![
+            observation("save", 400.0, 0),
+            observation("save", 100.0, 1),
+            observation("save", 500.0, 2),
+            observation("save", 900.0, -15),
+        ];
+        let today = NaiveDate::from_ymd_opt(2025, 9, 15).unwrap();
+
+        let mtd = savings.month_to_date(&observations, today).unwrap();
+        assert_eq!(mtd.total, 1000.0);
+        assert_eq!((mtd.days_elapsed, mtd.days_in_month), (15, 30));
+        assert_eq!(mtd.projected, 2000.0);
+        assert_eq!(mtd.target, Some(3000.0));
+
+        let indicators = vec![savings];
+        let widget = LiveMetricsWidget::new(&indicators, &observations, &theme).today(today);
+        let area = Rect::new(0, 0, 80, 4);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+        let text: String = buffer.content.iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("Savings MTD"));
+        assert!(text.contains("1000"));
+        assert!(text.contains("-33.3%"));
+        assert!(text.contains("→ 2000"));
+
+        // Non-dollar indicators keep per-day values
+        let percent = indicator("p", "P", None, IndicatorDirection::HigherIsBetter);
+        assert!(percent.month_to_date(&observations, today).is_none());
+    }
+
     #[test]
     fn widget_renders_as_table() {
         let theme = FinancialTheme::default();
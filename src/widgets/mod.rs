@@ -1,12 +1,18 @@
 pub mod alternative_signals;
+pub mod compare_chart;
+pub mod gratitude;
 pub mod live_metrics;
 pub mod performance_chart;
 pub mod progress;
 pub mod sentiment_analysis;
 pub mod status_line;
+pub mod virtual_list;
 
+pub use compare_chart::{CompareChart, CompareScale, CompareSeries};
+pub use gratitude::GratitudeWidget;
 pub use live_metrics::LiveMetricsWidget;
 pub use performance_chart::PerformanceChart;
 pub use progress::{IndicatorProgress, TrendDirection};
 pub use sentiment_analysis::SentimentWidget;
 pub use status_line::StatusLineWidget;
+pub use virtual_list::{VirtualList, VirtualListState};
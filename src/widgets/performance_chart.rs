@@ -1,11 +1,13 @@
-use crate::models::Observation;
-use crate::ui::theme::FinancialTheme;
+use crate::forecast::Forecast;
+use crate::models::{IndicatorAggregation, IndicatorDef, IndicatorDirection, Observation};
+use crate::ui::theme::{FinancialTheme, Severity};
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
     style::{Color, Style},
     symbols,
-    text::Span,
+    text::{Line, Span},
     widgets::{Axis, Block, Borders, Chart, Dataset, GraphType, Widget},
 };
 
@@ -15,8 +17,19 @@ pub struct PerformanceChart<'a> {
     theme: &'a FinancialTheme,
     title: &'a str,
     title_color: Option<Color>,
+    range: Option<(f64, f64)>,
+    target: Option<(f64, IndicatorDirection, Option<NaiveDate>)>,
+    aggregation: IndicatorAggregation,
+    intraday: bool,
+    window: Option<(i64, NaiveDate)>,
 }
 
+/// Fewest days the forecast line reaches past the latest observation
+const MIN_FORECAST_DAYS: i64 = 3;
+
+/// Day spans the dashboard cycles the chart through
+pub const CHART_WINDOWS: [i64; 4] = [7, 30, 90, 365];
+
 impl<'a> PerformanceChart<'a> {
     pub fn new(
         observations: &'a [Observation],
@@ -30,6 +43,11 @@ impl<'a> PerformanceChart<'a> {
             theme,
             title,
             title_color: None,
+            range: None,
+            target: None,
+            aggregation: IndicatorAggregation::default(),
+            intraday: false,
+            window: None,
         }
     }
 
@@ -38,17 +56,129 @@ impl<'a> PerformanceChart<'a> {
         self
     }
 
-    fn filtered_observations(&self) -> Vec<&'a Observation> {
-        let mut filtered: Vec<&'a Observation> = self
+    /// Draw the acceptable band for WithinRange indicators and color the
+    /// series by whether the latest value sits inside it.
+    pub fn range(mut self, range: (f64, f64)) -> Self {
+        self.range = Some(range);
+        self
+    }
+
+    /// Project the trend toward `target`, drawing a dotted forecast line and
+    /// an ETA label measured against the objective's `due` date.
+    pub fn forecast(
+        mut self,
+        target: f64,
+        direction: IndicatorDirection,
+        due: Option<NaiveDate>,
+    ) -> Self {
+        self.target = Some((target, direction, due));
+        self
+    }
+
+    /// How several observations on one day combine into the plotted point
+    pub fn aggregation(mut self, aggregation: IndicatorAggregation) -> Self {
+        self.aggregation = aggregation;
+        self
+    }
+
+    /// Plot every sample at its time of day instead of one point per day.
+    /// The forecast is left out, as it runs one step per day.
+    pub fn intraday(mut self, intraday: bool) -> Self {
+        self.intraday = intraday;
+        self
+    }
+
+    /// Plot only the `days` days ending on `end`; the title names the span.
+    /// Without a window every observation given is plotted.
+    pub fn window(mut self, days: i64, end: NaiveDate) -> Self {
+        self.window = Some((days, end));
+        self
+    }
+
+    fn in_window(&self, when: NaiveDate) -> bool {
+        self.window
+            .is_none_or(|(days, end)| when <= end && when > end - Duration::days(days))
+    }
+
+    fn build_forecast(&self, filtered: &[(NaiveDate, f64)]) -> Option<Forecast> {
+        let (target, direction, due) = self.target.as_ref()?;
+        Forecast::new(filtered, *target, direction.clone(), *due)
+    }
+
+    /// Forecast points continuing the index-based x axis, where each point
+    /// stands for `per_point` days, out to the ETA or due date but no wider
+    /// than the observed span
+    fn forecast_line(
+        &self,
+        forecast: &Forecast,
+        data_len: usize,
+        per_point: usize,
+    ) -> Vec<(f64, f64)> {
+        let last_x = data_len.saturating_sub(1) as f64;
+        let span = (data_len as i64 * per_point as i64).max(MIN_FORECAST_DAYS);
+        let days = forecast
+            .eta
+            .filter(|_| !forecast.reached)
+            .or(forecast.due)
+            .map(|date| (date - forecast.last_date).num_days())
+            .unwrap_or(span)
+            .clamp(1, span);
+        (0..=days)
+            .map(|day| {
+                let date = forecast.last_date + Duration::days(day);
+                (
+                    last_x + day as f64 / per_point as f64,
+                    forecast.fit.value_at(date),
+                )
+            })
+            .collect()
+    }
+
+    /// One point per day, oldest first
+    fn filtered_observations(&self) -> Vec<(NaiveDate, f64)> {
+        let mut days = self.aggregation.daily(self.indicator_id, self.observations);
+        days.retain(|(when, _)| self.in_window(*when));
+        days
+    }
+
+    /// Every sample in time order; untimed ones sort to the start of their day
+    fn intraday_samples(&self) -> Vec<(NaiveDateTime, f64)> {
+        let mut samples: Vec<&Observation> = self
             .observations
             .iter()
-            .filter(|obs| obs.indicator_id == self.indicator_id)
+            .filter(|obs| obs.indicator_id == self.indicator_id && self.in_window(obs.when))
+            .collect();
+        samples.sort_by_key(|obs| (obs.when, obs.at, obs.created));
+        samples
+            .into_iter()
+            .map(|obs| {
+                (
+                    obs.when.and_time(obs.at.unwrap_or(NaiveTime::MIN)),
+                    obs.value,
+                )
+            })
+            .collect()
+    }
+
+    /// Average runs of consecutive points so at most `max_points` remain,
+    /// each labelled by the last of its run; returns the run length too
+    fn downsample<T: Clone>(points: &[(T, f64)], max_points: usize) -> (Vec<(T, f64)>, usize) {
+        let per_point = points.len().div_ceil(max_points.max(1)).max(1);
+        if per_point == 1 {
+            return (points.to_vec(), 1);
+        }
+        let merged = points
+            .chunks(per_point)
+            .filter_map(|run| {
+                let (last, _) = run.last()?;
+                let mean = run.iter().map(|(_, value)| value).sum::<f64>() / run.len() as f64;
+                Some((last.clone(), mean))
+            })
             .collect();
-        filtered.sort_by_key(|obs| obs.when);
-        filtered
+        (merged, per_point)
     }
 
-    fn prepare_dataset(&self, filtered: &[&'a Observation]) -> Vec<(f64, f64)> {
+    fn prepare_dataset<T>(&self, filtered: &[(T, f64)]) -> Vec<(f64, f64)> {
         if filtered.is_empty() {
             return vec![(0.0, 0.0), (1.0, 0.0)];
         }
@@ -56,7 +186,7 @@ impl<'a> PerformanceChart<'a> {
         let mut points: Vec<(f64, f64)> = filtered
             .iter()
             .enumerate()
-            .map(|(idx, obs)| (idx as f64, obs.value))
+            .map(|(idx, (_, value))| (idx as f64, *value))
             .collect();
 
         if points.len() == 1 {
@@ -73,7 +203,15 @@ impl<'a> PerformanceChart<'a> {
 
         let mut y_min = f64::INFINITY;
         let mut y_max = f64::NEG_INFINITY;
-        for (_, value) in data {
+        let range_edges = self
+            .range
+            .map(|(min, max)| [min, max])
+            .unwrap_or([f64::NAN; 2]);
+        let values = data
+            .iter()
+            .map(|(_, value)| value)
+            .chain(range_edges.iter().filter(|edge| edge.is_finite()));
+        for value in values {
             if *value < y_min {
                 y_min = *value;
             }
@@ -103,21 +241,23 @@ impl<'a> PerformanceChart<'a> {
         }
     }
 
-    fn x_axis_labels(&self, filtered: &[&'a Observation]) -> Vec<Span<'static>> {
+    fn x_axis_labels<T>(
+        &self,
+        filtered: &[(T, f64)],
+        format_date: impl Fn(&T) -> String,
+    ) -> Vec<Span<'static>> {
         if filtered.is_empty() {
             return vec![Span::raw("NO DATA")];
         }
 
-        let format_date = |obs: &&Observation| obs.when.format("%b %d").to_string();
-
         if filtered.len() == 1 {
-            let label = format_date(&filtered[0]);
+            let label = format_date(&filtered[0].0);
             return vec![Span::raw(label.clone()), Span::raw(label)];
         }
 
-        let first = format_date(&filtered[0]);
-        let mid = format_date(&filtered[filtered.len() / 2]);
-        let last = format_date(&filtered[filtered.len() - 1]);
+        let first = format_date(&filtered[0].0);
+        let mid = format_date(&filtered[filtered.len() / 2].0);
+        let last = format_date(&filtered[filtered.len() - 1].0);
 
         let mut labels = vec![first, mid, last];
         labels.dedup();
@@ -136,51 +276,147 @@ impl<'a> PerformanceChart<'a> {
             .collect()
     }
 
-    fn trend_color(&self, filtered: &[&'a Observation]) -> Color {
+    fn trend_severity(&self, filtered: &[(NaiveDate, f64)]) -> Severity {
         if filtered.len() < 2 {
-            return self.theme.neutral;
+            return Severity::Warning;
         }
 
-        let first = filtered.first().unwrap().value;
-        let last = filtered.last().unwrap().value;
-        self.theme.get_trend_color(last, first)
+        let first = filtered.first().unwrap().1;
+        let last = filtered.last().unwrap().1;
+        if let Some(range) = self.range {
+            let first_gap = IndicatorDef::range_deviation(first, range).abs();
+            let last_gap = IndicatorDef::range_deviation(last, range).abs();
+            return if last_gap <= f64::EPSILON {
+                Severity::Good
+            } else if last_gap < first_gap {
+                Severity::Warning
+            } else {
+                Severity::Bad
+            };
+        }
+        FinancialTheme::trend_severity(last, first)
+    }
+
+    fn range_lines(&self, x_bounds: [f64; 2]) -> Vec<Vec<(f64, f64)>> {
+        let Some((min, max)) = self.range else {
+            return Vec::new();
+        };
+        [min, max]
+            .into_iter()
+            .filter(|edge| edge.is_finite())
+            .map(|edge| vec![(x_bounds[0], edge), (x_bounds[1], edge)])
+            .collect()
     }
 }
 
 impl<'a> Widget for PerformanceChart<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        // Braille packs two points per cell; leave room for borders and the y axis
+        let max_points = usize::from(area.width.saturating_sub(10)).max(2) * 2;
         let filtered = self.filtered_observations();
-        let data = self.prepare_dataset(&filtered);
-        let bounds = self.compute_y_bounds(&data);
-        let x_bounds = self.compute_x_bounds(data.len());
-        let trend_color = self.trend_color(&filtered);
-        let x_labels = self.x_axis_labels(&filtered);
+        let (data, x_labels, per_point) = if self.intraday {
+            let (samples, per_point) = Self::downsample(&self.intraday_samples(), max_points);
+            let labels = self.x_axis_labels(&samples, |at| at.format("%d %H:%M").to_string());
+            (self.prepare_dataset(&samples), labels, per_point)
+        } else {
+            let (days, per_point) = Self::downsample(&filtered, max_points);
+            let format = if self.window.is_some_and(|(days, _)| days > 90) {
+                "%b %y"
+            } else {
+                "%b %d"
+            };
+            let labels = self.x_axis_labels(&days, |date| date.format(format).to_string());
+            (self.prepare_dataset(&days), labels, per_point)
+        };
+        let forecast = if self.intraday {
+            None
+        } else {
+            self.build_forecast(&filtered)
+        };
+        let forecast_line = forecast
+            .as_ref()
+            .map(|forecast| self.forecast_line(forecast, data.len(), per_point))
+            .unwrap_or_default();
+        let bounds_data: Vec<(f64, f64)> =
+            data.iter().chain(forecast_line.iter()).copied().collect();
+        let bounds = self.compute_y_bounds(&bounds_data);
+        let mut x_bounds = self.compute_x_bounds(data.len());
+        if let Some((last_x, _)) = forecast_line.last() {
+            x_bounds[1] = x_bounds[1].max(*last_x);
+        }
+        let trend = self.trend_severity(&filtered);
         let y_labels = self.y_axis_labels(bounds.0, bounds.1);
 
-        let dataset = Dataset::default()
-            .name(self.title)
-            .marker(symbols::Marker::Braille)
-            .graph_type(GraphType::Line)
-            .style(Style::default().fg(trend_color))
-            .data(&data);
+        let range_lines = self.range_lines(x_bounds);
+
+        let mut datasets: Vec<Dataset> = range_lines
+            .iter()
+            .map(|line| {
+                Dataset::default()
+                    .marker(symbols::Marker::Dot)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(self.theme.text_dim))
+                    .data(line)
+            })
+            .collect();
+        datasets.push(
+            Dataset::default()
+                .name(self.title)
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(self.theme.severity_color(trend)))
+                .data(&data),
+        );
 
         let title_color = self.title_color.unwrap_or(self.theme.text_dim);
-        let chart = Chart::new(vec![dataset])
-            .block(
-                Block::default()
-                    .title(format!(
-                        " {} PERFORMANCE (7-DAY) ",
-                        self.title.to_uppercase()
+        let mut block = Block::default()
+            .title(format!(
+                " {} PERFORMANCE ({}-DAY{}{}) {} ",
+                self.title.to_uppercase(),
+                self.window.map_or(7, |(days, _)| days),
+                self.window
+                    .filter(|(_, end)| *end < chrono::Local::now().date_naive())
+                    .map(|(_, end)| format!(
+                        " TO {}",
+                        end.format("%b %d").to_string().to_uppercase()
                     ))
-                    .title_style(
-                        Style::default()
-                            .fg(title_color)
-                            .add_modifier(ratatui::style::Modifier::BOLD),
-                    )
-                    .borders(Borders::ALL)
-                    .border_style(Style::default().fg(self.theme.text_dim))
-                    .style(Style::default().bg(self.theme.bg_panel)),
+                    .unwrap_or_default(),
+                if self.intraday { ", INTRA-DAY" } else { "" },
+                trend.symbol()
+            ))
+            .title_style(
+                Style::default()
+                    .fg(title_color)
+                    .add_modifier(ratatui::style::Modifier::BOLD),
             )
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.text_dim))
+            .style(Style::default().bg(self.theme.bg_panel));
+        if let Some(forecast) = &forecast {
+            let status = if forecast.on_track() {
+                Severity::Good
+            } else {
+                Severity::Bad
+            };
+            let color = self.theme.severity_color(status);
+            datasets.push(
+                Dataset::default()
+                    .marker(symbols::Marker::Dot)
+                    .graph_type(GraphType::Scatter)
+                    .style(Style::default().fg(color))
+                    .data(&forecast_line),
+            );
+            block = block.title_bottom(
+                Line::from(Span::styled(
+                    format!(" {} {} ", status.symbol(), forecast.label()),
+                    Style::default().fg(color),
+                ))
+                .right_aligned(),
+            );
+        }
+
+        let chart = Chart::new(datasets)
+            .block(block)
             .x_axis(
                 Axis::default()
                     .style(Style::default().fg(self.theme.text_dim))
@@ -215,6 +451,7 @@ mod tests {
             action_id: None,
             note: None,
             created: Utc::now(),
+            at: None,
         }
     }
 
@@ -238,6 +475,99 @@ mod tests {
         assert_eq!(data[2], (2.0, 40.0));
     }
 
+    #[test]
+    fn same_day_points_are_combined() {
+        let theme = FinancialTheme::default();
+        let observations = vec![
+            observation("ind", 0, 20.0),
+            observation("ind", 1, 15.0),
+            observation("ind", 1, 25.0),
+        ];
+        let chart = PerformanceChart::new(&observations, "ind", &theme, "Indicator")
+            .aggregation(IndicatorAggregation::Sum);
+        let data = chart.prepare_dataset(&chart.filtered_observations());
+        assert_eq!(data, vec![(0.0, 20.0), (1.0, 40.0)]);
+
+        let chart = chart.aggregation(IndicatorAggregation::Average);
+        let data = chart.prepare_dataset(&chart.filtered_observations());
+        assert_eq!(data, vec![(0.0, 20.0), (1.0, 20.0)]);
+    }
+
+    #[test]
+    fn intraday_keeps_every_sample_in_time_order() {
+        let theme = FinancialTheme::default();
+        let mut morning = observation("ind", 1, 15.0);
+        morning.at = NaiveTime::from_hms_opt(9, 30, 0);
+        let mut evening = observation("ind", 1, 25.0);
+        evening.at = NaiveTime::from_hms_opt(18, 0, 0);
+        let observations = vec![evening, observation("ind", 0, 20.0), morning];
+        let chart = PerformanceChart::new(&observations, "ind", &theme, "Indicator")
+            .aggregation(IndicatorAggregation::Sum)
+            .intraday(true);
+
+        let samples = chart.intraday_samples();
+        let values: Vec<f64> = samples.iter().map(|(_, value)| *value).collect();
+        assert_eq!(values, vec![20.0, 15.0, 25.0]);
+        assert_eq!(samples[1].0.format("%H:%M").to_string(), "09:30");
+
+        let area = Rect::new(0, 0, 60, 12);
+        let mut buffer = Buffer::empty(area);
+        chart.render(area, &mut buffer);
+        let text: String = buffer.content.iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("INTRA-DAY"));
+        assert!(text.contains("02 18:00"));
+    }
+
+    #[test]
+    fn window_limits_days_and_long_spans_are_downsampled() {
+        let theme = FinancialTheme::default();
+        let observations: Vec<Observation> = (0..120)
+            .map(|offset| observation("ind", offset, offset as f64))
+            .collect();
+        let end = NaiveDate::from_ymd_opt(2025, 9, 1).unwrap() + Duration::days(99);
+        let chart =
+            PerformanceChart::new(&observations, "ind", &theme, "Indicator").window(30, end);
+
+        let filtered = chart.filtered_observations();
+        assert_eq!(filtered.len(), 30);
+        assert_eq!(filtered.first().unwrap().1, 70.0);
+        assert_eq!(filtered.last().unwrap().0, end);
+
+        let (merged, per_point) = PerformanceChart::downsample(&filtered, 10);
+        assert_eq!(per_point, 3);
+        assert_eq!(merged.len(), 10);
+        assert_eq!(merged[0], (end - Duration::days(27), 71.0));
+        let (same, per_point) = PerformanceChart::downsample(&filtered, 30);
+        assert_eq!((same.len(), per_point), (30, 1));
+
+        let area = Rect::new(0, 0, 60, 12);
+        let mut buffer = Buffer::empty(area);
+        chart.render(area, &mut buffer);
+        let text: String = buffer.content.iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("(30-DAY TO DEC 09)"));
+    }
+
+    #[test]
+    fn range_widens_bounds_and_drives_color() {
+        let theme = FinancialTheme::default();
+        let observations = vec![observation("ind", 0, 80.0), observation("ind", 1, 65.0)];
+        let chart =
+            PerformanceChart::new(&observations, "ind", &theme, "Indicator").range((60.0, 70.0));
+
+        let filtered = chart.filtered_observations();
+        let data = chart.prepare_dataset(&filtered);
+        let (y_min, _) = chart.compute_y_bounds(&data);
+        assert!(y_min < 60.0);
+        assert_eq!(chart.trend_severity(&filtered), Severity::Good);
+        assert_eq!(chart.range_lines([0.0, 1.0]).len(), 2);
+
+        let drifting = vec![observation("ind", 0, 65.0), observation("ind", 1, 75.0)];
+        let chart =
+            PerformanceChart::new(&drifting, "ind", &theme, "Indicator").range((60.0, 70.0));
+        let filtered = chart.filtered_observations();
+        assert_eq!(chart.trend_severity(&filtered), Severity::Bad);
+    }
+
     #[test]
     fn dataset_handles_empty_and_single_point() {
         let theme = FinancialTheme::default();
@@ -271,17 +601,50 @@ mod tests {
         assert!(max > 75.0);
     }
 
+    #[test]
+    fn forecast_extends_axis_and_labels_eta() {
+        let theme = FinancialTheme::default();
+        let observations = vec![
+            observation("ind", 0, 10.0),
+            observation("ind", 1, 20.0),
+            observation("ind", 2, 30.0),
+        ];
+        let due = NaiveDate::from_ymd_opt(2025, 9, 10).unwrap();
+        let chart = PerformanceChart::new(&observations, "ind", &theme, "Indicator").forecast(
+            50.0,
+            IndicatorDirection::HigherIsBetter,
+            Some(due),
+        );
+
+        let filtered = chart.filtered_observations();
+        let forecast = chart.build_forecast(&filtered).unwrap();
+        assert_eq!(forecast.eta, NaiveDate::from_ymd_opt(2025, 9, 5));
+        assert!(forecast.on_track());
+
+        // Two days to the ETA, continuing from the last index
+        let line = chart.forecast_line(&forecast, 3, 1);
+        assert_eq!(line.len(), 3);
+        assert_eq!(line[0].0, 2.0);
+        assert!((line[2].1 - 50.0).abs() < 1e-9);
+
+        let area = Rect::new(0, 0, 60, 12);
+        let mut buffer = Buffer::empty(area);
+        chart.render(area, &mut buffer);
+        let text: String = buffer.content.iter().map(|cell| cell.symbol()).collect();
+        assert!(text.contains("ETA Sep 05 · due Sep 10"));
+    }
+
     #[test]
     fn trend_color_reflects_direction() {
         let theme = FinancialTheme::default();
         let observations = vec![observation("ind", 0, 10.0), observation("ind", 1, 15.0)];
         let chart = PerformanceChart::new(&observations, "ind", &theme, "Indicator");
         let filtered = chart.filtered_observations();
-        assert_eq!(chart.trend_color(&filtered), theme.positive);
+        assert_eq!(chart.trend_severity(&filtered), Severity::Good);
 
         let declining = vec![observation("ind", 0, 15.0), observation("ind", 1, 10.0)];
         let chart = PerformanceChart::new(&declining, "ind", &theme, "Indicator");
         let filtered = chart.filtered_observations();
-        assert_eq!(chart.trend_color(&filtered), theme.negative);
+        assert_eq!(chart.trend_severity(&filtered), Severity::Bad);
     }
 }
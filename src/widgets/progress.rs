@@ -1,3 +1,4 @@
+use crate::ui::theme::Severity;
 use ratatui::{
     prelude::*,
     widgets::{Gauge, Paragraph},
@@ -68,16 +69,12 @@ impl IndicatorProgress {
 
     /// Create a styled paragraph with the progress bar
     pub fn render_bar_widget(&self) -> Paragraph<'static> {
-        let percentage = (self.current / self.target * 100.0).min(100.0).max(0.0);
         let bar = self.render_bar();
 
-        let style = match percentage as u32 {
-            100.. => Style::default().fg(Color::Green),
-            70..=99 => Style::default().fg(Color::Yellow),
-            _ => Style::default().fg(Color::Red),
-        };
+        let severity = self.severity();
 
-        Paragraph::new(bar).style(style)
+        Paragraph::new(format!("{} {}", severity.symbol(), bar))
+            .style(Self::severity_style(severity))
     }
 
     /// Get sparkline data (for use with Sparkline widget)
@@ -94,6 +91,19 @@ impl IndicatorProgress {
         }
     }
 
+    /// At target, at least 70% of the way, or further behind
+    pub fn severity(&self) -> Severity {
+        Severity::from_percentage(f64::from(self.get_percentage()), 100.0, 70.0)
+    }
+
+    fn severity_style(severity: Severity) -> Style {
+        match severity {
+            Severity::Good => Style::default().fg(Color::Green),
+            Severity::Warning => Style::default().fg(Color::Yellow),
+            Severity::Bad => Style::default().fg(Color::Red),
+        }
+    }
+
     /// Get progress percentage
     pub fn get_percentage(&self) -> u16 {
         ((self.current / self.target * 100.0).min(100.0).max(0.0)) as u16
@@ -102,14 +112,12 @@ impl IndicatorProgress {
     /// Create a gauge widget for the progress
     pub fn render_gauge(&self) -> Gauge<'static> {
         let percentage = self.get_percentage();
-        let style = match percentage {
-            100.. => Style::default().fg(Color::Green),
-            70..=99 => Style::default().fg(Color::Yellow),
-            _ => Style::default().fg(Color::Red),
-        };
+        let severity = self.severity();
+        let style = Self::severity_style(severity);
 
         Gauge::default()
             .percent(percentage)
+            .label(format!("{} {}%", severity.symbol(), percentage))
             .style(style)
             .gauge_style(style)
     }
@@ -7,7 +7,11 @@ use ratatui::{
 };
 
 use crate::models::{Action, ActionStatus, OutcomeType};
-use crate::ui::theme::FinancialTheme;
+use crate::ui::theme::{FinancialTheme, Severity};
+use chrono::NaiveDate;
+
+/// Most recent reflection scores shown in the mood sparkline
+const MOOD_POINTS: usize = 14;
 
 #[derive(Debug, Default, Clone)]
 struct SentimentBreakdown {
@@ -112,6 +116,7 @@ impl SentimentBreakdown {
 pub struct SentimentWidget<'a> {
     outcome: OutcomeType,
     actions: &'a [Action],
+    mood: &'a [(NaiveDate, f64)],
     theme: &'a FinancialTheme,
     title_color: Option<Color>,
 }
@@ -121,6 +126,7 @@ impl<'a> SentimentWidget<'a> {
         Self {
             outcome,
             actions,
+            mood: &[],
             theme,
             title_color: None,
         }
@@ -131,6 +137,54 @@ impl<'a> SentimentWidget<'a> {
         self
     }
 
+    /// Reflection sentiment scores (-1.0 to 1.0) in date order
+    pub fn mood(mut self, series: &'a [(NaiveDate, f64)]) -> Self {
+        self.mood = series;
+        self
+    }
+
+    fn mood_severity(score: f64) -> Severity {
+        if score >= 0.25 {
+            Severity::Good
+        } else if score <= -0.25 {
+            Severity::Bad
+        } else {
+            Severity::Warning
+        }
+    }
+
+    fn mood_color(&self, score: f64) -> Color {
+        self.theme.severity_color(Self::mood_severity(score))
+    }
+
+    /// Latest reflection score followed by a sparkline of recent days
+    fn mood_line(&self) -> Option<Line<'static>> {
+        let (_, latest) = *self.mood.last()?;
+        let recent = &self.mood[self.mood.len().saturating_sub(MOOD_POINTS)..];
+        let levels = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+        let mut spans = vec![
+            Span::styled(
+                format!("{:<10}", "Mood"),
+                Style::default()
+                    .fg(self.theme.info)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("{:+.2} {}", latest, Self::mood_severity(latest).symbol()),
+                Style::default().fg(self.mood_color(latest)),
+            ),
+            Span::raw("  "),
+        ];
+        spans.extend(recent.iter().map(|(_, score)| {
+            let level = ((score.clamp(-1.0, 1.0) + 1.0) / 2.0 * 7.0).round() as usize;
+            Span::styled(
+                levels[level].to_string(),
+                Style::default().fg(self.mood_color(*score)),
+            )
+        }));
+        Some(Line::from(spans))
+    }
+
     fn bar_width(&self, available_width: u16) -> usize {
         if available_width <= 24 {
             return 0;
@@ -255,7 +309,7 @@ impl<'a> SentimentWidget<'a> {
                 .fg(self.theme.info)
                 .add_modifier(Modifier::BOLD),
         )]));
-        lines.push(Line::raw(""));
+        lines.push(self.mood_line().unwrap_or_else(|| Line::raw("")));
 
         lines.push(self.category_line(
             "Positive",
@@ -302,11 +356,12 @@ impl<'a> SentimentWidget<'a> {
         }
 
         let score = breakdown.momentum_score();
-        let gauge_color = self.theme.get_status_color(score as f64);
+        let status = FinancialTheme::status_severity(score as f64);
+        let gauge_color = self.theme.severity_color(status);
 
         Gauge::default()
             .percent(score)
-            .label(format!("{}%", score))
+            .label(format!("{} {}%", status.symbol(), score))
             .gauge_style(Style::default().fg(gauge_color).bg(self.theme.bg_panel))
             .style(Style::default().bg(self.theme.bg_panel).fg(gauge_color))
             .render(inner, buf);
@@ -340,6 +395,14 @@ impl<'a> Widget for SentimentWidget<'a> {
         let breakdown = SentimentBreakdown::from_actions(self.actions);
 
         if breakdown.total() == 0 {
+            let mut inner = inner;
+            if let Some(mood) = self.mood_line() {
+                Paragraph::new(mood)
+                    .style(Style::default().bg(self.theme.bg_panel))
+                    .render(Rect { height: 1, ..inner }, buf);
+                inner.y += 1;
+                inner.height = inner.height.saturating_sub(1);
+            }
             Paragraph::new("No actions recorded for this outcome")
                 .style(
                     Style::default()
@@ -424,4 +487,24 @@ mod tests {
         let breakdown = SentimentBreakdown::from_actions(&[]);
         assert_eq!(breakdown.momentum_score(), 0);
     }
+
+    #[test]
+    fn mood_line_shows_latest_score_and_sparkline() {
+        let theme = FinancialTheme::default();
+        let day = |d| NaiveDate::from_ymd_opt(2025, 4, d).unwrap();
+        let mood = vec![(day(1), -1.0), (day(2), 0.0), (day(3), 0.5)];
+        let widget = SentimentWidget::new(OutcomeType::Work, &[], &theme).mood(&mood);
+
+        let text: String = widget
+            .mood_line()
+            .unwrap()
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+        assert_eq!(text, "Mood      +0.50 ✓  ▁▅▆");
+
+        let empty = SentimentWidget::new(OutcomeType::Work, &[], &theme);
+        assert!(empty.mood_line().is_none());
+    }
 }
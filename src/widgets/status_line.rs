@@ -9,6 +9,7 @@ use ratatui::{
 
 pub struct StatusLineWidget<'a> {
     text: Option<&'a str>,
+    timer: Option<(&'a str, String)>,
     theme: &'a FinancialTheme,
 }
 
@@ -16,6 +17,7 @@ impl<'a> StatusLineWidget<'a> {
     pub fn new(theme: &'a FinancialTheme) -> Self {
         Self {
             text: None,
+            timer: None,
             theme,
         }
     }
@@ -24,11 +26,25 @@ impl<'a> StatusLineWidget<'a> {
         self.text = Some(text);
         self
     }
+
+    /// Show a running timer on `label` instead of the selection
+    pub fn timer(mut self, label: &'a str, elapsed: String) -> Self {
+        self.timer = Some((label, elapsed));
+        self
+    }
 }
 
 impl<'a> Widget for StatusLineWidget<'a> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        let content = if let Some(text) = self.text {
+        let content = if let Some((label, elapsed)) = self.timer {
+            Line::from(vec![
+                Span::styled("⏱ Tracking: ", Style::default().fg(self.theme.text_secondary)),
+                Span::styled(label, Style::default().fg(self.theme.text_primary)),
+                Span::styled(format!("  {}", elapsed), Style::default()
+                    .fg(self.theme.accent_yellow)
+                    .add_modifier(Modifier::BOLD)),
+            ])
+        } else if let Some(text) = self.text {
             Line::from(vec![
                 Span::styled("Selected: ", Style::default().fg(self.theme.text_secondary)),
                 Span::styled(text, Style::default()
@@ -84,4 +100,19 @@ mod tests {
         // Basic smoke test
         assert!(!buffer.content.is_empty());
     }
+
+    #[test]
+    fn widget_renders_running_timer() {
+        let theme = FinancialTheme::default();
+        let widget = StatusLineWidget::new(&theme)
+            .text("ignored")
+            .timer("Write report", "12:34".to_string());
+
+        let area = Rect::new(0, 0, 80, 2);
+        let mut buffer = Buffer::empty(area);
+        widget.render(area, &mut buffer);
+
+        let row: String = (0..area.width).map(|x| buffer[(x, 1)].symbol()).collect();
+        assert!(row.contains("Write report  12:34"));
+    }
 }
\ No newline at end of file
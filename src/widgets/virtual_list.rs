@@ -0,0 +1,194 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::Style,
+    widgets::{List, ListItem, ListState, StatefulWidget},
+};
+use std::ops::Range;
+
+/// Scroll position for a [`VirtualList`].
+///
+/// Unlike `ListState`, the offset is tracked against the full item count so the
+/// list can be rendered without materialising every row.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VirtualListState {
+    pub selected: Option<usize>,
+    pub offset: usize,
+}
+
+impl VirtualListState {
+    pub fn with_selected(mut self, index: Option<usize>) -> Self {
+        self.selected = index;
+        self
+    }
+
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+    }
+
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Move the selection down by one, clamped to `len`.
+    pub fn next(&mut self, len: usize) {
+        if len == 0 {
+            self.selected = None;
+            return;
+        }
+        self.selected = Some(match self.selected {
+            Some(i) => (i + 1).min(len - 1),
+            None => 0,
+        });
+    }
+
+    /// Move the selection up by one.
+    pub fn previous(&mut self, len: usize) {
+        if len == 0 {
+            self.selected = None;
+            return;
+        }
+        self.selected = Some(self.selected.map_or(0, |i| i.saturating_sub(1)));
+    }
+
+    /// Adjust the offset so the selection stays inside a window of `height`
+    /// rows and return the range of item indices that should be built.
+    pub fn visible_range(&mut self, len: usize, height: usize) -> Range<usize> {
+        if len == 0 || height == 0 {
+            self.offset = 0;
+            return 0..0;
+        }
+
+        if let Some(selected) = self.selected {
+            let selected = selected.min(len - 1);
+            self.selected = Some(selected);
+            if selected < self.offset {
+                self.offset = selected;
+            } else if selected >= self.offset + height {
+                self.offset = selected + 1 - height;
+            }
+        }
+
+        // Never leave blank rows at the bottom when the list could fill them
+        self.offset = self.offset.min(len.saturating_sub(height));
+        self.offset..(self.offset + height).min(len)
+    }
+}
+
+/// A list that only builds `ListItem`s for the rows currently on screen.
+///
+/// Rows are produced lazily by the `item` closure, so history, search and
+/// observation browsers can page through thousands of entries cheaply.
+pub struct VirtualList<'a, F>
+where
+    F: Fn(usize) -> ListItem<'a>,
+{
+    len: usize,
+    item: F,
+    style: Style,
+    highlight_style: Style,
+    highlight_symbol: Option<&'a str>,
+}
+
+impl<'a, F> VirtualList<'a, F>
+where
+    F: Fn(usize) -> ListItem<'a>,
+{
+    pub fn new(len: usize, item: F) -> Self {
+        Self {
+            len,
+            item,
+            style: Style::default(),
+            highlight_style: Style::default(),
+            highlight_symbol: None,
+        }
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    pub fn highlight_symbol(mut self, symbol: &'a str) -> Self {
+        self.highlight_symbol = Some(symbol);
+        self
+    }
+}
+
+impl<'a, F> StatefulWidget for VirtualList<'a, F>
+where
+    F: Fn(usize) -> ListItem<'a>,
+{
+    type State = VirtualListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let range = state.visible_range(self.len, area.height as usize);
+        let offset = range.start;
+        let items: Vec<ListItem> = range.map(&self.item).collect();
+
+        let mut list = List::new(items)
+            .style(self.style)
+            .highlight_style(self.highlight_style);
+        if let Some(symbol) = self.highlight_symbol {
+            list = list.highlight_symbol(symbol);
+        }
+
+        // Translate the global selection into the window we actually built
+        let mut window_state =
+            ListState::default().with_selected(state.selected.map(|i| i - offset));
+        StatefulWidget::render(list, area, buf, &mut window_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn visible_range_follows_selection() {
+        let mut state = VirtualListState::default();
+        state.select(Some(25));
+
+        assert_eq!(state.visible_range(100, 10), 16..26);
+
+        state.select(Some(3));
+        assert_eq!(state.visible_range(100, 10), 3..13);
+    }
+
+    #[test]
+    fn visible_range_clamps_to_len() {
+        let mut state = VirtualListState {
+            selected: None,
+            offset: 95,
+        };
+
+        assert_eq!(state.visible_range(100, 10), 90..100);
+        assert_eq!(state.visible_range(4, 10), 0..4);
+        assert_eq!(state.visible_range(0, 10), 0..0);
+    }
+
+    #[test]
+    fn only_visible_rows_are_built() {
+        let built = Cell::new(0usize);
+        let list = VirtualList::new(10_000, |i| {
+            built.set(built.get() + 1);
+            ListItem::new(format!("row {}", i))
+        });
+
+        let mut state = VirtualListState::default();
+        state.select(Some(5_000));
+
+        let area = Rect::new(0, 0, 20, 8);
+        let mut buffer = Buffer::empty(area);
+        StatefulWidget::render(list, area, &mut buffer, &mut state);
+
+        assert_eq!(built.get(), 8);
+        assert_eq!(state.offset, 4_993);
+    }
+}
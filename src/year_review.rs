@@ -0,0 +1,259 @@
+//! A calendar year condensed into highlights: completion by month, the
+//! longest streak, objectives completed and indicator bests. Shown in the
+//! TUI and exported as a shareable markdown summary.
+
+use crate::models::{Config, IndicatorDirection, ObjectiveStatus, OutcomeType};
+use crate::ui::stats::{period_rollup, RollupPeriod};
+use anyhow::{bail, Context, Result};
+use chrono::{Datelike, NaiveDate};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Completed objectives listed before the rest are summed up
+pub const TOP_OBJECTIVES: usize = 10;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MonthSummary {
+    pub label: String,
+    pub days_tracked: u32,
+    pub completion: f64,
+}
+
+/// Consecutive days with at least one completed action
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Streak {
+    pub days: u32,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompletedObjective {
+    pub title: String,
+    pub domain: OutcomeType,
+    pub completed: NaiveDate,
+}
+
+/// Best observation of an indicator over the year, by its direction
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndicatorBest {
+    pub name: String,
+    pub value: String,
+    pub date: NaiveDate,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct YearReview {
+    pub year: i32,
+    /// Last day counted: the end of the year, or today for the current one
+    pub through: NaiveDate,
+    pub days_tracked: u32,
+    pub completion: f64,
+    pub outcome_completion: [f64; 3],
+    pub months: Vec<MonthSummary>,
+    pub longest_streak: Option<Streak>,
+    /// Most recent first
+    pub objectives: Vec<CompletedObjective>,
+    pub indicator_bests: Vec<IndicatorBest>,
+}
+
+/// Review of `year`, counting days up to `today`
+pub fn build(year: i32, today: NaiveDate, config: &Config) -> Result<YearReview> {
+    let start = NaiveDate::from_ymd_opt(year, 1, 1).context("Invalid year")?;
+    let end = NaiveDate::from_ymd_opt(year, 12, 31).context("Invalid year")?;
+    if start > today {
+        bail!("{} has not started yet", year);
+    }
+    let through = end.min(today);
+
+    let cache = crate::ui::stats::load_day_cache(start, through, config);
+    let months = (1..=through.month())
+        .filter_map(|month| NaiveDate::from_ymd_opt(year, month, 1))
+        .map(|first| {
+            let rollup = period_rollup(RollupPeriod::Month, first, through, &cache, &[]);
+            MonthSummary {
+                label: rollup.label,
+                days_tracked: rollup.days_tracked,
+                completion: rollup.completion,
+            }
+        })
+        .collect();
+
+    let days: Vec<_> = cache.days.range(start..=through).collect();
+    let count = days.len().max(1) as f64;
+    let completion = days.iter().map(|(_, day)| day.completion).sum::<f64>() / count;
+    let outcome_completion =
+        [0, 1, 2].map(|i| days.iter().map(|(_, day)| day.outcomes[i]).sum::<f64>() / count);
+
+    let mut longest_streak: Option<Streak> = None;
+    let mut current: Option<Streak> = None;
+    for date in start.iter_days().take_while(|date| *date <= through) {
+        let active = cache
+            .days
+            .get(&date)
+            .is_some_and(|day| day.completion > 0.0);
+        current = match (active, current) {
+            (false, _) => None,
+            (true, Some(streak)) => Some(Streak {
+                days: streak.days + 1,
+                end: date,
+                ..streak
+            }),
+            (true, None) => Some(Streak {
+                days: 1,
+                start: date,
+                end: date,
+            }),
+        };
+        if let Some(streak) = current {
+            if longest_streak.is_none_or(|longest| streak.days > longest.days) {
+                longest_streak = Some(streak);
+            }
+        }
+    }
+
+    let objectives_data = crate::data::load_or_create_objectives(config)?;
+    let mut objectives: Vec<CompletedObjective> = objectives_data
+        .objectives
+        .iter()
+        .filter(|objective| objective.status == ObjectiveStatus::Completed)
+        .map(|objective| CompletedObjective {
+            title: objective.title.clone(),
+            domain: objective.domain,
+            completed: objective
+                .completed_on
+                .or(objective.end)
+                .unwrap_or_else(|| objective.modified.date_naive()),
+        })
+        .filter(|objective| objective.completed >= start && objective.completed <= through)
+        .collect();
+    objectives.sort_by_key(|objective| std::cmp::Reverse(objective.completed));
+
+    let indicators = crate::data::load_or_create_indicators(config)?;
+    let observations = crate::data::read_observations_range(start, through, config)?;
+    let mut indicator_bests = Vec::new();
+    for def in &indicators.indicators {
+        let readings = observations.iter().filter(|obs| obs.indicator_id == def.id);
+        let best = match def.direction {
+            IndicatorDirection::HigherIsBetter => {
+                readings.max_by(|a, b| a.value.total_cmp(&b.value))
+            }
+            IndicatorDirection::LowerIsBetter => {
+                readings.min_by(|a, b| a.value.total_cmp(&b.value))
+            }
+            IndicatorDirection::WithinRange => None,
+        };
+        if let Some(obs) = best {
+            indicator_bests.push(IndicatorBest {
+                name: def.name.clone(),
+                value: crate::summary::format_value(obs.value, &def.unit),
+                date: obs.when,
+            });
+        }
+    }
+    indicator_bests.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(YearReview {
+        year,
+        through,
+        days_tracked: days.len() as u32,
+        completion,
+        outcome_completion,
+        months,
+        longest_streak,
+        objectives,
+        indicator_bests,
+    })
+}
+
+/// Ten-cell bar for a completion percentage
+pub fn bar(percent: f64) -> String {
+    let filled = ((percent / 10.0).round() as usize).min(10);
+    format!("{}{}", "█".repeat(filled), "░".repeat(10 - filled))
+}
+
+/// The review as a markdown document
+pub fn to_markdown(review: &YearReview) -> String {
+    let mut doc = format!("# FocusFive year in review: {}\n\n", review.year);
+    if review.through.month() != 12 || review.through.day() != 31 {
+        doc.push_str(&format!(
+            "_So far, through {}._\n\n",
+            review.through.format("%B %d")
+        ));
+    }
+
+    doc.push_str(&format!("- Days tracked: {}\n", review.days_tracked));
+    doc.push_str(&format!(
+        "- Average completion: {:.0}%\n",
+        review.completion
+    ));
+    doc.push_str(&format!(
+        "- By outcome: Work {:.0}% · Health {:.0}% · Family {:.0}%\n",
+        review.outcome_completion[0], review.outcome_completion[1], review.outcome_completion[2]
+    ));
+    match review.longest_streak {
+        Some(streak) => doc.push_str(&format!(
+            "- Longest streak: {} day{} ({} to {})\n",
+            streak.days,
+            if streak.days == 1 { "" } else { "s" },
+            streak.start.format("%b %d"),
+            streak.end.format("%b %d")
+        )),
+        None => doc.push_str("- Longest streak: none yet\n"),
+    }
+
+    doc.push_str("\n## Completion by month\n\n| Month | Days | Completion |\n|---|---|---|\n");
+    for month in &review.months {
+        doc.push_str(&format!(
+            "| {} | {} | {} {:.0}% |\n",
+            month.label,
+            month.days_tracked,
+            bar(month.completion),
+            month.completion
+        ));
+    }
+
+    doc.push_str("\n## Objectives completed\n\n");
+    if review.objectives.is_empty() {
+        doc.push_str("_None this year._\n");
+    }
+    for objective in review.objectives.iter().take(TOP_OBJECTIVES) {
+        doc.push_str(&format!(
+            "- {}: {} ({})\n",
+            objective.domain.as_str(),
+            objective.title,
+            objective.completed.format("%b %d")
+        ));
+    }
+    if review.objectives.len() > TOP_OBJECTIVES {
+        doc.push_str(&format!(
+            "- …and {} more\n",
+            review.objectives.len() - TOP_OBJECTIVES
+        ));
+    }
+
+    doc.push_str("\n## Indicator bests\n\n");
+    if review.indicator_bests.is_empty() {
+        doc.push_str("_No observations this year._\n");
+    }
+    for best in &review.indicator_bests {
+        doc.push_str(&format!(
+            "- {}: {} ({})\n",
+            best.name,
+            best.value,
+            best.date.format("%b %d")
+        ));
+    }
+    doc
+}
+
+/// Write the review to exports/year-review-YYYY.md
+pub fn write_export(review: &YearReview, config: &Config) -> Result<PathBuf> {
+    let dir = Path::new(&config.data_root).join("exports");
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create exports directory: {}", dir.display()))?;
+    let path = dir.join(format!("year-review-{}.md", review.year));
+    fs::write(&path, to_markdown(review))
+        .with_context(|| format!("Failed to write year review: {}", path.display()))?;
+    Ok(path)
+}
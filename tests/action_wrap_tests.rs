@@ -0,0 +1,75 @@
+mod common;
+
+use common::temp_config;
+use focusfive::ui::app::FocusPanel;
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::Terminal;
+
+const LONG: &str = "Draft the quarterly report for the board including the revenue \
+    forecast, hiring plan and the open questions from the last review";
+
+fn render(app: &mut App) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+    terminal.backend().buffer().clone()
+}
+
+fn rows(buffer: &Buffer) -> Vec<String> {
+    buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect())
+        .collect()
+}
+
+#[test]
+fn long_actions_wrap_and_stay_highlighted() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+    app.goals.work.actions[0].text = LONG.to_string();
+    app.goals.work.actions[1].text = "Short one".to_string();
+    app.focus_panel = FocusPanel::Actions;
+
+    let buffer = render(&mut app);
+    let screen = rows(&buffer);
+    let first = screen.iter().position(|l| l.contains("Draft the")).unwrap();
+    let last = screen
+        .iter()
+        .position(|l| l.contains("last review"))
+        .unwrap();
+    assert!(last > first, "the action takes more than one row");
+    let short = screen.iter().position(|l| l.contains("Short one")).unwrap();
+    assert_eq!(short, last + 1);
+
+    // Continuation rows line up under the text, not the checkbox
+    let column = screen[first][..screen[first].find("Draft").unwrap()]
+        .chars()
+        .count();
+    let next: Vec<char> = screen[first + 1].chars().collect();
+    assert_eq!(next[column - 1], ' ');
+    assert_ne!(next[column], ' ');
+
+    // Every wrapped row has the selection background
+    let column = column as u16;
+    let selected_bg = buffer[(column, first as u16)].bg;
+    for row in first..=last {
+        assert_eq!(buffer[(column, row as u16)].bg, selected_bg);
+    }
+    assert_ne!(buffer[(column, short as u16)].bg, selected_bg);
+}
+
+#[test]
+fn editor_popup_grows_to_show_long_text() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+    let text = LONG.repeat(3);
+    app.text_editor.activate_with("Edit Action", &text, 500);
+
+    let screen = rows(&render(&mut app)).join("\n");
+    assert!(screen.contains("last review│"));
+    assert!(screen.contains("[Enter] Save"));
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::models::Action;
+use focusfive::ui::app::FocusPanel;
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+fn render(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(160, 12)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn expanded_actions_scroll_with_the_selection() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+    while app.goals.work.actions.len() < 5 {
+        app.goals.work.actions.push(Action::new_empty());
+    }
+    let names = ["Alpha", "Bravo", "Charlie", "Delta", "Echo"];
+    for (action, name) in app.goals.work.actions.iter_mut().zip(names) {
+        action.text = name.to_string();
+        app.ui_state.toggle_expansion(action.id.clone());
+    }
+    app.focus_panel = FocusPanel::Actions;
+
+    let screen = render(&mut app);
+    assert!(screen.contains("Alpha"));
+    assert!(!screen.contains("Echo"), "ten lines overflow the panel");
+    assert_eq!(app.actions_scroll, 0);
+
+    for _ in 0..4 {
+        app.handle_key(KeyCode::Char('j')).unwrap();
+    }
+    let screen = render(&mut app);
+    assert_eq!(app.selected_action, 4);
+    assert!(screen.contains("Echo"));
+    assert!(!screen.contains("Alpha"));
+    assert!(app.actions_scroll > 0);
+    // The selected action's expanded line is kept in view with it
+    let echo_row = screen
+        .lines()
+        .position(|line| line.contains("Echo"))
+        .unwrap();
+    assert!(screen
+        .lines()
+        .nth(echo_row + 1)
+        .unwrap()
+        .contains("No objective linked"));
+
+    // Moving back up scrolls back once the selection leaves the top
+    for _ in 0..4 {
+        app.handle_key(KeyCode::Char('k')).unwrap();
+    }
+    let screen = render(&mut app);
+    assert_eq!(app.actions_scroll, 0);
+    assert!(screen.contains("Alpha"));
+}
@@ -0,0 +1,111 @@
+mod common;
+
+use chrono::{Duration, NaiveDate, Utc};
+use common::temp_config;
+use focusfive::command::{parse, run_headless, ExCommand};
+use focusfive::data::{load_or_create_indicators, save_indicators};
+use focusfive::models::{
+    IndicatorAggregation, IndicatorDef, IndicatorKind, IndicatorUnit, IndicatorsData, Observation,
+};
+
+fn indicator(id: &str, name: &str, aggregation: IndicatorAggregation) -> IndicatorDef {
+    let mut def = IndicatorDef::new(
+        name.to_string(),
+        IndicatorKind::Leading,
+        IndicatorUnit::Count,
+    );
+    def.id = id.to_string();
+    def.aggregation = aggregation;
+    def
+}
+
+/// An observation `seconds` after a fixed instant, so "last" is well defined
+fn observation(id: &str, when: NaiveDate, value: f64, seconds: i64) -> Observation {
+    let mut obs = Observation::new(id.to_string(), when, value, IndicatorUnit::Count);
+    obs.created = Utc::now() + Duration::seconds(seconds);
+    obs
+}
+
+#[test]
+fn days_combine_by_each_indicators_aggregation() {
+    let monday = NaiveDate::from_ymd_opt(2025, 3, 3).unwrap();
+    let tuesday = monday + Duration::days(1);
+    // Recorded out of order; the later weigh-in is the day's value
+    let observations = vec![
+        observation("ind", tuesday, 30.0, 3),
+        observation("ind", monday, 20.0, 2),
+        observation("ind", monday, 10.0, 1),
+        observation("other", monday, 99.0, 4),
+    ];
+
+    let last = indicator("ind", "Weight", IndicatorAggregation::Last);
+    assert_eq!(
+        last.daily_values(&observations),
+        vec![(monday, 20.0), (tuesday, 30.0)]
+    );
+    let sum = indicator("ind", "Minutes exercised", IndicatorAggregation::Sum);
+    assert_eq!(
+        sum.daily_values(&observations),
+        vec![(monday, 30.0), (tuesday, 30.0)]
+    );
+    let average = indicator("ind", "Mood", IndicatorAggregation::Average);
+    assert_eq!(
+        average.daily_values(&observations),
+        vec![(monday, 15.0), (tuesday, 30.0)]
+    );
+
+    assert!(last.daily_values(&[]).is_empty());
+}
+
+#[test]
+fn last_is_the_default_and_stays_out_of_the_file() {
+    let def = indicator("ind", "Weight", IndicatorAggregation::Last);
+    let json = serde_json::to_string(&def).unwrap();
+    assert!(!json.contains("aggregation"));
+
+    let def = indicator("ind", "Minutes", IndicatorAggregation::Sum);
+    let json = serde_json::to_string(&def).unwrap();
+    assert!(json.contains("\"aggregation\":\"sum\""));
+    let parsed: IndicatorDef = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.aggregation, IndicatorAggregation::Sum);
+
+    // Focus sessions log the day's running total, so the last one is the day
+    assert_eq!(
+        IndicatorDef::focus_minutes().aggregation,
+        IndicatorAggregation::Last
+    );
+    assert_eq!(IndicatorDef::mood().aggregation, IndicatorAggregation::Average);
+}
+
+#[test]
+fn aggregate_command_sets_the_rule() {
+    let (_temp_dir, config) = temp_config();
+    save_indicators(
+        &IndicatorsData {
+            version: 1,
+            indicators: vec![indicator("run", "Running", IndicatorAggregation::Last)],
+        },
+        &config,
+    )
+    .unwrap();
+    let today = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+
+    assert_eq!(
+        parse("aggregate \"Running\" total").unwrap(),
+        ExCommand::Aggregate {
+            indicator: "Running".to_string(),
+            aggregation: IndicatorAggregation::Sum,
+        }
+    );
+    let message = run_headless(&parse("aggregate running sum").unwrap(), today, &config).unwrap();
+    assert_eq!(message, "Running now adds up each day's values");
+    let stored = load_or_create_indicators(&config).unwrap();
+    assert_eq!(
+        stored.find("run").unwrap().aggregation,
+        IndicatorAggregation::Sum
+    );
+
+    assert!(parse("aggregate Running median").is_err());
+    assert!(parse("aggregate Running").is_err());
+    assert!(run_headless(&parse("aggregate Rowing avg").unwrap(), today, &config).is_err());
+}
@@ -0,0 +1,112 @@
+mod common;
+
+use chrono::{Local, NaiveDate};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::automation::{action_tags, observations_for};
+use focusfive::command::{parse, run_headless, ExCommand};
+use focusfive::data::{load_or_create_indicators, read_observations_range, save_indicators};
+use focusfive::models::{
+    Action, IndicatorDef, IndicatorKind, IndicatorUnit, IndicatorsData, ObservationSource,
+    OutcomeType,
+};
+use focusfive::ui::App;
+
+fn pushups() -> IndicatorsData {
+    IndicatorsData {
+        version: 1,
+        indicators: vec![IndicatorDef::new(
+            "Pushups".to_string(),
+            IndicatorKind::Leading,
+            IndicatorUnit::Count,
+        )],
+    }
+}
+
+#[test]
+fn tags_come_from_hashtags_and_metadata() {
+    assert_eq!(
+        action_tags("Morning #PushUps, then #run", &["#Fitness".to_string()]),
+        vec!["pushups", "run", "fitness"]
+    );
+    assert!(action_tags("Call mom", &[]).is_empty());
+}
+
+#[test]
+fn rules_are_set_and_removed_by_command() {
+    let (_temp_dir, config) = temp_config();
+    save_indicators(&pushups(), &config).unwrap();
+    let today = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+
+    let command = parse("rule Pushups #pushups +20").unwrap();
+    assert_eq!(
+        command,
+        ExCommand::Rule {
+            indicator: "Pushups".to_string(),
+            tag: "#pushups".to_string(),
+            value: Some(20.0),
+        }
+    );
+    run_headless(&command, today, &config).unwrap();
+    let stored = load_or_create_indicators(&config).unwrap();
+    assert_eq!(stored.indicators[0].auto_rules.len(), 1);
+    assert_eq!(stored.indicators[0].auto_rules[0].tag, "pushups");
+
+    let mut action = Action::new_empty();
+    action.text = "Do #pushups".to_string();
+    let observations = observations_for(&action, &["pushups".to_string()], &stored, today);
+    assert_eq!(observations.len(), 1);
+    assert_eq!(observations[0].value, 20.0);
+    action.text = "Stretch".to_string();
+    assert!(observations_for(&action, &[], &stored, today).is_empty());
+
+    run_headless(
+        &parse("rule Pushups #pushups none").unwrap(),
+        today,
+        &config,
+    )
+    .unwrap();
+    let stored = load_or_create_indicators(&config).unwrap();
+    assert!(stored.indicators[0].auto_rules.is_empty());
+
+    assert!(parse("rule Pushups pushups 20").is_err());
+    assert!(run_headless(&parse("rule Squats #legs 5").unwrap(), today, &config).is_err());
+}
+
+#[test]
+fn completing_a_tagged_action_adds_the_rule_value() {
+    let (_temp_dir, config) = temp_config();
+    save_indicators(&pushups(), &config).unwrap();
+
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    app.run_ex_command("rule Pushups #pushups 20").unwrap();
+    let indicator_id = app.indicators.indicators[0].id.clone();
+
+    app.goals.health.actions[0].text = "Morning #pushups".to_string();
+    app.selected_outcome = OutcomeType::Health;
+    app.handle_key(KeyCode::Tab).unwrap();
+    app.selected_action = 0;
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    assert!(app.goals.health.actions[0].completed);
+
+    let today = Local::now().date_naive();
+    let observations = read_observations_range(today, today, &config).unwrap();
+    assert_eq!(observations.len(), 1);
+    assert_eq!(observations[0].indicator_id, indicator_id);
+    assert_eq!(observations[0].value, 20.0);
+    assert_eq!(observations[0].source, ObservationSource::Automated);
+    assert_eq!(
+        observations[0].action_id.as_deref(),
+        Some(app.goals.health.actions[0].id.as_str())
+    );
+
+    // Unchecking adds nothing
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    assert_eq!(
+        read_observations_range(today, today, &config)
+            .unwrap()
+            .len(),
+        1
+    );
+}
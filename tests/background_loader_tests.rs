@@ -0,0 +1,106 @@
+mod common;
+
+use chrono::{Duration, Local, NaiveDate};
+use common::temp_config;
+use focusfive::data::{append_observation, write_goals_file};
+use focusfive::loader::{BackgroundLoader, Loaded};
+use focusfive::models::{DailyGoals, IndicatorUnit, Observation, OutcomeType};
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+fn render(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(200, 50)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn only_the_newest_request_is_delivered() {
+    let (_temp_dir, config) = temp_config();
+    let day = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    append_observation(
+        &Observation::new("steps".to_string(), day, 9000.0, IndicatorUnit::Count),
+        &config,
+    )
+    .unwrap();
+
+    let mut loader = BackgroundLoader::start(config);
+    loader.request_dashboard(day - Duration::days(40));
+    loader.request_dashboard(day);
+    assert!(loader.dashboard_loading());
+
+    let loaded = loader.wait(std::time::Duration::from_secs(10));
+    assert_eq!(loaded.len(), 1);
+    match &loaded[0] {
+        Loaded::Dashboard(data) => {
+            assert_eq!(data.date, day);
+            assert_eq!(data.observations.len(), 1);
+            assert_eq!(data.since(day + Duration::days(1)).len(), 0);
+        }
+        other => panic!("expected dashboard data, got {:?}", other),
+    }
+    assert!(!loader.dashboard_loading());
+    assert!(loader.try_next().is_none());
+}
+
+#[test]
+fn statistics_history_arrives_after_startup() {
+    let (_temp_dir, config) = temp_config();
+    let yesterday = Local::now().date_naive() - Duration::days(1);
+    let mut goals = DailyGoals::new(yesterday);
+    for action in goals.work.actions.iter_mut() {
+        action.completed = true;
+    }
+    write_goals_file(&goals, &config).unwrap();
+
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+    assert!(!app.loader.history_loading());
+    assert_eq!(app.statistics.weekly_trend.len(), 7);
+    assert_eq!(app.statistics.monthly_trend.len(), 30);
+    let previous = app.statistics.weekly_trend[5];
+    assert!((previous - 100.0 / 3.0).abs() < 1e-9);
+}
+
+#[test]
+fn dashboard_shows_spinner_until_data_loads() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config).unwrap();
+    app.show_dashboard = true;
+
+    assert!(render(&mut app).contains("Loading..."));
+    assert!(app.loader.dashboard_loading());
+
+    app.wait_for_background();
+    assert!(app.dashboard_data.is_some());
+    assert!(!render(&mut app).contains("Loading..."));
+}
+
+#[test]
+fn outcomes_show_two_weeks_of_momentum() {
+    let (_temp_dir, config) = temp_config();
+    let today = Local::now().date_naive();
+    let mut goals = DailyGoals::new(today - Duration::days(1));
+    for action in goals.work.actions.iter_mut() {
+        action.completed = true;
+    }
+    write_goals_file(&goals, &config).unwrap();
+
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+    let work = app.statistics.outcome_trend(OutcomeType::Work);
+    assert_eq!(work.len(), 14);
+    assert_eq!(work[12], 100.0);
+    assert_eq!(app.statistics.outcome_trend(OutcomeType::Health)[12], 0.0);
+
+    let screen = render(&mut app);
+    let work_row = screen.lines().find(|line| line.contains("Work [")).unwrap();
+    assert!(work_row.contains("▁▁▁▁▁▁▁▁▁▁▁▁█▁"));
+}
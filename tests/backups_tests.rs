@@ -0,0 +1,125 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::backups;
+use focusfive::data::{
+    goals_file_path, load_or_create_objectives, read_goals_file, save_objectives, save_settings,
+    write_goals_file,
+};
+use focusfive::models::{
+    BackupSettings, DailyGoals, Objective, ObjectivesData, OutcomeType, Settings,
+};
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+use std::fs;
+
+fn day(date: NaiveDate, first_action: &str) -> DailyGoals {
+    let mut goals = DailyGoals::new(date);
+    goals.work.actions[0].text = first_action.to_string();
+    goals
+}
+
+#[test]
+fn overwriting_a_goals_file_backs_up_the_old_version() {
+    let (_temp_dir, config) = temp_config();
+    let date = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+
+    write_goals_file(&day(date, "Draft"), &config).unwrap();
+    assert!(backups::list(&config).unwrap().is_empty());
+    // Saving the same content is not destructive
+    write_goals_file(&day(date, "Draft"), &config).unwrap();
+    assert!(backups::list(&config).unwrap().is_empty());
+
+    write_goals_file(&day(date, "Final"), &config).unwrap();
+    let list = backups::list(&config).unwrap();
+    assert_eq!(list.len(), 1);
+    assert_eq!(list[0].file, "goals/2025-03-01.md");
+    assert!(fs::read_to_string(&list[0].path).unwrap().contains("Draft"));
+
+    // Restoring keeps the version it replaces
+    backups::restore(&list[0], &config).unwrap();
+    let restored = read_goals_file(&goals_file_path(date, &config)).unwrap();
+    assert_eq!(restored.work.actions[0].text, "Draft");
+    let list = backups::list(&config).unwrap();
+    assert_eq!(list.len(), 2);
+    assert!(list
+        .iter()
+        .any(|backup| fs::read_to_string(&backup.path).unwrap().contains("Final")));
+}
+
+#[test]
+fn deleting_an_objective_can_be_undone() {
+    let (_temp_dir, config) = temp_config();
+    let objective = Objective::new(OutcomeType::Work, "Ship v2".to_string());
+    save_objectives(
+        &ObjectivesData {
+            objectives: vec![objective.clone()],
+            ..Default::default()
+        },
+        &config,
+    )
+    .unwrap();
+    save_objectives(&ObjectivesData::default(), &config).unwrap();
+
+    let list = backups::list(&config).unwrap();
+    assert_eq!(list[0].file, "objectives.json");
+    backups::restore(&list[0], &config).unwrap();
+    assert_eq!(
+        load_or_create_objectives(&config).unwrap().objectives[0].id,
+        objective.id
+    );
+}
+
+#[test]
+fn old_copies_are_pruned() {
+    let (_temp_dir, config) = temp_config();
+    save_settings(
+        &Settings {
+            backups: BackupSettings {
+                keep: 2,
+                max_age_days: 30,
+            },
+            ..Default::default()
+        },
+        &config,
+    )
+    .unwrap();
+    let date = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+    for text in ["One", "Two", "Three", "Four"] {
+        write_goals_file(&day(date, text), &config).unwrap();
+    }
+
+    let list = backups::list(&config).unwrap();
+    assert_eq!(list.len(), 2);
+    assert!(fs::read_to_string(&list[0].path).unwrap().contains("Three"));
+    assert!(fs::read_to_string(&list[1].path).unwrap().contains("Two"));
+}
+
+#[test]
+fn restore_picker_needs_a_second_enter() {
+    let (_temp_dir, config) = temp_config();
+    let today = chrono::Local::now().date_naive();
+    write_goals_file(&day(today, "Before"), &config).unwrap();
+    write_goals_file(&day(today, "After"), &config).unwrap();
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    assert_eq!(app.goals.work.actions[0].text, "After");
+
+    app.handle_key(KeyCode::Char('U')).unwrap();
+    assert!(matches!(app.modal, Some(ModalState::Backups(_))));
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert_eq!(app.goals.work.actions[0].text, "After");
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert!(app.modal.is_none());
+    assert_eq!(app.goals.work.actions[0].text, "Before");
+    assert_eq!(
+        read_goals_file(&goals_file_path(today, &config))
+            .unwrap()
+            .work
+            .actions[0]
+            .text,
+        "Before"
+    );
+}
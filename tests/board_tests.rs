@@ -0,0 +1,122 @@
+mod common;
+
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::board::{cards, set_status, StatusCounts};
+use focusfive::data::{load_or_create_day_meta, load_or_create_goals, write_goals_file};
+use focusfive::models::{ActionStatus, DailyGoals, OutcomeType};
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+
+#[test]
+fn statuses_persist_in_meta_and_drive_the_checkbox() {
+    let (_temp_dir, config) = temp_config();
+    let mut goals = DailyGoals::new(chrono::NaiveDate::from_ymd_opt(2025, 6, 4).unwrap());
+    goals.work.actions[0].text = "Draft".to_string();
+    goals.work.actions[1].text = "Review".to_string();
+    goals.health.actions[0].text = "Swim".to_string();
+    goals.health.actions[0].completed = true;
+    write_goals_file(&goals, &config).unwrap();
+
+    let statuses: Vec<_> = cards(&goals, &config)
+        .unwrap()
+        .iter()
+        .map(|c| c.status)
+        .collect();
+    assert_eq!(
+        statuses,
+        [
+            ActionStatus::Planned,
+            ActionStatus::Planned,
+            ActionStatus::Done
+        ]
+    );
+
+    set_status(
+        &mut goals,
+        OutcomeType::Work,
+        0,
+        ActionStatus::InProgress,
+        &config,
+    )
+    .unwrap();
+    set_status(
+        &mut goals,
+        OutcomeType::Work,
+        1,
+        ActionStatus::Skipped,
+        &config,
+    )
+    .unwrap();
+    set_status(
+        &mut goals,
+        OutcomeType::Health,
+        0,
+        ActionStatus::Planned,
+        &config,
+    )
+    .unwrap();
+    assert!(!goals.health.actions[0].completed);
+    set_status(
+        &mut goals,
+        OutcomeType::Work,
+        0,
+        ActionStatus::Done,
+        &config,
+    )
+    .unwrap();
+
+    let saved = load_or_create_goals(goals.date, &config).unwrap();
+    assert!(saved.work.actions[0].completed);
+    assert!(!saved.health.actions[0].completed);
+    let meta = load_or_create_day_meta(goals.date, &saved, &config).unwrap();
+    assert_eq!(meta.work[1].status, ActionStatus::Skipped);
+
+    let counts = StatusCounts::from_cards(&cards(&saved, &config).unwrap());
+    assert_eq!((counts.done, counts.skipped, counts.planned), (1, 1, 1));
+    // Skipped actions do not count against the day
+    assert_eq!(counts.completion(), 50.0);
+}
+
+#[test]
+fn board_moves_actions_between_columns_with_h_and_l() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    app.goals.work.actions[0].text = "Draft".to_string();
+    app.goals.family.actions[0].text = "Call mum".to_string();
+
+    app.handle_key(KeyCode::Char('K')).unwrap();
+    let Some(ModalState::Board(state)) = app.modal.clone() else {
+        panic!("expected the board");
+    };
+    assert_eq!(state.column_cards(0).len(), 2);
+
+    // Planned -> In Progress -> Blocked -> Done
+    app.handle_key(KeyCode::Char('l')).unwrap();
+    assert_eq!(app.statistics.status_counts.in_progress, 1);
+    app.handle_key(KeyCode::Char('l')).unwrap();
+    app.handle_key(KeyCode::Char('l')).unwrap();
+    assert!(app.goals.work.actions[0].completed);
+    let Some(ModalState::Board(state)) = app.modal.clone() else {
+        panic!("board closed");
+    };
+    assert_eq!(state.column, 3);
+    assert_eq!(state.selected().unwrap().text, "Draft");
+
+    app.handle_key(KeyCode::Char('h')).unwrap();
+    assert!(!app.goals.work.actions[0].completed);
+    assert_eq!(app.statistics.status_counts.blocked, 1);
+
+    app.handle_key(KeyCode::Left).unwrap();
+    app.handle_key(KeyCode::Left).unwrap();
+    let Some(ModalState::Board(state)) = app.modal.clone() else {
+        panic!("board closed");
+    };
+    assert_eq!(state.selected().unwrap().text, "Call mum");
+
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(app.modal.is_none());
+    let saved = load_or_create_goals(app.goals.date, &config).unwrap();
+    assert_eq!(saved.work.actions[0].text, "Draft");
+}
@@ -0,0 +1,107 @@
+use chrono::{Duration, Local};
+use crossterm::event::KeyCode;
+use focusfive::data::{append_observation, read_observations_range, save_indicators};
+use focusfive::models::{
+    Config, IndicatorDef, IndicatorKind, IndicatorUnit, IndicatorsData, Observation,
+};
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+use std::fs;
+use tempfile::TempDir;
+
+fn setup_app() -> (App, Config, TempDir) {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let goals_dir = temp_dir.path().join("goals");
+    fs::create_dir_all(&goals_dir).unwrap();
+
+    let config = Config {
+        goals_dir: goals_dir.to_str().unwrap().to_string(),
+        data_root: temp_dir.path().to_str().unwrap().to_string(),
+    };
+
+    let mut paused = IndicatorDef::new(
+        "Paused metric".to_string(),
+        IndicatorKind::Lagging,
+        IndicatorUnit::Count,
+    );
+    paused.active = false;
+
+    let indicators = IndicatorsData {
+        version: 1,
+        indicators: vec![
+            IndicatorDef::new(
+                "Pushups".to_string(),
+                IndicatorKind::Leading,
+                IndicatorUnit::Count,
+            ),
+            paused,
+            IndicatorDef::new(
+                "Deep work".to_string(),
+                IndicatorKind::Leading,
+                IndicatorUnit::Minutes,
+            ),
+        ],
+    };
+    save_indicators(&indicators, &config).unwrap();
+
+    let yesterday = Local::now().date_naive() - Duration::days(1);
+    let obs = Observation::new(
+        indicators.indicators[0].id.clone(),
+        yesterday,
+        12.0,
+        IndicatorUnit::Count,
+    );
+    append_observation(&obs, &config).unwrap();
+
+    let app = App::new(config.clone()).expect("app should initialise");
+    (app, config, temp_dir)
+}
+
+fn modal_state(app: &App) -> (String, String, Option<(usize, usize)>) {
+    match &app.modal {
+        Some(ModalState::IndicatorUpdate(state)) => (
+            state.name.clone(),
+            state.buffer.clone(),
+            state.review_position,
+        ),
+        other => panic!("expected indicator modal, got {:?}", other),
+    }
+}
+
+#[test]
+fn log_all_walks_active_indicators_with_yesterdays_values() {
+    let (mut app, config, _temp) = setup_app();
+
+    app.handle_key(KeyCode::Char('L')).unwrap();
+    let (name, buffer, position) = modal_state(&app);
+    assert_eq!(name, "Pushups");
+    assert_eq!(buffer, "12");
+    assert_eq!(position, Some((1, 2)));
+
+    // Accept the prefilled value and move on; the paused indicator is skipped
+    app.handle_key(KeyCode::Enter).unwrap();
+    let (name, _, position) = modal_state(&app);
+    assert_eq!(name, "Deep work");
+    assert_eq!(position, Some((2, 2)));
+
+    // Tab skips without recording
+    app.handle_key(KeyCode::Tab).unwrap();
+    assert!(app.modal.is_none());
+    assert_eq!(app.bulk_review_total, 0);
+
+    let today = Local::now().date_naive();
+    let logged = read_observations_range(today, today, &config).unwrap();
+    assert_eq!(logged.len(), 1);
+    assert_eq!(logged[0].value, 12.0);
+}
+
+#[test]
+fn escape_ends_the_review() {
+    let (mut app, _config, _temp) = setup_app();
+
+    app.handle_key(KeyCode::Char('L')).unwrap();
+    app.handle_key(KeyCode::Esc).unwrap();
+
+    assert!(app.modal.is_none());
+    assert!(app.bulk_review_queue.is_empty());
+}
@@ -0,0 +1,74 @@
+use chrono::Local;
+use crossterm::event::KeyCode;
+use focusfive::data::save_indicators;
+use focusfive::models::{
+    CapStatus, Config, IndicatorDef, IndicatorDirection, IndicatorKind, IndicatorUnit,
+    IndicatorsData,
+};
+use focusfive::ui::App;
+use std::fs;
+use tempfile::TempDir;
+
+fn capped(name: &str, cap: f64) -> IndicatorDef {
+    let mut def = IndicatorDef::new(
+        name.to_string(),
+        IndicatorKind::Lagging,
+        IndicatorUnit::Minutes,
+    );
+    def.direction = IndicatorDirection::LowerIsBetter;
+    def.target = Some(cap);
+    def
+}
+
+#[test]
+fn cap_status_tracks_distance_to_cap() {
+    let screen = capped("Screen time", 120.0);
+    assert_eq!(screen.cap_status(30.0), Some(CapStatus::Under));
+    assert_eq!(screen.cap_status(96.0), Some(CapStatus::Approaching));
+    assert_eq!(screen.cap_status(120.0), Some(CapStatus::Approaching));
+    assert_eq!(screen.cap_status(121.0), Some(CapStatus::Exceeded));
+
+    let mut uncapped = screen.clone();
+    uncapped.target = None;
+    assert_eq!(uncapped.cap_status(500.0), None);
+
+    let mut higher = screen.clone();
+    higher.direction = IndicatorDirection::HigherIsBetter;
+    assert_eq!(higher.cap_status(500.0), None);
+}
+
+#[test]
+fn end_of_day_callout_lists_capped_indicators() {
+    let temp_dir = TempDir::new().unwrap();
+    let goals_dir = temp_dir.path().join("goals");
+    fs::create_dir_all(&goals_dir).unwrap();
+    let config = Config {
+        goals_dir: goals_dir.to_str().unwrap().to_string(),
+        data_root: temp_dir.path().to_str().unwrap().to_string(),
+    };
+
+    let indicators = IndicatorsData {
+        version: 1,
+        indicators: vec![capped("Screen time", 120.0), capped("Snacks", 10.0)],
+    };
+    save_indicators(&indicators, &config).unwrap();
+
+    let mut app = App::new(config).unwrap();
+    let today = Local::now().date_naive();
+    assert_eq!(app.end_of_day_cap_callout(today), None);
+
+    // Log 150 minutes of screen time through the quick-log picker
+    app.handle_key(KeyCode::Char('+')).unwrap();
+    for ch in "screen".chars() {
+        app.handle_key(KeyCode::Char(ch)).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+    for ch in "150".chars() {
+        app.handle_key(KeyCode::Char(ch)).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+
+    let callout = app.end_of_day_cap_callout(today).unwrap();
+    assert!(callout.contains("Over cap today: Screen time 150/120"));
+    assert!(!callout.contains("Snacks"));
+}
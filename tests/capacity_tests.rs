@@ -0,0 +1,110 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use focusfive::capacity::{set_estimate, DayCapacity};
+use focusfive::command::{parse, run_headless, ExCommand};
+use focusfive::data::{load_or_create_day_meta, load_settings, write_goals_file};
+use focusfive::models::{DailyGoals, OutcomeType, Settings, DEFAULT_DAILY_CAPACITY_MIN};
+use focusfive::ui::app::FocusPanel;
+use focusfive::ui::App;
+
+#[test]
+fn estimates_sum_against_the_daily_capacity() {
+    let (_temp_dir, config) = temp_config();
+    let mut goals = DailyGoals::new(NaiveDate::from_ymd_opt(2025, 6, 4).unwrap());
+    goals.work.actions[0].text = "Write report".to_string();
+    goals.health.actions[0].text = "Run".to_string();
+    write_goals_file(&goals, &config).unwrap();
+
+    let settings = Settings::default();
+    assert_eq!(settings.daily_capacity(), DEFAULT_DAILY_CAPACITY_MIN);
+
+    set_estimate(&goals, OutcomeType::Work, 0, Some(8 * 60), &config).unwrap();
+    set_estimate(&goals, OutcomeType::Health, 0, Some(3 * 60), &config).unwrap();
+    assert!(set_estimate(&goals, OutcomeType::Work, 1, Some(30), &config).is_err());
+    assert!(set_estimate(&goals, OutcomeType::Work, 9, Some(30), &config).is_err());
+
+    let capacity = DayCapacity::for_day(&goals, &settings, &config).unwrap();
+    assert_eq!(capacity.planned, 11 * 60);
+    assert!(capacity.is_over());
+    assert_eq!(capacity.label(), "11h / 8h");
+    assert_eq!(
+        capacity.warning().unwrap(),
+        "Planned 11h of work for a capacity of 8h (3h over)"
+    );
+
+    set_estimate(&goals, OutcomeType::Work, 0, None, &config).unwrap();
+    let capacity = DayCapacity::for_day(&goals, &settings, &config).unwrap();
+    assert_eq!(capacity.planned, 3 * 60);
+    assert!(capacity.warning().is_none());
+}
+
+#[test]
+fn capacity_and_estimate_commands() {
+    let (_temp_dir, config) = temp_config();
+    assert_eq!(parse("capacity 7h30m").unwrap(), ExCommand::Capacity(450));
+    assert_eq!(
+        parse("est h 2 45m").unwrap(),
+        ExCommand::Estimate {
+            target: Some((OutcomeType::Health, 1)),
+            minutes: Some(45),
+        }
+    );
+    assert_eq!(
+        parse("estimate none").unwrap(),
+        ExCommand::Estimate {
+            target: None,
+            minutes: None,
+        }
+    );
+    for bad in [
+        "capacity 0",
+        "capacity soon",
+        "estimate",
+        "estimate work 0 1h",
+    ] {
+        assert!(parse(bad).is_err(), "{:?} should not parse", bad);
+    }
+
+    let day = NaiveDate::from_ymd_opt(2025, 6, 4).unwrap();
+    let mut goals = DailyGoals::new(day);
+    goals.work.actions[0].text = "Plan launch".to_string();
+    write_goals_file(&goals, &config).unwrap();
+
+    run_headless(&parse("capacity 2h").unwrap(), day, &config).unwrap();
+    assert_eq!(
+        load_settings(&config).unwrap().daily_capacity_min,
+        Some(120)
+    );
+
+    assert!(run_headless(&parse("estimate 1h").unwrap(), day, &config).is_err());
+    let message = run_headless(&parse("estimate work 1 3h").unwrap(), day, &config).unwrap();
+    assert!(message.starts_with("Estimated 3h for: Plan launch"));
+    assert!(message.contains("Warning: Planned 3h of work for a capacity of 2h"));
+    let meta = load_or_create_day_meta(day, &goals, &config).unwrap();
+    assert_eq!(meta.work[0].estimated_min, Some(180));
+}
+
+#[test]
+fn app_estimates_the_selected_action_and_tracks_the_load() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    app.focus_panel = FocusPanel::Actions;
+    app.goals.work.actions[0].text = "Deep work".to_string();
+    app.goals.work.actions[1].text = "Meetings".to_string();
+
+    app.run_ex_command("estimate 6h").unwrap();
+    app.run_ex_command("estimate work 2 3h").unwrap();
+    assert_eq!(app.day_capacity().planned, 9 * 60);
+    assert!(app.day_capacity().is_over());
+
+    app.run_ex_command("capacity 10h").unwrap();
+    assert_eq!(app.settings.daily_capacity(), 600);
+    assert!(!app.day_capacity().is_over());
+
+    let meta = load_or_create_day_meta(app.goals.date, &app.goals, &config).unwrap();
+    assert_eq!(meta.work[0].estimated_min, Some(360));
+    assert_eq!(meta.work[1].estimated_min, Some(180));
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use chrono::{Duration, Local, NaiveDate};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::carryover::{apply, pending_items, CarryChoice};
+use focusfive::data::{
+    goals_file_path, load_or_create_day_meta, load_or_create_goals, save_day_meta, write_goals_file,
+};
+use focusfive::models::{ActionOrigin, Config, DailyGoals, DayMeta};
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+use std::fs;
+
+/// Yesterday: two open work actions (one already carried since `since`),
+/// one finished work action and one open health action
+fn write_yesterday(config: &Config, yesterday: NaiveDate, since: NaiveDate) {
+    let mut goals = DailyGoals::new(yesterday);
+    goals.work.actions[0].text = "Write report".to_string();
+    goals.work.actions[0].add_objective_id("obj-1".to_string());
+    goals.work.actions[1].text = "Done already".to_string();
+    goals.work.actions[1].completed = true;
+    goals.work.actions[2].text = "Call vendor".to_string();
+    goals.health.actions[0].text = "Book dentist".to_string();
+    write_goals_file(&goals, config).unwrap();
+
+    let mut meta = DayMeta::from_goals(&goals);
+    meta.work[2].origin = ActionOrigin::CarryOver;
+    meta.work[2].carried_from = Some(since);
+    save_day_meta(yesterday, &meta, config).unwrap();
+}
+
+#[test]
+fn pending_items_keep_lineage_and_apply_places_them() {
+    let (_temp_dir, config) = temp_config();
+    let today = NaiveDate::from_ymd_opt(2025, 6, 4).unwrap();
+    let yesterday = today - Duration::days(1);
+    let since = today - Duration::days(5);
+    write_yesterday(&config, yesterday, since);
+
+    let mut items = pending_items(today, &config).unwrap();
+    let texts: Vec<_> = items.iter().map(|i| i.text.as_str()).collect();
+    assert_eq!(texts, ["Write report", "Call vendor", "Book dentist"]);
+    assert_eq!(items[0].first_planned, yesterday);
+    assert_eq!(items[1].first_planned, since);
+    assert_eq!(items[0].objective_ids, ["obj-1"]);
+    assert!(items.iter().all(|i| i.choice == CarryChoice::Carry));
+
+    let later = today + Duration::days(3);
+    items[1].choice = CarryChoice::Snooze(later);
+    items[2].choice = CarryChoice::Drop;
+
+    let mut goals = load_or_create_goals(today, &config).unwrap();
+    goals.work.actions[0].text = "Planned today".to_string();
+    let summary = apply(&items, &mut goals, &config).unwrap();
+    assert_eq!(
+        (summary.carried, summary.snoozed, summary.dropped),
+        (1, 1, 1)
+    );
+
+    let saved = load_or_create_goals(today, &config).unwrap();
+    assert_eq!(saved.work.actions[0].text, "Planned today");
+    assert_eq!(saved.work.actions[1].text, "Write report");
+    assert_eq!(goals.work.actions[1].origin, ActionOrigin::CarryOver);
+    assert_eq!(saved.work.actions[1].get_all_objective_ids(), ["obj-1"]);
+    assert!(saved.health.actions.iter().all(|a| a.text.is_empty()));
+    let meta = load_or_create_day_meta(today, &saved, &config).unwrap();
+    assert_eq!(meta.work[1].origin, ActionOrigin::CarryOver);
+    assert_eq!(meta.work[1].carried_from, Some(yesterday));
+
+    let snoozed = load_or_create_goals(later, &config).unwrap();
+    assert_eq!(snoozed.work.actions[0].text, "Call vendor");
+    let meta = load_or_create_day_meta(later, &snoozed, &config).unwrap();
+    assert_eq!(meta.work[0].origin, ActionOrigin::CarryOver);
+    assert_eq!(meta.work[0].carried_from, Some(since));
+}
+
+#[test]
+fn new_day_opens_the_wizard_and_applies_choices() {
+    let (_temp_dir, config) = temp_config();
+    let today = Local::now().date_naive();
+    let yesterday = today - Duration::days(1);
+    write_yesterday(&config, yesterday, yesterday - Duration::days(2));
+
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    let Some(ModalState::CarryOver(state)) = app.modal.clone() else {
+        panic!("expected the carry-over wizard");
+    };
+    assert_eq!(state.items.len(), 3);
+
+    // Keep the first, snooze the second two days out, drop the third
+    app.handle_key(KeyCode::Char('j')).unwrap();
+    app.handle_key(KeyCode::Char('z')).unwrap();
+    app.handle_key(KeyCode::Right).unwrap();
+    app.handle_key(KeyCode::Char('j')).unwrap();
+    app.handle_key(KeyCode::Char('x')).unwrap();
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert!(app.modal.is_none());
+
+    assert_eq!(app.goals.work.actions[0].text, "Write report");
+    assert_eq!(app.goals.work.actions[0].origin, ActionOrigin::CarryOver);
+    assert!(app.goals.health.actions[0].text.is_empty());
+    let snoozed = load_or_create_goals(today + Duration::days(2), &config).unwrap();
+    assert_eq!(snoozed.work.actions[0].text, "Call vendor");
+
+    // Today's file now exists, so reopening does not ask again
+    assert!(goals_file_path(today, &config).exists());
+    let app = App::new(config).unwrap();
+    assert!(app.modal.is_none());
+}
+
+#[test]
+fn esc_leaves_yesterday_untouched_and_existing_days_skip_the_wizard() {
+    let (_temp_dir, config) = temp_config();
+    let today = Local::now().date_naive();
+    let yesterday = today - Duration::days(1);
+    write_yesterday(&config, yesterday, yesterday);
+
+    let mut app = App::new(config.clone()).unwrap();
+    assert!(matches!(app.modal, Some(ModalState::CarryOver(_))));
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(app.modal.is_none());
+    assert!(app.goals.work.actions.iter().all(|a| a.text.is_empty()));
+
+    fs::remove_file(goals_file_path(today, &config)).ok();
+    write_goals_file(&DailyGoals::new(today), &config).unwrap();
+    let app = App::new(config).unwrap();
+    assert!(app.modal.is_none());
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use chrono::{Duration, Local};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{append_observation, save_indicators};
+use focusfive::models::{IndicatorDef, IndicatorKind, IndicatorUnit, IndicatorsData, Observation};
+use focusfive::ui::app::{App, DashboardPanel};
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+fn render(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(200, 50)).unwrap();
+    app.show_dashboard = true;
+    for _ in 0..2 {
+        terminal.draw(|frame| app.render(frame)).unwrap();
+        app.wait_for_background();
+    }
+    terminal
+        .backend()
+        .buffer()
+        .content
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect()
+}
+
+#[test]
+fn performance_window_zooms_and_pans() {
+    let (_temp, config) = temp_config();
+    let mut weight = IndicatorDef::new(
+        "Weight".to_string(),
+        IndicatorKind::Lagging,
+        IndicatorUnit::Count,
+    );
+    weight.id = "weight".to_string();
+    save_indicators(
+        &IndicatorsData {
+            version: 1,
+            indicators: vec![weight],
+        },
+        &config,
+    )
+    .unwrap();
+    let today = Local::now().date_naive();
+    for days_ago in [60, 45, 2, 1] {
+        let observation = Observation::new(
+            "weight".to_string(),
+            today - Duration::days(days_ago),
+            80.0 - days_ago as f64 / 10.0,
+            IndicatorUnit::Count,
+        );
+        append_observation(&observation, &config).unwrap();
+    }
+
+    let mut app = App::new(config).unwrap();
+    assert!(render(&mut app).contains("WEIGHT PERFORMANCE (7-DAY)"));
+
+    app.dashboard_focus = DashboardPanel::Performance;
+    app.handle_key(KeyCode::Char('z')).unwrap();
+    assert!(render(&mut app).contains("(30-DAY)"));
+    app.handle_key(KeyCode::Char('z')).unwrap();
+    let text = render(&mut app);
+    assert!(text.contains("(90-DAY)"));
+    // The older readings are read from disk for the wider window
+    assert!(text.contains(&(today - Duration::days(60)).format("%b %d").to_string()));
+
+    // Half a window back at a time, and never past today
+    app.handle_key(KeyCode::Char('[')).unwrap();
+    assert_eq!(app.dashboard_chart_pan, 45);
+    let end = (today - Duration::days(45)).format("%b %d").to_string();
+    assert!(render(&mut app).contains(&format!("(90-DAY TO {})", end.to_uppercase())));
+    app.handle_key(KeyCode::Char(']')).unwrap();
+    app.handle_key(KeyCode::Char(']')).unwrap();
+    assert_eq!(app.dashboard_chart_pan, 0);
+
+    app.handle_key(KeyCode::Char('Z')).unwrap();
+    app.handle_key(KeyCode::Char('Z')).unwrap();
+    app.handle_key(KeyCode::Char('Z')).unwrap();
+    assert!(render(&mut app).contains("(365-DAY)"));
+}
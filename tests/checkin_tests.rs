@@ -0,0 +1,109 @@
+mod common;
+
+use chrono::{Duration, Local, NaiveDate};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::checkin::{CheckInAnalytics, CheckInPeriod};
+use focusfive::data::{load_or_create_indicators, read_observations_range};
+use focusfive::models::{IndicatorUnit, Observation, ENERGY_INDICATOR_ID, MOOD_INDICATOR_ID};
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+
+fn rating(indicator_id: &str, date: NaiveDate, value: f64) -> Observation {
+    Observation::new(
+        indicator_id.to_string(),
+        date,
+        value,
+        IndicatorUnit::Custom("/5".to_string()),
+    )
+}
+
+#[test]
+fn period_splits_at_mid_afternoon() {
+    assert_eq!(CheckInPeriod::for_hour(7), CheckInPeriod::Morning);
+    assert_eq!(CheckInPeriod::for_hour(14), CheckInPeriod::Morning);
+    assert_eq!(CheckInPeriod::for_hour(15), CheckInPeriod::Evening);
+}
+
+#[test]
+fn analytics_correlate_ratings_with_completion() {
+    let start = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap();
+    let day = |offset| start + Duration::days(offset);
+    let observations = vec![
+        // Morning and evening on the first day average to 2
+        rating(MOOD_INDICATOR_ID, day(0), 1.0),
+        rating(MOOD_INDICATOR_ID, day(0), 3.0),
+        rating(MOOD_INDICATOR_ID, day(1), 3.0),
+        rating(MOOD_INDICATOR_ID, day(2), 5.0),
+        rating(ENERGY_INDICATOR_ID, day(0), 4.0),
+        rating(ENERGY_INDICATOR_ID, day(1), 4.0),
+    ];
+    let completion = vec![
+        (day(0), 20.0),
+        (day(1), 50.0),
+        (day(2), 90.0),
+        (day(3), 100.0),
+    ];
+
+    let analytics = CheckInAnalytics::from_data(&observations, &completion);
+    assert_eq!(analytics.days.len(), 3);
+    assert_eq!(analytics.days[0].mood, Some(2.0));
+    assert_eq!(analytics.days[2].energy, None);
+    assert!(analytics.mood_correlation.unwrap() > 0.9);
+    // Only two energy days
+    assert_eq!(analytics.energy_correlation, None);
+    assert_eq!(
+        analytics.completion_by_mood,
+        [None, Some(20.0), Some(50.0), None, Some(90.0)]
+    );
+}
+
+#[test]
+fn check_in_records_builtin_indicators() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config.clone()).unwrap();
+    let today = Local::now().date_naive();
+
+    // Nothing rated yet: Enter keeps the modal open
+    app.handle_key(KeyCode::Char('m')).unwrap();
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert!(matches!(app.modal, Some(ModalState::CheckIn(_))));
+
+    // Mood 4, then energy 2 nudged up to 3
+    app.handle_key(KeyCode::Char('4')).unwrap();
+    app.handle_key(KeyCode::Char('2')).unwrap();
+    app.handle_key(KeyCode::Right).unwrap();
+    match &app.modal {
+        Some(ModalState::CheckIn(state)) => {
+            assert_eq!((state.mood, state.energy), (Some(4), Some(3)));
+        }
+        other => panic!("expected check-in, got {:?}", other),
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert!(app.modal.is_none());
+
+    let indicators = load_or_create_indicators(&config).unwrap();
+    assert!(indicators.find(MOOD_INDICATOR_ID).is_some());
+    assert!(indicators.find(ENERGY_INDICATOR_ID).is_some());
+
+    let observations = read_observations_range(today, today, &config).unwrap();
+    let value = |id: &str| {
+        observations
+            .iter()
+            .find(|obs| obs.indicator_id == id)
+            .map(|obs| obs.value)
+    };
+    assert_eq!(value(MOOD_INDICATOR_ID), Some(4.0));
+    assert_eq!(value(ENERGY_INDICATOR_ID), Some(3.0));
+
+    app.handle_key(KeyCode::Char('M')).unwrap();
+    match &app.modal {
+        Some(ModalState::CheckInAnalytics(analytics)) => {
+            assert_eq!(analytics.days.len(), 1);
+            assert_eq!(analytics.days[0].mood, Some(4.0));
+        }
+        other => panic!("expected analytics, got {:?}", other),
+    }
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(app.modal.is_none());
+}
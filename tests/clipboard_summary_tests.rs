@@ -0,0 +1,96 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::save_settings;
+use focusfive::models::{Action, DailyGoals, Settings};
+use focusfive::summary::standup_text;
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use std::fs;
+
+fn action(text: &str, completed: bool) -> Action {
+    let mut action = Action::new(text.to_string());
+    action.completed = completed;
+    action
+}
+
+fn screen(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(160, 40)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+    terminal
+        .backend()
+        .buffer()
+        .content
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect()
+}
+
+#[test]
+fn standup_text_lists_each_outcome_as_a_checklist() {
+    let mut goals = DailyGoals::new(NaiveDate::from_ymd_opt(2025, 3, 7).unwrap());
+    goals.work.goal = Some("Ship the release".to_string());
+    goals.work.actions = vec![
+        action("Write release notes", true),
+        action("Tag the build", false),
+        action("", false),
+    ];
+    goals.health.actions = vec![action("Run 5k", true)];
+    goals.family.actions = vec![action(" ", false)];
+
+    assert_eq!(
+        standup_text(&goals),
+        "FocusFive · Fri, Mar 7 · 2/3 done\n\
+         \n\
+         Work - Ship the release (1/2)\n\
+         - [x] Write release notes\n\
+         - [ ] Tag the build\n\
+         \n\
+         Health (1/1)\n\
+         - [x] Run 5k\n\
+         \n\
+         Family (0/0)\n\
+         - nothing planned\n"
+    );
+}
+
+#[test]
+fn y_copies_the_summary_through_the_clipboard_command() {
+    let (temp_dir, config) = temp_config();
+    let copied = temp_dir.path().join("copied.txt");
+    let settings = Settings {
+        clipboard_command: Some(format!("cat > '{}'", copied.display())),
+        ..Default::default()
+    };
+    save_settings(&settings, &config).unwrap();
+
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    app.goals.work.actions[0].text = "Review the PR".to_string();
+    app.goals.work.actions[0].completed = true;
+    app.toasts.clear();
+    app.handle_key(KeyCode::Char('Y')).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(&copied).unwrap(),
+        standup_text(&app.goals)
+    );
+    assert!(fs::read_to_string(&copied)
+        .unwrap()
+        .contains("- [x] Review the PR"));
+    assert!(screen(&mut app).contains("Copied the summary"));
+
+    let settings = Settings {
+        clipboard_command: Some("exit 3".to_string()),
+        ..Default::default()
+    };
+    save_settings(&settings, &config).unwrap();
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+    app.toasts.clear();
+    app.handle_key(KeyCode::Char('Y')).unwrap();
+    assert!(screen(&mut app).contains("Copy failed"));
+}
@@ -0,0 +1,77 @@
+use chrono::NaiveDate;
+use crossterm::event::KeyCode;
+use focusfive::data::{
+    clone_day_plan, load_or_create_day_meta, load_or_create_goals, save_day_meta, write_goals_file,
+};
+use focusfive::models::{ActionOrigin, Config, DailyGoals, DayMeta};
+use focusfive::ui::App;
+use std::fs;
+use tempfile::TempDir;
+
+fn test_config(temp_dir: &TempDir) -> Config {
+    let goals_dir = temp_dir.path().join("goals");
+    fs::create_dir_all(&goals_dir).unwrap();
+    Config {
+        goals_dir: goals_dir.to_str().unwrap().to_string(),
+        data_root: temp_dir.path().to_str().unwrap().to_string(),
+    }
+}
+
+#[test]
+fn clone_copies_structure_but_not_completion() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = test_config(&temp_dir);
+    let today = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+
+    let mut goals = DailyGoals::new(today);
+    goals.day_number = Some(12);
+    goals.work.goal = Some("Ship v2".to_string());
+    goals.work.actions[0].text = "Write release notes".to_string();
+    goals.work.actions[0].completed = true;
+    goals.work.actions[0].add_objective_id("obj-1".to_string());
+    goals.health.actions[1].text = "Run 5k".to_string();
+    goals.health.reflection = Some("Tired".to_string());
+    write_goals_file(&goals, &config).unwrap();
+
+    let mut meta = DayMeta::from_goals(&goals);
+    meta.work[0].estimated_min = Some(45);
+    meta.work[0].tags = vec!["writing".to_string()];
+    save_day_meta(today, &meta, &config).unwrap();
+
+    let tomorrow = today.succ_opt().unwrap();
+    clone_day_plan(&goals, tomorrow, &config).unwrap();
+
+    let cloned = load_or_create_goals(tomorrow, &config).unwrap();
+    assert_eq!(cloned.day_number, Some(13));
+    assert_eq!(cloned.work.goal.as_deref(), Some("Ship v2"));
+    assert_eq!(cloned.work.actions[0].text, "Write release notes");
+    assert!(!cloned.work.actions[0].completed);
+    assert_eq!(
+        cloned.work.actions[0].get_all_objective_ids(),
+        vec!["obj-1"]
+    );
+    assert_eq!(cloned.health.actions[1].text, "Run 5k");
+    assert!(cloned.health.reflection.is_none());
+
+    let cloned_meta = load_or_create_day_meta(tomorrow, &cloned, &config).unwrap();
+    assert_eq!(cloned_meta.work[0].estimated_min, Some(45));
+    assert_eq!(cloned_meta.work[0].tags, vec!["writing".to_string()]);
+    assert_eq!(cloned_meta.work[0].origin, ActionOrigin::Template);
+
+    // A second clone would clobber the plan, so it is refused
+    assert!(clone_day_plan(&goals, tomorrow, &config).is_err());
+}
+
+#[test]
+fn shift_c_repeats_today_tomorrow() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = test_config(&temp_dir);
+
+    let mut app = App::new(config.clone()).unwrap();
+    app.goals.family.actions[0].text = "Call grandma".to_string();
+    app.handle_key(KeyCode::Char('C')).unwrap();
+
+    let tomorrow = app.goals.date.succ_opt().unwrap();
+    let cloned = load_or_create_goals(tomorrow, &config).unwrap();
+    assert_eq!(cloned.family.actions[0].text, "Call grandma");
+}
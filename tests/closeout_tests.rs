@@ -0,0 +1,132 @@
+mod common;
+
+use chrono::{Local, NaiveDate};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::closeout;
+use focusfive::data::{load_or_create_day_meta, load_or_create_goals, write_goals_file};
+use focusfive::models::{
+    ActionOrigin, Config, DailyGoals, IndicatorsData, OutcomeType, SummarySettings,
+};
+use focusfive::ui::app::{LinkSuggestion, ModalState};
+use focusfive::ui::App;
+use std::fs;
+
+fn day_with_work(date: NaiveDate) -> DailyGoals {
+    let mut goals = DailyGoals::new(date);
+    goals.work.actions[0].text = "Ship release".to_string();
+    goals.work.actions[0].completed = true;
+    goals.work.actions[1].text = "Fix flaky test".to_string();
+    goals.health.actions[0].text = "Stretch".to_string();
+    goals
+}
+
+fn close(goals: &DailyGoals, config: &Config) -> closeout::CloseOut {
+    closeout::close_day(
+        goals,
+        &IndicatorsData::default(),
+        &SummarySettings::default(),
+        config,
+    )
+    .unwrap()
+}
+
+#[test]
+fn closing_writes_summary_rolls_unfinished_and_locks() {
+    let (_temp_dir, config) = temp_config();
+    let date = NaiveDate::from_ymd_opt(2025, 6, 10).unwrap();
+    let goals = day_with_work(date);
+    write_goals_file(&goals, &config).unwrap();
+    assert!(!closeout::is_closed(date, &config).unwrap());
+
+    let closed = close(&goals, &config);
+    assert_eq!((closed.completed, closed.planned), (1, 3));
+    assert_eq!(closed.percent(), 33);
+    assert_eq!(closed.rolled.carried, 2);
+    assert!(!fs::read_to_string(&closed.summary_path).unwrap().is_empty());
+    assert!(closed.summary_path.ends_with("summaries/2025-06-10.md"));
+    assert!(closeout::is_closed(date, &config).unwrap());
+
+    let next = load_or_create_goals(date.succ_opt().unwrap(), &config).unwrap();
+    assert_eq!(next.work.actions[0].text, "Fix flaky test");
+    assert_eq!(next.health.actions[0].text, "Stretch");
+    let meta = load_or_create_day_meta(next.date, &next, &config).unwrap();
+    assert_eq!(meta.work[0].carried_from, Some(date));
+    assert_eq!(meta.work[0].origin, ActionOrigin::CarryOver);
+    assert!(meta.triaged);
+
+    // Closing twice is refused; after a reopen nothing is rolled again
+    assert!(closeout::close_day(
+        &goals,
+        &IndicatorsData::default(),
+        &SummarySettings::default(),
+        &config
+    )
+    .is_err());
+    assert!(closeout::reopen_day(&goals, &config).unwrap());
+    assert!(!closeout::reopen_day(&goals, &config).unwrap());
+    assert_eq!(close(&goals, &config).rolled.carried, 0);
+}
+
+#[test]
+fn closed_day_is_read_only_until_reopened() {
+    let (_temp_dir, config) = temp_config();
+    let today = Local::now().date_naive();
+    write_goals_file(&day_with_work(today), &config).unwrap();
+    let mut app = App::new(config.clone()).unwrap();
+
+    app.handle_key(KeyCode::Char('X')).unwrap();
+    match &app.modal {
+        Some(ModalState::CloseDay(state)) => {
+            assert_eq!((state.completed, state.planned), (1, 3));
+            assert_eq!(state.unfinished.len(), 2);
+        }
+        _ => panic!("close confirmation should be open"),
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert!(app.modal.is_none());
+    assert!(app.day_closed);
+
+    // Toggling an action is refused while closed
+    app.handle_key(KeyCode::Tab).unwrap();
+    app.handle_key(KeyCode::Char('j')).unwrap();
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    assert!(!app.goals.work.actions[1].completed);
+    app.handle_key(KeyCode::Char('E')).unwrap();
+    assert!(!app.text_editor.is_active);
+
+    app.handle_key(KeyCode::Char('X')).unwrap();
+    assert!(!app.day_closed);
+    assert!(!closeout::is_closed(today, &config).unwrap());
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    assert!(app.goals.work.actions[1].completed);
+}
+
+#[test]
+fn closed_day_refuses_objective_links() {
+    let (_temp_dir, config) = temp_config();
+    let today = Local::now().date_naive();
+    write_goals_file(&day_with_work(today), &config).unwrap();
+    let mut app = App::new(config.clone()).unwrap();
+    app.handle_key(KeyCode::Char('X')).unwrap();
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert!(app.day_closed);
+
+    app.handle_key(KeyCode::Tab).unwrap();
+    app.handle_key(KeyCode::Char('o')).unwrap();
+    assert!(app.modal.is_none());
+    app.handle_key(KeyCode::Char('g')).unwrap();
+    assert!(app.modal.is_none());
+
+    // A suggestion accepted after the close links nothing either
+    app.modal = Some(ModalState::LinkSuggestion(LinkSuggestion {
+        outcome_type: OutcomeType::Work,
+        action_index: 0,
+        objective_id: "objective-1".to_string(),
+        title: "Launch".to_string(),
+    }));
+    app.handle_key(KeyCode::Char('y')).unwrap();
+    assert!(app.goals.work.actions[0].get_all_objective_ids().is_empty());
+    let saved = load_or_create_goals(today, &config).unwrap();
+    assert!(saved.work.actions[0].get_all_objective_ids().is_empty());
+}
@@ -0,0 +1,18 @@
+//! Helpers shared by the integration tests
+
+use focusfive::models::Config;
+use std::fs;
+use tempfile::TempDir;
+
+/// A config whose goals and data directories live in a fresh temp dir,
+/// removed when the returned `TempDir` is dropped
+pub fn temp_config() -> (TempDir, Config) {
+    let temp_dir = TempDir::new().unwrap();
+    let goals_dir = temp_dir.path().join("goals");
+    fs::create_dir_all(&goals_dir).unwrap();
+    let config = Config {
+        goals_dir: goals_dir.to_str().unwrap().to_string(),
+        data_root: temp_dir.path().to_str().unwrap().to_string(),
+    };
+    (temp_dir, config)
+}
@@ -0,0 +1,155 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use focusfive::command::{parse, run_headless, ExCommand};
+use focusfive::data::{
+    append_observation, load_or_create_indicators, save_indicators, write_goals_file,
+};
+use focusfive::models::{
+    Config, DailyGoals, IndicatorDef, IndicatorDirection, IndicatorKind, IndicatorUnit, Observation,
+};
+use focusfive::ui::app::ModalState;
+use focusfive::ui::stats::{
+    compare_ranges, comparison_rows, ComparePreset, CompareRanges, DateRange,
+};
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+fn date(m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, m, d).unwrap()
+}
+
+/// A day with `done` of the three Work actions completed
+fn write_day(day: NaiveDate, done: usize, config: &Config) {
+    let mut goals = DailyGoals::new(day);
+    for action in goals.work.actions.iter_mut().take(done) {
+        action.completed = true;
+    }
+    write_goals_file(&goals, config).unwrap();
+}
+
+/// February: one day, Work 1/3, sleep 8h; March: two days, Work 3/3, sleep 6h
+fn write_months(config: &Config) {
+    write_day(date(2, 10), 1, config);
+    write_day(date(3, 3), 3, config);
+    write_day(date(3, 4), 3, config);
+
+    let mut indicators = load_or_create_indicators(config).unwrap();
+    let mut sleep = IndicatorDef::new(
+        "Sleep".to_string(),
+        IndicatorKind::Leading,
+        IndicatorUnit::Custom("h".to_string()),
+    );
+    sleep.direction = IndicatorDirection::HigherIsBetter;
+    let id = sleep.id.clone();
+    indicators.indicators.push(sleep);
+    save_indicators(&indicators, config).unwrap();
+    for (day, value) in [(date(2, 10), 8.0), (date(3, 3), 6.0)] {
+        let observation = Observation::new(
+            id.clone(),
+            day,
+            value,
+            IndicatorUnit::Custom("h".to_string()),
+        );
+        append_observation(&observation, config).unwrap();
+    }
+}
+
+#[test]
+fn ranges_parse_months_weeks_days_and_spans() {
+    let march = DateRange::parse("2025-03").unwrap();
+    assert_eq!((march.start, march.end), (date(3, 1), date(3, 31)));
+    assert_eq!(march.label, "Mar 2025");
+    let week = DateRange::parse("2025-W11").unwrap();
+    assert_eq!((week.start, week.end), (date(3, 10), date(3, 16)));
+    assert_eq!(week.label, "Week 11 2025");
+    let day = DateRange::parse("2025-03-10").unwrap();
+    assert_eq!((day.start, day.end), (date(3, 10), date(3, 10)));
+    let span = DateRange::parse("2025-03-01..2025-03-15").unwrap();
+    assert_eq!((span.start, span.end), (date(3, 1), date(3, 15)));
+
+    assert!(DateRange::parse("2025-03-15..2025-03-01").is_err());
+    assert!(DateRange::parse("March").is_err());
+    assert!(parse("compare fortnight").is_err());
+    assert_eq!(
+        parse("compare").unwrap(),
+        ExCommand::Compare(CompareRanges::Preset(ComparePreset::Week))
+    );
+}
+
+#[test]
+fn presets_compare_with_the_period_before() {
+    let (this, last) = CompareRanges::Preset(ComparePreset::Week).resolve(date(3, 12));
+    assert_eq!(
+        (this.start, last.start, last.end),
+        (date(3, 10), date(3, 3), date(3, 9))
+    );
+    let (this, last) = CompareRanges::Preset(ComparePreset::Month).resolve(date(3, 12));
+    assert_eq!(
+        (this.label.as_str(), last.label.as_str()),
+        ("Mar 2025", "Feb 2025")
+    );
+    let (this, last) = CompareRanges::Preset(ComparePreset::Quarter).resolve(date(1, 5));
+    assert_eq!(
+        (this.label.as_str(), last.label.as_str()),
+        ("Q1 2025", "Q4 2024")
+    );
+}
+
+#[test]
+fn rows_mark_changes_by_direction() {
+    let (_temp_dir, config) = temp_config();
+    write_months(&config);
+    let march = DateRange::parse("2025-03").unwrap();
+    let february = DateRange::parse("2025-02").unwrap();
+    let comparison = compare_ranges(&march, &february, date(3, 31), &config).unwrap();
+    assert_eq!(comparison.current.daily_completion.len(), 2);
+
+    let indicators = load_or_create_indicators(&config).unwrap();
+    let rows = comparison_rows(&comparison, &indicators);
+    let row = |label: &str| rows.iter().find(|row| row.label == label).unwrap().clone();
+    let work = row("Work");
+    assert_eq!(work.format(work.current), "100%");
+    assert_eq!(work.improved(), Some(true));
+    assert!(work.marker().starts_with('▲'));
+    let family = row("Family");
+    assert_eq!(family.improved(), None);
+    assert_eq!(family.marker(), "=");
+    let sleep = row("Sleep (avg)");
+    assert_eq!(sleep.change(), Some(-2.0));
+    assert_eq!(sleep.improved(), Some(false));
+    assert_eq!(sleep.marker(), "▼2");
+    assert_eq!(row("Days tracked").marker(), "▲1");
+}
+
+#[test]
+fn compare_prints_headless_and_opens_in_the_tui() {
+    let (_temp_dir, config) = temp_config();
+    write_months(&config);
+    let command = parse("compare 2025-03 2025-02").unwrap();
+    let text = run_headless(&command, date(3, 31), &config).unwrap();
+    assert!(text.starts_with("Mar 2025 (Mar 01 to Mar 31) vs Feb 2025 (Feb 01 to Feb 28)"));
+    assert!(text.contains("Sleep (avg)"));
+    assert!(text.contains("▼2"));
+
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    app.run_ex_command("compare 2025-03 2025-02").unwrap();
+    assert!(matches!(app.modal, Some(ModalState::Compare(_))));
+    let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    let screen: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+    assert!(screen.contains("Compare: Mar 2025 vs Feb 2025"));
+    assert!(screen.contains("Sleep (avg)"));
+
+    app.handle_key(crossterm::event::KeyCode::Char('w'))
+        .unwrap();
+    assert!(
+        matches!(app.modal, Some(ModalState::Compare(ref c)) if c.current.label.starts_with("Week"))
+    );
+    app.handle_key(crossterm::event::KeyCode::Esc).unwrap();
+    assert!(app.modal.is_none());
+}
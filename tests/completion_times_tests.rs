@@ -0,0 +1,104 @@
+mod common;
+
+use chrono::{Local, TimeZone, Utc};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::completion_times::CompletionTimes;
+use focusfive::data::{
+    load_or_create_day_meta, record_completion_times, save_day_meta, write_goals_file,
+};
+use focusfive::models::{DailyGoals, OutcomeType};
+use focusfive::ui::App;
+
+#[test]
+fn checking_off_stamps_and_unchecking_clears() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    app.goals.work.actions[0].text = "Write report".to_string();
+    app.handle_key(KeyCode::Tab).unwrap();
+    app.selected_action = 0;
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    app.flush_pending_save().unwrap();
+
+    let meta = load_or_create_day_meta(app.goals.date, &app.goals, &config).unwrap();
+    let stamped = meta.work[0].completed_at.expect("completion is stamped");
+    assert!(Utc::now() - stamped < chrono::Duration::minutes(1));
+    assert!(meta.health[0].completed_at.is_none());
+
+    // Saving again keeps the first stamp
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    app.flush_pending_save().unwrap();
+    let meta = load_or_create_day_meta(app.goals.date, &app.goals, &config).unwrap();
+    assert!(meta.work[0].completed_at.is_some());
+
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    app.flush_pending_save().unwrap();
+    let meta = load_or_create_day_meta(app.goals.date, &app.goals, &config).unwrap();
+    assert!(meta.work[0].completed_at.is_none());
+}
+
+#[test]
+fn days_with_nothing_done_get_no_metadata() {
+    let (temp_dir, config) = temp_config();
+    let goals = DailyGoals::new(Local::now().date_naive());
+    record_completion_times(&goals, &config).unwrap();
+    assert!(!temp_dir.path().join("meta").exists());
+}
+
+#[test]
+fn hours_are_summed_per_outcome() {
+    let mut hours = vec![
+        (OutcomeType::Work, 9),
+        (OutcomeType::Work, 10),
+        (OutcomeType::Work, 11),
+    ];
+    hours.extend([
+        (OutcomeType::Family, 21),
+        (OutcomeType::Family, 22),
+        (OutcomeType::Family, 8),
+    ]);
+    hours.push((OutcomeType::Health, 23));
+    let times = CompletionTimes::from_hours(&hours);
+
+    assert_eq!(times.total(OutcomeType::Work), 3);
+    assert_eq!(times.hour_total(21), 1);
+    assert_eq!(times.median_hour(OutcomeType::Work), Some(10));
+    assert_eq!(times.median_hour(OutcomeType::Family), Some(21));
+    assert_eq!(times.late_share(OutcomeType::Work), Some(0.0));
+    let family_late = times.late_share(OutcomeType::Family).unwrap();
+    assert!((family_late - 2.0 / 3.0).abs() < 1e-9);
+    // Health is all late but has too few completions to tell
+    let (outcome, share) = times.slipping_outcome().unwrap();
+    assert_eq!(outcome, OutcomeType::Family);
+    assert!((share - family_late).abs() < 1e-9);
+
+    assert_eq!(
+        CompletionTimes::default().median_hour(OutcomeType::Work),
+        None
+    );
+    assert_eq!(CompletionTimes::default().slipping_outcome(), None);
+}
+
+#[test]
+fn stamps_are_read_back_in_local_hours() {
+    let (_temp_dir, config) = temp_config();
+    let today = Local::now().date_naive();
+    let mut goals = DailyGoals::new(today);
+    goals.health.actions[0].text = "Evening walk".to_string();
+    goals.health.actions[0].completed = true;
+    write_goals_file(&goals, &config).unwrap();
+
+    let mut meta = load_or_create_day_meta(today, &goals, &config).unwrap();
+    let evening = Local
+        .from_local_datetime(&today.and_hms_opt(21, 30, 0).unwrap())
+        .unwrap();
+    meta.health[0].completed_at = Some(evening.with_timezone(&Utc));
+    save_day_meta(today, &meta, &config).unwrap();
+
+    let times = CompletionTimes::calculate(today, 30, &config).unwrap();
+    assert_eq!(times.days, 1);
+    assert_eq!(times.by_hour[21], [0, 1, 0]);
+    assert_eq!(times.late_share(OutcomeType::Health), Some(1.0));
+}
@@ -57,6 +57,12 @@ fn test_controller_saves_all_data_types() -> Result<()> {
         created: chrono::Utc::now(),
         modified: chrono::Utc::now(),
         parent_id: None,
+        color: None,
+        weekly_budget_min: None,
+        kind: Default::default(),
+        queue: Vec::new(),
+        completed_on: None,
+        archived: false,
     };
     app.objectives.objectives.push(objective);
     app.objectives_needs_save = true;
@@ -75,6 +81,16 @@ fn test_controller_saves_all_data_types() -> Result<()> {
         modified: chrono::Utc::now(),
         lineage_of: None,
         notes: Some("Test notes".to_string()),
+        target_schedule: Vec::new(),
+        range_min: None,
+        range_max: None,
+        monthly_target: None,
+        ratio_of: None,
+        exclude_from_signals: false,
+        auto_rules: Vec::new(),
+        group: None,
+        aggregation: Default::default(),
+        target_period: Default::default(),
     };
     app.indicators.indicators.push(indicator);
     app.indicators_needs_save = true;
@@ -207,6 +223,12 @@ fn test_atomic_write_prevents_corruption() -> Result<()> {
                 created: chrono::Utc::now(),
                 modified: chrono::Utc::now(),
                 parent_id: None,
+                color: None,
+                weekly_budget_min: None,
+                kind: Default::default(),
+                queue: Vec::new(),
+                completed_on: None,
+                archived: false,
             });
 
             // Try to save
@@ -9,7 +9,7 @@ use focusfive::models::{
     Config, IndicatorDef, IndicatorDirection, IndicatorKind, IndicatorUnit, IndicatorsData,
     Observation, ObservationSource,
 };
-use focusfive::ui::app::{App, ModalState};
+use focusfive::ui::app::{App, DashboardPanel, ModalState};
 use ratatui::backend::TestBackend;
 use ratatui::Terminal;
 use tempfile::tempdir;
@@ -44,11 +44,15 @@ fn render_dashboard(app: &mut App) -> Vec<String> {
 
     app.show_dashboard = true;
 
-    terminal
-        .draw(|frame| {
-            app.render(frame);
-        })
-        .expect("render dashboard");
+    // The first frame queues the dashboard load; draw again once it lands
+    for _ in 0..2 {
+        terminal
+            .draw(|frame| {
+                app.render(frame);
+            })
+            .expect("render dashboard");
+        app.wait_for_background();
+    }
 
     let backend = terminal.backend_mut();
     let buffer = backend.buffer().clone();
@@ -106,6 +110,16 @@ fn alternative_signals_respects_zero_targets_from_markdown_context() -> Result<(
         modified: Utc::now(),
         lineage_of: None,
         notes: None,
+        target_schedule: Vec::new(),
+        range_min: None,
+        range_max: None,
+        monthly_target: None,
+        ratio_of: None,
+        exclude_from_signals: false,
+        auto_rules: Vec::new(),
+        group: None,
+        aggregation: Default::default(),
+        target_period: Default::default(),
     };
 
     let indicators_path = data_root.join("indicators.json");
@@ -133,6 +147,7 @@ fn alternative_signals_respects_zero_targets_from_markdown_context() -> Result<(
         action_id: None,
         note: None,
         created: Utc::now(),
+        at: None,
     };
 
     let latest = Observation {
@@ -145,6 +160,7 @@ fn alternative_signals_respects_zero_targets_from_markdown_context() -> Result<(
         action_id: None,
         note: None,
         created: Utc::now(),
+        at: None,
     };
 
     writeln!(
@@ -162,6 +178,7 @@ fn alternative_signals_respects_zero_targets_from_markdown_context() -> Result<(
 
     // Recreate the app now that data files exist.
     let mut app = App::new(config)?;
+    app.dashboard_show_signals = true;
     let lines = render_dashboard(&mut app);
     let screen = lines.join("\n");
 
@@ -211,6 +228,16 @@ fn dashboard_signal_update_appends_observation() -> Result<()> {
         modified: Utc::now(),
         lineage_of: None,
         notes: None,
+        target_schedule: Vec::new(),
+        range_min: None,
+        range_max: None,
+        monthly_target: None,
+        ratio_of: None,
+        exclude_from_signals: false,
+        auto_rules: Vec::new(),
+        group: None,
+        aggregation: Default::default(),
+        target_period: Default::default(),
     };
 
     let indicators_path = data_root.join("indicators.json");
@@ -236,6 +263,7 @@ fn dashboard_signal_update_appends_observation() -> Result<()> {
         action_id: None,
         note: None,
         created: Utc::now(),
+        at: None,
     };
 
     let latest = Observation {
@@ -248,6 +276,7 @@ fn dashboard_signal_update_appends_observation() -> Result<()> {
         action_id: None,
         note: None,
         created: Utc::now(),
+        at: None,
     };
 
     writeln!(
@@ -268,6 +297,10 @@ fn dashboard_signal_update_appends_observation() -> Result<()> {
     app.handle_key(KeyCode::Char('l'))?;
     app.handle_key(KeyCode::Char('l'))?;
     app.handle_key(KeyCode::Char('l'))?;
+    // The corner quadrant opens on the portfolio; `a` swaps in the signals
+    assert_eq!(app.dashboard_focus, DashboardPanel::Portfolio);
+    app.handle_key(KeyCode::Char('a'))?;
+    assert_eq!(app.dashboard_focus, DashboardPanel::Signals);
     render_dashboard(&mut app);
 
     app.handle_key(KeyCode::Char('i'))?;
@@ -289,3 +322,58 @@ fn dashboard_signal_update_appends_observation() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn excluded_indicators_drop_out_of_signals() -> Result<()> {
+    let (config, _guard, _today, data_root) = setup_markdown_fixture("day_zero_target.md");
+
+    let leading = |id: &str, name: &str| {
+        let mut def = IndicatorDef::new(
+            name.to_string(),
+            IndicatorKind::Leading,
+            IndicatorUnit::Count,
+        );
+        def.id = id.to_string();
+        def
+    };
+    let mut noisy = leading("noisy", "Inbox Count");
+    noisy.exclude_from_signals = true;
+    let indicators = IndicatorsData {
+        version: 1,
+        indicators: vec![leading("calls", "Sales Calls"), noisy],
+    };
+    fs::write(
+        data_root.join("indicators.json"),
+        serde_json::to_string_pretty(&indicators).unwrap(),
+    )?;
+
+    let mut app = App::new(config.clone())?;
+    app.dashboard_show_signals = true;
+    render_dashboard(&mut app);
+    assert_eq!(app.dashboard_signal_ids, vec!["calls".to_string()]);
+    // Still charted under Performance
+    assert_eq!(app.dashboard_performance_ids.len(), 2);
+
+    // `x` on the performance chart brings it back
+    app.dashboard_focus = DashboardPanel::Performance;
+    app.dashboard_performance_index = 1;
+    app.handle_key(KeyCode::Char('x'))?;
+    render_dashboard(&mut app);
+    assert_eq!(app.dashboard_signal_ids.len(), 2);
+
+    // ...and `x` on a signal hides it again, persisted
+    app.dashboard_focus = DashboardPanel::Signals;
+    app.dashboard_signal_index = app
+        .dashboard_signal_ids
+        .iter()
+        .position(|id| id == "calls")
+        .unwrap();
+    app.handle_key(KeyCode::Char('x'))?;
+    render_dashboard(&mut app);
+    assert_eq!(app.dashboard_signal_ids, vec!["noisy".to_string()]);
+
+    let stored = focusfive::data::load_or_create_indicators(&config)?;
+    let calls = stored.find("calls").unwrap();
+    assert!(calls.exclude_from_signals);
+    Ok(())
+}
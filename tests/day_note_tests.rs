@@ -0,0 +1,74 @@
+mod common;
+
+use chrono::{Duration, Local, NaiveDate};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::command::{parse, run_headless};
+use focusfive::data::{load_day_note, load_or_create_day_meta, save_day_note, write_goals_file};
+use focusfive::export::{render_export, ExportPeriod};
+use focusfive::models::DailyGoals;
+use focusfive::ui::App;
+
+#[test]
+fn day_note_is_stored_in_meta_and_shown_in_exports() {
+    let (_temp_dir, config) = temp_config();
+    let day = NaiveDate::from_ymd_opt(2025, 6, 4).unwrap();
+    let mut goals = DailyGoals::new(day);
+    goals.work.actions[0].text = "Prep slides".to_string();
+    goals.work.actions[0].completed = true;
+    write_goals_file(&goals, &config).unwrap();
+    assert_eq!(load_day_note(day, &config).unwrap(), None);
+
+    let saved = save_day_note(&goals, "  Offsite in Berlin  ", &config).unwrap();
+    assert_eq!(saved.as_deref(), Some("Offsite in Berlin"));
+    assert_eq!(
+        load_day_note(day, &config).unwrap().as_deref(),
+        Some("Offsite in Berlin")
+    );
+    // The note sits next to the action metadata, which is left alone
+    let meta = load_or_create_day_meta(day, &goals, &config).unwrap();
+    assert_eq!(meta.work.len(), goals.work.actions.len());
+
+    let shown = run_headless(&parse("goto 2025-06-04").unwrap(), day, &config).unwrap();
+    assert!(shown.starts_with("# June 04, 2025\n\n> Offsite in Berlin\n\n## Work"));
+    let export = render_export(ExportPeriod::Week, day, &config).unwrap();
+    assert!(export.contains("## June 04, 2025\n\n> Offsite in Berlin\n\n### Work"));
+
+    assert_eq!(save_day_note(&goals, "   ", &config).unwrap(), None);
+    assert_eq!(load_day_note(day, &config).unwrap(), None);
+}
+
+#[test]
+fn n_edits_the_note_of_the_displayed_day() {
+    let (_temp_dir, config) = temp_config();
+    let today = Local::now().date_naive();
+    let yesterday = today - Duration::days(1);
+    let mut past = DailyGoals::new(yesterday);
+    past.work.actions[0].text = "Old".to_string();
+    write_goals_file(&past, &config).unwrap();
+    save_day_note(&past, "Travel day", &config).unwrap();
+
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    // Leave yesterday's unfinished action where it is
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert_eq!(app.day_note, None);
+
+    app.handle_key(KeyCode::Char('N')).unwrap();
+    assert!(app.text_editor.is_active);
+    for c in "Dentist at 3".chars() {
+        app.handle_key(KeyCode::Char(c)).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert_eq!(app.day_note.as_deref(), Some("Dentist at 3"));
+    assert_eq!(
+        load_day_note(today, &config).unwrap().as_deref(),
+        Some("Dentist at 3")
+    );
+
+    app.handle_key(KeyCode::PageUp).unwrap();
+    assert_eq!(app.goals.date, yesterday);
+    assert_eq!(app.day_note.as_deref(), Some("Travel day"));
+    app.handle_key(KeyCode::PageDown).unwrap();
+    assert_eq!(app.day_note.as_deref(), Some("Dentist at 3"));
+}
@@ -0,0 +1,114 @@
+use crossterm::event::KeyCode;
+use focusfive::models::Config;
+use focusfive::ui::app::{FocusPanel, SAVE_DEBOUNCE};
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Instant;
+use tempfile::TempDir;
+
+fn setup_app() -> (App, TempDir) {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let goals_dir = temp_dir.path().join("goals");
+    fs::create_dir_all(&goals_dir).unwrap();
+
+    let config = Config {
+        goals_dir: goals_dir.to_str().unwrap().to_string(),
+        data_root: temp_dir.path().to_str().unwrap().to_string(),
+    };
+
+    let mut app = App::new(config).expect("app should initialise");
+    app.focus_panel = FocusPanel::Actions;
+    (app, temp_dir)
+}
+
+fn goals_path(app: &App) -> PathBuf {
+    PathBuf::from(&app.config.goals_dir).join(format!("{}.md", app.current_date.format("%Y-%m-%d")))
+}
+
+/// The header row of the main view
+fn header(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    (0..buffer.area.width)
+        .map(|x| buffer[(x, 1)].symbol().to_string())
+        .collect()
+}
+
+#[test]
+fn toggles_are_not_written_until_debounce_elapses() {
+    let (mut app, _temp) = setup_app();
+
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    app.tick();
+
+    assert!(app.pending_save.is_some());
+    assert!(!goals_path(&app).exists(), "write should be deferred");
+
+    // Pretend the quiet period has passed
+    app.pending_save = Some(Instant::now() - SAVE_DEBOUNCE);
+    app.tick();
+
+    assert!(app.pending_save.is_none());
+    let content = fs::read_to_string(goals_path(&app)).unwrap();
+    assert!(content.contains("- [x]"));
+}
+
+#[test]
+fn flush_writes_pending_changes_immediately() {
+    let (mut app, _temp) = setup_app();
+
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    app.flush_pending_save().unwrap();
+
+    assert!(app.pending_save.is_none());
+    let content = fs::read_to_string(goals_path(&app)).unwrap();
+    assert!(content.contains("- [x]"));
+}
+
+#[test]
+fn day_navigation_flushes_pending_changes() {
+    let (mut app, _temp) = setup_app();
+    let path = goals_path(&app);
+
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    app.handle_key(KeyCode::PageUp).unwrap();
+
+    assert!(app.pending_save.is_none());
+    assert!(fs::read_to_string(path).unwrap().contains("- [x]"));
+}
+
+#[test]
+fn header_shows_unsaved_changes() {
+    let (mut app, _temp) = setup_app();
+    assert!(header(&mut app).contains("saved"));
+
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    let dirty = header(&mut app);
+    assert!(dirty.contains('●'));
+    assert!(!dirty.contains("saved"));
+
+    app.flush_pending_save().unwrap();
+    assert!(header(&mut app).contains("saved"));
+    assert!(!app.save_failed);
+}
+
+#[test]
+fn a_failed_action_edit_is_flagged_as_a_failed_save() {
+    let (mut app, temp) = setup_app();
+    // A file where the goals directory should be makes every write fail
+    let blocked = temp.path().join("blocked");
+    fs::write(&blocked, "").unwrap();
+    app.config.goals_dir = blocked.to_str().unwrap().to_string();
+
+    app.handle_key(KeyCode::Char('E')).unwrap();
+    app.handle_key(KeyCode::Char('x')).unwrap();
+    assert!(app.handle_key(KeyCode::Enter).is_err());
+    assert!(app.save_failed);
+    assert_eq!(app.goals.work.actions[0].text, "");
+}
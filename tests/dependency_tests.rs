@@ -0,0 +1,157 @@
+mod common;
+
+use chrono::{Duration, NaiveDate};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{load_or_create_day_meta, load_or_create_goals, write_goals_file};
+use focusfive::dependencies::{
+    blocked_actions, blocker_done, candidates, release_unblocked, set_blocker,
+};
+use focusfive::models::{ActionStatus, BlockerRef, DailyGoals, OutcomeType};
+use focusfive::ui::app::{FocusPanel, ModalState};
+use focusfive::ui::App;
+
+#[test]
+fn blockers_come_from_today_and_earlier_days() {
+    let (_temp_dir, config) = temp_config();
+    let day = NaiveDate::from_ymd_opt(2025, 6, 4).unwrap();
+    let mut earlier = DailyGoals::new(day - Duration::days(2));
+    earlier.work.actions[0].text = "Get sign-off".to_string();
+    earlier.work.actions[1].text = "Done before".to_string();
+    earlier.work.actions[1].completed = true;
+    write_goals_file(&earlier, &config).unwrap();
+
+    let mut goals = DailyGoals::new(day);
+    goals.work.actions[0].text = "Ship release".to_string();
+    goals.work.actions[1].text = "Write changelog".to_string();
+    goals.family.actions[0].text = "Celebrate".to_string();
+    write_goals_file(&goals, &config).unwrap();
+
+    let found = candidates(&goals, OutcomeType::Work, 0, &config).unwrap();
+    let texts: Vec<_> = found.iter().map(|b| b.text.as_str()).collect();
+    assert_eq!(texts, ["Write changelog", "Celebrate", "Get sign-off"]);
+    assert_eq!(found[2].date, day - Duration::days(2));
+
+    let later = BlockerRef {
+        date: day + Duration::days(1),
+        outcome_type: OutcomeType::Work,
+        text: "Future".to_string(),
+        action_id: None,
+    };
+    assert!(set_blocker(&goals, OutcomeType::Work, 0, Some(later), &config).is_err());
+
+    set_blocker(
+        &goals,
+        OutcomeType::Work,
+        0,
+        Some(found[0].clone()),
+        &config,
+    )
+    .unwrap();
+    set_blocker(
+        &goals,
+        OutcomeType::Family,
+        0,
+        Some(found[2].clone()),
+        &config,
+    )
+    .unwrap();
+    let meta = load_or_create_day_meta(day, &goals, &config).unwrap();
+    assert_eq!(meta.work[0].status, ActionStatus::Blocked);
+    let blocked = blocked_actions(&goals, &config).unwrap();
+    assert_eq!(blocked[&(OutcomeType::Work, 0)], "Write changelog");
+    assert_eq!(blocked.len(), 2);
+
+    // Nothing done yet: nothing released
+    assert!(release_unblocked(&goals, &config).unwrap().is_empty());
+    assert_eq!(
+        blocker_done(&found[2], &goals, &config).unwrap(),
+        Some(false)
+    );
+
+    goals.work.actions[1].completed = true;
+    assert_eq!(
+        release_unblocked(&goals, &config).unwrap(),
+        ["Ship release"]
+    );
+    let meta = load_or_create_day_meta(day, &goals, &config).unwrap();
+    assert_eq!(meta.work[0].status, ActionStatus::Planned);
+    assert!(meta.work[0].blocked_by.is_none());
+    assert!(meta.family[0].blocked_by.is_some());
+
+    set_blocker(&goals, OutcomeType::Family, 0, None, &config).unwrap();
+    let meta = load_or_create_day_meta(day, &goals, &config).unwrap();
+    assert_eq!(meta.family[0].status, ActionStatus::Planned);
+}
+
+#[test]
+fn blockers_are_found_by_id_after_a_rename() {
+    let (_temp_dir, config) = temp_config();
+    let day = NaiveDate::from_ymd_opt(2025, 6, 4).unwrap();
+    let mut earlier = DailyGoals::new(day - Duration::days(1));
+    earlier.work.actions[0].text = "Get sign-off".to_string();
+    write_goals_file(&earlier, &config).unwrap();
+    let mut goals = DailyGoals::new(day);
+    goals.work.actions[0].text = "Ship release".to_string();
+    write_goals_file(&goals, &config).unwrap();
+
+    let found = candidates(&goals, OutcomeType::Work, 0, &config).unwrap();
+    set_blocker(
+        &goals,
+        OutcomeType::Work,
+        0,
+        Some(found[0].clone()),
+        &config,
+    )
+    .unwrap();
+
+    // Renamed and checked off on its own day
+    let mut earlier = load_or_create_goals(day - Duration::days(1), &config).unwrap();
+    earlier.work.actions[0].text = "Get sign-off from legal".to_string();
+    earlier.work.actions[0].completed = true;
+    write_goals_file(&earlier, &config).unwrap();
+
+    let meta = load_or_create_day_meta(day, &goals, &config).unwrap();
+    let blocker = meta.work[0].blocked_by.clone().unwrap();
+    assert_eq!(blocker_done(&blocker, &goals, &config).unwrap(), Some(true));
+    assert_eq!(
+        release_unblocked(&goals, &config).unwrap(),
+        ["Ship release"]
+    );
+}
+
+#[test]
+fn app_marks_blocked_actions_and_releases_them_on_completion() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+    app.focus_panel = FocusPanel::Actions;
+    app.goals.work.actions[0].text = "Deploy".to_string();
+    app.goals.work.actions[1].text = "Run migrations".to_string();
+
+    app.handle_key(KeyCode::Char('B')).unwrap();
+    let Some(ModalState::BlockerPicker(state)) = app.modal.clone() else {
+        panic!("expected the blocker picker");
+    };
+    assert_eq!(state.candidates.len(), 1);
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert_eq!(
+        app.blocked.get(&(OutcomeType::Work, 0)).map(String::as_str),
+        Some("Run migrations")
+    );
+
+    app.handle_key(KeyCode::Char('j')).unwrap();
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    assert!(app.goals.work.actions[1].completed);
+    assert!(app.blocked.is_empty());
+
+    // x in the picker clears a link
+    app.handle_key(KeyCode::Char('k')).unwrap();
+    app.goals.work.actions[2].text = "Announce".to_string();
+    app.handle_key(KeyCode::Char('B')).unwrap();
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert_eq!(app.blocked.len(), 1);
+    app.handle_key(KeyCode::Char('B')).unwrap();
+    app.handle_key(KeyCode::Char('x')).unwrap();
+    assert!(app.blocked.is_empty());
+}
@@ -0,0 +1,110 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use focusfive::data::write_goals_file;
+use focusfive::digest::{build, file_name, render, send};
+use focusfive::models::{Config, DailyGoals, DigestRecipient, DigestSettings, OutcomeType};
+use std::fs;
+
+fn day(d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 3, d).unwrap()
+}
+
+/// Mon Mar 10: Work 1/2, Health 1/1; Wed Mar 12: Work 0/1
+fn write_week(config: &Config) {
+    let mut monday = DailyGoals::new(day(10));
+    monday.work.actions[0].text = "Ship the secret project".to_string();
+    monday.work.actions[0].completed = true;
+    monday.work.actions[1].text = "Call the lawyer".to_string();
+    monday.health.actions[0].text = "Run 5k".to_string();
+    monday.health.actions[0].completed = true;
+    write_goals_file(&monday, config).unwrap();
+
+    let mut wednesday = DailyGoals::new(day(12));
+    wednesday.work.actions[0].text = "Board deck".to_string();
+    write_goals_file(&wednesday, config).unwrap();
+}
+
+fn partner(share_actions: Vec<OutcomeType>) -> DigestRecipient {
+    DigestRecipient {
+        name: "Sam".to_string(),
+        email: None,
+        folder: Some("shared/sam".to_string()),
+        share_actions,
+    }
+}
+
+#[test]
+fn digests_leave_out_action_text_by_default() {
+    let (_temp_dir, config) = temp_config();
+    write_week(&config);
+    let digest = build(day(13), &config).unwrap();
+    assert_eq!((digest.start, digest.end), (day(10), day(13)));
+    assert_eq!(digest.outcomes, [(1, 3), (1, 1), (0, 0)]);
+    assert_eq!(digest.totals(), (2, 4));
+    assert_eq!(digest.streak, 0);
+
+    let text = render(&digest, Some(&partner(Vec::new())));
+    assert!(text.contains("FocusFive weekly digest: 2025-W11"));
+    assert!(text.contains("For Sam"));
+    assert!(text.contains("Completion: 50% (2/4 actions)"));
+    assert!(text.contains("  Health  100% (1/1)"));
+    assert!(text.contains("Days with something done: 1/4"));
+    for secret in [
+        "Ship the secret project",
+        "Call the lawyer",
+        "Run 5k",
+        "Board deck",
+    ] {
+        assert!(!text.contains(secret), "leaked {}", secret);
+    }
+}
+
+#[test]
+fn whitelisted_outcomes_show_their_actions() {
+    let (_temp_dir, config) = temp_config();
+    write_week(&config);
+    let digest = build(day(16), &config).unwrap();
+    let text = render(&digest, Some(&partner(vec![OutcomeType::Health])));
+    assert!(text.contains("Health actions\n  ✓ Run 5k (Mon)"));
+    assert!(!text.contains("Ship the secret project"));
+    assert!(!text.contains("Work actions"));
+}
+
+#[test]
+fn digests_go_to_the_share_folder_and_mail_command() {
+    let (temp_dir, config) = temp_config();
+    write_week(&config);
+    let digest = build(day(16), &config).unwrap();
+    let mut recipient = partner(Vec::new());
+    recipient.email = Some("sam@example.com".to_string());
+
+    // Email needs a mail command
+    assert!(send(&digest, &recipient, &DigestSettings::default(), &config).is_err());
+
+    let mailbox = temp_dir.path().join("mailbox.txt");
+    let settings = DigestSettings {
+        mail_command: Some(format!("cat > '{}'", mailbox.display())),
+        recipients: vec![recipient.clone()],
+    };
+    let delivered = send(&digest, &recipient, &settings, &config).unwrap();
+    assert_eq!(delivered.len(), 2);
+
+    let shared = temp_dir.path().join("shared/sam").join(file_name(&digest));
+    assert_eq!(file_name(&digest), "focusfive-digest-2025-W11.txt");
+    assert!(fs::read_to_string(shared)
+        .unwrap()
+        .contains("Completion: 50%"));
+    let message = fs::read_to_string(mailbox).unwrap();
+    assert!(
+        message.starts_with("To: sam@example.com\nSubject: FocusFive weekly digest: 2025-W11\n")
+    );
+    assert!(!message.contains("Call the lawyer"));
+
+    let failing = DigestSettings {
+        mail_command: Some("exit 3".to_string()),
+        ..settings
+    };
+    assert!(send(&digest, &recipient, &failing, &config).is_err());
+}
@@ -0,0 +1,184 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use focusfive::command::{parse, ExCommand};
+use focusfive::data::{
+    append_observation, generate_markdown, goals_file_path, load_or_create_indicators,
+    load_or_create_objectives, load_or_create_projects, read_goals_file, read_observations_range,
+    save_indicators, save_objectives, save_projects, write_goals_file,
+};
+use focusfive::doctor::{self, Severity};
+use focusfive::models::{
+    DailyGoals, IndicatorDef, IndicatorKind, IndicatorUnit, IndicatorsData, Objective,
+    ObjectivesData, Observation, OutcomeType, Project, ProjectsData,
+};
+use std::fs;
+use std::path::Path;
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+}
+
+fn counter(name: &str) -> IndicatorDef {
+    IndicatorDef::new(
+        name.to_string(),
+        IndicatorKind::Leading,
+        IndicatorUnit::Count,
+    )
+}
+
+#[test]
+fn clean_data_passes() {
+    let (_temp_dir, config) = temp_config();
+    write_goals_file(&DailyGoals::new(date(1)), &config).unwrap();
+
+    let report = doctor::run(&config, false).unwrap();
+    assert!(report.is_clean());
+    assert_eq!(report.files, 1);
+}
+
+#[test]
+fn goals_files_are_checked_against_the_parser() {
+    let (_temp_dir, config) = temp_config();
+    fs::write(goals_file_path(date(2), &config), "not a goals file").unwrap();
+    fs::write(
+        goals_file_path(date(3), &config),
+        generate_markdown(&DailyGoals::new(date(4))),
+    )
+    .unwrap();
+
+    let report = doctor::run(&config, false).unwrap();
+    assert_eq!(report.issues.len(), 2);
+    assert_eq!(report.issues[0].severity, Severity::Error);
+    assert!(!report.issues[0].fixable);
+    assert!(report.issues[1].fixable);
+
+    let report = doctor::run(&config, true).unwrap();
+    assert_eq!(report.fixed, 1);
+    assert!(report.has_unfixed_errors());
+    assert_eq!(
+        read_goals_file(&goals_file_path(date(3), &config))
+            .unwrap()
+            .date,
+        date(3)
+    );
+    assert!(!goals_file_path(date(4), &config).exists());
+}
+
+#[test]
+fn broken_references_and_duplicate_ids_are_repaired() {
+    let (_temp_dir, config) = temp_config();
+    let mut objective = Objective::new(OutcomeType::Health, "Run more".to_string());
+    let mut orphan = counter("Runs");
+    orphan.objective_id = Some("gone".to_string());
+    let kept = counter("Km");
+    let mut twin = counter("Km again");
+    twin.id = kept.id.clone();
+    objective.indicators = vec![kept.id.clone(), "deleted".to_string()];
+    objective.parent_id = Some("gone".to_string());
+    let mut project = Project::new("Spring".to_string());
+    project.objective_ids = vec![objective.id.clone(), "gone".to_string()];
+    save_objectives(
+        &ObjectivesData {
+            objectives: vec![objective.clone()],
+            ..Default::default()
+        },
+        &config,
+    )
+    .unwrap();
+    save_indicators(
+        &IndicatorsData {
+            indicators: vec![orphan, kept.clone(), twin],
+            ..Default::default()
+        },
+        &config,
+    )
+    .unwrap();
+    save_projects(
+        &ProjectsData {
+            projects: vec![project],
+            ..Default::default()
+        },
+        &config,
+    )
+    .unwrap();
+
+    let report = doctor::run(&config, false).unwrap();
+    assert_eq!(report.issues.len(), 5);
+    assert!(report.issues.iter().all(|issue| issue.fixable));
+
+    let report = doctor::run(&config, true).unwrap();
+    assert_eq!(report.fixed, 5);
+    assert!(doctor::run(&config, false).unwrap().is_clean());
+
+    let objectives = load_or_create_objectives(&config).unwrap().objectives;
+    assert_eq!(objectives[0].indicators, std::slice::from_ref(&kept.id));
+    assert_eq!(objectives[0].parent_id, None);
+    let indicators = load_or_create_indicators(&config).unwrap().indicators;
+    assert_eq!(indicators[0].objective_id, None);
+    assert_eq!(indicators[1].id, kept.id);
+    assert_ne!(indicators[2].id, kept.id);
+    assert_eq!(
+        load_or_create_projects(&config).unwrap().projects[0].objective_ids,
+        [objective.id]
+    );
+}
+
+#[test]
+fn observations_log_is_sorted_and_unreadable_lines_set_aside() {
+    let (_temp_dir, config) = temp_config();
+    let def = counter("Pages");
+    save_indicators(
+        &IndicatorsData {
+            indicators: vec![def.clone()],
+            ..Default::default()
+        },
+        &config,
+    )
+    .unwrap();
+    let late = Observation::new(def.id.clone(), date(5), 10.0, IndicatorUnit::Count);
+    let early = Observation::new(def.id.clone(), date(2), 4.0, IndicatorUnit::Count);
+    let stray = Observation::new("gone".to_string(), date(3), 1.0, IndicatorUnit::Count);
+    for obs in [&late, &early, &early, &stray] {
+        append_observation(obs, &config).unwrap();
+    }
+    let log = Path::new(&config.data_root).join("observations.ndjson");
+    let mut content = fs::read_to_string(&log).unwrap();
+    content.push_str("{broken\n");
+    fs::write(&log, content).unwrap();
+
+    let report = doctor::run(&config, false).unwrap();
+    let messages: Vec<_> = report
+        .issues
+        .iter()
+        .map(|issue| issue.message.as_str())
+        .collect();
+    assert_eq!(report.issues.len(), 4, "{:?}", messages);
+    assert!(messages.iter().any(|m| m.contains("out of date order")));
+    assert!(messages
+        .iter()
+        .any(|m| m.contains("missing indicator gone")));
+
+    doctor::run(&config, true).unwrap();
+    let observations = read_observations_range(date(1), date(31), &config).unwrap();
+    let dates: Vec<_> = observations.iter().map(|obs| obs.when).collect();
+    assert_eq!(dates, [date(2), date(3), date(5)]);
+    let rejected = Path::new(&config.data_root).join("observations.rejected.ndjson");
+    assert_eq!(fs::read_to_string(rejected).unwrap(), "{broken\n");
+
+    // Only the observations of the missing indicator are left to look at
+    let report = doctor::run(&config, false).unwrap();
+    assert_eq!(report.issues.len(), 1);
+    assert!(!report.issues[0].fixable);
+}
+
+#[test]
+fn doctor_is_an_ex_command() {
+    assert_eq!(parse(":doctor").unwrap(), ExCommand::Doctor { fix: false });
+    assert_eq!(
+        parse("doctor fix").unwrap(),
+        ExCommand::Doctor { fix: true }
+    );
+    assert!(parse("doctor now").is_err());
+}
@@ -0,0 +1,231 @@
+use chrono::{Duration, NaiveDate};
+use crossterm::event::KeyCode;
+use focusfive::data::{load_reflection, save_settings};
+use focusfive::models::{
+    Config, DailyGoals, IndicatorDef, IndicatorKind, IndicatorUnit, IndicatorsData, Observation,
+    Settings, SummarySettings,
+};
+use focusfive::summary::{build_prompt, request_draft, template_draft, SummaryInput};
+use focusfive::ui::App;
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread::JoinHandle;
+use tempfile::TempDir;
+
+/// A chat completions endpoint that answers one request with `status` and
+/// `body`; joining the handle gives the request it received
+fn serve_once(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!(
+        "http://{}/v1/chat/completions",
+        listener.local_addr().unwrap()
+    );
+    let handle = std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut request = String::new();
+        let mut length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                length = value.trim().parse().unwrap();
+            }
+            request.push_str(&line);
+            if line == "\r\n" {
+                break;
+            }
+        }
+        let mut content = vec![0; length];
+        reader.read_exact(&mut content).unwrap();
+        request.push_str(&String::from_utf8(content).unwrap());
+
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        reader.get_mut().write_all(response.as_bytes()).unwrap();
+        request
+    });
+    (url, handle)
+}
+
+fn sample_input() -> SummaryInput {
+    let date = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+    let mut goals = DailyGoals::new(date);
+    goals.work.actions[0].text = "Ship release notes".to_string();
+    goals.work.actions[0].completed = true;
+    goals.health.actions[0].text = "Run 5k".to_string();
+    goals.family.reflection = Some("Dinner ran late".to_string());
+
+    let sleep = IndicatorDef::new(
+        "Sleep".to_string(),
+        IndicatorKind::Leading,
+        IndicatorUnit::Minutes,
+    );
+    let observations = vec![
+        Observation::new(
+            sleep.id.clone(),
+            date - Duration::days(2),
+            400.0,
+            IndicatorUnit::Minutes,
+        ),
+        Observation::new(sleep.id.clone(), date, 450.0, IndicatorUnit::Minutes),
+    ];
+    let indicators = IndicatorsData {
+        version: 1,
+        indicators: vec![sleep],
+    };
+
+    SummaryInput::gather(&goals, &indicators, &observations)
+}
+
+#[test]
+fn gather_collects_actions_deltas_and_notes() {
+    let input = sample_input();
+    assert_eq!(
+        input.completed,
+        vec![("Work".to_string(), "Ship release notes".to_string())]
+    );
+    assert_eq!(input.open, 1);
+    assert_eq!(input.deltas.len(), 1);
+    assert_eq!(input.deltas[0].previous, Some(400.0));
+    assert_eq!(input.deltas[0].current, 450.0);
+    assert_eq!(input.notes, vec!["Family: Dinner ran late".to_string()]);
+
+    let prompt = build_prompt(&input);
+    assert!(prompt.contains("Friday, March 14"));
+    assert!(prompt.contains("- [Work] Ship release notes"));
+    assert!(prompt.contains("- Sleep 450 min (from 400 min)"));
+
+    let draft = template_draft(&input);
+    assert!(draft.starts_with("I completed 1 action: Ship release notes."));
+    assert!(draft.contains("1 action stayed open."));
+    assert!(draft.contains("Family: Dinner ran late."));
+}
+
+#[test]
+fn provider_receives_prompt_and_key() {
+    let input = sample_input();
+
+    let none = request_draft(&input, &SummarySettings::default()).unwrap();
+    assert_eq!(none, None);
+
+    std::env::set_var("FOCUSFIVE_TEST_SUMMARY_KEY", "secret");
+    let (endpoint, server) = serve_once(
+        "200 OK",
+        r#"{"choices":[{"message":{"role":"assistant","content":"Shipped it.\n Rested well."}}]}"#,
+    );
+    let draft = request_draft(
+        &input,
+        &SummarySettings {
+            endpoint: Some(endpoint),
+            model: Some("tiny".to_string()),
+            api_key_env: Some("FOCUSFIVE_TEST_SUMMARY_KEY".to_string()),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(draft.as_deref(), Some("Shipped it. Rested well."));
+    let request = server.join().unwrap();
+    assert!(request.starts_with("POST /v1/chat/completions"));
+    assert!(request.contains("Authorization: Bearer secret"));
+    assert!(request.contains(r#""model":"tiny""#));
+    assert!(request.contains("Ship release notes"));
+
+    let (endpoint, server) = serve_once("500 Internal Server Error", "model not loaded");
+    let failed = request_draft(
+        &input,
+        &SummarySettings {
+            endpoint: Some(endpoint),
+            ..Default::default()
+        },
+    );
+    server.join().unwrap();
+    let message = format!("{:#}", failed.unwrap_err());
+    assert!(message.contains("500") && message.contains("model not loaded"));
+
+    let missing_key = request_draft(
+        &input,
+        &SummarySettings {
+            endpoint: Some("http://127.0.0.1:9/".to_string()),
+            api_key_env: Some("FOCUSFIVE_TEST_UNSET_KEY".to_string()),
+            ..Default::default()
+        },
+    );
+    assert!(format!("{:#}", missing_key.unwrap_err()).contains("FOCUSFIVE_TEST_UNSET_KEY"));
+}
+
+#[test]
+fn slow_providers_time_out_without_blocking() {
+    // Accepts the connection but never answers
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let endpoint = format!("http://{}/", listener.local_addr().unwrap());
+    let settings = SummarySettings {
+        endpoint: Some(endpoint),
+        timeout_secs: Some(1),
+        ..Default::default()
+    };
+    let input = sample_input();
+
+    let started = std::time::Instant::now();
+    let request = focusfive::summary::DraftRequest::start(&input, &settings).unwrap();
+    assert!(request.try_finish().is_none());
+    let _connection = listener.accept().unwrap();
+    let result = request.finish();
+    assert!(started.elapsed() < std::time::Duration::from_secs(5));
+    assert!(format!("{:#}", result.unwrap_err()).contains("1s"));
+    assert_eq!(request.fallback, template_draft(&input));
+}
+
+#[test]
+fn app_drafts_then_reopens_saved_reflection() {
+    let temp_dir = TempDir::new().unwrap();
+    let goals_dir = temp_dir.path().join("goals");
+    fs::create_dir_all(&goals_dir).unwrap();
+    let config = Config {
+        goals_dir: goals_dir.to_str().unwrap().to_string(),
+        data_root: temp_dir.path().to_str().unwrap().to_string(),
+    };
+    let (endpoint, server) = serve_once(
+        "200 OK",
+        r#"{"choices":[{"message":{"content":"Good\n day"}}]}"#,
+    );
+    let settings = Settings {
+        summary: SummarySettings {
+            endpoint: Some(endpoint),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    save_settings(&settings, &config).unwrap();
+
+    let mut app = App::new(config.clone()).unwrap();
+    let date = app.goals.date;
+    app.handle_key(KeyCode::Char('S')).unwrap();
+    // The editor opens once the draft arrives
+    assert!(!app.text_editor.is_active);
+    assert!(app.summary_draft.is_some());
+    app.wait_for_background();
+    server.join().unwrap();
+    assert!(app.text_editor.is_active);
+    assert_eq!(app.text_editor.text, "Good day");
+
+    app.handle_key(KeyCode::Char('!')).unwrap();
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert_eq!(
+        load_reflection(date, &config).unwrap().as_deref(),
+        Some("Good day!")
+    );
+    // Skip the gratitude prompts that follow
+    app.handle_key(KeyCode::Esc).unwrap();
+
+    // A saved reflection is reopened instead of drafting a new one
+    app.handle_key(KeyCode::Char('S')).unwrap();
+    assert_eq!(app.text_editor.text, "Good day!");
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(!app.text_editor.is_active);
+}
@@ -0,0 +1,127 @@
+use crossterm::event::KeyCode;
+use focusfive::data::{save_indicators, save_settings};
+use focusfive::events::{Event, EventBus, EventKind};
+use focusfive::models::{
+    Config, EventSettings, IndicatorDef, IndicatorKind, IndicatorUnit, IndicatorsData, Settings,
+};
+use focusfive::ui::app::FocusPanel;
+use focusfive::ui::App;
+use std::fs;
+use tempfile::TempDir;
+
+fn read_events(path: &std::path::Path) -> Vec<Event> {
+    fs::read_to_string(path)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn app_emits_completion_observation_and_day_closed_events() {
+    let temp_dir = TempDir::new().unwrap();
+    let goals_dir = temp_dir.path().join("goals");
+    fs::create_dir_all(&goals_dir).unwrap();
+    let config = Config {
+        goals_dir: goals_dir.to_str().unwrap().to_string(),
+        data_root: temp_dir.path().to_str().unwrap().to_string(),
+    };
+
+    let events_path = temp_dir.path().join("events.ndjson");
+    let settings = Settings {
+        events: EventSettings {
+            socket: None,
+            file: Some(events_path.to_str().unwrap().to_string()),
+        },
+        ..Default::default()
+    };
+    save_settings(&settings, &config).unwrap();
+
+    let indicators = IndicatorsData {
+        version: 1,
+        indicators: vec![IndicatorDef::new(
+            "Pushups".to_string(),
+            IndicatorKind::Leading,
+            IndicatorUnit::Count,
+        )],
+    };
+    save_indicators(&indicators, &config).unwrap();
+
+    let mut app = App::new(config).unwrap();
+    assert!(app.events.is_enabled());
+
+    app.goals.work.actions[0].text = "Ship it".to_string();
+    app.focus_panel = FocusPanel::Actions;
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+
+    app.handle_key(KeyCode::Char('+')).unwrap();
+    app.handle_key(KeyCode::Enter).unwrap();
+    app.handle_key(KeyCode::Char('5')).unwrap();
+    app.handle_key(KeyCode::Enter).unwrap();
+
+    // Moving to another day is not closing it; X and Enter close it out
+    app.navigate_to_date(app.goals.date.pred_opt().unwrap())
+        .unwrap();
+    app.navigate_to_date(app.goals.date.succ_opt().unwrap())
+        .unwrap();
+    app.handle_key(KeyCode::Char('X')).unwrap();
+    app.handle_key(KeyCode::Enter).unwrap();
+    // Dropping the app drains the delivery thread
+    drop(app);
+
+    let events = read_events(&events_path);
+    let kinds: Vec<EventKind> = events.iter().map(|e| e.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            EventKind::ActionCompleted,
+            EventKind::ObservationAdded,
+            EventKind::DayClosed
+        ]
+    );
+    assert_eq!(events[0].data["text"], "Ship it");
+    assert_eq!(events[0].data["outcome"], "Work");
+    assert_eq!(events[1].data["value"], 5.0);
+    assert_eq!(events[2].data["completed"], 1);
+    assert!(events[2].data["summary"]
+        .as_str()
+        .unwrap()
+        .contains("Completion: 1/9"));
+
+    let raw = fs::read_to_string(&events_path).unwrap();
+    assert!(raw.starts_with("{\"type\":\"action_completed\""));
+}
+
+#[cfg(unix)]
+#[test]
+fn events_are_written_to_unix_socket() {
+    use std::io::{BufRead, BufReader};
+    use std::os::unix::net::UnixListener;
+
+    let temp_dir = TempDir::new().unwrap();
+    let socket_path = temp_dir.path().join("focusfive.sock");
+    let listener = UnixListener::bind(&socket_path).unwrap();
+
+    let bus = EventBus::start(&EventSettings {
+        socket: Some(socket_path.to_str().unwrap().to_string()),
+        file: None,
+    });
+    bus.emit(Event::new(
+        EventKind::DayClosed,
+        serde_json::json!({"date": "2025-01-01"}),
+    ));
+
+    let (stream, _) = listener.accept().unwrap();
+    let mut line = String::new();
+    BufReader::new(stream).read_line(&mut line).unwrap();
+    let event: Event = serde_json::from_str(&line).unwrap();
+    assert_eq!(event.kind, EventKind::DayClosed);
+    assert_eq!(event.data["date"], "2025-01-01");
+}
+
+#[test]
+fn bus_without_sinks_is_disabled() {
+    let bus = EventBus::start(&EventSettings::default());
+    assert!(!bus.is_enabled());
+    bus.emit(Event::new(EventKind::DayClosed, serde_json::json!({})));
+}
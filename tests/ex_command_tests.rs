@@ -0,0 +1,131 @@
+mod common;
+
+use chrono::{Duration, Local, NaiveDate};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::command::{parse, run_headless, split_words, ExCommand};
+use focusfive::data::{load_or_create_goals, load_settings, write_goals_file};
+use focusfive::export::ExportPeriod;
+use focusfive::models::{DailyGoals, OutcomeType, ThemePreset};
+use focusfive::ui::theme::FocusFiveTheme;
+use focusfive::ui::App;
+use std::fs;
+
+fn type_command(app: &mut App, line: &str) {
+    app.handle_key(KeyCode::Char(':')).unwrap();
+    for c in line.chars() {
+        app.handle_key(KeyCode::Char(c)).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+}
+
+#[test]
+fn commands_parse_with_quotes_and_aliases() {
+    assert_eq!(
+        split_words(r#"add work "Ship v2" 'now' a\ b"#).unwrap(),
+        vec!["add", "work", "Ship v2", "now", "a b"]
+    );
+    assert!(split_words("add work \"Ship").is_err());
+
+    assert_eq!(
+        parse(":goto 2024-03-10").unwrap(),
+        ExCommand::Goto(NaiveDate::from_ymd_opt(2024, 3, 10).unwrap())
+    );
+    assert_eq!(
+        parse("add h Stretch for ten minutes").unwrap(),
+        ExCommand::Add {
+            outcome: OutcomeType::Health,
+            text: "Stretch for ten minutes".to_string(),
+        }
+    );
+    assert_eq!(
+        parse("export week").unwrap(),
+        ExCommand::Export(ExportPeriod::Week)
+    );
+    assert_eq!(
+        parse("theme Tritan").unwrap(),
+        ExCommand::Theme(ThemePreset::Tritan)
+    );
+
+    for bad in [
+        "",
+        "goto",
+        "goto 10/03/2024",
+        "add play x",
+        "add work",
+        "lol",
+    ] {
+        assert!(parse(bad).is_err(), "{:?} should not parse", bad);
+    }
+    assert!(parse("frobnicate")
+        .unwrap_err()
+        .to_string()
+        .contains("goto YYYY-MM-DD"));
+}
+
+#[test]
+fn headless_commands_write_files() {
+    let (temp_dir, config) = temp_config();
+    let day = NaiveDate::from_ymd_opt(2025, 6, 4).unwrap();
+
+    let mut goals = DailyGoals::new(day);
+    goals.work.actions[0].text = "Existing".to_string();
+    write_goals_file(&goals, &config).unwrap();
+
+    let add = parse(r#"add work "Ship v2""#).unwrap();
+    let message = run_headless(&add, day, &config).unwrap();
+    assert!(message.contains("action 2"));
+    let goals = load_or_create_goals(day, &config).unwrap();
+    assert_eq!(goals.work.actions[1].text, "Ship v2");
+
+    let shown = run_headless(&parse("goto 2025-06-04").unwrap(), day, &config).unwrap();
+    assert!(shown.contains("Ship v2"));
+    assert!(run_headless(&parse("goto 2025-06-01").unwrap(), day, &config).is_err());
+
+    run_headless(&parse("export week").unwrap(), day, &config).unwrap();
+    let export = fs::read_to_string(temp_dir.path().join("exports/week-2025-W23.md")).unwrap();
+    assert!(export.starts_with("# FocusFive week export: 2025-06-02 to 2025-06-08\n"));
+    assert!(export.contains("Ship v2"));
+
+    run_headless(&parse("theme monochrome").unwrap(), day, &config).unwrap();
+    assert_eq!(
+        load_settings(&config).unwrap().theme,
+        ThemePreset::Monochrome
+    );
+}
+
+#[test]
+fn colon_opens_the_command_line_in_the_app() {
+    let (_temp_dir, config) = temp_config();
+    let today = Local::now().date_naive();
+    let yesterday = today - Duration::days(1);
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+
+    type_command(&mut app, "add family \"Call grandma\"");
+    assert_eq!(app.goals.family.actions[0].text, "Call grandma");
+    assert_eq!(app.selected_outcome, OutcomeType::Family);
+    let saved = load_or_create_goals(today, &config).unwrap();
+    assert_eq!(saved.family.actions[0].text, "Call grandma");
+
+    type_command(&mut app, &format!("goto {}", yesterday.format("%Y-%m-%d")));
+    assert_eq!(app.goals.date, yesterday);
+    // Future days stay out of reach, as with PageDown
+    type_command(&mut app, "goto 2999-01-01");
+    assert_eq!(app.goals.date, yesterday);
+
+    type_command(&mut app, "theme colorblind");
+    assert_eq!(
+        app.theme.completed,
+        FocusFiveTheme::preset(ThemePreset::Colorblind).completed
+    );
+    assert_eq!(
+        load_settings(&config).unwrap().theme,
+        ThemePreset::Colorblind
+    );
+
+    // A typo reports an error and leaves the app running
+    type_command(&mut app, "thme dark");
+    assert!(!app.text_editor.is_active);
+    assert_eq!(app.settings.theme, ThemePreset::Colorblind);
+}
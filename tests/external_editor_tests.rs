@@ -0,0 +1,82 @@
+mod common;
+
+use common::temp_config;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use focusfive::data::{load_or_create_vision, load_reflection};
+use focusfive::external_editor::edit_with;
+use focusfive::models::OutcomeType;
+use focusfive::ui::app::FocusPanel;
+use focusfive::ui::App;
+
+fn ctrl_e(app: &mut App) {
+    app.handle_key_event(KeyEvent::new(KeyCode::Char('e'), KeyModifiers::CONTROL))
+        .unwrap();
+}
+
+#[test]
+fn edit_with_round_trips_through_a_file() {
+    // The command gets the file path as its last argument
+    let edited = edit_with("printf 'Line one\\nLine two\\n' >", "old").unwrap();
+    assert_eq!(edited, "Line one\nLine two");
+
+    let err = edit_with("false", "keep me").unwrap_err();
+    assert!(err.to_string().contains("nothing changed"));
+}
+
+#[test]
+fn ctrl_e_hands_visions_to_the_external_editor() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+
+    // Action edits stay in the app
+    app.focus_panel = FocusPanel::Actions;
+    app.handle_key(KeyCode::Char('E')).unwrap();
+    assert!(app.text_editor.is_active);
+    ctrl_e(&mut app);
+    assert_eq!(app.take_external_edit(), None);
+    app.handle_key(KeyCode::Esc).unwrap();
+
+    app.handle_key(KeyCode::Char('v')).unwrap();
+    for c in "Lead".chars() {
+        app.handle_key(KeyCode::Char(c)).unwrap();
+    }
+    ctrl_e(&mut app);
+    assert_eq!(app.take_external_edit(), Some("Lead".to_string()));
+    assert_eq!(app.take_external_edit(), None, "asked for once");
+
+    let edited = edit_with("printf 'Lead a team\\n\\nand ship' >", "Lead");
+    app.finish_external_edit(edited).unwrap();
+    assert!(!app.text_editor.is_active);
+    let saved = load_or_create_vision(&config).unwrap();
+    assert_eq!(
+        saved.get_vision(&OutcomeType::Work),
+        "Lead a team\n\nand ship"
+    );
+}
+
+#[test]
+fn a_failed_external_edit_keeps_the_reflection_open() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+
+    app.handle_key(KeyCode::Char('S')).unwrap();
+    assert!(app.text_editor.is_active);
+    let draft = app.text_editor.text.clone();
+    ctrl_e(&mut app);
+    let text = app.take_external_edit().unwrap();
+    app.finish_external_edit(edit_with("false", &text)).unwrap();
+    assert!(app.text_editor.is_active);
+    assert_eq!(app.text_editor.text, draft);
+    assert_eq!(load_reflection(app.goals.date, &config).unwrap(), None);
+
+    ctrl_e(&mut app);
+    let text = app.take_external_edit().unwrap();
+    app.finish_external_edit(edit_with("printf 'Calm day\\n' >", &text))
+        .unwrap();
+    assert_eq!(
+        load_reflection(app.goals.date, &config).unwrap().as_deref(),
+        Some("Calm day")
+    );
+}
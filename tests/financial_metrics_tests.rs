@@ -0,0 +1,59 @@
+use chrono::NaiveDate;
+use focusfive::models::{IndicatorDef, IndicatorKind, IndicatorUnit, Observation};
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn revenue() -> IndicatorDef {
+    let mut def = IndicatorDef::new(
+        "Revenue".to_string(),
+        IndicatorKind::Lagging,
+        IndicatorUnit::Dollars,
+    );
+    def.monthly_target = Some(6200.0);
+    def
+}
+
+#[test]
+fn month_to_date_resets_at_month_boundary() {
+    let def = revenue();
+    let obs = |when, value| Observation::new(def.id.clone(), when, value, IndicatorUnit::Dollars);
+    let observations = vec![
+        obs(date(2025, 12, 31), 900.0),
+        obs(date(2026, 1, 1), 200.0),
+        obs(date(2026, 1, 5), 300.0),
+        // Later in the month than "today"
+        obs(date(2026, 1, 20), 1000.0),
+    ];
+
+    let mtd = def.month_to_date(&observations, date(2026, 1, 10)).unwrap();
+    assert_eq!(mtd.total, 500.0);
+    assert_eq!((mtd.days_elapsed, mtd.days_in_month), (10, 31));
+    assert_eq!(mtd.projected, 1550.0);
+    assert_eq!(mtd.target, Some(6200.0));
+
+    let december = def
+        .month_to_date(&observations, date(2025, 12, 31))
+        .unwrap();
+    assert_eq!(december.total, 900.0);
+    assert_eq!(december.projected, 900.0);
+}
+
+#[test]
+fn monthly_target_round_trips_and_is_optional() {
+    let def = revenue();
+    let json = serde_json::to_value(&def).unwrap();
+    assert_eq!(json["monthly_target"], 6200.0);
+
+    let mut plain = def.clone();
+    plain.monthly_target = None;
+    let json = serde_json::to_string(&plain).unwrap();
+    assert!(!json.contains("monthly_target"));
+    let back: IndicatorDef = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.monthly_target, None);
+
+    let mut minutes = revenue();
+    minutes.unit = IndicatorUnit::Minutes;
+    assert!(minutes.month_to_date(&[], date(2026, 1, 10)).is_none());
+}
@@ -0,0 +1,83 @@
+mod common;
+
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::models::{Config, OutcomeType};
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+fn screen(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Work: Plan, then Ship at priority 1; Health: Stretch; Family: nothing
+fn app_with_plan(config: &Config) -> App {
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    app.goals.work.actions[0].text = "Plan the sprint".to_string();
+    app.goals.work.actions[1].text = "Ship the release".to_string();
+    app.goals.health.actions[0].text = "Stretch".to_string();
+    app.handle_key(KeyCode::Tab).unwrap();
+    app.selected_action = 1;
+    app.handle_key(KeyCode::Char('1')).unwrap();
+    app
+}
+
+#[test]
+fn focus_mode_shows_only_the_top_action_of_each_outcome() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = app_with_plan(&config);
+    assert_eq!(app.action_priorities.get(&(OutcomeType::Work, 1)), Some(&1));
+
+    app.handle_key(KeyCode::Char('f')).unwrap();
+    assert!(app.focus_mode);
+    app.toasts.clear();
+    let screen = screen(&mut app);
+    assert!(screen.contains("P1 Ship the release"));
+    assert!(screen.contains("Stretch"));
+    assert!(screen.contains("All done"));
+    assert!(!screen.contains("Plan the sprint"));
+    assert!(!screen.contains("STATISTICS"));
+
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(!app.focus_mode);
+}
+
+#[test]
+fn completing_in_focus_mode_moves_to_the_next_action() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = app_with_plan(&config);
+    app.selected_outcome = OutcomeType::Work;
+    app.handle_key(KeyCode::Char('f')).unwrap();
+
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    assert!(app.goals.work.actions[1].completed);
+    assert_eq!(app.selected_action, 0);
+    assert!(screen(&mut app).contains("Plan the sprint"));
+
+    // j moves to the next outcome; keys outside focus mode do nothing here
+    app.handle_key(KeyCode::Char('j')).unwrap();
+    assert_eq!(app.selected_outcome, OutcomeType::Health);
+    app.handle_key(KeyCode::Char('K')).unwrap();
+    assert!(app.modal.is_none());
+    app.handle_key(KeyCode::Char('f')).unwrap();
+    assert!(!app.focus_mode);
+}
+
+#[test]
+fn focus_mode_needs_something_to_do() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+    app.handle_key(KeyCode::Char('f')).unwrap();
+    assert!(!app.focus_mode);
+}
@@ -0,0 +1,107 @@
+use chrono::{Duration, Local, Utc};
+use crossterm::event::KeyCode;
+use focusfive::data::{load_or_create_indicators, read_focus_sessions, read_observations_range};
+use focusfive::models::{Config, FocusSessionKind, ObservationSource, FOCUS_INDICATOR_ID};
+use focusfive::ui::app::FocusTimer;
+use focusfive::ui::App;
+use std::fs;
+use tempfile::TempDir;
+
+fn setup_app() -> (App, Config, TempDir) {
+    let temp_dir = TempDir::new().unwrap();
+    let goals_dir = temp_dir.path().join("goals");
+    fs::create_dir_all(&goals_dir).unwrap();
+    let config = Config {
+        goals_dir: goals_dir.to_str().unwrap().to_string(),
+        data_root: temp_dir.path().to_str().unwrap().to_string(),
+    };
+    let app = App::new(config.clone()).unwrap();
+    (app, config, temp_dir)
+}
+
+fn running(kind: FocusSessionKind, minutes_ago: i64) -> Option<FocusTimer> {
+    Some(FocusTimer {
+        kind,
+        started: Utc::now() - Duration::minutes(minutes_ago),
+    })
+}
+
+#[test]
+fn stopwatch_sessions_feed_the_focus_indicator() {
+    let (mut app, config, _temp) = setup_app();
+
+    app.handle_key(KeyCode::Char('P')).unwrap();
+    assert_eq!(
+        app.focus_timer.as_ref().map(|t| t.kind),
+        Some(FocusSessionKind::Stopwatch)
+    );
+
+    app.focus_timer = running(FocusSessionKind::Stopwatch, 30);
+    app.handle_key(KeyCode::Char('P')).unwrap();
+    app.focus_timer = running(FocusSessionKind::Stopwatch, 15);
+    app.handle_key(KeyCode::Char('p')).unwrap();
+    assert!(app.focus_timer.is_none());
+
+    let indicators = load_or_create_indicators(&config).unwrap();
+    let focus = indicators
+        .indicators
+        .iter()
+        .find(|def| def.id == FOCUS_INDICATOR_ID)
+        .expect("focus indicator is created on first session");
+    assert_eq!(focus.name, "Focused minutes");
+    assert_eq!(indicators.indicators.len(), 1);
+
+    let today = Local::now().date_naive();
+    let start = today - Duration::days(1);
+    let sessions = read_focus_sessions(start, today, &config).unwrap();
+    assert_eq!(sessions.len(), 2);
+
+    let observations = read_observations_range(start, today, &config).unwrap();
+    assert_eq!(observations.len(), 2);
+    assert!(observations
+        .iter()
+        .all(|obs| obs.source == ObservationSource::Automated));
+    // Each observation carries the running daily total
+    let last = observations.last().unwrap();
+    let day_total: f64 = sessions
+        .iter()
+        .filter(|s| s.day() == last.when)
+        .map(|s| s.minutes())
+        .sum();
+    assert_eq!(last.value, day_total);
+
+    app.wait_for_background();
+    assert_eq!(app.statistics.weekly_focus_minutes, 45.0);
+}
+
+#[test]
+fn pomodoro_finishes_itself_after_25_minutes() {
+    let (mut app, config, _temp) = setup_app();
+
+    app.focus_timer = running(FocusSessionKind::Pomodoro, 40);
+    app.tick();
+    assert!(app.focus_timer.is_none());
+
+    let today = Local::now().date_naive();
+    let sessions = read_focus_sessions(today - Duration::days(1), today, &config).unwrap();
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0].minutes(), 25.0);
+}
+
+#[test]
+fn sessions_under_a_minute_are_discarded() {
+    let (mut app, config, _temp) = setup_app();
+
+    app.handle_key(KeyCode::Char('p')).unwrap();
+    app.handle_key(KeyCode::Char('p')).unwrap();
+    assert!(app.focus_timer.is_none());
+
+    let today = Local::now().date_naive();
+    assert!(read_focus_sessions(today, today, &config)
+        .unwrap()
+        .is_empty());
+    assert!(load_or_create_indicators(&config)
+        .unwrap()
+        .indicators
+        .is_empty());
+}
@@ -0,0 +1,62 @@
+use chrono::{Duration, NaiveDate};
+use focusfive::forecast::{Forecast, TrendFit, TrendModel};
+use focusfive::models::IndicatorDirection;
+
+fn series(values: &[f64]) -> Vec<(NaiveDate, f64)> {
+    let start = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+    values
+        .iter()
+        .enumerate()
+        .map(|(day, value)| (start + Duration::days(day as i64), *value))
+        .collect()
+}
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+}
+
+#[test]
+fn picks_linear_or_exponential_by_fit() {
+    let linear = TrendFit::fit(&series(&[10.0, 12.0, 14.0, 16.0])).unwrap();
+    assert_eq!(linear.model, TrendModel::Linear);
+    assert!((linear.value_at(date(11)) - 30.0).abs() < 1e-9);
+
+    let doubling = TrendFit::fit(&series(&[1.0, 2.0, 4.0, 8.0, 16.0])).unwrap();
+    assert_eq!(doubling.model, TrendModel::Exponential);
+    assert_eq!(
+        doubling.crossing(64.0, IndicatorDirection::HigherIsBetter),
+        Some(date(7))
+    );
+
+    // A single day gives no trend
+    assert!(TrendFit::fit(&[(date(1), 5.0), (date(1), 7.0)]).is_none());
+}
+
+#[test]
+fn eta_is_compared_with_due_date() {
+    let points = series(&[100.0, 96.0, 92.0]);
+
+    // Lower is better: 4 a day down to 80 lands on Mar 6
+    let forecast = Forecast::new(
+        &points,
+        80.0,
+        IndicatorDirection::LowerIsBetter,
+        Some(date(5)),
+    )
+    .unwrap();
+    assert_eq!(forecast.eta, Some(date(6)));
+    assert!(!forecast.on_track());
+    assert_eq!(forecast.label(), "ETA Mar 06 · 1d late");
+
+    // Heading away from the target: no ETA
+    let forecast = Forecast::new(&points, 120.0, IndicatorDirection::HigherIsBetter, None).unwrap();
+    assert_eq!(forecast.eta, None);
+    assert_eq!(forecast.label(), "No ETA at current trend");
+
+    let forecast = Forecast::new(&points, 95.0, IndicatorDirection::LowerIsBetter, None).unwrap();
+    assert!(forecast.reached);
+    assert!(forecast.on_track());
+    assert_eq!(forecast.label(), "Target hit");
+
+    assert!(Forecast::new(&points, 95.0, IndicatorDirection::WithinRange, None).is_none());
+}
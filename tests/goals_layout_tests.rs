@@ -0,0 +1,107 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use focusfive::data::{
+    goals_file_path, load_or_create_goals, load_settings, migrate_goals_layout, write_goals_file,
+};
+use focusfive::models::{Config, DailyGoals, GoalsLayout};
+use std::fs;
+use std::path::Path;
+
+fn date(month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+}
+
+fn write_day(date: NaiveDate, text: &str, config: &Config) {
+    let mut goals = DailyGoals::new(date);
+    goals.work.actions[0].text = text.to_string();
+    write_goals_file(&goals, config).unwrap();
+}
+
+#[test]
+fn layouts_must_round_trip_dates() {
+    assert!(GoalsLayout::default().validate().is_ok());
+    assert!(GoalsLayout("%Y/%m/%Y-%m-%d.md".into()).validate().is_ok());
+    assert!(GoalsLayout("%Y/%m/%d.md".into()).validate().is_ok());
+
+    for bad in [
+        "%Y-%m.md",
+        "%Y-%m-%d.txt",
+        "../%Y-%m-%d.md",
+        "/tmp/%Y-%m-%d.md",
+        "%Y//%m-%d.md",
+        "%Q-%Y-%m-%d.md",
+    ] {
+        assert!(GoalsLayout(bad.into()).validate().is_err(), "{}", bad);
+    }
+}
+
+#[test]
+fn migration_moves_files_and_switches_layout() {
+    let (_temp_dir, config) = temp_config();
+    let goals_dir = Path::new(&config.goals_dir).to_path_buf();
+    write_day(date(8, 31), "August", &config);
+    write_day(date(9, 1), "September", &config);
+    fs::write(goals_dir.join("notes.md"), "# not a day").unwrap();
+
+    let nested = GoalsLayout("%Y/%m/%Y-%m-%d.md".into());
+    let dry = migrate_goals_layout(&nested, &config, true).unwrap();
+    assert_eq!(dry.moved.len(), 2);
+    assert_eq!(dry.unrecognized, vec![goals_dir.join("notes.md")]);
+    assert!(goals_dir.join("2025-09-01.md").exists());
+    assert!(load_settings(&config).unwrap().goals_layout.is_default());
+
+    let report = migrate_goals_layout(&nested, &config, false).unwrap();
+    assert_eq!(report.moved.len(), 2);
+    assert!(goals_dir.join("2025/09/2025-09-01.md").exists());
+    assert!(!goals_dir.join("2025-09-01.md").exists());
+    assert_eq!(load_settings(&config).unwrap().goals_layout, nested);
+
+    // Reads and writes follow the new layout
+    assert_eq!(
+        load_or_create_goals(date(8, 31), &config)
+            .unwrap()
+            .work
+            .actions[0]
+            .text,
+        "August"
+    );
+    write_day(date(9, 2), "New day", &config);
+    assert_eq!(
+        goals_file_path(date(9, 2), &config),
+        goals_dir.join("2025").join("09").join("2025-09-02.md")
+    );
+    assert!(goals_dir.join("2025/09/2025-09-02.md").exists());
+
+    // Running it again changes nothing
+    let again = migrate_goals_layout(&nested, &config, false).unwrap();
+    assert_eq!((again.moved.len(), again.unchanged), (0, 3));
+
+    // Back to flat; emptied folders are removed
+    let flat = migrate_goals_layout(&GoalsLayout::default(), &config, false).unwrap();
+    assert_eq!(flat.moved.len(), 3);
+    assert!(goals_dir.join("2025-09-02.md").exists());
+    assert!(!goals_dir.join("2025").exists());
+    assert!(goals_dir.join("notes.md").exists());
+}
+
+#[test]
+fn existing_destinations_are_not_overwritten() {
+    let (_temp_dir, config) = temp_config();
+    let goals_dir = Path::new(&config.goals_dir).to_path_buf();
+    write_day(date(9, 1), "Flat", &config);
+    fs::create_dir_all(goals_dir.join("2025")).unwrap();
+    fs::write(goals_dir.join("2025/09-01.md"), "keep me").unwrap();
+
+    let layout = GoalsLayout("%Y/%m-%d.md".into());
+    let report = migrate_goals_layout(&layout, &config, false).unwrap();
+    assert_eq!(report.conflicts, vec![goals_dir.join("2025-09-01.md")]);
+    // The nested file already matches the new layout
+    assert_eq!(report.unchanged, 1);
+    assert_eq!(
+        fs::read_to_string(goals_dir.join("2025/09-01.md")).unwrap(),
+        "keep me"
+    );
+    assert!(goals_dir.join("2025-09-01.md").exists());
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use chrono::{Duration, NaiveDate};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{load_reflection, save_reflection};
+use focusfive::gratitude;
+use focusfive::ui::App;
+
+fn items(list: &[&str]) -> Vec<String> {
+    list.iter().map(|item| item.to_string()).collect()
+}
+
+fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        app.handle_key(KeyCode::Char(c)).unwrap();
+    }
+}
+
+#[test]
+fn split_and_join_round_trip() {
+    let text = gratitude::join("A calm day.", &items(&["coffee", "a walk", "  "]));
+    assert_eq!(text, "A calm day.\n\n## Grateful for\n- coffee\n- a walk");
+
+    let (body, list) = gratitude::split(&text);
+    assert_eq!(body, "A calm day.");
+    assert_eq!(list, items(&["coffee", "a walk"]));
+
+    assert_eq!(gratitude::join("Only text", &[]), "Only text");
+    assert_eq!(gratitude::split("Only text").1, Vec::<String>::new());
+}
+
+#[test]
+fn save_keeps_reflection_text() {
+    let (_temp_dir, config) = temp_config();
+    let date = NaiveDate::from_ymd_opt(2025, 5, 4).unwrap();
+    save_reflection(date, "Slow Sunday.", &config).unwrap();
+
+    gratitude::save(date, &items(&["sunshine"]), &config).unwrap();
+    gratitude::save(date, &items(&["sunshine", "friends"]), &config).unwrap();
+
+    assert_eq!(
+        load_reflection(date, &config).unwrap().as_deref(),
+        Some("Slow Sunday.\n\n## Grateful for\n- sunshine\n- friends")
+    );
+    assert_eq!(
+        gratitude::load(date, &config).unwrap(),
+        items(&["sunshine", "friends"])
+    );
+}
+
+#[test]
+fn streak_counts_back_from_yesterday_when_today_is_open() {
+    let (_temp_dir, config) = temp_config();
+    let today = NaiveDate::from_ymd_opt(2025, 5, 10).unwrap();
+    for days_ago in 1..=3 {
+        gratitude::save(today - Duration::days(days_ago), &items(&["tea"]), &config).unwrap();
+    }
+    // A gap before the run ends it
+    gratitude::save(today - Duration::days(5), &items(&["rain"]), &config).unwrap();
+    save_reflection(today - Duration::days(4), "No list that day", &config).unwrap();
+
+    assert_eq!(gratitude::streak(today, &config).unwrap(), 3);
+    gratitude::save(today, &items(&["books"]), &config).unwrap();
+    assert_eq!(gratitude::streak(today, &config).unwrap(), 4);
+}
+
+#[test]
+fn recall_prefers_the_same_day_in_earlier_years() {
+    let (_temp_dir, config) = temp_config();
+    let today = NaiveDate::from_ymd_opt(2025, 5, 10).unwrap();
+    assert_eq!(gratitude::recall(today, &config).unwrap(), None);
+
+    gratitude::save(today - Duration::days(2), &items(&["tea"]), &config).unwrap();
+    let recall = gratitude::recall(today, &config).unwrap().unwrap();
+    assert_eq!(recall.item, "tea");
+    assert!(!recall.on_this_day);
+
+    let last_year = NaiveDate::from_ymd_opt(2024, 5, 10).unwrap();
+    gratitude::save(last_year, &items(&["a new job"]), &config).unwrap();
+    let recall = gratitude::recall(today, &config).unwrap().unwrap();
+    assert_eq!(recall.date, last_year);
+    assert_eq!(recall.item, "a new job");
+    assert!(recall.on_this_day);
+
+    // Today's own list is never recalled
+    gratitude::save(today, &items(&["now"]), &config).unwrap();
+    assert_eq!(
+        gratitude::recall(today, &config).unwrap().unwrap().date,
+        last_year
+    );
+}
+
+#[test]
+fn evening_reflection_asks_for_three_items() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config.clone()).unwrap();
+    let date = app.goals.date;
+
+    app.handle_key(KeyCode::Char('S')).unwrap();
+    app.text_editor.text = "Good day".to_string();
+    app.text_editor.cursor_position = app.text_editor.text.len();
+    app.handle_key(KeyCode::Enter).unwrap();
+
+    for item in ["coffee", "family", "sleep"] {
+        assert!(app.text_editor.is_active);
+        type_text(&mut app, item);
+        app.handle_key(KeyCode::Enter).unwrap();
+    }
+    assert!(!app.text_editor.is_active);
+    assert_eq!(
+        gratitude::load(date, &config).unwrap(),
+        items(&["coffee", "family", "sleep"])
+    );
+    assert_eq!(app.gratitude_streak, 1);
+
+    // Reopening shows the reflection alone and keeps the list on save
+    app.handle_key(KeyCode::Char('S')).unwrap();
+    assert_eq!(app.text_editor.text, "Good day");
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert_eq!(app.text_editor.text, "coffee");
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert_eq!(
+        gratitude::split(&load_reflection(date, &config).unwrap().unwrap()),
+        (
+            "Good day".to_string(),
+            items(&["coffee", "family", "sleep"])
+        )
+    );
+}
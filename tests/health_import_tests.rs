@@ -0,0 +1,93 @@
+use assert_cmd::Command;
+use focusfive::data::{read_observations_range, save_indicators};
+use focusfive::models::{
+    Config, IndicatorDef, IndicatorKind, IndicatorUnit, IndicatorsData, ObservationSource,
+};
+use predicates::str::contains;
+use std::fs;
+use tempfile::TempDir;
+
+fn setup_home() -> (TempDir, Config) {
+    let home = TempDir::new().unwrap();
+    let data_root = home.path().join("FocusFive");
+    let config = Config {
+        goals_dir: data_root.join("goals").to_string_lossy().to_string(),
+        data_root: data_root.to_string_lossy().to_string(),
+    };
+
+    let indicators = IndicatorsData {
+        version: 1,
+        indicators: vec![
+            IndicatorDef::new(
+                "Steps".to_string(),
+                IndicatorKind::Leading,
+                IndicatorUnit::Count,
+            ),
+            IndicatorDef::new(
+                "Sleep".to_string(),
+                IndicatorKind::Lagging,
+                IndicatorUnit::Custom("hours".to_string()),
+            ),
+        ],
+    };
+    save_indicators(&indicators, &config).unwrap();
+    (home, config)
+}
+
+#[test]
+fn import_health_records_observations_once() {
+    let (home, config) = setup_home();
+    let export = home.path().join("daily.csv");
+    fs::write(
+        &export,
+        "Date,Step count,Sleep duration (ms)\n2025-04-01,9000,25200000\n2025-04-02,7000,\n",
+    )
+    .unwrap();
+
+    Command::cargo_bin("focusfive")
+        .unwrap()
+        .env("HOME", home.path())
+        .args(["import-health", export.to_str().unwrap()])
+        .args(["--steps", "steps", "--sleep", "Sleep"])
+        .assert()
+        .success()
+        .stdout(contains("3 imported"));
+
+    let day = |d| chrono::NaiveDate::from_ymd_opt(2025, 4, d).unwrap();
+    let observations = read_observations_range(day(1), day(2), &config).unwrap();
+    assert_eq!(observations.len(), 3);
+    assert!(observations
+        .iter()
+        .all(|obs| obs.source == ObservationSource::Import));
+    assert!(observations.iter().any(|obs| obs.value == 7.0));
+
+    // Re-importing the same export is a no-op
+    Command::cargo_bin("focusfive")
+        .unwrap()
+        .env("HOME", home.path())
+        .args(["import-health", export.to_str().unwrap()])
+        .args(["--steps", "Steps", "--sleep", "Sleep"])
+        .assert()
+        .success()
+        .stdout(contains("0 imported, 3 duplicates skipped"));
+}
+
+#[test]
+fn import_health_rejects_unknown_indicator() {
+    let (home, _config) = setup_home();
+    let export = home.path().join("daily.csv");
+    fs::write(&export, "Date,Step count\n2025-04-01,9000\n").unwrap();
+
+    Command::cargo_bin("focusfive")
+        .unwrap()
+        .env("HOME", home.path())
+        .args([
+            "import-health",
+            export.to_str().unwrap(),
+            "--steps",
+            "Walking",
+        ])
+        .assert()
+        .failure()
+        .stderr(contains("No indicator with id or name 'Walking'"));
+}
@@ -0,0 +1,148 @@
+mod common;
+
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::save_settings;
+use focusfive::models::Settings;
+use focusfive::ui::app::ModalState;
+use focusfive::ui::keymap::{parse_key, KeyAction, Keymap, Mode};
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use std::collections::BTreeMap;
+
+fn render(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(160, 50)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn type_text(app: &mut App, text: &str) {
+    for c in text.chars() {
+        app.handle_key(KeyCode::Char(c)).unwrap();
+    }
+}
+
+#[test]
+fn help_lists_the_keymap_by_category_and_filters_as_you_type() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+
+    app.handle_key(KeyCode::Char('?')).unwrap();
+    assert!(matches!(app.modal, Some(ModalState::Help(_))));
+    let screen = render(&mut app);
+    assert!(screen.contains("Type to search"));
+    assert!(screen.contains("Navigation:"));
+    assert!(screen.contains("Timers:"));
+    assert!(screen.contains("Snooze the action to a later day"));
+
+    // Keys typed into the overlay search rather than run
+    type_text(&mut app, "snooze");
+    let screen = render(&mut app);
+    assert!(screen.contains("Search: snooze"));
+    assert!(screen.contains("goals.snooze"));
+    assert!(!screen.contains("Timers:"));
+    assert!(matches!(app.modal, Some(ModalState::Help(_))));
+
+    type_text(&mut app, " xyzzy");
+    assert!(render(&mut app).contains("No keys match"));
+
+    // Esc clears the search first, then closes
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(matches!(app.modal, Some(ModalState::Help(ref state)) if state.query.is_empty()));
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(app.modal.is_none());
+}
+
+#[test]
+fn help_follows_the_screen_it_was_opened_from() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+    app.handle_key(KeyCode::Char('d')).unwrap();
+    app.handle_key(KeyCode::Char('?')).unwrap();
+
+    let screen = render(&mut app);
+    assert!(screen.contains("Keys · Dashboard"));
+    assert!(screen.contains("Zoom the chart in"));
+    assert!(!screen.contains("Snooze the action"));
+    app.handle_key(KeyCode::Char('?')).unwrap();
+    assert!(app.modal.is_none());
+    assert!(app.show_dashboard);
+}
+
+#[test]
+fn remaps_in_settings_change_handlers_and_help() {
+    let (_temp_dir, config) = temp_config();
+    let mut settings = Settings::default();
+    settings
+        .keys
+        .insert("goals.snooze".to_string(), "s F2".to_string());
+    // `v` is taken from the vision editor
+    settings
+        .keys
+        .insert("goals.messages".to_string(), "v".to_string());
+    settings
+        .keys
+        .insert("goals.no_such_thing".to_string(), "x".to_string());
+    save_settings(&settings, &config).unwrap();
+
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+    assert_eq!(
+        app.keymap.action(Mode::Goals, KeyCode::Char('s')),
+        Some(KeyAction::Snooze)
+    );
+    assert_eq!(
+        app.keymap.action(Mode::Goals, KeyCode::F(2)),
+        Some(KeyAction::Snooze)
+    );
+    assert_eq!(app.keymap.action(Mode::Goals, KeyCode::Char('z')), None);
+    assert!(app
+        .toasts
+        .history()
+        .iter()
+        .any(|toast| toast.message.contains("goals.no_such_thing")));
+
+    app.handle_key(KeyCode::Char('v')).unwrap();
+    assert!(matches!(app.modal, Some(ModalState::Messages(_))));
+    app.handle_key(KeyCode::Esc).unwrap();
+
+    app.handle_key(KeyCode::Char('?')).unwrap();
+    type_text(&mut app, "snooze");
+    let screen = render(&mut app);
+    assert!(screen.contains("s/F2"));
+    assert!(screen.contains("goals.snooze *"));
+}
+
+#[test]
+fn keys_parse_from_settings() {
+    assert_eq!(parse_key("s"), Some(KeyCode::Char('s')));
+    assert_eq!(parse_key("Space"), Some(KeyCode::Char(' ')));
+    assert_eq!(parse_key("pgdn"), Some(KeyCode::PageDown));
+    assert_eq!(parse_key("F12"), Some(KeyCode::F(12)));
+    assert_eq!(parse_key("Hyper"), None);
+
+    let mut overrides = BTreeMap::new();
+    overrides.insert("focus.leave_focus".to_string(), "Backspace".to_string());
+    overrides.insert("dashboard.zoom_in".to_string(), "Nope".to_string());
+    let (keymap, warnings) = Keymap::with_overrides(&overrides);
+    assert_eq!(
+        keymap.action(Mode::Focus, KeyCode::Backspace),
+        Some(KeyAction::LeaveFocus)
+    );
+    assert_eq!(keymap.action(Mode::Focus, KeyCode::Esc), None);
+    // A remap with no usable key keeps the default
+    assert_eq!(
+        keymap.action(Mode::Dashboard, KeyCode::Char('z')),
+        Some(KeyAction::ZoomIn)
+    );
+    assert_eq!(warnings, vec!["Unknown key 'Nope' for dashboard.zoom_in"]);
+}
@@ -0,0 +1,96 @@
+use chrono::{Duration, Local};
+use crossterm::event::KeyCode;
+use focusfive::data::{append_observation, save_indicators};
+use focusfive::models::{
+    Config, IndicatorDef, IndicatorKind, IndicatorUnit, IndicatorsData, Observation,
+};
+use focusfive::ui::app::DashboardPanel;
+use focusfive::ui::App;
+use focusfive::widgets::CompareScale;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use std::fs;
+use tempfile::TempDir;
+
+fn render(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(200, 50)).unwrap();
+    // The first frame queues the dashboard load; draw again once it lands
+    for _ in 0..2 {
+        terminal.draw(|frame| app.render(frame)).unwrap();
+        app.wait_for_background();
+    }
+    let buffer = terminal.backend().buffer().clone();
+    buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn compare_mode_overlays_two_selected_indicators() {
+    let temp_dir = TempDir::new().unwrap();
+    let goals_dir = temp_dir.path().join("goals");
+    fs::create_dir_all(&goals_dir).unwrap();
+    let config = Config {
+        goals_dir: goals_dir.to_str().unwrap().to_string(),
+        data_root: temp_dir.path().to_str().unwrap().to_string(),
+    };
+
+    let sleep = IndicatorDef::new(
+        "Sleep".to_string(),
+        IndicatorKind::Leading,
+        IndicatorUnit::Minutes,
+    );
+    let steps = IndicatorDef::new(
+        "Steps".to_string(),
+        IndicatorKind::Lagging,
+        IndicatorUnit::Count,
+    );
+    let today = Local::now().date_naive();
+    for day in 0..4 {
+        let when = today - Duration::days(day);
+        let minutes = 420.0 + 10.0 * day as f64;
+        append_observation(
+            &Observation::new(sleep.id.clone(), when, minutes, IndicatorUnit::Minutes),
+            &config,
+        )
+        .unwrap();
+        append_observation(
+            &Observation::new(steps.id.clone(), when, minutes * 20.0, IndicatorUnit::Count),
+            &config,
+        )
+        .unwrap();
+    }
+    let indicators = IndicatorsData {
+        version: 1,
+        indicators: vec![sleep.clone(), steps.clone()],
+    };
+    save_indicators(&indicators, &config).unwrap();
+
+    let mut app = App::new(config).unwrap();
+    app.handle_key(KeyCode::Char('d')).unwrap();
+    assert!(app.show_dashboard);
+    render(&mut app);
+
+    app.dashboard_focus = DashboardPanel::Performance;
+    app.handle_key(KeyCode::Char('c')).unwrap();
+    assert_eq!(app.compare_anchor.as_deref(), Some(sleep.id.as_str()));
+    app.handle_key(KeyCode::Char('j')).unwrap();
+    app.handle_key(KeyCode::Char('c')).unwrap();
+    assert_eq!(app.compare_pair, Some((sleep.id.clone(), steps.id.clone())));
+
+    let screen = render(&mut app);
+    assert!(screen.contains("SLEEP vs STEPS (NORMALIZED) r=+1.00"));
+
+    app.handle_key(KeyCode::Char('n')).unwrap();
+    assert_eq!(app.compare_scale, CompareScale::DualAxis);
+    assert!(render(&mut app).contains("(DUAL AXIS)"));
+
+    // Esc leaves compare mode before it leaves the dashboard
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(app.compare_pair.is_none());
+    assert!(app.show_dashboard);
+    assert!(!render(&mut app).contains("SLEEP vs STEPS"));
+}
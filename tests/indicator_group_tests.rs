@@ -0,0 +1,152 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use focusfive::command::{parse, run_headless, ExCommand};
+use focusfive::data::{load_or_create_indicators, load_settings, save_indicators};
+use focusfive::models::{
+    DashboardGroups, IndicatorDef, IndicatorKind, IndicatorPanel, IndicatorUnit, IndicatorsData,
+};
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+fn indicator(id: &str, name: &str, group: Option<&str>) -> IndicatorDef {
+    let mut def = IndicatorDef::new(
+        name.to_string(),
+        IndicatorKind::Leading,
+        IndicatorUnit::Count,
+    );
+    def.id = id.to_string();
+    def.group = group.map(String::from);
+    def
+}
+
+/// Draw the dashboard until its background load lands
+fn render_dashboard(app: &mut App) {
+    let mut terminal = Terminal::new(TestBackend::new(200, 50)).unwrap();
+    app.show_dashboard = true;
+    for _ in 0..2 {
+        terminal.draw(|frame| app.render(frame)).unwrap();
+        app.wait_for_background();
+    }
+}
+
+#[test]
+fn panels_without_groups_show_everything() {
+    let layout = DashboardGroups {
+        performance: vec!["fitness".to_string()],
+        ..Default::default()
+    };
+    let pushups = indicator("pushups", "Pushups", Some("Fitness"));
+    let savings = indicator("savings", "Savings", Some("Finances"));
+    let loose = indicator("loose", "Pages", None);
+
+    assert!(layout.shows(IndicatorPanel::Performance, &pushups));
+    assert!(!layout.shows(IndicatorPanel::Performance, &savings));
+    assert!(!layout.shows(IndicatorPanel::Performance, &loose));
+    assert!(layout.shows(IndicatorPanel::LiveMetrics, &loose));
+
+    // Default layouts stay out of settings.json
+    assert!(DashboardGroups::default().is_default());
+    assert!(!layout.is_default());
+}
+
+#[test]
+fn commands_group_indicators_and_lay_out_panels() {
+    let (_temp_dir, config) = temp_config();
+    save_indicators(
+        &IndicatorsData {
+            version: 1,
+            indicators: vec![
+                indicator("pushups", "Pushups", Some("Fitness")),
+                indicator("savings", "Savings", None),
+            ],
+        },
+        &config,
+    )
+    .unwrap();
+    let today = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+
+    // An existing group's spelling wins
+    let message = run_headless(&parse("group Savings fitness").unwrap(), today, &config).unwrap();
+    assert_eq!(message, "Savings is now in Fitness");
+    run_headless(&parse("group Savings Finances").unwrap(), today, &config).unwrap();
+    let stored = load_or_create_indicators(&config).unwrap();
+    assert_eq!(
+        stored.find("savings").unwrap().group.as_deref(),
+        Some("Finances")
+    );
+
+    assert_eq!(
+        parse("panel performance Fitness,Output").unwrap(),
+        ExCommand::Panel {
+            panel: IndicatorPanel::Performance,
+            groups: vec!["Fitness".to_string(), "Output".to_string()],
+        }
+    );
+    let message = run_headless(
+        &parse("panel performance Fitness,Output").unwrap(),
+        today,
+        &config,
+    )
+    .unwrap();
+    assert_eq!(
+        message,
+        "performance shows Fitness, Output (no indicators in Output yet)"
+    );
+    let settings = load_settings(&config).unwrap();
+    assert_eq!(settings.dashboard.performance, vec!["Fitness", "Output"]);
+    assert!(settings.dashboard.live_metrics.is_empty());
+
+    run_headless(&parse("panel performance all").unwrap(), today, &config).unwrap();
+    assert!(load_settings(&config).unwrap().dashboard.is_default());
+
+    run_headless(&parse("group Savings none").unwrap(), today, &config).unwrap();
+    let stored = load_or_create_indicators(&config).unwrap();
+    assert_eq!(stored.find("savings").unwrap().group, None);
+
+    assert!(parse("panel sidebar Fitness").is_err());
+    assert!(run_headless(&parse("group Squats Fitness").unwrap(), today, &config).is_err());
+}
+
+#[test]
+fn dashboard_panels_follow_the_saved_layout() {
+    let (_temp_dir, config) = temp_config();
+    save_indicators(
+        &IndicatorsData {
+            version: 1,
+            indicators: vec![
+                indicator("pushups", "Pushups", Some("Fitness")),
+                indicator("savings", "Savings", Some("Finances")),
+                indicator("pages", "Pages", Some("Output")),
+            ],
+        },
+        &config,
+    )
+    .unwrap();
+
+    let mut app = App::new(config.clone()).unwrap();
+    render_dashboard(&mut app);
+    assert_eq!(app.dashboard_market_ids.len(), 3);
+    assert_eq!(app.dashboard_performance_ids.len(), 3);
+
+    app.run_ex_command("panel metrics Finances").unwrap();
+    app.run_ex_command("panel performance Fitness,Output")
+        .unwrap();
+    app.run_ex_command("panel signals Output").unwrap();
+    // Signals share their corner with the portfolio, shown by default
+    app.dashboard_show_signals = true;
+    render_dashboard(&mut app);
+    assert_eq!(app.dashboard_market_ids, vec!["savings".to_string()]);
+    assert_eq!(
+        app.dashboard_performance_ids,
+        vec!["pushups".to_string(), "pages".to_string()]
+    );
+    assert_eq!(app.dashboard_signal_ids, vec!["pages".to_string()]);
+
+    // The layout is kept for the next start
+    let mut app = App::new(config.clone()).unwrap();
+    render_dashboard(&mut app);
+    assert_eq!(app.dashboard_market_ids, vec!["savings".to_string()]);
+}
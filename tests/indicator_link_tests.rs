@@ -0,0 +1,153 @@
+mod common;
+
+use chrono::{Local, NaiveDate};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{
+    generate_markdown, parse_markdown, read_observations_range, save_indicators,
+};
+use focusfive::models::{
+    Config, DailyGoals, IndicatorDef, IndicatorKind, IndicatorUnit, IndicatorsData,
+    ObservationSource,
+};
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+use std::fs;
+
+/// An app with "Run 5k" as the first Health action and two indicators
+fn running_app(config: &Config) -> App {
+    save_indicators(
+        &IndicatorsData {
+            version: 1,
+            indicators: vec![
+                IndicatorDef::new(
+                    "Distance km".to_string(),
+                    IndicatorKind::Leading,
+                    IndicatorUnit::Custom("km".to_string()),
+                ),
+                IndicatorDef::new(
+                    "Resting HR".to_string(),
+                    IndicatorKind::Lagging,
+                    IndicatorUnit::Count,
+                ),
+            ],
+        },
+        config,
+    )
+    .unwrap();
+
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    app.goals.health.actions[0].text = "Run 5k".to_string();
+    app.selected_outcome = focusfive::models::OutcomeType::Health;
+    app.handle_key(KeyCode::Tab).unwrap();
+    app.selected_action = 0;
+    app
+}
+
+fn type_text(app: &mut App, text: &str) {
+    for ch in text.chars() {
+        app.handle_key(KeyCode::Char(ch)).unwrap();
+    }
+}
+
+#[test]
+fn links_round_trip_through_markdown() {
+    let mut goals = DailyGoals::new(NaiveDate::from_ymd_opt(2025, 3, 1).unwrap());
+    goals.health.actions[0].text = "Run 5k".to_string();
+    goals.health.actions[0].link_indicator("km-indicator", Some(5.0));
+    goals.health.actions[0].link_indicator("hr-indicator", None);
+    goals.health.actions[1].text = "Stretch".to_string();
+
+    let markdown = generate_markdown(&goals);
+    assert!(markdown.contains("  indicator: km-indicator = 5\n"));
+    assert!(markdown.contains("  indicator: hr-indicator\n"));
+
+    let parsed = parse_markdown(&markdown).unwrap();
+    assert_eq!(parsed.health.actions[0].text, "Run 5k");
+    assert_eq!(
+        parsed.health.actions[0].indicator_links,
+        goals.health.actions[0].indicator_links
+    );
+    assert_eq!(parsed.health.actions[1].text, "Stretch");
+    assert!(parsed.health.actions[1].indicator_links.is_empty());
+}
+
+#[test]
+fn completing_an_action_logs_its_linked_value() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = running_app(&config);
+    let km_id = app.indicators.indicators[0].id.clone();
+
+    app.handle_key(KeyCode::Char('g')).unwrap();
+    assert!(matches!(app.modal, Some(ModalState::IndicatorLinks(_))));
+    app.handle_key(KeyCode::Char('a')).unwrap();
+    type_text(&mut app, "5");
+    app.handle_key(KeyCode::Enter).unwrap();
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert_eq!(
+        app.goals.health.actions[0]
+            .indicator_link(&km_id)
+            .and_then(|link| link.value),
+        Some(5.0)
+    );
+
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    assert!(app.goals.health.actions[0].completed);
+    assert!(app.modal.is_none());
+
+    let today = Local::now().date_naive();
+    let observations = read_observations_range(today, today, &config).unwrap();
+    assert_eq!(observations.len(), 1);
+    assert_eq!(observations[0].indicator_id, km_id);
+    assert_eq!(observations[0].value, 5.0);
+    assert_eq!(observations[0].source, ObservationSource::Automated);
+    assert_eq!(
+        observations[0].action_id.as_deref(),
+        Some(app.goals.health.actions[0].id.as_str())
+    );
+
+    // The link is kept in the goals file
+    app.flush_pending_save().unwrap();
+    let stored = fs::read_to_string(focusfive::data::goals_file_path(today, &config)).unwrap();
+    assert!(stored.contains(&format!("indicator: {} = 5", km_id)));
+}
+
+#[test]
+fn links_without_a_value_ask_on_completion() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = running_app(&config);
+    let hr_id = app.indicators.indicators[1].id.clone();
+
+    app.handle_key(KeyCode::Char('g')).unwrap();
+    app.handle_key(KeyCode::Down).unwrap();
+    app.handle_key(KeyCode::Enter).unwrap();
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(app.goals.health.actions[0].indicator_link(&hr_id).is_some());
+
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    let Some(ModalState::IndicatorUpdate(state)) = &app.modal else {
+        panic!("expected to be asked for the value");
+    };
+    assert_eq!(state.indicator_id, hr_id);
+
+    while matches!(&app.modal, Some(ModalState::IndicatorUpdate(state)) if !state.buffer.is_empty())
+    {
+        app.handle_key(KeyCode::Backspace).unwrap();
+    }
+    type_text(&mut app, "52");
+    app.handle_key(KeyCode::Enter).unwrap();
+
+    let today = Local::now().date_naive();
+    let observations = read_observations_range(today, today, &config).unwrap();
+    assert_eq!(observations.len(), 1);
+    assert_eq!(observations[0].value, 52.0);
+    assert_eq!(
+        observations[0].action_id.as_deref(),
+        Some(app.goals.health.actions[0].id.as_str())
+    );
+
+    // Unchecking doesn't ask again
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    assert!(app.modal.is_none());
+}
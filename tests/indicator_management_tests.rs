@@ -83,6 +83,16 @@ fn create_test_indicator(objective_id: &str, name: &str) -> IndicatorDef {
         modified: Utc::now(),
         lineage_of: None,
         notes: Some("Test indicator".to_string()),
+        target_schedule: Vec::new(),
+        range_min: None,
+        range_max: None,
+        monthly_target: None,
+        ratio_of: None,
+        exclude_from_signals: false,
+        auto_rules: Vec::new(),
+        group: None,
+        aggregation: Default::default(),
+        target_period: Default::default(),
     }
 }
 
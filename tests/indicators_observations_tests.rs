@@ -5,7 +5,7 @@ use focusfive::data::{
 };
 use focusfive::models::{
     Config, IndicatorDef, IndicatorDirection, IndicatorKind, IndicatorUnit, IndicatorsData,
-    Observation, ObservationSource,
+    Observation, ObservationSource, TargetStep,
 };
 use std::fs;
 use tempfile::TempDir;
@@ -29,6 +29,16 @@ fn test_indicator_serialization() -> Result<()> {
                 modified: chrono::Utc::now(),
                 lineage_of: None,
                 notes: Some("Weekly sales leads".to_string()),
+                target_schedule: Vec::new(),
+                range_min: None,
+                range_max: None,
+                monthly_target: None,
+                ratio_of: None,
+                exclude_from_signals: false,
+                auto_rules: Vec::new(),
+                group: None,
+                aggregation: Default::default(),
+                target_period: Default::default(),
             },
             IndicatorDef {
                 id: "ind-2".to_string(),
@@ -43,6 +53,16 @@ fn test_indicator_serialization() -> Result<()> {
                 modified: chrono::Utc::now(),
                 lineage_of: Some("old-ind-2".to_string()),
                 notes: None,
+                target_schedule: Vec::new(),
+                range_min: None,
+                range_max: None,
+                monthly_target: None,
+                ratio_of: None,
+                exclude_from_signals: false,
+                auto_rules: Vec::new(),
+                group: None,
+                aggregation: Default::default(),
+                target_period: Default::default(),
             },
             IndicatorDef {
                 id: "ind-3".to_string(),
@@ -57,6 +77,16 @@ fn test_indicator_serialization() -> Result<()> {
                 modified: chrono::Utc::now(),
                 lineage_of: None,
                 notes: None,
+                target_schedule: Vec::new(),
+                range_min: None,
+                range_max: None,
+                monthly_target: None,
+                ratio_of: None,
+                exclude_from_signals: false,
+                auto_rules: Vec::new(),
+                group: None,
+                aggregation: Default::default(),
+                target_period: Default::default(),
             },
         ],
     };
@@ -100,6 +130,16 @@ fn test_indicator_serialization() -> Result<()> {
         modified: chrono::Utc::now(),
         lineage_of: None,
         notes: None,
+        target_schedule: Vec::new(),
+        range_min: None,
+        range_max: None,
+        monthly_target: None,
+        ratio_of: None,
+        exclude_from_signals: false,
+        auto_rules: Vec::new(),
+        group: None,
+        aggregation: Default::default(),
+        target_period: Default::default(),
     });
 
     let json2 = serde_json::to_string(&indicators)?;
@@ -128,6 +168,7 @@ fn test_observation_serialization() -> Result<()> {
         action_id: Some("action-123".to_string()),
         note: Some("Morning count".to_string()),
         created: chrono::Utc::now(),
+        at: None,
     };
 
     // Test JSON serialization (should be single line for NDJSON)
@@ -392,3 +433,109 @@ fn test_observation_creation_helper() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_observation_read_range_unsorted_and_appended() -> Result<()> {
+    let temp = TempDir::new()?;
+    let config = Config {
+        goals_dir: temp.path().join("goals").to_string_lossy().to_string(),
+        data_root: temp.path().to_string_lossy().to_string(),
+    };
+    let day = |d| NaiveDate::from_ymd_opt(2025, 3, d).unwrap();
+
+    // Backfilled entries arrive out of date order
+    for (d, value) in [(10, 1.0), (2, 2.0), (20, 3.0), (10, 4.0)] {
+        let obs = Observation::new("ind-1".to_string(), day(d), value, IndicatorUnit::Count);
+        append_observation(&obs, &config)?;
+    }
+
+    let march_10 = read_observations_range(day(10), day(10), &config)?;
+    let values: Vec<f64> = march_10.iter().map(|o| o.value).collect();
+    assert_eq!(values, vec![1.0, 4.0], "results keep file order");
+
+    // Appends after the first read are picked up by the cached index
+    let obs = Observation::new("ind-1".to_string(), day(5), 5.0, IndicatorUnit::Count);
+    append_observation(&obs, &config)?;
+
+    let early = read_observations_range(day(1), day(9), &config)?;
+    let values: Vec<f64> = early.iter().map(|o| o.value).collect();
+    assert_eq!(values, vec![2.0, 5.0]);
+
+    // A final line without a trailing newline is still returned
+    let tail = Observation::new("ind-1".to_string(), day(6), 6.0, IndicatorUnit::Count);
+    let path = temp.path().join("observations.ndjson");
+    let mut content = fs::read_to_string(&path)?;
+    content.push_str(&serde_json::to_string(&tail)?);
+    fs::write(&path, content)?;
+
+    let early = read_observations_range(day(1), day(9), &config)?;
+    assert_eq!(early.len(), 3);
+    assert_eq!(early[2].value, 6.0);
+
+    Ok(())
+}
+
+#[test]
+fn test_target_schedule_ramps_between_points() -> Result<()> {
+    let day = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap();
+    let mut indicator = IndicatorDef::new(
+        "Pushups".to_string(),
+        IndicatorKind::Leading,
+        IndicatorUnit::Count,
+    );
+    indicator.target = Some(5.0);
+    assert_eq!(indicator.target_on(day(3, 1)), Some(5.0));
+
+    // Listed out of order on purpose; the schedule is sorted by date
+    indicator.target_schedule = vec![
+        TargetStep { date: day(6, 1), target: 30.0 },
+        TargetStep { date: day(1, 1), target: 10.0 },
+    ];
+
+    assert_eq!(indicator.target_on(day(1, 1)), Some(10.0));
+    assert_eq!(indicator.target_on(day(6, 1)), Some(30.0));
+    assert_eq!(indicator.target_on(day(12, 31)), Some(30.0));
+    assert_eq!(indicator.target_on(NaiveDate::from_ymd_opt(2024, 12, 1).unwrap()), Some(5.0));
+
+    let midpoint = indicator.target_on(day(3, 17)).unwrap();
+    assert!(midpoint > 19.0 && midpoint < 21.0, "got {}", midpoint);
+
+    // Schedules survive a JSON round-trip; plain indicators omit the field
+    let json = serde_json::to_string(&indicator)?;
+    let parsed: IndicatorDef = serde_json::from_str(&json)?;
+    assert_eq!(parsed.target_schedule, indicator.target_schedule);
+    indicator.target_schedule.clear();
+    assert!(!serde_json::to_string(&indicator)?.contains("target_schedule"));
+
+    Ok(())
+}
+
+#[test]
+fn test_within_range_bounds() {
+    let day = NaiveDate::from_ymd_opt(2025, 5, 1).unwrap();
+    let mut sleep = IndicatorDef::new(
+        "Sleep hours".to_string(),
+        IndicatorKind::Lagging,
+        IndicatorUnit::Custom("hours".to_string()),
+    );
+    sleep.target = Some(8.0);
+
+    // Only WithinRange indicators have a band
+    assert_eq!(sleep.range_on(day), None);
+
+    sleep.direction = IndicatorDirection::WithinRange;
+    assert_eq!(sleep.range_on(day), Some((6.4, 9.6)));
+
+    sleep.range_min = Some(7.0);
+    sleep.range_max = Some(9.0);
+    let range = sleep.range_on(day).unwrap();
+    assert_eq!(range, (7.0, 9.0));
+    assert_eq!(IndicatorDef::range_deviation(8.0, range), 0.0);
+    assert_eq!(IndicatorDef::range_deviation(6.5, range), -0.5);
+    assert_eq!(IndicatorDef::range_deviation(10.0, range), 1.0);
+
+    // A one-sided band is open on the other end
+    sleep.range_max = None;
+    let range = sleep.range_on(day).unwrap();
+    assert_eq!(IndicatorDef::range_deviation(20.0, range), 0.0);
+}
@@ -0,0 +1,124 @@
+mod common;
+
+use chrono::{Duration, Local, NaiveTime};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{append_observation, read_observations_range, save_indicators};
+use focusfive::models::{
+    IndicatorAggregation, IndicatorDef, IndicatorKind, IndicatorUnit, IndicatorsData, Observation,
+};
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+fn exercise() -> IndicatorDef {
+    let mut def = IndicatorDef::new(
+        "Exercise".to_string(),
+        IndicatorKind::Leading,
+        IndicatorUnit::Minutes,
+    );
+    def.id = "exercise".to_string();
+    def.aggregation = IndicatorAggregation::Sum;
+    def
+}
+
+#[test]
+fn samples_carry_their_time_of_day() {
+    let today = Local::now().date_naive();
+
+    // Backfilled days stay untimed and keep the old file format
+    let mut backfill = Observation::new(
+        "exercise".to_string(),
+        today - Duration::days(3),
+        20.0,
+        IndicatorUnit::Minutes,
+    );
+    backfill.stamp_time();
+    assert_eq!(backfill.at, None);
+    assert!(!serde_json::to_string(&backfill).unwrap().contains("\"at\""));
+
+    let now = Observation::now("exercise".to_string(), 15.0, IndicatorUnit::Minutes);
+    assert_eq!(now.when, today);
+    assert!(now.at.is_some());
+    let parsed: Observation = serde_json::from_str(&serde_json::to_string(&now).unwrap()).unwrap();
+    assert_eq!(parsed.at, now.at);
+}
+
+#[test]
+fn last_follows_the_time_of_day() {
+    let today = Local::now().date_naive();
+    let mut weight = exercise();
+    weight.aggregation = IndicatorAggregation::Last;
+
+    // Logged in the evening first, then the morning reading was added
+    let mut evening = Observation::new(weight.id.clone(), today, 71.0, IndicatorUnit::Minutes);
+    evening.at = NaiveTime::from_hms_opt(21, 0, 0);
+    let mut morning = Observation::new(weight.id.clone(), today, 72.5, IndicatorUnit::Minutes);
+    morning.at = NaiveTime::from_hms_opt(7, 0, 0);
+
+    assert_eq!(
+        weight.daily_values(&[evening, morning]),
+        vec![(today, 71.0)]
+    );
+}
+
+#[test]
+fn summed_indicators_are_logged_in_increments() {
+    let (_temp_dir, config) = temp_config();
+    save_indicators(
+        &IndicatorsData {
+            version: 1,
+            indicators: vec![exercise()],
+        },
+        &config,
+    )
+    .unwrap();
+    let today = Local::now().date_naive();
+    for (hour, minutes) in [(12, 15.0), (9, 20.0)] {
+        let mut obs = Observation::new(
+            "exercise".to_string(),
+            today,
+            minutes,
+            IndicatorUnit::Minutes,
+        );
+        obs.at = NaiveTime::from_hms_opt(hour, 10, 0);
+        append_observation(&obs, &config).unwrap();
+    }
+
+    let mut app = App::new(config.clone()).unwrap();
+    app.handle_key(KeyCode::Char('L')).unwrap();
+    match &app.modal {
+        Some(ModalState::IndicatorUpdate(state)) => {
+            assert_eq!(state.buffer, "0");
+            assert_eq!(
+                state.today_samples,
+                vec![
+                    (NaiveTime::from_hms_opt(9, 10, 0), 20.0),
+                    (NaiveTime::from_hms_opt(12, 10, 0), 15.0),
+                ]
+            );
+            assert_eq!(state.latest_value, Some(35.0));
+        }
+        other => panic!("expected indicator modal, got {:?}", other),
+    }
+
+    let mut terminal = Terminal::new(TestBackend::new(160, 50)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+    let text: String = terminal
+        .backend()
+        .buffer()
+        .content
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect();
+    assert!(text.contains("Add to Today · 09:10 20 · 12:10 15 = 35"));
+
+    // +30m adds a third sample, stamped with the time it was logged
+    app.handle_key(KeyCode::Char('a')).unwrap();
+    app.handle_key(KeyCode::Enter).unwrap();
+    let logged = read_observations_range(today, today, &config).unwrap();
+    assert_eq!(logged.len(), 3);
+    let added = logged.iter().find(|obs| obs.value == 30.0).unwrap();
+    assert!(added.at.is_some());
+}
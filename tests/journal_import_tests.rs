@@ -0,0 +1,126 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use focusfive::data::{load_or_create_goals, write_goals_file};
+use focusfive::journal_import::{
+    distinct_headings, heading_key, import_notes, note_date, parse_note, prompt_mapping,
+    scan_journal_dir, suggest_outcome, HeadingMap,
+};
+use focusfive::models::{DailyGoals, OutcomeType};
+use std::fs;
+use std::path::Path;
+
+const NOTE: &str = "\
+# Monday
+- [ ] Loose item
+## Office
+- [x] Ship release notes
+- [ ] Review PR
+Some prose that is not an item.
+## Gym
+* run 5k
+## Reading
+- Finish chapter 3
+";
+
+#[test]
+fn notes_split_into_headed_items() {
+    let sections = parse_note(NOTE);
+    let headings: Vec<&str> = sections.iter().map(|s| s.heading.as_str()).collect();
+    assert_eq!(headings, ["Monday", "Office", "Gym", "Reading"]);
+    assert_eq!(sections[1].items.len(), 2);
+    assert!(sections[1].items[0].done);
+    assert_eq!(sections[1].items[1].text, "Review PR");
+    // Plain bullets import as unchecked
+    assert!(!sections[2].items[0].done);
+    assert_eq!(sections[2].items[0].text, "run 5k");
+
+    assert_eq!(
+        note_date(Path::new("journal/daily 2025-02-03.md")),
+        NaiveDate::from_ymd_opt(2025, 2, 3)
+    );
+    assert_eq!(note_date(Path::new("ideas.md")), None);
+    assert_eq!(suggest_outcome("Office"), Some(OutcomeType::Work));
+    assert_eq!(suggest_outcome("Gym"), Some(OutcomeType::Health));
+    assert_eq!(suggest_outcome("Reading"), None);
+}
+
+#[test]
+fn prompts_only_for_unmapped_headings() {
+    let headings = vec![
+        ("Office".to_string(), 3),
+        ("Gym".to_string(), 2),
+        ("Reading".to_string(), 1),
+        ("Errands".to_string(), 1),
+    ];
+    let mut preset = HeadingMap::new();
+    preset.insert(heading_key("office"), OutcomeType::Work);
+
+    // Gym takes the suggestion, a bad answer re-asks, Errands is skipped
+    let input = "\nnope\nfamily\ns\n";
+    let mut output = Vec::new();
+    let mapping = prompt_mapping(&headings, preset, &[], input.as_bytes(), &mut output).unwrap();
+
+    assert_eq!(mapping.get("office"), Some(&OutcomeType::Work));
+    assert_eq!(mapping.get("gym"), Some(&OutcomeType::Health));
+    assert_eq!(mapping.get("reading"), Some(&OutcomeType::Family));
+    assert!(!mapping.contains_key("errands"));
+    let output = String::from_utf8(output).unwrap();
+    assert!(!output.contains("Office"));
+    assert!(output.contains("Gym (2 notes)"));
+    assert!(output.contains("got 'nope'"));
+}
+
+#[test]
+fn import_writes_new_days_and_keeps_existing_ones() {
+    let (temp_dir, config) = temp_config();
+    let journal = temp_dir.path().join("journal");
+    fs::create_dir_all(journal.join("2025")).unwrap();
+    fs::write(journal.join("2025/2025-02-03.md"), NOTE).unwrap();
+    fs::write(
+        journal.join("2025-02-04.md"),
+        "## Office\n- [x] Plan sprint\n",
+    )
+    .unwrap();
+    fs::write(journal.join("2025-02-05.md"), "## Reading\n- Novel\n").unwrap();
+    fs::write(journal.join("someday.md"), "- [ ] Learn piano\n").unwrap();
+
+    let kept = NaiveDate::from_ymd_opt(2025, 2, 4).unwrap();
+    let mut existing = DailyGoals::new(kept);
+    existing.work.actions[0].text = "Already planned".to_string();
+    write_goals_file(&existing, &config).unwrap();
+
+    let (notes, undated) = scan_journal_dir(&journal).unwrap();
+    assert_eq!(notes.len(), 3);
+    assert_eq!(undated.len(), 1);
+    assert_eq!(distinct_headings(&notes)[1], ("Office".to_string(), 2));
+
+    let mut mapping = HeadingMap::new();
+    mapping.insert(heading_key("Office"), OutcomeType::Work);
+    mapping.insert(heading_key("Gym"), OutcomeType::Health);
+
+    let dry = import_notes(&notes, &mapping, &config, true).unwrap();
+    assert_eq!(dry.imported, 1);
+    let day = NaiveDate::from_ymd_opt(2025, 2, 3).unwrap();
+    assert!(load_or_create_goals(day, &config).unwrap().work.actions[0]
+        .text
+        .is_empty());
+
+    let summary = import_notes(&notes, &mapping, &config, false).unwrap();
+    assert_eq!(
+        (summary.imported, summary.existing, summary.empty),
+        (1, 1, 1)
+    );
+
+    let goals = load_or_create_goals(day, &config).unwrap();
+    assert_eq!(goals.work.actions[0].text, "Ship release notes");
+    assert!(goals.work.actions[0].completed);
+    assert_eq!(goals.work.actions[1].text, "Review PR");
+    assert_eq!(goals.health.actions[0].text, "run 5k");
+    assert!(goals.family.actions.iter().all(|a| a.text.is_empty()));
+    assert_eq!(
+        load_or_create_goals(kept, &config).unwrap().work.actions[0].text,
+        "Already planned"
+    );
+}
@@ -0,0 +1,136 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{load_reflection, save_reflection};
+use focusfive::journal::{self, JournalEntry};
+use focusfive::models::Config;
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 4, day).unwrap()
+}
+
+fn seed(config: &Config) {
+    save_reflection(date(1), "Shipped the beta.\nFelt tired but proud.", config).unwrap();
+    save_reflection(date(3), "Long run in the rain.", config).unwrap();
+    save_reflection(date(2), "Family dinner, then an early night", config).unwrap();
+}
+
+#[test]
+fn entries_are_newest_first_with_word_counts() {
+    let (_temp_dir, config) = temp_config();
+    seed(&config);
+
+    let entries = journal::load_entries(&config).unwrap();
+    let dates: Vec<_> = entries.iter().map(|entry| entry.date).collect();
+    assert_eq!(dates, [date(3), date(2), date(1)]);
+    assert_eq!(entries[2].words, 7);
+    assert_eq!(journal::word_count("  one\ttwo\n\nthree "), 3);
+}
+
+#[test]
+fn search_matches_all_words_in_text_or_date() {
+    let (_temp_dir, config) = temp_config();
+    seed(&config);
+    let entries = journal::load_entries(&config).unwrap();
+
+    assert_eq!(journal::search(&entries, ""), [0, 1, 2]);
+    assert_eq!(journal::search(&entries, "PROUD beta"), [2]);
+    assert_eq!(journal::search(&entries, "proud rain"), Vec::<usize>::new());
+    // Weekday and month names match too
+    assert_eq!(journal::search(&entries, "wednesday"), [1]);
+    assert_eq!(journal::search(&entries, "2025-04-03"), [0]);
+
+    let entry = JournalEntry::new(date(1), "\nShipped it.\nFelt proud.".to_string());
+    assert_eq!(entry.snippet("proud"), "Felt proud.");
+    assert_eq!(entry.snippet(""), "Shipped it.");
+}
+
+#[test]
+fn browser_searches_and_edits_a_past_reflection() {
+    let (_temp_dir, config) = temp_config();
+    seed(&config);
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+
+    app.handle_key(KeyCode::Char('J')).unwrap();
+    app.handle_key(KeyCode::Char('/')).unwrap();
+    for c in "dinner".chars() {
+        app.handle_key(KeyCode::Char(c)).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+    let Some(ModalState::Journal(state)) = app.modal.clone() else {
+        panic!("expected the journal");
+    };
+    assert!(!state.searching);
+    assert_eq!(state.selected().unwrap().date, date(2));
+
+    app.handle_key(KeyCode::Char('e')).unwrap();
+    app.handle_key(KeyCode::End).unwrap();
+    for c in " at ten".chars() {
+        app.handle_key(KeyCode::Char(c)).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+
+    assert_eq!(
+        load_reflection(date(2), &app.config).unwrap().unwrap(),
+        "Family dinner, then an early night at ten"
+    );
+    let Some(ModalState::Journal(state)) = app.modal.clone() else {
+        panic!("expected the journal to stay open");
+    };
+    assert_eq!(
+        state.selected().unwrap().text,
+        "Family dinner, then an early night at ten"
+    );
+
+    // Esc while searching clears the query
+    app.handle_key(KeyCode::Char('/')).unwrap();
+    app.handle_key(KeyCode::Esc).unwrap();
+    let Some(ModalState::Journal(state)) = app.modal.clone() else {
+        panic!("expected the journal");
+    };
+    assert_eq!(state.results.len(), 3);
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(app.modal.is_none());
+}
+
+#[test]
+fn the_list_scrolls_to_keep_the_selection_in_view() {
+    let (_temp_dir, config) = temp_config();
+    let first = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    for day in 0..200 {
+        let day = first + chrono::Duration::days(day);
+        save_reflection(day, &format!("Entry for {}", day), &config).unwrap();
+    }
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+
+    app.handle_key(KeyCode::Char('J')).unwrap();
+    for _ in 0..150 {
+        app.handle_key(KeyCode::Char('j')).unwrap();
+    }
+    let Some(ModalState::Journal(state)) = app.modal.clone() else {
+        panic!("expected the journal");
+    };
+    let selected = state.selected().unwrap().date;
+    assert_eq!(selected, first + chrono::Duration::days(49));
+
+    let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+    let screen: String = terminal
+        .backend()
+        .buffer()
+        .content()
+        .iter()
+        .map(|cell| cell.symbol())
+        .collect();
+    assert!(screen.contains(&format!("➤ {}", selected.format("%Y-%m-%d"))));
+    // The newest entries have scrolled off
+    assert!(!screen.contains("2024-07-18"));
+}
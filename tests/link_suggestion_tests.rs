@@ -0,0 +1,80 @@
+mod common;
+
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{load_or_create_goals, save_objectives};
+use focusfive::models::{Objective, ObjectiveStatus, ObjectivesData, OutcomeType};
+use focusfive::ui::app::{FocusPanel, ModalState};
+use focusfive::ui::App;
+use tempfile::TempDir;
+
+fn app_with_objectives() -> (TempDir, App, String) {
+    let (temp_dir, config) = temp_config();
+    let launch = Objective::new(OutcomeType::Work, "Product launch".to_string());
+    let launch_id = launch.id.clone();
+    let mut finished = Objective::new(OutcomeType::Work, "Launch website".to_string());
+    finished.status = ObjectiveStatus::Completed;
+    let health = Objective::new(OutcomeType::Health, "Product launch fitness".to_string());
+    let data = ObjectivesData {
+        objectives: vec![finished, launch, health],
+        ..Default::default()
+    };
+    save_objectives(&data, &config).unwrap();
+
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+    app.focus_panel = FocusPanel::Actions;
+    (temp_dir, app, launch_id)
+}
+
+fn write_action(app: &mut App, text: &str) {
+    app.handle_key(KeyCode::Char('E')).unwrap();
+    for c in text.chars() {
+        app.handle_key(KeyCode::Char(c)).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+}
+
+#[test]
+fn new_action_offers_matching_objective_and_y_links_it() {
+    let (_temp_dir, mut app, launch_id) = app_with_objectives();
+
+    write_action(&mut app, "Draft product launch email");
+    let Some(ModalState::LinkSuggestion(suggestion)) = app.modal.clone() else {
+        panic!("expected a link suggestion");
+    };
+    assert_eq!(suggestion.objective_id, launch_id);
+    assert_eq!(suggestion.title, "Product launch");
+
+    app.handle_key(KeyCode::Char('y')).unwrap();
+    assert!(app.modal.is_none());
+    let saved = load_or_create_goals(app.goals.date, &app.config).unwrap();
+    for goals in [&app.goals, &saved] {
+        assert_eq!(
+            goals.work.actions[0].get_all_objective_ids(),
+            [launch_id.as_str()]
+        );
+    }
+
+    // Linked actions are not asked about again
+    assert!(app.suggest_objective(OutcomeType::Work, 0).is_none());
+}
+
+#[test]
+fn suggestions_are_skipped_when_declined_or_unmatched() {
+    let (_temp_dir, mut app, _) = app_with_objectives();
+
+    write_action(&mut app, "Buy groceries");
+    assert!(app.modal.is_none());
+
+    app.handle_key(KeyCode::Down).unwrap();
+    write_action(&mut app, "Launching the product beta");
+    assert!(matches!(app.modal, Some(ModalState::LinkSuggestion(_))));
+    app.handle_key(KeyCode::Char('n')).unwrap();
+    assert!(app.modal.is_none());
+    assert!(app.goals.work.actions[1].get_all_objective_ids().is_empty());
+
+    // Editing an existing action does not prompt
+    write_action(&mut app, " v2");
+    assert!(app.modal.is_none());
+}
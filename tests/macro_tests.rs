@@ -0,0 +1,152 @@
+mod common;
+
+use common::temp_config;
+use crossterm::event::{KeyCode, KeyEvent};
+use focusfive::models::{Action, Config};
+use focusfive::ui::keymap::{KeyAction, Keymap, Mode};
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+/// An app on the work actions "One", "Two", "Three"
+fn app_with_actions(config: Config) -> App {
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+    for (i, text) in ["One", "Two", "Three"].iter().enumerate() {
+        app.goals.work.actions[i] = Action::new(text.to_string());
+    }
+    press(&mut app, &[KeyCode::Tab]);
+    app
+}
+
+fn press(app: &mut App, keys: &[KeyCode]) {
+    for key in keys {
+        assert!(!app.handle_key_event(KeyEvent::from(*key)).unwrap());
+    }
+}
+
+fn last_toast(app: &App) -> String {
+    app.toasts.history().back().unwrap().message.clone()
+}
+
+fn status_bar(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(160, 40)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    let rows: Vec<String> = buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect();
+    rows[rows.len() - 2..].join("\n")
+}
+
+#[test]
+fn a_recorded_flow_replays_on_the_next_action() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = app_with_actions(config);
+
+    press(&mut app, &[KeyCode::Char('r'), KeyCode::Char('a')]);
+    assert_eq!(app.macros.recording(), Some('a'));
+    assert!(status_bar(&mut app).contains("REC @a"));
+    press(&mut app, &[KeyCode::Char(' '), KeyCode::Char('j')]);
+    press(&mut app, &[KeyCode::Char('r')]);
+    assert_eq!(app.macros.recording(), None);
+    assert_eq!(last_toast(&app), "Recorded 2 keys into @a");
+    assert!(!status_bar(&mut app).contains("REC"));
+    assert!(app.goals.work.actions[0].completed);
+    assert_eq!(app.selected_action, 1);
+
+    press(&mut app, &[KeyCode::Char('@'), KeyCode::Char('a')]);
+    assert!(app.goals.work.actions[1].completed);
+    assert_eq!(app.selected_action, 2);
+    // `@@` replays the last register again
+    press(&mut app, &[KeyCode::Char('@'), KeyCode::Char('@')]);
+    assert!(app.goals.work.actions[2].completed);
+}
+
+#[test]
+fn text_typed_into_the_editor_is_replayed() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = app_with_actions(config);
+
+    press(&mut app, &[KeyCode::Char('r'), KeyCode::Char('b')]);
+    press(
+        &mut app,
+        &[
+            KeyCode::Char('E'),
+            KeyCode::Char('!'),
+            KeyCode::Enter,
+            KeyCode::Char('j'),
+        ],
+    );
+    // `r` in the editor would be text; here it stops the recording
+    press(&mut app, &[KeyCode::Char('r')]);
+    assert_eq!(app.goals.work.actions[0].text, "One!");
+
+    press(&mut app, &[KeyCode::Char('@'), KeyCode::Char('b')]);
+    assert_eq!(app.goals.work.actions[1].text, "Two!");
+    assert_eq!(app.selected_action, 2);
+}
+
+#[test]
+fn empty_and_invalid_registers_warn() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = app_with_actions(config);
+
+    press(&mut app, &[KeyCode::Char('@'), KeyCode::Char('z')]);
+    assert_eq!(last_toast(&app), "Nothing recorded in @z");
+    press(&mut app, &[KeyCode::Char('@'), KeyCode::Char('@')]);
+    assert_eq!(last_toast(&app), "No macro replayed yet");
+    press(&mut app, &[KeyCode::Char('r'), KeyCode::Char('1')]);
+    assert_eq!(last_toast(&app), "Macro registers are the letters a-z");
+    assert_eq!(app.macros.recording(), None);
+    // The key after `r` names the register, even one bound to an action
+    press(&mut app, &[KeyCode::Char('r'), KeyCode::Char('j')]);
+    assert_eq!(app.macros.recording(), Some('j'));
+    assert_eq!(app.selected_action, 0);
+}
+
+#[test]
+fn a_macro_that_replays_itself_stops() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = app_with_actions(config);
+
+    press(&mut app, &[KeyCode::Char('r'), KeyCode::Char('c')]);
+    press(
+        &mut app,
+        &[KeyCode::Char('j'), KeyCode::Char('@'), KeyCode::Char('c')],
+    );
+    press(&mut app, &[KeyCode::Char('r')]);
+    assert_eq!(last_toast(&app), "Recorded 3 keys into @c");
+
+    let stops = |app: &App| {
+        app.toasts
+            .history()
+            .iter()
+            .filter(|toast| toast.message == "Macro stopped: it keeps replaying itself")
+            .count()
+    };
+    press(&mut app, &[KeyCode::Char('@'), KeyCode::Char('c')]);
+    assert_eq!(stops(&app), 1);
+    assert_eq!(app.macros.depth, 0);
+    // Later replays run again
+    app.toasts.clear();
+    press(&mut app, &[KeyCode::Char('@'), KeyCode::Char('c')]);
+    assert_eq!(stops(&app), 2);
+}
+
+#[test]
+fn macro_keys_are_in_every_mode() {
+    let keymap = Keymap::default();
+    for mode in [Mode::Goals, Mode::Focus, Mode::Dashboard] {
+        assert_eq!(
+            keymap.action(mode, KeyCode::Char('r')),
+            Some(KeyAction::RecordMacro)
+        );
+        assert_eq!(
+            keymap.action(mode, KeyCode::Char('@')),
+            Some(KeyAction::ReplayMacro)
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use chrono::NaiveDate;
+use focusfive::data::{load_or_create_goals, read_observations_range, save_indicators};
+use focusfive::mcp::{serve, PROTOCOL_VERSION};
+use focusfive::models::{
+    Config, IndicatorDef, IndicatorKind, IndicatorUnit, IndicatorsData, ObservationSource,
+};
+use serde_json::{json, Value};
+use std::fs;
+use std::io::Cursor;
+use tempfile::TempDir;
+
+fn test_config(temp_dir: &TempDir) -> Config {
+    let goals_dir = temp_dir.path().join("goals");
+    fs::create_dir_all(&goals_dir).unwrap();
+    Config {
+        goals_dir: goals_dir.to_str().unwrap().to_string(),
+        data_root: temp_dir.path().to_str().unwrap().to_string(),
+    }
+}
+
+fn run_session(messages: &[Value], config: &Config) -> Vec<Value> {
+    let input: String = messages.iter().map(|m| format!("{}\n", m)).collect();
+    let mut output = Vec::new();
+    serve(Cursor::new(input), &mut output, config).unwrap();
+    String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+fn call(id: u64, name: &str, arguments: Value) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "method": "tools/call",
+        "params": { "name": name, "arguments": arguments },
+    })
+}
+
+fn tool_text(response: &Value) -> &str {
+    response["result"]["content"][0]["text"].as_str().unwrap()
+}
+
+#[test]
+fn handshake_lists_tools_and_skips_notifications() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = test_config(&temp_dir);
+
+    let responses = run_session(
+        &[
+            json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}}),
+            json!({"jsonrpc": "2.0", "method": "notifications/initialized"}),
+            json!({"jsonrpc": "2.0", "id": 2, "method": "tools/list"}),
+            json!({"jsonrpc": "2.0", "id": 3, "method": "resources/list"}),
+        ],
+        &config,
+    );
+
+    assert_eq!(responses.len(), 3);
+    assert_eq!(responses[0]["result"]["protocolVersion"], PROTOCOL_VERSION);
+    let names: Vec<&str> = responses[1]["result"]["tools"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|tool| tool["name"].as_str().unwrap())
+        .collect();
+    assert!(names.contains(&"get_plan"));
+    assert!(names.contains(&"append_action"));
+    assert!(names.contains(&"log_observation"));
+    assert_eq!(responses[2]["error"]["code"], -32601);
+}
+
+#[test]
+fn tools_read_and_write_through_data_layer() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = test_config(&temp_dir);
+    let indicators = IndicatorsData {
+        version: 1,
+        indicators: vec![IndicatorDef::new(
+            "Pushups".to_string(),
+            IndicatorKind::Leading,
+            IndicatorUnit::Count,
+        )],
+    };
+    save_indicators(&indicators, &config).unwrap();
+
+    let responses = run_session(
+        &[
+            call(
+                1,
+                "append_action",
+                json!({"outcome": "health", "text": "Stretch", "date": "2025-06-02"}),
+            ),
+            call(2, "get_plan", json!({"date": "2025-06-02"})),
+            call(
+                3,
+                "log_observation",
+                json!({"indicator": "pushups", "value": 40, "date": "2025-06-02"}),
+            ),
+            call(
+                4,
+                "append_action",
+                json!({"outcome": "chores", "text": "x"}),
+            ),
+        ],
+        &config,
+    );
+
+    assert_eq!(responses[0]["result"]["isError"], false);
+    let plan: Value = serde_json::from_str(tool_text(&responses[1])).unwrap();
+    assert_eq!(plan["outcomes"][1]["actions"][0]["text"], "Stretch");
+    assert_eq!(responses[2]["result"]["isError"], false);
+    assert_eq!(responses[3]["result"]["isError"], true);
+    assert!(tool_text(&responses[3]).contains("Unknown outcome"));
+
+    let date = NaiveDate::from_ymd_opt(2025, 6, 2).unwrap();
+    let goals = load_or_create_goals(date, &config).unwrap();
+    assert_eq!(goals.health.actions[0].text, "Stretch");
+
+    let observations = read_observations_range(date, date, &config).unwrap();
+    assert_eq!(observations.len(), 1);
+    assert_eq!(observations[0].value, 40.0);
+    assert_eq!(observations[0].source, ObservationSource::Automated);
+}
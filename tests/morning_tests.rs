@@ -0,0 +1,157 @@
+mod common;
+
+use chrono::{Local, NaiveDate};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{load_or_create_day_meta, load_or_create_goals, write_goals_file};
+use focusfive::models::{Config, DailyGoals, OutcomeType};
+use focusfive::morning::{self, Choice, MorningPlan, TOP_PRIORITY};
+use focusfive::ui::app::{ModalState, MorningStep};
+use focusfive::ui::App;
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 6, day).unwrap()
+}
+
+fn today_goals(date: NaiveDate) -> DailyGoals {
+    let mut goals = DailyGoals::new(date);
+    goals.work.actions[0].text = "Answer email".to_string();
+    goals.work.actions[0].completed = true;
+    goals.work.actions[1].text = "Write proposal".to_string();
+    goals.health.actions[0].text = "Run".to_string();
+    goals
+}
+
+fn priorities(goals: &DailyGoals, config: &Config, outcome_type: OutcomeType) -> Vec<bool> {
+    load_or_create_day_meta(goals.date, goals, config)
+        .unwrap()
+        .outcome(outcome_type)
+        .iter()
+        .map(|meta| meta.priority == Some(TOP_PRIORITY))
+        .collect()
+}
+
+#[test]
+fn plan_reviews_yesterday_and_suggests_unfinished_actions() {
+    let (_temp_dir, config) = temp_config();
+    let mut yesterday = DailyGoals::new(date(9));
+    yesterday.work.actions[0].text = "Ship release".to_string();
+    yesterday.work.actions[0].completed = true;
+    yesterday.work.actions[1].text = "Fix flaky test".to_string();
+    write_goals_file(&yesterday, &config).unwrap();
+    let goals = today_goals(date(10));
+    write_goals_file(&goals, &config).unwrap();
+
+    let plan = MorningPlan::start(&goals, &config).unwrap();
+    let review = plan.yesterday.as_ref().unwrap();
+    assert_eq!(review[0].outcome_type, OutcomeType::Work);
+    assert_eq!((review[0].completed, review[0].planned), (1, 2));
+    assert_eq!(review[0].unfinished, ["Fix flaky test"]);
+    assert_eq!(review[1].planned, 0);
+
+    // The first unfinished action is proposed, and empty outcomes get none
+    assert_eq!(plan.intentions[0].choice, Some(Choice::Existing(1)));
+    assert_eq!(plan.intentions[0].chosen_text(), Some("Write proposal"));
+    assert_eq!(plan.intentions[1].choice, Some(Choice::Existing(0)));
+    assert_eq!(plan.intentions[2].choice, None);
+    assert!(plan.intentions[2].actions.is_empty());
+
+    let first_day = MorningPlan::start(&DailyGoals::new(date(1)), &config).unwrap();
+    assert_eq!(first_day.yesterday, None);
+}
+
+#[test]
+fn apply_writes_priorities_estimates_and_new_actions() {
+    let (_temp_dir, config) = temp_config();
+    let mut goals = today_goals(date(10));
+    write_goals_file(&goals, &config).unwrap();
+    assert!(!morning::is_planned(&goals, &config).unwrap());
+
+    let mut plan = MorningPlan::start(&goals, &config).unwrap();
+    plan.intentions[0].estimate_min = Some(90);
+    plan.intentions[1].choice = None;
+    plan.intentions[2].choice = Some(Choice::New("Call grandma".to_string()));
+    plan.intentions[2].estimate_min = Some(20);
+    assert_eq!(plan.total_estimate(), 110);
+
+    assert_eq!(morning::apply(&plan, &mut goals, &config).unwrap(), 2);
+    assert!(morning::is_planned(&goals, &config).unwrap());
+
+    let saved = load_or_create_goals(date(10), &config).unwrap();
+    assert_eq!(saved.family.actions[0].text, "Call grandma");
+    assert_eq!(
+        priorities(&saved, &config, OutcomeType::Work),
+        [false, true, false]
+    );
+    assert_eq!(
+        priorities(&saved, &config, OutcomeType::Family),
+        [true, false, false]
+    );
+    let meta = load_or_create_day_meta(date(10), &saved, &config).unwrap();
+    assert_eq!(meta.work[1].estimated_min, Some(90));
+    assert_eq!(meta.family[0].estimated_min, Some(20));
+
+    // A later plan starts from the saved choice and moves the priority
+    let mut plan = MorningPlan::start(&saved, &config).unwrap();
+    assert_eq!(plan.intentions[0].choice, Some(Choice::Existing(1)));
+    assert_eq!(plan.intentions[0].estimate_min, Some(90));
+    plan.intentions[0].choice = Some(Choice::Existing(0));
+    let mut goals = saved;
+    morning::apply(&plan, &mut goals, &config).unwrap();
+    assert_eq!(
+        priorities(&goals, &config, OutcomeType::Work),
+        [true, false, false]
+    );
+}
+
+#[test]
+fn wizard_walks_through_the_steps_and_saves_once() {
+    let (_temp_dir, config) = temp_config();
+    let today = Local::now().date_naive();
+    write_goals_file(&today_goals(today), &config).unwrap();
+    let mut app = App::new(config.clone()).unwrap();
+
+    app.handle_key(KeyCode::Char('I')).unwrap();
+    let step = |app: &App| match &app.modal {
+        Some(ModalState::Morning(state)) => Some(state.step),
+        _ => None,
+    };
+    assert_eq!(step(&app), Some(MorningStep::Review));
+
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert_eq!(step(&app), Some(MorningStep::Priority(0)));
+    // Work: pick "Answer email" instead of the proposed action
+    app.handle_key(KeyCode::Enter).unwrap();
+    // Health: no priority today
+    app.handle_key(KeyCode::Char('x')).unwrap();
+    app.handle_key(KeyCode::Char('l')).unwrap();
+    // Family: type a new action
+    app.handle_key(KeyCode::Char('n')).unwrap();
+    assert!(app.text_editor.is_active);
+    for c in "Board games".chars() {
+        app.handle_key(KeyCode::Char(c)).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+    app.handle_key(KeyCode::Char('l')).unwrap();
+    assert_eq!(step(&app), Some(MorningStep::Estimates));
+
+    app.handle_key(KeyCode::Char('+')).unwrap();
+    app.handle_key(KeyCode::Char('+')).unwrap();
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert_eq!(step(&app), Some(MorningStep::Confirm));
+
+    // Nothing is written before the confirmation
+    assert!(!morning::is_planned(&app.goals, &config).unwrap());
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert!(app.modal.is_none());
+
+    let saved = load_or_create_goals(today, &config).unwrap();
+    assert_eq!(saved.family.actions[0].text, "Board games");
+    assert_eq!(
+        priorities(&saved, &config, OutcomeType::Work),
+        [true, false, false]
+    );
+    assert!(!priorities(&saved, &config, OutcomeType::Health).contains(&true));
+    let meta = load_or_create_day_meta(today, &saved, &config).unwrap();
+    assert_eq!(meta.work[0].estimated_min, Some(30));
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use chrono::Local;
+use common::temp_config;
+use focusfive::data::save_settings;
+use focusfive::models::{Config, OutcomeType, Settings};
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use std::fs;
+
+fn screen(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Work half done, Health planned but untouched, Family with nothing planned
+fn app_with_evening(config: &Config, settings: &Settings) -> App {
+    save_settings(settings, config).unwrap();
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    app.goals.work.actions[0].text = "Write report".to_string();
+    app.goals.work.actions[0].completed = true;
+    app.goals.work.actions[1].text = "Review PR".to_string();
+    app.goals.health.actions[0].text = "Run".to_string();
+    app.statistics.refresh_today(&app.goals);
+    app
+}
+
+#[test]
+fn untouched_outcomes_are_at_risk_after_the_nudge_hour() {
+    let (_temp_dir, config) = temp_config();
+    let app = app_with_evening(&config, &Settings::default());
+    let today = Local::now().date_naive();
+
+    let afternoon = today.and_hms_opt(18, 59, 0).unwrap();
+    assert!(app.outcomes_at_risk(afternoon).is_empty());
+    let evening = today.and_hms_opt(19, 0, 0).unwrap();
+    assert_eq!(app.outcomes_at_risk(evening), [OutcomeType::Health]);
+    // Only the day on screen being today counts
+    let tomorrow = evening + chrono::Duration::days(1);
+    assert!(app.outcomes_at_risk(tomorrow).is_empty());
+}
+
+#[test]
+fn banner_names_the_outcomes_at_risk() {
+    let (_temp_dir, config) = temp_config();
+    let mut settings = Settings::default();
+    settings.nudge.hour = 0;
+    let mut app = app_with_evening(&config, &settings);
+    app.toasts.clear();
+    assert!(screen(&mut app).contains("Streak at risk: Health has nothing done yet today"));
+
+    app.goals.health.actions[0].completed = true;
+    app.statistics.refresh_today(&app.goals);
+    assert!(!screen(&mut app).contains("Streak at risk"));
+}
+
+#[test]
+fn notify_emits_one_event_a_day() {
+    let (temp_dir, config) = temp_config();
+    let events_file = temp_dir.path().join("events.ndjson");
+    let mut settings = Settings::default();
+    settings.nudge.hour = 0;
+    settings.nudge.notify = true;
+    settings.events.file = Some(events_file.to_str().unwrap().to_string());
+    let mut app = app_with_evening(&config, &settings);
+    app.tick();
+    app.tick();
+    drop(app);
+
+    let events = fs::read_to_string(&events_file).unwrap();
+    let lines: Vec<&str> = events
+        .lines()
+        .filter(|line| line.contains("streak_at_risk"))
+        .collect();
+    assert_eq!(lines.len(), 1);
+    assert!(lines[0].contains("\"outcomes\":[\"Health\"]"));
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use chrono::{Duration, NaiveDate};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{
+    load_or_create_objectives, objective_minutes_for_week, save_day_meta, save_objectives,
+    write_goals_file,
+};
+use focusfive::models::{
+    format_minutes, parse_minutes, Config, DailyGoals, DayMeta, Objective, ObjectivesData,
+    OutcomeType,
+};
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+
+/// Write a day where the first work action is linked to `objective_id` and took `minutes`
+fn log_effort(date: NaiveDate, objective_id: &str, minutes: u32, config: &Config) {
+    let mut goals = DailyGoals::new(date);
+    goals.work.actions[0].text = "Draft chapter".to_string();
+    goals.work.actions[0].objective_ids = vec![objective_id.to_string()];
+    goals.work.actions[1].text = "Unlinked".to_string();
+    write_goals_file(&goals, config).unwrap();
+
+    let mut meta = DayMeta::from_goals(&goals);
+    meta.work[0].actual_min = Some(minutes);
+    meta.work[1].actual_min = Some(500);
+    save_day_meta(date, &meta, config).unwrap();
+}
+
+#[test]
+fn parses_and_formats_effort() {
+    assert_eq!(parse_minutes("90"), Some(90));
+    assert_eq!(parse_minutes("45m"), Some(45));
+    assert_eq!(parse_minutes("2h"), Some(120));
+    assert_eq!(parse_minutes(" 1h30m "), Some(90));
+    assert_eq!(parse_minutes("1.5h"), Some(90));
+    assert_eq!(parse_minutes("h"), None);
+    assert_eq!(parse_minutes("-1h"), None);
+    assert_eq!(parse_minutes("soon"), None);
+
+    assert_eq!(format_minutes(45), "45m");
+    assert_eq!(format_minutes(120), "2h");
+    assert_eq!(format_minutes(95), "1h35m");
+}
+
+#[test]
+fn weekly_minutes_sum_linked_actions_within_the_week() {
+    let (_temp_dir, config) = temp_config();
+    let objective_id = "book-objective";
+
+    // Wednesday 2025-03-12; the week runs Monday 10th to Sunday 16th
+    let wednesday = NaiveDate::from_ymd_opt(2025, 3, 12).unwrap();
+    log_effort(wednesday - Duration::days(2), objective_id, 60, &config);
+    log_effort(wednesday + Duration::days(4), objective_id, 45, &config);
+    // Previous Sunday belongs to last week
+    log_effort(wednesday - Duration::days(3), objective_id, 30, &config);
+
+    let totals = objective_minutes_for_week(wednesday, &config).unwrap();
+    assert_eq!(totals.get(objective_id), Some(&105));
+    assert_eq!(totals.len(), 1);
+
+    let empty = objective_minutes_for_week(wednesday + Duration::days(7), &config).unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn picker_sets_and_clears_weekly_budget() {
+    let (_temp_dir, config) = temp_config();
+    let objective = Objective::new(OutcomeType::Work, "Write book".to_string());
+    save_objectives(
+        &ObjectivesData {
+            version: 1,
+            objectives: vec![objective],
+        },
+        &config,
+    )
+    .unwrap();
+
+    let mut app = App::new(config.clone()).unwrap();
+    app.handle_key(KeyCode::Char('o')).unwrap();
+    assert!(matches!(app.modal, Some(ModalState::ObjectivePicker(_))));
+
+    app.handle_key(KeyCode::Char('b')).unwrap();
+    assert!(app.text_editor.is_active);
+    for ch in "4h30m".chars() {
+        app.handle_key(KeyCode::Char(ch)).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert!(!app.text_editor.is_active);
+    let stored = load_or_create_objectives(&config).unwrap();
+    assert_eq!(stored.objectives[0].weekly_budget_min, Some(270));
+
+    // The editor opens with the current budget; clearing it removes the budget
+    app.handle_key(KeyCode::Char('b')).unwrap();
+    assert_eq!(app.text_editor.text, "4h30m");
+    for _ in 0..5 {
+        app.handle_key(KeyCode::Backspace).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+    let stored = load_or_create_objectives(&config).unwrap();
+    assert_eq!(stored.objectives[0].weekly_budget_min, None);
+}
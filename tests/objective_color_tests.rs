@@ -0,0 +1,80 @@
+use crossterm::event::KeyCode;
+use focusfive::data::{load_or_create_objectives, save_objectives};
+use focusfive::models::{Config, Objective, ObjectivesData, OutcomeType};
+use focusfive::ui::app::FocusPanel;
+use focusfive::ui::theme::{next_objective_color, objective_accent, OBJECTIVE_PALETTE};
+use focusfive::ui::App;
+use ratatui::style::Color;
+use std::fs;
+use tempfile::TempDir;
+
+fn test_config(temp_dir: &TempDir) -> Config {
+    let goals_dir = temp_dir.path().join("goals");
+    fs::create_dir_all(&goals_dir).unwrap();
+    Config {
+        goals_dir: goals_dir.to_str().unwrap().to_string(),
+        data_root: temp_dir.path().to_str().unwrap().to_string(),
+    }
+}
+
+#[test]
+fn accents_parse_and_cycle_through_palette() {
+    assert_eq!(
+        objective_accent(Some("#ff0000")),
+        Some(Color::Rgb(255, 0, 0))
+    );
+    assert_eq!(objective_accent(Some("magenta")), Some(Color::Magenta));
+    assert_eq!(objective_accent(Some("not a color")), None);
+    assert_eq!(objective_accent(None), None);
+
+    assert_eq!(next_objective_color(None), OBJECTIVE_PALETTE[0]);
+    assert_eq!(
+        next_objective_color(Some(OBJECTIVE_PALETTE[0])),
+        OBJECTIVE_PALETTE[1]
+    );
+    let last = OBJECTIVE_PALETTE[OBJECTIVE_PALETTE.len() - 1];
+    assert_eq!(next_objective_color(Some(last)), OBJECTIVE_PALETTE[0]);
+}
+
+#[test]
+fn objectives_without_color_still_load() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = test_config(&temp_dir);
+
+    let data = ObjectivesData {
+        version: 1,
+        objectives: vec![Objective::new(OutcomeType::Work, "Legacy".to_string())],
+    };
+    save_objectives(&data, &config).unwrap();
+
+    let raw = fs::read_to_string(temp_dir.path().join("objectives.json")).unwrap();
+    assert!(!raw.contains("\"color\""));
+    let loaded = load_or_create_objectives(&config).unwrap();
+    assert_eq!(loaded.objectives[0].color, None);
+}
+
+#[test]
+fn picker_c_cycles_and_persists_objective_color() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = test_config(&temp_dir);
+
+    let mut objective = Objective::new(OutcomeType::Work, "Launch".to_string());
+    objective.color = Some(OBJECTIVE_PALETTE[2].to_string());
+    let data = ObjectivesData {
+        version: 1,
+        objectives: vec![objective],
+    };
+    save_objectives(&data, &config).unwrap();
+
+    let mut app = App::new(config.clone()).unwrap();
+    app.focus_panel = FocusPanel::Actions;
+    app.handle_key(KeyCode::Char('o')).unwrap();
+    app.handle_key(KeyCode::Char('c')).unwrap();
+    app.handle_key(KeyCode::Esc).unwrap();
+
+    let loaded = load_or_create_objectives(&config).unwrap();
+    assert_eq!(
+        loaded.objectives[0].color.as_deref(),
+        Some(OBJECTIVE_PALETTE[3])
+    );
+}
@@ -0,0 +1,160 @@
+mod common;
+
+use chrono::{Duration, NaiveDate};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{load_or_create_objectives, save_objectives};
+use focusfive::forecast::behind_pace;
+use focusfive::models::{
+    IndicatorDef, IndicatorKind, IndicatorUnit, Objective, ObjectiveStatus, ObjectivesData,
+    Observation, OutcomeType,
+};
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+}
+
+/// An indicator with `target` observed on consecutive days from March 1st
+fn indicator_with(
+    name: &str,
+    target: f64,
+    values: &[f64],
+    observations: &mut Vec<Observation>,
+) -> IndicatorDef {
+    let mut def = IndicatorDef::new(
+        name.to_string(),
+        IndicatorKind::Lagging,
+        IndicatorUnit::Count,
+    );
+    def.target = Some(target);
+    for (day, value) in values.iter().enumerate() {
+        observations.push(Observation::new(
+            def.id.clone(),
+            date(1) + Duration::days(day as i64),
+            *value,
+            IndicatorUnit::Count,
+        ));
+    }
+    def
+}
+
+#[test]
+fn countdown_counts_days_to_the_target_date() {
+    let mut objective = Objective::new(OutcomeType::Health, "Run a marathon".to_string());
+    assert_eq!(objective.countdown(date(1)), None);
+
+    objective.end = Some(date(20));
+    assert_eq!(objective.days_left(date(1)), Some(19));
+    assert_eq!(
+        objective.countdown(date(1)).as_deref(),
+        Some("19 days left")
+    );
+    assert_eq!(objective.countdown(date(19)).as_deref(), Some("1 day left"));
+    assert_eq!(objective.countdown(date(20)).as_deref(), Some("due today"));
+    assert_eq!(
+        objective.countdown(date(23)).as_deref(),
+        Some("3 days overdue")
+    );
+
+    // Finished objectives no longer count down
+    objective.status = ObjectiveStatus::Completed;
+    assert_eq!(objective.countdown(date(1)), None);
+}
+
+#[test]
+fn flags_indicators_trending_past_the_target_date() {
+    let mut objective = Objective::new(OutcomeType::Work, "Ship the book".to_string());
+    let mut observations = Vec::new();
+    // One page a day toward 100 by March 20th won't make it
+    let slow = indicator_with("Pages", 100.0, &[1.0, 2.0, 3.0, 4.0], &mut observations);
+    // Twenty a day toward 200 lands on March 11th
+    let fast = indicator_with("Chapters", 200.0, &[0.0, 20.0, 40.0], &mut observations);
+    // Already at target
+    let done = indicator_with("Outline", 5.0, &[3.0, 6.0], &mut observations);
+    // A single observation has no trend
+    let fresh = indicator_with("Reviews", 10.0, &[1.0], &mut observations);
+    objective.indicators = vec![
+        slow.id.clone(),
+        fast.id.clone(),
+        done.id.clone(),
+        fresh.id.clone(),
+    ];
+    let indicators = vec![slow, fast, done, fresh];
+
+    // Open-ended objectives have no pace to keep
+    assert!(behind_pace(&objective, &indicators, &observations).is_empty());
+
+    objective.end = Some(date(20));
+    let behind: Vec<&str> = behind_pace(&objective, &indicators, &observations)
+        .iter()
+        .map(|def| def.name.as_str())
+        .collect();
+    assert_eq!(behind, vec!["Pages"]);
+
+    objective.status = ObjectiveStatus::Paused;
+    assert!(behind_pace(&objective, &indicators, &observations).is_empty());
+}
+
+#[test]
+fn picker_sets_and_clears_target_dates() {
+    let (_temp_dir, config) = temp_config();
+    let objective = Objective::new(OutcomeType::Work, "Write book".to_string());
+    save_objectives(
+        &ObjectivesData {
+            version: 1,
+            objectives: vec![objective],
+        },
+        &config,
+    )
+    .unwrap();
+
+    let mut app = App::new(config.clone()).unwrap();
+    app.handle_key(KeyCode::Char('o')).unwrap();
+    assert!(matches!(app.modal, Some(ModalState::ObjectivePicker(_))));
+
+    let type_and_enter = |app: &mut App, text: &str| {
+        app.handle_key(KeyCode::Char('t')).unwrap();
+        assert!(app.text_editor.is_active);
+        while !app.text_editor.text.is_empty() {
+            app.handle_key(KeyCode::Backspace).unwrap();
+        }
+        for ch in text.chars() {
+            app.handle_key(KeyCode::Char(ch)).unwrap();
+        }
+        app.handle_key(KeyCode::Enter).unwrap();
+    };
+
+    type_and_enter(&mut app, "2025-01-01..2025-06-30");
+    let stored = load_or_create_objectives(&config).unwrap();
+    assert_eq!(
+        stored.objectives[0].start,
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()
+    );
+    assert_eq!(
+        stored.objectives[0].end,
+        NaiveDate::from_ymd_opt(2025, 6, 30)
+    );
+
+    // A target before the start is refused
+    type_and_enter(&mut app, "2024-12-01");
+    let stored = load_or_create_objectives(&config).unwrap();
+    assert_eq!(
+        stored.objectives[0].end,
+        NaiveDate::from_ymd_opt(2025, 6, 30)
+    );
+
+    // The editor opens with the current dates; clearing them makes it open-ended
+    app.toasts.clear();
+    app.handle_key(KeyCode::Char('t')).unwrap();
+    assert_eq!(app.text_editor.text, "2025-01-01..2025-06-30");
+    app.handle_key(KeyCode::Esc).unwrap();
+    type_and_enter(&mut app, "");
+    let stored = load_or_create_objectives(&config).unwrap();
+    assert_eq!(stored.objectives[0].end, None);
+    assert_eq!(
+        stored.objectives[0].start,
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()
+    );
+}
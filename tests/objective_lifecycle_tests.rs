@@ -0,0 +1,126 @@
+mod common;
+
+use chrono::{Local, NaiveDate};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{load_or_create_objectives, save_objectives};
+use focusfive::models::{Config, Objective, ObjectiveStatus, ObjectivesData, OutcomeType};
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+
+fn save(objectives: Vec<Objective>, config: &Config) {
+    save_objectives(
+        &ObjectivesData {
+            version: 1,
+            objectives,
+        },
+        config,
+    )
+    .unwrap();
+}
+
+#[test]
+fn status_cycles_and_completion_archives() {
+    let mut objective = Objective::new(OutcomeType::Health, "Run a marathon".to_string());
+    objective.cycle_status();
+    assert_eq!(objective.status, ObjectiveStatus::Paused);
+    objective.cycle_status();
+    assert_eq!(objective.status, ObjectiveStatus::Dropped);
+    objective.cycle_status();
+    assert_eq!(objective.status, ObjectiveStatus::Active);
+
+    let day = NaiveDate::from_ymd_opt(2025, 10, 12).unwrap();
+    objective.complete(day);
+    assert_eq!(objective.status, ObjectiveStatus::Completed);
+    assert_eq!(objective.completed_on, Some(day));
+    assert!(objective.archived);
+
+    // Reopening brings it back out of the archive
+    objective.cycle_status();
+    assert_eq!(objective.status, ObjectiveStatus::Active);
+    assert_eq!(objective.completed_on, None);
+    assert!(!objective.archived);
+
+    // Unarchived objectives keep their stored form unchanged
+    let json = serde_json::to_string(&objective).unwrap();
+    assert!(!json.contains("archived"));
+    assert!(!json.contains("completed_on"));
+}
+
+#[test]
+fn picker_cycles_status() {
+    let (_temp_dir, config) = temp_config();
+    save(
+        vec![Objective::new(OutcomeType::Work, "Write book".to_string())],
+        &config,
+    );
+
+    let mut app = App::new(config.clone()).unwrap();
+    app.handle_key(KeyCode::Char('o')).unwrap();
+    app.handle_key(KeyCode::Char('s')).unwrap();
+    let stored = load_or_create_objectives(&config).unwrap();
+    assert_eq!(stored.objectives[0].status, ObjectiveStatus::Paused);
+    assert!(app.recent_activity[0]
+        .summary
+        .contains("Write book is now paused"));
+
+    app.handle_key(KeyCode::Char('s')).unwrap();
+    app.handle_key(KeyCode::Char('s')).unwrap();
+    let stored = load_or_create_objectives(&config).unwrap();
+    assert_eq!(stored.objectives[0].status, ObjectiveStatus::Active);
+}
+
+#[test]
+fn completing_from_the_picker_celebrates_and_archives() {
+    let (_temp_dir, config) = temp_config();
+    save(
+        vec![
+            Objective::new(OutcomeType::Work, "Write book".to_string()),
+            Objective::new(OutcomeType::Work, "Launch site".to_string()),
+        ],
+        &config,
+    );
+
+    let mut app = App::new(config.clone()).unwrap();
+    app.handle_key(KeyCode::Char('o')).unwrap();
+
+    // Esc on the celebration goes back to the picker without changes
+    app.handle_key(KeyCode::Char('x')).unwrap();
+    assert!(matches!(app.modal, Some(ModalState::ObjectiveComplete(_))));
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(matches!(app.modal, Some(ModalState::ObjectivePicker(_))));
+    let stored = load_or_create_objectives(&config).unwrap();
+    assert_eq!(stored.objectives[0].status, ObjectiveStatus::Active);
+
+    app.handle_key(KeyCode::Char('x')).unwrap();
+    app.handle_key(KeyCode::Enter).unwrap();
+    let stored = load_or_create_objectives(&config).unwrap();
+    let book = &stored.objectives[0];
+    assert_eq!(book.status, ObjectiveStatus::Completed);
+    assert_eq!(book.completed_on, Some(Local::now().date_naive()));
+    assert!(book.archived);
+    assert_eq!(
+        app.recent_activity[0].summary,
+        "Completed objective: Write book"
+    );
+
+    // The archived objective drops out of the picker until asked for
+    let Some(ModalState::ObjectivePicker(state)) = app.modal.clone() else {
+        panic!("expected the objective picker");
+    };
+    assert!(!state.show_archived);
+    assert!(!app.objectives.objectives[1].archived);
+
+    app.handle_key(KeyCode::Char('v')).unwrap();
+    let Some(ModalState::ObjectivePicker(state)) = app.modal.clone() else {
+        panic!("expected the objective picker");
+    };
+    assert!(state.show_archived);
+    assert_eq!(state.selection, 0);
+
+    // Reopening restores it
+    app.handle_key(KeyCode::Char('s')).unwrap();
+    let stored = load_or_create_objectives(&config).unwrap();
+    assert_eq!(stored.objectives[0].status, ObjectiveStatus::Active);
+    assert!(!stored.objectives[0].archived);
+}
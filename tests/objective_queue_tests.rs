@@ -0,0 +1,99 @@
+use chrono::NaiveDate;
+use crossterm::event::KeyCode;
+use focusfive::data::{load_or_create_objectives, save_objectives};
+use focusfive::models::{Config, Objective, ObjectiveKind, ObjectivesData, OutcomeType};
+use focusfive::ui::App;
+use std::fs;
+use tempfile::TempDir;
+
+fn reading_list() -> Objective {
+    let mut objective = Objective::new(OutcomeType::Work, "Reading list".to_string());
+    for title in ["Deep Work", "Range", "Atomic Habits"] {
+        objective.push_queue_item(title.to_string());
+    }
+    objective
+}
+
+#[test]
+fn queue_advances_in_order_and_rewinds_same_day() {
+    let monday = NaiveDate::from_ymd_opt(2025, 7, 7).unwrap();
+    let tuesday = NaiveDate::from_ymd_opt(2025, 7, 8).unwrap();
+    let mut objective = reading_list();
+    assert_eq!(objective.kind, ObjectiveKind::Queue);
+    assert_eq!(objective.queue_progress(), Some((0, 3)));
+
+    assert_eq!(
+        objective.advance_queue(monday).as_deref(),
+        Some("Deep Work")
+    );
+    assert_eq!(objective.advance_queue(tuesday).as_deref(), Some("Range"));
+    assert_eq!(objective.queue_progress(), Some((2, 3)));
+    assert_eq!(objective.next_queue_item().unwrap().title, "Atomic Habits");
+
+    // Only items finished on the given day are undone
+    assert_eq!(objective.rewind_queue(tuesday).as_deref(), Some("Range"));
+    assert_eq!(objective.rewind_queue(tuesday), None);
+    assert_eq!(objective.queue_progress(), Some((1, 3)));
+
+    let mut standard = Objective::new(OutcomeType::Health, "Run".to_string());
+    assert_eq!(standard.queue_progress(), None);
+    assert_eq!(standard.advance_queue(monday), None);
+}
+
+#[test]
+fn standard_objectives_serialize_without_queue_fields() {
+    let standard = Objective::new(OutcomeType::Family, "Call home".to_string());
+    let json = serde_json::to_value(&standard).unwrap();
+    assert!(json.get("kind").is_none());
+    assert!(json.get("queue").is_none());
+
+    let queue = serde_json::to_value(reading_list()).unwrap();
+    assert_eq!(queue["kind"], "Queue");
+    assert_eq!(queue["queue"][0]["title"], "Deep Work");
+}
+
+#[test]
+fn completing_linked_action_advances_queue() {
+    let temp_dir = TempDir::new().unwrap();
+    let goals_dir = temp_dir.path().join("goals");
+    fs::create_dir_all(&goals_dir).unwrap();
+    let config = Config {
+        goals_dir: goals_dir.to_str().unwrap().to_string(),
+        data_root: temp_dir.path().to_str().unwrap().to_string(),
+    };
+    let objective = reading_list();
+    let objective_id = objective.id.clone();
+    save_objectives(
+        &ObjectivesData {
+            version: 1,
+            objectives: vec![objective],
+        },
+        &config,
+    )
+    .unwrap();
+
+    let mut app = App::new(config.clone()).unwrap();
+    app.goals.work.actions[0].text = "Read for 30 minutes".to_string();
+    app.goals.work.actions[0].objective_ids = vec![objective_id];
+
+    app.handle_key(KeyCode::Tab).unwrap();
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    let stored = load_or_create_objectives(&config).unwrap();
+    assert_eq!(stored.objectives[0].queue_progress(), Some((1, 3)));
+
+    // Unchecking the action puts the item back
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    let stored = load_or_create_objectives(&config).unwrap();
+    assert_eq!(stored.objectives[0].queue_progress(), Some((0, 3)));
+
+    // `a` in the objective picker appends to the queue
+    app.handle_key(KeyCode::Char('o')).unwrap();
+    app.handle_key(KeyCode::Char('a')).unwrap();
+    for ch in "SICP".chars() {
+        app.handle_key(KeyCode::Char(ch)).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+    let stored = load_or_create_objectives(&config).unwrap();
+    assert_eq!(stored.objectives[0].queue_progress(), Some((0, 4)));
+    assert_eq!(stored.objectives[0].queue[3].title, "SICP");
+}
@@ -21,6 +21,12 @@ fn test_objective_serialization_deserialization() -> Result<()> {
                 created: chrono::Utc::now(),
                 modified: chrono::Utc::now(),
                 parent_id: None,
+                color: None,
+                weekly_budget_min: None,
+                kind: Default::default(),
+                queue: Vec::new(),
+                completed_on: None,
+                archived: false,
             },
             Objective {
                 id: "test-id-2".to_string(),
@@ -33,6 +39,12 @@ fn test_objective_serialization_deserialization() -> Result<()> {
                 created: chrono::Utc::now(),
                 modified: chrono::Utc::now(),
                 parent_id: Some("parent-id".to_string()),
+                color: None,
+                weekly_budget_min: None,
+                kind: Default::default(),
+                queue: Vec::new(),
+                completed_on: None,
+                archived: false,
             },
             Objective {
                 id: "test-id-3".to_string(),
@@ -45,6 +57,12 @@ fn test_objective_serialization_deserialization() -> Result<()> {
                 created: chrono::Utc::now(),
                 modified: chrono::Utc::now(),
                 parent_id: None,
+                color: None,
+                weekly_budget_min: None,
+                kind: Default::default(),
+                queue: Vec::new(),
+                completed_on: None,
+                archived: false,
             },
         ],
     };
@@ -54,6 +54,12 @@ fn test_objective_linking_and_unlinking() -> Result<()> {
         created: chrono::Utc::now(),
         modified: chrono::Utc::now(),
         parent_id: None,
+        color: None,
+        weekly_budget_min: None,
+        kind: Default::default(),
+        queue: Vec::new(),
+        completed_on: None,
+        archived: false,
     };
 
     app.objectives.objectives.push(objective);
@@ -95,6 +101,12 @@ fn test_objective_navigation_in_selector() -> Result<()> {
         created: chrono::Utc::now(),
         modified: chrono::Utc::now(),
         parent_id: None,
+        color: None,
+        weekly_budget_min: None,
+        kind: Default::default(),
+        queue: Vec::new(),
+        completed_on: None,
+        archived: false,
     };
 
     let obj2 = Objective {
@@ -108,6 +120,12 @@ fn test_objective_navigation_in_selector() -> Result<()> {
         created: chrono::Utc::now(),
         modified: chrono::Utc::now(),
         parent_id: None,
+        color: None,
+        weekly_budget_min: None,
+        kind: Default::default(),
+        queue: Vec::new(),
+        completed_on: None,
+        archived: false,
     };
 
     app.objectives.objectives.extend(vec![obj1, obj2]);
@@ -169,6 +187,12 @@ fn test_objective_domain_filtering() -> Result<()> {
         created: chrono::Utc::now(),
         modified: chrono::Utc::now(),
         parent_id: None,
+        color: None,
+        weekly_budget_min: None,
+        kind: Default::default(),
+        queue: Vec::new(),
+        completed_on: None,
+        archived: false,
     };
 
     let health_obj = Objective {
@@ -182,6 +206,12 @@ fn test_objective_domain_filtering() -> Result<()> {
         created: chrono::Utc::now(),
         modified: chrono::Utc::now(),
         parent_id: None,
+        color: None,
+        weekly_budget_min: None,
+        kind: Default::default(),
+        queue: Vec::new(),
+        completed_on: None,
+        archived: false,
     };
 
     app.objectives.objectives.extend(vec![work_obj, health_obj]);
@@ -0,0 +1,103 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use focusfive::data::{
+    append_observation, iter_observations_range, observation_index_path, read_observations_range,
+};
+use focusfive::models::{Config, IndicatorUnit, Observation};
+use std::fs;
+use tempfile::TempDir;
+
+fn day(d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 4, d).unwrap()
+}
+
+fn log(config: &Config, d: u32, value: f64) {
+    let obs = Observation::new("steps".to_string(), day(d), value, IndicatorUnit::Count);
+    append_observation(&obs, config).unwrap();
+}
+
+/// Copy the log and the given sidecar into a fresh data root
+fn copy_data(from: &Config, sidecar: &[u8]) -> (TempDir, Config) {
+    let (temp_dir, config) = temp_config();
+    fs::copy(
+        format!("{}/observations.ndjson", from.data_root),
+        format!("{}/observations.ndjson", config.data_root),
+    )
+    .unwrap();
+    fs::write(observation_index_path(&config), sidecar).unwrap();
+    (temp_dir, config)
+}
+
+#[test]
+fn streams_in_date_order_and_pages_without_parsing_everything() {
+    let (_temp_dir, config) = temp_config();
+    // Backfilled out of order
+    for (d, value) in [(3, 3.0), (1, 1.0), (5, 5.0), (2, 2.0), (3, 3.5), (9, 9.0)] {
+        log(&config, d, value);
+    }
+
+    let values = |observations: Vec<Observation>| -> Vec<f64> {
+        observations.iter().map(|obs| obs.value).collect()
+    };
+    let streamed: Vec<Observation> = iter_observations_range(day(1), day(5), &config)
+        .unwrap()
+        .collect::<anyhow::Result<_>>()
+        .unwrap();
+    assert_eq!(values(streamed), vec![1.0, 2.0, 3.0, 3.5, 5.0]);
+    // The Vec reader keeps the order observations were logged
+    let read = read_observations_range(day(1), day(5), &config).unwrap();
+    assert_eq!(values(read), vec![3.0, 1.0, 5.0, 2.0, 3.5]);
+
+    let iter = iter_observations_range(day(2), day(9), &config).unwrap();
+    assert_eq!(iter.len(), 5);
+    let page: Vec<f64> = iter.skip(2).take(2).map(|obs| obs.unwrap().value).collect();
+    assert_eq!(page, vec![3.5, 5.0]);
+
+    let mut iter = iter_observations_range(day(6), day(8), &config).unwrap();
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn sidecar_index_is_written_and_reused_only_when_it_matches() {
+    let (_temp_dir, config) = temp_config();
+    for d in 1..=4 {
+        log(&config, d, f64::from(d));
+    }
+    assert_eq!(
+        read_observations_range(day(2), day(3), &config)
+            .unwrap()
+            .len(),
+        2
+    );
+    let sidecar = fs::read(observation_index_path(&config)).unwrap();
+
+    // A matching sidecar serves a fresh data root, and new lines get indexed
+    let (_copy_dir, copy) = copy_data(&config, &sidecar);
+    log(&copy, 2, 20.0);
+    let values: Vec<f64> = read_observations_range(day(2), day(2), &copy)
+        .unwrap()
+        .iter()
+        .map(|obs| obs.value)
+        .collect();
+    assert_eq!(values, vec![2.0, 20.0]);
+
+    // A sidecar from some other log is ignored and rebuilt
+    let (_other_dir, other) = temp_config();
+    log(&other, 7, 7.0);
+    let (_stale_dir, stale) = copy_data(&other, &sidecar);
+    assert_eq!(
+        read_observations_range(day(1), day(9), &stale)
+            .unwrap()
+            .len(),
+        1
+    );
+    let (_garbage_dir, garbage) = copy_data(&config, b"not json");
+    assert_eq!(
+        read_observations_range(day(1), day(9), &garbage)
+            .unwrap()
+            .len(),
+        4
+    );
+}
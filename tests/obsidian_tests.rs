@@ -0,0 +1,144 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use focusfive::carryover::unfinished_items;
+use focusfive::data::{
+    generate_markdown, get_yesterday_goals, goals_file_path, load_or_create_goals, parse_markdown,
+    read_goals_file, save_objectives, save_settings, write_goals_file,
+};
+use focusfive::models::{
+    Config, DailyGoals, Objective, ObjectivesData, ObsidianSettings, OutcomeType, Settings,
+};
+use std::fs;
+use tempfile::TempDir;
+
+fn date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 3, 10).unwrap()
+}
+
+/// Obsidian mode on, with one objective
+fn obsidian_config() -> (TempDir, Config, Objective) {
+    let (temp_dir, config) = temp_config();
+    let settings = Settings {
+        obsidian: ObsidianSettings {
+            enabled: true,
+            vault_dir: None,
+        },
+        ..Settings::default()
+    };
+    save_settings(&settings, &config).unwrap();
+    let objective = Objective::new(OutcomeType::Work, "Ship v2".to_string());
+    save_objectives(
+        &ObjectivesData {
+            version: 1,
+            objectives: vec![objective.clone()],
+        },
+        &config,
+    )
+    .unwrap();
+    (temp_dir, config, objective)
+}
+
+#[test]
+fn notes_get_frontmatter_and_wiki_links() {
+    let (_temp_dir, config, objective) = obsidian_config();
+    let mut goals = DailyGoals::new(date());
+    goals.work.actions[0].text = "Cut the release".to_string();
+    goals.work.actions[0].add_objective_id(objective.id.clone());
+    let path = write_goals_file(&goals, &config).unwrap();
+
+    let note = fs::read_to_string(&path).unwrap();
+    assert!(note.starts_with("---\n"));
+    assert!(note.contains("date: 2025-03-10") || note.contains("date: '2025-03-10'"));
+    assert!(note.contains("- focusfive"));
+    assert!(note.contains(&format!("Ship v2: {}", objective.id)));
+    assert!(note.contains("- [ ] Cut the release\n  objective: [[Ship v2]]\n"));
+
+    let read = read_goals_file(&path).unwrap();
+    assert_eq!(read.date, date());
+    assert_eq!(
+        read.work.actions[0].get_all_objective_ids(),
+        vec![objective.id]
+    );
+}
+
+#[test]
+fn rewriting_a_vault_note_keeps_its_own_content() {
+    let (_temp_dir, config, _) = obsidian_config();
+    let path = goals_file_path(date(), &config);
+    fs::write(
+        &path,
+        "---\nmood: calm\ntags: [daily]\n---\nMorning pages.\n\n- [ ] Call mom #family\n- [x] Lift #health\n- [ ] Water plants\n\n## Reading\n- [ ] Finish chapter 3\n",
+    )
+    .unwrap();
+
+    let goals = load_or_create_goals(date(), &config).unwrap();
+    assert_eq!(goals.date, date());
+    assert_eq!(goals.family.actions[0].text, "Call mom");
+    assert_eq!(goals.health.actions[0].text, "Lift");
+    assert!(goals.health.actions[0].completed);
+    // Untagged tasks are not actions
+    assert!(goals.work.actions.iter().all(|a| a.text.is_empty()));
+
+    write_goals_file(&goals, &config).unwrap();
+    let note = fs::read_to_string(&path).unwrap();
+    assert!(note.contains("mood: calm"));
+    assert!(note.contains("- daily") && note.contains("- focusfive"));
+    assert!(note.contains("## Notes\nMorning pages."));
+    assert!(note.contains("- [ ] Water plants"));
+    assert!(note.contains("## Reading\n- [ ] Finish chapter 3"));
+    // Tagged tasks moved into their outcome sections
+    assert!(!note.contains("#family"));
+    assert!(note.contains("## Family\n- [ ] Call mom"));
+
+    let again = read_goals_file(&path).unwrap();
+    assert_eq!(generate_markdown(&again), generate_markdown(&goals));
+}
+
+#[test]
+fn notes_without_a_date_header_are_dated_by_name() {
+    let (_temp_dir, config, _) = obsidian_config();
+    let path = goals_file_path(date(), &config);
+    fs::write(&path, "## Work\n- [ ] Plan sprint\n").unwrap();
+    let goals = load_or_create_goals(date(), &config).unwrap();
+    assert_eq!(goals.date, date());
+    assert_eq!(goals.work.actions[0].text, "Plan sprint");
+    // Every other reader of the day dates it the same way
+    let tomorrow = date().succ_opt().unwrap();
+    let yesterday = get_yesterday_goals(tomorrow, &config).unwrap().unwrap();
+    assert_eq!(yesterday.work.actions[0].text, "Plan sprint");
+    assert_eq!(unfinished_items(date(), &config).unwrap().len(), 1);
+
+    let dated =
+        parse_markdown("---\ndate: 2025-04-01\nday: 3\n---\n## Health\n- [x] Run\n").unwrap();
+    assert_eq!(dated.date, NaiveDate::from_ymd_opt(2025, 4, 1).unwrap());
+    assert_eq!(dated.day_number, Some(3));
+    assert!(dated.health.actions[0].completed);
+}
+
+#[test]
+fn links_typed_in_obsidian_resolve_by_title() {
+    let (_temp_dir, config, objective) = obsidian_config();
+    let path = goals_file_path(date(), &config);
+    fs::write(
+        &path,
+        "# March 10, 2025\n\n## Work\n- [ ] Draft notes\n  objective: [[ship v2]]\n",
+    )
+    .unwrap();
+    let goals = load_or_create_goals(date(), &config).unwrap();
+    assert_eq!(
+        goals.work.actions[0].get_all_objective_ids(),
+        vec![objective.id]
+    );
+}
+
+#[test]
+fn other_sections_end_an_outcome() {
+    let goals = parse_markdown(
+        "# March 10, 2025\n\n## Family\n- [ ] Dinner\n\n## Someday\n- [ ] Learn piano\n",
+    )
+    .unwrap();
+    assert_eq!(goals.family.actions[0].text, "Dinner");
+    assert!(goals.family.actions[1].text.is_empty());
+}
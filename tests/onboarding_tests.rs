@@ -0,0 +1,141 @@
+use crossterm::event::KeyCode;
+use focusfive::models::{Config, IndicatorUnit, OutcomeType};
+use focusfive::onboarding::{self, Setup};
+use focusfive::ui::first_run::{Finish, FirstRunWizard, Step};
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use std::fs;
+use tempfile::TempDir;
+
+fn temp_config() -> (TempDir, Config) {
+    let temp_dir = TempDir::new().unwrap();
+    let goals_dir = temp_dir.path().join("goals");
+    let config = Config {
+        goals_dir: goals_dir.to_str().unwrap().to_string(),
+        data_root: temp_dir.path().to_str().unwrap().to_string(),
+    };
+    (temp_dir, config)
+}
+
+fn render(wizard: &FirstRunWizard) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+    terminal.draw(|frame| wizard.render(frame)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Type `text` and press Enter
+fn answer(wizard: &mut FirstRunWizard, text: &str) -> Option<Finish> {
+    for c in text.chars() {
+        assert!(wizard.handle_key(KeyCode::Char(c)).is_none());
+    }
+    wizard.handle_key(KeyCode::Enter)
+}
+
+#[test]
+fn an_empty_data_root_is_a_first_run() {
+    let (_temp_dir, config) = temp_config();
+    assert!(onboarding::is_first_run(&config));
+
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    drop(app);
+    assert!(!onboarding::is_first_run(&config));
+}
+
+#[test]
+fn the_wizard_seeds_a_new_data_directory() {
+    let (temp_dir, config) = temp_config();
+    let chosen = temp_dir.path().join("elsewhere");
+    let mut wizard = FirstRunWizard::new(&config);
+    assert!(render(&wizard).contains("Where should FocusFive keep your data?"));
+
+    // Replace the suggested directory
+    for _ in 0..config.data_root.chars().count() {
+        wizard.handle_key(KeyCode::Backspace);
+    }
+    assert!(answer(&mut wizard, chosen.to_str().unwrap()).is_none());
+    assert_eq!(wizard.step, Step::Goal(0));
+    answer(&mut wizard, "Ship the new website");
+    answer(&mut wizard, "Leading a small product team");
+    // Empty answers are skipped
+    answer(&mut wizard, "");
+    answer(&mut wizard, "");
+    answer(&mut wizard, "");
+    answer(&mut wizard, "Weekends together");
+    assert_eq!(wizard.step, Step::Objective);
+    wizard.handle_key(KeyCode::Tab);
+    assert_eq!(wizard.setup.objective_domain, OutcomeType::Health);
+    answer(&mut wizard, "Run a half marathon");
+    wizard.handle_key(KeyCode::Tab);
+    assert_eq!(wizard.setup.indicator_unit, IndicatorUnit::Minutes);
+    answer(&mut wizard, "Minutes running");
+
+    assert_eq!(wizard.step, Step::Review);
+    let screen = render(&wizard);
+    assert!(screen.contains("Ship the new website"));
+    assert!(screen.contains("Run a half marathon (Health)"));
+    assert!(screen.contains("Minutes running (minutes)"));
+
+    let Some(Finish::Setup(setup)) = wizard.handle_key(KeyCode::Enter) else {
+        panic!("review finishes the wizard");
+    };
+    let location = temp_dir.path().join("config").join("data_root");
+    let seeded = onboarding::apply(&setup, &config, Some(&location)).unwrap();
+    assert_eq!(seeded.data_root, chosen.to_str().unwrap());
+    assert_eq!(onboarding::saved_location(&location), Some(chosen.clone()));
+    assert!(!onboarding::is_first_run(&seeded));
+
+    let mut app = App::new(seeded).unwrap();
+    app.wait_for_background();
+    assert_eq!(app.goals.work.goal.as_deref(), Some("Ship the new website"));
+    assert_eq!(app.goals.health.goal, None);
+    assert_eq!(app.vision.work, "Leading a small product team");
+    assert_eq!(app.vision.family, "Weekends together");
+    let objective = &app.objectives.objectives[0];
+    assert_eq!(objective.title, "Run a half marathon");
+    assert_eq!(objective.domain, OutcomeType::Health);
+    let indicator = &app.indicators.indicators[0];
+    assert_eq!(indicator.name, "Minutes running");
+    assert_eq!(indicator.objective_id.as_ref(), Some(&objective.id));
+    assert_eq!(objective.indicators, vec![indicator.id.clone()]);
+}
+
+#[test]
+fn keeping_the_default_directory_writes_no_location() {
+    let (temp_dir, config) = temp_config();
+    let location = temp_dir.path().join("config").join("data_root");
+    let mut setup = Setup::new(&config);
+    setup.goals[1] = "Sleep by eleven".to_string();
+    let seeded = onboarding::apply(&setup, &config, Some(&location)).unwrap();
+    assert_eq!(seeded.data_root, config.data_root);
+    assert!(!location.exists());
+    assert!(fs::read_dir(&config.goals_dir).unwrap().next().is_some());
+}
+
+#[test]
+fn esc_skips_setup_and_goes_back_a_step() {
+    let (_temp_dir, config) = temp_config();
+    let mut wizard = FirstRunWizard::new(&config);
+    for _ in 0..config.data_root.chars().count() {
+        wizard.handle_key(KeyCode::Backspace);
+    }
+    assert!(wizard.handle_key(KeyCode::Enter).is_none());
+    assert_eq!(wizard.step, Step::DataDir);
+    assert!(render(&wizard).contains("Choose a directory for your data"));
+
+    answer(&mut wizard, "/tmp/focusfive-data");
+    assert_eq!(wizard.step, Step::Goal(0));
+    wizard.handle_key(KeyCode::Esc);
+    assert_eq!(wizard.step, Step::DataDir);
+    assert!(matches!(
+        wizard.handle_key(KeyCode::Esc),
+        Some(Finish::Skip)
+    ));
+}
@@ -0,0 +1,180 @@
+mod common;
+
+use common::temp_config;
+use focusfive::data::{
+    load_or_create_indicators, load_or_create_objectives, load_or_create_templates, save_objectives,
+};
+use focusfive::models::{
+    IndicatorDirection, IndicatorKind, IndicatorUnit, ObjectiveStatus, OutcomeType,
+};
+use focusfive::packs::{
+    export, import, read_pack, write_pack, ImportCounts, OnConflict, Pack, PackIndicator,
+    PackObjective,
+};
+
+fn marathon_pack() -> Pack {
+    Pack {
+        name: "Marathon".to_string(),
+        description: Some("16 weeks to race day".to_string()),
+        templates: [(
+            "Long run day".to_string(),
+            vec!["Long run".to_string(), "Stretch".to_string()],
+        )]
+        .into_iter()
+        .collect(),
+        objectives: vec![PackObjective {
+            id: "marathon".to_string(),
+            domain: OutcomeType::Health,
+            title: "Finish a marathon".to_string(),
+            description: None,
+            parent_id: None,
+            color: None,
+            weekly_budget_min: Some(300),
+        }],
+        indicators: vec![PackIndicator {
+            id: "weekly-km".to_string(),
+            name: "Weekly km".to_string(),
+            kind: IndicatorKind::Leading,
+            unit: IndicatorUnit::Custom("km".to_string()),
+            objective_id: Some("marathon".to_string()),
+            target: Some(50.0),
+            direction: IndicatorDirection::HigherIsBetter,
+            notes: None,
+        }],
+        ..Default::default()
+    }
+}
+
+fn added(n: usize) -> ImportCounts {
+    ImportCounts {
+        added: n,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn import_adds_everything_and_links_indicators() {
+    let (_temp_dir, config) = temp_config();
+
+    let report = import(&marathon_pack(), OnConflict::Skip, &config, false).unwrap();
+    assert_eq!(
+        (report.objectives, report.indicators, report.templates),
+        (added(1), added(1), added(1))
+    );
+
+    let objectives = load_or_create_objectives(&config).unwrap().objectives;
+    assert_eq!(objectives[0].id, "marathon");
+    assert_eq!(objectives[0].weekly_budget_min, Some(300));
+    assert_eq!(objectives[0].indicators, ["weekly-km"]);
+    let indicators = load_or_create_indicators(&config).unwrap().indicators;
+    assert_eq!(indicators[0].objective_id.as_deref(), Some("marathon"));
+    assert_eq!(indicators[0].target, Some(50.0));
+    assert_eq!(
+        load_or_create_templates(&config)
+            .unwrap()
+            .get_template("Long run day")
+            .unwrap()
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn conflicts_are_skipped_replaced_or_renamed() {
+    let (_temp_dir, config) = temp_config();
+    import(&marathon_pack(), OnConflict::Skip, &config, false).unwrap();
+    let mut objectives = load_or_create_objectives(&config).unwrap();
+    objectives.objectives[0].status = ObjectiveStatus::Paused;
+    save_objectives(&objectives, &config).unwrap();
+
+    let mut pack = marathon_pack();
+    pack.objectives[0].title = "Run a sub-4 marathon".to_string();
+
+    let report = import(&pack, OnConflict::Skip, &config, false).unwrap();
+    assert_eq!(report.objectives.skipped, 1);
+    assert_eq!(report.templates.skipped, 1);
+    let objectives = load_or_create_objectives(&config).unwrap().objectives;
+    assert_eq!(objectives[0].title, "Finish a marathon");
+
+    let report = import(&pack, OnConflict::Replace, &config, false).unwrap();
+    assert_eq!(report.objectives.replaced, 1);
+    let objectives = load_or_create_objectives(&config).unwrap().objectives;
+    assert_eq!(objectives.len(), 1);
+    assert_eq!(objectives[0].title, "Run a sub-4 marathon");
+    assert_eq!(objectives[0].status, ObjectiveStatus::Paused);
+    assert_eq!(objectives[0].indicators, ["weekly-km"]);
+
+    let report = import(&pack, OnConflict::Rename, &config, false).unwrap();
+    assert_eq!(report.indicators.renamed, 1);
+    let objectives = load_or_create_objectives(&config).unwrap().objectives;
+    assert_eq!(objectives.len(), 2);
+    let copy = &objectives[1];
+    assert_ne!(copy.id, "marathon");
+    let indicators = load_or_create_indicators(&config).unwrap().indicators;
+    assert_eq!(indicators.len(), 2);
+    assert_eq!(copy.indicators, [indicators[1].id.clone()]);
+    assert_eq!(indicators[1].objective_id.as_ref(), Some(&copy.id));
+    assert!(load_or_create_templates(&config)
+        .unwrap()
+        .get_template("Long run day (2)")
+        .is_some());
+}
+
+#[test]
+fn bad_references_and_duplicate_ids_are_refused() {
+    let (_temp_dir, config) = temp_config();
+
+    let mut pack = marathon_pack();
+    pack.indicators[0].objective_id = Some("triathlon".to_string());
+    let err = import(&pack, OnConflict::Skip, &config, false).unwrap_err();
+    assert!(err.to_string().contains("triathlon"));
+
+    let mut pack = marathon_pack();
+    pack.objectives.push(pack.objectives[0].clone());
+    assert!(import(&pack, OnConflict::Skip, &config, false).is_err());
+    assert!(load_or_create_objectives(&config)
+        .unwrap()
+        .objectives
+        .is_empty());
+}
+
+#[test]
+fn dry_run_writes_nothing() {
+    let (_temp_dir, config) = temp_config();
+
+    let report = import(&marathon_pack(), OnConflict::Skip, &config, true).unwrap();
+    assert_eq!(report.objectives, added(1));
+    assert!(load_or_create_objectives(&config)
+        .unwrap()
+        .objectives
+        .is_empty());
+    assert!(load_or_create_indicators(&config)
+        .unwrap()
+        .indicators
+        .is_empty());
+}
+
+#[test]
+fn exported_pack_imports_into_another_setup() {
+    let (temp_dir, config) = temp_config();
+    import(&marathon_pack(), OnConflict::Skip, &config, false).unwrap();
+
+    for file in ["coach.toml", "coach.yaml", "coach.json"] {
+        let path = temp_dir.path().join(file);
+        write_pack(&export("Coach", &config).unwrap(), &path).unwrap();
+        let pack = read_pack(&path).unwrap();
+        assert_eq!(pack.name, "Coach");
+        assert_eq!(pack.objectives, marathon_pack().objectives);
+        assert_eq!(pack.indicators, marathon_pack().indicators);
+
+        let (_other_dir, other) = temp_config();
+        let report = import(&pack, OnConflict::Skip, &other, false).unwrap();
+        assert_eq!(report.objectives, added(1));
+        assert_eq!(
+            load_or_create_objectives(&other).unwrap().objectives[0].indicators,
+            ["weekly-km"]
+        );
+    }
+
+    assert!(read_pack(&temp_dir.path().join("coach.txt")).is_err());
+}
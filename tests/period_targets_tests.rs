@@ -0,0 +1,147 @@
+mod common;
+
+use chrono::{Duration, NaiveDate};
+use common::temp_config;
+use focusfive::command::{parse, run_headless, ExCommand};
+use focusfive::data::{load_or_create_indicators, save_indicators};
+use focusfive::models::{
+    IndicatorAggregation, IndicatorDef, IndicatorKind, IndicatorUnit, IndicatorsData, Observation,
+    TargetPeriod,
+};
+use focusfive::targets::parse_target;
+use focusfive::ui::theme::FinancialTheme;
+use focusfive::widgets::LiveMetricsWidget;
+use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+
+fn running() -> IndicatorDef {
+    let mut def = IndicatorDef::new(
+        "Running".to_string(),
+        IndicatorKind::Leading,
+        IndicatorUnit::Custom("km".to_string()),
+    );
+    def.id = "run".to_string();
+    def.aggregation = IndicatorAggregation::Sum;
+    def.target = Some(21.0);
+    def.target_period = TargetPeriod::Week;
+    def
+}
+
+fn run(when: NaiveDate, km: f64) -> Observation {
+    Observation::new(
+        "run".to_string(),
+        when,
+        km,
+        IndicatorUnit::Custom("km".to_string()),
+    )
+}
+
+#[test]
+fn targets_parse_with_a_period_and_units() {
+    let km = IndicatorUnit::Custom("km".to_string());
+    assert_eq!(
+        parse_target("20km/week", &km).unwrap(),
+        (20.0, TargetPeriod::Week)
+    );
+    assert_eq!(
+        parse_target("20 per month", &km).unwrap(),
+        (20.0, TargetPeriod::Month)
+    );
+    assert_eq!(parse_target("8", &km).unwrap(), (8.0, TargetPeriod::Day));
+    assert_eq!(
+        parse_target("10h/month", &IndicatorUnit::Minutes).unwrap(),
+        (600.0, TargetPeriod::Month)
+    );
+    assert!(parse_target("20/fortnight", &km).is_err());
+    assert!(parse_target("-5/week", &km).is_err());
+}
+
+#[test]
+fn weekly_progress_is_pro_rated_to_today() {
+    // Wednesday is day 3 of 7, so 9 of the 21 km are due
+    let monday = NaiveDate::from_ymd_opt(2025, 3, 3).unwrap();
+    let wednesday = monday + Duration::days(2);
+    let observations = vec![
+        run(monday - Duration::days(1), 30.0), // Last week's Sunday
+        run(monday, 5.0),
+        run(wednesday, 3.0),
+        run(wednesday, 4.0),
+    ];
+
+    let progress = running().period_to_date(&observations, wednesday).unwrap();
+    assert_eq!(progress.total, 12.0);
+    assert_eq!(progress.pro_rated, 9.0);
+    assert_eq!((progress.days_elapsed, progress.days_in_period), (3, 7));
+    assert!((progress.pace_percent().unwrap() - 133.333).abs() < 0.01);
+    assert_eq!(progress.pace_gap(), 3.0);
+
+    let mut monthly = running();
+    monthly.target = Some(90.0);
+    monthly.target_period = TargetPeriod::Month;
+    let progress = monthly.period_to_date(&observations, wednesday).unwrap();
+    // March 2 was the Sunday before, but the same month
+    assert_eq!((progress.days_elapsed, progress.days_in_period), (5, 31));
+    assert_eq!(progress.total, 42.0);
+
+    let mut daily = running();
+    daily.target_period = TargetPeriod::Day;
+    assert!(daily.period_to_date(&observations, wednesday).is_none());
+}
+
+#[test]
+fn live_metrics_show_the_week_to_date() {
+    let monday = NaiveDate::from_ymd_opt(2025, 3, 3).unwrap();
+    let wednesday = monday + Duration::days(2);
+    let observations = vec![run(monday, 5.0), run(wednesday, 1.0)];
+    let indicators = vec![running()];
+    let theme = FinancialTheme::default();
+
+    let widget = LiveMetricsWidget::new(&indicators, &observations, &theme).today(wednesday);
+    let area = Rect::new(0, 0, 80, 4);
+    let mut buffer = Buffer::empty(area);
+    widget.render(area, &mut buffer);
+    let text: String = buffer.content.iter().map(|cell| cell.symbol()).collect();
+    assert!(text.contains("Running WTD"));
+    assert!(text.contains("6.0"));
+    assert!(text.contains("9.0"));
+    assert!(text.contains("-33.3%"));
+    assert!(text.contains("/ 21"));
+}
+
+#[test]
+fn target_command_sets_and_clears_the_period() {
+    let (_temp, config) = temp_config();
+    let mut def = running();
+    def.target = None;
+    def.target_period = TargetPeriod::Day;
+    save_indicators(
+        &IndicatorsData {
+            version: 1,
+            indicators: vec![def],
+        },
+        &config,
+    )
+    .unwrap();
+    let today = NaiveDate::from_ymd_opt(2025, 3, 5).unwrap();
+
+    let command = parse("target Running 20km/week").unwrap();
+    assert_eq!(
+        command,
+        ExCommand::Target {
+            indicator: "Running".to_string(),
+            target: "20km/week".to_string(),
+        }
+    );
+    let message = run_headless(&command, today, &config).unwrap();
+    assert_eq!(message, "Running now targets 20 km a week");
+    let saved = load_or_create_indicators(&config).unwrap();
+    assert_eq!(saved.indicators[0].target, Some(20.0));
+    assert_eq!(saved.indicators[0].target_period, TargetPeriod::Week);
+
+    run_headless(&parse("target running none").unwrap(), today, &config).unwrap();
+    let saved = load_or_create_indicators(&config).unwrap();
+    assert_eq!(saved.indicators[0].target, None);
+    assert_eq!(saved.indicators[0].target_period, TargetPeriod::Day);
+
+    assert!(parse("target Running").is_err());
+    assert!(run_headless(&parse("target Cycling 5/week").unwrap(), today, &config).is_err());
+}
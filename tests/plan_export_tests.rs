@@ -0,0 +1,81 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use focusfive::command::{parse, run_headless};
+use focusfive::data::{save_day_meta, save_objectives, write_goals_file};
+use focusfive::export::{render_plan, ExportPeriod, PLAN_WIDTH};
+use focusfive::models::{BlockerRef, DailyGoals, DayMeta, Objective, ObjectivesData, OutcomeType};
+use std::fs;
+
+#[test]
+fn plan_lists_goals_actions_estimates_and_links() {
+    let (temp_dir, config) = temp_config();
+    let day = NaiveDate::from_ymd_opt(2025, 6, 4).unwrap();
+    let launch = Objective::new(OutcomeType::Work, "Product launch".to_string());
+    let mut goals = DailyGoals::new(day);
+    goals.day_number = Some(12);
+    goals.work.goal = Some("Ship the beta".to_string());
+    goals.work.actions[0].text = "Write release notes".to_string();
+    goals.work.actions[0].add_objective_id(launch.id.clone());
+    goals.work.actions[1].text = "Tag release".to_string();
+    goals.work.actions[2].text = "Answer email".to_string();
+    goals.work.actions[2].completed = true;
+    goals.health.actions[0].text = "Run ".repeat(30).trim().to_string();
+    write_goals_file(&goals, &config).unwrap();
+    save_objectives(
+        &ObjectivesData {
+            objectives: vec![launch],
+            ..Default::default()
+        },
+        &config,
+    )
+    .unwrap();
+
+    let mut meta = DayMeta::from_goals(&goals);
+    meta.note = Some("Offsite all afternoon".to_string());
+    meta.work[0].estimated_min = Some(90);
+    meta.work[1].estimated_min = Some(15);
+    meta.work[1].blocked_by = Some(BlockerRef {
+        date: day,
+        outcome_type: OutcomeType::Work,
+        text: "Write release notes".to_string(),
+        action_id: None,
+    });
+    save_day_meta(day, &meta, &config).unwrap();
+
+    let page = render_plan(day, &config).unwrap();
+    assert!(page.starts_with("FOCUSFIVE - Wednesday, June 04, 2025 - Day 12\n====="));
+    assert!(page.contains("Today: Offsite all afternoon\n"));
+    assert!(page.contains("WORK - Ship the beta\n"));
+    assert!(page.contains("[ ] Write release notes (1h30m)\n    > Product launch\n"));
+    assert!(page.contains("[ ] Tag release (15m)\n    waits on: Write release notes\n"));
+    assert!(page.contains("[x] Answer email\n"));
+    assert!(page.contains("Planned time: 1h45m\n"));
+    // Family has nothing planned, so it gets blank lines to fill in
+    let family = page.split("FAMILY\n").nth(1).unwrap();
+    assert_eq!(
+        family.lines().filter(|l| l.starts_with("[ ] ___")).count(),
+        3
+    );
+    assert!(page.lines().all(|line| line.chars().count() <= PLAN_WIDTH));
+
+    assert_eq!(
+        parse("export print").unwrap(),
+        focusfive::command::ExCommand::Export(ExportPeriod::Plan)
+    );
+    run_headless(&parse("export plan").unwrap(), day, &config).unwrap();
+    let saved = fs::read_to_string(temp_dir.path().join("exports/plan-2025-06-04.txt")).unwrap();
+    assert_eq!(saved, page);
+}
+
+#[test]
+fn plan_for_an_empty_day_is_a_blank_sheet() {
+    let (_temp_dir, config) = temp_config();
+    let day = NaiveDate::from_ymd_opt(2025, 6, 5).unwrap();
+    let page = render_plan(day, &config).unwrap();
+    assert!(page.contains("WORK\n"));
+    assert_eq!(page.matches("[ ] ___").count(), 9);
+    assert!(!page.contains("Planned time"));
+    assert!(page.contains("NOTES\n"));
+}
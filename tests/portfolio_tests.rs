@@ -0,0 +1,142 @@
+use chrono::{Duration, NaiveDate};
+use focusfive::models::{
+    IndicatorDef, IndicatorKind, IndicatorUnit, Objective, ObjectiveStatus, Observation,
+    OutcomeType,
+};
+use focusfive::portfolio::{Health, Portfolio, STALE_DAYS};
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 3, day).unwrap()
+}
+
+/// An indicator with `target` observed on consecutive days from March 1st
+fn indicator_with(
+    name: &str,
+    target: f64,
+    values: &[f64],
+    observations: &mut Vec<Observation>,
+) -> IndicatorDef {
+    let mut def = IndicatorDef::new(
+        name.to_string(),
+        IndicatorKind::Lagging,
+        IndicatorUnit::Count,
+    );
+    def.target = Some(target);
+    for (day, value) in values.iter().enumerate() {
+        observations.push(Observation::new(
+            def.id.clone(),
+            date(1) + Duration::days(day as i64),
+            *value,
+            IndicatorUnit::Count,
+        ));
+    }
+    def
+}
+
+#[test]
+fn sorts_active_objectives_worst_first() {
+    let mut observations = Vec::new();
+    // One page a day toward 100 by March 20th won't make it
+    let slow = indicator_with("Pages", 100.0, &[1.0, 2.0, 3.0, 4.0], &mut observations);
+    // Twenty a day toward 200 lands on March 11th
+    let fast = indicator_with("Chapters", 200.0, &[0.0, 20.0, 40.0], &mut observations);
+    let today = date(4);
+
+    let mut book = Objective::new(OutcomeType::Work, "Ship the book".to_string());
+    book.end = Some(date(20));
+    book.indicators = vec![slow.id.clone()];
+
+    let mut course = Objective::new(OutcomeType::Work, "Finish course".to_string());
+    course.end = Some(date(20));
+    course.indicators = vec![slow.id.clone(), fast.id.clone(), "missing".to_string()];
+
+    let mut draft = Objective::new(OutcomeType::Work, "Outline".to_string());
+    draft.indicators = vec![fast.id.clone()];
+
+    let mut overdue = Objective::new(OutcomeType::Health, "Run 10k".to_string());
+    overdue.end = Some(date(2));
+
+    let mut paused = Objective::new(OutcomeType::Family, "Paused".to_string());
+    paused.status = ObjectiveStatus::Paused;
+    let mut archived = Objective::new(OutcomeType::Family, "Archived".to_string());
+    archived.archived = true;
+
+    let objectives = vec![draft, course, paused, book, archived, overdue];
+    let indicators = vec![slow, fast];
+    let portfolio = Portfolio::build(&objectives, &indicators, &observations, today);
+
+    let summary: Vec<(&str, Health)> = portfolio
+        .entries
+        .iter()
+        .map(|entry| (entry.objective.title.as_str(), entry.health))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("Run 10k", Health::Behind),
+            ("Finish course", Health::Behind),
+            ("Ship the book", Health::Behind),
+            ("Outline", Health::OnTrack),
+        ]
+    );
+    assert_eq!(
+        portfolio.entries[0].detail.as_deref(),
+        Some("2 days overdue")
+    );
+    assert_eq!(
+        portfolio.entries[1].detail.as_deref(),
+        Some("Pages behind pace")
+    );
+    assert_eq!(portfolio.count(Health::Behind), 3);
+    assert_eq!(portfolio.count(Health::AtRisk), 0);
+    assert_eq!(portfolio.count(Health::OnTrack), 1);
+}
+
+#[test]
+fn one_lagging_indicator_of_several_is_only_a_risk() {
+    let mut observations = Vec::new();
+    let slow = indicator_with("Pages", 100.0, &[1.0, 2.0, 3.0, 4.0], &mut observations);
+    let fast = indicator_with("Chapters", 200.0, &[0.0, 20.0, 40.0], &mut observations);
+    let done = indicator_with("Outline", 5.0, &[3.0, 6.0], &mut observations);
+
+    let mut book = Objective::new(OutcomeType::Work, "Ship the book".to_string());
+    book.end = Some(date(20));
+    book.indicators = vec![slow.id.clone(), fast.id.clone(), done.id.clone()];
+    let objectives = vec![book];
+    let indicators = vec![slow, fast, done];
+
+    let portfolio = Portfolio::build(&objectives, &indicators, &observations, date(4));
+    assert_eq!(portfolio.entries[0].health, Health::AtRisk);
+    assert_eq!(
+        portfolio.entries[0].detail.as_deref(),
+        Some("Pages behind pace")
+    );
+}
+
+#[test]
+fn stale_indicators_put_an_objective_at_risk() {
+    let mut observations = Vec::new();
+    let steps = indicator_with("Steps", 10.0, &[1.0, 2.0], &mut observations);
+
+    let mut walk = Objective::new(OutcomeType::Health, "Walk daily".to_string());
+    walk.indicators = vec![steps.id.clone()];
+    let objectives = vec![walk];
+    let indicators = vec![steps];
+
+    let fresh = Portfolio::build(&objectives, &indicators, &observations, date(5));
+    assert_eq!(fresh.entries[0].health, Health::OnTrack);
+    assert_eq!(fresh.entries[0].detail, None);
+
+    let later = date(2) + Duration::days(STALE_DAYS);
+    let stale = Portfolio::build(&objectives, &indicators, &observations, later);
+    assert_eq!(stale.entries[0].health, Health::AtRisk);
+    assert_eq!(
+        stale.entries[0].detail.as_deref(),
+        Some("no updates in 14 days")
+    );
+
+    // Objectives without indicators have nothing to go stale
+    let bare = vec![Objective::new(OutcomeType::Family, "Call mom".to_string())];
+    let portfolio = Portfolio::build(&bare, &indicators, &observations, later);
+    assert_eq!(portfolio.entries[0].health, Health::OnTrack);
+}
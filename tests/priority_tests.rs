@@ -0,0 +1,97 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use focusfive::command::{parse, ExCommand};
+use focusfive::data::{load_or_create_day_meta, read_goals_file, write_goals_file};
+use focusfive::models::{DailyGoals, OutcomeType};
+use focusfive::priority::{completion_by_level, priorities, set_priority, sort_by_priority};
+
+fn date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 3, 10).unwrap()
+}
+
+fn day(texts: [&str; 3]) -> DailyGoals {
+    let mut goals = DailyGoals::new(date());
+    for (action, text) in goals.work.actions.iter_mut().zip(texts) {
+        action.text = text.to_string();
+    }
+    goals
+}
+
+#[test]
+fn priorities_are_kept_in_the_day_metadata() {
+    let (_temp_dir, config) = temp_config();
+    let goals = day(["Plan", "Write", ""]);
+    write_goals_file(&goals, &config).unwrap();
+    set_priority(&goals, OutcomeType::Work, 1, Some(2), &config).unwrap();
+
+    let set = priorities(&goals, &config).unwrap();
+    assert_eq!(set.get(&(OutcomeType::Work, 1)), Some(&2));
+    assert_eq!(set.len(), 1);
+
+    assert!(set_priority(&goals, OutcomeType::Work, 2, Some(1), &config).is_err());
+    assert!(set_priority(&goals, OutcomeType::Work, 0, Some(4), &config).is_err());
+    set_priority(&goals, OutcomeType::Work, 1, None, &config).unwrap();
+    assert!(priorities(&goals, &config).unwrap().is_empty());
+}
+
+#[test]
+fn sorting_moves_metadata_with_its_action() {
+    let (_temp_dir, config) = temp_config();
+    let mut goals = day(["Plan", "", "Ship"]);
+    write_goals_file(&goals, &config).unwrap();
+    set_priority(&goals, OutcomeType::Work, 2, Some(1), &config).unwrap();
+    let mut meta = load_or_create_day_meta(date(), &goals, &config).unwrap();
+    meta.work[0].estimated_min = Some(30);
+    focusfive::data::save_day_meta(date(), &meta, &config).unwrap();
+
+    let order = sort_by_priority(&mut goals, OutcomeType::Work, &config).unwrap();
+    assert_eq!(order, [2, 0, 1]);
+    let texts: Vec<String> = read_goals_file(&focusfive::data::goals_file_path(date(), &config))
+        .unwrap()
+        .work
+        .actions
+        .iter()
+        .map(|a| a.text.clone())
+        .collect();
+    assert_eq!(texts, ["Ship", "Plan", ""]);
+    let meta = load_or_create_day_meta(date(), &goals, &config).unwrap();
+    assert_eq!(meta.work[0].priority, Some(1));
+    assert_eq!(meta.work[1].estimated_min, Some(30));
+
+    // Already in order
+    let order = sort_by_priority(&mut goals, OutcomeType::Work, &config).unwrap();
+    assert_eq!(order, [0, 1, 2]);
+}
+
+#[test]
+fn completion_is_counted_per_level() {
+    let (_temp_dir, config) = temp_config();
+    let mut goals = day(["Plan", "Write", "Ship"]);
+    goals.work.actions[0].completed = true;
+    write_goals_file(&goals, &config).unwrap();
+    set_priority(&goals, OutcomeType::Work, 0, Some(1), &config).unwrap();
+    set_priority(&goals, OutcomeType::Work, 1, Some(1), &config).unwrap();
+    set_priority(&goals, OutcomeType::Work, 2, Some(3), &config).unwrap();
+
+    assert_eq!(
+        completion_by_level(date(), 30, &config).unwrap(),
+        [(1, 2), (0, 0), (0, 1)]
+    );
+    let before = date() - chrono::Duration::days(1);
+    assert_eq!(
+        completion_by_level(before, 30, &config).unwrap(),
+        [(0, 0); 3]
+    );
+}
+
+#[test]
+fn sort_command_parses() {
+    assert_eq!(parse(":sort").unwrap(), ExCommand::Sort(None));
+    assert_eq!(
+        parse("sort health").unwrap(),
+        ExCommand::Sort(Some(OutcomeType::Health))
+    );
+    assert!(parse("sort health work").is_err());
+}
@@ -0,0 +1,136 @@
+mod common;
+
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{load_or_create_projects, save_objectives};
+use focusfive::models::{Objective, ObjectiveStatus, ObjectivesData, OutcomeType, Project};
+use focusfive::projects::{self, ProjectRollup};
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+use std::collections::HashMap;
+
+fn objectives() -> Vec<Objective> {
+    let mut launch = Objective::new(OutcomeType::Work, "Launch the course".to_string());
+    launch.status = ObjectiveStatus::Completed;
+    let mut energy = Objective::new(OutcomeType::Health, "Sleep 8 hours".to_string());
+    energy.indicators = vec!["sleep".to_string(), "missing".to_string()];
+    let mut reading = Objective::new(OutcomeType::Work, "Read 4 books".to_string());
+    for title in ["One", "Two", "Three", "Four"] {
+        reading.push_queue_item(title.to_string());
+    }
+    reading.advance_queue(chrono::Local::now().date_naive());
+    vec![launch, energy, reading]
+}
+
+#[test]
+fn rollup_spans_domains_and_averages_member_progress() {
+    let objectives = objectives();
+    let mut project = Project::new("Sabbatical".to_string());
+    project.objective_ids = objectives.iter().map(|o| o.id.clone()).collect();
+    project.objective_ids.push("deleted-objective".to_string());
+    let indicators = HashMap::from([("sleep".to_string(), 0.5)]);
+
+    assert_eq!(
+        projects::objective_progress(&objectives[0], &indicators),
+        1.0
+    );
+    assert_eq!(
+        projects::objective_progress(&objectives[1], &indicators),
+        0.5
+    );
+    assert_eq!(
+        projects::objective_progress(&objectives[2], &indicators),
+        0.25
+    );
+
+    let rollup = ProjectRollup::new(&project, &objectives, &indicators);
+    assert_eq!(rollup.objectives, 3);
+    assert_eq!(rollup.completed, 1);
+    assert_eq!(rollup.domains, [OutcomeType::Work, OutcomeType::Health]);
+    assert!((rollup.progress - 175.0 / 3.0).abs() < 1e-9);
+
+    let empty = ProjectRollup::new(&Project::new("Empty".to_string()), &objectives, &indicators);
+    assert_eq!(empty, ProjectRollup::default());
+}
+
+#[test]
+fn projects_are_created_toggled_renamed_and_deleted() {
+    let (_temp_dir, config) = temp_config();
+    assert!(projects::create_project("  ", &config).is_err());
+    let id = projects::create_project("Move abroad", &config).unwrap();
+
+    let data = projects::toggle_objective(&id, "a", &config).unwrap();
+    assert_eq!(data.projects[0].objective_ids, ["a"]);
+    projects::toggle_objective(&id, "b", &config).unwrap();
+    let data = projects::toggle_objective(&id, "a", &config).unwrap();
+    assert_eq!(data.projects[0].objective_ids, ["b"]);
+
+    projects::rename_project(&id, "Move to Lisbon", &config).unwrap();
+    projects::forget_objective("b", &config).unwrap();
+    let saved = load_or_create_projects(&config).unwrap();
+    assert_eq!(saved.projects[0].title, "Move to Lisbon");
+    assert!(saved.projects[0].objective_ids.is_empty());
+
+    assert!(projects::delete_project(&id, &config)
+        .unwrap()
+        .projects
+        .is_empty());
+    assert!(projects::delete_project(&id, &config).is_err());
+}
+
+#[test]
+fn browser_creates_a_project_and_assigns_objectives() {
+    let (_temp_dir, config) = temp_config();
+    let objectives = objectives();
+    save_objectives(
+        &ObjectivesData {
+            objectives: objectives.clone(),
+            ..Default::default()
+        },
+        &config,
+    )
+    .unwrap();
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+
+    app.handle_key(KeyCode::Char('G')).unwrap();
+    app.handle_key(KeyCode::Char('n')).unwrap();
+    for c in "Sabbatical".chars() {
+        app.handle_key(KeyCode::Char(c)).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+
+    // Add the first two objectives
+    app.handle_key(KeyCode::Enter).unwrap();
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    app.handle_key(KeyCode::Char('j')).unwrap();
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    app.handle_key(KeyCode::Esc).unwrap();
+
+    let Some(ModalState::Projects(state)) = app.modal.clone() else {
+        panic!("expected the projects browser");
+    };
+    assert_eq!(state.assigning, None);
+    let saved = load_or_create_projects(&app.config).unwrap();
+    assert_eq!(state.projects, saved);
+    assert_eq!(saved.projects[0].title, "Sabbatical");
+    assert_eq!(
+        saved.projects[0].objective_ids,
+        [objectives[0].id.clone(), objectives[1].id.clone()]
+    );
+
+    // Deleting needs a second d
+    app.handle_key(KeyCode::Char('d')).unwrap();
+    assert_eq!(
+        load_or_create_projects(&app.config).unwrap().projects.len(),
+        1
+    );
+    app.handle_key(KeyCode::Char('d')).unwrap();
+    assert!(load_or_create_projects(&app.config)
+        .unwrap()
+        .projects
+        .is_empty());
+
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(app.modal.is_none());
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{save_objectives, save_vision};
+use focusfive::models::{FiveYearVision, Objective, ObjectiveStatus, ObjectivesData, OutcomeType};
+use focusfive::quarterly::{self, Alignment, QuarterlyCheckIn};
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+use std::fs;
+
+fn sample() -> (FiveYearVision, ObjectivesData) {
+    let mut vision = FiveYearVision::new();
+    vision.set_vision(&OutcomeType::Work, "Run my own studio".to_string());
+    vision.set_vision(&OutcomeType::Health, "Stay strong at 50".to_string());
+    let mut done = Objective::new(OutcomeType::Work, "Old launch".to_string());
+    done.status = ObjectiveStatus::Completed;
+    let objectives = ObjectivesData {
+        objectives: vec![
+            Objective::new(OutcomeType::Work, "Land three clients".to_string()),
+            done,
+            Objective::new(OutcomeType::Work, "Learn Kubernetes".to_string()),
+            Objective::new(OutcomeType::Health, "Lift twice a week".to_string()),
+        ],
+        ..Default::default()
+    };
+    (vision, objectives)
+}
+
+#[test]
+fn check_in_covers_active_objectives_per_domain() {
+    let (vision, objectives) = sample();
+    let date = NaiveDate::from_ymd_opt(2025, 8, 14).unwrap();
+    let mut checkin = QuarterlyCheckIn::start(date, &vision, &objectives);
+
+    assert_eq!(quarterly::quarter_of(date), (2025, 3));
+    assert_eq!(checkin.label(), "2025-Q3");
+    let work = &checkin.domains[0];
+    assert_eq!(work.vision, "Run my own studio");
+    let titles: Vec<_> = work.objectives.iter().map(|o| o.title.as_str()).collect();
+    assert_eq!(titles, ["Land three clients", "Learn Kubernetes"]);
+    assert!(checkin.domains[2].objectives.is_empty());
+
+    checkin.domains[0].objectives[0].alignment = Alignment::Aligned;
+    checkin.domains[0].objectives[1].alignment = Alignment::Drifting;
+    checkin.domains[0].note = "Kubernetes is a side quest".to_string();
+    assert_eq!(checkin.drifting(), 1);
+
+    let markdown = quarterly::to_markdown(&checkin);
+    assert!(markdown.starts_with("# Quarterly check-in: 2025-Q3"));
+    assert!(markdown.contains("## Work\n\n> Run my own studio\n"));
+    assert!(markdown.contains("- [aligned] Land three clients\n"));
+    assert!(markdown.contains("- [drifting] Learn Kubernetes\n"));
+    assert!(markdown.contains("Notes: Kubernetes is a side quest"));
+    assert!(markdown.contains("- [not reviewed] Lift twice a week\n"));
+    assert!(markdown.contains("## Family\n\n> _No vision written yet._\n\nNo active objectives."));
+}
+
+#[test]
+fn guided_check_in_saves_a_quarter_document() {
+    let (temp_dir, config) = temp_config();
+    let (vision, objectives) = sample();
+    save_vision(&vision, &config).unwrap();
+    save_objectives(&objectives, &config).unwrap();
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+
+    app.handle_key(KeyCode::Char('Q')).unwrap();
+    app.handle_key(KeyCode::Char('y')).unwrap();
+    app.handle_key(KeyCode::Char('n')).unwrap();
+    app.handle_key(KeyCode::Char('e')).unwrap();
+    for c in "Drop side quests".chars() {
+        app.handle_key(KeyCode::Char(c)).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+
+    // Enter moves through the domains, then saves on the last one
+    app.handle_key(KeyCode::Enter).unwrap();
+    app.handle_key(KeyCode::Char('y')).unwrap();
+    app.handle_key(KeyCode::Enter).unwrap();
+    let Some(ModalState::QuarterlyCheckIn(state)) = app.modal.clone() else {
+        panic!("expected the check-in");
+    };
+    assert_eq!(state.step, 2);
+    assert_eq!(state.checkin.domains[0].note, "Drop side quests");
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert!(app.modal.is_none());
+
+    let (year, quarter) = quarterly::quarter_of(app.max_date);
+    let path = temp_dir
+        .path()
+        .join("quarterly")
+        .join(format!("{}-Q{}.md", year, quarter));
+    let saved = fs::read_to_string(path).unwrap();
+    assert!(saved.contains("- [aligned] Land three clients"));
+    assert!(saved.contains("- [drifting] Learn Kubernetes"));
+    assert!(saved.contains("- [aligned] Lift twice a week"));
+    assert!(saved.contains("Notes: Drop side quests"));
+    assert!(quarterly::has_checkin(app.max_date, &app.config));
+}
@@ -0,0 +1,99 @@
+use chrono::{Duration, Local};
+use crossterm::event::KeyCode;
+use focusfive::data::{read_observations_range, save_indicators};
+use focusfive::models::{Config, IndicatorDef, IndicatorKind, IndicatorUnit, IndicatorsData};
+use focusfive::ui::app::{ModalState, QuickObservationField};
+use focusfive::ui::App;
+use std::fs;
+use tempfile::TempDir;
+
+fn setup_app() -> (App, Config, TempDir) {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let goals_dir = temp_dir.path().join("goals");
+    fs::create_dir_all(&goals_dir).unwrap();
+
+    let config = Config {
+        goals_dir: goals_dir.to_str().unwrap().to_string(),
+        data_root: temp_dir.path().to_str().unwrap().to_string(),
+    };
+
+    let mut retired = IndicatorDef::new(
+        "Push notifications sent".to_string(),
+        IndicatorKind::Lagging,
+        IndicatorUnit::Count,
+    );
+    retired.active = false;
+
+    let indicators = IndicatorsData {
+        version: 1,
+        indicators: vec![
+            IndicatorDef::new(
+                "Deep work".to_string(),
+                IndicatorKind::Leading,
+                IndicatorUnit::Minutes,
+            ),
+            IndicatorDef::new(
+                "Pushups".to_string(),
+                IndicatorKind::Leading,
+                IndicatorUnit::Count,
+            ),
+            retired,
+        ],
+    };
+    save_indicators(&indicators, &config).unwrap();
+
+    let app = App::new(config.clone()).expect("app should initialise");
+    (app, config, temp_dir)
+}
+
+fn type_text(app: &mut App, text: &str) {
+    for ch in text.chars() {
+        app.handle_key(KeyCode::Char(ch)).unwrap();
+    }
+}
+
+#[test]
+fn plus_logs_observation_for_fuzzy_matched_indicator() {
+    let (mut app, config, _temp) = setup_app();
+    let pushups_id = app.indicators.indicators[1].id.clone();
+
+    app.handle_key(KeyCode::Char('+')).unwrap();
+    assert!(matches!(app.modal, Some(ModalState::QuickObservation(_))));
+
+    // Letters go to the query rather than triggering normal-mode bindings
+    type_text(&mut app, "push");
+    app.handle_key(KeyCode::Enter).unwrap();
+    match &app.modal {
+        Some(ModalState::QuickObservation(state)) => {
+            assert_eq!(state.field, QuickObservationField::Value)
+        }
+        other => panic!("unexpected modal: {:?}", other),
+    }
+
+    type_text(&mut app, "25");
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert!(app.modal.is_none());
+
+    let today = Local::now().date_naive();
+    let observations = read_observations_range(today - Duration::days(1), today, &config).unwrap();
+    assert_eq!(observations.len(), 1);
+    assert_eq!(observations[0].indicator_id, pushups_id);
+    assert_eq!(observations[0].value, 25.0);
+}
+
+#[test]
+fn escape_cancels_without_logging() {
+    let (mut app, config, _temp) = setup_app();
+
+    app.handle_key(KeyCode::Char('+')).unwrap();
+    type_text(&mut app, "deep");
+    app.handle_key(KeyCode::Tab).unwrap();
+    type_text(&mut app, "45");
+    app.handle_key(KeyCode::Esc).unwrap();
+
+    assert!(app.modal.is_none());
+    let today = Local::now().date_naive();
+    assert!(read_observations_range(today, today, &config)
+        .unwrap()
+        .is_empty());
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use focusfive::data::{read_observations_range, save_indicators, sync_ratio_indicators};
+use focusfive::mcp::handle_message;
+use focusfive::models::{
+    IndicatorDef, IndicatorKind, IndicatorUnit, IndicatorsData, Observation, ObservationSource,
+    RatioSource,
+};
+use serde_json::json;
+
+fn counter(id: &str, name: &str) -> IndicatorDef {
+    let mut def = IndicatorDef::new(
+        name.to_string(),
+        IndicatorKind::Leading,
+        IndicatorUnit::Count,
+    );
+    def.id = id.to_string();
+    def
+}
+
+/// A Percent indicator kept at "responded" over "sent"
+fn response_rate() -> IndicatorDef {
+    let mut def = IndicatorDef::new(
+        "Response rate".to_string(),
+        IndicatorKind::Lagging,
+        IndicatorUnit::Percent,
+    );
+    def.ratio_of = Some(RatioSource {
+        numerator_id: "responded".to_string(),
+        denominator_id: "sent".to_string(),
+    });
+    def
+}
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 4, day).unwrap()
+}
+
+fn count(id: &str, day: u32, value: f64) -> Observation {
+    Observation::new(id.to_string(), date(day), value, IndicatorUnit::Count)
+}
+
+#[test]
+fn ratio_uses_counter_totals_through_date() {
+    let rate = response_rate();
+    assert_eq!(rate.unit, IndicatorUnit::Percent);
+
+    let observations = vec![
+        count("responded", 2, 1.0),
+        count("sent", 1, 4.0),
+        count("sent", 2, 4.0),
+        count("responded", 3, 3.0),
+    ];
+    assert_eq!(rate.ratio_percent(&observations, date(1)), Some(0.0));
+    assert_eq!(rate.ratio_percent(&observations, date(2)), Some(12.5));
+    assert_eq!(rate.ratio_percent(&observations, date(3)), Some(50.0));
+    // Nothing sent yet
+    assert_eq!(
+        rate.ratio_percent(&[count("responded", 1, 1.0)], date(1)),
+        None
+    );
+    assert_eq!(
+        counter("sent", "Sent").ratio_percent(&observations, date(3)),
+        None
+    );
+}
+
+#[test]
+fn logging_either_counter_updates_the_ratio() {
+    let (_temp_dir, config) = temp_config();
+    let mut rate = response_rate();
+    rate.id = "rate".to_string();
+    let indicators = IndicatorsData {
+        version: 1,
+        indicators: vec![
+            counter("sent", "Applications sent"),
+            counter("responded", "Applications responded"),
+            rate,
+        ],
+    };
+    save_indicators(&indicators, &config).unwrap();
+
+    let call = |name: &str, value: f64| {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "tools/call",
+            "params": {
+                "name": "log_observation",
+                "arguments": { "indicator": name, "value": value, "date": "2025-04-05" },
+            },
+        });
+        let response = handle_message(&request, &config).unwrap();
+        assert!(response.get("error").is_none(), "{}", response);
+    };
+    call("Applications sent", 10.0);
+    call("Applications responded", 2.0);
+
+    let logged = read_observations_range(date(5), date(5), &config).unwrap();
+    let ratios: Vec<&Observation> = logged
+        .iter()
+        .filter(|obs| obs.indicator_id == "rate")
+        .collect();
+    assert_eq!(ratios.len(), 2);
+    assert_eq!(ratios[0].value, 0.0);
+    assert_eq!(ratios[1].value, 20.0);
+    assert_eq!(ratios[1].source, ObservationSource::Automated);
+
+    // Unrelated indicators don't trigger a recompute
+    let other = Observation::new("other".to_string(), date(5), 1.0, IndicatorUnit::Count);
+    assert!(sync_ratio_indicators(&other, &indicators, &config)
+        .unwrap()
+        .is_empty());
+}
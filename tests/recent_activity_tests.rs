@@ -0,0 +1,86 @@
+use crossterm::event::KeyCode;
+use focusfive::data::{append_activity, read_recent_activity, save_indicators};
+use focusfive::models::{
+    ActivityEvent, ActivityKind, Config, IndicatorDef, IndicatorKind, IndicatorUnit, IndicatorsData,
+};
+use focusfive::ui::app::FocusPanel;
+use focusfive::ui::App;
+use std::fs;
+use tempfile::TempDir;
+
+fn test_config(temp_dir: &TempDir) -> Config {
+    let goals_dir = temp_dir.path().join("goals");
+    fs::create_dir_all(&goals_dir).unwrap();
+    Config {
+        goals_dir: goals_dir.to_str().unwrap().to_string(),
+        data_root: temp_dir.path().to_str().unwrap().to_string(),
+    }
+}
+
+#[test]
+fn recent_activity_is_newest_first_and_limited() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = test_config(&temp_dir);
+
+    for i in 0..15 {
+        let event = ActivityEvent::new(ActivityKind::Completion, format!("event {}", i));
+        append_activity(&event, &config).unwrap();
+    }
+
+    // A torn line doesn't hide the rest of the feed
+    let path = temp_dir.path().join("activity.ndjson");
+    let mut content = fs::read_to_string(&path).unwrap();
+    content.push_str("{\"at\":\n");
+    fs::write(&path, content).unwrap();
+
+    let recent = read_recent_activity(10, &config).unwrap();
+    assert_eq!(recent.len(), 10);
+    assert_eq!(recent[0].summary, "event 14");
+    assert_eq!(recent[9].summary, "event 5");
+}
+
+#[test]
+fn completions_and_observations_feed_the_panel() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = test_config(&temp_dir);
+
+    let indicators = IndicatorsData {
+        version: 1,
+        indicators: vec![IndicatorDef::new(
+            "Pushups".to_string(),
+            IndicatorKind::Leading,
+            IndicatorUnit::Count,
+        )],
+    };
+    save_indicators(&indicators, &config).unwrap();
+
+    let mut app = App::new(config.clone()).unwrap();
+    assert!(app.recent_activity.is_empty());
+
+    app.goals.work.actions[0].text = "Ship the release".to_string();
+    app.focus_panel = FocusPanel::Actions;
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+    // Unchecking again is not a new completion
+    app.handle_key(KeyCode::Char(' ')).unwrap();
+
+    app.handle_key(KeyCode::Char('+')).unwrap();
+    app.handle_key(KeyCode::Enter).unwrap();
+    app.handle_key(KeyCode::Char('3')).unwrap();
+    app.handle_key(KeyCode::Char('0')).unwrap();
+    app.handle_key(KeyCode::Enter).unwrap();
+
+    let kinds: Vec<ActivityKind> = app.recent_activity.iter().map(|e| e.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![ActivityKind::Observation, ActivityKind::Completion]
+    );
+    assert_eq!(
+        app.recent_activity[1].summary,
+        "Completed: Ship the release"
+    );
+    assert!(app.recent_activity[0].summary.contains("Pushups"));
+
+    // The feed survives a restart
+    let reopened = App::new(config).unwrap();
+    assert_eq!(reopened.recent_activity, app.recent_activity);
+}
@@ -0,0 +1,79 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use focusfive::data::{
+    append_observation, goals_file_dates, load_sentiment_history, load_stats_cache,
+    observation_index_path, read_observations_range, rebuild_observation_index, reflection_dates,
+    save_reflection, write_goals_file,
+};
+use focusfive::models::{DailyGoals, IndicatorUnit, Observation};
+use focusfive::sentiment::rebuild_history;
+use focusfive::ui::stats::rebuild_stats_cache;
+use std::fs;
+
+fn date(day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 5, day).unwrap()
+}
+
+#[test]
+fn caches_are_rebuilt_from_raw_files() {
+    let (_temp_dir, config) = temp_config();
+    for (day, done) in [(3, 9), (1, 3), (2, 0)] {
+        let mut goals = DailyGoals::new(date(day));
+        for outcome in goals.outcomes_mut() {
+            for action in outcome.actions.iter_mut() {
+                action.text = "Something".to_string();
+            }
+        }
+        for action in goals
+            .outcomes_mut()
+            .into_iter()
+            .flat_map(|outcome| outcome.actions.iter_mut())
+            .take(done)
+        {
+            action.completed = true;
+        }
+        write_goals_file(&goals, &config).unwrap();
+    }
+    fs::write(format!("{}/notes.md", config.goals_dir), "not a goals file").unwrap();
+    save_reflection(date(4), "Great, focused day. Felt calm.", &config).unwrap();
+    for day in [1, 2] {
+        let obs = Observation::new("steps".to_string(), date(day), 100.0, IndicatorUnit::Count);
+        append_observation(&obs, &config).unwrap();
+    }
+
+    // Stale caches left behind by hand edits
+    fs::write(format!("{}/stats.json", config.data_root), "{ broken").unwrap();
+    fs::write(observation_index_path(&config), "{ broken").unwrap();
+
+    assert_eq!(rebuild_observation_index(&config).unwrap(), 2);
+    assert_eq!(
+        read_observations_range(date(1), date(2), &config)
+            .unwrap()
+            .len(),
+        2
+    );
+
+    let dates = goals_file_dates(&config).unwrap();
+    assert_eq!(dates, vec![date(1), date(2), date(3)]);
+    assert_eq!(reflection_dates(&config).unwrap(), vec![date(4)]);
+
+    let mut steps = Vec::new();
+    let days =
+        rebuild_stats_cache(&dates, &config, |done, total| steps.push((done, total))).unwrap();
+    assert_eq!(days, 3);
+    assert_eq!(steps, vec![(1, 3), (2, 3), (3, 3)]);
+    let cache = load_stats_cache(&config).unwrap();
+    assert_eq!(cache.days[&date(3)].completion, 100.0);
+    assert!((cache.days[&date(1)].completion - 100.0 / 3.0).abs() < 1e-9);
+
+    let mut last = (0, 0);
+    let history = rebuild_history(&config, |done, total| last = (done, total)).unwrap();
+    assert_eq!(last, (4, 4));
+    assert!(history
+        .points
+        .iter()
+        .any(|point| point.date == date(4) && point.score > 0.0));
+    assert_eq!(load_sentiment_history(&config).unwrap(), history);
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use common::temp_config;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use focusfive::data::{read_observations_range, save_indicators};
+use focusfive::models::{IndicatorDef, IndicatorKind, IndicatorUnit, IndicatorsData, OutcomeType};
+use focusfive::recovery::{self, PendingEdit};
+use focusfive::ui::app::{FocusPanel, ModalState};
+use focusfive::ui::App;
+
+fn press(app: &mut App, code: KeyCode) {
+    app.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE))
+        .unwrap();
+}
+
+#[test]
+fn action_edit_is_journaled_until_saved() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config.clone()).unwrap();
+    app.focus_panel = FocusPanel::Actions;
+
+    press(&mut app, KeyCode::Char('E'));
+    assert_eq!(recovery::load(&config).unwrap(), None);
+    press(&mut app, KeyCode::Char('H'));
+    press(&mut app, KeyCode::Char('i'));
+    let journal = recovery::load(&config).unwrap().unwrap();
+    assert_eq!(
+        journal.edit,
+        PendingEdit::Action {
+            date: app.goals.date,
+            outcome: OutcomeType::Work,
+            index: 0,
+            text: "Hi".to_string(),
+        }
+    );
+
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.goals.work.actions[0].text, "Hi");
+    assert_eq!(recovery::load(&config).unwrap(), None);
+}
+
+#[test]
+fn crashed_action_edit_reopens_in_the_editor() {
+    let (_temp_dir, config) = temp_config();
+    let today = chrono::Local::now().date_naive();
+    recovery::record(
+        &PendingEdit::Action {
+            date: today,
+            outcome: OutcomeType::Health,
+            index: 1,
+            text: "Stretch for 10 minutes".to_string(),
+        },
+        &config,
+    )
+    .unwrap();
+
+    let mut app = App::new(config.clone()).unwrap();
+    assert!(matches!(app.modal, Some(ModalState::Recovery(_))));
+    // Other keys leave the offer open
+    press(&mut app, KeyCode::Char('j'));
+    assert!(recovery::load(&config).unwrap().is_some());
+
+    press(&mut app, KeyCode::Enter);
+    assert!(app.text_editor.is_active);
+    assert_eq!(app.text_editor.text, "Stretch for 10 minutes");
+    assert_eq!(app.selected_outcome, OutcomeType::Health);
+    press(&mut app, KeyCode::Enter);
+    assert_eq!(app.goals.health.actions[1].text, "Stretch for 10 minutes");
+    assert_eq!(recovery::load(&config).unwrap(), None);
+}
+
+#[test]
+fn recovered_observation_is_recorded() {
+    let (_temp_dir, config) = temp_config();
+    let indicator = IndicatorDef::new(
+        "Pages read".to_string(),
+        IndicatorKind::Leading,
+        IndicatorUnit::Count,
+    );
+    save_indicators(
+        &IndicatorsData {
+            indicators: vec![indicator.clone()],
+            ..Default::default()
+        },
+        &config,
+    )
+    .unwrap();
+    recovery::record(
+        &PendingEdit::Observation {
+            indicator_id: indicator.id.clone(),
+            name: indicator.name.clone(),
+            value: "42".to_string(),
+        },
+        &config,
+    )
+    .unwrap();
+
+    let mut app = App::new(config.clone()).unwrap();
+    press(&mut app, KeyCode::Enter);
+    assert!(app.modal.is_none());
+    let today = chrono::Local::now().date_naive();
+    let observations = read_observations_range(today, today, &config).unwrap();
+    assert_eq!(observations.len(), 1);
+    assert_eq!(observations[0].value, 42.0);
+    assert_eq!(recovery::load(&config).unwrap(), None);
+}
+
+#[test]
+fn discarding_clears_the_journal() {
+    let (_temp_dir, config) = temp_config();
+    recovery::record(
+        &PendingEdit::Observation {
+            indicator_id: "gone".to_string(),
+            name: "Gone".to_string(),
+            value: "1".to_string(),
+        },
+        &config,
+    )
+    .unwrap();
+
+    let mut app = App::new(config.clone()).unwrap();
+    press(&mut app, KeyCode::Esc);
+    assert!(app.modal.is_none());
+    assert_eq!(recovery::load(&config).unwrap(), None);
+    assert!(App::new(config).unwrap().modal.is_none());
+}
@@ -0,0 +1,81 @@
+mod common;
+
+use common::temp_config;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use focusfive::data::{load_or_create_day_meta, load_or_create_goals, save_day_meta};
+use focusfive::models::{DayMeta, OutcomeType};
+use focusfive::ui::app::FocusPanel;
+use focusfive::ui::App;
+
+fn alt(code: KeyCode) -> KeyEvent {
+    KeyEvent::new(code, KeyModifiers::ALT)
+}
+
+fn work_texts(app: &App) -> Vec<String> {
+    app.goals
+        .work
+        .actions
+        .iter()
+        .map(|a| a.text.clone())
+        .collect()
+}
+
+#[test]
+fn alt_j_and_k_reorder_and_persist_actions() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    for (action, text) in app.goals.work.actions.iter_mut().zip(["A", "B", "C"]) {
+        action.text = text.to_string();
+    }
+    app.goals.work.actions[0].completed = true;
+
+    let mut meta = DayMeta::from_goals(&app.goals);
+    meta.work[0].estimated_min = Some(45);
+    save_day_meta(app.goals.date, &meta, &config).unwrap();
+
+    // Reordering only applies while the actions list has focus
+    app.handle_key_event(alt(KeyCode::Char('j'))).unwrap();
+    assert_eq!(work_texts(&app), ["A", "B", "C"]);
+
+    app.focus_panel = FocusPanel::Actions;
+    app.handle_key_event(alt(KeyCode::Char('j'))).unwrap();
+    app.handle_key_event(alt(KeyCode::Down)).unwrap();
+    assert_eq!(work_texts(&app), ["B", "C", "A"]);
+    assert_eq!(app.selected_action, 2);
+
+    // Already last: nothing to move
+    app.handle_key_event(alt(KeyCode::Char('j'))).unwrap();
+    assert_eq!(app.selected_action, 2);
+
+    app.handle_key_event(alt(KeyCode::Char('k'))).unwrap();
+    assert_eq!(work_texts(&app), ["B", "A", "C"]);
+    assert_eq!(app.selected_action, 1);
+
+    // Plain j still just moves the selection
+    app.handle_key_event(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE))
+        .unwrap();
+    assert_eq!(app.selected_action, 2);
+    assert_eq!(work_texts(&app), ["B", "A", "C"]);
+
+    let saved = load_or_create_goals(app.goals.date, &config).unwrap();
+    let saved_texts: Vec<_> = saved.work.actions.iter().map(|a| a.text.as_str()).collect();
+    assert_eq!(saved_texts, ["B", "A", "C"]);
+    assert!(saved.work.actions[1].completed);
+
+    let meta = load_or_create_day_meta(saved.date, &saved, &config).unwrap();
+    assert_eq!(meta.work[1].estimated_min, Some(45));
+    assert_eq!(meta.work[0].estimated_min, None);
+}
+
+#[test]
+fn outcome_move_action_checks_bounds() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config).unwrap();
+    let health = &mut app.goals.health;
+    health.actions[2].text = "Last".to_string();
+    health.move_action(2, 0).unwrap();
+    assert_eq!(health.actions[0].text, "Last");
+    assert!(health.move_action(0, 3).is_err());
+    assert_eq!(health.outcome_type, OutcomeType::Health);
+}
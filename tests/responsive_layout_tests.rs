@@ -0,0 +1,71 @@
+mod common;
+
+use common::temp_config;
+use focusfive::ui::layout::create_layout;
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+use ratatui::Terminal;
+
+fn render(app: &mut App, width: u16, height: u16) -> Vec<String> {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect()
+}
+
+#[test]
+fn layout_breakpoints() {
+    let wide = create_layout(Rect::new(0, 0, 160, 40));
+    assert!(wide.stats.is_some());
+    assert!(!wide.compact_header);
+    assert_eq!(wide.outcomes.y, wide.actions.y);
+
+    // Narrow: no stats column, outcomes stacked above the actions
+    let narrow = create_layout(Rect::new(0, 0, 80, 40));
+    assert!(narrow.stats.is_none());
+    assert_eq!(narrow.outcomes.height, 5);
+    assert_eq!(narrow.actions.y, narrow.outcomes.bottom());
+    assert_eq!(narrow.actions.width, 80);
+
+    // Short: a one-line header
+    let short = create_layout(Rect::new(0, 0, 160, 20));
+    assert!(short.compact_header);
+    assert_eq!(short.header.height, 1);
+    assert!(short.stats.is_some());
+
+    // Narrow and short: side by side without the stats column
+    let small = create_layout(Rect::new(0, 0, 80, 20));
+    assert!(small.stats.is_none());
+    assert_eq!(small.outcomes.y, small.actions.y);
+    assert_eq!(small.outcomes.width + small.actions.width, 80);
+}
+
+#[test]
+fn small_terminals_drop_the_stats_and_condense_the_header() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+
+    let screen = render(&mut app, 160, 40);
+    assert!(screen.iter().any(|line| line.contains("STATISTICS")));
+    assert!(screen[1].contains("FOCUSFIVE"));
+
+    let screen = render(&mut app, 80, 40);
+    assert!(!screen.iter().any(|line| line.contains("STATISTICS")));
+    let outcomes = screen.iter().position(|l| l.contains("OUTCOMES")).unwrap();
+    let actions = screen.iter().position(|l| l.contains("ACTIONS")).unwrap();
+    assert!(actions > outcomes, "actions sit below the outcomes");
+    for label in ["Work", "Health", "Family"] {
+        assert!(screen.iter().any(|line| line.contains(label)));
+    }
+
+    let screen = render(&mut app, 80, 20);
+    assert!(screen[0].contains("FOCUSFIVE"));
+    assert!(!screen[0].contains('╭'), "the short header has no border");
+    assert!(!screen.iter().any(|line| line.contains("STATISTICS")));
+}
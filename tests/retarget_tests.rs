@@ -0,0 +1,222 @@
+use chrono::{Local, NaiveDate};
+use crossterm::event::KeyCode;
+use focusfive::data::{
+    append_observation, load_or_create_indicators, load_or_create_objectives, save_indicators,
+    save_objectives,
+};
+use focusfive::models::{
+    Config, IndicatorDef, IndicatorDirection, IndicatorKind, IndicatorUnit, IndicatorsData,
+    Objective, ObjectiveStatus, ObjectivesData, Observation, OutcomeType, TargetStep,
+};
+use focusfive::retarget::{apply, build_items, plan_year, RetargetSubject};
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+use std::fs;
+use tempfile::TempDir;
+
+fn day(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn indicator(name: &str, direction: IndicatorDirection, target: f64) -> IndicatorDef {
+    let mut def = IndicatorDef::new(
+        name.to_string(),
+        IndicatorKind::Leading,
+        IndicatorUnit::Count,
+    );
+    def.direction = direction;
+    def.target = Some(target);
+    def
+}
+
+#[test]
+fn plan_year_rolls_over_in_december() {
+    assert_eq!(plan_year(day(2025, 1, 3)), 2025);
+    assert_eq!(plan_year(day(2025, 11, 30)), 2025);
+    assert_eq!(plan_year(day(2025, 12, 1)), 2026);
+}
+
+#[test]
+fn proposals_follow_last_years_actuals_and_direction() {
+    let pushups = indicator("Pushups", IndicatorDirection::HigherIsBetter, 20.0);
+    let screen = indicator("Screen", IndicatorDirection::LowerIsBetter, 120.0);
+    let mut ramped = indicator("Pages", IndicatorDirection::HigherIsBetter, 10.0);
+    ramped.target_schedule = vec![TargetStep {
+        date: day(2024, 6, 1),
+        target: 15.0,
+    }];
+    let idle = indicator("Idle", IndicatorDirection::HigherIsBetter, 5.0);
+
+    let mut observations = vec![
+        Observation::new(
+            pushups.id.clone(),
+            day(2024, 3, 1),
+            30.0,
+            IndicatorUnit::Count,
+        ),
+        Observation::new(
+            pushups.id.clone(),
+            day(2024, 3, 2),
+            50.0,
+            IndicatorUnit::Count,
+        ),
+        Observation::new(
+            screen.id.clone(),
+            day(2024, 5, 1),
+            100.0,
+            IndicatorUnit::Count,
+        ),
+        Observation::new(
+            ramped.id.clone(),
+            day(2024, 7, 1),
+            20.0,
+            IndicatorUnit::Count,
+        ),
+    ];
+    // A later entry on the same day replaces the earlier one
+    let mut correction = Observation::new(
+        pushups.id.clone(),
+        day(2024, 3, 2),
+        10.0,
+        IndicatorUnit::Count,
+    );
+    correction.created = observations[1].created + chrono::Duration::seconds(5);
+    observations.push(correction);
+
+    let mut indicators = IndicatorsData {
+        version: 1,
+        indicators: vec![pushups, screen, ramped, idle],
+    };
+    let mut finished = Objective::new(OutcomeType::Work, "Launch v2".to_string());
+    finished.end = Some(day(2024, 12, 31));
+    let mut paused = Objective::new(OutcomeType::Health, "Marathon".to_string());
+    paused.status = ObjectiveStatus::Paused;
+    let mut objectives = ObjectivesData {
+        version: 1,
+        objectives: vec![finished, paused],
+    };
+
+    let items = build_items(&indicators, &objectives, &observations, 2025);
+    assert_eq!(items.len(), 5);
+    // (30 + 10) / 2 = 20, stretched 10%
+    assert_eq!(items[0].proposed, Some(22.0));
+    assert!(matches!(
+        items[0].subject,
+        RetargetSubject::Indicator {
+            actual: Some((avg, 2)),
+            ..
+        } if avg == 20.0
+    ));
+    assert_eq!(items[1].proposed, Some(90.0));
+    assert_eq!(items[2].proposed, Some(22.0));
+    // No data keeps the current target and is not pre-selected
+    assert_eq!(items[3].proposed, Some(5.0));
+    assert!(!items[3].accepted);
+    assert_eq!(items[4].name, "Launch v2");
+    assert!(items[4].accepted);
+
+    let changed = apply(&items, &mut indicators, &mut objectives, 2025);
+    assert_eq!(changed, 4);
+    assert_eq!(indicators.indicators[0].target, Some(22.0));
+    assert_eq!(indicators.indicators[1].target, Some(90.0));
+    assert_eq!(indicators.indicators[2].target, Some(10.0));
+    assert_eq!(
+        indicators.indicators[2].target_schedule.last(),
+        Some(&TargetStep {
+            date: day(2025, 1, 1),
+            target: 22.0
+        })
+    );
+    assert_eq!(objectives.objectives[0].end, Some(day(2025, 12, 31)));
+}
+
+#[test]
+fn wizard_applies_edits_in_one_confirmed_batch() {
+    let temp_dir = TempDir::new().unwrap();
+    let goals_dir = temp_dir.path().join("goals");
+    fs::create_dir_all(&goals_dir).unwrap();
+    let config = Config {
+        goals_dir: goals_dir.to_str().unwrap().to_string(),
+        data_root: temp_dir.path().to_str().unwrap().to_string(),
+    };
+
+    let year = plan_year(Local::now().date_naive());
+    let steps = indicator("Steps", IndicatorDirection::HigherIsBetter, 8000.0);
+    let sleep = indicator("Sleep", IndicatorDirection::HigherIsBetter, 7.0);
+    append_observation(
+        &Observation::new(
+            steps.id.clone(),
+            day(year - 1, 8, 1),
+            9000.0,
+            IndicatorUnit::Count,
+        ),
+        &config,
+    )
+    .unwrap();
+    append_observation(
+        &Observation::new(
+            sleep.id.clone(),
+            day(year - 1, 8, 1),
+            7.0,
+            IndicatorUnit::Count,
+        ),
+        &config,
+    )
+    .unwrap();
+    save_indicators(
+        &IndicatorsData {
+            version: 1,
+            indicators: vec![steps.clone(), sleep.clone()],
+        },
+        &config,
+    )
+    .unwrap();
+    let objective = Objective::new(OutcomeType::Family, "Weekly call".to_string());
+    save_objectives(
+        &ObjectivesData {
+            version: 1,
+            objectives: vec![objective],
+        },
+        &config,
+    )
+    .unwrap();
+
+    let mut app = App::new(config.clone()).unwrap();
+
+    // Cancelling writes nothing
+    app.handle_key(KeyCode::Char('R')).unwrap();
+    assert!(matches!(app.modal, Some(ModalState::Retarget(_))));
+    app.handle_key(KeyCode::Enter).unwrap();
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(app.modal.is_none());
+    let stored = load_or_create_indicators(&config).unwrap();
+    assert_eq!(stored.indicators[0].target, Some(8000.0));
+
+    app.handle_key(KeyCode::Char('R')).unwrap();
+    // Steps: accept the 9900 proposal
+    app.handle_key(KeyCode::Enter).unwrap();
+    // Sleep: replace the 7.7 proposal with 8
+    app.handle_key(KeyCode::Backspace).unwrap();
+    app.handle_key(KeyCode::Backspace).unwrap();
+    app.handle_key(KeyCode::Backspace).unwrap();
+    app.handle_key(KeyCode::Char('8')).unwrap();
+    app.handle_key(KeyCode::Enter).unwrap();
+    // Objective: skip
+    app.handle_key(KeyCode::Char('n')).unwrap();
+    match &app.modal {
+        Some(ModalState::Retarget(state)) => assert!(state.confirming),
+        other => panic!("expected confirmation, got {:?}", other),
+    }
+    // Nothing is written before confirming
+    let stored = load_or_create_indicators(&config).unwrap();
+    assert_eq!(stored.indicators[1].target, Some(7.0));
+
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert!(app.modal.is_none());
+
+    let stored = load_or_create_indicators(&config).unwrap();
+    assert_eq!(stored.indicators[0].target, Some(9900.0));
+    assert_eq!(stored.indicators[1].target, Some(8.0));
+    let objectives = load_or_create_objectives(&config).unwrap();
+    assert_eq!(objectives.objectives[0].end, None);
+}
@@ -0,0 +1,178 @@
+mod common;
+
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{
+    append_observation, load_or_create_indicators, load_profile, save_indicators, write_goals_file,
+};
+use focusfive::models::{
+    Config, DailyGoals, DayScore, IndicatorDef, IndicatorDirection, IndicatorKind, IndicatorUnit,
+    Observation,
+};
+use focusfive::scoreboard::{day_xp, level_for, meets_target, refresh_profile, week_xp, Level};
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+fn screen(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A day with `done` of two planned Work actions completed
+fn write_day(date: NaiveDate, done: usize, config: &Config) {
+    let mut goals = DailyGoals::new(date);
+    goals.work.actions[0].text = "Write".to_string();
+    goals.work.actions[1].text = "Review".to_string();
+    for action in goals.work.actions.iter_mut().take(done) {
+        action.completed = true;
+    }
+    write_goals_file(&goals, config).unwrap();
+}
+
+#[test]
+fn levels_take_more_xp_each_time() {
+    assert_eq!(
+        level_for(0),
+        Level {
+            level: 1,
+            progress: 0,
+            needed: 100
+        }
+    );
+    assert_eq!(level_for(100).level, 2);
+    assert_eq!(
+        level_for(350),
+        Level {
+            level: 3,
+            progress: 50,
+            needed: 300
+        }
+    );
+
+    let score = DayScore {
+        completions: 3,
+        planned: 4,
+        streak: 5,
+        target_hits: 1,
+    };
+    assert_eq!(day_xp(&score), 30 + 15 + 10);
+    // The streak bonus stops growing after 30 days
+    let long = DayScore {
+        streak: 90,
+        ..score
+    };
+    assert_eq!(day_xp(&long), 30 + 15 + 60);
+}
+
+#[test]
+fn targets_are_met_in_the_indicator_direction() {
+    let date = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+    let mut def = IndicatorDef::new(
+        "Steps".to_string(),
+        IndicatorKind::Leading,
+        IndicatorUnit::Count,
+    );
+    assert!(!meets_target(&def, 10.0, date));
+    def.target = Some(8000.0);
+    assert!(meets_target(&def, 8000.0, date));
+    assert!(!meets_target(&def, 7999.0, date));
+    def.direction = IndicatorDirection::LowerIsBetter;
+    assert!(meets_target(&def, 7999.0, date));
+    def.direction = IndicatorDirection::WithinRange;
+    def.range_min = Some(10.0);
+    def.range_max = Some(20.0);
+    assert!(meets_target(&def, 15.0, date));
+    assert!(!meets_target(&def, 21.0, date));
+}
+
+#[test]
+fn history_is_scored_into_the_profile() {
+    let (_temp_dir, config) = temp_config();
+    let today = Local::now().date_naive();
+    write_day(today - Duration::days(3), 1, &config);
+    write_day(today - Duration::days(1), 2, &config);
+    write_day(today, 1, &config);
+
+    let mut indicators = load_or_create_indicators(&config).unwrap();
+    let mut def = IndicatorDef::new(
+        "Steps".to_string(),
+        IndicatorKind::Leading,
+        IndicatorUnit::Count,
+    );
+    def.target = Some(8000.0);
+    let id = def.id.clone();
+    indicators.indicators.push(def);
+    save_indicators(&indicators, &config).unwrap();
+    // Only the day's last reading counts
+    for value in [9000.0, 7000.0] {
+        let observation = Observation::new(id.clone(), today, value, IndicatorUnit::Count);
+        append_observation(&observation, &config).unwrap();
+    }
+    let observation = Observation::new(
+        id.clone(),
+        today - Duration::days(1),
+        8500.0,
+        IndicatorUnit::Count,
+    );
+    append_observation(&observation, &config).unwrap();
+
+    let (profile, earned) = refresh_profile(today, &config).unwrap();
+    let ids: Vec<&str> = earned.iter().map(|a| a.id).collect();
+    assert_eq!(ids, ["first_step", "perfect_day", "on_target"]);
+    assert_eq!(profile.days.len(), 3);
+    assert_eq!(profile.days[&(today - Duration::days(3))].streak, 1);
+    let yesterday = profile.days[&(today - Duration::days(1))];
+    assert_eq!(
+        yesterday,
+        DayScore {
+            completions: 2,
+            planned: 2,
+            streak: 1,
+            target_hits: 1
+        }
+    );
+    assert_eq!(profile.days[&today].streak, 2);
+    assert_eq!(profile.days[&today].target_hits, 0);
+    assert_eq!(load_profile(&config).unwrap(), profile);
+
+    let monday = today - Duration::days(i64::from(today.weekday().num_days_from_monday()));
+    let week: u32 = profile
+        .days
+        .iter()
+        .filter(|(date, _)| **date >= monday)
+        .map(|(_, score)| day_xp(score))
+        .sum();
+    assert_eq!(week_xp(&profile, today), week);
+
+    // Trophies are only awarded once
+    let (again, earned) = refresh_profile(today, &config).unwrap();
+    assert!(earned.is_empty());
+    assert_eq!(again, profile);
+}
+
+#[test]
+fn header_shows_the_level_and_trophies_list_achievements() {
+    let (_temp_dir, config) = temp_config();
+    write_day(Local::now().date_naive(), 1, &config);
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    app.toasts.clear();
+    assert!(screen(&mut app).contains("★ Lv 1 · 12 XP this week"));
+
+    app.handle_key(KeyCode::Char('O')).unwrap();
+    let screen = screen(&mut app);
+    assert!(screen.contains("Trophies"));
+    assert!(screen.contains("First Step"));
+    assert!(screen.contains("12/100 XP to level 2"));
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(app.modal.is_none());
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::models::{Action, Config};
+use focusfive::ui::snapshot::{to_ansi, to_svg};
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::Terminal;
+use regex::Regex;
+use std::fs;
+use std::path::PathBuf;
+
+fn draw(app: &mut App, width: u16, height: u16) {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+}
+
+/// Files written under exports/, sorted
+fn exports(config: &Config) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(PathBuf::from(&config.data_root).join("exports"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    files.sort();
+    files
+}
+
+#[test]
+fn f12_saves_the_screen_as_ansi_text_and_svg() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    app.goals.work.actions[0] = Action::new("Write report".to_string());
+    draw(&mut app, 100, 30);
+
+    app.handle_key(KeyCode::F(12)).unwrap();
+    let files = exports(&config);
+    assert_eq!(files.len(), 2);
+    assert_eq!(files[0].extension().unwrap(), "ans");
+    assert_eq!(files[1].extension().unwrap(), "svg");
+    assert!(app
+        .toasts
+        .history()
+        .iter()
+        .any(|toast| toast.message.starts_with("Saved the screen")));
+
+    let ansi = fs::read_to_string(&files[0]).unwrap();
+    let plain = Regex::new("\x1b\\[[0-9;]*m")
+        .unwrap()
+        .replace_all(&ansi, "");
+    let rows: Vec<&str> = plain.lines().collect();
+    assert_eq!(rows.len(), 30, "one line per row of the frame");
+    assert!(rows.iter().all(|row| row.chars().count() <= 100));
+    assert!(plain.contains("Write report"));
+
+    let svg = fs::read_to_string(&files[1]).unwrap();
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.contains("width=\"840\""));
+    assert!(svg.contains("Write report"));
+    assert!(svg.trim_end().ends_with("</svg>"));
+}
+
+#[test]
+fn the_dashboard_can_be_saved_too() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    app.handle_key(KeyCode::Char('d')).unwrap();
+    draw(&mut app, 120, 40);
+
+    app.handle_key(KeyCode::F(12)).unwrap();
+    assert!(app.show_dashboard);
+    let ansi = fs::read_to_string(&exports(&config)[0]).unwrap();
+    assert_eq!(ansi.lines().count(), 40);
+}
+
+#[test]
+fn styles_become_escapes_and_svg_attributes() {
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+    buffer.set_string(
+        0,
+        0,
+        "a<b",
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+    );
+    buffer.set_string(3, 0, "日x", Style::default().bg(Color::Rgb(1, 2, 3)));
+
+    let ansi = to_ansi(&buffer);
+    assert!(ansi.starts_with("\x1b[0;31;1ma<b"));
+    // The wide character covers two cells without a filler
+    assert!(ansi.contains("\x1b[0;48;2;1;2;3m日x\x1b[0m\n"));
+
+    let svg = to_svg(&buffer, Color::White, Color::Black);
+    assert!(svg.contains("fill=\"#cd0000\" font-weight=\"bold\">a&lt;b</text>"));
+    assert!(svg.contains("fill=\"#010203\""));
+    assert!(svg.contains(">日x</text>"));
+}
@@ -0,0 +1,112 @@
+mod common;
+
+use chrono::{Duration, Local, NaiveDate};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{load_sentiment_history, save_reflection, write_goals_file};
+use focusfive::models::{DailyGoals, OutcomeType};
+use focusfive::sentiment::{refresh_history, score_day};
+use focusfive::ui::App;
+
+/// Score of `text` as the only reflection of a day
+fn score_text(text: &str) -> Option<f64> {
+    let mut goals = DailyGoals::new(NaiveDate::from_ymd_opt(2025, 5, 1).unwrap());
+    goals.work.reflection = Some(text.to_string());
+    score_day(&goals, None).first().map(|point| point.score)
+}
+
+#[test]
+fn lexicon_scores_with_negation() {
+    assert_eq!(score_text("Great run, felt strong and proud."), Some(1.0));
+    assert_eq!(
+        score_text("Tired and stressed after a rough day"),
+        Some(-1.0)
+    );
+    assert_eq!(
+        score_text("Productive morning but tired by evening"),
+        Some(0.0)
+    );
+    assert_eq!(score_text("I wasn’t happy with the draft"), Some(-1.0));
+    assert_eq!(score_text("Not too bad"), Some(1.0));
+    assert_eq!(score_text("Walked the dog"), None);
+}
+
+#[test]
+fn day_scores_combine_outcome_and_evening_reflections() {
+    let date = NaiveDate::from_ymd_opt(2025, 5, 2).unwrap();
+    let mut goals = DailyGoals::new(date);
+    goals.work.reflection = Some("Blocked on review, frustrated".to_string());
+    goals.health.reflection = Some("Rested and happy".to_string());
+    goals.family.actions[0].text = "Board games".to_string();
+
+    let points = score_day(&goals, None);
+    assert_eq!(points.len(), 2);
+    assert_eq!(points[0].outcome, OutcomeType::Work);
+    assert_eq!(points[0].score, -1.0);
+    assert_eq!(points[1].outcome, OutcomeType::Health);
+    assert_eq!(points[1].hits, 2);
+
+    // Sentences naming an outcome or its action stay with it; the rest count everywhere
+    let points = score_day(
+        &goals,
+        Some("Work felt better after lunch. Board games were fun! A good day."),
+    );
+    assert_eq!(points.len(), 3);
+    // Work: two negative notes, "better" and "good"
+    assert_eq!(points[0].hits, 4);
+    assert_eq!(points[0].score, 0.0);
+    // Health: its note and "good"
+    assert_eq!(points[1].hits, 3);
+    assert_eq!(points[2].outcome, OutcomeType::Family);
+    assert_eq!(points[2].hits, 2);
+    assert_eq!(points[2].score, 1.0);
+}
+
+#[test]
+fn history_is_persisted_and_rescored_in_window() {
+    let (_temp_dir, config) = temp_config();
+    let end = NaiveDate::from_ymd_opt(2025, 5, 10).unwrap();
+    let earlier = end - Duration::days(3);
+
+    save_reflection(earlier, "Family dinner, we laughed a lot", &config).unwrap();
+    save_reflection(end, "Exhausted but proud", &config).unwrap();
+    // A day with a plan but nothing to score adds no points
+    write_goals_file(&DailyGoals::new(end - Duration::days(1)), &config).unwrap();
+
+    let history = refresh_history(end, 7, &config).unwrap();
+    assert_eq!(
+        history.series(OutcomeType::Family),
+        vec![(earlier, 1.0), (end, 0.0)]
+    );
+    assert_eq!(history.series(OutcomeType::Work), vec![(end, 0.0)]);
+    assert_eq!(load_sentiment_history(&config).unwrap(), history);
+
+    // Re-scoring one day replaces only that day's points
+    save_reflection(end, "Exhausted", &config).unwrap();
+    let history = refresh_history(end, 1, &config).unwrap();
+    assert_eq!(
+        history.series(OutcomeType::Family),
+        vec![(earlier, 1.0), (end, -1.0)]
+    );
+}
+
+#[test]
+fn saving_evening_reflection_updates_app_sentiment() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config.clone()).unwrap();
+    let today = Local::now().date_naive();
+    assert!(app.sentiment.series(OutcomeType::Work).is_empty());
+
+    app.handle_key(KeyCode::Char('S')).unwrap();
+    for _ in 0..app.text_editor.text.chars().count() {
+        app.handle_key(KeyCode::Backspace).unwrap();
+    }
+    for ch in "Calm and focused".chars() {
+        app.handle_key(KeyCode::Char(ch)).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+
+    assert_eq!(app.sentiment.series(OutcomeType::Work), vec![(today, 1.0)]);
+    let stored = load_sentiment_history(&config).unwrap();
+    assert_eq!(stored.points.len(), 3);
+}
@@ -0,0 +1,109 @@
+mod common;
+
+use chrono::{Duration, Local, NaiveDate};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::carryover::{arrivals, is_new_day, snooze_action};
+use focusfive::data::{load_or_create_day_meta, load_or_create_goals, write_goals_file};
+use focusfive::models::{ActionOrigin, DailyGoals, OutcomeType};
+use focusfive::ui::app::{FocusPanel, ModalState};
+use focusfive::ui::App;
+
+#[test]
+fn snoozing_moves_the_action_and_leaves_a_pointer() {
+    let (_temp_dir, config) = temp_config();
+    let day = NaiveDate::from_ymd_opt(2025, 6, 4).unwrap();
+    let later = day + Duration::days(3);
+    let mut goals = DailyGoals::new(day);
+    goals.health.actions[1].text = "Book physio".to_string();
+    goals.health.actions[1].add_objective_id("obj-7".to_string());
+    write_goals_file(&goals, &config).unwrap();
+
+    assert!(snooze_action(&mut goals, OutcomeType::Health, 1, day, &config).is_err());
+    assert!(snooze_action(&mut goals, OutcomeType::Health, 0, later, &config).is_err());
+    snooze_action(&mut goals, OutcomeType::Health, 1, later, &config).unwrap();
+
+    let saved = load_or_create_goals(day, &config).unwrap();
+    assert!(saved.health.actions[1].text.is_empty());
+    assert_eq!(saved.health.actions.len(), 3);
+    let meta = load_or_create_day_meta(day, &saved, &config).unwrap();
+    assert_eq!(meta.snoozed.len(), 1);
+    assert_eq!(meta.snoozed[0].text, "Book physio");
+    assert_eq!(meta.snoozed[0].until, later);
+
+    let target = load_or_create_goals(later, &config).unwrap();
+    assert_eq!(target.health.actions[0].text, "Book physio");
+    assert_eq!(target.health.actions[0].get_all_objective_ids(), ["obj-7"]);
+    let meta = load_or_create_day_meta(later, &target, &config).unwrap();
+    assert_eq!(meta.health[0].origin, ActionOrigin::CarryOver);
+    assert_eq!(meta.health[0].snoozed_from, Some(day));
+    assert_eq!(meta.health[0].carried_from, Some(day));
+    assert_eq!(arrivals(&target, &config).unwrap(), ["Book physio"]);
+    assert!(arrivals(&saved, &config).unwrap().is_empty());
+
+    // A day holding only snoozed arrivals still counts as new
+    assert!(is_new_day(later, &config).unwrap());
+    assert!(!is_new_day(day, &config).unwrap());
+}
+
+#[test]
+fn z_snoozes_the_selected_action_from_the_app() {
+    let (_temp_dir, config) = temp_config();
+    let today = Local::now().date_naive();
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    app.focus_panel = FocusPanel::Actions;
+
+    app.handle_key(KeyCode::Char('z')).unwrap();
+    assert!(app.modal.is_none(), "empty actions cannot be snoozed");
+
+    app.goals.work.actions[0].text = "Write proposal".to_string();
+    app.handle_key(KeyCode::Char('z')).unwrap();
+    let Some(ModalState::Snooze(state)) = app.modal.clone() else {
+        panic!("expected the snooze prompt");
+    };
+    assert_eq!(state.until, today + Duration::days(1));
+    // Never earlier than tomorrow
+    app.handle_key(KeyCode::Left).unwrap();
+    app.handle_key(KeyCode::Char('w')).unwrap();
+    app.handle_key(KeyCode::Right).unwrap();
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert!(app.modal.is_none());
+    assert!(app.goals.work.actions[0].text.is_empty());
+
+    let until = today + Duration::days(9);
+    let target = load_or_create_goals(until, &config).unwrap();
+    assert_eq!(target.work.actions[0].text, "Write proposal");
+    let saved = load_or_create_goals(today, &config).unwrap();
+    assert!(saved.work.actions[0].text.is_empty());
+}
+
+#[test]
+fn arrivals_are_announced_and_still_get_the_carry_over_wizard() {
+    let (_temp_dir, config) = temp_config();
+    let today = Local::now().date_naive();
+    let yesterday = today - Duration::days(1);
+    let earlier = today - Duration::days(4);
+
+    let mut past = DailyGoals::new(earlier);
+    past.family.actions[0].text = "Plan picnic".to_string();
+    write_goals_file(&past, &config).unwrap();
+    snooze_action(&mut past, OutcomeType::Family, 0, today, &config).unwrap();
+
+    let mut open = DailyGoals::new(yesterday);
+    open.work.actions[0].text = "Unfinished".to_string();
+    write_goals_file(&open, &config).unwrap();
+
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    assert_eq!(app.snoozed_arrivals, ["Plan picnic"]);
+    assert!(matches!(app.modal, Some(ModalState::CarryOver(_))));
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert_eq!(app.goals.family.actions[0].text, "Plan picnic");
+    assert_eq!(app.goals.work.actions[0].text, "Unfinished");
+
+    // Triage is done once
+    assert!(!is_new_day(today, &config).unwrap());
+    let app = App::new(config).unwrap();
+    assert!(app.modal.is_none());
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{generate_markdown, parse_markdown, MARKDOWN_WRAP_WIDTH};
+use focusfive::models::{DailyGoals, OutcomeType, MAX_ACTION_LENGTH, MAX_VISION_LENGTH};
+use focusfive::ui::app::FocusPanel;
+use focusfive::ui::popup::{EditorResult, TextEditor};
+use focusfive::ui::App;
+
+fn long_text(words: usize) -> String {
+    (0..words)
+        .map(|i| format!("word{}", i))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[test]
+fn editor_counts_past_the_limit_and_needs_a_second_enter() {
+    let mut editor = TextEditor::new("Edit");
+    editor.activate_with("Edit", "", 5);
+    for c in "abcdefg".chars() {
+        editor.handle_input(KeyCode::Char(c));
+    }
+    assert_eq!(editor.text, "abcdefg");
+    assert!(editor.is_over_limit());
+
+    assert!(editor.handle_input(KeyCode::Enter) == EditorResult::Continue);
+    assert!(editor.override_pending);
+    // Editing again asks for confirmation again
+    editor.handle_input(KeyCode::Backspace);
+    assert!(!editor.override_pending);
+    assert!(editor.handle_input(KeyCode::Enter) == EditorResult::Continue);
+    assert!(editor.handle_input(KeyCode::Enter) == EditorResult::Save);
+
+    editor.activate_with("Edit", "ok", 5);
+    assert!(!editor.override_pending);
+    assert!(editor.handle_input(KeyCode::Enter) == EditorResult::Save);
+}
+
+#[test]
+fn over_limit_actions_wrap_in_markdown_and_read_back_whole() {
+    let mut goals = DailyGoals::new(NaiveDate::from_ymd_opt(2025, 6, 4).unwrap());
+    let text = long_text(120);
+    assert!(text.len() > MAX_ACTION_LENGTH);
+    goals.work.actions[0].text = format!("{} - objective: # kept", text);
+    goals.work.actions[0].completed = true;
+    goals.work.actions[0].add_objective_id("obj-1".to_string());
+    goals.work.actions[1].text = "Short one".to_string();
+
+    let markdown = generate_markdown(&goals);
+    let work: Vec<&str> = markdown
+        .lines()
+        .skip_while(|line| !line.starts_with("## Work"))
+        .skip(1)
+        .take_while(|line| !line.is_empty())
+        .collect();
+    assert!(work[0].starts_with("- [x] word0 word1"));
+    assert!(work[1].starts_with("  word"));
+    // Words that would read back as markup stay on the line before them
+    assert!(work
+        .iter()
+        .filter(|line| !line.ends_with("- objective: #"))
+        .all(|line| line.chars().count() <= MARKDOWN_WRAP_WIDTH + 6));
+    assert!(work.iter().any(|line| line.ends_with("- objective: #")));
+    assert!(work.contains(&"  objective: obj-1"));
+    assert!(work.contains(&"- [ ] Short one"));
+
+    let parsed = parse_markdown(&markdown).unwrap();
+    assert_eq!(parsed.work.actions[0].text, goals.work.actions[0].text);
+    assert!(parsed.work.actions[0].completed);
+    assert_eq!(parsed.work.actions[0].get_all_objective_ids(), ["obj-1"]);
+    assert_eq!(parsed.work.actions[1].text, "Short one");
+}
+
+#[test]
+fn app_saves_long_action_and_vision_after_override() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+    app.focus_panel = FocusPanel::Actions;
+
+    let text = "x".repeat(MAX_ACTION_LENGTH + 20);
+    app.handle_key(KeyCode::Char('E')).unwrap();
+    for c in text.chars() {
+        app.handle_key(KeyCode::Char(c)).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert!(app.text_editor.is_active, "first Enter only warns");
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert!(!app.text_editor.is_active);
+    assert_eq!(app.goals.work.actions[0].text, text);
+
+    let vision = "v".repeat(MAX_VISION_LENGTH + 1);
+    app.handle_key(KeyCode::Char('v')).unwrap();
+    for c in vision.chars() {
+        app.handle_key(KeyCode::Char(c)).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert_eq!(app.vision.get_vision(&OutcomeType::Work), vision);
+}
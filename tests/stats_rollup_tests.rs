@@ -0,0 +1,103 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use focusfive::data::{append_observation, load_stats_cache, save_stats_cache, write_goals_file};
+use focusfive::models::{Config, DailyGoals, IndicatorUnit, Observation, OutcomeType};
+use focusfive::ui::stats::{calculate_rollups, RollupPeriod};
+use std::fs;
+
+fn date(month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+}
+
+/// A day with `work` Work actions and `health` Health actions done
+fn write_day(date: NaiveDate, work: usize, health: usize, config: &Config) {
+    let mut goals = DailyGoals::new(date);
+    for (outcome, done) in [(&mut goals.work, work), (&mut goals.health, health)] {
+        for action in outcome.actions.iter_mut().take(done) {
+            action.text = "Done".to_string();
+            action.completed = true;
+        }
+    }
+    write_goals_file(&goals, config).unwrap();
+}
+
+#[test]
+fn period_bounds_and_labels() {
+    let (start, end) = RollupPeriod::Month.bounds(date(2, 14));
+    assert_eq!((start, end), (date(2, 1), date(2, 28)));
+    assert_eq!(RollupPeriod::Month.label(start), "Feb 2025");
+
+    let (start, end) = RollupPeriod::Quarter.bounds(date(11, 3));
+    assert_eq!((start, end), (date(10, 1), date(12, 31)));
+    assert_eq!(RollupPeriod::Quarter.label(start), "Q4 2025");
+}
+
+#[test]
+fn rollups_compare_with_previous_period() {
+    let (_temp_dir, config) = temp_config();
+    // June (Q2): one Health-heavy day
+    write_day(date(6, 20), 0, 3, &config);
+    // August: one day at 1/9
+    write_day(date(8, 5), 1, 0, &config);
+    // September: 3/9 and 6/9, Work ahead
+    write_day(date(9, 1), 3, 0, &config);
+    write_day(date(9, 2), 3, 3, &config);
+    for (day, value) in [(1, 4.0), (2, 8.0)] {
+        let obs = Observation::new(
+            "calls".to_string(),
+            date(9, day),
+            value,
+            IndicatorUnit::Count,
+        );
+        append_observation(&obs, &config).unwrap();
+    }
+
+    let (months, quarters) = calculate_rollups(date(9, 10), &config).unwrap();
+    assert_eq!(months.current.label, "Sep 2025");
+    assert_eq!(months.current.days_tracked, 2);
+    assert!((months.current.completion - 50.0).abs() < 1e-9);
+    assert_eq!(months.current.best_outcome, Some(OutcomeType::Work));
+    assert_eq!(months.current.worst_outcome, Some(OutcomeType::Family));
+    assert_eq!(months.current.indicator_averages["calls"], 6.0);
+    let change = months.completion_change().unwrap();
+    assert!((change - (50.0 - 100.0 / 9.0)).abs() < 1e-9);
+
+    assert_eq!(quarters.current.label, "Q3 2025");
+    assert_eq!(quarters.current.days_tracked, 3);
+    assert_eq!(quarters.previous.days_tracked, 1);
+    assert_eq!(quarters.previous.best_outcome, Some(OutcomeType::Health));
+
+    // An untracked previous period has nothing to compare against
+    let (months, _) = calculate_rollups(date(7, 31), &config).unwrap();
+    assert_eq!(months.current.days_tracked, 0);
+    assert_eq!(months.completion_change(), None);
+}
+
+#[test]
+fn unchanged_files_come_from_the_stats_cache() {
+    let (_temp_dir, config) = temp_config();
+    write_day(date(9, 1), 0, 0, &config);
+    calculate_rollups(date(9, 1), &config).unwrap();
+
+    let mut cache = load_stats_cache(&config).unwrap();
+    assert_eq!(cache.days.len(), 1);
+
+    // Same size and mtime: the cached figure is trusted
+    cache.days.get_mut(&date(9, 1)).unwrap().completion = 100.0;
+    save_stats_cache(&cache, &config).unwrap();
+    let (months, _) = calculate_rollups(date(9, 1), &config).unwrap();
+    assert_eq!(months.current.completion, 100.0);
+
+    // Rewriting the file is picked up again
+    write_day(date(9, 1), 3, 0, &config);
+    let (months, _) = calculate_rollups(date(9, 1), &config).unwrap();
+    assert!((months.current.completion - 100.0 / 3.0).abs() < 1e-9);
+
+    // Deleted files drop out of the cache
+    fs::remove_file(format!("{}/2025-09-01.md", config.goals_dir)).unwrap();
+    let (months, _) = calculate_rollups(date(9, 1), &config).unwrap();
+    assert_eq!(months.current.days_tracked, 0);
+    assert!(load_stats_cache(&config).unwrap().days.is_empty());
+}
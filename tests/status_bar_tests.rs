@@ -0,0 +1,165 @@
+mod common;
+
+use chrono::Local;
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{append_observation, save_indicators};
+use focusfive::models::{
+    Action, IndicatorDef, IndicatorKind, IndicatorUnit, IndicatorsData, Observation,
+};
+use focusfive::ui::app::DashboardPanel;
+use focusfive::ui::keymap::{KeyAction, Keymap, Mode, Scope};
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+/// The last two rows of a 160x40 screen
+fn status_bar(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(160, 40)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    let rows: Vec<String> = buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect();
+    rows[rows.len() - 2..].join("\n")
+}
+
+#[test]
+fn hints_follow_the_focused_panel() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+    app.goals.work.actions[0] = Action::new("Write report".to_string());
+
+    let bar = status_bar(&mut app);
+    assert!(bar.contains("OUTCOMES"));
+    assert!(bar.contains("j/k Move"));
+    assert!(bar.contains("v Vision"));
+    assert!(bar.contains("S Reflect"));
+    assert!(!bar.contains("Snooze"), "action hints stay out: {}", bar);
+
+    app.handle_key(KeyCode::Tab).unwrap();
+    let bar = status_bar(&mut app);
+    assert!(bar.contains("ACTIONS"));
+    assert!(bar.contains("Space Done"));
+    assert!(bar.contains("1/2/3 Priority"));
+    assert!(bar.contains("z Snooze"));
+    assert!(!bar.contains("Vision"));
+
+    app.handle_key(KeyCode::Char('f')).unwrap();
+    assert!(app.focus_mode);
+    let screen = {
+        let mut terminal = Terminal::new(TestBackend::new(160, 40)).unwrap();
+        terminal.draw(|frame| app.render(frame)).unwrap();
+        let buffer = terminal.backend().buffer().clone();
+        buffer
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect::<String>()
+    };
+    assert!(screen.contains("Esc Back to planning"));
+    assert!(screen.contains("p/P Focus timer"));
+}
+
+#[test]
+fn dashboard_hints_follow_the_panel() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+    app.handle_key(KeyCode::Char('d')).unwrap();
+    assert!(app.show_dashboard);
+
+    let bar = status_bar(&mut app);
+    assert!(bar.contains("LIVE METRICS"));
+    assert!(bar.contains("h/l Panels"));
+    assert!(!bar.contains("Zoom"));
+
+    app.dashboard_focus = DashboardPanel::Performance;
+    let bar = status_bar(&mut app);
+    assert!(bar.contains("PERFORMANCE"));
+    assert!(bar.contains("z/Z Zoom"));
+    assert!(bar.contains("[/] Pan"));
+    assert!(!bar.contains("Normalize"), "only while comparing");
+}
+
+#[test]
+fn handlers_and_hints_share_the_keymap() {
+    let keymap = Keymap::default();
+    assert_eq!(
+        keymap.action(Mode::Goals, KeyCode::Char('j')),
+        Some(KeyAction::MoveDown)
+    );
+    assert_eq!(
+        keymap.action(Mode::Goals, KeyCode::Down),
+        Some(KeyAction::MoveDown)
+    );
+    assert_eq!(
+        keymap.action(Mode::Focus, KeyCode::Tab),
+        Some(KeyAction::MoveDown)
+    );
+    assert_eq!(
+        keymap.action(Mode::Goals, KeyCode::Char('2')),
+        Some(KeyAction::Priority(2))
+    );
+    // `d` leaves the dashboard; deleting an indicator has its own key
+    assert_eq!(
+        keymap.action(Mode::Dashboard, KeyCode::Char('d')),
+        Some(KeyAction::ToggleDashboard)
+    );
+    assert_eq!(
+        keymap.action(Mode::Dashboard, KeyCode::Delete),
+        Some(KeyAction::DeleteIndicator)
+    );
+    assert_eq!(keymap.action(Mode::Focus, KeyCode::Char('d')), None);
+
+    let hints = keymap.hints(Mode::Focus, |_| true);
+    assert_eq!(hints[0], ("j/k".to_string(), "Choose"));
+    let all = keymap.hints(Mode::Dashboard, |_| true);
+    let scoped = keymap.hints(Mode::Dashboard, |scope| scope == Scope::All);
+    assert!(scoped.len() < all.len());
+}
+
+#[test]
+fn delete_moves_the_selected_indicator_to_the_trash() {
+    let (_temp_dir, config) = temp_config();
+    let sleep = IndicatorDef::new(
+        "Sleep".to_string(),
+        IndicatorKind::Leading,
+        IndicatorUnit::Minutes,
+    );
+    append_observation(
+        &Observation::new(
+            sleep.id.clone(),
+            Local::now().date_naive(),
+            420.0,
+            IndicatorUnit::Minutes,
+        ),
+        &config,
+    )
+    .unwrap();
+    let indicators = IndicatorsData {
+        version: 1,
+        indicators: vec![sleep.clone()],
+    };
+    save_indicators(&indicators, &config).unwrap();
+
+    let mut app = App::new(config).unwrap();
+    app.handle_key(KeyCode::Char('d')).unwrap();
+    // The first frame queues the dashboard load
+    status_bar(&mut app);
+    app.wait_for_background();
+    status_bar(&mut app);
+    app.dashboard_focus = DashboardPanel::Performance;
+    assert!(app.dashboard_performance_ids.contains(&sleep.id));
+
+    app.handle_key(KeyCode::Delete).unwrap();
+    assert!(app
+        .indicators
+        .indicators
+        .iter()
+        .all(|def| def.id != sleep.id));
+    assert!(app.show_dashboard, "Delete does not leave the dashboard");
+}
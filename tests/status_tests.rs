@@ -0,0 +1,69 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use focusfive::data::{load_stats_cache, write_goals_file};
+use focusfive::models::{Config, DailyGoals};
+use focusfive::ui::stats::day_status;
+use std::fs;
+use std::path::Path;
+
+fn date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 3, 10).unwrap()
+}
+
+/// A day with `planned` work actions, the first `done` of them completed
+fn write_day(date: NaiveDate, planned: usize, done: usize, config: &Config) {
+    let mut goals = DailyGoals::new(date);
+    for (index, action) in goals.work.actions.iter_mut().take(planned).enumerate() {
+        action.text = format!("Task {}", index + 1);
+        action.completed = index < done;
+    }
+    write_goals_file(&goals, config).unwrap();
+}
+
+#[test]
+fn short_status_counts_actions_and_streak() {
+    let (_temp_dir, config) = temp_config();
+    write_day(date() - chrono::Duration::days(3), 1, 1, &config);
+    write_day(date() - chrono::Duration::days(1), 3, 1, &config);
+    write_day(date(), 3, 2, &config);
+
+    let status = day_status(date(), &config);
+    assert_eq!(status.actions, [(2, 3), (0, 0), (0, 0)]);
+    assert_eq!(status.streak, 2);
+    assert_eq!(status.short(), "W 2/3 H 0/0 F 0/0 🔥2");
+    // Figures are kept in stats.json for the next call
+    let cache = load_stats_cache(&config).unwrap();
+    assert_eq!(cache.days[&date()].actions[0], (2, 3));
+}
+
+#[test]
+fn days_without_a_completion_end_the_streak() {
+    let (_temp_dir, config) = temp_config();
+    write_day(date() - chrono::Duration::days(1), 2, 2, &config);
+    write_day(date(), 2, 0, &config);
+    assert_eq!(day_status(date(), &config).streak, 0);
+    assert_eq!(
+        day_status(date() - chrono::Duration::days(1), &config).streak,
+        1
+    );
+}
+
+#[test]
+fn old_caches_are_rebuilt_with_counts() {
+    let (_temp_dir, config) = temp_config();
+    write_day(date(), 3, 3, &config);
+    day_status(date(), &config);
+    // A version 1 cache has no per-outcome counts
+    let path = Path::new(&config.data_root).join("stats.json");
+    let mut cache: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    cache["version"] = 1.into();
+    for day in cache["days"].as_object_mut().unwrap().values_mut() {
+        day.as_object_mut().unwrap().remove("actions");
+    }
+    fs::write(&path, cache.to_string()).unwrap();
+
+    assert_eq!(day_status(date(), &config).actions[0], (3, 3));
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use focusfive::app::App as RitualApp;
+use focusfive::data::{save_reflection, save_settings, write_goals_file};
+use focusfive::models::{DailyGoals, FiveYearVision, Settings, SummarySettings};
+use focusfive::summary::{
+    load_template, render_summary, render_template, summary_values, SummaryInput, SummaryValues,
+    TemplateValue,
+};
+use std::fs;
+
+fn sample_goals() -> DailyGoals {
+    let mut goals = DailyGoals::new(NaiveDate::from_ymd_opt(2025, 6, 2).unwrap());
+    goals.day_number = Some(4);
+    goals.work.actions[0].text = "Ship release notes".to_string();
+    goals.work.actions[0].completed = true;
+    goals.work.actions[1].text = "Review PR".to_string();
+    goals.health.actions[0].text = "Run 5k".to_string();
+    goals.health.actions[0].completed = true;
+    goals.family.reflection = Some("Long dinner".to_string());
+    goals
+}
+
+#[test]
+fn placeholders_blocks_and_standalone_lines() {
+    let mut values = SummaryValues::new();
+    values.insert("name", TemplateValue::Text("Day".to_string()));
+    values.insert("zero", TemplateValue::Text("0".to_string()));
+    values.insert(
+        "wins",
+        TemplateValue::List(vec!["a".to_string(), "b".to_string()]),
+    );
+    values.insert("none", TemplateValue::List(Vec::new()));
+
+    let template = "\
+{{ name }}: {{wins}}
+{{#each wins}}
+- {{this}}
+{{/each}}
+{{#if none}}
+hidden
+{{else}}
+no items{{#if zero}}!{{/if}}
+{{/if}}
+";
+    assert_eq!(
+        render_template(template, &values).unwrap(),
+        "Day: a, b\n- a\n- b\nno items\n"
+    );
+
+    let unknown = render_template("{{wnis}}", &values).unwrap_err();
+    assert!(unknown.to_string().contains("'wnis'"));
+    assert!(unknown.to_string().contains("wins"));
+    assert!(render_template("{{#each wins}}x", &values).is_err());
+    assert!(render_template("{{/if}}", &values).is_err());
+    assert!(render_template("{{#each name}}x{{/each}}", &values).is_err());
+    assert!(render_template("{{this}}", &values).is_err());
+}
+
+#[test]
+fn built_in_template_keeps_the_classic_summary() {
+    let (_temp_dir, config) = temp_config();
+    let goals = sample_goals();
+    let input = SummaryInput::gather(&goals, &Default::default(), &[]);
+
+    let text = render_summary(&goals, &input, None, &SummarySettings::default(), &config).unwrap();
+    assert!(text.starts_with("📊 Day 4 Summary\nCompletion: 2/9 (22%)\n\n"));
+    assert!(text.contains("⚠️ Work: 1/3\n⚠️ Health: 1/3\n❌ Family: 0/3\n"));
+    assert!(text.ends_with("\n📝 Reflections:\nFamily: Long dinner\n"));
+
+    let values = summary_values(&goals, &input, Some("Calm day"));
+    assert_eq!(
+        values["wins"],
+        TemplateValue::List(vec!["Ship release notes".into(), "Run 5k".into()])
+    );
+    assert_eq!(
+        values["open_actions"],
+        TemplateValue::List(vec!["Review PR".into()])
+    );
+    assert_eq!(values["reflection"], TemplateValue::Text("Calm day".into()));
+}
+
+#[test]
+fn user_template_is_found_in_the_data_root_or_settings() {
+    let (temp_dir, config) = temp_config();
+    let settings = SummarySettings::default();
+    assert_eq!(load_template(&settings, &config).unwrap(), None);
+
+    fs::write(
+        temp_dir.path().join("summary_template.md"),
+        "{{date}} {{percentage}}%\n{{#each wins}}* {{this}}\n{{/each}}{{reflection}}",
+    )
+    .unwrap();
+    let goals = sample_goals();
+    write_goals_file(&goals, &config).unwrap();
+    save_reflection(goals.date, "Calm day", &config).unwrap();
+    let input = SummaryInput::gather(&goals, &Default::default(), &[]);
+    let text = render_summary(&goals, &input, Some("Calm day"), &settings, &config).unwrap();
+    assert_eq!(
+        text,
+        "2025-06-02 22%\n* Ship release notes\n* Run 5k\nCalm day"
+    );
+
+    // The evening phase of the ritual app uses the same template
+    let mut app = RitualApp::new(goals.clone(), config.clone(), FiveYearVision::new());
+    app.generate_daily_summary();
+    assert_eq!(app.daily_summary, text);
+
+    // A configured template that is missing falls back to the built-in one
+    save_settings(
+        &Settings {
+            summary: SummarySettings {
+                template: Some("templates/missing.md".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        &config,
+    )
+    .unwrap();
+    app.generate_daily_summary();
+    assert!(app.daily_summary.starts_with("📊 Day 4 Summary"));
+    assert!(app.error_message.as_deref().unwrap().contains("missing.md"));
+}
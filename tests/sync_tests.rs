@@ -0,0 +1,391 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use focusfive::data::{
+    append_observation, goals_file_path, load_or_create_goals, read_goals_file,
+    read_observations_range, save_day_meta, write_goals_file,
+};
+use focusfive::models::{
+    Action, Config, DailyGoals, DayMeta, IndicatorUnit, Observation, OutcomeType,
+};
+use focusfive::sync::{self, Side, Spot};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use tempfile::TempDir;
+
+fn at(folder: &Path) -> sync::Target {
+    sync::Target::Folder(folder.to_path_buf())
+}
+
+fn date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 3, 10).unwrap()
+}
+
+fn day(texts: [&str; 3]) -> DailyGoals {
+    let mut goals = DailyGoals::new(date());
+    for (action, text) in goals.work.actions.iter_mut().zip(texts) {
+        action.text = text.to_string();
+    }
+    goals
+}
+
+fn work_texts(config: &Config) -> Vec<String> {
+    let goals = read_goals_file(&goals_file_path(date(), config)).unwrap();
+    goals.work.actions.iter().map(|a| a.text.clone()).collect()
+}
+
+/// Two devices sharing a sync folder, both synced with day(texts)
+fn two_devices(texts: [&str; 3]) -> (Vec<(TempDir, Config)>, TempDir) {
+    let folder = TempDir::new().unwrap();
+    let devices = vec![temp_config(), temp_config()];
+    write_goals_file(&day(texts), &devices[0].1).unwrap();
+    for (_, config) in &devices {
+        sync::run(&at(folder.path()), config).unwrap();
+    }
+    (devices, folder)
+}
+
+#[test]
+fn changes_travel_through_the_folder() {
+    let (devices, folder) = two_devices(["Plan", "Write", "Ship"]);
+    let (a, b) = (&devices[0].1, &devices[1].1);
+    assert_eq!(work_texts(b), ["Plan", "Write", "Ship"]);
+    assert!(folder.path().join("goals/2025-03-10.md").exists());
+    // Per-device files stay put
+    assert!(!folder.path().join("sync").exists());
+
+    write_goals_file(&day(["Plan", "Write", "Launch"]), b).unwrap();
+    let report = sync::run(&at(folder.path()), b).unwrap();
+    assert_eq!(report.pushed, ["goals/2025-03-10.md"]);
+    let report = sync::run(&at(folder.path()), a).unwrap();
+    assert_eq!(report.pulled, ["goals/2025-03-10.md"]);
+    assert_eq!(work_texts(a), ["Plan", "Write", "Launch"]);
+
+    // Nothing left to do
+    let report = sync::run(&at(folder.path()), a).unwrap();
+    assert!(report.pushed.is_empty() && report.pulled.is_empty());
+}
+
+#[test]
+fn edits_to_different_actions_merge() {
+    let (devices, folder) = two_devices(["Plan", "Write", "Ship"]);
+    let (a, b) = (&devices[0].1, &devices[1].1);
+    write_goals_file(&day(["Plan v2", "Write", "Ship"]), a).unwrap();
+    write_goals_file(&day(["Plan", "Write", "Ship it"]), b).unwrap();
+
+    sync::run(&at(folder.path()), a).unwrap();
+    let report = sync::run(&at(folder.path()), b).unwrap();
+    assert_eq!(report.merged, ["goals/2025-03-10.md"]);
+    assert!(report.conflicts.is_empty());
+    assert_eq!(work_texts(b), ["Plan v2", "Write", "Ship it"]);
+
+    sync::run(&at(folder.path()), a).unwrap();
+    assert_eq!(work_texts(a), ["Plan v2", "Write", "Ship it"]);
+}
+
+#[test]
+fn conflicting_edits_are_resolved_by_choice() {
+    let (devices, folder) = two_devices(["Plan", "Write", "Ship"]);
+    let (a, b) = (&devices[0].1, &devices[1].1);
+    write_goals_file(&day(["Plan A", "Write", "Ship"]), a).unwrap();
+    write_goals_file(&day(["Plan B", "Write", "Ship"]), b).unwrap();
+
+    sync::run(&at(folder.path()), a).unwrap();
+    let report = sync::run(&at(folder.path()), b).unwrap();
+    assert_eq!(report.conflicts.len(), 1);
+    let conflict = &report.conflicts[0];
+    assert_eq!(conflict.hunks.len(), 1);
+    let hunk = &conflict.hunks[0];
+    assert_eq!(hunk.spot, Spot::Action(OutcomeType::Work, 0));
+    assert_eq!(hunk.base.as_deref(), Some("[ ] Plan"));
+    assert_eq!(hunk.local.as_deref(), Some("[ ] Plan B"));
+    assert_eq!(hunk.remote.as_deref(), Some("[ ] Plan A"));
+    // Left alone until resolved
+    assert_eq!(work_texts(b), ["Plan B", "Write", "Ship"]);
+    assert_eq!(sync::run(&at(folder.path()), b).unwrap().conflicts.len(), 1);
+
+    assert!(sync::resolve(conflict, &[], &at(folder.path()), b).is_err());
+    sync::resolve(conflict, &[Side::Remote], &at(folder.path()), b).unwrap();
+    assert_eq!(work_texts(b), ["Plan A", "Write", "Ship"]);
+    let report = sync::run(&at(folder.path()), b).unwrap();
+    assert!(report.conflicts.is_empty());
+}
+
+/// Save `goals` with the day's metadata, which keeps the action ids
+fn save_with_ids(goals: &DailyGoals, config: &Config) {
+    write_goals_file(goals, config).unwrap();
+    save_day_meta(date(), &DayMeta::from_goals(goals), config).unwrap();
+}
+
+#[test]
+fn actions_are_matched_by_id_across_inserts_and_deletes() {
+    let folder = TempDir::new().unwrap();
+    let (_a_dir, a) = temp_config();
+    let (_b_dir, b) = temp_config();
+    save_with_ids(&day(["Plan", "Write", "Ship"]), &a);
+    sync::run(&at(folder.path()), &a).unwrap();
+    sync::run(&at(folder.path()), &b).unwrap();
+
+    let mut goals = load_or_create_goals(date(), &a).unwrap();
+    goals
+        .work
+        .actions
+        .insert(0, Action::new("Review".to_string()));
+    goals.work.actions[3].text = "Launch".to_string();
+    save_with_ids(&goals, &a);
+    let mut goals = load_or_create_goals(date(), &b).unwrap();
+    goals.work.actions.remove(0);
+    goals.work.actions[1].text = "Ship it".to_string();
+    goals.work.actions.push(Action::new("Call".to_string()));
+    save_with_ids(&goals, &b);
+
+    sync::run(&at(folder.path()), &a).unwrap();
+    let report = sync::run(&at(folder.path()), &b).unwrap();
+    // Only the action both renamed is in conflict, wherever it moved to
+    assert_eq!(report.conflicts.len(), 1);
+    let conflict = &report.conflicts[0];
+    assert_eq!(
+        conflict.hunks,
+        [sync::Hunk {
+            spot: Spot::Action(OutcomeType::Work, 2),
+            base: Some("[ ] Ship".to_string()),
+            local: Some("[ ] Ship it".to_string()),
+            remote: Some("[ ] Launch".to_string()),
+        }]
+    );
+
+    sync::resolve(conflict, &[Side::Remote], &at(folder.path()), &b).unwrap();
+    assert_eq!(work_texts(&b), ["Review", "Write", "Launch", "Call"]);
+    sync::run(&at(folder.path()), &a).unwrap();
+    assert_eq!(work_texts(&a), ["Review", "Write", "Launch", "Call"]);
+    // The ids follow the merged actions on both devices
+    let ids = |config: &Config| {
+        let goals = load_or_create_goals(date(), config).unwrap();
+        goals
+            .work
+            .actions
+            .iter()
+            .map(|a| a.id.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ids(&a), ids(&b));
+}
+
+#[test]
+fn observation_logs_keep_lines_from_both_sides() {
+    let folder = TempDir::new().unwrap();
+    let (_a_dir, a) = temp_config();
+    let (_b_dir, b) = temp_config();
+    let observe = |value: f64, config: &Config| {
+        let obs = Observation::new("steps".to_string(), date(), value, IndicatorUnit::Count);
+        append_observation(&obs, config).unwrap();
+    };
+    observe(1.0, &a);
+    sync::run(&at(folder.path()), &a).unwrap();
+    sync::run(&at(folder.path()), &b).unwrap();
+
+    observe(2.0, &a);
+    observe(3.0, &b);
+    sync::run(&at(folder.path()), &a).unwrap();
+    let report = sync::run(&at(folder.path()), &b).unwrap();
+    assert_eq!(report.merged, ["observations.ndjson"]);
+    let values: Vec<f64> = read_observations_range(date(), date(), &b)
+        .unwrap()
+        .iter()
+        .map(|obs| obs.value)
+        .collect();
+    assert_eq!(values.len(), 3);
+    for value in [1.0, 2.0, 3.0] {
+        assert!(values.contains(&value));
+    }
+}
+
+/// What a minimal WebDAV server holds: files and folders by path, and the
+/// requests it served
+#[derive(Default)]
+struct Dav {
+    files: BTreeMap<String, Vec<u8>>,
+    folders: BTreeSet<String>,
+    requests: Vec<String>,
+}
+
+fn etag(content: &[u8]) -> String {
+    format!(
+        "\"{}-{}\"",
+        content.len(),
+        content.iter().map(|&b| b as u32).sum::<u32>()
+    )
+}
+
+fn propfind(dav: &Dav, folder: &str) -> Option<String> {
+    if !folder.is_empty() && !dav.folders.contains(folder) {
+        return None;
+    }
+    let response = |path: &str, etag: Option<String>| {
+        match etag {
+        Some(etag) => format!(
+            "<d:response><d:href>/dav/{}</d:href><d:propstat><d:prop><d:resourcetype/><d:getetag>{}</d:getetag></d:prop></d:propstat></d:response>",
+            path, etag
+        ),
+        None => format!(
+            "<d:response><d:href>/dav/{}</d:href><d:propstat><d:prop><d:resourcetype><d:collection/></d:resourcetype></d:prop></d:propstat></d:response>",
+            path
+        ),
+    }
+    };
+    let child = |path: &str| {
+        path.strip_prefix(folder)
+            .is_some_and(|rest| !rest.is_empty() && !rest.trim_end_matches('/').contains('/'))
+    };
+    let mut body = vec![response(folder, None)];
+    body.extend(
+        dav.folders
+            .iter()
+            .filter(|f| child(f))
+            .map(|f| response(f, None)),
+    );
+    body.extend(
+        dav.files
+            .iter()
+            .filter(|(f, _)| child(f))
+            .map(|(f, content)| response(f, Some(etag(content)))),
+    );
+    Some(format!(
+        "<?xml version=\"1.0\"?><d:multistatus xmlns:d=\"DAV:\">{}</d:multistatus>",
+        body.concat()
+    ))
+}
+
+/// Serve a WebDAV folder at /dav/ until the test ends
+fn serve_webdav() -> (String, Arc<Mutex<Dav>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/dav/", listener.local_addr().unwrap());
+    let dav = Arc::new(Mutex::new(Dav::default()));
+    let shared = Arc::clone(&dav);
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut reader = BufReader::new(stream.unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    length = value.trim().parse().unwrap();
+                }
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut content = vec![0; length];
+            reader.read_exact(&mut content).unwrap();
+
+            let mut parts = request_line.split_whitespace();
+            let (method, path) = (parts.next().unwrap(), parts.next().unwrap());
+            let path = path.strip_prefix("/dav/").unwrap().to_string();
+            let mut dav = shared.lock().unwrap();
+            dav.requests.push(format!("{} {}", method, path));
+            let parent = path.rsplit_once('/').map(|(p, _)| format!("{}/", p));
+            let (status, etag_header, body) = match method {
+                "PROPFIND" => match propfind(&dav, &path) {
+                    Some(body) => ("207 Multi-Status", None, body.into_bytes()),
+                    None => ("404 Not Found", None, Vec::new()),
+                },
+                "GET" => match dav.files.get(&path) {
+                    Some(content) => ("200 OK", None, content.clone()),
+                    None => ("404 Not Found", None, Vec::new()),
+                },
+                "PUT" if parent.as_ref().is_some_and(|p| !dav.folders.contains(p)) => {
+                    ("409 Conflict", None, Vec::new())
+                }
+                "PUT" => {
+                    let tag = etag(&content);
+                    dav.files.insert(path, content);
+                    ("201 Created", Some(tag), Vec::new())
+                }
+                "MKCOL" if dav.folders.contains(&path) => {
+                    ("405 Method Not Allowed", None, Vec::new())
+                }
+                "MKCOL" => {
+                    dav.folders.insert(path);
+                    ("201 Created", None, Vec::new())
+                }
+                "DELETE" => match dav.files.remove(&path) {
+                    Some(_) => ("204 No Content", None, Vec::new()),
+                    None => ("404 Not Found", None, Vec::new()),
+                },
+                _ => ("405 Method Not Allowed", None, Vec::new()),
+            };
+            drop(dav);
+            let etag_header = etag_header.map_or(String::new(), |tag| format!("ETag: {}\r\n", tag));
+            let head = format!(
+                "HTTP/1.1 {}\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n",
+                status,
+                etag_header,
+                body.len()
+            );
+            let stream = reader.get_mut();
+            stream.write_all(head.as_bytes()).unwrap();
+            stream.write_all(&body).unwrap();
+        }
+    });
+    (url, dav)
+}
+
+#[test]
+fn devices_sync_through_a_webdav_server() {
+    let (url, dav) = serve_webdav();
+    let target = sync::Target::parse(&url);
+    assert!(matches!(target, sync::Target::WebDav(_)));
+    let devices = [temp_config(), temp_config()];
+    let (a, b) = (&devices[0].1, &devices[1].1);
+
+    write_goals_file(&day(["Plan", "Write", "Ship"]), a).unwrap();
+    let report = sync::run(&target, a).unwrap();
+    assert_eq!(report.pushed, ["goals/2025-03-10.md"]);
+    assert!(dav.lock().unwrap().folders.contains("goals/"));
+    sync::run(&target, b).unwrap();
+    assert_eq!(work_texts(b), ["Plan", "Write", "Ship"]);
+
+    write_goals_file(&day(["Plan", "Write", "Launch"]), b).unwrap();
+    assert_eq!(
+        sync::run(&target, b).unwrap().pushed,
+        ["goals/2025-03-10.md"]
+    );
+    assert_eq!(
+        sync::run(&target, a).unwrap().pulled,
+        ["goals/2025-03-10.md"]
+    );
+    assert_eq!(work_texts(a), ["Plan", "Write", "Launch"]);
+
+    // Files whose ETag is unchanged are not downloaded again
+    dav.lock().unwrap().requests.clear();
+    let report = sync::run(&target, a).unwrap();
+    assert!(report.pushed.is_empty() && report.pulled.is_empty());
+    let requests = dav.lock().unwrap().requests.clone();
+    assert!(
+        requests.iter().all(|r| r.starts_with("PROPFIND")),
+        "{:?}",
+        requests
+    );
+}
+
+#[test]
+fn missing_webdav_folder_is_an_error() {
+    let (url, _dav) = serve_webdav();
+    let (_dir, config) = temp_config();
+    let target = sync::Target::parse(&format!("{}missing", url));
+    assert!(sync::run(&target, &config).is_err());
+}
+
+#[test]
+fn missing_folder_is_an_error() {
+    let (_dir, config) = temp_config();
+    assert!(sync::run(&at(Path::new("/nonexistent/focusfive-sync")), &config).is_err());
+    assert!(sync::target(&config).is_err());
+}
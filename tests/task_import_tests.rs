@@ -0,0 +1,182 @@
+mod common;
+
+use chrono::{NaiveDate, Weekday};
+use common::temp_config;
+use focusfive::data::{load_or_create_day_meta, load_or_create_goals, load_or_create_templates};
+use focusfive::journal_import::{heading_key, HeadingMap};
+use focusfive::models::OutcomeType;
+use focusfive::task_import::{
+    csv_records, distinct_projects, import_tasks, parse_ticktick_csv, parse_todoist_csv,
+    read_export, ticktick_repeat, todoist_repeat, ImportedTask, Repeat,
+};
+use std::fs;
+use tempfile::TempDir;
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 3, 10).unwrap()
+}
+
+const TODOIST: &str = "\
+TYPE,CONTENT,DESCRIPTION,PRIORITY,INDENT,AUTHOR,RESPONSIBLE,DATE,DATE_LANG,TIMEZONE
+section,Planning,,,,,,,,
+task,\"Review \"\"Q1\"\" plan, with notes\",,4,1,Me,,2025-03-10,en,UTC
+task,Standup,,1,1,Me,,\"every mon, fri\",en,UTC
+task,Pay rent,,1,1,Me,,every month,en,UTC
+task,File taxes,,1,1,Me,,Apr 15,en,UTC
+note,Remember the receipts,,,,,,,,
+";
+
+const TICKTICK: &str = "\
+\"Date: 2025-03-10+0000\"
+\"Version: 7.1\"
+\"Status:
+0 Normal
+1 Completed\"
+\"Folder Name\",\"List Name\",\"Title\",\"Tags\",\"Content\",\"Due Date\",\"Repeat\",\"Status\"
+\"\",\"Gym\",\"Stretch\",\"\",\"\",\"2025-03-10T08:00:00+0000\",\"FREQ=DAILY;INTERVAL=1\",\"0\"
+\"\",\"Gym\",\"Long run\",\"\",\"\",\"2025-03-09T08:00:00+0000\",\"FREQ=WEEKLY;INTERVAL=1\",\"0\"
+\"\",\"Home\",\"Fix shelf\",\"\",\"\",\"\",\"\",\"2\"
+";
+
+#[test]
+fn quoted_fields_keep_commas_quotes_and_line_breaks() {
+    let records = csv_records("a,\"b, c\",\"say \"\"hi\"\"\"\r\n\"two\nlines\",x\n\n");
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0], ["a", "b, c", "say \"hi\""]);
+    assert_eq!(records[1], ["two\nlines", "x"]);
+}
+
+#[test]
+fn todoist_exports_read_tasks_and_repeats() {
+    let tasks = parse_todoist_csv(TODOIST, "Office", today()).unwrap();
+    let texts: Vec<&str> = tasks.iter().map(|t| t.text.as_str()).collect();
+    assert_eq!(
+        texts,
+        [
+            "Review \"Q1\" plan, with notes",
+            "Standup",
+            "Pay rent",
+            "File taxes"
+        ]
+    );
+    assert!(tasks.iter().all(|t| t.project == "Office"));
+    assert_eq!(tasks[0].due, Some(today()));
+    assert_eq!(tasks[0].repeat, None);
+    assert_eq!(
+        tasks[1].repeat,
+        Some(Repeat::Weekly(vec![Weekday::Mon, Weekday::Fri]))
+    );
+    assert!(matches!(tasks[2].repeat, Some(Repeat::Other(_))));
+    assert_eq!(tasks[3].due, NaiveDate::from_ymd_opt(2025, 4, 15));
+
+    assert_eq!(
+        todoist_repeat("every weekday"),
+        Some(Repeat::Weekly(vec![
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri
+        ]))
+    );
+    assert_eq!(todoist_repeat("tomorrow"), None);
+    assert!(parse_todoist_csv("a,b\n1,2\n", "x", today()).is_err());
+}
+
+#[test]
+fn ticktick_backups_skip_the_preamble() {
+    let tasks = parse_ticktick_csv(TICKTICK).unwrap();
+    assert_eq!(tasks.len(), 3);
+    assert_eq!(tasks[0].project, "Gym");
+    assert_eq!(tasks[0].repeat.as_ref().map(|_| ()), Some(()));
+    // A weekly rule without days repeats on the due date's weekday
+    assert_eq!(tasks[1].repeat, Some(Repeat::Weekly(vec![Weekday::Sun])));
+    assert!(tasks[2].done);
+    assert_eq!(
+        ticktick_repeat("RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO", None),
+        Some(Repeat::Other("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO".to_string()))
+    );
+    assert_eq!(
+        distinct_projects(&tasks),
+        [("Gym".to_string(), 2), ("Home".to_string(), 1)]
+    );
+}
+
+#[test]
+fn exports_are_told_apart_by_their_columns() {
+    let dir = TempDir::new().unwrap();
+    let todoist = dir.path().join("Office.csv");
+    let ticktick = dir.path().join("backup.csv");
+    fs::write(&todoist, TODOIST).unwrap();
+    fs::write(&ticktick, TICKTICK).unwrap();
+
+    let tasks = read_export(&todoist, None, today()).unwrap();
+    assert_eq!(tasks[0].project, "Office");
+    let tasks = read_export(&todoist, Some("Work stuff"), today()).unwrap();
+    assert_eq!(tasks[0].project, "Work stuff");
+    let tasks = read_export(&ticktick, None, today()).unwrap();
+    assert_eq!(tasks[0].project, "Gym");
+}
+
+fn mapping() -> HeadingMap {
+    let mut mapping = HeadingMap::new();
+    mapping.insert(heading_key("Office"), OutcomeType::Work);
+    mapping.insert(heading_key("Gym"), OutcomeType::Health);
+    mapping
+}
+
+fn tasks() -> Vec<ImportedTask> {
+    let mut tasks = parse_todoist_csv(TODOIST, "Office", today()).unwrap();
+    tasks.extend(parse_ticktick_csv(TICKTICK).unwrap());
+    tasks
+}
+
+#[test]
+fn import_places_actions_and_writes_templates() {
+    let (_temp_dir, config) = temp_config();
+    let summary = import_tasks(&tasks(), &mapping(), today(), &config, false).unwrap();
+    assert_eq!(summary.actions, 1);
+    assert_eq!(summary.later, 1);
+    assert_eq!(summary.unsupported_repeat, 1);
+    assert_eq!(summary.skipped, 1);
+    assert_eq!(
+        summary.templates,
+        [
+            "Gym (Mon, Tue, Wed, Thu, Fri, Sat, Sun)",
+            "Gym (Sun)",
+            "Office"
+        ]
+    );
+
+    let goals = load_or_create_goals(today(), &config).unwrap();
+    assert_eq!(goals.work.actions[0].text, "Review \"Q1\" plan, with notes");
+    let meta = load_or_create_day_meta(today(), &goals, &config).unwrap();
+    assert_eq!(meta.work[0].tags, ["Office"]);
+
+    let templates = load_or_create_templates(&config).unwrap();
+    let office = &templates.weekday_templates["Office"];
+    assert_eq!(office.weekdays, [Weekday::Mon, Weekday::Fri]);
+    assert_eq!(office.work, ["Standup"]);
+    assert_eq!(
+        templates.weekday_templates["Gym (Sun)"].health,
+        ["Long run"]
+    );
+
+    // Importing again adds nothing twice
+    let again = import_tasks(&tasks(), &mapping(), today(), &config, false).unwrap();
+    assert_eq!(again.actions, 0);
+}
+
+#[test]
+fn dry_run_writes_nothing() {
+    let (_temp_dir, config) = temp_config();
+    let summary = import_tasks(&tasks(), &mapping(), today(), &config, true).unwrap();
+    assert_eq!(summary.actions, 1);
+    assert!(!summary.templates.is_empty());
+    let goals = load_or_create_goals(today(), &config).unwrap();
+    assert!(goals.work.actions.iter().all(|a| a.text.is_empty()));
+    assert!(load_or_create_templates(&config)
+        .unwrap()
+        .weekday_templates
+        .is_empty());
+}
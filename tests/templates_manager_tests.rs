@@ -0,0 +1,198 @@
+mod common;
+
+use chrono::{Local, Weekday};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{load_or_create_templates, save_templates, write_goals_file};
+use focusfive::models::{
+    ActionOrigin, ActionTemplates, Config, DailyGoals, OutcomeType, WeekdayTemplate,
+};
+use focusfive::templates::{self, TemplateRef};
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+
+fn texts(list: &[&str]) -> Vec<String> {
+    list.iter().map(|text| text.to_string()).collect()
+}
+
+fn saved_templates(config: &Config) -> ActionTemplates {
+    let mut data = ActionTemplates::new();
+    data.add_template("Deep work".to_string(), texts(&["Write", "Review PRs"]));
+    data.add_template("Errands".to_string(), texts(&["Groceries"]));
+    data.set_weekday_template(
+        "Monday".to_string(),
+        WeekdayTemplate {
+            weekdays: vec![Weekday::Mon],
+            work: texts(&["Plan the week"]),
+            health: Vec::new(),
+            family: texts(&["Call home"]),
+        },
+    );
+    save_templates(&data, config).unwrap();
+    data
+}
+
+#[test]
+fn list_orders_action_templates_before_weekday_templates() {
+    let (_temp_dir, config) = temp_config();
+    let data = saved_templates(&config);
+
+    assert_eq!(
+        templates::list(&data),
+        [
+            TemplateRef::Actions("Deep work".to_string()),
+            TemplateRef::Actions("Errands".to_string()),
+            TemplateRef::Weekday("Monday".to_string()),
+        ]
+    );
+    let actions = templates::actions(&data, &TemplateRef::Weekday("Monday".to_string()));
+    assert_eq!(actions.len(), 2);
+    assert_eq!(actions[1].outcome, Some(OutcomeType::Family));
+    assert_eq!(actions[1].text, "Call home");
+}
+
+#[test]
+fn rename_refuses_an_existing_name() {
+    let (_temp_dir, config) = temp_config();
+    saved_templates(&config);
+    let deep_work = TemplateRef::Actions("Deep work".to_string());
+
+    assert!(templates::rename(&deep_work, "Errands", &config).is_err());
+    assert!(templates::rename(&deep_work, "  ", &config).is_err());
+
+    let data = templates::rename(&deep_work, "Focus block", &config).unwrap();
+    assert_eq!(data, load_or_create_templates(&config).unwrap());
+    assert!(data.get_template("Deep work").is_none());
+    assert_eq!(data.get_template("Focus block").unwrap().len(), 2);
+    assert!(templates::rename(&deep_work, "Again", &config).is_err());
+}
+
+#[test]
+fn set_action_edits_appends_and_removes() {
+    let (_temp_dir, config) = temp_config();
+    saved_templates(&config);
+    let errands = TemplateRef::Actions("Errands".to_string());
+
+    templates::set_action(&errands, None, 0, "Pharmacy", &config).unwrap();
+    let data = templates::set_action(&errands, None, 1, "Post office", &config).unwrap();
+    assert_eq!(
+        data.get_template("Errands").unwrap(),
+        &texts(&["Pharmacy", "Post office"])
+    );
+    let data = templates::set_action(&errands, None, 0, "", &config).unwrap();
+    assert_eq!(
+        data.get_template("Errands").unwrap(),
+        &texts(&["Post office"])
+    );
+
+    for text in ["A", "B", "C", "D"] {
+        templates::set_action(&errands, None, 9, text, &config).unwrap();
+    }
+    assert!(templates::set_action(&errands, None, 9, "Sixth", &config).is_err());
+
+    let monday = TemplateRef::Weekday("Monday".to_string());
+    assert!(templates::set_action(&monday, None, 0, "Gym", &config).is_err());
+    let data =
+        templates::set_action(&monday, Some(OutcomeType::Health), 0, "Gym", &config).unwrap();
+    assert_eq!(data.weekday_templates["Monday"].health, texts(&["Gym"]));
+}
+
+#[test]
+fn delete_removes_only_that_template() {
+    let (_temp_dir, config) = temp_config();
+    saved_templates(&config);
+
+    let data = templates::delete(&TemplateRef::Weekday("Monday".to_string()), &config).unwrap();
+    assert!(data.weekday_templates.is_empty());
+    assert_eq!(data.get_template_names().len(), 2);
+    assert!(templates::delete(&TemplateRef::Weekday("Monday".to_string()), &config).is_err());
+}
+
+#[test]
+fn apply_skips_actions_already_on_the_day() {
+    let (_temp_dir, config) = temp_config();
+    let data = saved_templates(&config);
+    let mut goals = DailyGoals::new(Local::now().date_naive());
+    goals.health.actions[0].text = "Groceries".to_string();
+
+    let errands = TemplateRef::Actions("Errands".to_string());
+    assert_eq!(
+        templates::apply(&data, &errands, &mut goals, OutcomeType::Health),
+        0
+    );
+    let monday = TemplateRef::Weekday("Monday".to_string());
+    assert_eq!(
+        templates::apply(&data, &monday, &mut goals, OutcomeType::Health),
+        2
+    );
+    assert_eq!(goals.work.actions[0].text, "Plan the week");
+    assert_eq!(goals.work.actions[0].origin, ActionOrigin::Template);
+    assert_eq!(goals.family.actions[0].text, "Call home");
+}
+
+#[test]
+fn manager_renames_edits_and_deletes_from_the_tui() {
+    let (_temp_dir, config) = temp_config();
+    write_goals_file(&DailyGoals::new(Local::now().date_naive()), &config).unwrap();
+    saved_templates(&config);
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+
+    app.handle_key(KeyCode::Char('D')).unwrap();
+    assert!(matches!(app.modal, Some(ModalState::Templates(_))));
+
+    // Rename "Errands" to "Chores"
+    app.handle_key(KeyCode::Char('j')).unwrap();
+    app.handle_key(KeyCode::Char('r')).unwrap();
+    for _ in "Errands".chars() {
+        app.handle_key(KeyCode::Backspace).unwrap();
+    }
+    for c in "Chores".chars() {
+        app.handle_key(KeyCode::Char(c)).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+    let saved = load_or_create_templates(&config).unwrap();
+    assert!(saved.get_template("Errands").is_none());
+    assert_eq!(
+        saved.get_template("Chores").unwrap(),
+        &texts(&["Groceries"])
+    );
+
+    // Add an action inline
+    app.handle_key(KeyCode::Enter).unwrap();
+    app.handle_key(KeyCode::Char('n')).unwrap();
+    for c in "Laundry".chars() {
+        app.handle_key(KeyCode::Char(c)).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+    let Some(ModalState::Templates(state)) = app.modal.clone() else {
+        panic!("expected the template manager");
+    };
+    assert_eq!(state.action, Some(1));
+    assert_eq!(
+        load_or_create_templates(&config)
+            .unwrap()
+            .get_template("Chores")
+            .unwrap(),
+        &texts(&["Groceries", "Laundry"])
+    );
+
+    // Apply it to the day, then delete it with d d
+    app.handle_key(KeyCode::Esc).unwrap();
+    app.handle_key(KeyCode::Char('a')).unwrap();
+    assert_eq!(app.goals.work.actions[0].text, "Groceries");
+    assert_eq!(app.goals.work.actions[1].text, "Laundry");
+    app.handle_key(KeyCode::Char('d')).unwrap();
+    assert!(load_or_create_templates(&config)
+        .unwrap()
+        .get_template("Chores")
+        .is_some());
+    app.handle_key(KeyCode::Char('d')).unwrap();
+    assert!(load_or_create_templates(&config)
+        .unwrap()
+        .get_template("Chores")
+        .is_none());
+
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(app.modal.is_none());
+}
@@ -0,0 +1,100 @@
+mod common;
+
+use common::temp_config;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use focusfive::ui::popup::TextEditor;
+use focusfive::ui::App;
+
+fn editor_with(text: &str) -> TextEditor {
+    let mut editor = TextEditor::new("Edit");
+    editor.activate_with("Edit", text, 500);
+    editor
+}
+
+fn press(editor: &mut TextEditor, code: KeyCode, modifiers: KeyModifiers) {
+    editor.handle_key_event(KeyEvent::new(code, modifiers));
+}
+
+#[test]
+fn cursor_moves_by_character_word_and_line() {
+    let mut editor = editor_with("Call the bank");
+    press(&mut editor, KeyCode::Home, KeyModifiers::NONE);
+    assert_eq!(editor.cursor_position, 0);
+    press(&mut editor, KeyCode::Right, KeyModifiers::CONTROL);
+    assert_eq!(editor.cursor_position, 4);
+    press(&mut editor, KeyCode::Right, KeyModifiers::CONTROL);
+    assert_eq!(editor.cursor_position, 8);
+    press(&mut editor, KeyCode::Left, KeyModifiers::ALT);
+    assert_eq!(editor.cursor_position, 5);
+    press(&mut editor, KeyCode::Right, KeyModifiers::NONE);
+    press(&mut editor, KeyCode::Char('X'), KeyModifiers::SHIFT);
+    assert_eq!(editor.text, "Call tXhe bank");
+    press(&mut editor, KeyCode::End, KeyModifiers::NONE);
+    press(&mut editor, KeyCode::Char('!'), KeyModifiers::NONE);
+    assert_eq!(editor.text, "Call tXhe bank!");
+
+    // Multi-byte characters are stepped over whole
+    let mut editor = editor_with("café ok");
+    press(&mut editor, KeyCode::Left, KeyModifiers::NONE);
+    press(&mut editor, KeyCode::Left, KeyModifiers::NONE);
+    press(&mut editor, KeyCode::Left, KeyModifiers::NONE);
+    press(&mut editor, KeyCode::Backspace, KeyModifiers::NONE);
+    assert_eq!(editor.text, "caf ok");
+    press(&mut editor, KeyCode::Delete, KeyModifiers::NONE);
+    assert_eq!(editor.text, "cafok");
+}
+
+#[test]
+fn shift_selects_and_typing_replaces_the_selection() {
+    let mut editor = editor_with("Call the bank");
+    press(
+        &mut editor,
+        KeyCode::Left,
+        KeyModifiers::SHIFT | KeyModifiers::CONTROL,
+    );
+    assert_eq!(editor.selection(), Some((9, 13)));
+    press(&mut editor, KeyCode::Char('s'), KeyModifiers::NONE);
+    assert_eq!(editor.text, "Call the s");
+    assert_eq!(editor.selection(), None);
+
+    press(&mut editor, KeyCode::Home, KeyModifiers::SHIFT);
+    assert_eq!(editor.selection(), Some((0, 10)));
+    // An arrow without Shift collapses the selection to that side
+    press(&mut editor, KeyCode::Right, KeyModifiers::NONE);
+    assert_eq!(editor.cursor_position, 10);
+    assert_eq!(editor.selection(), None);
+
+    press(&mut editor, KeyCode::Char('a'), KeyModifiers::CONTROL);
+    press(&mut editor, KeyCode::Backspace, KeyModifiers::NONE);
+    assert_eq!(editor.text, "");
+
+    let mut editor = editor_with("Call the bank");
+    press(&mut editor, KeyCode::Backspace, KeyModifiers::CONTROL);
+    assert_eq!(editor.text, "Call the ");
+}
+
+#[test]
+fn pasted_text_lands_at_the_cursor_on_one_line() {
+    let mut editor = editor_with("Call bank");
+    press(&mut editor, KeyCode::Left, KeyModifiers::CONTROL);
+    editor.paste("the\nbig ");
+    assert_eq!(editor.text, "Call the big bank");
+
+    press(
+        &mut editor,
+        KeyCode::Left,
+        KeyModifiers::SHIFT | KeyModifiers::CONTROL,
+    );
+    editor.paste("old \n");
+    assert_eq!(editor.text, "Call the old bank");
+
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+    app.handle_paste("ignored outside the editor");
+    app.text_editor.activate_with("Edit Action", "Walk", 500);
+    app.handle_paste(" the dog");
+    app.handle_key_event(KeyEvent::new(KeyCode::Home, KeyModifiers::SHIFT))
+        .unwrap();
+    assert_eq!(app.text_editor.selection(), Some((0, 12)));
+}
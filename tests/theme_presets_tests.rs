@@ -0,0 +1,89 @@
+mod common;
+
+use common::temp_config;
+use focusfive::data::save_settings;
+use focusfive::models::{Settings, ThemePreset};
+use focusfive::ui::theme::{FinancialTheme, FocusFiveTheme, Severity};
+use focusfive::ui::App;
+use focusfive::widgets::IndicatorProgress;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use std::collections::HashSet;
+use std::fs;
+
+fn render(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(200, 50)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn presets_parse_and_keep_status_colors_distinct() {
+    assert_eq!(
+        "Colorblind".parse::<ThemePreset>().unwrap(),
+        ThemePreset::Colorblind
+    );
+    let err = "neon".parse::<ThemePreset>().unwrap_err().to_string();
+    assert!(err.contains("dark, colorblind, tritan, monochrome"));
+
+    for preset in ThemePreset::ALL {
+        let theme = FocusFiveTheme::preset(preset);
+        let statuses: HashSet<_> = [theme.completed, theme.partial, theme.pending]
+            .into_iter()
+            .collect();
+        assert_eq!(statuses.len(), 3, "{:?}", preset);
+        if preset != ThemePreset::Dark {
+            let financial = FinancialTheme::preset(preset);
+            assert_eq!(financial.severity_color(Severity::Bad), theme.pending);
+        }
+    }
+    assert_eq!(
+        FinancialTheme::preset(ThemePreset::Dark).positive,
+        FinancialTheme::default().positive
+    );
+
+    let symbols: HashSet<_> = [Severity::Good, Severity::Warning, Severity::Bad]
+        .iter()
+        .map(Severity::symbol)
+        .collect();
+    assert_eq!(symbols.len(), 3);
+    assert_eq!(Severity::from_completion(85.0), Severity::Good);
+    assert_eq!(Severity::from_completion(40.0), Severity::Warning);
+    assert_eq!(Severity::from_completion(10.0), Severity::Bad);
+    assert_eq!(
+        IndicatorProgress::new(7.5, 10.0, vec![]).severity(),
+        Severity::Warning
+    );
+}
+
+#[test]
+fn app_uses_the_configured_preset_and_marks_status_with_symbols() {
+    let (_temp_dir, config) = temp_config();
+    let settings = Settings {
+        theme: ThemePreset::Colorblind,
+        ..Default::default()
+    };
+    save_settings(&settings, &config).unwrap();
+    let saved = fs::read_to_string(format!("{}/settings.json", config.data_root)).unwrap();
+    assert!(saved.contains("\"theme\": \"colorblind\""));
+
+    let mut app = App::new(config).unwrap();
+    assert_eq!(
+        app.theme.completed,
+        FocusFiveTheme::preset(ThemePreset::Colorblind).completed
+    );
+    assert_eq!(
+        app.financial_theme.negative,
+        FocusFiveTheme::preset(ThemePreset::Colorblind).pending
+    );
+
+    // Nothing is done yet, so the completion gauge reads as behind
+    app.wait_for_background();
+    assert!(render(&mut app).contains("■ 0%"));
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use chrono::{Duration, NaiveDate};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{load_or_create_day_meta, save_day_meta, write_goals_file};
+use focusfive::models::{DailyGoals, DayMeta, OutcomeType};
+use focusfive::tracking::{self, ActionTimer, OutcomeTime};
+use focusfive::ui::app::FocusPanel;
+use focusfive::ui::App;
+
+fn backdate(app: &mut App, minutes: i64) {
+    let timer = app.action_timer.as_mut().expect("a running timer");
+    timer.started -= Duration::minutes(minutes);
+}
+
+#[test]
+fn tracked_minutes_add_up_per_outcome() {
+    let (_temp_dir, config) = temp_config();
+    let mut goals = DailyGoals::new(NaiveDate::from_ymd_opt(2025, 6, 4).unwrap());
+    goals.work.actions[0].text = "Write report".to_string();
+    goals.work.actions[1].text = "Review PRs".to_string();
+    goals.health.actions[0].text = "Run".to_string();
+    write_goals_file(&goals, &config).unwrap();
+
+    let mut meta = DayMeta::from_goals(&goals);
+    meta.work[0].estimated_min = Some(60);
+    meta.work[1].estimated_min = Some(30);
+    meta.health[0].actual_min = Some(10);
+    save_day_meta(goals.date, &meta, &config).unwrap();
+
+    assert_eq!(
+        tracking::add_actual_minutes(&goals, OutcomeType::Work, 0, 45, &config).unwrap(),
+        45
+    );
+    assert_eq!(
+        tracking::add_actual_minutes(&goals, OutcomeType::Work, 0, 30, &config).unwrap(),
+        75
+    );
+    assert!(tracking::add_actual_minutes(&goals, OutcomeType::Work, 9, 5, &config).is_err());
+
+    let times = tracking::outcome_times(&goals, &config).unwrap();
+    assert_eq!(
+        times,
+        [
+            OutcomeTime {
+                estimated: 90,
+                actual: 75
+            },
+            OutcomeTime {
+                estimated: 0,
+                actual: 10
+            },
+            OutcomeTime::default(),
+        ]
+    );
+
+    assert!(ActionTimer::start(&goals, OutcomeType::Family, 0).is_err());
+    let mut timer = ActionTimer::start(&goals, OutcomeType::Health, 0).unwrap();
+    timer.started -= Duration::seconds(3725);
+    assert_eq!(timer.minutes(), 62);
+    assert_eq!(timer.display(), "1:02:05");
+}
+
+#[test]
+fn t_starts_stops_and_switches_the_action_timer() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    app.focus_panel = FocusPanel::Actions;
+    app.goals.work.actions[0].text = "Deep work".to_string();
+    app.goals.work.actions[1].text = "Email".to_string();
+
+    // Empty actions are not tracked
+    app.selected_action = 2;
+    app.handle_key(KeyCode::Char('t')).unwrap();
+    assert!(app.action_timer.is_none());
+
+    app.selected_action = 0;
+    app.handle_key(KeyCode::Char('t')).unwrap();
+    assert_eq!(app.action_timer.as_ref().unwrap().text, "Deep work");
+    backdate(&mut app, 25);
+
+    // Another action switches the timer and records the first one
+    app.handle_key(KeyCode::Down).unwrap();
+    app.handle_key(KeyCode::Char('t')).unwrap();
+    assert_eq!(app.action_timer.as_ref().unwrap().text, "Email");
+    backdate(&mut app, 10);
+    app.handle_key(KeyCode::Char('t')).unwrap();
+    assert!(app.action_timer.is_none());
+
+    let meta = load_or_create_day_meta(app.goals.date, &app.goals, &config).unwrap();
+    assert_eq!(meta.work[0].actual_min, Some(25));
+    assert_eq!(meta.work[1].actual_min, Some(10));
+    assert_eq!(app.statistics.outcome_times[0].actual, 35);
+
+    // Under a minute is dropped; stopping with nothing running is a no-op
+    app.handle_key(KeyCode::Char('t')).unwrap();
+    app.stop_action_timer().unwrap();
+    app.stop_action_timer().unwrap();
+    let meta = load_or_create_day_meta(app.goals.date, &app.goals, &config).unwrap();
+    assert_eq!(meta.work[1].actual_min, Some(10));
+}
@@ -0,0 +1,108 @@
+mod common;
+
+use chrono::{NaiveDate, NaiveTime};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{load_or_create_day_meta, save_day_meta, write_goals_file};
+use focusfive::models::{DailyGoals, DayMeta, OutcomeType, WorkingHours};
+use focusfive::timeline::{self, Block, DEFAULT_BLOCK_MIN};
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+
+fn at(hour: u32, minute: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+}
+
+fn block(index: usize, start: Option<NaiveTime>, minutes: u32) -> Block {
+    Block {
+        outcome_type: OutcomeType::Work,
+        index,
+        text: format!("Block {}", index),
+        start,
+        minutes,
+    }
+}
+
+#[test]
+fn blocks_are_ordered_and_overlaps_are_conflicts() {
+    let (_temp_dir, config) = temp_config();
+    let mut goals = DailyGoals::new(NaiveDate::from_ymd_opt(2025, 6, 4).unwrap());
+    goals.work.actions[0].text = "Write report".to_string();
+    goals.work.actions[1].text = "Review PRs".to_string();
+    goals.health.actions[0].text = "Run".to_string();
+    goals.family.actions[0].text = "Call mom".to_string();
+    write_goals_file(&goals, &config).unwrap();
+
+    let mut meta = DayMeta::from_goals(&goals);
+    meta.work[0].scheduled_at = Some(at(10, 0));
+    meta.work[0].estimated_min = Some(90);
+    meta.work[1].scheduled_at = Some(at(11, 0));
+    meta.health[0].scheduled_at = Some(at(9, 0));
+    save_day_meta(goals.date, &meta, &config).unwrap();
+
+    let blocks = timeline::blocks(&goals, &config).unwrap();
+    let texts: Vec<_> = blocks.iter().map(|b| b.text.as_str()).collect();
+    assert_eq!(texts, ["Run", "Write report", "Review PRs", "Call mom"]);
+    assert_eq!(blocks[1].span_label(), "10:00-11:30");
+    assert_eq!(blocks[2].minutes, DEFAULT_BLOCK_MIN);
+    assert_eq!(blocks[3].start, None);
+
+    let conflicts = timeline::conflicts(&blocks);
+    assert_eq!(conflicts.len(), 2);
+    assert!(conflicts.contains(&(OutcomeType::Work, 0)));
+    assert!(conflicts.contains(&(OutcomeType::Work, 1)));
+}
+
+#[test]
+fn free_slots_skip_scheduled_blocks_within_working_hours() {
+    let hours = WorkingHours { start: 9, end: 13 };
+    let blocks = vec![block(0, Some(at(9, 0)), 60), block(1, Some(at(10, 30)), 60)];
+    assert_eq!(timeline::first_free_slot(&blocks, 30, hours), at(10, 0));
+    assert_eq!(timeline::first_free_slot(&blocks, 60, hours), at(11, 30));
+    // Nothing fits: fall back to the start of the day
+    assert_eq!(timeline::first_free_slot(&blocks, 120, hours), at(9, 0));
+
+    assert_eq!(timeline::shift_time(at(9, 0), -15), at(8, 45));
+    assert_eq!(timeline::shift_time(at(0, 10), -60), at(0, 0));
+    assert_eq!(timeline::shift_time(at(23, 30), 60), at(23, 45));
+}
+
+#[test]
+fn timeline_keys_schedule_shift_resize_and_clear() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    app.goals.work.actions[0].text = "Deep work".to_string();
+    app.goals.health.actions[0].text = "Walk".to_string();
+
+    app.handle_key(KeyCode::Char('T')).unwrap();
+    assert!(matches!(app.modal, Some(ModalState::Timeline(_))));
+
+    // Both go into the first free slots of the default 9-18 day
+    app.handle_key(KeyCode::Char('s')).unwrap();
+    app.handle_key(KeyCode::Char('j')).unwrap();
+    app.handle_key(KeyCode::Enter).unwrap();
+    let meta = load_or_create_day_meta(app.goals.date, &app.goals, &config).unwrap();
+    assert_eq!(meta.work[0].scheduled_at, Some(at(9, 0)));
+    assert_eq!(meta.health[0].scheduled_at, Some(at(9, 30)));
+
+    // Walk stays selected as it moves and grows into Deep work's block
+    app.handle_key(KeyCode::Char('h')).unwrap();
+    app.handle_key(KeyCode::Char(']')).unwrap();
+    let Some(ModalState::Timeline(state)) = app.modal.clone() else {
+        panic!("expected the timeline");
+    };
+    let walk = &state.blocks[state.selection];
+    assert_eq!(walk.text, "Walk");
+    assert_eq!(walk.span_label(), "09:15-10:00");
+    assert_eq!(timeline::conflicts(&state.blocks).len(), 2);
+
+    app.handle_key(KeyCode::Char('L')).unwrap();
+    app.handle_key(KeyCode::Char('x')).unwrap();
+    let meta = load_or_create_day_meta(app.goals.date, &app.goals, &config).unwrap();
+    assert_eq!(meta.health[0].scheduled_at, None);
+    assert_eq!(meta.health[0].estimated_min, Some(45));
+
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(app.modal.is_none());
+}
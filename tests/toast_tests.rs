@@ -0,0 +1,55 @@
+mod common;
+
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+fn rows(app: &mut App) -> Vec<String> {
+    let mut terminal = Terminal::new(TestBackend::new(160, 40)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect())
+        .collect()
+}
+
+#[test]
+fn a_quick_save_does_not_hide_the_error_after_it() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+    app.toasts.clear();
+
+    app.toasts.show_success("Saved".to_string());
+    app.toasts.show_error("Sync folder is missing".to_string());
+    let screen = rows(&mut app);
+    let error = screen
+        .iter()
+        .position(|row| row.contains("✗ Sync folder is missing"))
+        .unwrap();
+    let saved = screen
+        .iter()
+        .position(|row| row.contains("✓ Saved"))
+        .unwrap();
+    assert!(error < saved, "the newest toast is on top");
+
+    // Both stay in the history after they are dismissed
+    app.handle_key(KeyCode::Char('!')).unwrap();
+    assert!(matches!(app.modal, Some(ModalState::Messages(0))));
+    assert!(!app.toasts.is_active());
+    let screen = rows(&mut app).join("\n");
+    assert!(screen.contains("Messages (2)"));
+    let error = screen.find("Sync folder is missing").unwrap();
+    let saved = screen.find("✓ Saved").unwrap();
+    assert!(error < saved);
+
+    app.handle_key(KeyCode::Char('j')).unwrap();
+    assert!(!rows(&mut app).join("\n").contains("Sync folder is missing"));
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(app.modal.is_none());
+}
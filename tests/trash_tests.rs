@@ -0,0 +1,202 @@
+mod common;
+
+use chrono::{Duration, NaiveDate, Utc};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{
+    append_observation, goals_file_path, load_or_create_indicators, load_or_create_objectives,
+    load_or_create_trash, read_goals_file, read_observations_range, save_indicators,
+    save_objectives, save_settings, save_trash, write_goals_file,
+};
+use focusfive::models::{
+    Config, DailyGoals, IndicatorDef, IndicatorKind, IndicatorUnit, IndicatorsData, Objective,
+    ObjectivesData, Observation, OutcomeType, Settings, TrashData, Trashed,
+};
+use focusfive::trash::{self, TrashKind};
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+
+/// An objective with an indicator, linked from the first work action of
+/// each of `dates`
+fn linked_objective(dates: &[NaiveDate], config: &Config) -> (Objective, IndicatorDef) {
+    let mut objective = Objective::new(OutcomeType::Work, "Ship v2".to_string());
+    let mut indicator = IndicatorDef::new(
+        "Releases".to_string(),
+        IndicatorKind::Lagging,
+        IndicatorUnit::Count,
+    );
+    indicator.objective_id = Some(objective.id.clone());
+    objective.indicators.push(indicator.id.clone());
+    save_objectives(
+        &ObjectivesData {
+            objectives: vec![objective.clone()],
+            ..Default::default()
+        },
+        config,
+    )
+    .unwrap();
+    save_indicators(
+        &IndicatorsData {
+            indicators: vec![indicator.clone()],
+            ..Default::default()
+        },
+        config,
+    )
+    .unwrap();
+    for &date in dates {
+        let mut goals = DailyGoals::new(date);
+        goals.work.actions[0].text = "Cut the release".to_string();
+        goals.work.actions[0].add_objective_id(objective.id.clone());
+        write_goals_file(&goals, config).unwrap();
+    }
+    (objective, indicator)
+}
+
+fn linked_ids(date: NaiveDate, config: &Config) -> Vec<String> {
+    read_goals_file(&goals_file_path(date, config))
+        .unwrap()
+        .work
+        .actions[0]
+        .get_all_objective_ids()
+}
+
+#[test]
+fn trashed_objective_keeps_its_links_until_restored() {
+    let (_temp_dir, config) = temp_config();
+    let date = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+    let (objective, _) = linked_objective(&[date], &config);
+
+    let mut objectives = load_or_create_objectives(&config).unwrap();
+    trash::trash_objective(&mut objectives, &objective.id, &config).unwrap();
+    assert!(objectives.objectives.is_empty());
+    assert!(load_or_create_objectives(&config)
+        .unwrap()
+        .objectives
+        .is_empty());
+    assert_eq!(linked_ids(date, &config), vec![objective.id.clone()]);
+    // Links to the trash are not reported as broken
+    assert!(focusfive::doctor::run(&config, false).unwrap().is_clean());
+
+    let name = trash::restore(TrashKind::Objective, &objective.id, &config).unwrap();
+    assert_eq!(name, "Ship v2");
+    assert_eq!(
+        load_or_create_objectives(&config).unwrap().objectives[0].id,
+        objective.id
+    );
+    assert!(load_or_create_trash(&config).unwrap().objectives.is_empty());
+}
+
+#[test]
+fn purging_an_objective_unlinks_it_everywhere() {
+    let (_temp_dir, config) = temp_config();
+    let dates = [
+        NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+        NaiveDate::from_ymd_opt(2025, 3, 2).unwrap(),
+    ];
+    let (objective, _) = linked_objective(&dates, &config);
+    let mut objectives = load_or_create_objectives(&config).unwrap();
+    trash::trash_objective(&mut objectives, &objective.id, &config).unwrap();
+
+    trash::purge(TrashKind::Objective, &objective.id, &config).unwrap();
+    for date in dates {
+        assert!(linked_ids(date, &config).is_empty());
+    }
+    assert_eq!(
+        load_or_create_indicators(&config).unwrap().indicators[0].objective_id,
+        None
+    );
+    assert!(trash::restore(TrashKind::Objective, &objective.id, &config).is_err());
+}
+
+#[test]
+fn purging_an_indicator_drops_its_observations() {
+    let (_temp_dir, config) = temp_config();
+    let date = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+    let (_, indicator) = linked_objective(&[], &config);
+    append_observation(
+        &Observation::new(indicator.id.clone(), date, 1.0, IndicatorUnit::Count),
+        &config,
+    )
+    .unwrap();
+
+    let mut indicators = load_or_create_indicators(&config).unwrap();
+    trash::trash_indicator(&mut indicators, &indicator.id, &config).unwrap();
+    assert_eq!(
+        read_observations_range(date, date, &config).unwrap().len(),
+        1
+    );
+    assert!(focusfive::doctor::run(&config, false).unwrap().is_clean());
+
+    trash::purge(TrashKind::Indicator, &indicator.id, &config).unwrap();
+    assert!(read_observations_range(date, date, &config)
+        .unwrap()
+        .is_empty());
+    assert!(load_or_create_objectives(&config).unwrap().objectives[0]
+        .indicators
+        .is_empty());
+}
+
+#[test]
+fn only_expired_items_are_purged() {
+    let (_temp_dir, config) = temp_config();
+    save_settings(
+        &Settings {
+            trash_retention_days: Some(7),
+            ..Default::default()
+        },
+        &config,
+    )
+    .unwrap();
+    let old = Objective::new(OutcomeType::Work, "Old".to_string());
+    let recent = Objective::new(OutcomeType::Health, "Recent".to_string());
+    save_trash(
+        &TrashData {
+            objectives: vec![
+                Trashed {
+                    item: old,
+                    trashed: Utc::now() - Duration::days(8),
+                },
+                Trashed {
+                    item: recent.clone(),
+                    trashed: Utc::now() - Duration::days(6),
+                },
+            ],
+            ..Default::default()
+        },
+        &config,
+    )
+    .unwrap();
+
+    assert_eq!(trash::purge_expired(&config).unwrap(), 1);
+    let left = trash::entries(&load_or_create_trash(&config).unwrap());
+    assert_eq!(left.len(), 1);
+    assert_eq!(left[0].id, recent.id);
+}
+
+#[test]
+fn trash_browser_restores_and_purges() {
+    let (_temp_dir, config) = temp_config();
+    let (objective, indicator) = linked_objective(&[], &config);
+    let mut objectives = load_or_create_objectives(&config).unwrap();
+    trash::trash_objective(&mut objectives, &objective.id, &config).unwrap();
+    let mut indicators = load_or_create_indicators(&config).unwrap();
+    trash::trash_indicator(&mut indicators, &indicator.id, &config).unwrap();
+
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    assert!(app.objectives.objectives.is_empty());
+    app.handle_key(KeyCode::Char('Z')).unwrap();
+    assert!(matches!(app.modal, Some(ModalState::Trash(_))));
+
+    // Newest first: the indicator, restored with Enter
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert_eq!(app.indicators.indicators[0].id, indicator.id);
+
+    // The objective needs d twice to go for good
+    app.handle_key(KeyCode::Char('d')).unwrap();
+    assert_eq!(load_or_create_trash(&config).unwrap().objectives.len(), 1);
+    app.handle_key(KeyCode::Char('d')).unwrap();
+    assert!(app.modal.is_none());
+    assert!(load_or_create_trash(&config).unwrap().objectives.is_empty());
+    assert!(app.objectives.objectives.is_empty());
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use common::temp_config;
+use focusfive::command::{parse, run_headless, ExCommand};
+use focusfive::data::load_settings;
+use focusfive::models::{DistanceUnit, DurationDisplay, IndicatorUnit, UnitPreferences};
+use focusfive::units::{display, parse_value};
+
+fn today() -> chrono::NaiveDate {
+    chrono::Local::now().date_naive()
+}
+
+#[test]
+fn entries_convert_into_the_indicators_unit() {
+    let minutes = IndicatorUnit::Minutes;
+    assert_eq!(parse_value("45", &minutes).unwrap(), 45.0);
+    assert_eq!(parse_value("1.5h", &minutes).unwrap(), 90.0);
+    assert_eq!(parse_value("1h30m", &minutes).unwrap(), 90.0);
+    assert_eq!(parse_value("1h30", &minutes).unwrap(), 90.0);
+    assert_eq!(parse_value("90s", &minutes).unwrap(), 1.5);
+
+    let hours = IndicatorUnit::Custom("hours".to_string());
+    assert_eq!(parse_value("45m", &hours).unwrap(), 0.75);
+
+    let dollars = IndicatorUnit::Dollars;
+    assert_eq!(parse_value("1.2k", &dollars).unwrap(), 1200.0);
+    assert_eq!(parse_value("$1,250", &dollars).unwrap(), 1250.0);
+    assert_eq!(parse_value("2m", &dollars).unwrap(), 2_000_000.0);
+
+    let km = IndicatorUnit::Custom("km".to_string());
+    let miles = IndicatorUnit::Custom("miles".to_string());
+    assert!((parse_value("5mi", &km).unwrap() - 8.04672).abs() < 1e-9);
+    assert!((parse_value("10km", &miles).unwrap() - 6.213712).abs() < 1e-6);
+    assert_eq!(parse_value("800m", &km).unwrap(), 0.8);
+    assert_eq!(parse_value("3km", &km).unwrap(), 3.0);
+}
+
+#[test]
+fn mismatched_suffixes_are_rejected() {
+    assert!(parse_value("5mi", &IndicatorUnit::Minutes).is_err());
+    assert!(parse_value("1h", &IndicatorUnit::Dollars).is_err());
+    assert!(parse_value("1k2k", &IndicatorUnit::Dollars).is_err());
+    assert!(parse_value("3h", &IndicatorUnit::Count).is_err());
+    assert!(parse_value("", &IndicatorUnit::Count).is_err());
+    assert_eq!(
+        parse_value("4 pages", &IndicatorUnit::Custom("pages".to_string())).unwrap(),
+        4.0
+    );
+}
+
+#[test]
+fn display_follows_the_preferences() {
+    let defaults = UnitPreferences::default();
+    assert_eq!(display(90.0, &IndicatorUnit::Minutes, &defaults), None);
+    assert_eq!(display(1200.0, &IndicatorUnit::Dollars, &defaults), None);
+
+    let preferences = UnitPreferences {
+        duration: DurationDisplay::Hours,
+        distance: Some(DistanceUnit::Miles),
+        compact_money: true,
+    };
+    assert_eq!(
+        display(90.0, &IndicatorUnit::Minutes, &preferences).as_deref(),
+        Some("1h30m")
+    );
+    assert_eq!(
+        display(1200.0, &IndicatorUnit::Dollars, &preferences).as_deref(),
+        Some("$1.2k")
+    );
+    assert_eq!(
+        display(950.0, &IndicatorUnit::Dollars, &preferences).as_deref(),
+        Some("$950")
+    );
+    assert_eq!(
+        display(
+            8.04672,
+            &IndicatorUnit::Custom("km".to_string()),
+            &preferences
+        )
+        .as_deref(),
+        Some("5.0 mi")
+    );
+    // Already in the preferred unit
+    assert_eq!(
+        display(
+            5.0,
+            &IndicatorUnit::Custom("miles".to_string()),
+            &preferences
+        ),
+        None
+    );
+}
+
+#[test]
+fn units_command_saves_preferences() {
+    let (_temp, config) = temp_config();
+    let command = parse("units hours miles compact").unwrap();
+    assert_eq!(
+        command,
+        ExCommand::Units(vec![
+            "hours".to_string(),
+            "miles".to_string(),
+            "compact".to_string()
+        ])
+    );
+    let message = run_headless(&command, today(), &config).unwrap();
+    assert_eq!(
+        message,
+        "Units: durations in hours, distances in mi, compact dollars"
+    );
+
+    let settings = load_settings(&config).unwrap();
+    assert_eq!(settings.units.duration, DurationDisplay::Hours);
+    assert_eq!(settings.units.distance, Some(DistanceUnit::Miles));
+    assert!(settings.units.compact_money);
+
+    run_headless(
+        &parse("units minutes defined plain").unwrap(),
+        today(),
+        &config,
+    )
+    .unwrap();
+    assert!(load_settings(&config).unwrap().units.is_default());
+    assert!(parse("units furlongs").is_err());
+    assert!(parse("units").is_err());
+}
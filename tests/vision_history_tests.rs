@@ -0,0 +1,114 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{load_vision_history, save_vision};
+use focusfive::models::{FiveYearVision, OutcomeType};
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+use focusfive::vision_history::{self, DiffPart};
+use std::fs;
+
+fn vision(work: &str, health: &str) -> FiveYearVision {
+    let mut vision = FiveYearVision::new();
+    vision.set_vision(&OutcomeType::Work, work.to_string());
+    vision.set_vision(&OutcomeType::Health, health.to_string());
+    vision
+}
+
+#[test]
+fn saving_keeps_a_version_per_text_change() {
+    let (_temp_dir, config) = temp_config();
+
+    save_vision(&vision("Lead a team", "Run a 10k"), &config).unwrap();
+    save_vision(&vision("Lead a team", "Run a 10k"), &config).unwrap();
+    save_vision(&vision("Lead a product team", "Run a 10k"), &config).unwrap();
+    save_vision(&vision("Lead a product team", "Run a marathon"), &config).unwrap();
+
+    let history = load_vision_history(&config).unwrap();
+    assert_eq!(history.len(), 3);
+    assert!(history.windows(2).all(|pair| pair[0].saved < pair[1].saved));
+
+    let work = vision_history::changes(&history, OutcomeType::Work);
+    assert_eq!(work.len(), 2);
+    assert_eq!(work[0].previous, "");
+    assert_eq!(work[1].previous, "Lead a team");
+    assert_eq!(work[1].text, "Lead a product team");
+    assert_eq!(
+        vision_history::changes(&history, OutcomeType::Health).len(),
+        2
+    );
+    assert!(vision_history::changes(&history, OutcomeType::Family).is_empty());
+}
+
+#[test]
+fn first_save_keeps_the_vision_written_before_history_existed() {
+    let (temp_dir, config) = temp_config();
+    let mut old = vision("Start a business", "");
+    old.modified = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    fs::write(
+        temp_dir.path().join("vision.json"),
+        serde_json::to_string(&old).unwrap(),
+    )
+    .unwrap();
+
+    save_vision(&vision("Grow the business", ""), &config).unwrap();
+    let history = load_vision_history(&config).unwrap();
+    assert_eq!(history.len(), 2);
+    assert_eq!(
+        history[0].saved,
+        NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    );
+    assert_eq!(history[0].vision.work, "Start a business");
+    assert_eq!(history[1].vision.work, "Grow the business");
+}
+
+#[test]
+fn word_diff_marks_added_and_removed_runs() {
+    assert_eq!(
+        vision_history::diff_words("Run a 10k every spring", "Run a full marathon every year"),
+        [
+            DiffPart::Same("Run a".to_string()),
+            DiffPart::Removed("10k".to_string()),
+            DiffPart::Added("full marathon".to_string()),
+            DiffPart::Same("every".to_string()),
+            DiffPart::Removed("spring".to_string()),
+            DiffPart::Added("year".to_string()),
+        ]
+    );
+    assert_eq!(
+        vision_history::diff_words("", "New text"),
+        [DiffPart::Added("New text".to_string())]
+    );
+    assert!(vision_history::diff_words("", "").is_empty());
+}
+
+#[test]
+fn v_key_opens_the_viewer_and_tab_switches_outcome() {
+    let (_temp_dir, config) = temp_config();
+    save_vision(&vision("Lead a team", "Run a 10k"), &config).unwrap();
+    save_vision(&vision("Lead a product team", "Run a 10k"), &config).unwrap();
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+
+    app.handle_key(KeyCode::Char('V')).unwrap();
+    let Some(ModalState::VisionHistory(state)) = app.modal.clone() else {
+        panic!("expected the vision history");
+    };
+    assert_eq!(state.outcome_type, OutcomeType::Work);
+    assert_eq!(state.changes.len(), 2);
+
+    app.handle_key(KeyCode::Tab).unwrap();
+    let Some(ModalState::VisionHistory(state)) = app.modal.clone() else {
+        panic!("expected the vision history");
+    };
+    assert_eq!(state.outcome_type, OutcomeType::Health);
+    assert_eq!(state.changes.len(), 1);
+
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(app.modal.is_none());
+}
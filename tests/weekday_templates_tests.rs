@@ -0,0 +1,156 @@
+mod common;
+
+use chrono::{Datelike, Duration, Local, NaiveDate, Weekday};
+use common::temp_config;
+use focusfive::command::{parse, run_headless, ExCommand};
+use focusfive::data::{
+    load_or_create_goals, load_or_create_templates, save_templates, write_goals_file,
+};
+use focusfive::models::{ActionTemplates, DailyGoals, WeekdayTemplate};
+
+fn texts(list: &[&str]) -> Vec<String> {
+    list.iter().map(|text| text.to_string()).collect()
+}
+
+fn monday_planning() -> WeekdayTemplate {
+    WeekdayTemplate {
+        weekdays: vec![Weekday::Mon],
+        work: texts(&["Plan the week", "Inbox zero"]),
+        health: texts(&["Meal prep"]),
+        family: Vec::new(),
+    }
+}
+
+/// The first day from today on that falls on `weekday`
+fn next(weekday: Weekday) -> NaiveDate {
+    let today = Local::now().date_naive();
+    let ahead = (7 + weekday.num_days_from_monday() - today.weekday().num_days_from_monday()) % 7;
+    today + Duration::days(ahead as i64)
+}
+
+#[test]
+fn weekday_templates_are_bound_by_weekday() {
+    let mut templates = ActionTemplates::new();
+    templates.set_weekday_template("Monday planning".to_string(), monday_planning());
+    templates.set_weekday_template(
+        "Review".to_string(),
+        WeekdayTemplate {
+            weekdays: vec![Weekday::Fri, Weekday::Mon, Weekday::Fri],
+            work: texts(&["Weekly review", "", "a", "b", "c", "d", "e"]),
+            ..Default::default()
+        },
+    );
+    let review = &templates.weekday_templates["Review"];
+    assert_eq!(review.weekdays, [Weekday::Mon, Weekday::Fri]);
+    assert_eq!(review.work, texts(&["Weekly review", "a", "b", "c", "d"]));
+
+    let monday = NaiveDate::from_ymd_opt(2025, 6, 2).unwrap();
+    let names: Vec<_> = templates
+        .weekday_templates_for(monday)
+        .into_iter()
+        .map(|(name, _)| name.as_str())
+        .collect();
+    assert_eq!(names, ["Monday planning", "Review"]);
+    assert!(templates
+        .weekday_templates_for(monday + Duration::days(1))
+        .is_empty());
+
+    let mut goals = DailyGoals::new(monday);
+    goals.work.actions[0].text = "Inbox zero".to_string();
+    assert_eq!(templates.apply_weekday_templates(&mut goals), 5);
+    let work: Vec<_> = goals.work.actions.iter().map(|a| a.text.as_str()).collect();
+    assert_eq!(
+        work,
+        ["Inbox zero", "Plan the week", "Weekly review", "a", "b"]
+    );
+    assert_eq!(goals.health.actions[0].text, "Meal prep");
+
+    // Older files without weekday templates still load
+    let json = r#"{"templates":{"Dev":["Review"]},"created":"2025-01-01","modified":"2025-01-01"}"#;
+    let old: ActionTemplates = serde_json::from_str(json).unwrap();
+    assert!(old.weekday_templates.is_empty());
+    assert!(!serde_json::to_string(&old)
+        .unwrap()
+        .contains("weekday_templates"));
+}
+
+#[test]
+fn new_day_files_start_with_their_weekday_templates() {
+    let (_temp_dir, config) = temp_config();
+    let mut templates = ActionTemplates::new();
+    templates.set_weekday_template("Monday planning".to_string(), monday_planning());
+    save_templates(&templates, &config).unwrap();
+
+    let monday = next(Weekday::Mon);
+    let goals = load_or_create_goals(monday, &config).unwrap();
+    assert_eq!(goals.work.actions[0].text, "Plan the week");
+    assert_eq!(goals.work.actions[1].text, "Inbox zero");
+    assert_eq!(goals.health.actions[0].text, "Meal prep");
+
+    // Other weekdays, past days and existing files are left alone
+    let tuesday = load_or_create_goals(monday + Duration::days(1), &config).unwrap();
+    assert!(tuesday.work.actions[0].text.is_empty());
+    let past = load_or_create_goals(monday - Duration::days(7), &config).unwrap();
+    assert!(past.work.actions[0].text.is_empty());
+    let mut edited = DailyGoals::new(monday + Duration::days(7));
+    edited.work.actions[0].text = "Holiday".to_string();
+    write_goals_file(&edited, &config).unwrap();
+    let loaded = load_or_create_goals(edited.date, &config).unwrap();
+    assert_eq!(loaded.work.actions[0].text, "Holiday");
+    assert!(loaded.work.actions[1].text.is_empty());
+}
+
+#[test]
+fn daytemplate_command_saves_and_removes() {
+    let (_temp_dir, config) = temp_config();
+    assert_eq!(
+        parse("daytemplate 'Friday review' weekdays").unwrap(),
+        ExCommand::DayTemplate {
+            name: "Friday review".to_string(),
+            weekdays: vec![
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri
+            ],
+        }
+    );
+    assert_eq!(
+        parse("dt x sat,Sunday").unwrap(),
+        ExCommand::DayTemplate {
+            name: "x".to_string(),
+            weekdays: vec![Weekday::Sat, Weekday::Sun],
+        }
+    );
+    assert!(parse("daytemplate x someday").is_err());
+    assert!(parse("daytemplate x").is_err());
+
+    let today = NaiveDate::from_ymd_opt(2025, 6, 6).unwrap();
+    let command = parse("daytemplate 'Friday review' fri").unwrap();
+    assert!(run_headless(&command, today, &config).is_err());
+
+    let mut goals = DailyGoals::new(today);
+    goals.work.actions[0].text = "Weekly review".to_string();
+    goals.family.actions[2].text = "Pizza night".to_string();
+    write_goals_file(&goals, &config).unwrap();
+    let message = run_headless(&command, today, &config).unwrap();
+    assert_eq!(
+        message,
+        "Saved 'Friday review' with 2 actions for new Fri days"
+    );
+
+    let templates = load_or_create_templates(&config).unwrap();
+    let saved = &templates.weekday_templates["Friday review"];
+    assert_eq!(saved.weekdays, [Weekday::Fri]);
+    assert_eq!(saved.work, texts(&["Weekly review"]));
+    assert_eq!(saved.family, texts(&["Pizza night"]));
+
+    let remove = parse("daytemplate 'Friday review' none").unwrap();
+    run_headless(&remove, today, &config).unwrap();
+    assert!(load_or_create_templates(&config)
+        .unwrap()
+        .weekday_templates
+        .is_empty());
+    assert!(run_headless(&remove, today, &config).is_err());
+}
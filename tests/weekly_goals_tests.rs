@@ -0,0 +1,127 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{
+    load_or_create_day_meta, load_weekly_goals, save_weekly_goals, set_weekly_focus_link,
+    write_goals_file,
+};
+use focusfive::models::{
+    iso_week_of, DailyGoals, OutcomeType, WeeklyGoals, MAX_WEEKLY_FOCUS_LENGTH,
+};
+use focusfive::ui::app::{FocusPanel, ModalState};
+use focusfive::ui::App;
+
+#[test]
+fn weekly_goals_round_trip_per_iso_week() {
+    let (temp_dir, config) = temp_config();
+    // Sunday of 2025-W01, which starts in December 2024
+    let date = NaiveDate::from_ymd_opt(2025, 1, 5).unwrap();
+    assert_eq!(iso_week_of(date), (2025, 1));
+    assert_eq!(
+        iso_week_of(NaiveDate::from_ymd_opt(2024, 12, 30).unwrap()),
+        (2025, 1)
+    );
+
+    let empty = load_weekly_goals((2025, 1), &config).unwrap();
+    assert_eq!(empty, WeeklyGoals::new((2025, 1)));
+    assert_eq!(
+        empty.start(),
+        Some(NaiveDate::from_ymd_opt(2024, 12, 30).unwrap())
+    );
+
+    let mut weekly = WeeklyGoals::new((2025, 1));
+    weekly.set_focus(OutcomeType::Work, "  Ship the beta  ");
+    weekly.set_focus(
+        OutcomeType::Family,
+        &"x".repeat(MAX_WEEKLY_FOCUS_LENGTH + 5),
+    );
+    let path = save_weekly_goals(&weekly, &config).unwrap();
+    assert_eq!(path, temp_dir.path().join("weekly/2025-W01.json"));
+
+    let loaded = load_weekly_goals((2025, 1), &config).unwrap();
+    assert_eq!(loaded.get_focus(OutcomeType::Work), "Ship the beta");
+    assert_eq!(loaded.get_focus(OutcomeType::Health), "");
+    assert_eq!(
+        loaded.get_focus(OutcomeType::Family).len(),
+        MAX_WEEKLY_FOCUS_LENGTH
+    );
+    assert_eq!(
+        load_weekly_goals((2025, 2), &config).unwrap().work,
+        String::new()
+    );
+}
+
+#[test]
+fn actions_link_to_the_weekly_focus_in_day_metadata() {
+    let (_temp_dir, config) = temp_config();
+    let mut goals = DailyGoals::new(NaiveDate::from_ymd_opt(2025, 6, 4).unwrap());
+    goals.work.actions[0].text = "Write beta notes".to_string();
+    write_goals_file(&goals, &config).unwrap();
+
+    set_weekly_focus_link(&goals, OutcomeType::Work, 0, true, &config).unwrap();
+    assert!(set_weekly_focus_link(&goals, OutcomeType::Work, 9, true, &config).is_err());
+    let meta = load_or_create_day_meta(goals.date, &goals, &config).unwrap();
+    assert!(meta.work[0].weekly_focus);
+    assert!(!meta.work[1].weekly_focus);
+
+    set_weekly_focus_link(&goals, OutcomeType::Work, 0, false, &config).unwrap();
+    let meta = load_or_create_day_meta(goals.date, &goals, &config).unwrap();
+    assert!(!meta.work[0].weekly_focus);
+}
+
+#[test]
+fn weekly_plan_screen_edits_focus_and_w_links_actions() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    let week = iso_week_of(app.goals.date);
+
+    app.selected_outcome = OutcomeType::Health;
+    app.handle_key(KeyCode::Char('W')).unwrap();
+    let Some(ModalState::WeeklyPlan(state)) = app.modal.clone() else {
+        panic!("expected the weekly plan");
+    };
+    assert_eq!(state.selection, 1);
+    assert_eq!(state.weekly.week_iso(), week);
+
+    app.handle_key(KeyCode::Enter).unwrap();
+    for c in "Run three times".chars() {
+        app.handle_key(KeyCode::Char(c)).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert_eq!(
+        app.weekly_goals.get_focus(OutcomeType::Health),
+        "Run three times"
+    );
+    assert_eq!(
+        load_weekly_goals(week, &config)
+            .unwrap()
+            .get_focus(OutcomeType::Health),
+        "Run three times"
+    );
+
+    // Next week is planned separately
+    app.handle_key(KeyCode::Char('l')).unwrap();
+    let Some(ModalState::WeeklyPlan(state)) = app.modal.clone() else {
+        panic!("expected the weekly plan");
+    };
+    assert_ne!(state.weekly.week_iso(), week);
+    assert_eq!(state.weekly.get_focus(OutcomeType::Health), "");
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(app.modal.is_none());
+
+    app.focus_panel = FocusPanel::Actions;
+    app.handle_key(KeyCode::Char('w')).unwrap();
+    assert!(app.weekly_linked.is_empty(), "empty actions are not linked");
+
+    app.goals.health.actions[0].text = "Morning run".to_string();
+    app.handle_key(KeyCode::Char('w')).unwrap();
+    assert!(app.weekly_linked.contains(&(OutcomeType::Health, 0)));
+    let meta = load_or_create_day_meta(app.goals.date, &app.goals, &config).unwrap();
+    assert!(meta.health[0].weekly_focus);
+
+    app.handle_key(KeyCode::Char('w')).unwrap();
+    assert!(app.weekly_linked.is_empty());
+}
@@ -0,0 +1,98 @@
+mod common;
+
+use chrono::Local;
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::changelog::{self, CURRENT_VERSION, RELEASES};
+use focusfive::command::{self, ExCommand};
+use focusfive::data::{load_settings, save_settings};
+use focusfive::models::Settings;
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+fn render(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(140, 45)).unwrap();
+    terminal.draw(|frame| app.render(frame)).unwrap();
+    let buffer = terminal.backend().buffer().clone();
+    buffer
+        .content()
+        .chunks(buffer.area.width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn notes_show_once_after_an_upgrade() {
+    let (_temp_dir, config) = temp_config();
+    let settings = Settings {
+        last_seen_version: Some("0.0.1".to_string()),
+        ..Settings::default()
+    };
+    save_settings(&settings, &config).unwrap();
+
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    assert!(matches!(app.modal, Some(ModalState::WhatsNew(_))));
+    let screen = render(&mut app);
+    assert!(screen.contains("What's new in FocusFive"));
+    assert!(screen.contains(&format!("Version {}", CURRENT_VERSION)));
+    assert!(screen.contains("Dashboard of your indicators"));
+
+    // Keys go to the notes while they are open
+    app.handle_key(KeyCode::Char('j')).unwrap();
+    assert!(matches!(app.modal, Some(ModalState::WhatsNew(ref state)) if state.scroll == 1));
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(app.modal.is_none());
+    assert_eq!(
+        load_settings(&config).unwrap().last_seen_version.as_deref(),
+        Some(CURRENT_VERSION)
+    );
+
+    let app = App::new(config).unwrap();
+    assert!(app.modal.is_none());
+}
+
+#[test]
+fn new_installs_start_from_this_version() {
+    let (_temp_dir, config) = temp_config();
+    let app = App::new(config.clone()).unwrap();
+    assert!(app.modal.is_none());
+    assert_eq!(
+        load_settings(&config).unwrap().last_seen_version.as_deref(),
+        Some(CURRENT_VERSION)
+    );
+}
+
+#[test]
+fn whatsnew_lists_every_release() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config.clone()).unwrap();
+    app.wait_for_background();
+    app.handle_key(KeyCode::Char(':')).unwrap();
+    for c in "whatsnew".chars() {
+        app.handle_key(KeyCode::Char(c)).unwrap();
+    }
+    app.handle_key(KeyCode::Enter).unwrap();
+    assert!(
+        matches!(app.modal, Some(ModalState::WhatsNew(ref state)) if state.releases.len() == RELEASES.len())
+    );
+
+    assert_eq!(command::parse(":news").unwrap(), ExCommand::WhatsNew);
+    let text =
+        command::run_headless(&ExCommand::WhatsNew, Local::now().date_naive(), &config).unwrap();
+    assert!(text.starts_with(&format!("What's new in {}", RELEASES[0].version)));
+    assert!(text.contains("F12"));
+}
+
+#[test]
+fn releases_are_compared_by_version() {
+    // Bumping the version in Cargo.toml needs notes for it
+    assert_eq!(RELEASES[0].version, CURRENT_VERSION);
+    assert_eq!(changelog::unseen("0.0.9").len(), RELEASES.len());
+    assert_eq!(changelog::unseen("v0.0.10").len(), RELEASES.len());
+    assert!(changelog::unseen(CURRENT_VERSION).is_empty());
+    assert!(changelog::unseen("10.0.0").is_empty());
+}
@@ -0,0 +1,184 @@
+mod common;
+
+use chrono::{Datelike, Local, NaiveDate};
+use common::temp_config;
+use crossterm::event::KeyCode;
+use focusfive::data::{append_observation, save_indicators, save_objectives, write_goals_file};
+use focusfive::models::{
+    Action, Config, DailyGoals, IndicatorDef, IndicatorDirection, IndicatorKind, IndicatorUnit,
+    IndicatorsData, Objective, ObjectiveStatus, ObjectivesData, Observation, OutcomeType,
+};
+use focusfive::ui::app::ModalState;
+use focusfive::ui::App;
+use focusfive::year_review;
+use std::fs;
+
+fn date(month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+}
+
+/// A day with two work actions, `done` of them completed
+fn write_day(date: NaiveDate, done: usize, config: &Config) {
+    let mut goals = DailyGoals::new(date);
+    for i in 0..2 {
+        let mut action = Action::new(format!("Task {}", i + 1));
+        action.completed = i < done;
+        goals.work.actions[i] = action;
+    }
+    write_goals_file(&goals, config).unwrap();
+}
+
+fn seed_year(config: &Config) {
+    for day in 1..=4 {
+        write_day(date(1, day), 2, config);
+    }
+    write_day(date(1, 10), 1, config);
+    write_day(date(3, 5), 0, config);
+    write_day(date(3, 6), 1, config);
+
+    let mut shipped = Objective::new(OutcomeType::Work, "Ship the beta".to_string());
+    shipped.status = ObjectiveStatus::Completed;
+    shipped.end = Some(date(2, 14));
+    let mut marathon = Objective::new(OutcomeType::Health, "Run a marathon".to_string());
+    marathon.status = ObjectiveStatus::Completed;
+    marathon.end = Some(date(10, 1));
+    let mut last_year = Objective::new(OutcomeType::Family, "Plan the move".to_string());
+    last_year.status = ObjectiveStatus::Completed;
+    last_year.end = NaiveDate::from_ymd_opt(2024, 12, 1);
+    let open = Objective::new(OutcomeType::Work, "Hire a designer".to_string());
+    save_objectives(
+        &ObjectivesData {
+            objectives: vec![shipped, marathon, last_year, open],
+            ..Default::default()
+        },
+        config,
+    )
+    .unwrap();
+
+    let steps = IndicatorDef::new(
+        "Steps".to_string(),
+        IndicatorKind::Leading,
+        IndicatorUnit::Count,
+    );
+    let mut resting = IndicatorDef::new(
+        "Resting HR".to_string(),
+        IndicatorKind::Lagging,
+        IndicatorUnit::Count,
+    );
+    resting.direction = IndicatorDirection::LowerIsBetter;
+    for (when, step_count, heart_rate) in [
+        (date(2, 1), 9000.0, 62.0),
+        (date(6, 15), 15000.0, 55.0),
+        (date(9, 3), 12000.0, 58.0),
+    ] {
+        append_observation(
+            &Observation::new(steps.id.clone(), when, step_count, IndicatorUnit::Count),
+            config,
+        )
+        .unwrap();
+        append_observation(
+            &Observation::new(resting.id.clone(), when, heart_rate, IndicatorUnit::Count),
+            config,
+        )
+        .unwrap();
+    }
+    save_indicators(
+        &IndicatorsData {
+            indicators: vec![steps, resting],
+            ..Default::default()
+        },
+        config,
+    )
+    .unwrap();
+}
+
+#[test]
+fn review_aggregates_months_streak_objectives_and_bests() {
+    let (_temp_dir, config) = temp_config();
+    seed_year(&config);
+
+    let review =
+        year_review::build(2025, date(12, 31) + chrono::Duration::days(30), &config).unwrap();
+    assert_eq!(review.through, date(12, 31));
+    assert_eq!(review.months.len(), 12);
+    assert_eq!(review.days_tracked, 7);
+    assert_eq!(review.months[0].days_tracked, 5);
+    assert_eq!(review.months[1].days_tracked, 0);
+    assert_eq!(review.months[2].days_tracked, 2);
+    assert!(review.months[0].completion > review.months[2].completion);
+
+    let streak = review.longest_streak.unwrap();
+    assert_eq!(
+        (streak.days, streak.start, streak.end),
+        (4, date(1, 1), date(1, 4))
+    );
+
+    let titles: Vec<_> = review.objectives.iter().map(|o| o.title.as_str()).collect();
+    assert_eq!(titles, ["Run a marathon", "Ship the beta"]);
+
+    let bests: Vec<_> = review
+        .indicator_bests
+        .iter()
+        .map(|best| (best.name.as_str(), best.date))
+        .collect();
+    assert_eq!(bests, [("Resting HR", date(6, 15)), ("Steps", date(6, 15))]);
+}
+
+#[test]
+fn current_year_stops_at_today_and_future_years_fail() {
+    let (_temp_dir, config) = temp_config();
+    seed_year(&config);
+
+    let review = year_review::build(2025, date(3, 31), &config).unwrap();
+    assert_eq!(review.through, date(3, 31));
+    assert_eq!(review.months.len(), 3);
+    assert_eq!(review.objectives.len(), 1);
+    assert!(year_review::build(2026, date(3, 31), &config).is_err());
+}
+
+#[test]
+fn markdown_export_is_written_under_exports() {
+    let (temp_dir, config) = temp_config();
+    seed_year(&config);
+
+    let review = year_review::build(2025, date(12, 31), &config).unwrap();
+    let markdown = year_review::to_markdown(&review);
+    assert!(markdown.starts_with("# FocusFive year in review: 2025"));
+    assert!(markdown.contains("- Days tracked: 7"));
+    assert!(markdown.contains("- Longest streak: 4 days (Jan 01 to Jan 04)"));
+    assert!(markdown.contains("- Health: Run a marathon (Oct 01)"));
+    assert!(markdown.contains("- Steps: 15000"));
+    assert!(!markdown.contains("So far"));
+
+    let path = year_review::write_export(&review, &config).unwrap();
+    assert_eq!(
+        path,
+        temp_dir.path().join("exports").join("year-review-2025.md")
+    );
+    assert_eq!(fs::read_to_string(path).unwrap(), markdown);
+}
+
+#[test]
+fn a_key_opens_the_review_and_h_goes_back_a_year() {
+    let (_temp_dir, config) = temp_config();
+    let mut app = App::new(config).unwrap();
+    app.wait_for_background();
+    let year = Local::now().year();
+
+    app.handle_key(KeyCode::Char('A')).unwrap();
+    let Some(ModalState::YearReview(state)) = app.modal.clone() else {
+        panic!("expected the year review");
+    };
+    assert_eq!(state.review.year, year);
+
+    // The next year has not started yet
+    app.handle_key(KeyCode::Char('l')).unwrap();
+    app.handle_key(KeyCode::Char('h')).unwrap();
+    let Some(ModalState::YearReview(state)) = app.modal.clone() else {
+        panic!("expected the year review");
+    };
+    assert_eq!(state.review.year, year - 1);
+
+    app.handle_key(KeyCode::Esc).unwrap();
+    assert!(app.modal.is_none());
+}